@@ -0,0 +1,276 @@
+//! Finds connection strings in a project folder and validates them, to audit
+//! repositories for hard-coded credentials. The walk honours `.gitignore`.
+//! Strings are picked up from connection-looking variables in `.env` files,
+//! docker-compose files and other config, from the `ConnectionStrings`
+//! section of `appsettings*.json`, and from database URLs in any text file.
+
+use ignore::WalkBuilder;
+use regex::Regex;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use validator_core::{ValidationMessage, ValidationResult};
+use validator_registry::{mask_password, redact, Registry};
+
+/// Files larger than this are skipped; they are rarely hand-written config
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// Extensions of source and config files searched for URLs
+const TEXT_EXTENSIONS: &[&str] = &[
+    "env", "json", "yml", "yaml", "toml", "ini", "cfg", "conf", "properties", "xml", "config", "rs", "go", "py",
+    "js", "mjs", "cjs", "ts", "jsx", "tsx", "java", "kt", "scala", "cs", "rb", "php", "ex", "exs", "sh", "tf",
+];
+
+static URL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?i)\b(?:postgres(?:ql)?|mysql|mariadb|redis|rediss|mongodb(?:\+srv)?|sqlserver|clickhouse)://[^\s'"`<>\\]+"#,
+    )
+    .expect("valid URL pattern")
+});
+
+/// `NAME=value`, `NAME: value`, `export NAME=value` and `- NAME=value`
+static ASSIGNMENT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\s*(?:export\s+|-\s*)?["']?([A-Za-z_][A-Za-z0-9_.]*)["']?\s*[=:]\s*(.+?)\s*$"#)
+        .expect("valid assignment pattern")
+});
+
+/// Variable names that usually hold a connection string
+static CONNECTION_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:database|db|redis|mongo|postgres|pg|mysql|mssql|sql|cache).*(?:url|uri|dsn)|connection_?string")
+        .expect("valid name pattern")
+});
+
+/// Password keys in keyword/value strings such as ADO.NET's
+static PASSWORD_KEY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:^|[;\s])(?:password|pwd)\s*=\s*([^;\s]+)").expect("valid password pattern")
+});
+
+/// Where in the file a connection string was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FindingSource {
+    /// A variable whose name suggests a connection string
+    Variable,
+    /// An entry of the `ConnectionStrings` section of appsettings.json
+    AppSettings,
+    /// A database URL anywhere in the file
+    Url,
+}
+
+/// A connection string found in the project
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub path: PathBuf,
+    /// 1-based line number
+    pub line: usize,
+    pub source: FindingSource,
+    /// Variable or appsettings key holding the string
+    pub name: Option<String>,
+    /// The connection string with its password masked
+    pub value: String,
+    /// Validator that recognised the string; None when none did
+    pub validator: Option<String>,
+    pub result: ValidationResult,
+}
+
+/// Walks a folder looking for connection strings
+pub struct Scanner {
+    registry: Registry,
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self {
+            registry: Registry::new(),
+        }
+    }
+
+    /// Findings in every file under `root` that git would not ignore, in path
+    /// order. Files that cannot be read are skipped.
+    pub fn scan(&self, root: &Path) -> std::io::Result<Vec<Finding>> {
+        if !root.is_dir() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("{} is not a directory", root.display()),
+            ));
+        }
+
+        // Hidden files are searched so .env files are found; .git is not
+        let walker = WalkBuilder::new(root)
+            .hidden(false)
+            .filter_entry(|entry| entry.file_name() != ".git")
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .build();
+
+        let mut findings = Vec::new();
+        for entry in walker.filter_map(Result::ok) {
+            let path = entry.path();
+            let is_small_file = entry
+                .metadata()
+                .is_ok_and(|metadata| metadata.is_file() && metadata.len() <= MAX_FILE_SIZE);
+            if !is_small_file || !is_scanned(path) {
+                continue;
+            }
+            if let Ok(text) = std::fs::read_to_string(path) {
+                let relative = path.strip_prefix(root).unwrap_or(path);
+                findings.extend(self.scan_text(relative, &text));
+            }
+        }
+        Ok(findings)
+    }
+
+    /// Findings in the contents of one file; `path` decides how it is read
+    pub fn scan_text(&self, path: &Path, text: &str) -> Vec<Finding> {
+        let file_name = path.file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+        let mut findings = Vec::new();
+
+        if file_name.starts_with("appsettings") && file_name.ends_with(".json") {
+            findings.extend(self.scan_app_settings(path, text));
+        }
+
+        let reads_variables = !matches!(extension(path).as_deref(), Some("json"))
+            && (is_env_file(&file_name) || !is_source_file(path));
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            if findings.iter().any(|finding| finding.line == line_number) {
+                continue;
+            }
+
+            let variable = ASSIGNMENT
+                .captures(line)
+                .filter(|captures| reads_variables && CONNECTION_NAME.is_match(&captures[1]));
+            if let Some(captures) = variable {
+                let value = unquote(&captures[2]);
+                if !is_placeholder(value) {
+                    findings.push(self.finding(path, line_number, FindingSource::Variable, Some(&captures[1]), value));
+                }
+                continue;
+            }
+
+            for url in URL.find_iter(line) {
+                let value = url.as_str().trim_end_matches([',', ';', ')', ']', '}']);
+                findings.push(self.finding(path, line_number, FindingSource::Url, None, value));
+            }
+        }
+        findings
+    }
+
+    /// Entries of the `ConnectionStrings` section
+    fn scan_app_settings(&self, path: &Path, text: &str) -> Vec<Finding> {
+        let Ok(settings) = serde_json::from_str::<serde_json::Value>(text) else {
+            return vec![];
+        };
+        let Some(connection_strings) = settings.get("ConnectionStrings").and_then(|c| c.as_object()) else {
+            return vec![];
+        };
+
+        connection_strings
+            .iter()
+            .filter_map(|(name, value)| Some((name, value.as_str()?)))
+            .filter(|(_, value)| !is_placeholder(value))
+            .map(|(name, value)| {
+                let line = line_of(text, &format!("\"{}\"", name));
+                self.finding(path, line, FindingSource::AppSettings, Some(name), value)
+            })
+            .collect()
+    }
+
+    fn finding(&self, path: &Path, line: usize, source: FindingSource, name: Option<&str>, value: &str) -> Finding {
+        let detected = if is_ado_net(value) { None } else { self.registry.detect(value) };
+        let (validator, mut result) = match detected {
+            Some((info, result)) => (Some(info.id), result),
+            None => (None, ValidationResult::from_messages(None, vec![], vec![])),
+        };
+
+        let parsed_password = result.parsed.as_ref().and_then(|parsed| parsed.password.clone());
+        let mut masked = redact(value, &mut result);
+        // Strings no validator parsed may still carry a Password= key
+        let password = match parsed_password {
+            Some(password) => Some(password),
+            None => PASSWORD_KEY.captures(value).map(|captures| {
+                masked = mask_password(&masked, &captures[1]);
+                captures[1].to_string()
+            }),
+        };
+
+        if password.is_some_and(|password| !password.is_empty() && !is_placeholder(&password)) {
+            result.warnings.push(
+                ValidationMessage::new(
+                    "HARDCODED_PASSWORD",
+                    "The connection string contains a password; load it from the environment or a secret store",
+                )
+                .with_field("password"),
+            );
+        }
+
+        Finding {
+            path: path.to_path_buf(),
+            line,
+            source,
+            name: name.map(str::to_string),
+            value: masked,
+            validator,
+            result,
+        }
+    }
+}
+
+impl Default for Scanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension().map(|extension| extension.to_string_lossy().to_lowercase())
+}
+
+fn is_env_file(file_name: &str) -> bool {
+    file_name == ".env" || file_name.starts_with(".env.") || file_name.ends_with(".env")
+}
+
+fn is_scanned(path: &Path) -> bool {
+    let file_name = path.file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+    is_env_file(&file_name)
+        || file_name == "dockerfile"
+        || extension(path).is_some_and(|extension| TEXT_EXTENSIONS.contains(&extension.as_str()))
+}
+
+/// Program source, where only URLs are looked for; assignments in code are
+/// too varied to read reliably
+fn is_source_file(path: &Path) -> bool {
+    let config = ["env", "yml", "yaml", "toml", "ini", "cfg", "conf", "properties", "sh", "tf"];
+    extension(path).is_some_and(|extension| !config.contains(&extension.as_str()))
+}
+
+/// `Server=...;Database=...;` strings used by .NET, which no validator reads
+/// yet; the Go validator would otherwise take them for lib/pq keyword DSNs
+fn is_ado_net(value: &str) -> bool {
+    !value.contains("://") && value.contains('=') && value.trim_end().trim_end_matches(';').contains(';')
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote).and_then(|v| v.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    // Unquoted values may carry a trailing comment
+    value.split(" #").next().unwrap_or(value).trim()
+}
+
+/// Values taken from elsewhere, such as `${DATABASE_URL}` or `%DB_PASSWORD%`
+fn is_placeholder(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty()
+        || value.starts_with('$')
+        || (value.starts_with('%') && value.ends_with('%'))
+        || (value.starts_with("{{") && value.ends_with("}}"))
+        || (value.starts_with('<') && value.ends_with('>'))
+}
+
+fn line_of(text: &str, needle: &str) -> usize {
+    text.lines().position(|line| line.contains(needle)).map_or(1, |index| index + 1)
+}
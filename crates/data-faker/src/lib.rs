@@ -0,0 +1,262 @@
+//! Fake row generation for dbfordevs. A column's name and type pick a
+//! [`FieldKind`], and a [`Faker`] turns kinds into JSON values that the
+//! database drivers bind like any other parameter.
+
+mod words;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+use words::*;
+
+/// How far back generated dates and timestamps reach
+const PAST_DAYS: i64 = 730;
+
+/// What kind of value to generate for a column
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldKind {
+    Email,
+    FirstName,
+    LastName,
+    FullName,
+    Username,
+    Phone,
+    Street,
+    City,
+    Country,
+    PostalCode,
+    Url,
+    Company,
+    Status,
+    /// A few words, for titles and short labels
+    Words,
+    /// A couple of sentences, for descriptions and notes
+    Paragraph,
+    Uuid,
+    Boolean,
+    Integer { min: i64, max: i64 },
+    Decimal { min: f64, max: f64 },
+    Timestamp,
+    Date,
+    Time,
+    Json,
+    IpAddress,
+}
+
+impl FieldKind {
+    /// Pick a kind from a column's name and its database type name
+    pub fn infer(column_name: &str, data_type: &str) -> Self {
+        let name = column_name.to_ascii_lowercase().replace(['-', ' '], "_");
+        let ty = data_type.to_ascii_lowercase();
+        let has = |parts: &[&str]| parts.iter().any(|p| name.contains(p));
+
+        if ty.contains("bool") || ty == "bit" || ty == "tinyint(1)" {
+            return FieldKind::Boolean;
+        }
+        if ty.contains("uuid") || ty.contains("uniqueidentifier") {
+            return FieldKind::Uuid;
+        }
+        if ty.contains("timestamp") || ty.contains("datetime") {
+            return FieldKind::Timestamp;
+        }
+        if ty == "date" {
+            return FieldKind::Date;
+        }
+        if ty.starts_with("time") {
+            return FieldKind::Time;
+        }
+        if ty.contains("json") {
+            return FieldKind::Json;
+        }
+        if ty == "inet" || ty == "cidr" {
+            return FieldKind::IpAddress;
+        }
+        if ty.contains("int") || ty.contains("serial") {
+            return match () {
+                _ if has(&["age"]) => FieldKind::Integer { min: 18, max: 90 },
+                _ if has(&["year"]) => FieldKind::Integer { min: 1990, max: 2030 },
+                _ if has(&["qty", "quantity", "count", "stock"]) => FieldKind::Integer { min: 0, max: 500 },
+                _ if ty.contains("tinyint") => FieldKind::Integer { min: 0, max: 127 },
+                _ if ty.contains("smallint") => FieldKind::Integer { min: 1, max: 1000 },
+                _ => FieldKind::Integer { min: 1, max: 100_000 },
+            };
+        }
+        if ["numeric", "decimal", "real", "float", "double", "money"].iter().any(|t| ty.contains(t)) {
+            return match () {
+                _ if has(&["rate", "ratio", "percent"]) => FieldKind::Decimal { min: 0.0, max: 1.0 },
+                _ if has(&["lat"]) => FieldKind::Decimal { min: -90.0, max: 90.0 },
+                _ if has(&["lng", "lon"]) => FieldKind::Decimal { min: -180.0, max: 180.0 },
+                _ => FieldKind::Decimal { min: 1.0, max: 1000.0 },
+            };
+        }
+
+        // Text columns are recognised by name
+        match () {
+            _ if has(&["email"]) => FieldKind::Email,
+            _ if has(&["first_name", "firstname", "given_name"]) => FieldKind::FirstName,
+            _ if has(&["last_name", "lastname", "surname", "family_name"]) => FieldKind::LastName,
+            _ if has(&["username", "user_name", "login", "handle"]) => FieldKind::Username,
+            _ if has(&["phone", "mobile", "fax"]) => FieldKind::Phone,
+            _ if has(&["street", "address"]) => FieldKind::Street,
+            _ if has(&["city", "town"]) => FieldKind::City,
+            _ if has(&["country"]) => FieldKind::Country,
+            _ if has(&["zip", "postal", "postcode"]) => FieldKind::PostalCode,
+            _ if has(&["url", "website", "link", "homepage"]) => FieldKind::Url,
+            _ if has(&["company", "organization", "organisation", "employer"]) => FieldKind::Company,
+            _ if has(&["status", "state"]) => FieldKind::Status,
+            _ if has(&["description", "bio", "body", "content", "note", "comment", "summary"]) => {
+                FieldKind::Paragraph
+            }
+            _ if has(&["uuid", "guid"]) => FieldKind::Uuid,
+            _ if has(&["ip_address", "ip_addr"]) || name == "ip" => FieldKind::IpAddress,
+            _ if has(&["name"]) => FieldKind::FullName,
+            _ => FieldKind::Words,
+        }
+    }
+}
+
+/// Generates values; seeded fakers produce the same choices on every run
+pub struct Faker {
+    rng: StdRng,
+    now: NaiveDateTime,
+    /// Appended to emails and usernames so they satisfy unique constraints
+    sequence: u64,
+}
+
+impl Faker {
+    pub fn new() -> Self {
+        Self::from_rng(StdRng::from_os_rng())
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
+        Self {
+            rng,
+            now: Utc::now().naive_utc(),
+            sequence: 0,
+        }
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.rng.random_bool(p.clamp(0.0, 1.0))
+    }
+
+    /// One of `values`, or null when there are none
+    pub fn sample(&mut self, values: &[Value]) -> Value {
+        values.choose(&mut self.rng).cloned().unwrap_or(Value::Null)
+    }
+
+    /// Generate a value of `kind`
+    pub fn value(&mut self, kind: &FieldKind) -> Value {
+        match kind {
+            FieldKind::Email => {
+                let (first, last) = (self.pick(FIRST_NAMES), self.pick(LAST_NAMES));
+                let domain = self.pick(EMAIL_DOMAINS);
+                let n = self.next_sequence();
+                Value::from(format!("{}.{}{}@{}", first, last, n, domain).to_lowercase())
+            }
+            FieldKind::FirstName => Value::from(self.pick(FIRST_NAMES)),
+            FieldKind::LastName => Value::from(self.pick(LAST_NAMES)),
+            FieldKind::FullName => Value::from(format!("{} {}", self.pick(FIRST_NAMES), self.pick(LAST_NAMES))),
+            FieldKind::Username => {
+                let (first, last) = (self.pick(FIRST_NAMES), self.pick(LAST_NAMES));
+                let n = self.next_sequence();
+                Value::from(format!("{}_{}{}", first, last, n).to_lowercase())
+            }
+            FieldKind::Phone => Value::from(format!(
+                "+1-{:03}-555-{:04}",
+                self.rng.random_range(201..990),
+                self.rng.random_range(0..10_000)
+            )),
+            FieldKind::Street => {
+                let number = self.rng.random_range(1..9999);
+                Value::from(format!("{} {} {}", number, self.pick(STREETS), self.pick(STREET_SUFFIXES)))
+            }
+            FieldKind::City => Value::from(self.pick(CITIES)),
+            FieldKind::Country => Value::from(self.pick(COUNTRIES)),
+            FieldKind::PostalCode => Value::from(format!("{:05}", self.rng.random_range(1000..99_999))),
+            FieldKind::Url => {
+                let word = self.pick(COMPANY_WORDS).to_lowercase();
+                Value::from(format!("https://{}.{}", word, self.pick(EMAIL_DOMAINS)))
+            }
+            FieldKind::Company => {
+                Value::from(format!("{} {}", self.pick(COMPANY_WORDS), self.pick(COMPANY_SUFFIXES)))
+            }
+            FieldKind::Status => Value::from(self.pick(STATUSES)),
+            FieldKind::Words => {
+                let count = self.rng.random_range(2..=4);
+                Value::from(self.words(count))
+            }
+            FieldKind::Paragraph => {
+                let sentences: Vec<String> = (0..self.rng.random_range(1..=3))
+                    .map(|_| {
+                        let count = self.rng.random_range(6..=12);
+                        let mut sentence = self.words(count);
+                        sentence[..1].make_ascii_uppercase();
+                        sentence + "."
+                    })
+                    .collect();
+                Value::from(sentences.join(" "))
+            }
+            FieldKind::Uuid => {
+                let mut bytes: [u8; 16] = self.rng.random();
+                // Version 4, RFC 4122 variant
+                bytes[6] = (bytes[6] & 0x0f) | 0x40;
+                bytes[8] = (bytes[8] & 0x3f) | 0x80;
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                Value::from(format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..]))
+            }
+            FieldKind::Boolean => Value::from(self.rng.random_bool(0.5)),
+            FieldKind::Integer { min, max } => Value::from(self.rng.random_range(*min..=*max)),
+            FieldKind::Decimal { min, max } => {
+                let value: f64 = self.rng.random_range(*min..=*max);
+                Value::from((value * 100.0).round() / 100.0)
+            }
+            FieldKind::Timestamp => Value::from(self.past().format("%Y-%m-%d %H:%M:%S").to_string()),
+            FieldKind::Date => Value::from(self.past().format("%Y-%m-%d").to_string()),
+            FieldKind::Time => Value::from(self.past().format("%H:%M:%S").to_string()),
+            FieldKind::Json => serde_json::json!({
+                "source": "mock",
+                "score": self.rng.random_range(0..100),
+                "tags": [self.pick(LOREM), self.pick(LOREM)],
+            }),
+            FieldKind::IpAddress => Value::from(format!(
+                "10.{}.{}.{}",
+                self.rng.random_range(0..=255),
+                self.rng.random_range(0..=255),
+                self.rng.random_range(1..=254)
+            )),
+        }
+    }
+
+    fn pick(&mut self, words: &[&'static str]) -> &'static str {
+        words.choose(&mut self.rng).copied().unwrap_or_default()
+    }
+
+    fn words(&mut self, count: usize) -> String {
+        (0..count).map(|_| self.pick(LOREM)).collect::<Vec<_>>().join(" ")
+    }
+
+    fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+
+    /// A moment within the last `PAST_DAYS` days
+    fn past(&mut self) -> NaiveDateTime {
+        let seconds = self.rng.random_range(0..PAST_DAYS * 24 * 60 * 60);
+        self.now - Duration::seconds(seconds)
+    }
+}
+
+impl Default for Faker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
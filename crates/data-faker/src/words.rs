@@ -0,0 +1,53 @@
+//! Word lists the generators pick from
+
+pub const FIRST_NAMES: &[&str] = &[
+    "James", "Mary", "John", "Patricia", "Robert", "Jennifer", "Michael", "Linda", "David", "Elizabeth",
+    "William", "Barbara", "Richard", "Susan", "Joseph", "Jessica", "Thomas", "Sarah", "Carlos", "Karen",
+    "Daniel", "Lisa", "Matthew", "Nancy", "Anthony", "Sofia", "Mark", "Emma", "Luis", "Olivia",
+    "Paul", "Amelia", "Steven", "Mia", "Andrew", "Chloe", "Kenji", "Aisha", "Mateo", "Priya",
+];
+
+pub const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Williams", "Brown", "Jones", "Garcia", "Miller", "Davis", "Rodriguez", "Martinez",
+    "Hernandez", "Lopez", "Gonzalez", "Wilson", "Anderson", "Thomas", "Taylor", "Moore", "Jackson", "Martin",
+    "Lee", "Perez", "Thompson", "White", "Harris", "Sanchez", "Clark", "Ramirez", "Lewis", "Robinson",
+    "Walker", "Young", "Allen", "King", "Wright", "Scott", "Nguyen", "Kim", "Patel", "Silva",
+];
+
+pub const CITIES: &[&str] = &[
+    "Springfield", "Riverside", "Franklin", "Greenville", "Bristol", "Clinton", "Fairview", "Salem",
+    "Madison", "Georgetown", "Arlington", "Ashland", "Dover", "Oxford", "Jackson", "Burlington",
+    "Manchester", "Milton", "Newport", "Auburn",
+];
+
+pub const COUNTRIES: &[&str] = &[
+    "United States", "Canada", "Mexico", "Brazil", "Argentina", "United Kingdom", "Ireland", "France",
+    "Germany", "Spain", "Portugal", "Italy", "Netherlands", "Sweden", "Poland", "Japan", "South Korea",
+    "India", "Australia", "New Zealand",
+];
+
+pub const STREETS: &[&str] = &[
+    "Main", "Oak", "Pine", "Maple", "Cedar", "Elm", "Washington", "Lake", "Hill", "Park", "View", "Sunset",
+    "Ridge", "Church", "Mill", "River",
+];
+
+pub const STREET_SUFFIXES: &[&str] = &["St", "Ave", "Rd", "Blvd", "Ln", "Dr", "Ct", "Way"];
+
+pub const COMPANY_WORDS: &[&str] = &[
+    "Acme", "Globex", "Initech", "Umbrella", "Stark", "Wayne", "Vertex", "Summit", "Pioneer", "Horizon",
+    "Nimbus", "Atlas", "Bluebird", "Evergreen", "Northwind", "Contoso",
+];
+
+pub const COMPANY_SUFFIXES: &[&str] = &["Inc", "LLC", "Ltd", "Group", "Labs", "Systems", "Holdings", "Co"];
+
+/// Reserved for documentation (RFC 2606), so generated addresses never reach anyone
+pub const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+pub const STATUSES: &[&str] = &["active", "inactive", "pending", "archived"];
+
+pub const LOREM: &[&str] = &[
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do", "eiusmod",
+    "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim", "ad", "minim",
+    "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi", "aliquip", "ex", "ea",
+    "commodo", "consequat",
+];
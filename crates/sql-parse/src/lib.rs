@@ -0,0 +1,557 @@
+//! Dialect-aware SQL parsing for dbfordevs, built on sqlparser-rs. Statement
+//! splitting and classification use sqlparser's tokenizer, so quoting rules
+//! (dollar-quoted strings, backslash escapes, bracketed identifiers) match the
+//! database the SQL is meant for, and procedure bodies stay in one statement.
+
+mod split;
+
+use sqlparser::ast::{
+    Expr, FromTable, FunctionArg, FunctionArgExpr, FunctionArguments, Query, SetExpr, Statement, TableFactor,
+    TableWithJoins,
+};
+use std::collections::HashSet;
+use sqlparser::dialect::{
+    ClickHouseDialect, DuckDbDialect, GenericDialect, MsSqlDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect,
+};
+use sqlparser::parser::Parser;
+use sqlparser::tokenizer::{Token, Tokenizer};
+
+pub use split::{split_statements, StatementSplitter};
+pub use sqlparser::ast;
+pub use sqlparser::parser::ParserError;
+
+/// SQLite pragmas that take an argument but only read
+const READ_PRAGMAS: &[&str] = &[
+    "table_info", "table_xinfo", "index_list", "index_info", "index_xinfo", "foreign_key_list", "foreign_key_check",
+    "integrity_check", "quick_check",
+];
+
+/// SQL flavour, deciding which quotes, comments and statements are recognised
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dialect {
+    Postgres,
+    MySql,
+    Sqlite,
+    Mssql,
+    ClickHouse,
+    DuckDb,
+    Oracle,
+    Generic,
+}
+
+impl Dialect {
+    fn parser_dialect(self) -> Box<dyn sqlparser::dialect::Dialect> {
+        match self {
+            Dialect::Postgres => Box::new(PostgreSqlDialect {}),
+            Dialect::MySql => Box::new(MySqlDialect {}),
+            Dialect::Sqlite => Box::new(SQLiteDialect {}),
+            Dialect::Mssql => Box::new(MsSqlDialect {}),
+            Dialect::ClickHouse => Box::new(ClickHouseDialect {}),
+            Dialect::DuckDb => Box::new(DuckDbDialect {}),
+            Dialect::Oracle | Dialect::Generic => Box::new(GenericDialect),
+        }
+    }
+
+    /// Leading keywords of statements that only read, for SQL that does not parse
+    fn read_keywords(self) -> &'static [&'static str] {
+        match self {
+            Dialect::MySql => &["SELECT", "SHOW", "DESCRIBE", "DESC"],
+            Dialect::Mssql => &["SELECT"],
+            Dialect::ClickHouse => &["SELECT", "SHOW", "DESCRIBE", "DESC", "EXISTS"],
+            // DuckDB queries may start with FROM and leave out SELECT
+            Dialect::DuckDb => &["SELECT", "FROM", "SHOW", "DESCRIBE", "DESC", "SUMMARIZE"],
+            _ => &["SELECT", "SHOW"],
+        }
+    }
+
+    /// Leading keywords of statements that return rows, for SQL that does not parse
+    fn row_keywords(self) -> &'static [&'static str] {
+        match self {
+            Dialect::Postgres => &["SELECT", "WITH", "VALUES", "TABLE", "SHOW", "EXPLAIN"],
+            Dialect::MySql => &["SELECT", "WITH", "VALUES", "TABLE", "SHOW", "DESCRIBE", "DESC", "EXPLAIN"],
+            Dialect::Sqlite => &["SELECT", "WITH", "VALUES", "PRAGMA", "EXPLAIN"],
+            Dialect::Mssql => &["SELECT", "WITH", "EXEC", "EXECUTE", "DECLARE"],
+            Dialect::ClickHouse => &["SELECT", "WITH", "SHOW", "DESCRIBE", "DESC", "EXPLAIN", "EXISTS"],
+            Dialect::DuckDb => &[
+                "SELECT", "FROM", "WITH", "VALUES", "TABLE", "SHOW", "DESCRIBE", "DESC", "SUMMARIZE", "PIVOT", "UNPIVOT",
+                "PRAGMA", "CALL", "EXPLAIN",
+            ],
+            Dialect::Oracle => &["SELECT", "WITH"],
+            Dialect::Generic => &["SELECT", "WITH", "VALUES", "SHOW", "EXPLAIN"],
+        }
+    }
+}
+
+/// Parse SQL into statements
+pub fn parse(sql: &str, dialect: Dialect) -> Result<Vec<Statement>, ParserError> {
+    Parser::parse_sql(dialect.parser_dialect().as_ref(), sql)
+}
+
+/// Whether running the SQL produces a result set: queries, SHOW and EXPLAIN,
+/// and INSERT, UPDATE or DELETE with RETURNING. SQL that does not parse is
+/// judged by its keywords.
+pub fn returns_rows(sql: &str, dialect: Dialect) -> bool {
+    match parse(sql, dialect) {
+        Ok(statements) if !statements.is_empty() => statements.iter().any(statement_returns_rows),
+        _ => keywords_return_rows(sql, dialect),
+    }
+}
+
+fn statement_returns_rows(statement: &Statement) -> bool {
+    match statement {
+        Statement::Query(_)
+        | Statement::Explain { .. }
+        | Statement::ExplainTable { .. }
+        | Statement::Pragma { .. }
+        | Statement::Execute { .. }
+        | Statement::ShowFunctions { .. }
+        | Statement::ShowVariable { .. }
+        | Statement::ShowStatus { .. }
+        | Statement::ShowVariables { .. }
+        | Statement::ShowCreate { .. }
+        | Statement::ShowColumns { .. }
+        | Statement::ShowDatabases { .. }
+        | Statement::ShowSchemas { .. }
+        | Statement::ShowTables { .. }
+        | Statement::ShowViews { .. }
+        | Statement::ShowCollation { .. } => true,
+        Statement::Insert(insert) => insert.returning.is_some(),
+        Statement::Update { returning, .. } => returning.is_some(),
+        Statement::Delete(delete) => delete.returning.is_some(),
+        _ => false,
+    }
+}
+
+/// Fallback for SQL the parser rejects: a leading row-returning keyword, or
+/// RETURNING anywhere
+fn keywords_return_rows(sql: &str, dialect: Dialect) -> bool {
+    let parser_dialect = dialect.parser_dialect();
+    let Ok(tokens) = Tokenizer::new(parser_dialect.as_ref(), sql).tokenize() else {
+        return false;
+    };
+    let mut words = tokens.iter().filter(|t| !matches!(t, Token::Whitespace(_))).map(|t| match t {
+        Token::Word(word) if word.quote_style.is_none() => word.value.to_ascii_uppercase(),
+        _ => String::new(),
+    });
+
+    let Some(first) = words.next() else {
+        return false;
+    };
+    dialect.row_keywords().contains(&first.as_str())
+        // System procedures called without EXEC
+        || (dialect == Dialect::Mssql && first.starts_with("SP_"))
+        || words.any(|word| word == "RETURNING")
+}
+
+/// Whether running the SQL cannot change data or schema: queries without
+/// `SELECT INTO` or data-modifying CTEs, SHOW, EXPLAIN without ANALYZE and
+/// PRAGMA reads. SQL that does not parse only counts if every statement starts
+/// with a reading keyword such as SELECT.
+pub fn is_read_only(sql: &str, dialect: Dialect) -> bool {
+    match parse(sql, dialect) {
+        Ok(statements) => statements.iter().all(statement_is_read_only),
+        Err(_) => split_statements(sql, dialect).iter().all(|statement| keywords_read_only(statement, dialect)),
+    }
+}
+
+fn statement_is_read_only(statement: &Statement) -> bool {
+    match statement {
+        Statement::Query(query) => query_is_read_only(query),
+        Statement::Explain { analyze, statement, .. } => !analyze || statement_is_read_only(statement),
+        Statement::Pragma { name, value, is_eq } => {
+            value.is_none() || (!is_eq && READ_PRAGMAS.iter().any(|p| name.to_string().eq_ignore_ascii_case(p)))
+        }
+        Statement::ExplainTable { .. }
+        | Statement::ShowFunctions { .. }
+        | Statement::ShowVariable { .. }
+        | Statement::ShowStatus { .. }
+        | Statement::ShowVariables { .. }
+        | Statement::ShowCreate { .. }
+        | Statement::ShowColumns { .. }
+        | Statement::ShowDatabases { .. }
+        | Statement::ShowSchemas { .. }
+        | Statement::ShowTables { .. }
+        | Statement::ShowViews { .. }
+        | Statement::ShowCollation { .. } => true,
+        _ => false,
+    }
+}
+
+fn query_is_read_only(query: &Query) -> bool {
+    let ctes_read = query
+        .with
+        .as_ref()
+        .is_none_or(|with| with.cte_tables.iter().all(|cte| query_is_read_only(&cte.query)));
+    ctes_read && set_expr_is_read_only(&query.body)
+}
+
+fn set_expr_is_read_only(body: &SetExpr) -> bool {
+    match body {
+        SetExpr::Select(select) => select.into.is_none(),
+        SetExpr::Query(query) => query_is_read_only(query),
+        SetExpr::SetOperation { left, right, .. } => set_expr_is_read_only(left) && set_expr_is_read_only(right),
+        SetExpr::Values(_) | SetExpr::Table(_) => true,
+        SetExpr::Insert(_) | SetExpr::Update(_) => false,
+    }
+}
+
+/// Fallback for a statement the parser rejects: a leading reading keyword and
+/// no INTO, which could make SELECT create a table, or a reading pragma
+fn keywords_read_only(sql: &str, dialect: Dialect) -> bool {
+    let parser_dialect = dialect.parser_dialect();
+    let Ok(tokens) = Tokenizer::new(parser_dialect.as_ref(), sql).tokenize() else {
+        return false;
+    };
+    let mut words = tokens.iter().filter_map(|t| match t {
+        Token::Word(word) if word.quote_style.is_none() => Some(word.value.to_ascii_uppercase()),
+        _ => None,
+    });
+
+    let Some(first) = words.next() else {
+        return false;
+    };
+    if dialect == Dialect::Sqlite && first == "PRAGMA" {
+        return words.next().is_some_and(|name| READ_PRAGMAS.iter().any(|p| p.eq_ignore_ascii_case(&name)));
+    }
+    dialect.read_keywords().contains(&first.as_str()) && !words.any(|word| word == "INTO")
+}
+
+/// Tables a statement reads or writes, in order of first mention, from FROM
+/// and JOIN clauses, derived tables and the targets of INSERT, UPDATE and
+/// DELETE. Names of CTEs are left out; subqueries in expressions are not
+/// searched. Empty when the SQL does not parse.
+pub fn referenced_tables(sql: &str, dialect: Dialect) -> Vec<String> {
+    let mut tables = Vec::new();
+    for statement in parse(sql, dialect).unwrap_or_default() {
+        statement_tables(&statement, &HashSet::new(), &mut tables);
+    }
+    tables
+}
+
+fn statement_tables(statement: &Statement, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    match statement {
+        Statement::Query(query) => query_tables(query, ctes, tables),
+        Statement::Explain { statement, .. } => statement_tables(statement, ctes, tables),
+        Statement::Insert(insert) => {
+            add_table(insert.table_name.to_string(), ctes, tables);
+            if let Some(source) = &insert.source {
+                query_tables(source, ctes, tables);
+            }
+        }
+        Statement::Update { table, from, .. } => {
+            joined_tables(table, ctes, tables);
+            if let Some(from) = from {
+                joined_tables(from, ctes, tables);
+            }
+        }
+        Statement::Delete(delete) => {
+            let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = &delete.from;
+            for table in from.iter().chain(delete.using.iter().flatten()) {
+                joined_tables(table, ctes, tables);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn query_tables(query: &Query, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    let mut ctes = ctes.clone();
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            ctes.insert(cte.alias.name.value.to_lowercase());
+            query_tables(&cte.query, &ctes, tables);
+        }
+    }
+    set_expr_tables(&query.body, &ctes, tables);
+}
+
+fn set_expr_tables(body: &SetExpr, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    match body {
+        SetExpr::Select(select) => {
+            for table in &select.from {
+                joined_tables(table, ctes, tables);
+            }
+        }
+        SetExpr::Query(query) => query_tables(query, ctes, tables),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_tables(left, ctes, tables);
+            set_expr_tables(right, ctes, tables);
+        }
+        SetExpr::Insert(statement) | SetExpr::Update(statement) => statement_tables(statement, ctes, tables),
+        SetExpr::Table(table) => {
+            if let Some(name) = &table.table_name {
+                let name = match &table.schema_name {
+                    Some(schema) => format!("{}.{}", schema, name),
+                    None => name.clone(),
+                };
+                add_table(name, ctes, tables);
+            }
+        }
+        SetExpr::Values(_) => {}
+    }
+}
+
+fn joined_tables(table: &TableWithJoins, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    factor_tables(&table.relation, ctes, tables);
+    for join in &table.joins {
+        factor_tables(&join.relation, ctes, tables);
+    }
+}
+
+fn factor_tables(factor: &TableFactor, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    match factor {
+        TableFactor::Table { name, .. } => add_table(name.to_string(), ctes, tables),
+        TableFactor::Derived { subquery, .. } => query_tables(subquery, ctes, tables),
+        TableFactor::NestedJoin { table_with_joins, .. } => joined_tables(table_with_joins, ctes, tables),
+        _ => {}
+    }
+}
+
+fn add_table(name: String, ctes: &HashSet<String>, tables: &mut Vec<String>) {
+    if !ctes.contains(&name.to_lowercase()) && !tables.contains(&name) {
+        tables.push(name);
+    }
+}
+
+/// Columns compared in WHERE clauses, each with the table it belongs to when
+/// the statement tells: qualified names resolve through the FROM clause's
+/// aliases, and unqualified ones belong to the only table in scope. Subqueries
+/// and CTEs are searched too; columns of derived tables and CTEs have no
+/// table. Empty when the SQL does not parse.
+pub fn filtered_columns(sql: &str, dialect: Dialect) -> Vec<(Option<String>, String)> {
+    let mut columns = Vec::new();
+    for statement in parse(sql, dialect).unwrap_or_default() {
+        statement_filters(&statement, &HashSet::new(), &mut columns);
+    }
+    columns
+}
+
+/// Tables in a FROM clause by lowercased alias, or name when unaliased; None
+/// for derived tables and CTEs
+type Scope = Vec<(String, Option<String>)>;
+
+type Columns = Vec<(Option<String>, String)>;
+
+fn statement_filters(statement: &Statement, ctes: &HashSet<String>, columns: &mut Columns) {
+    match statement {
+        Statement::Query(query) => query_filters(query, ctes, columns),
+        Statement::Explain { statement, .. } => statement_filters(statement, ctes, columns),
+        Statement::Insert(insert) => {
+            if let Some(source) = &insert.source {
+                query_filters(source, ctes, columns);
+            }
+        }
+        Statement::Update { table, from, selection, .. } => {
+            let from: Vec<&TableWithJoins> = std::iter::once(table).chain(from).collect();
+            where_filters(&from, selection.as_ref(), ctes, columns);
+        }
+        Statement::Delete(delete) => {
+            let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = &delete.from;
+            let from: Vec<&TableWithJoins> = from.iter().chain(delete.using.iter().flatten()).collect();
+            where_filters(&from, delete.selection.as_ref(), ctes, columns);
+        }
+        _ => {}
+    }
+}
+
+fn query_filters(query: &Query, ctes: &HashSet<String>, columns: &mut Columns) {
+    let mut ctes = ctes.clone();
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            ctes.insert(cte.alias.name.value.to_lowercase());
+            query_filters(&cte.query, &ctes, columns);
+        }
+    }
+    set_expr_filters(&query.body, &ctes, columns);
+}
+
+fn set_expr_filters(body: &SetExpr, ctes: &HashSet<String>, columns: &mut Columns) {
+    match body {
+        SetExpr::Select(select) => {
+            let from: Vec<&TableWithJoins> = select.from.iter().collect();
+            where_filters(&from, select.selection.as_ref(), ctes, columns);
+        }
+        SetExpr::Query(query) => query_filters(query, ctes, columns),
+        SetExpr::SetOperation { left, right, .. } => {
+            set_expr_filters(left, ctes, columns);
+            set_expr_filters(right, ctes, columns);
+        }
+        SetExpr::Insert(statement) | SetExpr::Update(statement) => statement_filters(statement, ctes, columns),
+        _ => {}
+    }
+}
+
+fn where_filters(from: &[&TableWithJoins], selection: Option<&Expr>, ctes: &HashSet<String>, columns: &mut Columns) {
+    let mut scope = Scope::new();
+    for table in from {
+        joined_scope(table, ctes, &mut scope, columns);
+    }
+    if let Some(selection) = selection {
+        expr_columns(selection, &scope, ctes, columns);
+    }
+}
+
+fn joined_scope(table: &TableWithJoins, ctes: &HashSet<String>, scope: &mut Scope, columns: &mut Columns) {
+    factor_scope(&table.relation, ctes, scope, columns);
+    for join in &table.joins {
+        factor_scope(&join.relation, ctes, scope, columns);
+    }
+}
+
+fn factor_scope(factor: &TableFactor, ctes: &HashSet<String>, scope: &mut Scope, columns: &mut Columns) {
+    match factor {
+        TableFactor::Table { name, alias, .. } => {
+            let key = match alias {
+                Some(alias) => alias.name.value.clone(),
+                None => name.0.last().map(|part| part.value.clone()).unwrap_or_default(),
+            };
+            let table = name.to_string();
+            let table = (!ctes.contains(&table.to_lowercase())).then_some(table);
+            scope.push((key.to_lowercase(), table));
+        }
+        TableFactor::Derived { subquery, alias, .. } => {
+            query_filters(subquery, ctes, columns);
+            if let Some(alias) = alias {
+                scope.push((alias.name.value.to_lowercase(), None));
+            }
+        }
+        TableFactor::NestedJoin { table_with_joins, .. } => joined_scope(table_with_joins, ctes, scope, columns),
+        _ => {}
+    }
+}
+
+fn add_column(table: Option<String>, column: &str, columns: &mut Columns) {
+    let entry = (table, column.to_string());
+    if !columns.contains(&entry) {
+        columns.push(entry);
+    }
+}
+
+fn expr_columns(expr: &Expr, scope: &Scope, ctes: &HashSet<String>, columns: &mut Columns) {
+    let mut recurse = |expr: &Expr| expr_columns(expr, scope, ctes, columns);
+    match expr {
+        Expr::Identifier(ident) => {
+            let table = match scope.as_slice() {
+                [(_, table)] => table.clone(),
+                _ => None,
+            };
+            add_column(table, &ident.value, columns);
+        }
+        Expr::CompoundIdentifier(parts) if parts.len() >= 2 => {
+            let qualifier = parts[parts.len() - 2].value.to_lowercase();
+            let table = scope
+                .iter()
+                .find(|(key, _)| *key == qualifier)
+                .and_then(|(_, table)| table.clone());
+            add_column(table, &parts[parts.len() - 1].value, columns);
+        }
+        Expr::Nested(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Cast { expr, .. }
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::IsTrue(expr)
+        | Expr::IsNotTrue(expr)
+        | Expr::IsFalse(expr)
+        | Expr::IsNotFalse(expr) => recurse(expr),
+        Expr::BinaryOp { left, right, .. }
+        | Expr::AnyOp { left, right, .. }
+        | Expr::AllOp { left, right, .. }
+        | Expr::IsDistinctFrom(left, right)
+        | Expr::IsNotDistinctFrom(left, right)
+        | Expr::Like { expr: left, pattern: right, .. }
+        | Expr::ILike { expr: left, pattern: right, .. }
+        | Expr::SimilarTo { expr: left, pattern: right, .. }
+        | Expr::RLike { expr: left, pattern: right, .. } => {
+            recurse(left);
+            recurse(right);
+        }
+        Expr::Between { expr, low, high, .. } => {
+            recurse(expr);
+            recurse(low);
+            recurse(high);
+        }
+        Expr::InList { expr, list, .. } => {
+            recurse(expr);
+            list.iter().for_each(recurse);
+        }
+        Expr::InSubquery { expr, subquery, .. } => {
+            recurse(expr);
+            query_filters(subquery, ctes, columns);
+        }
+        Expr::Exists { subquery, .. } | Expr::Subquery(subquery) => query_filters(subquery, ctes, columns),
+        Expr::Function(function) => {
+            if let FunctionArguments::List(list) = &function.args {
+                for arg in &list.args {
+                    if let FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))
+                    | FunctionArg::Named { arg: FunctionArgExpr::Expr(expr), .. } = arg
+                    {
+                        recurse(expr);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Tables changed by UPDATE or DELETE statements that have no WHERE clause
+/// (or LIMIT) and so touch every row. Statements that do not parse are judged
+/// by their keywords.
+pub fn unbounded_writes(sql: &str, dialect: Dialect) -> Vec<String> {
+    split_statements(sql, dialect)
+        .iter()
+        .filter_map(|statement| match parse(statement, dialect) {
+            Ok(parsed) => parsed.first().and_then(unbounded_write_table),
+            Err(_) => keywords_unbounded_write(statement, dialect),
+        })
+        .collect()
+}
+
+fn unbounded_write_table(statement: &Statement) -> Option<String> {
+    match statement {
+        Statement::Update { table, selection: None, .. } => Some(relation_name(table)),
+        Statement::Delete(delete) if delete.selection.is_none() && delete.limit.is_none() => {
+            let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = &delete.from;
+            // Multi-table DELETE lists its targets before FROM
+            delete.tables.first().map(ToString::to_string).or_else(|| from.first().map(relation_name))
+        }
+        _ => None,
+    }
+}
+
+fn relation_name(table: &TableWithJoins) -> String {
+    match &table.relation {
+        TableFactor::Table { name, .. } => name.to_string(),
+        relation => relation.to_string(),
+    }
+}
+
+/// Fallback for SQL the parser rejects: UPDATE or DELETE with no WHERE, LIMIT
+/// or TOP anywhere in the statement
+fn keywords_unbounded_write(sql: &str, dialect: Dialect) -> Option<String> {
+    let parser_dialect = dialect.parser_dialect();
+    let tokens = Tokenizer::new(parser_dialect.as_ref(), sql).tokenize().ok()?;
+    let tokens: Vec<&Token> = tokens.iter().filter(|t| !matches!(t, Token::Whitespace(_))).collect();
+    let is_word = |token: &Token, words: &[&str]| {
+        matches!(token, Token::Word(word) if word.quote_style.is_none()
+            && words.iter().any(|w| word.value.eq_ignore_ascii_case(w)))
+    };
+
+    let bounded = tokens.iter().any(|t| is_word(t, &["WHERE", "LIMIT", "TOP"]));
+    if bounded || !is_word(tokens.first()?, &["UPDATE", "DELETE"]) {
+        return None;
+    }
+    // The table name follows the keyword and any modifiers, as words joined by dots
+    let mut name = String::new();
+    let mut expect_word = true;
+    for token in tokens[1..].iter().skip_while(|t| is_word(t, &["FROM", "ONLY", "LOW_PRIORITY", "QUICK", "IGNORE"])) {
+        match token {
+            Token::Word(_) if expect_word => name.push_str(&token.to_string()),
+            Token::Period if !expect_word => name.push('.'),
+            _ => break,
+        }
+        expect_word = !expect_word;
+    }
+    Some(name).filter(|name| !name.is_empty())
+}
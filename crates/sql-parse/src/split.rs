@@ -0,0 +1,342 @@
+use crate::Dialect;
+use sqlparser::tokenizer::{Location, Token, TokenWithSpan, Tokenizer};
+
+/// Words after BEGIN that make it start a transaction rather than a block
+const TRANSACTION_WORDS: &[&str] = &[
+    "TRANSACTION", "TRAN", "WORK", "DEFERRED", "IMMEDIATE", "EXCLUSIVE", "ISOLATION", "READ", "DISTRIBUTED",
+];
+
+/// Words after END that close a MySQL control statement rather than a block
+const CONTROL_END_WORDS: &[&str] = &["IF", "LOOP", "WHILE", "REPEAT"];
+
+/// Oracle objects written in PL/SQL, whose CREATE statements end at a `/` line
+const PLSQL_OBJECTS: &[&str] = &["PROCEDURE", "FUNCTION", "PACKAGE", "TRIGGER", "TYPE"];
+
+/// Incremental statement splitter. Text can be fed in chunks of any size, so
+/// large files never need to be held in memory.
+///
+/// Semicolons inside strings, comments, dollar quotes and BEGIN ... END
+/// blocks do not end a statement, and for MySQL a `DELIMITER` line changes the
+/// terminator until the next one, as in the mysql client. Oracle statements
+/// can also end at a `/` alone on its line, which is the only end of a PL/SQL
+/// block, as in SQL*Plus.
+pub struct StatementSplitter {
+    dialect: Dialect,
+    /// Text after the last complete statement
+    pending: String,
+    /// Terminator set by a `DELIMITER` line; None while it is `;`
+    delimiter: Option<String>,
+}
+
+impl StatementSplitter {
+    pub fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            pending: String::new(),
+            delimiter: None,
+        }
+    }
+
+    /// Feed the next chunk of SQL and return the statements it completes
+    pub fn push(&mut self, chunk: &str) -> Vec<String> {
+        self.pending.push_str(chunk);
+        // Nothing can end before the next terminator
+        let terminator = self.delimiter.as_deref().and_then(|d| d.chars().next()).unwrap_or(';');
+        let may_change_delimiter = self.dialect == Dialect::MySql && chunk.contains('\n');
+        let may_end_block = self.dialect == Dialect::Oracle && chunk.contains(['/', '\n']);
+        if !chunk.contains(terminator) && !may_change_delimiter && !may_end_block {
+            return Vec::new();
+        }
+        self.drain(false)
+    }
+
+    /// Return the statements left, the last of which may lack a terminator
+    pub fn finish(mut self) -> Vec<String> {
+        self.drain(true)
+    }
+
+    fn drain(&mut self, last: bool) -> Vec<String> {
+        let mut statements = Vec::new();
+        let mut start = 0;
+
+        loop {
+            let rest = &self.pending[start..];
+            if self.dialect == Dialect::MySql {
+                let blank = rest.len() - rest.trim_start().len();
+                if let Some(delimiter) = delimiter_command(&rest[blank..]) {
+                    match rest[blank..].find('\n') {
+                        Some(end) => start += blank + end + 1,
+                        None if last => start = self.pending.len(),
+                        // The rest of the line is still to come
+                        None => break,
+                    }
+                    self.delimiter = Some(delimiter).filter(|d| d != ";");
+                    continue;
+                }
+            }
+
+            // The current terminator applies up to the next DELIMITER line
+            let region_end = match self.dialect {
+                Dialect::MySql => next_delimiter_command(rest).unwrap_or(rest.len()),
+                _ => rest.len(),
+            };
+            let complete = last || region_end < rest.len();
+            let region = &rest[..region_end];
+
+            let scanned = match &self.delimiter {
+                None => split_at_semicolons(region, self.dialect),
+                Some(delimiter) => split_at_delimiter(region, delimiter, self.dialect),
+            };
+            let (found, consumed) = match scanned {
+                Some(scanned) => scanned,
+                // An unterminated string or comment may be closed by the next chunk
+                None if !complete => break,
+                None => (Vec::new(), 0),
+            };
+            statements.extend(found);
+
+            if !complete {
+                start += consumed;
+                break;
+            }
+            // The end of the input or a DELIMITER line ends whatever is left
+            statements.extend(trim_statement(&region[consumed..], self.dialect));
+            start += region_end;
+            if region_end == rest.len() {
+                break;
+            }
+        }
+
+        self.pending.drain(..start);
+        statements
+    }
+}
+
+/// Split SQL into statements. Each statement is trimmed of surrounding
+/// whitespace and comments, and empty statements are dropped. SQL that cannot
+/// be tokenized (an unterminated string, say) is returned whole, so the
+/// database reports the error.
+pub fn split_statements(sql: &str, dialect: Dialect) -> Vec<String> {
+    let mut splitter = StatementSplitter::new(dialect);
+    let mut statements = splitter.push(sql);
+    statements.extend(splitter.finish());
+    statements
+}
+
+/// Statements ended by `;` in `region`, and the offset after the last `;`.
+/// None if the region cannot be tokenized.
+fn split_at_semicolons(region: &str, dialect: Dialect) -> Option<(Vec<String>, usize)> {
+    let parser_dialect = dialect.parser_dialect();
+    let tokens = Tokenizer::new(parser_dialect.as_ref(), region).tokenize_with_location().ok()?;
+    let tokens: Vec<&TokenWithSpan> = tokens
+        .iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_) | Token::EOF))
+        .collect();
+
+    // A `/` that is the last token only ends a statement once its line is complete
+    let line_complete = region.trim_end_matches([' ', '\t', '\r']).ends_with('\n');
+
+    let mut offsets = Offsets::new(region);
+    let mut statements = Vec::new();
+    let mut consumed = 0;
+    // First and last token of the current statement
+    let mut span: Option<(Location, Location)> = None;
+    let mut creates = false;
+    let mut plsql = false;
+    let mut depth = 0usize;
+
+    for (i, token) in tokens.iter().enumerate() {
+        let next = tokens.get(i + 1).map(|t| &t.token);
+        let slash_line = dialect == Dialect::Oracle && is_slash_line(&tokens, i, line_complete);
+        match &token.token {
+            _ if slash_line || (token.token == Token::SemiColon && depth == 0 && !plsql) => {
+                if let Some((start, end)) = span.take() {
+                    let start = offsets.at(start);
+                    statements.push(region[start..offsets.at(end)].to_string());
+                }
+                consumed = offsets.at(token.span.end);
+                creates = false;
+                plsql = false;
+                depth = 0;
+                continue;
+            }
+            Token::Word(word) if word.quote_style.is_none() => {
+                let word = word.value.to_ascii_uppercase();
+                if span.is_none() {
+                    creates = word == "CREATE";
+                    plsql = dialect == Dialect::Oracle && starts_plsql(&tokens[i..]);
+                }
+                match word.as_str() {
+                    "BEGIN" if (creates || dialect == Dialect::Mssql) && !starts_transaction(next) => depth += 1,
+                    "CASE" if depth > 0 => depth += 1,
+                    "END" if !is_word_in(next, CONTROL_END_WORDS) => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        let start = span.map_or(token.span.start, |(start, _)| start);
+        span = Some((start, token.span.end));
+    }
+
+    Some((statements, consumed))
+}
+
+/// Statements ended by a custom MySQL delimiter in `region`, and the offset
+/// after the last delimiter. None if a string or comment is left open.
+fn split_at_delimiter(region: &str, delimiter: &str, dialect: Dialect) -> Option<(Vec<String>, usize)> {
+    let bytes = region.as_bytes();
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                loop {
+                    match bytes.get(i)? {
+                        b'\\' if quote != b'`' => i += 2,
+                        &c if c == quote && bytes.get(i + 1) == Some(&quote) => i += 2,
+                        &c if c == quote => break,
+                        _ => i += 1,
+                    }
+                }
+                i += 1;
+            }
+            b'#' => i = region[i..].find('\n').map_or(bytes.len(), |n| i + n),
+            b'-' if bytes[i..].starts_with(b"--") && bytes.get(i + 2).is_none_or(|c| c.is_ascii_whitespace()) => {
+                i = region[i..].find('\n').map_or(bytes.len(), |n| i + n)
+            }
+            b'/' if bytes[i..].starts_with(b"/*") => i += 2 + region[i + 2..].find("*/")? + 2,
+            _ if bytes[i..].starts_with(delimiter.as_bytes()) => {
+                statements.extend(trim_statement(&region[statement_start..i], dialect));
+                i += delimiter.len();
+                statement_start = i;
+            }
+            _ => i += 1,
+        }
+    }
+
+    Some((statements, statement_start))
+}
+
+/// Statement text without surrounding whitespace and comments, if any is left
+fn trim_statement(text: &str, dialect: Dialect) -> Option<String> {
+    let parser_dialect = dialect.parser_dialect();
+    let Ok(tokens) = Tokenizer::new(parser_dialect.as_ref(), text).tokenize_with_location() else {
+        let trimmed = text.trim();
+        return (!trimmed.is_empty()).then(|| trimmed.to_string());
+    };
+
+    let mut significant: Vec<&TokenWithSpan> = tokens
+        .iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_) | Token::EOF))
+        .collect();
+    // A `/` line after the last Oracle statement ends it
+    let last_index = significant.len().saturating_sub(1);
+    if dialect == Dialect::Oracle && !significant.is_empty() && is_slash_line(&significant, last_index, true) {
+        significant.pop();
+    }
+    let first = *significant.first()?;
+    let last = *significant.last()?;
+    let mut offsets = Offsets::new(text);
+    let start = offsets.at(first.span.start);
+    Some(text[start..offsets.at(last.span.end)].to_string())
+}
+
+/// Whether `tokens[i]` is a `/` alone on its line. Comments may share the line.
+fn is_slash_line(tokens: &[&TokenWithSpan], i: usize, line_complete: bool) -> bool {
+    let line = tokens[i].span.start.line;
+    tokens[i].token == Token::Div
+        && (i == 0 || tokens[i - 1].span.end.line < line)
+        && tokens.get(i + 1).map_or(line_complete, |next| next.span.start.line > line)
+}
+
+/// Whether an Oracle statement starting with `tokens` is an anonymous PL/SQL
+/// block or creates a PL/SQL object
+fn starts_plsql(tokens: &[&TokenWithSpan]) -> bool {
+    let mut words = tokens.iter().map_while(|t| match &t.token {
+        Token::Word(word) if word.quote_style.is_none() => Some(word.value.to_ascii_uppercase()),
+        _ => None,
+    });
+    match words.next().as_deref() {
+        Some("DECLARE" | "BEGIN") => true,
+        Some("CREATE") => {
+            let mut word = words.next();
+            if word.as_deref() == Some("OR") {
+                // OR REPLACE
+                words.next();
+                word = words.next();
+            }
+            if matches!(word.as_deref(), Some("EDITIONABLE" | "NONEDITIONABLE")) {
+                word = words.next();
+            }
+            word.is_some_and(|word| PLSQL_OBJECTS.contains(&word.as_str()))
+        }
+        _ => false,
+    }
+}
+
+/// BEGIN followed by nothing or a transaction keyword
+fn starts_transaction(next: Option<&Token>) -> bool {
+    matches!(next, None | Some(Token::SemiColon)) || is_word_in(next, TRANSACTION_WORDS)
+}
+
+fn is_word_in(token: Option<&Token>, words: &[&str]) -> bool {
+    matches!(token, Some(Token::Word(word)) if word.quote_style.is_none()
+        && words.iter().any(|w| word.value.eq_ignore_ascii_case(w)))
+}
+
+/// The new delimiter if `line` is a `DELIMITER` command
+fn delimiter_command(line: &str) -> Option<String> {
+    let keyword = line.get(..9)?;
+    if !keyword.eq_ignore_ascii_case("DELIMITER") || !line[9..].starts_with([' ', '\t']) {
+        return None;
+    }
+    line[9..].split_whitespace().next().map(str::to_string)
+}
+
+/// Offset of the first line after the first one that is a `DELIMITER` command
+fn next_delimiter_command(text: &str) -> Option<usize> {
+    text.match_indices('\n')
+        .map(|(i, _)| i + 1)
+        .find(|&start| delimiter_command(text[start..].trim_start_matches([' ', '\t'])).is_some())
+}
+
+/// Turns tokenizer locations into byte offsets. Locations must be asked for
+/// in order, so the text is walked only once.
+struct Offsets<'a> {
+    text: &'a str,
+    offset: usize,
+    line: u64,
+    column: u64,
+}
+
+impl<'a> Offsets<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn at(&mut self, location: Location) -> usize {
+        let mut chars = self.text[self.offset..].chars();
+        while (self.line, self.column) < (location.line, location.column) {
+            let Some(c) = chars.next() else {
+                break;
+            };
+            self.offset += c.len_utf8();
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset
+    }
+}
@@ -0,0 +1,180 @@
+//! Colors as the app's stylesheet uses them: hue, saturation and lightness,
+//! rendered as bare `H S% L%` components so Tailwind can wrap them in `hsl()`.
+
+use crate::ThemeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    /// Degrees, 0 to 360
+    hue: f64,
+    /// Percent
+    saturation: f64,
+    /// Percent
+    lightness: f64,
+}
+
+impl Color {
+    /// A color from hue in degrees and saturation and lightness in percent
+    pub const fn hsl(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+
+    /// A color from `#rgb` or `#rrggbb`
+    pub fn hex(text: &str) -> Result<Self, ThemeError> {
+        let invalid = || ThemeError::InvalidColor(text.to_string());
+        let digits = text.strip_prefix('#').ok_or_else(invalid)?;
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |hex: &str| u8::from_str_radix(hex, 16).map_err(|_| invalid());
+        let (r, g, b) = match digits.len() {
+            3 => {
+                let expand = |i: usize| channel(&digits[i..i + 1].repeat(2));
+                (expand(0)?, expand(1)?, expand(2)?)
+            }
+            6 => (channel(&digits[0..2])?, channel(&digits[2..4])?, channel(&digits[4..6])?),
+            _ => return Err(invalid()),
+        };
+        Ok(Self::rgb(r, g, b))
+    }
+
+    /// A color from 8-bit red, green and blue
+    pub fn rgb(r: u8, g: u8, b: u8) -> Self {
+        let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return Self::hsl(0.0, 0.0, lightness * 100.0);
+        }
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * (((g - b) / delta).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Self::hsl(hue, saturation * 100.0, lightness * 100.0)
+    }
+
+    /// Red, green and blue from 0 to 1
+    pub fn to_rgb(&self) -> [f64; 3] {
+        let s = self.saturation / 100.0;
+        let l = self.lightness / 100.0;
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let h = self.hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        [r + m, g + m, b + m]
+    }
+
+    /// Relative luminance as defined by WCAG 2
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |channel: f64| {
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let [r, g, b] = self.to_rgb();
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+
+    /// WCAG contrast ratio between the two colors, from 1 to 21
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// `H S% L%`, the form the stylesheet's variables take
+    pub fn css_components(&self) -> String {
+        format!(
+            "{} {}% {}%",
+            number(self.hue),
+            number(self.saturation),
+            number(self.lightness)
+        )
+    }
+}
+
+/// At most one decimal, without a trailing `.0`
+fn number(value: f64) -> String {
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{:.1}", rounded)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "hsl({})", self.css_components())
+    }
+}
+
+/// Accepts `#rgb`, `#rrggbb`, `hsl(H S% L%)` and bare `H S% L%`, with or
+/// without commas
+impl FromStr for Color {
+    type Err = ThemeError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if text.starts_with('#') {
+            return Self::hex(text);
+        }
+        let invalid = || ThemeError::InvalidColor(text.to_string());
+        let inner = match text.strip_prefix("hsl(") {
+            Some(rest) => rest.strip_suffix(')').ok_or_else(invalid)?,
+            None => text,
+        };
+        let parts: Vec<&str> = inner.split([' ', ',']).filter(|part| !part.is_empty()).collect();
+        let [hue, saturation, lightness] = parts[..] else {
+            return Err(invalid());
+        };
+        let hue: f64 = hue.strip_suffix("deg").unwrap_or(hue).parse().map_err(|_| invalid())?;
+        let percent = |part: &str| -> Result<f64, ThemeError> {
+            let value: f64 = part.strip_suffix('%').ok_or_else(invalid)?.parse().map_err(|_| invalid())?;
+            if (0.0..=100.0).contains(&value) {
+                Ok(value)
+            } else {
+                Err(invalid())
+            }
+        };
+        if !hue.is_finite() {
+            return Err(invalid());
+        }
+        Ok(Self::hsl(hue.rem_euclid(360.0), percent(saturation)?, percent(lightness)?))
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
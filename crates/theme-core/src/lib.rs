@@ -0,0 +1,366 @@
+//! Color themes for dbfordevs. A [`ThemeDefinition`] gives a color for each
+//! semantic [`ColorToken`] the interface is styled with, rather than raw CSS,
+//! so a theme can be checked before it is used: every required token must be
+//! set, and text must stand out from what it is drawn on by at least the WCAG
+//! AA contrast ratio. [`CssBuilder`] renders a definition as the CSS
+//! variables the app's stylesheet reads.
+
+mod color;
+pub mod nordic;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+pub use color::Color;
+
+/// WCAG AA minimum for body text
+pub const AA_TEXT: f64 = 4.5;
+
+/// WCAG AA minimum for large text and for controls such as focus rings
+pub const AA_LARGE_TEXT: f64 = 3.0;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ThemeError {
+    #[error("'{0}' is not a color; use #rrggbb or hsl(H S% L%)")]
+    InvalidColor(String),
+
+    #[error("--{} is not set", .0.name())]
+    MissingColor(ColorToken),
+
+    #[error(
+        "--{} on --{} has a contrast ratio of {ratio:.2}, below the {required} needed to be readable",
+        .foreground.name(),
+        .background.name()
+    )]
+    LowContrast {
+        foreground: ColorToken,
+        background: ColorToken,
+        ratio: f64,
+        required: f64,
+    },
+
+    #[error("Invalid theme definition: {0}")]
+    Parse(String),
+}
+
+/// Whether a theme is meant to be dark or light
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+    Dark,
+    Light,
+}
+
+/// The semantic colors the interface is styled with. Serialized as the name
+/// of the CSS variable each one sets, e.g. `card-foreground`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorToken {
+    Background,
+    Foreground,
+    Card,
+    CardForeground,
+    Popover,
+    PopoverForeground,
+    Primary,
+    PrimaryForeground,
+    Secondary,
+    SecondaryForeground,
+    Muted,
+    MutedForeground,
+    Accent,
+    AccentForeground,
+    Destructive,
+    DestructiveForeground,
+    Border,
+    Input,
+    Ring,
+    Success,
+    Warning,
+    Info,
+    TableHeaderBg,
+    TableRowOdd,
+    TableRowEven,
+    TableRowHover,
+    TextPrimary,
+    TextSecondary,
+    TextDim,
+    SidebarBackground,
+    SidebarForeground,
+    SidebarPrimary,
+    SidebarPrimaryForeground,
+    SidebarAccent,
+    SidebarAccentForeground,
+    SidebarBorder,
+    SidebarRing,
+}
+
+impl ColorToken {
+    pub const ALL: &'static [ColorToken] = &[
+        ColorToken::Background,
+        ColorToken::Foreground,
+        ColorToken::Card,
+        ColorToken::CardForeground,
+        ColorToken::Popover,
+        ColorToken::PopoverForeground,
+        ColorToken::Primary,
+        ColorToken::PrimaryForeground,
+        ColorToken::Secondary,
+        ColorToken::SecondaryForeground,
+        ColorToken::Muted,
+        ColorToken::MutedForeground,
+        ColorToken::Accent,
+        ColorToken::AccentForeground,
+        ColorToken::Destructive,
+        ColorToken::DestructiveForeground,
+        ColorToken::Border,
+        ColorToken::Input,
+        ColorToken::Ring,
+        ColorToken::Success,
+        ColorToken::Warning,
+        ColorToken::Info,
+        ColorToken::TableHeaderBg,
+        ColorToken::TableRowOdd,
+        ColorToken::TableRowEven,
+        ColorToken::TableRowHover,
+        ColorToken::TextPrimary,
+        ColorToken::TextSecondary,
+        ColorToken::TextDim,
+        ColorToken::SidebarBackground,
+        ColorToken::SidebarForeground,
+        ColorToken::SidebarPrimary,
+        ColorToken::SidebarPrimaryForeground,
+        ColorToken::SidebarAccent,
+        ColorToken::SidebarAccentForeground,
+        ColorToken::SidebarBorder,
+        ColorToken::SidebarRing,
+    ];
+
+    /// Name of the CSS variable, without the leading `--`
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorToken::Background => "background",
+            ColorToken::Foreground => "foreground",
+            ColorToken::Card => "card",
+            ColorToken::CardForeground => "card-foreground",
+            ColorToken::Popover => "popover",
+            ColorToken::PopoverForeground => "popover-foreground",
+            ColorToken::Primary => "primary",
+            ColorToken::PrimaryForeground => "primary-foreground",
+            ColorToken::Secondary => "secondary",
+            ColorToken::SecondaryForeground => "secondary-foreground",
+            ColorToken::Muted => "muted",
+            ColorToken::MutedForeground => "muted-foreground",
+            ColorToken::Accent => "accent",
+            ColorToken::AccentForeground => "accent-foreground",
+            ColorToken::Destructive => "destructive",
+            ColorToken::DestructiveForeground => "destructive-foreground",
+            ColorToken::Border => "border",
+            ColorToken::Input => "input",
+            ColorToken::Ring => "ring",
+            ColorToken::Success => "success",
+            ColorToken::Warning => "warning",
+            ColorToken::Info => "info",
+            ColorToken::TableHeaderBg => "table-header-bg",
+            ColorToken::TableRowOdd => "table-row-odd",
+            ColorToken::TableRowEven => "table-row-even",
+            ColorToken::TableRowHover => "table-row-hover",
+            ColorToken::TextPrimary => "text-primary",
+            ColorToken::TextSecondary => "text-secondary",
+            ColorToken::TextDim => "text-dim",
+            ColorToken::SidebarBackground => "sidebar-background",
+            ColorToken::SidebarForeground => "sidebar-foreground",
+            ColorToken::SidebarPrimary => "sidebar-primary",
+            ColorToken::SidebarPrimaryForeground => "sidebar-primary-foreground",
+            ColorToken::SidebarAccent => "sidebar-accent",
+            ColorToken::SidebarAccentForeground => "sidebar-accent-foreground",
+            ColorToken::SidebarBorder => "sidebar-border",
+            ColorToken::SidebarRing => "sidebar-ring",
+        }
+    }
+
+    /// Token used when this one is not set; None for the required tokens
+    pub fn fallback(self) -> Option<ColorToken> {
+        match self {
+            ColorToken::TableHeaderBg => Some(ColorToken::Muted),
+            ColorToken::TableRowOdd => Some(ColorToken::Background),
+            ColorToken::TableRowEven => Some(ColorToken::Card),
+            ColorToken::TableRowHover => Some(ColorToken::Secondary),
+            ColorToken::TextPrimary => Some(ColorToken::Foreground),
+            ColorToken::TextSecondary => Some(ColorToken::MutedForeground),
+            ColorToken::TextDim => Some(ColorToken::MutedForeground),
+            ColorToken::SidebarBackground => Some(ColorToken::Background),
+            ColorToken::SidebarForeground => Some(ColorToken::Foreground),
+            ColorToken::SidebarPrimary => Some(ColorToken::Primary),
+            ColorToken::SidebarPrimaryForeground => Some(ColorToken::PrimaryForeground),
+            ColorToken::SidebarAccent => Some(ColorToken::Accent),
+            ColorToken::SidebarAccentForeground => Some(ColorToken::AccentForeground),
+            ColorToken::SidebarBorder => Some(ColorToken::Border),
+            ColorToken::SidebarRing => Some(ColorToken::Ring),
+            _ => None,
+        }
+    }
+}
+
+/// Text colors and what they are drawn on, with the contrast each pair needs
+pub const CONTRAST_PAIRS: &[(ColorToken, ColorToken, f64)] = &[
+    (ColorToken::Foreground, ColorToken::Background, AA_TEXT),
+    (ColorToken::CardForeground, ColorToken::Card, AA_TEXT),
+    (ColorToken::PopoverForeground, ColorToken::Popover, AA_TEXT),
+    (ColorToken::PrimaryForeground, ColorToken::Primary, AA_TEXT),
+    (ColorToken::SecondaryForeground, ColorToken::Secondary, AA_TEXT),
+    (ColorToken::MutedForeground, ColorToken::Background, AA_TEXT),
+    (ColorToken::AccentForeground, ColorToken::Accent, AA_TEXT),
+    (ColorToken::DestructiveForeground, ColorToken::Destructive, AA_TEXT),
+    (ColorToken::TextPrimary, ColorToken::Background, AA_TEXT),
+    (ColorToken::TextSecondary, ColorToken::Background, AA_TEXT),
+    (ColorToken::SidebarForeground, ColorToken::SidebarBackground, AA_TEXT),
+    (ColorToken::SidebarPrimaryForeground, ColorToken::SidebarPrimary, AA_TEXT),
+    (ColorToken::SidebarAccentForeground, ColorToken::SidebarAccent, AA_TEXT),
+    // Dimmed text is for hints next to regular text, so only needs the large text ratio
+    (ColorToken::TextDim, ColorToken::Background, AA_LARGE_TEXT),
+    (ColorToken::Ring, ColorToken::Background, AA_LARGE_TEXT),
+];
+
+/// A theme variant: a name, whether it is dark or light, and its colors
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeDefinition {
+    pub name: String,
+    pub appearance: Appearance,
+    /// Tokens with a fallback may be left out
+    pub colors: BTreeMap<ColorToken, Color>,
+}
+
+impl ThemeDefinition {
+    /// Parse a definition from JSON and check it
+    pub fn from_json(json: &str) -> Result<Self, Vec<ThemeError>> {
+        let definition: ThemeDefinition =
+            serde_json::from_str(json).map_err(|e| vec![ThemeError::Parse(e.to_string())])?;
+        definition.validate()?;
+        Ok(definition)
+    }
+
+    /// The token's color, or its fallback's when it is not set
+    pub fn color(&self, token: ColorToken) -> Option<Color> {
+        self.colors.get(&self.source(token)).copied()
+    }
+
+    /// The token whose color is used for this one
+    fn source(&self, token: ColorToken) -> ColorToken {
+        match token.fallback() {
+            Some(fallback) if !self.colors.contains_key(&token) => self.source(fallback),
+            _ => token,
+        }
+    }
+
+    /// Every problem with the definition: missing required tokens, and text
+    /// that does not meet its contrast ratio
+    pub fn validate(&self) -> Result<(), Vec<ThemeError>> {
+        let mut problems: Vec<ThemeError> = ColorToken::ALL
+            .iter()
+            .filter(|token| token.fallback().is_none() && !self.colors.contains_key(token))
+            .map(|token| ThemeError::MissingColor(*token))
+            .collect();
+
+        // Pairs that fall back to the same colors are only reported once
+        let mut checked = Vec::new();
+        for &(foreground, background, required) in CONTRAST_PAIRS {
+            let sources = (self.source(foreground), self.source(background));
+            if checked.contains(&sources) {
+                continue;
+            }
+            checked.push(sources);
+            let (Some(fg), Some(bg)) = (self.color(foreground), self.color(background)) else {
+                continue;
+            };
+            let ratio = fg.contrast_ratio(&bg);
+            if ratio < required {
+                problems.push(ThemeError::LowContrast {
+                    foreground,
+                    background,
+                    ratio,
+                    required,
+                });
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+}
+
+/// Builds a [`ThemeDefinition`] token by token
+#[derive(Debug, Clone)]
+pub struct ThemeBuilder {
+    definition: ThemeDefinition,
+}
+
+impl ThemeBuilder {
+    pub fn new(name: impl Into<String>, appearance: Appearance) -> Self {
+        Self {
+            definition: ThemeDefinition {
+                name: name.into(),
+                appearance,
+                colors: BTreeMap::new(),
+            },
+        }
+    }
+
+    pub fn color(mut self, token: ColorToken, color: Color) -> Self {
+        self.definition.colors.insert(token, color);
+        self
+    }
+
+    /// The finished definition, if it passes [`ThemeDefinition::validate`]
+    pub fn build(self) -> Result<ThemeDefinition, Vec<ThemeError>> {
+        self.definition.validate()?;
+        Ok(self.definition)
+    }
+}
+
+/// Renders a definition as CSS variable declarations, one per token, with
+/// fallbacks filled in so the theme never inherits colors from another one
+#[derive(Debug, Clone)]
+pub struct CssBuilder<'a> {
+    definition: &'a ThemeDefinition,
+    selector: Option<String>,
+}
+
+impl<'a> CssBuilder<'a> {
+    pub fn new(definition: &'a ThemeDefinition) -> Self {
+        Self {
+            definition,
+            selector: None,
+        }
+    }
+
+    /// Wrap the declarations in a rule for this selector, e.g. `:root.theme-nordic-dark`
+    pub fn selector(mut self, selector: impl Into<String>) -> Self {
+        self.selector = Some(selector.into());
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let indent = if self.selector.is_some() { "  " } else { "" };
+        let mut css = String::new();
+        if let Some(selector) = &self.selector {
+            css.push_str(selector);
+            css.push_str(" {\n");
+        }
+        css.push_str(&format!("{}/* {} */\n", indent, self.definition.name.replace("*/", "")));
+        for token in ColorToken::ALL {
+            if let Some(color) = self.definition.color(*token) {
+                css.push_str(&format!("{}--{}: {};\n", indent, token.name(), color.css_components()));
+            }
+        }
+        if self.selector.is_some() {
+            css.push_str("}\n");
+        }
+        css
+    }
+}
@@ -0,0 +1,122 @@
+//! Nordic, an arctic, north-bluish palette based on Nord
+//! (<https://www.nordtheme.com/>): Polar Night surfaces with Frost accents for
+//! the dark variant, Snow Storm surfaces with Polar Night text for the light one.
+//!
+//! A few colors are a little lighter or darker than Nord's own so that text
+//! meets the WCAG AA contrast ratio: the muted and dimmed text, Frost accents
+//! and Aurora red in both variants, and the light variant's primary blue.
+
+use crate::{Appearance, Color, ThemeBuilder, ThemeDefinition};
+use crate::ColorToken::*;
+
+pub fn dark() -> ThemeDefinition {
+    ThemeBuilder::new("Nordic Dark", Appearance::Dark)
+        // Background colors - Polar Night
+        .color(Background, Color::hsl(220.0, 16.0, 22.0))
+        .color(Foreground, Color::hsl(218.0, 27.0, 92.0))
+        // Card colors - Polar Night (lighter)
+        .color(Card, Color::hsl(220.0, 17.0, 24.0))
+        .color(CardForeground, Color::hsl(218.0, 27.0, 92.0))
+        // Popover colors
+        .color(Popover, Color::hsl(220.0, 16.0, 22.0))
+        .color(PopoverForeground, Color::hsl(218.0, 27.0, 92.0))
+        // Primary - Frost Blue (nord8: #88C0D0)
+        .color(Primary, Color::hsl(193.0, 43.0, 67.0))
+        .color(PrimaryForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Secondary - Polar Night (nord2)
+        .color(Secondary, Color::hsl(220.0, 16.0, 31.0))
+        .color(SecondaryForeground, Color::hsl(218.0, 27.0, 92.0))
+        // Muted colors
+        .color(Muted, Color::hsl(220.0, 16.0, 31.0))
+        .color(MutedForeground, Color::hsl(220.0, 17.0, 64.0))
+        // Accent - Frost (nord9: #81A1C1)
+        .color(Accent, Color::hsl(213.0, 32.0, 64.0))
+        .color(AccentForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Destructive - Aurora Red (nord11: #BF616A)
+        .color(Destructive, Color::hsl(354.0, 42.0, 48.0))
+        .color(DestructiveForeground, Color::hsl(218.0, 27.0, 92.0))
+        // Border and input
+        .color(Border, Color::hsl(220.0, 16.0, 36.0))
+        .color(Input, Color::hsl(220.0, 16.0, 36.0))
+        .color(Ring, Color::hsl(193.0, 43.0, 67.0))
+        // Semantic colors - Aurora
+        .color(Success, Color::hsl(92.0, 28.0, 65.0))
+        .color(Warning, Color::hsl(40.0, 81.0, 73.0))
+        .color(Info, Color::hsl(193.0, 43.0, 67.0))
+        // Table Specifics
+        .color(TableHeaderBg, Color::hsl(220.0, 16.0, 20.0))
+        .color(TableRowOdd, Color::hsl(220.0, 16.0, 22.0))
+        .color(TableRowEven, Color::hsl(220.0, 17.0, 24.0))
+        .color(TableRowHover, Color::hsl(220.0, 16.0, 28.0))
+        // Text Hierarchy
+        .color(TextPrimary, Color::hsl(218.0, 27.0, 92.0))
+        .color(TextSecondary, Color::hsl(218.0, 20.0, 70.0))
+        .color(TextDim, Color::hsl(220.0, 17.0, 53.0))
+        // Sidebar - Polar Night darker
+        .color(SidebarBackground, Color::hsl(220.0, 16.0, 18.0))
+        .color(SidebarForeground, Color::hsl(218.0, 27.0, 92.0))
+        .color(SidebarPrimary, Color::hsl(193.0, 43.0, 67.0))
+        .color(SidebarPrimaryForeground, Color::hsl(220.0, 16.0, 22.0))
+        .color(SidebarAccent, Color::hsl(220.0, 16.0, 26.0))
+        .color(SidebarAccentForeground, Color::hsl(218.0, 27.0, 92.0))
+        .color(SidebarBorder, Color::hsl(220.0, 16.0, 28.0))
+        .color(SidebarRing, Color::hsl(193.0, 43.0, 67.0))
+        .build()
+        .expect("Nordic Dark meets the contrast requirements")
+}
+
+pub fn light() -> ThemeDefinition {
+    ThemeBuilder::new("Nordic Light", Appearance::Light)
+        // Background colors - Snow Storm
+        .color(Background, Color::hsl(219.0, 28.0, 96.0))
+        .color(Foreground, Color::hsl(220.0, 16.0, 22.0))
+        // Card colors
+        .color(Card, Color::hsl(220.0, 27.0, 98.0))
+        .color(CardForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Popover colors
+        .color(Popover, Color::hsl(220.0, 27.0, 98.0))
+        .color(PopoverForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Primary - Deep Frost (nord10: #5E81AC)
+        .color(Primary, Color::hsl(213.0, 32.0, 46.0))
+        .color(PrimaryForeground, Color::hsl(219.0, 28.0, 96.0))
+        // Secondary - Snow Storm (darker)
+        .color(Secondary, Color::hsl(219.0, 28.0, 88.0))
+        .color(SecondaryForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Muted colors
+        .color(Muted, Color::hsl(219.0, 28.0, 90.0))
+        .color(MutedForeground, Color::hsl(220.0, 16.0, 36.0))
+        // Accent - Frost (nord9: #81A1C1)
+        .color(Accent, Color::hsl(213.0, 32.0, 64.0))
+        .color(AccentForeground, Color::hsl(220.0, 16.0, 22.0))
+        // Destructive - Aurora Red (nord11: #BF616A)
+        .color(Destructive, Color::hsl(354.0, 42.0, 51.0))
+        .color(DestructiveForeground, Color::hsl(219.0, 28.0, 96.0))
+        // Border and input
+        .color(Border, Color::hsl(218.0, 27.0, 82.0))
+        .color(Input, Color::hsl(218.0, 27.0, 82.0))
+        .color(Ring, Color::hsl(213.0, 32.0, 52.0))
+        // Semantic colors - Aurora (darker for contrast)
+        .color(Success, Color::hsl(92.0, 28.0, 52.0))
+        .color(Warning, Color::hsl(28.0, 72.0, 50.0))
+        .color(Info, Color::hsl(213.0, 32.0, 52.0))
+        // Table Specifics
+        .color(TableHeaderBg, Color::hsl(219.0, 28.0, 92.0))
+        .color(TableRowOdd, Color::hsl(219.0, 28.0, 96.0))
+        .color(TableRowEven, Color::hsl(220.0, 27.0, 98.0))
+        .color(TableRowHover, Color::hsl(219.0, 28.0, 88.0))
+        // Text Hierarchy
+        .color(TextPrimary, Color::hsl(220.0, 16.0, 22.0))
+        .color(TextSecondary, Color::hsl(220.0, 16.0, 36.0))
+        .color(TextDim, Color::hsl(220.0, 17.0, 50.0))
+        // Sidebar - Slightly darker
+        .color(SidebarBackground, Color::hsl(219.0, 28.0, 94.0))
+        .color(SidebarForeground, Color::hsl(220.0, 16.0, 22.0))
+        .color(SidebarPrimary, Color::hsl(213.0, 32.0, 46.0))
+        .color(SidebarPrimaryForeground, Color::hsl(219.0, 28.0, 96.0))
+        .color(SidebarAccent, Color::hsl(219.0, 28.0, 88.0))
+        .color(SidebarAccentForeground, Color::hsl(220.0, 16.0, 22.0))
+        .color(SidebarBorder, Color::hsl(218.0, 27.0, 82.0))
+        .color(SidebarRing, Color::hsl(213.0, 32.0, 52.0))
+        .build()
+        .expect("Nordic Light meets the contrast requirements")
+}
@@ -0,0 +1,230 @@
+//! `dbfordevs-validate`: validate connection strings from the command line so
+//! CI pipelines can lint the ones in config files.
+//!
+//! ```text
+//! dbfordevs-validate --lang go "postgres://app@db:5432/app?sslmode=disable"
+//! printenv DATABASE_URL | dbfordevs-validate --lang go --format json -
+//! dbfordevs-validate --scan . --deny-warnings
+//! ```
+//!
+//! Exits 0 when every string is valid, 1 when any has errors (or warnings,
+//! with `--deny-warnings`) and 2 on usage errors.
+
+use clap::{Parser, ValueEnum};
+use connection_scanner::{Finding, Scanner};
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use validator_core::{ValidationMessage, ValidationResult};
+use validator_registry::{redact, Registry};
+
+#[derive(Parser)]
+#[command(name = "dbfordevs-validate", version, about = "Validate database connection strings")]
+struct Args {
+    /// Validator to use, e.g. `go` or `redis`; detected from the string when omitted
+    #[arg(short, long)]
+    lang: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Fail on warnings as well as errors
+    #[arg(long)]
+    deny_warnings: bool,
+
+    /// List the available validators and exit
+    #[arg(long)]
+    list: bool,
+
+    /// Search a project folder for connection strings instead, honouring .gitignore
+    #[arg(long, value_name = "DIR", conflicts_with_all = ["lang", "connection_strings"])]
+    scan: Option<PathBuf>,
+
+    /// Connection strings to check; `-` reads one per line from stdin
+    #[arg(required_unless_present_any = ["list", "scan"])]
+    connection_strings: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Result for one input, as printed with `--format json`
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Report {
+    input: String,
+    validator: String,
+    #[serde(flatten)]
+    result: ValidationResult,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let registry = Registry::new();
+
+    if args.list {
+        for info in registry.list() {
+            println!("{:<8} {}", info.id, info.description);
+        }
+        return ExitCode::SUCCESS;
+    }
+    if let Some(root) = &args.scan {
+        return scan(root, &args);
+    }
+
+    let inputs = match read_inputs(&args.connection_strings) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("error: failed to read stdin: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut reports = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let report = match &args.lang {
+            Some(lang) => match registry.get(lang) {
+                Some(validator) => Report {
+                    result: validator.validate(&input),
+                    validator: validator.info().id,
+                    input,
+                },
+                None => {
+                    eprintln!("error: unknown validator '{}'; available: {}", lang, registry.ids().join(", "));
+                    return ExitCode::from(2);
+                }
+            },
+            None => match registry.detect(&input) {
+                Some((info, result)) => Report { input, validator: info.id, result },
+                None => Report {
+                    input,
+                    validator: String::new(),
+                    result: ValidationResult::from_messages(
+                        None,
+                        vec![ValidationMessage::new(
+                            "UNSUPPORTED_FORMAT",
+                            "No validator recognises this connection string; pass --lang",
+                        )],
+                        vec![],
+                    ),
+                },
+            },
+        };
+        reports.push(masked(report));
+    }
+
+    let failed = reports
+        .iter()
+        .any(|report| !report.result.valid || (args.deny_warnings && !report.result.warnings.is_empty()));
+
+    match args.format {
+        Format::Json => match serde_json::to_string_pretty(&reports) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+        Format::Text => reports.iter().for_each(print_text),
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn scan(root: &Path, args: &Args) -> ExitCode {
+    let findings = match Scanner::new().scan(root) {
+        Ok(findings) => findings,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    match args.format {
+        Format::Json => match serde_json::to_string_pretty(&findings) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return ExitCode::from(2);
+            }
+        },
+        Format::Text => {
+            findings.iter().for_each(print_finding);
+            println!("{} connection strings found", findings.len());
+        }
+    }
+
+    let failed = findings
+        .iter()
+        .any(|finding| !finding.result.valid || (args.deny_warnings && !finding.result.warnings.is_empty()));
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// The arguments, with `-` replaced by the non-empty lines of stdin
+fn read_inputs(args: &[String]) -> std::io::Result<Vec<String>> {
+    let mut inputs = Vec::with_capacity(args.len());
+    for arg in args {
+        if arg == "-" {
+            let mut stdin = String::new();
+            std::io::stdin().read_to_string(&mut stdin)?;
+            inputs.extend(stdin.lines().map(str::trim).filter(|line| !line.is_empty()).map(str::to_string));
+        } else {
+            inputs.push(arg.clone());
+        }
+    }
+    Ok(inputs)
+}
+
+/// Hide the password so results can go into CI logs
+fn masked(mut report: Report) -> Report {
+    report.input = redact(&report.input, &mut report.result);
+    report
+}
+
+fn print_text(report: &Report) {
+    print_result(&report.input, &report.validator, &report.result);
+}
+
+fn print_finding(finding: &Finding) {
+    let location = format!("{}:{}: {}", finding.path.display(), finding.line, finding.value);
+    print_result(&location, finding.validator.as_deref().unwrap_or_default(), &finding.result);
+}
+
+fn print_result(input: &str, validator: &str, result: &ValidationResult) {
+    let status = if result.valid { "valid" } else { "invalid" };
+    let validator = if validator.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", validator)
+    };
+    println!(
+        "{}{}: {} ({} errors, {} warnings)",
+        input,
+        validator,
+        status,
+        result.errors.len(),
+        result.warnings.len()
+    );
+
+    for (kind, messages) in [("error", &result.errors), ("warning", &result.warnings)] {
+        for message in messages {
+            match &message.field {
+                Some(field) => println!("  {}[{}] {}: {}", kind, message.code, field, message.message),
+                None => println!("  {}[{}] {}", kind, message.code, message.message),
+            }
+        }
+    }
+}
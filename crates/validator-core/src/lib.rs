@@ -0,0 +1,173 @@
+mod options;
+
+pub use options::{option_schema, validate_options, OptionReport, OptionSpec, OptionType};
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Database engines a connection string can target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseType {
+    PostgreSQL,
+    MySQL,
+    MariaDB,
+    SQLite,
+    MSSQL,
+    Oracle,
+    MongoDB,
+    Redis,
+    CockroachDB,
+    Cassandra,
+}
+
+impl DatabaseType {
+    /// Default TCP port for the database engine, if it has one
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            DatabaseType::PostgreSQL => Some(5432),
+            DatabaseType::MySQL | DatabaseType::MariaDB => Some(3306),
+            DatabaseType::SQLite => None,
+            DatabaseType::MSSQL => Some(1433),
+            DatabaseType::Oracle => Some(1521),
+            DatabaseType::MongoDB => Some(27017),
+            DatabaseType::Redis => Some(6379),
+            DatabaseType::CockroachDB => Some(26257),
+            DatabaseType::Cassandra => Some(9042),
+        }
+    }
+}
+
+/// Connection components extracted from a connection string
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedConnection {
+    pub database_type: Option<DatabaseType>,
+    pub host: Option<String>,
+    pub port: Option<u16>,
+    pub database: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub ssl_mode: Option<String>,
+    /// Any parameters not mapped to a dedicated field
+    pub options: HashMap<String, String>,
+    /// Name of the format the string was parsed from (e.g. "url", "keyvalue")
+    pub original_format: Option<String>,
+}
+
+/// A single error or warning produced during validation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationMessage {
+    pub code: String,
+    pub message: String,
+    pub field: Option<String>,
+}
+
+impl ValidationMessage {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            field: None,
+        }
+    }
+
+    /// Attach the name of the offending field
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+}
+
+/// Outcome of validating a connection string
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationResult {
+    pub valid: bool,
+    pub parsed: Option<ParsedConnection>,
+    pub errors: Vec<ValidationMessage>,
+    pub warnings: Vec<ValidationMessage>,
+}
+
+impl ValidationResult {
+    /// Build a result from collected messages; valid when there are no errors
+    pub fn from_messages(
+        parsed: Option<ParsedConnection>,
+        errors: Vec<ValidationMessage>,
+        warnings: Vec<ValidationMessage>,
+    ) -> Self {
+        Self {
+            valid: errors.is_empty(),
+            parsed,
+            errors,
+            warnings,
+        }
+    }
+
+    /// Build a failed result from a parse error
+    pub fn from_error(error: &ValidatorError) -> Self {
+        Self::from_messages(None, vec![error.to_message()], vec![])
+    }
+}
+
+/// Metadata describing a validator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfo {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub supported_databases: Vec<DatabaseType>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidatorError {
+    #[error("Unsupported format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    #[error("Invalid value for {field}: {message}")]
+    InvalidValue { field: String, message: String },
+
+    #[error("Missing required field: {0}")]
+    MissingField(String),
+}
+
+impl ValidatorError {
+    /// Convert the error into a validation message for the frontend
+    pub fn to_message(&self) -> ValidationMessage {
+        match self {
+            ValidatorError::UnsupportedFormat(_) => {
+                ValidationMessage::new("UNSUPPORTED_FORMAT", self.to_string())
+            }
+            ValidatorError::ParseError(_) => ValidationMessage::new("PARSE_ERROR", self.to_string()),
+            ValidatorError::InvalidValue { field, .. } => {
+                ValidationMessage::new("INVALID_VALUE", self.to_string()).with_field(field.clone())
+            }
+            ValidatorError::MissingField(field) => {
+                ValidationMessage::new("MISSING_FIELD", self.to_string()).with_field(field.clone())
+            }
+        }
+    }
+}
+
+pub type ValidatorResult<T> = Result<T, ValidatorError>;
+
+/// Common interface implemented by every connection string validator
+pub trait ConnectionValidator: Send + Sync {
+    /// Describe the validator
+    fn info(&self) -> ValidatorInfo;
+
+    /// Extract connection components from a connection string
+    fn parse(&self, connection_string: &str) -> ValidatorResult<ParsedConnection>;
+
+    /// Check a connection string for errors and warnings
+    fn validate(&self, connection_string: &str) -> ValidationResult;
+
+    /// Convert parsed components back into a connection string
+    fn to_connection_string(&self, parsed: &ParsedConnection) -> ValidatorResult<String>;
+}
@@ -0,0 +1,309 @@
+//! Known connection string options per database type.
+
+use crate::{DatabaseType, ValidationMessage};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Value type accepted by an option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionType {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    /// One of `allowed_values` (case-insensitive)
+    Enum,
+    /// Go-style duration such as `5s` or `1m30s`
+    Duration,
+    Path,
+}
+
+/// Description of a single connection string option
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSpec {
+    pub name: &'static str,
+    pub option_type: OptionType,
+    pub allowed_values: &'static [&'static str],
+    pub default: Option<&'static str>,
+    /// Set when the option is deprecated; explains what to use instead
+    pub deprecated: Option<&'static str>,
+}
+
+impl OptionSpec {
+    const fn new(name: &'static str, option_type: OptionType) -> Self {
+        Self {
+            name,
+            option_type,
+            allowed_values: &[],
+            default: None,
+            deprecated: None,
+        }
+    }
+
+    const fn one_of(name: &'static str, allowed_values: &'static [&'static str]) -> Self {
+        Self {
+            allowed_values,
+            ..Self::new(name, OptionType::Enum)
+        }
+    }
+
+    const fn default(mut self, value: &'static str) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    const fn deprecated(mut self, note: &'static str) -> Self {
+        self.deprecated = Some(note);
+        self
+    }
+}
+
+use OptionType::{Boolean, Duration, Integer, Number, Path, String as Text};
+
+const POSTGRES_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::one_of("sslmode", &["disable", "allow", "prefer", "require", "verify-ca", "verify-full"]).default("prefer"),
+    OptionSpec::new("sslcert", Path),
+    OptionSpec::new("sslkey", Path),
+    OptionSpec::new("sslrootcert", Path),
+    OptionSpec::new("sslcrl", Path),
+    OptionSpec::new("sslpassword", Text),
+    OptionSpec::one_of("sslnegotiation", &["postgres", "direct"]).default("postgres"),
+    OptionSpec::one_of("channel_binding", &["disable", "prefer", "require"]).default("prefer"),
+    OptionSpec::one_of("gssencmode", &["disable", "prefer", "require"]).default("prefer"),
+    OptionSpec::new("connect_timeout", Integer),
+    OptionSpec::new("application_name", Text),
+    OptionSpec::new("fallback_application_name", Text),
+    OptionSpec::new("options", Text),
+    OptionSpec::new("client_encoding", Text),
+    OptionSpec::new("search_path", Text),
+    OptionSpec::new("keepalives", Boolean).default("1"),
+    OptionSpec::new("keepalives_idle", Integer),
+    OptionSpec::new("keepalives_interval", Integer),
+    OptionSpec::new("keepalives_count", Integer),
+    OptionSpec::one_of("target_session_attrs", &["any", "read-write", "read-only", "primary", "standby", "prefer-standby"]).default("any"),
+    OptionSpec::one_of("load_balance_hosts", &["disable", "random"]).default("disable"),
+    OptionSpec::new("pool_max_conns", Integer),
+    OptionSpec::new("pool_min_conns", Integer),
+    OptionSpec::new("statement_cache_capacity", Integer),
+    OptionSpec::new("requiressl", Boolean).deprecated("use sslmode=require instead"),
+    OptionSpec::new("sslcompression", Boolean).deprecated("SSL compression is disabled in modern OpenSSL builds"),
+];
+
+const MYSQL_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::one_of("ssl-mode", &["disabled", "preferred", "required", "verify_ca", "verify_identity"]).default("preferred"),
+    OptionSpec::new("ssl-ca", Path),
+    OptionSpec::new("ssl-cert", Path),
+    OptionSpec::new("ssl-key", Path),
+    OptionSpec::new("tls", Text).default("false"),
+    OptionSpec::new("charset", Text),
+    OptionSpec::new("collation", Text),
+    OptionSpec::new("parseTime", Boolean).default("false"),
+    OptionSpec::new("loc", Text).default("UTC"),
+    OptionSpec::new("timeout", Duration),
+    OptionSpec::new("readTimeout", Duration),
+    OptionSpec::new("writeTimeout", Duration),
+    OptionSpec::new("connect_timeout", Integer),
+    OptionSpec::new("allowNativePasswords", Boolean).default("true"),
+    OptionSpec::new("allowCleartextPasswords", Boolean).default("false"),
+    OptionSpec::new("allowOldPasswords", Boolean).default("false"),
+    OptionSpec::new("multiStatements", Boolean).default("false"),
+    OptionSpec::new("interpolateParams", Boolean).default("false"),
+    OptionSpec::new("clientFoundRows", Boolean).default("false"),
+    OptionSpec::new("columnsWithAlias", Boolean).default("false"),
+    OptionSpec::new("rejectReadOnly", Boolean).default("false"),
+    OptionSpec::new("maxAllowedPacket", Integer),
+    OptionSpec::new("statement-cache-capacity", Integer),
+    OptionSpec::new("useSSL", Boolean).deprecated("use ssl-mode (or tls for Go) instead"),
+];
+
+const SQLITE_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::one_of("mode", &["ro", "rw", "rwc", "memory"]).default("rwc"),
+    OptionSpec::one_of("cache", &["shared", "private"]).default("private"),
+    OptionSpec::new("immutable", Boolean).default("false"),
+    OptionSpec::new("vfs", Text),
+];
+
+const MSSQL_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::one_of("encrypt", &["true", "false", "strict", "mandatory", "optional"]).default("true"),
+    OptionSpec::new("trustServerCertificate", Boolean).default("false"),
+    OptionSpec::one_of("applicationIntent", &["readonly", "readwrite"]).default("readwrite"),
+    OptionSpec::new("multiSubnetFailover", Boolean).default("false"),
+    OptionSpec::new("connectTimeout", Integer),
+    OptionSpec::new("applicationName", Text),
+    OptionSpec::new("instanceName", Text),
+];
+
+const MONGODB_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::new("authSource", Text),
+    OptionSpec::one_of("authMechanism", &["SCRAM-SHA-1", "SCRAM-SHA-256", "MONGODB-X509", "GSSAPI", "PLAIN", "MONGODB-AWS"]),
+    OptionSpec::new("replicaSet", Text),
+    OptionSpec::new("tls", Boolean).default("false"),
+    OptionSpec::new("tlsCAFile", Path),
+    OptionSpec::new("tlsCertificateKeyFile", Path),
+    OptionSpec::new("tlsAllowInvalidCertificates", Boolean).default("false"),
+    OptionSpec::new("retryWrites", Boolean).default("true"),
+    OptionSpec::new("w", Text),
+    OptionSpec::one_of("readPreference", &["primary", "primaryPreferred", "secondary", "secondaryPreferred", "nearest"]).default("primary"),
+    OptionSpec::new("maxPoolSize", Integer).default("100"),
+    OptionSpec::new("minPoolSize", Integer).default("0"),
+    OptionSpec::new("connectTimeoutMS", Integer),
+    OptionSpec::new("serverSelectionTimeoutMS", Integer),
+    OptionSpec::new("appName", Text),
+    OptionSpec::new("directConnection", Boolean).default("false"),
+    OptionSpec::new("ssl", Boolean).deprecated("use tls instead"),
+];
+
+const REDIS_OPTIONS: &[OptionSpec] = &[
+    OptionSpec::one_of("protocol", &["resp2", "resp3"]).default("resp2"),
+    OptionSpec::new("db", Integer).default("0"),
+    OptionSpec::one_of("ssl_cert_reqs", &["none", "optional", "required"]).default("required"),
+    OptionSpec::new("ssl_ca_certs", Path),
+    OptionSpec::new("ssl_certfile", Path),
+    OptionSpec::new("ssl_keyfile", Path),
+    OptionSpec::new("socket_timeout", Number),
+    OptionSpec::new("socket_connect_timeout", Number),
+    OptionSpec::new("health_check_interval", Integer),
+    OptionSpec::new("client_name", Text),
+];
+
+/// Known options for a database type; empty when no schema is defined
+pub fn option_schema(database_type: DatabaseType) -> &'static [OptionSpec] {
+    match database_type {
+        DatabaseType::PostgreSQL | DatabaseType::CockroachDB => POSTGRES_OPTIONS,
+        DatabaseType::MySQL | DatabaseType::MariaDB => MYSQL_OPTIONS,
+        DatabaseType::SQLite => SQLITE_OPTIONS,
+        DatabaseType::MSSQL => MSSQL_OPTIONS,
+        DatabaseType::MongoDB => MONGODB_OPTIONS,
+        DatabaseType::Redis => REDIS_OPTIONS,
+        DatabaseType::Oracle | DatabaseType::Cassandra => &[],
+    }
+}
+
+/// Messages produced by [`validate_options`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionReport {
+    pub errors: Vec<ValidationMessage>,
+    pub warnings: Vec<ValidationMessage>,
+}
+
+/// Check parsed options against the schema for a database type.
+///
+/// Unknown options are reported as warnings with a suggestion when a known option
+/// is close enough to be a typo; values that don't match the option type are errors.
+pub fn validate_options(database_type: DatabaseType, options: &HashMap<String, String>) -> OptionReport {
+    let schema = option_schema(database_type);
+    let mut report = OptionReport::default();
+    if schema.is_empty() {
+        return report;
+    }
+
+    // Sort for stable output
+    let mut names: Vec<&String> = options.keys().collect();
+    names.sort();
+
+    for name in names {
+        let value = &options[name];
+        let Some(spec) = schema.iter().find(|s| s.name == name) else {
+            let message = match suggest(name, schema) {
+                Some(suggestion) => format!("Unknown option '{}'; did you mean '{}'?", name, suggestion),
+                None => format!("Unknown option '{}'", name),
+            };
+            report.warnings.push(ValidationMessage::new("UNKNOWN_OPTION", message).with_field(name.clone()));
+            continue;
+        };
+
+        if let Some(note) = spec.deprecated {
+            report.warnings.push(
+                ValidationMessage::new("DEPRECATED_OPTION", format!("'{}' is deprecated: {}", name, note))
+                    .with_field(name.clone()),
+            );
+        }
+
+        if let Some(problem) = check_value(spec, value) {
+            report.errors.push(
+                ValidationMessage::new("INVALID_OPTION_VALUE", format!("Invalid value '{}' for '{}': {}", value, name, problem))
+                    .with_field(name.clone()),
+            );
+        }
+    }
+
+    report
+}
+
+fn check_value(spec: &OptionSpec, value: &str) -> Option<String> {
+    match spec.option_type {
+        OptionType::String => None,
+        OptionType::Path => value.is_empty().then(|| "expected a file path".to_string()),
+        OptionType::Integer => value.parse::<i64>().is_err().then(|| "expected an integer".to_string()),
+        OptionType::Number => value.parse::<f64>().is_err().then(|| "expected a number".to_string()),
+        OptionType::Boolean => {
+            let ok = matches!(
+                value.to_ascii_lowercase().as_str(),
+                "true" | "false" | "1" | "0" | "yes" | "no" | "on" | "off"
+            );
+            (!ok).then(|| "expected true or false".to_string())
+        }
+        OptionType::Enum => {
+            let ok = spec.allowed_values.iter().any(|v| v.eq_ignore_ascii_case(value));
+            (!ok).then(|| format!("expected one of {}", spec.allowed_values.join(", ")))
+        }
+        OptionType::Duration => (!is_duration(value)).then(|| "expected a duration such as 30s or 1m30s".to_string()),
+    }
+}
+
+/// Go `time.ParseDuration` syntax: one or more `<number><unit>` pairs
+fn is_duration(value: &str) -> bool {
+    const UNITS: &[&str] = &["ns", "us", "µs", "ms", "s", "m", "h"];
+    let mut rest = value;
+    if rest.is_empty() {
+        return false;
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .char_indices()
+            .find(|(_, c)| !(c.is_ascii_digit() || *c == '.'))
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return false;
+        }
+        rest = &rest[digits..];
+        // Longest unit first so "ms" isn't read as "m"
+        match UNITS.iter().filter(|u| rest.starts_with(**u)).max_by_key(|u| u.len()) {
+            Some(unit) => rest = &rest[unit.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Find the closest known option name for a likely typo
+fn suggest(name: &str, schema: &[OptionSpec]) -> Option<&'static str> {
+    let lower = name.to_ascii_lowercase();
+    let threshold = (name.len() / 3).max(2);
+
+    schema
+        .iter()
+        .map(|spec| (spec.name, levenshtein(&lower, &spec.name.to_ascii_lowercase())))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            current[j + 1] = (previous[j + 1] + 1).min(current[j] + 1).min(previous[j] + cost);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
@@ -0,0 +1,230 @@
+mod mysql;
+mod pq;
+mod urls;
+
+use validator_core::{
+    validate_options, ConnectionValidator, DatabaseType, ParsedConnection, ValidationMessage,
+    ValidationResult, ValidatorError, ValidatorInfo, ValidatorResult,
+};
+
+/// sslmode values understood by lib/pq
+const PQ_SSL_MODES: &[&str] = &["disable", "require", "verify-ca", "verify-full"];
+
+/// sslmode values only understood by pgx
+const PGX_ONLY_SSL_MODES: &[&str] = &["allow", "prefer"];
+
+/// Connection string formats used by Go database drivers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoFormat {
+    /// lib/pq keyword/value DSN
+    KeyValue,
+    /// go-sql-driver/mysql DSN
+    MySqlDsn,
+    /// postgres://, postgresql:// or mysql:// URL
+    Url,
+}
+
+impl GoFormat {
+    /// Detect the format of a connection string
+    pub fn detect(connection_string: &str) -> Option<Self> {
+        let trimmed = connection_string.trim();
+        let lower = trimmed.to_ascii_lowercase();
+
+        if lower.starts_with("postgres://") || lower.starts_with("postgresql://") || lower.starts_with("mysql://") {
+            return Some(GoFormat::Url);
+        }
+
+        // Keyword/value DSNs start with `keyword=` (whitespace around '=' is allowed)
+        let first_token = trimmed.split_whitespace().next().unwrap_or("");
+        let starts_with_keyword = first_token
+            .split('=')
+            .next()
+            .map(|k| !k.is_empty() && k.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(false);
+        if starts_with_keyword && (first_token.contains('=') || trimmed.contains(" =")) {
+            return Some(GoFormat::KeyValue);
+        }
+
+        if trimmed.contains('/') {
+            return Some(GoFormat::MySqlDsn);
+        }
+
+        None
+    }
+
+    fn from_original(format: Option<&str>) -> Option<Self> {
+        match format {
+            Some(pq::FORMAT) => Some(GoFormat::KeyValue),
+            Some(mysql::FORMAT) => Some(GoFormat::MySqlDsn),
+            Some(urls::FORMAT) => Some(GoFormat::Url),
+            _ => None,
+        }
+    }
+}
+
+/// Validator for connection strings used by Go's lib/pq, pgx and go-sql-driver/mysql
+pub struct GoValidator;
+
+impl GoValidator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn check_postgres(&self, parsed: &ParsedConnection, errors: &mut Vec<ValidationMessage>, warnings: &mut Vec<ValidationMessage>) {
+        match parsed.ssl_mode.as_deref() {
+            None => warnings.push(
+                ValidationMessage::new(
+                    "SSLMODE_MISSING",
+                    "No sslmode set; the driver default applies. Set sslmode explicitly (verify-full is recommended)",
+                )
+                .with_field("sslMode"),
+            ),
+            Some(mode) if PGX_ONLY_SSL_MODES.contains(&mode) => warnings.push(
+                ValidationMessage::new(
+                    "SSLMODE_PGX_ONLY",
+                    format!("sslmode={} is supported by pgx but rejected by lib/pq", mode),
+                )
+                .with_field("sslMode"),
+            ),
+            Some("disable") if !is_local(parsed.host.as_deref()) => warnings.push(
+                ValidationMessage::new("SSL_DISABLED", "TLS is disabled for a remote host").with_field("sslMode"),
+            ),
+            Some(mode) if !PQ_SSL_MODES.contains(&mode) => errors.push(
+                ValidationMessage::new(
+                    "INVALID_SSLMODE",
+                    format!("Unknown sslmode '{}' (expected one of {})", mode, PQ_SSL_MODES.join(", ")),
+                )
+                .with_field("sslMode"),
+            ),
+            _ => {}
+        }
+
+        if parsed.database.is_none() {
+            warnings.push(
+                ValidationMessage::new("DATABASE_MISSING", "No database name set; the user name is used instead")
+                    .with_field("database"),
+            );
+        }
+    }
+
+    fn check_mysql(&self, parsed: &ParsedConnection, warnings: &mut Vec<ValidationMessage>) {
+        let unix = parsed.options.get(mysql::PROTOCOL_OPTION).map(|p| p == "unix").unwrap_or(false);
+
+        match parsed.ssl_mode.as_deref() {
+            None if !unix => warnings.push(
+                ValidationMessage::new(
+                    "TLS_MISSING",
+                    "No tls parameter set; go-sql-driver/mysql connects without TLS by default",
+                )
+                .with_field("sslMode"),
+            ),
+            Some("false") if !is_local(parsed.host.as_deref()) => warnings.push(
+                ValidationMessage::new("TLS_DISABLED", "TLS is disabled for a remote host").with_field("sslMode"),
+            ),
+            Some("skip-verify") => warnings.push(
+                ValidationMessage::new("TLS_VERIFICATION_DISABLED", "tls=skip-verify does not verify the server certificate")
+                    .with_field("sslMode"),
+            ),
+            _ => {}
+        }
+
+        if parsed.options.get("parseTime").map(|v| v != "true").unwrap_or(true) {
+            warnings.push(
+                ValidationMessage::new(
+                    "PARSE_TIME_DISABLED",
+                    "parseTime is not enabled; DATE and DATETIME columns scan as []byte instead of time.Time",
+                )
+                .with_field("parseTime"),
+            );
+        }
+
+        if parsed.host.is_none() {
+            warnings.push(
+                ValidationMessage::new("HOST_MISSING", "No address set; the driver connects to 127.0.0.1:3306")
+                    .with_field("host"),
+            );
+        }
+    }
+}
+
+impl Default for GoValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionValidator for GoValidator {
+    fn info(&self) -> ValidatorInfo {
+        ValidatorInfo {
+            id: "go".to_string(),
+            name: "Go Connection String Validator".to_string(),
+            description: "Validates lib/pq keyword/value DSNs, go-sql-driver/mysql DSNs and database URLs".to_string(),
+            supported_databases: vec![DatabaseType::PostgreSQL, DatabaseType::MySQL],
+        }
+    }
+
+    fn parse(&self, connection_string: &str) -> ValidatorResult<ParsedConnection> {
+        match GoFormat::detect(connection_string) {
+            Some(GoFormat::KeyValue) => pq::parse(connection_string),
+            Some(GoFormat::MySqlDsn) => mysql::parse(connection_string),
+            Some(GoFormat::Url) => urls::parse(connection_string),
+            None => Err(ValidatorError::UnsupportedFormat(
+                "Expected a lib/pq keyword/value DSN, a go-sql-driver/mysql DSN or a URL".to_string(),
+            )),
+        }
+    }
+
+    fn validate(&self, connection_string: &str) -> ValidationResult {
+        let parsed = match self.parse(connection_string) {
+            Ok(parsed) => parsed,
+            Err(e) => return ValidationResult::from_error(&e),
+        };
+
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        match parsed.database_type {
+            Some(DatabaseType::PostgreSQL) => self.check_postgres(&parsed, &mut errors, &mut warnings),
+            Some(DatabaseType::MySQL) => self.check_mysql(&parsed, &mut warnings),
+            _ => {}
+        }
+
+        if parsed.port == Some(0) {
+            errors.push(ValidationMessage::new("INVALID_PORT", "Port 0 is not valid").with_field("port"));
+        }
+
+        if let Some(database_type) = parsed.database_type {
+            // The unix protocol marker is ours, not a driver parameter
+            let mut options = parsed.options.clone();
+            options.remove(mysql::PROTOCOL_OPTION);
+
+            let report = validate_options(database_type, &options);
+            errors.extend(report.errors);
+            warnings.extend(report.warnings);
+        }
+
+        ValidationResult::from_messages(Some(parsed), errors, warnings)
+    }
+
+    fn to_connection_string(&self, parsed: &ParsedConnection) -> ValidatorResult<String> {
+        let format = GoFormat::from_original(parsed.original_format.as_deref()).unwrap_or(
+            match parsed.database_type {
+                Some(DatabaseType::MySQL) | Some(DatabaseType::MariaDB) => GoFormat::MySqlDsn,
+                _ => GoFormat::KeyValue,
+            },
+        );
+
+        match format {
+            GoFormat::KeyValue => Ok(pq::build(parsed)),
+            GoFormat::MySqlDsn => Ok(mysql::build(parsed)),
+            GoFormat::Url => urls::build(parsed),
+        }
+    }
+}
+
+fn is_local(host: Option<&str>) -> bool {
+    match host {
+        None => true,
+        Some(h) => h.starts_with('/') || h == "localhost" || h == "127.0.0.1" || h == "::1",
+    }
+}
@@ -0,0 +1,173 @@
+//! go-sql-driver/mysql DSNs: `user:pass@tcp(host:3306)/dbname?parseTime=true`
+
+use std::collections::HashMap;
+use validator_core::{DatabaseType, ParsedConnection, ValidatorError, ValidatorResult};
+
+pub const FORMAT: &str = "mysql-dsn";
+
+/// Key under which a non-TCP protocol (e.g. `unix`) is kept in the options map
+pub const PROTOCOL_OPTION: &str = "protocol";
+
+pub fn parse(dsn: &str) -> ValidatorResult<ParsedConnection> {
+    let dsn = dsn.trim();
+
+    // The driver splits on the last '/' so passwords may contain slashes
+    let slash = dsn
+        .rfind('/')
+        .ok_or_else(|| ValidatorError::ParseError("Missing '/' before the database name".to_string()))?;
+    let (prefix, rest) = (&dsn[..slash], &dsn[slash + 1..]);
+
+    let (database, query) = match rest.split_once('?') {
+        Some((db, q)) => (db, Some(q)),
+        None => (rest, None),
+    };
+
+    // ...and on the last '@' so passwords may contain '@'
+    let (credentials, address_part) = match prefix.rfind('@') {
+        Some(at) => (Some(&prefix[..at]), &prefix[at + 1..]),
+        None => (None, prefix),
+    };
+
+    let (username, password) = match credentials {
+        Some(creds) => match creds.split_once(':') {
+            Some((user, pass)) => (Some(user.to_string()), Some(pass.to_string())),
+            None => (Some(creds.to_string()), None),
+        },
+        None => (None, None),
+    };
+
+    let mut options: HashMap<String, String> = query
+        .map(|q| url::form_urlencoded::parse(q.as_bytes()).into_owned().collect())
+        .unwrap_or_default();
+
+    let (protocol, address) = if address_part.is_empty() {
+        (None, None)
+    } else {
+        let open = address_part.find('(');
+        match (open, address_part.ends_with(')')) {
+            (Some(open), true) => (
+                Some(&address_part[..open]),
+                Some(&address_part[open + 1..address_part.len() - 1]),
+            ),
+            (None, _) => (Some(address_part), None),
+            (Some(_), false) => {
+                return Err(ValidatorError::ParseError(format!(
+                    "Unbalanced parentheses in address '{}'",
+                    address_part
+                )))
+            }
+        }
+    };
+
+    let mut host = None;
+    let mut port = None;
+    match protocol {
+        Some("tcp") | None => {
+            if let Some(address) = address.filter(|a| !a.is_empty()) {
+                let (h, p) = split_host_port(address)?;
+                host = Some(h);
+                port = p;
+            }
+        }
+        Some("unix") => {
+            host = address.map(|a| a.to_string());
+            options.insert(PROTOCOL_OPTION.to_string(), "unix".to_string());
+        }
+        Some(other) => {
+            return Err(ValidatorError::InvalidValue {
+                field: "protocol".to_string(),
+                message: format!("Unknown protocol '{}' (expected tcp or unix)", other),
+            })
+        }
+    }
+
+    let ssl_mode = options.get("tls").cloned();
+
+    Ok(ParsedConnection {
+        database_type: Some(DatabaseType::MySQL),
+        host,
+        port,
+        database: Some(database.to_string()).filter(|d| !d.is_empty()),
+        username,
+        password,
+        ssl_mode,
+        options,
+        original_format: Some(FORMAT.to_string()),
+    })
+}
+
+fn split_host_port(address: &str) -> ValidatorResult<(String, Option<u16>)> {
+    // IPv6 literals are bracketed: [::1]:3306
+    let (host, port) = if let Some(stripped) = address.strip_prefix('[') {
+        match stripped.split_once(']') {
+            Some((h, rest)) => (h.to_string(), rest.strip_prefix(':')),
+            None => return Err(ValidatorError::ParseError(format!("Invalid address '{}'", address))),
+        }
+    } else {
+        match address.rsplit_once(':') {
+            Some((h, p)) => (h.to_string(), Some(p)),
+            None => (address.to_string(), None),
+        }
+    };
+
+    let port = port
+        .map(|p| {
+            p.parse::<u16>().map_err(|_| ValidatorError::InvalidValue {
+                field: "port".to_string(),
+                message: format!("'{}' is not a valid port", p),
+            })
+        })
+        .transpose()?;
+
+    Ok((host, port))
+}
+
+pub fn build(parsed: &ParsedConnection) -> String {
+    let mut dsn = String::new();
+
+    match (&parsed.username, &parsed.password) {
+        (Some(user), Some(pass)) => dsn.push_str(&format!("{}:{}@", user, pass)),
+        (Some(user), None) => dsn.push_str(&format!("{}@", user)),
+        (None, Some(pass)) => dsn.push_str(&format!(":{}@", pass)),
+        (None, None) => {}
+    }
+
+    let unix = parsed.options.get(PROTOCOL_OPTION).map(|p| p == "unix").unwrap_or(false);
+    if let Some(host) = &parsed.host {
+        if unix {
+            dsn.push_str(&format!("unix({})", host));
+        } else {
+            let host = if host.contains(':') { format!("[{}]", host) } else { host.clone() };
+            match parsed.port {
+                Some(port) => dsn.push_str(&format!("tcp({}:{})", host, port)),
+                None => dsn.push_str(&format!("tcp({})", host)),
+            }
+        }
+    }
+
+    dsn.push('/');
+    if let Some(db) = &parsed.database {
+        dsn.push_str(db);
+    }
+
+    let mut params: Vec<(String, String)> = parsed
+        .options
+        .iter()
+        .filter(|(k, _)| k.as_str() != PROTOCOL_OPTION && k.as_str() != "tls")
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if let Some(tls) = &parsed.ssl_mode {
+        params.push(("tls".to_string(), tls.clone()));
+    }
+    params.sort();
+
+    if !params.is_empty() {
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params.iter())
+            .finish();
+        dsn.push('?');
+        dsn.push_str(&query);
+    }
+
+    dsn
+}
@@ -0,0 +1,154 @@
+//! lib/pq keyword/value DSNs: `host=localhost port=5432 user=app dbname=app sslmode=verify-full`
+
+use std::collections::HashMap;
+use validator_core::{DatabaseType, ParsedConnection, ValidatorError, ValidatorResult};
+
+pub const FORMAT: &str = "keyvalue";
+
+/// Split a keyword/value DSN into pairs, honouring single quotes and backslash escapes
+pub fn parse_pairs(dsn: &str) -> ValidatorResult<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    let mut chars = dsn.trim().chars().peekable();
+
+    loop {
+        // Skip whitespace between pairs
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '=' || c.is_whitespace() {
+                break;
+            }
+            key.push(c);
+            chars.next();
+        }
+
+        // libpq allows whitespace around '='
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.next() != Some('=') {
+            return Err(ValidatorError::ParseError(format!("Missing '=' after keyword '{}'", key)));
+        }
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'\'') {
+            chars.next();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => match chars.next() {
+                        Some(escaped) => value.push(escaped),
+                        None => break,
+                    },
+                    '\'' => {
+                        closed = true;
+                        break;
+                    }
+                    _ => value.push(c),
+                }
+            }
+            if !closed {
+                return Err(ValidatorError::ParseError(format!(
+                    "Unterminated quoted value for '{}'",
+                    key
+                )));
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                chars.next();
+                if c == '\\' {
+                    if let Some(escaped) = chars.next() {
+                        value.push(escaped);
+                    }
+                } else {
+                    value.push(c);
+                }
+            }
+        }
+
+        if key.is_empty() {
+            return Err(ValidatorError::ParseError("Empty keyword".to_string()));
+        }
+        pairs.push((key, value));
+    }
+
+    Ok(pairs)
+}
+
+pub fn parse(dsn: &str) -> ValidatorResult<ParsedConnection> {
+    let mut parsed = ParsedConnection {
+        database_type: Some(DatabaseType::PostgreSQL),
+        original_format: Some(FORMAT.to_string()),
+        ..Default::default()
+    };
+    let mut options = HashMap::new();
+
+    for (key, value) in parse_pairs(dsn)? {
+        match key.as_str() {
+            "host" => parsed.host = Some(value),
+            "port" => {
+                parsed.port = Some(value.parse().map_err(|_| ValidatorError::InvalidValue {
+                    field: "port".to_string(),
+                    message: format!("'{}' is not a valid port", value),
+                })?)
+            }
+            "dbname" => parsed.database = Some(value),
+            "user" => parsed.username = Some(value),
+            "password" => parsed.password = Some(value),
+            "sslmode" => parsed.ssl_mode = Some(value),
+            _ => {
+                options.insert(key, value);
+            }
+        }
+    }
+
+    parsed.options = options;
+    Ok(parsed)
+}
+
+pub fn build(parsed: &ParsedConnection) -> String {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    let fields = [
+        ("host", parsed.host.clone()),
+        ("port", parsed.port.map(|p| p.to_string())),
+        ("user", parsed.username.clone()),
+        ("password", parsed.password.clone()),
+        ("dbname", parsed.database.clone()),
+        ("sslmode", parsed.ssl_mode.clone()),
+    ];
+    for (key, value) in fields {
+        if let Some(value) = value {
+            pairs.push((key.to_string(), value));
+        }
+    }
+
+    let mut extra: Vec<(String, String)> = parsed.options.clone().into_iter().collect();
+    extra.sort();
+    pairs.extend(extra);
+
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, quote(v)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn quote(value: &str) -> String {
+    let needs_quotes = value.is_empty() || value.chars().any(|c| c.is_whitespace() || c == '\'' || c == '\\');
+    if !needs_quotes {
+        return value.to_string();
+    }
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
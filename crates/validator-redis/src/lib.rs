@@ -0,0 +1,330 @@
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::HashMap;
+use url::Url;
+use validator_core::{
+    validate_options, ConnectionValidator, DatabaseType, ParsedConnection, ValidationMessage,
+    ValidationResult, ValidatorError, ValidatorInfo, ValidatorResult,
+};
+
+/// Characters that must be escaped in the userinfo part of a URI
+const USERINFO: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Number of logical databases in a stock redis.conf
+const DEFAULT_DATABASE_COUNT: u32 = 16;
+
+/// Query parameters that only make sense over TLS
+const TLS_OPTIONS: &[&str] = &["ssl_cert_reqs", "ssl_ca_certs", "ssl_certfile", "ssl_keyfile"];
+
+/// Validator for Redis URIs (`redis://`, `rediss://` and `unix://`)
+pub struct RedisValidator;
+
+impl RedisValidator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse `redis://` and `rediss://` URIs
+    fn parse_tcp(&self, connection_string: &str) -> ValidatorResult<ParsedConnection> {
+        let url = Url::parse(connection_string)
+            .map_err(|e| ValidatorError::ParseError(format!("Invalid Redis URI: {}", e)))?;
+
+        let tls = url.scheme() == "rediss";
+        let host = url
+            .host_str()
+            .filter(|h| !h.is_empty())
+            .ok_or_else(|| ValidatorError::MissingField("host".to_string()))?;
+
+        let username = Some(decode(url.username())?).filter(|u| !u.is_empty());
+        let password = url.password().map(decode).transpose()?;
+
+        let mut options: HashMap<String, String> = url
+            .query_pairs()
+            .map(|(k, v)| (k.into_owned(), v.into_owned()))
+            .collect();
+
+        // The database index may come from the path or from a `db` parameter
+        let path = url.path().trim_matches('/');
+        let database = if !path.is_empty() {
+            if path.contains('/') {
+                return Err(ValidatorError::InvalidValue {
+                    field: "database".to_string(),
+                    message: format!("'{}' is not a database index", path),
+                });
+            }
+            options.remove("db");
+            Some(path.to_string())
+        } else {
+            options.remove("db")
+        };
+
+        // redis-rs style `#insecure` fragment disables certificate verification
+        let ssl_mode = match (tls, url.fragment()) {
+            (true, Some("insecure")) => Some("insecure".to_string()),
+            (true, _) => Some("require".to_string()),
+            (false, _) => None,
+        };
+
+        Ok(ParsedConnection {
+            database_type: Some(DatabaseType::Redis),
+            host: Some(host.to_string()),
+            port: url.port(),
+            database,
+            username,
+            password,
+            ssl_mode,
+            options,
+            original_format: Some(url.scheme().to_string()),
+        })
+    }
+
+    /// Parse `unix://` socket URIs; credentials and the database travel as query parameters
+    fn parse_unix(&self, connection_string: &str) -> ValidatorResult<ParsedConnection> {
+        let rest = &connection_string["unix://".len()..];
+        let (path, query) = match rest.split_once('?') {
+            Some((p, q)) => (p, Some(q)),
+            None => (rest, None),
+        };
+
+        if path.is_empty() {
+            return Err(ValidatorError::MissingField("socket path".to_string()));
+        }
+        if path.contains('@') {
+            return Err(ValidatorError::ParseError(
+                "Unix socket URIs pass credentials as ?user=&pass= parameters".to_string(),
+            ));
+        }
+
+        let mut options: HashMap<String, String> = query
+            .map(|q| url::form_urlencoded::parse(q.as_bytes()).into_owned().collect())
+            .unwrap_or_default();
+
+        let database = options.remove("db");
+        let username = options.remove("user");
+        let password = options.remove("pass").or_else(|| options.remove("password"));
+
+        Ok(ParsedConnection {
+            database_type: Some(DatabaseType::Redis),
+            host: Some(decode(path)?),
+            port: None,
+            database,
+            username,
+            password,
+            ssl_mode: None,
+            options,
+            original_format: Some("unix".to_string()),
+        })
+    }
+}
+
+impl Default for RedisValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionValidator for RedisValidator {
+    fn info(&self) -> ValidatorInfo {
+        ValidatorInfo {
+            id: "redis".to_string(),
+            name: "Redis Connection String Validator".to_string(),
+            description: "Validates redis://, rediss:// and unix:// URIs used by Redis clients".to_string(),
+            supported_databases: vec![DatabaseType::Redis],
+        }
+    }
+
+    fn parse(&self, connection_string: &str) -> ValidatorResult<ParsedConnection> {
+        let trimmed = connection_string.trim();
+        let scheme = trimmed.split_once("://").map(|(s, _)| s.to_ascii_lowercase());
+
+        match scheme.as_deref() {
+            Some("redis") | Some("rediss") => self.parse_tcp(trimmed),
+            Some("unix") => self.parse_unix(trimmed),
+            Some(other) => Err(ValidatorError::UnsupportedFormat(format!(
+                "'{}://' is not a Redis scheme (expected redis://, rediss:// or unix://)",
+                other
+            ))),
+            None => Err(ValidatorError::UnsupportedFormat(
+                "Redis connection strings must be URIs (redis://, rediss:// or unix://)".to_string(),
+            )),
+        }
+    }
+
+    fn validate(&self, connection_string: &str) -> ValidationResult {
+        let parsed = match self.parse(connection_string) {
+            Ok(parsed) => parsed,
+            Err(e) => return ValidationResult::from_error(&e),
+        };
+
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        // Database index
+        if let Some(db) = &parsed.database {
+            match db.parse::<u32>() {
+                Ok(index) if index >= DEFAULT_DATABASE_COUNT => warnings.push(
+                    ValidationMessage::new(
+                        "DB_INDEX_OUT_OF_DEFAULT_RANGE",
+                        format!(
+                            "Database index {} exceeds the default of {} databases; make sure 'databases' is raised in redis.conf",
+                            index, DEFAULT_DATABASE_COUNT
+                        ),
+                    )
+                    .with_field("database"),
+                ),
+                Ok(_) => {}
+                Err(_) => errors.push(
+                    ValidationMessage::new(
+                        "INVALID_DB_INDEX",
+                        format!("Database index must be a non-negative integer, got '{}'", db),
+                    )
+                    .with_field("database"),
+                ),
+            }
+        }
+
+        if parsed.port == Some(0) {
+            errors.push(ValidationMessage::new("INVALID_PORT", "Port 0 is not a valid Redis port").with_field("port"));
+        }
+
+        // ACL credentials (Redis 6+) need both halves; a bare password uses legacy AUTH
+        match (&parsed.username, &parsed.password) {
+            (Some(user), None) => warnings.push(
+                ValidationMessage::new(
+                    "ACL_PASSWORD_MISSING",
+                    format!("ACL user '{}' has no password; this only works for users defined with 'nopass'", user),
+                )
+                .with_field("password"),
+            ),
+            (_, Some(password)) if password.is_empty() => warnings.push(
+                ValidationMessage::new("EMPTY_PASSWORD", "Password is present but empty").with_field("password"),
+            ),
+            _ => {}
+        }
+
+        // TLS requirements
+        let tls = parsed.ssl_mode.is_some();
+        if parsed.ssl_mode.as_deref() == Some("insecure") {
+            warnings.push(
+                ValidationMessage::new(
+                    "TLS_VERIFICATION_DISABLED",
+                    "The #insecure fragment disables certificate verification",
+                )
+                .with_field("sslMode"),
+            );
+        }
+
+        for option in TLS_OPTIONS {
+            if !tls && parsed.options.contains_key(*option) {
+                warnings.push(
+                    ValidationMessage::new(
+                        "TLS_OPTION_WITHOUT_TLS",
+                        format!("'{}' has no effect without TLS; use rediss:// instead", option),
+                    )
+                    .with_field(*option),
+                );
+            }
+        }
+
+        let is_local = parsed
+            .host
+            .as_deref()
+            .map(|h| h.starts_with('/') || h == "localhost" || h == "127.0.0.1" || h == "::1" || h == "[::1]")
+            .unwrap_or(false);
+
+        if !tls && !is_local && parsed.password.is_some() {
+            warnings.push(
+                ValidationMessage::new(
+                    "PLAINTEXT_CREDENTIALS",
+                    "Credentials are sent unencrypted to a remote host; use rediss:// to enable TLS",
+                )
+                .with_field("sslMode"),
+            );
+        }
+
+        let report = validate_options(DatabaseType::Redis, &parsed.options);
+        errors.extend(report.errors);
+        warnings.extend(report.warnings);
+
+        ValidationResult::from_messages(Some(parsed), errors, warnings)
+    }
+
+    fn to_connection_string(&self, parsed: &ParsedConnection) -> ValidatorResult<String> {
+        let host = parsed
+            .host
+            .as_deref()
+            .ok_or_else(|| ValidatorError::MissingField("host".to_string()))?;
+
+        let mut query: Vec<(String, String)> = Vec::new();
+        let unix = parsed.original_format.as_deref() == Some("unix") || host.starts_with('/');
+
+        let mut url = if unix {
+            if let Some(db) = &parsed.database {
+                query.push(("db".to_string(), db.clone()));
+            }
+            if let Some(user) = &parsed.username {
+                query.push(("user".to_string(), user.clone()));
+            }
+            if let Some(pass) = &parsed.password {
+                query.push(("pass".to_string(), pass.clone()));
+            }
+            format!("unix://{}", host)
+        } else {
+            let scheme = if parsed.ssl_mode.is_some() { "rediss" } else { "redis" };
+            let mut url = format!("{}://", scheme);
+
+            match (&parsed.username, &parsed.password) {
+                (Some(user), Some(pass)) => url.push_str(&format!("{}:{}@", encode(user), encode(pass))),
+                (Some(user), None) => url.push_str(&format!("{}@", encode(user))),
+                (None, Some(pass)) => url.push_str(&format!(":{}@", encode(pass))),
+                (None, None) => {}
+            }
+
+            url.push_str(host);
+            if let Some(port) = parsed.port {
+                url.push_str(&format!(":{}", port));
+            }
+            if let Some(db) = &parsed.database {
+                url.push_str(&format!("/{}", db));
+            }
+            url
+        };
+
+        // Sort extra options so the output is stable
+        let mut extra: Vec<(String, String)> = parsed
+            .options
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        extra.sort();
+        query.extend(extra);
+
+        if !query.is_empty() {
+            let encoded = url::form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(query.iter())
+                .finish();
+            url.push('?');
+            url.push_str(&encoded);
+        }
+
+        if !unix && parsed.ssl_mode.as_deref() == Some("insecure") {
+            url.push_str("#insecure");
+        }
+
+        Ok(url)
+    }
+}
+
+fn decode(value: &str) -> ValidatorResult<String> {
+    percent_decode_str(value)
+        .decode_utf8()
+        .map(|s| s.into_owned())
+        .map_err(|e| ValidatorError::ParseError(format!("Invalid percent-encoding: {}", e)))
+}
+
+fn encode(value: &str) -> String {
+    utf8_percent_encode(value, USERINFO).to_string()
+}
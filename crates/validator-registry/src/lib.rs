@@ -0,0 +1,91 @@
+//! Every connection string validator shipped with dbfordevs, looked up by the
+//! ID it reports in [`ValidatorInfo`] (`go`, `redis`).
+
+use validator_core::{ConnectionValidator, ValidationResult, ValidatorInfo};
+use validator_go::GoValidator;
+use validator_redis::RedisValidator;
+
+/// Shown in place of passwords
+pub const PASSWORD_MASK: &str = "********";
+
+/// The available validators
+pub struct Registry {
+    validators: Vec<Box<dyn ConnectionValidator>>,
+}
+
+impl Registry {
+    /// Registry holding every bundled validator
+    pub fn new() -> Self {
+        Self {
+            validators: vec![Box::new(RedisValidator::new()), Box::new(GoValidator::new())],
+        }
+    }
+
+    /// Validator with the given ID, ignoring case
+    pub fn get(&self, id: &str) -> Option<&dyn ConnectionValidator> {
+        self.validators
+            .iter()
+            .find(|validator| validator.info().id.eq_ignore_ascii_case(id))
+            .map(|validator| validator.as_ref())
+    }
+
+    /// Description of every validator
+    pub fn list(&self) -> Vec<ValidatorInfo> {
+        self.validators.iter().map(|validator| validator.info()).collect()
+    }
+
+    /// IDs of every validator, for error messages
+    pub fn ids(&self) -> Vec<String> {
+        self.validators.iter().map(|validator| validator.info().id).collect()
+    }
+
+    /// Validate with the first validator that can parse the string, for input
+    /// whose language is unknown. None when no validator recognises it.
+    pub fn detect(&self, connection_string: &str) -> Option<(ValidatorInfo, ValidationResult)> {
+        self.validators
+            .iter()
+            .find(|validator| validator.parse(connection_string).is_ok())
+            .map(|validator| (validator.info(), validator.validate(connection_string)))
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hide the password found by validation, in both the connection string and
+/// the parsed components, so results can be printed or logged
+pub fn redact(connection_string: &str, result: &mut ValidationResult) -> String {
+    let password = result.parsed.as_mut().and_then(|parsed| parsed.password.as_mut()).filter(|p| !p.is_empty());
+    match password {
+        Some(password) => {
+            let redacted = mask_password(connection_string, password);
+            *password = PASSWORD_MASK.to_string();
+            redacted
+        }
+        None => connection_string.to_string(),
+    }
+}
+
+/// Replace `password` where it follows `:` in URL userinfo or `=` in a
+/// keyword/value string, so a user name equal to the password stays readable
+pub fn mask_password(connection_string: &str, password: &str) -> String {
+    for (before, after) in [(":", "@"), ("=", ";"), ("=", " "), ("=", "")] {
+        let needle = format!("{}{}{}", before, password, after);
+        let found = if after.is_empty() {
+            connection_string.ends_with(&needle)
+        } else {
+            connection_string.contains(&needle)
+        };
+        if found {
+            let masked = format!("{}{}{}", before, PASSWORD_MASK, after);
+            return match after {
+                "" => format!("{}{}", &connection_string[..connection_string.len() - needle.len()], masked),
+                _ => connection_string.replacen(&needle, &masked, 1),
+            };
+        }
+    }
+    connection_string.replace(password, PASSWORD_MASK)
+}
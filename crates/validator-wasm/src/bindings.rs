@@ -0,0 +1,37 @@
+//! JavaScript exports. Values cross the boundary as plain objects shaped like
+//! the TypeScript types in `src/types`, matching what the Tauri commands return.
+
+use serde::Serialize;
+use validator_core::ParsedConnection;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(start)]
+fn start() {
+    console_error_panic_hook::set_once();
+}
+
+#[wasm_bindgen(js_name = listValidators)]
+pub fn list_validators() -> Result<JsValue, JsError> {
+    to_js(&crate::list_validators())
+}
+
+#[wasm_bindgen(js_name = validateConnectionString)]
+pub fn validate_connection_string(validator_id: &str, connection_string: &str) -> Result<JsValue, JsError> {
+    to_js(&crate::validate(validator_id, connection_string)?)
+}
+
+#[wasm_bindgen(js_name = detectConnectionString)]
+pub fn detect_connection_string(connection_string: &str) -> Result<JsValue, JsError> {
+    to_js(&crate::detect(connection_string))
+}
+
+#[wasm_bindgen(js_name = toConnectionString)]
+pub fn to_connection_string(validator_id: &str, parsed: JsValue) -> Result<String, JsError> {
+    let parsed: ParsedConnection = serde_wasm_bindgen::from_value(parsed)?;
+    Ok(crate::to_connection_string(validator_id, &parsed)?)
+}
+
+/// Maps become plain objects rather than JavaScript `Map`s
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsError> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
@@ -0,0 +1,73 @@
+//! The connection string validators compiled to WebAssembly, so the UI (and a
+//! web playground) can validate as the user types without a round trip to the
+//! backend. Build the JavaScript package with
+//!
+//! ```text
+//! wasm-pack build crates/validator-wasm --target web --out-dir ../../src/wasm/validator
+//! ```
+//!
+//! The `wasm` feature, on by default, adds the JavaScript bindings. Without it
+//! the crate is plain Rust and the same functions can be called natively.
+
+#[cfg(feature = "wasm")]
+mod bindings;
+
+use serde::Serialize;
+use std::sync::LazyLock;
+use validator_core::{ParsedConnection, ValidationMessage, ValidationResult, ValidatorError, ValidatorInfo};
+use validator_registry::Registry;
+
+static REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
+
+/// Result of validating a string, with the validator that checked it
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    /// Validator used; None when none recognised the string
+    pub validator: Option<String>,
+    #[serde(flatten)]
+    pub result: ValidationResult,
+}
+
+/// Description of every bundled validator
+pub fn list_validators() -> Vec<ValidatorInfo> {
+    REGISTRY.list()
+}
+
+/// Validate with the given validator
+pub fn validate(validator_id: &str, connection_string: &str) -> Result<ValidationResult, ValidatorError> {
+    Ok(REGISTRY.get(validator_id).ok_or_else(|| unknown_validator(validator_id))?.validate(connection_string))
+}
+
+/// Validate with whichever validator recognises the string
+pub fn detect(connection_string: &str) -> Report {
+    match REGISTRY.detect(connection_string) {
+        Some((info, result)) => Report {
+            validator: Some(info.id),
+            result,
+        },
+        None => Report {
+            validator: None,
+            result: ValidationResult::from_messages(
+                None,
+                vec![ValidationMessage::new(
+                    "UNSUPPORTED_FORMAT",
+                    "No validator recognises this connection string",
+                )],
+                vec![],
+            ),
+        },
+    }
+}
+
+/// Build a connection string in the given validator's format
+pub fn to_connection_string(validator_id: &str, parsed: &ParsedConnection) -> Result<String, ValidatorError> {
+    REGISTRY.get(validator_id).ok_or_else(|| unknown_validator(validator_id))?.to_connection_string(parsed)
+}
+
+fn unknown_validator(validator_id: &str) -> ValidatorError {
+    ValidatorError::InvalidValue {
+        field: "validatorId".to_string(),
+        message: format!("unknown validator '{}'; available: {}", validator_id, REGISTRY.ids().join(", ")),
+    }
+}
@@ -0,0 +1,96 @@
+//! Tokens spent on AI requests, for usage statistics and the monthly budget.
+//! The AI providers are called from the frontend, which reports each
+//! request's usage here; records are kept one JSON object per line in the app
+//! data directory.
+
+use crate::error::{AppError, AppResult};
+use crate::metrics;
+use crate::models::{AiModelUsage, AiUsageRecord, AiUsageStats};
+use crate::storage;
+use chrono::{NaiveDate, SecondsFormat, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const USAGE_FILE: &str = "ai_usage.log";
+
+/// Held while appending so records from concurrent requests never interleave
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+fn usage_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(USAGE_FILE))
+}
+
+/// Store a request's usage, stamped with the current time
+pub fn record(mut record: AiUsageRecord) -> AppResult<()> {
+    if let Some(duration_ms) = record.duration_ms {
+        metrics::record_ai_request(&record.operation, duration_ms);
+    }
+    record.timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let mut line = serde_json::to_string(&record)?;
+    line.push('\n');
+
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut file = OpenOptions::new().create(true).append(true).open(usage_path()?)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Totals for a month given as `YYYY-MM`, or the current month. Lines that
+/// cannot be read are skipped.
+pub fn stats(month: Option<&str>) -> AppResult<AiUsageStats> {
+    let month = match month {
+        Some(month) => {
+            NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d")
+                .map_err(|_| AppError::ValidationError(format!("Invalid month '{}'; expected YYYY-MM", month)))?;
+            month.to_string()
+        }
+        None => Utc::now().format("%Y-%m").to_string(),
+    };
+    let mut stats = AiUsageStats {
+        month,
+        ..Default::default()
+    };
+
+    let path = usage_path()?;
+    if !path.exists() {
+        return Ok(stats);
+    }
+
+    let records = BufReader::new(File::open(path)?)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<AiUsageRecord>(&line).ok())
+        .filter(|record| record.timestamp.starts_with(&stats.month));
+
+    for record in records {
+        stats.request_count += 1;
+        stats.prompt_tokens += record.prompt_tokens;
+        stats.completion_tokens += record.completion_tokens;
+        stats.total_tokens += record.total_tokens;
+        stats.estimated_cost += record.estimated_cost;
+
+        let index = stats
+            .by_model
+            .iter()
+            .position(|usage| usage.provider == record.provider && usage.model == record.model)
+            .unwrap_or_else(|| {
+                stats.by_model.push(AiModelUsage {
+                    provider: record.provider.clone(),
+                    model: record.model.clone(),
+                    request_count: 0,
+                    total_tokens: 0,
+                    estimated_cost: 0.0,
+                });
+                stats.by_model.len() - 1
+            });
+        let usage = &mut stats.by_model[index];
+        usage.request_count += 1;
+        usage.total_tokens += record.total_tokens;
+        usage.estimated_cost += record.estimated_cost;
+    }
+
+    stats.by_model.sort_by_key(|usage| std::cmp::Reverse(usage.total_tokens));
+    Ok(stats)
+}
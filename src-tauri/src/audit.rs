@@ -0,0 +1,155 @@
+//! Append-only log of the data and schema changes made through the row-editing
+//! and table commands, and of the queries run to answer AI data questions.
+//! Entries are kept one JSON object per line in the app data directory;
+//! nothing in the app rewrites or truncates the file.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{AuditEntry, AuditLogFilter, ColumnInfo, ConnectionConfig, QueryResult};
+use crate::storage;
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const AUDIT_FILE: &str = "audit.log";
+
+/// Held while appending so entries from concurrent commands never interleave
+static WRITE_LOCK: Mutex<()> = Mutex::new(());
+
+/// A change about to be recorded
+pub struct Change<'a> {
+    /// Command making the change, e.g. `update_row`
+    pub operation: &'a str,
+    pub table: Option<&'a str>,
+    /// SQL sent to the database, or a description when the driver builds the SQL
+    pub statement: String,
+    pub params: Vec<serde_json::Value>,
+}
+
+fn audit_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(AUDIT_FILE))
+}
+
+/// Record the outcome of a change. A failed write is ignored: the change has
+/// already happened, and reporting an error would suggest it had not.
+pub fn record(config: &ConnectionConfig, change: Change<'_>, result: &AppResult<QueryResult>) {
+    let user = std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string());
+
+    let entry = AuditEntry {
+        id: uuid::Uuid::new_v4().to_string(),
+        timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        user,
+        database_user: config.username.clone().filter(|u| !u.is_empty()),
+        connection_id: config.id.clone().unwrap_or_default(),
+        connection_name: config.name.clone(),
+        operation: change.operation.to_string(),
+        table: change.table.map(str::to_string),
+        statement: change.statement,
+        params: change.params,
+        affected_rows: result.as_ref().ok().and_then(|r| r.affected_rows),
+        success: result.is_ok(),
+        error: result.as_ref().err().map(|e| e.to_string()),
+    };
+    let _ = append(&entry);
+}
+
+fn append(entry: &AuditEntry) -> AppResult<()> {
+    let mut line = serde_json::to_string(entry)?;
+    line.push('\n');
+
+    let _guard = WRITE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut file = OpenOptions::new().create(true).append(true).open(audit_path()?)?;
+    file.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Entries matching `filter`, newest first. Lines that cannot be read, such as
+/// one cut short by a crash, are skipped.
+pub fn read(filter: &AuditLogFilter) -> AppResult<Vec<AuditEntry>> {
+    let since = filter.since.as_deref().map(parse_timestamp).transpose()?;
+    let until = filter.until.as_deref().map(parse_timestamp).transpose()?;
+
+    let path = audit_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries: Vec<AuditEntry> = BufReader::new(File::open(path)?)
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| serde_json::from_str::<AuditEntry>(&line).ok())
+        .filter(|entry| {
+            let at = DateTime::parse_from_rfc3339(&entry.timestamp).ok();
+            filter.connection_id.as_ref().is_none_or(|id| *id == entry.connection_id)
+                && filter.table.as_ref().is_none_or(|table| entry.table.as_ref() == Some(table))
+                && filter.operation.as_ref().is_none_or(|operation| *operation == entry.operation)
+                && since.is_none_or(|since| at.is_some_and(|at| at >= since))
+                && until.is_none_or(|until| at.is_some_and(|at| at < until))
+        })
+        .collect();
+
+    entries.reverse();
+    if let Some(limit) = filter.limit {
+        entries.truncate(limit);
+    }
+    Ok(entries)
+}
+
+fn parse_timestamp(value: &str) -> AppResult<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .map_err(|_| AppError::ValidationError(format!("Invalid timestamp '{}'; expected RFC 3339", value)))
+}
+
+/// Entries as a result set, so they can be written by the query exporters
+pub fn to_result(entries: Vec<AuditEntry>) -> QueryResult {
+    let column = |name: &str, data_type: &str| ColumnInfo {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        nullable: true,
+        is_primary_key: false,
+        native_type: None,
+        type_oid: None,
+    };
+
+    QueryResult {
+        columns: vec![
+            column("timestamp", "timestamp"),
+            column("user", "text"),
+            column("database_user", "text"),
+            column("connection_id", "text"),
+            column("connection_name", "text"),
+            column("operation", "text"),
+            column("table", "text"),
+            column("statement", "text"),
+            column("params", "json"),
+            column("affected_rows", "bigint"),
+            column("success", "boolean"),
+            column("error", "text"),
+        ],
+        rows: entries
+            .into_iter()
+            .map(|entry| {
+                vec![
+                    entry.timestamp.into(),
+                    entry.user.into(),
+                    entry.database_user.into(),
+                    entry.connection_id.into(),
+                    entry.connection_name.into(),
+                    entry.operation.into(),
+                    entry.table.into(),
+                    entry.statement.into(),
+                    entry.params.into(),
+                    entry.affected_rows.into(),
+                    entry.success.into(),
+                    entry.error.into(),
+                ]
+            })
+            .collect(),
+        affected_rows: None,
+        execution_time_ms: 0,
+        result_sets: Vec::new(),
+    }
+}
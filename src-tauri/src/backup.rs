@@ -0,0 +1,787 @@
+//! Backups and restores through the databases' own client tools (pg_dump,
+//! pg_restore and psql; mysqldump and mysql; sqlite3), found next to the app,
+//! on PATH or in the usual install folders. Without them a built-in SQL script
+//! of CREATE and INSERT statements is written instead.
+
+use crate::db::{
+    build_ddl_script, cell_literal, mysql_tls_params, postgres_tls_params, quote_identifier, quote_qualified,
+    sql_literal, string_literal, DatabaseDriver, DdlKind, DependencyGraph, PoolRef,
+};
+use crate::error::{AppError, AppResult};
+use crate::export::is_binary_type;
+use crate::models::{
+    BackupOperation, BackupProgress, CellValue, ColumnInfo, ConnectionConfig, DatabaseDdlOptions, DatabaseType,
+};
+use crate::storage;
+use std::collections::{HashSet, VecDeque};
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+
+/// Lines of tool output kept for the summary and error messages
+const MAX_MESSAGES: usize = 50;
+
+/// Rows the built-in script reads from the result stream at a time
+const SCRIPT_BATCH_ROWS: usize = 1000;
+
+/// Rows per INSERT statement in the built-in script
+const SCRIPT_INSERT_ROWS: usize = 100;
+
+/// Bytes fed to a tool's stdin per write while restoring
+const RESTORE_CHUNK: usize = 64 * 1024;
+
+pub const PG_DUMP: &[&str] = &["pg_dump"];
+pub const PG_RESTORE: &[&str] = &["pg_restore"];
+pub const PSQL: &[&str] = &["psql"];
+pub const MYSQL_DUMP: &[&str] = &["mysqldump", "mariadb-dump"];
+pub const MYSQL_CLIENT: &[&str] = &["mysql", "mariadb"];
+pub const SQLITE3: &[&str] = &["sqlite3"];
+
+/// The tools used for a database type, with what each is used for
+pub fn tools_for(database_type: &DatabaseType) -> Vec<(&'static [&'static str], &'static str)> {
+    match database_type {
+        DatabaseType::PostgreSQL => vec![
+            (PG_DUMP, "backup"),
+            (PG_RESTORE, "restore of archives"),
+            (PSQL, "restore of SQL scripts"),
+        ],
+        DatabaseType::MySQL => vec![(MYSQL_DUMP, "backup"), (MYSQL_CLIENT, "restore")],
+        DatabaseType::SQLite => vec![(SQLITE3, "backup and restore")],
+        _ => vec![],
+    }
+}
+
+/// What a file given to restore holds, from its first bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupFileKind {
+    /// A pg_dump archive in custom format
+    PgArchive,
+    /// A SQLite database file
+    SqliteFile,
+    /// A SQL script; `pg_dump` is set for scripts written by pg_dump, which
+    /// use psql commands such as `COPY ... FROM stdin`
+    Script { pg_dump: bool },
+}
+
+pub fn file_kind(path: &Path) -> AppResult<BackupFileKind> {
+    let mut head = Vec::with_capacity(4096);
+    File::open(path)?.take(4096).read_to_end(&mut head)?;
+    if head.starts_with(b"PGDMP") {
+        Ok(BackupFileKind::PgArchive)
+    } else if head.starts_with(b"SQLite format 3\0") {
+        Ok(BackupFileKind::SqliteFile)
+    } else {
+        let text = String::from_utf8_lossy(&head);
+        Ok(BackupFileKind::Script {
+            pg_dump: text.contains("PostgreSQL database dump"),
+        })
+    }
+}
+
+fn executable_name(name: &str) -> String {
+    if cfg!(windows) {
+        format!("{}.exe", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Subfolders of `parent` such as `16` or `mysql-8.0`, newest version first, each joined with `suffix`
+fn versioned_dirs(parent: &Path, suffix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(parent) else {
+        return Vec::new();
+    };
+    let version = |path: &PathBuf| -> Vec<u32> {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        name.split(|c: char| !c.is_ascii_digit())
+            .filter_map(|part| part.parse().ok())
+            .collect()
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort_by_key(|path| std::cmp::Reverse(version(path)));
+    dirs.into_iter().map(|dir| dir.join(suffix)).collect()
+}
+
+/// Folders searched for the tools, in order: a `tools` folder bundled next to
+/// the app or in its data folder, PATH, then where the installers put them
+fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    {
+        dirs.push(exe_dir.join("tools"));
+        dirs.push(exe_dir);
+    }
+    if let Ok(app_dir) = storage::app_dir() {
+        dirs.push(app_dir.join("tools"));
+    }
+    if let Some(path) = std::env::var_os("PATH") {
+        dirs.extend(std::env::split_paths(&path));
+    }
+
+    if cfg!(windows) {
+        for program_files in ["C:\\Program Files", "C:\\Program Files (x86)"] {
+            let program_files = Path::new(program_files);
+            dirs.extend(versioned_dirs(&program_files.join("PostgreSQL"), "bin"));
+            dirs.extend(versioned_dirs(&program_files.join("MySQL"), "bin"));
+            dirs.extend(versioned_dirs(&program_files.join("MariaDB"), "bin"));
+        }
+    } else {
+        dirs.extend(versioned_dirs(Path::new("/usr/lib/postgresql"), "bin"));
+        dirs.extend(versioned_dirs(
+            Path::new("/Applications/Postgres.app/Contents/Versions"),
+            "bin",
+        ));
+        for dir in [
+            "/opt/homebrew/opt/libpq/bin",
+            "/usr/local/opt/libpq/bin",
+            "/opt/homebrew/opt/mysql-client/bin",
+            "/usr/local/opt/mysql-client/bin",
+            "/usr/local/mysql/bin",
+            "/opt/homebrew/bin",
+            "/usr/local/bin",
+            "/usr/bin",
+        ] {
+            dirs.push(PathBuf::from(dir));
+        }
+    }
+    dirs
+}
+
+/// The first of `names` found in the search folders
+pub fn find_tool(names: &[&str]) -> Option<PathBuf> {
+    let dirs = search_dirs();
+    names.iter().find_map(|name| {
+        let file = executable_name(name);
+        dirs.iter().map(|dir| dir.join(&file)).find(|path| path.is_file())
+    })
+}
+
+/// First line the tool prints for `--version`
+pub async fn tool_version(path: &Path) -> Option<String> {
+    let mut command = Command::new(path);
+    command.arg("--version");
+    let output = quiet(command).output().await.ok()?;
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
+
+/// Name a tool is shown by in summaries and errors
+pub fn tool_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Keep Windows from opening a console window for the tool
+fn quiet(mut command: Command) -> Command {
+    #[cfg(windows)]
+    command.creation_flags(0x0800_0000);
+    command.kill_on_drop(true);
+    command
+}
+
+/// A pg_dump, pg_restore or psql command for the connection. The password and
+/// TLS settings go in the environment, where other users cannot see them.
+pub fn postgres_command(tool: &Path, config: &ConnectionConfig) -> Command {
+    let mut command = Command::new(tool);
+    command
+        .arg(format!("--host={}", config.host.as_deref().unwrap_or("localhost")))
+        .arg(format!("--port={}", config.port.unwrap_or(5432)))
+        .arg("--no-password");
+    if let Some(username) = config.username.as_deref().filter(|u| !u.is_empty()) {
+        command.arg(format!("--username={}", username));
+    }
+    if let Some(password) = config.password.as_deref().filter(|p| !p.is_empty()) {
+        command.env("PGPASSWORD", password);
+    }
+    for (key, value) in postgres_tls_params(config) {
+        let variable = match key {
+            "sslmode" => "PGSSLMODE",
+            "sslrootcert" => "PGSSLROOTCERT",
+            "sslcert" => "PGSSLCERT",
+            "sslkey" => "PGSSLKEY",
+            _ => continue,
+        };
+        command.env(variable, value);
+    }
+    quiet(command)
+}
+
+/// Client settings for mysqldump and mysql, written to a file only the current
+/// user can read so the password stays off the command line. Removed on drop.
+pub struct MySqlOptionFile {
+    path: PathBuf,
+}
+
+impl MySqlOptionFile {
+    pub fn create(config: &ConnectionConfig) -> AppResult<Self> {
+        let quote = |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut options = format!(
+            "[client]\nhost={}\nport={}\nprotocol=TCP\n",
+            quote(config.host.as_deref().unwrap_or("localhost")),
+            config.port.unwrap_or(3306)
+        );
+        if let Some(username) = config.username.as_deref().filter(|u| !u.is_empty()) {
+            options.push_str(&format!("user={}\n", quote(username)));
+        }
+        if let Some(password) = config.password.as_deref().filter(|p| !p.is_empty()) {
+            options.push_str(&format!("password={}\n", quote(password)));
+        }
+        for (key, value) in mysql_tls_params(config) {
+            options.push_str(&format!("{}={}\n", key, quote(&value)));
+        }
+
+        let path = std::env::temp_dir().join(format!("dbfordevs-{}.cnf", uuid::Uuid::new_v4()));
+        let mut open = std::fs::OpenOptions::new();
+        open.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut open, 0o600);
+        open.open(&path)?.write_all(options.as_bytes())?;
+        Ok(Self { path })
+    }
+
+    /// A mysqldump or mysql command reading these settings
+    pub fn command(&self, tool: &Path) -> Command {
+        let mut command = Command::new(tool);
+        // Must come before every other option
+        command.arg(format!("--defaults-extra-file={}", self.path.display()));
+        quiet(command)
+    }
+}
+
+impl Drop for MySqlOptionFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Path of a SQLite connection's database file
+pub fn sqlite_path(config: &ConnectionConfig) -> String {
+    let path = config.file_path.as_deref().unwrap_or(config.database.as_str());
+    path.strip_prefix("sqlite://")
+        .or_else(|| path.strip_prefix("sqlite:"))
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// A file name as an argument of a sqlite3 dot command
+pub fn dot_argument(path: &str) -> String {
+    format!("\"{}\"", path.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reports a backup or restore as `backup-progress` events
+pub struct Progress<'a> {
+    pub app: &'a AppHandle,
+    pub operation: BackupOperation,
+    pub path: &'a str,
+    /// Size of the file being restored
+    pub total_bytes: Option<u64>,
+}
+
+impl Progress<'_> {
+    pub fn emit(&self, bytes: u64, message: Option<String>, done: bool) {
+        let _ = self.app.emit(
+            "backup-progress",
+            BackupProgress {
+                operation: self.operation,
+                path: self.path.to_string(),
+                bytes,
+                total_bytes: self.total_bytes,
+                message,
+                done,
+            },
+        );
+    }
+}
+
+/// Where a tool's input comes from and its output goes
+#[derive(Clone, Copy)]
+pub enum ToolIo<'a> {
+    /// The tool reads and writes files named in its arguments
+    Arguments,
+    /// The tool's standard output is the backup file
+    StdoutTo(&'a Path),
+    /// The backup file is fed to the tool's standard input
+    StdinFrom(&'a Path),
+}
+
+/// Run a tool to completion, reporting each line it prints to stderr as
+/// progress. Returns the last lines of that output; fails with them when the
+/// tool exits with an error.
+pub async fn run_tool(mut command: Command, io: ToolIo<'_>, progress: &Progress<'_>) -> AppResult<Vec<String>> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    match io {
+        ToolIo::Arguments => {}
+        ToolIo::StdoutTo(path) => {
+            command.stdout(File::create(path)?);
+        }
+        ToolIo::StdinFrom(_) => {
+            command.stdin(Stdio::piped());
+        }
+    }
+
+    let name = tool_name(Path::new(command.as_std().get_program()));
+    let mut child = command
+        .spawn()
+        .map_err(|e| AppError::Internal(format!("Could not start {}: {}", name, e)))?;
+    let stdin = child.stdin.take();
+    let stderr = child.stderr.take();
+    let bytes = AtomicU64::new(0);
+
+    // Bytes so far: read from the file being restored, or written to the backup
+    let current_bytes = || match io {
+        ToolIo::StdinFrom(_) => bytes.load(Ordering::Relaxed),
+        ToolIo::StdoutTo(path) => std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        ToolIo::Arguments if progress.operation == BackupOperation::Backup => {
+            std::fs::metadata(progress.path).map(|m| m.len()).unwrap_or(0)
+        }
+        ToolIo::Arguments => 0,
+    };
+
+    let feed = async {
+        let (ToolIo::StdinFrom(path), Some(mut stdin)) = (&io, stdin) else {
+            return Ok(());
+        };
+        let mut file = tokio::fs::File::open(path).await?;
+        let mut buffer = vec![0u8; RESTORE_CHUNK];
+        loop {
+            let n = tokio::io::AsyncReadExt::read(&mut file, &mut buffer).await?;
+            if n == 0 {
+                break;
+            }
+            // The tool stopped reading, e.g. at an error it reports on stderr
+            if stdin.write_all(&buffer[..n]).await.is_err() {
+                break;
+            }
+            let total = bytes.fetch_add(n as u64, Ordering::Relaxed) + n as u64;
+            progress.emit(total, None, false);
+        }
+        // Closing stdin tells the tool the input is complete
+        drop(stdin);
+        Ok::<(), std::io::Error>(())
+    };
+
+    let read_messages = async {
+        let mut messages: VecDeque<String> = VecDeque::new();
+        if let Some(stderr) = stderr {
+            let mut lines = BufReader::new(stderr).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    continue;
+                }
+                progress.emit(current_bytes(), Some(line.clone()), false);
+                if messages.len() == MAX_MESSAGES {
+                    messages.pop_front();
+                }
+                messages.push_back(line);
+            }
+        }
+        messages
+    };
+
+    let (fed, messages) = tokio::join!(feed, read_messages);
+    let status = child.wait().await?;
+    fed?;
+
+    let messages: Vec<String> = messages.into();
+    if !status.success() {
+        let detail = messages
+            .iter()
+            .rev()
+            .take(10)
+            .rev()
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(AppError::QueryError(format!(
+            "{} failed ({}){}{}",
+            name,
+            status,
+            if detail.is_empty() { "" } else { ": " },
+            detail
+        )));
+    }
+    Ok(messages)
+}
+
+/// Copy a SQLite database to `path` without the sqlite3 tool
+pub async fn vacuum_into(driver: &dyn DatabaseDriver, pool: PoolRef<'_>, path: &str) -> AppResult<()> {
+    // VACUUM INTO refuses to overwrite a file
+    if Path::new(path).exists() {
+        std::fs::remove_file(path)?;
+    }
+    driver
+        .execute_query(
+            pool,
+            &format!("VACUUM INTO {}", string_literal(&DatabaseType::SQLite, path)),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Counts what the built-in script wrote
+#[derive(Debug, Default)]
+pub struct ScriptStats {
+    pub tables: usize,
+    pub rows: usize,
+    pub bytes: u64,
+}
+
+struct ScriptWriter {
+    out: BufWriter<File>,
+    bytes: u64,
+}
+
+impl ScriptWriter {
+    fn write(&mut self, text: &str) -> AppResult<()> {
+        self.out.write_all(text.as_bytes())?;
+        self.bytes += text.len() as u64;
+        Ok(())
+    }
+}
+
+/// Names in the first column of a catalog query, or none if it fails
+pub async fn catalog_names(driver: &dyn DatabaseDriver, pool: PoolRef<'_>, sql: &str) -> HashSet<String> {
+    match driver.execute_query(pool, sql).await {
+        Ok(result) => result
+            .rows
+            .iter()
+            .filter_map(|row| row.first().and_then(|value| value.as_str()).map(str::to_string))
+            .collect(),
+        Err(_) => HashSet::new(),
+    }
+}
+
+/// Columns the database computes, which INSERT statements must leave out
+pub async fn generated_columns(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    table: &str,
+) -> HashSet<String> {
+    let qualified = string_literal(database_type, &quote_qualified(database_type, table));
+    let name = string_literal(database_type, table);
+    let sql = match database_type {
+        DatabaseType::PostgreSQL => format!(
+            "SELECT attname::text FROM pg_attribute WHERE attrelid = {}::regclass AND attnum > 0 AND attgenerated <> ''",
+            qualified
+        ),
+        DatabaseType::MySQL => format!(
+            "SELECT COLUMN_NAME FROM information_schema.COLUMNS WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = {} \
+             AND EXTRA IN ('VIRTUAL GENERATED', 'STORED GENERATED', 'PERSISTENT GENERATED')",
+            name
+        ),
+        DatabaseType::SQLite => format!("SELECT name FROM pragma_table_xinfo({}) WHERE hidden IN (2, 3)", name),
+        DatabaseType::MSSQL => format!("SELECT name FROM sys.computed_columns WHERE object_id = OBJECT_ID({})", qualified),
+        DatabaseType::Oracle => format!(
+            "SELECT column_name FROM all_tab_cols WHERE {} AND virtual_column = 'YES' AND hidden_column = 'NO'",
+            oracle_table_filter(table)
+        ),
+        _ => return HashSet::new(),
+    };
+    catalog_names(driver, pool, &sql).await
+}
+
+/// Catalog view conditions for an Oracle table; a name without an owner is in the current schema
+fn oracle_table_filter(table: &str) -> String {
+    let literal = |text: &str| string_literal(&DatabaseType::Oracle, text);
+    match table.split_once('.') {
+        Some((owner, name)) => format!("owner = {} AND table_name = {}", literal(owner), literal(name)),
+        None => format!("owner = SYS_CONTEXT('USERENV', 'CURRENT_SCHEMA') AND table_name = {}", literal(table)),
+    }
+}
+
+/// Identity columns of an Oracle table and whether they are GENERATED ALWAYS
+async fn oracle_identity_columns(driver: &dyn DatabaseDriver, pool: PoolRef<'_>, table: &str) -> Vec<(String, bool)> {
+    let sql = format!(
+        "SELECT column_name, generation_type FROM all_tab_identity_cols WHERE {}",
+        oracle_table_filter(table)
+    );
+    match driver.execute_query(pool, &sql).await {
+        Ok(result) => result
+            .rows
+            .iter()
+            .filter_map(|row| {
+                let name = row.first()?.as_str()?.to_string();
+                Some((name, row.get(1).and_then(|value| value.as_str()) == Some("ALWAYS")))
+            })
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
+/// A cell as a literal; binary values arrive base64 encoded and are written as bytes
+fn value_literal(database_type: &DatabaseType, value: &serde_json::Value, binary: bool) -> String {
+    match value {
+        serde_json::Value::String(text) if binary || text.starts_with("[base64: ") => {
+            let encoded = text
+                .strip_prefix("[base64: ")
+                .and_then(|s| s.strip_suffix(']'))
+                .unwrap_or(text);
+            cell_literal(database_type, &CellValue::Bytes(encoded.to_string()))
+        }
+        value => sql_literal(database_type, value),
+    }
+}
+
+/// Write a backup script without the dump tools: the schema objects up to the
+/// tables, then each table's rows as INSERT statements, parents before the
+/// tables referencing them, then indexes, constraints, views and triggers, so
+/// loading the rows neither waits on indexes nor fires triggers.
+pub async fn write_script(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    config: &ConnectionConfig,
+    path: &str,
+    schema_only: bool,
+    progress: &Progress<'_>,
+) -> AppResult<ScriptStats> {
+    let database_type = &config.database_type;
+    // MySQL and ClickHouse names stay unqualified so the script can be loaded into another database
+    let options = DatabaseDdlOptions {
+        include_drops: false,
+        qualify_names: matches!(database_type, DatabaseType::PostgreSQL | DatabaseType::MSSQL | DatabaseType::Oracle),
+    };
+    let objects = driver.get_ddl_objects(pool, config, &options).await?;
+    let (before_data, after_data): (Vec<_>, Vec<_>) =
+        objects.into_iter().partition(|object| object.kind <= DdlKind::Table);
+
+    let mut script = ScriptWriter {
+        out: BufWriter::new(File::create(path)?),
+        bytes: 0,
+    };
+    script.write(&format!(
+        "-- Backup of {} written by dbfordevs on {}\n-- Restore it with dbfordevs or any SQL client\n\n",
+        config.database,
+        chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    ))?;
+    script.write(&build_ddl_script(database_type, before_data, &options))?;
+
+    let mut stats = ScriptStats::default();
+    if !schema_only {
+        let tables: Vec<String> = driver
+            .get_tables(pool, config)
+            .await?
+            .into_iter()
+            // Views hold no rows, and partitions' rows are read through their parent table
+            .filter(|table| !table.table_type.to_uppercase().contains("VIEW") && table.table_type != "PARTITION")
+            .map(|table| table.name)
+            .collect();
+        let listed: HashSet<&str> = tables.iter().map(String::as_str).collect();
+        let mut graph = DependencyGraph::new();
+        for table in &tables {
+            graph.add_object(table);
+        }
+        for dependency in driver.get_object_dependencies(pool).await.unwrap_or_default() {
+            if dependency.is_foreign_key()
+                && listed.contains(dependency.object.as_str())
+                && listed.contains(dependency.depends_on.as_str())
+            {
+                graph.add_dependency(&dependency.object, &dependency.depends_on);
+            }
+        }
+
+        let mysql = matches!(database_type, DatabaseType::MySQL);
+        if mysql {
+            script.write("\nSET FOREIGN_KEY_CHECKS = 0;\n")?;
+        }
+        for table in graph.creation_order() {
+            progress.emit(script.bytes, Some(format!("Writing rows of {}", table)), false);
+            let rows = write_table_rows(driver, pool, database_type, table, &mut script, progress).await?;
+            stats.tables += 1;
+            stats.rows += rows;
+        }
+        if mysql {
+            script.write("\nSET FOREIGN_KEY_CHECKS = 1;\n")?;
+        }
+        if matches!(database_type, DatabaseType::PostgreSQL) {
+            write_sequence_positions(driver, pool, &mut script).await?;
+        }
+    }
+
+    if !after_data.is_empty() {
+        script.write("\n")?;
+        script.write(&build_ddl_script(database_type, after_data, &options))?;
+    }
+    script.out.flush()?;
+    stats.bytes = script.bytes;
+    Ok(stats)
+}
+
+/// Move each sequence to where it stood, since the rows were inserted with their ids
+async fn write_sequence_positions(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    script: &mut ScriptWriter,
+) -> AppResult<()> {
+    let sql = "SELECT quote_ident(schemaname) || '.' || quote_ident(sequencename), last_value::text FROM pg_sequences \
+               WHERE last_value IS NOT NULL AND schemaname NOT IN ('pg_catalog', 'information_schema') ORDER BY 1";
+    // pg_sequences needs PostgreSQL 10
+    let Ok(sequences) = driver.execute_query(pool, sql).await else {
+        return Ok(());
+    };
+    if !sequences.rows.is_empty() {
+        script.write("\n-- Sequence positions\n")?;
+    }
+    for row in &sequences.rows {
+        if let (Some(name), Some(last_value)) = (row[0].as_str(), row[1].as_str()) {
+            let name = string_literal(&DatabaseType::PostgreSQL, name);
+            script.write(&format!("SELECT setval({}, {}, true);\n", name, last_value))?;
+        }
+    }
+    Ok(())
+}
+
+async fn write_table_rows(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    table: &str,
+    script: &mut ScriptWriter,
+    progress: &Progress<'_>,
+) -> AppResult<usize> {
+    let schema = driver.get_table_schema(pool, table).await?;
+    let generated = generated_columns(driver, pool, database_type, table).await;
+    let columns: Vec<&ColumnInfo> = schema
+        .columns
+        .iter()
+        .filter(|column| !generated.contains(&column.name))
+        .collect();
+    if columns.is_empty() {
+        return Ok(0);
+    }
+    let column_list = columns
+        .iter()
+        .map(|column| quote_identifier(database_type, &column.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let binary: Vec<bool> = columns
+        .iter()
+        .map(|column| is_binary_type(&column.data_type.to_lowercase()))
+        .collect();
+    let quoted_table = quote_qualified(database_type, table);
+    let oracle = matches!(database_type, DatabaseType::Oracle);
+
+    // Identity columns take the backed up values only when told to. Oracle
+    // before 23 has no multi-row VALUES, so its rows are a UNION ALL instead.
+    let insert = match database_type {
+        DatabaseType::PostgreSQL => format!(
+            "INSERT INTO {} ({}) OVERRIDING SYSTEM VALUE VALUES",
+            quoted_table, column_list
+        ),
+        DatabaseType::Oracle => format!("INSERT INTO {} ({})", quoted_table, column_list),
+        _ => format!("INSERT INTO {} ({}) VALUES", quoted_table, column_list),
+    };
+    // Oracle identity columns are switched to take them, and restart after the highest afterwards
+    let identities = if oracle {
+        oracle_identity_columns(driver, pool, table).await
+    } else {
+        vec![]
+    };
+    let identity_insert = matches!(database_type, DatabaseType::MSSQL)
+        && !catalog_names(
+            driver,
+            pool,
+            &format!(
+                "SELECT name FROM sys.identity_columns WHERE object_id = OBJECT_ID({})",
+                string_literal(database_type, &quoted_table)
+            ),
+        )
+        .await
+        .is_empty();
+
+    // One cursor over the whole table, so every row is read exactly once
+    // whether or not the table has a primary key to order by
+    let order = schema
+        .primary_keys
+        .iter()
+        .map(|column| quote_identifier(database_type, column))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = if order.is_empty() {
+        format!("SELECT {} FROM {}", column_list, quoted_table)
+    } else {
+        format!("SELECT {} FROM {} ORDER BY {}", column_list, quoted_table, order)
+    };
+
+    let mut rows_written = 0;
+    driver.stream_query(pool, &sql, SCRIPT_BATCH_ROWS, &mut |_, rows| {
+        if rows_written == 0 && !rows.is_empty() {
+            script.write(&format!("\n-- Rows of {}\n", table))?;
+            if identity_insert {
+                script.write(&format!("SET IDENTITY_INSERT {} ON;\n", quoted_table))?;
+            }
+            for (column, _) in identities.iter().filter(|(_, always)| *always) {
+                script.write(&format!(
+                    "ALTER TABLE {} MODIFY ({} GENERATED BY DEFAULT AS IDENTITY);\n",
+                    quoted_table,
+                    quote_identifier(database_type, column)
+                ))?;
+            }
+        }
+
+        for batch in rows.chunks(SCRIPT_INSERT_ROWS) {
+            let values = batch
+                .iter()
+                .map(|row| {
+                    let literals: Vec<String> = row
+                        .iter()
+                        .zip(&binary)
+                        .map(|(value, &binary)| value_literal(database_type, value, binary))
+                        .collect();
+                    if oracle {
+                        format!("SELECT {} FROM dual", literals.join(", "))
+                    } else {
+                        format!("({})", literals.join(", "))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(if oracle { "\n  UNION ALL " } else { ",\n  " });
+            script.write(&format!("{}\n  {};\n", insert, values))?;
+        }
+
+        rows_written += rows.len();
+        progress.emit(
+            script.bytes,
+            Some(format!("Writing rows of {} ({} so far)", table, rows_written)),
+            false,
+        );
+        Ok(())
+    }).await?;
+    if identity_insert && rows_written > 0 {
+        script.write(&format!("SET IDENTITY_INSERT {} OFF;\n", quoted_table))?;
+    }
+    if rows_written > 0 {
+        for (column, always) in &identities {
+            script.write(&format!(
+                "ALTER TABLE {} MODIFY ({} GENERATED {} AS IDENTITY (START WITH LIMIT VALUE));\n",
+                quoted_table,
+                quote_identifier(database_type, column),
+                if *always { "ALWAYS" } else { "BY DEFAULT" }
+            ))?;
+        }
+    }
+
+    Ok(rows_written)
+}
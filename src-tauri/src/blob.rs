@@ -0,0 +1,84 @@
+//! Binary cell values: decoding them from how the drivers render them and
+//! recognizing common file types from their first bytes.
+
+use crate::error::{AppError, AppResult};
+use crate::export::is_binary_type;
+use crate::models::ColumnInfo;
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::Value;
+
+/// Signatures of the file types recognized, with their content type and extension
+const SIGNATURES: &[(&[u8], &str, &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png", "png"),
+    (b"\xff\xd8\xff", "image/jpeg", "jpg"),
+    (b"GIF87a", "image/gif", "gif"),
+    (b"GIF89a", "image/gif", "gif"),
+    (b"BM", "image/bmp", "bmp"),
+    (b"\x00\x00\x01\x00", "image/x-icon", "ico"),
+    (b"%PDF-", "application/pdf", "pdf"),
+    (b"PK\x03\x04", "application/zip", "zip"),
+    (b"\x1f\x8b", "application/gzip", "gz"),
+    (b"SQLite format 3\x00", "application/vnd.sqlite3", "sqlite"),
+];
+
+/// The bytes of a cell. Drivers render binary values as base64, marked with
+/// `[base64: ...]` where the column type does not say it is binary, or as
+/// PostgreSQL `\x` hex; other values are taken as their text.
+pub fn decode_cell(value: &Value, column: &ColumnInfo) -> AppResult<Vec<u8>> {
+    let text = match value {
+        Value::Null => return Err(AppError::ValidationError("The cell is NULL".to_string())),
+        Value::String(text) => text,
+        other => return Ok(other.to_string().into_bytes()),
+    };
+
+    if let Some(encoded) = text.strip_prefix("[base64: ").and_then(|s| s.strip_suffix(']')) {
+        return general_purpose::STANDARD
+            .decode(encoded)
+            .map_err(|e| AppError::ValidationError(format!("The cell is not valid base64: {}", e)));
+    }
+    if is_binary_type(&column.data_type.to_lowercase()) {
+        if let Some(hex) = text.strip_prefix("\\x") {
+            if let Some(bytes) = decode_hex(hex) {
+                return Ok(bytes);
+            }
+        }
+        if let Ok(bytes) = general_purpose::STANDARD.decode(text) {
+            return Ok(bytes);
+        }
+    }
+    Ok(text.as_bytes().to_vec())
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// The content type and file extension of some bytes, from their signature,
+/// falling back to JSON, SVG or plain text when they are UTF-8
+pub fn sniff(bytes: &[u8]) -> (&'static str, &'static str) {
+    if let Some((_, content_type, extension)) = SIGNATURES.iter().find(|(signature, _, _)| bytes.starts_with(signature)) {
+        return (content_type, extension);
+    }
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return ("image/webp", "webp");
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => {
+            let start = text.trim_start();
+            if start.starts_with("<svg") || (start.starts_with("<?xml") && start.contains("<svg")) {
+                ("image/svg+xml", "svg")
+            } else if (start.starts_with('{') || start.starts_with('[')) && serde_json::from_str::<Value>(text).is_ok() {
+                ("application/json", "json")
+            } else {
+                ("text/plain", "txt")
+            }
+        }
+        Err(_) => ("application/octet-stream", "bin"),
+    }
+}
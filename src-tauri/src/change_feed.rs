@@ -0,0 +1,301 @@
+//! Change notifications for PostgreSQL connections.
+//!
+//! Channels: a connection that listens on any channel gets one task holding a
+//! pooled connection with `LISTEN` on each channel, emitting a
+//! `db-notification` event per NOTIFY. Channels are added and removed through
+//! the running task, so no notification is missed meanwhile.
+//!
+//! Tables: a watched table is polled through the row counters in
+//! `pg_stat_user_tables`, emitting a `table-changed` event when rows were
+//! inserted, updated or deleted. This needs no trigger, replication slot or
+//! `wal_level` change on the server; the counters lag commits by about a
+//! second. MySQL has no equivalent that works without reading the binlog.
+
+use crate::db::{get_connection_manager, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::models::{DbNotification, TableChangeEvent};
+use chrono::{SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use sqlx::postgres::{PgListener, PgPool};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::{mpsc, oneshot};
+
+/// Sent with a [`DbNotification`]
+pub const NOTIFICATION_EVENT: &str = "db-notification";
+
+/// Sent with a [`TableChangeEvent`]
+pub const TABLE_CHANGE_EVENT: &str = "table-changed";
+
+/// Longest channel name PostgreSQL accepts, in bytes
+const MAX_CHANNEL_LEN: usize = 63;
+
+enum ListenerCommand {
+    Listen(String, oneshot::Sender<AppResult<()>>),
+    Unlisten(String, oneshot::Sender<AppResult<()>>),
+}
+
+struct Listener {
+    commands: mpsc::UnboundedSender<ListenerCommand>,
+    channels: BTreeSet<String>,
+    handle: JoinHandle<()>,
+}
+
+/// Connection id -> its listening task
+static LISTENERS: OnceCell<tokio::sync::Mutex<HashMap<String, Listener>>> = OnceCell::new();
+
+/// (connection id, table) -> polling task
+type WatcherMap = HashMap<(String, String), JoinHandle<()>>;
+
+static WATCHERS: OnceCell<Mutex<WatcherMap>> = OnceCell::new();
+
+fn listeners() -> &'static tokio::sync::Mutex<HashMap<String, Listener>> {
+    LISTENERS.get_or_init(|| tokio::sync::Mutex::new(HashMap::new()))
+}
+
+fn watchers() -> &'static Mutex<WatcherMap> {
+    WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The pool of a connected PostgreSQL connection
+async fn postgres_pool(connection_id: &str) -> AppResult<PgPool> {
+    let manager = get_connection_manager().read().await;
+    if !manager.is_connected(connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+    match manager.get_pool_ref(connection_id)? {
+        PoolRef::Postgres(pool) => Ok(pool.clone()),
+        _ => Err(AppError::ValidationError(
+            "Change notifications need a PostgreSQL connection".to_string(),
+        )),
+    }
+}
+
+fn validate_channel(channel: &str) -> AppResult<()> {
+    if channel.is_empty() || channel.len() > MAX_CHANNEL_LEN {
+        return Err(AppError::ValidationError(format!(
+            "A channel name must be 1 to {} bytes long",
+            MAX_CHANNEL_LEN
+        )));
+    }
+    Ok(())
+}
+
+/// Listen on a channel, starting the connection's listener if needed.
+/// Returns the channels the connection now listens on.
+pub async fn listen(app: AppHandle, connection_id: &str, channel: &str) -> AppResult<Vec<String>> {
+    validate_channel(channel)?;
+    let mut listeners = listeners().lock().await;
+    if listeners.get(connection_id).is_none_or(|listener| listener.commands.is_closed()) {
+        let pool = postgres_pool(connection_id).await?;
+        let listener = PgListener::connect_with(&pool)
+            .await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to open a listening connection: {}", e)))?;
+        let (commands, receiver) = mpsc::unbounded_channel();
+        let handle = tauri::async_runtime::spawn(run_listener(app, connection_id.to_string(), listener, receiver));
+        listeners.insert(
+            connection_id.to_string(),
+            Listener {
+                commands,
+                channels: BTreeSet::new(),
+                handle,
+            },
+        );
+    }
+
+    let listener = listeners.get_mut(connection_id).expect("listener was just started");
+    let (reply, response) = oneshot::channel();
+    listener
+        .commands
+        .send(ListenerCommand::Listen(channel.to_string(), reply))
+        .map_err(|_| AppError::ConnectionError("The listening connection was closed".to_string()))?;
+    response
+        .await
+        .map_err(|_| AppError::ConnectionError("The listening connection was closed".to_string()))??;
+    listener.channels.insert(channel.to_string());
+    Ok(listener.channels.iter().cloned().collect())
+}
+
+/// Stop listening on a channel; the listener stops with the last channel.
+/// Returns the channels the connection still listens on.
+pub async fn unlisten(connection_id: &str, channel: &str) -> AppResult<Vec<String>> {
+    let mut listeners = listeners().lock().await;
+    let Some(listener) = listeners.get_mut(connection_id) else {
+        return Ok(Vec::new());
+    };
+    if listener.channels.contains(channel) {
+        let (reply, response) = oneshot::channel();
+        if listener.commands.send(ListenerCommand::Unlisten(channel.to_string(), reply)).is_ok() {
+            // A closed listener is not listening anyway
+            if let Ok(result) = response.await {
+                result?;
+            }
+        }
+        listener.channels.remove(channel);
+    }
+
+    let channels: Vec<String> = listener.channels.iter().cloned().collect();
+    if channels.is_empty() {
+        if let Some(listener) = listeners.remove(connection_id) {
+            listener.handle.abort();
+        }
+    }
+    Ok(channels)
+}
+
+/// The channels a connection listens on
+pub async fn channels(connection_id: &str) -> Vec<String> {
+    listeners()
+        .lock()
+        .await
+        .get(connection_id)
+        .filter(|listener| !listener.commands.is_closed())
+        .map(|listener| listener.channels.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+async fn run_listener(
+    app: AppHandle,
+    connection_id: String,
+    mut listener: PgListener,
+    mut commands: mpsc::UnboundedReceiver<ListenerCommand>,
+) {
+    loop {
+        tokio::select! {
+            command = commands.recv() => match command {
+                Some(ListenerCommand::Listen(channel, reply)) => {
+                    let result = listener
+                        .listen(&channel)
+                        .await
+                        .map_err(|e| AppError::QueryError(format!("Failed to listen on {}: {}", channel, e)));
+                    let _ = reply.send(result);
+                }
+                Some(ListenerCommand::Unlisten(channel, reply)) => {
+                    let result = listener
+                        .unlisten(&channel)
+                        .await
+                        .map_err(|e| AppError::QueryError(format!("Failed to stop listening on {}: {}", channel, e)));
+                    let _ = reply.send(result);
+                }
+                None => break,
+            },
+            notification = listener.recv() => match notification {
+                Ok(notification) => {
+                    let _ = app.emit(
+                        NOTIFICATION_EVENT,
+                        DbNotification {
+                            connection_id: connection_id.clone(),
+                            channel: notification.channel().to_string(),
+                            payload: notification.payload().to_string(),
+                            process_id: notification.process_id(),
+                            received_at: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+                        },
+                    );
+                }
+                // The pool was closed on disconnect, or reconnecting failed
+                Err(e) => {
+                    tracing::warn!(connection_id = %connection_id, "Stopped listening for notifications: {}", e);
+                    break;
+                }
+            },
+        }
+    }
+}
+
+/// Poll a table's row counters every `interval`, replacing any watcher
+/// already running for it
+pub async fn watch_table(app: AppHandle, connection_id: String, table: String, interval: Duration) -> AppResult<()> {
+    let pool = postgres_pool(&connection_id).await?;
+    let (schema, name) = match table.split_once('.') {
+        Some((schema, name)) => (Some(schema.to_string()), name.to_string()),
+        None => (None, table.clone()),
+    };
+    // Fails here when the table does not exist, rather than in the task
+    let mut last = row_counters(&pool, schema.as_deref(), &name).await?;
+
+    let key = (connection_id.clone(), table.clone());
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            if pool.is_closed() {
+                break;
+            }
+            let Ok(counters) = row_counters(&pool, schema.as_deref(), &name).await else {
+                continue;
+            };
+            if counters != last {
+                let _ = app.emit(
+                    TABLE_CHANGE_EVENT,
+                    TableChangeEvent {
+                        connection_id: connection_id.clone(),
+                        table: table.clone(),
+                        inserted: counters.0 - last.0,
+                        updated: counters.1 - last.1,
+                        deleted: counters.2 - last.2,
+                    },
+                );
+                last = counters;
+            }
+        }
+    });
+
+    let previous = watchers().lock().unwrap_or_else(|e| e.into_inner()).insert(key, handle);
+    if let Some(previous) = previous {
+        previous.abort();
+    }
+    Ok(())
+}
+
+/// Stop watching a table; returns false if it was not watched
+pub fn unwatch_table(connection_id: &str, table: &str) -> bool {
+    let handle = watchers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&(connection_id.to_string(), table.to_string()));
+    match handle {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Rows inserted, updated and deleted in a table since the statistics were
+/// reset. A table without a schema is looked up on the search path.
+async fn row_counters(pool: &PgPool, schema: Option<&str>, table: &str) -> AppResult<(i64, i64, i64)> {
+    sqlx::query_as::<_, (i64, i64, i64)>(
+        "SELECT n_tup_ins, n_tup_upd, n_tup_del FROM pg_stat_user_tables \
+         WHERE relname = $2 AND ($1::text IS NULL AND schemaname = ANY(current_schemas(false)) OR schemaname = $1) \
+         LIMIT 1",
+    )
+    .bind(schema)
+    .bind(table)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| AppError::QueryError(format!("Failed to read the table statistics: {}", e)))?
+    .ok_or_else(|| AppError::ValidationError(format!("Table '{}' not found", table)))
+}
+
+/// Stop listening and watching on a connection, when it disconnects
+pub async fn stop(connection_id: &str) {
+    if let Some(listener) = listeners().lock().await.remove(connection_id) {
+        listener.handle.abort();
+    }
+    watchers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .retain(|(id, _), handle| {
+            if id == connection_id {
+                handle.abort();
+            }
+            id != connection_id
+        });
+}
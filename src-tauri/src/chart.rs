@@ -0,0 +1,216 @@
+//! Aggregation of query results into chart series. Rows are grouped by their
+//! x value (and group_by value) and each measure is combined per group, so the
+//! frontend plots a few hundred points instead of every row.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{ChartAggregation, ChartData, ChartMeasure, ChartSeries, ChartSpec, QueryResult};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Distinct x values kept when the spec gives no limit
+const DEFAULT_MAX_POINTS: usize = 1000;
+
+/// Groups kept when the spec gives no limit
+const DEFAULT_MAX_GROUPS: usize = 20;
+
+#[derive(Clone, Copy, Default)]
+struct Accumulator {
+    /// Rows, or non-null values when counting a column
+    count: u64,
+    /// Numeric values
+    numbers: u64,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Accumulator {
+    fn add(&mut self, value: f64) {
+        self.numbers += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    fn result(&self, aggregation: ChartAggregation) -> Option<f64> {
+        match aggregation {
+            ChartAggregation::Count => Some(self.count as f64),
+            ChartAggregation::Sum => (self.numbers > 0).then_some(self.sum),
+            ChartAggregation::Avg => (self.numbers > 0).then(|| self.sum / self.numbers as f64),
+            ChartAggregation::Min => self.min,
+            ChartAggregation::Max => self.max,
+        }
+    }
+}
+
+/// Distinct values in order of first appearance, up to a limit
+struct Distinct {
+    values: Vec<Value>,
+    index: HashMap<String, usize>,
+    limit: usize,
+}
+
+impl Distinct {
+    fn new(limit: usize) -> Self {
+        Self {
+            values: Vec::new(),
+            index: HashMap::new(),
+            limit,
+        }
+    }
+
+    /// Whether a value is known or there is room for it
+    fn admits(&self, value: &Value) -> bool {
+        self.values.len() < self.limit || self.index.contains_key(&key(value))
+    }
+
+    /// The index of a value, adding it when it is new
+    fn position(&mut self, value: &Value) -> usize {
+        let key = key(value);
+        if let Some(position) = self.index.get(&key) {
+            return *position;
+        }
+        self.values.push(value.clone());
+        self.index.insert(key, self.values.len() - 1);
+        self.values.len() - 1
+    }
+}
+
+/// The JSON text keeps 1 and "1" apart
+fn key(value: &Value) -> String {
+    value.to_string()
+}
+
+/// A value as a number; numeric types the driver returns as text (decimals,
+/// 64-bit integers) are parsed
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse::<f64>().ok().filter(|number| number.is_finite()),
+        _ => None,
+    }
+}
+
+fn column_index(result: &QueryResult, name: &str) -> AppResult<usize> {
+    result
+        .columns
+        .iter()
+        .position(|column| column.name == name)
+        .ok_or_else(|| AppError::ValidationError(format!("Column '{}' is not in the result", name)))
+}
+
+fn measure_name(measure: &ChartMeasure) -> String {
+    let aggregation = serde_json::to_value(measure.aggregation)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+    match &measure.column {
+        Some(column) => format!("{}({})", aggregation, column),
+        None => aggregation,
+    }
+}
+
+fn group_name(group: &Value) -> String {
+    match group {
+        Value::Null => "NULL".to_string(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Aggregate a result's rows into one series per measure, or per measure
+/// and group when the spec has a group_by column
+pub fn prepare(result: &QueryResult, spec: &ChartSpec) -> AppResult<ChartData> {
+    if spec.measures.is_empty() {
+        return Err(AppError::ValidationError("A chart needs at least one measure".to_string()));
+    }
+    let x_column = column_index(result, &spec.x_column)?;
+    let group_column = spec.group_by.as_deref().map(|name| column_index(result, name)).transpose()?;
+    let measure_columns = spec
+        .measures
+        .iter()
+        .map(|measure| match (&measure.column, measure.aggregation) {
+            (Some(name), _) => column_index(result, name).map(Some),
+            (None, ChartAggregation::Count) => Ok(None),
+            (None, _) => Err(AppError::ValidationError(
+                "Only a count can be charted without a column".to_string(),
+            )),
+        })
+        .collect::<AppResult<Vec<_>>>()?;
+
+    let mut labels = Distinct::new(spec.max_points.unwrap_or(DEFAULT_MAX_POINTS).max(1));
+    let mut groups = Distinct::new(spec.max_groups.unwrap_or(DEFAULT_MAX_GROUPS).max(1));
+    let mut cells: HashMap<(usize, usize), Vec<Accumulator>> = HashMap::new();
+    let mut rows_used = 0;
+    let mut rows_dropped = 0;
+
+    for row in &result.rows {
+        let cell = |column: usize| row.get(column).unwrap_or(&Value::Null);
+        let fits_group = group_column.is_none_or(|column| groups.admits(cell(column)));
+        if !fits_group || !labels.admits(cell(x_column)) {
+            rows_dropped += 1;
+            continue;
+        }
+        let group = group_column.map_or(0, |column| groups.position(cell(column)));
+        let x = labels.position(cell(x_column));
+        rows_used += 1;
+
+        let accumulators = cells
+            .entry((group, x))
+            .or_insert_with(|| vec![Accumulator::default(); spec.measures.len()]);
+        for (accumulator, column) in accumulators.iter_mut().zip(&measure_columns) {
+            match column {
+                // Counting rows
+                None => accumulator.count += 1,
+                Some(column) => {
+                    let value = cell(*column);
+                    if !value.is_null() {
+                        accumulator.count += 1;
+                    }
+                    if let Some(number) = as_number(value) {
+                        accumulator.add(number);
+                    }
+                }
+            }
+        }
+    }
+
+    let group_values: Vec<Option<Value>> = match group_column {
+        Some(_) => groups.values.into_iter().map(Some).collect(),
+        None => vec![None],
+    };
+    let mut series = Vec::with_capacity(group_values.len() * spec.measures.len());
+    for (group, group_value) in group_values.into_iter().enumerate() {
+        for (measure_index, measure) in spec.measures.iter().enumerate() {
+            let name = match &group_value {
+                Some(value) if spec.measures.len() > 1 => format!("{} · {}", group_name(value), measure_name(measure)),
+                Some(value) => group_name(value),
+                None => measure_name(measure),
+            };
+            let values = (0..labels.values.len())
+                .map(|x| {
+                    let accumulator = cells.get(&(group, x)).map(|cells| cells[measure_index]);
+                    match accumulator {
+                        Some(accumulator) => accumulator.result(measure.aggregation),
+                        None if measure.aggregation == ChartAggregation::Count => Some(0.0),
+                        None => None,
+                    }
+                })
+                .collect();
+            series.push(ChartSeries {
+                name,
+                measure: measure_index,
+                group: group_value.clone(),
+                values,
+            });
+        }
+    }
+
+    Ok(ChartData {
+        labels: labels.values,
+        series,
+        rows_used,
+        rows_dropped,
+        truncated: rows_dropped > 0,
+    })
+}
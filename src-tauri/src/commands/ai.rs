@@ -0,0 +1,101 @@
+use crate::ai_usage;
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::models::{AiUsageRecord, AiUsageStats, PromptTemplate, SchemaDocument, SchemaIndexInfo, SchemaSearchHit};
+use crate::prompt_templates;
+use crate::schema_cache;
+use crate::schema_search;
+use crate::storage;
+
+/// Record the tokens spent on an AI request
+#[tauri::command]
+pub async fn record_ai_usage(record: AiUsageRecord) -> AppResult<()> {
+    ai_usage::record(record)
+}
+
+/// Tokens and cost of AI requests in a month (`YYYY-MM`), the current one by default
+#[tauri::command]
+pub async fn get_ai_usage_stats(month: Option<String>) -> AppResult<AiUsageStats> {
+    ai_usage::stats(month.as_deref())
+}
+
+/// Every AI prompt, with the user's template where one is saved
+#[tauri::command]
+pub async fn get_prompt_templates() -> AppResult<Vec<PromptTemplate>> {
+    prompt_templates::list()
+}
+
+/// Validate and save the user's template for a prompt
+#[tauri::command]
+pub async fn save_prompt_template(name: String, content: String) -> AppResult<PromptTemplate> {
+    prompt_templates::save(&name, &content)
+}
+
+/// Go back to the built-in prompt
+#[tauri::command]
+pub async fn reset_prompt_to_default(name: String) -> AppResult<PromptTemplate> {
+    prompt_templates::reset_to_default(&name)
+}
+
+/// Tables and columns of the connected database, with their comments, as documents to embed
+#[tauri::command]
+pub async fn get_schema_documents(connection_id: String) -> AppResult<Vec<SchemaDocument>> {
+    schema_documents(&connection_id).await
+}
+
+/// Store a schema index whose embeddings the AI provider computed
+#[tauri::command]
+pub async fn save_schema_index(
+    connection_id: String,
+    model: String,
+    documents: Vec<SchemaDocument>,
+    vectors: Vec<Vec<f32>>,
+) -> AppResult<SchemaIndexInfo> {
+    schema_search::save(&connection_id, &model, documents, vectors)
+}
+
+/// Index the schema with the built-in embeddings, which need no AI provider
+#[tauri::command]
+pub async fn build_local_schema_index(connection_id: String) -> AppResult<SchemaIndexInfo> {
+    let documents = schema_documents(&connection_id).await?;
+    schema_search::build_local(&connection_id, documents)
+}
+
+/// The model and size of the connection's schema index; None before it is built
+#[tauri::command]
+pub async fn get_schema_index_info(connection_id: String) -> AppResult<Option<SchemaIndexInfo>> {
+    schema_search::info(&connection_id)
+}
+
+/// Tables and columns that best match a description such as "customer billing address".
+/// Indexes built with a provider's model need the query embedded with that model.
+#[tauri::command]
+pub async fn semantic_search_schema(
+    connection_id: String,
+    query: String,
+    model: Option<String>,
+    query_vector: Option<Vec<f32>>,
+    limit: Option<usize>,
+) -> AppResult<Vec<SchemaSearchHit>> {
+    schema_search::search(&connection_id, &query, model.as_deref(), query_vector, limit)
+}
+
+async fn schema_documents(connection_id: &str) -> AppResult<Vec<SchemaDocument>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(connection_id)?;
+
+    let tables = driver.get_all_table_schemas(pool_ref, &config).await?;
+    let tables = schema_cache::store(connection_id, tables);
+    let comments = driver.get_comments(pool_ref).await?;
+    Ok(schema_search::documents(&tables, &comments))
+}
@@ -0,0 +1,22 @@
+use crate::error::AppResult;
+use crate::logging;
+use crate::models::{AppLogs, LogSettings};
+
+/// Recent application log events, to attach to a bug report
+#[tauri::command]
+pub async fn get_app_logs(limit: Option<usize>, level: Option<String>) -> AppResult<AppLogs> {
+    logging::recent(limit.unwrap_or(logging::DEFAULT_LIMIT), level.as_deref())
+}
+
+/// The app's log level and the levels set per module
+#[tauri::command]
+pub async fn get_log_settings() -> AppResult<LogSettings> {
+    logging::settings()
+}
+
+/// Change the log level of the app, or of one module when given; no level
+/// resets it
+#[tauri::command]
+pub async fn set_log_level(level: Option<String>, module: Option<String>) -> AppResult<LogSettings> {
+    logging::set_level(level.as_deref(), module.as_deref())
+}
@@ -0,0 +1,49 @@
+use crate::audit;
+use crate::error::AppResult;
+use crate::export::get_exporter;
+use crate::models::{AuditEntry, AuditLogFilter, ExportFormat, ExportOptions, ExportSummary};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::Instant;
+
+/// Entries shown when no limit is given
+const DEFAULT_AUDIT_LIMIT: usize = 500;
+
+/// Read recorded data and schema changes, newest first
+#[tauri::command]
+pub async fn get_audit_log(filter: Option<AuditLogFilter>) -> AppResult<Vec<AuditEntry>> {
+    let mut filter = filter.unwrap_or_default();
+    filter.limit.get_or_insert(DEFAULT_AUDIT_LIMIT);
+
+    audit::read(&filter)
+}
+
+/// Write recorded changes to a file in any export format, newest first
+#[tauri::command]
+pub async fn export_audit_log(
+    path: String,
+    format: ExportFormat,
+    filter: Option<AuditLogFilter>,
+    options: Option<ExportOptions>,
+) -> AppResult<ExportSummary> {
+    let start = Instant::now();
+    let mut options = options.unwrap_or_default();
+    options.sheet_name.get_or_insert_with(|| "Audit log".to_string());
+
+    let result = audit::to_result(audit::read(&filter.unwrap_or_default())?);
+
+    let exporter = get_exporter(format, options);
+    let mut out = BufWriter::new(File::create(&path)?);
+    exporter.export(&result, &mut out)?;
+    out.flush()?;
+
+    let bytes = std::fs::metadata(&path)?.len();
+
+    Ok(ExportSummary {
+        path,
+        format,
+        rows: result.rows.len(),
+        bytes,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
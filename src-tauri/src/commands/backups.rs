@@ -0,0 +1,374 @@
+use crate::backup::{self, BackupFileKind, MySqlOptionFile, Progress, ToolIo};
+use crate::confirmation;
+use crate::db::{get_connection_manager, get_driver};
+use crate::env_vars;
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    BackupFormat, BackupOperation, BackupRequest, BackupSummary, BackupTool, Confirmable, ConnectionConfig,
+    DatabaseType, RestoreRequest, SqlFileRequest,
+};
+use crate::read_only;
+use crate::schema_cache;
+use crate::storage;
+use std::path::Path;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use super::queries::run_sql_file;
+
+/// Config of a connected SQL database, with its placeholders filled in
+async fn connected_config(connection_id: &str) -> AppResult<ConnectionConfig> {
+    if !get_connection_manager().read().await.is_connected(connection_id) {
+        return Err(AppError::ConnectionError(
+            "Connection not found or not connected".to_string(),
+        ));
+    }
+    let config = storage::get_connection(connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Backups are only available for SQL databases".to_string(),
+        ));
+    }
+    Ok(config)
+}
+
+fn missing_tool(names: &[&str], needed_for: &str) -> AppError {
+    AppError::ValidationError(format!(
+        "{} needs {}, which was not found. Install the database's client tools, or put {} in the tools folder next to dbfordevs.",
+        needed_for, names[0], names[0]
+    ))
+}
+
+/// Label a summary with the tool's name and version
+async fn tool_method(tool: &Path) -> String {
+    let name = backup::tool_name(tool);
+    match backup::tool_version(tool).await {
+        Some(version) if version.contains(&name) => version,
+        // sqlite3 prints only its version, followed by the source id
+        Some(version) => format!("{} {}", name, version.split_whitespace().next().unwrap_or_default()),
+        None => name,
+    }
+}
+
+/// Database named in confirmations: the file name for SQLite and DuckDB
+fn database_label(config: &ConnectionConfig) -> String {
+    match config.database_type {
+        DatabaseType::SQLite | DatabaseType::DuckDB => {
+            let path = backup::sqlite_path(config);
+            Path::new(&path)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or(path)
+        }
+        _ => config.database.clone(),
+    }
+}
+
+/// The backup and restore tools a connection would use, and whether each is installed.
+/// Missing tools mean backups are written as the built-in SQL script.
+#[tauri::command]
+pub async fn get_backup_tools(connection_id: String) -> AppResult<Vec<BackupTool>> {
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let mut tools = Vec::new();
+    for (names, purpose) in backup::tools_for(&config.database_type) {
+        let path = backup::find_tool(names);
+        let version = match &path {
+            Some(path) => backup::tool_version(path).await,
+            None => None,
+        };
+        tools.push(BackupTool {
+            name: names[0].to_string(),
+            purpose: purpose.to_string(),
+            path: path.map(|p| p.display().to_string()),
+            version,
+        });
+    }
+    Ok(tools)
+}
+
+/// Back up a database to a file with pg_dump, mysqldump or sqlite3, or with the
+/// built-in SQL script when they are not installed. Progress is reported as
+/// `backup-progress` events.
+#[tauri::command]
+pub async fn backup_database(app: AppHandle, request: BackupRequest) -> AppResult<BackupSummary> {
+    let config = env_vars::resolve(&connected_config(&request.connection_id).await?)?;
+    let start = Instant::now();
+    let progress = Progress {
+        app: &app,
+        operation: BackupOperation::Backup,
+        path: &request.path,
+        total_bytes: None,
+    };
+    let native = request.format == BackupFormat::Native;
+    let find = |names: &[&str]| {
+        if request.builtin {
+            None
+        } else {
+            backup::find_tool(names)
+        }
+    };
+
+    let mut messages = Vec::new();
+    let tool = match config.database_type {
+        DatabaseType::PostgreSQL => find(backup::PG_DUMP).map(|tool| {
+            let mut command = backup::postgres_command(&tool, &config);
+            command
+                .arg(format!("--dbname={}", config.database))
+                .arg(format!("--format={}", if native { "custom" } else { "plain" }))
+                .arg(format!("--file={}", request.path))
+                .arg("--verbose");
+            if request.schema_only {
+                command.arg("--schema-only");
+            }
+            (tool, command, ToolIo::Arguments, None)
+        }),
+        DatabaseType::MySQL => match find(backup::MYSQL_DUMP) {
+            Some(tool) => {
+                let options = MySqlOptionFile::create(&config)?;
+                let mut command = options.command(&tool);
+                command
+                    .args([
+                        "--single-transaction",
+                        "--routines",
+                        "--triggers",
+                        "--hex-blob",
+                        "--verbose",
+                    ])
+                    .arg(format!("--result-file={}", request.path));
+                if request.schema_only {
+                    command.arg("--no-data");
+                }
+                command.arg(&config.database);
+                Some((tool, command, ToolIo::Arguments, Some(options)))
+            }
+            None => None,
+        },
+        DatabaseType::SQLite => find(backup::SQLITE3).map(|tool| {
+            let mut command = tokio::process::Command::new(&tool);
+            command.arg(backup::sqlite_path(&config));
+            let io = if native && !request.schema_only {
+                command.arg(format!(".backup {}", backup::dot_argument(&request.path)));
+                ToolIo::Arguments
+            } else {
+                command.arg(if request.schema_only { ".schema" } else { ".dump" });
+                ToolIo::StdoutTo(Path::new(&request.path))
+            };
+            (tool, command, io, None)
+        }),
+        _ => None,
+    };
+
+    let (method, tables, rows) = match tool {
+        Some((tool, command, io, _options)) => {
+            messages = backup::run_tool(command, io, &progress).await?;
+            (tool_method(&tool).await, None, None)
+        }
+        None => {
+            let manager = get_connection_manager().read().await;
+            let driver = get_driver(&config);
+            let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+
+            if matches!(config.database_type, DatabaseType::SQLite) && native && !request.schema_only {
+                backup::vacuum_into(driver.as_ref(), pool_ref, &request.path).await?;
+                ("built-in copy (VACUUM INTO)".to_string(), None, None)
+            } else {
+                if let Some((names, _)) = backup::tools_for(&config.database_type).first() {
+                    if !request.builtin {
+                        messages.push(format!(
+                            "{} was not found, so a SQL script was written instead",
+                            names[0]
+                        ));
+                    }
+                }
+                let stats = backup::write_script(
+                    driver.as_ref(),
+                    pool_ref,
+                    &config,
+                    &request.path,
+                    request.schema_only,
+                    &progress,
+                )
+                .await?;
+                ("built-in SQL script".to_string(), Some(stats.tables), Some(stats.rows))
+            }
+        }
+    };
+
+    let bytes = std::fs::metadata(&request.path)?.len();
+    progress.emit(bytes, None, true);
+    Ok(BackupSummary {
+        operation: BackupOperation::Backup,
+        path: request.path.clone(),
+        method,
+        bytes,
+        tables,
+        rows,
+        messages,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Restore a backup into a database: pg_dump archives with pg_restore, SQLite
+/// copies with sqlite3, and SQL scripts with the database's client or, when it
+/// is not installed, statement by statement like `run_sql_file`, which reports
+/// progress as `sql-file-progress` events instead of `backup-progress`.
+/// Needs a confirmation token, since the restore changes the database.
+#[tauri::command]
+pub async fn restore_database(app: AppHandle, request: RestoreRequest) -> AppResult<Confirmable<BackupSummary>> {
+    let stored = connected_config(&request.connection_id).await?;
+    read_only::check_change(&stored)?;
+    let config = env_vars::resolve(&stored)?;
+
+    let path = Path::new(&request.path);
+    let kind = backup::file_kind(path)?;
+    let total_bytes = std::fs::metadata(path)?.len();
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| request.path.clone());
+
+    let start = Instant::now();
+    let progress = Progress {
+        app: &app,
+        operation: BackupOperation::Restore,
+        path: &request.path,
+        total_bytes: Some(total_bytes),
+    };
+    let tool = match (&config.database_type, kind) {
+        (DatabaseType::PostgreSQL, BackupFileKind::PgArchive) => {
+            let tool = backup::find_tool(backup::PG_RESTORE)
+                .ok_or_else(|| missing_tool(backup::PG_RESTORE, "Restoring a pg_dump archive"))?;
+            let mut command = backup::postgres_command(&tool, &config);
+            // One transaction, so a failed restore leaves the database as it was
+            command.arg(format!("--dbname={}", config.database)).args([
+                "--exit-on-error",
+                "--single-transaction",
+                "--verbose",
+            ]);
+            if request.clean {
+                command.args(["--clean", "--if-exists"]);
+            }
+            command.arg(&request.path);
+            Some((tool, command, ToolIo::Arguments, None))
+        }
+        (_, BackupFileKind::PgArchive) => {
+            return Err(AppError::ValidationError(
+                "This is a pg_dump archive, which can only be restored into a PostgreSQL database".to_string(),
+            ));
+        }
+        (DatabaseType::SQLite, BackupFileKind::SqliteFile) => {
+            let tool = backup::find_tool(backup::SQLITE3)
+                .ok_or_else(|| missing_tool(backup::SQLITE3, "Restoring a SQLite database file"))?;
+            let mut command = tokio::process::Command::new(&tool);
+            command
+                .arg(backup::sqlite_path(&config))
+                .arg(format!(".restore {}", backup::dot_argument(&request.path)));
+            Some((tool, command, ToolIo::Arguments, None))
+        }
+        (_, BackupFileKind::SqliteFile) => {
+            return Err(AppError::ValidationError(
+                "This is a SQLite database file, which can only be restored into a SQLite connection".to_string(),
+            ));
+        }
+        (DatabaseType::PostgreSQL, BackupFileKind::Script { pg_dump }) => match backup::find_tool(backup::PSQL) {
+            Some(tool) => {
+                let mut command = backup::postgres_command(&tool, &config);
+                command.arg(format!("--dbname={}", config.database)).args([
+                    "--single-transaction",
+                    "--quiet",
+                    "--set=ON_ERROR_STOP=1",
+                ]);
+                Some((tool, command, ToolIo::StdinFrom(path), None))
+            }
+            // Scripts from pg_dump load their rows with psql's COPY ... FROM stdin
+            None if pg_dump => return Err(missing_tool(backup::PSQL, "Restoring a script written by pg_dump")),
+            None => None,
+        },
+        (DatabaseType::MySQL, BackupFileKind::Script { .. }) => match backup::find_tool(backup::MYSQL_CLIENT) {
+            Some(tool) => {
+                let options = MySqlOptionFile::create(&config)?;
+                let mut command = options.command(&tool);
+                command.arg(&config.database);
+                Some((tool, command, ToolIo::StdinFrom(path), Some(options)))
+            }
+            None => None,
+        },
+        (DatabaseType::SQLite, BackupFileKind::Script { .. }) => backup::find_tool(backup::SQLITE3).map(|tool| {
+            let mut command = tokio::process::Command::new(&tool);
+            command.arg("-bail").arg(backup::sqlite_path(&config));
+            (tool, command, ToolIo::StdinFrom(path), None)
+        }),
+        (_, BackupFileKind::Script { .. }) => None,
+    };
+
+    let database = database_label(&config);
+    let action = format!(
+        "restore:{}:{}:{}:{}",
+        request.connection_id, request.path, total_bytes, request.clean
+    );
+    let token = match request.confirmation_token {
+        Some(token) => token,
+        None => {
+            let summary = match kind {
+                BackupFileKind::SqliteFile => {
+                    format!("Replace everything in {} with the contents of {}", database, file_name)
+                }
+                _ if request.clean && kind == BackupFileKind::PgArchive => format!(
+                    "Drop the objects in {} from {} and recreate them with the backup's rows",
+                    file_name, database
+                ),
+                _ => format!(
+                    "Restore {} into {}, creating its objects and inserting its rows alongside what is already there",
+                    file_name, database
+                ),
+            };
+            return Ok(Confirmable::ConfirmationRequired(confirmation::request(
+                action, summary,
+            )));
+        }
+    };
+    confirmation::consume(&token, &action)?;
+
+    let (method, messages) = match tool {
+        Some((tool, command, io, _options)) => {
+            let messages = backup::run_tool(command, io, &progress).await?;
+            (tool_method(&tool).await, messages)
+        }
+        None => {
+            let summary = run_sql_file(
+                app.clone(),
+                SqlFileRequest {
+                    connection_id: request.connection_id.clone(),
+                    path: request.path.clone(),
+                    continue_on_error: Some(false),
+                    batch_size: None,
+                },
+            )
+            .await?;
+            if let Some(error) = summary.errors.first() {
+                return Err(AppError::QueryError(format!(
+                    "Statement {} of {} failed after {} succeeded: {}",
+                    error.statement_index, file_name, summary.statements_executed, error.message
+                )));
+            }
+            let method = format!("built-in ({} statements)", summary.statements_executed);
+            (method, Vec::new())
+        }
+    };
+
+    schema_cache::clear(&request.connection_id);
+    progress.emit(total_bytes, None, true);
+    Ok(Confirmable::Executed(BackupSummary {
+        operation: BackupOperation::Restore,
+        path: request.path.clone(),
+        method,
+        bytes: total_bytes,
+        tables: None,
+        rows: None,
+        messages,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    }))
+}
@@ -0,0 +1,396 @@
+use crate::connection_usage;
+use crate::db::{get_connection_manager, get_driver, quote_identifier, quote_qualified, DatabaseDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    BrowseRequest, BrowseResult, CellValue, ColumnFilter, DatabaseType, FilterOperator, QueryResult, RowReferenceRequest,
+    SortDirection, TableRelationship,
+};
+use crate::storage;
+
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+const DEFAULT_REFERENCING_ROWS: u32 = 100;
+
+/// Tables estimated below this size are counted exactly
+const EXACT_COUNT_THRESHOLD: u64 = 100_000;
+
+/// Fetch a page of table data with sorting and filtering done by the database
+#[tauri::command]
+pub async fn browse_table(request: BrowseRequest) -> AppResult<BrowseResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Table browsing is only available for SQL databases".to_string(),
+        ));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+    let database_type = &config.database_type;
+
+    let schema = driver.get_table_schema(pool_ref, &request.table_name).await?;
+    // Only columns the table has may appear in the query, so user input never reaches it as SQL
+    let column = |name: &str| -> AppResult<String> {
+        if schema.columns.iter().any(|c| c.name == name) {
+            Ok(quote_identifier(database_type, name))
+        } else {
+            Err(AppError::ValidationError(format!(
+                "Column '{}' does not exist in {}",
+                name, request.table_name
+            )))
+        }
+    };
+
+    let mut params = Vec::new();
+    let mut conditions = Vec::new();
+    for filter in request.filters.iter().flatten() {
+        let quoted = column(&filter.column)?;
+        conditions.push(filter_condition(driver.as_ref(), database_type, &quoted, filter, &mut params)?);
+    }
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
+
+    let sort = request.sort.unwrap_or_default();
+    let mut order = Vec::new();
+    for s in &sort {
+        let direction = match s.direction {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        };
+        order.push(format!("{} {}", column(&s.column)?, direction));
+    }
+    // Break ties on the primary key so rows do not shift between pages
+    for key in &schema.primary_keys {
+        if !sort.iter().any(|s| s.column == *key) {
+            order.push(format!("{} ASC", quote_identifier(database_type, key)));
+        }
+    }
+    let order_clause = match (order.is_empty(), database_type) {
+        (false, _) => format!(" ORDER BY {}", order.join(", ")),
+        // OFFSET ... FETCH requires an ORDER BY
+        (true, DatabaseType::MSSQL) => " ORDER BY (SELECT NULL)".to_string(),
+        (true, _) => String::new(),
+    };
+
+    let table = quote_qualified(database_type, &request.table_name);
+    let limit = request.limit.unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+    let offset = request.offset.unwrap_or(0);
+
+    let select = format!("SELECT * FROM {}{}{}", table, where_clause, order_clause);
+    let sql = match database_type {
+        DatabaseType::MSSQL | DatabaseType::Oracle => {
+            format!("{} OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", select, offset, limit)
+        }
+        _ => format!("{} LIMIT {} OFFSET {}", select, limit, offset),
+    };
+    let result = driver.execute_with_params(pool_ref, &sql, params.clone()).await?;
+    // Paging through the same view is not another use of the table
+    if offset == 0 {
+        let columns: Vec<(Option<String>, String)> = request
+            .filters
+            .iter()
+            .flatten()
+            .map(|filter| (Some(request.table_name.clone()), filter.column.clone()))
+            .collect();
+        if let Err(e) = connection_usage::record_query(
+            &request.connection_id,
+            std::slice::from_ref(&request.table_name),
+            &columns,
+        ) {
+            tracing::warn!(connection_id = %request.connection_id, "Failed to record connection usage: {}", e);
+        }
+    }
+
+    let page_rows = result.rows.len() as u64;
+    let (total_rows, total_is_estimate) = if page_rows > 0 && page_rows < limit as u64 {
+        // A partial page is the last one, so the total is known
+        (Some(offset as u64 + page_rows), false)
+    } else if conditions.is_empty() && !request.exact_count.unwrap_or(false) {
+        match estimate_rows(driver.as_ref(), pool_ref, database_type, &request.table_name).await {
+            Some(estimate) if estimate >= EXACT_COUNT_THRESHOLD => (Some(estimate), true),
+            _ => (count_rows(driver.as_ref(), pool_ref, &table, &where_clause, params).await?, false),
+        }
+    } else {
+        (count_rows(driver.as_ref(), pool_ref, &table, &where_clause, params).await?, false)
+    };
+
+    Ok(BrowseResult {
+        result,
+        total_rows,
+        total_is_estimate,
+        limit,
+        offset,
+    })
+}
+
+/// Fetch the row a foreign key of the given row points to
+#[tauri::command]
+pub async fn get_referenced_row(request: RowReferenceRequest) -> AppResult<QueryResult> {
+    follow_reference(request, true).await
+}
+
+/// Fetch the rows whose foreign key points to the given row
+#[tauri::command]
+pub async fn get_referencing_rows(request: RowReferenceRequest) -> AppResult<QueryResult> {
+    follow_reference(request, false).await
+}
+
+/// Join the row's table to the other side of the foreign key and select the other side's rows.
+/// `outbound` follows the row's own foreign key to its parent; otherwise children are fetched.
+async fn follow_reference(request: RowReferenceRequest, outbound: bool) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Foreign keys can only be followed on SQL databases".to_string(),
+        ));
+    }
+    if request.primary_key.is_empty() {
+        return Err(AppError::ValidationError("A primary key is needed to identify the row".to_string()));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+    let database_type = &config.database_type;
+
+    let relationships = driver.get_table_relationships(pool_ref, &request.table_name).await?;
+    let pairs = constraint_columns(&request.relationship, &relationships);
+
+    // The row's table is aliased `r`, the table being fetched `o`
+    // (other column, row column) pairs
+    let (other_table, join): (&String, Vec<(&String, &String)>) = if outbound {
+        let join = pairs.iter().map(|(source, target)| (target, source)).collect();
+        (&request.relationship.target_table, join)
+    } else {
+        let join = pairs.iter().map(|(source, target)| (source, target)).collect();
+        (&request.relationship.source_table, join)
+    };
+    let on: Vec<String> = join
+        .iter()
+        .map(|(other, row)| {
+            format!(
+                "o.{} = r.{}",
+                quote_identifier(database_type, other),
+                quote_identifier(database_type, row)
+            )
+        })
+        .collect();
+
+    let mut params = Vec::with_capacity(request.primary_key.len());
+    let conditions: Vec<String> = request
+        .primary_key
+        .into_iter()
+        .map(|(column, value)| {
+            let column = quote_identifier(database_type, &column);
+            if value.is_null() {
+                return format!("r.{} IS NULL", column);
+            }
+            params.push(CellValue::from(value));
+            format!("r.{} = {}", column, driver.placeholder(params.len()))
+        })
+        .collect();
+
+    let select = format!(
+        "o.* FROM {} o INNER JOIN {} r ON {} WHERE {}",
+        quote_qualified(database_type, other_table),
+        quote_qualified(database_type, &request.table_name),
+        on.join(" AND "),
+        conditions.join(" AND ")
+    );
+    // A parent is unique, so only children are limited
+    let limit = request.limit.unwrap_or(DEFAULT_REFERENCING_ROWS).max(1);
+    let sql = match (outbound, database_type) {
+        (true, _) => format!("SELECT {}", select),
+        (false, DatabaseType::MSSQL) => format!("SELECT TOP ({}) {}", limit, select),
+        (false, DatabaseType::Oracle) => format!("SELECT {} FETCH FIRST {} ROWS ONLY", select, limit),
+        (false, _) => format!("SELECT {} LIMIT {}", select, limit),
+    };
+
+    driver.execute_with_params(pool_ref, &sql, params).await
+}
+
+/// (source, target) column pairs of the relationship's constraint. Composite keys are matched
+/// on every column, unless the metadata does not pair them one to one.
+fn constraint_columns(relationship: &TableRelationship, all: &[TableRelationship]) -> Vec<(String, String)> {
+    let single = vec![(relationship.source_column.clone(), relationship.target_column.clone())];
+    let Some(name) = &relationship.constraint_name else {
+        return single;
+    };
+
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for r in all.iter().filter(|r| {
+        r.constraint_name.as_ref() == Some(name)
+            && r.source_table == relationship.source_table
+            && r.target_table == relationship.target_table
+    }) {
+        let pair = (r.source_column.clone(), r.target_column.clone());
+        if !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    }
+
+    // Some catalogs list every source column against every target column
+    let one_to_one = pairs.iter().all(|(source, target)| {
+        pairs.iter().filter(|(s, _)| s == source).count() == 1 && pairs.iter().filter(|(_, t)| t == target).count() == 1
+    });
+    if pairs.is_empty() || !one_to_one {
+        single
+    } else {
+        pairs
+    }
+}
+
+fn filter_condition(
+    driver: &dyn DatabaseDriver,
+    database_type: &DatabaseType,
+    column: &str,
+    filter: &ColumnFilter,
+    params: &mut Vec<CellValue>,
+) -> AppResult<String> {
+    let mut bind = |value: Option<&serde_json::Value>| -> AppResult<String> {
+        let value = value.filter(|v| !v.is_null()).cloned().ok_or_else(|| {
+            AppError::ValidationError(format!("The filter on '{}' needs a value", filter.column))
+        })?;
+        params.push(CellValue::from(value));
+        Ok(driver.placeholder(params.len()))
+    };
+
+    let condition = match filter.operator {
+        FilterOperator::Equals if matches!(filter.value, None | Some(serde_json::Value::Null)) => {
+            format!("{} IS NULL", column)
+        }
+        FilterOperator::Equals => format!("{} = {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::NotEquals => format!("{} <> {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::Contains => {
+            let text = match filter.value.as_ref() {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(serde_json::Value::Null) | None => String::new(),
+                Some(other) => other.to_string(),
+            };
+            let pattern = serde_json::Value::String(format!("%{}%", escape_like(database_type, &text)));
+            let placeholder = bind(Some(&pattern))?;
+            match database_type {
+                DatabaseType::PostgreSQL => format!("CAST({} AS TEXT) ILIKE {} ESCAPE '!'", column, placeholder),
+                DatabaseType::MySQL => format!("CAST({} AS CHAR) LIKE {} ESCAPE '!'", column, placeholder),
+                DatabaseType::MSSQL => format!("CAST({} AS NVARCHAR(MAX)) LIKE {} ESCAPE '!'", column, placeholder),
+                DatabaseType::DuckDB => format!("CAST({} AS VARCHAR) ILIKE {} ESCAPE '!'", column, placeholder),
+                // LOWER converts numbers and dates to text and keeps CLOBs whole
+                DatabaseType::Oracle => format!("LOWER({}) LIKE LOWER({}) ESCAPE '!'", column, placeholder),
+                _ => format!("{} LIKE {} ESCAPE '!'", column, placeholder),
+            }
+        }
+        FilterOperator::IsNull => format!("{} IS NULL", column),
+        FilterOperator::IsNotNull => format!("{} IS NOT NULL", column),
+        FilterOperator::GreaterThan => format!("{} > {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::GreaterOrEqual => format!("{} >= {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::LessThan => format!("{} < {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::LessOrEqual => format!("{} <= {}", column, bind(filter.value.as_ref())?),
+        FilterOperator::Between => {
+            let low = bind(filter.value.as_ref())?;
+            let high = bind(filter.value_to.as_ref())?;
+            format!("{} BETWEEN {} AND {}", column, low, high)
+        }
+    };
+
+    Ok(condition)
+}
+
+/// Escape LIKE wildcards with `!`, which needs no escaping in any dialect's string literals
+fn escape_like(database_type: &DatabaseType, text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        // SQL Server also treats [...] as a character class
+        if matches!(c, '%' | '_' | '!') || (c == '[' && matches!(database_type, DatabaseType::MSSQL)) {
+            escaped.push('!');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+async fn count_rows(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    table: &str,
+    where_clause: &str,
+    params: Vec<CellValue>,
+) -> AppResult<Option<u64>> {
+    let sql = format!("SELECT COUNT(*) FROM {}{}", table, where_clause);
+    let result = driver.execute_with_params(pool, &sql, params).await?;
+    Ok(first_number(&result.rows))
+}
+
+/// Row count from table statistics; None where the database keeps none or the lookup fails
+pub async fn estimate_rows(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    table_name: &str,
+) -> Option<u64> {
+    let (sql, params) = match database_type {
+        // reltuples is -1 until the table is first analyzed
+        DatabaseType::PostgreSQL => (
+            "SELECT reltuples::bigint FROM pg_class WHERE oid = to_regclass($1::text)".to_string(),
+            vec![CellValue::Text(quote_qualified(database_type, table_name))],
+        ),
+        DatabaseType::MySQL => match table_name.split_once('.') {
+            Some((schema, table)) => (
+                "SELECT TABLE_ROWS FROM information_schema.TABLES WHERE TABLE_SCHEMA = ? AND TABLE_NAME = ?".to_string(),
+                vec![CellValue::Text(schema.to_string()), CellValue::Text(table.to_string())],
+            ),
+            None => (
+                "SELECT TABLE_ROWS FROM information_schema.TABLES WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?"
+                    .to_string(),
+                vec![CellValue::Text(table_name.to_string())],
+            ),
+        },
+        DatabaseType::MSSQL => (
+            "SELECT SUM(rows) FROM sys.partitions WHERE object_id = OBJECT_ID(@P1) AND index_id IN (0, 1)".to_string(),
+            vec![CellValue::Text(quote_qualified(database_type, table_name))],
+        ),
+        DatabaseType::Oracle => match table_name.split_once('.') {
+            Some((schema, table)) => (
+                "SELECT num_rows FROM all_tables WHERE owner = :1 AND table_name = :2".to_string(),
+                vec![CellValue::Text(schema.to_string()), CellValue::Text(table.to_string())],
+            ),
+            None => (
+                "SELECT num_rows FROM all_tables \
+                 WHERE owner = SYS_CONTEXT('USERENV', 'CURRENT_SCHEMA') AND table_name = :1"
+                    .to_string(),
+                vec![CellValue::Text(table_name.to_string())],
+            ),
+        },
+        _ => return None,
+    };
+
+    let result = driver.execute_with_params(pool, &sql, params).await.ok()?;
+    first_number(&result.rows)
+}
+
+pub fn first_number(rows: &[Vec<serde_json::Value>]) -> Option<u64> {
+    match rows.first()?.first()? {
+        serde_json::Value::Number(n) => n.as_u64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
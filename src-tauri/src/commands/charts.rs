@@ -0,0 +1,10 @@
+use crate::chart;
+use crate::error::AppResult;
+use crate::models::{ChartData, ChartSpec, QueryResult};
+
+/// Aggregate a query result into series to plot, so large results need not
+/// be plotted row by row
+#[tauri::command]
+pub async fn prepare_chart_data(result: QueryResult, spec: ChartSpec) -> AppResult<ChartData> {
+    chart::prepare(&result, &spec)
+}
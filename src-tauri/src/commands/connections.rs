@@ -1,35 +1,68 @@
+use crate::change_feed;
+use crate::connection_usage;
 use crate::db::{get_connection_manager, get_driver};
+use crate::env_vars;
+use crate::metrics;
 use crate::error::{AppError, AppResult};
-use crate::models::{ConnectionConfig, ConnectionInfo, TestConnectionResult};
+use crate::models::{
+    ConnectionConfig, ConnectionInfo, ConnectionInsights, ConnectionTemplate, DatabaseType, TestConnectionResult,
+};
+use crate::monitor;
+use crate::schema_cache;
+use crate::schema_search;
 use crate::storage;
+use crate::templates;
 
 /// Test a database connection with the provided configuration
 #[tauri::command]
 pub async fn test_connection(config: ConnectionConfig) -> Result<TestConnectionResult, AppError> {
+    let config = env_vars::resolve(&config)?;
     let driver = get_driver(&config);
     driver.test_connection(&config).await
 }
 
-/// Save a connection configuration
+/// Build the summary shown in the sidebar
+fn connection_info(config: ConnectionConfig, connected: bool) -> ConnectionInfo {
+    let read_only = config.is_read_only();
+    ConnectionInfo {
+        id: config.id.unwrap_or_default(),
+        name: config.name,
+        database_type: config.database_type,
+        host: config.host,
+        database: config.database,
+        connected,
+        group: config.group,
+        color: config.color,
+        environment: config.environment,
+        read_only,
+    }
+}
+
+/// Save a connection configuration. New production connections are saved
+/// read-only unless `read_only` is given.
 #[tauri::command]
 pub async fn save_connection(config: ConnectionConfig) -> AppResult<ConnectionInfo> {
+    if let Some(color) = &config.color {
+        let hex = color.strip_prefix('#').unwrap_or_default();
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(AppError::ValidationError(format!("Invalid color '{}'; expected #rrggbb", color)));
+        }
+    }
+
     let id = config.id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
     
     // Create config with ID
     let mut config_with_id = config.clone();
     config_with_id.id = Some(id.clone());
+    config_with_id.group = config.group.map(|g| g.trim().trim_matches('/').to_string()).filter(|g| !g.is_empty());
+    if config.id.is_none() && config.read_only.is_none() {
+        config_with_id.read_only = Some(config_with_id.is_read_only());
+    }
     
     // Save to storage
     storage::save_connection(&config_with_id)?;
     
-    Ok(ConnectionInfo {
-        id,
-        name: config.name,
-        database_type: config.database_type,
-        host: config.host,
-        database: config.database,
-        connected: false,
-    })
+    Ok(connection_info(config_with_id, false))
 }
 
 /// Connect to a database
@@ -39,7 +72,39 @@ pub async fn connect(connection_id: String) -> AppResult<bool> {
         .ok_or_else(|| AppError::ConfigError("Connection not found".to_string()))?;
     
     let mut manager = get_connection_manager().write().await;
-    manager.connect(connection_id.clone(), &config).await?;
+    manager
+        .connect(connection_id.clone(), &config)
+        .await
+        .inspect_err(|e| {
+            metrics::record_connection(false);
+            tracing::warn!(connection_id = %connection_id, "Failed to connect: {}", e);
+        })?;
+    if let Err(e) = connection_usage::record_connect(&connection_id) {
+        tracing::warn!(connection_id = %connection_id, "Failed to record connection usage: {}", e);
+    }
+    
+    Ok(true)
+}
+
+/// Reconnect to another database on the same server. The saved connection is
+/// left unchanged, so the next `connect` opens its configured database again.
+#[tauri::command]
+pub async fn switch_database(connection_id: String, database: String) -> AppResult<bool> {
+    let mut config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection not found".to_string()))?;
+    
+    if matches!(config.database_type, DatabaseType::SQLite | DatabaseType::DuckDB) {
+        return Err(AppError::ValidationError(
+            "SQLite and DuckDB connections are bound to a single file".to_string(),
+        ));
+    }
+    if database.trim().is_empty() {
+        return Err(AppError::ValidationError("Database name cannot be empty".to_string()));
+    }
+    config.database = database;
+    
+    let mut manager = get_connection_manager().write().await;
+    manager.connect(connection_id, &config).await?;
     
     Ok(true)
 }
@@ -47,6 +112,9 @@ pub async fn connect(connection_id: String) -> AppResult<bool> {
 /// Disconnect from a database
 #[tauri::command]
 pub async fn disconnect(connection_id: String) -> AppResult<bool> {
+    monitor::stop(&connection_id);
+    change_feed::stop(&connection_id).await;
+    schema_cache::clear(&connection_id);
     let mut manager = get_connection_manager().write().await;
     manager.disconnect(&connection_id).await?;
     Ok(true)
@@ -61,15 +129,8 @@ pub async fn list_connections() -> AppResult<Vec<ConnectionInfo>> {
     let connection_infos: Vec<ConnectionInfo> = connections
         .into_iter()
         .map(|config| {
-            let id = config.id.clone().unwrap_or_default();
-            ConnectionInfo {
-                id: id.clone(),
-                name: config.name,
-                database_type: config.database_type,
-                host: config.host,
-                database: config.database,
-                connected: manager.is_connected(&id),
-            }
+            let connected = config.id.as_ref().is_some_and(|id| manager.is_connected(id));
+            connection_info(config, connected)
         })
         .collect();
     
@@ -80,6 +141,7 @@ pub async fn list_connections() -> AppResult<Vec<ConnectionInfo>> {
 #[tauri::command]
 pub async fn delete_connection(connection_id: String) -> AppResult<bool> {
     // Disconnect if connected
+    schema_cache::clear(&connection_id);
     let mut manager = get_connection_manager().write().await;
     if manager.is_connected(&connection_id) {
         manager.disconnect(&connection_id).await?;
@@ -87,6 +149,9 @@ pub async fn delete_connection(connection_id: String) -> AppResult<bool> {
 
     // Remove from storage
     storage::delete_connection(&connection_id)?;
+    schema_search::clear(&connection_id)?;
+    connection_usage::forget(&connection_id)?;
+    storage::forget_pins(&connection_id)?;
 
     Ok(true)
 }
@@ -97,3 +162,62 @@ pub async fn get_connection(connection_id: String) -> AppResult<Option<Connectio
     storage::get_connection(&connection_id)
 }
 
+/// When a connection was last used, its recently and most queried tables, and
+/// the columns its queries filter on most. Usage is only recorded locally.
+#[tauri::command]
+pub async fn get_connection_insights(connection_id: String) -> AppResult<ConnectionInsights> {
+    connection_usage::insights(&connection_id)
+}
+
+
+/// Export saved connections as a JSON bundle, encrypted when a password is given
+#[tauri::command]
+pub async fn export_connections(
+    connection_ids: Option<Vec<String>>,
+    include_credentials: bool,
+    password: Option<String>,
+) -> AppResult<String> {
+    let connections: Vec<ConnectionConfig> = storage::load_connections()?
+        .into_iter()
+        .filter(|config| match &connection_ids {
+            Some(ids) => config.id.as_ref().map(|id| ids.contains(id)).unwrap_or(false),
+            None => true,
+        })
+        .map(|mut config| {
+            if !include_credentials {
+                config.password = None;
+            }
+            config
+        })
+        .collect();
+
+    storage::export_bundle(connections, password.as_deref())
+}
+
+/// Import connections from a bundle produced by `export_connections`
+#[tauri::command]
+pub async fn import_connections(content: String, password: Option<String>) -> AppResult<Vec<ConnectionInfo>> {
+    let imported = storage::import_bundle(&content, password.as_deref())?;
+    let saved = storage::import_connections(imported)?;
+    let manager = get_connection_manager().read().await;
+
+    Ok(saved
+        .into_iter()
+        .map(|config| {
+            let connected = config.id.as_ref().is_some_and(|id| manager.is_connected(id));
+            connection_info(config, connected)
+        })
+        .collect())
+}
+
+/// Templates for managed services such as Amazon RDS, Supabase and Neon
+#[tauri::command]
+pub async fn list_connection_templates() -> AppResult<Vec<ConnectionTemplate>> {
+    Ok(templates::TEMPLATES.to_vec())
+}
+
+/// Check that `host` belongs to the service a template is for
+#[tauri::command]
+pub async fn check_template_host(template_id: String, host: String) -> AppResult<()> {
+    templates::check_host(templates::find(&template_id)?, &host)
+}
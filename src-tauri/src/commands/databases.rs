@@ -0,0 +1,170 @@
+use crate::confirmation;
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::models::{Confirmable, CreateDatabaseOptions, CreateSchemaOptions, DatabaseUser, QueryResult};
+use crate::read_only;
+use crate::storage;
+
+/// Treat blank option fields as unset
+fn non_blank(value: Option<String>) -> Option<String> {
+    value.filter(|v| !v.trim().is_empty())
+}
+
+fn check_name(name: &str, kind: &str) -> AppResult<()> {
+    if name.trim().is_empty() {
+        return Err(AppError::ValidationError(format!("{} name cannot be empty", kind)));
+    }
+    Ok(())
+}
+
+/// Create a database on the connected server
+#[tauri::command]
+pub async fn create_database(connection_id: String, options: CreateDatabaseOptions) -> AppResult<QueryResult> {
+    check_name(&options.name, "Database")?;
+    let options = CreateDatabaseOptions {
+        owner: non_blank(options.owner),
+        charset: non_blank(options.charset),
+        collation: non_blank(options.collation),
+        ..options
+    };
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.create_database(pool_ref, &options).await
+}
+
+/// Drop a database. Without `confirmation_token` nothing is dropped; a token is
+/// issued instead, and the drop runs when the command is called again with it.
+#[tauri::command]
+pub async fn drop_database(
+    connection_id: String,
+    name: String,
+    confirmation_token: Option<String>,
+) -> AppResult<Confirmable<QueryResult>> {
+    check_name(&name, "Database")?;
+    let action = format!("drop_database:{}:{}", connection_id, name);
+
+    let token = match confirmation_token {
+        Some(token) => token,
+        None => {
+            let summary = format!("Drop database {} and everything in it", name);
+            return Ok(Confirmable::ConfirmationRequired(confirmation::request(action, summary)));
+        }
+    };
+    confirmation::consume(&token, &action)?;
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.drop_database(pool_ref, &name).await.map(Confirmable::Executed)
+}
+
+/// Create a schema in the connected database
+#[tauri::command]
+pub async fn create_schema(connection_id: String, options: CreateSchemaOptions) -> AppResult<QueryResult> {
+    check_name(&options.name, "Schema")?;
+    let options = CreateSchemaOptions {
+        owner: non_blank(options.owner),
+        ..options
+    };
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.create_schema(pool_ref, &options).await
+}
+
+/// Drop a schema, confirmed the same way as `drop_database`
+#[tauri::command]
+pub async fn drop_schema(
+    connection_id: String,
+    name: String,
+    cascade: Option<bool>,
+    confirmation_token: Option<String>,
+) -> AppResult<Confirmable<QueryResult>> {
+    check_name(&name, "Schema")?;
+    let cascade = cascade.unwrap_or(false);
+    let action = format!("drop_schema:{}:{}:{}", connection_id, name, cascade);
+
+    let token = match confirmation_token {
+        Some(token) => token,
+        None => {
+            let summary = if cascade {
+                format!("Drop schema {} and all objects in it", name)
+            } else {
+                format!("Drop schema {}", name)
+            };
+            return Ok(Confirmable::ConfirmationRequired(confirmation::request(action, summary)));
+        }
+    };
+    confirmation::consume(&token, &action)?;
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.drop_schema(pool_ref, &name, cascade).await.map(Confirmable::Executed)
+}
+
+/// List logins and roles on the connected server
+#[tauri::command]
+pub async fn get_users_and_roles(connection_id: String) -> AppResult<Vec<DatabaseUser>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_users_and_roles(pool_ref).await
+}
@@ -0,0 +1,148 @@
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::models::{Cardinality, ErColumn, ErEdge, ErModel, ErNode, TableSchema};
+use crate::storage;
+use std::collections::{HashMap, HashSet};
+
+/// Build the ER diagram graph for the connected database. With `schemas`, only
+/// tables in those schemas are included; tables without a schema are always kept.
+#[tauri::command]
+pub async fn generate_er_model(connection_id: String, schemas: Option<Vec<String>>) -> AppResult<ErModel> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let mut tables = driver.get_all_table_schemas(pool_ref, &config).await?;
+    if let Some(schemas) = schemas.filter(|s| !s.is_empty()) {
+        tables.retain(|t| split_name(&t.table_name).0.is_none_or(|schema| schemas.iter().any(|s| s == schema)));
+    }
+    tables.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+
+    // Column sets that are unique in each table: the primary key and every unique index
+    let mut unique_keys: HashMap<String, Vec<HashSet<String>>> = HashMap::new();
+    for table in &tables {
+        let mut keys: Vec<HashSet<String>> = Vec::new();
+        if !table.primary_keys.is_empty() {
+            keys.push(table.primary_keys.iter().cloned().collect());
+        }
+        for index in driver.get_indexes(pool_ref, &table.table_name).await? {
+            if index.is_unique || index.is_primary {
+                keys.push(index.columns.into_iter().collect());
+            }
+        }
+        unique_keys.insert(table.table_name.clone(), keys);
+    }
+
+    let ids: Vec<&str> = tables.iter().map(|t| t.table_name.as_str()).collect();
+    let mut edges = Vec::new();
+    for table in &tables {
+        let keys = &unique_keys[&table.table_name];
+        for mut edge in build_edges(table, &ids) {
+            let fk_columns: HashSet<String> = edge.source_columns.iter().cloned().collect();
+            if keys.contains(&fk_columns) {
+                edge.cardinality = Cardinality::OneToOne;
+            }
+            edge.optional = edge
+                .source_columns
+                .iter()
+                .all(|c| table.columns.iter().any(|col| &col.name == c && col.nullable));
+            edges.push(edge);
+        }
+    }
+
+    let nodes = tables
+        .iter()
+        .map(|table| {
+            let keys = &unique_keys[&table.table_name];
+            let (schema, name) = split_name(&table.table_name);
+            ErNode {
+                id: table.table_name.clone(),
+                schema: schema.map(str::to_string),
+                name: name.to_string(),
+                columns: table
+                    .columns
+                    .iter()
+                    .map(|column| ErColumn {
+                        name: column.name.clone(),
+                        data_type: column.data_type.clone(),
+                        nullable: column.nullable,
+                        is_primary_key: column.is_primary_key,
+                        is_foreign_key: table.foreign_keys.iter().any(|fk| fk.column == column.name),
+                        is_unique: keys.iter().any(|key| key.len() == 1 && key.contains(&column.name)),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok(ErModel { nodes, edges })
+}
+
+/// Split `schema.table` into its parts
+fn split_name(table_name: &str) -> (Option<&str>, &str) {
+    match table_name.split_once('.') {
+        Some((schema, table)) => (Some(schema), table),
+        None => (None, table_name),
+    }
+}
+
+/// Find the node a foreign key points at. Drivers report the referenced table
+/// without its schema, so prefer a table in the source's own schema.
+fn resolve_target<'a>(source: &str, references_table: &str, ids: &[&'a str]) -> Option<&'a str> {
+    if let Some(id) = ids.iter().find(|id| **id == references_table) {
+        return Some(id);
+    }
+    let source_schema = split_name(source).0;
+    let candidates: Vec<&'a str> = ids
+        .iter()
+        .copied()
+        .filter(|id| split_name(id).1 == references_table)
+        .collect();
+    candidates
+        .iter()
+        .find(|id| split_name(id).0 == source_schema)
+        .or_else(|| candidates.first())
+        .copied()
+}
+
+/// Group a table's foreign key columns into edges. Columns only carry the table
+/// they reference, so columns pointing at the same table are treated as one
+/// composite key unless they reference the same target column (e.g. `created_by`
+/// and `updated_by` both referencing `users.id` stay separate edges).
+fn build_edges(table: &TableSchema, ids: &[&str]) -> Vec<ErEdge> {
+    let mut edges: Vec<ErEdge> = Vec::new();
+    for fk in &table.foreign_keys {
+        let Some(target) = resolve_target(&table.table_name, &fk.references_table, ids) else {
+            // Referenced table was filtered out
+            continue;
+        };
+
+        let existing = edges
+            .iter_mut()
+            .find(|e| e.target == target && !e.target_columns.contains(&fk.references_column));
+        match existing {
+            Some(edge) => {
+                edge.source_columns.push(fk.column.clone());
+                edge.target_columns.push(fk.references_column.clone());
+            }
+            None => edges.push(ErEdge {
+                source: table.table_name.clone(),
+                source_columns: vec![fk.column.clone()],
+                target: target.to_string(),
+                target_columns: vec![fk.references_column.clone()],
+                cardinality: Cardinality::ManyToOne,
+                optional: false,
+            }),
+        }
+    }
+    edges
+}
@@ -0,0 +1,594 @@
+use crate::db::{
+    column_list, get_connection_manager, get_driver, quote_identifier, quote_qualified, sql_literal, DatabaseDriver, PoolRef,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ColumnInfo, DatabaseType, ResultDiff, ResultRowChange, ResultRowDiff, RowDiff, RowDiffKind, TableDiffRequest,
+    TableDiffResult,
+};
+use crate::result_store;
+use crate::storage;
+use crate::transfer::{column_kind, ColumnKind};
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+const DEFAULT_CHUNK_SIZE: u32 = 1000;
+const DEFAULT_MAX_ROWS: u32 = 1000;
+
+/// Compare the rows of two tables, on the same or different connections, matched by key.
+/// Both tables are read page by page in key order and merged, so neither is loaded whole.
+#[tauri::command]
+pub async fn diff_table_data(request: TableDiffRequest) -> AppResult<TableDiffResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify both connections exist
+    for connection_id in [&request.source.connection_id, &request.target.connection_id] {
+        if !manager.is_connected(connection_id) {
+            return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+        }
+    }
+
+    let source_config = storage::get_connection(&request.source.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    let target_config = storage::get_connection(&request.target.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    for config in [&source_config, &target_config] {
+        if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+            return Err(AppError::ValidationError("Table data can only be compared on SQL databases".to_string()));
+        }
+    }
+
+    let source_driver = get_driver(&source_config);
+    let target_driver = get_driver(&target_config);
+    let source_pool = manager.get_pool_ref(&request.source.connection_id)?;
+    let target_pool = manager.get_pool_ref(&request.target.connection_id)?;
+
+    let start = Instant::now();
+
+    let source_schema = source_driver.get_table_schema(source_pool, &request.source.table_name).await?;
+    let target_schema = target_driver.get_table_schema(target_pool, &request.target.table_name).await?;
+
+    let key_columns = request.key_columns.unwrap_or(source_schema.primary_keys);
+    if key_columns.is_empty() {
+        return Err(AppError::ValidationError(format!(
+            "Table {} has no primary key; choose key columns to match rows",
+            request.source.table_name
+        )));
+    }
+
+    // Keys first, then every other column both tables share
+    let mut columns = key_columns.clone();
+    for column in &source_schema.columns {
+        if !columns.contains(&column.name) && target_schema.columns.iter().any(|c| c.name == column.name) {
+            columns.push(column.name.clone());
+        }
+    }
+    for key in &key_columns {
+        let in_source = source_schema.columns.iter().any(|c| c.name == *key);
+        let in_target = target_schema.columns.iter().any(|c| c.name == *key);
+        if !in_source || !in_target {
+            return Err(AppError::ValidationError(format!("Key column '{}' must exist in both tables", key)));
+        }
+    }
+
+    let chunk_size = request.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE).max(1) as usize;
+    let max_rows = request.max_rows.unwrap_or(DEFAULT_MAX_ROWS) as usize;
+    let generate_sql = request.generate_sql.unwrap_or(false);
+    let key_count = key_columns.len();
+
+    let mut source = KeyedRows::new(
+        source_driver.as_ref(),
+        source_pool,
+        &source_config.database_type,
+        &request.source.table_name,
+        &columns,
+        &key_types(&source_schema.columns, &key_columns),
+        chunk_size,
+    );
+    let mut target = KeyedRows::new(
+        target_driver.as_ref(),
+        target_pool,
+        &target_config.database_type,
+        &request.target.table_name,
+        &columns,
+        &key_types(&target_schema.columns, &key_columns),
+        chunk_size,
+    );
+
+    let mut result = TableDiffResult {
+        key_columns: key_columns.clone(),
+        compared_columns: columns.clone(),
+        source_rows: 0,
+        target_rows: 0,
+        inserted: 0,
+        updated: 0,
+        deleted: 0,
+        unchanged: 0,
+        rows: Vec::new(),
+        truncated: false,
+        sql: generate_sql.then(Vec::new),
+        execution_time_ms: 0,
+    };
+
+    let dml = Dml {
+        database_type: &target_config.database_type,
+        table: &request.target.table_name,
+        columns: &columns,
+        key_count,
+    };
+
+    let mut source_row = source.next().await?;
+    let mut target_row = target.next().await?;
+
+    loop {
+        let ordering = match (&source_row, &target_row) {
+            (None, None) => break,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(s), Some(t)) => compare_keys(&s[..key_count], &t[..key_count]),
+        };
+
+        let (kind, changed_columns) = match ordering {
+            Ordering::Less => {
+                if let Some(sql) = result.sql.as_mut() {
+                    sql.push(dml.insert(source_row.as_deref().unwrap_or_default()));
+                }
+                result.inserted += 1;
+                (RowDiffKind::Inserted, vec![])
+            }
+            Ordering::Greater => {
+                if let Some(sql) = result.sql.as_mut() {
+                    sql.push(dml.delete(target_row.as_deref().unwrap_or_default()));
+                }
+                result.deleted += 1;
+                (RowDiffKind::Deleted, vec![])
+            }
+            Ordering::Equal => {
+                let (s, t) = (source_row.as_deref().unwrap_or_default(), target_row.as_deref().unwrap_or_default());
+                let changed: Vec<usize> = (key_count..columns.len())
+                    .filter(|&i| !values_equal(&s[i], &t[i]))
+                    .collect();
+
+                if changed.is_empty() {
+                    result.unchanged += 1;
+                    source_row = source.next().await?;
+                    target_row = target.next().await?;
+                    continue;
+                }
+
+                if let Some(sql) = result.sql.as_mut() {
+                    sql.push(dml.update(s, &changed));
+                }
+                result.updated += 1;
+                (RowDiffKind::Updated, changed.iter().map(|&i| columns[i].clone()).collect())
+            }
+        };
+
+        let key_row = match kind {
+            RowDiffKind::Deleted => target_row.as_deref(),
+            _ => source_row.as_deref(),
+        }
+        .unwrap_or_default();
+
+        if result.rows.len() < max_rows {
+            result.rows.push(RowDiff {
+                kind,
+                key: key_columns.iter().cloned().zip(key_row[..key_count].iter().cloned()).collect(),
+                changed_columns,
+            });
+        } else {
+            result.truncated = true;
+        }
+
+        if kind != RowDiffKind::Deleted {
+            source_row = source.next().await?;
+        }
+        if kind != RowDiffKind::Inserted {
+            target_row = target.next().await?;
+        }
+    }
+
+    result.source_rows = source.count;
+    result.target_rows = target.count;
+    result.execution_time_ms = start.elapsed().as_millis() as u64;
+
+    Ok(result)
+}
+
+/// Compare two results kept by `execute_query`, such as one query's rows before and
+/// after a fix. Rows are matched on `key_columns`; without them a row matches an
+/// identical row, so rows are only added or removed.
+#[tauri::command]
+pub async fn diff_results(
+    result_a: String,
+    result_b: String,
+    key_columns: Option<Vec<String>>,
+    max_rows: Option<u32>,
+) -> AppResult<ResultDiff> {
+    let start = Instant::now();
+    let columns_a = result_store::columns(&result_a).await?;
+    let columns_b = result_store::columns(&result_b).await?;
+    let rows_a = result_store::all_rows(&result_a).await?;
+    let rows_b = result_store::all_rows(&result_b).await?;
+
+    let mut diff = compare_results(
+        &columns_a,
+        &rows_a,
+        &columns_b,
+        &rows_b,
+        key_columns.unwrap_or_default(),
+        max_rows.unwrap_or(DEFAULT_MAX_ROWS) as usize,
+    )?;
+    diff.execution_time_ms = start.elapsed().as_millis() as u64;
+    Ok(diff)
+}
+
+/// Match rows through a hash of their key values, so neither result needs sorting.
+/// Rows sharing a key are paired in the order they appear.
+fn compare_results(
+    columns_a: &[ColumnInfo],
+    rows_a: &[Vec<serde_json::Value>],
+    columns_b: &[ColumnInfo],
+    rows_b: &[Vec<serde_json::Value>],
+    key_columns: Vec<String>,
+    max_rows: usize,
+) -> AppResult<ResultDiff> {
+    let position = |columns: &[ColumnInfo], name: &str| columns.iter().position(|column| column.name == name);
+
+    // Columns both results have, with their index in each
+    let mut columns = Vec::new();
+    let mut indexes = Vec::new();
+    for (index_a, column) in columns_a.iter().enumerate() {
+        if columns.contains(&column.name) {
+            continue;
+        }
+        if let Some(index_b) = position(columns_b, &column.name) {
+            columns.push(column.name.clone());
+            indexes.push((index_a, index_b));
+        }
+    }
+    let removed_columns = columns_a.iter().filter(|c| !columns.contains(&c.name)).map(|c| c.name.clone()).collect();
+    let added_columns = columns_b.iter().filter(|c| !columns.contains(&c.name)).map(|c| c.name.clone()).collect();
+
+    let key_columns = if key_columns.is_empty() { columns.clone() } else { key_columns };
+    let mut keys = Vec::with_capacity(key_columns.len());
+    for key in &key_columns {
+        let index = columns.iter().position(|column| column == key).ok_or_else(|| {
+            AppError::ValidationError(format!("Column {} is not in both results", key))
+        })?;
+        keys.push(indexes[index]);
+    }
+    let values = |columns: &[usize], row: &[serde_json::Value]| -> Vec<serde_json::Value> {
+        columns.iter().map(|&i| row.get(i).cloned().unwrap_or_default()).collect()
+    };
+    let key_text = |side: fn(&(usize, usize)) -> usize, row: &[serde_json::Value]| {
+        let key: Vec<&serde_json::Value> = keys.iter().map(|k| row.get(side(k)).unwrap_or(&serde_json::Value::Null)).collect();
+        serde_json::to_string(&key)
+    };
+    let columns_of_a: Vec<usize> = indexes.iter().map(|&(a, _)| a).collect();
+    let columns_of_b: Vec<usize> = indexes.iter().map(|&(_, b)| b).collect();
+
+    let mut unmatched: HashMap<String, VecDeque<usize>> = HashMap::new();
+    for (index, row) in rows_b.iter().enumerate() {
+        unmatched.entry(key_text(|k| k.1, row)?).or_default().push_back(index);
+    }
+
+    let mut diff = ResultDiff {
+        key_columns: key_columns.clone(),
+        columns: columns.clone(),
+        removed_columns,
+        added_columns,
+        rows_a: rows_a.len(),
+        rows_b: rows_b.len(),
+        added: 0,
+        removed: 0,
+        changed: 0,
+        unchanged: 0,
+        rows: Vec::new(),
+        truncated: false,
+        execution_time_ms: 0,
+    };
+    let record = |diff: &mut ResultDiff, row: ResultRowDiff| {
+        if diff.rows.len() < max_rows {
+            diff.rows.push(row);
+        } else {
+            diff.truncated = true;
+        }
+    };
+    let key_of = |row: &[serde_json::Value], side: fn(&(usize, usize)) -> usize| -> serde_json::Map<String, serde_json::Value> {
+        key_columns.iter().cloned().zip(keys.iter().map(|k| row.get(side(k)).cloned().unwrap_or_default())).collect()
+    };
+
+    let mut matched = vec![false; rows_b.len()];
+    for row_a in rows_a {
+        let Some(index_b) = unmatched.get_mut(&key_text(|k| k.0, row_a)?).and_then(VecDeque::pop_front) else {
+            diff.removed += 1;
+            record(&mut diff, ResultRowDiff {
+                change: ResultRowChange::Removed,
+                key: key_of(row_a, |k| k.0),
+                before: Some(values(&columns_of_a, row_a)),
+                after: None,
+                changed_columns: vec![],
+            });
+            continue;
+        };
+        matched[index_b] = true;
+        let row_b = &rows_b[index_b];
+        let changed_columns: Vec<String> = indexes
+            .iter()
+            .zip(&columns)
+            .filter(|((a, b), _)| {
+                !values_equal(row_a.get(*a).unwrap_or(&serde_json::Value::Null), row_b.get(*b).unwrap_or(&serde_json::Value::Null))
+            })
+            .map(|(_, column)| column.clone())
+            .collect();
+        if changed_columns.is_empty() {
+            diff.unchanged += 1;
+            continue;
+        }
+        diff.changed += 1;
+        record(&mut diff, ResultRowDiff {
+            change: ResultRowChange::Changed,
+            key: key_of(row_a, |k| k.0),
+            before: Some(values(&columns_of_a, row_a)),
+            after: Some(values(&columns_of_b, row_b)),
+            changed_columns,
+        });
+    }
+
+    for (row_b, _) in rows_b.iter().zip(&matched).filter(|(_, matched)| !**matched) {
+        diff.added += 1;
+        record(&mut diff, ResultRowDiff {
+            change: ResultRowChange::Added,
+            key: key_of(row_b, |k| k.1),
+            before: None,
+            after: Some(values(&columns_of_b, row_b)),
+            changed_columns: vec![],
+        });
+    }
+    Ok(diff)
+}
+
+/// Data types of the key columns, as this table declares them
+fn key_types(columns: &[ColumnInfo], key_columns: &[String]) -> Vec<String> {
+    key_columns
+        .iter()
+        .map(|key| columns.iter().find(|c| c.name == *key).map(|c| c.data_type.clone()).unwrap_or_default())
+        .collect()
+}
+
+/// ORDER BY term for a key column. Text keys are sorted by bytes so the
+/// database order matches `compare_values`; SQLite, ClickHouse and DuckDB already do.
+fn binary_order(database_type: &DatabaseType, column: &str, data_type: &str) -> String {
+    let quoted = quote_identifier(database_type, column);
+    if !matches!(column_kind(database_type, data_type), ColumnKind::Text(_)) {
+        return quoted;
+    }
+    match database_type {
+        DatabaseType::PostgreSQL => format!("CAST({} AS text) COLLATE \"C\"", quoted),
+        DatabaseType::MySQL => format!("CAST({} AS BINARY)", quoted),
+        DatabaseType::MSSQL => format!("CAST({} AS nvarchar(max)) COLLATE Latin1_General_BIN2", quoted),
+        DatabaseType::Oracle => format!("NLSSORT({}, 'NLS_SORT=BINARY')", quoted),
+        _ => quoted,
+    }
+}
+
+/// Reads a table page by page, ordered by its key columns
+struct KeyedRows<'a> {
+    driver: &'a dyn DatabaseDriver,
+    pool: PoolRef<'a>,
+    database_type: &'a DatabaseType,
+    table: &'a str,
+    select: String,
+    key_count: usize,
+    chunk_size: usize,
+    offset: usize,
+    buffer: std::vec::IntoIter<Vec<serde_json::Value>>,
+    exhausted: bool,
+    previous_key: Option<Vec<serde_json::Value>>,
+    count: usize,
+}
+
+impl<'a> KeyedRows<'a> {
+    fn new(
+        driver: &'a dyn DatabaseDriver,
+        pool: PoolRef<'a>,
+        database_type: &'a DatabaseType,
+        table: &'a str,
+        columns: &[String],
+        key_types: &[String],
+        chunk_size: usize,
+    ) -> Self {
+        let key_count = key_types.len();
+        let order: Vec<String> = columns[..key_count]
+            .iter()
+            .zip(key_types)
+            .map(|(column, data_type)| binary_order(database_type, column, data_type))
+            .collect();
+        let select = format!(
+            "SELECT {} FROM {} ORDER BY {}",
+            column_list(database_type, columns),
+            quote_qualified(database_type, table),
+            order.join(", ")
+        );
+        Self {
+            driver,
+            pool,
+            database_type,
+            table,
+            select,
+            key_count,
+            chunk_size,
+            offset: 0,
+            buffer: Vec::new().into_iter(),
+            exhausted: false,
+            previous_key: None,
+            count: 0,
+        }
+    }
+
+    async fn next(&mut self) -> AppResult<Option<Vec<serde_json::Value>>> {
+        if let Some(row) = self.buffer.next() {
+            return self.check_order(row).map(Some);
+        }
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let sql = match self.database_type {
+            DatabaseType::MSSQL | DatabaseType::Oracle => format!(
+                "{} OFFSET {} ROWS FETCH NEXT {} ROWS ONLY",
+                self.select, self.offset, self.chunk_size
+            ),
+            _ => format!("{} LIMIT {} OFFSET {}", self.select, self.chunk_size, self.offset),
+        };
+        let page = self.driver.execute_query(self.pool, &sql).await?;
+
+        self.exhausted = page.rows.len() < self.chunk_size;
+        self.offset += page.rows.len();
+        self.buffer = page.rows.into_iter();
+
+        match self.buffer.next() {
+            Some(row) => self.check_order(row).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// The merge relies on strictly increasing keys; stop rather than report wrong differences
+    fn check_order(&mut self, row: Vec<serde_json::Value>) -> AppResult<Vec<serde_json::Value>> {
+        let key = row[..self.key_count.min(row.len())].to_vec();
+        if let Some(previous) = &self.previous_key {
+            match compare_keys(&key, previous) {
+                Ordering::Greater => {}
+                Ordering::Equal => {
+                    return Err(AppError::ValidationError(format!(
+                        "Key columns do not identify rows of {} uniquely",
+                        self.table
+                    )))
+                }
+                Ordering::Less => {
+                    return Err(AppError::ValidationError(format!(
+                        "{} returned keys in a different order than expected; text keys need a binary collation to be compared",
+                        self.table
+                    )))
+                }
+            }
+        }
+        self.previous_key = Some(key);
+        self.count += 1;
+        Ok(row)
+    }
+}
+
+/// Builds statements that make the target table match the source
+struct Dml<'a> {
+    database_type: &'a DatabaseType,
+    table: &'a str,
+    columns: &'a [String],
+    key_count: usize,
+}
+
+impl Dml<'_> {
+    fn insert(&self, row: &[serde_json::Value]) -> String {
+        let values: Vec<String> = row.iter().map(|v| sql_literal(self.database_type, v)).collect();
+        format!(
+            "INSERT INTO {} ({}) VALUES ({});",
+            quote_qualified(self.database_type, self.table),
+            column_list(self.database_type, self.columns),
+            values.join(", ")
+        )
+    }
+
+    fn update(&self, row: &[serde_json::Value], changed: &[usize]) -> String {
+        let assignments: Vec<String> = changed
+            .iter()
+            .map(|&i| format!("{} = {}", self.quote(&self.columns[i]), sql_literal(self.database_type, &row[i])))
+            .collect();
+        format!(
+            "UPDATE {} SET {} WHERE {};",
+            quote_qualified(self.database_type, self.table),
+            assignments.join(", "),
+            self.key_condition(row)
+        )
+    }
+
+    fn delete(&self, row: &[serde_json::Value]) -> String {
+        format!("DELETE FROM {} WHERE {};", quote_qualified(self.database_type, self.table), self.key_condition(row))
+    }
+
+    fn quote(&self, column: &str) -> String {
+        quote_identifier(self.database_type, column)
+    }
+
+    fn key_condition(&self, row: &[serde_json::Value]) -> String {
+        self.columns[..self.key_count]
+            .iter()
+            .zip(row)
+            .map(|(column, value)| format!("{} = {}", self.quote(column), sql_literal(self.database_type, value)))
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+}
+
+fn compare_keys(a: &[serde_json::Value], b: &[serde_json::Value]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| compare_values(x, y))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Order values the way databases sort keys: numbers numerically, text by bytes.
+/// A number compared with text (e.g. NUMERIC from another driver) is compared numerically.
+fn compare_values(a: &serde_json::Value, b: &serde_json::Value) -> Ordering {
+    use serde_json::Value;
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Less,
+        (_, Value::Null) => Ordering::Greater,
+        (Value::Number(x), Value::Number(y)) => match (x.as_i64(), y.as_i64()) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            _ => x.as_f64().partial_cmp(&y.as_f64()).unwrap_or(Ordering::Equal),
+        },
+        (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+        (Value::Number(_), Value::String(_)) | (Value::String(_), Value::Number(_)) => {
+            match (as_number(a), as_number(b)) {
+                (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+                _ => value_text(a).cmp(&value_text(b)),
+            }
+        }
+        _ => value_text(a).cmp(&value_text(b)),
+    }
+}
+
+/// Equality that tolerates representation differences between drivers (`1.5` vs `"1.50"`, `1` vs `true`)
+fn values_equal(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    use serde_json::Value;
+    if a == b {
+        return true;
+    }
+    match (a, b) {
+        (Value::Bool(x), Value::Number(n)) | (Value::Number(n), Value::Bool(x)) => n.as_i64() == Some(i64::from(*x)),
+        (Value::Number(_), Value::String(_)) | (Value::String(_), Value::Number(_)) => {
+            matches!((as_number(a), as_number(b)), (Some(x), Some(y)) if x == y)
+        }
+        _ => false,
+    }
+}
+
+fn as_number(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn value_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
@@ -0,0 +1,19 @@
+use crate::db::{get_connection_manager, MongoDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::models::{DocumentQuery, DocumentResult};
+
+/// Find documents in a collection for the document viewer
+#[tauri::command]
+pub async fn find_documents(request: DocumentQuery) -> AppResult<DocumentResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    match manager.get_pool_ref(&request.connection_id)? {
+        PoolRef::Mongo(database) => MongoDriver::find_documents(database, request).await,
+        _ => Err(AppError::QueryError("Document queries are only supported for MongoDB connections".to_string())),
+    }
+}
@@ -0,0 +1,83 @@
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::models::{DatabaseType, SqlCompletion, SqlDiagnostic, SqlFormatOptions};
+use crate::schema_cache;
+use crate::sql::{self, Dialect};
+use crate::storage;
+
+/// Pretty-print SQL for the editor's Format action. Runs locally, so it needs
+/// neither a connection nor the AI provider.
+#[tauri::command]
+pub async fn format_sql(
+    sql: String,
+    database_type: Option<DatabaseType>,
+    options: Option<SqlFormatOptions>,
+) -> AppResult<String> {
+    let dialect = sql_dialect(database_type.as_ref())?;
+
+    let options = options.unwrap_or_default();
+    if !(1..=8).contains(&options.indent_width) {
+        return Err(AppError::ValidationError("Indent width must be between 1 and 8".to_string()));
+    }
+
+    Ok(sql::format_sql(&sql, dialect, &options))
+}
+
+/// Check SQL for common problems without running it, for editor markers
+#[tauri::command]
+pub async fn lint_sql(sql: String, database_type: Option<DatabaseType>) -> AppResult<Vec<SqlDiagnostic>> {
+    let dialect = sql_dialect(database_type.as_ref())?;
+    Ok(sql::lint_sql(&sql, dialect))
+}
+
+/// Names of the `:name` and `${name}` placeholders in SQL, in order of first use,
+/// to prompt for before running it with `execute_query`'s `params`
+#[tauri::command]
+pub async fn get_query_parameters(sql: String, database_type: Option<DatabaseType>) -> AppResult<Vec<String>> {
+    let dialect = sql_dialect(database_type.as_ref())?;
+    Ok(sql::query_parameters(&sql, dialect))
+}
+
+/// Completion candidates for the cursor at 1-based `line` and `column`, best
+/// first. Uses the schemas cached by `get_all_table_schemas`, fetching them
+/// only if the connection has none cached yet.
+#[tauri::command]
+pub async fn get_sql_completions(
+    connection_id: String,
+    sql: String,
+    line: usize,
+    column: usize,
+) -> AppResult<Vec<SqlCompletion>> {
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    let dialect = sql_dialect(Some(&config.database_type))?;
+
+    let tables = match schema_cache::get(&connection_id) {
+        Some(tables) => tables,
+        None => {
+            let manager = get_connection_manager().read().await;
+
+            // Verify connection exists
+            if !manager.is_connected(&connection_id) {
+                return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+            }
+
+            let driver = get_driver(&config);
+            let pool_ref = manager.get_pool_ref(&connection_id)?;
+            let tables = driver.get_all_table_schemas(pool_ref, &config).await?;
+            schema_cache::store(&connection_id, tables)
+        }
+    };
+
+    Ok(sql::complete_sql(&sql, line, column, dialect, &tables))
+}
+
+fn sql_dialect(database_type: Option<&DatabaseType>) -> AppResult<Dialect> {
+    match database_type {
+        Some(DatabaseType::MongoDB | DatabaseType::Redis) => {
+            Err(AppError::ValidationError("This connection does not use SQL".to_string()))
+        }
+        Some(database_type) => Ok(Dialect::from(database_type)),
+        None => Ok(Dialect::Generic),
+    }
+}
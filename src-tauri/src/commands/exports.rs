@@ -0,0 +1,115 @@
+use crate::db::{get_connection_manager, get_driver, quote_qualified, DatabaseDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::export::{get_exporter, ParquetWriter};
+use crate::models::{ExportFormat, ExportOptions, ExportProgress, ExportRequest, ExportSummary};
+use crate::read_only;
+use crate::storage;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+
+const DEFAULT_BATCH_SIZE: u32 = 10_000;
+
+/// Run a query (or read a whole table) and write every result row to a file in the requested format
+#[tauri::command]
+pub async fn export_query_results(app: AppHandle, request: ExportRequest) -> AppResult<ExportSummary> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+
+    let mut options = request.options.unwrap_or_default();
+    let sql = match &request.table_name {
+        Some(table_name) => {
+            if options.sheet_name.is_none() {
+                options.sheet_name = Some(table_name.clone());
+            }
+            format!("SELECT * FROM {}", quote_qualified(&config.database_type, table_name))
+        }
+        None if request.sql.trim().is_empty() => {
+            return Err(AppError::ValidationError("Either sql or tableName is required".to_string()));
+        }
+        None => request.sql.clone(),
+    };
+    // Export only reads, whatever the connection allows
+    read_only::check_query(&config, &sql)?;
+    if !read_only::is_single_read(&config.database_type, &sql) {
+        return Err(AppError::ValidationError(
+            "Only a single read-only statement can be exported".to_string(),
+        ));
+    }
+
+    let start = Instant::now();
+
+    let rows = if request.format == ExportFormat::Parquet {
+        export_parquet(&app, driver.as_ref(), pool_ref, &sql, &request.path, &options).await?
+    } else {
+        let result = driver.execute_query(pool_ref, &sql).await?;
+
+        let exporter = get_exporter(request.format, options);
+        let mut out = BufWriter::new(File::create(&request.path)?);
+        exporter.export(&result, &mut out)?;
+        out.flush()?;
+        result.rows.len()
+    };
+
+    let bytes = std::fs::metadata(&request.path)?.len();
+
+    Ok(ExportSummary {
+        path: request.path,
+        format: request.format,
+        rows,
+        bytes,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Read the query through one cursor and append every `batch_size` rows to the Parquet file as a record batch
+async fn export_parquet(
+    app: &AppHandle,
+    driver: &dyn DatabaseDriver,
+    pool_ref: PoolRef<'_>,
+    sql: &str,
+    path: &str,
+    options: &ExportOptions,
+) -> AppResult<usize> {
+    let batch_size = options.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1) as usize;
+    let mut writer: Option<ParquetWriter<BufWriter<File>>> = None;
+    let mut rows_written = 0;
+
+    let rows = driver
+        .stream_query(pool_ref, sql, batch_size, &mut |columns, rows| {
+            let batch_writer = match writer.as_mut() {
+                Some(w) => w,
+                None => writer.insert(ParquetWriter::new(BufWriter::new(File::create(path)?), columns)?),
+            };
+            batch_writer.write_batch(&rows)?;
+            rows_written += rows.len();
+
+            let _ = app.emit(
+                "export-progress",
+                ExportProgress {
+                    path: path.to_string(),
+                    rows_written,
+                },
+            );
+            Ok(())
+        })
+        .await?;
+
+    if let Some(writer) = writer {
+        writer.finish()?.flush()?;
+    }
+
+    Ok(rows)
+}
+
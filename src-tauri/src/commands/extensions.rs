@@ -0,0 +1,207 @@
+use crate::error::{AppError, AppResult};
+use crate::extension::logging::{self, ExtensionLogger};
+use crate::extension::{builtin, dispatch, health, network, ExtensionLoader};
+use crate::marketplace;
+use crate::models::{
+    ExtensionCommandInfo, ExtensionCommandResult, ExtensionEntry, ExtensionFetchRequest, ExtensionFetchResponse,
+    ExtensionLogEntry, ExtensionLogLevel, ExtensionSettings, InstalledExtension, MarketplaceSearchResult, ThemeCss,
+    ThemeInfo, ThemeVariant,
+};
+use crate::theme;
+use std::fs;
+use tauri::{AppHandle, Emitter};
+
+/// Search the marketplace registry by text and category; `refresh` downloads the index again
+#[tauri::command]
+pub async fn search_marketplace(
+    query: Option<String>,
+    category: Option<String>,
+    refresh: Option<bool>,
+) -> AppResult<MarketplaceSearchResult> {
+    marketplace::search(query.as_deref(), category.as_deref(), refresh.unwrap_or(false)).await
+}
+
+/// URL of the registry index in use
+#[tauri::command]
+pub async fn get_marketplace_registry_url() -> AppResult<String> {
+    marketplace::registry_url()
+}
+
+/// Use another registry index; None goes back to the default
+#[tauri::command]
+pub async fn set_marketplace_registry_url(url: Option<String>) -> AppResult<String> {
+    marketplace::set_registry_url(url.as_deref())
+}
+
+/// Bundled extensions, then the ones installed from packages
+#[tauri::command]
+pub async fn list_installed_extensions() -> AppResult<Vec<InstalledExtension>> {
+    let mut extensions = builtin::list();
+    extensions.extend(ExtensionLoader::new()?.list()?);
+    Ok(extensions)
+}
+
+/// Turn a bundled extension on or off, running its activate or deactivate hook
+#[tauri::command]
+pub async fn set_builtin_extension_enabled(app: AppHandle, id: String, enabled: bool) -> AppResult<InstalledExtension> {
+    builtin::set_enabled(&app, &id, enabled)
+}
+
+/// Recover a failed extension without restarting the app. A bundled one runs
+/// its hooks again; for a package, the frontend runtime is asked to restart
+/// its worker through an `extension-restart` event.
+#[tauri::command]
+pub async fn restart_extension(app: AppHandle, id: String) -> AppResult<InstalledExtension> {
+    if builtin::is_builtin(&id) {
+        return builtin::restart(&app, &id);
+    }
+    let loader = ExtensionLoader::new()?;
+    let installed = loader.get(&id)?;
+    if installed.manifest.main.is_none() {
+        return Err(AppError::ValidationError(format!("{} has no code to restart", installed.manifest.name)));
+    }
+    health::clear(&app, &id);
+    ExtensionLogger::new(&id).info("Restart requested");
+    app.emit(health::RESTART_EVENT, &id)
+        .map_err(|e| AppError::Internal(format!("Failed to reach the extension runtime: {}", e)))?;
+    loader.get(&id)
+}
+
+/// Called by the extension runtime when an extension failed, with the reason,
+/// or started again, without one
+#[tauri::command]
+pub async fn report_extension_status(app: AppHandle, id: String, error: Option<String>) -> AppResult<()> {
+    match error {
+        Some(message) => health::fail(&app, &id, &message),
+        None => {
+            ExtensionLogger::new(&id).info("Started");
+            health::clear(&app, &id);
+        }
+    }
+    Ok(())
+}
+
+/// The last `tail` entries of an extension's log, oldest first, for diagnosing it
+#[tauri::command]
+pub async fn get_extension_logs(extension_id: String, tail: Option<usize>) -> AppResult<Vec<ExtensionLogEntry>> {
+    logging::read(&extension_id, tail.unwrap_or(logging::DEFAULT_TAIL))
+}
+
+/// Called by the extension runtime with what an extension logged
+#[tauri::command]
+pub async fn append_extension_log(extension_id: String, level: ExtensionLogLevel, message: String) -> AppResult<()> {
+    ExtensionLogger::new(&extension_id).log(level, &message);
+    Ok(())
+}
+
+/// Delete an extension's log
+#[tauri::command]
+pub async fn clear_extension_logs(extension_id: String) -> AppResult<()> {
+    logging::clear(&extension_id)
+}
+
+/// Install a `.dbx` package from disk; with `sha256` the package must match it
+#[tauri::command]
+pub async fn install_extension(path: String, sha256: Option<String>) -> AppResult<InstalledExtension> {
+    let package = fs::read(&path).map_err(|e| AppError::ValidationError(format!("Cannot read {}: {}", path, e)))?;
+    ExtensionLoader::new()?.install(&package, sha256.as_deref())
+}
+
+/// Download an extension from the marketplace and install it, checking its checksum
+#[tauri::command]
+pub async fn install_marketplace_extension(id: String) -> AppResult<InstalledExtension> {
+    let (package, sha256) = marketplace::download_package(&id).await?;
+    let installed = ExtensionLoader::new()?.install(&package, Some(&sha256))?;
+    if installed.manifest.id != id {
+        ExtensionLoader::new()?.uninstall(&installed.manifest.id)?;
+        return Err(AppError::ValidationError(format!(
+            "The package for '{}' contains the extension '{}'",
+            id, installed.manifest.id
+        )));
+    }
+    Ok(installed)
+}
+
+/// Remove an installed extension
+#[tauri::command]
+pub async fn uninstall_extension(app: AppHandle, id: String) -> AppResult<()> {
+    ExtensionLoader::new()?.uninstall(&id)?;
+    health::clear(&app, &id);
+    Ok(())
+}
+
+/// Manifest and entry point of an installed extension, for the frontend runtime to start it
+#[tauri::command]
+pub async fn load_extension(id: String) -> AppResult<ExtensionEntry> {
+    ExtensionLoader::new()?.entry(&id)
+}
+
+/// An extension's settings over its contributed defaults; password values are not returned
+#[tauri::command]
+pub async fn get_extension_settings(id: String) -> AppResult<ExtensionSettings> {
+    ExtensionLoader::new()?.settings(&id)
+}
+
+/// Change one extension setting, checked against its contributed type; null resets it
+#[tauri::command]
+pub async fn set_extension_setting(id: String, key: String, value: serde_json::Value) -> AppResult<()> {
+    ExtensionLoader::new()?.set_setting(&id, &key, value)
+}
+
+/// Commands contributed by the installed extensions
+#[tauri::command]
+pub async fn list_extension_commands() -> AppResult<Vec<ExtensionCommandInfo>> {
+    dispatch::commands(&ExtensionLoader::new()?)
+}
+
+/// Run a contributed command in its extension, starting the extension if needed
+#[tauri::command]
+pub async fn execute_extension_command(
+    app: AppHandle,
+    command_id: String,
+    args: Option<serde_json::Value>,
+) -> AppResult<ExtensionCommandResult> {
+    let loader = ExtensionLoader::new()?;
+    dispatch::execute(&app, &loader, &command_id, args.unwrap_or(serde_json::Value::Null)).await
+}
+
+/// Called by the extension runtime with the outcome of an `extension-command` request
+#[tauri::command]
+pub async fn complete_extension_command(
+    request_id: String,
+    result: Option<serde_json::Value>,
+    error: Option<String>,
+) -> AppResult<()> {
+    dispatch::complete(&request_id, result, error)
+}
+
+/// Make an HTTP request for an extension with the `network` permission
+#[tauri::command]
+pub async fn extension_fetch(id: String, request: ExtensionFetchRequest) -> AppResult<ExtensionFetchResponse> {
+    let installed = ExtensionLoader::new()?.get(&id)?;
+    network::fetch(&installed.manifest, request).await
+}
+
+/// Bundled themes and those contributed by installed theme extensions
+#[tauri::command]
+pub async fn list_themes() -> AppResult<Vec<ThemeInfo>> {
+    theme::list(&ExtensionLoader::new()?)
+}
+
+/// Stylesheet of one theme variant, scoped to its `theme-<id>-<variant>` class
+#[tauri::command]
+pub async fn get_theme_css(theme_id: String, variant: ThemeVariant) -> AppResult<ThemeCss> {
+    theme::css(&ExtensionLoader::new()?, &theme_id, variant)
+}
+
+/// The saved theme choice, e.g. `system` or `nordic-dark`
+#[tauri::command]
+pub async fn get_active_theme() -> AppResult<String> {
+    theme::active(&ExtensionLoader::new()?)
+}
+
+/// Save the theme choice: `system`, `light`, `dark` or `<theme id>-<variant>`
+#[tauri::command]
+pub async fn set_active_theme(theme: String) -> AppResult<String> {
+    theme::set_active(&ExtensionLoader::new()?, &theme)
+}
@@ -0,0 +1,169 @@
+use crate::db::{get_connection_manager, get_driver, quote_identifier, quote_qualified};
+use crate::error::{AppError, AppResult};
+use crate::import::{convert_value, infer_columns, read_import_file, sql_type};
+use crate::models::{
+    DatabaseType, ImportColumnMapping, ImportFileOptions, ImportMode, ImportPreview, ImportRequest, ImportRowError,
+    ImportSummary,
+};
+use crate::read_only;
+use crate::storage;
+use std::time::Instant;
+
+/// Rows returned by the preview and used to infer column types
+const PREVIEW_ROWS: usize = 100;
+
+const DEFAULT_BATCH_SIZE: u32 = 500;
+
+/// Row errors returned in an import summary; later failures are only counted
+const MAX_REPORTED_ERRORS: usize = 1000;
+
+/// Detect the format, delimiter and column types of a CSV, TSV or JSON file
+#[tauri::command]
+pub async fn preview_import_file(path: String, options: Option<ImportFileOptions>) -> AppResult<ImportPreview> {
+    let data = read_import_file(&path, &options.unwrap_or_default())?;
+
+    let total_rows = data.rows.len();
+    let mut rows = data.rows;
+    rows.truncate(PREVIEW_ROWS);
+
+    Ok(ImportPreview {
+        format: data.format,
+        delimiter: data.delimiter.map(String::from),
+        has_header: data.has_header,
+        columns: infer_columns(&data.columns, &rows),
+        rows,
+        total_rows,
+    })
+}
+
+/// Load a CSV, TSV or JSON file into a new or existing table in batched transactions
+#[tauri::command]
+pub async fn import_file_into_table(request: ImportRequest) -> AppResult<ImportSummary> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    if matches!(config.database_type, DatabaseType::Redis) {
+        return Err(AppError::ValidationError("Files cannot be imported into Redis".to_string()));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+
+    let start = Instant::now();
+    let data = read_import_file(&request.path, &request.options.unwrap_or_default())?;
+    let inferred = infer_columns(&data.columns, &data.rows);
+
+    let mapping = request.mapping.unwrap_or_else(|| {
+        data.columns
+            .iter()
+            .map(|name| ImportColumnMapping {
+                source: name.clone(),
+                target: name.clone(),
+                data_type: None,
+            })
+            .collect()
+    });
+    if mapping.is_empty() {
+        return Err(AppError::ValidationError("No columns selected for import".to_string()));
+    }
+
+    let source_indexes = mapping
+        .iter()
+        .map(|m| {
+            data.columns.iter().position(|c| *c == m.source).ok_or_else(|| {
+                AppError::ValidationError(format!("Column '{}' does not exist in the file", m.source))
+            })
+        })
+        .collect::<AppResult<Vec<usize>>>()?;
+    let targets: Vec<String> = mapping.iter().map(|m| m.target.clone()).collect();
+
+    if request.mode == ImportMode::Create {
+        let definitions: Vec<String> = mapping
+            .iter()
+            .zip(&source_indexes)
+            .filter_map(|(m, &index)| {
+                let data_type = m
+                    .data_type
+                    .as_deref()
+                    .or_else(|| sql_type(&config.database_type, inferred[index].column_type))?;
+                Some(format!("{} {}", quote_identifier(&config.database_type, &m.target), data_type))
+            })
+            .collect();
+
+        // MongoDB creates the collection on first insert
+        if !definitions.is_empty() {
+            let mut sql = format!(
+                "CREATE TABLE {} ({})",
+                quote_qualified(&config.database_type, &request.table_name),
+                definitions.join(", ")
+            );
+            if matches!(config.database_type, DatabaseType::ClickHouse) {
+                // ClickHouse tables need an engine; rows stay in insertion order
+                sql.push_str(" ENGINE = MergeTree ORDER BY tuple()");
+            }
+            driver.execute_query(pool_ref, &sql).await?;
+        }
+    }
+
+    let width = data.columns.len();
+    let batch_size = request.batch_size.unwrap_or(DEFAULT_BATCH_SIZE).max(1) as usize;
+    let mut rows_imported = 0;
+    let mut rows_failed = 0;
+    let mut errors: Vec<ImportRowError> = Vec::new();
+
+    let mut report = |row: usize, message: String| {
+        rows_failed += 1;
+        if errors.len() < MAX_REPORTED_ERRORS {
+            errors.push(ImportRowError { row: row + 1, message });
+        }
+    };
+
+    let mut rows = data.rows.into_iter().enumerate().peekable();
+    while rows.peek().is_some() {
+        let mut batch = Vec::with_capacity(batch_size);
+        // File row of each batch entry, since malformed rows are left out
+        let mut batch_rows = Vec::with_capacity(batch_size);
+
+        for (row_index, mut row) in rows.by_ref().take(batch_size) {
+            if row.len() > width {
+                report(row_index, format!("Row has {} fields, expected {}", row.len(), width));
+                continue;
+            }
+            row.resize(width, serde_json::Value::Null);
+
+            let values = source_indexes
+                .iter()
+                .map(|&index| convert_value(row[index].clone(), inferred[index].column_type))
+                .collect();
+            batch.push(values);
+            batch_rows.push(row_index);
+        }
+
+        if batch.is_empty() {
+            continue;
+        }
+
+        let attempted = batch.len();
+        let failed = driver.insert_rows(pool_ref, &request.table_name, &targets, batch).await?;
+        rows_imported += attempted - failed.len();
+        for (index, message) in failed {
+            report(batch_rows[index], message);
+        }
+    }
+
+    Ok(ImportSummary {
+        table_name: request.table_name,
+        rows_imported,
+        rows_failed,
+        errors,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
@@ -0,0 +1,22 @@
+use crate::error::AppResult;
+use crate::extension::ExtensionLoader;
+use crate::keymap;
+use crate::models::KeyBinding;
+
+/// The resolved keymap: built-in and extension shortcuts with the user's changes
+#[tauri::command]
+pub async fn get_keymap() -> AppResult<Vec<KeyBinding>> {
+    keymap::resolve(&ExtensionLoader::new()?)
+}
+
+/// Change a command's shortcut; None leaves it unbound
+#[tauri::command]
+pub async fn set_keybinding(command_id: String, shortcut: Option<String>) -> AppResult<Vec<KeyBinding>> {
+    keymap::set_shortcut(&ExtensionLoader::new()?, &command_id, shortcut.as_deref())
+}
+
+/// Give a command its default shortcut again
+#[tauri::command]
+pub async fn reset_keybinding(command_id: String) -> AppResult<Vec<KeyBinding>> {
+    keymap::reset_shortcut(&ExtensionLoader::new()?, &command_id)
+}
@@ -0,0 +1,193 @@
+use crate::db::{get_connection_manager, get_driver, quote_identifier, quote_qualified, DatabaseDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::extension::builtin;
+use crate::models::{
+    ColumnInfo, DatabaseType, ExtendedColumnInfo, ForeignKeyInfo, ImportRowError, MockDataSummary, TableSchema,
+};
+use crate::read_only;
+use crate::storage;
+use data_faker::{Faker, FieldKind};
+use std::time::Instant;
+
+const MAX_MOCK_ROWS: u32 = 10_000;
+
+/// Distinct parent keys read for each foreign key column
+const PARENT_SAMPLE_SIZE: u32 = 1000;
+
+/// Share of nullable, non-key columns left NULL
+const NULL_RATE: f64 = 0.1;
+
+/// Row errors returned in the summary; later failures are only counted
+const MAX_REPORTED_ERRORS: usize = 1000;
+
+/// Where a column's values come from
+enum ColumnSource {
+    Fake(FieldKind),
+    /// Existing keys of the referenced table
+    Parent(Vec<serde_json::Value>),
+}
+
+/// Insert `count` rows of realistic fake data into a table in one transaction.
+/// Values follow the column names and types; foreign keys reuse keys that exist
+/// in the parent table. Pass `seed` to generate the same rows again.
+#[tauri::command]
+pub async fn generate_mock_data(
+    connection_id: String,
+    table_name: String,
+    count: u32,
+    seed: Option<u64>,
+) -> AppResult<MockDataSummary> {
+    builtin::ensure_active("data-faker")?;
+    if count == 0 || count > MAX_MOCK_ROWS {
+        return Err(AppError::ValidationError(format!(
+            "Row count must be between 1 and {}",
+            MAX_MOCK_ROWS
+        )));
+    }
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    if matches!(config.database_type, DatabaseType::Redis) {
+        return Err(AppError::ValidationError("Mock data cannot be generated for Redis".to_string()));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let start = Instant::now();
+    let schema = driver.get_table_schema(pool_ref, &table_name).await?;
+    // Identity and generated column details; without them keys are guessed from the schema
+    let details = driver.get_table_properties(pool_ref, &table_name).await.map(|p| p.columns).unwrap_or_default();
+
+    let mut columns = Vec::new();
+    let mut sources = Vec::new();
+    for column in &schema.columns {
+        if is_filled_by_database(&config.database_type, &schema, &details, column) {
+            continue;
+        }
+
+        let source = match schema.foreign_keys.iter().find(|fk| fk.column == column.name) {
+            Some(fk) => {
+                let keys = sample_parent_keys(driver.as_ref(), pool_ref, &config.database_type, &table_name, fk).await?;
+                if keys.is_empty() && !column.nullable {
+                    return Err(AppError::ValidationError(format!(
+                        "Table {} has no rows for {} to reference; add data to it first",
+                        fk.references_table, column.name
+                    )));
+                }
+                ColumnSource::Parent(keys)
+            }
+            None => ColumnSource::Fake(FieldKind::infer(&column.name, &column.data_type)),
+        };
+        columns.push(column);
+        sources.push(source);
+    }
+
+    if columns.is_empty() {
+        return Err(AppError::ValidationError(format!(
+            "Table {} has no columns to fill",
+            table_name
+        )));
+    }
+
+    let mut faker = seed.map(Faker::seeded).unwrap_or_default();
+    let rows: Vec<Vec<serde_json::Value>> = (0..count)
+        .map(|_| {
+            columns
+                .iter()
+                .zip(&sources)
+                .map(|(column, source)| match source {
+                    ColumnSource::Parent(keys) => faker.sample(keys),
+                    ColumnSource::Fake(_) if column.nullable && !column.is_primary_key && faker.chance(NULL_RATE) => {
+                        serde_json::Value::Null
+                    }
+                    ColumnSource::Fake(kind) => faker.value(kind),
+                })
+                .collect()
+        })
+        .collect();
+
+    let names: Vec<String> = columns.iter().map(|c| c.name.clone()).collect();
+    let attempted = rows.len();
+    let failed = driver.insert_rows(pool_ref, &table_name, &names, rows).await?;
+
+    let rows_failed = failed.len();
+    let errors = failed
+        .into_iter()
+        .take(MAX_REPORTED_ERRORS)
+        .map(|(index, message)| ImportRowError { row: index + 1, message })
+        .collect();
+
+    Ok(MockDataSummary {
+        table_name,
+        rows_inserted: attempted - rows_failed,
+        rows_failed,
+        errors,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Columns the database fills in itself: MongoDB's `_id`, identity, autoincrement
+/// and generated columns. Without introspected details, single integer primary
+/// keys are assumed to be serial
+fn is_filled_by_database(
+    database_type: &DatabaseType,
+    schema: &TableSchema,
+    details: &[ExtendedColumnInfo],
+    column: &ColumnInfo,
+) -> bool {
+    if matches!(database_type, DatabaseType::MongoDB) {
+        return column.name == "_id";
+    }
+    if let Some(detail) = details.iter().find(|d| d.name == column.name) {
+        return detail.is_identity || detail.generation_expression.is_some();
+    }
+    let data_type = column.data_type.to_ascii_lowercase();
+    column.is_primary_key
+        && schema.primary_keys.len() == 1
+        && (data_type.contains("int") || data_type.contains("serial"))
+}
+
+/// Read distinct values of the referenced column so generated rows point at real parents
+async fn sample_parent_keys(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    table_name: &str,
+    fk: &ForeignKeyInfo,
+) -> AppResult<Vec<serde_json::Value>> {
+    // Foreign keys name the parent without its schema; it lives beside the child
+    let parent = match table_name.split_once('.') {
+        Some((schema, _)) if !fk.references_table.contains('.') => format!("{}.{}", schema, fk.references_table),
+        _ => fk.references_table.clone(),
+    };
+    let column = quote_identifier(database_type, &fk.references_column);
+    let table = quote_qualified(database_type, &parent);
+
+    let sql = match database_type {
+        DatabaseType::MSSQL => format!(
+            "SELECT DISTINCT TOP {} {} FROM {} WHERE {} IS NOT NULL",
+            PARENT_SAMPLE_SIZE, column, table, column
+        ),
+        DatabaseType::Oracle => format!(
+            "SELECT DISTINCT {} FROM {} WHERE {} IS NOT NULL FETCH FIRST {} ROWS ONLY",
+            column, table, column, PARENT_SAMPLE_SIZE
+        ),
+        _ => format!(
+            "SELECT DISTINCT {} FROM {} WHERE {} IS NOT NULL LIMIT {}",
+            column, table, column, PARENT_SAMPLE_SIZE
+        ),
+    };
+
+    let result = driver.execute_query(pool, &sql).await?;
+    Ok(result.rows.into_iter().filter_map(|row| row.into_iter().next()).collect())
+}
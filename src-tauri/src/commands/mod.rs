@@ -1,5 +1,29 @@
+pub mod ai;
+pub mod app_logs;
+pub mod audit_log;
+pub mod backups;
+pub mod browse;
+pub mod charts;
 pub mod connections;
+pub mod databases;
+pub mod diagram;
+pub mod diff;
+pub mod documents;
+pub mod editor;
+pub mod extensions;
+pub mod exports;
+pub mod imports;
+pub mod keybindings;
+pub mod mock_data;
+pub mod monitoring;
+pub mod notifications;
+pub mod pins;
 pub mod queries;
+pub mod redis_keys;
+pub mod saved_queries;
+pub mod search;
 pub mod tables;
+pub mod transfers;
 pub mod utils;
+pub mod workspace;
 
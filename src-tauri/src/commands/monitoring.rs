@@ -0,0 +1,127 @@
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::metrics;
+use crate::models::{ActiveQuery, PerformanceMetrics, ServerStats, SlowQueryEntry, SlowQueryOrder};
+use crate::monitor;
+use crate::storage;
+use std::time::Duration;
+use tauri::AppHandle;
+
+const DEFAULT_INTERVAL_MS: u64 = 5000;
+const MIN_INTERVAL_MS: u64 = 1000;
+const DEFAULT_SLOW_QUERY_LIMIT: u32 = 50;
+
+/// Get connection counts, uptime, cache hit ratio and database sizes
+#[tauri::command]
+pub async fn get_server_stats(connection_id: String) -> AppResult<ServerStats> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_server_stats(pool_ref).await
+}
+
+/// List sessions that are running a statement
+#[tauri::command]
+pub async fn get_active_queries(connection_id: String) -> AppResult<Vec<ActiveQuery>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_active_queries(pool_ref).await
+}
+
+/// Terminate a session listed by `get_active_queries`
+#[tauri::command]
+pub async fn kill_session(connection_id: String, session_id: String) -> AppResult<bool> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.kill_session(pool_ref, &session_id).await?;
+    Ok(true)
+}
+
+/// Top statements by total time, mean time or calls (default: top 50 by total time)
+#[tauri::command]
+pub async fn get_slow_queries(
+    connection_id: String,
+    order_by: Option<SlowQueryOrder>,
+    limit: Option<u32>,
+) -> AppResult<Vec<SlowQueryEntry>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let limit = limit.unwrap_or(DEFAULT_SLOW_QUERY_LIMIT).clamp(1, 1000);
+    driver.get_slow_queries(pool_ref, order_by.unwrap_or_default(), limit).await
+}
+
+/// Emit a `server-monitor` event every `interval_ms` (default 5s) until stopped
+#[tauri::command]
+pub async fn start_server_monitor(app: AppHandle, connection_id: String, interval_ms: Option<u64>) -> AppResult<bool> {
+    if !get_connection_manager().read().await.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let interval = interval_ms.unwrap_or(DEFAULT_INTERVAL_MS).max(MIN_INTERVAL_MS);
+    monitor::start(app, connection_id, Duration::from_millis(interval));
+    Ok(true)
+}
+
+/// Stop the monitor started by `start_server_monitor`
+#[tauri::command]
+pub async fn stop_server_monitor(connection_id: String) -> AppResult<bool> {
+    Ok(monitor::stop(&connection_id))
+}
+
+/// Query latency, connections, schema cache hits and AI request durations of
+/// this session, measured locally
+#[tauri::command]
+pub async fn get_performance_metrics() -> AppResult<PerformanceMetrics> {
+    let manager = get_connection_manager().read().await;
+    Ok(metrics::snapshot(manager.list_connections().len()))
+}
+
+/// Start measuring the performance metrics again
+#[tauri::command]
+pub async fn reset_performance_metrics() -> AppResult<()> {
+    metrics::reset();
+    Ok(())
+}
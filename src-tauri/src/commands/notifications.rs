@@ -0,0 +1,46 @@
+use crate::change_feed;
+use crate::error::AppResult;
+use std::time::Duration;
+use tauri::AppHandle;
+
+const DEFAULT_WATCH_INTERVAL_MS: u64 = 2000;
+const MIN_WATCH_INTERVAL_MS: u64 = 500;
+
+/// LISTEN on a PostgreSQL channel; each NOTIFY is emitted as a `db-notification`
+/// event. Returns the channels the connection listens on.
+#[tauri::command]
+pub async fn listen_channel(app: AppHandle, connection_id: String, channel: String) -> AppResult<Vec<String>> {
+    change_feed::listen(app, &connection_id, &channel).await
+}
+
+/// Stop listening on a channel; returns the channels still listened on
+#[tauri::command]
+pub async fn unlisten_channel(connection_id: String, channel: String) -> AppResult<Vec<String>> {
+    change_feed::unlisten(&connection_id, &channel).await
+}
+
+/// The channels a connection listens on
+#[tauri::command]
+pub async fn list_listen_channels(connection_id: String) -> AppResult<Vec<String>> {
+    Ok(change_feed::channels(&connection_id).await)
+}
+
+/// Emit a `table-changed` event when rows of a PostgreSQL table change,
+/// checking every `interval_ms` (default 2s)
+#[tauri::command]
+pub async fn watch_table(
+    app: AppHandle,
+    connection_id: String,
+    table: String,
+    interval_ms: Option<u64>,
+) -> AppResult<bool> {
+    let interval = interval_ms.unwrap_or(DEFAULT_WATCH_INTERVAL_MS).max(MIN_WATCH_INTERVAL_MS);
+    change_feed::watch_table(app, connection_id, table, Duration::from_millis(interval)).await?;
+    Ok(true)
+}
+
+/// Stop the watcher started by `watch_table`
+#[tauri::command]
+pub async fn unwatch_table(connection_id: String, table: String) -> AppResult<bool> {
+    Ok(change_feed::unwatch_table(&connection_id, &table))
+}
@@ -0,0 +1,22 @@
+use crate::error::AppResult;
+use crate::models::{PinnedObject, PinnedObjectType};
+use crate::storage;
+
+/// Pin a table, view or saved query to a connection's favorites, or unpin it
+/// with `pinned: false`. Returns the connection's pins.
+#[tauri::command]
+pub async fn pin_object(
+    connection_id: String,
+    object_type: PinnedObjectType,
+    name: String,
+    pinned: Option<bool>,
+) -> AppResult<Vec<PinnedObject>> {
+    storage::set_pinned(&connection_id, object_type, &name, pinned.unwrap_or(true))
+}
+
+/// The connection's pinned objects, in the order they were pinned
+#[tauri::command]
+pub async fn list_pinned(connection_id: String) -> AppResult<Vec<PinnedObject>> {
+    storage::list_pinned(&connection_id)
+}
+
@@ -1,11 +1,71 @@
-use crate::db::{get_connection_manager, get_driver};
+use crate::audit::{self, Change};
+use crate::blob;
+use crate::confirmation;
+use crate::connection_usage;
+use crate::db::{
+    column_condition, delete_statement, drop_dependents, get_connection_manager, get_driver, insert_statement, pgvector,
+    quote_identifier, quote_qualified, update_statement, upsert_statement, MongoDriver, PoolRef, QueryLimits,
+};
 use crate::error::{AppError, AppResult};
-use crate::models::{QueryRequest, QueryResult, TableInfo, TableSchema};
+use crate::federation::LocalDatabase;
+use crate::metrics;
+use crate::models::{
+    CellBlob, CellValue, ColumnInfo, Confirmable, ConfirmationRequired, DataQuestionAnswer, DataQuestionRequest,
+    DatabaseType, FederatedQueryRequest, FederatedQueryResult, FederatedSource, FederatedSourceInfo, PinnedObjectType,
+    PlanTable, QueryPlan, QueryRequest, QueryResult, ResultWindow, SqlFileError, SqlFileProgress, SqlFileRequest,
+    SqlFileSummary, TableInfo, TableSchema, VectorSearchRequest, WindowedQueryResult,
+};
+use crate::read_only;
+use crate::result_store;
+use crate::schema_cache;
+use crate::sql::fill_parameters;
 use crate::storage;
+use base64::{engine::general_purpose, Engine as _};
+use sql_parse::{Dialect, StatementSplitter};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::PathBuf;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
 
-/// Execute a SQL query against a connected database
+/// Rows returned for a data question when the request sets no cap
+const DEFAULT_QUESTION_ROWS: usize = 200;
+
+/// Rows returned by a vector search when the request sets no limit
+const DEFAULT_VECTOR_SEARCH_ROWS: u32 = 10;
+
+/// Largest binary cell sent to the frontend for previewing
+const MAX_BLOB_PREVIEW_BYTES: usize = 20 * 1024 * 1024;
+
+/// Most rows a data question can return
+const MAX_QUESTION_ROWS: usize = 5000;
+
+/// Rows copied from each source of a federated query when the request sets no cap
+const DEFAULT_FEDERATED_SOURCE_ROWS: usize = 100_000;
+
+/// Most rows copied from one source of a federated query
+const MAX_FEDERATED_SOURCE_ROWS: usize = 1_000_000;
+
+/// Most rows a federated query returns
+const MAX_FEDERATED_RESULT_ROWS: usize = 100_000;
+
+/// Bytes read from a SQL file at a time
+const SQL_FILE_CHUNK: usize = 64 * 1024;
+
+/// Statement errors returned in a SQL file summary; later failures are only counted
+const MAX_REPORTED_ERRORS: usize = 1000;
+
+/// Rows handed to the result store at a time while a windowed query is read
+const RESULT_BATCH_ROWS: usize = 1000;
+
+/// Execute a SQL query against a connected database, within the connection's
+/// statement timeout and row limit. UPDATE or DELETE without a WHERE clause
+/// only runs with a confirmation token; without one a token is issued instead.
+/// With a window size, the rows are kept in the backend and only the first
+/// window is returned.
 #[tauri::command]
-pub async fn execute_query(request: QueryRequest) -> Result<QueryResult, AppError> {
+pub async fn execute_query(request: QueryRequest) -> AppResult<Confirmable<WindowedQueryResult>> {
     let manager = get_connection_manager().read().await;
     
     // Verify connection exists
@@ -16,13 +76,50 @@ pub async fn execute_query(request: QueryRequest) -> Result<QueryResult, AppErro
     // Get config to determine driver type
     let config = storage::get_connection(&request.connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    // Fill in named placeholders first, so the checks below see the statement that runs
+    let statement = match &request.params {
+        None => request.sql.clone(),
+        Some(_) if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) => {
+            return Err(AppError::ValidationError("Query parameters are only supported for SQL databases".to_string()));
+        }
+        Some(values) => fill_parameters(&request.sql, &config.database_type, values)?,
+    };
+    read_only::check_query(&config, &statement)?;
     
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+
+    if !matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        let tables = sql_parse::unbounded_writes(&statement, Dialect::from(&config.database_type));
+        if !tables.is_empty() {
+            let action = format!("execute_query:{}:{}", request.connection_id, statement);
+            match &request.confirmation_token {
+                Some(token) => confirmation::consume(token, &action)?,
+                None => {
+                    let summary = format!("Change every row of {} (UPDATE or DELETE without WHERE)", tables.join(", "));
+                    let mut estimated_rows = None;
+                    for table in &tables {
+                        if let Ok(stats) = driver.get_table_stats(pool_ref, table).await {
+                            if let Some(rows) = stats.approximate_rows {
+                                estimated_rows = Some(estimated_rows.unwrap_or(0) + rows);
+                            }
+                        }
+                    }
+                    return Ok(Confirmable::ConfirmationRequired(ConfirmationRequired {
+                        table: tables.first().cloned(),
+                        estimated_rows,
+                        ..confirmation::request(action, summary)
+                    }));
+                }
+            }
+        }
+    }
     
-    // Apply limit/offset if provided
-    let mut sql = request.sql.clone();
-    if let Some(limit) = request.limit {
+    // Apply limit/offset if provided (MongoDB commands carry their own)
+    let mut sql = statement;
+    let is_mongo = matches!(config.database_type, DatabaseType::MongoDB);
+    if let Some(limit) = request.limit.filter(|_| !is_mongo) {
         if !sql.to_uppercase().contains("LIMIT") {
             sql.push_str(&format!(" LIMIT {}", limit));
             if let Some(offset) = request.offset {
@@ -31,10 +128,619 @@ pub async fn execute_query(request: QueryRequest) -> Result<QueryResult, AppErro
         }
     }
     
-    driver.execute_query(pool_ref, &sql).await
+    let limits = QueryLimits::for_connection(&config);
+    let session = match &request.session_id {
+        Some(name) => manager.session(&request.connection_id, name, &config).await?,
+        None => None,
+    };
+    // A windowed SELECT goes to the result store as it is read, so a large
+    // result never sits in memory whole. Scripts and row-limited results are
+    // read at once.
+    let window_size = request.window_size.map(|size| size.max(1) as usize);
+    let stream = window_size.is_some()
+        && limits.max_rows.is_none()
+        && !matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis)
+        && sql_parse::split_statements(&sql, Dialect::from(&config.database_type)).len() == 1
+        && sql_parse::returns_rows(&sql, Dialect::from(&config.database_type));
+    let mut writer = result_store::ResultWriter::new();
+    let runtime = tokio::runtime::Handle::current();
+    // The batch handler cannot await, so rows that go to the store's
+    // temporary file are written on this thread while the query waits
+    let mut keep = |columns: &[ColumnInfo], rows: Vec<Vec<serde_json::Value>>| {
+        tokio::task::block_in_place(|| runtime.block_on(writer.push(columns, rows)))
+    };
+
+    // The statement itself only at trace level, so logs shared in bug reports leave out the data
+    tracing::trace!(connection_id = %request.connection_id, sql = %sql, "Executing query");
+    let started = Instant::now();
+    // Columns and the first window of streamed rows come from the store below
+    let streamed = |_| QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: None,
+        execution_time_ms: started.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    };
+    let result = match session {
+        Some(session) => {
+            // Release the manager so connecting to other databases doesn't wait for the query
+            drop(manager);
+            let mut session = session.lock().await;
+            if stream {
+                session.stream(&sql, RESULT_BATCH_ROWS, &mut keep).await.map(streamed)
+            } else {
+                session.execute(&sql, &limits).await
+            }
+        }
+        None if stream => driver.stream_query(pool_ref, &sql, RESULT_BATCH_ROWS, &mut keep).await.map(streamed),
+        None => driver.execute_query_limited(pool_ref, &sql, &limits).await,
+    };
+    metrics::record_query(started.elapsed(), result.is_ok());
+
+    let mut stored = None;
+    let result = match result {
+        Ok(mut result) if stream => {
+            let total_rows = writer.total_rows();
+            result.columns = writer.columns().to_vec();
+            let result_id = writer.finish().await?;
+            result.rows = result_store::window(&result_id, 0, window_size.unwrap_or_default()).await?.rows;
+            stored = Some((result_id, total_rows));
+            Ok(result)
+        }
+        Err(e) if stream => {
+            writer.discard().await;
+            Err(e)
+        }
+        result => result,
+    };
+    match &result {
+        Ok(result) => tracing::debug!(
+            connection_id = %request.connection_id,
+            rows = stored.as_ref().map_or(result.rows.len(), |(_, total_rows)| *total_rows),
+            elapsed_ms = result.execution_time_ms,
+            "Query finished"
+        ),
+        Err(e) => tracing::warn!(connection_id = %request.connection_id, "Query failed: {}", e),
+    }
+
+    let mut result = result?;
+    if !matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        let dialect = Dialect::from(&config.database_type);
+        let tables = sql_parse::referenced_tables(&sql, dialect);
+        let columns = sql_parse::filtered_columns(&sql, dialect);
+        if let Err(e) = connection_usage::record_query(&request.connection_id, &tables, &columns) {
+            tracing::warn!(connection_id = %request.connection_id, "Failed to record connection usage: {}", e);
+        }
+    }
+    let (result_id, total_rows) = match (stored, window_size) {
+        (Some((result_id, total_rows)), _) => (Some(result_id), total_rows),
+        (None, Some(window_size)) if !result.columns.is_empty() => {
+            let total_rows = result.rows.len();
+            let rows = std::mem::take(&mut result.rows);
+            result.rows = rows[..window_size.min(total_rows)].to_vec();
+            (Some(result_store::store(result.columns.clone(), rows).await?), total_rows)
+        }
+        _ => (None, result.rows.len()),
+    };
+    Ok(Confirmable::Executed(WindowedQueryResult {
+        result,
+        result_id,
+        total_rows,
+    }))
+}
+
+/// Rows of a result kept by `execute_query`, from `offset` on
+#[tauri::command]
+pub async fn get_result_rows(result_id: String, offset: usize, count: usize) -> AppResult<ResultWindow> {
+    result_store::window(&result_id, offset, count).await
+}
+
+/// A binary cell of a kept result, with its sniffed content type, to preview
+#[tauri::command]
+pub async fn get_cell_blob(result_id: String, row: usize, column: usize) -> AppResult<CellBlob> {
+    let (info, value) = result_store::cell(&result_id, row, column).await?;
+    let bytes = blob::decode_cell(&value, &info)?;
+    let (content_type, extension) = blob::sniff(&bytes);
+    Ok(CellBlob {
+        content_type: content_type.to_string(),
+        extension: extension.to_string(),
+        size: bytes.len(),
+        data: (bytes.len() <= MAX_BLOB_PREVIEW_BYTES).then(|| general_purpose::STANDARD.encode(&bytes)),
+    })
+}
+
+/// Write a binary cell of a kept result to a file. Without a path it goes to
+/// the downloads folder, named after the column and row with the sniffed
+/// extension. Returns the path written.
+#[tauri::command]
+pub async fn save_blob_to_file(result_id: String, row: usize, column: usize, path: Option<String>) -> AppResult<String> {
+    let (info, value) = result_store::cell(&result_id, row, column).await?;
+    let bytes = blob::decode_cell(&value, &info)?;
+    let path = match path {
+        Some(path) => PathBuf::from(path),
+        None => {
+            let dir = dirs::download_dir()
+                .or_else(dirs::home_dir)
+                .ok_or_else(|| AppError::ConfigError("No downloads folder found".to_string()))?;
+            let (_, extension) = blob::sniff(&bytes);
+            let stem: String = info
+                .name
+                .chars()
+                .map(|c| if c.is_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
+                .collect();
+            let mut path = dir.join(format!("{}-{}.{}", stem, row + 1, extension));
+            let mut copy = 2;
+            while path.exists() {
+                path = dir.join(format!("{}-{} ({}).{}", stem, row + 1, copy, extension));
+                copy += 1;
+            }
+            path
+        }
+    };
+    std::fs::write(&path, bytes)?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Drop a kept result once its grid is closed or shows another result
+#[tauri::command]
+pub async fn release_result(result_id: String) -> AppResult<bool> {
+    Ok(result_store::release(&result_id).await)
+}
+
+/// Rows of a federated query's source, with at most `max_rows + 1` read so a cut can be told
+async fn federated_source_rows(
+    source: &FederatedSource,
+    max_rows: usize,
+) -> AppResult<(Vec<ColumnInfo>, Vec<Vec<serde_json::Value>>)> {
+    if let Some(result_id) = &source.result_id {
+        if source.connection_id.is_some() {
+            return Err(AppError::ValidationError(format!(
+                "{} takes either a kept result or a connection, not both",
+                source.alias
+            )));
+        }
+        return Ok((result_store::columns(result_id).await?, result_store::all_rows(result_id).await?));
+    }
+
+    let connection_id = source.connection_id.as_deref().ok_or_else(|| {
+        AppError::ValidationError(format!("{} needs a kept result or a connection", source.alias))
+    })?;
+    let manager = get_connection_manager().read().await;
+    if !manager.is_connected(connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+    let config = storage::get_connection(connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError("Federated queries read from SQL databases only".to_string()));
+    }
+
+    let sql = match (&source.table_name, &source.sql) {
+        (Some(table_name), None) => format!("SELECT * FROM {}", quote_qualified(&config.database_type, table_name)),
+        (None, Some(sql)) => {
+            let dialect = Dialect::from(&config.database_type);
+            if sql_parse::split_statements(sql, dialect).len() != 1 || !sql_parse::is_read_only(sql, dialect) {
+                return Err(AppError::ValidationError(format!(
+                    "The query for {} must be a single read-only statement",
+                    source.alias
+                )));
+            }
+            sql.clone()
+        }
+        _ => {
+            return Err(AppError::ValidationError(format!(
+                "{} takes either a table or a query",
+                source.alias
+            )))
+        }
+    };
+
+    let mut limits = QueryLimits::for_connection(&config);
+    limits.max_rows = Some(max_rows + 1);
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(connection_id)?;
+    let result = driver.execute_query_limited(pool_ref, &sql, &limits).await?;
+    Ok((result.columns, result.rows))
+}
+
+/// Join rows from several connections or kept results. The sources are copied
+/// into an in-memory SQLite database on this machine and the query runs there,
+/// on a snapshot; the connections are only read from.
+#[tauri::command]
+pub async fn run_federated_query(request: FederatedQueryRequest) -> AppResult<FederatedQueryResult> {
+    if request.sources.is_empty() {
+        return Err(AppError::ValidationError("A federated query needs at least one source".to_string()));
+    }
+    let max_source_rows = request
+        .max_source_rows
+        .map_or(DEFAULT_FEDERATED_SOURCE_ROWS, |rows| rows as usize)
+        .clamp(1, MAX_FEDERATED_SOURCE_ROWS);
+
+    let mut local = LocalDatabase::open().await?;
+    let result = async {
+        let mut sources = Vec::with_capacity(request.sources.len());
+        for source in &request.sources {
+            let (columns, mut rows) = federated_source_rows(source, max_source_rows).await?;
+            let truncated = rows.len() > max_source_rows;
+            rows.truncate(max_source_rows);
+            local.load(&source.alias, &columns, &rows).await?;
+            sources.push(FederatedSourceInfo {
+                alias: source.alias.clone(),
+                rows: rows.len(),
+                truncated,
+            });
+        }
+
+        let limits = QueryLimits {
+            statement_timeout_ms: None,
+            max_rows: Some(MAX_FEDERATED_RESULT_ROWS),
+        };
+        let result = local.query(&request.sql, &limits).await?;
+        Ok(FederatedQueryResult {
+            result,
+            sources,
+            engine: local.engine().await,
+        })
+    }
+    .await;
+    local.close().await;
+    result
+}
+
+/// Run a query the AI assistant wrote to answer a question. Only a single
+/// read-only statement is accepted, whatever the connection's own settings;
+/// rows are capped and the question and query go into the audit log.
+#[tauri::command]
+pub async fn ask_data_question(request: DataQuestionRequest) -> AppResult<DataQuestionAnswer> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError("Data questions need a SQL database".to_string()));
+    }
+
+    let dialect = Dialect::from(&config.database_type);
+    let statements = sql_parse::split_statements(&request.sql, dialect);
+    if statements.len() != 1 {
+        return Err(AppError::ValidationError(format!(
+            "Expected a single query, got {} statements; nothing was run",
+            statements.len()
+        )));
+    }
+    if !sql_parse::is_read_only(&request.sql, dialect) {
+        return Err(AppError::ValidationError("The query could change data; it was not run".to_string()));
+    }
+
+    let mut limits = QueryLimits::for_connection(&config);
+    let max_rows = request
+        .max_rows
+        .map_or(DEFAULT_QUESTION_ROWS, |rows| rows as usize)
+        .min(limits.max_rows.unwrap_or(MAX_QUESTION_ROWS))
+        .clamp(1, MAX_QUESTION_ROWS);
+    // Fetch one extra row to tell whether the result was cut short
+    limits.max_rows = Some(max_rows + 1);
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+    let result = driver.execute_read_only(pool_ref, &request.sql, &limits).await;
+    let change = Change {
+        operation: "ask_data_question",
+        table: None,
+        statement: request.sql.clone(),
+        params: vec![serde_json::Value::String(request.question.clone())],
+    };
+    audit::record(&config, change, &result);
+
+    let mut result = result?;
+    let truncated = result.rows.len() > max_rows;
+    QueryLimits { max_rows: Some(max_rows), ..limits }.cap_rows(&mut result);
+    Ok(DataQuestionAnswer {
+        question: request.question,
+        sql: request.sql,
+        result,
+        truncated,
+    })
+}
+
+/// Rows nearest a vector by a pgvector column, with their distance in a
+/// `distance` column. Ordering by the operator lets an HNSW or IVFFlat index
+/// serve the query.
+#[tauri::command]
+pub async fn vector_search(request: VectorSearchRequest) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if !matches!(config.database_type, DatabaseType::PostgreSQL) {
+        return Err(AppError::ValidationError("Vector search needs a PostgreSQL connection with pgvector".to_string()));
+    }
+
+    let distance = format!(
+        "{} {} {}",
+        quote_identifier(&config.database_type, &request.column),
+        pgvector::operator(request.metric),
+        pgvector::literal(&request.vector)?
+    );
+    let sql = format!(
+        "SELECT *, {} AS distance FROM {} ORDER BY {} LIMIT {}",
+        distance,
+        quote_qualified(&config.database_type, &request.table_name),
+        distance,
+        request.limit.unwrap_or(DEFAULT_VECTOR_SEARCH_ROWS).max(1)
+    );
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+    driver
+        .execute_query_limited(pool_ref, &sql, &QueryLimits::for_connection(&config))
+        .await
 }
 
-/// Get list of tables in the connected database
+/// Get a query's execution plan and the statistics and indexes of the tables
+/// it uses. With `analyze`, PostgreSQL, MySQL and DuckDB run the query to report
+/// actual rows and timings; only read-only queries are run, others get the
+/// estimated plan.
+#[tauri::command]
+pub async fn explain_query(connection_id: String, sql: String, analyze: bool) -> AppResult<QueryPlan> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let dialect = Dialect::from(&config.database_type);
+    let statements = sql_parse::split_statements(&sql, dialect);
+    if statements.len() != 1 {
+        return Err(AppError::ValidationError(format!(
+            "Expected a single query, got {} statements",
+            statements.len()
+        )));
+    }
+    let statement = statements[0].trim().trim_end_matches(';');
+    let mut analyze = analyze
+        && matches!(config.database_type, DatabaseType::PostgreSQL | DatabaseType::MySQL | DatabaseType::DuckDB)
+        && sql_parse::is_read_only(&sql, dialect);
+    let explain = explain_sql(&config.database_type, statement, analyze).ok_or_else(|| {
+        AppError::ValidationError(format!("Execution plans are not supported for {:?}", config.database_type))
+    })?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    let result = match driver.execute_query(pool_ref, &explain).await {
+        // MariaDB and MySQL before 8.0.18 have neither FORMAT=TREE nor EXPLAIN ANALYZE
+        Err(_) if matches!(config.database_type, DatabaseType::MySQL) => {
+            analyze = false;
+            driver.execute_query(pool_ref, &format!("EXPLAIN {}", statement)).await?
+        }
+        result => result?,
+    };
+
+    let mut tables = Vec::new();
+    for name in sql_parse::referenced_tables(statement, dialect) {
+        let stats = driver.get_table_stats(pool_ref, &name).await.ok();
+        let indexes = driver.get_indexes(pool_ref, &name).await.unwrap_or_default();
+        tables.push(PlanTable { name, stats, indexes });
+    }
+
+    Ok(QueryPlan {
+        plan: plan_text(&result),
+        sql,
+        analyzed: analyze,
+        tables,
+    })
+}
+
+/// EXPLAIN statement for a query; None for databases whose plans cannot be
+/// read back as rows
+fn explain_sql(database_type: &DatabaseType, sql: &str, analyze: bool) -> Option<String> {
+    match database_type {
+        DatabaseType::PostgreSQL if analyze => Some(format!("EXPLAIN (ANALYZE, BUFFERS) {}", sql)),
+        DatabaseType::PostgreSQL => Some(format!("EXPLAIN {}", sql)),
+        DatabaseType::MySQL if analyze => Some(format!("EXPLAIN ANALYZE {}", sql)),
+        DatabaseType::MySQL => Some(format!("EXPLAIN FORMAT=TREE {}", sql)),
+        DatabaseType::SQLite => Some(format!("EXPLAIN QUERY PLAN {}", sql)),
+        DatabaseType::ClickHouse => Some(format!("EXPLAIN indexes = 1 {}", sql)),
+        DatabaseType::DuckDB if analyze => Some(format!("EXPLAIN ANALYZE {}", sql)),
+        DatabaseType::DuckDB => Some(format!("EXPLAIN {}", sql)),
+        // Oracle writes the plan to PLAN_TABLE, and DBMS_XPLAN formats it as rows
+        DatabaseType::Oracle => Some(format!(
+            "EXPLAIN PLAN FOR {};\nSELECT plan_table_output FROM TABLE(DBMS_XPLAN.DISPLAY())",
+            sql
+        )),
+        // SQL Server returns plans through SET SHOWPLAN_XML, which needs a batch of its own
+        DatabaseType::MSSQL | DatabaseType::MongoDB | DatabaseType::Redis => None,
+    }
+}
+
+/// Plan rows as text: the value itself for single-column plans, otherwise
+/// `column=value` pairs, one row per line
+fn plan_text(result: &QueryResult) -> String {
+    let cell = |value: &serde_json::Value| match value {
+        serde_json::Value::String(text) => text.clone(),
+        value => value.to_string(),
+    };
+    result
+        .rows
+        .iter()
+        .map(|row| match row.as_slice() {
+            [value] => cell(value),
+            values => result
+                .columns
+                .iter()
+                .zip(values)
+                .map(|(column, value)| format!("{}={}", column.name, cell(value)))
+                .collect::<Vec<_>>()
+                .join(" "),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Close an editor tab's session. Its connection is closed once any query still
+/// running on it finishes, discarding its variables, temporary tables and open
+/// transaction.
+#[tauri::command]
+pub async fn close_session(connection_id: String, session_id: String) -> AppResult<()> {
+    get_connection_manager().read().await.close_session(&connection_id, &session_id);
+    Ok(())
+}
+
+/// Execute a .sql file (e.g. a dump) statement by statement, streaming it from disk
+#[tauri::command]
+pub async fn run_sql_file(app: AppHandle, request: SqlFileRequest) -> AppResult<SqlFileSummary> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError("SQL files can only be run against SQL databases".to_string()));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+    // One connection for the whole file, so SET, USE, transactions and table
+    // locks carry over from one statement to the next
+    let mut session = manager.dedicated_session(&request.connection_id, &config).await?;
+
+    let start = Instant::now();
+    let continue_on_error = request.continue_on_error.unwrap_or(false);
+    let batch_size = request.batch_size.unwrap_or(100).max(1) as usize;
+
+    let mut file = File::open(&request.path)?;
+    let total_bytes = file.metadata()?.len();
+    let mut buffer = vec![0u8; SQL_FILE_CHUNK];
+    // Bytes not yet decoded, e.g. a UTF-8 sequence split across reads
+    let mut pending: Vec<u8> = Vec::new();
+    let dialect = Dialect::from(&config.database_type);
+    let mut splitter = StatementSplitter::new(dialect);
+
+    let mut bytes_read = 0u64;
+    let mut statement_index = 0;
+    let mut statements_executed = 0;
+    let mut statements_failed = 0;
+    let mut errors: Vec<SqlFileError> = Vec::new();
+    let mut stopped = false;
+    let mut since_progress = 0;
+
+    'read: loop {
+        let n = file.read(&mut buffer)?;
+
+        let statements = if n == 0 {
+            if !pending.is_empty() {
+                return Err(AppError::ValidationError("SQL file is not valid UTF-8".to_string()));
+            }
+            std::mem::replace(&mut splitter, StatementSplitter::new(dialect)).finish()
+        } else {
+            if bytes_read == 0 && buffer[..n].starts_with(b"\xEF\xBB\xBF") {
+                pending.extend_from_slice(&buffer[3..n]);
+            } else {
+                pending.extend_from_slice(&buffer[..n]);
+            }
+            bytes_read += n as u64;
+
+            let valid = match std::str::from_utf8(&pending) {
+                Ok(text) => text.len(),
+                Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                Err(_) => return Err(AppError::ValidationError("SQL file is not valid UTF-8".to_string())),
+            };
+            let text = std::str::from_utf8(&pending[..valid])
+                .map_err(|_| AppError::ValidationError("SQL file is not valid UTF-8".to_string()))?;
+            let statements = splitter.push(text);
+            pending.drain(..valid);
+            statements
+        };
+
+        for statement in statements {
+            statement_index += 1;
+            since_progress += 1;
+
+            let result = match session.as_mut() {
+                Some(session) => session.execute(&statement, &QueryLimits::default()).await,
+                None => driver.execute_query(pool_ref, &statement).await,
+            };
+            match result {
+                Ok(_) => statements_executed += 1,
+                Err(e) => {
+                    statements_failed += 1;
+                    if errors.len() < MAX_REPORTED_ERRORS {
+                        errors.push(SqlFileError {
+                            statement_index,
+                            statement: statement.chars().take(200).collect(),
+                            message: e.to_string(),
+                        });
+                    }
+                    if !continue_on_error {
+                        stopped = true;
+                        break 'read;
+                    }
+                }
+            }
+
+            if since_progress >= batch_size {
+                since_progress = 0;
+                let _ = app.emit(
+                    "sql-file-progress",
+                    SqlFileProgress {
+                        path: request.path.clone(),
+                        bytes_read,
+                        total_bytes,
+                        statements_executed,
+                        statements_failed,
+                    },
+                );
+            }
+        }
+
+        if n == 0 {
+            break;
+        }
+    }
+
+    let _ = app.emit(
+        "sql-file-progress",
+        SqlFileProgress {
+            path: request.path.clone(),
+            bytes_read,
+            total_bytes,
+            statements_executed,
+            statements_failed,
+        },
+    );
+
+    Ok(SqlFileSummary {
+        path: request.path,
+        statements_executed,
+        statements_failed,
+        errors,
+        stopped,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Get list of tables in the connected database, marking the ones pinned to
+/// the connection's favorites
 #[tauri::command]
 pub async fn get_tables(connection_id: String) -> AppResult<Vec<TableInfo>> {
     let manager = get_connection_manager().read().await;
@@ -50,7 +756,54 @@ pub async fn get_tables(connection_id: String) -> AppResult<Vec<TableInfo>> {
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
     
-    driver.get_tables(pool_ref, &config).await
+    let mut tables = driver.get_tables(pool_ref, &config).await?;
+    let pinned: HashSet<String> = storage::list_pinned(&connection_id)?
+        .into_iter()
+        .filter(|pin| pin.object_type != PinnedObjectType::Query)
+        .map(|pin| pin.name)
+        .collect();
+    for table in &mut tables {
+        table.pinned = pinned.contains(&table.name);
+    }
+    Ok(tables)
+}
+
+/// List databases on the connected server
+#[tauri::command]
+pub async fn list_databases(connection_id: String) -> AppResult<Vec<String>> {
+    let manager = get_connection_manager().read().await;
+    
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+    
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    
+    driver.list_databases(pool_ref).await
+}
+
+/// List schemas in the connected database
+#[tauri::command]
+pub async fn list_schemas(connection_id: String) -> AppResult<Vec<String>> {
+    let manager = get_connection_manager().read().await;
+    
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+    
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    
+    driver.list_schemas(pool_ref).await
 }
 
 /// Get schema information for a specific table
@@ -93,7 +846,9 @@ pub async fn get_all_table_schemas(
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
     
-    driver.get_all_table_schemas(pool_ref, &config).await
+    let tables = driver.get_all_table_schemas(pool_ref, &config).await?;
+    schema_cache::store(&connection_id, tables.clone());
+    Ok(tables)
 }
 
 /// Insert a new row into a table
@@ -101,7 +856,7 @@ pub async fn get_all_table_schemas(
 pub async fn insert_row(
     connection_id: String,
     table_name: String,
-    values: std::collections::HashMap<String, serde_json::Value>,
+    values: std::collections::HashMap<String, CellValue>,
 ) -> AppResult<QueryResult> {
     let manager = get_connection_manager().read().await;
     
@@ -112,32 +867,103 @@ pub async fn insert_row(
     
     let config = storage::get_connection(&connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
     
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
     
-    // Build INSERT statement
-    let columns: Vec<String> = values.keys().cloned().collect();
-    
-    // For now, execute as a simple query - in production, use parameterized queries
-    let values_str: Vec<String> = values.values().map(|v| {
-        match v {
-            serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "NULL".to_string(),
-            _ => format!("'{}'", v.to_string().replace("'", "''")),
-        }
-    }).collect();
+    if let PoolRef::Mongo(database) = pool_ref {
+        let values = plain_values(values);
+        let document = serde_json::Value::Object(values.clone().into_iter().collect());
+        let result = MongoDriver::insert_document(database, &table_name, values).await;
+        let change = Change {
+            operation: "insert_row",
+            table: Some(&table_name),
+            statement: format!("Insert a document into {}", table_name),
+            params: vec![document],
+        };
+        audit::record(&config, change, &result);
+        return result;
+    }
+    
+    // Build INSERT statement with one placeholder per value
+    let (columns, params): (Vec<String>, Vec<CellValue>) = values.into_iter().unzip();
+    let sql = insert_statement(&config.database_type, &table_name, &columns);
+    
+    let result = driver.execute_with_params(pool_ref, &sql, params.clone()).await;
+    let change = Change {
+        operation: "insert_row",
+        table: Some(&table_name),
+        statement: sql,
+        params: params.iter().map(CellValue::to_json).collect(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Insert rows, updating the existing row instead where one has the same key. The key is
+/// the primary key, or else the first unique index, whose columns a row gives values for.
+#[tauri::command]
+pub async fn upsert_rows(
+    connection_id: String,
+    table_name: String,
+    rows: Vec<std::collections::HashMap<String, CellValue>>,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+    
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
     
-    let sql_with_values = format!(
-        "INSERT INTO {} ({}) VALUES ({})",
-        table_name,
-        columns.join(", "),
-        values_str.join(", ")
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+    
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    
+    // Keys a row can be matched on, the primary key first
+    let mut keys = vec![driver.get_table_schema(pool_ref, &table_name).await?.primary_keys];
+    keys.extend(
+        driver.get_indexes(pool_ref, &table_name).await?
+            .into_iter()
+            .filter(|index| index.is_unique || index.is_primary)
+            .map(|index| index.columns),
     );
+    keys.retain(|key| !key.is_empty());
+    
+    let start = Instant::now();
+    let mut affected_rows = 0;
+    for row in rows {
+        let (columns, params): (Vec<String>, Vec<CellValue>) = row.into_iter().unzip();
+        let key = keys.iter()
+            .find(|key| key.iter().all(|column| columns.contains(column)))
+            .ok_or_else(|| AppError::ValidationError(format!(
+                "Rows upserted into {} need values for its primary key or a unique index",
+                table_name
+            )))?;
+        let sql = upsert_statement(&config.database_type, &table_name, &columns, key)
+            .ok_or_else(|| AppError::ValidationError(format!("Upserts are not supported for {:?}", config.database_type)))?;
+    
+        let result = driver.execute_with_params(pool_ref, &sql, params.clone()).await;
+        let change = Change {
+            operation: "upsert_rows",
+            table: Some(&table_name),
+            statement: sql,
+            params: params.iter().map(CellValue::to_json).collect(),
+        };
+        audit::record(&config, change, &result);
+        affected_rows += result?.affected_rows.unwrap_or(0);
+    }
     
-    driver.execute_query(pool_ref, &sql_with_values).await
+    Ok(QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: Some(affected_rows),
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    })
 }
 
 /// Update a row in a table
@@ -145,8 +971,8 @@ pub async fn insert_row(
 pub async fn update_row(
     connection_id: String,
     table_name: String,
-    primary_key: std::collections::HashMap<String, serde_json::Value>,
-    values: std::collections::HashMap<String, serde_json::Value>,
+    primary_key: std::collections::HashMap<String, CellValue>,
+    values: std::collections::HashMap<String, CellValue>,
 ) -> AppResult<QueryResult> {
     let manager = get_connection_manager().read().await;
     
@@ -157,41 +983,43 @@ pub async fn update_row(
     
     let config = storage::get_connection(&connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
     
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
     
-    // Build UPDATE statement with WHERE clause from primary key
-    let set_clauses: Vec<String> = values.iter().map(|(k, v)| {
-        let value_str = match v {
-            serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "NULL".to_string(),
-            _ => format!("'{}'", v.to_string().replace("'", "''")),
-        };
-        format!("{} = {}", k, value_str)
-    }).collect();
-    
-    let where_clauses: Vec<String> = primary_key.iter().map(|(k, v)| {
-        let value_str = match v {
-            serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "NULL".to_string(),
-            _ => format!("'{}'", v.to_string().replace("'", "''")),
+    // Documents are matched by key paths rather than a WHERE clause
+    if let PoolRef::Mongo(database) = pool_ref {
+        let (primary_key, values) = (plain_values(primary_key), plain_values(values));
+        let params = vec![
+            serde_json::Value::Object(primary_key.clone().into_iter().collect()),
+            serde_json::Value::Object(values.clone().into_iter().collect()),
+        ];
+        let result = MongoDriver::update_document(database, &table_name, primary_key, values).await;
+        let change = Change {
+            operation: "update_row",
+            table: Some(&table_name),
+            statement: format!("Update a document in {}", table_name),
+            params,
         };
-        format!("{} = {}", k, value_str)
-    }).collect();
+        audit::record(&config, change, &result);
+        return result;
+    }
     
-    let sql = format!(
-        "UPDATE {} SET {} WHERE {}",
-        table_name,
-        set_clauses.join(", "),
-        where_clauses.join(" AND ")
-    );
+    // Build UPDATE statement with WHERE clause from primary key
+    let (columns, mut params): (Vec<String>, Vec<CellValue>) = values.into_iter().unzip();
+    let where_clauses = key_conditions(&config.database_type, primary_key, &mut params);
+    let sql = update_statement(&config.database_type, &table_name, &columns, &where_clauses);
     
-    driver.execute_query(pool_ref, &sql).await
+    let result = driver.execute_with_params(pool_ref, &sql, params.clone()).await;
+    let change = Change {
+        operation: "update_row",
+        table: Some(&table_name),
+        statement: sql,
+        params: params.iter().map(CellValue::to_json).collect(),
+    };
+    audit::record(&config, change, &result);
+    result
 }
 
 /// Delete a row from a table
@@ -199,7 +1027,7 @@ pub async fn update_row(
 pub async fn delete_row(
     connection_id: String,
     table_name: String,
-    primary_key: std::collections::HashMap<String, serde_json::Value>,
+    primary_key: std::collections::HashMap<String, CellValue>,
 ) -> AppResult<QueryResult> {
     let manager = get_connection_manager().read().await;
     
@@ -210,37 +1038,49 @@ pub async fn delete_row(
     
     let config = storage::get_connection(&connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
     
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
     
-    // Build DELETE statement with WHERE clause from primary key
-    let where_clauses: Vec<String> = primary_key.iter().map(|(k, v)| {
-        let value_str = match v {
-            serde_json::Value::String(s) => format!("'{}'", s.replace("'", "''")),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "NULL".to_string(),
-            _ => format!("'{}'", v.to_string().replace("'", "''")),
+    if let PoolRef::Mongo(database) = pool_ref {
+        let primary_key = plain_values(primary_key);
+        let key = serde_json::Value::Object(primary_key.clone().into_iter().collect());
+        let result = MongoDriver::delete_document(database, &table_name, primary_key).await;
+        let change = Change {
+            operation: "delete_row",
+            table: Some(&table_name),
+            statement: format!("Delete a document from {}", table_name),
+            params: vec![key],
         };
-        format!("{} = {}", k, value_str)
-    }).collect();
+        audit::record(&config, change, &result);
+        return result;
+    }
     
-    let sql = format!(
-        "DELETE FROM {} WHERE {}",
-        table_name,
-        where_clauses.join(" AND ")
-    );
+    // Build DELETE statement with WHERE clause from primary key
+    let mut params = Vec::with_capacity(primary_key.len());
+    let where_clauses = key_conditions(&config.database_type, primary_key, &mut params);
+    let sql = delete_statement(&config.database_type, &table_name, &where_clauses);
     
-    driver.execute_query(pool_ref, &sql).await
+    let result = driver.execute_with_params(pool_ref, &sql, params.clone()).await;
+    let change = Change {
+        operation: "delete_row",
+        table: Some(&table_name),
+        statement: sql,
+        params: params.iter().map(CellValue::to_json).collect(),
+    };
+    audit::record(&config, change, &result);
+    result
 }
 
-/// Drop a table from the database
+/// Drop a table from the database. Without `confirmation_token` nothing is
+/// dropped; a token is issued along with the table's estimated row count.
 #[tauri::command]
 pub async fn drop_table(
     connection_id: String,
     table_name: String,
-) -> AppResult<QueryResult> {
+    confirmation_token: Option<String>,
+) -> AppResult<Confirmable<QueryResult>> {
     let manager = get_connection_manager().read().await;
     
     // Verify connection exists
@@ -250,12 +1090,76 @@ pub async fn drop_table(
     
     let config = storage::get_connection(&connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
     
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    // Views and foreign keys that need the table; the token covers exactly these,
+    // so a cascade never removes anything the user was not shown
+    let dependencies = driver.get_object_dependencies(pool_ref).await.unwrap_or_default();
+    let dependents = drop_dependents(&dependencies, &table_name);
+    let action = format!("drop_table:{}:{}:{}", connection_id, table_name, dependents.join(","));
+    let token = match confirmation_token {
+        Some(token) => token,
+        None => {
+            let stats = driver.get_table_stats(pool_ref, &table_name).await.ok();
+            let estimated_rows = stats.and_then(|s| s.approximate_rows);
+            let summary = format!("Drop table {} and all of its rows", table_name);
+            return Ok(Confirmable::ConfirmationRequired(ConfirmationRequired {
+                table: Some(table_name),
+                estimated_rows,
+                dependents,
+                ..confirmation::request(action, summary)
+            }));
+        }
+    };
+    confirmation::consume(&token, &action)?;
     
-    let sql = format!("DROP TABLE {}", table_name);
-    
-    driver.execute_query(pool_ref, &sql).await
+    let (statement, result) = match pool_ref {
+        PoolRef::Mongo(database) => (
+            format!("Drop collection {}", table_name),
+            MongoDriver::drop_collection(database, &table_name).await,
+        ),
+        _ => {
+            // PostgreSQL refuses to drop a table others depend on unless told to take them along
+            let cascade = !dependents.is_empty() && matches!(config.database_type, DatabaseType::PostgreSQL);
+            let sql = format!(
+                "DROP TABLE {}{}",
+                quote_qualified(&config.database_type, &table_name),
+                if cascade { " CASCADE" } else { "" }
+            );
+            let result = driver.execute_query(pool_ref, &sql).await;
+            (sql, result)
+        }
+    };
+    let change = Change {
+        operation: "drop_table",
+        table: Some(&table_name),
+        statement,
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result.map(Confirmable::Executed)
 }
 
+
+/// Document databases take values as plain JSON
+fn plain_values(values: std::collections::HashMap<String, CellValue>) -> std::collections::HashMap<String, serde_json::Value> {
+    values.into_iter().map(|(name, value)| (name, value.to_json())).collect()
+}
+
+/// Build `column = placeholder` conditions for a primary key, pushing the bound values onto `params`
+fn key_conditions(
+    database_type: &DatabaseType,
+    primary_key: std::collections::HashMap<String, CellValue>,
+    params: &mut Vec<CellValue>,
+) -> Vec<String> {
+    primary_key.into_iter().map(|(k, v)| {
+        if v.is_null() {
+            return column_condition(database_type, &k, None);
+        }
+        params.push(v);
+        column_condition(database_type, &k, Some(params.len()))
+    }).collect()
+}
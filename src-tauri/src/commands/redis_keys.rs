@@ -0,0 +1,87 @@
+use crate::db::{get_connection_manager, PoolRef, RedisDriver};
+use crate::error::{AppError, AppResult};
+use crate::models::{RedisScanResult, RedisValue};
+use crate::read_only;
+use crate::storage;
+
+/// Scan keys matching a glob pattern
+#[tauri::command]
+pub async fn redis_scan_keys(
+    connection_id: String,
+    pattern: Option<String>,
+    cursor: Option<u64>,
+    count: Option<u32>,
+) -> AppResult<RedisScanResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    match manager.get_pool_ref(&connection_id)? {
+        PoolRef::Redis(conn) => RedisDriver::scan_keys(conn, pattern.as_deref(), cursor.unwrap_or(0), count).await,
+        _ => Err(AppError::QueryError("Not a Redis connection".to_string())),
+    }
+}
+
+/// Get a key's value, type and TTL
+#[tauri::command]
+pub async fn redis_get_value(connection_id: String, key: String) -> AppResult<RedisValue> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    match manager.get_pool_ref(&connection_id)? {
+        PoolRef::Redis(conn) => RedisDriver::get_value(conn, &key).await,
+        _ => Err(AppError::QueryError("Not a Redis connection".to_string())),
+    }
+}
+
+/// Set a string key, optionally with an expiry
+#[tauri::command]
+pub async fn redis_set_value(
+    connection_id: String,
+    key: String,
+    value: String,
+    ttl_seconds: Option<u64>,
+) -> AppResult<bool> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    match manager.get_pool_ref(&connection_id)? {
+        PoolRef::Redis(conn) => RedisDriver::set_value(conn, &key, &value, ttl_seconds).await.map(|_| true),
+        _ => Err(AppError::QueryError("Not a Redis connection".to_string())),
+    }
+}
+
+/// Delete keys, returning how many were removed
+#[tauri::command]
+pub async fn redis_delete_keys(connection_id: String, keys: Vec<String>) -> AppResult<u64> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    match manager.get_pool_ref(&connection_id)? {
+        PoolRef::Redis(conn) => RedisDriver::delete_keys(conn, &keys).await,
+        _ => Err(AppError::QueryError("Not a Redis connection".to_string())),
+    }
+}
@@ -0,0 +1,43 @@
+use crate::error::AppResult;
+use crate::models::{CellValue, SavedQuery};
+use crate::storage;
+use std::collections::HashMap;
+
+/// Every saved query with its variables and per-connection presets
+#[tauri::command]
+pub async fn list_saved_queries() -> AppResult<Vec<SavedQuery>> {
+    storage::load_saved_queries()
+}
+
+/// Save a query, declaring any placeholders its SQL uses that it does not list yet
+#[tauri::command]
+pub async fn save_saved_query(query: SavedQuery) -> AppResult<SavedQuery> {
+    storage::save_saved_query(query)
+}
+
+#[tauri::command]
+pub async fn delete_saved_query(query_id: String) -> AppResult<()> {
+    storage::delete_saved_query(&query_id)?;
+    storage::unpin_saved_query(&query_id)
+}
+
+/// Keep the values a connection runs the query with; empty values remove the preset
+#[tauri::command]
+pub async fn set_saved_query_preset(
+    query_id: String,
+    connection_id: String,
+    values: HashMap<String, String>,
+) -> AppResult<SavedQuery> {
+    storage::set_saved_query_preset(&query_id, &connection_id, values)
+}
+
+/// Typed values for the query's variables on a connection, to pass as the
+/// `params` of `execute_query`. Given values win over the preset and defaults.
+#[tauri::command]
+pub async fn get_saved_query_params(
+    query_id: String,
+    connection_id: Option<String>,
+    values: Option<HashMap<String, String>>,
+) -> AppResult<HashMap<String, CellValue>> {
+    storage::saved_query_params(&query_id, connection_id.as_deref(), &values.unwrap_or_default())
+}
@@ -0,0 +1,808 @@
+use crate::db::{
+    get_connection_manager, get_driver, placeholder, quote_identifier, quote_qualified, DatabaseDriver, PoolRef,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    CellValue, ConnectionConfig, DataMatch, DataMatchRow, DataSearchOptions, DataSearchSummary, DatabaseType,
+    ObjectMatch, ObjectSearchResult, TableDataMatches,
+};
+use crate::storage;
+use crate::transfer::{self, ColumnKind};
+use futures_util::stream::{self, StreamExt};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+
+/// Matches returned per group of an object search
+const MAX_OBJECT_MATCHES: usize = 200;
+
+/// Longest line of a definition or comment returned with a match
+const MAX_DETAIL_CHARS: usize = 160;
+
+const DEFAULT_ROWS_PER_TABLE: u32 = 50;
+
+const MAX_ROWS_PER_TABLE: u32 = 1000;
+
+const DEFAULT_CONCURRENCY: u32 = 4;
+
+const MAX_CONCURRENCY: u32 = 8;
+
+/// Longest cell text returned with a data match
+const MAX_VALUE_CHARS: usize = 200;
+
+#[derive(Clone, Copy)]
+enum ObjectGroup {
+    Tables,
+    Columns,
+    Views,
+    Routines,
+    Comments,
+}
+
+/// Catalog queries for an object search, by the group their matches go to.
+/// Each returns schema, object name, object type, column and detail, and takes
+/// the lowercased pattern once, as `s.term`.
+fn object_queries(config: &ConnectionConfig) -> Vec<(ObjectGroup, String)> {
+    let database_type = &config.database_type;
+    let from = if matches!(database_type, DatabaseType::Oracle) { " FROM dual" } else { "" };
+    let term = format!("(SELECT LOWER({}) AS term{}) s", placeholder(database_type, 1), from);
+    let queries: Vec<(ObjectGroup, &str)> = match database_type {
+        DatabaseType::PostgreSQL => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT n.nspname::text, c.relname::text, {relkind}, NULL::text, NULL::text
+                 FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace CROSS JOIN {term}
+                 WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f') AND {schemas}
+                   AND strpos(lower(c.relname), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT n.nspname::text, c.relname::text, {relkind}, a.attname::text,
+                        format_type(a.atttypid, a.atttypmod)
+                 FROM pg_attribute a
+                 JOIN pg_class c ON c.oid = a.attrelid
+                 JOIN pg_namespace n ON n.oid = c.relnamespace
+                 CROSS JOIN {term}
+                 WHERE a.attnum > 0 AND NOT a.attisdropped AND c.relkind IN ('r', 'p', 'v', 'm', 'f') AND {schemas}
+                   AND strpos(lower(a.attname), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT n.nspname::text, c.relname::text, {relkind}, NULL::text, pg_get_viewdef(c.oid, true)
+                 FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace CROSS JOIN {term}
+                 WHERE c.relkind IN ('v', 'm') AND {schemas}
+                   AND strpos(lower(pg_get_viewdef(c.oid, true)), s.term) > 0",
+            ),
+            // Functions that belong to extensions are left out
+            (
+                ObjectGroup::Routines,
+                "SELECT n.nspname::text, p.proname::text,
+                        CASE p.prokind WHEN 'p' THEN 'procedure' WHEN 'a' THEN 'aggregate'
+                                       WHEN 'w' THEN 'window function' ELSE 'function' END,
+                        NULL::text, p.prosrc
+                 FROM pg_proc p JOIN pg_namespace n ON n.oid = p.pronamespace CROSS JOIN {term}
+                 WHERE {schemas}
+                   AND NOT EXISTS (SELECT 1 FROM pg_depend d
+                                   WHERE d.classid = 'pg_proc'::regclass AND d.objid = p.oid AND d.deptype = 'e')
+                   AND (strpos(lower(p.proname), s.term) > 0 OR strpos(lower(p.prosrc), s.term) > 0)",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT n.nspname::text, c.relname::text, {relkind}, a.attname::text, d.description
+                 FROM pg_description d
+                 JOIN pg_class c ON d.classoid = 'pg_class'::regclass AND c.oid = d.objoid
+                 JOIN pg_namespace n ON n.oid = c.relnamespace
+                 LEFT JOIN pg_attribute a ON a.attrelid = c.oid AND d.objsubid > 0 AND a.attnum = d.objsubid
+                 CROSS JOIN {term}
+                 WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f') AND {schemas}
+                   AND strpos(lower(d.description), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT n.nspname::text, p.proname::text, 'function', NULL::text, d.description
+                 FROM pg_description d
+                 JOIN pg_proc p ON d.classoid = 'pg_proc'::regclass AND p.oid = d.objoid
+                 JOIN pg_namespace n ON n.oid = p.pronamespace
+                 CROSS JOIN {term}
+                 WHERE {schemas} AND strpos(lower(d.description), s.term) > 0",
+            ),
+        ],
+        DatabaseType::MySQL => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT t.TABLE_SCHEMA, t.TABLE_NAME, IF(t.TABLE_TYPE = 'VIEW', 'view', 'table'), NULL, NULL
+                 FROM information_schema.TABLES t CROSS JOIN {term}
+                 WHERE {schemas:t.TABLE_SCHEMA} AND LOCATE(s.term, LOWER(t.TABLE_NAME)) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT c.TABLE_SCHEMA, c.TABLE_NAME, IF(t.TABLE_TYPE = 'VIEW', 'view', 'table'), c.COLUMN_NAME,
+                        c.COLUMN_TYPE
+                 FROM information_schema.COLUMNS c
+                 JOIN information_schema.TABLES t ON t.TABLE_SCHEMA = c.TABLE_SCHEMA AND t.TABLE_NAME = c.TABLE_NAME
+                 CROSS JOIN {term}
+                 WHERE {schemas:c.TABLE_SCHEMA} AND LOCATE(s.term, LOWER(c.COLUMN_NAME)) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT v.TABLE_SCHEMA, v.TABLE_NAME, 'view', NULL, v.VIEW_DEFINITION
+                 FROM information_schema.VIEWS v CROSS JOIN {term}
+                 WHERE {schemas:v.TABLE_SCHEMA} AND LOCATE(s.term, LOWER(v.VIEW_DEFINITION)) > 0",
+            ),
+            (
+                ObjectGroup::Routines,
+                "SELECT r.ROUTINE_SCHEMA, r.ROUTINE_NAME, LOWER(r.ROUTINE_TYPE), NULL, r.ROUTINE_DEFINITION
+                 FROM information_schema.ROUTINES r CROSS JOIN {term}
+                 WHERE {schemas:r.ROUTINE_SCHEMA}
+                   AND (LOCATE(s.term, LOWER(r.ROUTINE_NAME)) > 0 OR LOCATE(s.term, LOWER(r.ROUTINE_DEFINITION)) > 0)",
+            ),
+            (
+                ObjectGroup::Routines,
+                "SELECT g.TRIGGER_SCHEMA, g.TRIGGER_NAME, 'trigger', NULL, g.ACTION_STATEMENT
+                 FROM information_schema.TRIGGERS g CROSS JOIN {term}
+                 WHERE {schemas:g.TRIGGER_SCHEMA}
+                   AND (LOCATE(s.term, LOWER(g.TRIGGER_NAME)) > 0 OR LOCATE(s.term, LOWER(g.ACTION_STATEMENT)) > 0)",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT t.TABLE_SCHEMA, t.TABLE_NAME, 'table', NULL, t.TABLE_COMMENT
+                 FROM information_schema.TABLES t CROSS JOIN {term}
+                 WHERE {schemas:t.TABLE_SCHEMA} AND t.TABLE_TYPE = 'BASE TABLE'
+                   AND LOCATE(s.term, LOWER(t.TABLE_COMMENT)) > 0",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT c.TABLE_SCHEMA, c.TABLE_NAME, 'table', c.COLUMN_NAME, c.COLUMN_COMMENT
+                 FROM information_schema.COLUMNS c CROSS JOIN {term}
+                 WHERE {schemas:c.TABLE_SCHEMA} AND LOCATE(s.term, LOWER(c.COLUMN_COMMENT)) > 0",
+            ),
+        ],
+        DatabaseType::MSSQL => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT SCHEMA_NAME(o.schema_id), o.name, {objtype}, NULL, NULL
+                 FROM sys.objects o CROSS JOIN {term}
+                 WHERE o.type IN ('U', 'V') AND o.is_ms_shipped = 0 AND CHARINDEX(s.term, LOWER(o.name)) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT SCHEMA_NAME(o.schema_id), o.name, {objtype}, c.name, TYPE_NAME(c.user_type_id)
+                 FROM sys.columns c JOIN sys.objects o ON o.object_id = c.object_id CROSS JOIN {term}
+                 WHERE o.type IN ('U', 'V') AND o.is_ms_shipped = 0 AND CHARINDEX(s.term, LOWER(c.name)) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT SCHEMA_NAME(o.schema_id), o.name, 'view', NULL, m.definition
+                 FROM sys.sql_modules m JOIN sys.objects o ON o.object_id = m.object_id CROSS JOIN {term}
+                 WHERE o.type = 'V' AND o.is_ms_shipped = 0 AND CHARINDEX(s.term, LOWER(m.definition)) > 0",
+            ),
+            (
+                ObjectGroup::Routines,
+                "SELECT SCHEMA_NAME(o.schema_id), o.name,
+                        CASE o.type WHEN 'P' THEN 'procedure' WHEN 'TR' THEN 'trigger' ELSE 'function' END,
+                        NULL, m.definition
+                 FROM sys.sql_modules m JOIN sys.objects o ON o.object_id = m.object_id CROSS JOIN {term}
+                 WHERE o.type IN ('P', 'FN', 'IF', 'TF', 'TR') AND o.is_ms_shipped = 0
+                   AND (CHARINDEX(s.term, LOWER(o.name)) > 0 OR CHARINDEX(s.term, LOWER(m.definition)) > 0)",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT SCHEMA_NAME(o.schema_id), o.name, {objtype}, c.name, CAST(ep.value AS nvarchar(max))
+                 FROM sys.extended_properties ep
+                 JOIN sys.objects o ON o.object_id = ep.major_id
+                 LEFT JOIN sys.columns c ON c.object_id = ep.major_id AND c.column_id = ep.minor_id AND ep.minor_id > 0
+                 CROSS JOIN {term}
+                 WHERE ep.class = 1 AND ep.name = 'MS_Description' AND o.is_ms_shipped = 0
+                   AND CHARINDEX(s.term, LOWER(CAST(ep.value AS nvarchar(max)))) > 0",
+            ),
+        ],
+        // SQLite keeps no comments, and its only routines are triggers
+        DatabaseType::SQLite => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT NULL, m.name, m.type, NULL, NULL
+                 FROM sqlite_master m CROSS JOIN {term}
+                 WHERE m.type IN ('table', 'view') AND m.name NOT LIKE 'sqlite\\_%' ESCAPE '\\'
+                   AND instr(lower(m.name), s.term) > 0",
+            ),
+            // The pragma's columns are cast so preparing the query does not look up their origin
+            (
+                ObjectGroup::Columns,
+                "SELECT NULL, m.name, m.type, CAST(p.name AS TEXT), CAST(p.type AS TEXT)
+                 FROM sqlite_master m, pragma_table_info(m.name) p CROSS JOIN {term}
+                 WHERE m.type IN ('table', 'view') AND m.name NOT LIKE 'sqlite\\_%' ESCAPE '\\'
+                   AND instr(lower(p.name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT NULL, m.name, 'view', NULL, m.sql
+                 FROM sqlite_master m CROSS JOIN {term}
+                 WHERE m.type = 'view' AND instr(lower(m.sql), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Routines,
+                "SELECT NULL, m.name, 'trigger', NULL, m.sql
+                 FROM sqlite_master m CROSS JOIN {term}
+                 WHERE m.type = 'trigger' AND (instr(lower(m.name), s.term) > 0 OR instr(lower(m.sql), s.term) > 0)",
+            ),
+        ],
+        // Empty strings stand in for NULL, which ClickHouse would type as Nothing
+        DatabaseType::ClickHouse => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT '', t.name, {engine}, '', ''
+                 FROM system.tables t CROSS JOIN {term}
+                 WHERE t.database = currentDatabase() AND NOT t.is_temporary AND position(lower(t.name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT '', c.table, {engine}, c.name, c.type
+                 FROM system.columns c
+                 JOIN system.tables t ON t.database = c.database AND t.name = c.table
+                 CROSS JOIN {term}
+                 WHERE c.database = currentDatabase() AND position(lower(c.name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT '', t.name, {engine}, '', t.create_table_query
+                 FROM system.tables t CROSS JOIN {term}
+                 WHERE t.database = currentDatabase() AND t.engine IN ('View', 'MaterializedView')
+                   AND position(lower(t.create_table_query), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT '', t.name, {engine}, '', t.comment
+                 FROM system.tables t CROSS JOIN {term}
+                 WHERE t.database = currentDatabase() AND position(lower(t.comment), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT '', c.table, {engine}, c.name, c.comment
+                 FROM system.columns c
+                 JOIN system.tables t ON t.database = c.database AND t.name = c.table
+                 CROSS JOIN {term}
+                 WHERE c.database = currentDatabase() AND position(lower(c.comment), s.term) > 0",
+            ),
+        ],
+        DatabaseType::DuckDB => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT t.schema_name, t.table_name, 'table', NULL, NULL
+                 FROM duckdb_tables() t CROSS JOIN {term}
+                 WHERE {duckdb:t} AND strpos(lower(t.table_name), s.term) > 0
+                 UNION ALL
+                 SELECT v.schema_name, v.view_name, 'view', NULL, NULL
+                 FROM duckdb_views() v CROSS JOIN {term}
+                 WHERE {duckdb:v} AND strpos(lower(v.view_name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT c.schema_name, c.table_name,
+                        CASE WHEN v.view_name IS NULL THEN 'table' ELSE 'view' END, c.column_name, c.data_type
+                 FROM duckdb_columns() c
+                 LEFT JOIN duckdb_views() v
+                   ON v.database_name = c.database_name AND v.schema_name = c.schema_name AND v.view_name = c.table_name
+                 CROSS JOIN {term}
+                 WHERE {duckdb:c} AND strpos(lower(c.column_name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Views,
+                "SELECT v.schema_name, v.view_name, 'view', NULL, v.sql
+                 FROM duckdb_views() v CROSS JOIN {term}
+                 WHERE {duckdb:v} AND strpos(lower(v.sql), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Routines,
+                "SELECT f.schema_name, f.function_name, 'macro', NULL, f.macro_definition
+                 FROM duckdb_functions() f CROSS JOIN {term}
+                 WHERE {duckdb:f} AND f.function_type IN ('macro', 'table_macro')
+                   AND (strpos(lower(f.function_name), s.term) > 0 OR strpos(lower(f.macro_definition), s.term) > 0)",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT t.schema_name, t.table_name, 'table', NULL, t.comment
+                 FROM duckdb_tables() t CROSS JOIN {term}
+                 WHERE {duckdb:t} AND strpos(lower(t.comment), s.term) > 0
+                 UNION ALL
+                 SELECT c.schema_name, c.table_name, 'table', c.column_name, c.comment
+                 FROM duckdb_columns() c CROSS JOIN {term}
+                 WHERE {duckdb:c} AND strpos(lower(c.comment), s.term) > 0",
+            ),
+        ],
+        // Positional binds count every use, so each query names the term once
+        DatabaseType::Oracle => vec![
+            (
+                ObjectGroup::Tables,
+                "SELECT o.owner, o.object_name, LOWER(o.object_type), NULL, NULL
+                 FROM all_objects o CROSS JOIN {term}
+                 WHERE {oracle:o.owner} AND o.object_type IN ('TABLE', 'VIEW') AND o.generated = 'N'
+                   AND o.object_name NOT LIKE 'BIN$%' AND INSTR(LOWER(o.object_name), s.term) > 0",
+            ),
+            (
+                ObjectGroup::Columns,
+                "SELECT c.owner, c.table_name, CASE WHEN v.view_name IS NULL THEN 'table' ELSE 'view' END,
+                        c.column_name, c.data_type
+                 FROM all_tab_columns c
+                 LEFT JOIN all_views v ON v.owner = c.owner AND v.view_name = c.table_name
+                 CROSS JOIN {term}
+                 WHERE {oracle:c.owner} AND c.table_name NOT LIKE 'BIN$%'
+                   AND INSTR(LOWER(c.column_name), s.term) > 0",
+            ),
+            // TEXT_VC holds the first 4000 characters of the definition
+            (
+                ObjectGroup::Views,
+                "SELECT v.owner, v.view_name, 'view', NULL, v.text_vc
+                 FROM all_views v CROSS JOIN {term}
+                 WHERE {oracle:v.owner} AND INSTR(LOWER(v.text_vc), s.term) > 0",
+            ),
+            // ALL_SOURCE holds routine bodies a line per row
+            (
+                ObjectGroup::Routines,
+                "SELECT src.owner, src.name, LOWER(src.type), NULL,
+                        MIN(CASE WHEN INSTR(LOWER(src.text), s.term) > 0 THEN src.text END)
+                 FROM all_source src CROSS JOIN {term}
+                 WHERE {oracle:src.owner}
+                   AND (INSTR(LOWER(src.name), s.term) > 0 OR INSTR(LOWER(src.text), s.term) > 0)
+                 GROUP BY src.owner, src.name, src.type",
+            ),
+            (
+                ObjectGroup::Comments,
+                "SELECT m.owner, m.table_name, m.object_type, m.column_name, m.comments
+                 FROM (SELECT owner, table_name, LOWER(table_type) AS object_type, NULL AS column_name, comments
+                       FROM all_tab_comments
+                       UNION ALL
+                       SELECT owner, table_name, 'table', column_name, comments FROM all_col_comments) m
+                 CROSS JOIN {term}
+                 WHERE {oracle:m.owner} AND INSTR(LOWER(m.comments), s.term) > 0",
+            ),
+        ],
+        DatabaseType::MongoDB | DatabaseType::Redis => vec![],
+    };
+
+    // MySQL connections without a database see every user schema, as the explorer does
+    let mysql_schemas = |column: &str| {
+        if config.database.trim().is_empty() {
+            format!(
+                "{} NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')",
+                column
+            )
+        } else {
+            format!("{} = DATABASE()", column)
+        }
+    };
+    queries
+        .into_iter()
+        .map(|(group, sql)| {
+            let mut sql = sql
+                .replace("{term}", &term)
+                .replace("{schemas}", "n.nspname <> 'information_schema' AND n.nspname !~ '^pg_'")
+                .replace(
+                    "{relkind}",
+                    "CASE c.relkind WHEN 'v' THEN 'view' WHEN 'm' THEN 'materialized view' \
+                     WHEN 'f' THEN 'foreign table' ELSE 'table' END",
+                )
+                .replace("{objtype}", "CASE o.type WHEN 'V' THEN 'view' ELSE 'table' END")
+                .replace(
+                    "{engine}",
+                    "multiIf(t.engine = 'View', 'view', t.engine = 'MaterializedView', 'materialized view', 'table')",
+                );
+            // DuckDB lists the objects of every attached database and its own catalogs
+            while let Some(start) = sql.find("{duckdb:") {
+                let end = start + sql[start..].find('}').unwrap_or(0);
+                let alias = sql[start + "{duckdb:".len()..end].to_string();
+                let condition = format!("{0}.database_name = current_database() AND NOT {0}.internal", alias);
+                sql.replace_range(start..=end, &condition);
+            }
+            // Oracle searches the current schema
+            while let Some(start) = sql.find("{oracle:") {
+                let end = start + sql[start..].find('}').unwrap_or(0);
+                let column = sql[start + "{oracle:".len()..end].to_string();
+                sql.replace_range(start..=end, &format!("{} = SYS_CONTEXT('USERENV', 'CURRENT_SCHEMA')", column));
+            }
+            while let Some(start) = sql.find("{schemas:") {
+                let end = start + sql[start..].find('}').unwrap_or(0);
+                let column = sql[start + "{schemas:".len()..end].to_string();
+                sql.replace_range(start..=end, &mysql_schemas(&column));
+            }
+            // One more than is kept, to tell whether the group was cut short
+            let sql = match database_type {
+                DatabaseType::MSSQL => sql.replacen("SELECT ", &format!("SELECT TOP {} ", MAX_OBJECT_MATCHES + 1), 1),
+                DatabaseType::Oracle => format!("{} FETCH FIRST {} ROWS ONLY", sql, MAX_OBJECT_MATCHES + 1),
+                _ => format!("{} LIMIT {}", sql, MAX_OBJECT_MATCHES + 1),
+            };
+            (group, sql)
+        })
+        .collect()
+}
+
+/// The line of `text` where `pattern` first appears, shortened around the match
+fn matching_line(text: &str, pattern: &str) -> Option<String> {
+    let line = text.lines().find(|line| line.to_lowercase().contains(pattern))?.trim();
+    Some(excerpt(line, pattern, MAX_DETAIL_CHARS))
+}
+
+/// `text` cut to `max_chars` around the first appearance of the lowercase `pattern`
+fn excerpt(text: &str, pattern: &str, max_chars: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= max_chars {
+        return text.to_string();
+    }
+    let lower = text.to_lowercase();
+    let at = lower.find(pattern).map_or(0, |index| lower[..index].chars().count());
+    let start = at.saturating_sub(max_chars / 3).min(chars.len() - max_chars);
+    let end = start + max_chars;
+    format!(
+        "{}{}{}",
+        if start > 0 { "…" } else { "" },
+        chars[start..end].iter().collect::<String>(),
+        if end < chars.len() { "…" } else { "" }
+    )
+}
+
+/// Find where a name or piece of text appears in the database's definitions:
+/// table and column names, view definitions, routine bodies and comments,
+/// read from the system catalogs. The pattern matches case-insensitively
+/// anywhere in the text; each group holds at most 200 matches.
+#[tauri::command]
+pub async fn search_database_objects(connection_id: String, pattern: String) -> AppResult<ObjectSearchResult> {
+    let term = pattern.trim().to_lowercase();
+    if term.is_empty() {
+        return Err(AppError::ValidationError(
+            "Enter a name or text to search for".to_string(),
+        ));
+    }
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError(
+            "Connection not found or not connected".to_string(),
+        ));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Object search is only available for SQL databases".to_string(),
+        ));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    let qualified = matches!(
+        config.database_type,
+        DatabaseType::PostgreSQL | DatabaseType::MSSQL | DatabaseType::DuckDB | DatabaseType::Oracle
+    );
+
+    let mut result = ObjectSearchResult {
+        pattern: pattern.trim().to_string(),
+        ..Default::default()
+    };
+    for (group, sql) in object_queries(&config) {
+        let rows = driver
+            .execute_with_params(pool_ref, &sql, vec![CellValue::Text(term.clone())])
+            .await?
+            .rows;
+        let text = |value: &serde_json::Value| value.as_str().filter(|s| !s.is_empty()).map(str::to_string);
+        let matches = match group {
+            ObjectGroup::Tables => &mut result.tables,
+            ObjectGroup::Columns => &mut result.columns,
+            ObjectGroup::Views => &mut result.views,
+            ObjectGroup::Routines => &mut result.routines,
+            ObjectGroup::Comments => &mut result.comments,
+        };
+        for row in rows {
+            let [schema, name, object_type, column, detail] = &row[..] else {
+                continue;
+            };
+            let schema = text(schema);
+            let name = text(name).unwrap_or_default();
+            let detail = match group {
+                ObjectGroup::Columns => text(detail),
+                _ => text(detail).and_then(|detail| matching_line(&detail, &term)),
+            };
+            matches.push(ObjectMatch {
+                object: match &schema {
+                    Some(schema) if qualified => format!("{}.{}", schema, name),
+                    _ => name,
+                },
+                schema,
+                object_type: text(object_type).unwrap_or_default(),
+                column: text(column),
+                detail,
+            });
+        }
+        if matches.len() > MAX_OBJECT_MATCHES {
+            matches.truncate(MAX_OBJECT_MATCHES);
+            result.truncated = true;
+        }
+    }
+
+    for matches in [
+        &mut result.tables,
+        &mut result.columns,
+        &mut result.views,
+        &mut result.routines,
+        &mut result.comments,
+    ] {
+        matches.sort_by(|a, b| (&a.object, &a.column).cmp(&(&b.object, &b.column)));
+    }
+    Ok(result)
+}
+
+/// How a data search compares a column's text with the term
+#[derive(Clone, Copy)]
+enum TextMatch {
+    Like,
+    Regex,
+}
+
+/// The column as text, for the types `LIKE` does not take directly
+fn column_text(database_type: &DatabaseType, quoted: &str) -> String {
+    match database_type {
+        DatabaseType::PostgreSQL => format!("CAST({} AS text)", quoted),
+        DatabaseType::MSSQL => format!("CAST({} AS nvarchar(max))", quoted),
+        DatabaseType::ClickHouse => format!("toString({})", quoted),
+        DatabaseType::DuckDB => format!("CAST({} AS VARCHAR)", quoted),
+        _ => quoted.to_string(),
+    }
+}
+
+/// Condition that `text` matches the term, bound as the search's one parameter
+fn text_condition(database_type: &DatabaseType, text: &str, mode: TextMatch) -> String {
+    let param = placeholder(database_type, 1);
+    match (database_type, mode) {
+        (DatabaseType::PostgreSQL, TextMatch::Regex) => format!("{} ~* {}", text, param),
+        (DatabaseType::PostgreSQL | DatabaseType::ClickHouse, _) => format!("{} ILIKE {}", text, param),
+        // DuckDB's LIKE has no escape character unless one is given
+        (DatabaseType::DuckDB, _) => format!("{} ILIKE {} ESCAPE '\\'", text, param),
+        (DatabaseType::MSSQL | DatabaseType::SQLite | DatabaseType::Oracle, _) => {
+            format!("LOWER({}) LIKE {} ESCAPE '\\'", text, param)
+        }
+        _ => format!("LOWER({}) LIKE {}", text, param),
+    }
+}
+
+/// `%term%` with LIKE's wildcards escaped, lowercased for the databases
+/// compared through LOWER()
+fn like_pattern(database_type: &DatabaseType, term: &str) -> String {
+    let mut pattern = String::from("%");
+    for c in term.to_lowercase().chars() {
+        if matches!(c, '\\' | '%' | '_') || (c == '[' && matches!(database_type, DatabaseType::MSSQL)) {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// Search one table's text columns, returning at most `limit` matching rows
+#[allow(clippy::too_many_arguments)]
+async fn search_table(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    search_id: &str,
+    table: &str,
+    term: &str,
+    mode: TextMatch,
+    limit: usize,
+) -> TableDataMatches {
+    let mut matches = TableDataMatches {
+        search_id: search_id.to_string(),
+        table: table.to_string(),
+        columns: vec![],
+        rows: vec![],
+        truncated: false,
+        error: None,
+    };
+    let schema = match driver.get_table_schema(pool, table).await {
+        Ok(schema) if schema.columns.is_empty() => {
+            matches.error = Some(format!("Table '{}' was not found or has no columns", table));
+            return matches;
+        }
+        Ok(schema) => schema,
+        Err(e) => {
+            matches.error = Some(e.to_string());
+            return matches;
+        }
+    };
+    matches.columns = schema
+        .columns
+        .iter()
+        .filter(|column| {
+            matches!(
+                transfer::column_kind(database_type, &column.data_type),
+                ColumnKind::Text(_)
+            )
+        })
+        .map(|column| column.name.clone())
+        .collect();
+    if matches.columns.is_empty() {
+        return matches;
+    }
+
+    // Each text column is selected only where it matches, so one scan tells
+    // which rows match and in which columns
+    let alias = |prefix: &str, index: usize| quote_identifier(database_type, &format!("{}{}", prefix, index));
+    let mut select: Vec<String> = schema
+        .primary_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| format!("{} AS {}", quote_identifier(database_type, key), alias("k", i)))
+        .collect();
+    select.extend(matches.columns.iter().enumerate().map(|(i, column)| {
+        let text = column_text(database_type, &quote_identifier(database_type, column));
+        format!(
+            "CASE WHEN {} THEN {} END AS {}",
+            text_condition(database_type, &text, mode),
+            text,
+            alias("m", i)
+        )
+    }));
+    let any_match = (0..matches.columns.len())
+        .map(|i| format!("{} IS NOT NULL", alias("m", i)))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let inner = format!(
+        "SELECT {} FROM {}",
+        select.join(", "),
+        quote_qualified(database_type, table)
+    );
+    // One more than is kept, to tell whether the table has more matches
+    let sql = match database_type {
+        DatabaseType::MSSQL => format!("SELECT TOP {} * FROM ({}) x WHERE {}", limit + 1, inner, any_match),
+        DatabaseType::Oracle => format!(
+            "SELECT * FROM ({}) x WHERE {} FETCH FIRST {} ROWS ONLY",
+            inner,
+            any_match,
+            limit + 1
+        ),
+        _ => format!("SELECT * FROM ({}) x WHERE {} LIMIT {}", inner, any_match, limit + 1),
+    };
+
+    let param = match mode {
+        TextMatch::Like => like_pattern(database_type, term),
+        TextMatch::Regex => term.to_string(),
+    };
+    // `?` placeholders, and Oracle's bound by position, take a value for each use
+    let uses = match database_type {
+        DatabaseType::MySQL | DatabaseType::SQLite | DatabaseType::Oracle => matches.columns.len(),
+        _ => 1,
+    };
+    let result = match driver
+        .execute_with_params(pool, &sql, vec![CellValue::Text(param); uses])
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            matches.error = Some(e.to_string());
+            return matches;
+        }
+    };
+
+    let lowered = term.to_lowercase();
+    let keys = schema.primary_keys.len();
+    matches.truncated = result.rows.len() > limit;
+    matches.rows = result
+        .rows
+        .into_iter()
+        .take(limit)
+        .map(|row| DataMatchRow {
+            primary_key: schema.primary_keys.iter().cloned().zip(row.iter().cloned()).collect(),
+            matches: row[keys..]
+                .iter()
+                .zip(&matches.columns)
+                .filter_map(|(value, column)| {
+                    let text = match value {
+                        serde_json::Value::Null => return None,
+                        serde_json::Value::String(text) => text.clone(),
+                        other => other.to_string(),
+                    };
+                    Some(DataMatch {
+                        column: column.clone(),
+                        value: excerpt(&text, &lowered, MAX_VALUE_CHARS),
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+    matches
+}
+
+/// Search the text columns of the given tables for a term, case-insensitively.
+/// Tables are searched a few at a time, and each table's matches are sent as a
+/// `table-data-search-matches` event as soon as it is done, with the primary
+/// key of every matching row. A table that cannot be searched reports its
+/// error without stopping the others.
+#[tauri::command]
+pub async fn search_table_data(
+    app: AppHandle,
+    search_id: String,
+    connection_id: String,
+    tables: Vec<String>,
+    term: String,
+    options: Option<DataSearchOptions>,
+) -> AppResult<DataSearchSummary> {
+    if term.is_empty() {
+        return Err(AppError::ValidationError("Enter text to search for".to_string()));
+    }
+    if tables.is_empty() {
+        return Err(AppError::ValidationError("Choose the tables to search".to_string()));
+    }
+    let options = options.unwrap_or_default();
+
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError(
+            "Connection not found or not connected".to_string(),
+        ));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    let database_type = &config.database_type;
+    if matches!(database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Data search is only available for SQL databases".to_string(),
+        ));
+    }
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    let mode = if options.regex.unwrap_or(false) {
+        if !matches!(database_type, DatabaseType::PostgreSQL) {
+            return Err(AppError::ValidationError(
+                "Regular expression search is only available for PostgreSQL".to_string(),
+            ));
+        }
+        // Reject a bad expression once rather than failing every table with it
+        driver
+            .execute_with_params(pool_ref, "SELECT '' ~* $1", vec![CellValue::Text(term.clone())])
+            .await
+            .map_err(|e| AppError::ValidationError(format!("Invalid regular expression: {}", e)))?;
+        TextMatch::Regex
+    } else {
+        TextMatch::Like
+    };
+    let limit = options
+        .max_rows_per_table
+        .unwrap_or(DEFAULT_ROWS_PER_TABLE)
+        .clamp(1, MAX_ROWS_PER_TABLE) as usize;
+    let concurrency = options
+        .concurrency
+        .unwrap_or(DEFAULT_CONCURRENCY)
+        .clamp(1, MAX_CONCURRENCY) as usize;
+    let start = Instant::now();
+
+    let mut searches = stream::iter(&tables)
+        .map(|table| {
+            search_table(
+                driver.as_ref(),
+                pool_ref,
+                database_type,
+                &search_id,
+                table,
+                &term,
+                mode,
+                limit,
+            )
+        })
+        .buffer_unordered(concurrency);
+    let mut searched = Vec::with_capacity(tables.len());
+    while let Some(matches) = searches.next().await {
+        let _ = app.emit("table-data-search-matches", &matches);
+        searched.push(matches);
+    }
+    drop(searches);
+
+    Ok(DataSearchSummary {
+        search_id,
+        tables: searched,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
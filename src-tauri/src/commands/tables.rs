@@ -1,6 +1,13 @@
-use crate::db::{get_connection_manager, get_driver};
+use crate::audit::{self, Change};
+use crate::confirmation;
+use crate::db::{build_ddl_script, get_connection_manager, get_driver, truncate_dependents};
 use crate::error::{AppError, AppResult};
-use crate::models::{QueryResult, TableProperties, TableRelationship};
+use crate::migration::{build_migration, CurrentTable};
+use crate::models::{
+    Confirmable, ConfirmationRequired, DatabaseDdlOptions, MigrationPlan, MigrationRequest, NewColumn, QueryResult,
+    SchemaComments, TablePartitioning, TablePrivilege, TableProperties, TableRelationship, TruncateOptions, TableStats
+};
+use crate::read_only;
 use crate::storage;
 
 /// Generate CREATE TABLE DDL for a table
@@ -25,6 +32,31 @@ pub async fn generate_table_ddl(
     driver.generate_table_ddl(pool_ref, &table_name).await
 }
 
+/// Generate one script that recreates the current database: types, tables,
+/// indexes, foreign keys, views and triggers, each after what it depends on
+#[tauri::command]
+pub async fn generate_database_ddl(
+    connection_id: String,
+    options: Option<DatabaseDdlOptions>,
+) -> AppResult<String> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+    let options = options.unwrap_or_default();
+
+    let objects = driver.get_ddl_objects(pool_ref, &config, &options).await?;
+    Ok(build_ddl_script(&config.database_type, objects, &options))
+}
+
 /// Rename a table
 #[tauri::command]
 pub async fn rename_table(
@@ -41,11 +73,330 @@ pub async fn rename_table(
 
     let config = storage::get_connection(&connection_id)?
         .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let result = driver.rename_table(pool_ref, &old_name, &new_name).await;
+    let change = Change {
+        operation: "rename_table",
+        table: Some(&old_name),
+        statement: format!("Rename table {} to {}", old_name, new_name),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Remove every row from a table. Without `confirmation_token` nothing is
+/// removed; a token is issued along with the table's estimated row count.
+#[tauri::command]
+pub async fn truncate_table(
+    connection_id: String,
+    table_name: String,
+    options: Option<TruncateOptions>,
+    confirmation_token: Option<String>,
+) -> AppResult<Confirmable<QueryResult>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    // The token covers these options and the tables a cascade empties, so a
+    // token for a plain truncate cannot be spent on a cascading one
+    let options = options.unwrap_or_default();
+    let dependents = if options.cascade {
+        let dependencies = driver.get_object_dependencies(pool_ref).await.unwrap_or_default();
+        truncate_dependents(&dependencies, &table_name)
+    } else {
+        Vec::new()
+    };
+    let action = format!(
+        "truncate_table:{}:{}:{}:{}:{}",
+        connection_id, table_name, options.restart_identity, options.cascade, dependents.join(",")
+    );
+    let token = match confirmation_token {
+        Some(token) => token,
+        None => {
+            let stats = driver.get_table_stats(pool_ref, &table_name).await.ok();
+            let estimated_rows = stats.and_then(|s| s.approximate_rows);
+            let summary = if dependents.is_empty() {
+                format!("Remove every row from {}", table_name)
+            } else {
+                format!(
+                    "Remove every row from {} and from the tables that reference it: {}",
+                    table_name,
+                    dependents.join(", ")
+                )
+            };
+            return Ok(Confirmable::ConfirmationRequired(ConfirmationRequired {
+                table: Some(table_name),
+                estimated_rows,
+                dependents,
+                ..confirmation::request(action, summary)
+            }));
+        }
+    };
+    confirmation::consume(&token, &action)?;
+
+    let result = driver.truncate_table(pool_ref, &table_name, &options).await;
+    let change = Change {
+        operation: "truncate_table",
+        table: Some(&table_name),
+        statement: format!(
+            "Truncate table {}{}{}",
+            table_name,
+            if options.restart_identity { " RESTART IDENTITY" } else { "" },
+            if options.cascade { " CASCADE" } else { "" }
+        ),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result.map(Confirmable::Executed)
+}
+
+/// Copy a table's structure, and optionally its rows, to a new table
+#[tauri::command]
+pub async fn duplicate_table(
+    connection_id: String,
+    table_name: String,
+    new_name: String,
+    include_data: bool,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let result = driver.duplicate_table(pool_ref, &table_name, &new_name, include_data).await;
+    let rows = if include_data { " with its rows" } else { "" };
+    let change = Change {
+        operation: "duplicate_table",
+        table: Some(&table_name),
+        statement: format!("Copy table {} to {}{}", table_name, new_name, rows),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Add a column to a table
+#[tauri::command]
+pub async fn add_column(
+    connection_id: String,
+    table_name: String,
+    column: NewColumn,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let result = driver.add_column(pool_ref, &table_name, &column).await;
+    let change = Change {
+        operation: "add_column",
+        table: Some(&table_name),
+        statement: format!(
+            "Add column {} {}{}{}",
+            column.name,
+            column.data_type,
+            if column.nullable { "" } else { " NOT NULL" },
+            column.default_value.as_ref().map(|d| format!(" DEFAULT {}", d)).unwrap_or_default()
+        ),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Drop a column from a table
+#[tauri::command]
+pub async fn drop_column(
+    connection_id: String,
+    table_name: String,
+    column_name: String,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let result = driver.drop_column(pool_ref, &table_name, &column_name).await;
+    let change = Change {
+        operation: "drop_column",
+        table: Some(&table_name),
+        statement: format!("Drop column {}", column_name),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Rename a column
+#[tauri::command]
+pub async fn rename_column(
+    connection_id: String,
+    table_name: String,
+    old_name: String,
+    new_name: String,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let result = driver.rename_column(pool_ref, &table_name, &old_name, &new_name).await;
+    let change = Change {
+        operation: "rename_column",
+        table: Some(&table_name),
+        statement: format!("Rename column {} to {}", old_name, new_name),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Change a column's type and nullability. SQLite copies the table to do this.
+#[tauri::command]
+pub async fn alter_column_type(
+    connection_id: String,
+    table_name: String,
+    column_name: String,
+    data_type: String,
+    nullable: bool,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
 
     let driver = get_driver(&config);
     let pool_ref = manager.get_pool_ref(&connection_id)?;
 
-    driver.rename_table(pool_ref, &old_name, &new_name).await
+    let result = driver.alter_column_type(pool_ref, &table_name, &column_name, &data_type, nullable).await;
+    let null = if nullable { "NULL" } else { "NOT NULL" };
+    let change = Change {
+        operation: "alter_column_type",
+        table: Some(&table_name),
+        statement: format!("Change column {} to {} {}", column_name, data_type, null),
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
+}
+
+/// Statements that would store the comments on a table and its columns (PostgreSQL and MySQL)
+#[tauri::command]
+pub async fn preview_schema_comments(
+    connection_id: String,
+    table_name: String,
+    comments: SchemaComments,
+) -> AppResult<Vec<String>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.comment_statements(pool_ref, &table_name, &comments).await
+}
+
+/// Store comments on a table and its columns (PostgreSQL and MySQL)
+#[tauri::command]
+pub async fn apply_schema_comments(
+    connection_id: String,
+    table_name: String,
+    comments: SchemaComments,
+) -> AppResult<QueryResult> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    read_only::check_change(&config)?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    let statements = driver.comment_statements(pool_ref, &table_name, &comments).await?;
+    if statements.is_empty() {
+        return Err(AppError::ValidationError("No comments to apply".to_string()));
+    }
+
+    // Several statements run in one transaction
+    let sql = statements.join(";\n");
+    let result = driver.execute_query(pool_ref, &sql).await;
+    let change = Change {
+        operation: "apply_schema_comments",
+        table: Some(&table_name),
+        statement: sql,
+        params: Vec::new(),
+    };
+    audit::record(&config, change, &result);
+    result
 }
 
 /// Get full table properties including extended column info, indexes, and constraints
@@ -70,6 +421,73 @@ pub async fn get_table_properties(
     driver.get_table_properties(pool_ref, &table_name).await
 }
 
+/// Count a table's rows exactly, for when the estimate in its properties is not enough
+#[tauri::command]
+pub async fn count_exact_rows(
+    connection_id: String,
+    table_name: String,
+) -> AppResult<i64> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.count_exact_rows(pool_ref, &table_name).await
+}
+
+/// Get on-disk size, index size, estimated rows and bloat indicators for a table
+#[tauri::command]
+pub async fn get_table_stats(
+    connection_id: String,
+    table_name: String,
+) -> AppResult<TableStats> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_table_stats(pool_ref, &table_name).await
+}
+
+/// List the partitions of a partitioned table with their bounds and sizes.
+/// None when the table is not partitioned or the database has no partitioning
+#[tauri::command]
+pub async fn get_table_partitions(
+    connection_id: String,
+    table_name: String,
+) -> AppResult<Option<TablePartitioning>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_partitions(pool_ref, &table_name).await
+}
+
 /// Get table relationships (foreign keys both inbound and outbound)
 #[tauri::command]
 pub async fn get_table_relationships(
@@ -91,3 +509,61 @@ pub async fn get_table_relationships(
 
     driver.get_table_relationships(pool_ref, &table_name).await
 }
+
+/// Get the privileges each user or role holds on a table
+#[tauri::command]
+pub async fn get_table_privileges(
+    connection_id: String,
+    table_name: String,
+) -> AppResult<Vec<TablePrivilege>> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&connection_id)?;
+
+    driver.get_table_privileges(pool_ref, &table_name).await
+}
+
+/// Generate the statements that migrate a table to the schema edited in the UI.
+/// Nothing is executed; the plan is returned for review.
+#[tauri::command]
+pub async fn generate_migration(request: MigrationRequest) -> AppResult<MigrationPlan> {
+    let manager = get_connection_manager().read().await;
+
+    // Verify connection exists
+    if !manager.is_connected(&request.connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(&request.connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(&request.connection_id)?;
+
+    let schema = driver.get_table_schema(pool_ref, &request.table_name).await?;
+    let indexes = driver.get_indexes(pool_ref, &request.table_name).await?;
+    let relationships = driver.get_table_relationships(pool_ref, &request.table_name).await?;
+
+    let current = CurrentTable {
+        schema: &schema,
+        indexes: &indexes,
+        relationships: &relationships,
+    };
+    build_migration(
+        &config.database_type,
+        current,
+        &request.table_name,
+        &request.desired,
+        request.renames.as_deref().unwrap_or_default(),
+        request.indexes.as_deref(),
+    )
+}
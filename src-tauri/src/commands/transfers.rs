@@ -0,0 +1,350 @@
+use crate::backup;
+use crate::db::{
+    get_connection_manager, get_driver, quote_identifier, quote_qualified, string_literal, DatabaseDriver, PoolRef,
+    Session,
+};
+use crate::error::{AppError, AppResult};
+use crate::export::is_binary_type;
+use crate::models::{
+    CellValue, ConnectionConfig, DatabaseType, TableCopyProgress, TableCopyRequest, TableCopyRowError, TableCopySummary,
+};
+use crate::read_only;
+use crate::schema_cache;
+use crate::storage;
+use crate::transfer::{self, ColumnKind, NewTableColumn, RunningCopy};
+use std::time::Instant;
+use tauri::{AppHandle, Emitter};
+
+use super::browse::{estimate_rows, first_number};
+
+const DEFAULT_BATCH_SIZE: u32 = 500;
+
+/// SQL Server takes at most 1000 rows in one VALUES list
+const MAX_BATCH_SIZE: u32 = 1000;
+
+const MAX_REPORTED_ERRORS: usize = 1000;
+
+/// A column copied from the source table into the target table
+struct CopyColumn {
+    source: String,
+    target: String,
+    /// Kind of the target column, which decides how values are converted
+    kind: ColumnKind,
+    /// The source column holds binary data
+    binary: bool,
+}
+
+fn sql_config(connection_id: &str) -> AppResult<ConnectionConfig> {
+    let config = storage::get_connection(connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+    if matches!(config.database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError(
+            "Tables can only be copied between SQL databases".to_string(),
+        ));
+    }
+    Ok(config)
+}
+
+/// Rows in the source table, from statistics where the database keeps them
+async fn source_rows(
+    driver: &dyn DatabaseDriver,
+    pool: PoolRef<'_>,
+    database_type: &DatabaseType,
+    table: &str,
+) -> Option<u64> {
+    match database_type {
+        // Counting is cheap in SQLite files, and ClickHouse and DuckDB keep the count
+        DatabaseType::SQLite | DatabaseType::ClickHouse | DatabaseType::DuckDB => {
+            let sql = format!("SELECT COUNT(*) FROM {}", quote_qualified(database_type, table));
+            let result = driver.execute_query(pool, &sql).await.ok()?;
+            first_number(&result.rows)
+        }
+        _ => estimate_rows(driver, pool, database_type, table).await,
+    }
+}
+
+/// Whether the database lists `listed` for the name `wanted`, which may leave out the schema
+fn same_table(listed: &str, wanted: &str) -> bool {
+    listed.eq_ignore_ascii_case(wanted)
+        || (!wanted.contains('.')
+            && listed
+                .rsplit_once('.')
+                .is_some_and(|(_, name)| name.eq_ignore_ascii_case(wanted)))
+}
+
+/// Copy a table's rows into another connection, which may be a different kind
+/// of database. A target table that does not exist is created from the
+/// source's columns; an existing one receives the columns both tables have.
+/// Progress is sent as `table-copy-progress` events, and `cancel_table_copy`
+/// stops the copy after its current batch.
+#[tauri::command]
+pub async fn copy_table_between_connections(app: AppHandle, request: TableCopyRequest) -> AppResult<TableCopySummary> {
+    let source_config = sql_config(&request.source_connection_id)?;
+    let target_config = sql_config(&request.target_connection_id)?;
+    read_only::check_change(&target_config)?;
+
+    let target_table = request
+        .target_table
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| {
+            request
+                .source_table
+                .rsplit_once('.')
+                .map_or(request.source_table.as_str(), |(_, name)| name)
+        })
+        .to_string();
+    if request.source_connection_id == request.target_connection_id && same_table(&request.source_table, &target_table)
+    {
+        return Err(AppError::ValidationError(
+            "The source and target are the same table".to_string(),
+        ));
+    }
+
+    let copy = RunningCopy::start(&request.transfer_id)?;
+    let manager = get_connection_manager().read().await;
+    for connection_id in [&request.source_connection_id, &request.target_connection_id] {
+        if !manager.is_connected(connection_id) {
+            return Err(AppError::ConnectionError(
+                "Connection not found or not connected".to_string(),
+            ));
+        }
+    }
+    let source_driver = get_driver(&source_config);
+    let target_driver = get_driver(&target_config);
+    let source_pool = manager.get_pool_ref(&request.source_connection_id)?;
+    let target_pool = manager.get_pool_ref(&request.target_connection_id)?;
+    let source_type = &source_config.database_type;
+    let target_type = &target_config.database_type;
+    let start = Instant::now();
+
+    let source_schema = source_driver
+        .get_table_schema(source_pool, &request.source_table)
+        .await?;
+    if source_schema.columns.is_empty() {
+        return Err(AppError::ValidationError(format!(
+            "Table '{}' was not found or has no columns",
+            request.source_table
+        )));
+    }
+    let existing = target_driver
+        .get_tables(target_pool, &target_config)
+        .await?
+        .into_iter()
+        .find(|table| same_table(&table.name, &target_table));
+
+    let (target_table, columns, create_statement) = match existing {
+        Some(table) => {
+            let target_schema = target_driver.get_table_schema(target_pool, &table.name).await?;
+            let generated =
+                backup::generated_columns(target_driver.as_ref(), target_pool, target_type, &table.name).await;
+            let columns: Vec<CopyColumn> = source_schema
+                .columns
+                .iter()
+                .filter_map(|source| {
+                    let target = target_schema.columns.iter().find(|target| {
+                        target.name.eq_ignore_ascii_case(&source.name) && !generated.contains(&target.name)
+                    })?;
+                    Some(CopyColumn {
+                        source: source.name.clone(),
+                        target: target.name.clone(),
+                        kind: transfer::column_kind(target_type, &target.data_type),
+                        binary: is_binary_type(&source.data_type.to_lowercase()),
+                    })
+                })
+                .collect();
+            if columns.is_empty() {
+                return Err(AppError::ValidationError(format!(
+                    "{} has no columns in common with {}",
+                    table.name, request.source_table
+                )));
+            }
+            (table.name, columns, None)
+        }
+        None => {
+            let new_columns: Vec<NewTableColumn> = source_schema
+                .columns
+                .iter()
+                .map(|column| NewTableColumn {
+                    name: column.name.clone(),
+                    kind: transfer::column_kind(source_type, &column.data_type),
+                    nullable: column.nullable,
+                })
+                .collect();
+            let sql = transfer::create_table_sql(target_type, &target_table, &new_columns, &source_schema.primary_keys);
+            target_driver.execute_query(target_pool, &sql).await?;
+            schema_cache::clear(&request.target_connection_id);
+
+            let columns = source_schema
+                .columns
+                .iter()
+                .zip(&new_columns)
+                .map(|(source, new)| CopyColumn {
+                    source: source.name.clone(),
+                    target: new.name.clone(),
+                    kind: new.kind,
+                    binary: is_binary_type(&source.data_type.to_lowercase()),
+                })
+                .collect();
+            (target_table, columns, Some(sql))
+        }
+    };
+
+    let select = columns
+        .iter()
+        .map(|column| quote_identifier(source_type, &column.source))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let column_list = columns
+        .iter()
+        .map(|column| quote_identifier(target_type, &column.target))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let quoted_target = quote_qualified(target_type, &target_table);
+    // Identity columns take the copied values only when told to
+    let insert = match target_type {
+        DatabaseType::PostgreSQL => format!(
+            "INSERT INTO {} ({}) OVERRIDING SYSTEM VALUE VALUES",
+            quoted_target, column_list
+        ),
+        DatabaseType::Oracle => format!("INSERT INTO {} ({})", quoted_target, column_list),
+        _ => format!("INSERT INTO {} ({}) VALUES", quoted_target, column_list),
+    };
+    let identity_insert = create_statement.is_none()
+        && matches!(target_type, DatabaseType::MSSQL)
+        && !backup::catalog_names(
+            target_driver.as_ref(),
+            target_pool,
+            &format!(
+                "SELECT name FROM sys.identity_columns WHERE object_id = OBJECT_ID({})",
+                string_literal(target_type, &quoted_target)
+            ),
+        )
+        .await
+        .is_empty();
+    let insert_sql = |rows: &[Vec<CellValue>]| {
+        let sql = format!("{}\n  {}", insert, transfer::values_list(target_type, rows));
+        if identity_insert {
+            format!(
+                "SET IDENTITY_INSERT {0} ON;\n{1};\nSET IDENTITY_INSERT {0} OFF;",
+                quoted_target, sql
+            )
+        } else {
+            sql
+        }
+    };
+
+    let batch_size = request
+        .batch_size
+        .unwrap_or(DEFAULT_BATCH_SIZE)
+        .clamp(1, MAX_BATCH_SIZE) as usize;
+    let total_rows = source_rows(source_driver.as_ref(), source_pool, source_type, &request.source_table).await;
+    let emit = |rows_copied: usize, rows_failed: usize, done: bool| {
+        let _ = app.emit(
+            "table-copy-progress",
+            TableCopyProgress {
+                transfer_id: request.transfer_id.clone(),
+                rows_copied,
+                rows_failed,
+                total_rows,
+                done,
+            },
+        );
+    };
+
+    // MSSQL, DuckDB and Oracle clients run one statement at a time, so a copy
+    // within one connection reads the source through a connection of its own
+    let source_session = if request.source_connection_id == request.target_connection_id
+        && matches!(source_type, DatabaseType::MSSQL | DatabaseType::DuckDB | DatabaseType::Oracle)
+    {
+        manager.dedicated_session(&request.source_connection_id, &source_config).await?
+    } else {
+        None
+    };
+    let reader = source_session.as_ref().and_then(Session::pool_ref).unwrap_or(source_pool);
+    let sql = format!("SELECT {} FROM {}", select, quote_qualified(source_type, &request.source_table));
+
+    let mut rows_read = 0;
+    let mut rows_copied = 0;
+    let mut rows_failed = 0;
+    let mut errors = Vec::new();
+    let mut cancelled = false;
+    // The batch handler cannot await, so each batch is inserted on this
+    // thread while the source stream waits for it
+    let runtime = tokio::runtime::Handle::current();
+    let streamed = source_driver.stream_query(reader, &sql, batch_size, &mut |_, page| {
+        if copy.is_cancelled() {
+            cancelled = true;
+            return Err(AppError::QueryError("Table copy cancelled".to_string()));
+        }
+        let fetched = page.len();
+        let rows: Vec<Vec<CellValue>> = page
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(&columns)
+                    .map(|(value, column)| transfer::copy_value(column.kind, value, column.binary))
+                    .collect()
+            })
+            .collect();
+
+        if !rows.is_empty() {
+            tokio::task::block_in_place(|| runtime.block_on(async {
+                if target_driver
+                    .execute_query(target_pool, &insert_sql(&rows))
+                    .await
+                    .is_ok()
+                {
+                    rows_copied += rows.len();
+                } else {
+                    // Find the rows the target rejects by inserting them one at a time
+                    for (index, row) in rows.iter().enumerate() {
+                        match target_driver
+                            .execute_query(target_pool, &insert_sql(std::slice::from_ref(row)))
+                            .await
+                        {
+                            Ok(_) => rows_copied += 1,
+                            Err(e) => {
+                                rows_failed += 1;
+                                if errors.len() < MAX_REPORTED_ERRORS {
+                                    errors.push(TableCopyRowError {
+                                        row: rows_read + index + 1,
+                                        message: e.to_string(),
+                                    });
+                                }
+                            }
+                        }
+                    }
+                }
+            }));
+        }
+
+        rows_read += fetched;
+        emit(rows_copied, rows_failed, false);
+        Ok(())
+    }).await;
+    if !cancelled {
+        streamed?;
+    }
+    emit(rows_copied, rows_failed, true);
+
+    Ok(TableCopySummary {
+        transfer_id: request.transfer_id.clone(),
+        source_table: request.source_table.clone(),
+        target_table,
+        create_statement,
+        rows_copied,
+        rows_failed,
+        errors,
+        cancelled,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// Stop a running table copy after its current batch; the rows copied so far
+/// stay in the target. Returns false when no copy runs under `transfer_id`.
+#[tauri::command]
+pub async fn cancel_table_copy(transfer_id: String) -> AppResult<bool> {
+    Ok(transfer::cancel(&transfer_id))
+}
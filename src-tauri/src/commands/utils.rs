@@ -1,4 +1,8 @@
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
+use crate::export::get_copy_exporter;
+use crate::models::{CopyResultsRequest, DatabaseType, QueryResult};
+use crate::result_store;
+use crate::storage;
 use arboard::Clipboard;
 
 /// Copy text to the system clipboard
@@ -17,3 +21,37 @@ pub async fn read_from_clipboard() -> AppResult<String> {
     Ok(text)
 }
 
+
+/// Copy rows to the clipboard as Markdown, HTML, TSV, JSON or INSERT
+/// statements, formatted here so large results don't stall the UI.
+/// Returns the number of rows copied.
+#[tauri::command]
+pub async fn copy_results_as(request: CopyResultsRequest) -> AppResult<usize> {
+    let rows = match (&request.result_id, request.rows) {
+        (Some(result_id), _) => result_store::all_rows(result_id).await?,
+        (None, Some(rows)) => rows,
+        (None, None) => return Err(AppError::ValidationError("No rows to copy".to_string())),
+    };
+    let database_type = match &request.connection_id {
+        Some(connection_id) => storage::get_connection(connection_id)?
+            .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?
+            .database_type,
+        None => DatabaseType::PostgreSQL,
+    };
+    let table = request.table_name.unwrap_or_else(|| "table_name".to_string());
+
+    let result = QueryResult {
+        columns: request.columns,
+        rows,
+        affected_rows: None,
+        execution_time_ms: 0,
+        result_sets: Vec::new(),
+    };
+    let mut text = Vec::new();
+    get_copy_exporter(request.format, table, database_type).export(&result, &mut text)?;
+    let text = String::from_utf8(text).map_err(|e| AppError::Internal(e.to_string()))?;
+
+    let mut clipboard = Clipboard::new().map_err(|e| AppError::GenericError(e.to_string()))?;
+    clipboard.set_text(text).map_err(|e| AppError::GenericError(e.to_string()))?;
+    Ok(result.rows.len())
+}
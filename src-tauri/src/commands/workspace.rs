@@ -0,0 +1,15 @@
+use crate::error::AppResult;
+use crate::models::Workspace;
+use crate::storage;
+
+/// Save the open tabs and their layout, to restore on the next start
+#[tauri::command]
+pub async fn save_workspace(workspace: Workspace) -> AppResult<()> {
+    storage::save_workspace(workspace)
+}
+
+/// The tabs open when the app was last used; None the first time
+#[tauri::command]
+pub async fn restore_workspace() -> AppResult<Option<Workspace>> {
+    storage::load_workspace()
+}
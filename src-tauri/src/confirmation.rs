@@ -0,0 +1,51 @@
+//! One-time confirmation tokens for destructive commands. The first call
+//! describes what would happen and issues a token; the command only runs when
+//! it is called again with that token.
+
+use crate::error::{AppError, AppResult};
+use crate::models::ConfirmationRequired;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long an issued token stays valid
+const TOKEN_TTL: Duration = Duration::from_secs(120);
+
+/// Token -> (action it confirms, when it was issued)
+static PENDING: OnceCell<Mutex<HashMap<String, (String, Instant)>>> = OnceCell::new();
+
+fn pending() -> &'static Mutex<HashMap<String, (String, Instant)>> {
+    PENDING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Issue a token for `action`, a key naming the exact operation (command,
+/// connection and object) so a token cannot be replayed against another one
+pub fn request(action: String, summary: String) -> ConfirmationRequired {
+    let token = uuid::Uuid::new_v4().to_string();
+
+    let mut pending = pending().lock().unwrap_or_else(|e| e.into_inner());
+    pending.retain(|_, (_, issued)| issued.elapsed() < TOKEN_TTL);
+    pending.insert(token.clone(), (action, Instant::now()));
+
+    ConfirmationRequired {
+        token,
+        summary,
+        table: None,
+        estimated_rows: None,
+        dependents: Vec::new(),
+        expires_in_secs: TOKEN_TTL.as_secs(),
+    }
+}
+
+/// Use up `token`, failing unless it was issued for `action` and has not expired
+pub fn consume(token: &str, action: &str) -> AppResult<()> {
+    let mut pending = pending().lock().unwrap_or_else(|e| e.into_inner());
+
+    match pending.remove(token) {
+        Some((issued_for, issued)) if issued_for == action && issued.elapsed() < TOKEN_TTL => Ok(()),
+        _ => Err(AppError::ValidationError(
+            "Confirmation token is invalid or has expired; request a new one".to_string(),
+        )),
+    }
+}
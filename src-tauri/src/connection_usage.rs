@@ -0,0 +1,213 @@
+//! Usage of each connection, kept on this machine only: when it was last
+//! opened, which tables its queries touch and which columns their WHERE
+//! clauses filter on. The explorer lists recent tables first from it, and the
+//! AI context builder gives frequently used tables more weight.
+
+use crate::error::AppResult;
+use crate::models::{ColumnUsage, ConnectionInsights, ConnectionUsage, TableInsight, TableUsage};
+use crate::storage;
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const USAGE_FILE: &str = "connection_usage.json";
+
+/// Tables and columns kept per connection; the least used go beyond this
+const MAX_ENTRIES: usize = 200;
+
+/// Length of each list in the insights
+const INSIGHT_ENTRIES: usize = 20;
+
+/// Days after which a table's queries count half as much toward its weight
+const HALF_LIFE_DAYS: f64 = 14.0;
+
+/// Held across each read-modify-write, so concurrent queries never lose counts
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn usage_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(USAGE_FILE))
+}
+
+fn load() -> AppResult<Vec<ConnectionUsage>> {
+    let path = usage_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn save(usage: &[ConnectionUsage]) -> AppResult<()> {
+    let path = usage_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string(usage)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+fn update(connection_id: &str, change: impl FnOnce(&mut ConnectionUsage)) -> AppResult<()> {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut all = load()?;
+    let index = match all.iter().position(|usage| usage.connection_id == connection_id) {
+        Some(index) => index,
+        None => {
+            all.push(ConnectionUsage {
+                connection_id: connection_id.to_string(),
+                ..Default::default()
+            });
+            all.len() - 1
+        }
+    };
+    change(&mut all[index]);
+    save(&all)
+}
+
+fn now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Table names as written in SQL, without identifier quotes
+fn unquote(name: &str) -> String {
+    name.replace(['"', '`', '[', ']'], "")
+}
+
+/// Keep the most used entries, the more recent first among equally used ones
+fn keep_most_used<T>(entries: &mut Vec<T>, key: impl Fn(&T) -> (u64, &str)) {
+    entries.sort_by(|a, b| key(b).cmp(&key(a)));
+    entries.truncate(MAX_ENTRIES);
+}
+
+/// Note a successful connect
+pub fn record_connect(connection_id: &str) -> AppResult<()> {
+    update(connection_id, |usage| {
+        usage.connect_count += 1;
+        usage.last_connected_at = Some(now());
+    })
+}
+
+/// Note a query that ran, with the tables it touched and the columns its
+/// WHERE clauses filter on, as found by `sql_parse`
+pub fn record_query(connection_id: &str, tables: &[String], columns: &[(Option<String>, String)]) -> AppResult<()> {
+    let now = now();
+    update(connection_id, |usage| {
+        usage.query_count += 1;
+        usage.last_query_at = Some(now.clone());
+
+        for table in tables.iter().map(|table| unquote(table)) {
+            match usage
+                .tables
+                .iter_mut()
+                .find(|entry| entry.table.eq_ignore_ascii_case(&table))
+            {
+                Some(entry) => {
+                    entry.count += 1;
+                    entry.last_used = now.clone();
+                }
+                None => usage.tables.push(TableUsage {
+                    table,
+                    count: 1,
+                    last_used: now.clone(),
+                }),
+            }
+        }
+        for (table, column) in columns {
+            let table = table.as_deref().map(unquote);
+            let same_table = |entry: &ColumnUsage| match (&entry.table, &table) {
+                (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                (a, b) => a == b,
+            };
+            match usage
+                .filter_columns
+                .iter_mut()
+                .find(|entry| same_table(entry) && entry.column.eq_ignore_ascii_case(column))
+            {
+                Some(entry) => {
+                    entry.count += 1;
+                    entry.last_used = now.clone();
+                }
+                None => usage.filter_columns.push(ColumnUsage {
+                    table,
+                    column: column.clone(),
+                    count: 1,
+                    last_used: now.clone(),
+                }),
+            }
+        }
+
+        keep_most_used(&mut usage.tables, |entry| (entry.count, &entry.last_used));
+        keep_most_used(&mut usage.filter_columns, |entry| (entry.count, &entry.last_used));
+    })
+}
+
+/// Drop a deleted connection's usage
+pub fn forget(connection_id: &str) -> AppResult<()> {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut all = load()?;
+    let count = all.len();
+    all.retain(|usage| usage.connection_id != connection_id);
+    if all.len() < count {
+        save(&all)?;
+    }
+    Ok(())
+}
+
+/// A connection's usage, ranked for the explorer and the AI context builder
+pub fn insights(connection_id: &str) -> AppResult<ConnectionInsights> {
+    let usage = {
+        let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        load()?
+            .into_iter()
+            .find(|usage| usage.connection_id == connection_id)
+            .unwrap_or_else(|| ConnectionUsage {
+                connection_id: connection_id.to_string(),
+                ..Default::default()
+            })
+    };
+
+    let mut recent_tables = usage.tables.clone();
+    recent_tables.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+    recent_tables.truncate(INSIGHT_ENTRIES);
+
+    let now = Utc::now();
+    let mut top_tables: Vec<TableInsight> = usage
+        .tables
+        .iter()
+        .map(|entry| {
+            let age_days = DateTime::parse_from_rfc3339(&entry.last_used)
+                .map(|at| (now - at.with_timezone(&Utc)).num_seconds().max(0) as f64 / 86_400.0)
+                .unwrap_or(0.0);
+            TableInsight {
+                table: entry.table.clone(),
+                count: entry.count,
+                last_used: entry.last_used.clone(),
+                weight: entry.count as f64 * 0.5_f64.powf(age_days / HALF_LIFE_DAYS),
+            }
+        })
+        .collect();
+    top_tables.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    top_tables.truncate(INSIGHT_ENTRIES);
+    if let Some(top) = top_tables
+        .first()
+        .map(|table| table.weight)
+        .filter(|weight| *weight > 0.0)
+    {
+        for table in &mut top_tables {
+            table.weight /= top;
+        }
+    }
+
+    let mut filter_columns = usage.filter_columns;
+    keep_most_used(&mut filter_columns, |entry| (entry.count, &entry.last_used));
+    filter_columns.truncate(INSIGHT_ENTRIES);
+
+    Ok(ConnectionInsights {
+        connection_id: usage.connection_id,
+        last_connected_at: usage.last_connected_at,
+        connect_count: usage.connect_count,
+        query_count: usage.query_count,
+        last_query_at: usage.last_query_at,
+        recent_tables,
+        top_tables,
+        filter_columns,
+    })
+}
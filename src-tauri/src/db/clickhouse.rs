@@ -0,0 +1,976 @@
+//! ClickHouse driver over the HTTP interface. ClickHouse has no transactions
+//! or foreign keys, so scripts run one statement after another and the
+//! relationship lookups are empty.
+
+use crate::db::{
+    combine_result_sets, insert_statement, placeholder, quote_identifier, quote_qualified, sql_literal, string_literal,
+    DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    CellValue, ActiveQuery, ColumnInfo, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions,
+    DatabaseDdlOptions, DatabaseSize, DatabaseType, DatabaseUser, ExtendedColumnInfo, IndexInfo, NewColumn,
+    ObjectDependency, QueryResult, ResultSet, ServerStats, SlowQueryEntry, SlowQueryOrder, TableInfo, TablePrivilege,
+    TableProperties, TableRelationship, TableSchema, TableStats, TestConnectionResult, TruncateOptions,
+};
+use async_trait::async_trait;
+use serde::Deserialize;
+use sql_parse::Dialect;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Seconds to wait for the server to answer a request
+const REQUEST_TIMEOUT_SECS: u64 = 300;
+
+pub struct ClickHouseDriver;
+
+/// HTTP endpoint and credentials of one ClickHouse connection. Requests share
+/// the client's connection pool, so the struct is cheap to use concurrently.
+pub struct ClickHouseClient {
+    http: reqwest::Client,
+    url: String,
+    database: String,
+    user: String,
+    password: String,
+}
+
+/// Body of a `JSONCompact` response
+#[derive(Deserialize)]
+struct CompactOutput {
+    meta: Vec<CompactColumn>,
+    data: Vec<Vec<serde_json::Value>>,
+}
+
+#[derive(Deserialize)]
+struct CompactColumn {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: String,
+}
+
+/// What one statement sent back
+struct Response {
+    /// None for statements without output, such as DDL and INSERT
+    output: Option<CompactOutput>,
+    /// Rows written, from the `X-ClickHouse-Summary` header
+    written_rows: Option<u64>,
+}
+
+impl ClickHouseClient {
+    /// Send one statement with extra settings as URL parameters
+    async fn send(&self, sql: &str, settings: &[(&str, String)]) -> AppResult<Response> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("default_format", "JSONCompact".to_string()),
+            // Keep decimals exact; 64-bit integers are converted back to numbers below
+            ("output_format_json_quote_decimals", "1".to_string()),
+        ];
+        if !self.database.is_empty() {
+            params.push(("database", self.database.clone()));
+        }
+        params.extend(settings.iter().cloned());
+
+        let response = self.http
+            .post(&self.url)
+            .query(&params)
+            .basic_auth(&self.user, Some(&self.password))
+            .body(sql.to_string())
+            .send()
+            .await
+            .map_err(|e| AppError::ConnectionError(format!("ClickHouse request failed: {}", e)))?;
+
+        let status = response.status();
+        let written_rows = response
+            .headers()
+            .get("X-ClickHouse-Summary")
+            .and_then(|summary| summary.to_str().ok())
+            .and_then(|summary| serde_json::from_str::<HashMap<String, String>>(summary).ok())
+            .and_then(|summary| summary.get("written_rows").and_then(|rows| rows.parse().ok()));
+        let body = response.text().await
+            .map_err(|e| AppError::QueryError(format!("Failed to read ClickHouse response: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AppError::QueryError(body.trim().to_string()));
+        }
+        if body.trim().is_empty() {
+            return Ok(Response { output: None, written_rows });
+        }
+
+        let output = match serde_json::from_str::<CompactOutput>(&body) {
+            Ok(output) => output,
+            // A FORMAT clause in the statement overrides JSONCompact; show the text line by line
+            Err(_) => CompactOutput {
+                meta: vec![CompactColumn {
+                    name: "result".to_string(),
+                    data_type: "String".to_string(),
+                }],
+                data: body.lines().map(|line| vec![serde_json::Value::String(line.to_string())]).collect(),
+            },
+        };
+        Ok(Response { output: Some(output), written_rows })
+    }
+
+    /// Rows of a metadata query
+    async fn rows(&self, sql: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        Ok(self.send(sql, &[]).await?.output.map(|output| output.data).unwrap_or_default())
+    }
+}
+
+/// Type inside `Nullable(...)` and `LowCardinality(...)` wrappers
+fn inner_type(data_type: &str) -> &str {
+    let mut inner = data_type;
+    for wrapper in ["LowCardinality(", "Nullable("] {
+        if let Some(rest) = inner.strip_prefix(wrapper).and_then(|rest| rest.strip_suffix(')')) {
+            inner = rest;
+        }
+    }
+    inner
+}
+
+/// Map a value from JSON output to the app's JSON: 64-bit integers, which
+/// ClickHouse quotes, become numbers again, and arrays are mapped element by
+/// element. Wider integers and decimals stay strings so no digits are lost.
+fn clickhouse_value_to_json(value: serde_json::Value, data_type: &str) -> serde_json::Value {
+    let data_type = inner_type(data_type);
+    match value {
+        serde_json::Value::String(text) if data_type == "Int64" => {
+            text.parse::<i64>().map(serde_json::Value::from).unwrap_or(serde_json::Value::String(text))
+        }
+        serde_json::Value::String(text) if data_type == "UInt64" => {
+            text.parse::<u64>().map(serde_json::Value::from).unwrap_or(serde_json::Value::String(text))
+        }
+        serde_json::Value::Array(items) => match data_type.strip_prefix("Array(").and_then(|t| t.strip_suffix(')')) {
+            Some(element_type) => serde_json::Value::Array(
+                items.into_iter().map(|item| clickhouse_value_to_json(item, element_type)).collect(),
+            ),
+            None => serde_json::Value::Array(items),
+        },
+        other => other,
+    }
+}
+
+fn result_columns(meta: &[CompactColumn]) -> Vec<ColumnInfo> {
+    meta.iter()
+        .map(|column| ColumnInfo {
+            name: column.name.clone(),
+            data_type: inner_type(&column.data_type).to_lowercase(),
+            nullable: column.data_type.starts_with("Nullable(")
+                || column.data_type.starts_with("LowCardinality(Nullable("),
+            is_primary_key: false,
+            native_type: Some(column.data_type.clone()),
+            type_oid: None,
+        })
+        .collect()
+}
+
+fn text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn number(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_u64().or_else(|| n.as_f64().map(|f| f as u64)),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn float(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// `database = ... AND <column> = ...` for a table name that may be qualified
+/// with its database; system tables name the table column `table` or `name`
+fn table_filter(table_name: &str, column: &str) -> String {
+    let literal = |text: &str| string_literal(&DatabaseType::ClickHouse, text);
+    match table_name.split_once('.') {
+        Some((database, table)) => format!("database = {} AND {} = {}", literal(database), column, literal(table)),
+        None => format!("database = currentDatabase() AND {} = {}", column, literal(table_name)),
+    }
+}
+
+/// Replace `{pN}` placeholders with the matching parameter as a literal
+fn bind_params(sql: &str, params: &[serde_json::Value]) -> AppResult<String> {
+    let mut bound = String::with_capacity(sql.len());
+    let mut rest = sql;
+    while let Some(start) = rest.find("{p") {
+        bound.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let digits = after.chars().take_while(char::is_ascii_digit).count();
+        let index = after[..digits].parse::<usize>().ok().filter(|_| after[digits..].starts_with('}'));
+        match index {
+            Some(index) => {
+                let value = params.get(index.wrapping_sub(1)).ok_or_else(|| {
+                    AppError::QueryError(format!("No value for parameter {}", index))
+                })?;
+                bound.push_str(&sql_literal(&DatabaseType::ClickHouse, value));
+                rest = &after[digits + 1..];
+            }
+            None => {
+                bound.push_str("{p");
+                rest = after;
+            }
+        }
+    }
+    bound.push_str(rest);
+    Ok(bound)
+}
+
+fn client_for(pool: PoolRef<'_>) -> AppResult<&ClickHouseClient> {
+    match pool {
+        PoolRef::ClickHouse(client) => Ok(client),
+        _ => Err(AppError::QueryError("Invalid pool type for ClickHouse driver".to_string())),
+    }
+}
+
+/// Result of a statement that changed something
+fn change_result(written_rows: Option<u64>, start: Instant) -> QueryResult {
+    QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: Some(written_rows.unwrap_or(0)),
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    }
+}
+
+/// Helper methods for ClickHouseDriver
+impl ClickHouseDriver {
+    /// Create an HTTP client for the connection and check that the server answers
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<ClickHouseClient> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .danger_accept_invalid_certs(config.ssl_mode.as_deref() == Some("require"))
+            .build()
+            .map_err(|e| AppError::ConnectionError(format!("Failed to create HTTP client: {}", e)))?;
+
+        let client = ClickHouseClient {
+            http,
+            url: Self.build_connection_string(config),
+            database: config.database.trim().to_string(),
+            user: config.username.clone().filter(|u| !u.is_empty()).unwrap_or_else(|| "default".to_string()),
+            password: config.password.clone().unwrap_or_default(),
+        };
+        client.send("SELECT 1", &[]).await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to connect to ClickHouse: {}", e)))?;
+        Ok(client)
+    }
+
+    /// Run each statement in turn with the given settings; ClickHouse takes
+    /// one statement per request
+    async fn execute_statements(
+        client: &ClickHouseClient,
+        sql: &str,
+        settings: &[(&str, String)],
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let statements = sql_parse::split_statements(sql, Dialect::ClickHouse);
+
+        let mut result_sets = Vec::with_capacity(statements.len());
+        for statement in &statements {
+            let statement_start = Instant::now();
+            let response = client.send(statement, settings).await?;
+            let (columns, rows, affected_rows) = match response.output {
+                Some(output) => {
+                    let columns = result_columns(&output.meta);
+                    let rows = output
+                        .data
+                        .into_iter()
+                        .map(|row| {
+                            row.into_iter()
+                                .zip(&output.meta)
+                                .map(|(value, column)| clickhouse_value_to_json(value, &column.data_type))
+                                .collect()
+                        })
+                        .collect();
+                    (columns, rows, None)
+                }
+                None => (vec![], vec![], Some(response.written_rows.unwrap_or(0))),
+            };
+            result_sets.push(ResultSet {
+                statement: statement.clone(),
+                columns,
+                rows,
+                affected_rows,
+                execution_time_ms: statement_start.elapsed().as_millis() as u64,
+            });
+        }
+
+        if result_sets.len() == 1 {
+            let set = result_sets.remove(0);
+            return Ok(QueryResult {
+                columns: set.columns,
+                rows: set.rows,
+                affected_rows: set.affected_rows,
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+        Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64))
+    }
+
+    /// Run a DDL statement
+    async fn execute_ddl(client: &ClickHouseClient, sql: &str, context: &str) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        client.send(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to {}: {}", context, e)))?;
+        Ok(change_result(None, start))
+    }
+
+    /// Columns of a table from system.columns, in table order
+    async fn table_columns(client: &ClickHouseClient, table_name: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        let rows = client.rows(&format!(
+            "SELECT name, type, is_in_primary_key, default_kind, default_expression, comment \
+             FROM system.columns WHERE {} ORDER BY position",
+            table_filter(table_name, "table")
+        )).await?;
+        if rows.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+        Ok(rows)
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for ClickHouseDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let client = Self::connect(config).await?;
+        let version = client.rows("SELECT version()").await?
+            .first()
+            .and_then(|row| row.first())
+            .map(text);
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!("ClickHouse connection to {} successful", client.url),
+            server_version: version,
+        })
+    }
+
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        Self::execute_statements(client_for(pool)?, sql, &[]).await
+    }
+
+    /// The server stops reading rows at the limit (`result_overflow_mode =
+    /// break` may overshoot by a block, so rows are trimmed as well) and
+    /// cancels statements that run past `max_execution_time`
+    async fn execute_query_limited(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let mut settings = Vec::new();
+        if let Some(timeout_ms) = limits.statement_timeout_ms {
+            settings.push(("max_execution_time", timeout_ms.div_ceil(1000).to_string()));
+        }
+        if let Some(max_rows) = limits.max_rows {
+            settings.push(("max_result_rows", max_rows.to_string()));
+            settings.push(("result_overflow_mode", "break".to_string()));
+        }
+
+        let mut result = Self::execute_statements(client_for(pool)?, sql, &settings).await?;
+        limits.cap_rows(&mut result);
+        Ok(result)
+    }
+
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult> {
+        let client = client_for(pool)?;
+        let start = Instant::now();
+        // Parameters become literals, so each is written in its plain JSON form
+        let params: Vec<serde_json::Value> = params.iter().map(CellValue::to_json).collect();
+        let response = client.send(&bind_params(sql, &params)?, &[]).await?;
+
+        match response.output {
+            Some(output) => Ok(QueryResult {
+                columns: result_columns(&output.meta),
+                rows: output
+                    .data
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .zip(&output.meta)
+                            .map(|(value, column)| clickhouse_value_to_json(value, &column.data_type))
+                            .collect()
+                    })
+                    .collect(),
+                affected_rows: None,
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            }),
+            None => Ok(change_result(response.written_rows, start)),
+        }
+    }
+
+    /// Values are inlined as literals when the statement is sent
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::ClickHouse, index)
+    }
+
+    /// ClickHouse has no transactions, so each row is its own INSERT and rows
+    /// that went in stay in when others fail
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let client = client_for(pool)?;
+        let sql = insert_statement(&DatabaseType::ClickHouse, table, columns);
+
+        let mut failures = Vec::new();
+        for (index, row) in rows.into_iter().enumerate() {
+            let result = match bind_params(&sql, &row) {
+                Ok(statement) => client.send(&statement, &[]).await.map(|_| ()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                failures.push((index, e.to_string()));
+            }
+        }
+        Ok(failures)
+    }
+
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let rows = client_for(pool)?.rows(
+            "SELECT name, engine, total_rows FROM system.tables \
+             WHERE database = currentDatabase() AND NOT is_temporary ORDER BY name",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch tables: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TableInfo {
+                name: text(&row[0]),
+                schema: None,
+                table_type: if text(&row[1]).contains("View") { "VIEW" } else { "BASE TABLE" }.to_string(),
+                row_count: number(&row[2]).map(|rows| rows as i64),
+                parent_table: None,
+                pinned: false,
+            })
+            .collect())
+    }
+
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let rows = client_for(pool)?.rows("SELECT name FROM system.databases ORDER BY name").await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+        Ok(rows.iter().map(|row| text(&row[0])).collect())
+    }
+
+    async fn list_schemas(&self, _pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    /// ClickHouse has no owners, character sets or collations per database
+    async fn create_database(&self, pool: PoolRef<'_>, options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        let sql = format!("CREATE DATABASE {}", quote_identifier(&DatabaseType::ClickHouse, &options.name));
+        Self::execute_ddl(client_for(pool)?, &sql, "create database").await
+    }
+
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult> {
+        let sql = format!("DROP DATABASE {}", quote_identifier(&DatabaseType::ClickHouse, name));
+        Self::execute_ddl(client_for(pool)?, &sql, "drop database").await
+    }
+
+    async fn create_schema(&self, _pool: PoolRef<'_>, _options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("ClickHouse does not support schemas; create a database instead".to_string()))
+    }
+
+    async fn drop_schema(&self, _pool: PoolRef<'_>, _name: &str, _cascade: bool) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("ClickHouse does not support schemas".to_string()))
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let rows = Self::table_columns(client_for(pool)?, table_name).await?;
+
+        let columns: Vec<ColumnInfo> = rows
+            .iter()
+            .map(|row| {
+                let data_type = text(&row[1]);
+                ColumnInfo {
+                    name: text(&row[0]),
+                    data_type: inner_type(&data_type).to_lowercase(),
+                    nullable: data_type.contains("Nullable("),
+                    is_primary_key: number(&row[2]) == Some(1),
+                    native_type: Some(data_type),
+                    type_oid: None,
+                }
+            })
+            .collect();
+        let primary_keys = columns.iter().filter(|c| c.is_primary_key).map(|c| c.name.clone()).collect();
+
+        Ok(TableSchema {
+            table_name: table_name.to_string(),
+            columns,
+            primary_keys,
+            foreign_keys: vec![],
+        })
+    }
+
+    async fn get_all_table_schemas(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableSchema>> {
+        let rows = client_for(pool)?.rows(
+            "SELECT table, name, type, is_in_primary_key FROM system.columns \
+             WHERE database = currentDatabase() ORDER BY table, position",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch schemas: {}", e)))?;
+
+        let mut schemas: Vec<TableSchema> = Vec::new();
+        for row in &rows {
+            let table_name = text(&row[0]);
+            if schemas.last().is_none_or(|schema| schema.table_name != table_name) {
+                schemas.push(TableSchema {
+                    table_name,
+                    columns: vec![],
+                    primary_keys: vec![],
+                    foreign_keys: vec![],
+                });
+            }
+            let Some(schema) = schemas.last_mut() else {
+                continue;
+            };
+
+            let data_type = text(&row[2]);
+            let column = ColumnInfo {
+                name: text(&row[1]),
+                data_type: inner_type(&data_type).to_lowercase(),
+                nullable: data_type.contains("Nullable("),
+                is_primary_key: number(&row[3]) == Some(1),
+                native_type: Some(data_type),
+                type_oid: None,
+            };
+            if column.is_primary_key {
+                schema.primary_keys.push(column.name.clone());
+            }
+            schema.columns.push(column);
+        }
+        Ok(schemas)
+    }
+
+    /// HTTP(S) endpoint; a full URL pasted into the host field is used as is
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let host = config.host.as_deref().unwrap_or("localhost");
+        if host.starts_with("http://") || host.starts_with("https://") {
+            return host.trim_end_matches('/').to_string();
+        }
+
+        let secure = !matches!(config.ssl_mode.as_deref(), None | Some("disable"));
+        let port = config.port.unwrap_or(if secure { 8443 } else { 8123 });
+        format!("{}://{}:{}", if secure { "https" } else { "http" }, host, port)
+    }
+
+    async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
+        let sql = format!("SHOW CREATE TABLE {}", quote_qualified(&DatabaseType::ClickHouse, table_name));
+        let rows = client_for(pool)?.rows(&sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to generate DDL: {}", e)))?;
+
+        rows.first()
+            .and_then(|row| row.first())
+            .map(|statement| format!("{};", text(statement)))
+            .ok_or_else(|| AppError::QueryError(format!("Table '{}' not found", table_name)))
+    }
+
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        // ClickHouse has no foreign keys; each table lists the views that read from it
+        let sql = "SELECT view, name, engine \
+                   FROM system.tables ARRAY JOIN dependencies_table AS view \
+                   WHERE database = currentDatabase() \
+                   ORDER BY view, name";
+        let rows = client_for(pool)?.rows(sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get object dependencies: {}", e)))?;
+
+        Ok(rows.iter().map(|row| ObjectDependency {
+            object: text(&row[0]),
+            object_type: "VIEW".to_string(),
+            depends_on: text(&row[1]),
+            constraint_name: None,
+        }).collect())
+    }
+
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        // Inner tables of materialized views are created along with the view
+        let sql = "SELECT name, engine, create_table_query, dependencies_table, currentDatabase() \
+                   FROM system.tables \
+                   WHERE database = currentDatabase() AND NOT is_temporary AND NOT startsWith(name, '.inner') \
+                   ORDER BY name";
+        let rows = client_for(pool)?.rows(sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to list tables: {}", e)))?;
+
+        // Each table lists the views that read from it; turn that around into each view's dependencies
+        let mut view_dependencies: HashMap<String, Vec<String>> = HashMap::new();
+        for row in &rows {
+            if let Some(serde_json::Value::Array(views)) = row.get(3) {
+                for view in views {
+                    view_dependencies.entry(text(view)).or_default().push(text(&row[0]));
+                }
+            }
+        }
+
+        let mut objects = Vec::new();
+        for row in &rows {
+            let name = text(&row[0]);
+            let engine = text(&row[1]);
+            let database = text(&row[4]);
+            let mut create = text(&row[2]);
+            let mut quoted = quote_identifier(&DatabaseType::ClickHouse, &name);
+            if options.qualify_names {
+                quoted = format!("{}.{}", quote_identifier(&DatabaseType::ClickHouse, &database), quoted);
+            } else {
+                // The stored query names every table with its database, backquoted when needed
+                create = create.replace(&format!(" `{}`.", database), " ").replace(&format!(" {}.", database), " ");
+            }
+            let (kind, drop) = match engine.as_str() {
+                "View" | "MaterializedView" | "LiveView" | "WindowView" => (DdlKind::View, "VIEW"),
+                "Dictionary" => (DdlKind::View, "DICTIONARY"),
+                _ => (DdlKind::Table, "TABLE"),
+            };
+            let depends_on = view_dependencies.remove(&name).unwrap_or_default();
+            objects.push(
+                DdlObject::new(kind, name, format!("{};", create))
+                    .with_drop(format!("DROP {} IF EXISTS {};", drop, quoted))
+                    .with_dependencies(depends_on),
+            );
+        }
+
+        Ok(objects)
+    }
+
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let sql = format!(
+            "RENAME TABLE {} TO {}",
+            quote_qualified(&DatabaseType::ClickHouse, old_name),
+            quote_qualified(&DatabaseType::ClickHouse, new_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "rename table").await
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            quote_qualified(&DatabaseType::ClickHouse, table_name),
+            crate::db::column_definition(&DatabaseType::ClickHouse, column)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "add column").await
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            quote_qualified(&DatabaseType::ClickHouse, table_name),
+            quote_identifier(&DatabaseType::ClickHouse, column_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "drop column").await
+    }
+
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            quote_qualified(&DatabaseType::ClickHouse, table_name),
+            quote_identifier(&DatabaseType::ClickHouse, old_name),
+            quote_identifier(&DatabaseType::ClickHouse, new_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "rename column").await
+    }
+
+    /// Nullability is part of the type in ClickHouse, as `Nullable(T)`
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        let data_type = inner_type(data_type);
+        let data_type = if nullable { format!("Nullable({})", data_type) } else { data_type.to_string() };
+        let sql = format!(
+            "ALTER TABLE {} MODIFY COLUMN {} {}",
+            quote_qualified(&DatabaseType::ClickHouse, table_name),
+            quote_identifier(&DatabaseType::ClickHouse, column_name),
+            data_type
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "change column type").await
+    }
+
+    /// The PostgreSQL-only options are ignored
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        let sql = format!("TRUNCATE TABLE {}", quote_qualified(&DatabaseType::ClickHouse, table_name));
+        Self::execute_ddl(client_for(pool)?, &sql, "truncate table").await
+    }
+
+    /// `CREATE TABLE ... AS` copies the columns and the engine, including its sorting key
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let client = client_for(pool)?;
+        let start = Instant::now();
+        let source = quote_qualified(&DatabaseType::ClickHouse, table_name);
+        let target = quote_qualified(&DatabaseType::ClickHouse, new_name);
+
+        Self::execute_ddl(client, &format!("CREATE TABLE {} AS {}", target, source), "duplicate table").await?;
+        if !include_data {
+            return Ok(change_result(None, start));
+        }
+
+        let response = client.send(&format!("INSERT INTO {} SELECT * FROM {}", target, source), &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to copy rows: {}", e)))?;
+        Ok(change_result(response.written_rows, start))
+    }
+
+    /// The primary key (a sparse index, so not unique) and data skipping indexes
+    async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let client = client_for(pool)?;
+
+        let mut indexes = Vec::new();
+        let keys = client.rows(&format!(
+            "SELECT primary_key FROM system.tables WHERE {}",
+            table_filter(table_name, "name")
+        )).await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch indexes: {}", e)))?;
+        if let Some(key) = keys.first().map(|row| text(&row[0])).filter(|key| !key.is_empty()) {
+            indexes.push(IndexInfo {
+                name: "PRIMARY".to_string(),
+                columns: key.split(',').map(|column| column.trim().to_string()).collect(),
+                is_unique: false,
+                is_primary: true,
+            });
+        }
+
+        let skipping = client.rows(&format!(
+            "SELECT name, expr FROM system.data_skipping_indices WHERE {}",
+            table_filter(table_name, "table")
+        )).await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch indexes: {}", e)))?;
+        indexes.extend(skipping.iter().map(|row| IndexInfo {
+            name: text(&row[0]),
+            columns: vec![text(&row[1])],
+            is_unique: false,
+            is_primary: false,
+        }));
+        Ok(indexes)
+    }
+
+    async fn get_constraints(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        Ok(vec![])
+    }
+
+    async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties> {
+        let client = client_for(pool)?;
+        let rows = Self::table_columns(client, table_name).await?;
+
+        let columns: Vec<ExtendedColumnInfo> = rows
+            .iter()
+            .map(|row| {
+                let data_type = text(&row[1]);
+                let default_kind = text(&row[3]);
+                let default_expression = text(&row[4]);
+                let generation_expression =
+                    matches!(default_kind.as_str(), "MATERIALIZED" | "ALIAS").then(|| default_expression.clone());
+                ExtendedColumnInfo {
+                    name: text(&row[0]),
+                    nullable: data_type.contains("Nullable("),
+                    data_type,
+                    is_primary_key: number(&row[2]) == Some(1),
+                    // MATERIALIZED and ALIAS columns are computed, so show the kind with the expression
+                    default_value: match default_kind.as_str() {
+                        "" => None,
+                        "DEFAULT" => Some(default_expression),
+                        kind => Some(format!("{} {}", kind, default_expression)),
+                    },
+                    comment: Some(text(&row[5])).filter(|c| !c.is_empty()),
+                    is_identity: false,
+                    identity_always: false,
+                    generation_expression,
+                    collation: None,
+                }
+            })
+            .collect();
+        let primary_keys = columns.iter().filter(|c| c.is_primary_key).map(|c| c.name.clone()).collect();
+
+        let table = client.rows(&format!(
+            "SELECT total_rows, comment FROM system.tables WHERE {}",
+            table_filter(table_name, "name")
+        )).await?;
+        let table = table.first();
+
+        Ok(TableProperties {
+            table_name: table_name.to_string(),
+            schema: None,
+            columns,
+            primary_keys,
+            foreign_keys: vec![],
+            indexes: self.get_indexes(pool, table_name).await?,
+            constraints: vec![],
+            row_count: table.and_then(|row| number(&row[0])).map(|rows| rows as i64),
+            // MergeTree tables keep an exact total; other engines report none
+            row_count_exact: table.and_then(|row| number(&row[0])).is_some(),
+            table_comment: table.map(|row| text(&row[1])).filter(|c| !c.is_empty()),
+        })
+    }
+
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let rows = client_for(pool)?
+            .rows(&format!("SELECT count() FROM {}", quote_qualified(&DatabaseType::ClickHouse, table_name)))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))?;
+        rows.first()
+            .and_then(|row| row.first())
+            .and_then(number)
+            .map(|count| count as i64)
+            .ok_or_else(|| AppError::QueryError(format!("Failed to count rows of {}", table_name)))
+    }
+
+    /// Sizes of the table's active parts; ClickHouse merges parts itself, so
+    /// there are no dead rows to report
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let rows = client_for(pool)?.rows(&format!(
+            "SELECT sum(bytes_on_disk), sum(primary_key_bytes_in_memory), sum(rows) \
+             FROM system.parts WHERE active AND {}",
+            table_filter(table_name, "table")
+        )).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?;
+        let row = rows.first();
+        let field = |i: usize| row.and_then(|row| row.get(i)).and_then(number);
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: field(0),
+            index_size_bytes: field(1),
+            total_size_bytes: field(0),
+            approximate_rows: field(2),
+            ..TableStats::default()
+        })
+    }
+
+    async fn get_table_relationships(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        Ok(vec![])
+    }
+
+    async fn get_users_and_roles(&self, pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        let rows = client_for(pool)?.rows(
+            "SELECT name, 1, groupArray(granted_role_name) FROM system.users \
+             LEFT JOIN system.role_grants ON role_grants.user_name = users.name GROUP BY name \
+             UNION ALL SELECT name, 0, [] FROM system.roles ORDER BY 1",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to list users: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| DatabaseUser {
+                name: text(&row[0]),
+                host: None,
+                can_login: number(&row[1]) == Some(1),
+                is_superuser: false,
+                member_of: row[2]
+                    .as_array()
+                    .map(|roles| roles.iter().map(text).filter(|role| !role.is_empty()).collect())
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError("Privilege introspection is not supported for ClickHouse".to_string()))
+    }
+
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let client = client_for(pool)?;
+        let stats = client.rows(
+            "SELECT (SELECT sum(value) FROM system.metrics \
+             WHERE metric IN ('TCPConnection', 'HTTPConnection', 'MySQLConnection', 'PostgreSQLConnection')), uptime()",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+        let sizes = client.rows(
+            "SELECT database, sum(bytes_on_disk) AS size FROM system.parts WHERE active \
+             GROUP BY database ORDER BY size DESC",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+        let stats = stats.first();
+
+        Ok(ServerStats {
+            active_connections: stats.and_then(|row| number(&row[0])),
+            max_connections: None,
+            uptime_seconds: stats.and_then(|row| number(&row[1])),
+            cache_hit_ratio: None,
+            database_sizes: sizes
+                .iter()
+                .map(|row| DatabaseSize {
+                    name: text(&row[0]),
+                    size_bytes: number(&row[1]).unwrap_or(0),
+                })
+                .collect(),
+        })
+    }
+
+    /// Running queries from system.processes; the session ID is the query ID
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let rows = client_for(pool)?.rows(
+            "SELECT query_id, user, current_database, toString(address), query, elapsed \
+             FROM system.processes WHERE query_id != queryID() ORDER BY elapsed DESC",
+        ).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ActiveQuery {
+                session_id: text(&row[0]),
+                user: Some(text(&row[1])).filter(|v| !v.is_empty()),
+                database: Some(text(&row[2])).filter(|v| !v.is_empty()),
+                client: Some(text(&row[3])).filter(|v| !v.is_empty()),
+                state: Some("running".to_string()),
+                query: Some(text(&row[4])),
+                duration_ms: float(&row[5]).map(|seconds| (seconds * 1000.0) as u64),
+            })
+            .collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let sql = format!(
+            "KILL QUERY WHERE query_id = {} SYNC",
+            string_literal(&DatabaseType::ClickHouse, session_id)
+        );
+        let killed = client_for(pool)?.rows(&sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to kill session: {}", e)))?;
+
+        if killed.is_empty() {
+            return Err(AppError::QueryError(format!("Query {} not found", session_id)));
+        }
+        Ok(())
+    }
+
+    /// Aggregated from system.query_log over the last seven days
+    async fn get_slow_queries(&self, pool: PoolRef<'_>, order: SlowQueryOrder, limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        let order_by = match order {
+            SlowQueryOrder::TotalTime => "total_time",
+            SlowQueryOrder::MeanTime => "mean_time",
+            SlowQueryOrder::Calls => "calls",
+        };
+        let rows = client_for(pool)?.rows(&format!(
+            "SELECT any(normalizeQuery(query)), count() AS calls, sum(query_duration_ms) AS total_time, \
+             avg(query_duration_ms) AS mean_time, max(query_duration_ms), sum(result_rows + written_rows), \
+             any(current_database) \
+             FROM system.query_log WHERE type = 'QueryFinish' AND event_date >= today() - 7 \
+             GROUP BY normalized_query_hash ORDER BY {} DESC LIMIT {}",
+            order_by, limit
+        )).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get slow queries (is query_log enabled?): {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| SlowQueryEntry {
+                query: text(&row[0]),
+                calls: number(&row[1]).unwrap_or(0),
+                total_time_ms: float(&row[2]).unwrap_or(0.0),
+                mean_time_ms: float(&row[3]).unwrap_or(0.0),
+                max_time_ms: float(&row[4]),
+                rows: number(&row[5]),
+                database: Some(text(&row[6])).filter(|v| !v.is_empty()),
+            })
+            .collect())
+    }
+}
@@ -1,15 +1,34 @@
-use crate::error::AppResult;
+use crate::error::{AppError, AppResult};
 use crate::models::{
-    ConnectionConfig, ConstraintInfo, IndexInfo, QueryResult, TableInfo,
-    TableProperties, TableRelationship, TableSchema, TestConnectionResult
+    ActiveQuery, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseDdlOptions, DatabaseUser,
+    CellValue, IndexInfo, NewColumn, QueryResult, ServerStats, TableInfo, TruncateOptions, TableProperties, TablePrivilege,
+    TablePartitioning, TableRelationship, TableSchema, TestConnectionResult, TableStats, ObjectComment, SchemaComments, SlowQueryEntry,
+    SlowQueryOrder, ObjectDependency
 };
 use async_trait::async_trait;
 use sqlx::{PgPool, MySqlPool, SqlitePool};
+use tokio::sync::Mutex;
+use mongodb::Database;
+use redis::aio::ConnectionManager as RedisConnection;
 
+use super::{
+    BatchHandler, ClickHouseClient, DdlObject, DuckDbClient, MssqlClient, OracleClient, QueryLimits, RowBatches,
+};
+
+/// Tables with fewer estimated rows than this are counted exactly for their properties
+pub const EXACT_ROW_COUNT_LIMIT: i64 = 100_000;
+
+#[derive(Clone, Copy)]
 pub enum PoolRef<'a> {
     Postgres(&'a PgPool),
     MySql(&'a MySqlPool),
     Sqlite(&'a SqlitePool),
+    Mssql(&'a Mutex<MssqlClient>),
+    Mongo(&'a Database),
+    Redis(&'a RedisConnection),
+    ClickHouse(&'a ClickHouseClient),
+    DuckDb(&'a DuckDbClient),
+    Oracle(&'a OracleClient),
 }
 
 /// Trait defining the interface for database drivers
@@ -21,9 +40,76 @@ pub trait DatabaseDriver: Send + Sync {
     /// Execute a SQL query and return results
     async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult>;
 
+    /// Execute SQL typed by the user within `limits`. Drivers that cannot
+    /// set a timeout or stop reading early just trim the rows afterwards.
+    async fn execute_query_limited(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let mut result = self.execute_query(pool, sql).await?;
+        limits.cap_rows(&mut result);
+        Ok(result)
+    }
+
+    /// Execute SQL the user did not write, such as a generated query, in a
+    /// read-only transaction that is rolled back afterwards. Drivers without
+    /// read-only transactions run it like `execute_query_limited`.
+    async fn execute_read_only(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        self.execute_query_limited(pool, sql, limits).await
+    }
+
+    /// Run a single statement that returns rows, handing them to `on_batch`
+    /// `batch_size` at a time as they are read, and return how many there
+    /// were. Drivers that cannot stream read the whole result first.
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let result = self.execute_query(pool, sql).await?;
+        let mut batches = RowBatches::new(on_batch, result.columns, batch_size);
+        for row in result.rows {
+            batches.push(row)?;
+        }
+        batches.finish()
+    }
+
+    /// Execute a single SQL statement with bound parameters, each bound as its kind
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult>;
+
+    /// Placeholder for the 1-based parameter `index` in this dialect
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Insert rows in one transaction, rolling back only the rows that fail.
+    /// Returns the index and error message of each rejected row.
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>>;
+
     /// Get list of tables in the database
     async fn get_tables(&self, pool: PoolRef<'_>, config: &ConnectionConfig) -> AppResult<Vec<TableInfo>>;
 
+    /// List databases on the server the connection can open
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>>;
+
+    /// List schemas in the current database; empty where the database has none
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>>;
+
+    /// Create a database on the server
+    async fn create_database(&self, pool: PoolRef<'_>, options: &CreateDatabaseOptions) -> AppResult<QueryResult>;
+
+    /// Drop a database and everything in it
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult>;
+
+    /// Create a schema in the current database
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult>;
+
+    /// Drop a schema; `cascade` also drops the objects in it where supported
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, cascade: bool) -> AppResult<QueryResult>;
+
     /// Get schema for a specific table
     async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema>;
 
@@ -36,9 +122,50 @@ pub trait DatabaseDriver: Send + Sync {
     /// Generate CREATE TABLE DDL for a table
     async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String>;
 
+    /// Views and foreign keys that depend on other tables and views of the current database
+    async fn get_object_dependencies(&self, _pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        Ok(vec![])
+    }
+
+    /// Every schema object of the current database with its CREATE and DROP
+    /// statements, for `build_ddl_script` to order into one script
+    async fn get_ddl_objects(
+        &self,
+        _pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        _options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        Err(AppError::ValidationError("DDL can only be generated for SQL databases".to_string()))
+    }
+
     /// Rename a table
     async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult>;
 
+    /// Add a column to a table
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult>;
+
+    /// Drop a column from a table
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult>;
+
+    /// Rename a column
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult>;
+
+    /// Change a column's type, setting its nullability at the same time
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult>;
+
+    /// Remove every row from a table
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, options: &TruncateOptions) -> AppResult<QueryResult>;
+
+    /// Copy a table's structure, and its rows if `include_data` is set, to a new table
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult>;
+
     /// Get indexes for a table
     async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>>;
 
@@ -48,8 +175,73 @@ pub trait DatabaseDriver: Send + Sync {
     /// Get full table properties including extended column info, indexes, and constraints
     async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties>;
 
+    /// Get on-disk size, index size, estimated rows and bloat indicators for a table
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats>;
+
+    /// Count every row of a table; slow on large tables, where `get_table_properties` estimates instead
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64>;
+
+    /// Row count for table properties and whether it is exact: tables estimated below
+    /// `EXACT_ROW_COUNT_LIMIT` rows are counted, larger ones keep the estimate.
+    /// None as the estimate means the size is unknown and nothing is counted.
+    async fn row_count_from_estimate(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        estimate: Option<i64>,
+    ) -> (Option<i64>, bool) {
+        match estimate {
+            Some(rows) if rows < EXACT_ROW_COUNT_LIMIT => match self.count_exact_rows(pool, table_name).await {
+                Ok(count) => (Some(count), true),
+                Err(_) => (estimate, false),
+            },
+            _ => (estimate, false),
+        }
+    }
+
+    /// How a table is partitioned and its partitions; None when it is not partitioned
+    async fn get_partitions(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Option<TablePartitioning>> {
+        Ok(None)
+    }
+
+    /// Comments on every table and column, named as `get_all_table_schemas`
+    /// names the tables; empty where the database has no comments
+    async fn get_comments(&self, _pool: PoolRef<'_>) -> AppResult<Vec<ObjectComment>> {
+        Ok(vec![])
+    }
+
+    /// Statements that store `comments` on a table and its columns
+    async fn comment_statements(
+        &self,
+        _pool: PoolRef<'_>,
+        _table_name: &str,
+        _comments: &SchemaComments,
+    ) -> AppResult<Vec<String>> {
+        Err(AppError::QueryError(
+            "Comments can only be applied to PostgreSQL, MySQL, DuckDB and Oracle tables".to_string(),
+        ))
+    }
+
     /// Get table relationships (foreign keys both inbound and outbound)
     async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>>;
+
+    /// List logins and roles on the server
+    async fn get_users_and_roles(&self, pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>>;
+
+    /// List the privileges each grantee holds on a table
+    async fn get_table_privileges(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TablePrivilege>>;
+
+    /// Get connection counts, uptime, cache hit ratio and database sizes
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats>;
+
+    /// List sessions that are running a statement, excluding this connection's own
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>>;
+
+    /// Terminate a session listed by `get_active_queries`
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()>;
+
+    /// Top `limit` statements from the server's statement statistics
+    async fn get_slow_queries(&self, pool: PoolRef<'_>, order: SlowQueryOrder, limit: u32) -> AppResult<Vec<SlowQueryEntry>>;
 }
 
 /// Factory function to get the appropriate driver for a database type
@@ -60,10 +252,12 @@ pub fn get_driver(config: &ConnectionConfig) -> Box<dyn DatabaseDriver> {
         DatabaseType::PostgreSQL => Box::new(super::PostgresDriver),
         DatabaseType::MySQL => Box::new(super::MySqlDriver),
         DatabaseType::SQLite => Box::new(super::SqliteDriver),
-        DatabaseType::MSSQL => {
-            // TODO: Implement MSSQL driver
-            Box::new(super::PostgresDriver) // Placeholder
-        }
+        DatabaseType::MSSQL => Box::new(super::MssqlDriver),
+        DatabaseType::MongoDB => Box::new(super::MongoDriver),
+        DatabaseType::Redis => Box::new(super::RedisDriver),
+        DatabaseType::ClickHouse => Box::new(super::ClickHouseDriver),
+        DatabaseType::DuckDB => Box::new(super::DuckDbDriver),
+        DatabaseType::Oracle => Box::new(super::OracleDriver),
     }
 }
 
@@ -0,0 +1,134 @@
+//! Whole-database DDL scripts: drivers list their objects and this module
+//! orders them so every statement runs after the objects it needs.
+
+use super::DependencyGraph;
+use crate::models::{DatabaseDdlOptions, DatabaseType};
+use std::collections::HashMap;
+
+/// Kinds of schema objects, in the order a script creates them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DdlKind {
+    Schema,
+    Extension,
+    Type,
+    Sequence,
+    Function,
+    Table,
+    Index,
+    /// Foreign keys and constraints added after every table exists
+    Constraint,
+    View,
+    Trigger,
+}
+
+impl DdlKind {
+    fn heading(&self) -> &'static str {
+        match self {
+            DdlKind::Schema => "Schemas",
+            DdlKind::Extension => "Extensions",
+            DdlKind::Type => "Types",
+            DdlKind::Sequence => "Sequences",
+            DdlKind::Function => "Functions",
+            DdlKind::Table => "Tables",
+            DdlKind::Index => "Indexes",
+            DdlKind::Constraint => "Constraints",
+            DdlKind::View => "Views",
+            DdlKind::Trigger => "Triggers",
+        }
+    }
+}
+
+/// One object of a database and the statements that create and drop it
+#[derive(Debug, Clone)]
+pub struct DdlObject {
+    pub kind: DdlKind,
+    /// Identifies the object in other objects' `depends_on`
+    pub key: String,
+    pub create: String,
+    /// None for objects dropped along with their table, such as indexes
+    pub drop: Option<String>,
+    /// Keys of objects of the same kind that must be created first
+    pub depends_on: Vec<String>,
+}
+
+impl DdlObject {
+    pub fn new(kind: DdlKind, key: impl Into<String>, create: impl Into<String>) -> Self {
+        Self { kind, key: key.into(), create: create.into(), drop: None, depends_on: vec![] }
+    }
+
+    pub fn with_drop(mut self, drop: impl Into<String>) -> Self {
+        self.drop = Some(drop.into());
+        self
+    }
+
+    pub fn with_dependencies(mut self, depends_on: Vec<String>) -> Self {
+        self.depends_on = depends_on;
+        self
+    }
+}
+
+/// Order objects by kind, then so each comes after the objects of its kind it depends on.
+/// Objects keep their listed order otherwise; cycles fall back to it.
+fn creation_order(mut objects: Vec<DdlObject>) -> Vec<DdlObject> {
+    objects.sort_by_key(|object| object.kind);
+
+    let mut ordered = Vec::with_capacity(objects.len());
+    let mut pending = objects.into_iter().peekable();
+    while let Some(kind) = pending.peek().map(|object| object.kind) {
+        let mut group: HashMap<String, DdlObject> = HashMap::new();
+        let mut graph = DependencyGraph::new();
+        while let Some(object) = pending.next_if(|object| object.kind == kind) {
+            graph.add_object(&object.key);
+            group.insert(object.key.clone(), object);
+        }
+        for object in group.values() {
+            for dependency in object.depends_on.iter().filter(|dependency| group.contains_key(*dependency)) {
+                graph.add_dependency(&object.key, dependency);
+            }
+        }
+        ordered.extend(graph.creation_order().into_iter().filter_map(|key| group.remove(key)));
+    }
+    ordered
+}
+
+/// Assemble a script that creates `objects` in dependency order, preceded by
+/// their DROP statements in reverse order when `options.include_drops` is set
+pub fn build_ddl_script(database_type: &DatabaseType, objects: Vec<DdlObject>, options: &DatabaseDdlOptions) -> String {
+    let objects = creation_order(objects);
+    let mut script = String::new();
+
+    // MySQL checks that referenced tables exist, which cycles make impossible to satisfy
+    let mysql = matches!(database_type, DatabaseType::MySQL);
+    if mysql {
+        script.push_str("SET FOREIGN_KEY_CHECKS = 0;\n\n");
+    }
+
+    if options.include_drops {
+        let drops: Vec<&str> = objects.iter().rev().filter_map(|object| object.drop.as_deref()).collect();
+        if !drops.is_empty() {
+            script.push_str("-- Drop existing objects\n");
+            for drop in drops {
+                script.push_str(drop);
+                script.push('\n');
+            }
+            script.push('\n');
+        }
+    }
+
+    let mut kind = None;
+    for object in &objects {
+        if kind != Some(object.kind) {
+            kind = Some(object.kind);
+            script.push_str(&format!("-- {}\n", object.kind.heading()));
+        }
+        script.push_str(object.create.trim_end());
+        script.push_str("\n\n");
+    }
+
+    if mysql {
+        script.push_str("SET FOREIGN_KEY_CHECKS = 1;\n");
+    }
+    script.truncate(script.trim_end().len());
+    script.push('\n');
+    script
+}
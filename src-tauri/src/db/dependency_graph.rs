@@ -0,0 +1,154 @@
+//! Directed graph of database objects and the objects they need, used to
+//! order CREATE and DROP statements and to find what a drop takes with it.
+
+use crate::models::ObjectDependency;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Objects keyed by name, with edges from each object to the objects it depends on
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    keys: Vec<String>,
+    index: HashMap<String, usize>,
+    depends_on: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+}
+
+impl DependencyGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an object if it is not in the graph yet; objects keep the order they were added in
+    pub fn add_object(&mut self, key: &str) -> usize {
+        if let Some(&node) = self.index.get(key) {
+            return node;
+        }
+        let node = self.keys.len();
+        self.keys.push(key.to_string());
+        self.index.insert(key.to_string(), node);
+        self.depends_on.push(Vec::new());
+        self.dependents.push(Vec::new());
+        node
+    }
+
+    /// Record that `object` needs `depends_on`, adding either if missing. Self references are ignored.
+    pub fn add_dependency(&mut self, object: &str, depends_on: &str) {
+        let from = self.add_object(object);
+        let to = self.add_object(depends_on);
+        if from != to && !self.depends_on[from].contains(&to) {
+            self.depends_on[from].push(to);
+            self.dependents[to].push(from);
+        }
+    }
+
+    /// Objects ordered so each comes after the objects it depends on, otherwise in the
+    /// order they were added. A cycle is broken at its earliest added object.
+    pub fn creation_order(&self) -> Vec<&str> {
+        let mut remaining: Vec<usize> = self.depends_on.iter().map(Vec::len).collect();
+        let mut done = vec![false; self.keys.len()];
+        let mut ready: BinaryHeap<Reverse<usize>> =
+            (0..self.keys.len()).filter(|&node| remaining[node] == 0).map(Reverse).collect();
+        let mut order = Vec::with_capacity(self.keys.len());
+        let mut next_unvisited = 0;
+
+        while order.len() < self.keys.len() {
+            let node = match ready.pop() {
+                Some(Reverse(node)) if done[node] => continue,
+                Some(Reverse(node)) => node,
+                None => {
+                    // Only cycles are left
+                    while done[next_unvisited] {
+                        next_unvisited += 1;
+                    }
+                    next_unvisited
+                }
+            };
+            done[node] = true;
+            order.push(self.keys[node].as_str());
+            for &dependent in &self.dependents[node] {
+                remaining[dependent] -= 1;
+                if remaining[dependent] == 0 && !done[dependent] {
+                    ready.push(Reverse(dependent));
+                }
+            }
+        }
+        order
+    }
+
+    /// The reverse of `creation_order`: every object before the objects it depends on
+    pub fn drop_order(&self) -> Vec<&str> {
+        let mut order = self.creation_order();
+        order.reverse();
+        order
+    }
+
+    /// Objects that depend on `key` directly or through other objects, in drop order
+    pub fn dependents_of(&self, key: &str) -> Vec<&str> {
+        let Some(&start) = self.index.get(key) else {
+            return Vec::new();
+        };
+        let mut reached = vec![false; self.keys.len()];
+        let mut stack = vec![start];
+        reached[start] = true;
+        while let Some(node) = stack.pop() {
+            for &dependent in &self.dependents[node] {
+                if !reached[dependent] {
+                    reached[dependent] = true;
+                    stack.push(dependent);
+                }
+            }
+        }
+        self.drop_order()
+            .into_iter()
+            .filter(|dependent| *dependent != key && reached[self.index[*dependent]])
+            .collect()
+    }
+}
+
+/// Whether a view or routine definition mentions `name` as an identifier, bare or
+/// quoted. Used where the catalog does not record which objects a view reads.
+pub fn definition_mentions(definition: &str, name: &str) -> bool {
+    let definition = definition.to_lowercase();
+    let name = name.to_lowercase();
+    let is_identifier = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    definition.match_indices(&name).any(|(start, _)| {
+        let before = definition[..start].chars().next_back();
+        let after = definition[start + name.len()..].chars().next();
+        !before.is_some_and(is_identifier) && !after.is_some_and(is_identifier)
+    })
+}
+
+/// What dropping `table` with CASCADE takes with it: the views that read it, directly or
+/// through other views, in drop order, then the foreign keys of other tables that reference it
+pub fn drop_dependents(dependencies: &[ObjectDependency], table: &str) -> Vec<String> {
+    let mut views = DependencyGraph::new();
+    let mut view_types = HashMap::new();
+    for dependency in dependencies.iter().filter(|dependency| !dependency.is_foreign_key()) {
+        views.add_dependency(&dependency.object, &dependency.depends_on);
+        view_types.insert(dependency.object.as_str(), dependency.object_type.to_lowercase());
+    }
+
+    let mut dependents: Vec<String> = views.dependents_of(table)
+        .into_iter()
+        .map(|view| format!("{} {}", view_types.get(view).map(String::as_str).unwrap_or("view"), view))
+        .collect();
+    for foreign_key in dependencies.iter().filter(|d| d.is_foreign_key() && d.depends_on == table && d.object != table) {
+        dependents.push(format!(
+            "foreign key {} on {}",
+            foreign_key.constraint_name.as_deref().unwrap_or_default(),
+            foreign_key.object
+        ));
+    }
+    dependents
+}
+
+/// The tables TRUNCATE ... CASCADE empties along with `table`: those with a foreign key
+/// to it, directly or through other such tables, in drop order
+pub fn truncate_dependents(dependencies: &[ObjectDependency], table: &str) -> Vec<String> {
+    let mut tables = DependencyGraph::new();
+    for foreign_key in dependencies.iter().filter(|dependency| dependency.is_foreign_key()) {
+        tables.add_dependency(&foreign_key.object, &foreign_key.depends_on);
+    }
+    tables.dependents_of(table).into_iter().map(str::to_string).collect()
+}
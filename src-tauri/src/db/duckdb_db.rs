@@ -0,0 +1,1603 @@
+//! DuckDB driver. DuckDB runs inside the app rather than as a server: a
+//! connection opens a database file, or an in-memory database when the path
+//! is empty, and Parquet, CSV and JSON files are queried where they lie with
+//! `SELECT * FROM 'data.parquet'` or `read_csv('data.csv')`. Builds without
+//! the `duckdb` feature leave the engine out, and connecting fails.
+
+use crate::db::{
+    column_definition, combine_result_sets, definition_mentions, insert_statement, placeholder, quote_identifier,
+    quote_qualified, string_literal, BatchHandler, DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ActiveQuery, CellValue, ColumnInfo, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions,
+    DatabaseDdlOptions, DatabaseSize, DatabaseType, DatabaseUser, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo,
+    NewColumn, ObjectComment, ObjectDependency, QueryResult, ResultSet, SchemaComments, ServerStats, SlowQueryEntry,
+    SlowQueryOrder, TableInfo, TablePrivilege, TableProperties, TableRelationship, TableSchema, TableStats,
+    TestConnectionResult, TruncateOptions,
+};
+use async_trait::async_trait;
+use sql_parse::Dialect;
+use std::collections::HashMap;
+use std::time::Instant;
+
+pub use engine::DuckDbClient;
+
+pub struct DuckDbDriver;
+
+/// Path given for an in-memory database
+const IN_MEMORY: &str = ":memory:";
+
+#[cfg(feature = "duckdb")]
+mod engine {
+    use super::IN_MEMORY;
+    use crate::db::{BatchHandler, RowBatches};
+    use crate::error::{AppError, AppResult};
+    use crate::models::{CellValue, ColumnInfo, ResultSet};
+    use duckdb::arrow::array::{Array, ArrayRef, AsArray};
+    use duckdb::arrow::datatypes::{
+        ArrowPrimitiveType, DataType, Decimal128Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+        Int8Type, Schema, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    };
+    use duckdb::arrow::record_batch::RecordBatch;
+    use duckdb::arrow::util::display::{ArrayFormatter, FormatOptions};
+    use duckdb::types::Value;
+    use duckdb::{params_from_iter, AccessMode, Config, Connection};
+    use once_cell::sync::Lazy;
+    use serde_json::Value as Json;
+    use sql_parse::Dialect;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex, MutexGuard, Weak};
+    use std::time::Instant;
+    use tokio::sync::mpsc;
+
+    /// Timestamps written as the other drivers write them
+    const FORMAT: FormatOptions<'static> = FormatOptions::new()
+        .with_timestamp_format(Some("%Y-%m-%d %H:%M:%S%.f"))
+        .with_timestamp_tz_format(Some("%Y-%m-%d %H:%M:%S%.f%:z"));
+
+    /// Database files open in this process. A file must only be opened once,
+    /// so clients of the same file connect to the one open database.
+    static DATABASES: Lazy<Mutex<HashMap<PathBuf, Weak<Mutex<Connection>>>>> = Lazy::new(Default::default);
+
+    /// A connection to a DuckDB database. It runs one statement at a time on
+    /// the blocking thread pool; `session` opens another connection to the
+    /// same database for an editor tab.
+    pub struct DuckDbClient {
+        connection: Arc<Mutex<Connection>>,
+        /// Keeps the database open while any of its connections is
+        database: Arc<Mutex<Connection>>,
+        path: String,
+    }
+
+    impl DuckDbClient {
+        /// Open the database file at `path`, creating it if it is missing, or
+        /// an in-memory database when the path is empty
+        pub async fn open(path: String, read_only: bool) -> AppResult<Self> {
+            blocking(move || {
+                let database = if path.is_empty() || path == IN_MEMORY {
+                    Arc::new(Mutex::new(Connection::open_in_memory().map_err(open_error)?))
+                } else {
+                    open_file(&path, read_only)?
+                };
+                let connection = lock(&database).try_clone().map_err(open_error)?;
+                Ok(Self {
+                    connection: Arc::new(Mutex::new(connection)),
+                    database,
+                    path: if path.is_empty() { IN_MEMORY.to_string() } else { path },
+                })
+            })
+            .await
+        }
+
+        /// Another connection to the same database, with settings and
+        /// temporary tables of its own
+        pub fn session(&self) -> AppResult<Self> {
+            let connection = lock(&self.database).try_clone().map_err(open_error)?;
+            Ok(Self {
+                connection: Arc::new(Mutex::new(connection)),
+                database: self.database.clone(),
+                path: self.path.clone(),
+            })
+        }
+
+        /// The database file, or `:memory:`
+        pub fn path(&self) -> &str {
+            &self.path
+        }
+
+        /// Run one statement with `params`, keeping at most `max_rows` rows
+        pub async fn execute(
+            &self,
+            sql: String,
+            params: Vec<CellValue>,
+            max_rows: Option<usize>,
+        ) -> AppResult<ResultSet> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let params: Vec<Value> = params.into_iter().map(bind_value).collect();
+                run_statement(&lock(&connection), &sql, &params, max_rows)
+            })
+            .await
+        }
+
+        /// Run statements in one transaction, keeping every result set
+        pub async fn execute_script(
+            &self,
+            statements: Vec<String>,
+            max_rows: Option<usize>,
+        ) -> AppResult<Vec<ResultSet>> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let mut connection = lock(&connection);
+                let transaction = connection.transaction().map_err(query_error)?;
+                let result_sets = statements
+                    .iter()
+                    .map(|sql| run_statement(&transaction, sql, &[], max_rows))
+                    .collect::<AppResult<Vec<_>>>()?;
+                transaction.commit().map_err(query_error)?;
+                Ok(result_sets)
+            })
+            .await
+        }
+
+        /// Run the INSERT `sql` for each row. DuckDB aborts a transaction at
+        /// its first error and has no savepoints, so the rows go in together
+        /// and, when one fails, are inserted again one at a time.
+        pub async fn insert_rows(
+            &self,
+            sql: String,
+            rows: Vec<Vec<serde_json::Value>>,
+        ) -> AppResult<Vec<(usize, String)>> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let mut connection = lock(&connection);
+                let rows: Vec<Vec<Value>> = rows
+                    .into_iter()
+                    .map(|row| row.into_iter().map(|value| bind_value(value.into())).collect())
+                    .collect();
+
+                let transaction = connection.transaction().map_err(query_error)?;
+                let inserted = {
+                    let mut statement = transaction.prepare(&sql).map_err(query_error)?;
+                    rows.iter().all(|row| statement.execute(params_from_iter(row)).is_ok())
+                };
+                if inserted {
+                    transaction.commit().map_err(query_error)?;
+                    return Ok(vec![]);
+                }
+                transaction.rollback().map_err(query_error)?;
+
+                let mut statement = connection.prepare(&sql).map_err(query_error)?;
+                Ok(rows
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, row)| {
+                        statement
+                            .execute(params_from_iter(row))
+                            .err()
+                            .map(|e| (index, e.to_string()))
+                    })
+                    .collect())
+            })
+            .await
+        }
+
+        /// Read the rows of `sql` as DuckDB produces them, handing them to
+        /// `on_batch` `batch_size` at a time, and return how many there were
+        pub async fn stream(
+            &self,
+            sql: String,
+            batch_size: usize,
+            on_batch: &mut BatchHandler<'_>,
+        ) -> AppResult<usize> {
+            let connection = self.connection.clone();
+            let (sender, mut receiver) = mpsc::channel(2);
+            let reader = tokio::task::spawn_blocking(move || {
+                if let Err(e) = send_batches(&lock(&connection), &sql, &sender) {
+                    let _ = sender.blocking_send(Err(e));
+                }
+            });
+
+            // The first message holds the columns
+            let Some(first) = receiver.recv().await else {
+                reader.await.map_err(join_error)?;
+                return Err(AppError::QueryError("DuckDB returned no result".to_string()));
+            };
+            let mut batches = RowBatches::new(on_batch, first?.0, batch_size);
+            while let Some(message) = receiver.recv().await {
+                for row in message?.1 {
+                    batches.push(row)?;
+                }
+            }
+            reader.await.map_err(join_error)?;
+            batches.finish()
+        }
+    }
+
+    /// Open a database file, or connect to it when it is already open
+    fn open_file(path: &str, read_only: bool) -> AppResult<Arc<Mutex<Connection>>> {
+        let mut databases = DATABASES.lock().unwrap_or_else(|e| e.into_inner());
+        databases.retain(|_, database| database.strong_count() > 0);
+        let key = |path: &str| Path::new(path).canonicalize().ok();
+        if let Some(database) = key(path).and_then(|key| databases.get(&key)).and_then(Weak::upgrade) {
+            return Ok(database);
+        }
+
+        let mode = if read_only {
+            AccessMode::ReadOnly
+        } else {
+            AccessMode::Automatic
+        };
+        let config = Config::default().access_mode(mode).map_err(open_error)?;
+        let database = Arc::new(Mutex::new(
+            Connection::open_with_flags(path, config).map_err(open_error)?,
+        ));
+        if let Some(key) = key(path) {
+            databases.insert(key, Arc::downgrade(&database));
+        }
+        Ok(database)
+    }
+
+    /// Run blocking work on the blocking thread pool
+    async fn blocking<T: Send + 'static>(work: impl FnOnce() -> AppResult<T> + Send + 'static) -> AppResult<T> {
+        tokio::task::spawn_blocking(work).await.map_err(join_error)?
+    }
+
+    fn lock(connection: &Mutex<Connection>) -> MutexGuard<'_, Connection> {
+        connection.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn open_error(e: duckdb::Error) -> AppError {
+        AppError::ConnectionError(format!("Failed to open DuckDB database: {}", e))
+    }
+
+    fn query_error(e: duckdb::Error) -> AppError {
+        AppError::QueryError(e.to_string())
+    }
+
+    fn join_error(e: tokio::task::JoinError) -> AppError {
+        AppError::QueryError(format!("DuckDB stopped unexpectedly: {}", e))
+    }
+
+    /// Bind a value as its kind; DuckDB casts it to the type the statement expects
+    fn bind_value(value: CellValue) -> Value {
+        match value {
+            CellValue::Null => Value::Null,
+            CellValue::Bool(b) => Value::Boolean(b),
+            CellValue::Int(i) => Value::BigInt(i),
+            CellValue::Float(f) => Value::Double(f),
+            CellValue::Bytes(ref encoded) => match value.bytes() {
+                Some(bytes) => Value::Blob(bytes),
+                None => Value::Text(encoded.clone()),
+            },
+            CellValue::Text(text) | CellValue::Timestamp(text) => Value::Text(text),
+            CellValue::Json(json) => Value::Text(json.to_string()),
+        }
+    }
+
+    /// Run one statement, keeping at most `max_rows` rows of its result
+    fn run_statement(
+        connection: &Connection,
+        sql: &str,
+        params: &[Value],
+        max_rows: Option<usize>,
+    ) -> AppResult<ResultSet> {
+        let start = Instant::now();
+        let mut statement = connection.prepare(sql).map_err(query_error)?;
+        let changed = statement.execute(params_from_iter(params)).map_err(query_error)?;
+        let schema = statement.schema();
+
+        // Statements that change data answer with the count as their only column
+        let counts = schema.fields().is_empty() || (schema.fields().len() == 1 && schema.field(0).name() == "Count");
+        if counts && !sql_parse::returns_rows(sql, Dialect::DuckDb) {
+            return Ok(ResultSet {
+                statement: sql.to_string(),
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(changed as u64),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+            });
+        }
+
+        let mut rows = Vec::new();
+        while let Some(chunk) = statement.step() {
+            rows.extend(json_rows(chunk.columns()));
+            if let Some(max_rows) = max_rows.filter(|&max_rows| rows.len() >= max_rows) {
+                rows.truncate(max_rows);
+                break;
+            }
+        }
+        Ok(ResultSet {
+            statement: sql.to_string(),
+            columns: result_columns(&schema),
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    type Batch = AppResult<(Vec<ColumnInfo>, Vec<Vec<Json>>)>;
+
+    /// Send the columns of `sql`, then its rows a record batch at a time. The
+    /// result is streamed when a query for no rows describes it beforehand,
+    /// and read whole otherwise, as for PRAGMA and DESCRIBE.
+    fn send_batches(connection: &Connection, sql: &str, sender: &mpsc::Sender<Batch>) -> AppResult<()> {
+        let sql = sql.trim().trim_end_matches(';');
+        let described = connection
+            .prepare(&format!("SELECT * FROM ({}) LIMIT 0", sql))
+            .and_then(|mut probe| Ok(probe.query_arrow([])?.get_schema()))
+            .ok();
+
+        let mut statement = connection.prepare(sql).map_err(query_error)?;
+        let (schema, batches): (_, Box<dyn Iterator<Item = RecordBatch>>) = match described {
+            Some(schema) => (
+                schema.clone(),
+                Box::new(statement.stream_arrow([], schema).map_err(query_error)?),
+            ),
+            None => {
+                let batches = statement.query_arrow([]).map_err(query_error)?;
+                (batches.get_schema(), Box::new(batches))
+            }
+        };
+        if sender.blocking_send(Ok((result_columns(&schema), vec![]))).is_err() {
+            return Ok(());
+        }
+        for batch in batches {
+            // The receiver is gone when the handler failed
+            if sender.blocking_send(Ok((vec![], json_rows(batch.columns())))).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn result_columns(schema: &Schema) -> Vec<ColumnInfo> {
+        schema
+            .fields()
+            .iter()
+            .map(|field| ColumnInfo {
+                name: field.name().clone(),
+                data_type: type_name(field.data_type()),
+                nullable: field.is_nullable(),
+                is_primary_key: false,
+                native_type: Some(field.data_type().to_string()),
+                type_oid: None,
+            })
+            .collect()
+    }
+
+    /// DuckDB's name for the Arrow type a result column arrives as
+    fn type_name(data_type: &DataType) -> String {
+        let name = match data_type {
+            DataType::Boolean => "boolean",
+            DataType::Int8 => "tinyint",
+            DataType::Int16 => "smallint",
+            DataType::Int32 => "integer",
+            DataType::Int64 => "bigint",
+            DataType::UInt8 => "utinyint",
+            DataType::UInt16 => "usmallint",
+            DataType::UInt32 => "uinteger",
+            DataType::UInt64 => "ubigint",
+            DataType::Float32 => "float",
+            DataType::Float64 => "double",
+            DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+                return format!("decimal({},{})", precision, scale)
+            }
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => "varchar",
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView | DataType::FixedSizeBinary(_) => "blob",
+            DataType::Date32 | DataType::Date64 => "date",
+            DataType::Time32(_) | DataType::Time64(_) => "time",
+            DataType::Timestamp(_, None) => "timestamp",
+            DataType::Timestamp(_, Some(_)) => "timestamp with time zone",
+            DataType::Interval(_) | DataType::Duration(_) => "interval",
+            DataType::List(item) | DataType::LargeList(item) => return format!("{}[]", type_name(item.data_type())),
+            DataType::FixedSizeList(item, size) => return format!("{}[{}]", type_name(item.data_type()), size),
+            DataType::Struct(_) => "struct",
+            DataType::Map(..) => "map",
+            DataType::Dictionary(..) => "enum",
+            DataType::Union(..) => "union",
+            other => return other.to_string().to_lowercase(),
+        };
+        name.to_string()
+    }
+
+    /// Rows of a record batch, from its columns
+    fn json_rows(columns: &[ArrayRef]) -> Vec<Vec<Json>> {
+        let rows = columns.first().map_or(0, |column| column.len());
+        let mut values: Vec<_> = columns
+            .iter()
+            .map(|column| json_values(column.as_ref()).into_iter())
+            .collect();
+        (0..rows)
+            .map(|_| {
+                values
+                    .iter_mut()
+                    .map(|column| column.next().unwrap_or(Json::Null))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// A column's values as JSON. Integers stay numbers, decimals with a
+    /// scale and wide integers become strings so no digits are lost, binary
+    /// data is base64 as in the other drivers, and lists, structs and maps
+    /// become arrays and objects.
+    fn json_values(array: &dyn Array) -> Vec<Json> {
+        match array.data_type() {
+            DataType::Boolean => array
+                .as_boolean()
+                .iter()
+                .map(|v| v.map_or(Json::Null, Json::Bool))
+                .collect(),
+            DataType::Int8 => numbers::<Int8Type>(array),
+            DataType::Int16 => numbers::<Int16Type>(array),
+            DataType::Int32 => numbers::<Int32Type>(array),
+            DataType::Int64 => numbers::<Int64Type>(array),
+            DataType::UInt8 => numbers::<UInt8Type>(array),
+            DataType::UInt16 => numbers::<UInt16Type>(array),
+            DataType::UInt32 => numbers::<UInt32Type>(array),
+            DataType::UInt64 => numbers::<UInt64Type>(array),
+            DataType::Float32 => floats::<Float32Type>(array),
+            DataType::Float64 => floats::<Float64Type>(array),
+            // HUGEINT arrives as a decimal without scale
+            DataType::Decimal128(_, 0) => array
+                .as_primitive::<Decimal128Type>()
+                .iter()
+                .map(|v| {
+                    v.map_or(Json::Null, |v| {
+                        i64::try_from(v)
+                            .map(Json::from)
+                            .unwrap_or_else(|_| Json::String(v.to_string()))
+                    })
+                })
+                .collect(),
+            DataType::Utf8 => strings(array.as_string::<i32>().iter()),
+            DataType::LargeUtf8 => strings(array.as_string::<i64>().iter()),
+            DataType::Binary => blobs(array.as_binary::<i32>().iter()),
+            DataType::LargeBinary => blobs(array.as_binary::<i64>().iter()),
+            DataType::FixedSizeBinary(_) => blobs(array.as_fixed_size_binary().iter()),
+            DataType::List(_) => lists(array.as_list::<i32>().iter()),
+            DataType::LargeList(_) => lists(array.as_list::<i64>().iter()),
+            DataType::FixedSizeList(..) => lists(array.as_fixed_size_list().iter()),
+            DataType::Struct(fields) => {
+                let values: Vec<Vec<Json>> = array
+                    .as_struct()
+                    .columns()
+                    .iter()
+                    .map(|c| json_values(c.as_ref()))
+                    .collect();
+                (0..array.len())
+                    .map(|row| {
+                        if array.is_null(row) {
+                            return Json::Null;
+                        }
+                        fields
+                            .iter()
+                            .zip(&values)
+                            .map(|(field, values)| (field.name().clone(), values[row].clone()))
+                            .collect::<serde_json::Map<_, _>>()
+                            .into()
+                    })
+                    .collect()
+            }
+            DataType::Map(..) => array
+                .as_map()
+                .iter()
+                .map(|entries| {
+                    let Some(entries) = entries else {
+                        return Json::Null;
+                    };
+                    let keys = json_values(entries.column(0).as_ref());
+                    let values = json_values(entries.column(1).as_ref());
+                    keys.into_iter()
+                        .map(|key| match key {
+                            Json::String(key) => key,
+                            other => other.to_string(),
+                        })
+                        .zip(values)
+                        .collect::<serde_json::Map<_, _>>()
+                        .into()
+                })
+                .collect(),
+            _ => formatted(array),
+        }
+    }
+
+    fn numbers<T: ArrowPrimitiveType>(array: &dyn Array) -> Vec<Json>
+    where
+        T::Native: Into<Json>,
+    {
+        array
+            .as_primitive::<T>()
+            .iter()
+            .map(|v| v.map_or(Json::Null, Into::into))
+            .collect()
+    }
+
+    /// Floats as numbers, and NaN and infinities, which JSON has no numbers for, as text
+    fn floats<T: ArrowPrimitiveType>(array: &dyn Array) -> Vec<Json>
+    where
+        T::Native: Into<f64>,
+    {
+        array
+            .as_primitive::<T>()
+            .iter()
+            .map(|v| {
+                v.map_or(Json::Null, |v| {
+                    let v: f64 = v.into();
+                    serde_json::Number::from_f64(v)
+                        .map(Json::Number)
+                        .unwrap_or_else(|| Json::String(v.to_string()))
+                })
+            })
+            .collect()
+    }
+
+    fn strings<'a>(values: impl Iterator<Item = Option<&'a str>>) -> Vec<Json> {
+        values
+            .map(|v| v.map_or(Json::Null, |v| Json::String(v.to_string())))
+            .collect()
+    }
+
+    /// Binary data, marked so the blob viewer and exporters can decode it
+    fn blobs<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<Json> {
+        use base64::{engine::general_purpose, Engine as _};
+        values
+            .map(|v| {
+                v.map_or(Json::Null, |v| {
+                    Json::String(format!("[base64: {}]", general_purpose::STANDARD.encode(v)))
+                })
+            })
+            .collect()
+    }
+
+    fn lists(values: impl Iterator<Item = Option<ArrayRef>>) -> Vec<Json> {
+        values
+            .map(|v| v.map_or(Json::Null, |v| Json::Array(json_values(v.as_ref()))))
+            .collect()
+    }
+
+    /// Dates, times, timestamps, intervals, decimals and enums as text
+    fn formatted(array: &dyn Array) -> Vec<Json> {
+        let formatter = ArrayFormatter::try_new(array, &FORMAT).ok();
+        (0..array.len())
+            .map(|row| {
+                if array.is_null(row) {
+                    return Json::Null;
+                }
+                let text = formatter
+                    .as_ref()
+                    .and_then(|formatter| formatter.value(row).try_to_string().ok());
+                Json::String(text.unwrap_or_else(|| "Unsupported type".to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(feature = "duckdb"))]
+mod engine {
+    use crate::db::BatchHandler;
+    use crate::error::{AppError, AppResult};
+    use crate::models::{CellValue, ResultSet};
+
+    /// Stands in for the client in builds without the `duckdb` feature. It
+    /// has no values, so opening a database is the only call that can happen.
+    pub enum DuckDbClient {}
+
+    impl DuckDbClient {
+        pub async fn open(_path: String, _read_only: bool) -> AppResult<Self> {
+            Err(AppError::ConfigError(
+                "This build does not include the DuckDB driver".to_string(),
+            ))
+        }
+
+        pub fn session(&self) -> AppResult<Self> {
+            match *self {}
+        }
+
+        pub fn path(&self) -> &str {
+            match *self {}
+        }
+
+        pub async fn execute(
+            &self,
+            _sql: String,
+            _params: Vec<CellValue>,
+            _max_rows: Option<usize>,
+        ) -> AppResult<ResultSet> {
+            match *self {}
+        }
+
+        pub async fn execute_script(
+            &self,
+            _statements: Vec<String>,
+            _max_rows: Option<usize>,
+        ) -> AppResult<Vec<ResultSet>> {
+            match *self {}
+        }
+
+        pub async fn insert_rows(
+            &self,
+            _sql: String,
+            _rows: Vec<Vec<serde_json::Value>>,
+        ) -> AppResult<Vec<(usize, String)>> {
+            match *self {}
+        }
+
+        pub async fn stream(
+            &self,
+            _sql: String,
+            _batch_size: usize,
+            _on_batch: &mut BatchHandler<'_>,
+        ) -> AppResult<usize> {
+            match *self {}
+        }
+    }
+}
+
+impl DuckDbClient {
+    /// Rows of a metadata query
+    async fn rows(&self, sql: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        Ok(self.execute(sql.to_string(), vec![], None).await?.rows)
+    }
+}
+
+fn text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn number(value: &serde_json::Value) -> Option<i64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_i64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+/// The strings of a list value, such as the columns of a constraint
+fn names(value: &serde_json::Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| items.iter().map(text).collect())
+        .unwrap_or_default()
+}
+
+fn literal(text: &str) -> String {
+    string_literal(&DatabaseType::DuckDB, text)
+}
+
+/// Conditions matching a table of the current database in the `duckdb_*`
+/// functions; a name without a schema is looked up in the current one
+fn table_filter(table_name: &str, column: &str) -> String {
+    match table_name.split_once('.') {
+        Some((schema, table)) => format!(
+            "database_name = current_database() AND schema_name = {} AND {} = {}",
+            literal(schema),
+            column,
+            literal(table)
+        ),
+        None => format!(
+            "database_name = current_database() AND schema_name = current_schema() AND {} = {}",
+            column,
+            literal(table_name)
+        ),
+    }
+}
+
+/// Schema of a table name, when it names one
+fn schema_of(table_name: &str) -> Option<&str> {
+    table_name.split_once('.').map(|(schema, _)| schema)
+}
+
+fn client_for(pool: PoolRef<'_>) -> AppResult<&DuckDbClient> {
+    match pool {
+        PoolRef::DuckDb(client) => Ok(client),
+        _ => Err(AppError::QueryError("Invalid pool type for DuckDB driver".to_string())),
+    }
+}
+
+/// A single result set as the whole result
+fn query_result(set: ResultSet, start: Instant) -> QueryResult {
+    QueryResult {
+        columns: set.columns,
+        rows: set.rows,
+        affected_rows: set.affected_rows,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    }
+}
+
+/// Helper methods for DuckDbDriver
+impl DuckDbDriver {
+    /// Open the connection's database
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<DuckDbClient> {
+        DuckDbClient::open(Self.build_connection_string(config), config.is_read_only()).await
+    }
+
+    /// Run SQL typed by the user; a script runs in one transaction
+    async fn execute_statements(client: &DuckDbClient, sql: &str, max_rows: Option<usize>) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let mut statements = sql_parse::split_statements(sql, Dialect::DuckDb);
+        if statements.len() > 1 {
+            let result_sets = client.execute_script(statements, max_rows).await?;
+            return Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64));
+        }
+        let sql = statements.pop().unwrap_or_default();
+        Ok(query_result(client.execute(sql, vec![], max_rows).await?, start))
+    }
+
+    /// Run a DDL statement
+    async fn execute_ddl(client: &DuckDbClient, sql: &str, context: &str) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let set = client
+            .execute(sql.to_string(), vec![], None)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to {}: {}", context, e)))?;
+        Ok(query_result(set, start))
+    }
+
+    /// Columns of a table or view from duckdb_columns(), in table order
+    async fn table_columns(client: &DuckDbClient, table_name: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        let rows = client
+            .rows(&format!(
+                "SELECT column_name, data_type, is_nullable, column_default, comment \
+                 FROM duckdb_columns() WHERE {} ORDER BY column_index",
+                table_filter(table_name, "table_name")
+            ))
+            .await?;
+        if rows.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+        Ok(rows)
+    }
+
+    /// Constraints of a table: type, name, columns, referenced table,
+    /// referenced columns and definition
+    async fn table_constraints(client: &DuckDbClient, table_name: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        client
+            .rows(&format!(
+                "SELECT constraint_type, constraint_name, constraint_column_names, referenced_table, \
+                 referenced_column_names, constraint_text \
+                 FROM duckdb_constraints() WHERE {} ORDER BY constraint_index",
+                table_filter(table_name, "table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch constraints: {}", e)))
+    }
+}
+
+/// Primary key columns among constraint rows
+fn primary_keys(constraints: &[Vec<serde_json::Value>]) -> Vec<String> {
+    constraints
+        .iter()
+        .filter(|row| text(&row[0]) == "PRIMARY KEY")
+        .flat_map(|row| names(&row[2]))
+        .collect()
+}
+
+/// Foreign keys among constraint rows; a key references a table of its own schema
+fn foreign_keys(constraints: &[Vec<serde_json::Value>], schema: Option<&str>) -> Vec<ForeignKeyInfo> {
+    constraints
+        .iter()
+        .filter(|row| text(&row[0]) == "FOREIGN KEY")
+        .flat_map(|row| {
+            let references_table = match schema {
+                Some(schema) => format!("{}.{}", schema, text(&row[3])),
+                None => text(&row[3]),
+            };
+            names(&row[2])
+                .into_iter()
+                .zip(names(&row[4]))
+                .map(move |(column, references_column)| ForeignKeyInfo {
+                    column,
+                    references_table: references_table.clone(),
+                    references_column,
+                })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl DatabaseDriver for DuckDbDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let client = Self::connect(config).await?;
+        let version = client
+            .rows("SELECT version()")
+            .await?
+            .first()
+            .and_then(|row| row.first())
+            .map(text);
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!("DuckDB connection to {} successful", client.path()),
+            server_version: version,
+        })
+    }
+
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        Self::execute_statements(client_for(pool)?, sql, None).await
+    }
+
+    /// Reading stops at the row limit; DuckDB has no statement timeout
+    async fn execute_query_limited(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        limits: &QueryLimits,
+    ) -> AppResult<QueryResult> {
+        Self::execute_statements(client_for(pool)?, sql, limits.max_rows).await
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        client_for(pool)?.stream(sql.to_string(), batch_size, on_batch).await
+    }
+
+    async fn execute_with_params(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        params: Vec<CellValue>,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let set = client_for(pool)?.execute(sql.to_string(), params, None).await?;
+        Ok(query_result(set, start))
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::DuckDB, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let sql = insert_statement(&DatabaseType::DuckDB, table, columns);
+        client_for(pool)?.insert_rows(sql, rows).await
+    }
+
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let rows = client_for(pool)?
+            .rows(
+                "SELECT schema_name, table_name, 'BASE TABLE', estimated_size FROM duckdb_tables() \
+                 WHERE database_name = current_database() AND NOT internal AND NOT temporary \
+                 UNION ALL \
+                 SELECT schema_name, view_name, 'VIEW', NULL FROM duckdb_views() \
+                 WHERE database_name = current_database() AND NOT internal AND NOT temporary \
+                 ORDER BY 1, 2",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch tables: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TableInfo {
+                name: format!("{}.{}", text(&row[0]), text(&row[1])),
+                schema: Some(text(&row[0])),
+                table_type: text(&row[2]),
+                row_count: number(&row[3]),
+                parent_table: None,
+                pinned: false,
+            })
+            .collect())
+    }
+
+    /// Databases attached to the connection, including the one it opened
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let rows = client_for(pool)?
+            .rows("SELECT database_name FROM duckdb_databases() WHERE NOT internal ORDER BY 1")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+        Ok(rows.iter().map(|row| text(&row[0])).collect())
+    }
+
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let rows = client_for(pool)?
+            .rows(
+                "SELECT schema_name FROM duckdb_schemas() \
+                 WHERE database_name = current_database() AND NOT internal ORDER BY 1",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list schemas: {}", e)))?;
+        Ok(rows.iter().map(|row| text(&row[0])).collect())
+    }
+
+    async fn create_database(&self, _pool: PoolRef<'_>, _options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError(
+            "DuckDB databases are files; create one with a new connection".to_string(),
+        ))
+    }
+
+    async fn drop_database(&self, _pool: PoolRef<'_>, _name: &str) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError(
+            "DuckDB databases are files; delete the file instead".to_string(),
+        ))
+    }
+
+    /// DuckDB schemas have no owner
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        let sql = format!(
+            "CREATE SCHEMA {}",
+            quote_identifier(&DatabaseType::DuckDB, &options.name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "create schema").await
+    }
+
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, cascade: bool) -> AppResult<QueryResult> {
+        let sql = format!(
+            "DROP SCHEMA {}{}",
+            quote_identifier(&DatabaseType::DuckDB, name),
+            if cascade { " CASCADE" } else { "" }
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "drop schema").await
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let client = client_for(pool)?;
+        let rows = Self::table_columns(client, table_name).await?;
+        let constraints = Self::table_constraints(client, table_name).await?;
+        let primary_keys = primary_keys(&constraints);
+
+        let columns = rows
+            .iter()
+            .map(|row| {
+                let data_type = text(&row[1]);
+                ColumnInfo {
+                    name: text(&row[0]),
+                    data_type: data_type.to_lowercase(),
+                    nullable: row[2].as_bool().unwrap_or(true),
+                    is_primary_key: primary_keys.contains(&text(&row[0])),
+                    native_type: Some(data_type),
+                    type_oid: None,
+                }
+            })
+            .collect();
+
+        Ok(TableSchema {
+            table_name: table_name.to_string(),
+            columns,
+            foreign_keys: foreign_keys(&constraints, schema_of(table_name)),
+            primary_keys,
+        })
+    }
+
+    async fn get_all_table_schemas(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+    ) -> AppResult<Vec<TableSchema>> {
+        let client = client_for(pool)?;
+        let rows = client
+            .rows(
+                "SELECT schema_name || '.' || table_name, column_name, data_type, is_nullable FROM duckdb_columns() \
+                 WHERE database_name = current_database() AND NOT internal \
+                 ORDER BY schema_name, table_name, column_index",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch schemas: {}", e)))?;
+        let constraint_rows = client
+            .rows(
+                "SELECT schema_name || '.' || table_name, constraint_type, constraint_name, constraint_column_names, \
+                 referenced_table, referenced_column_names, constraint_text FROM duckdb_constraints() \
+                 WHERE database_name = current_database() AND constraint_type IN ('PRIMARY KEY', 'FOREIGN KEY')",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch constraints: {}", e)))?;
+        let mut constraints: HashMap<String, Vec<Vec<serde_json::Value>>> = HashMap::new();
+        for row in constraint_rows {
+            constraints.entry(text(&row[0])).or_default().push(row[1..].to_vec());
+        }
+
+        let mut schemas: Vec<TableSchema> = Vec::new();
+        for row in &rows {
+            let table_name = text(&row[0]);
+            if schemas.last().is_none_or(|schema| schema.table_name != table_name) {
+                let table_constraints = constraints.get(&table_name).map(Vec::as_slice).unwrap_or_default();
+                schemas.push(TableSchema {
+                    primary_keys: primary_keys(table_constraints),
+                    foreign_keys: foreign_keys(table_constraints, schema_of(&table_name)),
+                    table_name,
+                    columns: vec![],
+                });
+            }
+            let Some(schema) = schemas.last_mut() else {
+                continue;
+            };
+
+            let data_type = text(&row[2]);
+            let name = text(&row[1]);
+            schema.columns.push(ColumnInfo {
+                is_primary_key: schema.primary_keys.contains(&name),
+                name,
+                data_type: data_type.to_lowercase(),
+                nullable: row[3].as_bool().unwrap_or(true),
+                native_type: Some(data_type),
+                type_oid: None,
+            });
+        }
+        Ok(schemas)
+    }
+
+    /// Path of the database file; `:memory:` for an in-memory database
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let path = config.file_path.as_deref().unwrap_or(config.database.as_str()).trim();
+        if path.is_empty() {
+            IN_MEMORY.to_string()
+        } else {
+            path.to_string()
+        }
+    }
+
+    /// The statement DuckDB keeps for the table or view, and the table's indexes
+    async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT sql FROM duckdb_tables() WHERE {} \
+                 UNION ALL SELECT sql FROM duckdb_views() WHERE {} \
+                 UNION ALL SELECT sql FROM duckdb_indexes() WHERE {} AND sql IS NOT NULL",
+                table_filter(table_name, "table_name"),
+                table_filter(table_name, "view_name"),
+                table_filter(table_name, "table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to generate DDL: {}", e)))?;
+        if rows.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+
+        let statements: Vec<String> = rows
+            .iter()
+            .map(|row| format!("{};", text(&row[0]).trim().trim_end_matches(';')))
+            .collect();
+        Ok(statements.join("\n\n"))
+    }
+
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let client = client_for(pool)?;
+        let foreign_keys = client
+            .rows(
+                "SELECT schema_name, table_name, referenced_table, constraint_name FROM duckdb_constraints() \
+                 WHERE database_name = current_database() AND constraint_type = 'FOREIGN KEY' \
+                 ORDER BY schema_name, table_name, constraint_index",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get foreign keys: {}", e)))?;
+        let mut dependencies: Vec<ObjectDependency> = foreign_keys
+            .iter()
+            .map(|row| ObjectDependency {
+                object: format!("{}.{}", text(&row[0]), text(&row[1])),
+                object_type: "TABLE".to_string(),
+                depends_on: format!("{}.{}", text(&row[0]), text(&row[2])),
+                constraint_name: Some(text(&row[3])),
+            })
+            .collect();
+
+        // DuckDB keeps no record of what a view reads, so view queries are searched
+        let objects = client
+            .rows(
+                "SELECT schema_name, table_name, NULL FROM duckdb_tables() \
+                 WHERE database_name = current_database() AND NOT internal \
+                 UNION ALL SELECT schema_name, view_name, sql FROM duckdb_views() \
+                 WHERE database_name = current_database() AND NOT internal",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get view definitions: {}", e)))?;
+        for view in objects.iter().filter(|row| !row[2].is_null()) {
+            let definition = text(&view[2]);
+            let name = format!("{}.{}", text(&view[0]), text(&view[1]));
+            for object in &objects {
+                let object_name = format!("{}.{}", text(&object[0]), text(&object[1]));
+                if object_name != name && definition_mentions(&definition, &text(&object[1])) {
+                    dependencies.push(ObjectDependency {
+                        object: name.clone(),
+                        object_type: "VIEW".to_string(),
+                        depends_on: object_name,
+                        constraint_name: None,
+                    });
+                }
+            }
+        }
+        Ok(dependencies)
+    }
+
+    /// The statements DuckDB keeps for each object, which name objects
+    /// outside the main schema with their schema
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        _options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let client = client_for(pool)?;
+        let dependencies = self.get_object_dependencies(pool).await?;
+        let mut depends_on: HashMap<String, Vec<String>> = HashMap::new();
+        for dependency in dependencies {
+            depends_on
+                .entry(dependency.object)
+                .or_default()
+                .push(dependency.depends_on);
+        }
+
+        let rows = client
+            .rows(
+                "SELECT 'schema', schema_name, NULL, sql FROM duckdb_schemas() \
+                 WHERE database_name = current_database() AND NOT internal AND schema_name <> 'main' \
+                 UNION ALL SELECT 'sequence', schema_name, sequence_name, sql FROM duckdb_sequences() \
+                 WHERE database_name = current_database() AND NOT temporary \
+                 UNION ALL SELECT 'macro', schema_name, function_name, \
+                 'CREATE MACRO ' || quote_name || '(' || array_to_string(parameters, ', ') || ') AS ' \
+                 || CASE WHEN function_type = 'table_macro' THEN 'TABLE ' ELSE '' END || macro_definition \
+                 FROM (SELECT *, CASE WHEN schema_name = 'main' THEN '' ELSE schema_name || '.' END \
+                 || function_name AS quote_name FROM duckdb_functions()) \
+                 WHERE database_name = current_database() AND NOT internal \
+                 AND function_type IN ('macro', 'table_macro') \
+                 UNION ALL SELECT 'table', schema_name, table_name, sql FROM duckdb_tables() \
+                 WHERE database_name = current_database() AND NOT internal AND NOT temporary \
+                 UNION ALL SELECT 'index', schema_name, index_name, sql FROM duckdb_indexes() \
+                 WHERE database_name = current_database() AND sql IS NOT NULL \
+                 UNION ALL SELECT 'view', schema_name, view_name, sql FROM duckdb_views() \
+                 WHERE database_name = current_database() AND NOT internal AND NOT temporary",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list schema objects: {}", e)))?;
+
+        let mut objects = Vec::new();
+        for row in &rows {
+            let schema = text(&row[1]);
+            let create = format!("{};", text(&row[3]).trim().trim_end_matches(';'));
+            let key = format!("{}.{}", schema, text(&row[2]));
+            let quoted = quote_qualified(&DatabaseType::DuckDB, &key);
+            let object = match text(&row[0]).as_str() {
+                "schema" => {
+                    let quoted = quote_identifier(&DatabaseType::DuckDB, &schema);
+                    DdlObject::new(
+                        DdlKind::Schema,
+                        schema,
+                        format!("CREATE SCHEMA IF NOT EXISTS {};", quoted),
+                    )
+                    .with_drop(format!("DROP SCHEMA IF EXISTS {};", quoted))
+                }
+                // DuckDB leaves the schema out of a sequence's statement
+                "sequence" => {
+                    let create = match create.find(" INCREMENT BY ") {
+                        Some(options) => format!("CREATE SEQUENCE {}{}", quoted, &create[options..]),
+                        None => create,
+                    };
+                    DdlObject::new(DdlKind::Sequence, key, create)
+                        .with_drop(format!("DROP SEQUENCE IF EXISTS {};", quoted))
+                }
+                "macro" => DdlObject::new(DdlKind::Function, key, create)
+                    .with_drop(format!("DROP MACRO IF EXISTS {};", quoted)),
+                "table" => DdlObject::new(DdlKind::Table, key.clone(), create)
+                    .with_drop(format!("DROP TABLE IF EXISTS {};", quoted))
+                    .with_dependencies(depends_on.remove(&key).unwrap_or_default()),
+                "index" => DdlObject::new(DdlKind::Index, key, create),
+                _ => DdlObject::new(DdlKind::View, key.clone(), create)
+                    .with_drop(format!("DROP VIEW IF EXISTS {};", quoted))
+                    .with_dependencies(depends_on.remove(&key).unwrap_or_default()),
+            };
+            objects.push(object);
+        }
+        Ok(objects)
+    }
+
+    /// The new name stays in the table's schema. DuckDB renames views only
+    /// with ALTER VIEW.
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let client = client_for(pool)?;
+        let is_view = !client
+            .rows(&format!(
+                "SELECT 1 FROM duckdb_views() WHERE {}",
+                table_filter(old_name, "view_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename table: {}", e)))?
+            .is_empty();
+        let new_name = new_name.rsplit('.').next().unwrap_or(new_name);
+        let sql = format!(
+            "ALTER {} {} RENAME TO {}",
+            if is_view { "VIEW" } else { "TABLE" },
+            quote_qualified(&DatabaseType::DuckDB, old_name),
+            quote_identifier(&DatabaseType::DuckDB, new_name)
+        );
+        Self::execute_ddl(client, &sql, "rename table").await
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            quote_qualified(&DatabaseType::DuckDB, table_name),
+            column_definition(&DatabaseType::DuckDB, column)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "add column").await
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            quote_qualified(&DatabaseType::DuckDB, table_name),
+            quote_identifier(&DatabaseType::DuckDB, column_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "drop column").await
+    }
+
+    async fn rename_column(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            quote_qualified(&DatabaseType::DuckDB, table_name),
+            quote_identifier(&DatabaseType::DuckDB, old_name),
+            quote_identifier(&DatabaseType::DuckDB, new_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "rename column").await
+    }
+
+    /// The type and the nullability change in one transaction
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        let table = quote_qualified(&DatabaseType::DuckDB, table_name);
+        let column = quote_identifier(&DatabaseType::DuckDB, column_name);
+        let statements = vec![
+            format!("ALTER TABLE {} ALTER COLUMN {} TYPE {}", table, column, data_type),
+            format!(
+                "ALTER TABLE {} ALTER COLUMN {} {} NOT NULL",
+                table,
+                column,
+                if nullable { "DROP" } else { "SET" }
+            ),
+        ];
+        let start = Instant::now();
+        let result_sets = client_for(pool)?
+            .execute_script(statements, None)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to change column type: {}", e)))?;
+        Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64))
+    }
+
+    /// The PostgreSQL-only options are ignored
+    async fn truncate_table(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        _options: &TruncateOptions,
+    ) -> AppResult<QueryResult> {
+        let sql = format!("TRUNCATE {}", quote_qualified(&DatabaseType::DuckDB, table_name));
+        Self::execute_ddl(client_for(pool)?, &sql, "truncate table").await
+    }
+
+    /// `CREATE TABLE ... AS` copies the columns and their types, not the constraints
+    async fn duplicate_table(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        new_name: &str,
+        include_data: bool,
+    ) -> AppResult<QueryResult> {
+        let sql = format!(
+            "CREATE TABLE {} AS SELECT * FROM {}{}",
+            quote_qualified(&DatabaseType::DuckDB, new_name),
+            quote_qualified(&DatabaseType::DuckDB, table_name),
+            if include_data { "" } else { " LIMIT 0" }
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "duplicate table").await
+    }
+
+    /// Primary and unique keys, which DuckDB enforces with indexes of their
+    /// own, and the indexes created with CREATE INDEX
+    async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let client = client_for(pool)?;
+        let constraints = Self::table_constraints(client, table_name).await?;
+        let mut indexes: Vec<IndexInfo> = constraints
+            .iter()
+            .filter(|row| matches!(text(&row[0]).as_str(), "PRIMARY KEY" | "UNIQUE"))
+            .map(|row| IndexInfo {
+                name: text(&row[1]),
+                columns: names(&row[2]),
+                is_unique: true,
+                is_primary: text(&row[0]) == "PRIMARY KEY",
+            })
+            .collect();
+
+        let rows = client
+            .rows(&format!(
+                "SELECT index_name, is_unique, expressions FROM duckdb_indexes() WHERE {} ORDER BY index_name",
+                table_filter(table_name, "table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to fetch indexes: {}", e)))?;
+        indexes.extend(rows.iter().map(|row| {
+            IndexInfo {
+                name: text(&row[0]),
+                // Expressions are listed as text, such as `[a, lower(b)]`
+                columns: match &row[2] {
+                    serde_json::Value::Array(_) => names(&row[2]),
+                    other => text(other)
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .split(", ")
+                        .map(|expression| expression.trim_matches('\'').to_string())
+                        .collect(),
+                },
+                is_unique: row[1].as_bool().unwrap_or(false),
+                is_primary: false,
+            }
+        }));
+        Ok(indexes)
+    }
+
+    async fn get_constraints(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        let constraints = Self::table_constraints(client_for(pool)?, table_name).await?;
+        Ok(constraints
+            .iter()
+            .filter(|row| matches!(text(&row[0]).as_str(), "CHECK" | "UNIQUE"))
+            .map(|row| ConstraintInfo {
+                name: text(&row[1]),
+                constraint_type: text(&row[0]),
+                definition: text(&row[5]),
+            })
+            .collect())
+    }
+
+    async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties> {
+        let client = client_for(pool)?;
+        let rows = Self::table_columns(client, table_name).await?;
+        let constraints = Self::table_constraints(client, table_name).await?;
+        let primary_keys = primary_keys(&constraints);
+
+        let columns = rows
+            .iter()
+            .map(|row| {
+                let name = text(&row[0]);
+                let default_value = Some(text(&row[3])).filter(|d| !d.is_empty());
+                ExtendedColumnInfo {
+                    is_primary_key: primary_keys.contains(&name),
+                    // Columns filled from a sequence default to nextval('...')
+                    is_identity: default_value.as_deref().is_some_and(|d| d.starts_with("nextval(")),
+                    name,
+                    data_type: text(&row[1]),
+                    nullable: row[2].as_bool().unwrap_or(true),
+                    default_value,
+                    comment: Some(text(&row[4])).filter(|c| !c.is_empty()),
+                    identity_always: false,
+                    generation_expression: None,
+                    collation: None,
+                }
+            })
+            .collect();
+
+        let table = client
+            .rows(&format!(
+                "SELECT estimated_size, comment FROM duckdb_tables() WHERE {} \
+                 UNION ALL SELECT NULL, comment FROM duckdb_views() WHERE {}",
+                table_filter(table_name, "table_name"),
+                table_filter(table_name, "view_name")
+            ))
+            .await?;
+        let table = table.first();
+        let (row_count, row_count_exact) = self
+            .row_count_from_estimate(pool, table_name, table.and_then(|row| number(&row[0])))
+            .await;
+
+        Ok(TableProperties {
+            table_name: table_name.to_string(),
+            schema: schema_of(table_name).map(str::to_string),
+            columns,
+            foreign_keys: foreign_keys(&constraints, schema_of(table_name)),
+            primary_keys,
+            indexes: self.get_indexes(pool, table_name).await?,
+            constraints: self.get_constraints(pool, table_name).await?,
+            row_count,
+            row_count_exact,
+            table_comment: table.map(|row| text(&row[1])).filter(|c| !c.is_empty()),
+        })
+    }
+
+    /// Rows from the table's storage; DuckDB does not report sizes per table
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT estimated_size FROM duckdb_tables() WHERE {}",
+                table_filter(table_name, "table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?;
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            approximate_rows: rows.first().and_then(|row| number(&row[0])).map(|rows| rows as u64),
+            ..TableStats::default()
+        })
+    }
+
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT count(*) FROM {}",
+                quote_qualified(&DatabaseType::DuckDB, table_name)
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))?;
+        rows.first()
+            .and_then(|row| row.first())
+            .and_then(number)
+            .ok_or_else(|| AppError::QueryError(format!("Failed to count rows of {}", table_name)))
+    }
+
+    async fn get_comments(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectComment>> {
+        let rows = client_for(pool)?
+            .rows(
+                "SELECT schema_name || '.' || table_name, NULL, comment FROM duckdb_tables() \
+                 WHERE database_name = current_database() AND NOT internal AND comment IS NOT NULL \
+                 UNION ALL SELECT schema_name || '.' || view_name, NULL, comment FROM duckdb_views() \
+                 WHERE database_name = current_database() AND NOT internal AND comment IS NOT NULL \
+                 UNION ALL SELECT schema_name || '.' || table_name, column_name, comment FROM duckdb_columns() \
+                 WHERE database_name = current_database() AND NOT internal AND comment IS NOT NULL",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get comments: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ObjectComment {
+                table: text(&row[0]),
+                column: Some(text(&row[1])).filter(|column| !column.is_empty()),
+                comment: text(&row[2]),
+            })
+            .collect())
+    }
+
+    async fn comment_statements(
+        &self,
+        _pool: PoolRef<'_>,
+        table_name: &str,
+        comments: &SchemaComments,
+    ) -> AppResult<Vec<String>> {
+        let table = quote_qualified(&DatabaseType::DuckDB, table_name);
+        // IS NULL removes a comment
+        let comment = |comment: &str| {
+            if comment.is_empty() {
+                "NULL".to_string()
+            } else {
+                literal(comment)
+            }
+        };
+
+        let mut statements = Vec::with_capacity(comments.columns.len() + 1);
+        if let Some(table_comment) = &comments.table_comment {
+            statements.push(format!("COMMENT ON TABLE {} IS {}", table, comment(table_comment)));
+        }
+        for column in &comments.columns {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                table,
+                quote_identifier(&DatabaseType::DuckDB, &column.column),
+                comment(&column.comment)
+            ));
+        }
+        Ok(statements)
+    }
+
+    /// Foreign keys of the table and those referencing it, which must be in its schema
+    async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        let (schema, table) = match table_name.split_once('.') {
+            Some((schema, table)) => (literal(schema), table),
+            None => ("current_schema()".to_string(), table_name),
+        };
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT schema_name, table_name, constraint_column_names, referenced_table, \
+                 referenced_column_names, constraint_name FROM duckdb_constraints() \
+                 WHERE database_name = current_database() AND schema_name = {} AND constraint_type = 'FOREIGN KEY' \
+                 AND (table_name = {} OR referenced_table = {}) ORDER BY table_name, constraint_index",
+                schema,
+                literal(table),
+                literal(table)
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get foreign keys: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .flat_map(|row| {
+                let schema = text(&row[0]);
+                let source_table = format!("{}.{}", schema, text(&row[1]));
+                let target_table = format!("{}.{}", schema, text(&row[3]));
+                let constraint_name = Some(text(&row[5]));
+                names(&row[2])
+                    .into_iter()
+                    .zip(names(&row[4]))
+                    .map(move |(source_column, target_column)| TableRelationship {
+                        source_table: source_table.clone(),
+                        source_column,
+                        target_table: target_table.clone(),
+                        target_column,
+                        constraint_name: constraint_name.clone(),
+                    })
+            })
+            .collect())
+    }
+
+    async fn get_users_and_roles(&self, _pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        Err(AppError::ValidationError(
+            "DuckDB has no users or privileges".to_string(),
+        ))
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError(
+            "DuckDB has no users or privileges".to_string(),
+        ))
+    }
+
+    /// Sizes of the attached databases; DuckDB runs inside the app, so there
+    /// are no connections or uptime to report
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let rows = client_for(pool)?
+            .rows("SELECT database_name, used_blocks * block_size FROM pragma_database_size() ORDER BY 2 DESC")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+
+        Ok(ServerStats {
+            active_connections: None,
+            max_connections: None,
+            uptime_seconds: None,
+            cache_hit_ratio: None,
+            database_sizes: rows
+                .iter()
+                .map(|row| DatabaseSize {
+                    name: text(&row[0]),
+                    size_bytes: number(&row[1]).unwrap_or(0) as u64,
+                })
+                .collect(),
+        })
+    }
+
+    async fn get_active_queries(&self, _pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        Err(AppError::ValidationError(
+            "DuckDB runs inside the app and has no server sessions".to_string(),
+        ))
+    }
+
+    async fn kill_session(&self, _pool: PoolRef<'_>, _session_id: &str) -> AppResult<()> {
+        Err(AppError::ValidationError(
+            "DuckDB runs inside the app and has no server sessions".to_string(),
+        ))
+    }
+
+    async fn get_slow_queries(
+        &self,
+        _pool: PoolRef<'_>,
+        _order: SlowQueryOrder,
+        _limit: u32,
+    ) -> AppResult<Vec<SlowQueryEntry>> {
+        Err(AppError::ValidationError(
+            "DuckDB does not keep statement statistics".to_string(),
+        ))
+    }
+}
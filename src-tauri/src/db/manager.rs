@@ -1,22 +1,45 @@
+use crate::env_vars;
 use crate::error::{AppError, AppResult};
-use crate::models::{ConnectionConfig, DatabaseType};
-use crate::db::PoolRef;
+use crate::metrics;
+use crate::models::{ConnectionConfig, DatabaseType, PoolSettings};
+use crate::db::{
+    append_query_params, check_tls_files, mysql_tls_params, postgres_tls_params, ClickHouseClient,
+    ClickHouseDriver, DatabaseDriver, DuckDbClient, DuckDbDriver, MongoDriver, MssqlClient, MssqlDriver,
+    OracleClient, OracleDriver, PoolRef, RedisDriver, Session, SharedSession,
+};
+use mongodb::Database;
 use once_cell::sync::OnceCell;
-use sqlx::{postgres::PgPool, mysql::MySqlPool, sqlite::SqlitePool};
+use redis::aio::ConnectionManager as RedisConnection;
+use sqlx::pool::{PoolConnection, PoolOptions};
+use sqlx::postgres::{PgConnectOptions, PgPool, Postgres};
+use sqlx::{mysql::{MySql, MySqlPool}, sqlite::{Sqlite, SqlitePool}};
 use std::collections::HashMap;
-use tokio::sync::RwLock;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, RwLock};
 
 /// Enum to hold different database pool types
 pub enum ConnectionPool {
     Postgres(PgPool),
     MySql(MySqlPool),
     Sqlite(SqlitePool),
+    Mssql(Box<Mutex<MssqlClient>>),
+    Mongo(Database),
+    Redis(RedisConnection),
+    ClickHouse(ClickHouseClient),
+    DuckDb(DuckDbClient),
+    Oracle(OracleClient),
 }
 
+/// Editor tab sessions keyed by connection ID and session name
+type SessionMap = HashMap<(String, String), SharedSession>;
+
 /// Manages active database connections
 pub struct ConnectionManager {
     connections: HashMap<String, ConnectionPool>,
     connection_strings: HashMap<String, String>, // Store connection strings for reference
+    /// Behind its own lock so sessions can be opened while the manager is only read
+    sessions: std::sync::Mutex<SessionMap>,
 }
 
 impl ConnectionManager {
@@ -24,6 +47,7 @@ impl ConnectionManager {
         Self {
             connections: HashMap::new(),
             connection_strings: HashMap::new(),
+            sessions: std::sync::Mutex::new(HashMap::new()),
         }
     }
 
@@ -34,30 +58,94 @@ impl ConnectionManager {
             self.disconnect(&connection_id).await?;
         }
 
+        let config = &env_vars::resolve(config)?;
+        let settings = config.pool.clone().unwrap_or_default();
+
         let (pool, connection_string) = match config.database_type {
             DatabaseType::PostgreSQL => {
                 let connection_string = build_postgres_connection_string(config)?;
-                let pool = PgPool::connect(&connection_string).await
+                let mut connect_options: PgConnectOptions = connection_string.parse()
+                    .map_err(|e| AppError::ConfigError(format!("Invalid PostgreSQL connection string: {}", e)))?;
+                if let Some(ms) = settings.statement_timeout_ms {
+                    connect_options = connect_options.options([("statement_timeout", ms.to_string())]);
+                }
+                if config.is_read_only() {
+                    connect_options = connect_options.options([("default_transaction_read_only", "on")]);
+                }
+                let pool = pool_options::<Postgres>(&settings).connect_with(connect_options).await
                     .map_err(|e| AppError::ConnectionError(format!("Failed to connect to PostgreSQL: {}", e)))?;
                 (ConnectionPool::Postgres(pool), connection_string)
             }
             DatabaseType::MySQL => {
                 let connection_string = build_mysql_connection_string(config)?;
-                let pool = MySqlPool::connect(&connection_string).await
+                let mut options = pool_options::<MySql>(&settings);
+                let timeout_ms = settings.statement_timeout_ms;
+                let read_only = config.is_read_only();
+                if timeout_ms.is_some() || read_only {
+                    options = options.after_connect(move |conn, _meta| Box::pin(async move {
+                        // MySQL only enforces max_execution_time for read-only SELECTs
+                        if let Some(ms) = timeout_ms {
+                            let sql = format!("SET SESSION max_execution_time = {}", ms);
+                            sqlx::query(&sql).execute(&mut *conn).await?;
+                        }
+                        if read_only {
+                            sqlx::query("SET SESSION TRANSACTION READ ONLY").execute(&mut *conn).await?;
+                        }
+                        Ok(())
+                    }));
+                }
+                let pool = options.connect(&connection_string).await
                     .map_err(|e| AppError::ConnectionError(format!("Failed to connect to MySQL: {}", e)))?;
                 (ConnectionPool::MySql(pool), connection_string)
             }
             DatabaseType::SQLite => {
+                // SQLite has no server-side statement timeout
                 let connection_string = build_sqlite_connection_string(config)?;
-                let pool = SqlitePool::connect(&connection_string).await
+                let mut options = pool_options::<Sqlite>(&settings);
+                if config.is_read_only() {
+                    options = options.after_connect(|conn, _meta| Box::pin(async move {
+                        sqlx::query("PRAGMA query_only = ON").execute(conn).await?;
+                        Ok(())
+                    }));
+                }
+                let pool = options.connect(&connection_string).await
                     .map_err(|e| AppError::ConnectionError(format!("Failed to connect to SQLite: {}", e)))?;
                 (ConnectionPool::Sqlite(pool), connection_string)
             }
             DatabaseType::MSSQL => {
-                return Err(AppError::ConnectionError("MSSQL not yet implemented".to_string()));
+                let connection_string = MssqlDriver.build_connection_string(config);
+                let client = MssqlDriver::connect(config).await?;
+                (ConnectionPool::Mssql(Box::new(Mutex::new(client))), connection_string)
+            }
+            DatabaseType::MongoDB => {
+                let connection_string = MongoDriver.build_connection_string(config);
+                let database = MongoDriver::connect(config).await?;
+                (ConnectionPool::Mongo(database), connection_string)
+            }
+            DatabaseType::Redis => {
+                let connection_string = RedisDriver.build_connection_string(config);
+                let conn = RedisDriver::connect(config).await?;
+                (ConnectionPool::Redis(conn), connection_string)
+            }
+            DatabaseType::ClickHouse => {
+                let connection_string = ClickHouseDriver.build_connection_string(config);
+                let client = ClickHouseDriver::connect(config).await?;
+                (ConnectionPool::ClickHouse(client), connection_string)
+            }
+            DatabaseType::DuckDB => {
+                let connection_string = DuckDbDriver.build_connection_string(config);
+                let client = DuckDbDriver::connect(config).await?;
+                (ConnectionPool::DuckDb(client), connection_string)
+            }
+            DatabaseType::Oracle => {
+                let connection_string = OracleDriver.build_connection_string(config);
+                let client = OracleDriver::connect(config).await?;
+                (ConnectionPool::Oracle(client), connection_string)
             }
         };
 
+        tracing::info!(connection_id = %connection_id, database_type = ?config.database_type, "Connected");
+        metrics::record_connection(true);
         self.connection_strings.insert(connection_id.clone(), connection_string);
         self.connections.insert(connection_id, pool);
         Ok(())
@@ -65,12 +153,25 @@ impl ConnectionManager {
 
     /// Disconnect from a database
     pub async fn disconnect(&mut self, connection_id: &str) -> AppResult<()> {
+        // Pools wait for their connections to come back before closing
+        self.lock_sessions().retain(|(id, _), _| id != connection_id);
+
         if let Some(pool) = self.connections.remove(connection_id) {
             match pool {
                 ConnectionPool::Postgres(p) => p.close().await,
                 ConnectionPool::MySql(p) => p.close().await,
                 ConnectionPool::Sqlite(p) => p.close().await,
+                ConnectionPool::Mssql(c) => {
+                    let _ = c.into_inner().close().await;
+                }
+                ConnectionPool::Mongo(d) => d.client().clone().shutdown().await,
+                // Dropping the manager or client closes the connection
+                ConnectionPool::Redis(_)
+                | ConnectionPool::ClickHouse(_)
+                | ConnectionPool::DuckDb(_)
+                | ConnectionPool::Oracle(_) => {}
             }
+            tracing::info!(connection_id, "Disconnected");
         }
         self.connection_strings.remove(connection_id);
         Ok(())
@@ -91,7 +192,68 @@ impl ConnectionManager {
             ConnectionPool::Postgres(p) => Ok(PoolRef::Postgres(p)),
             ConnectionPool::MySql(p) => Ok(PoolRef::MySql(p)),
             ConnectionPool::Sqlite(p) => Ok(PoolRef::Sqlite(p)),
+            ConnectionPool::Mssql(c) => Ok(PoolRef::Mssql(c)),
+            ConnectionPool::Mongo(d) => Ok(PoolRef::Mongo(d)),
+            ConnectionPool::Redis(c) => Ok(PoolRef::Redis(c)),
+            ConnectionPool::ClickHouse(c) => Ok(PoolRef::ClickHouse(c)),
+            ConnectionPool::DuckDb(c) => Ok(PoolRef::DuckDb(c)),
+            ConnectionPool::Oracle(c) => Ok(PoolRef::Oracle(c)),
+        }
+    }
+
+    /// The named session of a connection, opened on first use. None when the
+    /// database does not need sessions (MongoDB, Redis and ClickHouse clients
+    /// are shared safely) or when another session would leave no pooled connection for
+    /// metadata queries.
+    pub async fn session(
+        &self,
+        connection_id: &str,
+        name: &str,
+        config: &ConnectionConfig,
+    ) -> AppResult<Option<SharedSession>> {
+        let key = (connection_id.to_string(), name.to_string());
+        if let Some(session) = self.lock_sessions().get(&key) {
+            return Ok(Some(session.clone()));
         }
+        let open = self.lock_sessions().keys().filter(|(id, _)| id == connection_id).count() as u32;
+
+        let pool = self.connections.get(connection_id)
+            .ok_or_else(|| AppError::ConnectionError("Connection not found".to_string()))?;
+        let spare = match pool {
+            ConnectionPool::Postgres(p) => open + 1 < p.options().get_max_connections(),
+            ConnectionPool::MySql(p) => open + 1 < p.options().get_max_connections(),
+            ConnectionPool::Sqlite(p) => open + 1 < p.options().get_max_connections(),
+            _ => true,
+        };
+        if !spare {
+            return Ok(None);
+        }
+        let Some(session) = open_session(pool, config).await? else {
+            return Ok(None);
+        };
+
+        // Another query may have opened the session meanwhile; keep the first
+        let session = self.lock_sessions().entry(key).or_insert_with(|| Arc::new(Mutex::new(session))).clone();
+        Ok(Some(session))
+    }
+
+    /// A connection of its own for statements that share session state, such
+    /// as the statements of a SQL file. It is not kept with the editor sessions
+    /// and closes when dropped. None when the database does not need sessions.
+    pub async fn dedicated_session(&self, connection_id: &str, config: &ConnectionConfig) -> AppResult<Option<Session>> {
+        let pool = self.connections.get(connection_id)
+            .ok_or_else(|| AppError::ConnectionError("Connection not found".to_string()))?;
+        open_session(pool, config).await
+    }
+
+    /// Close a session once any query still running on it finishes; the pool
+    /// opens a fresh connection in its place
+    pub fn close_session(&self, connection_id: &str, name: &str) {
+        self.lock_sessions().remove(&(connection_id.to_string(), name.to_string()));
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, SessionMap> {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     /// Get a connection pool
@@ -106,13 +268,60 @@ impl ConnectionManager {
     }
 
     /// List all active connection IDs
-    #[allow(dead_code)]
     pub fn list_connections(&self) -> Vec<String> {
         self.connections.keys().cloned().collect()
     }
 }
 
+/// Open a session on a connection, or None for databases whose clients are
+/// shared safely
+async fn open_session(pool: &ConnectionPool, config: &ConnectionConfig) -> AppResult<Option<Session>> {
+    let session = match pool {
+        ConnectionPool::Postgres(p) => Session::Postgres(acquire_session(p).await?),
+        ConnectionPool::MySql(p) => Session::MySql(acquire_session(p).await?),
+        ConnectionPool::Sqlite(p) => Session::Sqlite(acquire_session(p).await?),
+        ConnectionPool::Mssql(_) => {
+            let client = MssqlDriver::connect(&env_vars::resolve(config)?).await?;
+            Session::Mssql(Box::new(Mutex::new(client)))
+        }
+        ConnectionPool::DuckDb(c) => Session::DuckDb(c.session()?),
+        ConnectionPool::Oracle(_) => Session::Oracle(OracleDriver::connect(&env_vars::resolve(config)?).await?),
+        _ => return Ok(None),
+    };
+    Ok(Some(session))
+}
+
+/// Take a connection out of the pool for a session. It is closed rather than
+/// returned when the session ends, so the tab's roles, variables, temporary
+/// tables and open transactions never reach other queries.
+async fn acquire_session<DB: sqlx::Database>(pool: &sqlx::Pool<DB>) -> AppResult<PoolConnection<DB>> {
+    let mut connection = pool.acquire().await
+        .map_err(|e| AppError::ConnectionError(format!("Failed to open session: {}", e)))?;
+    connection.close_on_drop();
+    Ok(connection)
+}
+
+/// Apply per-connection pool settings on top of the sqlx defaults
+fn pool_options<DB: sqlx::Database>(settings: &PoolSettings) -> PoolOptions<DB> {
+    let mut options = PoolOptions::<DB>::new();
+    if let Some(max) = settings.max_connections {
+        options = options.max_connections(max.max(1));
+    }
+    if let Some(min) = settings.min_idle {
+        options = options.min_connections(min);
+    }
+    if let Some(secs) = settings.acquire_timeout_secs {
+        options = options.acquire_timeout(Duration::from_secs(secs));
+    }
+    if let Some(secs) = settings.idle_timeout_secs {
+        options = options.idle_timeout(Duration::from_secs(secs));
+    }
+    options
+}
+
 fn build_postgres_connection_string(config: &ConnectionConfig) -> AppResult<String> {
+    check_tls_files(config)?;
+    
     let host = config.host.as_deref().unwrap_or("localhost");
     let port = config.port.unwrap_or(5432);
     let username = config.username.as_deref().unwrap_or("postgres");
@@ -121,14 +330,14 @@ fn build_postgres_connection_string(config: &ConnectionConfig) -> AppResult<Stri
     let mut url = format!("postgresql://{}:{}@{}:{}/{}", 
         username, password, host, port, config.database);
     
-    if let Some(ssl_mode) = &config.ssl_mode {
-        url.push_str(&format!("?sslmode={}", ssl_mode));
-    }
+    append_query_params(&mut url, &postgres_tls_params(config));
     
     Ok(url)
 }
 
 fn build_mysql_connection_string(config: &ConnectionConfig) -> AppResult<String> {
+    check_tls_files(config)?;
+    
     let host = config.host.as_deref().unwrap_or("localhost");
     let port = config.port.unwrap_or(3306);
     let username = config.username.as_deref().unwrap_or("root");
@@ -141,9 +350,11 @@ fn build_mysql_connection_string(config: &ConnectionConfig) -> AppResult<String>
         config.database.clone()
     };
     
-    let url = format!("mysql://{}:{}@{}:{}/{}", 
+    let mut url = format!("mysql://{}:{}@{}:{}/{}", 
         username, password, host, port, database);
     
+    append_query_params(&mut url, &mysql_tls_params(config));
+    
     Ok(url)
 }
 
@@ -1,12 +1,36 @@
 mod connection;
+mod ddl_script;
+mod dependency_graph;
 mod manager;
 mod postgres;
+mod pg_types;
+pub mod pgvector;
 mod mysql;
 mod sqlite;
+mod mssql;
+mod mongo;
+mod redis_db;
+mod clickhouse;
+mod duckdb_db;
+mod oracle;
+mod session;
+mod statements;
+mod tls;
 
 pub use connection::*;
+pub use ddl_script::*;
+pub use dependency_graph::*;
 pub use manager::*;
 pub use postgres::PostgresDriver;
 pub use mysql::MySqlDriver;
 pub use sqlite::SqliteDriver;
+pub use mssql::{MssqlClient, MssqlDriver};
+pub use mongo::MongoDriver;
+pub use redis_db::RedisDriver;
+pub use clickhouse::{ClickHouseClient, ClickHouseDriver};
+pub use duckdb_db::{DuckDbClient, DuckDbDriver};
+pub use oracle::{OracleClient, OracleDriver};
+pub use session::{Session, SharedSession};
+pub use statements::*;
+pub use tls::*;
 
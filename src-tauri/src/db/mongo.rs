@@ -0,0 +1,1106 @@
+//! MongoDB driver. Collections are exposed as tables and documents as rows.
+//!
+//! Documents are addressed by key paths: each key of a primary key map is a
+//! dot-separated path into the document (`_id`, `account.number`). A leading
+//! `$.` (JSONPath root) is accepted and stripped. Values use MongoDB extended
+//! JSON, so an ObjectId round-trips as `{"$oid": "..."}`.
+
+use crate::db::{DatabaseDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseUser, DocumentQuery,
+    DocumentResult, ExtendedColumnInfo, IndexInfo, NewColumn, QueryResult, TableInfo, TableProperties,
+    TablePrivilege, TableRelationship, TableSchema, TestConnectionResult, TruncateOptions, ColumnInfo, ActiveQuery,
+    ServerStats, DatabaseSize, TableStats, SlowQueryEntry, SlowQueryOrder
+};
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::options::ClientOptions;
+use mongodb::{Client, Database};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Number of documents sampled to infer a collection's fields
+const SCHEMA_SAMPLE_SIZE: i64 = 100;
+
+/// Default page size for the document viewer
+const DEFAULT_DOCUMENT_LIMIT: i64 = 50;
+
+pub struct MongoDriver;
+
+/// Helper methods for MongoDriver
+impl MongoDriver {
+    /// Connect and return a handle to the configured database
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<Database> {
+        let uri = Self.build_connection_string(config);
+
+        let mut options = ClientOptions::parse(&uri).await
+            .map_err(|e| AppError::ConnectionError(format!("Invalid MongoDB connection string: {}", e)))?;
+
+        if let Some(pool) = &config.pool {
+            if let Some(max) = pool.max_connections {
+                options.max_pool_size = Some(max);
+            }
+            if let Some(min) = pool.min_idle {
+                options.min_pool_size = Some(min);
+            }
+            if let Some(secs) = pool.idle_timeout_secs {
+                options.max_idle_time = Some(Duration::from_secs(secs));
+            }
+            if let Some(secs) = pool.acquire_timeout_secs {
+                options.server_selection_timeout = Some(Duration::from_secs(secs));
+            }
+        }
+
+        let client = Client::with_options(options)
+            .map_err(|e| AppError::ConnectionError(format!("Failed to connect to MongoDB: {}", e)))?;
+
+        let database = if config.database.trim().is_empty() {
+            client.default_database().unwrap_or_else(|| client.database("test"))
+        } else {
+            client.database(&config.database)
+        };
+
+        // The driver connects lazily; ping so bad credentials fail here
+        database.run_command(doc! { "ping": 1 }).await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to connect to MongoDB: {}", e)))?;
+
+        Ok(database)
+    }
+
+    /// Parse an extended JSON value into a BSON document
+    fn json_to_document(value: serde_json::Value, what: &str) -> AppResult<Document> {
+        match Bson::try_from(value) {
+            Ok(Bson::Document(document)) => Ok(document),
+            Ok(_) => Err(AppError::QueryError(format!("{} must be a JSON object", what))),
+            Err(e) => Err(AppError::QueryError(format!("Invalid {}: {}", what.to_lowercase(), e))),
+        }
+    }
+
+    fn json_to_bson(value: serde_json::Value) -> AppResult<Bson> {
+        Bson::try_from(value).map_err(|e| AppError::QueryError(format!("Invalid value: {}", e)))
+    }
+
+    /// Normalize a key path, accepting JSONPath style `$.a.b`
+    fn key_path(path: &str) -> AppResult<String> {
+        let path = path.strip_prefix("$.").unwrap_or(path);
+        if path.is_empty() || path.split('.').any(|segment| segment.is_empty()) {
+            return Err(AppError::QueryError(format!("Invalid key path '{}'", path)));
+        }
+        Ok(path.to_string())
+    }
+
+    /// Build a filter that matches a document by its key paths
+    fn key_filter(primary_key: &HashMap<String, serde_json::Value>) -> AppResult<Document> {
+        if primary_key.is_empty() {
+            return Err(AppError::QueryError("A document key is required".to_string()));
+        }
+
+        let mut filter = Document::new();
+        for (path, value) in primary_key {
+            filter.insert(Self::key_path(path)?, Self::json_to_bson(value.clone())?);
+        }
+        Ok(filter)
+    }
+
+    /// Set a value at a dot-separated path, creating nested documents as needed
+    fn set_path(document: &mut Document, path: &str, value: Bson) {
+        match path.split_once('.') {
+            Some((head, rest)) => {
+                if !matches!(document.get(head), Some(Bson::Document(_))) {
+                    document.insert(head, Document::new());
+                }
+                if let Some(Bson::Document(child)) = document.get_mut(head) {
+                    Self::set_path(child, rest, value);
+                }
+            }
+            None => {
+                document.insert(path, value);
+            }
+        }
+    }
+
+    /// Numeric reply field as f64, whichever BSON number type the server used
+    fn bson_number(value: Option<&Bson>) -> Option<f64> {
+        match value? {
+            Bson::Int32(v) => Some(*v as f64),
+            Bson::Int64(v) => Some(*v as f64),
+            Bson::Double(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Documents from a command reply's cursor.firstBatch
+    fn first_batch(reply: &Document) -> Option<Vec<Document>> {
+        let batch = reply.get_document("cursor").ok()?.get_array("firstBatch").ok()?;
+        Some(batch.iter().filter_map(|b| b.as_document().cloned()).collect())
+    }
+
+    /// MongoDB $type alias for a value
+    fn bson_type_name(value: &Bson) -> &'static str {
+        match value {
+            Bson::Double(_) => "double",
+            Bson::String(_) => "string",
+            Bson::Document(_) => "object",
+            Bson::Array(_) => "array",
+            Bson::Binary(_) => "binData",
+            Bson::ObjectId(_) => "objectId",
+            Bson::Boolean(_) => "bool",
+            Bson::DateTime(_) => "date",
+            Bson::Null | Bson::Undefined => "null",
+            Bson::RegularExpression(_) => "regex",
+            Bson::JavaScriptCode(_) | Bson::JavaScriptCodeWithScope(_) => "javascript",
+            Bson::Int32(_) => "int",
+            Bson::Timestamp(_) => "timestamp",
+            Bson::Int64(_) => "long",
+            Bson::Decimal128(_) => "decimal",
+            Bson::Symbol(_) => "symbol",
+            Bson::MaxKey => "maxKey",
+            Bson::MinKey => "minKey",
+            Bson::DbPointer(_) => "dbPointer",
+        }
+    }
+
+    /// Flatten documents into columns (top-level fields in first-seen order) and rows
+    fn documents_to_result(documents: Vec<Document>, start: Instant) -> QueryResult {
+        let mut names: Vec<String> = Vec::new();
+        for document in &documents {
+            for key in document.keys() {
+                if !names.contains(key) {
+                    names.push(key.clone());
+                }
+            }
+        }
+
+        let rows = documents
+            .into_iter()
+            .map(|mut document| {
+                names
+                    .iter()
+                    .map(|name| document.remove(name).map(Bson::into_relaxed_extjson).unwrap_or(serde_json::Value::Null))
+                    .collect()
+            })
+            .collect();
+
+        let columns = names
+            .into_iter()
+            .map(|name| ColumnInfo {
+                is_primary_key: name == "_id",
+                native_type: None,
+                type_oid: None,
+                name,
+                data_type: "unknown".to_string(),
+                nullable: true,
+            })
+            .collect();
+
+        QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        }
+    }
+
+    /// Infer fields and their types from a sample of documents
+    async fn sample_schema(database: &Database, collection: &str) -> AppResult<Vec<ExtendedColumnInfo>> {
+        let sample: Vec<Document> = database
+            .collection::<Document>(collection)
+            .aggregate(vec![doc! { "$sample": { "size": SCHEMA_SAMPLE_SIZE } }])
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to sample documents: {}", e)))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to sample documents: {}", e)))?;
+
+        // field -> (types seen, number of documents containing it)
+        let mut fields: Vec<(String, Vec<&'static str>, usize)> = Vec::new();
+        for document in &sample {
+            for (key, value) in document {
+                let type_name = Self::bson_type_name(value);
+                match fields.iter_mut().find(|(name, _, _)| name == key) {
+                    Some((_, types, count)) => {
+                        if !types.contains(&type_name) {
+                            types.push(type_name);
+                        }
+                        *count += 1;
+                    }
+                    None => fields.push((key.clone(), vec![type_name], 1)),
+                }
+            }
+        }
+
+        Ok(fields
+            .into_iter()
+            .map(|(name, types, count)| ExtendedColumnInfo {
+                is_primary_key: name == "_id",
+                nullable: count < sample.len() || types.contains(&"null"),
+                data_type: types.join(" | "),
+                name,
+                default_value: None,
+                comment: None,
+                is_identity: false,
+                identity_always: false,
+                generation_expression: None,
+                collation: None,
+            })
+            .collect())
+    }
+
+    async fn collection_options(database: &Database, collection: &str) -> AppResult<Document> {
+        let reply = database
+            .run_command(doc! { "listCollections": 1, "filter": { "name": collection } })
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get collection info: {}", e)))?;
+
+        Self::first_batch(&reply)
+            .and_then(|batch| batch.into_iter().next())
+            .map(|spec| spec.get_document("options").cloned().unwrap_or_default())
+            .ok_or_else(|| AppError::QueryError(format!("Collection '{}' not found", collection)))
+    }
+
+    /// Find documents for the document viewer
+    pub async fn find_documents(database: &Database, query: DocumentQuery) -> AppResult<DocumentResult> {
+        let start = Instant::now();
+
+        let filter = match query.filter {
+            Some(filter) => Self::json_to_document(filter, "Filter")?,
+            None => Document::new(),
+        };
+
+        let collection = database.collection::<Document>(&query.collection);
+        let mut find = collection
+            .find(filter)
+            .limit(query.limit.map(i64::from).unwrap_or(DEFAULT_DOCUMENT_LIMIT))
+            .skip(query.skip.map(u64::from).unwrap_or(0));
+
+        if let Some(projection) = query.projection {
+            find = find.projection(Self::json_to_document(projection, "Projection")?);
+        }
+        if let Some(sort) = query.sort {
+            find = find.sort(Self::json_to_document(sort, "Sort")?);
+        }
+
+        let documents: Vec<Document> = find
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to find documents: {}", e)))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to find documents: {}", e)))?;
+
+        Ok(DocumentResult {
+            documents: documents.into_iter().map(|d| Bson::Document(d).into_relaxed_extjson()).collect(),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Insert a document built from key paths
+    pub async fn insert_document(
+        database: &Database,
+        collection: &str,
+        values: HashMap<String, serde_json::Value>,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        let mut document = Document::new();
+        for (path, value) in values {
+            Self::set_path(&mut document, &Self::key_path(&path)?, Self::json_to_bson(value)?);
+        }
+
+        let result = database
+            .collection::<Document>(collection)
+            .insert_one(document)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to insert document: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![ColumnInfo {
+                name: "_id".to_string(),
+                data_type: Self::bson_type_name(&result.inserted_id).to_string(),
+                nullable: false,
+                is_primary_key: true,
+                native_type: None,
+                type_oid: None,
+            }],
+            rows: vec![vec![result.inserted_id.into_relaxed_extjson()]],
+            affected_rows: Some(1),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// Set fields (by key path) on the document matching the key
+    pub async fn update_document(
+        database: &Database,
+        collection: &str,
+        primary_key: HashMap<String, serde_json::Value>,
+        values: HashMap<String, serde_json::Value>,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        let filter = Self::key_filter(&primary_key)?;
+        let mut set = Document::new();
+        for (path, value) in values {
+            set.insert(Self::key_path(&path)?, Self::json_to_bson(value)?);
+        }
+
+        let result = database
+            .collection::<Document>(collection)
+            .update_one(filter, doc! { "$set": set })
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to update document: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.modified_count),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// Delete the document matching the key
+    pub async fn delete_document(
+        database: &Database,
+        collection: &str,
+        primary_key: HashMap<String, serde_json::Value>,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        let result = database
+            .collection::<Document>(collection)
+            .delete_one(Self::key_filter(&primary_key)?)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to delete document: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.deleted_count),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// Drop a collection
+    pub async fn drop_collection(database: &Database, collection: &str) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        database
+            .collection::<Document>(collection)
+            .drop()
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to drop collection: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for MongoDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let database = Self::connect(config).await?;
+
+        // Get server version
+        let build_info = database.run_command(doc! { "buildInfo": 1 }).await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?;
+        let version = build_info.get_str("version").ok().map(|v| format!("MongoDB {}", v));
+
+        database.client().clone().shutdown().await;
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!("MongoDB connection to {} successful", database.name()),
+            server_version: version,
+        })
+    }
+
+    /// Run a database command given as extended JSON, e.g. `{"find": "users", "filter": {"age": {"$gt": 30}}}`
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let value: serde_json::Value = serde_json::from_str(sql.trim())
+            .map_err(|e| AppError::QueryError(format!("MongoDB commands must be JSON documents: {}", e)))?;
+        let command = Self::json_to_document(value, "Command")?;
+
+        let reply = database.run_command(command).await
+            .map_err(|e| AppError::QueryError(format!("Command failed: {}", e)))?;
+
+        // Cursor-returning commands (find, aggregate, list*)
+        if let Some(documents) = Self::first_batch(&reply) {
+            return Ok(Self::documents_to_result(documents, start));
+        }
+
+        // Write commands report counts
+        let count = ["nModified", "n"]
+            .iter()
+            .find_map(|key| match reply.get(*key) {
+                Some(Bson::Int32(n)) => Some(*n as u64),
+                Some(Bson::Int64(n)) => Some(*n as u64),
+                _ => None,
+            });
+        if let Some(affected) = count {
+            return Ok(QueryResult {
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(affected),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        Ok(Self::documents_to_result(vec![reply], start))
+    }
+
+    async fn execute_with_params(&self, _pool: PoolRef<'_>, _sql: &str, _params: Vec<CellValue>) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("MongoDB commands take no bound parameters; put values in the command document".to_string()))
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let paths = columns.iter().map(|c| Self::key_path(c)).collect::<AppResult<Vec<String>>>()?;
+        let mut failed = Vec::new();
+        let mut documents = Vec::with_capacity(rows.len());
+        // Row index of each document, since rows that fail conversion are skipped
+        let mut row_indexes = Vec::with_capacity(rows.len());
+
+        for (index, row) in rows.into_iter().enumerate() {
+            let mut document = Document::new();
+            let converted = row.into_iter().zip(&paths).try_for_each(|(value, path)| {
+                Self::set_path(&mut document, path, Self::json_to_bson(value)?);
+                Ok::<_, AppError>(())
+            });
+            match converted {
+                Ok(()) => {
+                    documents.push(document);
+                    row_indexes.push(index);
+                }
+                Err(e) => failed.push((index, e.to_string())),
+            }
+        }
+
+        if documents.is_empty() {
+            return Ok(failed);
+        }
+
+        // Unordered so the server keeps inserting past failed documents
+        let result = database
+            .collection::<Document>(table)
+            .insert_many(documents)
+            .ordered(false)
+            .await;
+
+        if let Err(e) = result {
+            match *e.kind {
+                mongodb::error::ErrorKind::InsertMany(ref insert_error) if insert_error.write_concern_error.is_none() => {
+                    for write_error in insert_error.write_errors.iter().flatten() {
+                        if let Some(&index) = row_indexes.get(write_error.index) {
+                            failed.push((index, write_error.message.clone()));
+                        }
+                    }
+                    failed.sort_by_key(|(index, _)| *index);
+                }
+                _ => return Err(AppError::QueryError(format!("Failed to insert documents: {}", e))),
+            }
+        }
+
+        Ok(failed)
+    }
+
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let mut names = database.list_collection_names().await
+            .map_err(|e| AppError::QueryError(format!("Failed to get collections: {}", e)))?;
+        names.sort();
+
+        Ok(names
+            .into_iter()
+            .filter(|name| !name.starts_with("system."))
+            .map(|name| TableInfo {
+                name,
+                schema: None,
+                table_type: "collection".to_string(),
+                row_count: None,
+                parent_table: None,
+                pinned: false,
+            })
+            .collect())
+    }
+
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let mut names = database.client().list_database_names().await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+        names.sort();
+        Ok(names)
+    }
+
+    async fn list_schemas(&self, _pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn create_database(&self, _pool: PoolRef<'_>, _options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError(
+            "MongoDB creates a database when its first collection is created".to_string(),
+        ))
+    }
+
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        database.client().database(name).drop().await
+            .map_err(|e| AppError::QueryError(format!("Failed to drop database: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn create_schema(&self, _pool: PoolRef<'_>, _options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("MongoDB does not support schemas".to_string()))
+    }
+
+    async fn drop_schema(&self, _pool: PoolRef<'_>, _name: &str, _cascade: bool) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("MongoDB does not support schemas".to_string()))
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let columns: Vec<ColumnInfo> = Self::sample_schema(database, table_name).await?
+            .into_iter()
+            .map(|c| ColumnInfo {
+                name: c.name,
+                data_type: c.data_type,
+                nullable: c.nullable,
+                is_primary_key: c.is_primary_key,
+                native_type: None,
+                type_oid: None,
+            })
+            .collect();
+
+        Ok(TableSchema {
+            table_name: table_name.to_string(),
+            columns,
+            primary_keys: vec!["_id".to_string()],
+            foreign_keys: vec![],
+        })
+    }
+
+    async fn get_all_table_schemas(&self, pool: PoolRef<'_>, config: &ConnectionConfig) -> AppResult<Vec<TableSchema>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let tables = self.get_tables(PoolRef::Mongo(database), config).await?;
+
+        let mut schemas = Vec::new();
+        for table in tables {
+            schemas.push(self.get_table_schema(PoolRef::Mongo(database), &table.name).await?);
+        }
+
+        Ok(schemas)
+    }
+
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let host = config.host.as_deref().unwrap_or("localhost");
+
+        // Accept a full URI (e.g. mongodb+srv://) pasted into the host field
+        if host.starts_with("mongodb://") || host.starts_with("mongodb+srv://") {
+            return host.to_string();
+        }
+
+        let port = config.port.unwrap_or(27017);
+        let credentials = match (&config.username, &config.password) {
+            (Some(user), Some(pass)) if !user.is_empty() => format!(
+                "{}:{}@",
+                utf8_percent_encode(user, NON_ALPHANUMERIC),
+                utf8_percent_encode(pass, NON_ALPHANUMERIC)
+            ),
+            (Some(user), _) if !user.is_empty() => format!("{}@", utf8_percent_encode(user, NON_ALPHANUMERIC)),
+            _ => String::new(),
+        };
+
+        let mut uri = format!("mongodb://{}{}:{}/{}", credentials, host, port, config.database);
+
+        match config.ssl_mode.as_deref() {
+            Some("disable") | None => {}
+            Some("require") => uri.push_str("?tls=true&tlsAllowInvalidCertificates=true"),
+            Some(_) => uri.push_str("?tls=true"),
+        }
+
+        uri
+    }
+
+    /// Emit a createCollection call with the collection's options (validator, capped, ...)
+    async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let options = Self::collection_options(database, table_name).await?;
+        let name = serde_json::Value::String(table_name.to_string());
+
+        if options.is_empty() {
+            return Ok(format!("db.createCollection({});", name));
+        }
+
+        let options_json = serde_json::to_string_pretty(&Bson::Document(options).into_relaxed_extjson())?;
+        Ok(format!("db.createCollection({}, {});", name, options_json))
+    }
+
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        // renameCollection must run against the admin database with full namespaces
+        let command = doc! {
+            "renameCollection": format!("{}.{}", database.name(), old_name),
+            "to": format!("{}.{}", database.name(), new_name),
+        };
+        database.client().database("admin").run_command(command).await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename collection: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn add_column(&self, _pool: PoolRef<'_>, _table_name: &str, _column: &NewColumn) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Collections have no fixed fields; set the field on documents instead".to_string()))
+    }
+
+    /// Removes the field from every document in the collection
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let field = Self::key_path(column_name)?;
+        let result = database
+            .collection::<Document>(table_name)
+            .update_many(doc! { &field: { "$exists": true } }, doc! { "$unset": { &field: "" } })
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to remove field: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.modified_count),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// Renames the field in every document in the collection
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let old_field = Self::key_path(old_name)?;
+        let new_field = Self::key_path(new_name)?;
+        let result = database
+            .collection::<Document>(table_name)
+            .update_many(doc! { &old_field: { "$exists": true } }, doc! { "$rename": { &old_field: new_field } })
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename field: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.modified_count),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn alter_column_type(
+        &self,
+        _pool: PoolRef<'_>,
+        _table_name: &str,
+        _column_name: &str,
+        _data_type: &str,
+        _nullable: bool,
+    ) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Collections have no field types to change".to_string()))
+    }
+
+    /// Deletes every document, keeping the collection and its indexes
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let result = database
+            .collection::<Document>(table_name)
+            .delete_many(doc! {})
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to empty collection: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.deleted_count),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// Copies the collection's indexes, and its documents if `include_data` is set
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let start = Instant::now();
+        let source = database.collection::<Document>(table_name);
+
+        if include_data {
+            // $out writes the pipeline's output to a new collection on the server
+            source.aggregate(vec![doc! { "$out": new_name }]).await
+                .map_err(|e| AppError::QueryError(format!("Failed to copy documents: {}", e)))?;
+        } else {
+            database.create_collection(new_name).await
+                .map_err(|e| AppError::QueryError(format!("Failed to create collection: {}", e)))?;
+        }
+
+        let indexes: Vec<_> = source.list_indexes().await
+            .map_err(|e| AppError::QueryError(format!("Failed to list indexes: {}", e)))?
+            .try_collect()
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list indexes: {}", e)))?;
+        // The _id index exists on every collection
+        let indexes: Vec<_> = indexes
+            .into_iter()
+            .filter(|index| index.options.as_ref().and_then(|o| o.name.as_deref()) != Some("_id_"))
+            .collect();
+        if !indexes.is_empty() {
+            database.collection::<Document>(new_name).create_indexes(indexes).await
+                .map_err(|e| AppError::QueryError(format!("Failed to copy indexes: {}", e)))?;
+        }
+
+        let copied = if include_data {
+            database.collection::<Document>(new_name).estimated_document_count().await.unwrap_or(0)
+        } else {
+            0
+        };
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(copied),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let reply = database.run_command(doc! { "listIndexes": table_name }).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get indexes: {}", e)))?;
+
+        Ok(Self::first_batch(&reply)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|index| {
+                let name = index.get_str("name").unwrap_or_default().to_string();
+                IndexInfo {
+                    columns: index.get_document("key").map(|k| k.keys().cloned().collect()).unwrap_or_default(),
+                    is_unique: index.get_bool("unique").unwrap_or(false) || name == "_id_",
+                    is_primary: name == "_id_",
+                    name,
+                }
+            })
+            .collect())
+    }
+
+    /// The collection's JSON schema validator, if any
+    async fn get_constraints(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let options = Self::collection_options(database, table_name).await?;
+
+        let constraints = match options.get_document("validator") {
+            Ok(validator) => vec![ConstraintInfo {
+                name: "validator".to_string(),
+                constraint_type: "VALIDATOR".to_string(),
+                definition: serde_json::to_string(&Bson::Document(validator.clone()).into_relaxed_extjson())?,
+            }],
+            Err(_) => vec![],
+        };
+
+        Ok(constraints)
+    }
+
+    async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let columns = Self::sample_schema(database, table_name).await?;
+        let indexes = self.get_indexes(PoolRef::Mongo(database), table_name).await?;
+        let constraints = self.get_constraints(PoolRef::Mongo(database), table_name).await?;
+
+        // Collection metadata, which can drift after an unclean shutdown
+        let estimate = database
+            .collection::<Document>(table_name)
+            .estimated_document_count()
+            .await
+            .ok()
+            .map(|count| count as i64);
+        let (row_count, row_count_exact) =
+            self.row_count_from_estimate(PoolRef::Mongo(database), table_name, estimate).await;
+
+        Ok(TableProperties {
+            table_name: table_name.to_string(),
+            schema: Some(database.name().to_string()),
+            columns,
+            primary_keys: vec!["_id".to_string()],
+            foreign_keys: vec![],
+            indexes,
+            constraints,
+            row_count,
+            row_count_exact,
+            table_comment: None,
+        })
+    }
+
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        database
+            .collection::<Document>(table_name)
+            .count_documents(doc! {})
+            .await
+            .map(|count| count as i64)
+            .map_err(|e| AppError::QueryError(format!("Failed to count documents: {}", e)))
+    }
+
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let stats = database.run_command(doc! { "collStats": table_name }).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?;
+        let number = |field: &str| Self::bson_number(stats.get(field)).map(|v| v as u64);
+
+        let table_size = number("storageSize");
+        let index_size = number("totalIndexSize");
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: table_size,
+            index_size_bytes: index_size,
+            total_size_bytes: table_size.zip(index_size).map(|(t, i)| t + i),
+            approximate_rows: number("count"),
+            dead_rows: None,
+            dead_row_ratio: None,
+            // Reclaimable with compact (MongoDB 4.4+)
+            free_bytes: number("freeStorageSize"),
+            last_vacuum: None,
+            last_analyze: None,
+        })
+    }
+
+    /// MongoDB has no foreign keys
+    async fn get_table_relationships(&self, pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        match pool {
+            PoolRef::Mongo(_) => Ok(vec![]),
+            _ => Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        }
+    }
+
+    async fn get_users_and_roles(&self, _pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for MongoDB".to_string()))
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for MongoDB".to_string()))
+    }
+
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let client = database.client();
+        let status = client.database("admin").run_command(doc! { "serverStatus": 1 }).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+
+        let connections = status.get_document("connections").ok();
+        let current = connections.and_then(|c| Self::bson_number(c.get("current")));
+        let available = connections.and_then(|c| Self::bson_number(c.get("available")));
+
+        // WiredTiger only; pages read from disk out of all pages requested
+        let cache = status.get_document("wiredTiger").and_then(|wt| wt.get_document("cache")).ok();
+        let requested = cache.and_then(|c| Self::bson_number(c.get("pages requested from the cache")));
+        let read = cache.and_then(|c| Self::bson_number(c.get("pages read into cache")));
+        let cache_hit_ratio = match (read, requested) {
+            (Some(read), Some(requested)) if requested > 0.0 => Some(1.0 - read / requested),
+            _ => None,
+        };
+
+        let mut database_sizes: Vec<DatabaseSize> = client.list_databases().await
+            .map_err(|e| AppError::QueryError(format!("Failed to get database sizes: {}", e)))?
+            .into_iter()
+            .map(|spec| DatabaseSize {
+                name: spec.name,
+                size_bytes: spec.size_on_disk,
+            })
+            .collect();
+        database_sizes.sort_by_key(|size| std::cmp::Reverse(size.size_bytes));
+
+        Ok(ServerStats {
+            active_connections: current.map(|v| v as u64),
+            max_connections: current.zip(available).map(|(c, a)| (c + a) as u64),
+            uptime_seconds: Self::bson_number(status.get("uptime")).map(|v| v as u64),
+            cache_hit_ratio,
+            database_sizes,
+        })
+    }
+
+    /// Operations from currentOp; `session_id` is the opid
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        let reply = database.client().database("admin")
+            .run_command(doc! { "currentOp": 1, "active": true })
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        let operations = reply.get_array("inprog").map(|ops| ops.as_slice()).unwrap_or_default();
+
+        Ok(operations
+            .iter()
+            .filter_map(|op| op.as_document())
+            // Skip the currentOp command issued above
+            .filter(|op| !op.get_document("command").is_ok_and(|c| c.contains_key("currentOp")))
+            .map(|op| ActiveQuery {
+                session_id: match op.get("opid") {
+                    Some(Bson::String(id)) => id.clone(),
+                    other => Self::bson_number(other).map(|id| (id as i64).to_string()).unwrap_or_default(),
+                },
+                user: op.get_array("effectiveUsers").ok()
+                    .and_then(|users| users.first())
+                    .and_then(|user| user.as_document())
+                    .and_then(|user| user.get_str("user").ok())
+                    .map(|user| user.to_string()),
+                database: op.get_str("ns").ok()
+                    .and_then(|ns| ns.split('.').next())
+                    .filter(|db| !db.is_empty())
+                    .map(|db| db.to_string()),
+                client: op.get_str("client").ok().map(|c| c.to_string()),
+                state: op.get_str("op").ok().map(|s| s.to_string()),
+                query: op.get_document("command").ok()
+                    .map(|c| Bson::Document(c.clone()).into_relaxed_extjson().to_string()),
+                duration_ms: Self::bson_number(op.get("microsecs_running")).map(|us| (us / 1000.0) as u64),
+            })
+            .collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let database = match pool {
+            PoolRef::Mongo(d) => d,
+            _ => return Err(AppError::QueryError("Invalid pool type for MongoDB driver".to_string())),
+        };
+
+        // Sharded clusters report opids as "shard:id" strings
+        let op = session_id.parse::<i64>()
+            .map(Bson::Int64)
+            .unwrap_or_else(|_| Bson::String(session_id.to_string()));
+
+        database.client().database("admin").run_command(doc! { "killOp": 1, "op": op }).await
+            .map_err(|e| AppError::QueryError(format!("Failed to kill session: {}", e)))?;
+        Ok(())
+    }
+
+    async fn get_slow_queries(&self, _pool: PoolRef<'_>, _order: SlowQueryOrder, _limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        Err(AppError::ValidationError("Slow query analysis is not supported for MongoDB".to_string()))
+    }
+}
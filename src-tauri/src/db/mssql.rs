@@ -0,0 +1,1818 @@
+use crate::db::{
+    column_definition, insert_statement, placeholder, string_literal, BatchHandler, DatabaseDriver, DdlKind, DdlObject,
+    PoolRef, RowBatches,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseDdlOptions, DatabaseUser,
+    DatabaseType, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo, NewColumn, QueryResult, TableInfo, TableProperties,
+    TablePrivilege, TableRelationship, TableSchema, TestConnectionResult, TruncateOptions, ColumnInfo, ActiveQuery,
+    ServerStats, DatabaseSize, TableStats, SlowQueryEntry, SlowQueryOrder, ObjectDependency
+};
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+use sql_parse::Dialect;
+use tiberius::{AuthMethod, Client, ColumnData, ColumnType, Config, EncryptionLevel, FromSql, QueryItem, Row, ToSql};
+use tokio::net::TcpStream;
+use tokio_util::compat::{Compat, TokioAsyncWriteCompatExt};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// A single TDS connection; tiberius has no pool of its own
+pub type MssqlClient = Client<Compat<TcpStream>>;
+
+pub struct MssqlDriver;
+
+/// Base64 encode binary data
+fn base64_encode(data: &[u8]) -> String {
+    use base64::{Engine as _, engine::general_purpose};
+    general_purpose::STANDARD.encode(data)
+}
+
+/// Quote an identifier with brackets, escaping any closing bracket
+fn quote_ident(name: &str) -> String {
+    format!("[{}]", name.replace(']', "]]"))
+}
+
+/// Column details read from sys.columns
+struct MssqlColumn {
+    name: String,
+    data_type: String,
+    nullable: bool,
+    default_value: Option<String>,
+    comment: Option<String>,
+    identity: Option<(i64, i64)>,
+}
+
+/// Helper methods for MssqlDriver
+impl MssqlDriver {
+    /// Open a new connection, following a single server redirect (Azure SQL gateways)
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<MssqlClient> {
+        let mut tds_config = Self::tds_config(config);
+
+        match Self::connect_with(tds_config.clone()).await {
+            Err(tiberius::error::Error::Routing { host, port }) => {
+                tds_config.host(&host);
+                tds_config.port(port);
+                Self::connect_with(tds_config).await
+                    .map_err(|e| AppError::ConnectionError(format!("Failed to connect to MSSQL: {}", e)))
+            }
+            result => result.map_err(|e| AppError::ConnectionError(format!("Failed to connect to MSSQL: {}", e))),
+        }
+    }
+
+    async fn connect_with(tds_config: Config) -> tiberius::Result<MssqlClient> {
+        let tcp = TcpStream::connect(tds_config.get_addr()).await?;
+        tcp.set_nodelay(true)?;
+        Client::connect(tds_config, tcp.compat_write()).await
+    }
+
+    /// Build the tiberius configuration from a connection config
+    fn tds_config(config: &ConnectionConfig) -> Config {
+        let mut tds_config = Config::new();
+        tds_config.host(config.host.as_deref().unwrap_or("localhost"));
+        tds_config.port(config.port.unwrap_or(1433));
+        tds_config.application_name("dbfordevs");
+
+        if !config.database.trim().is_empty() {
+            tds_config.database(&config.database);
+        }
+
+        tds_config.authentication(AuthMethod::sql_server(
+            config.username.as_deref().unwrap_or("sa"),
+            config.password.as_deref().unwrap_or(""),
+        ));
+
+        // Local SQL Server installs ship with a self-signed certificate, so only
+        // the verify modes check it
+        match config.ssl_mode.as_deref() {
+            Some("disable") => tds_config.encryption(EncryptionLevel::NotSupported),
+            Some("verify-ca") | Some("verify-full") => tds_config.encryption(EncryptionLevel::Required),
+            Some("require") => {
+                tds_config.encryption(EncryptionLevel::Required);
+                tds_config.trust_cert();
+            }
+            _ => {
+                tds_config.encryption(EncryptionLevel::On);
+                tds_config.trust_cert();
+            }
+        }
+
+        tds_config
+    }
+
+    /// Parse schema.table format, defaulting to dbo
+    fn split_table_name(table_name: &str) -> (String, String) {
+        match table_name.split_once('.') {
+            Some((schema, table)) => (schema.to_string(), table.to_string()),
+            None => ("dbo".to_string(), table_name.to_string()),
+        }
+    }
+
+    /// Bracket-quoted two part name suitable for OBJECT_ID()
+    fn object_name(table_name: &str) -> String {
+        let (schema, table) = Self::split_table_name(table_name);
+        format!("{}.{}", quote_ident(&schema), quote_ident(&table))
+    }
+
+    async fn fetch_rows(client: &mut MssqlClient, sql: &str, params: &[&dyn ToSql], context: &str) -> AppResult<Vec<Row>> {
+        let stream = client.query(sql, params).await
+            .map_err(|e| AppError::QueryError(format!("{}: {}", context, e)))?;
+
+        stream.into_first_result().await
+            .map_err(|e| AppError::QueryError(format!("{}: {}", context, e)))
+    }
+
+    /// Run a statement as a plain SQL batch and discard its results
+    async fn run_batch(client: &mut MssqlClient, sql: &str) -> AppResult<()> {
+        client.simple_query(sql).await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+            .into_results().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+        Ok(())
+    }
+
+    /// Convert a parameter to a TDS parameter; SQL Server converts it to the column type
+    fn to_sql_param(value: CellValue) -> Box<dyn ToSql> {
+        match value {
+            CellValue::Null => Box::new(Option::<String>::None),
+            CellValue::Bool(b) => Box::new(b),
+            CellValue::Int(i) => Box::new(i),
+            CellValue::Float(f) => Box::new(f),
+            CellValue::Bytes(ref encoded) => match value.bytes() {
+                Some(bytes) => Box::new(bytes),
+                None => Box::new(encoded.clone()),
+            },
+            CellValue::Json(json) => Box::new(json.to_string()),
+            CellValue::Text(s) | CellValue::Timestamp(s) => Box::new(s),
+        }
+    }
+
+    fn get_string(row: &Row, column: &str) -> String {
+        row.get::<&str, _>(column).unwrap_or_default().to_string()
+    }
+
+    fn get_opt_string(row: &Row, column: &str) -> Option<String> {
+        row.get::<&str, _>(column).map(|s| s.to_string())
+    }
+
+    /// Convert a TDS value to a JSON value
+    fn mssql_value_to_json(data: &ColumnData<'static>) -> serde_json::Value {
+        use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+        use serde_json::Value;
+
+        let float = |v: f64| serde_json::Number::from_f64(v).map(Value::Number).unwrap_or(Value::Null);
+
+        let value = match data {
+            ColumnData::U8(v) => v.map(Value::from),
+            ColumnData::I16(v) => v.map(Value::from),
+            ColumnData::I32(v) => v.map(Value::from),
+            ColumnData::I64(v) => v.map(Value::from),
+            ColumnData::F32(v) => v.map(|v| float(v as f64)),
+            ColumnData::F64(v) => v.map(float),
+            ColumnData::Bit(v) => v.map(Value::Bool),
+            ColumnData::String(v) => v.as_ref().map(|s| Value::String(s.to_string())),
+            ColumnData::Guid(v) => v.map(|g| Value::String(g.to_string())),
+            ColumnData::Binary(v) => v.as_ref().map(|b| Value::String(base64_encode(b))),
+            ColumnData::Numeric(v) => v.map(|n| Value::String(n.to_string())),
+            ColumnData::Xml(v) => v.as_ref().map(|x| Value::String(x.to_string())),
+            ColumnData::DateTime(_) | ColumnData::SmallDateTime(_) | ColumnData::DateTime2(_) => {
+                NaiveDateTime::from_sql(data).ok().flatten().map(|dt| Value::String(dt.to_string()))
+            }
+            ColumnData::Date(_) => NaiveDate::from_sql(data).ok().flatten().map(|d| Value::String(d.to_string())),
+            ColumnData::Time(_) => NaiveTime::from_sql(data).ok().flatten().map(|t| Value::String(t.to_string())),
+            ColumnData::DateTimeOffset(_) => {
+                DateTime::<FixedOffset>::from_sql(data).ok().flatten().map(|dt| Value::String(dt.to_rfc3339()))
+            }
+        };
+
+        value.unwrap_or(Value::Null)
+    }
+
+    /// SQL Server type name for a result column
+    fn column_type_name(column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Null => "null",
+            ColumnType::Bit | ColumnType::Bitn => "bit",
+            ColumnType::Int1 => "tinyint",
+            ColumnType::Int2 => "smallint",
+            ColumnType::Int4 | ColumnType::Intn => "int",
+            ColumnType::Int8 => "bigint",
+            ColumnType::Float4 => "real",
+            ColumnType::Float8 | ColumnType::Floatn => "float",
+            ColumnType::Money => "money",
+            ColumnType::Money4 => "smallmoney",
+            ColumnType::Datetime4 => "smalldatetime",
+            ColumnType::Datetime | ColumnType::Datetimen => "datetime",
+            ColumnType::Daten => "date",
+            ColumnType::Timen => "time",
+            ColumnType::Datetime2 => "datetime2",
+            ColumnType::DatetimeOffsetn => "datetimeoffset",
+            ColumnType::Guid => "uniqueidentifier",
+            ColumnType::Decimaln | ColumnType::Numericn => "decimal",
+            ColumnType::BigVarBin => "varbinary",
+            ColumnType::BigBinary => "binary",
+            ColumnType::BigVarChar => "varchar",
+            ColumnType::BigChar => "char",
+            ColumnType::NVarchar => "nvarchar",
+            ColumnType::NChar => "nchar",
+            ColumnType::Xml => "xml",
+            ColumnType::Udt => "udt",
+            ColumnType::Text => "text",
+            ColumnType::Image => "image",
+            ColumnType::NText => "ntext",
+            ColumnType::SSVariant => "sql_variant",
+        }
+        .to_string()
+    }
+
+    /// Build a type string with length, precision or scale
+    fn format_type(data_type: &str, max_length: i32, precision: i32, scale: i32) -> String {
+        let length = |divisor: i32| {
+            if max_length == -1 {
+                "MAX".to_string()
+            } else {
+                (max_length / divisor).to_string()
+            }
+        };
+
+        match data_type {
+            "varchar" | "char" | "varbinary" | "binary" => format!("{}({})", data_type.to_uppercase(), length(1)),
+            "nvarchar" | "nchar" => format!("{}({})", data_type.to_uppercase(), length(2)),
+            "decimal" | "numeric" => format!("{}({},{})", data_type.to_uppercase(), precision, scale),
+            "datetime2" | "time" | "datetimeoffset" => format!("{}({})", data_type.to_uppercase(), scale),
+            _ => data_type.to_uppercase(),
+        }
+    }
+
+    async fn fetch_columns(client: &mut MssqlClient, object_name: &str) -> AppResult<Vec<MssqlColumn>> {
+        let query = r#"
+            SELECT
+                c.name AS column_name,
+                TYPE_NAME(c.user_type_id) AS data_type,
+                CAST(c.max_length AS INT) AS max_length,
+                CAST(c.precision AS INT) AS numeric_precision,
+                CAST(c.scale AS INT) AS numeric_scale,
+                c.is_nullable,
+                OBJECT_DEFINITION(c.default_object_id) AS column_default,
+                CAST(ep.value AS NVARCHAR(MAX)) AS comment,
+                CAST(ic.seed_value AS BIGINT) AS identity_seed,
+                CAST(ic.increment_value AS BIGINT) AS identity_increment
+            FROM sys.columns c
+            LEFT JOIN sys.identity_columns ic
+                ON ic.object_id = c.object_id AND ic.column_id = c.column_id
+            LEFT JOIN sys.extended_properties ep
+                ON ep.class = 1 AND ep.major_id = c.object_id
+                AND ep.minor_id = c.column_id AND ep.name = 'MS_Description'
+            WHERE c.object_id = OBJECT_ID(@P1)
+            ORDER BY c.column_id
+        "#;
+
+        let rows = Self::fetch_rows(client, query, &[&object_name], "Failed to get columns").await?;
+
+        Ok(rows.iter().map(|row| {
+            let data_type = Self::get_string(row, "data_type");
+            let identity = match (row.get::<i64, _>("identity_seed"), row.get::<i64, _>("identity_increment")) {
+                (Some(seed), Some(increment)) => Some((seed, increment)),
+                _ => None,
+            };
+
+            MssqlColumn {
+                name: Self::get_string(row, "column_name"),
+                data_type: Self::format_type(
+                    &data_type,
+                    row.get::<i32, _>("max_length").unwrap_or_default(),
+                    row.get::<i32, _>("numeric_precision").unwrap_or_default(),
+                    row.get::<i32, _>("numeric_scale").unwrap_or_default(),
+                ),
+                nullable: row.get::<bool, _>("is_nullable").unwrap_or(true),
+                default_value: Self::get_opt_string(row, "column_default"),
+                comment: Self::get_opt_string(row, "comment"),
+                identity,
+            }
+        }).collect())
+    }
+
+    /// Primary key constraint name and its columns in key order
+    async fn fetch_primary_key(client: &mut MssqlClient, object_name: &str) -> AppResult<(Option<String>, Vec<String>)> {
+        let query = r#"
+            SELECT kc.name AS constraint_name, c.name AS column_name
+            FROM sys.key_constraints kc
+            JOIN sys.index_columns ic
+                ON ic.object_id = kc.parent_object_id AND ic.index_id = kc.unique_index_id
+            JOIN sys.columns c
+                ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+            WHERE kc.type = 'PK'
+            AND kc.parent_object_id = OBJECT_ID(@P1)
+            ORDER BY ic.key_ordinal
+        "#;
+
+        let rows = Self::fetch_rows(client, query, &[&object_name], "Failed to get primary keys").await?;
+
+        let constraint_name = rows.first().map(|row| Self::get_string(row, "constraint_name"));
+        let columns = rows.iter().map(|row| Self::get_string(row, "column_name")).collect();
+
+        Ok((constraint_name, columns))
+    }
+
+    /// Foreign keys where the table is the referencing side (outgoing) or the referenced side (incoming)
+    async fn fetch_relationships(client: &mut MssqlClient, object_name: &str, incoming: bool) -> AppResult<Vec<TableRelationship>> {
+        let filter = if incoming { "fk.referenced_object_id" } else { "fk.parent_object_id" };
+        let query = format!(r#"
+            SELECT
+                fk.name AS constraint_name,
+                SCHEMA_NAME(pt.schema_id) + '.' + pt.name AS source_table,
+                pc.name AS source_column,
+                SCHEMA_NAME(rt.schema_id) + '.' + rt.name AS target_table,
+                rc.name AS target_column
+            FROM sys.foreign_keys fk
+            JOIN sys.foreign_key_columns fkc ON fkc.constraint_object_id = fk.object_id
+            JOIN sys.tables pt ON pt.object_id = fkc.parent_object_id
+            JOIN sys.columns pc
+                ON pc.object_id = fkc.parent_object_id AND pc.column_id = fkc.parent_column_id
+            JOIN sys.tables rt ON rt.object_id = fkc.referenced_object_id
+            JOIN sys.columns rc
+                ON rc.object_id = fkc.referenced_object_id AND rc.column_id = fkc.referenced_column_id
+            WHERE {} = OBJECT_ID(@P1)
+            ORDER BY fk.name, fkc.constraint_column_id
+        "#, filter);
+
+        let rows = Self::fetch_rows(client, &query, &[&object_name], "Failed to get foreign keys").await?;
+
+        Ok(rows.iter().map(|row| TableRelationship {
+            source_table: Self::get_string(row, "source_table"),
+            source_column: Self::get_string(row, "source_column"),
+            target_table: Self::get_string(row, "target_table"),
+            target_column: Self::get_string(row, "target_column"),
+            constraint_name: Self::get_opt_string(row, "constraint_name"),
+        }).collect())
+    }
+
+    async fn fetch_indexes(client: &mut MssqlClient, object_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let query = r#"
+            SELECT
+                i.name AS index_name,
+                c.name AS column_name,
+                i.is_unique,
+                i.is_primary_key
+            FROM sys.indexes i
+            JOIN sys.index_columns ic
+                ON ic.object_id = i.object_id AND ic.index_id = i.index_id
+            JOIN sys.columns c
+                ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+            WHERE i.object_id = OBJECT_ID(@P1)
+            AND i.name IS NOT NULL
+            AND ic.is_included_column = 0
+            ORDER BY i.name, ic.key_ordinal
+        "#;
+
+        let rows = Self::fetch_rows(client, query, &[&object_name], "Failed to get indexes").await?;
+
+        // Rows come one per indexed column; group them by index
+        let mut indexes: Vec<IndexInfo> = Vec::new();
+        for row in &rows {
+            let name = Self::get_string(row, "index_name");
+            let column = Self::get_string(row, "column_name");
+
+            match indexes.last_mut() {
+                Some(index) if index.name == name => index.columns.push(column),
+                _ => indexes.push(IndexInfo {
+                    name,
+                    columns: vec![column],
+                    is_unique: row.get::<bool, _>("is_unique").unwrap_or(false),
+                    is_primary: row.get::<bool, _>("is_primary_key").unwrap_or(false),
+                }),
+            }
+        }
+
+        Ok(indexes)
+    }
+
+    async fn fetch_constraints(client: &mut MssqlClient, object_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        let query = r#"
+            SELECT
+                cc.name AS name,
+                CAST('CHECK' AS NVARCHAR(20)) AS constraint_type,
+                CAST('CHECK ' + cc.definition AS NVARCHAR(MAX)) AS definition
+            FROM sys.check_constraints cc
+            WHERE cc.parent_object_id = OBJECT_ID(@P1)
+            UNION ALL
+            SELECT
+                kc.name AS name,
+                CAST('UNIQUE' AS NVARCHAR(20)) AS constraint_type,
+                CAST('UNIQUE (' + STUFF((
+                    SELECT ', ' + QUOTENAME(c.name)
+                    FROM sys.index_columns ic
+                    JOIN sys.columns c
+                        ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+                    WHERE ic.object_id = kc.parent_object_id AND ic.index_id = kc.unique_index_id
+                    ORDER BY ic.key_ordinal
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') + ')' AS NVARCHAR(MAX)) AS definition
+            FROM sys.key_constraints kc
+            WHERE kc.type = 'UQ'
+            AND kc.parent_object_id = OBJECT_ID(@P1)
+            ORDER BY name
+        "#;
+
+        let rows = Self::fetch_rows(client, query, &[&object_name], "Failed to get constraints").await?;
+
+        Ok(rows.iter().map(|row| ConstraintInfo {
+            name: Self::get_string(row, "name"),
+            constraint_type: Self::get_string(row, "constraint_type"),
+            definition: Self::get_string(row, "definition"),
+        }).collect())
+    }
+
+    /// CREATE TABLE statement with the primary key and, optionally, the foreign keys
+    async fn table_ddl(client: &mut MssqlClient, table_name: &str, include_foreign_keys: bool) -> AppResult<String> {
+        let object_name = Self::object_name(table_name);
+
+        let columns = Self::fetch_columns(client, &object_name).await?;
+        if columns.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+
+        let (pk_name, pk_columns) = Self::fetch_primary_key(client, &object_name).await?;
+        let foreign_keys = if include_foreign_keys {
+            Self::fetch_relationships(client, &object_name, false).await?
+        } else {
+            vec![]
+        };
+
+        let mut ddl = format!("CREATE TABLE {} (\n", object_name);
+
+        // Add columns
+        let column_defs: Vec<String> = columns.iter().map(|col| {
+            let mut col_def = format!("    {} {}", quote_ident(&col.name), col.data_type);
+
+            if let Some((seed, increment)) = col.identity {
+                col_def.push_str(&format!(" IDENTITY({},{})", seed, increment));
+            }
+
+            if !col.nullable {
+                col_def.push_str(" NOT NULL");
+            }
+
+            if let Some(default) = &col.default_value {
+                col_def.push_str(&format!(" DEFAULT {}", default));
+            }
+
+            col_def
+        }).collect();
+
+        ddl.push_str(&column_defs.join(",\n"));
+
+        // Add primary key constraint
+        if !pk_columns.is_empty() {
+            let pk_cols_quoted: Vec<String> = pk_columns.iter().map(|c| quote_ident(c)).collect();
+            match pk_name {
+                Some(name) => ddl.push_str(&format!(
+                    ",\n    CONSTRAINT {} PRIMARY KEY ({})",
+                    quote_ident(&name),
+                    pk_cols_quoted.join(", ")
+                )),
+                None => ddl.push_str(&format!(",\n    PRIMARY KEY ({})", pk_cols_quoted.join(", "))),
+            }
+        }
+
+        // Add foreign key constraints; rows arrive ordered by constraint then column
+        let mut grouped: Vec<(String, String, Vec<String>, Vec<String>)> = Vec::new();
+        for fk in foreign_keys {
+            let name = fk.constraint_name.unwrap_or_default();
+            match grouped.last_mut() {
+                Some((last_name, _, src, dst)) if *last_name == name => {
+                    src.push(fk.source_column);
+                    dst.push(fk.target_column);
+                }
+                _ => grouped.push((name, fk.target_table, vec![fk.source_column], vec![fk.target_column])),
+            }
+        }
+
+        for (name, target_table, source_columns, target_columns) in grouped {
+            let src_cols_quoted: Vec<String> = source_columns.iter().map(|c| quote_ident(c)).collect();
+            let target_cols_quoted: Vec<String> = target_columns.iter().map(|c| quote_ident(c)).collect();
+
+            ddl.push_str(&format!(
+                ",\n    CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+                quote_ident(&name),
+                src_cols_quoted.join(", "),
+                Self::object_name(&target_table),
+                target_cols_quoted.join(", ")
+            ));
+        }
+
+        ddl.push_str("\n);");
+
+        Ok(ddl)
+    }
+
+    async fn fetch_table_schema(client: &mut MssqlClient, table_name: &str) -> AppResult<TableSchema> {
+        let object_name = Self::object_name(table_name);
+
+        let columns = Self::fetch_columns(client, &object_name).await?;
+        let (_, primary_keys) = Self::fetch_primary_key(client, &object_name).await?;
+        let foreign_keys = Self::fetch_relationships(client, &object_name, false).await?
+            .into_iter()
+            .map(|r| ForeignKeyInfo {
+                column: r.source_column,
+                references_table: r.target_table,
+                references_column: r.target_column,
+            })
+            .collect();
+
+        let columns: Vec<ColumnInfo> = columns
+            .into_iter()
+            .map(|c| ColumnInfo {
+                is_primary_key: primary_keys.contains(&c.name),
+                native_type: None,
+                type_oid: None,
+                name: c.name,
+                data_type: c.data_type,
+                nullable: c.nullable,
+            })
+            .collect();
+
+        Ok(TableSchema {
+            table_name: table_name.to_string(),
+            columns,
+            primary_keys,
+            foreign_keys,
+        })
+    }
+
+    async fn fetch_table_names(client: &mut MssqlClient) -> AppResult<Vec<(String, String)>> {
+        let query = r#"
+            SELECT TABLE_SCHEMA AS table_schema, TABLE_NAME AS table_name
+            FROM INFORMATION_SCHEMA.TABLES
+            WHERE TABLE_TYPE = 'BASE TABLE'
+            ORDER BY TABLE_SCHEMA, TABLE_NAME
+        "#;
+
+        let rows = Self::fetch_rows(client, query, &[], "Failed to get tables").await?;
+
+        Ok(rows.iter()
+            .map(|row| (Self::get_string(row, "table_schema"), Self::get_string(row, "table_name")))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for MssqlDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let mut client = Self::connect(config).await?;
+
+        // Get server version
+        let version = client.simple_query("SELECT @@VERSION").await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?
+            .into_row().await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?
+            .and_then(|row| row.get::<&str, _>(0).map(|v| v.lines().next().unwrap_or(v).trim().to_string()));
+
+        let _ = client.close().await;
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!("MSSQL connection to {} successful", config.database),
+            server_version: version,
+        })
+    }
+
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        if !sql_parse::returns_rows(sql, Dialect::Mssql) {
+            let result = client.execute(sql, &[]).await
+                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+            return Ok(QueryResult {
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(result.total()),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        let mut stream = client.simple_query(sql).await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        // A batch can return several result sets; keep the last one
+        let mut columns: Vec<ColumnInfo> = Vec::new();
+        let mut rows: Vec<Vec<serde_json::Value>> = Vec::new();
+
+        while let Some(item) = stream.try_next().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+        {
+            match item {
+                QueryItem::Metadata(meta) => {
+                    columns = meta.columns().iter().map(|col| ColumnInfo {
+                        name: col.name().to_string(),
+                        data_type: Self::column_type_name(col.column_type()),
+                        nullable: true,
+                        is_primary_key: false,
+                        native_type: Some(format!("{:?}", col.column_type())),
+                        type_oid: None,
+                    }).collect();
+                    rows.clear();
+                }
+                QueryItem::Row(row) => {
+                    rows.push(row.into_iter().map(|data| Self::mssql_value_to_json(&data)).collect());
+                }
+            }
+        }
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let mut stream = client.simple_query(sql).await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        let mut batches = RowBatches::new(on_batch, vec![], batch_size);
+        let mut described = false;
+        while let Some(item) = stream.try_next().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+        {
+            match item {
+                // Only the first result set is read
+                QueryItem::Metadata(_) if described => break,
+                QueryItem::Metadata(meta) => {
+                    batches.columns = meta.columns().iter().map(|col| ColumnInfo {
+                        name: col.name().to_string(),
+                        data_type: Self::column_type_name(col.column_type()),
+                        nullable: true,
+                        is_primary_key: false,
+                        native_type: Some(format!("{:?}", col.column_type())),
+                        type_oid: None,
+                    }).collect();
+                    described = true;
+                }
+                QueryItem::Row(row) => {
+                    batches.push(row.into_iter().map(|data| Self::mssql_value_to_json(&data)).collect())?;
+                }
+            }
+        }
+        batches.finish()
+    }
+
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let params: Vec<Box<dyn ToSql>> = params.into_iter().map(Self::to_sql_param).collect();
+        let param_refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+        if !sql_parse::returns_rows(sql, Dialect::Mssql) {
+            let result = client.execute(sql, &param_refs).await
+                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+            return Ok(QueryResult {
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(result.total()),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        let mut stream = client.query(sql, &param_refs).await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        let columns: Vec<ColumnInfo> = stream.columns().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+            .unwrap_or_default()
+            .iter()
+            .map(|col| ColumnInfo {
+                name: col.name().to_string(),
+                data_type: Self::column_type_name(col.column_type()),
+                nullable: true,
+                is_primary_key: false,
+                native_type: Some(format!("{:?}", col.column_type())),
+                type_oid: None,
+            })
+            .collect();
+
+        let rows = stream.into_first_result().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+            .into_iter()
+            .map(|row| row.into_iter().map(|data| Self::mssql_value_to_json(&data)).collect())
+            .collect();
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::MSSQL, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let sql = insert_statement(&DatabaseType::MSSQL, table, columns);
+
+        // Transaction control goes through plain batches; inside sp_executesql it would unbalance @@TRANCOUNT
+        Self::run_batch(&mut client, "BEGIN TRANSACTION").await?;
+        let mut failed = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            Self::run_batch(&mut client, "SAVE TRANSACTION import_row").await?;
+
+            let params: Vec<Box<dyn ToSql>> = row.into_iter().map(|value| Self::to_sql_param(value.into())).collect();
+            let param_refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+
+            if let Err(e) = client.execute(sql.as_str(), &param_refs).await {
+                failed.push((index, e.to_string()));
+                if let Err(rollback_err) = Self::run_batch(&mut client, "ROLLBACK TRANSACTION import_row").await {
+                    // The error doomed the whole transaction; undo everything
+                    let _ = Self::run_batch(&mut client, "IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION").await;
+                    return Err(rollback_err);
+                }
+            }
+        }
+
+        Self::run_batch(&mut client, "COMMIT TRANSACTION").await?;
+
+        Ok(failed)
+    }
+
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let tables = Self::fetch_table_names(&mut client).await?
+            .into_iter()
+            .map(|(schema, name)| TableInfo {
+                name: format!("{}.{}", schema, name),
+                schema: Some(schema),
+                table_type: "BASE TABLE".to_string(),
+                row_count: None,
+                parent_table: None,
+                pinned: false,
+            })
+            .collect();
+
+        Ok(tables)
+    }
+
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let query = r#"
+            SELECT name
+            FROM sys.databases
+            WHERE state_desc = 'ONLINE' AND HAS_DBACCESS(name) = 1
+            ORDER BY name
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[], "Failed to list databases").await?;
+        Ok(rows.iter().map(|row| Self::get_string(row, "name")).collect())
+    }
+
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        // Skip the system schemas and the fixed database-role schemas (db_owner, ...)
+        let query = r#"
+            SELECT name
+            FROM sys.schemas
+            WHERE name NOT IN ('sys', 'INFORMATION_SCHEMA', 'guest')
+            AND name NOT LIKE 'db[_]%'
+            ORDER BY name
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[], "Failed to list schemas").await?;
+        Ok(rows.iter().map(|row| Self::get_string(row, "name")).collect())
+    }
+
+    async fn create_database(&self, pool: PoolRef<'_>, options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+
+        if options.charset.is_some() {
+            return Err(AppError::ValidationError(
+                "SQL Server sets the character set through the collation".to_string(),
+            ));
+        }
+
+        let mut sql = format!("CREATE DATABASE {}", quote_ident(&options.name));
+        if let Some(collation) = &options.collation {
+            // Collation names cannot be quoted or parameterized
+            if !collation.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(AppError::ValidationError(format!("Invalid collation name: {}", collation)));
+            }
+            sql.push_str(&format!(" COLLATE {}", collation));
+        }
+
+        let mut client = client.lock().await;
+        let start = Instant::now();
+
+        client.execute(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to create database: {}", e)))?;
+
+        if let Some(owner) = &options.owner {
+            let sql = format!("ALTER AUTHORIZATION ON DATABASE::{} TO {}", quote_ident(&options.name), quote_ident(owner));
+            client.execute(sql, &[]).await
+                .map_err(|e| AppError::QueryError(format!("Database created, but failed to set its owner: {}", e)))?;
+        }
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        client.execute(format!("DROP DATABASE {}", quote_ident(name)), &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to drop database: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let mut sql = format!("CREATE SCHEMA {}", quote_ident(&options.name));
+        if let Some(owner) = &options.owner {
+            sql.push_str(&format!(" AUTHORIZATION {}", quote_ident(owner)));
+        }
+        client.execute(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to create schema: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// SQL Server only drops empty schemas
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, cascade: bool) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+
+        if cascade {
+            return Err(AppError::ValidationError(
+                "SQL Server cannot cascade a schema drop; drop or move its objects first".to_string(),
+            ));
+        }
+
+        let mut client = client.lock().await;
+        let start = Instant::now();
+
+        client.execute(format!("DROP SCHEMA {}", quote_ident(name)), &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to drop schema: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        Self::fetch_table_schema(&mut client, table_name).await
+    }
+
+    async fn get_all_table_schemas(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableSchema>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let table_names = Self::fetch_table_names(&mut client).await?;
+
+        let mut schemas = Vec::new();
+        for (schema, name) in table_names {
+            let table_name = format!("{}.{}", schema, name);
+            schemas.push(Self::fetch_table_schema(&mut client, &table_name).await?);
+        }
+
+        Ok(schemas)
+    }
+
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let host = config.host.as_deref().unwrap_or("localhost");
+        let port = config.port.unwrap_or(1433);
+        let username = config.username.as_deref().unwrap_or("sa");
+        let password = config.password.as_deref().unwrap_or("");
+
+        let mut ado = format!("Server=tcp:{},{};Database={};User Id={};Password={}",
+            host, port, config.database, username, password);
+
+        match config.ssl_mode.as_deref() {
+            Some("disable") => ado.push_str(";Encrypt=false"),
+            Some("verify-ca") | Some("verify-full") => ado.push_str(";Encrypt=true"),
+            _ => ado.push_str(";Encrypt=true;TrustServerCertificate=true"),
+        }
+
+        ado
+    }
+
+    async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        Self::table_ddl(&mut client, table_name, true).await
+    }
+
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let query = r#"
+            SELECT DISTINCT
+                SCHEMA_NAME(v.schema_id) + '.' + v.name AS object,
+                CAST('VIEW' AS NVARCHAR(20)) AS object_type,
+                SCHEMA_NAME(t.schema_id) + '.' + t.name AS depends_on,
+                CAST(NULL AS NVARCHAR(128)) AS constraint_name
+            FROM sys.sql_expression_dependencies d
+            JOIN sys.views v ON v.object_id = d.referencing_id
+            JOIN sys.objects t ON t.object_id = d.referenced_id AND t.type IN ('U', 'V')
+            WHERE t.object_id <> v.object_id
+            UNION ALL
+            SELECT
+                SCHEMA_NAME(pt.schema_id) + '.' + pt.name AS object,
+                CAST('TABLE' AS NVARCHAR(20)) AS object_type,
+                SCHEMA_NAME(rt.schema_id) + '.' + rt.name AS depends_on,
+                fk.name AS constraint_name
+            FROM sys.foreign_keys fk
+            JOIN sys.tables pt ON pt.object_id = fk.parent_object_id
+            JOIN sys.tables rt ON rt.object_id = fk.referenced_object_id
+            ORDER BY object, depends_on
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[], "Failed to get object dependencies").await?;
+
+        Ok(rows.iter().map(|row| ObjectDependency {
+            object: Self::get_string(row, "object"),
+            object_type: Self::get_string(row, "object_type"),
+            depends_on: Self::get_string(row, "depends_on"),
+            constraint_name: Self::get_opt_string(row, "constraint_name"),
+        }).collect())
+    }
+
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        _options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        // Names are always schema qualified since each login has its own default schema
+        let mut objects = Vec::new();
+
+        // User schemas; ids from 16384 belong to the fixed database roles
+        let schemas = Self::fetch_rows(
+            &mut client,
+            "SELECT name FROM sys.schemas WHERE schema_id > 4 AND schema_id < 16384 ORDER BY name",
+            &[],
+            "Failed to list schemas",
+        ).await?;
+        for row in &schemas {
+            let name = Self::get_string(row, "name");
+            let create = format!(
+                "IF SCHEMA_ID({}) IS NULL EXEC({});",
+                string_literal(&DatabaseType::MSSQL, &name),
+                string_literal(&DatabaseType::MSSQL, &format!("CREATE SCHEMA {}", quote_ident(&name)))
+            );
+            objects.push(
+                DdlObject::new(DdlKind::Schema, name.clone(), create)
+                    .with_drop(format!("DROP SCHEMA IF EXISTS {};", quote_ident(&name))),
+            );
+        }
+
+        // Alias types; table types are skipped
+        let types = Self::fetch_rows(
+            &mut client,
+            r#"
+            SELECT
+                SCHEMA_NAME(t.schema_id) AS schema_name,
+                t.name AS type_name,
+                TYPE_NAME(t.system_type_id) AS base_type,
+                CAST(t.max_length AS INT) AS max_length,
+                CAST(t.precision AS INT) AS numeric_precision,
+                CAST(t.scale AS INT) AS numeric_scale,
+                t.is_nullable
+            FROM sys.types t
+            WHERE t.is_user_defined = 1 AND t.is_table_type = 0
+            ORDER BY schema_name, type_name
+            "#,
+            &[],
+            "Failed to list types",
+        ).await?;
+        for row in &types {
+            let name = format!(
+                "{}.{}",
+                quote_ident(&Self::get_string(row, "schema_name")),
+                quote_ident(&Self::get_string(row, "type_name"))
+            );
+            let base_type = Self::format_type(
+                &Self::get_string(row, "base_type"),
+                row.get::<i32, _>("max_length").unwrap_or_default(),
+                row.get::<i32, _>("numeric_precision").unwrap_or_default(),
+                row.get::<i32, _>("numeric_scale").unwrap_or_default(),
+            );
+            let nullable = if row.get::<bool, _>("is_nullable").unwrap_or(true) { "NULL" } else { "NOT NULL" };
+            objects.push(
+                DdlObject::new(DdlKind::Type, name.clone(), format!("CREATE TYPE {} FROM {} {};", name, base_type, nullable))
+                    .with_drop(format!("DROP TYPE IF EXISTS {};", name)),
+            );
+        }
+
+        let tables = Self::fetch_rows(
+            &mut client,
+            "SELECT SCHEMA_NAME(schema_id) + '.' + name AS name FROM sys.tables WHERE is_ms_shipped = 0 ORDER BY name",
+            &[],
+            "Failed to list tables",
+        ).await?;
+        for row in &tables {
+            let name = Self::get_string(row, "name");
+            let create = Self::table_ddl(&mut client, &name, false).await?;
+            objects.push(
+                DdlObject::new(DdlKind::Table, name.clone(), create)
+                    .with_drop(format!("DROP TABLE IF EXISTS {};", Self::object_name(&name))),
+            );
+        }
+
+        // Rowstore indexes other than those behind primary keys and unique constraints
+        let indexes = Self::fetch_rows(
+            &mut client,
+            r#"
+            SELECT
+                i.name AS index_name,
+                QUOTENAME(SCHEMA_NAME(t.schema_id)) + '.' + QUOTENAME(t.name) AS table_name,
+                i.is_unique,
+                i.type_desc,
+                i.filter_definition,
+                STUFF((
+                    SELECT ', ' + QUOTENAME(c.name) + CASE WHEN ic.is_descending_key = 1 THEN ' DESC' ELSE '' END
+                    FROM sys.index_columns ic
+                    JOIN sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+                    WHERE ic.object_id = i.object_id AND ic.index_id = i.index_id AND ic.is_included_column = 0
+                    ORDER BY ic.key_ordinal
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') AS key_columns,
+                STUFF((
+                    SELECT ', ' + QUOTENAME(c.name)
+                    FROM sys.index_columns ic
+                    JOIN sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+                    WHERE ic.object_id = i.object_id AND ic.index_id = i.index_id AND ic.is_included_column = 1
+                    ORDER BY ic.index_column_id
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') AS included_columns
+            FROM sys.indexes i
+            JOIN sys.tables t ON t.object_id = i.object_id
+            WHERE i.type IN (1, 2)
+            AND i.is_primary_key = 0
+            AND i.is_unique_constraint = 0
+            AND i.is_hypothetical = 0
+            AND t.is_ms_shipped = 0
+            ORDER BY table_name, i.name
+            "#,
+            &[],
+            "Failed to list indexes",
+        ).await?;
+        for row in &indexes {
+            let index_name = Self::get_string(row, "index_name");
+            let table_name = Self::get_string(row, "table_name");
+            let mut create = format!(
+                "CREATE {}{} INDEX {} ON {} ({})",
+                if row.get::<bool, _>("is_unique").unwrap_or(false) { "UNIQUE " } else { "" },
+                Self::get_string(row, "type_desc"),
+                quote_ident(&index_name),
+                table_name,
+                Self::get_string(row, "key_columns")
+            );
+            if let Some(included) = Self::get_opt_string(row, "included_columns") {
+                create.push_str(&format!(" INCLUDE ({})", included));
+            }
+            if let Some(filter) = Self::get_opt_string(row, "filter_definition") {
+                create.push_str(&format!(" WHERE {}", filter));
+            }
+            create.push(';');
+            objects.push(DdlObject::new(DdlKind::Index, format!("{}.{}", table_name, index_name), create));
+        }
+
+        // Check and unique constraints, then foreign keys once every key they reference exists
+        let constraints = Self::fetch_rows(
+            &mut client,
+            r#"
+            SELECT
+                QUOTENAME(SCHEMA_NAME(t.schema_id)) + '.' + QUOTENAME(t.name) AS table_name,
+                cc.name AS name,
+                CAST('CHECK ' + cc.definition AS NVARCHAR(MAX)) AS definition,
+                0 AS is_foreign_key
+            FROM sys.check_constraints cc
+            JOIN sys.tables t ON t.object_id = cc.parent_object_id
+            WHERE t.is_ms_shipped = 0
+            UNION ALL
+            SELECT
+                QUOTENAME(SCHEMA_NAME(t.schema_id)) + '.' + QUOTENAME(t.name) AS table_name,
+                kc.name AS name,
+                CAST('UNIQUE (' + STUFF((
+                    SELECT ', ' + QUOTENAME(c.name)
+                    FROM sys.index_columns ic
+                    JOIN sys.columns c ON c.object_id = ic.object_id AND c.column_id = ic.column_id
+                    WHERE ic.object_id = kc.parent_object_id AND ic.index_id = kc.unique_index_id
+                    ORDER BY ic.key_ordinal
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') + ')' AS NVARCHAR(MAX)) AS definition,
+                0 AS is_foreign_key
+            FROM sys.key_constraints kc
+            JOIN sys.tables t ON t.object_id = kc.parent_object_id
+            WHERE kc.type = 'UQ' AND t.is_ms_shipped = 0
+            UNION ALL
+            SELECT
+                QUOTENAME(SCHEMA_NAME(t.schema_id)) + '.' + QUOTENAME(t.name) AS table_name,
+                fk.name AS name,
+                CAST('FOREIGN KEY (' + STUFF((
+                    SELECT ', ' + QUOTENAME(c.name)
+                    FROM sys.foreign_key_columns fkc
+                    JOIN sys.columns c ON c.object_id = fkc.parent_object_id AND c.column_id = fkc.parent_column_id
+                    WHERE fkc.constraint_object_id = fk.object_id
+                    ORDER BY fkc.constraint_column_id
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') + ') REFERENCES '
+                + QUOTENAME(OBJECT_SCHEMA_NAME(fk.referenced_object_id)) + '.' + QUOTENAME(OBJECT_NAME(fk.referenced_object_id))
+                + ' (' + STUFF((
+                    SELECT ', ' + QUOTENAME(c.name)
+                    FROM sys.foreign_key_columns fkc
+                    JOIN sys.columns c ON c.object_id = fkc.referenced_object_id AND c.column_id = fkc.referenced_column_id
+                    WHERE fkc.constraint_object_id = fk.object_id
+                    ORDER BY fkc.constraint_column_id
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 2, '') + ')'
+                + ' ON DELETE ' + REPLACE(fk.delete_referential_action_desc, '_', ' ')
+                + ' ON UPDATE ' + REPLACE(fk.update_referential_action_desc, '_', ' ') AS NVARCHAR(MAX)) AS definition,
+                1 AS is_foreign_key
+            FROM sys.foreign_keys fk
+            JOIN sys.tables t ON t.object_id = fk.parent_object_id
+            WHERE t.is_ms_shipped = 0
+            ORDER BY is_foreign_key, table_name, name
+            "#,
+            &[],
+            "Failed to list constraints",
+        ).await?;
+        for row in &constraints {
+            let table_name = Self::get_string(row, "table_name");
+            let name = Self::get_string(row, "name");
+            let create = format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} {};",
+                table_name,
+                quote_ident(&name),
+                Self::get_string(row, "definition")
+            );
+            objects.push(DdlObject::new(DdlKind::Constraint, format!("{}.{}", table_name, name), create));
+        }
+
+        // Views and triggers keep the text they were created with; each must start its own batch
+        let modules = Self::fetch_rows(
+            &mut client,
+            r#"
+            SELECT
+                CAST(o.object_id AS NVARCHAR(20)) AS object_key,
+                CAST(o.type AS NVARCHAR(2)) AS object_type,
+                QUOTENAME(SCHEMA_NAME(o.schema_id)) + '.' + QUOTENAME(o.name) AS object_name,
+                m.definition,
+                STUFF((
+                    SELECT DISTINCT ',' + CAST(d.referenced_id AS NVARCHAR(20))
+                    FROM sys.sql_expression_dependencies d
+                    WHERE d.referencing_id = o.object_id AND d.referenced_id IS NOT NULL
+                    FOR XML PATH(''), TYPE
+                ).value('.', 'NVARCHAR(MAX)'), 1, 1, '') AS depends_on
+            FROM sys.objects o
+            JOIN sys.sql_modules m ON m.object_id = o.object_id
+            WHERE o.type IN ('V', 'TR') AND o.is_ms_shipped = 0
+            ORDER BY o.type, object_name
+            "#,
+            &[],
+            "Failed to list views and triggers",
+        ).await?;
+        for row in &modules {
+            let object_name = Self::get_string(row, "object_name");
+            let mut create = Self::get_string(row, "definition").trim().to_string();
+            if !create.ends_with(';') {
+                create.push(';');
+            }
+            let depends_on = Self::get_opt_string(row, "depends_on")
+                .map(|ids| ids.split(',').map(str::to_string).collect())
+                .unwrap_or_default();
+            let object = if Self::get_string(row, "object_type").trim() == "V" {
+                DdlObject::new(DdlKind::View, Self::get_string(row, "object_key"), create)
+                    .with_drop(format!("DROP VIEW IF EXISTS {};", object_name))
+                    .with_dependencies(depends_on)
+            } else {
+                DdlObject::new(DdlKind::Trigger, Self::get_string(row, "object_key"), create)
+            };
+            objects.push(object);
+        }
+
+        Ok(objects)
+    }
+
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        // sp_rename takes the qualified old name but only the bare new name
+        let old_object = Self::object_name(old_name);
+        let (_, new_table) = Self::split_table_name(new_name);
+
+        client.execute("EXEC sp_rename @P1, @P2", &[&old_object, &new_table]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename table: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let sql = format!(
+            "ALTER TABLE {} ADD {}",
+            Self::object_name(table_name),
+            column_definition(&DatabaseType::MSSQL, column)
+        );
+        client.execute(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to add column: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        // A column with a default cannot be dropped until its default constraint is
+        let object = Self::object_name(table_name);
+        let sql = format!(
+            r#"
+            DECLARE @constraint sysname;
+            SELECT @constraint = dc.name
+            FROM sys.default_constraints dc
+            JOIN sys.columns c ON c.object_id = dc.parent_object_id AND c.column_id = dc.parent_column_id
+            WHERE dc.parent_object_id = OBJECT_ID(@P1) AND c.name = @P2;
+            IF @constraint IS NOT NULL
+                EXEC('ALTER TABLE ' + @P1 + ' DROP CONSTRAINT ' + QUOTENAME(@constraint));
+            ALTER TABLE {} DROP COLUMN {};
+            "#,
+            object,
+            quote_ident(column_name)
+        );
+        client.execute(sql, &[&object, &column_name]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to drop column: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let old_object = format!("{}.{}", Self::object_name(table_name), quote_ident(old_name));
+        client.execute("EXEC sp_rename @P1, @P2, 'COLUMN'", &[&old_object, &new_name]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename column: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let sql = format!(
+            "ALTER TABLE {} ALTER COLUMN {} {} {}",
+            Self::object_name(table_name),
+            quote_ident(column_name),
+            data_type,
+            if nullable { "NULL" } else { "NOT NULL" }
+        );
+        client.execute(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to change column type: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        // TRUNCATE reseeds identity columns and is refused for tables referenced by foreign keys
+        client.execute(format!("TRUNCATE TABLE {}", Self::object_name(table_name)), &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to truncate table: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// SELECT INTO copies columns, nullability and identity, but not keys, indexes or defaults
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let start = Instant::now();
+
+        let sql = format!(
+            "SELECT * INTO {} FROM {}{}",
+            Self::object_name(new_name),
+            Self::object_name(table_name),
+            if include_data { "" } else { " WHERE 1 = 0" }
+        );
+        let result = client.execute(sql, &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to duplicate table: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.total()),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        Self::fetch_indexes(&mut client, &Self::object_name(table_name)).await
+    }
+
+    async fn get_constraints(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        Self::fetch_constraints(&mut client, &Self::object_name(table_name)).await
+    }
+
+    async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let (schema, _) = Self::split_table_name(table_name);
+        let object_name = Self::object_name(table_name);
+
+        let columns = Self::fetch_columns(&mut client, &object_name).await?;
+        let (_, primary_keys) = Self::fetch_primary_key(&mut client, &object_name).await?;
+        let foreign_keys: Vec<ForeignKeyInfo> = Self::fetch_relationships(&mut client, &object_name, false).await?
+            .into_iter()
+            .map(|r| ForeignKeyInfo {
+                column: r.source_column,
+                references_table: r.target_table,
+                references_column: r.target_column,
+            })
+            .collect();
+        let indexes = Self::fetch_indexes(&mut client, &object_name).await?;
+        let constraints = Self::fetch_constraints(&mut client, &object_name).await?;
+
+        // Estimate the row count from partition metadata; views have no partitions
+        let estimate_query = r#"
+            SELECT CAST(SUM(rows) AS BIGINT) AS estimate
+            FROM sys.partitions
+            WHERE object_id = OBJECT_ID(@P1) AND index_id IN (0, 1)
+        "#;
+        let estimate: Option<i64> = Self::fetch_rows(&mut client, estimate_query, &[&object_name], "Failed to estimate rows").await
+            .ok()
+            .and_then(|rows| rows.first().and_then(|row| row.get::<i64, _>("estimate")));
+
+        // Get table comment
+        let comment_query = r#"
+            SELECT CAST(value AS NVARCHAR(MAX)) AS comment
+            FROM sys.extended_properties
+            WHERE class = 1 AND major_id = OBJECT_ID(@P1) AND minor_id = 0 AND name = 'MS_Description'
+        "#;
+        let table_comment: Option<String> = Self::fetch_rows(&mut client, comment_query, &[&object_name], "Failed to get table comment").await
+            .ok()
+            .and_then(|rows| rows.first().and_then(|row| Self::get_opt_string(row, "comment")));
+
+        // Identity, computed and collation details per column
+        let details_query = r#"
+            SELECT c.name, c.is_identity, cc.definition, c.collation_name
+            FROM sys.columns c
+            LEFT JOIN sys.computed_columns cc ON cc.object_id = c.object_id AND cc.column_id = c.column_id
+            WHERE c.object_id = OBJECT_ID(@P1)
+        "#;
+        let details: HashMap<String, Row> = Self::fetch_rows(&mut client, details_query, &[&object_name], "Failed to get column details").await?
+            .into_iter()
+            .map(|row| (Self::get_string(&row, "name"), row))
+            .collect();
+
+        let columns: Vec<ExtendedColumnInfo> = columns.into_iter().map(|col| {
+            let detail = details.get(&col.name);
+            let is_identity = detail.and_then(|row| row.get::<bool, _>("is_identity")).unwrap_or(false);
+            ExtendedColumnInfo {
+                is_primary_key: primary_keys.contains(&col.name),
+                is_identity,
+                // IDENTITY columns only take explicit values under IDENTITY_INSERT
+                identity_always: is_identity,
+                generation_expression: detail.and_then(|row| Self::get_opt_string(row, "definition")),
+                collation: detail.and_then(|row| Self::get_opt_string(row, "collation_name")),
+                name: col.name,
+                data_type: col.data_type,
+                nullable: col.nullable,
+                default_value: col.default_value,
+                comment: col.comment,
+            }
+        }).collect();
+
+        // Counting takes the connection again
+        drop(client);
+        let (row_count, row_count_exact) = self.row_count_from_estimate(pool, table_name, estimate).await;
+
+        Ok(TableProperties {
+            table_name: table_name.to_string(),
+            schema: Some(schema),
+            columns,
+            primary_keys,
+            foreign_keys,
+            indexes,
+            constraints,
+            row_count,
+            row_count_exact,
+            table_comment,
+        })
+    }
+
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let sql = format!("SELECT COUNT_BIG(*) AS count FROM {}", Self::object_name(table_name));
+        Self::fetch_rows(&mut client, &sql, &[], "Failed to count rows").await?
+            .first()
+            .and_then(|row| row.get::<i64, _>("count"))
+            .ok_or_else(|| AppError::QueryError(format!("Failed to count rows of {}", table_name)))
+    }
+
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let object_name = Self::object_name(table_name);
+
+        // Heap (0) and clustered index (1) pages hold the data; the rest are secondary indexes
+        let query = r#"
+            SELECT
+                CAST(SUM(CASE WHEN i.index_id IN (0, 1) THEN a.used_pages ELSE 0 END) * 8192 AS BIGINT) AS table_size,
+                CAST(SUM(CASE WHEN i.index_id > 1 THEN a.used_pages ELSE 0 END) * 8192 AS BIGINT) AS index_size,
+                CAST(SUM(a.total_pages) * 8192 AS BIGINT) AS total_size,
+                CAST(SUM(a.total_pages - a.used_pages) * 8192 AS BIGINT) AS free_bytes,
+                (
+                    SELECT CAST(SUM(p2.rows) AS BIGINT)
+                    FROM sys.partitions p2
+                    WHERE p2.object_id = OBJECT_ID(@P1) AND p2.index_id IN (0, 1)
+                ) AS approximate_rows,
+                (
+                    SELECT CONVERT(NVARCHAR(30), MAX(STATS_DATE(s.object_id, s.stats_id)), 126)
+                    FROM sys.stats s
+                    WHERE s.object_id = OBJECT_ID(@P1)
+                ) AS last_analyze
+            FROM sys.indexes i
+            JOIN sys.partitions p ON p.object_id = i.object_id AND p.index_id = i.index_id
+            JOIN sys.allocation_units a ON a.container_id = p.partition_id
+            WHERE i.object_id = OBJECT_ID(@P1)
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[&object_name], "Failed to get table stats").await?;
+        let row = rows.first()
+            .ok_or_else(|| AppError::QueryError(format!("Table {} not found", table_name)))?;
+        let table_size = row.get::<i64, _>("table_size");
+        if table_size.is_none() {
+            return Err(AppError::QueryError(format!("Table {} not found", table_name)));
+        }
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: table_size.map(|v| v as u64),
+            index_size_bytes: row.get::<i64, _>("index_size").map(|v| v as u64),
+            total_size_bytes: row.get::<i64, _>("total_size").map(|v| v as u64),
+            approximate_rows: row.get::<i64, _>("approximate_rows").map(|v| v as u64),
+            dead_rows: None,
+            dead_row_ratio: None,
+            free_bytes: row.get::<i64, _>("free_bytes").map(|v| v as u64),
+            last_vacuum: None,
+            last_analyze: Self::get_opt_string(row, "last_analyze"),
+        })
+    }
+
+    async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let object_name = Self::object_name(table_name);
+
+        let mut relationships = Self::fetch_relationships(&mut client, &object_name, false).await?;
+        relationships.extend(Self::fetch_relationships(&mut client, &object_name, true).await?);
+
+        Ok(relationships)
+    }
+
+    async fn get_users_and_roles(&self, _pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for SQL Server".to_string()))
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for SQL Server".to_string()))
+    }
+
+    /// Needs VIEW SERVER STATE
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let query = r#"
+            SELECT
+                CAST((SELECT COUNT(*) FROM sys.dm_exec_sessions WHERE is_user_process = 1) AS BIGINT) AS active_connections,
+                CAST(@@MAX_CONNECTIONS AS BIGINT) AS max_connections,
+                CAST(DATEDIFF(SECOND, sqlserver_start_time, SYSDATETIME()) AS BIGINT) AS uptime_seconds,
+                (
+                    SELECT CAST(a.cntr_value AS FLOAT) / NULLIF(b.cntr_value, 0)
+                    FROM sys.dm_os_performance_counters a
+                    JOIN sys.dm_os_performance_counters b ON b.object_name = a.object_name
+                    WHERE a.counter_name = 'Buffer cache hit ratio'
+                    AND b.counter_name = 'Buffer cache hit ratio base'
+                ) AS cache_hit_ratio
+            FROM sys.dm_os_sys_info
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[], "Failed to get server stats").await?;
+        let row = rows.first()
+            .ok_or_else(|| AppError::QueryError("Failed to get server stats: no rows returned".to_string()))?;
+
+        let sizes_query = r#"
+            SELECT DB_NAME(database_id) AS name, SUM(CAST(size AS BIGINT)) * 8192 AS size_bytes
+            FROM sys.master_files
+            GROUP BY database_id
+            ORDER BY size_bytes DESC
+        "#;
+
+        let sizes = Self::fetch_rows(&mut client, sizes_query, &[], "Failed to get database sizes").await?;
+
+        Ok(ServerStats {
+            active_connections: row.get::<i64, _>("active_connections").map(|v| v as u64),
+            max_connections: row.get::<i64, _>("max_connections").map(|v| v as u64),
+            uptime_seconds: row.get::<i64, _>("uptime_seconds").map(|v| v as u64),
+            cache_hit_ratio: row.get::<f64, _>("cache_hit_ratio"),
+            database_sizes: sizes.iter().map(|row| DatabaseSize {
+                name: Self::get_string(row, "name"),
+                size_bytes: row.get::<i64, _>("size_bytes").unwrap_or(0) as u64,
+            }).collect(),
+        })
+    }
+
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+        let mut client = client.lock().await;
+
+        let query = r#"
+            SELECT
+                CAST(r.session_id AS BIGINT) AS session_id,
+                s.login_name AS user_name,
+                DB_NAME(r.database_id) AS database_name,
+                s.host_name AS client,
+                r.status AS state,
+                t.text AS query,
+                CAST(r.total_elapsed_time AS BIGINT) AS duration_ms
+            FROM sys.dm_exec_requests r
+            JOIN sys.dm_exec_sessions s ON s.session_id = r.session_id
+            OUTER APPLY sys.dm_exec_sql_text(r.sql_handle) t
+            WHERE s.is_user_process = 1 AND r.session_id <> @@SPID
+            ORDER BY r.total_elapsed_time DESC
+        "#;
+
+        let rows = Self::fetch_rows(&mut client, query, &[], "Failed to get active queries").await?;
+
+        Ok(rows.iter().map(|row| ActiveQuery {
+            session_id: row.get::<i64, _>("session_id").unwrap_or_default().to_string(),
+            user: Self::get_opt_string(row, "user_name"),
+            database: Self::get_opt_string(row, "database_name"),
+            client: Self::get_opt_string(row, "client"),
+            state: Self::get_opt_string(row, "state"),
+            query: Self::get_opt_string(row, "query"),
+            duration_ms: row.get::<i64, _>("duration_ms").map(|v| v.max(0) as u64),
+        }).collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let client = match pool {
+            PoolRef::Mssql(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for MSSQL driver".to_string())),
+        };
+
+        let id: i32 = session_id.parse()
+            .map_err(|_| AppError::ValidationError(format!("Invalid session id: {}", session_id)))?;
+
+        let mut client = client.lock().await;
+        client.execute(format!("KILL {}", id), &[]).await
+            .map_err(|e| AppError::QueryError(format!("Failed to kill session: {}", e)))?;
+        Ok(())
+    }
+
+    async fn get_slow_queries(&self, _pool: PoolRef<'_>, _order: SlowQueryOrder, _limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        Err(AppError::ValidationError("Slow query analysis is not supported for SQL Server".to_string()))
+    }
+}
@@ -1,12 +1,22 @@
-use crate::db::{DatabaseDriver, PoolRef};
+use crate::db::{
+    append_query_params, column_definition, combine_result_sets, insert_statement, placeholder, mysql_tls_params, quote_identifier,
+    quote_qualified, string_literal, definition_mentions, BatchHandler, DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits,
+    RowBatches,
+};
 use crate::error::{AppError, AppResult};
 use crate::models::{
-    ConnectionConfig, ConstraintInfo, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo,
-    QueryResult, TableInfo, TableProperties, TableRelationship, TableSchema,
-    TestConnectionResult, ColumnInfo
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseDdlOptions, DatabaseUser, PrivilegeScope,
+    DatabaseType, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo, NewColumn, QueryResult, ResultSet, TableInfo,
+    TableProperties, TablePrivilege, TableRelationship, TableSchema, TestConnectionResult, TruncateOptions,
+    ColumnInfo, ActiveQuery, ServerStats, DatabaseSize, TableStats, ObjectComment, SchemaComments, SlowQueryEntry,
+    SlowQueryOrder, PartitionInfo, TablePartitioning, ObjectDependency
 };
 use async_trait::async_trait;
-use sqlx::{mysql::MySqlPool, Row, Column};
+use futures_util::{StreamExt, TryStreamExt};
+use sql_parse::Dialect;
+use sqlx::mysql::{MySql, MySqlArguments, MySqlColumn, MySqlConnection, MySqlPool, MySqlRow};
+use sqlx::query::Query;
+use sqlx::{Acquire, Row, Column, Executor, TypeInfo};
 use std::collections::HashMap;
 use std::time::Instant;
 
@@ -32,6 +42,320 @@ fn decode_string_opt(row: &sqlx::mysql::MySqlRow, column: &str) -> Option<String
 
 pub struct MySqlDriver;
 
+/// Convert a MySQL row value at a given index to a JSON value
+fn mysql_value_to_json(row: &sqlx::mysql::MySqlRow, idx: usize) -> serde_json::Value {
+    if let Ok(val) = row.try_get::<String, _>(idx) {
+        serde_json::Value::String(val)
+    } else if let Ok(val) = row.try_get::<Vec<u8>, _>(idx) {
+        // Binary strings that aren't text are marked so the blob viewer and exporters can decode them
+        match String::from_utf8(val) {
+            Ok(text) => serde_json::Value::String(text),
+            Err(e) => {
+                use base64::{Engine as _, engine::general_purpose};
+                serde_json::Value::String(format!("[base64: {}]", general_purpose::STANDARD.encode(e.into_bytes())))
+            }
+        }
+    } else if let Ok(val) = row.try_get::<i64, _>(idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<i32, _>(idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<f64, _>(idx) {
+        serde_json::Value::Number(serde_json::Number::from_f64(val).unwrap_or(0.into()))
+    } else if let Ok(val) = row.try_get::<bool, _>(idx) {
+        serde_json::Value::Bool(val)
+    } else if let Ok(val) = row.try_get::<chrono::NaiveDateTime, _>(idx) {
+        serde_json::Value::String(val.to_string())
+    } else if let Ok(val) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(idx) {
+        serde_json::Value::String(val.to_rfc3339())
+    } else {
+        // Fallback for unsupported types
+        serde_json::Value::String("Unsupported type".to_string())
+    }
+}
+
+/// Build result column metadata from the types MySQL reports
+fn result_columns(columns: &[MySqlColumn], nullable: impl Fn(usize) -> Option<bool>) -> Vec<ColumnInfo> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| ColumnInfo {
+            name: col.name().to_string(),
+            data_type: col.type_info().name().to_lowercase(),
+            nullable: nullable(i).unwrap_or(true),
+            is_primary_key: false,
+            native_type: Some(col.type_info().name().to_string()),
+            type_oid: None,
+        })
+        .collect()
+}
+
+/// Fetch the rows of a query, stopping after `max_rows`
+async fn fetch_rows(conn: &mut MySqlConnection, sql: &str, max_rows: Option<usize>) -> AppResult<Vec<MySqlRow>> {
+    let rows = sqlx::query(sql).fetch(conn);
+    match max_rows {
+        Some(max_rows) => rows.take(max_rows).try_collect().await,
+        None => rows.try_collect().await,
+    }
+    .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))
+}
+
+/// Add a MAX_EXECUTION_TIME optimizer hint to a SELECT, the only statement
+/// MySQL applies it to. Servers that do not know the hint ignore it.
+fn with_execution_time_hint(statement: &str, timeout_ms: u64) -> String {
+    match statement.get(..6) {
+        Some(keyword) if keyword.eq_ignore_ascii_case("SELECT") && statement[6..].starts_with(char::is_whitespace) => {
+            format!("SELECT /*+ MAX_EXECUTION_TIME({}) */{}", timeout_ms, &statement[6..])
+        }
+        _ => statement.to_string(),
+    }
+}
+
+impl MySqlDriver {
+    /// Execute SQL typed by the user within `limits` on a connection the
+    /// caller holds, such as an editor tab's session
+    pub async fn execute_on_connection(
+        &self,
+        conn: &mut MySqlConnection,
+        sql: &str,
+        limits: &QueryLimits,
+    ) -> AppResult<QueryResult> {
+        let mut statements = sql_parse::split_statements(sql, Dialect::MySql);
+        if let Some(timeout_ms) = limits.statement_timeout_ms {
+            for statement in &mut statements {
+                *statement = with_execution_time_hint(statement, timeout_ms);
+            }
+        }
+        execute_statements(conn, &statements, limits.max_rows).await
+    }
+
+    /// `stream_query` on a connection the caller holds
+    pub async fn stream_on_connection(
+        &self,
+        conn: &mut MySqlConnection,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let columns = match (&mut *conn).describe(sql).await {
+            Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+            Err(_) => vec![],
+        };
+
+        let mut batches = RowBatches::new(on_batch, columns, batch_size);
+        let mut rows = sqlx::query(sql).fetch(&mut *conn);
+        while let Some(row) = rows.try_next().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+        {
+            if batches.columns.is_empty() {
+                batches.columns = result_columns(row.columns(), |_| None);
+            }
+            batches.push((0..row.len()).map(|i| mysql_value_to_json(&row, i)).collect())?;
+        }
+        batches.finish()
+    }
+}
+
+/// Run split statements: one on its own, several as a script
+async fn execute_statements(
+    conn: &mut MySqlConnection,
+    statements: &[String],
+    max_rows: Option<usize>,
+) -> AppResult<QueryResult> {
+    let start = Instant::now();
+
+    if statements.len() > 1 {
+        return execute_script(conn, statements, start, max_rows).await;
+    }
+    let sql = statements.first().map_or("", String::as_str);
+
+    let is_select = sql_parse::returns_rows(sql, Dialect::MySql);
+
+    if is_select {
+        let rows = fetch_rows(&mut *conn, sql, max_rows).await?;
+
+        // Describing the statement gives nullability and columns even when no rows come back
+        let columns = match (&mut *conn).describe(sql).await {
+            Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+            Err(_) => rows
+                .first()
+                .map(|row| result_columns(row.columns(), |_| None))
+                .unwrap_or_default(),
+        };
+
+        let json_rows: Vec<Vec<serde_json::Value>> = rows
+            .iter()
+            .map(|row| {
+                (0..columns.len())
+                    .map(|i| mysql_value_to_json(row, i))
+                    .collect()
+            })
+            .collect();
+
+        Ok(QueryResult {
+            columns,
+            rows: json_rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    } else {
+        let result = sqlx::query(sql)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.rows_affected()),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+}
+
+/// Run each statement of a script in one transaction, keeping every result set
+async fn execute_script(
+    conn: &mut MySqlConnection,
+    statements: &[String],
+    start: Instant,
+    max_rows: Option<usize>,
+) -> AppResult<QueryResult> {
+    let mut tx = conn.begin().await
+        .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
+
+    let execution_result: AppResult<Vec<ResultSet>> = async {
+        let mut result_sets = Vec::with_capacity(statements.len());
+
+        for stmt in statements {
+            let stmt_start = Instant::now();
+
+            let is_select = sql_parse::returns_rows(stmt, Dialect::MySql);
+
+            let result_set = if is_select {
+                let rows = fetch_rows(&mut tx, stmt, max_rows).await?;
+
+                let columns = match (&mut *tx).describe(stmt).await {
+                    Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+                    Err(_) => rows
+                        .first()
+                        .map(|row| result_columns(row.columns(), |_| None))
+                        .unwrap_or_default(),
+                };
+
+                let json_rows: Vec<Vec<serde_json::Value>> = rows
+                    .iter()
+                    .map(|row| {
+                        (0..columns.len())
+                            .map(|i| mysql_value_to_json(row, i))
+                            .collect()
+                    })
+                    .collect();
+
+                ResultSet {
+                    statement: stmt.clone(),
+                    columns,
+                    rows: json_rows,
+                    affected_rows: None,
+                    execution_time_ms: stmt_start.elapsed().as_millis() as u64,
+                }
+            } else {
+                let result = sqlx::query(stmt)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+                ResultSet {
+                    statement: stmt.clone(),
+                    columns: vec![],
+                    rows: vec![],
+                    affected_rows: Some(result.rows_affected()),
+                    execution_time_ms: stmt_start.elapsed().as_millis() as u64,
+                }
+            };
+
+            result_sets.push(result_set);
+        }
+        Ok(result_sets)
+    }.await;
+
+    match execution_result {
+        Ok(result_sets) => {
+            tx.commit().await
+                .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+            Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64))
+        }
+        Err(e) => {
+            tx.rollback().await
+                .map_err(|rollback_err| {
+                    AppError::QueryError(format!(
+                        "Query failed: {}. Transaction rollback also failed: {}",
+                        e,
+                        rollback_err
+                    ))
+                })?;
+            Err(e)
+        }
+    }
+}
+
+/// Bind a value using the closest native type; MySQL coerces it to the column type
+fn bind_mysql_param<'q>(query: Query<'q, MySql, MySqlArguments>, value: CellValue) -> Query<'q, MySql, MySqlArguments> {
+    match value {
+        CellValue::Null => query.bind(None::<String>),
+        CellValue::Bool(b) => query.bind(b),
+        CellValue::Int(i) => query.bind(i),
+        CellValue::Float(f) => query.bind(f),
+        CellValue::Bytes(ref encoded) => match value.bytes() {
+            Some(bytes) => query.bind(bytes),
+            None => query.bind(encoded.clone()),
+        },
+        CellValue::Json(json) => query.bind(json.to_string()),
+        CellValue::Text(s) | CellValue::Timestamp(s) => query.bind(s),
+    }
+}
+
+/// A column definition from SHOW CREATE TABLE without its trailing COMMENT clause
+fn without_comment(definition: &str) -> &str {
+    let Some(start) = definition.rfind(" COMMENT '") else {
+        return definition;
+    };
+
+    // Only strip the clause when its literal runs to the end of the definition
+    let mut chars = definition[start + " COMMENT '".len()..].chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+            }
+            '\'' => return if chars.next().is_none() { &definition[..start] } else { definition },
+            _ => {}
+        }
+    }
+    definition
+}
+
+/// Run a single DDL statement, prefixing failures with `context`
+async fn execute_ddl(pool: &MySqlPool, sql: &str, context: &str) -> AppResult<QueryResult> {
+    let start = Instant::now();
+
+    sqlx::query(sql)
+        .execute(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("{}: {}", context, e)))?;
+
+    Ok(QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: Some(0),
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    })
+}
+
 #[async_trait]
 impl DatabaseDriver for MySqlDriver {
     async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
@@ -56,109 +380,152 @@ impl DatabaseDriver for MySqlDriver {
     }
 
     async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        self.execute_query_limited(pool, sql, &QueryLimits::default()).await
+    }
+
+    async fn execute_query_limited(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.execute_on_connection(&mut conn, sql, limits).await
+    }
+
+    async fn execute_read_only(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        conn.execute("START TRANSACTION READ ONLY")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to start a read-only transaction: {}", e)))?;
+        let result = self.execute_on_connection(&mut conn, sql, limits).await;
+        if conn.execute("ROLLBACK").await.is_err() {
+            conn.close_on_drop();
+        }
+        result
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.stream_on_connection(&mut conn, sql, batch_size, on_batch).await
+    }
+
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult> {
         let pool = match pool {
             PoolRef::MySql(p) => p,
             _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
         };
 
         let start = Instant::now();
-        
-        let mut clean_sql = sql.trim();
-        while clean_sql.starts_with("--") || clean_sql.starts_with("/*") {
-            if clean_sql.starts_with("--") {
-                if let Some(newline_pos) = clean_sql.find('\n') {
-                    clean_sql = clean_sql[newline_pos..].trim();
-                } else {
-                    clean_sql = "";
-                    break;
-                }
-            } else if clean_sql.starts_with("/*") {
-                if let Some(end_pos) = clean_sql.find("*/") {
-                    clean_sql = clean_sql[end_pos + 2..].trim();
-                } else {
-                    break;
-                }
-            }
+
+        let describe = pool.describe(sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to prepare query: {}", e)))?;
+
+        let mut query = sqlx::query(sql);
+        for value in params {
+            query = bind_mysql_param(query, value);
         }
 
-        let sql_upper = clean_sql.to_uppercase();
-        let is_select = sql_upper.starts_with("SELECT") || sql_upper.starts_with("WITH") || sql_upper.starts_with("SHOW") || sql_upper.starts_with("DESCRIBE");
-        
-        if is_select {
-            let rows = sqlx::query(sql)
-                .fetch_all(pool)
-                .await
-                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-            
-            if rows.is_empty() {
-                return Ok(QueryResult {
-                    columns: vec![],
-                    rows: vec![],
-                    affected_rows: None,
-                    execution_time_ms: start.elapsed().as_millis() as u64,
-                });
-            }
-            
-            let columns: Vec<ColumnInfo> = rows[0]
-                .columns()
-                .iter()
-                .map(|col| ColumnInfo {
-                    name: col.name().to_string(),
-                    data_type: "unknown".to_string(),
-                    nullable: true,
-                    is_primary_key: false,
-                })
-                .collect();
-            
-            let json_rows: Vec<Vec<serde_json::Value>> = rows
-                .iter()
-                .map(|row| {
-                    (0..columns.len())
-                        .map(|i| {
-                            if let Ok(val) = row.try_get::<String, _>(i) {
-                                serde_json::Value::String(val)
-                            } else if let Ok(val) = row.try_get::<Vec<u8>, _>(i) {
-                                serde_json::Value::String(String::from_utf8_lossy(&val).into_owned())
-                            } else if let Ok(val) = row.try_get::<i64, _>(i) {
-                                serde_json::Value::Number(val.into())
-                            } else if let Ok(val) = row.try_get::<i32, _>(i) {
-                                serde_json::Value::Number(val.into())
-                            } else if let Ok(val) = row.try_get::<f64, _>(i) {
-                                serde_json::Value::Number(serde_json::Number::from_f64(val).unwrap_or(0.into()))
-                            } else if let Ok(val) = row.try_get::<bool, _>(i) {
-                                serde_json::Value::Bool(val)
-                            } else if let Ok(val) = row.try_get::<chrono::NaiveDateTime, _>(i) {
-                                serde_json::Value::String(val.to_string())
-                            } else if let Ok(val) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(i) {
-                                serde_json::Value::String(val.to_rfc3339())
-                            } else {
-                                // Fallback for unsupported types
-                                serde_json::Value::String("Unsupported type".to_string())
-                            }
-                        })
-                        .collect()
-                })
-                .collect();
-            
-            Ok(QueryResult {
-                columns,
-                rows: json_rows,
-                affected_rows: None,
-                execution_time_ms: start.elapsed().as_millis() as u64,
-            })
-        } else {
-            let result = sqlx::query(sql)
+        if describe.columns().is_empty() {
+            let result = query
                 .execute(pool)
                 .await
                 .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-            
-            Ok(QueryResult {
+
+            return Ok(QueryResult {
                 columns: vec![],
                 rows: vec![],
                 affected_rows: Some(result.rows_affected()),
                 execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        let rows = query
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        let columns = result_columns(describe.columns(), |i| describe.nullable(i));
+
+        let json_rows: Vec<Vec<serde_json::Value>> = rows
+            .iter()
+            .map(|row| {
+                (0..columns.len())
+                    .map(|i| mysql_value_to_json(row, i))
+                    .collect()
             })
+            .collect();
+
+        Ok(QueryResult {
+            columns,
+            rows: json_rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::MySQL, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let sql = insert_statement(&DatabaseType::MySQL, table, columns);
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
+        let mut failed = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            // Each row gets a savepoint so one bad row does not abort the transaction
+            let mut savepoint = tx.begin().await
+                .map_err(|e| AppError::QueryError(format!("Failed to create savepoint: {}", e)))?;
+
+            let query = row.into_iter().fold(sqlx::query(&sql), |query, value| bind_mysql_param(query, value.into()));
+            match query.execute(&mut *savepoint).await {
+                Ok(_) => savepoint.commit().await,
+                Err(e) => {
+                    failed.push((index, e.to_string()));
+                    savepoint.rollback().await
+                }
+            }
+            .map_err(|e| AppError::QueryError(format!("Failed to release savepoint: {}", e)))?;
         }
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(failed)
     }
 
     async fn get_tables(&self, pool: PoolRef<'_>, config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
@@ -177,7 +544,8 @@ impl DatabaseDriver for MySqlDriver {
             SELECT 
                 TABLE_NAME as table_name,
                 TABLE_SCHEMA as table_schema,
-                TABLE_TYPE as table_type
+                TABLE_TYPE as table_type,
+                CREATE_OPTIONS as create_options
             FROM information_schema.TABLES
             WHERE {}
             AND TABLE_TYPE = 'BASE TABLE'
@@ -194,12 +562,16 @@ impl DatabaseDriver for MySqlDriver {
             .map(|row| {
                 let schema = decode_string_opt(row, "table_schema");
                 let name = decode_string(row, "table_name");
+                // MySQL partitions are not tables of their own, so only the parent is marked
+                let partitioned = decode_string(row, "create_options").contains("partitioned");
                 
                 TableInfo {
                     name,
                     schema,
-                    table_type: "BASE TABLE".to_string(),
+                    table_type: if partitioned { "PARTITIONED TABLE" } else { "BASE TABLE" }.to_string(),
                     row_count: None,
+                    parent_table: None,
+                    pinned: false,
                 }
             })
             .collect();
@@ -207,6 +579,79 @@ impl DatabaseDriver for MySqlDriver {
         Ok(tables)
     }
 
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT SCHEMA_NAME AS name
+            FROM information_schema.SCHEMATA
+            WHERE SCHEMA_NAME NOT IN ('mysql', 'information_schema', 'performance_schema', 'sys')
+            ORDER BY SCHEMA_NAME
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+
+        Ok(rows.iter().map(|row| decode_string(row, "name")).collect())
+    }
+
+    /// MySQL schemas are databases
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        self.list_databases(pool).await
+    }
+
+    async fn create_database(&self, pool: PoolRef<'_>, options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        if options.owner.is_some() {
+            return Err(AppError::ValidationError("MySQL databases have no owner; grant privileges instead".to_string()));
+        }
+
+        let mut sql = format!("CREATE DATABASE {}", quote_identifier(&DatabaseType::MySQL, &options.name));
+        if let Some(charset) = &options.charset {
+            sql.push_str(&format!(" CHARACTER SET {}", string_literal(&DatabaseType::MySQL, charset)));
+        }
+        if let Some(collation) = &options.collation {
+            sql.push_str(&format!(" COLLATE {}", string_literal(&DatabaseType::MySQL, collation)));
+        }
+
+        execute_ddl(pool, &sql, "Failed to create database").await
+    }
+
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let sql = format!("DROP DATABASE {}", quote_identifier(&DatabaseType::MySQL, name));
+        execute_ddl(pool, &sql, "Failed to drop database").await
+    }
+
+    /// MySQL schemas are databases
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        let options = CreateDatabaseOptions {
+            name: options.name.clone(),
+            owner: options.owner.clone(),
+            charset: None,
+            collation: None,
+        };
+        self.create_database(pool, &options).await
+    }
+
+    /// Dropping a MySQL database always drops its tables
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, _cascade: bool) -> AppResult<QueryResult> {
+        self.drop_database(pool, name).await
+    }
+
     async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
         let pool = match pool {
             PoolRef::MySql(p) => p,
@@ -288,6 +733,8 @@ impl DatabaseDriver for MySqlDriver {
                     data_type: decode_string(row, "data_type"),
                     nullable: decode_string(row, "is_nullable") == "YES",
                     is_primary_key: column_key == "PRI",
+                    native_type: None,
+                    type_oid: None,
                 }
             })
             .collect();
@@ -372,6 +819,8 @@ impl DatabaseDriver for MySqlDriver {
                 data_type: decode_string(&row, "data_type"),
                 nullable: decode_string(&row, "is_nullable") == "YES",
                 is_primary_key: false, // Will be updated below
+                native_type: None,
+                type_oid: None,
             };
 
             table_columns.entry(table_name.clone()).or_default().push(column_info);
@@ -428,8 +877,12 @@ impl DatabaseDriver for MySqlDriver {
         let username = config.username.as_deref().unwrap_or("root");
         let password = config.password.as_deref().unwrap_or("");
 
-        format!("mysql://{}:{}@{}:{}/{}",
-            username, password, host, port, config.database)
+        let mut url = format!("mysql://{}:{}@{}:{}/{}",
+            username, password, host, port, config.database);
+
+        append_query_params(&mut url, &mysql_tls_params(config));
+
+        url
     }
 
     async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
@@ -439,7 +892,7 @@ impl DatabaseDriver for MySqlDriver {
         };
 
         // MySQL has SHOW CREATE TABLE which gives us the exact DDL
-        let query = format!("SHOW CREATE TABLE {}", table_name);
+        let query = format!("SHOW CREATE TABLE {}", quote_qualified(&DatabaseType::MySQL, table_name));
         let row = sqlx::query(&query)
             .fetch_one(pool)
             .await
@@ -453,6 +906,164 @@ impl DatabaseDriver for MySqlDriver {
         Ok(ddl)
     }
 
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let foreign_keys = sqlx::query(
+            "SELECT TABLE_NAME AS object, REFERENCED_TABLE_NAME AS depends_on, CONSTRAINT_NAME AS constraint_name \
+             FROM information_schema.REFERENTIAL_CONSTRAINTS \
+             WHERE CONSTRAINT_SCHEMA = DATABASE() AND UNIQUE_CONSTRAINT_SCHEMA = DATABASE() \
+             ORDER BY TABLE_NAME, CONSTRAINT_NAME",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get foreign keys: {}", e)))?;
+
+        let mut dependencies: Vec<ObjectDependency> = foreign_keys.iter().map(|row| ObjectDependency {
+            object: decode_string(row, "object"),
+            object_type: "TABLE".to_string(),
+            depends_on: decode_string(row, "depends_on"),
+            constraint_name: decode_string_opt(row, "constraint_name"),
+        }).collect();
+
+        // VIEW_TABLE_USAGE is missing before MySQL 8.0.13 and on MariaDB, so view queries are searched instead
+        let objects = sqlx::query(
+            "SELECT t.TABLE_NAME AS name, v.VIEW_DEFINITION AS definition \
+             FROM information_schema.TABLES t \
+             LEFT JOIN information_schema.VIEWS v ON v.TABLE_SCHEMA = t.TABLE_SCHEMA AND v.TABLE_NAME = t.TABLE_NAME \
+             WHERE t.TABLE_SCHEMA = DATABASE() ORDER BY t.TABLE_NAME",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get view definitions: {}", e)))?;
+        let names: Vec<String> = objects.iter().map(|row| decode_string(row, "name")).collect();
+        for (row, view) in objects.iter().zip(&names) {
+            let Some(definition) = decode_string_opt(row, "definition") else {
+                continue;
+            };
+            for name in names.iter().filter(|name| *name != view && definition_mentions(&definition, name)) {
+                dependencies.push(ObjectDependency {
+                    object: view.clone(),
+                    object_type: "VIEW".to_string(),
+                    depends_on: name.clone(),
+                    constraint_name: None,
+                });
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+
+        let database: String = sqlx::query("SELECT DATABASE() AS db")
+            .fetch_one(&mut *conn)
+            .await
+            .map(|row| decode_string(&row, "db"))
+            .map_err(|e| AppError::QueryError(format!("Failed to get current database: {}", e)))?;
+        let database_prefix = format!("{}.", quote_identifier(&DatabaseType::MySQL, &database));
+        let object_name = |name: &str| {
+            let name = quote_identifier(&DatabaseType::MySQL, name);
+            if options.qualify_names { format!("{}{}", database_prefix, name) } else { name }
+        };
+        let fetch_error = |what: &str, e: sqlx::Error| AppError::QueryError(format!("Failed to list {}: {}", what, e));
+        let mut objects = Vec::new();
+
+        // SHOW CREATE TABLE includes indexes and foreign keys; the script turns off
+        // foreign key checks, so tables need no particular order
+        let tables = sqlx::query(
+            "SELECT TABLE_NAME AS name FROM information_schema.TABLES \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_TYPE = 'BASE TABLE' ORDER BY TABLE_NAME",
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| fetch_error("tables", e))?;
+        for row in &tables {
+            let name = decode_string(row, "name");
+            let quoted = quote_identifier(&DatabaseType::MySQL, &name);
+            let create_row = sqlx::query(&format!("SHOW CREATE TABLE {}", quoted))
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to get DDL of {}: {}", name, e)))?;
+            let create = decode_string(&create_row, "Create Table")
+                .replacen(&format!("CREATE TABLE {}", quoted), &format!("CREATE TABLE {}", object_name(&name)), 1);
+            objects.push(
+                DdlObject::new(DdlKind::Table, name.clone(), format!("{};", create))
+                    .with_drop(format!("DROP TABLE IF EXISTS {};", object_name(&name))),
+            );
+        }
+
+        // MySQL stores view queries with every name qualified by the database
+        let views = sqlx::query(
+            "SELECT TABLE_NAME AS name, VIEW_DEFINITION AS definition, SECURITY_TYPE AS security \
+             FROM information_schema.VIEWS WHERE TABLE_SCHEMA = DATABASE() ORDER BY TABLE_NAME",
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| fetch_error("views", e))?;
+        let view_names: Vec<String> = views.iter().map(|row| decode_string(row, "name")).collect();
+        for (row, name) in views.iter().zip(&view_names) {
+            let mut definition = decode_string(row, "definition");
+            if !options.qualify_names {
+                definition = definition.replace(&database_prefix, "");
+            }
+            let depends_on = view_names.iter()
+                .filter(|other| *other != name && definition.contains(&quote_identifier(&DatabaseType::MySQL, other)))
+                .cloned()
+                .collect();
+            let create = format!(
+                "CREATE OR REPLACE SQL SECURITY {} VIEW {} AS {};",
+                decode_string(row, "security"),
+                object_name(name),
+                definition
+            );
+            objects.push(
+                DdlObject::new(DdlKind::View, name.clone(), create)
+                    .with_drop(format!("DROP VIEW IF EXISTS {};", object_name(name)))
+                    .with_dependencies(depends_on),
+            );
+        }
+
+        // Trigger bodies may hold several statements, so each gets its own delimiter
+        let triggers = sqlx::query(
+            "SELECT TRIGGER_NAME AS name, ACTION_TIMING AS timing, EVENT_MANIPULATION AS event, \
+             EVENT_OBJECT_TABLE AS table_name, ACTION_STATEMENT AS body \
+             FROM information_schema.TRIGGERS WHERE TRIGGER_SCHEMA = DATABASE() \
+             ORDER BY EVENT_OBJECT_TABLE, ACTION_ORDER",
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| fetch_error("triggers", e))?;
+        for row in &triggers {
+            let name = decode_string(row, "name");
+            let create = format!(
+                "DELIMITER ;;\nCREATE TRIGGER {} {} {} ON {} FOR EACH ROW {};;\nDELIMITER ;",
+                object_name(&name),
+                decode_string(row, "timing"),
+                decode_string(row, "event"),
+                object_name(&decode_string(row, "table_name")),
+                decode_string(row, "body")
+            );
+            objects.push(DdlObject::new(DdlKind::Trigger, name, create));
+        }
+
+        Ok(objects)
+    }
+
     async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
         let pool = match pool {
             PoolRef::MySql(p) => p,
@@ -461,7 +1072,11 @@ impl DatabaseDriver for MySqlDriver {
 
         let start = Instant::now();
 
-        let sql = format!("RENAME TABLE {} TO {}", old_name, new_name);
+        let sql = format!(
+            "RENAME TABLE {} TO {}",
+            quote_qualified(&DatabaseType::MySQL, old_name),
+            quote_qualified(&DatabaseType::MySQL, new_name)
+        );
 
         sqlx::query(&sql)
             .execute(pool)
@@ -473,6 +1088,128 @@ impl DatabaseDriver for MySqlDriver {
             rows: vec![],
             affected_rows: Some(0),
             execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let sql = format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            quote_qualified(&DatabaseType::MySQL, table_name),
+            column_definition(&DatabaseType::MySQL, column)
+        );
+        execute_ddl(pool, &sql, "Failed to add column").await
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let sql = format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            quote_qualified(&DatabaseType::MySQL, table_name),
+            quote_identifier(&DatabaseType::MySQL, column_name)
+        );
+        execute_ddl(pool, &sql, "Failed to drop column").await
+    }
+
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        // RENAME COLUMN needs MySQL 8.0 / MariaDB 10.5
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            quote_qualified(&DatabaseType::MySQL, table_name),
+            quote_identifier(&DatabaseType::MySQL, old_name),
+            quote_identifier(&DatabaseType::MySQL, new_name)
+        );
+        execute_ddl(pool, &sql, "Failed to rename column").await
+    }
+
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        // MODIFY replaces the whole definition, so the column's default and comment are reset
+        let sql = format!(
+            "ALTER TABLE {} MODIFY COLUMN {} {} {}",
+            quote_qualified(&DatabaseType::MySQL, table_name),
+            quote_identifier(&DatabaseType::MySQL, column_name),
+            data_type,
+            if nullable { "NULL" } else { "NOT NULL" }
+        );
+        execute_ddl(pool, &sql, "Failed to change column type").await
+    }
+
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        // TRUNCATE always resets AUTO_INCREMENT and is refused for tables referenced by foreign keys
+        let sql = format!("TRUNCATE TABLE {}", quote_qualified(&DatabaseType::MySQL, table_name));
+        execute_ddl(pool, &sql, "Failed to truncate table").await
+    }
+
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let start = Instant::now();
+        let source = quote_qualified(&DatabaseType::MySQL, table_name);
+        let target = quote_qualified(&DatabaseType::MySQL, new_name);
+
+        // LIKE copies columns and indexes, but not foreign keys
+        sqlx::query(&format!("CREATE TABLE {} LIKE {}", target, source))
+            .execute(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to duplicate table: {}", e)))?;
+
+        let mut affected_rows = 0;
+        if include_data {
+            // Generated columns are computed again in the copy
+            let columns = self.get_table_properties(PoolRef::MySql(pool), table_name).await?.columns;
+            let columns = columns
+                .iter()
+                .filter(|c| c.is_insertable())
+                .map(|c| quote_identifier(&DatabaseType::MySQL, &c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let result = sqlx::query(&format!("INSERT INTO {} ({}) SELECT {} FROM {}", target, columns, columns, source))
+                .execute(pool)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to copy rows: {}", e)))?;
+            affected_rows = result.rows_affected();
+        }
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(affected_rows),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
         })
     }
 
@@ -562,7 +1299,10 @@ impl DatabaseDriver for MySqlDriver {
                 IS_NULLABLE as is_nullable,
                 COLUMN_DEFAULT as column_default,
                 COLUMN_KEY as column_key,
-                COLUMN_COMMENT as comment
+                COLUMN_COMMENT as comment,
+                EXTRA as extra,
+                GENERATION_EXPRESSION as generation_expression,
+                COLLATION_NAME as collation
             FROM information_schema.COLUMNS
             WHERE TABLE_SCHEMA = DATABASE()
             AND TABLE_NAME = ?
@@ -627,13 +1367,21 @@ impl DatabaseDriver for MySqlDriver {
         // Get constraints
         let constraints = self.get_constraints(PoolRef::MySql(pool), table_name).await?;
 
-        // Get row count
-        let count_query = format!("SELECT COUNT(*) as count FROM {}", table_name);
-        let row_count: Option<i64> = sqlx::query_scalar(&count_query)
+        // Estimate the row count; TABLE_ROWS is approximate for InnoDB and NULL for views
+        let estimate_query = r#"
+            SELECT CAST(TABLE_ROWS AS SIGNED)
+            FROM information_schema.TABLES
+            WHERE TABLE_SCHEMA = DATABASE()
+            AND TABLE_NAME = ?
+        "#;
+        let estimate: Option<i64> = sqlx::query_scalar(estimate_query)
+            .bind(table_name)
             .fetch_optional(pool)
             .await
             .ok()
             .flatten();
+        let (row_count, row_count_exact) =
+            self.row_count_from_estimate(PoolRef::MySql(pool), table_name, estimate).await;
 
         // Get table comment
         let comment_query = r#"
@@ -654,6 +1402,7 @@ impl DatabaseDriver for MySqlDriver {
         let columns: Vec<ExtendedColumnInfo> = columns_rows.iter().map(|row| {
             let col_name = decode_string(row, "column_name");
             let column_key = decode_string(row, "column_key");
+            let extra = decode_string(row, "extra").to_lowercase();
             ExtendedColumnInfo {
                 name: col_name,
                 data_type: decode_string(row, "data_type"),
@@ -661,6 +1410,10 @@ impl DatabaseDriver for MySqlDriver {
                 is_primary_key: column_key == "PRI",
                 default_value: decode_string_opt(row, "column_default"),
                 comment: decode_string_opt(row, "comment"),
+                is_identity: extra.contains("auto_increment"),
+                identity_always: false,
+                generation_expression: decode_string_opt(row, "generation_expression").filter(|e| !e.is_empty()),
+                collation: decode_string_opt(row, "collation"),
             }
         }).collect();
 
@@ -673,10 +1426,205 @@ impl DatabaseDriver for MySqlDriver {
             indexes,
             constraints,
             row_count,
+            row_count_exact,
             table_comment,
         })
     }
 
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let sql = format!("SELECT COUNT(*) FROM {}", quote_qualified(&DatabaseType::MySQL, table_name));
+        sqlx::query_scalar(&sql)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))
+    }
+
+    /// Figures come from information_schema.TABLES and are estimates for InnoDB
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                CAST(DATA_LENGTH AS SIGNED) as table_size,
+                CAST(INDEX_LENGTH AS SIGNED) as index_size,
+                CAST(TABLE_ROWS AS SIGNED) as approximate_rows,
+                CAST(DATA_FREE AS SIGNED) as free_bytes,
+                CAST(UPDATE_TIME AS CHAR) as last_update
+            FROM information_schema.TABLES
+            WHERE TABLE_SCHEMA = DATABASE()
+            AND TABLE_NAME = ?
+        "#;
+
+        let row = sqlx::query(query)
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?
+            .ok_or_else(|| AppError::QueryError(format!("Table {} not found", table_name)))?;
+
+        let table_size = row.try_get::<i64, _>("table_size").ok().map(|v| v as u64);
+        let index_size = row.try_get::<i64, _>("index_size").ok().map(|v| v as u64);
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: table_size,
+            index_size_bytes: index_size,
+            total_size_bytes: table_size.zip(index_size).map(|(t, i)| t + i),
+            approximate_rows: row.try_get::<i64, _>("approximate_rows").ok().map(|v| v as u64),
+            dead_rows: None,
+            dead_row_ratio: None,
+            free_bytes: row.try_get::<i64, _>("free_bytes").ok().map(|v| v as u64),
+            last_vacuum: None,
+            last_analyze: None,
+        })
+    }
+
+    async fn get_partitions(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Option<TablePartitioning>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        // Subpartitions are rows of their own, so they are folded into their partition
+        let query = r#"
+            SELECT
+                CAST(PARTITION_NAME AS CHAR) as name,
+                CAST(MAX(PARTITION_METHOD) AS CHAR) as method,
+                CAST(MAX(PARTITION_EXPRESSION) AS CHAR) as expression,
+                CAST(MAX(PARTITION_DESCRIPTION) AS CHAR) as description,
+                CAST(MAX(SUBPARTITION_METHOD) AS CHAR) as sub_method,
+                CAST(MAX(SUBPARTITION_EXPRESSION) AS CHAR) as sub_expression,
+                CAST(SUM(DATA_LENGTH + INDEX_LENGTH) AS SIGNED) as size_bytes,
+                CAST(SUM(TABLE_ROWS) AS SIGNED) as approximate_rows
+            FROM information_schema.PARTITIONS
+            WHERE TABLE_SCHEMA = DATABASE()
+            AND TABLE_NAME = ?
+            AND PARTITION_NAME IS NOT NULL
+            GROUP BY PARTITION_NAME, PARTITION_ORDINAL_POSITION
+            ORDER BY PARTITION_ORDINAL_POSITION
+        "#;
+
+        let rows = sqlx::query(query)
+            .bind(table_name)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list partitions: {}", e)))?;
+        let Some(first) = rows.first() else {
+            return Ok(None);
+        };
+        let strategy = decode_string(first, "method");
+
+        let partitions = rows
+            .iter()
+            .map(|row| {
+                let description = decode_string(row, "description");
+                let bound = if strategy.starts_with("RANGE") {
+                    match description.as_str() {
+                        "MAXVALUE" => "VALUES LESS THAN MAXVALUE".to_string(),
+                        _ => format!("VALUES LESS THAN ({})", description),
+                    }
+                } else if strategy.starts_with("LIST") {
+                    format!("VALUES IN ({})", description)
+                } else {
+                    String::new()
+                };
+                PartitionInfo {
+                    name: decode_string(row, "name"),
+                    bound,
+                    size_bytes: row.try_get::<i64, _>("size_bytes").ok().map(|v| v as u64),
+                    approximate_rows: row.try_get::<i64, _>("approximate_rows").ok().map(|v| v as u64),
+                    sub_partitioning: decode_string_opt(row, "sub_method")
+                        .map(|method| format!("{} ({})", method, decode_string(row, "sub_expression"))),
+                }
+            })
+            .collect();
+
+        Ok(Some(TablePartitioning {
+            table_name: table_name.to_string(),
+            key: decode_string(first, "expression"),
+            strategy,
+            partitions,
+        }))
+    }
+
+    async fn get_comments(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectComment>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT CAST(TABLE_NAME AS CHAR) AS table_name, CAST(NULL AS CHAR) AS column_name,
+                   CAST(TABLE_COMMENT AS CHAR) AS comment
+            FROM information_schema.TABLES
+            WHERE TABLE_SCHEMA = DATABASE() AND TABLE_TYPE = 'BASE TABLE' AND TABLE_COMMENT <> ''
+            UNION ALL
+            SELECT CAST(TABLE_NAME AS CHAR), CAST(COLUMN_NAME AS CHAR), CAST(COLUMN_COMMENT AS CHAR)
+            FROM information_schema.COLUMNS
+            WHERE TABLE_SCHEMA = DATABASE() AND COLUMN_COMMENT <> ''
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get comments: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ObjectComment {
+                table: row.get("table_name"),
+                column: row.get("column_name"),
+                comment: row.get("comment"),
+            })
+            .collect())
+    }
+
+    async fn comment_statements(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        comments: &SchemaComments,
+    ) -> AppResult<Vec<String>> {
+        let table = quote_qualified(&DatabaseType::MySQL, table_name);
+        let mut clauses = Vec::with_capacity(comments.columns.len() + 1);
+
+        // MODIFY COLUMN replaces the whole definition, so each column keeps the one SHOW CREATE TABLE gives
+        if !comments.columns.is_empty() {
+            let ddl = self.generate_table_ddl(pool, &table).await?;
+            for column in &comments.columns {
+                let prefix = format!("{} ", quote_identifier(&DatabaseType::MySQL, &column.column));
+                let definition = ddl
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| line.starts_with(&prefix))
+                    .ok_or_else(|| {
+                        AppError::QueryError(format!("Column '{}' not found in {}", column.column, table_name))
+                    })?;
+                clauses.push(format!(
+                    "MODIFY COLUMN {} COMMENT {}",
+                    without_comment(definition.trim_end_matches(',')),
+                    string_literal(&DatabaseType::MySQL, &column.comment)
+                ));
+            }
+        }
+        if let Some(comment) = &comments.table_comment {
+            clauses.push(format!("COMMENT = {}", string_literal(&DatabaseType::MySQL, comment)));
+        }
+
+        if clauses.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![format!("ALTER TABLE {} {}", table, clauses.join(", "))])
+    }
+
     async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
         let pool = match pool {
             PoolRef::MySql(p) => p,
@@ -736,5 +1684,263 @@ impl DatabaseDriver for MySqlDriver {
 
         Ok(relationships)
     }
+
+    /// Reads mysql.user, which needs SELECT on the mysql schema
+    async fn get_users_and_roles(&self, pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                User as name,
+                Host as host,
+                account_locked = 'N' as can_login,
+                Super_priv = 'Y' as is_superuser
+            FROM mysql.user
+            ORDER BY User, Host
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get users and roles: {}", e)))?;
+
+        // Role grants (MySQL 8); older servers have no role_edges table
+        let role_query = r#"
+            SELECT TO_USER as user, TO_HOST as host, FROM_USER as role
+            FROM mysql.role_edges
+            ORDER BY FROM_USER
+        "#;
+        let mut roles: HashMap<(String, String), Vec<String>> = HashMap::new();
+        if let Ok(edges) = sqlx::query(role_query).fetch_all(pool).await {
+            for edge in &edges {
+                roles
+                    .entry((decode_string(edge, "user"), decode_string(edge, "host")))
+                    .or_default()
+                    .push(decode_string(edge, "role"));
+            }
+        }
+
+        Ok(rows.iter().map(|row| {
+            let name = decode_string(row, "name");
+            let host = decode_string(row, "host");
+            DatabaseUser {
+                member_of: roles.remove(&(name.clone(), host.clone())).unwrap_or_default(),
+                name,
+                host: Some(host),
+                can_login: row.get("can_login"),
+                is_superuser: row.get("is_superuser"),
+            }
+        }).collect())
+    }
+
+    /// Includes database-wide and global grants that cover the table
+    async fn get_table_privileges(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let table_privileges = "('SELECT', 'INSERT', 'UPDATE', 'DELETE', 'CREATE', 'DROP', 'REFERENCES', \
+                                'INDEX', 'ALTER', 'CREATE VIEW', 'SHOW VIEW', 'TRIGGER')";
+        let query = format!(r#"
+            SELECT GRANTEE as grantee, PRIVILEGE_TYPE as privilege_type, IS_GRANTABLE as is_grantable, 'table' as scope
+            FROM information_schema.TABLE_PRIVILEGES
+            WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ?
+            UNION ALL
+            SELECT GRANTEE, PRIVILEGE_TYPE, IS_GRANTABLE, 'database'
+            FROM information_schema.SCHEMA_PRIVILEGES
+            WHERE TABLE_SCHEMA = DATABASE() AND PRIVILEGE_TYPE IN {privileges}
+            UNION ALL
+            SELECT GRANTEE, PRIVILEGE_TYPE, IS_GRANTABLE, 'server'
+            FROM information_schema.USER_PRIVILEGES
+            WHERE PRIVILEGE_TYPE IN {privileges}
+            ORDER BY 1, 2
+        "#, privileges = table_privileges);
+
+        let rows = sqlx::query(&query)
+            .bind(table_name)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table privileges: {}", e)))?;
+
+        Ok(rows.iter().map(|row| TablePrivilege {
+            grantee: decode_string(row, "grantee"),
+            privilege_type: decode_string(row, "privilege_type"),
+            is_grantable: decode_string(row, "is_grantable") == "YES",
+            grantor: None,
+            scope: match decode_string(row, "scope").as_str() {
+                "server" => PrivilegeScope::Server,
+                "database" => PrivilegeScope::Database,
+                _ => PrivilegeScope::Table,
+            },
+        }).collect())
+    }
+
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let query = r#"
+            SHOW GLOBAL STATUS WHERE Variable_name IN
+                ('Threads_connected', 'Uptime', 'Innodb_buffer_pool_read_requests', 'Innodb_buffer_pool_reads')
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+
+        let status: HashMap<String, u64> = rows
+            .iter()
+            .filter_map(|row| {
+                let value = decode_string(row, "Value").parse().ok()?;
+                Some((decode_string(row, "Variable_name"), value))
+            })
+            .collect();
+
+        let max_connections: Option<i64> = sqlx::query_scalar("SELECT CAST(@@max_connections AS SIGNED)")
+            .fetch_one(pool)
+            .await
+            .ok();
+
+        // Reads that had to go to disk, out of all buffer pool reads
+        let cache_hit_ratio = match (
+            status.get("Innodb_buffer_pool_reads"),
+            status.get("Innodb_buffer_pool_read_requests"),
+        ) {
+            (Some(&disk), Some(&requests)) if requests > 0 => Some(1.0 - disk as f64 / requests as f64),
+            _ => None,
+        };
+
+        let sizes_query = r#"
+            SELECT
+                TABLE_SCHEMA as name,
+                CAST(COALESCE(SUM(DATA_LENGTH + INDEX_LENGTH), 0) AS SIGNED) as size_bytes
+            FROM information_schema.TABLES
+            GROUP BY TABLE_SCHEMA
+            ORDER BY size_bytes DESC
+        "#;
+
+        let sizes = sqlx::query(sizes_query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get database sizes: {}", e)))?;
+
+        Ok(ServerStats {
+            active_connections: status.get("Threads_connected").copied(),
+            max_connections: max_connections.map(|v| v as u64),
+            uptime_seconds: status.get("Uptime").copied(),
+            cache_hit_ratio,
+            database_sizes: sizes.iter().map(|row| DatabaseSize {
+                name: decode_string(row, "name"),
+                size_bytes: row.get::<i64, _>("size_bytes") as u64,
+            }).collect(),
+        })
+    }
+
+    /// Same rows as SHOW PROCESSLIST, without idle connections
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                CAST(ID AS SIGNED) as id,
+                USER as user_name,
+                DB as database_name,
+                HOST as client,
+                COALESCE(NULLIF(STATE, ''), COMMAND) as state,
+                INFO as query,
+                CAST(TIME AS SIGNED) * 1000 as duration_ms
+            FROM information_schema.PROCESSLIST
+            WHERE COMMAND NOT IN ('Sleep', 'Daemon', 'Binlog Dump')
+            AND ID <> CONNECTION_ID()
+            ORDER BY TIME DESC
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        Ok(rows.iter().map(|row| ActiveQuery {
+            session_id: row.get::<i64, _>("id").to_string(),
+            user: decode_string_opt(row, "user_name"),
+            database: decode_string_opt(row, "database_name"),
+            client: decode_string_opt(row, "client"),
+            state: decode_string_opt(row, "state"),
+            query: decode_string_opt(row, "query"),
+            duration_ms: row.try_get::<i64, _>("duration_ms").ok().map(|v| v.max(0) as u64),
+        }).collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let id: u64 = session_id.parse()
+            .map_err(|_| AppError::ValidationError(format!("Invalid session id: {}", session_id)))?;
+
+        execute_ddl(pool, &format!("KILL {}", id), "Failed to kill session").await?;
+        Ok(())
+    }
+
+    /// Reads the statement digest summary in performance_schema, which is the
+    /// aggregated form of what the slow query log records
+    async fn get_slow_queries(&self, pool: PoolRef<'_>, order: SlowQueryOrder, limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        let pool = match pool {
+            PoolRef::MySql(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for MySQL driver".to_string())),
+        };
+
+        let order_column = match order {
+            SlowQueryOrder::TotalTime => "SUM_TIMER_WAIT",
+            SlowQueryOrder::MeanTime => "AVG_TIMER_WAIT",
+            SlowQueryOrder::Calls => "COUNT_STAR",
+        };
+
+        // Timers are in picoseconds
+        let query = format!(r#"
+            SELECT
+                DIGEST_TEXT as query,
+                CAST(COUNT_STAR AS SIGNED) as calls,
+                SUM_TIMER_WAIT / 1e9 as total_time,
+                AVG_TIMER_WAIT / 1e9 as mean_time,
+                MAX_TIMER_WAIT / 1e9 as max_time,
+                CAST(SUM_ROWS_SENT + SUM_ROWS_AFFECTED AS SIGNED) as row_count,
+                SCHEMA_NAME as database_name
+            FROM performance_schema.events_statements_summary_by_digest
+            WHERE DIGEST_TEXT IS NOT NULL
+            ORDER BY {} DESC
+            LIMIT ?
+        "#, order_column);
+
+        let rows = sqlx::query(&query)
+            .bind(limit)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!(
+                "Failed to get slow queries (performance_schema must be enabled): {}", e
+            )))?;
+
+        Ok(rows.iter().map(|row| SlowQueryEntry {
+            query: decode_string(row, "query"),
+            calls: row.get::<i64, _>("calls") as u64,
+            total_time_ms: row.try_get("total_time").unwrap_or_default(),
+            mean_time_ms: row.try_get("mean_time").unwrap_or_default(),
+            max_time_ms: row.try_get("max_time").ok(),
+            rows: row.try_get::<i64, _>("row_count").ok().map(|v| v as u64),
+            database: decode_string_opt(row, "database_name"),
+        }).collect())
+    }
 }
 
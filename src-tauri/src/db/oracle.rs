@@ -0,0 +1,1683 @@
+//! Oracle driver, built on ODPI-C through the `oracle` crate. The Oracle
+//! Instant Client libraries must be installed where the app can load them;
+//! without them connecting fails with the client's own message. Catalog
+//! queries read the ALL_* views of the current schema and need Oracle 12.2 or
+//! later. Builds without the `oracle` feature leave the driver out, and
+//! connecting fails.
+
+use crate::db::{
+    column_definition, combine_result_sets, insert_statement, placeholder, quote_identifier, quote_qualified,
+    string_literal, BatchHandler, DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits,
+};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ActiveQuery, CellValue, ColumnInfo, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions,
+    DatabaseDdlOptions, DatabaseSize, DatabaseType, DatabaseUser, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo,
+    NewColumn, ObjectComment, ObjectDependency, PrivilegeScope, QueryResult, ResultSet, SchemaComments, ServerStats,
+    SlowQueryEntry, SlowQueryOrder, TableInfo, TablePrivilege, TableProperties, TableRelationship, TableSchema,
+    TableStats, TestConnectionResult, TruncateOptions,
+};
+use async_trait::async_trait;
+use sql_parse::Dialect;
+use std::collections::HashMap;
+use std::time::Instant;
+
+pub use engine::OracleClient;
+
+pub struct OracleDriver;
+
+/// The schema catalog queries look in when a name has none
+const CURRENT_SCHEMA: &str = "SYS_CONTEXT('USERENV', 'CURRENT_SCHEMA')";
+
+#[cfg(feature = "oracle")]
+mod engine {
+    use crate::db::{BatchHandler, QueryLimits, RowBatches};
+    use crate::error::{AppError, AppResult};
+    use crate::models::{CellValue, ColumnInfo, ResultSet};
+    use base64::{engine::general_purpose, Engine as _};
+    use oracle::sql_type::{OracleType, ToSql};
+    use oracle::{Connection, Connector, Row, SqlValue};
+    use serde_json::Value as Json;
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use std::time::{Duration, Instant};
+    use tokio::sync::mpsc;
+
+    /// Dates, timestamps and numbers convert to and from text the way the
+    /// other drivers write them
+    const SESSION_SETTINGS: &str = "ALTER SESSION SET \
+        NLS_DATE_FORMAT = 'YYYY-MM-DD HH24:MI:SS' \
+        NLS_TIMESTAMP_FORMAT = 'YYYY-MM-DD HH24:MI:SS.FF' \
+        NLS_TIMESTAMP_TZ_FORMAT = 'YYYY-MM-DD HH24:MI:SS.FF TZH:TZM' \
+        NLS_NUMERIC_CHARACTERS = '.,'";
+
+    /// A single Oracle connection. Statements commit as they run and use the
+    /// blocking thread pool, one at a time.
+    pub struct OracleClient {
+        connection: Arc<Mutex<Connection>>,
+    }
+
+    impl OracleClient {
+        /// Connect to the database at `connect_string`
+        pub async fn connect(username: String, password: String, connect_string: String) -> AppResult<Self> {
+            blocking(move || {
+                let mut connection = Connector::new(username, password, connect_string)
+                    .connect()
+                    .map_err(connect_error)?;
+                connection.set_autocommit(true);
+                connection.execute(SESSION_SETTINGS, &[]).map_err(connect_error)?;
+                Ok(Self {
+                    connection: Arc::new(Mutex::new(connection)),
+                })
+            })
+            .await
+        }
+
+        /// The server's version banner
+        pub async fn version(&self) -> AppResult<String> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let (_, banner) = lock(&connection).server_version().map_err(query_error)?;
+                Ok(banner.lines().next().unwrap_or_default().trim().to_string())
+            })
+            .await
+        }
+
+        /// Run one statement with `params` within `limits`
+        pub async fn execute(&self, sql: String, params: Vec<CellValue>, limits: QueryLimits) -> AppResult<ResultSet> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let connection = lock(&connection);
+                with_timeout(&connection, &limits, || {
+                    run_statement(&connection, &sql, params, limits.max_rows)
+                })
+            })
+            .await
+        }
+
+        /// Run statements in one transaction, keeping every result set. DDL
+        /// commits whatever ran before it, as it always does in Oracle.
+        pub async fn execute_script(&self, statements: Vec<String>, limits: QueryLimits) -> AppResult<Vec<ResultSet>> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let mut connection = lock(&connection);
+                connection.set_autocommit(false);
+                let result = with_timeout(&connection, &limits, || {
+                    statements
+                        .iter()
+                        .map(|sql| run_statement(&connection, sql, vec![], limits.max_rows))
+                        .collect::<AppResult<Vec<_>>>()
+                });
+                let result = match result {
+                    Ok(result_sets) => connection.commit().map(|_| result_sets).map_err(query_error),
+                    Err(e) => {
+                        let _ = connection.rollback();
+                        Err(e)
+                    }
+                };
+                connection.set_autocommit(true);
+                result
+            })
+            .await
+        }
+
+        /// Run one statement in a read-only transaction that is rolled back afterwards
+        pub async fn execute_read_only(&self, sql: String, limits: QueryLimits) -> AppResult<ResultSet> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let mut connection = lock(&connection);
+                connection.set_autocommit(false);
+                let result = connection
+                    .execute("SET TRANSACTION READ ONLY", &[])
+                    .map_err(query_error)
+                    .and_then(|_| {
+                        with_timeout(&connection, &limits, || {
+                            run_statement(&connection, &sql, vec![], limits.max_rows)
+                        })
+                    });
+                let _ = connection.rollback();
+                connection.set_autocommit(true);
+                result
+            })
+            .await
+        }
+
+        /// Run the INSERT `sql` for each row in one transaction. A failed
+        /// statement leaves no trace in Oracle, so the other rows still commit.
+        pub async fn insert_rows(
+            &self,
+            sql: String,
+            rows: Vec<Vec<serde_json::Value>>,
+        ) -> AppResult<Vec<(usize, String)>> {
+            let connection = self.connection.clone();
+            blocking(move || {
+                let mut connection = lock(&connection);
+                connection.set_autocommit(false);
+                let failed = connection
+                    .statement(&sql)
+                    .build()
+                    .map_err(query_error)
+                    .map(|mut statement| {
+                        rows.into_iter()
+                            .enumerate()
+                            .filter_map(|(index, row)| {
+                                let params: Vec<Box<dyn ToSql>> =
+                                    row.into_iter().map(|value| bind_value(value.into())).collect();
+                                let params: Vec<&dyn ToSql> = params.iter().map(|param| param.as_ref()).collect();
+                                statement.execute(&params).err().map(|e| (index, e.to_string()))
+                            })
+                            .collect::<Vec<_>>()
+                    });
+                let committed = match failed {
+                    Ok(failed) => connection.commit().map(|_| failed).map_err(query_error),
+                    Err(e) => Err(e),
+                };
+                connection.set_autocommit(true);
+                committed
+            })
+            .await
+        }
+
+        /// Read the rows of the query `sql`, handing them to `on_batch`
+        /// `batch_size` at a time, and return how many there were
+        pub async fn stream(
+            &self,
+            sql: String,
+            batch_size: usize,
+            on_batch: &mut BatchHandler<'_>,
+        ) -> AppResult<usize> {
+            let connection = self.connection.clone();
+            let (sender, mut receiver) = mpsc::channel(2);
+            let reader = tokio::task::spawn_blocking(move || {
+                if let Err(e) = send_rows(&lock(&connection), &sql, batch_size, &sender) {
+                    let _ = sender.blocking_send(Err(e));
+                }
+            });
+
+            // The first message holds the columns
+            let Some(first) = receiver.recv().await else {
+                reader.await.map_err(join_error)?;
+                return Err(AppError::QueryError("Oracle returned no result".to_string()));
+            };
+            let mut batches = RowBatches::new(on_batch, first?.0, batch_size);
+            while let Some(message) = receiver.recv().await {
+                for row in message?.1 {
+                    batches.push(row)?;
+                }
+            }
+            reader.await.map_err(join_error)?;
+            batches.finish()
+        }
+    }
+
+    /// Run blocking work on the blocking thread pool
+    async fn blocking<T: Send + 'static>(work: impl FnOnce() -> AppResult<T> + Send + 'static) -> AppResult<T> {
+        tokio::task::spawn_blocking(work).await.map_err(join_error)?
+    }
+
+    fn lock(connection: &Mutex<Connection>) -> MutexGuard<'_, Connection> {
+        connection.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn connect_error(e: oracle::Error) -> AppError {
+        AppError::ConnectionError(format!("Failed to connect to Oracle: {}", e))
+    }
+
+    fn query_error(e: oracle::Error) -> AppError {
+        AppError::QueryError(e.to_string())
+    }
+
+    fn join_error(e: tokio::task::JoinError) -> AppError {
+        AppError::QueryError(format!("The Oracle client stopped unexpectedly: {}", e))
+    }
+
+    /// Run `work` with the statement timeout of `limits` as the call timeout
+    fn with_timeout<T>(
+        connection: &Connection,
+        limits: &QueryLimits,
+        work: impl FnOnce() -> AppResult<T>,
+    ) -> AppResult<T> {
+        let Some(timeout_ms) = limits.statement_timeout_ms else {
+            return work();
+        };
+        connection
+            .set_call_timeout(Some(Duration::from_millis(timeout_ms)))
+            .map_err(query_error)?;
+        let result = work();
+        let _ = connection.set_call_timeout(None);
+        result
+    }
+
+    /// Bind a value as its kind; Oracle converts it to the type the
+    /// statement expects. Booleans bind as 1 and 0, since columns before
+    /// Oracle 23 have no boolean type.
+    fn bind_value(value: CellValue) -> Box<dyn ToSql> {
+        match value {
+            CellValue::Null => Box::new(None::<String>),
+            CellValue::Bool(b) => Box::new(b as i64),
+            CellValue::Int(i) => Box::new(i),
+            CellValue::Float(f) => Box::new(f),
+            CellValue::Bytes(ref encoded) => match value.bytes() {
+                Some(bytes) => Box::new(bytes),
+                None => Box::new(encoded.clone()),
+            },
+            CellValue::Text(text) | CellValue::Timestamp(text) => Box::new(text),
+            CellValue::Json(json) => Box::new(json.to_string()),
+        }
+    }
+
+    /// Run one statement, keeping at most `max_rows` rows of its result
+    fn run_statement(
+        connection: &Connection,
+        sql: &str,
+        params: Vec<CellValue>,
+        max_rows: Option<usize>,
+    ) -> AppResult<ResultSet> {
+        let start = Instant::now();
+        let params: Vec<Box<dyn ToSql>> = params.into_iter().map(bind_value).collect();
+        let params: Vec<&dyn ToSql> = params.iter().map(|param| param.as_ref()).collect();
+        let mut statement = connection.statement(sql).build().map_err(query_error)?;
+
+        if !statement.is_query() {
+            statement.execute(&params).map_err(query_error)?;
+            let affected_rows = if statement.is_dml() {
+                statement.row_count().map_err(query_error)?
+            } else {
+                0
+            };
+            return Ok(ResultSet {
+                statement: sql.to_string(),
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(affected_rows),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+            });
+        }
+
+        let result = statement.query(&params).map_err(query_error)?;
+        let columns = result_columns(result.column_info());
+        let mut rows = Vec::new();
+        for row in result {
+            if max_rows.is_some_and(|max_rows| rows.len() >= max_rows) {
+                break;
+            }
+            rows.push(json_row(&row.map_err(query_error)?));
+        }
+        Ok(ResultSet {
+            statement: sql.to_string(),
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    type Batch = AppResult<(Vec<ColumnInfo>, Vec<Vec<Json>>)>;
+
+    /// Send the columns of `sql`, then its rows `batch_size` at a time
+    fn send_rows(connection: &Connection, sql: &str, batch_size: usize, sender: &mpsc::Sender<Batch>) -> AppResult<()> {
+        let batch_size = batch_size.max(1);
+        let mut statement = connection
+            .statement(sql)
+            .fetch_array_size(batch_size.min(10_000) as u32)
+            .build()
+            .map_err(query_error)?;
+        let result = statement.query(&[]).map_err(query_error)?;
+        if sender
+            .blocking_send(Ok((result_columns(result.column_info()), vec![])))
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let mut batch = Vec::with_capacity(batch_size);
+        for row in result {
+            batch.push(json_row(&row.map_err(query_error)?));
+            if batch.len() == batch_size {
+                // The receiver is gone when the handler failed
+                let rows = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
+                if sender.blocking_send(Ok((vec![], rows))).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        if !batch.is_empty() {
+            let _ = sender.blocking_send(Ok((vec![], batch)));
+        }
+        Ok(())
+    }
+
+    fn result_columns(columns: &[oracle::ColumnInfo]) -> Vec<ColumnInfo> {
+        columns
+            .iter()
+            .map(|column| {
+                let native_type = column.oracle_type().to_string();
+                ColumnInfo {
+                    name: column.name().to_string(),
+                    // VARCHAR2(20) is a varchar2
+                    data_type: native_type.split('(').next().unwrap_or_default().to_lowercase(),
+                    nullable: column.nullable(),
+                    is_primary_key: false,
+                    native_type: Some(native_type),
+                    type_oid: None,
+                }
+            })
+            .collect()
+    }
+
+    fn json_row(row: &Row) -> Vec<Json> {
+        row.sql_values().iter().map(json_value).collect()
+    }
+
+    /// Convert an Oracle value to a JSON value. Whole numbers become JSON
+    /// numbers; decimals stay text so no digits are lost.
+    fn json_value(value: &SqlValue) -> Json {
+        if value.is_null().unwrap_or(true) {
+            return Json::Null;
+        }
+        let float = |v: f64| serde_json::Number::from_f64(v).map(Json::Number).unwrap_or(Json::Null);
+        let json = match value.oracle_type() {
+            Ok(OracleType::Int64) => value.get::<i64>().map(Json::from),
+            Ok(OracleType::UInt64) => value.get::<u64>().map(Json::from),
+            Ok(OracleType::Number(precision, 0)) if (1..=18).contains(precision) => value.get::<i64>().map(Json::from),
+            // Unconstrained NUMBER, as COUNT(*) returns, holds whole numbers more often than not
+            Ok(OracleType::Number(_, scale)) if *scale <= 0 => value
+                .get::<String>()
+                .map(|text| text.parse::<i64>().map(Json::from).unwrap_or(Json::String(text))),
+            Ok(OracleType::BinaryFloat | OracleType::BinaryDouble) => value.get::<f64>().map(float),
+            Ok(OracleType::Boolean) => value.get::<bool>().map(Json::Bool),
+            Ok(OracleType::Raw(_) | OracleType::LongRaw | OracleType::BLOB) => value
+                .get::<Vec<u8>>()
+                .map(|bytes| Json::String(general_purpose::STANDARD.encode(bytes))),
+            _ => value.get::<String>().map(Json::String),
+        };
+        json.unwrap_or_else(|_| Json::String(format!("{}", value)))
+    }
+}
+
+#[cfg(not(feature = "oracle"))]
+mod engine {
+    use crate::db::{BatchHandler, QueryLimits};
+    use crate::error::{AppError, AppResult};
+    use crate::models::{CellValue, ResultSet};
+
+    /// Stands in for the client in builds without the `oracle` feature. It
+    /// has no values, so connecting is the only call that can happen.
+    pub enum OracleClient {}
+
+    impl OracleClient {
+        pub async fn connect(_username: String, _password: String, _connect_string: String) -> AppResult<Self> {
+            Err(AppError::ConfigError(
+                "This build does not include the Oracle driver".to_string(),
+            ))
+        }
+
+        pub async fn version(&self) -> AppResult<String> {
+            match *self {}
+        }
+
+        pub async fn execute(
+            &self,
+            _sql: String,
+            _params: Vec<CellValue>,
+            _limits: QueryLimits,
+        ) -> AppResult<ResultSet> {
+            match *self {}
+        }
+
+        pub async fn execute_script(
+            &self,
+            _statements: Vec<String>,
+            _limits: QueryLimits,
+        ) -> AppResult<Vec<ResultSet>> {
+            match *self {}
+        }
+
+        pub async fn execute_read_only(&self, _sql: String, _limits: QueryLimits) -> AppResult<ResultSet> {
+            match *self {}
+        }
+
+        pub async fn insert_rows(
+            &self,
+            _sql: String,
+            _rows: Vec<Vec<serde_json::Value>>,
+        ) -> AppResult<Vec<(usize, String)>> {
+            match *self {}
+        }
+
+        pub async fn stream(
+            &self,
+            _sql: String,
+            _batch_size: usize,
+            _on_batch: &mut BatchHandler<'_>,
+        ) -> AppResult<usize> {
+            match *self {}
+        }
+    }
+}
+
+impl OracleClient {
+    /// Rows of a metadata query
+    async fn rows(&self, sql: &str) -> AppResult<Vec<Vec<serde_json::Value>>> {
+        Ok(self
+            .execute(sql.to_string(), vec![], QueryLimits::default())
+            .await?
+            .rows)
+    }
+}
+
+fn text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn opt_text(value: &serde_json::Value) -> Option<String> {
+    Some(text(value)).filter(|text| !text.is_empty())
+}
+
+fn number(value: &serde_json::Value) -> Option<i64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_i64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn float(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn literal(text: &str) -> String {
+    string_literal(&DatabaseType::Oracle, text)
+}
+
+fn quote(name: &str) -> String {
+    quote_identifier(&DatabaseType::Oracle, name)
+}
+
+fn qualified(name: &str) -> String {
+    quote_qualified(&DatabaseType::Oracle, name)
+}
+
+/// Conditions matching a table in the `owner` and `table` columns of a
+/// catalog view; a name without a schema is looked up in the current one
+fn table_filter(table_name: &str, owner: &str, table: &str) -> String {
+    match table_name.split_once('.') {
+        Some((schema, name)) => format!("{} = {} AND {} = {}", owner, literal(schema), table, literal(name)),
+        None => format!("{} = {} AND {} = {}", owner, CURRENT_SCHEMA, table, literal(table_name)),
+    }
+}
+
+/// Schema of a table name, when it names one
+fn schema_of(table_name: &str) -> Option<&str> {
+    table_name.split_once('.').map(|(schema, _)| schema)
+}
+
+fn client_for(pool: PoolRef<'_>) -> AppResult<&OracleClient> {
+    match pool {
+        PoolRef::Oracle(client) => Ok(client),
+        _ => Err(AppError::QueryError("Invalid pool type for Oracle driver".to_string())),
+    }
+}
+
+/// A single result set as the whole result
+fn query_result(set: ResultSet, start: Instant) -> QueryResult {
+    QueryResult {
+        columns: set.columns,
+        rows: set.rows,
+        affected_rows: set.affected_rows,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    }
+}
+
+/// Wrap a DROP statement in a block that ignores the error Oracle raises
+/// when the object is missing, since Oracle before 23 has no IF EXISTS
+fn drop_if_exists(statement: &str, missing_error: i32) -> String {
+    format!(
+        "BEGIN\n  EXECUTE IMMEDIATE {};\nEXCEPTION\n  WHEN OTHERS THEN\n    IF SQLCODE != {} THEN\n      RAISE;\n    END IF;\nEND;\n/",
+        literal(statement),
+        missing_error
+    )
+}
+
+/// Column details read from ALL_TAB_COLUMNS
+struct OracleColumn {
+    name: String,
+    data_type: String,
+    nullable: bool,
+    default_value: Option<String>,
+    comment: Option<String>,
+}
+
+/// Columns `table_columns` reads, after the table name
+const COLUMN_FIELDS: &str = "c.column_name, c.data_type, c.char_length, c.data_precision, c.data_scale, \
+    c.data_length, c.nullable, c.data_default, m.comments";
+
+impl OracleColumn {
+    /// A column from the `COLUMN_FIELDS` of a row
+    fn from_row(row: &[serde_json::Value]) -> Self {
+        let data_type = text(&row[1]);
+        let data_type = match data_type.as_str() {
+            "VARCHAR2" | "NVARCHAR2" | "CHAR" | "NCHAR" => format!("{}({})", data_type, number(&row[2]).unwrap_or(1)),
+            "RAW" => format!("RAW({})", number(&row[5]).unwrap_or(1)),
+            "NUMBER" => match (number(&row[3]), number(&row[4])) {
+                (Some(precision), Some(0)) => format!("NUMBER({})", precision),
+                (Some(precision), Some(scale)) => format!("NUMBER({},{})", precision, scale),
+                (None, Some(0)) => "INTEGER".to_string(),
+                _ => "NUMBER".to_string(),
+            },
+            "FLOAT" => format!("FLOAT({})", number(&row[3]).unwrap_or(126)),
+            _ => data_type,
+        };
+        Self {
+            name: text(&row[0]),
+            data_type,
+            nullable: text(&row[6]) != "N",
+            default_value: opt_text(&row[7]).map(|default| default.trim().to_string()),
+            comment: opt_text(&row[8]),
+        }
+    }
+}
+
+/// A constraint of a table with its columns in key order
+struct OracleConstraint {
+    /// `OWNER.TABLE`
+    table: String,
+    /// P, U, R or C
+    kind: String,
+    name: String,
+    columns: Vec<String>,
+    references_table: Option<String>,
+    references_columns: Vec<String>,
+    condition: Option<String>,
+}
+
+/// Helper methods for OracleDriver
+impl OracleDriver {
+    /// Open a new connection
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<OracleClient> {
+        OracleClient::connect(
+            config.username.clone().unwrap_or_default(),
+            config.password.clone().unwrap_or_default(),
+            Self.build_connection_string(config),
+        )
+        .await
+    }
+
+    /// Run SQL typed by the user; a script runs in one transaction
+    async fn execute_statements(client: &OracleClient, sql: &str, limits: QueryLimits) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let mut statements = sql_parse::split_statements(sql, Dialect::Oracle);
+        if statements.len() > 1 {
+            let result_sets = client.execute_script(statements, limits).await?;
+            return Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64));
+        }
+        let sql = statements.pop().unwrap_or_default();
+        Ok(query_result(client.execute(sql, vec![], limits).await?, start))
+    }
+
+    /// Run a DDL statement
+    async fn execute_ddl(client: &OracleClient, sql: &str, context: &str) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let set = client
+            .execute(sql.to_string(), vec![], QueryLimits::default())
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to {}: {}", context, e)))?;
+        Ok(query_result(set, start))
+    }
+
+    /// Columns of a table or view in table order
+    async fn table_columns(client: &OracleClient, table_name: &str) -> AppResult<Vec<OracleColumn>> {
+        let rows = client
+            .rows(&format!(
+                "SELECT {} FROM all_tab_columns c \
+                 LEFT JOIN all_col_comments m \
+                 ON m.owner = c.owner AND m.table_name = c.table_name AND m.column_name = c.column_name \
+                 WHERE {} ORDER BY c.column_id",
+                COLUMN_FIELDS,
+                table_filter(table_name, "c.owner", "c.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get columns: {}", e)))?;
+        if rows.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+        Ok(rows.iter().map(|row| OracleColumn::from_row(row)).collect())
+    }
+
+    /// Primary key, unique, foreign key and check constraints matching
+    /// `filter` on ALL_CONSTRAINTS `c`. NOT NULL constraints are left out.
+    async fn fetch_constraints(client: &OracleClient, filter: &str) -> AppResult<Vec<OracleConstraint>> {
+        let rows = client
+            .rows(&format!(
+                "SELECT c.owner || '.' || c.table_name, c.constraint_type, c.constraint_name, cc.column_name, \
+                 r.owner, r.table_name, rc.column_name, c.search_condition_vc \
+                 FROM all_constraints c \
+                 LEFT JOIN all_cons_columns cc \
+                 ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name AND cc.table_name = c.table_name \
+                 LEFT JOIN all_constraints r ON r.owner = c.r_owner AND r.constraint_name = c.r_constraint_name \
+                 LEFT JOIN all_cons_columns rc \
+                 ON rc.owner = r.owner AND rc.constraint_name = r.constraint_name AND rc.position = cc.position \
+                 WHERE {} AND c.constraint_type IN ('P', 'U', 'R', 'C') \
+                 AND NOT (c.constraint_type = 'C' AND c.generated = 'GENERATED NAME' \
+                 AND c.search_condition_vc LIKE '% IS NOT NULL') \
+                 ORDER BY 1, c.constraint_name, cc.position",
+                filter
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get constraints: {}", e)))?;
+
+        // Rows come one per constrained column; group them by constraint
+        let mut constraints: Vec<OracleConstraint> = Vec::new();
+        for row in &rows {
+            let table = text(&row[0]);
+            let name = text(&row[2]);
+            let constraint = match constraints.last_mut() {
+                Some(constraint) if constraint.table == table && constraint.name == name => constraint,
+                _ => {
+                    constraints.push(OracleConstraint {
+                        table,
+                        kind: text(&row[1]),
+                        name,
+                        columns: vec![],
+                        references_table: opt_text(&row[4]).map(|owner| format!("{}.{}", owner, text(&row[5]))),
+                        references_columns: vec![],
+                        condition: opt_text(&row[7]),
+                    });
+                    let Some(constraint) = constraints.last_mut() else {
+                        continue;
+                    };
+                    constraint
+                }
+            };
+            constraint.columns.extend(opt_text(&row[3]));
+            constraint.references_columns.extend(opt_text(&row[6]));
+        }
+        Ok(constraints)
+    }
+
+    /// Ask DBMS_METADATA for statements ending in their terminator, without
+    /// storage clauses, and with foreign keys only when `foreign_keys` is set
+    async fn set_ddl_transforms(client: &OracleClient, foreign_keys: bool) -> AppResult<()> {
+        let sql = format!(
+            "BEGIN \
+             DBMS_METADATA.SET_TRANSFORM_PARAM(DBMS_METADATA.SESSION_TRANSFORM, 'DEFAULT', TRUE); \
+             DBMS_METADATA.SET_TRANSFORM_PARAM(DBMS_METADATA.SESSION_TRANSFORM, 'SQLTERMINATOR', TRUE); \
+             DBMS_METADATA.SET_TRANSFORM_PARAM(DBMS_METADATA.SESSION_TRANSFORM, 'SEGMENT_ATTRIBUTES', FALSE); \
+             DBMS_METADATA.SET_TRANSFORM_PARAM(DBMS_METADATA.SESSION_TRANSFORM, 'REF_CONSTRAINTS', {}); \
+             END;",
+            if foreign_keys { "TRUE" } else { "FALSE" }
+        );
+        client
+            .execute(sql, vec![], QueryLimits::default())
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to set up DBMS_METADATA: {}", e)))?;
+        Ok(())
+    }
+}
+
+fn primary_keys(constraints: &[OracleConstraint]) -> Vec<String> {
+    constraints
+        .iter()
+        .filter(|constraint| constraint.kind == "P")
+        .flat_map(|constraint| constraint.columns.clone())
+        .collect()
+}
+
+fn foreign_keys(constraints: &[OracleConstraint]) -> Vec<ForeignKeyInfo> {
+    constraints
+        .iter()
+        .filter(|constraint| constraint.kind == "R")
+        .flat_map(|constraint| {
+            let references_table = constraint.references_table.clone().unwrap_or_default();
+            constraint
+                .columns
+                .iter()
+                .zip(&constraint.references_columns)
+                .map(move |(column, references_column)| ForeignKeyInfo {
+                    column: column.clone(),
+                    references_table: references_table.clone(),
+                    references_column: references_column.clone(),
+                })
+        })
+        .collect()
+}
+
+#[async_trait]
+impl DatabaseDriver for OracleDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let client = Self::connect(config).await?;
+        let version = client.version().await.ok();
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!(
+                "Oracle connection to {} successful",
+                self.build_connection_string(config)
+            ),
+            server_version: version,
+        })
+    }
+
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        Self::execute_statements(client_for(pool)?, sql, QueryLimits::default()).await
+    }
+
+    /// The statement timeout is the call timeout, after which Oracle cancels the call
+    async fn execute_query_limited(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        limits: &QueryLimits,
+    ) -> AppResult<QueryResult> {
+        Self::execute_statements(client_for(pool)?, sql, *limits).await
+    }
+
+    async fn execute_read_only(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let sql = sql_parse::split_statements(sql, Dialect::Oracle)
+            .pop()
+            .unwrap_or_default();
+        let set = client_for(pool)?.execute_read_only(sql, *limits).await?;
+        Ok(query_result(set, start))
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let sql = sql_parse::split_statements(sql, Dialect::Oracle)
+            .pop()
+            .unwrap_or_default();
+        client_for(pool)?.stream(sql, batch_size, on_batch).await
+    }
+
+    async fn execute_with_params(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        params: Vec<CellValue>,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+        let set = client_for(pool)?
+            .execute(sql.to_string(), params, QueryLimits::default())
+            .await?;
+        Ok(query_result(set, start))
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::Oracle, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let sql = insert_statement(&DatabaseType::Oracle, table, columns);
+        client_for(pool)?.insert_rows(sql, rows).await
+    }
+
+    /// Tables and views of the current schema
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT owner, table_name, 'BASE TABLE', num_rows FROM all_tables \
+                 WHERE owner = {0} AND nested = 'NO' AND secondary = 'N' AND dropped = 'NO' \
+                 UNION ALL SELECT owner, view_name, 'VIEW', NULL FROM all_views WHERE owner = {0} \
+                 ORDER BY 2",
+                CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get tables: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TableInfo {
+                name: format!("{}.{}", text(&row[0]), text(&row[1])),
+                schema: Some(text(&row[0])),
+                table_type: text(&row[2]),
+                row_count: number(&row[3]),
+                parent_table: None,
+                pinned: false,
+            })
+            .collect())
+    }
+
+    /// The service the connection opened; switching to another means connecting to it
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let rows = client_for(pool)?
+            .rows("SELECT SYS_CONTEXT('USERENV', 'SERVICE_NAME') FROM dual")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+        Ok(rows.iter().map(|row| text(&row[0])).collect())
+    }
+
+    /// Users other than those Oracle creates and maintains
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let rows = client_for(pool)?
+            .rows("SELECT username FROM all_users WHERE oracle_maintained = 'N' ORDER BY 1")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list schemas: {}", e)))?;
+        Ok(rows.iter().map(|row| text(&row[0])).collect())
+    }
+
+    async fn create_database(&self, _pool: PoolRef<'_>, _options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError(
+            "Oracle databases are created by the DBA; connect to another service instead".to_string(),
+        ))
+    }
+
+    async fn drop_database(&self, _pool: PoolRef<'_>, _name: &str) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError(
+            "Oracle databases are dropped by the DBA".to_string(),
+        ))
+    }
+
+    /// An Oracle schema is a user; this creates one that cannot log in
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        if options.owner.is_some() {
+            return Err(AppError::ValidationError(
+                "An Oracle schema is owned by the user of the same name".to_string(),
+            ));
+        }
+        let sql = format!("CREATE USER {} NO AUTHENTICATION", quote(&options.name));
+        Self::execute_ddl(client_for(pool)?, &sql, "create schema").await
+    }
+
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, cascade: bool) -> AppResult<QueryResult> {
+        let sql = format!("DROP USER {}{}", quote(name), if cascade { " CASCADE" } else { "" });
+        Self::execute_ddl(client_for(pool)?, &sql, "drop schema").await
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let client = client_for(pool)?;
+        let columns = Self::table_columns(client, table_name).await?;
+        let constraints = Self::fetch_constraints(client, &table_filter(table_name, "c.owner", "c.table_name")).await?;
+        let primary_keys = primary_keys(&constraints);
+
+        let columns = columns
+            .into_iter()
+            .map(|column| ColumnInfo {
+                is_primary_key: primary_keys.contains(&column.name),
+                native_type: None,
+                type_oid: None,
+                name: column.name,
+                data_type: column.data_type,
+                nullable: column.nullable,
+            })
+            .collect();
+
+        Ok(TableSchema {
+            table_name: table_name.to_string(),
+            columns,
+            foreign_keys: foreign_keys(&constraints),
+            primary_keys,
+        })
+    }
+
+    async fn get_all_table_schemas(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+    ) -> AppResult<Vec<TableSchema>> {
+        let client = client_for(pool)?;
+        let rows = client
+            .rows(&format!(
+                "SELECT c.owner || '.' || c.table_name, {} FROM all_tab_columns c \
+                 LEFT JOIN all_col_comments m \
+                 ON m.owner = c.owner AND m.table_name = c.table_name AND m.column_name = c.column_name \
+                 WHERE c.owner = {} AND c.table_name NOT LIKE 'BIN$%' \
+                 ORDER BY c.table_name, c.column_id",
+                COLUMN_FIELDS, CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get schemas: {}", e)))?;
+        let constraints = Self::fetch_constraints(
+            client,
+            &format!("c.owner = {} AND c.constraint_type IN ('P', 'R')", CURRENT_SCHEMA),
+        )
+        .await?;
+        let mut by_table: HashMap<String, Vec<OracleConstraint>> = HashMap::new();
+        for constraint in constraints {
+            by_table.entry(constraint.table.clone()).or_default().push(constraint);
+        }
+
+        let mut schemas: Vec<TableSchema> = Vec::new();
+        for row in &rows {
+            let table_name = text(&row[0]);
+            if schemas.last().is_none_or(|schema| schema.table_name != table_name) {
+                let table_constraints = by_table.remove(&table_name).unwrap_or_default();
+                schemas.push(TableSchema {
+                    primary_keys: primary_keys(&table_constraints),
+                    foreign_keys: foreign_keys(&table_constraints),
+                    table_name,
+                    columns: vec![],
+                });
+            }
+            let Some(schema) = schemas.last_mut() else {
+                continue;
+            };
+
+            let column = OracleColumn::from_row(&row[1..]);
+            schema.columns.push(ColumnInfo {
+                is_primary_key: schema.primary_keys.contains(&column.name),
+                native_type: None,
+                type_oid: None,
+                name: column.name,
+                data_type: column.data_type,
+                nullable: column.nullable,
+            });
+        }
+        Ok(schemas)
+    }
+
+    /// Easy Connect string, `//host:port/service`; the service is the
+    /// connection's database
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let protocol = match config.ssl_mode.as_deref() {
+            Some("require") | Some("verify-ca") | Some("verify-full") => "tcps://",
+            _ => "//",
+        };
+        let mut connect_string = format!(
+            "{}{}:{}",
+            protocol,
+            config.host.as_deref().unwrap_or("localhost"),
+            config.port.unwrap_or(1521)
+        );
+        let service = config.database.trim();
+        if !service.is_empty() {
+            connect_string.push('/');
+            connect_string.push_str(service);
+        }
+        connect_string
+    }
+
+    /// DBMS_METADATA's statement for the table or view, and the table's
+    /// indexes other than those behind its constraints
+    async fn generate_table_ddl(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<String> {
+        let client = client_for(pool)?;
+        Self::set_ddl_transforms(client, true).await?;
+        let rows = client
+            .rows(&format!(
+                "SELECT DBMS_METADATA.GET_DDL(o.object_type, o.object_name, o.owner) FROM all_objects o \
+                 WHERE {} AND o.object_type IN ('TABLE', 'VIEW') \
+                 UNION ALL SELECT DBMS_METADATA.GET_DDL('INDEX', i.index_name, i.owner) FROM all_indexes i \
+                 WHERE {} AND i.index_type <> 'LOB' AND NOT EXISTS (SELECT 1 FROM all_constraints c \
+                 WHERE c.owner = i.table_owner AND c.index_name = i.index_name)",
+                table_filter(table_name, "o.owner", "o.object_name"),
+                table_filter(table_name, "i.table_owner", "i.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to generate DDL: {}", e)))?;
+        if rows.is_empty() {
+            return Err(AppError::QueryError(format!("Table '{}' not found", table_name)));
+        }
+
+        let statements: Vec<String> = rows.iter().map(|row| text(&row[0]).trim().to_string()).collect();
+        Ok(statements.join("\n\n"))
+    }
+
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT d.owner || '.' || d.name, 'VIEW', d.referenced_owner || '.' || d.referenced_name, NULL \
+                 FROM all_dependencies d \
+                 WHERE d.owner = {0} AND d.type = 'VIEW' AND d.referenced_owner = {0} \
+                 AND d.referenced_type IN ('TABLE', 'VIEW') \
+                 UNION ALL SELECT c.owner || '.' || c.table_name, 'TABLE', r.owner || '.' || r.table_name, \
+                 c.constraint_name FROM all_constraints c \
+                 JOIN all_constraints r ON r.owner = c.r_owner AND r.constraint_name = c.r_constraint_name \
+                 WHERE c.owner = {0} AND c.constraint_type = 'R' \
+                 ORDER BY 1, 3",
+                CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get object dependencies: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ObjectDependency {
+                object: text(&row[0]),
+                object_type: text(&row[1]),
+                depends_on: text(&row[2]),
+                constraint_name: opt_text(&row[3]),
+            })
+            .collect())
+    }
+
+    /// DBMS_METADATA's statements for the objects of the current schema.
+    /// Names always carry their schema, as DBMS_METADATA writes them.
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        _options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let client = client_for(pool)?;
+        Self::set_ddl_transforms(client, false).await?;
+
+        // Identity columns make sequences and LOB columns make indexes of their own
+        let rows = client
+            .rows(&format!(
+                "SELECT o.object_type, o.object_name, \
+                 DBMS_METADATA.GET_DDL(DECODE(o.object_type, 'PACKAGE', 'PACKAGE_SPEC', 'PACKAGE BODY', \
+                 'PACKAGE_BODY', 'TYPE', 'TYPE_SPEC', 'TYPE BODY', 'TYPE_BODY', o.object_type), \
+                 o.object_name, o.owner) \
+                 FROM all_objects o \
+                 WHERE o.owner = {0} AND o.generated = 'N' AND o.object_name NOT LIKE 'BIN$%' \
+                 AND o.object_type IN ('SEQUENCE', 'TYPE', 'TYPE BODY', 'FUNCTION', 'PROCEDURE', 'PACKAGE', \
+                 'PACKAGE BODY', 'TABLE', 'VIEW', 'TRIGGER') \
+                 AND NOT (o.object_type = 'SEQUENCE' AND o.object_name LIKE 'ISEQ$$%') \
+                 UNION ALL SELECT 'INDEX', i.index_name, DBMS_METADATA.GET_DDL('INDEX', i.index_name, i.owner) \
+                 FROM all_indexes i \
+                 WHERE i.owner = {0} AND i.table_owner = {0} AND i.generated = 'N' AND i.index_type <> 'LOB' \
+                 AND NOT EXISTS (SELECT 1 FROM all_constraints c \
+                 WHERE c.owner = i.table_owner AND c.index_name = i.index_name) \
+                 UNION ALL SELECT 'REF_CONSTRAINT', c.table_name || '.' || c.constraint_name, \
+                 DBMS_METADATA.GET_DDL('REF_CONSTRAINT', c.constraint_name, c.owner) \
+                 FROM all_constraints c WHERE c.owner = {0} AND c.constraint_type = 'R'",
+                CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list schema objects: {}", e)))?;
+
+        let view_dependencies = client
+            .rows(&format!(
+                "SELECT name, referenced_name FROM all_dependencies \
+                 WHERE owner = {0} AND type = 'VIEW' AND referenced_owner = {0} AND referenced_type = 'VIEW'",
+                CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get view dependencies: {}", e)))?;
+        let mut depends_on: HashMap<String, Vec<String>> = HashMap::new();
+        for row in &view_dependencies {
+            depends_on
+                .entry(format!("VIEW {}", text(&row[0])))
+                .or_default()
+                .push(format!("VIEW {}", text(&row[1])));
+        }
+
+        let schema = client
+            .rows(&format!("SELECT {} FROM dual", CURRENT_SCHEMA))
+            .await?
+            .first()
+            .map(|row| text(&row[0]))
+            .unwrap_or_default();
+
+        let mut objects = Vec::new();
+        for row in &rows {
+            let object_type = text(&row[0]);
+            let name = text(&row[1]);
+            let create = text(&row[2]).trim().to_string();
+            let key = format!("{} {}", object_type, name);
+            let quoted = format!("{}.{}", quote(&schema), quote(&name));
+            let object = match object_type.as_str() {
+                "SEQUENCE" => DdlObject::new(DdlKind::Sequence, key, create)
+                    .with_drop(drop_if_exists(&format!("DROP SEQUENCE {}", quoted), -2289)),
+                "TYPE" => DdlObject::new(DdlKind::Type, key, create)
+                    .with_drop(drop_if_exists(&format!("DROP TYPE {} FORCE", quoted), -4043)),
+                "TABLE" => DdlObject::new(DdlKind::Table, key, create).with_drop(drop_if_exists(
+                    &format!("DROP TABLE {} CASCADE CONSTRAINTS", quoted),
+                    -942,
+                )),
+                "INDEX" => DdlObject::new(DdlKind::Index, key, create),
+                "REF_CONSTRAINT" => DdlObject::new(DdlKind::Constraint, key, create),
+                "VIEW" => DdlObject::new(DdlKind::View, key.clone(), create)
+                    .with_drop(drop_if_exists(&format!("DROP VIEW {}", quoted), -942))
+                    .with_dependencies(depends_on.remove(&key).unwrap_or_default()),
+                "TRIGGER" => DdlObject::new(DdlKind::Trigger, key, create),
+                // A body follows its specification
+                "PACKAGE BODY" | "TYPE BODY" => {
+                    let specification = format!("{} {}", object_type.trim_end_matches(" BODY"), name);
+                    DdlObject::new(DdlKind::Function, key, create).with_dependencies(vec![specification])
+                }
+                _ => DdlObject::new(DdlKind::Function, key, create)
+                    .with_drop(drop_if_exists(&format!("DROP {} {}", object_type, quoted), -4043)),
+            };
+            objects.push(object);
+        }
+        Ok(objects)
+    }
+
+    /// The new name stays in the table's schema
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let new_name = new_name.rsplit('.').next().unwrap_or(new_name);
+        let sql = format!("ALTER TABLE {} RENAME TO {}", qualified(old_name), quote(new_name));
+        Self::execute_ddl(client_for(pool)?, &sql, "rename table").await
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} ADD ({})",
+            qualified(table_name),
+            column_definition(&DatabaseType::Oracle, column)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "add column").await
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            qualified(table_name),
+            quote(column_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "drop column").await
+    }
+
+    async fn rename_column(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        old_name: &str,
+        new_name: &str,
+    ) -> AppResult<QueryResult> {
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            qualified(table_name),
+            quote(old_name),
+            quote(new_name)
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "rename column").await
+    }
+
+    /// Oracle refuses to set the nullability a column already has, so it is
+    /// only given when it changes
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        let client = client_for(pool)?;
+        let columns = Self::table_columns(client, table_name).await?;
+        let column = columns
+            .iter()
+            .find(|column| column.name == column_name)
+            .ok_or_else(|| AppError::QueryError(format!("Column '{}' not found", column_name)))?;
+
+        let mut sql = format!(
+            "ALTER TABLE {} MODIFY ({} {}",
+            qualified(table_name),
+            quote(column_name),
+            data_type
+        );
+        if column.nullable != nullable {
+            sql.push_str(if nullable { " NULL" } else { " NOT NULL" });
+        }
+        sql.push(')');
+        Self::execute_ddl(client, &sql, "change column type").await
+    }
+
+    /// The PostgreSQL-only options are ignored
+    async fn truncate_table(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        _options: &TruncateOptions,
+    ) -> AppResult<QueryResult> {
+        let sql = format!("TRUNCATE TABLE {}", qualified(table_name));
+        Self::execute_ddl(client_for(pool)?, &sql, "truncate table").await
+    }
+
+    /// `CREATE TABLE ... AS` copies the columns, their types and NOT NULL, but not keys, indexes or defaults
+    async fn duplicate_table(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        new_name: &str,
+        include_data: bool,
+    ) -> AppResult<QueryResult> {
+        let sql = format!(
+            "CREATE TABLE {} AS SELECT * FROM {}{}",
+            qualified(new_name),
+            qualified(table_name),
+            if include_data { "" } else { " WHERE 1 = 0" }
+        );
+        Self::execute_ddl(client_for(pool)?, &sql, "duplicate table").await
+    }
+
+    async fn get_indexes(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT i.index_name, ic.column_name, i.uniqueness, \
+                 CASE WHEN EXISTS (SELECT 1 FROM all_constraints c WHERE c.owner = i.table_owner \
+                 AND c.index_name = i.index_name AND c.constraint_type = 'P') THEN 1 ELSE 0 END \
+                 FROM all_indexes i \
+                 JOIN all_ind_columns ic ON ic.index_owner = i.owner AND ic.index_name = i.index_name \
+                 WHERE {} AND i.index_type <> 'LOB' \
+                 ORDER BY i.index_name, ic.column_position",
+                table_filter(table_name, "i.table_owner", "i.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get indexes: {}", e)))?;
+
+        // Rows come one per indexed column; group them by index
+        let mut indexes: Vec<IndexInfo> = Vec::new();
+        for row in &rows {
+            let name = text(&row[0]);
+            let column = text(&row[1]);
+            match indexes.last_mut() {
+                Some(index) if index.name == name => index.columns.push(column),
+                _ => indexes.push(IndexInfo {
+                    name,
+                    columns: vec![column],
+                    is_unique: text(&row[2]) == "UNIQUE",
+                    is_primary: number(&row[3]) == Some(1),
+                }),
+            }
+        }
+        Ok(indexes)
+    }
+
+    async fn get_constraints(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        let constraints =
+            Self::fetch_constraints(client_for(pool)?, &table_filter(table_name, "c.owner", "c.table_name")).await?;
+        Ok(constraints
+            .into_iter()
+            .filter_map(|constraint| {
+                let (constraint_type, definition) = match constraint.kind.as_str() {
+                    "C" => ("CHECK", format!("CHECK ({})", constraint.condition.unwrap_or_default())),
+                    "U" => {
+                        let columns: Vec<String> = constraint.columns.iter().map(|column| quote(column)).collect();
+                        ("UNIQUE", format!("UNIQUE ({})", columns.join(", ")))
+                    }
+                    _ => return None,
+                };
+                Some(ConstraintInfo {
+                    name: constraint.name,
+                    constraint_type: constraint_type.to_string(),
+                    definition,
+                })
+            })
+            .collect())
+    }
+
+    async fn get_table_properties(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableProperties> {
+        let client = client_for(pool)?;
+        let columns = Self::table_columns(client, table_name).await?;
+        let constraints = Self::fetch_constraints(client, &table_filter(table_name, "c.owner", "c.table_name")).await?;
+        let primary_keys = primary_keys(&constraints);
+
+        // Identity, virtual and collation details per column
+        let details: HashMap<String, Vec<serde_json::Value>> = client
+            .rows(&format!(
+                "SELECT c.column_name, c.identity_column, c.virtual_column, i.generation_type, c.collation \
+                 FROM all_tab_cols c \
+                 LEFT JOIN all_tab_identity_cols i \
+                 ON i.owner = c.owner AND i.table_name = c.table_name AND i.column_name = c.column_name \
+                 WHERE {} AND c.hidden_column = 'NO'",
+                table_filter(table_name, "c.owner", "c.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get column details: {}", e)))?
+            .into_iter()
+            .map(|row| (text(&row[0]), row))
+            .collect();
+
+        let columns = columns
+            .into_iter()
+            .map(|column| {
+                let detail = details.get(&column.name);
+                let is_identity = detail.is_some_and(|row| text(&row[1]) == "YES");
+                let is_virtual = detail.is_some_and(|row| text(&row[2]) == "YES");
+                ExtendedColumnInfo {
+                    is_primary_key: primary_keys.contains(&column.name),
+                    is_identity,
+                    identity_always: detail.is_some_and(|row| text(&row[3]) == "ALWAYS"),
+                    // Identity columns default to their sequence and virtual ones hold their expression
+                    generation_expression: if is_virtual { column.default_value.clone() } else { None },
+                    default_value: if is_identity || is_virtual {
+                        None
+                    } else {
+                        column.default_value
+                    },
+                    collation: detail.and_then(|row| opt_text(&row[4])),
+                    name: column.name,
+                    data_type: column.data_type,
+                    nullable: column.nullable,
+                    comment: column.comment,
+                }
+            })
+            .collect();
+
+        let table = client
+            .rows(&format!(
+                "SELECT t.num_rows, m.comments FROM all_tab_comments m \
+                 LEFT JOIN all_tables t ON t.owner = m.owner AND t.table_name = m.table_name WHERE {}",
+                table_filter(table_name, "m.owner", "m.table_name")
+            ))
+            .await?;
+        let table = table.first();
+        let (row_count, row_count_exact) = self
+            .row_count_from_estimate(pool, table_name, table.and_then(|row| number(&row[0])))
+            .await;
+
+        Ok(TableProperties {
+            table_name: table_name.to_string(),
+            schema: schema_of(table_name).map(str::to_string),
+            columns,
+            foreign_keys: foreign_keys(&constraints),
+            primary_keys,
+            indexes: self.get_indexes(pool, table_name).await?,
+            constraints: self.get_constraints(pool, table_name).await?,
+            row_count,
+            row_count_exact,
+            table_comment: table.and_then(|row| opt_text(&row[1])),
+        })
+    }
+
+    /// Rows from the optimizer statistics; sizes come from the segments of
+    /// the current user, so tables of other schemas have none
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT t.num_rows, t.last_analyzed, \
+                 (SELECT SUM(s.bytes) FROM user_segments s \
+                 WHERE s.segment_name = t.table_name AND s.segment_type LIKE 'TABLE%' \
+                 AND t.owner = USER), \
+                 (SELECT SUM(s.bytes) FROM user_segments s \
+                 JOIN user_indexes i ON i.index_name = s.segment_name \
+                 WHERE i.table_name = t.table_name AND t.owner = USER) \
+                 FROM all_tables t WHERE {}",
+                table_filter(table_name, "t.owner", "t.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?;
+        let row = rows
+            .first()
+            .ok_or_else(|| AppError::QueryError(format!("Table {} not found", table_name)))?;
+
+        let table_size = number(&row[2]).map(|bytes| bytes as u64);
+        let index_size = number(&row[3]).map(|bytes| bytes as u64);
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: table_size,
+            index_size_bytes: index_size,
+            total_size_bytes: table_size.map(|table| table + index_size.unwrap_or(0)),
+            approximate_rows: number(&row[0]).map(|rows| rows as u64),
+            last_analyze: opt_text(&row[1]),
+            ..TableStats::default()
+        })
+    }
+
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let rows = client_for(pool)?
+            .rows(&format!("SELECT COUNT(*) FROM {}", qualified(table_name)))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))?;
+        rows.first()
+            .and_then(|row| row.first())
+            .and_then(number)
+            .ok_or_else(|| AppError::QueryError(format!("Failed to count rows of {}", table_name)))
+    }
+
+    async fn get_comments(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectComment>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT owner || '.' || table_name, NULL, comments FROM all_tab_comments \
+                 WHERE owner = {0} AND comments IS NOT NULL \
+                 UNION ALL SELECT owner || '.' || table_name, column_name, comments FROM all_col_comments \
+                 WHERE owner = {0} AND comments IS NOT NULL",
+                CURRENT_SCHEMA
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get comments: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ObjectComment {
+                table: text(&row[0]),
+                column: opt_text(&row[1]),
+                comment: text(&row[2]),
+            })
+            .collect())
+    }
+
+    async fn comment_statements(
+        &self,
+        _pool: PoolRef<'_>,
+        table_name: &str,
+        comments: &SchemaComments,
+    ) -> AppResult<Vec<String>> {
+        let table = qualified(table_name);
+        // An empty comment removes it
+        let mut statements = Vec::with_capacity(comments.columns.len() + 1);
+        if let Some(table_comment) = &comments.table_comment {
+            statements.push(format!("COMMENT ON TABLE {} IS {}", table, literal(table_comment)));
+        }
+        for column in &comments.columns {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                table,
+                quote(&column.column),
+                literal(&column.comment)
+            ));
+        }
+        Ok(statements)
+    }
+
+    async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT c.constraint_name, c.owner || '.' || c.table_name, cc.column_name, \
+                 r.owner || '.' || r.table_name, rc.column_name \
+                 FROM all_constraints c \
+                 JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name \
+                 JOIN all_constraints r ON r.owner = c.r_owner AND r.constraint_name = c.r_constraint_name \
+                 JOIN all_cons_columns rc \
+                 ON rc.owner = r.owner AND rc.constraint_name = r.constraint_name AND rc.position = cc.position \
+                 WHERE c.constraint_type = 'R' AND (({}) OR ({})) \
+                 ORDER BY c.constraint_name, cc.position",
+                table_filter(table_name, "c.owner", "c.table_name"),
+                table_filter(table_name, "r.owner", "r.table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get foreign keys: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TableRelationship {
+                source_table: text(&row[1]),
+                source_column: text(&row[2]),
+                target_table: text(&row[3]),
+                target_column: text(&row[4]),
+                constraint_name: opt_text(&row[0]),
+            })
+            .collect())
+    }
+
+    /// Needs SELECT on DBA_USERS, DBA_ROLES and DBA_ROLE_PRIVS
+    async fn get_users_and_roles(&self, pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        let rows = client_for(pool)?
+            .rows(
+                "SELECT u.username, CASE WHEN u.account_status = 'OPEN' THEN 1 ELSE 0 END, \
+                 (SELECT LISTAGG(p.granted_role, ',') WITHIN GROUP (ORDER BY p.granted_role) \
+                 FROM dba_role_privs p WHERE p.grantee = u.username) \
+                 FROM dba_users u WHERE u.oracle_maintained = 'N' \
+                 UNION ALL SELECT r.role, 0, \
+                 (SELECT LISTAGG(p.granted_role, ',') WITHIN GROUP (ORDER BY p.granted_role) \
+                 FROM dba_role_privs p WHERE p.grantee = r.role) \
+                 FROM dba_roles r WHERE r.oracle_maintained = 'N' \
+                 ORDER BY 1",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get users: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let member_of: Vec<String> = opt_text(&row[2])
+                    .map(|roles| roles.split(',').map(str::to_string).collect())
+                    .unwrap_or_default();
+                DatabaseUser {
+                    name: text(&row[0]),
+                    host: None,
+                    can_login: number(&row[1]) == Some(1),
+                    is_superuser: member_of.iter().any(|role| role == "DBA"),
+                    member_of,
+                }
+            })
+            .collect())
+    }
+
+    /// Grants on the table the current user can see
+    async fn get_table_privileges(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT grantee, privilege, grantable, grantor FROM all_tab_privs \
+                 WHERE {} ORDER BY grantee, privilege",
+                table_filter(table_name, "table_schema", "table_name")
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get privileges: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TablePrivilege {
+                grantee: text(&row[0]),
+                privilege_type: text(&row[1]),
+                is_grantable: text(&row[2]) == "YES",
+                grantor: opt_text(&row[3]),
+                scope: PrivilegeScope::Table,
+            })
+            .collect())
+    }
+
+    /// Needs SELECT_CATALOG_ROLE; sizes are per tablespace
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let client = client_for(pool)?;
+        let rows = client
+            .rows(
+                "SELECT \
+                 (SELECT COUNT(*) FROM v$session WHERE type = 'USER'), \
+                 (SELECT TO_NUMBER(value) FROM v$parameter WHERE name = 'sessions'), \
+                 ROUND((SYSDATE - startup_time) * 86400), \
+                 (SELECT 1 - phy.value / NULLIF(cur.value + con.value, 0) \
+                 FROM v$sysstat phy, v$sysstat cur, v$sysstat con \
+                 WHERE phy.name = 'physical reads cache' AND cur.name = 'db block gets from cache' \
+                 AND con.name = 'consistent gets from cache') \
+                 FROM v$instance",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+        let row = rows
+            .first()
+            .ok_or_else(|| AppError::QueryError("Failed to get server stats: no rows returned".to_string()))?;
+
+        let sizes = client
+            .rows(
+                "SELECT tablespace_name, SUM(bytes) FROM dba_data_files \
+                 GROUP BY tablespace_name ORDER BY 2 DESC",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get tablespace sizes: {}", e)))?;
+
+        Ok(ServerStats {
+            active_connections: number(&row[0]).map(|v| v as u64),
+            max_connections: number(&row[1]).map(|v| v as u64),
+            uptime_seconds: number(&row[2]).map(|v| v as u64),
+            cache_hit_ratio: float(&row[3]),
+            database_sizes: sizes
+                .iter()
+                .map(|row| DatabaseSize {
+                    name: text(&row[0]),
+                    size_bytes: number(&row[1]).unwrap_or(0) as u64,
+                })
+                .collect(),
+        })
+    }
+
+    /// Session ids are `sid,serial#`, as ALTER SYSTEM KILL SESSION takes them
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let rows = client_for(pool)?
+            .rows(
+                "SELECT s.sid || ',' || s.serial#, s.username, s.service_name, s.machine, s.status, q.sql_text, \
+                 s.last_call_et * 1000 \
+                 FROM v$session s \
+                 LEFT JOIN v$sql q ON q.sql_id = s.sql_id AND q.child_number = s.sql_child_number \
+                 WHERE s.type = 'USER' AND s.status = 'ACTIVE' AND s.sid <> SYS_CONTEXT('USERENV', 'SID') \
+                 ORDER BY s.last_call_et DESC",
+            )
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ActiveQuery {
+                session_id: text(&row[0]),
+                user: opt_text(&row[1]),
+                database: opt_text(&row[2]),
+                client: opt_text(&row[3]),
+                state: opt_text(&row[4]),
+                query: opt_text(&row[5]),
+                duration_ms: number(&row[6]).map(|v| v.max(0) as u64),
+            })
+            .collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let valid = session_id
+            .split_once(',')
+            .is_some_and(|(sid, serial)| sid.parse::<u32>().is_ok() && serial.parse::<u32>().is_ok());
+        if !valid {
+            return Err(AppError::ValidationError(format!("Invalid session id: {}", session_id)));
+        }
+
+        let sql = format!("ALTER SYSTEM KILL SESSION '{}' IMMEDIATE", session_id);
+        Self::execute_ddl(client_for(pool)?, &sql, "kill session").await?;
+        Ok(())
+    }
+
+    /// Statements in the shared pool; times are in microseconds there
+    async fn get_slow_queries(
+        &self,
+        pool: PoolRef<'_>,
+        order: SlowQueryOrder,
+        limit: u32,
+    ) -> AppResult<Vec<SlowQueryEntry>> {
+        let order_by = match order {
+            SlowQueryOrder::TotalTime => "elapsed_time",
+            SlowQueryOrder::MeanTime => "elapsed_time / executions",
+            SlowQueryOrder::Calls => "executions",
+        };
+        let rows = client_for(pool)?
+            .rows(&format!(
+                "SELECT sql_text, executions, elapsed_time / 1000, elapsed_time / 1000 / executions, \
+                 rows_processed, parsing_schema_name \
+                 FROM v$sql \
+                 WHERE executions > 0 AND parsing_schema_name NOT IN ('SYS', 'SYSTEM') \
+                 ORDER BY {} DESC FETCH FIRST {} ROWS ONLY",
+                order_by, limit
+            ))
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get slow queries: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| SlowQueryEntry {
+                query: text(&row[0]),
+                calls: number(&row[1]).unwrap_or(0) as u64,
+                total_time_ms: float(&row[2]).unwrap_or(0.0),
+                mean_time_ms: float(&row[3]).unwrap_or(0.0),
+                max_time_ms: None,
+                rows: number(&row[4]).map(|v| v as u64),
+                database: opt_text(&row[5]),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,331 @@
+//! Postgres enums and composite types. The catalog entries of a result's
+//! user-defined types are looked up in pg_type, then values are decoded from
+//! their binary format: enums as their label, composites as JSON objects keyed
+//! by field name, with nested composites, domains and arrays of them expanded.
+
+use serde_json::{Map, Number, Value};
+use sqlx::postgres::PgConnection;
+use std::collections::HashMap;
+
+/// OIDs below this belong to built-in types
+const FIRST_USER_OID: u32 = 16384;
+
+/// Anonymous records such as ROW(1, 'a'), whose fields have no names
+const RECORD_OID: u32 = 2249;
+
+/// Levels of nested field types looked up
+const MAX_LOOKUP_DEPTH: usize = 8;
+
+enum UserType {
+    Enum,
+    /// Field names and type OIDs, in column order
+    Composite(Vec<(String, u32)>),
+    /// Arrays carry their element type in the value itself
+    Array,
+    /// A domain, decoded as its base type
+    Domain(u32),
+}
+
+/// The user-defined types of a result, by OID
+#[derive(Default)]
+pub struct UserTypes {
+    types: HashMap<u32, UserType>,
+}
+
+fn read_i16(bytes: &[u8], at: usize) -> Option<i16> {
+    Some(i16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_i32(bytes: &[u8], at: usize) -> Option<i32> {
+    Some(i32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn read_i64(bytes: &[u8], at: usize) -> Option<i64> {
+    Some(i64::from_be_bytes(bytes.get(at..at + 8)?.try_into().ok()?))
+}
+
+fn float(value: f64) -> Value {
+    Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+}
+
+/// NUMERIC: digit count, weight of the first digit, sign, display scale, then
+/// base-10000 digits
+fn numeric(bytes: &[u8]) -> Option<String> {
+    let count = read_i16(bytes, 0)?.max(0) as usize;
+    let weight = read_i16(bytes, 2)? as i32;
+    let sign = read_u16(bytes, 4)?;
+    let scale = read_u16(bytes, 6)? as usize;
+    match sign {
+        0xC000 => return Some("NaN".to_string()),
+        0xD000 => return Some("Infinity".to_string()),
+        0xF000 => return Some("-Infinity".to_string()),
+        _ => {}
+    }
+    let digits = (0..count).map(|i| read_i16(bytes, 8 + i * 2)).collect::<Option<Vec<_>>>()?;
+    let digit = |position: i32| usize::try_from(position).ok().and_then(|i| digits.get(i).copied()).unwrap_or(0);
+
+    let mut text = String::new();
+    if sign == 0x4000 {
+        text.push('-');
+    }
+    if weight < 0 {
+        text.push('0');
+    } else {
+        text.push_str(&digit(0).to_string());
+        for position in 1..=weight {
+            text.push_str(&format!("{:04}", digit(position)));
+        }
+    }
+    if scale > 0 {
+        let mut fraction = String::new();
+        let mut position = weight + 1;
+        while fraction.len() < scale {
+            fraction.push_str(&format!("{:04}", digit(position)));
+            position += 1;
+        }
+        fraction.truncate(scale);
+        text.push('.');
+        text.push_str(&fraction);
+    }
+    Some(text)
+}
+
+/// Microseconds since 2000-01-01, the epoch of timestamps and dates
+fn timestamp(micros: i64) -> Option<chrono::NaiveDateTime> {
+    chrono::NaiveDate::from_ymd_opt(2000, 1, 1)?
+        .and_hms_opt(0, 0, 0)?
+        .checked_add_signed(chrono::Duration::microseconds(micros))
+}
+
+/// Built-in types found inside composites, from their binary format
+fn builtin(oid: u32, bytes: &[u8]) -> Option<Value> {
+    Some(match oid {
+        // bool
+        16 => Value::Bool(*bytes.first()? != 0),
+        // bytea, as base64 like top-level columns
+        17 => {
+            use base64::{engine::general_purpose, Engine as _};
+            Value::String(general_purpose::STANDARD.encode(bytes))
+        }
+        // "char", name, text, bpchar, varchar, unknown
+        18 | 19 | 25 | 705 | 1042 | 1043 => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+        // int8, int2, int4, oid
+        20 => Value::from(read_i64(bytes, 0)?),
+        21 => Value::from(read_i16(bytes, 0)?),
+        23 => Value::from(read_i32(bytes, 0)?),
+        26 => Value::from(read_u32(bytes, 0)?),
+        // json, and jsonb after its version byte
+        114 => serde_json::from_slice(bytes).ok()?,
+        3802 => serde_json::from_slice(bytes.get(1..)?).ok()?,
+        // float4, float8
+        700 => float(f32::from_bits(read_u32(bytes, 0)?) as f64),
+        701 => float(f64::from_bits(read_i64(bytes, 0)? as u64)),
+        // numeric, as a string like top-level columns
+        1700 => Value::String(numeric(bytes)?),
+        // date: days since 2000-01-01
+        1082 => Value::String(match read_i32(bytes, 0)? {
+            i32::MAX => "infinity".to_string(),
+            i32::MIN => "-infinity".to_string(),
+            days => timestamp(days as i64 * 86_400_000_000)?.date().to_string(),
+        }),
+        // timestamp, timestamptz
+        1114 | 1184 => Value::String(match read_i64(bytes, 0)? {
+            i64::MAX => "infinity".to_string(),
+            i64::MIN => "-infinity".to_string(),
+            micros if oid == 1184 => timestamp(micros)?.and_utc().to_rfc3339(),
+            micros => timestamp(micros)?.to_string(),
+        }),
+        // uuid
+        2950 => Value::String(uuid::Uuid::from_slice(bytes).ok()?.to_string()),
+        _ => return None,
+    })
+}
+
+/// Types with no decoder: printable UTF-8 as a string, anything else as base64
+fn fallback(bytes: &[u8]) -> Value {
+    match std::str::from_utf8(bytes) {
+        Ok(text) if text.chars().all(|c| !c.is_control() || c.is_whitespace()) => Value::String(text.to_string()),
+        _ => {
+            use base64::{engine::general_purpose, Engine as _};
+            Value::String(format!("[base64: {}]", general_purpose::STANDARD.encode(bytes)))
+        }
+    }
+}
+
+/// Group row-major array elements into nested arrays, one level per dimension
+fn nest(mut items: Vec<Value>, lengths: &[usize]) -> Value {
+    for length in lengths.iter().skip(1).rev() {
+        items = items.chunks(*length).map(|chunk| Value::Array(chunk.to_vec())).collect();
+    }
+    Value::Array(items)
+}
+
+impl UserTypes {
+    /// Look up the user-defined types among `oids` and those of their fields
+    /// and elements. A failed lookup leaves those values to the generic decoding.
+    pub async fn load(conn: &mut PgConnection, oids: Vec<u32>) -> Self {
+        let mut user_types = Self::default();
+        let mut pending: Vec<u32> = oids.into_iter().filter(|oid| *oid >= FIRST_USER_OID).collect();
+        if pending.is_empty() {
+            return user_types;
+        }
+        pending.sort_unstable();
+        pending.dedup();
+
+        for _ in 0..MAX_LOOKUP_DEPTH {
+            if pending.is_empty() {
+                break;
+            }
+            match user_types.lookup(conn, &pending).await {
+                Ok(referenced) => pending = referenced,
+                Err(e) => {
+                    tracing::debug!(error = %e, "Failed to look up user-defined types");
+                    break;
+                }
+            }
+        }
+        user_types
+    }
+
+    /// Record the enums, composites, domains and arrays among `oids`; returns
+    /// the user-defined types they refer to that are not known yet
+    async fn lookup(&mut self, conn: &mut PgConnection, oids: &[u32]) -> Result<Vec<u32>, sqlx::Error> {
+        let oids: Vec<i64> = oids.iter().map(|oid| *oid as i64).collect();
+        let types: Vec<(i64, String, String, i64, i64, i64)> = sqlx::query_as(
+            "SELECT oid::int8, typtype::text, typcategory::text, typrelid::int8, typelem::int8, typbasetype::int8 \
+             FROM pg_catalog.pg_type WHERE oid::int8 = ANY($1)",
+        )
+        .bind(&oids)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let mut referenced = Vec::new();
+        let mut relations = HashMap::new();
+        for (oid, kind, category, relation, element, base) in types {
+            let oid = oid as u32;
+            match (kind.as_str(), category.as_str()) {
+                ("e", _) => {
+                    self.types.insert(oid, UserType::Enum);
+                }
+                ("c", _) => {
+                    self.types.insert(oid, UserType::Composite(Vec::new()));
+                    relations.insert(relation, oid);
+                }
+                ("d", _) => {
+                    self.types.insert(oid, UserType::Domain(base as u32));
+                    referenced.push(base as u32);
+                }
+                (_, "A") => {
+                    self.types.insert(oid, UserType::Array);
+                    referenced.push(element as u32);
+                }
+                _ => {}
+            }
+        }
+
+        if !relations.is_empty() {
+            let relation_oids: Vec<i64> = relations.keys().copied().collect();
+            let fields: Vec<(i64, String, i64)> = sqlx::query_as(
+                "SELECT attrelid::int8, attname::text, atttypid::int8 FROM pg_catalog.pg_attribute \
+                 WHERE attrelid::int8 = ANY($1) AND attnum > 0 AND NOT attisdropped ORDER BY attrelid, attnum",
+            )
+            .bind(&relation_oids)
+            .fetch_all(&mut *conn)
+            .await?;
+            for (relation, name, field_oid) in fields {
+                let field_oid = field_oid as u32;
+                if let Some(UserType::Composite(fields)) = relations.get(&relation).and_then(|oid| self.types.get_mut(oid)) {
+                    fields.push((name, field_oid));
+                }
+                referenced.push(field_oid);
+            }
+        }
+
+        referenced.retain(|oid| *oid >= FIRST_USER_OID && !self.types.contains_key(oid));
+        referenced.sort_unstable();
+        referenced.dedup();
+        Ok(referenced)
+    }
+
+    /// Decode a value of a user-defined type or an anonymous record from its
+    /// binary format; None for other types
+    pub fn decode(&self, oid: u32, bytes: &[u8]) -> Option<Value> {
+        if oid != RECORD_OID && !self.types.contains_key(&oid) {
+            return None;
+        }
+        Some(self.value(oid, bytes))
+    }
+
+    fn value(&self, oid: u32, bytes: &[u8]) -> Value {
+        let decoded = match self.types.get(&oid) {
+            Some(UserType::Enum) => Some(Value::String(String::from_utf8_lossy(bytes).into_owned())),
+            Some(UserType::Composite(fields)) => self.record(bytes, Some(fields)),
+            Some(UserType::Array) => self.array(bytes),
+            Some(UserType::Domain(base)) => Some(self.value(*base, bytes)),
+            None if oid == RECORD_OID => self.record(bytes, None),
+            None => builtin(oid, bytes),
+        };
+        decoded.unwrap_or_else(|| fallback(bytes))
+    }
+
+    /// A length-prefixed value at `at` (NULL when the length is -1), and the
+    /// position after it
+    fn element(&self, oid: u32, bytes: &[u8], at: usize) -> Option<(Value, usize)> {
+        let length = read_i32(bytes, at)?;
+        if length < 0 {
+            return Some((Value::Null, at + 4));
+        }
+        let end = at + 4 + length as usize;
+        Some((self.value(oid, bytes.get(at + 4..end)?), end))
+    }
+
+    /// Field count, then each field's type OID and value. Fields of anonymous
+    /// records are named f1, f2, ... as Postgres names them.
+    fn record(&self, bytes: &[u8], fields: Option<&[(String, u32)]>) -> Option<Value> {
+        let count = read_i32(bytes, 0)?.max(0) as usize;
+        let mut object = Map::new();
+        let mut at = 4;
+        for i in 0..count {
+            let oid = read_u32(bytes, at)?;
+            let (value, next) = self.element(oid, bytes, at + 4)?;
+            at = next;
+            let name = fields
+                .and_then(|fields| fields.get(i))
+                .map(|(name, _)| name.clone())
+                .unwrap_or_else(|| format!("f{}", i + 1));
+            object.insert(name, value);
+        }
+        Some(Value::Object(object))
+    }
+
+    /// Dimension count, a has-NULLs flag, the element type OID, each
+    /// dimension's length and lower bound, then the elements in row-major order
+    fn array(&self, bytes: &[u8]) -> Option<Value> {
+        let dimensions = read_i32(bytes, 0)?.max(0) as usize;
+        let element_oid = read_u32(bytes, 8)?;
+        let lengths = (0..dimensions)
+            .map(|d| read_i32(bytes, 12 + d * 8).map(|length| length.max(0) as usize))
+            .collect::<Option<Vec<_>>>()?;
+        if dimensions == 0 || lengths.contains(&0) {
+            return Some(Value::Array(Vec::new()));
+        }
+
+        let mut items = Vec::new();
+        let mut at = 12 + dimensions * 8;
+        let count = lengths.iter().try_fold(1usize, |count, length| count.checked_mul(*length))?;
+        for _ in 0..count {
+            let (value, next) = self.element(element_oid, bytes, at)?;
+            items.push(value);
+            at = next;
+        }
+        Some(nest(items, &lengths))
+    }
+}
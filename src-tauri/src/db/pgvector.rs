@@ -0,0 +1,103 @@
+//! pgvector types. Their binary format is decoded into JSON (`vector` and
+//! `halfvec` as arrays of numbers, `sparsevec` in its text form), and vectors
+//! are written as literals for nearest-neighbor queries.
+
+use crate::error::{AppError, AppResult};
+use crate::models::VectorMetric;
+use serde_json::Value;
+
+/// A float as JSON, printed at its own precision so 0.1f32 stays 0.1
+fn float_value(value: f32) -> Value {
+    value
+        .to_string()
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+fn read_f32(bytes: &[u8], at: usize) -> Option<f32> {
+    read_u32(bytes, at).map(f32::from_bits)
+}
+
+/// IEEE 754 half precision to single precision
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1f) as i32;
+    let fraction = (bits & 0x3ff) as f32;
+    match exponent {
+        0 => sign * fraction * 2f32.powi(-24),
+        0x1f if fraction == 0.0 => sign * f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => sign * (1.0 + fraction / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+/// Decode a pgvector value in binary format; None for other types or
+/// malformed data
+pub fn decode(type_name: &str, bytes: &[u8]) -> Option<Value> {
+    match type_name {
+        // dim u16, unused u16, dim f32
+        "vector" => {
+            let dim = read_u16(bytes, 0)? as usize;
+            (0..dim)
+                .map(|i| read_f32(bytes, 4 + i * 4).map(float_value))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array)
+        }
+        // dim u16, unused u16, dim f16
+        "halfvec" => {
+            let dim = read_u16(bytes, 0)? as usize;
+            (0..dim)
+                .map(|i| read_u16(bytes, 4 + i * 2).map(|bits| float_value(f16_to_f32(bits))))
+                .collect::<Option<Vec<_>>>()
+                .map(Value::Array)
+        }
+        // dim i32, nnz i32, unused i32, nnz zero-based indices, nnz f32
+        "sparsevec" => {
+            let dim = read_u32(bytes, 0)?;
+            let nnz = read_u32(bytes, 4)? as usize;
+            let values_at = 12 + nnz * 4;
+            let elements = (0..nnz)
+                .map(|i| {
+                    let index = read_u32(bytes, 12 + i * 4)?;
+                    let value = read_f32(bytes, values_at + i * 4)?;
+                    Some(format!("{}:{}", index + 1, value))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(Value::String(format!("{{{}}}/{}", elements.join(","), dim)))
+        }
+        _ => None,
+    }
+}
+
+/// A vector as a `'[...]'::vector` literal
+pub fn literal(vector: &[f32]) -> AppResult<String> {
+    if vector.is_empty() {
+        return Err(AppError::ValidationError("The vector is empty".to_string()));
+    }
+    if vector.iter().any(|value| !value.is_finite()) {
+        return Err(AppError::ValidationError("The vector must hold only finite numbers".to_string()));
+    }
+    let values: Vec<String> = vector.iter().map(f32::to_string).collect();
+    Ok(format!("'[{}]'::vector", values.join(",")))
+}
+
+/// The pgvector distance operator for a metric
+pub fn operator(metric: VectorMetric) -> &'static str {
+    match metric {
+        VectorMetric::L2 => "<->",
+        VectorMetric::Cosine => "<=>",
+        VectorMetric::InnerProduct => "<#>",
+        VectorMetric::L1 => "<+>",
+    }
+}
@@ -1,15 +1,33 @@
-use crate::db::{DatabaseDriver, PoolRef};
+use crate::db::{
+    pgvector, append_query_params, column_definition, combine_result_sets, insert_statement, placeholder, postgres_tls_params, quote_identifier,
+    quote_qualified, string_literal, BatchHandler, DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits, RowBatches,
+};
+use crate::db::pg_types::UserTypes;
 use crate::error::{AppError, AppResult};
 use crate::models::{
-    ConnectionConfig, ConstraintInfo, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo,
-    QueryResult, TableInfo, TableProperties, TableRelationship, TableSchema,
-    TestConnectionResult, ColumnInfo
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseDdlOptions, DatabaseUser, PrivilegeScope,
+    DatabaseType, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo, NewColumn, QueryResult, ResultSet, TableInfo,
+    TableProperties, TablePrivilege, TableRelationship, TableSchema, TestConnectionResult, TruncateOptions,
+    ColumnInfo, ActiveQuery, ServerStats, DatabaseSize, TableStats, ObjectComment, SchemaComments, SlowQueryEntry,
+    SlowQueryOrder, PartitionInfo, TablePartitioning, ObjectDependency
 };
+use crate::sql::{tokenize, TokenKind};
 use async_trait::async_trait;
-use sqlx::{postgres::PgPool, Row, Column, ValueRef};
+use futures_util::{StreamExt, TryStreamExt};
+use sql_parse::Dialect;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{
+    PgArgumentBuffer, PgArguments, PgColumn, PgConnection, PgPool, PgRow, PgTypeInfo, PgTypeKind, PgValueFormat, Postgres,
+};
+use sqlx::pool::PoolConnection;
+use sqlx::query::Query;
+use sqlx::{Acquire, Row, Column, Executor, TypeInfo, ValueRef};
 use std::collections::HashMap;
 use std::time::Instant;
 
+type PgQuery<'q> = Query<'q, Postgres, PgArguments>;
+
 pub struct PostgresDriver;
 
 /// Base64 encode binary data
@@ -18,11 +36,111 @@ fn base64_encode(data: &[u8]) -> String {
     general_purpose::STANDARD.encode(data)
 }
 
+/// A parameter sent in text form for types whose binary format is their text
+/// form (text, varchar, enums, citext, json, ...), tagged with the expected type.
+/// Other types reach it only through a placeholder cast from text; see
+/// `PostgresDriver::cast_text_params`.
+struct PgTextParam {
+    type_info: PgTypeInfo,
+    value: Option<String>,
+}
+
+impl sqlx::Type<Postgres> for PgTextParam {
+    fn type_info() -> PgTypeInfo {
+        <String as sqlx::Type<Postgres>>::type_info()
+    }
+}
+
+impl sqlx::Encode<'_, Postgres> for PgTextParam {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        match &self.value {
+            Some(value) => {
+                buf.extend_from_slice(value.as_bytes());
+                Ok(IsNull::No)
+            }
+            None => Ok(IsNull::Yes),
+        }
+    }
+
+    fn produces(&self) -> Option<PgTypeInfo> {
+        Some(self.type_info.clone())
+    }
+}
+
+/// Types `bind_param` binds in their own binary format, by name
+const BINARY_PARAM_TYPES: &[&str] = &[
+    "BOOL", "INT2", "INT4", "INT8", "FLOAT4", "FLOAT8", "NUMERIC", "UUID", "DATE", "TIME", "TIMESTAMP", "TIMESTAMPTZ",
+    "BYTEA", "INET", "CIDR", "MACADDR", "JSONB", "TEXT[]", "VARCHAR[]", "BPCHAR[]", "NAME[]", "BOOL[]", "INT2[]",
+    "INT4[]", "INT8[]", "FLOAT4[]", "FLOAT8[]", "NUMERIC[]", "UUID[]",
+];
+
+/// Types whose binary format is their text, so text bytes can be sent as they are
+const TEXT_PARAM_TYPES: &[&str] = &["TEXT", "VARCHAR", "BPCHAR", "NAME", "CHAR", "\"CHAR\"", "CITEXT", "JSON", "XML", "UNKNOWN"];
+
+/// Whether a parameter of this type can be bound directly; the rest are sent
+/// as text and cast by the server
+fn binds_directly(type_info: &PgTypeInfo) -> bool {
+    let name = type_info.name().to_uppercase();
+    match type_info.kind() {
+        PgTypeKind::Enum(_) => true,
+        PgTypeKind::Domain(base) => binds_directly(base),
+        _ => BINARY_PARAM_TYPES.contains(&name.as_str()) || TEXT_PARAM_TYPES.contains(&name.as_str()),
+    }
+}
+
+/// Text form of a JSON parameter (strings are used as-is)
+fn param_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Interpret a JSON parameter as a boolean
+fn param_bool(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(b) => Some(*b),
+        serde_json::Value::Number(n) => n.as_i64().map(|n| n != 0),
+        serde_json::Value::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "t" | "yes" | "y" | "on" | "1" => Some(true),
+            "false" | "f" | "no" | "n" | "off" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Parse a JSON array (or a string holding one) with `parse` applied to each element
+fn param_array<T>(value: &serde_json::Value, parse: impl Fn(&serde_json::Value) -> Option<T>) -> Option<Vec<Option<T>>> {
+    let items = match value {
+        serde_json::Value::Array(items) => items.clone(),
+        serde_json::Value::String(s) => serde_json::from_str::<Vec<serde_json::Value>>(s).ok()?,
+        _ => return None,
+    };
+
+    items
+        .iter()
+        .map(|item| if item.is_null() { Some(None) } else { parse(item).map(Some) })
+        .collect()
+}
+
+/// Split `pg_get_partkeydef` output such as `RANGE (created_at)` into strategy and key
+fn split_partition_key(definition: &str) -> (String, String) {
+    match definition.split_once(' ') {
+        Some((strategy, key)) => {
+            let key = key.trim();
+            let key = key.strip_prefix('(').and_then(|k| k.strip_suffix(')')).unwrap_or(key);
+            (strategy.to_string(), key.to_string())
+        }
+        None => (definition.to_string(), String::new()),
+    }
+}
+
 /// Helper methods for PostgresDriver
 impl PostgresDriver {
     /// Convert a PostgreSQL row value at a given index to a JSON value
     /// Handles all PostgreSQL data types comprehensively
-    fn pg_value_to_json(row: &sqlx::postgres::PgRow, idx: usize) -> serde_json::Value {
+    fn pg_value_to_json(row: &sqlx::postgres::PgRow, idx: usize, user_types: &UserTypes) -> serde_json::Value {
         use sqlx::postgres::types::{PgInterval, PgMoney};
 
         // Check for NULL first
@@ -30,6 +148,14 @@ impl PostgresDriver {
             if raw.is_null() {
                 return serde_json::Value::Null;
             }
+
+            // Enums, composites and arrays of them, from their catalog entries
+            if raw.format() == PgValueFormat::Binary {
+                let oid = raw.type_info().oid().map(|oid| oid.0);
+                if let Some(value) = oid.and_then(|oid| user_types.decode(oid, raw.as_bytes().unwrap_or(&[]))) {
+                    return value;
+                }
+            }
         }
 
         // Try each type in order of likelihood
@@ -182,8 +308,17 @@ impl PostgresDriver {
             );
         }
 
+        // pgvector columns, in their binary format
+        if let Ok(raw) = row.try_get_raw(idx) {
+            if raw.format() == PgValueFormat::Binary {
+                if let Some(value) = pgvector::decode(raw.type_info().name(), raw.as_bytes().unwrap_or(&[])) {
+                    return value;
+                }
+            }
+        }
+
         // Generic fallback: use the raw value and convert to string
-        // This handles enums, composite types, tsquery, tsvector, and any other custom types
+        // This handles tsquery, tsvector, and any other custom types
         match row.try_get_raw(idx) {
             Ok(raw) => {
                 if raw.is_null() {
@@ -208,151 +343,46 @@ impl PostgresDriver {
         }
     }
 
-    /// Safely split SQL into individual statements, handling quotes and comments
-    fn split_sql_statements(sql: &str) -> Vec<String> {
-        let mut statements = Vec::new();
-        let mut current = String::new();
-        let mut chars = sql.chars().peekable();
-        let mut in_single_quote = false;
-        let mut in_double_quote = false;
-        let mut in_backtick = false;
-        let mut in_line_comment = false;
-        let mut in_block_comment = false;
-
-        while let Some(c) = chars.next() {
-            match c {
-                '\'' if !in_double_quote && !in_backtick && !in_line_comment && !in_block_comment => {
-                    // Handle PostgreSQL escaped quotes ('') inside string literals
-                    if in_single_quote && chars.peek() == Some(&'\'') {
-                        // It's an escaped quote, consume both and treat as a literal
-                        current.push(c);
-                        current.push(chars.next().unwrap());
-                        // Stay in single quote mode
-                    } else {
-                        in_single_quote = !in_single_quote;
-                        current.push(c);
-                    }
-                }
-                '"' if !in_single_quote && !in_backtick && !in_line_comment && !in_block_comment => {
-                    in_double_quote = !in_double_quote;
-                    current.push(c);
-                }
-                '`' if !in_single_quote && !in_double_quote && !in_line_comment && !in_block_comment => {
-                    in_backtick = !in_backtick;
-                    current.push(c);
-                }
-                '-' if !in_single_quote && !in_double_quote && !in_backtick && !in_line_comment && !in_block_comment => {
-                    if let Some(&'-') = chars.peek() {
-                        chars.next();
-                        in_line_comment = true;
-                    } else {
-                        current.push(c);
-                    }
-                }
-                '\n' if in_line_comment => {
-                    in_line_comment = false;
-                }
-                '/' if !in_single_quote && !in_double_quote && !in_backtick && !in_line_comment && !in_block_comment => {
-                    if let Some(&'*') = chars.peek() {
-                        chars.next();
-                        in_block_comment = true;
-                    } else {
-                        current.push(c);
-                    }
-                }
-                '*' if in_block_comment => {
-                    if let Some(&'/') = chars.peek() {
-                        chars.next();
-                        in_block_comment = false;
-                    }
-                }
-                ';' if !in_single_quote && !in_double_quote && !in_backtick && !in_line_comment && !in_block_comment => {
-                    let trimmed = current.trim().to_string();
-                    if !trimmed.is_empty() {
-                        statements.push(trimmed);
-                    }
-                    current.clear();
-                }
-                _ if !in_line_comment && !in_block_comment => {
-                    current.push(c);
-                }
-                _ => {
-                    // Skip characters in comments
-                }
-            }
-        }
-
-        let trimmed = current.trim().to_string();
-        if !trimmed.is_empty() {
-            statements.push(trimmed);
+    /// Fetch the rows of a query, stopping after `max_rows`
+    async fn fetch_rows(conn: &mut PgConnection, sql: &str, max_rows: Option<usize>) -> AppResult<Vec<PgRow>> {
+        let rows = sqlx::query(sql).fetch(conn);
+        match max_rows {
+            Some(max_rows) => rows.take(max_rows).try_collect().await,
+            None => rows.try_collect().await,
         }
-
-        statements
+        .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))
     }
 
     /// Execute a single SQL statement
-    async fn execute_single_query(&self, pool_ref: PoolRef<'_>, sql: &str, start: Instant) -> AppResult<QueryResult> {
-        let pool = match pool_ref {
-            PoolRef::Postgres(p) => p,
-            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
-        };
-
-        // Check if it's a SELECT query, handling comments
-        let mut clean_sql = sql.trim();
-        while clean_sql.starts_with("--") || clean_sql.starts_with("/*") {
-            if clean_sql.starts_with("--") {
-                if let Some(newline_pos) = clean_sql.find('\n') {
-                    clean_sql = clean_sql[newline_pos..].trim();
-                } else {
-                    clean_sql = "";
-                    break;
-                }
-            } else if clean_sql.starts_with("/*") {
-                if let Some(end_pos) = clean_sql.find("*/") {
-                    clean_sql = clean_sql[end_pos + 2..].trim();
-                } else {
-                    break;
-                }
-            }
-        }
-
-        let sql_upper = clean_sql.to_uppercase();
-        let is_select = sql_upper.starts_with("SELECT") || sql_upper.starts_with("WITH");
+    async fn execute_single_query(
+        &self,
+        conn: &mut PgConnection,
+        sql: &str,
+        start: Instant,
+        max_rows: Option<usize>,
+    ) -> AppResult<QueryResult> {
+        let is_select = sql_parse::returns_rows(sql, Dialect::Postgres);
 
         if is_select {
             // Execute as query and fetch results
-            let rows = sqlx::query(sql)
-                .fetch_all(pool)
-                .await
-                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-
-            if rows.is_empty() {
-                return Ok(QueryResult {
-                    columns: vec![],
-                    rows: vec![],
-                    affected_rows: None,
-                    execution_time_ms: start.elapsed().as_millis() as u64,
-                });
-            }
-
-            // Get column names from first row
-            let columns: Vec<ColumnInfo> = rows[0]
-                .columns()
-                .iter()
-                .map(|col| ColumnInfo {
-                    name: col.name().to_string(),
-                    data_type: "unknown".to_string(), // Will be filled from schema if needed
-                    nullable: true,
-                    is_primary_key: false,
-                })
-                .collect();
+            let rows = Self::fetch_rows(&mut *conn, sql, max_rows).await?;
+
+            // Describing the statement gives nullability and columns even when no rows come back
+            let columns = match (&mut *conn).describe(sql).await {
+                Ok(describe) => Self::result_columns(describe.columns(), |i| describe.nullable(i)),
+                Err(_) => rows
+                    .first()
+                    .map(|row| Self::result_columns(row.columns(), |_| None))
+                    .unwrap_or_default(),
+            };
 
             // Convert rows to JSON values
+            let user_types = UserTypes::load(&mut *conn, columns.iter().filter_map(|col| col.type_oid).collect()).await;
             let json_rows: Vec<Vec<serde_json::Value>> = rows
                 .iter()
                 .map(|row| {
                     (0..columns.len())
-                        .map(|i| Self::pg_value_to_json(row, i))
+                        .map(|i| Self::pg_value_to_json(row, i, &user_types))
                         .collect()
                 })
                 .collect();
@@ -362,11 +392,12 @@ impl PostgresDriver {
                 rows: json_rows,
                 affected_rows: None,
                 execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
             })
         } else {
             // Execute as execute (INSERT, UPDATE, DELETE, CREATE, DROP, etc.)
             let result = sqlx::query(sql)
-                .execute(pool)
+                .execute(&mut *conn)
                 .await
                 .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
 
@@ -375,132 +406,70 @@ impl PostgresDriver {
                 rows: vec![],
                 affected_rows: Some(result.rows_affected()),
                 execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
             })
         }
     }
-}
-
-#[async_trait]
-impl DatabaseDriver for PostgresDriver {
-    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
-        let connection_string = self.build_connection_string(config);
-        
-        let pool = PgPool::connect(&connection_string).await
-            .map_err(|e| AppError::ConnectionError(format!("PostgreSQL connection failed: {}", e)))?;
-        
-        // Get server version
-        let version: String = sqlx::query_scalar("SELECT version()")
-            .fetch_one(&pool)
-            .await
-            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?;
-        
-        pool.close().await;
-        
-        Ok(TestConnectionResult {
-            success: true,
-            message: format!("PostgreSQL connection to {} successful", config.database),
-            server_version: Some(version),
-        })
-    }
-
-    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
-        let pool = match pool {
-            PoolRef::Postgres(p) => p,
-            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
-        };
 
+    /// Execute SQL on one connection; several statements run in a transaction
+    async fn execute_statements(
+        &self,
+        conn: &mut PgConnection,
+        sql: &str,
+        max_rows: Option<usize>,
+    ) -> AppResult<QueryResult> {
         let start = Instant::now();
 
         // Split SQL into individual statements
-        let statements = Self::split_sql_statements(sql);
+        let statements = sql_parse::split_statements(sql, Dialect::Postgres);
 
         // If there's only one statement, execute it directly (original behavior)
         if statements.len() == 1 {
-            return self.execute_single_query(PoolRef::Postgres(pool), &statements[0], start).await;
+            return self.execute_single_query(conn, &statements[0], start, max_rows).await;
         }
 
         // Execute multiple statements in a transaction
         // Start transaction
-        let mut tx = pool.begin().await
+        let mut tx = conn.begin().await
             .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
 
-        let execution_result: AppResult<QueryResult> = async {
-            let mut final_result = QueryResult {
-                columns: vec![],
-                rows: vec![],
-                affected_rows: None,
-                execution_time_ms: 0,
-            };
+        let execution_result: AppResult<Vec<ResultSet>> = async {
+            let mut result_sets = Vec::with_capacity(statements.len());
 
-            for (i, stmt) in statements.iter().enumerate() {
+            for stmt in &statements {
                 let stmt_start = Instant::now();
 
-                // Execute the statement directly on the transaction
-                let clean_sql = stmt.trim();
-                let mut check_sql = clean_sql;
-                while check_sql.starts_with("--") || check_sql.starts_with("/*") {
-                    if check_sql.starts_with("--") {
-                        if let Some(newline_pos) = check_sql.find('\n') {
-                            check_sql = check_sql[newline_pos..].trim();
-                        } else {
-                            check_sql = "";
-                            break;
-                        }
-                    } else if check_sql.starts_with("/*") {
-                        if let Some(end_pos) = check_sql.find("*/") {
-                            check_sql = check_sql[end_pos + 2..].trim();
-                        } else {
-                            break;
-                        }
-                    }
-                }
-
-                let sql_upper = check_sql.to_uppercase();
-                let is_select = sql_upper.starts_with("SELECT") || sql_upper.starts_with("WITH");
+                let is_select = sql_parse::returns_rows(stmt, Dialect::Postgres);
 
-                let result = if is_select {
+                let result_set = if is_select {
                     // Execute SELECT and fetch results
-                    let rows = sqlx::query(stmt)
-                        .fetch_all(&mut *tx)
-                        .await
-                        .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-
-                    if rows.is_empty() {
-                        QueryResult {
-                            columns: vec![],
-                            rows: vec![],
-                            affected_rows: None,
-                            execution_time_ms: stmt_start.elapsed().as_millis() as u64,
-                        }
-                    } else {
-                        // Get column names from first row
-                        let columns: Vec<ColumnInfo> = rows[0]
-                            .columns()
-                            .iter()
-                            .map(|col| ColumnInfo {
-                                name: col.name().to_string(),
-                                data_type: "unknown".to_string(),
-                                nullable: true,
-                                is_primary_key: false,
-                            })
-                            .collect();
-
-                        // Convert rows to JSON values
-                        let json_rows: Vec<Vec<serde_json::Value>> = rows
-                            .iter()
-                            .map(|row| {
-                                (0..columns.len())
-                                    .map(|idx| Self::pg_value_to_json(row, idx))
-                                    .collect()
-                            })
-                            .collect();
-
-                        QueryResult {
-                            columns,
-                            rows: json_rows,
-                            affected_rows: None,
-                            execution_time_ms: stmt_start.elapsed().as_millis() as u64,
-                        }
+                    let rows = Self::fetch_rows(&mut tx, stmt, max_rows).await?;
+
+                    let columns = match (&mut *tx).describe(stmt).await {
+                        Ok(describe) => Self::result_columns(describe.columns(), |i| describe.nullable(i)),
+                        Err(_) => rows
+                            .first()
+                            .map(|row| Self::result_columns(row.columns(), |_| None))
+                            .unwrap_or_default(),
+                    };
+
+                    // Convert rows to JSON values
+                    let user_types = UserTypes::load(&mut tx, columns.iter().filter_map(|col| col.type_oid).collect()).await;
+                    let json_rows: Vec<Vec<serde_json::Value>> = rows
+                        .iter()
+                        .map(|row| {
+                            (0..columns.len())
+                                .map(|idx| Self::pg_value_to_json(row, idx, &user_types))
+                                .collect()
+                        })
+                        .collect();
+
+                    ResultSet {
+                        statement: stmt.clone(),
+                        columns,
+                        rows: json_rows,
+                        affected_rows: None,
+                        execution_time_ms: stmt_start.elapsed().as_millis() as u64,
                     }
                 } else {
                     // Execute INSERT, UPDATE, DELETE, CREATE, DROP, etc.
@@ -509,7 +478,8 @@ impl DatabaseDriver for PostgresDriver {
                         .await
                         .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
 
-                    QueryResult {
+                    ResultSet {
+                        statement: stmt.clone(),
                         columns: vec![],
                         rows: vec![],
                         affected_rows: Some(execute_result.rows_affected()),
@@ -517,37 +487,17 @@ impl DatabaseDriver for PostgresDriver {
                     }
                 };
 
-                // Keep track of total affected rows and the last query result
-                if let Some(affected) = result.affected_rows {
-                    if let Some(total) = final_result.affected_rows {
-                        final_result.affected_rows = Some(total + affected);
-                    } else {
-                        final_result.affected_rows = Some(affected);
-                    }
-                }
-
-                // Use the last SELECT query's results as the final result
-                if result.rows.len() > 0 {
-                    // Save accumulated affected_rows before replacing result
-                    let accumulated_affected = final_result.affected_rows;
-                    final_result = result;
-                    // Restore accumulated affected_rows
-                    final_result.affected_rows = accumulated_affected;
-                } else if i == statements.len() - 1 && final_result.rows.is_empty() {
-                    // If no SELECT queries, use the last result
-                    final_result = result;
-                }
+                result_sets.push(result_set);
             }
-            Ok(final_result)
+            Ok(result_sets)
         }.await;
 
         // Commit or rollback based on execution result
         match execution_result {
-            Ok(mut result) => {
+            Ok(result_sets) => {
                 tx.commit().await
                     .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
-                result.execution_time_ms = start.elapsed().as_millis() as u64;
-                Ok(result)
+                Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64))
             }
             Err(e) => {
                 tx.rollback().await
@@ -563,56 +513,242 @@ impl DatabaseDriver for PostgresDriver {
         }
     }
 
-    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
-        let pool = match pool {
-            PoolRef::Postgres(p) => p,
-            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+    /// Execute SQL typed by the user within `limits` on a connection the
+    /// caller holds, such as an editor tab's session
+    pub async fn execute_on_connection(
+        &self,
+        conn: &mut PoolConnection<Postgres>,
+        sql: &str,
+        limits: &QueryLimits,
+    ) -> AppResult<QueryResult> {
+        let Some(timeout_ms) = limits.statement_timeout_ms else {
+            return self.execute_statements(conn, sql, limits.max_rows).await;
         };
 
-        let query = r#"
-            SELECT 
-                table_name::text as table_name,
-                table_schema::text as table_schema,
-                'BASE TABLE'::text as table_type
-            FROM information_schema.tables
-            WHERE table_schema NOT IN ('pg_catalog', 'information_schema')
-            AND table_type = 'BASE TABLE'
-            ORDER BY table_schema, table_name
-        "#;
-        
-        let rows = sqlx::query(query)
-            .fetch_all(pool)
+        conn.execute(format!("SET statement_timeout = {}", timeout_ms).as_str())
             .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get tables: {}", e)))?;
-        
-        let tables: Vec<TableInfo> = rows
+            .map_err(|e| AppError::QueryError(format!("Failed to set statement timeout: {}", e)))?;
+        let result = self.execute_statements(conn, sql, limits.max_rows).await;
+
+        // The connection goes back to the pool, so it must not keep the timeout
+        if conn.execute("RESET statement_timeout").await.is_err() {
+            conn.close_on_drop();
+        }
+        result
+    }
+
+    /// `stream_query` on a connection the caller holds
+    pub async fn stream_on_connection(
+        &self,
+        conn: &mut PoolConnection<Postgres>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let columns = match (&mut **conn).describe(sql).await {
+            Ok(describe) => Self::result_columns(describe.columns(), |i| describe.nullable(i)),
+            Err(_) => vec![],
+        };
+        let user_types = UserTypes::load(conn, columns.iter().filter_map(|col| col.type_oid).collect()).await;
+
+        let mut batches = RowBatches::new(on_batch, columns, batch_size);
+        let mut rows = sqlx::query(sql).fetch(&mut **conn);
+        while let Some(row) = rows.try_next().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+        {
+            if batches.columns.is_empty() {
+                batches.columns = Self::result_columns(row.columns(), |_| None);
+            }
+            batches.push((0..row.len()).map(|i| Self::pg_value_to_json(&row, i, &user_types)).collect())?;
+        }
+        batches.finish()
+    }
+
+    /// Build result column metadata from the types Postgres reports
+    fn result_columns(columns: &[PgColumn], nullable: impl Fn(usize) -> Option<bool>) -> Vec<ColumnInfo> {
+        columns
             .iter()
-            .map(|row| {
-                let schema: Option<String> = row.try_get("table_schema").ok();
-                let name: String = row.get("table_name");
-                let full_name = if let Some(schema) = &schema {
-                    format!("{}.{}", schema, name)
-                } else {
-                    name.clone()
-                };
-                
-                TableInfo {
-                    name: full_name,
-                    schema,
-                    table_type: "BASE TABLE".to_string(),
-                    row_count: None, // Could be added with COUNT query if needed
+            .enumerate()
+            .map(|(i, col)| {
+                let type_info = col.type_info();
+                ColumnInfo {
+                    name: col.name().to_string(),
+                    data_type: type_info.name().to_lowercase(),
+                    nullable: nullable(i).unwrap_or(true),
+                    is_primary_key: false,
+                    native_type: Some(type_info.name().to_string()),
+                    type_oid: type_info.oid().map(|oid| oid.0),
                 }
             })
+            .collect()
+    }
+
+    /// Rewrite the placeholders of parameters that cannot be bound directly
+    /// (interval, money, bit, ranges, geometric types, ...) as `($n::text::type)`,
+    /// so their values are sent as text and converted by the server. Returns the
+    /// SQL and the type to bind each parameter as.
+    async fn cast_text_params(pool: &PgPool, sql: &str, param_types: Vec<PgTypeInfo>) -> AppResult<(String, Vec<PgTypeInfo>)> {
+        let cast: Vec<usize> = (0..param_types.len()).filter(|&i| !binds_directly(&param_types[i])).collect();
+        if cast.is_empty() {
+            return Ok((sql.to_string(), param_types));
+        }
+
+        let oids: Vec<i64> = cast
+            .iter()
+            .map(|&i| param_types[i].oid().map_or(0, |oid| oid.0 as i64))
             .collect();
-        
-        Ok(tables)
+        // Without a length, bit means bit(1); bit varying is assigned to bit(n) unchanged
+        let names: Vec<String> = sqlx::query_scalar(
+            "SELECT CASE WHEN t::oid = 'bit'::regtype THEN 'bit varying' ELSE format_type(t::oid, NULL) END \
+             FROM unnest($1::int8[]) WITH ORDINALITY AS u(t, n) ORDER BY n",
+        )
+        .bind(oids)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to look up parameter types: {}", e)))?;
+
+        let mut rewritten = String::with_capacity(sql.len());
+        let mut copied = 0;
+        for token in tokenize(sql, Dialect::Postgres) {
+            if token.kind != TokenKind::Parameter {
+                continue;
+            }
+            let Some(position) = token
+                .text
+                .strip_prefix('$')
+                .and_then(|n| n.parse::<usize>().ok())
+                .and_then(|index| cast.iter().position(|&i| i + 1 == index))
+            else {
+                continue;
+            };
+            rewritten.push_str(&sql[copied..token.offset]);
+            rewritten.push_str(&format!("({}::text::{})", token.text, names[position]));
+            copied = token.offset + token.text.len();
+        }
+        rewritten.push_str(&sql[copied..]);
+
+        let text = <String as sqlx::Type<Postgres>>::type_info();
+        let param_types = param_types
+            .into_iter()
+            .enumerate()
+            .map(|(i, type_info)| if cast.contains(&i) { text.clone() } else { type_info })
+            .collect();
+        Ok((rewritten, param_types))
     }
 
-    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
-        let pool = match pool {
-            PoolRef::Postgres(p) => p,
-            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+    /// Bind a value as the type Postgres inferred for its placeholder
+    fn bind_param<'q>(query: PgQuery<'q>, value: CellValue, type_info: &PgTypeInfo) -> AppResult<PgQuery<'q>> {
+        use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+        let type_name = type_info.name().to_uppercase();
+        let value = match value {
+            CellValue::Null => return Ok(query.bind(PgTextParam { type_info: type_info.clone(), value: None })),
+            // JSON values are bound as they are; only text is parsed as JSON below
+            CellValue::Json(json) if type_name == "JSONB" => return Ok(query.bind(sqlx::types::Json(json))),
+            CellValue::Json(json) if type_name == "JSON" => {
+                return Ok(query.bind(PgTextParam { type_info: type_info.clone(), value: Some(json.to_string()) }))
+            }
+            other => other.to_json(),
+        };
+        let text = param_text(&value);
+        let invalid = |reason: String| {
+            AppError::QueryError(format!("Cannot bind '{}' as {}: {}", text, type_name, reason))
+        };
+
+        fn parse<T: std::str::FromStr>(item: &serde_json::Value) -> Option<T> {
+            param_text(item).trim().parse().ok()
+        }
+
+        let query = match type_name.as_str() {
+            "BOOL" => query.bind(param_bool(&value).ok_or_else(|| invalid("expected true or false".to_string()))?),
+            "INT2" => query.bind(text.trim().parse::<i16>().map_err(|e| invalid(e.to_string()))?),
+            "INT4" => query.bind(text.trim().parse::<i32>().map_err(|e| invalid(e.to_string()))?),
+            "INT8" => query.bind(text.trim().parse::<i64>().map_err(|e| invalid(e.to_string()))?),
+            "FLOAT4" => query.bind(text.trim().parse::<f32>().map_err(|e| invalid(e.to_string()))?),
+            "FLOAT8" => query.bind(text.trim().parse::<f64>().map_err(|e| invalid(e.to_string()))?),
+            "NUMERIC" => query.bind(text.trim().parse::<sqlx::types::Decimal>().map_err(|e| invalid(e.to_string()))?),
+            "UUID" => query.bind(text.trim().parse::<uuid::Uuid>().map_err(|e| invalid(e.to_string()))?),
+            "DATE" => query.bind(NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map_err(|e| invalid(e.to_string()))?),
+            "TIME" => query.bind(text.trim().parse::<NaiveTime>().map_err(|e| invalid(e.to_string()))?),
+            "TIMESTAMP" => {
+                let parsed = NaiveDateTime::parse_from_str(text.trim(), "%Y-%m-%d %H:%M:%S%.f")
+                    .or_else(|_| text.trim().parse::<NaiveDateTime>())
+                    .map_err(|e| invalid(e.to_string()))?;
+                query.bind(parsed)
+            }
+            "TIMESTAMPTZ" => {
+                let parsed = DateTime::<FixedOffset>::parse_from_rfc3339(text.trim())
+                    .or_else(|_| DateTime::<FixedOffset>::parse_from_str(text.trim(), "%Y-%m-%d %H:%M:%S%.f%#z"))
+                    .or_else(|_| {
+                        NaiveDateTime::parse_from_str(text.trim(), "%Y-%m-%d %H:%M:%S%.f")
+                            .map(|dt| dt.and_utc().fixed_offset())
+                    })
+                    .map_err(|e| invalid(e.to_string()))?;
+                query.bind(parsed)
+            }
+            "BYTEA" => {
+                use base64::{Engine as _, engine::general_purpose};
+                let bytes = general_purpose::STANDARD.decode(text.trim())
+                    .map_err(|e| invalid(format!("expected base64 data ({})", e)))?;
+                query.bind(bytes)
+            }
+            "INET" | "CIDR" => query.bind(text.trim().parse::<sqlx::types::ipnetwork::IpNetwork>().map_err(|e| invalid(e.to_string()))?),
+            "MACADDR" => query.bind(text.trim().parse::<sqlx::types::mac_address::MacAddress>().map_err(|e| invalid(e.to_string()))?),
+            "JSONB" => {
+                // Edited cells arrive as text; keep them as a JSON string only if they do not parse
+                let json = match value {
+                    serde_json::Value::String(s) => serde_json::from_str(&s).unwrap_or(serde_json::Value::String(s)),
+                    other => other,
+                };
+                query.bind(sqlx::types::Json(json))
+            }
+            "JSON" => {
+                let json = match &value {
+                    serde_json::Value::String(s) if serde_json::from_str::<serde_json::Value>(s).is_ok() => s.clone(),
+                    other => other.to_string(),
+                };
+                query.bind(PgTextParam { type_info: type_info.clone(), value: Some(json) })
+            }
+            "TEXT[]" | "VARCHAR[]" | "BPCHAR[]" | "NAME[]" => query.bind(
+                param_array(&value, |item| Some(param_text(item))).ok_or_else(|| invalid("expected a JSON array".to_string()))?,
+            ),
+            "BOOL[]" => query.bind(param_array(&value, param_bool).ok_or_else(|| invalid("expected a JSON array of booleans".to_string()))?),
+            "INT2[]" => query.bind(param_array(&value, parse::<i16>).ok_or_else(|| invalid("expected a JSON array of integers".to_string()))?),
+            "INT4[]" => query.bind(param_array(&value, parse::<i32>).ok_or_else(|| invalid("expected a JSON array of integers".to_string()))?),
+            "INT8[]" => query.bind(param_array(&value, parse::<i64>).ok_or_else(|| invalid("expected a JSON array of integers".to_string()))?),
+            "FLOAT4[]" => query.bind(param_array(&value, parse::<f32>).ok_or_else(|| invalid("expected a JSON array of numbers".to_string()))?),
+            "FLOAT8[]" => query.bind(param_array(&value, parse::<f64>).ok_or_else(|| invalid("expected a JSON array of numbers".to_string()))?),
+            "NUMERIC[]" => query.bind(
+                param_array(&value, parse::<sqlx::types::Decimal>).ok_or_else(|| invalid("expected a JSON array of numbers".to_string()))?,
+            ),
+            "UUID[]" => query.bind(param_array(&value, parse::<uuid::Uuid>).ok_or_else(|| invalid("expected a JSON array of UUIDs".to_string()))?),
+            // Text-like types and enums, and placeholders cast from text
+            _ => query.bind(PgTextParam { type_info: type_info.clone(), value: Some(text.clone()) }),
         };
+
+        Ok(query)
+    }
+
+    /// Run a single DDL statement, prefixing failures with `context`
+    async fn execute_ddl(pool: &PgPool, sql: &str, context: &str) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        sqlx::query(sql)
+            .execute(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("{}: {}", context, e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    /// CREATE TABLE statement for a table; foreign keys are left out unless `include_foreign_keys`
+    async fn table_ddl(conn: &mut PgConnection, table_name: &str, include_foreign_keys: bool) -> AppResult<String> {
         // Parse schema.table format
         let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
             let (s, t) = table_name.split_at(dot_pos);
@@ -620,30 +756,42 @@ impl DatabaseDriver for PostgresDriver {
         } else {
             (None, table_name.to_string())
         };
-        
-        // Get columns
+
+        // Get columns with full details
         let columns_query = r#"
-            SELECT 
+            SELECT
                 column_name::text as column_name,
                 data_type::text as data_type,
+                character_maximum_length::int as max_length,
+                numeric_precision::int as numeric_precision,
+                numeric_scale::int as numeric_scale,
                 is_nullable::text as is_nullable,
-                column_default::text as column_default
+                column_default::text as column_default,
+                udt_name::text as udt_name,
+                identity_generation::text as identity_generation,
+                CASE WHEN is_generated = 'ALWAYS' THEN generation_expression::text END as generation_expression,
+                CASE
+                    WHEN domain_name IS NOT NULL THEN (quote_ident(domain_schema) || '.' || quote_ident(domain_name))::regtype::text
+                    WHEN data_type = 'USER-DEFINED' THEN (quote_ident(udt_schema) || '.' || quote_ident(udt_name))::regtype::text
+                END as user_type
             FROM information_schema.columns
             WHERE table_schema = COALESCE($1, current_schema())
             AND table_name = $2
             ORDER BY ordinal_position
         "#;
-        
-        let columns_rows = sqlx::query(columns_query)
+
+        let columns = sqlx::query(columns_query)
             .bind(&schema)
             .bind(&table)
-            .fetch_all(pool)
+            .fetch_all(&mut *conn)
             .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get columns: {}", e)))?;
-        
-        // Get primary keys
+            .map_err(|e| AppError::QueryError(format!("Failed to get columns for DDL: {}", e)))?;
+
+        // Get primary key constraint
         let pk_query = r#"
-            SELECT column_name::text as column_name
+            SELECT
+                tc.constraint_name::text as constraint_name,
+                array_agg(kcu.column_name::text ORDER BY kcu.ordinal_position)::text[] as columns
             FROM information_schema.table_constraints tc
             JOIN information_schema.key_column_usage kcu
                 ON tc.constraint_name = kcu.constraint_name
@@ -651,23 +799,629 @@ impl DatabaseDriver for PostgresDriver {
             WHERE tc.constraint_type = 'PRIMARY KEY'
             AND tc.table_schema = COALESCE($1, current_schema())
             AND tc.table_name = $2
+            GROUP BY tc.constraint_name
         "#;
-        
+
         let pk_rows = sqlx::query(pk_query)
             .bind(&schema)
             .bind(&table)
-            .fetch_all(pool)
+            .fetch_all(&mut *conn)
             .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get primary keys: {}", e)))?;
-        
-        let primary_keys: Vec<String> = pk_rows
-            .iter()
-            .map(|row| row.get::<String, _>("column_name"))
-            .collect();
-        
-        // Get foreign keys
-        let fk_query = r#"
-            SELECT
+            .map_err(|e| AppError::QueryError(format!("Failed to get PK for DDL: {}", e)))?;
+
+        // Get foreign keys with grouped columns
+        let fk_query = r#"
+            SELECT
+                tc.constraint_name::text as constraint_name,
+                array_agg(kcu.column_name::text ORDER BY kcu.ordinal_position)::text[] as source_columns,
+                ccu.table_schema::text || '.' || ccu.table_name::text AS foreign_table,
+                array_agg(ccu.column_name::text ORDER BY kcu.ordinal_position)::text[] AS foreign_columns
+            FROM information_schema.table_constraints AS tc
+            JOIN information_schema.key_column_usage AS kcu
+                ON tc.constraint_name = kcu.constraint_name
+                AND tc.table_schema = kcu.table_schema
+            JOIN information_schema.constraint_column_usage AS ccu
+                ON ccu.constraint_name = tc.constraint_name
+                AND ccu.table_schema = tc.table_schema
+            WHERE tc.constraint_type = 'FOREIGN KEY'
+            AND tc.table_schema = COALESCE($1, current_schema())
+            AND tc.table_name = $2
+            GROUP BY tc.constraint_name, ccu.table_schema, ccu.table_name
+        "#;
+
+        let fk_rows = if include_foreign_keys {
+            sqlx::query(fk_query)
+                .bind(&schema)
+                .bind(&table)
+                .fetch_all(&mut *conn)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to get FK for DDL: {}", e)))?
+        } else {
+            vec![]
+        };
+
+        // Partition key of a partitioned table, and parent and bound of a partition
+        let partition_query = r#"
+            SELECT
+                pg_get_partkeydef(c.oid) as partition_key,
+                CASE WHEN c.relispartition THEN pg_get_expr(c.relpartbound, c.oid) END as partition_bound,
+                (
+                    SELECT quote_ident(pn.nspname) || '.' || quote_ident(pc.relname)
+                    FROM pg_inherits i
+                    JOIN pg_class pc ON pc.oid = i.inhparent
+                    JOIN pg_namespace pn ON pn.oid = pc.relnamespace
+                    WHERE i.inhrelid = c.oid AND c.relispartition
+                ) as partition_parent
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relname = $2
+            AND n.nspname = COALESCE($1, current_schema())
+        "#;
+
+        let partition_row = sqlx::query(partition_query)
+            .bind(&schema)
+            .bind(&table)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get partitioning for DDL: {}", e)))?;
+        let partition_key: Option<String> = partition_row.as_ref().and_then(|row| row.get("partition_key"));
+        let partition_by = partition_key.map(|key| format!(" PARTITION BY {}", key)).unwrap_or_default();
+
+        // Build the DDL
+        let schema_prefix = schema.as_ref().map(|s| format!("\"{}\".", s)).unwrap_or_default();
+
+        // Partitions take their columns and keys from the parent
+        if let Some(row) = &partition_row {
+            let parent: Option<String> = row.get("partition_parent");
+            let bound: Option<String> = row.get("partition_bound");
+            if let (Some(parent), Some(bound)) = (parent, bound) {
+                return Ok(format!(
+                    "CREATE TABLE {}\"{}\" PARTITION OF {} {}{};",
+                    schema_prefix, table, parent, bound, partition_by
+                ));
+            }
+        }
+
+        let mut ddl = format!("CREATE TABLE {}\"{}\" (\n", schema_prefix, table);
+
+        // Add columns
+        let column_defs: Vec<String> = columns.iter().map(|row| {
+            let col_name: String = row.get("column_name");
+            let data_type: String = row.get("data_type");
+            let udt_name: String = row.get("udt_name");
+            let max_length: Option<i32> = row.try_get("max_length").ok();
+            let numeric_precision: Option<i32> = row.try_get("numeric_precision").ok();
+            let numeric_scale: Option<i32> = row.try_get("numeric_scale").ok();
+            let is_nullable: String = row.get("is_nullable");
+            let column_default: Option<String> = row.try_get("column_default").ok();
+            let user_type: Option<String> = row.try_get("user_type").ok().flatten();
+
+            // Build type string; domains, enums and composite types keep their own name
+            let type_str = user_type.unwrap_or_else(|| match data_type.as_str() {
+                "character varying" => {
+                    if let Some(len) = max_length {
+                        format!("VARCHAR({})", len)
+                    } else {
+                        "VARCHAR".to_string()
+                    }
+                }
+                "character" => {
+                    if let Some(len) = max_length {
+                        format!("CHAR({})", len)
+                    } else {
+                        "CHAR".to_string()
+                    }
+                }
+                "numeric" => {
+                    match (numeric_precision, numeric_scale) {
+                        (Some(p), Some(s)) if s > 0 => format!("NUMERIC({},{})", p, s),
+                        (Some(p), _) => format!("NUMERIC({})", p),
+                        _ => "NUMERIC".to_string()
+                    }
+                }
+                "ARRAY" => format!("{}[]", udt_name.trim_start_matches('_')),
+                _ => data_type.to_uppercase()
+            });
+
+            let mut col_def = format!("    \"{}\" {}", col_name, type_str);
+
+            if is_nullable == "NO" {
+                col_def.push_str(" NOT NULL");
+            }
+
+            let identity_generation: Option<String> = row.try_get("identity_generation").ok().flatten();
+            let generation_expression: Option<String> = row.try_get("generation_expression").ok().flatten();
+            if let Some(expression) = generation_expression {
+                col_def.push_str(&format!(" GENERATED ALWAYS AS ({}) STORED", expression));
+            } else if let Some(generation) = identity_generation {
+                col_def.push_str(&format!(" GENERATED {} AS IDENTITY", generation));
+            } else if let Some(default) = column_default {
+                col_def.push_str(&format!(" DEFAULT {}", default));
+            }
+
+            col_def
+        }).collect();
+
+        ddl.push_str(&column_defs.join(",\n"));
+
+        // Add primary key constraint
+        if let Some(pk_row) = pk_rows.first() {
+            let pk_columns: Vec<String> = pk_row.get("columns");
+            let pk_cols_quoted: Vec<String> = pk_columns.iter().map(|c| format!("\"{}\"", c)).collect();
+            ddl.push_str(&format!(",\n    PRIMARY KEY ({})", pk_cols_quoted.join(", ")));
+        }
+
+        // Add foreign key constraints
+        for fk_row in &fk_rows {
+            let constraint_name: String = fk_row.get("constraint_name");
+            let source_columns: Vec<String> = fk_row.get("source_columns");
+            let foreign_table: String = fk_row.get("foreign_table");
+            let foreign_columns: Vec<String> = fk_row.get("foreign_columns");
+
+            let src_cols_quoted: Vec<String> = source_columns.iter().map(|c| format!("\"{}\"", c)).collect();
+            let target_cols_quoted: Vec<String> = foreign_columns.iter().map(|c| format!("\"{}\"", c)).collect();
+
+            // Split foreign table into schema and table if possible
+            let quoted_foreign_table = if let Some(dot_pos) = foreign_table.find('.') {
+                let (s, t) = foreign_table.split_at(dot_pos);
+                format!("\"{}\".\"{}\"", s, t.trim_start_matches('.'))
+            } else {
+                format!("\"{}\"", foreign_table)
+            };
+
+            ddl.push_str(&format!(
+                ",\n    CONSTRAINT \"{}\" FOREIGN KEY ({}) REFERENCES {} ({})",
+                constraint_name,
+                src_cols_quoted.join(", "),
+                quoted_foreign_table,
+                target_cols_quoted.join(", ")
+            ));
+        }
+
+        ddl.push_str("\n)");
+        ddl.push_str(&partition_by);
+        ddl.push(';');
+
+        Ok(ddl)
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for PostgresDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let connection_string = self.build_connection_string(config);
+        
+        let pool = PgPool::connect(&connection_string).await
+            .map_err(|e| AppError::ConnectionError(format!("PostgreSQL connection failed: {}", e)))?;
+        
+        // Get server version
+        let version: String = sqlx::query_scalar("SELECT version()")
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?;
+        
+        pool.close().await;
+        
+        Ok(TestConnectionResult {
+            success: true,
+            message: format!("PostgreSQL connection to {} successful", config.database),
+            server_version: Some(version),
+        })
+    }
+
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.execute_statements(&mut conn, sql, None).await
+    }
+
+    async fn execute_query_limited(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.execute_on_connection(&mut conn, sql, limits).await
+    }
+
+    async fn execute_read_only(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        conn.execute("BEGIN READ ONLY")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to start a read-only transaction: {}", e)))?;
+        let result = self.execute_on_connection(&mut conn, sql, limits).await;
+        if conn.execute("ROLLBACK").await.is_err() {
+            conn.close_on_drop();
+        }
+        result
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.stream_on_connection(&mut conn, sql, batch_size, on_batch).await
+    }
+
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        // Ask the server which type each placeholder needs so values can be bound as that type
+        let describe = pool.describe(sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to prepare query: {}", e)))?;
+
+        let param_types: Vec<PgTypeInfo> = match describe.parameters() {
+            Some(sqlx::Either::Left(types)) => types.to_vec(),
+            _ => vec![],
+        };
+
+        if param_types.len() != params.len() {
+            return Err(AppError::QueryError(format!(
+                "Query expects {} parameters but {} were given",
+                param_types.len(),
+                params.len()
+            )));
+        }
+
+        let (sql, param_types) = Self::cast_text_params(pool, sql, param_types).await?;
+        let mut query = sqlx::query(&sql);
+        for (value, type_info) in params.into_iter().zip(param_types.iter()) {
+            query = Self::bind_param(query, value, type_info)?;
+        }
+
+        if describe.columns().is_empty() {
+            let result = query
+                .execute(pool)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+            return Ok(QueryResult {
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(result.rows_affected()),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        let rows = query
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        let columns = Self::result_columns(describe.columns(), |i| describe.nullable(i));
+
+        let user_types = UserTypes::load(&mut conn, columns.iter().filter_map(|col| col.type_oid).collect()).await;
+        let json_rows: Vec<Vec<serde_json::Value>> = rows
+            .iter()
+            .map(|row| {
+                (0..columns.len())
+                    .map(|i| Self::pg_value_to_json(row, i, &user_types))
+                    .collect()
+            })
+            .collect();
+
+        Ok(QueryResult {
+            columns,
+            rows: json_rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::PostgreSQL, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let sql = insert_statement(&DatabaseType::PostgreSQL, table, columns);
+        let describe = pool.describe(&sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to prepare insert: {}", e)))?;
+        let param_types: Vec<PgTypeInfo> = match describe.parameters() {
+            Some(sqlx::Either::Left(types)) => types.to_vec(),
+            _ => vec![],
+        };
+        let (sql, param_types) = Self::cast_text_params(pool, &sql, param_types).await?;
+
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
+        let mut failed = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            // Each row gets a savepoint so one bad row does not abort the transaction
+            let mut savepoint = tx.begin().await
+                .map_err(|e| AppError::QueryError(format!("Failed to create savepoint: {}", e)))?;
+
+            let bound = row
+                .into_iter()
+                .zip(param_types.iter())
+                .try_fold(sqlx::query(&sql), |query, (value, type_info)| Self::bind_param(query, value.into(), type_info));
+            let outcome = match bound {
+                Ok(query) => query.execute(&mut *savepoint).await.map(|_| ()).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match outcome {
+                Ok(()) => savepoint.commit().await,
+                Err(message) => {
+                    failed.push((index, message));
+                    savepoint.rollback().await
+                }
+            }
+            .map_err(|e| AppError::QueryError(format!("Failed to release savepoint: {}", e)))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(failed)
+    }
+
+    async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT 
+                t.table_name::text as table_name,
+                t.table_schema::text as table_schema,
+                CASE
+                    WHEN c.relkind = 'p' THEN 'PARTITIONED TABLE'
+                    WHEN c.relispartition THEN 'PARTITION'
+                    ELSE 'BASE TABLE'
+                END as table_type,
+                (
+                    SELECT pn.nspname || '.' || pc.relname
+                    FROM pg_inherits i
+                    JOIN pg_class pc ON pc.oid = i.inhparent
+                    JOIN pg_namespace pn ON pn.oid = pc.relnamespace
+                    WHERE i.inhrelid = c.oid AND c.relispartition
+                )::text as parent_table
+            FROM information_schema.tables t
+            JOIN pg_namespace n ON n.nspname = t.table_schema
+            JOIN pg_class c ON c.relnamespace = n.oid AND c.relname = t.table_name
+            WHERE t.table_schema NOT IN ('pg_catalog', 'information_schema')
+            AND t.table_type = 'BASE TABLE'
+            ORDER BY t.table_schema, t.table_name
+        "#;
+        
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get tables: {}", e)))?;
+        
+        let tables: Vec<TableInfo> = rows
+            .iter()
+            .map(|row| {
+                let schema: Option<String> = row.try_get("table_schema").ok();
+                let name: String = row.get("table_name");
+                let full_name = if let Some(schema) = &schema {
+                    format!("{}.{}", schema, name)
+                } else {
+                    name.clone()
+                };
+                
+                TableInfo {
+                    name: full_name,
+                    schema,
+                    table_type: row.get("table_type"),
+                    row_count: None, // Could be added with COUNT query if needed
+                    parent_table: row.try_get("parent_table").ok().flatten(),
+                    pinned: false,
+                }
+            })
+            .collect();
+        
+        Ok(tables)
+    }
+
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        sqlx::query_scalar::<_, String>(
+            "SELECT datname::text FROM pg_database WHERE NOT datistemplate AND datallowconn ORDER BY datname",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))
+    }
+
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT nspname::text
+            FROM pg_namespace
+            WHERE nspname NOT LIKE 'pg\_%'
+            AND nspname <> 'information_schema'
+            ORDER BY nspname
+        "#;
+
+        sqlx::query_scalar::<_, String>(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list schemas: {}", e)))
+    }
+
+    async fn create_database(&self, pool: PoolRef<'_>, options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let pg = DatabaseType::PostgreSQL;
+        let mut sql = format!("CREATE DATABASE {}", quote_identifier(&pg, &options.name));
+        if let Some(owner) = &options.owner {
+            sql.push_str(&format!(" OWNER {}", quote_identifier(&pg, owner)));
+        }
+        // template1 may use another encoding or locale; template0 accepts any
+        if options.charset.is_some() || options.collation.is_some() {
+            sql.push_str(" TEMPLATE template0");
+        }
+        if let Some(charset) = &options.charset {
+            sql.push_str(&format!(" ENCODING {}", string_literal(&pg, charset)));
+        }
+        if let Some(collation) = &options.collation {
+            let locale = string_literal(&pg, collation);
+            sql.push_str(&format!(" LC_COLLATE {} LC_CTYPE {}", locale, locale));
+        }
+
+        Self::execute_ddl(pool, &sql, "Failed to create database").await
+    }
+
+    /// Fails while other sessions are connected to the database, including this connection's own
+    async fn drop_database(&self, pool: PoolRef<'_>, name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let sql = format!("DROP DATABASE {}", quote_identifier(&DatabaseType::PostgreSQL, name));
+        Self::execute_ddl(pool, &sql, "Failed to drop database").await
+    }
+
+    async fn create_schema(&self, pool: PoolRef<'_>, options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let mut sql = format!("CREATE SCHEMA {}", quote_identifier(&DatabaseType::PostgreSQL, &options.name));
+        if let Some(owner) = &options.owner {
+            sql.push_str(&format!(" AUTHORIZATION {}", quote_identifier(&DatabaseType::PostgreSQL, owner)));
+        }
+
+        Self::execute_ddl(pool, &sql, "Failed to create schema").await
+    }
+
+    async fn drop_schema(&self, pool: PoolRef<'_>, name: &str, cascade: bool) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let sql = format!(
+            "DROP SCHEMA {}{}",
+            quote_identifier(&DatabaseType::PostgreSQL, name),
+            if cascade { " CASCADE" } else { "" }
+        );
+        Self::execute_ddl(pool, &sql, "Failed to drop schema").await
+    }
+
+    async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+        // Parse schema.table format
+        let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
+            let (s, t) = table_name.split_at(dot_pos);
+            (Some(s.to_string()), t.trim_start_matches('.').to_string())
+        } else {
+            (None, table_name.to_string())
+        };
+        
+        // Get columns; extension types such as pgvector's are named with their modifier, e.g. vector(1536)
+        let columns_query = r#"
+            SELECT 
+                c.column_name::text as column_name,
+                CASE WHEN c.data_type = 'USER-DEFINED' THEN COALESCE(format_type(a.atttypid, a.atttypmod), c.data_type::text)
+                     ELSE c.data_type::text END as data_type,
+                c.is_nullable::text as is_nullable,
+                c.column_default::text as column_default
+            FROM information_schema.columns c
+            LEFT JOIN pg_catalog.pg_attribute a
+                ON a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass
+                AND a.attname = c.column_name
+            WHERE c.table_schema = COALESCE($1, current_schema())
+            AND c.table_name = $2
+            ORDER BY c.ordinal_position
+        "#;
+        
+        let columns_rows = sqlx::query(columns_query)
+            .bind(&schema)
+            .bind(&table)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get columns: {}", e)))?;
+        
+        // Get primary keys
+        let pk_query = r#"
+            SELECT column_name::text as column_name
+            FROM information_schema.table_constraints tc
+            JOIN information_schema.key_column_usage kcu
+                ON tc.constraint_name = kcu.constraint_name
+                AND tc.table_schema = kcu.table_schema
+            WHERE tc.constraint_type = 'PRIMARY KEY'
+            AND tc.table_schema = COALESCE($1, current_schema())
+            AND tc.table_name = $2
+        "#;
+        
+        let pk_rows = sqlx::query(pk_query)
+            .bind(&schema)
+            .bind(&table)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get primary keys: {}", e)))?;
+        
+        let primary_keys: Vec<String> = pk_rows
+            .iter()
+            .map(|row| row.get::<String, _>("column_name"))
+            .collect();
+        
+        // Get foreign keys
+        let fk_query = r#"
+            SELECT
                 kcu.column_name::text as column_name,
                 ccu.table_name::text AS foreign_table_name,
                 ccu.column_name::text AS foreign_column_name
@@ -708,6 +1462,8 @@ impl DatabaseDriver for PostgresDriver {
                     data_type: row.get("data_type"),
                     nullable: row.get::<String, _>("is_nullable") == "YES",
                     is_primary_key: primary_keys.contains(&col_name),
+                    native_type: None,
+                    type_oid: None,
                 }
             })
             .collect();
@@ -729,14 +1485,18 @@ impl DatabaseDriver for PostgresDriver {
         // Get all columns for all tables in one query
         let all_columns_query = r#"
             SELECT 
-                table_schema::text as table_schema,
-                table_name::text as table_name,
-                column_name::text as column_name,
-                data_type::text as data_type,
-                is_nullable::text as is_nullable
-            FROM information_schema.columns
-            WHERE table_schema NOT IN ('pg_catalog', 'information_schema')
-            ORDER BY table_schema, table_name, ordinal_position
+                c.table_schema::text as table_schema,
+                c.table_name::text as table_name,
+                c.column_name::text as column_name,
+                CASE WHEN c.data_type = 'USER-DEFINED' THEN COALESCE(format_type(a.atttypid, a.atttypmod), c.data_type::text)
+                     ELSE c.data_type::text END as data_type,
+                c.is_nullable::text as is_nullable
+            FROM information_schema.columns c
+            LEFT JOIN pg_catalog.pg_attribute a
+                ON a.attrelid = (quote_ident(c.table_schema) || '.' || quote_ident(c.table_name))::regclass
+                AND a.attname = c.column_name
+            WHERE c.table_schema NOT IN ('pg_catalog', 'information_schema')
+            ORDER BY c.table_schema, c.table_name, c.ordinal_position
         "#;
 
         let all_columns = sqlx::query(all_columns_query)
@@ -805,6 +1565,8 @@ impl DatabaseDriver for PostgresDriver {
                 data_type: row.get("data_type"),
                 nullable: row.get::<String, _>("is_nullable") == "YES",
                 is_primary_key: false, // Will be updated below
+                native_type: None,
+                type_oid: None,
             };
 
             table_columns.entry(table_key.clone()).or_default().push(column_info);
@@ -866,9 +1628,7 @@ impl DatabaseDriver for PostgresDriver {
         let mut url = format!("postgresql://{}:{}@{}:{}/{}",
             username, password, host, port, config.database);
 
-        if let Some(ssl_mode) = &config.ssl_mode {
-            url.push_str(&format!("?sslmode={}", ssl_mode));
-        }
+        append_query_params(&mut url, &postgres_tls_params(config));
 
         url
     }
@@ -879,216 +1639,592 @@ impl DatabaseDriver for PostgresDriver {
             _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
         };
 
-        // Parse schema.table format
-        let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
-            let (s, t) = table_name.split_at(dot_pos);
-            (Some(s.to_string()), t.trim_start_matches('.').to_string())
-        } else {
-            (None, table_name.to_string())
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        Self::table_ddl(&mut conn, table_name, true).await
+    }
+
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
         };
 
-        // Get columns with full details
-        let columns_query = r#"
+        // Views record what they read through the dependencies of their rewrite rule
+        let query = r#"
+            SELECT DISTINCT
+                vn.nspname || '.' || v.relname AS object,
+                CASE v.relkind WHEN 'm' THEN 'MATERIALIZED VIEW' ELSE 'VIEW' END AS object_type,
+                tn.nspname || '.' || t.relname AS depends_on,
+                NULL::text AS constraint_name
+            FROM pg_rewrite r
+            JOIN pg_class v ON v.oid = r.ev_class
+            JOIN pg_namespace vn ON vn.oid = v.relnamespace
+            JOIN pg_depend d ON d.classid = 'pg_rewrite'::regclass AND d.objid = r.oid AND d.refclassid = 'pg_class'::regclass
+            JOIN pg_class t ON t.oid = d.refobjid AND t.oid <> v.oid
+            JOIN pg_namespace tn ON tn.oid = t.relnamespace
+            WHERE v.relkind IN ('v', 'm')
+            AND vn.nspname NOT IN ('pg_catalog', 'information_schema')
+            UNION ALL
             SELECT
-                column_name::text as column_name,
-                data_type::text as data_type,
-                character_maximum_length::int as max_length,
-                numeric_precision::int as numeric_precision,
-                numeric_scale::int as numeric_scale,
-                is_nullable::text as is_nullable,
-                column_default::text as column_default,
-                udt_name::text as udt_name
-            FROM information_schema.columns
-            WHERE table_schema = COALESCE($1, current_schema())
-            AND table_name = $2
-            ORDER BY ordinal_position
+                n.nspname || '.' || c.relname AS object,
+                'TABLE' AS object_type,
+                rn.nspname || '.' || rc.relname AS depends_on,
+                con.conname::text AS constraint_name
+            FROM pg_constraint con
+            JOIN pg_class c ON c.oid = con.conrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            JOIN pg_class rc ON rc.oid = con.confrelid
+            JOIN pg_namespace rn ON rn.oid = rc.relnamespace
+            WHERE con.contype = 'f'
+            AND con.conparentid = 0
+            AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+            ORDER BY object, depends_on
         "#;
 
-        let columns = sqlx::query(columns_query)
-            .bind(&schema)
-            .bind(&table)
+        let rows = sqlx::query(query)
             .fetch_all(pool)
             .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get columns for DDL: {}", e)))?;
+            .map_err(|e| AppError::QueryError(format!("Failed to get object dependencies: {}", e)))?;
+
+        Ok(rows.iter().map(|row| ObjectDependency {
+            object: row.get("object"),
+            object_type: row.get("object_type"),
+            depends_on: row.get("depends_on"),
+            constraint_name: row.get("constraint_name"),
+        }).collect())
+    }
 
-        // Get primary key constraint
-        let pk_query = r#"
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        // Catalog functions qualify the names that are not on the search path,
+        // so an empty search path qualifies every name
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to begin transaction: {}", e)))?;
+        if options.qualify_names {
+            sqlx::query("SET LOCAL search_path = ''")
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to set search path: {}", e)))?;
+        }
+
+        // Objects of extensions come back with CREATE EXTENSION, so they are skipped
+        let user_objects = |alias: &str, catalog: &str| format!(
+            "n.nspname NOT IN ('pg_catalog', 'information_schema', 'pg_toast') AND n.nspname NOT LIKE 'pg\\_%temp%' \
+             AND NOT EXISTS (SELECT 1 FROM pg_depend d WHERE d.classid = '{}'::regclass AND d.objid = {}.oid AND d.deptype = 'e')",
+            catalog, alias
+        );
+        let fetch_error = |what: &str, e: sqlx::Error| AppError::QueryError(format!("Failed to list {}: {}", what, e));
+        let mut objects = Vec::new();
+
+        let schemas = sqlx::query(&format!(
+            "SELECT quote_ident(n.nspname) AS name FROM pg_namespace n \
+             WHERE n.nspname <> 'public' AND {} ORDER BY n.nspname",
+            user_objects("n", "pg_namespace")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("schemas", e))?;
+        for row in &schemas {
+            let name: String = row.get("name");
+            objects.push(
+                DdlObject::new(DdlKind::Schema, name.clone(), format!("CREATE SCHEMA IF NOT EXISTS {};", name))
+                    .with_drop(format!("DROP SCHEMA IF EXISTS {} CASCADE;", name)),
+            );
+        }
+
+        let extensions = sqlx::query(
+            "SELECT quote_ident(e.extname) AS name, quote_ident(n.nspname) AS schema_name \
+             FROM pg_extension e JOIN pg_namespace n ON n.oid = e.extnamespace \
+             WHERE e.extname <> 'plpgsql' ORDER BY e.extname",
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("extensions", e))?;
+        for row in &extensions {
+            let name: String = row.get("name");
+            let schema_name: String = row.get("schema_name");
+            objects.push(
+                DdlObject::new(
+                    DdlKind::Extension,
+                    name.clone(),
+                    format!("CREATE EXTENSION IF NOT EXISTS {} WITH SCHEMA {};", name, schema_name),
+                )
+                .with_drop(format!("DROP EXTENSION IF EXISTS {} CASCADE;", name)),
+            );
+        }
+
+        // Enums, domains and composite types; dependencies point at element types rather than array types
+        let types = sqlx::query(&format!(
+            r#"
             SELECT
-                tc.constraint_name::text as constraint_name,
-                array_agg(kcu.column_name::text ORDER BY kcu.ordinal_position)::text[] as columns
-            FROM information_schema.table_constraints tc
-            JOIN information_schema.key_column_usage kcu
-                ON tc.constraint_name = kcu.constraint_name
-                AND tc.table_schema = kcu.table_schema
-            WHERE tc.constraint_type = 'PRIMARY KEY'
-            AND tc.table_schema = COALESCE($1, current_schema())
-            AND tc.table_name = $2
-            GROUP BY tc.constraint_name
-        "#;
+                t.oid::text AS key,
+                format_type(t.oid, NULL) AS name,
+                t.typtype::text AS kind,
+                CASE t.typtype
+                    WHEN 'e' THEN (
+                        SELECT string_agg(quote_literal(e.enumlabel), ', ' ORDER BY e.enumsortorder)
+                        FROM pg_enum e WHERE e.enumtypid = t.oid
+                    )
+                    WHEN 'c' THEN (
+                        SELECT string_agg(quote_ident(a.attname) || ' ' || format_type(a.atttypid, a.atttypmod), ', ' ORDER BY a.attnum)
+                        FROM pg_attribute a WHERE a.attrelid = t.typrelid AND a.attnum > 0 AND NOT a.attisdropped
+                    )
+                    ELSE format_type(t.typbasetype, t.typtypmod)
+                        || COALESCE(' DEFAULT ' || t.typdefault, '')
+                        || CASE WHEN t.typnotnull THEN ' NOT NULL' ELSE '' END
+                        || COALESCE((
+                            SELECT string_agg(' CONSTRAINT ' || quote_ident(c.conname) || ' ' || pg_get_constraintdef(c.oid), '' ORDER BY c.conname)
+                            FROM pg_constraint c WHERE c.contypid = t.oid
+                        ), '')
+                END AS definition,
+                ARRAY(
+                    SELECT (CASE WHEN at.typcategory = 'A' THEN at.typelem ELSE at.oid END)::text
+                    FROM pg_attribute a JOIN pg_type at ON at.oid = a.atttypid
+                    WHERE a.attrelid = t.typrelid AND a.attnum > 0 AND NOT a.attisdropped
+                    UNION ALL
+                    SELECT t.typbasetype::text WHERE t.typtype = 'd'
+                ) AS depends_on
+            FROM pg_type t
+            JOIN pg_namespace n ON n.oid = t.typnamespace
+            WHERE (t.typtype IN ('e', 'd') OR (t.typtype = 'c' AND (SELECT c.relkind FROM pg_class c WHERE c.oid = t.typrelid) = 'c'))
+            AND {}
+            ORDER BY CASE t.typtype WHEN 'e' THEN 0 WHEN 'd' THEN 1 ELSE 2 END, n.nspname, t.typname
+            "#,
+            user_objects("t", "pg_type")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("types", e))?;
+        for row in &types {
+            let name: String = row.get("name");
+            let definition: String = row.get::<Option<String>, _>("definition").unwrap_or_default();
+            let (create, drop) = match row.get::<String, _>("kind").as_str() {
+                "e" => (format!("CREATE TYPE {} AS ENUM ({});", name, definition), "TYPE"),
+                "c" => (format!("CREATE TYPE {} AS ({});", name, definition), "TYPE"),
+                _ => (format!("CREATE DOMAIN {} AS {};", name, definition), "DOMAIN"),
+            };
+            objects.push(
+                DdlObject::new(DdlKind::Type, row.get::<String, _>("key"), create)
+                    .with_drop(format!("DROP {} IF EXISTS {} CASCADE;", drop, name))
+                    .with_dependencies(row.get("depends_on")),
+            );
+        }
 
-        let pk_rows = sqlx::query(pk_query)
-            .bind(&schema)
-            .bind(&table)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get PK for DDL: {}", e)))?;
+        // Identity sequences are created by their columns
+        let sequences = sqlx::query(&format!(
+            r#"
+            SELECT
+                c.oid::regclass::text AS name,
+                format_type(s.seqtypid, NULL) AS data_type,
+                s.seqstart, s.seqincrement, s.seqmin, s.seqmax, s.seqcache, s.seqcycle
+            FROM pg_sequence s
+            JOIN pg_class c ON c.oid = s.seqrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE NOT EXISTS (
+                SELECT 1 FROM pg_depend d
+                WHERE d.classid = 'pg_class'::regclass AND d.objid = c.oid AND d.deptype = 'i'
+            )
+            AND {}
+            ORDER BY n.nspname, c.relname
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("sequences", e))?;
+        for row in &sequences {
+            let name: String = row.get("name");
+            let create = format!(
+                "CREATE SEQUENCE IF NOT EXISTS {} AS {} INCREMENT BY {} MINVALUE {} MAXVALUE {} START WITH {} CACHE {}{};",
+                name,
+                row.get::<String, _>("data_type"),
+                row.get::<i64, _>("seqincrement"),
+                row.get::<i64, _>("seqmin"),
+                row.get::<i64, _>("seqmax"),
+                row.get::<i64, _>("seqstart"),
+                row.get::<i64, _>("seqcache"),
+                if row.get::<bool, _>("seqcycle") { " CYCLE" } else { "" },
+            );
+            objects.push(
+                DdlObject::new(DdlKind::Sequence, name.clone(), create)
+                    .with_drop(format!("DROP SEQUENCE IF EXISTS {} CASCADE;", name)),
+            );
+        }
 
-        // Get foreign keys with grouped columns
-        let fk_query = r#"
+        // Functions and procedures, which triggers need; bodies are not checked until they run
+        let functions = sqlx::query(&format!(
+            r#"
             SELECT
-                tc.constraint_name::text as constraint_name,
-                array_agg(kcu.column_name::text ORDER BY kcu.ordinal_position)::text[] as source_columns,
-                ccu.table_schema::text || '.' || ccu.table_name::text AS foreign_table,
-                array_agg(ccu.column_name::text ORDER BY kcu.ordinal_position)::text[] AS foreign_columns
-            FROM information_schema.table_constraints AS tc
-            JOIN information_schema.key_column_usage AS kcu
-                ON tc.constraint_name = kcu.constraint_name
-                AND tc.table_schema = kcu.table_schema
-            JOIN information_schema.constraint_column_usage AS ccu
-                ON ccu.constraint_name = tc.constraint_name
-                AND ccu.table_schema = tc.table_schema
-            WHERE tc.constraint_type = 'FOREIGN KEY'
-            AND tc.table_schema = COALESCE($1, current_schema())
-            AND tc.table_name = $2
-            GROUP BY tc.constraint_name, ccu.table_schema, ccu.table_name
-        "#;
+                p.oid::regprocedure::text AS signature,
+                p.prokind::text AS kind,
+                pg_get_functiondef(p.oid) AS definition
+            FROM pg_proc p
+            JOIN pg_namespace n ON n.oid = p.pronamespace
+            WHERE p.prokind IN ('f', 'p')
+            AND {}
+            ORDER BY n.nspname, p.proname, p.oid
+            "#,
+            user_objects("p", "pg_proc")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("functions", e))?;
+        for row in &functions {
+            let signature: String = row.get("signature");
+            let kind = if row.get::<String, _>("kind") == "p" { "PROCEDURE" } else { "FUNCTION" };
+            let definition: String = row.get("definition");
+            objects.push(
+                DdlObject::new(DdlKind::Function, signature.clone(), format!("{};", definition.trim_end()))
+                    .with_drop(format!("DROP {} IF EXISTS {} CASCADE;", kind, signature)),
+            );
+        }
 
-        let fk_rows = sqlx::query(fk_query)
-            .bind(&schema)
-            .bind(&table)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| AppError::QueryError(format!("Failed to get FK for DDL: {}", e)))?;
+        // Partitions depend on their parent table
+        let tables = sqlx::query(&format!(
+            r#"
+            SELECT
+                c.oid::text AS key,
+                c.oid::regclass::text AS name,
+                n.nspname::text AS schema_name,
+                c.relname::text AS table_name,
+                pg_table_is_visible(c.oid) AS visible,
+                ARRAY(SELECT i.inhparent::text FROM pg_inherits i WHERE i.inhrelid = c.oid AND c.relispartition) AS depends_on
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('r', 'p')
+            AND {}
+            ORDER BY n.nspname, c.relname
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("tables", e))?;
+        for row in &tables {
+            let name: String = row.get("name");
+            let table_name: String = row.get("table_name");
+            let table_name = if options.qualify_names || !row.get::<bool, _>("visible") {
+                format!("{}.{}", row.get::<String, _>("schema_name"), table_name)
+            } else {
+                table_name
+            };
+            let create = Self::table_ddl(&mut tx, &table_name, false).await?;
+            objects.push(
+                DdlObject::new(DdlKind::Table, row.get::<String, _>("key"), create)
+                    .with_drop(format!("DROP TABLE IF EXISTS {} CASCADE;", name))
+                    .with_dependencies(row.get("depends_on")),
+            );
+        }
 
-        // Build the DDL
-        let schema_prefix = schema.as_ref().map(|s| format!("\"{}\".", s)).unwrap_or_default();
-        let mut ddl = format!("CREATE TABLE {}\"{}\" (\n", schema_prefix, table);
+        // Indexes of constraints come with the constraint and those of partitions with the parent's index
+        let indexes = sqlx::query(&format!(
+            r#"
+            SELECT i.indexrelid::text AS key, pg_get_indexdef(i.indexrelid) AS definition
+            FROM pg_index i
+            JOIN pg_class c ON c.oid = i.indrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('r', 'p')
+            AND NOT EXISTS (
+                SELECT 1 FROM pg_constraint con
+                WHERE con.conindid = i.indexrelid AND con.contype IN ('p', 'u', 'x')
+            )
+            AND NOT EXISTS (SELECT 1 FROM pg_inherits inh WHERE inh.inhrelid = i.indexrelid)
+            AND {}
+            ORDER BY n.nspname, c.relname, i.indexrelid
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("indexes", e))?;
+        for row in &indexes {
+            let definition: String = row.get("definition");
+            objects.push(DdlObject::new(DdlKind::Index, row.get::<String, _>("key"), format!("{};", definition)));
+        }
 
-        // Add columns
-        let column_defs: Vec<String> = columns.iter().map(|row| {
-            let col_name: String = row.get("column_name");
-            let data_type: String = row.get("data_type");
-            let udt_name: String = row.get("udt_name");
-            let max_length: Option<i32> = row.try_get("max_length").ok();
-            let numeric_precision: Option<i32> = row.try_get("numeric_precision").ok();
-            let numeric_scale: Option<i32> = row.try_get("numeric_scale").ok();
-            let is_nullable: String = row.get("is_nullable");
-            let column_default: Option<String> = row.try_get("column_default").ok();
+        // Unique, exclusion and check constraints first, so foreign keys find the keys they reference
+        let constraints = sqlx::query(&format!(
+            r#"
+            SELECT
+                con.oid::text AS key,
+                c.oid::regclass::text AS table_name,
+                quote_ident(con.conname) AS name,
+                pg_get_constraintdef(con.oid) AS definition
+            FROM pg_constraint con
+            JOIN pg_class c ON c.oid = con.conrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE con.contype IN ('u', 'x', 'c', 'f')
+            AND con.conislocal
+            AND con.conparentid = 0
+            AND c.relkind IN ('r', 'p')
+            AND {}
+            ORDER BY con.contype = 'f', n.nspname, c.relname, con.conname
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("constraints", e))?;
+        for row in &constraints {
+            let create = format!(
+                "ALTER TABLE {} ADD CONSTRAINT {} {};",
+                row.get::<String, _>("table_name"),
+                row.get::<String, _>("name"),
+                row.get::<String, _>("definition")
+            );
+            objects.push(DdlObject::new(DdlKind::Constraint, row.get::<String, _>("key"), create));
+        }
 
-            // Build type string
-            let type_str = match data_type.as_str() {
-                "character varying" => {
-                    if let Some(len) = max_length {
-                        format!("VARCHAR({})", len)
-                    } else {
-                        "VARCHAR".to_string()
-                    }
-                }
-                "character" => {
-                    if let Some(len) = max_length {
-                        format!("CHAR({})", len)
-                    } else {
-                        "CHAR".to_string()
-                    }
-                }
-                "numeric" => {
-                    match (numeric_precision, numeric_scale) {
-                        (Some(p), Some(s)) if s > 0 => format!("NUMERIC({},{})", p, s),
-                        (Some(p), _) => format!("NUMERIC({})", p),
-                        _ => "NUMERIC".to_string()
-                    }
-                }
-                "ARRAY" => format!("{}[]", udt_name.trim_start_matches('_')),
-                _ => data_type.to_uppercase()
+        // Views depend on the views their rules read; indexes of materialized views follow them
+        let views = sqlx::query(&format!(
+            r#"
+            SELECT
+                c.oid::text AS key,
+                c.oid::regclass::text AS name,
+                c.relkind::text AS kind,
+                pg_get_viewdef(c.oid, true) AS definition,
+                ARRAY(
+                    SELECT DISTINCT d.refobjid::text
+                    FROM pg_rewrite r
+                    JOIN pg_depend d ON d.classid = 'pg_rewrite'::regclass AND d.objid = r.oid
+                    WHERE r.ev_class = c.oid AND d.refclassid = 'pg_class'::regclass AND d.refobjid <> c.oid
+                ) AS depends_on,
+                ARRAY(
+                    SELECT pg_get_indexdef(i.indexrelid) FROM pg_index i WHERE i.indrelid = c.oid ORDER BY i.indexrelid
+                ) AS indexes
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relkind IN ('v', 'm')
+            AND {}
+            ORDER BY n.nspname, c.relname
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("views", e))?;
+        for row in &views {
+            let name: String = row.get("name");
+            let definition: String = row.get("definition");
+            let definition = definition.trim().trim_end_matches(';');
+            let materialized = row.get::<String, _>("kind") == "m";
+            let mut create = if materialized {
+                format!("CREATE MATERIALIZED VIEW {} AS\n{};", name, definition)
+            } else {
+                format!("CREATE OR REPLACE VIEW {} AS\n{};", name, definition)
             };
+            for index in row.get::<Vec<String>, _>("indexes") {
+                create.push_str(&format!("\n{};", index));
+            }
+            let drop = if materialized { "MATERIALIZED VIEW" } else { "VIEW" };
+            objects.push(
+                DdlObject::new(DdlKind::View, row.get::<String, _>("key"), create)
+                    .with_drop(format!("DROP {} IF EXISTS {} CASCADE;", drop, name))
+                    .with_dependencies(row.get("depends_on")),
+            );
+        }
 
-            let mut col_def = format!("    \"{}\" {}", col_name, type_str);
+        // Triggers cloned onto partitions come back with the parent's trigger
+        let triggers = sqlx::query(&format!(
+            r#"
+            SELECT t.oid::text AS key, pg_get_triggerdef(t.oid, true) AS definition
+            FROM pg_trigger t
+            JOIN pg_class c ON c.oid = t.tgrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE NOT t.tgisinternal
+            AND NOT EXISTS (
+                SELECT 1 FROM pg_depend d
+                WHERE d.classid = 'pg_trigger'::regclass AND d.objid = t.oid AND d.deptype IN ('P', 'S')
+            )
+            AND {}
+            ORDER BY n.nspname, c.relname, t.tgname
+            "#,
+            user_objects("c", "pg_class")
+        ))
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| fetch_error("triggers", e))?;
+        for row in &triggers {
+            let definition: String = row.get("definition");
+            objects.push(DdlObject::new(DdlKind::Trigger, row.get::<String, _>("key"), format!("{};", definition)));
+        }
 
-            if is_nullable == "NO" {
-                col_def.push_str(" NOT NULL");
-            }
+        Ok(objects)
+    }
 
-            if let Some(default) = column_default {
-                col_def.push_str(&format!(" DEFAULT {}", default));
-            }
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
 
-            col_def
-        }).collect();
+        let start = Instant::now();
 
-        ddl.push_str(&column_defs.join(",\n"));
+        // The new name is bare; a table stays in its schema when renamed
+        let sql = format!(
+            "ALTER TABLE {} RENAME TO {}",
+            quote_qualified(&DatabaseType::PostgreSQL, old_name),
+            quote_identifier(&DatabaseType::PostgreSQL, new_name)
+        );
 
-        // Add primary key constraint
-        if let Some(pk_row) = pk_rows.first() {
-            let pk_columns: Vec<String> = pk_row.get("columns");
-            let pk_cols_quoted: Vec<String> = pk_columns.iter().map(|c| format!("\"{}\"", c)).collect();
-            ddl.push_str(&format!(",\n    PRIMARY KEY ({})", pk_cols_quoted.join(", ")));
-        }
+        sqlx::query(&sql)
+            .execute(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename table: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
 
-        // Add foreign key constraints
-        for fk_row in &fk_rows {
-            let constraint_name: String = fk_row.get("constraint_name");
-            let source_columns: Vec<String> = fk_row.get("source_columns");
-            let foreign_table: String = fk_row.get("foreign_table");
-            let foreign_columns: Vec<String> = fk_row.get("foreign_columns");
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
 
-            let src_cols_quoted: Vec<String> = source_columns.iter().map(|c| format!("\"{}\"", c)).collect();
-            let target_cols_quoted: Vec<String> = foreign_columns.iter().map(|c| format!("\"{}\"", c)).collect();
+        let sql = format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            quote_qualified(&DatabaseType::PostgreSQL, table_name),
+            column_definition(&DatabaseType::PostgreSQL, column)
+        );
+        Self::execute_ddl(pool, &sql, "Failed to add column").await
+    }
 
-            // Split foreign table into schema and table if possible
-            let quoted_foreign_table = if let Some(dot_pos) = foreign_table.find('.') {
-                let (s, t) = foreign_table.split_at(dot_pos);
-                format!("\"{}\".\"{}\"", s, t.trim_start_matches('.'))
-            } else {
-                format!("\"{}\"", foreign_table)
-            };
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
 
-            ddl.push_str(&format!(
-                ",\n    CONSTRAINT \"{}\" FOREIGN KEY ({}) REFERENCES {} ({})",
-                constraint_name,
-                src_cols_quoted.join(", "),
-                quoted_foreign_table,
-                target_cols_quoted.join(", ")
-            ));
-        }
+        let sql = format!(
+            "ALTER TABLE {} DROP COLUMN {}",
+            quote_qualified(&DatabaseType::PostgreSQL, table_name),
+            quote_identifier(&DatabaseType::PostgreSQL, column_name)
+        );
+        Self::execute_ddl(pool, &sql, "Failed to drop column").await
+    }
 
-        ddl.push_str("\n);");
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
 
-        Ok(ddl)
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            quote_qualified(&DatabaseType::PostgreSQL, table_name),
+            quote_identifier(&DatabaseType::PostgreSQL, old_name),
+            quote_identifier(&DatabaseType::PostgreSQL, new_name)
+        );
+        Self::execute_ddl(pool, &sql, "Failed to rename column").await
     }
 
-    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
         let pool = match pool {
             PoolRef::Postgres(p) => p,
             _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
         };
 
-        let start = Instant::now();
+        // USING converts existing values that have no implicit cast (e.g. text to integer)
+        let column = quote_identifier(&DatabaseType::PostgreSQL, column_name);
+        let sql = format!(
+            "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{}, ALTER COLUMN {} {}",
+            quote_qualified(&DatabaseType::PostgreSQL, table_name),
+            column,
+            data_type,
+            column,
+            data_type,
+            column,
+            if nullable { "DROP NOT NULL" } else { "SET NOT NULL" }
+        );
+        Self::execute_ddl(pool, &sql, "Failed to change column type").await
+    }
 
-        // Parse schema.table format for old name
-        let (schema, old_table) = if let Some(dot_pos) = old_name.find('.') {
-            let (s, t) = old_name.split_at(dot_pos);
-            (Some(s.to_string()), t.trim_start_matches('.').to_string())
-        } else {
-            (None, old_name.to_string())
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, options: &TruncateOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
         };
 
-        // Build the rename SQL
-        let sql = if let Some(s) = &schema {
-            format!("ALTER TABLE {}.{} RENAME TO {}", s, old_table, new_name)
-        } else {
-            format!("ALTER TABLE {} RENAME TO {}", old_table, new_name)
+        let mut sql = format!("TRUNCATE TABLE {}", quote_qualified(&DatabaseType::PostgreSQL, table_name));
+        if options.restart_identity {
+            sql.push_str(" RESTART IDENTITY");
+        }
+        if options.cascade {
+            sql.push_str(" CASCADE");
+        }
+        Self::execute_ddl(pool, &sql, "Failed to truncate table").await
+    }
+
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
         };
 
-        sqlx::query(&sql)
-            .execute(pool)
+        let start = Instant::now();
+        let source = quote_qualified(&DatabaseType::PostgreSQL, table_name);
+        let target = quote_qualified(&DatabaseType::PostgreSQL, new_name);
+
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to begin transaction: {}", e)))?;
+
+        // Copies defaults, constraints, indexes and identity columns, but not foreign keys
+        sqlx::query(&format!("CREATE TABLE {} (LIKE {} INCLUDING ALL)", target, source))
+            .execute(&mut *tx)
             .await
-            .map_err(|e| AppError::QueryError(format!("Failed to rename table: {}", e)))?;
+            .map_err(|e| AppError::QueryError(format!("Failed to duplicate table: {}", e)))?;
+
+        let mut affected_rows = 0;
+        if include_data {
+            // Generated columns are computed again in the copy
+            let columns = self.get_table_properties(PoolRef::Postgres(pool), table_name).await?.columns;
+            let columns = columns
+                .iter()
+                .filter(|c| c.generation_expression.is_none())
+                .map(|c| quote_identifier(&DatabaseType::PostgreSQL, &c.name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            // OVERRIDING SYSTEM VALUE keeps the values of GENERATED ALWAYS identity columns
+            let sql = format!("INSERT INTO {} ({}) OVERRIDING SYSTEM VALUE SELECT {} FROM {}", target, columns, columns, source);
+            let result = sqlx::query(&sql)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to copy rows: {}", e)))?;
+            affected_rows = result.rows_affected();
+        }
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
 
         Ok(QueryResult {
             columns: vec![],
             rows: vec![],
-            affected_rows: Some(0),
+            affected_rows: Some(affected_rows),
             execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
         })
     }
 
@@ -1214,7 +2350,11 @@ impl DatabaseDriver for PostgresDriver {
                 c.data_type::text as data_type,
                 c.is_nullable::text as is_nullable,
                 c.column_default::text as column_default,
-                pgd.description::text as comment
+                pgd.description::text as comment,
+                c.is_identity::text as is_identity,
+                c.identity_generation::text as identity_generation,
+                CASE WHEN c.is_generated = 'ALWAYS' THEN c.generation_expression::text END as generation_expression,
+                c.collation_name::text as collation
             FROM information_schema.columns c
             LEFT JOIN pg_catalog.pg_statio_all_tables st
                 ON c.table_schema = st.schemaname AND c.table_name = st.relname
@@ -1295,18 +2435,32 @@ impl DatabaseDriver for PostgresDriver {
         // Get constraints
         let constraints = self.get_constraints(PoolRef::Postgres(pool), table_name).await?;
 
-        // Get row count
-        let count_query = format!(
-            "SELECT COUNT(*)::bigint as count FROM {}{}",
-            schema.as_ref().map(|s| format!("{}.", s)).unwrap_or_default(),
-            table
-        );
+        // Estimate the row count from planner statistics, summed over the leaf partitions of a
+        // partitioned table. Tables never analyzed only get a count when they are small on disk.
+        let estimate_query = r#"
+            SELECT (
+                SELECT CASE
+                    WHEN SUM(GREATEST(l.reltuples, 0)) > 0 THEN SUM(GREATEST(l.reltuples, 0))::bigint
+                    WHEN SUM(pg_relation_size(l.oid)) < 8388608 THEN 0
+                END
+                FROM pg_class l
+                WHERE l.oid = c.oid AND c.relkind IN ('r', 'm')
+                    OR c.relkind = 'p' AND l.oid IN (SELECT relid FROM pg_partition_tree(c.oid) WHERE isleaf)
+            )
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relname = $2 AND n.nspname = COALESCE($1, current_schema())
+        "#;
 
-        let row_count: Option<i64> = sqlx::query_scalar(&count_query)
+        let estimate: Option<i64> = sqlx::query_scalar(estimate_query)
+            .bind(&schema)
+            .bind(&table)
             .fetch_optional(pool)
             .await
             .ok()
             .flatten();
+        let (row_count, row_count_exact) =
+            self.row_count_from_estimate(PoolRef::Postgres(pool), table_name, estimate).await;
 
         // Get table comment
         let comment_query = r#"
@@ -1328,13 +2482,21 @@ impl DatabaseDriver for PostgresDriver {
         // Build columns
         let columns: Vec<ExtendedColumnInfo> = columns_rows.iter().map(|row| {
             let col_name: String = row.get("column_name");
+            let default_value: Option<String> = row.try_get("column_default").ok().flatten();
+            let identity_generation: Option<String> = row.try_get("identity_generation").ok().flatten();
+            // serial columns are filled from a sequence default
+            let is_serial = default_value.as_deref().is_some_and(|d| d.starts_with("nextval("));
             ExtendedColumnInfo {
                 name: col_name.clone(),
                 data_type: row.get("data_type"),
                 nullable: row.get::<String, _>("is_nullable") == "YES",
                 is_primary_key: primary_keys.contains(&col_name),
-                default_value: row.try_get("column_default").ok(),
+                default_value,
                 comment: row.try_get("comment").ok(),
+                is_identity: row.try_get::<String, _>("is_identity").is_ok_and(|v| v == "YES") || is_serial,
+                identity_always: identity_generation.as_deref() == Some("ALWAYS"),
+                generation_expression: row.try_get("generation_expression").ok().flatten(),
+                collation: row.try_get("collation").ok().flatten(),
             }
         }).collect();
 
@@ -1347,10 +2509,225 @@ impl DatabaseDriver for PostgresDriver {
             indexes,
             constraints,
             row_count,
+            row_count_exact,
             table_comment,
         })
     }
 
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let sql = format!("SELECT COUNT(*)::bigint FROM {}", quote_qualified(&DatabaseType::PostgreSQL, table_name));
+        sqlx::query_scalar(&sql)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))
+    }
+
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        // Parse schema.table format
+        let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
+            let (s, t) = table_name.split_at(dot_pos);
+            (Some(s.to_string()), t.trim_start_matches('.').to_string())
+        } else {
+            (None, table_name.to_string())
+        };
+
+        // reltuples is -1 until the table is first vacuumed or analyzed
+        let query = r#"
+            SELECT
+                pg_table_size(c.oid) as table_size,
+                pg_indexes_size(c.oid) as index_size,
+                pg_total_relation_size(c.oid) as total_size,
+                CASE WHEN c.reltuples < 0 THEN NULL ELSE c.reltuples::bigint END as approximate_rows,
+                s.n_live_tup as live_rows,
+                s.n_dead_tup as dead_rows,
+                GREATEST(s.last_vacuum, s.last_autovacuum)::text as last_vacuum,
+                GREATEST(s.last_analyze, s.last_autoanalyze)::text as last_analyze
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            LEFT JOIN pg_stat_all_tables s ON s.relid = c.oid
+            WHERE c.relname = $2
+            AND n.nspname = COALESCE($1, current_schema())
+        "#;
+
+        let row = sqlx::query(query)
+            .bind(&schema)
+            .bind(&table)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?
+            .ok_or_else(|| AppError::QueryError(format!("Table {} not found", table_name)))?;
+
+        let live_rows: Option<i64> = row.get("live_rows");
+        let dead_rows: Option<i64> = row.get("dead_rows");
+        let dead_row_ratio = match (live_rows, dead_rows) {
+            (Some(live), Some(dead)) if live + dead > 0 => Some(dead as f64 / (live + dead) as f64),
+            _ => None,
+        };
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: row.get::<Option<i64>, _>("table_size").map(|v| v as u64),
+            index_size_bytes: row.get::<Option<i64>, _>("index_size").map(|v| v as u64),
+            total_size_bytes: row.get::<Option<i64>, _>("total_size").map(|v| v as u64),
+            approximate_rows: row.get::<Option<i64>, _>("approximate_rows").map(|v| v as u64),
+            dead_rows: dead_rows.map(|v| v as u64),
+            dead_row_ratio,
+            free_bytes: None,
+            last_vacuum: row.get("last_vacuum"),
+            last_analyze: row.get("last_analyze"),
+        })
+    }
+
+    async fn get_partitions(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Option<TablePartitioning>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        // Parse schema.table format
+        let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
+            let (s, t) = table_name.split_at(dot_pos);
+            (Some(s.to_string()), t.trim_start_matches('.').to_string())
+        } else {
+            (None, table_name.to_string())
+        };
+
+        let parent = sqlx::query(
+            r#"
+            SELECT c.oid, pg_get_partkeydef(c.oid) as partition_key
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE c.relname = $2
+            AND n.nspname = COALESCE($1, current_schema())
+            AND c.relkind = 'p'
+            "#,
+        )
+        .bind(&schema)
+        .bind(&table)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get partitioning: {}", e)))?;
+        let Some(parent) = parent else {
+            return Ok(None);
+        };
+        let oid: sqlx::postgres::types::Oid = parent.get("oid");
+        let (strategy, key) = split_partition_key(&parent.get::<String, _>("partition_key"));
+
+        // The default partition sorts last; sizes of sub-partitioned partitions are those of their children
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                pn.nspname::text || '.' || pc.relname::text as name,
+                pg_get_expr(pc.relpartbound, pc.oid) as bound,
+                CASE WHEN pc.relkind = 'p'
+                    THEN (SELECT SUM(pg_total_relation_size(t.relid))::bigint FROM pg_partition_tree(pc.oid) t WHERE t.isleaf)
+                    ELSE pg_total_relation_size(pc.oid)
+                END as size_bytes,
+                CASE WHEN pc.reltuples < 0 THEN NULL ELSE pc.reltuples::bigint END as approximate_rows,
+                pg_get_partkeydef(pc.oid) as sub_partitioning
+            FROM pg_inherits i
+            JOIN pg_class pc ON pc.oid = i.inhrelid
+            JOIN pg_namespace pn ON pn.oid = pc.relnamespace
+            WHERE i.inhparent = $1
+            ORDER BY pg_get_expr(pc.relpartbound, pc.oid) = 'DEFAULT', pc.relname
+            "#,
+        )
+        .bind(oid)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to list partitions: {}", e)))?;
+
+        Ok(Some(TablePartitioning {
+            table_name: table_name.to_string(),
+            strategy,
+            key,
+            partitions: rows
+                .iter()
+                .map(|row| PartitionInfo {
+                    name: row.get("name"),
+                    bound: row.get("bound"),
+                    size_bytes: row.get::<Option<i64>, _>("size_bytes").map(|v| v as u64),
+                    approximate_rows: row.get::<Option<i64>, _>("approximate_rows").map(|v| v as u64),
+                    sub_partitioning: row.get("sub_partitioning"),
+                })
+                .collect(),
+        }))
+    }
+
+    async fn get_comments(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectComment>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT n.nspname::text || '.' || c.relname::text AS table_name,
+                   a.attname::text AS column_name,
+                   d.description
+            FROM pg_description d
+            JOIN pg_class c ON c.oid = d.objoid AND d.classoid = 'pg_class'::regclass
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            LEFT JOIN pg_attribute a ON a.attrelid = c.oid AND a.attnum = d.objsubid AND d.objsubid > 0
+            WHERE c.relkind IN ('r', 'p', 'v', 'm', 'f')
+              AND n.nspname NOT IN ('pg_catalog', 'information_schema')
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get comments: {}", e)))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| ObjectComment {
+                table: row.get("table_name"),
+                column: row.get("column_name"),
+                comment: row.get("description"),
+            })
+            .collect())
+    }
+
+    async fn comment_statements(
+        &self,
+        _pool: PoolRef<'_>,
+        table_name: &str,
+        comments: &SchemaComments,
+    ) -> AppResult<Vec<String>> {
+        let table = quote_qualified(&DatabaseType::PostgreSQL, table_name);
+        // IS NULL removes a comment
+        let literal = |comment: &str| {
+            if comment.is_empty() {
+                "NULL".to_string()
+            } else {
+                string_literal(&DatabaseType::PostgreSQL, comment)
+            }
+        };
+
+        let mut statements = Vec::with_capacity(comments.columns.len() + 1);
+        if let Some(comment) = &comments.table_comment {
+            statements.push(format!("COMMENT ON TABLE {} IS {}", table, literal(comment)));
+        }
+        for column in &comments.columns {
+            statements.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {}",
+                table,
+                quote_identifier(&DatabaseType::PostgreSQL, &column.column),
+                literal(&column.comment)
+            ));
+        }
+        Ok(statements)
+    }
+
     async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
         let pool = match pool {
             PoolRef::Postgres(p) => p,
@@ -1433,5 +2810,258 @@ impl DatabaseDriver for PostgresDriver {
 
         Ok(relationships)
     }
-}
 
+    async fn get_users_and_roles(&self, pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                r.rolname::text as name,
+                r.rolcanlogin as can_login,
+                r.rolsuper as is_superuser,
+                ARRAY(
+                    SELECT m.rolname::text
+                    FROM pg_auth_members am
+                    JOIN pg_roles m ON m.oid = am.roleid
+                    WHERE am.member = r.oid
+                    ORDER BY m.rolname
+                ) as member_of
+            FROM pg_roles r
+            WHERE r.rolname NOT LIKE 'pg\_%'
+            ORDER BY r.rolname
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get users and roles: {}", e)))?;
+
+        Ok(rows.iter().map(|row| DatabaseUser {
+            name: row.get("name"),
+            host: None,
+            can_login: row.get("can_login"),
+            is_superuser: row.get("is_superuser"),
+            member_of: row.get("member_of"),
+        }).collect())
+    }
+
+    /// Reads the table's ACL directly, so grants between other roles are listed too
+    async fn get_table_privileges(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        // Parse schema.table format
+        let (schema, table) = if let Some(dot_pos) = table_name.find('.') {
+            let (s, t) = table_name.split_at(dot_pos);
+            (Some(s.to_string()), t.trim_start_matches('.').to_string())
+        } else {
+            (None, table_name.to_string())
+        };
+
+        // A NULL ACL means the owner's default privileges
+        let query = r#"
+            SELECT
+                CASE WHEN a.grantee = 0 THEN 'PUBLIC' ELSE pg_get_userbyid(a.grantee)::text END as grantee,
+                pg_get_userbyid(a.grantor)::text as grantor,
+                a.privilege_type::text as privilege_type,
+                a.is_grantable as is_grantable
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            CROSS JOIN LATERAL aclexplode(COALESCE(c.relacl, acldefault('r', c.relowner))) AS a
+            WHERE c.relname = $2
+            AND n.nspname = COALESCE($1, current_schema())
+            ORDER BY 1, 3
+        "#;
+
+        let rows = sqlx::query(query)
+            .bind(&schema)
+            .bind(&table)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table privileges: {}", e)))?;
+
+        Ok(rows.iter().map(|row| TablePrivilege {
+            grantee: row.get("grantee"),
+            privilege_type: row.get("privilege_type"),
+            is_grantable: row.get("is_grantable"),
+            grantor: row.get("grantor"),
+            scope: PrivilegeScope::Table,
+        }).collect())
+    }
+
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                (SELECT count(*) FROM pg_stat_activity WHERE backend_type = 'client backend')::bigint as active_connections,
+                current_setting('max_connections')::bigint as max_connections,
+                EXTRACT(EPOCH FROM now() - pg_postmaster_start_time())::bigint as uptime_seconds,
+                (
+                    SELECT sum(blks_hit)::float8 / NULLIF(sum(blks_hit) + sum(blks_read), 0)
+                    FROM pg_stat_database
+                ) as cache_hit_ratio
+        "#;
+
+        let row = sqlx::query(query)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+
+        // pg_database_size fails for databases the user cannot connect to
+        let sizes_query = r#"
+            SELECT datname::text as name, pg_database_size(oid) as size_bytes
+            FROM pg_database
+            WHERE NOT datistemplate AND has_database_privilege(oid, 'CONNECT')
+            ORDER BY size_bytes DESC
+        "#;
+
+        let sizes = sqlx::query(sizes_query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get database sizes: {}", e)))?;
+
+        Ok(ServerStats {
+            active_connections: row.get::<Option<i64>, _>("active_connections").map(|v| v as u64),
+            max_connections: row.get::<Option<i64>, _>("max_connections").map(|v| v as u64),
+            uptime_seconds: row.get::<Option<i64>, _>("uptime_seconds").map(|v| v as u64),
+            cache_hit_ratio: row.get("cache_hit_ratio"),
+            database_sizes: sizes.iter().map(|row| DatabaseSize {
+                name: row.get("name"),
+                size_bytes: row.get::<i64, _>("size_bytes") as u64,
+            }).collect(),
+        })
+    }
+
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let query = r#"
+            SELECT
+                pid,
+                usename::text as user_name,
+                datname::text as database_name,
+                client_addr::text as client,
+                state,
+                query,
+                (EXTRACT(EPOCH FROM clock_timestamp() - query_start) * 1000)::bigint as duration_ms
+            FROM pg_stat_activity
+            WHERE backend_type = 'client backend'
+            AND state IS DISTINCT FROM 'idle'
+            AND pid <> pg_backend_pid()
+            ORDER BY query_start
+        "#;
+
+        let rows = sqlx::query(query)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        Ok(rows.iter().map(|row| ActiveQuery {
+            session_id: row.get::<i32, _>("pid").to_string(),
+            user: row.get("user_name"),
+            database: row.get("database_name"),
+            client: row.get("client"),
+            state: row.get("state"),
+            query: row.get("query"),
+            duration_ms: row.get::<Option<i64>, _>("duration_ms").map(|v| v.max(0) as u64),
+        }).collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let pid: i32 = session_id.parse()
+            .map_err(|_| AppError::ValidationError(format!("Invalid session id: {}", session_id)))?;
+
+        let terminated: bool = sqlx::query_scalar("SELECT pg_terminate_backend($1)")
+            .bind(pid)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to kill session: {}", e)))?;
+
+        if !terminated {
+            return Err(AppError::QueryError(format!("Session {} not found", pid)));
+        }
+        Ok(())
+    }
+
+    /// Needs the pg_stat_statements extension
+    async fn get_slow_queries(&self, pool: PoolRef<'_>, order: SlowQueryOrder, limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        let pool = match pool {
+            PoolRef::Postgres(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for Postgres driver".to_string())),
+        };
+
+        let installed: Option<i32> = sqlx::query_scalar("SELECT 1 FROM pg_extension WHERE extname = 'pg_stat_statements'")
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get slow queries: {}", e)))?;
+        if installed.is_none() {
+            return Err(AppError::QueryError(
+                "pg_stat_statements is not installed; add it to shared_preload_libraries and run CREATE EXTENSION pg_stat_statements".to_string(),
+            ));
+        }
+
+        // PostgreSQL 13 renamed the *_time columns to *_exec_time
+        let version: i32 = sqlx::query_scalar("SELECT current_setting('server_version_num')::int")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get slow queries: {}", e)))?;
+        let (total, mean, max) = if version >= 130000 {
+            ("total_exec_time", "mean_exec_time", "max_exec_time")
+        } else {
+            ("total_time", "mean_time", "max_time")
+        };
+        let order_column = match order {
+            SlowQueryOrder::TotalTime => total,
+            SlowQueryOrder::MeanTime => mean,
+            SlowQueryOrder::Calls => "calls",
+        };
+
+        let query = format!(r#"
+            SELECT
+                s.query,
+                s.calls,
+                s.{total} as total_time,
+                s.{mean} as mean_time,
+                s.{max} as max_time,
+                s.rows,
+                d.datname::text as database_name
+            FROM pg_stat_statements s
+            LEFT JOIN pg_database d ON d.oid = s.dbid
+            ORDER BY s.{order_column} DESC
+            LIMIT $1
+        "#);
+
+        let rows = sqlx::query(&query)
+            .bind(limit as i64)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get slow queries: {}", e)))?;
+
+        Ok(rows.iter().map(|row| SlowQueryEntry {
+            query: row.get::<Option<String>, _>("query").unwrap_or_default(),
+            calls: row.get::<i64, _>("calls") as u64,
+            total_time_ms: row.get("total_time"),
+            mean_time_ms: row.get("mean_time"),
+            max_time_ms: row.get("max_time"),
+            rows: row.get::<Option<i64>, _>("rows").map(|v| v as u64),
+            database: row.get("database_name"),
+        }).collect())
+    }
+}
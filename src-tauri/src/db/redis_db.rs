@@ -0,0 +1,657 @@
+//! Redis driver. Redis has no tables, so the table-oriented trait methods are
+//! mostly empty; keys are browsed through the dedicated key commands instead.
+
+use crate::db::{DatabaseDriver, PoolRef};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseUser, IndexInfo,
+    NewColumn, QueryResult, RedisKeyInfo, RedisScanResult, RedisValue, TableInfo, TableProperties, TablePrivilege,
+    TableRelationship, TableSchema, TestConnectionResult, TruncateOptions, ColumnInfo, ActiveQuery, ServerStats,
+    TableStats, SlowQueryEntry, SlowQueryOrder
+};
+use async_trait::async_trait;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use redis::aio::ConnectionManager;
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Maximum number of elements returned when displaying a collection value
+const VALUE_ELEMENT_LIMIT: isize = 1000;
+
+/// Default COUNT hint for SCAN
+const DEFAULT_SCAN_COUNT: u32 = 100;
+
+pub struct RedisDriver;
+
+/// Base64 encode binary data
+fn base64_encode(data: &[u8]) -> String {
+    use base64::{Engine as _, engine::general_purpose};
+    general_purpose::STANDARD.encode(data)
+}
+
+/// Decode a Redis byte string, falling back to base64 for binary values
+fn bytes_to_string(data: &[u8]) -> String {
+    match std::str::from_utf8(data) {
+        Ok(s) => s.to_string(),
+        Err(_) => format!("[base64: {}]", base64_encode(data)),
+    }
+}
+
+/// Helper methods for RedisDriver
+impl RedisDriver {
+    /// Open a connection manager (auto-reconnecting, multiplexed connection)
+    pub async fn connect(config: &ConnectionConfig) -> AppResult<ConnectionManager> {
+        let client = redis::Client::open(Self.build_connection_string(config))
+            .map_err(|e| AppError::ConnectionError(format!("Invalid Redis connection settings: {}", e)))?;
+
+        client.get_connection_manager().await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to connect to Redis: {}", e)))
+    }
+
+    /// Split a command line into arguments, honoring single and double quotes
+    fn split_command(line: &str) -> AppResult<Vec<String>> {
+        let mut args = Vec::new();
+        let mut current = String::new();
+        let mut in_token = false;
+        let mut quote: Option<char> = None;
+        let mut chars = line.trim().chars();
+
+        while let Some(c) = chars.next() {
+            match (quote, c) {
+                (Some('"'), '\\') => match chars.next() {
+                    Some('n') => current.push('\n'),
+                    Some('t') => current.push('\t'),
+                    Some(other) => current.push(other),
+                    None => break,
+                },
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), c) => current.push(c),
+                (None, '"') | (None, '\'') => {
+                    quote = Some(c);
+                    in_token = true;
+                }
+                (None, c) if c.is_whitespace() => {
+                    if in_token {
+                        args.push(std::mem::take(&mut current));
+                        in_token = false;
+                    }
+                }
+                (None, c) => {
+                    current.push(c);
+                    in_token = true;
+                }
+            }
+        }
+
+        if quote.is_some() {
+            return Err(AppError::QueryError("Unterminated quoted argument".to_string()));
+        }
+        if in_token {
+            args.push(current);
+        }
+
+        Ok(args)
+    }
+
+    /// Convert a Redis reply to a JSON value
+    fn redis_value_to_json(value: redis::Value) -> serde_json::Value {
+        use serde_json::Value;
+
+        match value {
+            redis::Value::Nil => Value::Null,
+            redis::Value::Int(n) => Value::from(n),
+            redis::Value::BulkString(data) => Value::String(bytes_to_string(&data)),
+            redis::Value::SimpleString(s) => Value::String(s),
+            redis::Value::Okay => Value::String("OK".to_string()),
+            redis::Value::Array(items) | redis::Value::Set(items) => {
+                Value::Array(items.into_iter().map(Self::redis_value_to_json).collect())
+            }
+            redis::Value::Map(pairs) => {
+                let mut object = serde_json::Map::new();
+                for (k, v) in pairs {
+                    let key = match Self::redis_value_to_json(k) {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    object.insert(key, Self::redis_value_to_json(v));
+                }
+                Value::Object(object)
+            }
+            redis::Value::Double(d) => serde_json::Number::from_f64(d).map(Value::Number).unwrap_or(Value::Null),
+            redis::Value::Boolean(b) => Value::Bool(b),
+            redis::Value::VerbatimString { text, .. } => Value::String(text),
+            redis::Value::Attribute { data, .. } => Self::redis_value_to_json(*data),
+            redis::Value::ServerError(e) => Value::String(format!("ERR {:?}", e)),
+            other => Value::String(format!("{:?}", other)),
+        }
+    }
+
+    fn ttl_from_reply(ttl: i64) -> Option<i64> {
+        // -1 means no expiry, -2 means the key does not exist
+        if ttl >= 0 { Some(ttl) } else { None }
+    }
+
+    /// Scan keys matching a glob pattern, with type and TTL for each
+    pub async fn scan_keys(
+        conn: &ConnectionManager,
+        pattern: Option<&str>,
+        cursor: u64,
+        count: Option<u32>,
+    ) -> AppResult<RedisScanResult> {
+        let mut conn = conn.clone();
+
+        let (next_cursor, keys): (u64, Vec<Vec<u8>>) = redis::cmd("SCAN")
+            .arg(cursor)
+            .arg("MATCH")
+            .arg(pattern.unwrap_or("*"))
+            .arg("COUNT")
+            .arg(count.unwrap_or(DEFAULT_SCAN_COUNT))
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to scan keys: {}", e)))?;
+
+        let mut pipe = redis::pipe();
+        for key in &keys {
+            pipe.cmd("TYPE").arg(key).cmd("TTL").arg(key);
+        }
+
+        let details: Vec<redis::Value> = if keys.is_empty() {
+            vec![]
+        } else {
+            pipe.query_async(&mut conn)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to get key details: {}", e)))?
+        };
+
+        let keys = keys
+            .iter()
+            .zip(details.chunks(2))
+            .map(|(key, detail)| RedisKeyInfo {
+                key: bytes_to_string(key),
+                key_type: redis::from_redis_value::<String>(&detail[0]).unwrap_or_else(|_| "unknown".to_string()),
+                ttl: detail.get(1)
+                    .and_then(|v| redis::from_redis_value::<i64>(v).ok())
+                    .and_then(Self::ttl_from_reply),
+            })
+            .collect();
+
+        Ok(RedisScanResult {
+            cursor: next_cursor,
+            keys,
+        })
+    }
+
+    /// Read a key's value according to its type
+    pub async fn get_value(conn: &ConnectionManager, key: &str) -> AppResult<RedisValue> {
+        let mut conn = conn.clone();
+        let query_error = |e: redis::RedisError| AppError::QueryError(format!("Failed to read key: {}", e));
+
+        let (key_type, ttl): (String, i64) = redis::pipe()
+            .cmd("TYPE").arg(key)
+            .cmd("TTL").arg(key)
+            .query_async(&mut conn)
+            .await
+            .map_err(query_error)?;
+
+        let (length_cmd, value_cmd) = match key_type.as_str() {
+            "none" => return Err(AppError::QueryError(format!("Key '{}' does not exist", key))),
+            "string" => ("STRLEN", redis::cmd("GET").arg(key).clone()),
+            "hash" => ("HLEN", redis::cmd("HGETALL").arg(key).clone()),
+            "list" => ("LLEN", redis::cmd("LRANGE").arg(key).arg(0).arg(VALUE_ELEMENT_LIMIT - 1).clone()),
+            "set" => ("SCARD", redis::cmd("SRANDMEMBER").arg(key).arg(VALUE_ELEMENT_LIMIT).clone()),
+            "zset" => (
+                "ZCARD",
+                redis::cmd("ZRANGE").arg(key).arg(0).arg(VALUE_ELEMENT_LIMIT - 1).arg("WITHSCORES").clone(),
+            ),
+            "stream" => ("XLEN", redis::cmd("XRANGE").arg(key).arg("-").arg("+").arg("COUNT").arg(VALUE_ELEMENT_LIMIT).clone()),
+            other => return Err(AppError::QueryError(format!("Unsupported Redis type '{}'", other))),
+        };
+
+        let length: u64 = redis::cmd(length_cmd).arg(key).query_async(&mut conn).await.map_err(query_error)?;
+        let raw: redis::Value = value_cmd.query_async(&mut conn).await.map_err(query_error)?;
+
+        let value = match (key_type.as_str(), Self::redis_value_to_json(raw)) {
+            // HGETALL is a flat field/value list under RESP2
+            ("hash", serde_json::Value::Array(items)) => {
+                let mut object = serde_json::Map::new();
+                for pair in items.chunks(2) {
+                    if let [serde_json::Value::String(field), value] = pair {
+                        object.insert(field.clone(), value.clone());
+                    }
+                }
+                serde_json::Value::Object(object)
+            }
+            // ZRANGE WITHSCORES alternates member and score
+            ("zset", serde_json::Value::Array(items)) => serde_json::Value::Array(
+                items.chunks(2)
+                    .map(|pair| serde_json::json!({
+                        "member": pair[0],
+                        "score": pair.get(1).and_then(|s| s.as_str()).and_then(|s| s.parse::<f64>().ok()),
+                    }))
+                    .collect(),
+            ),
+            (_, value) => value,
+        };
+
+        Ok(RedisValue {
+            key: key.to_string(),
+            key_type,
+            ttl: Self::ttl_from_reply(ttl),
+            length,
+            value,
+        })
+    }
+
+    /// SET a string value with an optional expiry in seconds
+    pub async fn set_value(conn: &ConnectionManager, key: &str, value: &str, ttl_seconds: Option<u64>) -> AppResult<()> {
+        let mut conn = conn.clone();
+
+        let mut cmd = redis::cmd("SET");
+        cmd.arg(key).arg(value);
+        if let Some(ttl) = ttl_seconds {
+            cmd.arg("EX").arg(ttl);
+        }
+
+        cmd.query_async::<()>(&mut conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to set key: {}", e)))
+    }
+
+    /// DEL one or more keys, returning how many were removed
+    pub async fn delete_keys(conn: &ConnectionManager, keys: &[String]) -> AppResult<u64> {
+        if keys.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = conn.clone();
+        redis::cmd("DEL")
+            .arg(keys)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to delete keys: {}", e)))
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for RedisDriver {
+    async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
+        let mut conn = Self::connect(config).await?;
+
+        // Get server version from INFO server
+        let info: String = redis::cmd("INFO").arg("server").query_async(&mut conn).await
+            .map_err(|e| AppError::ConnectionError(format!("Failed to get version: {}", e)))?;
+        let version = info
+            .lines()
+            .find_map(|line| line.strip_prefix("redis_version:"))
+            .map(|v| format!("Redis {}", v.trim()));
+
+        Ok(TestConnectionResult {
+            success: true,
+            message: "Redis connection successful".to_string(),
+            server_version: version,
+        })
+    }
+
+    /// Run a single command line, e.g. `HGETALL user:1`
+    async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let args = Self::split_command(sql)?;
+        let (name, rest) = args.split_first()
+            .ok_or_else(|| AppError::QueryError("Empty command".to_string()))?;
+
+        let mut cmd = redis::cmd(name);
+        for arg in rest {
+            cmd.arg(arg);
+        }
+
+        let mut conn = conn.clone();
+        let reply: redis::Value = cmd.query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Command failed: {}", e)))?;
+
+        let column = |name: &str| ColumnInfo {
+            name: name.to_string(),
+            data_type: "unknown".to_string(),
+            nullable: true,
+            is_primary_key: false,
+            native_type: None,
+            type_oid: None,
+        };
+
+        let (columns, rows) = match Self::redis_value_to_json(reply) {
+            serde_json::Value::Array(items) => (
+                vec![column("value")],
+                items.into_iter().map(|item| vec![item]).collect(),
+            ),
+            serde_json::Value::Object(map) => (
+                vec![column("field"), column("value")],
+                map.into_iter().map(|(k, v)| vec![serde_json::Value::String(k), v]).collect(),
+            ),
+            value => (vec![column("result")], vec![vec![value]]),
+        };
+
+        Ok(QueryResult {
+            columns,
+            rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn execute_with_params(&self, _pool: PoolRef<'_>, _sql: &str, _params: Vec<CellValue>) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis commands take no bound parameters".to_string()))
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    async fn insert_rows(
+        &self,
+        _pool: PoolRef<'_>,
+        _table: &str,
+        _columns: &[String],
+        _rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        Err(AppError::QueryError("Redis has no tables to insert rows into".to_string()))
+    }
+
+    async fn get_tables(&self, _pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
+        Ok(vec![])
+    }
+
+    /// Numbered logical databases, as many as the server's `databases` setting
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let mut conn = conn.clone();
+        // CONFIG is often disabled on managed servers; fall back to the default of 16
+        let count = redis::cmd("CONFIG")
+            .arg("GET")
+            .arg("databases")
+            .query_async::<Vec<String>>(&mut conn)
+            .await
+            .ok()
+            .and_then(|reply| reply.get(1).and_then(|v| v.parse::<u32>().ok()))
+            .unwrap_or(16);
+
+        Ok((0..count).map(|i| i.to_string()).collect())
+    }
+
+    async fn list_schemas(&self, _pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn create_database(&self, _pool: PoolRef<'_>, _options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("Redis has a fixed set of numbered databases".to_string()))
+    }
+
+    async fn drop_database(&self, _pool: PoolRef<'_>, _name: &str) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("Redis has a fixed set of numbered databases".to_string()))
+    }
+
+    async fn create_schema(&self, _pool: PoolRef<'_>, _options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("Redis does not support schemas".to_string()))
+    }
+
+    async fn drop_schema(&self, _pool: PoolRef<'_>, _name: &str, _cascade: bool) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("Redis does not support schemas".to_string()))
+    }
+
+    async fn get_table_schema(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<TableSchema> {
+        Err(AppError::QueryError("Redis keys have no table schema".to_string()))
+    }
+
+    async fn get_all_table_schemas(&self, _pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableSchema>> {
+        Ok(vec![])
+    }
+
+    fn build_connection_string(&self, config: &ConnectionConfig) -> String {
+        let host = config.host.as_deref().unwrap_or("localhost");
+
+        // Accept a full URI pasted into the host field
+        if host.starts_with("redis://") || host.starts_with("rediss://") {
+            return host.to_string();
+        }
+
+        let scheme = match config.ssl_mode.as_deref() {
+            None | Some("disable") => "redis",
+            Some(_) => "rediss",
+        };
+        let port = config.port.unwrap_or(6379);
+        let credentials = match (&config.username, &config.password) {
+            (Some(user), Some(pass)) if !user.is_empty() => format!(
+                "{}:{}@",
+                utf8_percent_encode(user, NON_ALPHANUMERIC),
+                utf8_percent_encode(pass, NON_ALPHANUMERIC)
+            ),
+            (_, Some(pass)) if !pass.is_empty() => format!(":{}@", utf8_percent_encode(pass, NON_ALPHANUMERIC)),
+            _ => String::new(),
+        };
+        let db: u32 = config.database.trim().parse().unwrap_or(0);
+
+        let mut url = format!("{}://{}{}:{}/{}", scheme, credentials, host, port, db);
+        if config.ssl_mode.as_deref() == Some("require") {
+            url.push_str("#insecure");
+        }
+
+        url
+    }
+
+    async fn generate_table_ddl(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<String> {
+        Err(AppError::QueryError("DDL is not available for Redis".to_string()))
+    }
+
+    /// Renames a key
+    async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let mut conn = conn.clone();
+        redis::cmd("RENAME").arg(old_name).arg(new_name).query_async::<()>(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to rename key: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(0),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn add_column(&self, _pool: PoolRef<'_>, _table_name: &str, _column: &NewColumn) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis keys have no columns".to_string()))
+    }
+
+    async fn drop_column(&self, _pool: PoolRef<'_>, _table_name: &str, _column_name: &str) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis keys have no columns".to_string()))
+    }
+
+    async fn rename_column(&self, _pool: PoolRef<'_>, _table_name: &str, _old_name: &str, _new_name: &str) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis keys have no columns".to_string()))
+    }
+
+    async fn alter_column_type(
+        &self,
+        _pool: PoolRef<'_>,
+        _table_name: &str,
+        _column_name: &str,
+        _data_type: &str,
+        _nullable: bool,
+    ) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis keys have no columns".to_string()))
+    }
+
+    async fn truncate_table(&self, _pool: PoolRef<'_>, _table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        Err(AppError::QueryError("Redis keys cannot be truncated; delete the key instead".to_string()))
+    }
+
+    /// Copies a key and its value (Redis 6.2+)
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        if !include_data {
+            return Err(AppError::QueryError("A Redis key cannot be copied without its value".to_string()));
+        }
+
+        let start = Instant::now();
+
+        let mut conn = conn.clone();
+        let copied: bool = redis::cmd("COPY").arg(table_name).arg(new_name).query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to copy key: {}", e)))?;
+        if !copied {
+            return Err(AppError::QueryError(format!(
+                "Key '{}' does not exist or '{}' already exists",
+                table_name, new_name
+            )));
+        }
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(1),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn get_indexes(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<IndexInfo>> {
+        Ok(vec![])
+    }
+
+    async fn get_constraints(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<ConstraintInfo>> {
+        Ok(vec![])
+    }
+
+    async fn get_table_properties(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<TableProperties> {
+        Err(AppError::QueryError("Redis keys have no table properties".to_string()))
+    }
+
+    async fn get_table_stats(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<TableStats> {
+        Err(AppError::QueryError("Redis keys have no table statistics".to_string()))
+    }
+
+    async fn count_exact_rows(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<i64> {
+        Err(AppError::QueryError("Redis keys have no rows to count".to_string()))
+    }
+
+    async fn get_table_relationships(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TableRelationship>> {
+        Ok(vec![])
+    }
+
+    async fn get_users_and_roles(&self, _pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for Redis".to_string()))
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError("User and privilege introspection is not supported for Redis".to_string()))
+    }
+
+    /// Redis does not report sizes per database, so `database_sizes` is empty
+    async fn get_server_stats(&self, pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let mut conn = conn.clone();
+        let info: String = redis::cmd("INFO").query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get server stats: {}", e)))?;
+        let field = |name: &str| -> Option<u64> {
+            info.lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+                .and_then(|v| v.trim().parse().ok())
+        };
+
+        let cache_hit_ratio = match (field("keyspace_hits"), field("keyspace_misses")) {
+            (Some(hits), Some(misses)) if hits + misses > 0 => Some(hits as f64 / (hits + misses) as f64),
+            _ => None,
+        };
+
+        Ok(ServerStats {
+            active_connections: field("connected_clients"),
+            max_connections: field("maxclients"),
+            uptime_seconds: field("uptime_in_seconds"),
+            cache_hit_ratio,
+            database_sizes: vec![],
+        })
+    }
+
+    /// Every client but this one; Redis runs one command at a time, so only
+    /// blocked clients (BLPOP and the like) have a `duration_ms`
+    async fn get_active_queries(&self, pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let mut conn = conn.clone();
+        let own_id: i64 = redis::cmd("CLIENT").arg("ID").query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+        let list: String = redis::cmd("CLIENT").arg("LIST").query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to get active queries: {}", e)))?;
+
+        Ok(list
+            .lines()
+            .map(|line| {
+                line.split(' ')
+                    .filter_map(|pair| pair.split_once('='))
+                    .collect::<HashMap<&str, &str>>()
+            })
+            .filter(|client| client.get("id").and_then(|id| id.parse::<i64>().ok()) != Some(own_id))
+            .map(|client| {
+                let field = |name: &str| client.get(name).filter(|v| !v.is_empty()).map(|v| v.to_string());
+                let blocked = client.get("flags").is_some_and(|flags| flags.contains('b'));
+                ActiveQuery {
+                    session_id: field("id").unwrap_or_default(),
+                    user: field("user"),
+                    database: field("db"),
+                    client: field("addr"),
+                    state: field("flags"),
+                    query: field("cmd"),
+                    duration_ms: field("idle")
+                        .filter(|_| blocked)
+                        .and_then(|idle| idle.parse::<u64>().ok())
+                        .map(|seconds| seconds * 1000),
+                }
+            })
+            .collect())
+    }
+
+    async fn kill_session(&self, pool: PoolRef<'_>, session_id: &str) -> AppResult<()> {
+        let conn = match pool {
+            PoolRef::Redis(c) => c,
+            _ => return Err(AppError::QueryError("Invalid pool type for Redis driver".to_string())),
+        };
+
+        let mut conn = conn.clone();
+        let killed: u64 = redis::cmd("CLIENT").arg("KILL").arg("ID").arg(session_id).query_async(&mut conn).await
+            .map_err(|e| AppError::QueryError(format!("Failed to kill session: {}", e)))?;
+
+        if killed == 0 {
+            return Err(AppError::QueryError(format!("Session {} not found", session_id)));
+        }
+        Ok(())
+    }
+
+    async fn get_slow_queries(&self, _pool: PoolRef<'_>, _order: SlowQueryOrder, _limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        Err(AppError::ValidationError("Slow query analysis is not supported for Redis".to_string()))
+    }
+}
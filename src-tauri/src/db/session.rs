@@ -0,0 +1,69 @@
+//! Editor tab sessions. Each query tab can keep a connection of its own, taken
+//! from the connection's pool (SQL Server and Oracle, which have no pool, open
+//! another client, and DuckDB another connection to the same database), so a
+//! long query in one tab leaves the rest of the pool free for schema browsing
+//! and other tabs. Session state such as `SET` variables and temporary tables also
+//! stays with the tab: the connection is closed when the session ends instead
+//! of going back to the pool.
+
+use crate::db::{
+    BatchHandler, DatabaseDriver, DuckDbClient, DuckDbDriver, MssqlClient, MssqlDriver, MySqlDriver, OracleClient, OracleDriver,
+    PoolRef, PostgresDriver, QueryLimits, SqliteDriver,
+};
+use crate::error::AppResult;
+use crate::models::QueryResult;
+use sqlx::pool::PoolConnection;
+use sqlx::{MySql, Postgres, Sqlite};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// A connection held by one editor tab
+pub enum Session {
+    Postgres(PoolConnection<Postgres>),
+    MySql(PoolConnection<MySql>),
+    Sqlite(PoolConnection<Sqlite>),
+    Mssql(Box<Mutex<MssqlClient>>),
+    DuckDb(DuckDbClient),
+    Oracle(OracleClient),
+}
+
+/// Session shared between the manager and the query running on it
+pub type SharedSession = Arc<Mutex<Session>>;
+
+impl Session {
+    /// The session's client in place of the shared one, for databases whose
+    /// clients hold a single connection
+    pub fn pool_ref(&self) -> Option<PoolRef<'_>> {
+        match self {
+            Session::Mssql(client) => Some(PoolRef::Mssql(client)),
+            Session::DuckDb(client) => Some(PoolRef::DuckDb(client)),
+            Session::Oracle(client) => Some(PoolRef::Oracle(client)),
+            _ => None,
+        }
+    }
+
+    /// Execute SQL typed by the user within `limits`
+    pub async fn execute(&mut self, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        match self {
+            Session::Postgres(conn) => PostgresDriver.execute_on_connection(conn, sql, limits).await,
+            Session::MySql(conn) => MySqlDriver.execute_on_connection(conn, sql, limits).await,
+            Session::Sqlite(conn) => SqliteDriver.execute_on_connection(conn, sql, limits).await,
+            Session::Mssql(client) => MssqlDriver.execute_query_limited(PoolRef::Mssql(client), sql, limits).await,
+            Session::DuckDb(client) => DuckDbDriver.execute_query_limited(PoolRef::DuckDb(client), sql, limits).await,
+            Session::Oracle(client) => OracleDriver.execute_query_limited(PoolRef::Oracle(client), sql, limits).await,
+        }
+    }
+
+    /// Run a single statement that returns rows, handing them to `on_batch`
+    /// `batch_size` at a time as `DatabaseDriver::stream_query` does
+    pub async fn stream(&mut self, sql: &str, batch_size: usize, on_batch: &mut BatchHandler<'_>) -> AppResult<usize> {
+        match self {
+            Session::Postgres(conn) => PostgresDriver.stream_on_connection(conn, sql, batch_size, on_batch).await,
+            Session::MySql(conn) => MySqlDriver.stream_on_connection(conn, sql, batch_size, on_batch).await,
+            Session::Sqlite(conn) => SqliteDriver.stream_on_connection(conn, sql, batch_size, on_batch).await,
+            Session::Mssql(client) => MssqlDriver.stream_query(PoolRef::Mssql(client), sql, batch_size, on_batch).await,
+            Session::DuckDb(client) => DuckDbDriver.stream_query(PoolRef::DuckDb(client), sql, batch_size, on_batch).await,
+            Session::Oracle(client) => OracleDriver.stream_query(PoolRef::Oracle(client), sql, batch_size, on_batch).await,
+        }
+    }
+}
@@ -1,16 +1,423 @@
-use crate::db::{DatabaseDriver, PoolRef};
+use crate::db::{
+    column_definition, combine_result_sets, definition_mentions, insert_statement, placeholder, quote_identifier, BatchHandler,
+    DatabaseDriver, DdlKind, DdlObject, PoolRef, QueryLimits, RowBatches,
+};
 use crate::error::{AppError, AppResult};
 use crate::models::{
-    ConnectionConfig, ConstraintInfo, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo,
-    QueryResult, TableInfo, TableProperties, TableRelationship, TableSchema,
-    TestConnectionResult, ColumnInfo
+    CellValue, ConnectionConfig, ConstraintInfo, CreateDatabaseOptions, CreateSchemaOptions, DatabaseDdlOptions, DatabaseUser,
+    DatabaseType, ExtendedColumnInfo, ForeignKeyInfo, IndexInfo, NewColumn, QueryResult, ResultSet, TableInfo, TableProperties,
+    TablePrivilege, TableRelationship, TableSchema, TestConnectionResult, TruncateOptions, ColumnInfo, ActiveQuery,
+    ServerStats, TableStats, SlowQueryEntry, SlowQueryOrder, ObjectDependency
 };
+use crate::migration::{build_migration, CurrentTable};
 use async_trait::async_trait;
-use sqlx::{sqlite::SqlitePool, Row, Column};
+use futures_util::{StreamExt, TryStreamExt};
+use sql_parse::Dialect;
+use sqlx::sqlite::{Sqlite, SqliteArguments, SqliteColumn, SqliteConnection, SqlitePool, SqliteRow};
+use sqlx::query::Query;
+use sqlx::{Acquire, Row, Column, Executor, TypeInfo};
 use std::time::Instant;
 
 pub struct SqliteDriver;
 
+/// Convert a SQLite row value at a given index to a JSON value
+fn sqlite_value_to_json(row: &sqlx::sqlite::SqliteRow, idx: usize) -> serde_json::Value {
+    use sqlx::ValueRef;
+    // NULL decodes as an empty string, so it is checked first
+    if row.try_get_raw(idx).is_ok_and(|value| value.is_null()) {
+        serde_json::Value::Null
+    } else if let Ok(val) = row.try_get::<String, _>(idx) {
+        serde_json::Value::String(val)
+    } else if let Ok(val) = row.try_get::<i64, _>(idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<i32, _>(idx) {
+        serde_json::Value::Number(val.into())
+    } else if let Ok(val) = row.try_get::<f64, _>(idx) {
+        serde_json::Value::Number(serde_json::Number::from_f64(val).unwrap_or(0.into()))
+    } else if let Ok(val) = row.try_get::<bool, _>(idx) {
+        serde_json::Value::Bool(val)
+    } else if let Ok(val) = row.try_get::<chrono::NaiveDateTime, _>(idx) {
+        serde_json::Value::String(val.to_string())
+    } else if let Ok(val) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(idx) {
+        serde_json::Value::String(val.to_rfc3339())
+    } else if let Ok(val) = row.try_get::<Vec<u8>, _>(idx) {
+        // BLOB, marked so the blob viewer and exporters can decode it
+        use base64::{Engine as _, engine::general_purpose};
+        serde_json::Value::String(format!("[base64: {}]", general_purpose::STANDARD.encode(val)))
+    } else {
+        // Fallback for unsupported types
+        serde_json::Value::String("Unsupported type".to_string())
+    }
+}
+
+/// Build result column metadata from the types SQLite reports
+fn result_columns(columns: &[SqliteColumn], nullable: impl Fn(usize) -> Option<bool>) -> Vec<ColumnInfo> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| ColumnInfo {
+            name: col.name().to_string(),
+            data_type: col.type_info().name().to_lowercase(),
+            nullable: nullable(i).unwrap_or(true),
+            is_primary_key: false,
+            native_type: Some(col.type_info().name().to_string()),
+            type_oid: None,
+        })
+        .collect()
+}
+
+/// Fetch the rows of a query, stopping after `max_rows`
+async fn fetch_rows(conn: &mut SqliteConnection, sql: &str, max_rows: Option<usize>) -> AppResult<Vec<SqliteRow>> {
+    let rows = sqlx::query(sql).fetch(conn);
+    match max_rows {
+        Some(max_rows) => rows.take(max_rows).try_collect().await,
+        None => rows.try_collect().await,
+    }
+    .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))
+}
+
+impl SqliteDriver {
+    /// Execute SQL typed by the user within `limits` on a connection the
+    /// caller holds, such as an editor tab's session. SQLite has no statement
+    /// timeout, so only the row limit applies.
+    pub async fn execute_on_connection(
+        &self,
+        conn: &mut SqliteConnection,
+        sql: &str,
+        limits: &QueryLimits,
+    ) -> AppResult<QueryResult> {
+        let start = Instant::now();
+
+        let statements = sql_parse::split_statements(sql, Dialect::Sqlite);
+        if statements.len() > 1 {
+            return execute_script(conn, &statements, start, limits.max_rows).await;
+        }
+        let sql = statements.first().map_or("", String::as_str);
+
+        let is_select = sql_parse::returns_rows(sql, Dialect::Sqlite);
+
+        if is_select {
+            let rows = fetch_rows(&mut *conn, sql, limits.max_rows).await?;
+
+            // Describing the statement gives nullability and columns even when no rows come back
+            let columns = match (&mut *conn).describe(sql).await {
+                Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+                Err(_) => rows
+                    .first()
+                    .map(|row| result_columns(row.columns(), |_| None))
+                    .unwrap_or_default(),
+            };
+
+            let json_rows: Vec<Vec<serde_json::Value>> = rows
+                .iter()
+                .map(|row| {
+                    (0..columns.len())
+                        .map(|i| sqlite_value_to_json(row, i))
+                        .collect()
+                })
+                .collect();
+
+            Ok(QueryResult {
+                columns,
+                rows: json_rows,
+                affected_rows: None,
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            })
+        } else {
+            let result = sqlx::query(sql)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+            Ok(QueryResult {
+                columns: vec![],
+                rows: vec![],
+                affected_rows: Some(result.rows_affected()),
+                execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            })
+        }
+    }
+
+    /// `stream_query` on a connection the caller holds
+    pub async fn stream_on_connection(
+        &self,
+        conn: &mut SqliteConnection,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let columns = match (&mut *conn).describe(sql).await {
+            Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+            Err(_) => vec![],
+        };
+
+        let mut batches = RowBatches::new(on_batch, columns, batch_size);
+        let mut rows = sqlx::query(sql).fetch(&mut *conn);
+        while let Some(row) = rows.try_next().await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?
+        {
+            if batches.columns.is_empty() {
+                batches.columns = result_columns(row.columns(), |_| None);
+            }
+            batches.push((0..row.len()).map(|i| sqlite_value_to_json(&row, i)).collect())?;
+        }
+        batches.finish()
+    }
+}
+
+/// Run each statement of a script in one transaction, keeping every result set
+async fn execute_script(
+    conn: &mut SqliteConnection,
+    statements: &[String],
+    start: Instant,
+    max_rows: Option<usize>,
+) -> AppResult<QueryResult> {
+    let mut tx = conn.begin().await
+        .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
+
+    let execution_result: AppResult<Vec<ResultSet>> = async {
+        let mut result_sets = Vec::with_capacity(statements.len());
+
+        for stmt in statements {
+            let stmt_start = Instant::now();
+
+            let is_select = sql_parse::returns_rows(stmt, Dialect::Sqlite);
+
+            let result_set = if is_select {
+                let rows = fetch_rows(&mut tx, stmt, max_rows).await?;
+
+                let columns = match (&mut *tx).describe(stmt).await {
+                    Ok(describe) => result_columns(describe.columns(), |i| describe.nullable(i)),
+                    Err(_) => rows
+                        .first()
+                        .map(|row| result_columns(row.columns(), |_| None))
+                        .unwrap_or_default(),
+                };
+
+                let json_rows: Vec<Vec<serde_json::Value>> = rows
+                    .iter()
+                    .map(|row| {
+                        (0..columns.len())
+                            .map(|i| sqlite_value_to_json(row, i))
+                            .collect()
+                    })
+                    .collect();
+
+                ResultSet {
+                    statement: stmt.clone(),
+                    columns,
+                    rows: json_rows,
+                    affected_rows: None,
+                    execution_time_ms: stmt_start.elapsed().as_millis() as u64,
+                }
+            } else {
+                let result = sqlx::query(stmt)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+                ResultSet {
+                    statement: stmt.clone(),
+                    columns: vec![],
+                    rows: vec![],
+                    affected_rows: Some(result.rows_affected()),
+                    execution_time_ms: stmt_start.elapsed().as_millis() as u64,
+                }
+            };
+
+            result_sets.push(result_set);
+        }
+        Ok(result_sets)
+    }.await;
+
+    match execution_result {
+        Ok(result_sets) => {
+            tx.commit().await
+                .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+            Ok(combine_result_sets(result_sets, start.elapsed().as_millis() as u64))
+        }
+        Err(e) => {
+            tx.rollback().await
+                .map_err(|rollback_err| {
+                    AppError::QueryError(format!(
+                        "Query failed: {}. Transaction rollback also failed: {}",
+                        e,
+                        rollback_err
+                    ))
+                })?;
+            Err(e)
+        }
+    }
+}
+
+/// Bind a value using the closest native type; SQLite coerces it to the column type
+fn bind_sqlite_param<'q>(query: Query<'q, Sqlite, SqliteArguments<'q>>, value: CellValue) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+    match value {
+        CellValue::Null => query.bind(None::<String>),
+        CellValue::Bool(b) => query.bind(b),
+        CellValue::Int(i) => query.bind(i),
+        CellValue::Float(f) => query.bind(f),
+        CellValue::Bytes(ref encoded) => match value.bytes() {
+            Some(bytes) => query.bind(bytes),
+            None => query.bind(encoded.clone()),
+        },
+        CellValue::Json(json) => query.bind(json.to_string()),
+        CellValue::Text(s) | CellValue::Timestamp(s) => query.bind(s),
+    }
+}
+
+/// Run a single DDL statement, prefixing failures with `context`
+async fn execute_ddl(pool: &SqlitePool, sql: &str, context: &str) -> AppResult<QueryResult> {
+    let start = Instant::now();
+
+    sqlx::query(sql)
+        .execute(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("{}: {}", context, e)))?;
+
+    Ok(QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: Some(0),
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    })
+}
+
+/// Apply `edit` to the table's schema. ALTER TABLE is used where SQLite supports the
+/// change; otherwise the table is copied into a new one with the edited schema.
+async fn migrate_table(
+    pool: PoolRef<'_>,
+    table_name: &str,
+    edit: impl FnOnce(&mut TableSchema) -> AppResult<()>,
+) -> AppResult<QueryResult> {
+    let current = SqliteDriver.get_table_schema(pool, table_name).await?;
+    let indexes = SqliteDriver.get_indexes(pool, table_name).await?;
+    let relationships = SqliteDriver.get_table_relationships(pool, table_name).await?;
+
+    let mut desired = current.clone();
+    edit(&mut desired)?;
+
+    let table = CurrentTable {
+        schema: &current,
+        indexes: &indexes,
+        relationships: &relationships,
+    };
+    let plan = build_migration(&DatabaseType::SQLite, table, table_name, &desired, &[], None)?;
+
+    let pool = match pool {
+        PoolRef::Sqlite(p) => p,
+        _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+    };
+
+    let start = Instant::now();
+
+    // PRAGMA foreign_keys only affects the connection it runs on
+    let mut conn = pool.acquire().await
+        .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+
+    for statement in &plan.statements {
+        if let Err(e) = sqlx::query(statement).execute(&mut *conn).await {
+            if plan.rebuild {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                let _ = sqlx::query("PRAGMA foreign_keys = ON").execute(&mut *conn).await;
+            }
+            return Err(AppError::QueryError(format!("Failed to alter table: {}", e)));
+        }
+    }
+
+    Ok(QueryResult {
+        columns: vec![],
+        rows: vec![],
+        affected_rows: Some(0),
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        result_sets: vec![],
+    })
+}
+
+/// Point a stored CREATE INDEX statement at another table under a new index name.
+/// None if the statement does not have the expected shape.
+fn retarget_index(sql: &str, index_name: &str, table_name: &str) -> Option<String> {
+    let upper = sql.to_uppercase();
+    let on = upper.find(" ON ")?;
+    let columns = on + sql[on..].find('(')?;
+    let unique = if upper.starts_with("CREATE UNIQUE") { "UNIQUE " } else { "" };
+
+    Some(format!(
+        "CREATE {}INDEX {} ON {} {}",
+        unique,
+        quote_identifier(&DatabaseType::SQLite, index_name),
+        quote_identifier(&DatabaseType::SQLite, table_name),
+        &sql[columns..]
+    ))
+}
+
+/// The named column of an edited schema
+fn schema_column<'a>(schema: &'a mut TableSchema, column_name: &str) -> AppResult<&'a mut ColumnInfo> {
+    schema.columns.iter_mut().find(|c| c.name == column_name).ok_or_else(|| {
+        AppError::ValidationError(format!("Column '{}' does not exist in {}", column_name, schema.table_name))
+    })
+}
+
+/// Definition of a column in a stored CREATE TABLE statement, e.g. `total REAL AS (price * qty) STORED`
+fn stored_column_definition<'a>(ddl: &'a str, column_name: &str) -> Option<&'a str> {
+    let body = &ddl[ddl.find('(')? + 1..ddl.rfind(')')?];
+    let mut parts = Vec::new();
+    let (mut depth, mut start, mut quote) = (0, 0, None);
+    for (i, c) in body.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&body[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&body[start..]);
+
+    parts.into_iter().map(str::trim).find(|part| {
+        let name = part.split_whitespace().next().unwrap_or_default();
+        name.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']')).eq_ignore_ascii_case(column_name)
+    })
+}
+
+/// Expression of a generated column definition
+fn generation_expression(definition: &str) -> Option<String> {
+    let upper = definition.to_uppercase();
+    let (as_pos, _) = upper.match_indices("AS").find(|(i, _)| {
+        upper[..*i].ends_with(char::is_whitespace) && upper[i + 2..].trim_start().starts_with('(')
+    })?;
+    let open = as_pos + definition[as_pos..].find('(')?;
+    let mut depth = 0;
+    for (i, c) in definition[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(definition[open + 1..open + i].trim().to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Collation named by a `COLLATE` clause of a column definition
+fn column_collation(definition: &str) -> Option<String> {
+    let upper = definition.to_uppercase();
+    let start = upper.find(" COLLATE ")? + " COLLATE ".len();
+    definition[start..].split_whitespace().next().map(|name| name.trim_matches(|c| matches!(c, '"' | '\'' | ',')).to_string())
+}
+
 #[async_trait]
 impl DatabaseDriver for SqliteDriver {
     async fn test_connection(&self, config: &ConnectionConfig) -> AppResult<TestConnectionResult> {
@@ -35,107 +442,153 @@ impl DatabaseDriver for SqliteDriver {
     }
 
     async fn execute_query(&self, pool: PoolRef<'_>, sql: &str) -> AppResult<QueryResult> {
+        self.execute_query_limited(pool, sql, &QueryLimits::default()).await
+    }
+
+    async fn execute_query_limited(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.execute_on_connection(&mut conn, sql, limits).await
+    }
+
+    /// SQLite has no read-only transactions; `query_only` refuses writes on the connection instead
+    async fn execute_read_only(&self, pool: PoolRef<'_>, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        conn.execute("PRAGMA query_only = ON")
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to make the connection read-only: {}", e)))?;
+        let result = self.execute_on_connection(&mut conn, sql, limits).await;
+        if conn.execute("PRAGMA query_only = OFF").await.is_err() {
+            conn.close_on_drop();
+        }
+        result
+    }
+
+    async fn stream_query(
+        &self,
+        pool: PoolRef<'_>,
+        sql: &str,
+        batch_size: usize,
+        on_batch: &mut BatchHandler<'_>,
+    ) -> AppResult<usize> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let mut conn = pool.acquire().await
+            .map_err(|e| AppError::QueryError(format!("Failed to acquire connection: {}", e)))?;
+        self.stream_on_connection(&mut conn, sql, batch_size, on_batch).await
+    }
+
+    async fn execute_with_params(&self, pool: PoolRef<'_>, sql: &str, params: Vec<CellValue>) -> AppResult<QueryResult> {
         let pool = match pool {
             PoolRef::Sqlite(p) => p,
             _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
         };
 
         let start = Instant::now();
-        
-        let mut clean_sql = sql.trim();
-        while clean_sql.starts_with("--") || clean_sql.starts_with("/*") {
-            if clean_sql.starts_with("--") {
-                if let Some(newline_pos) = clean_sql.find('\n') {
-                    clean_sql = clean_sql[newline_pos..].trim();
-                } else {
-                    clean_sql = "";
-                    break;
-                }
-            } else if clean_sql.starts_with("/*") {
-                if let Some(end_pos) = clean_sql.find("*/") {
-                    clean_sql = clean_sql[end_pos + 2..].trim();
-                } else {
-                    break;
-                }
-            }
+
+        let describe = pool.describe(sql).await
+            .map_err(|e| AppError::QueryError(format!("Failed to prepare query: {}", e)))?;
+
+        let mut query = sqlx::query(sql);
+        for value in params {
+            query = bind_sqlite_param(query, value);
         }
 
-        let sql_upper = clean_sql.to_uppercase();
-        let is_select = sql_upper.starts_with("SELECT") || sql_upper.starts_with("WITH") || sql_upper.starts_with("PRAGMA");
-        
-        if is_select {
-            let rows = sqlx::query(sql)
-                .fetch_all(pool)
-                .await
-                .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-            
-            if rows.is_empty() {
-                return Ok(QueryResult {
-                    columns: vec![],
-                    rows: vec![],
-                    affected_rows: None,
-                    execution_time_ms: start.elapsed().as_millis() as u64,
-                });
-            }
-            
-            let columns: Vec<ColumnInfo> = rows[0]
-                .columns()
-                .iter()
-                .map(|col| ColumnInfo {
-                    name: col.name().to_string(),
-                    data_type: "unknown".to_string(),
-                    nullable: true,
-                    is_primary_key: false,
-                })
-                .collect();
-            
-            let json_rows: Vec<Vec<serde_json::Value>> = rows
-                .iter()
-                .map(|row| {
-                    (0..columns.len())
-                        .map(|i| {
-                            if let Ok(val) = row.try_get::<String, _>(i) {
-                                serde_json::Value::String(val)
-                            } else if let Ok(val) = row.try_get::<i64, _>(i) {
-                                serde_json::Value::Number(val.into())
-                            } else if let Ok(val) = row.try_get::<i32, _>(i) {
-                                serde_json::Value::Number(val.into())
-                            } else if let Ok(val) = row.try_get::<f64, _>(i) {
-                                serde_json::Value::Number(serde_json::Number::from_f64(val).unwrap_or(0.into()))
-                            } else if let Ok(val) = row.try_get::<bool, _>(i) {
-                                serde_json::Value::Bool(val)
-                            } else if let Ok(val) = row.try_get::<chrono::NaiveDateTime, _>(i) {
-                                serde_json::Value::String(val.to_string())
-                            } else if let Ok(val) = row.try_get::<chrono::DateTime<chrono::Utc>, _>(i) {
-                                serde_json::Value::String(val.to_rfc3339())
-                            } else {
-                                // Fallback for unsupported types
-                                serde_json::Value::String("Unsupported type".to_string())
-                            }
-                        })
-                        .collect()
-                })
-                .collect();
-            
-            Ok(QueryResult {
-                columns,
-                rows: json_rows,
-                affected_rows: None,
-                execution_time_ms: start.elapsed().as_millis() as u64,
-            })
-        } else {
-            let result = sqlx::query(sql)
+        if describe.columns().is_empty() {
+            let result = query
                 .execute(pool)
                 .await
                 .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
-            
-            Ok(QueryResult {
+
+            return Ok(QueryResult {
                 columns: vec![],
                 rows: vec![],
                 affected_rows: Some(result.rows_affected()),
                 execution_time_ms: start.elapsed().as_millis() as u64,
+                result_sets: vec![],
+            });
+        }
+
+        let rows = query
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Query execution failed: {}", e)))?;
+
+        let columns = result_columns(describe.columns(), |i| describe.nullable(i));
+
+        let json_rows: Vec<Vec<serde_json::Value>> = rows
+            .iter()
+            .map(|row| {
+                (0..columns.len())
+                    .map(|i| sqlite_value_to_json(row, i))
+                    .collect()
             })
+            .collect();
+
+        Ok(QueryResult {
+            columns,
+            rows: json_rows,
+            affected_rows: None,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        placeholder(&DatabaseType::SQLite, index)
+    }
+
+    async fn insert_rows(
+        &self,
+        pool: PoolRef<'_>,
+        table: &str,
+        columns: &[String],
+        rows: Vec<Vec<serde_json::Value>>,
+    ) -> AppResult<Vec<(usize, String)>> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let sql = insert_statement(&DatabaseType::SQLite, table, columns);
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to start transaction: {}", e)))?;
+        let mut failed = Vec::new();
+
+        for (index, row) in rows.into_iter().enumerate() {
+            // Each row gets a savepoint so one bad row does not abort the transaction
+            let mut savepoint = tx.begin().await
+                .map_err(|e| AppError::QueryError(format!("Failed to create savepoint: {}", e)))?;
+
+            let query = row.into_iter().fold(sqlx::query(&sql), |query, value| bind_sqlite_param(query, value.into()));
+            match query.execute(&mut *savepoint).await {
+                Ok(_) => savepoint.commit().await,
+                Err(e) => {
+                    failed.push((index, e.to_string()));
+                    savepoint.rollback().await
+                }
+            }
+            .map_err(|e| AppError::QueryError(format!("Failed to release savepoint: {}", e)))?;
         }
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(failed)
     }
 
     async fn get_tables(&self, pool: PoolRef<'_>, _config: &ConnectionConfig) -> AppResult<Vec<TableInfo>> {
@@ -167,6 +620,8 @@ impl DatabaseDriver for SqliteDriver {
                     schema: None,
                     table_type: "table".to_string(),
                     row_count: None,
+                    parent_table: None,
+                    pinned: false,
                 }
             })
             .collect();
@@ -174,13 +629,49 @@ impl DatabaseDriver for SqliteDriver {
         Ok(tables)
     }
 
+    /// Attached databases: `main`, `temp` if used, and any ATTACHed files
+    async fn list_databases(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let rows = sqlx::query("PRAGMA database_list")
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to list databases: {}", e)))?;
+
+        Ok(rows.iter().map(|row| row.get::<String, _>("name")).collect())
+    }
+
+    /// Attached databases double as schema qualifiers, e.g. `temp.t`
+    async fn list_schemas(&self, pool: PoolRef<'_>) -> AppResult<Vec<String>> {
+        self.list_databases(pool).await
+    }
+
+    async fn create_database(&self, _pool: PoolRef<'_>, _options: &CreateDatabaseOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("SQLite databases are files; create one with a new connection".to_string()))
+    }
+
+    async fn drop_database(&self, _pool: PoolRef<'_>, _name: &str) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("SQLite databases are files; delete the file instead".to_string()))
+    }
+
+    async fn create_schema(&self, _pool: PoolRef<'_>, _options: &CreateSchemaOptions) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("SQLite does not support schemas".to_string()))
+    }
+
+    async fn drop_schema(&self, _pool: PoolRef<'_>, _name: &str, _cascade: bool) -> AppResult<QueryResult> {
+        Err(AppError::ValidationError("SQLite does not support schemas".to_string()))
+    }
+
     async fn get_table_schema(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableSchema> {
         let pool = match pool {
             PoolRef::Sqlite(p) => p,
             _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
         };
         // Use PRAGMA table_info to get column information
-        let pragma_query = format!("PRAGMA table_info({})", table_name);
+        let pragma_query = format!("PRAGMA table_info({})", quote_identifier(&DatabaseType::SQLite, table_name));
         
         let columns_rows = sqlx::query(&pragma_query)
             .fetch_all(pool)
@@ -205,12 +696,14 @@ impl DatabaseDriver for SqliteDriver {
                     data_type,
                     nullable: notnull == 0,
                     is_primary_key: pk > 0,
+                    native_type: None,
+                    type_oid: None,
                 }
             })
             .collect();
         
         // Get foreign keys using PRAGMA
-        let fk_query = format!("PRAGMA foreign_key_list({})", table_name);
+        let fk_query = format!("PRAGMA foreign_key_list({})", quote_identifier(&DatabaseType::SQLite, table_name));
         let fk_rows = sqlx::query(&fk_query)
             .fetch_all(pool)
             .await
@@ -297,6 +790,108 @@ impl DatabaseDriver for SqliteDriver {
         ddl.ok_or_else(|| AppError::QueryError(format!("Table '{}' not found", table_name)))
     }
 
+    async fn get_object_dependencies(&self, pool: PoolRef<'_>) -> AppResult<Vec<ObjectDependency>> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let foreign_keys = sqlx::query(
+            "SELECT DISTINCT m.name AS object, f.\"table\" AS depends_on, f.id AS id \
+             FROM sqlite_master m, pragma_foreign_key_list(m.name) f \
+             WHERE m.type = 'table' ORDER BY m.name, f.id",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get foreign keys: {}", e)))?;
+
+        // SQLite names foreign keys only when the DDL does; fall back to the table and key number
+        let mut dependencies: Vec<ObjectDependency> = foreign_keys.iter().map(|row| {
+            let object: String = row.get("object");
+            let id: i64 = row.get("id");
+            ObjectDependency {
+                constraint_name: Some(format!("{}_fk{}", object, id)),
+                object,
+                object_type: "TABLE".to_string(),
+                depends_on: row.get("depends_on"),
+            }
+        }).collect();
+
+        // SQLite keeps no record of what a view reads, so view queries are searched
+        let objects = sqlx::query(
+            "SELECT type, name, sql FROM sqlite_master WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\'",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to get view definitions: {}", e)))?;
+        let names: Vec<String> = objects.iter().map(|row| row.get("name")).collect();
+        for (row, view) in objects.iter().zip(&names) {
+            if row.get::<String, _>("type") != "view" {
+                continue;
+            }
+            let definition: String = row.get::<Option<String>, _>("sql").unwrap_or_default();
+            for name in names.iter().filter(|name| *name != view && definition_mentions(&definition, name)) {
+                dependencies.push(ObjectDependency {
+                    object: view.clone(),
+                    object_type: "VIEW".to_string(),
+                    depends_on: name.clone(),
+                    constraint_name: None,
+                });
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    async fn get_ddl_objects(
+        &self,
+        pool: PoolRef<'_>,
+        _config: &ConnectionConfig,
+        _options: &DatabaseDdlOptions,
+    ) -> AppResult<Vec<DdlObject>> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        // Objects in the order they were created; automatic indexes have no SQL.
+        // A database file has no schemas, so names are never qualified.
+        let rows = sqlx::query(
+            "SELECT type, name, sql FROM sqlite_master \
+             WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\' ORDER BY rowid",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| AppError::QueryError(format!("Failed to list schema objects: {}", e)))?;
+
+        // Virtual tables create their own shadow tables
+        let virtual_tables: Vec<String> = rows.iter()
+            .filter(|row| row.get::<String, _>("sql").to_uppercase().starts_with("CREATE VIRTUAL TABLE"))
+            .map(|row| format!("{}_", row.get::<String, _>("name")))
+            .collect();
+
+        let mut objects = Vec::new();
+        for row in &rows {
+            let object_type: String = row.get("type");
+            let name: String = row.get("name");
+            let sql: String = row.get("sql");
+            let quoted = quote_identifier(&DatabaseType::SQLite, &name);
+            let object = match object_type.as_str() {
+                "table" if virtual_tables.iter().any(|prefix| name.starts_with(prefix.as_str())) => continue,
+                "table" => DdlObject::new(DdlKind::Table, name, format!("{};", sql))
+                    .with_drop(format!("DROP TABLE IF EXISTS {};", quoted)),
+                "index" => DdlObject::new(DdlKind::Index, name, format!("{};", sql)),
+                "view" => DdlObject::new(DdlKind::View, name, format!("{};", sql))
+                    .with_drop(format!("DROP VIEW IF EXISTS {};", quoted)),
+                "trigger" => DdlObject::new(DdlKind::Trigger, name, format!("{};", sql)),
+                _ => continue,
+            };
+            objects.push(object);
+        }
+
+        Ok(objects)
+    }
+
     async fn rename_table(&self, pool: PoolRef<'_>, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
         let pool = match pool {
             PoolRef::Sqlite(p) => p,
@@ -305,7 +900,11 @@ impl DatabaseDriver for SqliteDriver {
 
         let start = Instant::now();
 
-        let sql = format!("ALTER TABLE {} RENAME TO {}", old_name, new_name);
+        let sql = format!(
+            "ALTER TABLE {} RENAME TO {}",
+            quote_identifier(&DatabaseType::SQLite, old_name),
+            quote_identifier(&DatabaseType::SQLite, new_name)
+        );
 
         sqlx::query(&sql)
             .execute(pool)
@@ -317,6 +916,183 @@ impl DatabaseDriver for SqliteDriver {
             rows: vec![],
             affected_rows: Some(0),
             execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn add_column(&self, pool: PoolRef<'_>, table_name: &str, column: &NewColumn) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        // SQLite adds NOT NULL columns only when they have a non-null default
+        let sql = format!(
+            "ALTER TABLE {} ADD COLUMN {}",
+            quote_identifier(&DatabaseType::SQLite, table_name),
+            column_definition(&DatabaseType::SQLite, column)
+        );
+        execute_ddl(pool, &sql, "Failed to add column").await
+    }
+
+    async fn drop_column(&self, pool: PoolRef<'_>, table_name: &str, column_name: &str) -> AppResult<QueryResult> {
+        // DROP COLUMN refuses key, indexed and foreign key columns, which need a table copy
+        migrate_table(pool, table_name, |schema| {
+            schema_column(schema, column_name)?;
+            schema.columns.retain(|c| c.name != column_name);
+            schema.primary_keys.retain(|k| k != column_name);
+            schema.foreign_keys.retain(|fk| fk.column != column_name);
+            Ok(())
+        })
+        .await
+    }
+
+    async fn rename_column(&self, pool: PoolRef<'_>, table_name: &str, old_name: &str, new_name: &str) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        // RENAME COLUMN needs SQLite 3.25 and also updates indexes, triggers and views
+        let sql = format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {}",
+            quote_identifier(&DatabaseType::SQLite, table_name),
+            quote_identifier(&DatabaseType::SQLite, old_name),
+            quote_identifier(&DatabaseType::SQLite, new_name)
+        );
+        execute_ddl(pool, &sql, "Failed to rename column").await
+    }
+
+    async fn alter_column_type(
+        &self,
+        pool: PoolRef<'_>,
+        table_name: &str,
+        column_name: &str,
+        data_type: &str,
+        nullable: bool,
+    ) -> AppResult<QueryResult> {
+        // SQLite cannot change a column in place, so the table is always copied
+        migrate_table(pool, table_name, |schema| {
+            let column = schema_column(schema, column_name)?;
+            column.data_type = data_type.to_string();
+            column.nullable = nullable;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn truncate_table(&self, pool: PoolRef<'_>, table_name: &str, _options: &TruncateOptions) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to begin transaction: {}", e)))?;
+
+        // SQLite has no TRUNCATE; an unfiltered DELETE is optimised to the same thing
+        let result = sqlx::query(&format!("DELETE FROM {}", quote_identifier(&DatabaseType::SQLite, table_name)))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to truncate table: {}", e)))?;
+
+        // Restart AUTOINCREMENT counters; sqlite_sequence only exists once such a table does
+        let _ = sqlx::query("DELETE FROM sqlite_sequence WHERE name = ?")
+            .bind(table_name)
+            .execute(&mut *tx)
+            .await;
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(result.rows_affected()),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
+        })
+    }
+
+    async fn duplicate_table(&self, pool: PoolRef<'_>, table_name: &str, new_name: &str, include_data: bool) -> AppResult<QueryResult> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let start = Instant::now();
+
+        let create: Option<String> = sqlx::query_scalar("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to read table definition: {}", e)))?;
+        let create = create.ok_or_else(|| AppError::QueryError(format!("Table '{}' not found", table_name)))?;
+
+        // Reuse the original definition with the new name; the column list starts at the first parenthesis
+        let columns = create.find('(')
+            .ok_or_else(|| AppError::QueryError("Unexpected table definition".to_string()))?;
+        let target = quote_identifier(&DatabaseType::SQLite, new_name);
+        let create = format!("CREATE TABLE {} {}", target, &create[columns..]);
+
+        let indexes = sqlx::query("SELECT name, sql FROM sqlite_master WHERE type = 'index' AND tbl_name = ? AND sql IS NOT NULL")
+            .bind(table_name)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to read indexes: {}", e)))?;
+
+        // Generated columns are computed again in the copy
+        let copied_columns = if include_data {
+            self.get_table_properties(PoolRef::Sqlite(pool), table_name).await?.columns
+                .iter()
+                .filter(|c| c.is_insertable())
+                .map(|c| quote_identifier(&DatabaseType::SQLite, &c.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            String::new()
+        };
+
+        let mut tx = pool.begin().await
+            .map_err(|e| AppError::QueryError(format!("Failed to begin transaction: {}", e)))?;
+
+        sqlx::query(&create)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to duplicate table: {}", e)))?;
+
+        for row in &indexes {
+            let name: String = row.get("name");
+            let sql: String = row.get("sql");
+            if let Some(sql) = retarget_index(&sql, &format!("{}_{}", new_name, name), new_name) {
+                sqlx::query(&sql)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| AppError::QueryError(format!("Failed to copy index {}: {}", name, e)))?;
+            }
+        }
+
+        let mut affected_rows = 0;
+        if include_data {
+            let source = quote_identifier(&DatabaseType::SQLite, table_name);
+            let result = sqlx::query(&format!("INSERT INTO {} ({}) SELECT {} FROM {}", target, copied_columns, copied_columns, source))
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AppError::QueryError(format!("Failed to copy rows: {}", e)))?;
+            affected_rows = result.rows_affected();
+        }
+
+        tx.commit().await
+            .map_err(|e| AppError::QueryError(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(QueryResult {
+            columns: vec![],
+            rows: vec![],
+            affected_rows: Some(affected_rows),
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            result_sets: vec![],
         })
     }
 
@@ -327,7 +1103,7 @@ impl DatabaseDriver for SqliteDriver {
         };
 
         // Get index list
-        let index_query = format!("PRAGMA index_list({})", table_name);
+        let index_query = format!("PRAGMA index_list({})", quote_identifier(&DatabaseType::SQLite, table_name));
         let index_rows = sqlx::query(&index_query)
             .fetch_all(pool)
             .await
@@ -341,7 +1117,7 @@ impl DatabaseDriver for SqliteDriver {
             let origin: String = row.try_get("origin").unwrap_or_else(|_| "c".to_string());
 
             // Get columns for this index
-            let info_query = format!("PRAGMA index_info({})", name);
+            let info_query = format!("PRAGMA index_info({})", quote_identifier(&DatabaseType::SQLite, &name));
             let info_rows = sqlx::query(&info_query)
                 .fetch_all(pool)
                 .await
@@ -445,13 +1221,22 @@ impl DatabaseDriver for SqliteDriver {
             _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
         };
 
-        // Get columns using PRAGMA
-        let pragma_query = format!("PRAGMA table_info({})", table_name);
+        // Get columns using PRAGMA; table_xinfo also lists generated columns
+        let pragma_query = format!("PRAGMA table_xinfo({})", quote_identifier(&DatabaseType::SQLite, table_name));
         let columns_rows = sqlx::query(&pragma_query)
             .fetch_all(pool)
             .await
             .map_err(|e| AppError::QueryError(format!("Failed to get table info: {}", e)))?;
 
+        // Generated expressions and collations are only in the stored DDL
+        let ddl: Option<String> = sqlx::query_scalar("SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+        let pk_count = columns_rows.iter().filter(|row| row.get::<i64, _>("pk") > 0).count();
+
         let mut primary_keys = Vec::new();
         let columns: Vec<ExtendedColumnInfo> = columns_rows
             .iter()
@@ -461,6 +1246,12 @@ impl DatabaseDriver for SqliteDriver {
                 let pk: i64 = row.get("pk");
                 let data_type: String = row.get("type");
                 let default_value: Option<String> = row.try_get("dflt_value").ok();
+                // 2 and 3 are virtual and stored generated columns
+                let hidden: i64 = row.try_get("hidden").unwrap_or(0);
+                let definition = ddl.as_deref().and_then(|sql| stored_column_definition(sql, &name));
+
+                // A lone INTEGER PRIMARY KEY is an alias of the rowid
+                let is_identity = pk > 0 && pk_count == 1 && data_type.eq_ignore_ascii_case("INTEGER");
 
                 if pk > 0 {
                     primary_keys.push(name.clone());
@@ -473,12 +1264,17 @@ impl DatabaseDriver for SqliteDriver {
                     is_primary_key: pk > 0,
                     default_value,
                     comment: None, // SQLite doesn't support column comments
+                    is_identity,
+                    identity_always: false,
+                    generation_expression: (hidden >= 2)
+                        .then(|| definition.and_then(generation_expression).unwrap_or_default()),
+                    collation: definition.and_then(column_collation),
                 }
             })
             .collect();
 
         // Get foreign keys
-        let fk_query = format!("PRAGMA foreign_key_list({})", table_name);
+        let fk_query = format!("PRAGMA foreign_key_list({})", quote_identifier(&DatabaseType::SQLite, table_name));
         let fk_rows = sqlx::query(&fk_query)
             .fetch_all(pool)
             .await
@@ -499,13 +1295,19 @@ impl DatabaseDriver for SqliteDriver {
         // Get constraints
         let constraints = self.get_constraints(PoolRef::Sqlite(pool), table_name).await?;
 
-        // Get row count
-        let count_query = format!("SELECT COUNT(*) as count FROM {}", table_name);
-        let row_count: Option<i64> = sqlx::query_scalar(&count_query)
+        // Estimate the row count from the largest rowid, an index lookup that overcounts after
+        // deletes; views and WITHOUT ROWID tables have no rowid and get no estimate
+        let estimate_query = format!(
+            "SELECT COALESCE(MAX(rowid), 0) FROM {}",
+            quote_identifier(&DatabaseType::SQLite, table_name)
+        );
+        let estimate: Option<i64> = sqlx::query_scalar(&estimate_query)
             .fetch_optional(pool)
             .await
             .ok()
             .flatten();
+        let (row_count, row_count_exact) =
+            self.row_count_from_estimate(PoolRef::Sqlite(pool), table_name, estimate).await;
 
         Ok(TableProperties {
             table_name: table_name.to_string(),
@@ -516,10 +1318,75 @@ impl DatabaseDriver for SqliteDriver {
             indexes,
             constraints,
             row_count,
+            row_count_exact,
             table_comment: None, // SQLite doesn't support table comments
         })
     }
 
+    async fn count_exact_rows(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<i64> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let sql = format!("SELECT COUNT(*) FROM {}", quote_identifier(&DatabaseType::SQLite, table_name));
+        sqlx::query_scalar(&sql)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to count rows: {}", e)))
+    }
+
+    /// Sizes come from the dbstat virtual table; row counts only exist after ANALYZE
+    async fn get_table_stats(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<TableStats> {
+        let pool = match pool {
+            PoolRef::Sqlite(p) => p,
+            _ => return Err(AppError::QueryError("Invalid pool type for SQLite driver".to_string())),
+        };
+
+        let exists: Option<i64> = sqlx::query_scalar("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| AppError::QueryError(format!("Failed to get table stats: {}", e)))?;
+        if exists.is_none() {
+            return Err(AppError::QueryError(format!("Table {} not found", table_name)));
+        }
+
+        let size_query = r#"
+            SELECT
+                (SELECT SUM(pgsize) FROM dbstat WHERE name = ?) as table_size,
+                (
+                    SELECT COALESCE(SUM(pgsize), 0) FROM dbstat
+                    WHERE name IN (SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = ?)
+                ) as index_size
+        "#;
+        let sizes = sqlx::query(size_query)
+            .bind(table_name)
+            .bind(table_name)
+            .fetch_one(pool)
+            .await
+            .ok();
+        let table_size = sizes.as_ref().and_then(|row| row.get::<Option<i64>, _>("table_size")).map(|v| v as u64);
+        let index_size = sizes.as_ref().and_then(|row| row.get::<Option<i64>, _>("index_size")).map(|v| v as u64);
+
+        // sqlite_stat1 holds "rows [rows per key ...]" for the table's entries
+        let stat: Option<String> = sqlx::query_scalar("SELECT stat FROM sqlite_stat1 WHERE tbl = ? LIMIT 1")
+            .bind(table_name)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+
+        Ok(TableStats {
+            table_name: table_name.to_string(),
+            table_size_bytes: table_size,
+            index_size_bytes: index_size,
+            total_size_bytes: table_size.zip(index_size).map(|(t, i)| t + i),
+            approximate_rows: stat.and_then(|s| s.split(' ').next().and_then(|n| n.parse().ok())),
+            ..TableStats::default()
+        })
+    }
+
     async fn get_table_relationships(&self, pool: PoolRef<'_>, table_name: &str) -> AppResult<Vec<TableRelationship>> {
         let pool = match pool {
             PoolRef::Sqlite(p) => p,
@@ -529,7 +1396,7 @@ impl DatabaseDriver for SqliteDriver {
         let mut relationships = Vec::new();
 
         // Get outgoing relationships (this table's foreign keys)
-        let fk_query = format!("PRAGMA foreign_key_list({})", table_name);
+        let fk_query = format!("PRAGMA foreign_key_list({})", quote_identifier(&DatabaseType::SQLite, table_name));
         let fk_rows = sqlx::query(&fk_query)
             .fetch_all(pool)
             .await
@@ -563,7 +1430,7 @@ impl DatabaseDriver for SqliteDriver {
                 continue;
             }
 
-            let other_fk_query = format!("PRAGMA foreign_key_list({})", other_table);
+            let other_fk_query = format!("PRAGMA foreign_key_list({})", quote_identifier(&DatabaseType::SQLite, &other_table));
             let other_fk_rows = sqlx::query(&other_fk_query)
                 .fetch_all(pool)
                 .await
@@ -588,5 +1455,29 @@ impl DatabaseDriver for SqliteDriver {
 
         Ok(relationships)
     }
+
+    async fn get_users_and_roles(&self, _pool: PoolRef<'_>) -> AppResult<Vec<DatabaseUser>> {
+        Err(AppError::ValidationError("SQLite has no users or privileges".to_string()))
+    }
+
+    async fn get_table_privileges(&self, _pool: PoolRef<'_>, _table_name: &str) -> AppResult<Vec<TablePrivilege>> {
+        Err(AppError::ValidationError("SQLite has no users or privileges".to_string()))
+    }
+
+    async fn get_server_stats(&self, _pool: PoolRef<'_>) -> AppResult<ServerStats> {
+        Err(AppError::ValidationError("SQLite has no server to monitor".to_string()))
+    }
+
+    async fn get_active_queries(&self, _pool: PoolRef<'_>) -> AppResult<Vec<ActiveQuery>> {
+        Err(AppError::ValidationError("SQLite has no server to monitor".to_string()))
+    }
+
+    async fn kill_session(&self, _pool: PoolRef<'_>, _session_id: &str) -> AppResult<()> {
+        Err(AppError::ValidationError("SQLite has no server sessions".to_string()))
+    }
+
+    async fn get_slow_queries(&self, _pool: PoolRef<'_>, _order: SlowQueryOrder, _limit: u32) -> AppResult<Vec<SlowQueryEntry>> {
+        Err(AppError::ValidationError("SQLite does not keep statement statistics".to_string()))
+    }
 }
 
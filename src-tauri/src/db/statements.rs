@@ -0,0 +1,340 @@
+use crate::error::AppResult;
+use crate::models::{CellValue, ColumnInfo, ConnectionConfig, DatabaseType, NewColumn, QueryResult, ResultSet};
+
+/// Fold per-statement results into one QueryResult. The last statement that
+/// returned columns supplies the top-level rows; affected rows are summed.
+pub fn combine_result_sets(result_sets: Vec<ResultSet>, execution_time_ms: u64) -> QueryResult {
+    let affected_rows = result_sets
+        .iter()
+        .filter_map(|set| set.affected_rows)
+        .reduce(|total, affected| total + affected);
+
+    let primary = result_sets
+        .iter()
+        .rev()
+        .find(|set| !set.columns.is_empty())
+        .or(result_sets.last());
+
+    let (columns, rows) = primary
+        .map(|set| (set.columns.clone(), set.rows.clone()))
+        .unwrap_or_default();
+
+    QueryResult {
+        columns,
+        rows,
+        affected_rows,
+        execution_time_ms,
+        result_sets,
+    }
+}
+
+/// Limits on SQL run from the editor, so a runaway query cannot hang the app
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QueryLimits {
+    /// Milliseconds before the server cancels a statement
+    pub statement_timeout_ms: Option<u64>,
+    /// Most rows kept from each result set
+    pub max_rows: Option<usize>,
+}
+
+impl QueryLimits {
+    pub fn for_connection(config: &ConnectionConfig) -> Self {
+        Self {
+            statement_timeout_ms: config.pool.as_ref().and_then(|pool| pool.statement_timeout_ms).filter(|&ms| ms > 0),
+            max_rows: config.default_row_limit.filter(|&rows| rows > 0).map(|rows| rows as usize),
+        }
+    }
+
+    /// Drop the rows past `max_rows` from every result set
+    pub fn cap_rows(&self, result: &mut QueryResult) {
+        let Some(max_rows) = self.max_rows else {
+            return;
+        };
+        result.rows.truncate(max_rows);
+        for set in &mut result.result_sets {
+            set.rows.truncate(max_rows);
+        }
+    }
+}
+
+/// Receives the rows of `DatabaseDriver::stream_query` a batch at a time, with the result's columns
+pub type BatchHandler<'a> = dyn FnMut(&[ColumnInfo], Vec<Vec<serde_json::Value>>) -> AppResult<()> + Send + 'a;
+
+/// Rows read from a result stream, handed to a `BatchHandler` `batch_size` at a time
+pub struct RowBatches<'a, 'b> {
+    handler: &'a mut BatchHandler<'b>,
+    /// Set from the first row when the statement could not be described
+    pub columns: Vec<ColumnInfo>,
+    batch_size: usize,
+    batch: Vec<Vec<serde_json::Value>>,
+    rows: usize,
+}
+
+impl<'a, 'b> RowBatches<'a, 'b> {
+    pub fn new(handler: &'a mut BatchHandler<'b>, columns: Vec<ColumnInfo>, batch_size: usize) -> Self {
+        let batch_size = batch_size.max(1);
+        Self {
+            handler,
+            columns,
+            batch_size,
+            batch: Vec::with_capacity(batch_size),
+            rows: 0,
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<serde_json::Value>) -> AppResult<()> {
+        self.batch.push(row);
+        if self.batch.len() == self.batch_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> AppResult<()> {
+        let batch = std::mem::replace(&mut self.batch, Vec::with_capacity(self.batch_size));
+        self.rows += batch.len();
+        (self.handler)(&self.columns, batch)
+    }
+
+    /// Hand on the last rows and return how many were read. A result without
+    /// rows still reaches the handler once, so it learns the columns.
+    pub fn finish(mut self) -> AppResult<usize> {
+        if self.rows == 0 || !self.batch.is_empty() {
+            self.flush()?;
+        }
+        Ok(self.rows)
+    }
+}
+
+/// Bind parameter marker for the 1-based `index`th parameter of a statement
+pub fn placeholder(database_type: &DatabaseType, index: usize) -> String {
+    match database_type {
+        DatabaseType::PostgreSQL | DatabaseType::DuckDB => format!("${}", index),
+        DatabaseType::MSSQL => format!("@P{}", index),
+        DatabaseType::Oracle => format!(":{}", index),
+        DatabaseType::ClickHouse => format!("{{p{}}}", index),
+        _ => "?".to_string(),
+    }
+}
+
+/// Quoted, comma separated column names
+pub fn column_list(database_type: &DatabaseType, columns: &[String]) -> String {
+    columns.iter().map(|column| quote_identifier(database_type, column)).collect::<Vec<_>>().join(", ")
+}
+
+/// `INSERT INTO "table" ("a", "b") VALUES (<p1>, <p2>)`
+pub fn insert_statement(database_type: &DatabaseType, table: &str, columns: &[String]) -> String {
+    let placeholders: Vec<String> = (1..=columns.len()).map(|i| placeholder(database_type, i)).collect();
+    format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quote_qualified(database_type, table),
+        column_list(database_type, columns),
+        placeholders.join(", ")
+    )
+}
+
+/// `UPDATE "table" SET "a" = <p1>, "b" = <p2> WHERE <conditions>`. The columns take the
+/// first parameters, so conditions number theirs from `columns.len() + 1`.
+pub fn update_statement(database_type: &DatabaseType, table: &str, columns: &[String], conditions: &[String]) -> String {
+    let assignments: Vec<String> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| format!("{} = {}", quote_identifier(database_type, column), placeholder(database_type, i + 1)))
+        .collect();
+    format!(
+        "UPDATE {} SET {} WHERE {}",
+        quote_qualified(database_type, table),
+        assignments.join(", "),
+        conditions.join(" AND ")
+    )
+}
+
+/// `DELETE FROM "table" WHERE <conditions>`
+pub fn delete_statement(database_type: &DatabaseType, table: &str, conditions: &[String]) -> String {
+    format!("DELETE FROM {} WHERE {}", quote_qualified(database_type, table), conditions.join(" AND "))
+}
+
+/// Insert a row or, when one with the same `key_columns` exists, update its other columns.
+/// Placeholders follow `columns`, which must include the key. None where the database has no upsert.
+pub fn upsert_statement(
+    database_type: &DatabaseType,
+    table: &str,
+    columns: &[String],
+    key_columns: &[String],
+) -> Option<String> {
+    let q = |name: &str| quote_identifier(database_type, name);
+    let updated: Vec<&String> = columns.iter().filter(|column| !key_columns.contains(column)).collect();
+
+    let sql = match database_type {
+        // SQLite has the same clause since 3.24; INSERT OR REPLACE would delete the row first,
+        // firing delete triggers and cascades and resetting columns that were not given
+        DatabaseType::PostgreSQL | DatabaseType::SQLite | DatabaseType::DuckDB => {
+            let action = if updated.is_empty() {
+                "NOTHING".to_string()
+            } else {
+                let assignments: Vec<String> =
+                    updated.iter().map(|column| format!("{} = EXCLUDED.{}", q(column), q(column))).collect();
+                format!("UPDATE SET {}", assignments.join(", "))
+            };
+            format!(
+                "{} ON CONFLICT ({}) DO {}",
+                insert_statement(database_type, table, columns),
+                column_list(database_type, key_columns),
+                action
+            )
+        }
+        // MySQL matches on whichever unique key collides; assigning a key column to itself is a no-op
+        DatabaseType::MySQL => {
+            let assignments: Vec<String> = if updated.is_empty() {
+                vec![format!("{} = {}", q(&key_columns[0]), q(&key_columns[0]))]
+            } else {
+                updated.iter().map(|column| format!("{} = VALUES({})", q(column), q(column))).collect()
+            };
+            format!(
+                "{} ON DUPLICATE KEY UPDATE {}",
+                insert_statement(database_type, table, columns),
+                assignments.join(", ")
+            )
+        }
+        // Oracle selects the source row from DUAL, takes no AS before table aliases and no terminator
+        DatabaseType::MSSQL | DatabaseType::Oracle => {
+            let oracle = matches!(database_type, DatabaseType::Oracle);
+            let source: Vec<String> = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| format!("{} AS {}", placeholder(database_type, i + 1), q(column)))
+                .collect();
+            let on: Vec<String> =
+                key_columns.iter().map(|column| format!("target.{} = source.{}", q(column), q(column))).collect();
+            let values: Vec<String> = columns.iter().map(|column| format!("source.{}", q(column))).collect();
+            let mut sql = if oracle {
+                format!(
+                    "MERGE INTO {} target USING (SELECT {} FROM dual) source ON ({})",
+                    quote_qualified(database_type, table),
+                    source.join(", "),
+                    on.join(" AND ")
+                )
+            } else {
+                format!(
+                    "MERGE INTO {} WITH (HOLDLOCK) AS target USING (SELECT {}) AS source ON {}",
+                    quote_qualified(database_type, table),
+                    source.join(", "),
+                    on.join(" AND ")
+                )
+            };
+            if !updated.is_empty() {
+                let assignments: Vec<String> =
+                    updated.iter().map(|column| format!("target.{} = source.{}", q(column), q(column))).collect();
+                sql.push_str(&format!(" WHEN MATCHED THEN UPDATE SET {}", assignments.join(", ")));
+            }
+            sql.push_str(&format!(
+                " WHEN NOT MATCHED THEN INSERT ({}) VALUES ({})",
+                column_list(database_type, columns),
+                values.join(", ")
+            ));
+            if !oracle {
+                sql.push(';');
+            }
+            sql
+        }
+        DatabaseType::ClickHouse | DatabaseType::MongoDB | DatabaseType::Redis => return None,
+    };
+    Some(sql)
+}
+
+/// `"column" = <pN>` matching the `index`th parameter, or `"column" IS NULL` without one,
+/// since `= NULL` never matches
+pub fn column_condition(database_type: &DatabaseType, column: &str, index: Option<usize>) -> String {
+    match index {
+        Some(index) => format!("{} = {}", quote_identifier(database_type, column), placeholder(database_type, index)),
+        None => format!("{} IS NULL", quote_identifier(database_type, column)),
+    }
+}
+
+/// Render a JSON value as a SQL literal for the given database
+pub fn sql_literal(database_type: &DatabaseType, value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "NULL".to_string(),
+        serde_json::Value::Bool(b) => match database_type {
+            DatabaseType::MSSQL | DatabaseType::Oracle => if *b { "1" } else { "0" }.to_string(),
+            _ => if *b { "TRUE" } else { "FALSE" }.to_string(),
+        },
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::String(s) => string_literal(database_type, s),
+        other => string_literal(database_type, &other.to_string()),
+    }
+}
+
+/// Render a typed cell value as a SQL literal for the given database. Bytes
+/// that are not valid base64 are written as text, as the drivers bind them.
+pub fn cell_literal(database_type: &DatabaseType, value: &CellValue) -> String {
+    match value {
+        CellValue::Null => "NULL".to_string(),
+        CellValue::Int(value) => value.to_string(),
+        CellValue::Float(value) if value.is_finite() => value.to_string(),
+        CellValue::Float(value) => string_literal(database_type, &value.to_string()),
+        CellValue::Bool(value) => sql_literal(database_type, &serde_json::Value::Bool(*value)),
+        CellValue::Text(text) | CellValue::Timestamp(text) => string_literal(database_type, text),
+        CellValue::Json(json) => string_literal(database_type, &json.to_string()),
+        CellValue::Bytes(encoded) => match value.bytes() {
+            Some(bytes) => {
+                let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+                match database_type {
+                    DatabaseType::PostgreSQL => format!("'\\x{}'::bytea", hex),
+                    DatabaseType::MSSQL => format!("0x{}", hex),
+                    DatabaseType::ClickHouse | DatabaseType::DuckDB => format!("unhex('{}')", hex),
+                    DatabaseType::Oracle => format!("HEXTORAW('{}')", hex),
+                    _ => format!("X'{}'", hex),
+                }
+            }
+            None => string_literal(database_type, encoded),
+        },
+    }
+}
+
+/// Quote text as a SQL string literal for the given database
+pub fn string_literal(database_type: &DatabaseType, text: &str) -> String {
+    let escaped = text.replace('\'', "''");
+    match database_type {
+        // MySQL and ClickHouse treat backslash as an escape character
+        DatabaseType::MySQL | DatabaseType::ClickHouse => format!("'{}'", escaped.replace('\\', "\\\\")),
+        DatabaseType::MSSQL => format!("N'{}'", escaped),
+        _ => format!("'{}'", escaped),
+    }
+}
+
+/// Quote an identifier for the database, escaping embedded quote characters
+pub fn quote_identifier(database_type: &DatabaseType, name: &str) -> String {
+    match database_type {
+        DatabaseType::MySQL => format!("`{}`", name.replace('`', "``")),
+        DatabaseType::MSSQL => format!("[{}]", name.replace(']', "]]")),
+        _ => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+/// Quote each part of a possibly schema-qualified name (`schema.table`)
+pub fn quote_qualified(database_type: &DatabaseType, name: &str) -> String {
+    name.split('.')
+        .map(|part| quote_identifier(database_type, part))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Column definition for ADD COLUMN: quoted name, type, default and nullability
+pub fn column_definition(database_type: &DatabaseType, column: &NewColumn) -> String {
+    let data_type = match database_type {
+        // ClickHouse columns reject NULL unless the type is wrapped
+        DatabaseType::ClickHouse if column.nullable && !column.data_type.starts_with("Nullable(") => {
+            format!("Nullable({})", column.data_type)
+        }
+        _ => column.data_type.clone(),
+    };
+    let mut definition = format!("{} {}", quote_identifier(database_type, &column.name), data_type);
+    if let Some(default) = &column.default_value {
+        definition.push_str(&format!(" DEFAULT {}", default));
+    }
+    if !column.nullable {
+        definition.push_str(" NOT NULL");
+    }
+    definition
+}
@@ -0,0 +1,116 @@
+use crate::error::{AppError, AppResult};
+use crate::models::{ConnectionConfig, TlsVerifyMode};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::path::Path;
+
+/// Characters escaped in connection string query values
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'/');
+
+/// Query parameters carrying the TLS settings for a PostgreSQL URL
+pub fn postgres_tls_params(config: &ConnectionConfig) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    let tls = config.tls.clone().unwrap_or_default();
+
+    let ssl_mode = match tls.verify_mode {
+        Some(TlsVerifyMode::None) => Some("require".to_string()),
+        Some(TlsVerifyMode::Ca) => Some("verify-ca".to_string()),
+        Some(TlsVerifyMode::Full) => Some("verify-full".to_string()),
+        None => config.ssl_mode.clone(),
+    };
+
+    if let Some(mode) = ssl_mode {
+        params.push(("sslmode", mode));
+    }
+    if let Some(path) = tls.ca_cert_path {
+        params.push(("sslrootcert", path));
+    }
+    if let Some(path) = tls.client_cert_path {
+        params.push(("sslcert", path));
+    }
+    if let Some(path) = tls.client_key_path {
+        params.push(("sslkey", path));
+    }
+
+    params
+}
+
+/// Query parameters carrying the TLS settings for a MySQL URL
+pub fn mysql_tls_params(config: &ConnectionConfig) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    let tls = config.tls.clone().unwrap_or_default();
+
+    let ssl_mode = match tls.verify_mode {
+        Some(TlsVerifyMode::None) => Some("REQUIRED".to_string()),
+        Some(TlsVerifyMode::Ca) => Some("VERIFY_CA".to_string()),
+        Some(TlsVerifyMode::Full) => Some("VERIFY_IDENTITY".to_string()),
+        // Accept the PostgreSQL spellings used by the connection form
+        None => config.ssl_mode.as_deref().map(|mode| match mode {
+            "disable" => "DISABLED".to_string(),
+            "prefer" => "PREFERRED".to_string(),
+            "require" => "REQUIRED".to_string(),
+            "verify-ca" => "VERIFY_CA".to_string(),
+            "verify-full" => "VERIFY_IDENTITY".to_string(),
+            other => other.to_uppercase(),
+        }),
+    };
+
+    if let Some(mode) = ssl_mode {
+        params.push(("ssl-mode", mode));
+    }
+    if let Some(path) = tls.ca_cert_path {
+        params.push(("ssl-ca", path));
+    }
+    if let Some(path) = tls.client_cert_path {
+        params.push(("ssl-cert", path));
+    }
+    if let Some(path) = tls.client_key_path {
+        params.push(("ssl-key", path));
+    }
+
+    params
+}
+
+/// Append `params` to a URL as an encoded query string
+pub fn append_query_params(url: &mut String, params: &[(&str, String)]) {
+    for (i, (key, value)) in params.iter().enumerate() {
+        url.push(if i == 0 { '?' } else { '&' });
+        url.push_str(key);
+        url.push('=');
+        url.push_str(&utf8_percent_encode(value, QUERY_VALUE).to_string());
+    }
+}
+
+/// Check that configured certificate files exist and come in cert/key pairs
+pub fn check_tls_files(config: &ConnectionConfig) -> AppResult<()> {
+    let tls = match &config.tls {
+        Some(tls) => tls,
+        None => return Ok(()),
+    };
+
+    if tls.client_cert_path.is_some() != tls.client_key_path.is_some() {
+        return Err(AppError::ConfigError(
+            "Mutual TLS needs both a client certificate and a client key".to_string(),
+        ));
+    }
+
+    let files = [
+        ("CA certificate", &tls.ca_cert_path),
+        ("Client certificate", &tls.client_cert_path),
+        ("Client key", &tls.client_key_path),
+    ];
+
+    for (label, path) in files {
+        if let Some(path) = path {
+            if !Path::new(path).is_file() {
+                return Err(AppError::ConfigError(format!("{} not found: {}", label, path)));
+            }
+        }
+    }
+
+    Ok(())
+}
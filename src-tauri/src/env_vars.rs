@@ -0,0 +1,81 @@
+//! `${NAME}` placeholders in connection settings, filled in from the
+//! connection's `.env` file or the process environment when connecting, so
+//! hosts and secrets can stay out of connections.json.
+
+use crate::error::{AppError, AppResult};
+use crate::models::ConnectionConfig;
+use std::collections::HashMap;
+
+/// Copy of `config` with placeholders replaced. Variables in the linked `.env`
+/// file take precedence over the process environment; text such as `${1}`
+/// that is not a variable name is left as it is.
+pub fn resolve(config: &ConnectionConfig) -> AppResult<ConnectionConfig> {
+    let file_vars = match &config.env_file {
+        Some(path) if !path.trim().is_empty() => read_env_file(path.trim())?,
+        _ => HashMap::new(),
+    };
+    let lookup = |name: &str| file_vars.get(name).cloned().or_else(|| std::env::var(name).ok());
+    let fill = |value: &mut String| -> AppResult<()> {
+        *value = substitute(value, &lookup)?;
+        Ok(())
+    };
+
+    let mut resolved = config.clone();
+    fill(&mut resolved.database)?;
+    for value in [
+        &mut resolved.host,
+        &mut resolved.username,
+        &mut resolved.password,
+        &mut resolved.ssl_mode,
+        &mut resolved.file_path,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        fill(value)?;
+    }
+    if let Some(tls) = &mut resolved.tls {
+        for path in [&mut tls.ca_cert_path, &mut tls.client_cert_path, &mut tls.client_key_path].into_iter().flatten() {
+            fill(path)?;
+        }
+    }
+    Ok(resolved)
+}
+
+fn read_env_file(path: &str) -> AppResult<HashMap<String, String>> {
+    dotenvy::from_path_iter(path)
+        .and_then(|vars| vars.collect::<Result<HashMap<_, _>, _>>())
+        .map_err(|e| AppError::ConfigError(format!("Failed to read env file '{}': {}", path, e)))
+}
+
+fn substitute(value: &str, lookup: &impl Fn(&str) -> Option<String>) -> AppResult<String> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        resolved.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let name = after.find('}').map(|end| &after[..end]).filter(|name| is_variable_name(name));
+
+        match name {
+            Some(name) => {
+                let value = lookup(name).ok_or_else(|| {
+                    AppError::ConfigError(format!("Environment variable '{}' is not set", name))
+                })?;
+                resolved.push_str(&value);
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                resolved.push_str("${");
+                rest = after;
+            }
+        }
+    }
+    resolved.push_str(rest);
+    Ok(resolved)
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -9,7 +9,6 @@ pub enum AppError {
     #[error("Query execution error: {0}")]
     QueryError(String),
 
-    #[allow(dead_code)]
     #[error("Validation error: {0}")]
     ValidationError(String),
 
@@ -25,7 +24,6 @@ pub enum AppError {
     #[error("Error: {0}")]
     GenericError(String),
 
-    #[allow(dead_code)]
     #[error("Internal error: {0}")]
     Internal(String),
 }
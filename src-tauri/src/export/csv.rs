@@ -0,0 +1,47 @@
+use super::{prepare_value, ResultExporter};
+use crate::error::AppResult;
+use crate::models::{ExportOptions, QueryResult};
+use std::io::Write;
+
+/// RFC 4180 CSV with a header row
+pub struct CsvExporter {
+    pub options: ExportOptions,
+}
+
+impl CsvExporter {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    fn cell_text(value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl ResultExporter for CsvExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        if self.options.include_headers.unwrap_or(true) {
+            let header: Vec<String> = result.columns.iter().map(|c| Self::escape(&c.name)).collect();
+            write!(out, "{}\r\n", header.join(","))?;
+        }
+
+        for row in &result.rows {
+            let fields: Vec<String> = row
+                .iter()
+                .zip(&result.columns)
+                .map(|(value, column)| Self::escape(&Self::cell_text(&prepare_value(value, column, &self.options))))
+                .collect();
+            write!(out, "{}\r\n", fields.join(","))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use super::{prepare_value, unique_column_names, ResultExporter};
+use crate::error::AppResult;
+use crate::models::{ExportOptions, QueryResult};
+use std::io::Write;
+
+/// Rows as a JSON array of objects
+pub struct JsonExporter {
+    pub options: ExportOptions,
+}
+
+/// Rows as newline-delimited JSON objects
+pub struct NdjsonExporter {
+    pub options: ExportOptions,
+}
+
+/// Serialize one row as an object, keeping the column order of the result
+fn row_object(keys: &[String], row: &[serde_json::Value], result: &QueryResult, options: &ExportOptions, indent: Option<&str>) -> AppResult<String> {
+    let mut fields = Vec::with_capacity(keys.len());
+
+    for ((key, value), column) in keys.iter().zip(row).zip(&result.columns) {
+        let value = prepare_value(value, column, options);
+        let field = match indent {
+            Some(indent) => {
+                // Nested values are pretty-printed and shifted under their key
+                let rendered = serde_json::to_string_pretty(&value)?.replace('\n', &format!("\n{}  ", indent));
+                format!("{}  {}: {}", indent, serde_json::to_string(key)?, rendered)
+            }
+            None => format!("{}:{}", serde_json::to_string(key)?, serde_json::to_string(&value)?),
+        };
+        fields.push(field);
+    }
+
+    Ok(match indent {
+        Some(indent) if fields.is_empty() => format!("{}{{}}", indent),
+        Some(indent) => format!("{}{{\n{}\n{}}}", indent, fields.join(",\n"), indent),
+        None => format!("{{{}}}", fields.join(",")),
+    })
+}
+
+impl ResultExporter for JsonExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let keys = unique_column_names(&result.columns);
+        let pretty = self.options.pretty.unwrap_or(false);
+
+        if result.rows.is_empty() {
+            writeln!(out, "[]")?;
+            return Ok(());
+        }
+
+        write!(out, "[")?;
+        for (i, row) in result.rows.iter().enumerate() {
+            if i > 0 {
+                write!(out, ",")?;
+            }
+            if pretty {
+                write!(out, "\n{}", row_object(&keys, row, result, &self.options, Some("  "))?)?;
+            } else {
+                write!(out, "{}", row_object(&keys, row, result, &self.options, None)?)?;
+            }
+        }
+        if pretty {
+            writeln!(out)?;
+        }
+        writeln!(out, "]")?;
+
+        Ok(())
+    }
+}
+
+impl ResultExporter for NdjsonExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let keys = unique_column_names(&result.columns);
+
+        for row in &result.rows {
+            writeln!(out, "{}", row_object(&keys, row, result, &self.options, None)?)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Writers that turn a `QueryResult` into export files.
+
+mod csv;
+mod json;
+mod parquet;
+mod text;
+mod xlsx;
+mod zip;
+
+use crate::error::AppResult;
+use crate::models::{ColumnInfo, CopyFormat, DatabaseType, ExportFormat, ExportOptions, QueryResult};
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use std::fmt::Write as _;
+use std::io::Write;
+
+pub use self::csv::CsvExporter;
+pub use self::json::{JsonExporter, NdjsonExporter};
+pub use self::parquet::{ParquetExporter, ParquetWriter};
+pub use self::text::{HtmlExporter, InsertExporter, MarkdownExporter, TsvExporter};
+pub use self::xlsx::XlsxExporter;
+
+/// Trait implemented by every export format
+pub trait ResultExporter: Send + Sync {
+    /// Write all rows of `result` to `out`
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()>;
+}
+
+/// Factory function to get the exporter for a format
+pub fn get_exporter(format: ExportFormat, options: ExportOptions) -> Box<dyn ResultExporter> {
+    match format {
+        ExportFormat::Csv => Box::new(CsvExporter { options }),
+        ExportFormat::Json => Box::new(JsonExporter { options }),
+        ExportFormat::Ndjson => Box::new(NdjsonExporter { options }),
+        ExportFormat::Xlsx => Box::new(XlsxExporter { options }),
+        ExportFormat::Parquet => Box::new(ParquetExporter { options }),
+    }
+}
+
+/// The exporter for copying results to the clipboard; `table` and
+/// `database_type` are used by INSERT statements
+pub fn get_copy_exporter(format: CopyFormat, table: String, database_type: DatabaseType) -> Box<dyn ResultExporter> {
+    match format {
+        CopyFormat::Markdown => Box::new(MarkdownExporter),
+        CopyFormat::Html => Box::new(HtmlExporter),
+        CopyFormat::Tsv => Box::new(TsvExporter),
+        CopyFormat::Json => Box::new(JsonExporter {
+            options: ExportOptions {
+                pretty: Some(true),
+                ..Default::default()
+            },
+        }),
+        CopyFormat::Insert => Box::new(InsertExporter { table, database_type }),
+    }
+}
+
+/// Column names made unique (`id`, `id_2`, ...) so they can be used as object keys
+pub fn unique_column_names(columns: &[ColumnInfo]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(columns.len());
+    for column in columns {
+        let mut name = column.name.clone();
+        let mut suffix = 2;
+        while names.contains(&name) {
+            name = format!("{}_{}", column.name, suffix);
+            suffix += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// Apply the date and binary options to a cell based on its column type
+pub fn prepare_value(value: &serde_json::Value, column: &ColumnInfo, options: &ExportOptions) -> serde_json::Value {
+    let text = match value {
+        serde_json::Value::String(s) => s,
+        other => return other.clone(),
+    };
+
+    let data_type = column.data_type.to_lowercase();
+
+    if is_binary_type(&data_type) || text.starts_with("[base64: ") {
+        let encoded = text
+            .strip_prefix("[base64: ")
+            .and_then(|s| s.strip_suffix(']'))
+            .unwrap_or(text);
+
+        if options.binary_as_base64.unwrap_or(true) {
+            return serde_json::Value::String(encoded.to_string());
+        }
+        if let Ok(bytes) = general_purpose::STANDARD.decode(encoded) {
+            let mut hex = String::with_capacity(2 + bytes.len() * 2);
+            hex.push_str("\\x");
+            for byte in bytes {
+                let _ = write!(hex, "{:02x}", byte);
+            }
+            return serde_json::Value::String(hex);
+        }
+    }
+
+    if let Some(format) = &options.date_format {
+        if is_temporal_type(&data_type) {
+            if let Some(formatted) = format_temporal(text, format) {
+                return serde_json::Value::String(formatted);
+            }
+        }
+    }
+
+    value.clone()
+}
+
+pub fn is_binary_type(data_type: &str) -> bool {
+    ["bytea", "blob", "binary", "image"].iter().any(|t| data_type.contains(t))
+}
+
+fn is_temporal_type(data_type: &str) -> bool {
+    (data_type.contains("date") || data_type.contains("time")) && !data_type.contains("interval")
+}
+
+/// Reformat a date/time string; returns None if it does not parse or the format does not apply
+fn format_temporal(text: &str, format: &str) -> Option<String> {
+    let text = text.trim();
+    let mut out = String::new();
+
+    let written = if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        write!(out, "{}", dt.format(format))
+    } else if let Ok(dt) = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+    {
+        write!(out, "{}", dt.format(format))
+    } else if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        write!(out, "{}", date.format(format))
+    } else if let Ok(time) = NaiveTime::parse_from_str(text, "%H:%M:%S%.f") {
+        write!(out, "{}", time.format(format))
+    } else {
+        return None;
+    };
+
+    // chrono reports formats that do not fit the value (e.g. %H on a date) as fmt errors
+    written.ok().map(|_| out)
+}
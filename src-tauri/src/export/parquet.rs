@@ -0,0 +1,244 @@
+use super::{unique_column_names, ResultExporter};
+use crate::error::{AppError, AppResult};
+use crate::models::{ColumnInfo, ExportOptions, QueryResult};
+use arrow_array::{
+    ArrayRef, BinaryArray, BooleanArray, Date32Array, Float32Array, Float64Array, Int16Array, Int32Array,
+    Int64Array, RecordBatch, StringArray, Time64MicrosecondArray, TimestampMicrosecondArray, UInt64Array,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::io::Write;
+use std::sync::Arc;
+
+/// Whole-result Parquet export; large exports should use `ParquetWriter` directly
+pub struct ParquetExporter {
+    pub options: ExportOptions,
+}
+
+impl ResultExporter for ParquetExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        // ArrowWriter needs a `Send` sink, so build the file in memory first
+        let mut writer = ParquetWriter::new(Vec::new(), &result.columns)?;
+        let batch_size = self.options.batch_size.unwrap_or(10_000).max(1) as usize;
+        for rows in result.rows.chunks(batch_size) {
+            writer.write_batch(rows)?;
+        }
+        out.write_all(&writer.finish()?)?;
+        Ok(())
+    }
+}
+
+/// Streams rows into a Parquet file, one record batch per call
+pub struct ParquetWriter<W: Write + Send> {
+    writer: ArrowWriter<W>,
+    schema: SchemaRef,
+    columns: Vec<ColumnInfo>,
+}
+
+impl<W: Write + Send> ParquetWriter<W> {
+    pub fn new(out: W, columns: &[ColumnInfo]) -> AppResult<Self> {
+        if columns.is_empty() {
+            return Err(AppError::ValidationError("Query returned no columns to export".to_string()));
+        }
+
+        let fields: Vec<Field> = unique_column_names(columns)
+            .into_iter()
+            .zip(columns)
+            .map(|(name, column)| Field::new(name, arrow_type(column), true))
+            .collect();
+        let schema = Arc::new(Schema::new(fields));
+
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+        let writer = ArrowWriter::try_new(out, schema.clone(), Some(properties)).map_err(parquet_error)?;
+
+        Ok(Self {
+            writer,
+            schema,
+            columns: columns.to_vec(),
+        })
+    }
+
+    /// Convert `rows` to Arrow arrays and write them as one record batch
+    pub fn write_batch(&mut self, rows: &[Vec<serde_json::Value>]) -> AppResult<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let arrays = self
+            .schema
+            .fields()
+            .iter()
+            .zip(&self.columns)
+            .enumerate()
+            .map(|(index, (field, column))| build_array(field.data_type(), column, index, rows))
+            .collect::<AppResult<Vec<ArrayRef>>>()?;
+
+        let batch = RecordBatch::try_new(self.schema.clone(), arrays).map_err(parquet_error)?;
+        self.writer.write(&batch).map_err(parquet_error)?;
+        // Flush each batch as its own row group so memory stays bounded
+        self.writer.flush().map_err(parquet_error)
+    }
+
+    /// Write the footer and return the underlying sink
+    pub fn finish(self) -> AppResult<W> {
+        self.writer.into_inner().map_err(parquet_error)
+    }
+}
+
+fn parquet_error(e: impl std::fmt::Display) -> AppError {
+    AppError::Internal(format!("Parquet write failed: {}", e))
+}
+
+/// Arrow type for a result column; NUMERIC/DECIMAL stay strings to keep their exact value
+fn arrow_type(column: &ColumnInfo) -> DataType {
+    let data_type = column.data_type.to_lowercase();
+    if data_type.ends_with("[]") || data_type.starts_with('_') {
+        return DataType::Utf8;
+    }
+
+    let unsigned = data_type.contains("unsigned");
+    let with_time_zone = data_type.contains("with time zone");
+    let base = data_type.split(['(', ' ']).next().unwrap_or_default();
+
+    match base {
+        "bool" | "boolean" => DataType::Boolean,
+        "tinyint" => DataType::Int16,
+        "smallint" | "int2" | "smallserial" if unsigned => DataType::Int32,
+        "smallint" | "int2" | "smallserial" => DataType::Int16,
+        "int" | "int4" | "mediumint" | "serial" if unsigned => DataType::Int64,
+        "int" | "int4" | "mediumint" | "serial" => DataType::Int32,
+        // SQLite INTEGER is always 64-bit
+        "integer" | "bigint" | "int8" | "bigserial" if unsigned => DataType::UInt64,
+        "integer" | "bigint" | "int8" | "bigserial" => DataType::Int64,
+        "real" | "float4" => DataType::Float32,
+        "float" | "double" | "float8" => DataType::Float64,
+        "date" => DataType::Date32,
+        "timestamptz" => DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+        "timestamp" | "datetime" | "datetime2" | "smalldatetime" if with_time_zone => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+        }
+        "timestamp" | "datetime" | "datetime2" | "smalldatetime" => DataType::Timestamp(TimeUnit::Microsecond, None),
+        "time" if !with_time_zone => DataType::Time64(TimeUnit::Microsecond),
+        "bytea" | "blob" | "tinyblob" | "mediumblob" | "longblob" | "binary" | "varbinary" | "image" => {
+            DataType::Binary
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+/// Collect one column, failing on values that do not fit the column's Arrow type
+fn collect<T>(
+    rows: &[Vec<serde_json::Value>],
+    index: usize,
+    column: &ColumnInfo,
+    convert: impl Fn(&serde_json::Value) -> Option<T>,
+) -> AppResult<Vec<Option<T>>> {
+    rows.iter()
+        .map(|row| match row.get(index) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(value) => convert(value).map(Some).ok_or_else(|| {
+                AppError::ValidationError(format!(
+                    "Cannot write {} to Parquet column '{}' ({})",
+                    value, column.name, column.data_type
+                ))
+            }),
+        })
+        .collect()
+}
+
+fn build_array(
+    data_type: &DataType,
+    column: &ColumnInfo,
+    index: usize,
+    rows: &[Vec<serde_json::Value>],
+) -> AppResult<ArrayRef> {
+    let array: ArrayRef = match data_type {
+        DataType::Boolean => Arc::new(BooleanArray::from(collect(rows, index, column, as_bool)?)),
+        DataType::Int16 => Arc::new(Int16Array::from(collect(rows, index, column, |v| {
+            as_i64(v).and_then(|n| i16::try_from(n).ok())
+        })?)),
+        DataType::Int32 => Arc::new(Int32Array::from(collect(rows, index, column, |v| {
+            as_i64(v).and_then(|n| i32::try_from(n).ok())
+        })?)),
+        DataType::Int64 => Arc::new(Int64Array::from(collect(rows, index, column, as_i64)?)),
+        DataType::UInt64 => Arc::new(UInt64Array::from(collect(rows, index, column, |v| {
+            v.as_u64().or_else(|| v.as_str()?.trim().parse().ok())
+        })?)),
+        DataType::Float32 => Arc::new(Float32Array::from(collect(rows, index, column, |v| {
+            as_f64(v).map(|n| n as f32)
+        })?)),
+        DataType::Float64 => Arc::new(Float64Array::from(collect(rows, index, column, as_f64)?)),
+        DataType::Date32 => Arc::new(Date32Array::from(collect(rows, index, column, |v| {
+            let date = NaiveDate::parse_from_str(v.as_str()?.trim(), "%Y-%m-%d").ok()?;
+            i32::try_from((date - NaiveDate::from_ymd_opt(1970, 1, 1)?).num_days()).ok()
+        })?)),
+        DataType::Timestamp(_, tz) => Arc::new(
+            TimestampMicrosecondArray::from(collect(rows, index, column, |v| timestamp_micros(v.as_str()?))?)
+                .with_timezone_opt(tz.clone()),
+        ),
+        DataType::Time64(_) => Arc::new(Time64MicrosecondArray::from(collect(rows, index, column, |v| {
+            let time = NaiveTime::parse_from_str(v.as_str()?.trim(), "%H:%M:%S%.f").ok()?;
+            Some(time.num_seconds_from_midnight() as i64 * 1_000_000 + (time.nanosecond() / 1_000) as i64)
+        })?)),
+        DataType::Binary => {
+            let values = collect(rows, index, column, |v| {
+                let text = v.as_str()?;
+                let encoded = text
+                    .strip_prefix("[base64: ")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .unwrap_or(text);
+                general_purpose::STANDARD.decode(encoded).ok()
+            })?;
+            Arc::new(values.into_iter().collect::<BinaryArray>())
+        }
+        _ => {
+            let values = collect(rows, index, column, |v| {
+                Some(match v {
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+            })?;
+            Arc::new(StringArray::from(values))
+        }
+    };
+    Ok(array)
+}
+
+fn as_bool(value: &serde_json::Value) -> Option<bool> {
+    match value {
+        serde_json::Value::Bool(b) => Some(*b),
+        serde_json::Value::Number(n) => n.as_i64().map(|n| n != 0),
+        serde_json::Value::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "t" | "1" => Some(true),
+            "false" | "f" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn as_i64(value: &serde_json::Value) -> Option<i64> {
+    value.as_i64().or_else(|| value.as_str()?.trim().parse().ok())
+}
+
+fn as_f64(value: &serde_json::Value) -> Option<f64> {
+    value.as_f64().or_else(|| value.as_str()?.trim().parse().ok())
+}
+
+/// Microseconds since the Unix epoch; offsets are converted to UTC
+fn timestamp_micros(text: &str) -> Option<i64> {
+    let text = text.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Some(dt.timestamp_micros());
+    }
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+        .map(|dt| dt.and_utc().timestamp_micros())
+}
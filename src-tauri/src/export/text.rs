@@ -0,0 +1,131 @@
+//! Text formats for copying results to the clipboard
+
+use super::ResultExporter;
+use crate::db::{quote_identifier, quote_qualified, sql_literal};
+use crate::error::AppResult;
+use crate::models::{DatabaseType, QueryResult};
+use std::io::Write;
+
+fn cell_text(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "NULL".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// A GitHub-flavored Markdown table
+pub struct MarkdownExporter;
+
+impl MarkdownExporter {
+    fn escape(text: &str) -> String {
+        text.replace('\\', "\\\\")
+            .replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace(['\n', '\r'], "<br>")
+    }
+}
+
+impl ResultExporter for MarkdownExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let header: Vec<String> = result.columns.iter().map(|c| Self::escape(&c.name)).collect();
+        writeln!(out, "| {} |", header.join(" | "))?;
+        writeln!(out, "|{}", " --- |".repeat(result.columns.len()))?;
+        for row in &result.rows {
+            let cells: Vec<String> = row.iter().map(|value| Self::escape(&cell_text(value))).collect();
+            writeln!(out, "| {} |", cells.join(" | "))?;
+        }
+        Ok(())
+    }
+}
+
+/// An HTML table, pasteable into documents and spreadsheets
+pub struct HtmlExporter;
+
+impl HtmlExporter {
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+}
+
+impl ResultExporter for HtmlExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        writeln!(out, "<table>")?;
+        writeln!(out, "  <thead>")?;
+        write!(out, "    <tr>")?;
+        for column in &result.columns {
+            write!(out, "<th>{}</th>", Self::escape(&column.name))?;
+        }
+        writeln!(out, "</tr>")?;
+        writeln!(out, "  </thead>")?;
+        writeln!(out, "  <tbody>")?;
+        for row in &result.rows {
+            write!(out, "    <tr>")?;
+            for value in row {
+                write!(out, "<td>{}</td>", Self::escape(&cell_text(value)))?;
+            }
+            writeln!(out, "</tr>")?;
+        }
+        writeln!(out, "  </tbody>")?;
+        writeln!(out, "</table>")?;
+        Ok(())
+    }
+}
+
+/// Tab-separated values with a header row, as spreadsheets paste them.
+/// NULL is an empty cell.
+pub struct TsvExporter;
+
+impl TsvExporter {
+    fn escape(field: &str) -> String {
+        if field.contains(['\t', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+impl ResultExporter for TsvExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let header: Vec<String> = result.columns.iter().map(|c| Self::escape(&c.name)).collect();
+        writeln!(out, "{}", header.join("\t"))?;
+        for row in &result.rows {
+            let fields: Vec<String> = row
+                .iter()
+                .map(|value| match value {
+                    serde_json::Value::Null => String::new(),
+                    value => Self::escape(&cell_text(value)),
+                })
+                .collect();
+            writeln!(out, "{}", fields.join("\t"))?;
+        }
+        Ok(())
+    }
+}
+
+/// One INSERT statement per row, quoted for the database
+pub struct InsertExporter {
+    pub table: String,
+    pub database_type: DatabaseType,
+}
+
+impl ResultExporter for InsertExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let table = quote_qualified(&self.database_type, &self.table);
+        let columns: Vec<String> = result
+            .columns
+            .iter()
+            .map(|column| quote_identifier(&self.database_type, &column.name))
+            .collect();
+        let columns = columns.join(", ");
+        for row in &result.rows {
+            let values: Vec<String> = row.iter().map(|value| sql_literal(&self.database_type, value)).collect();
+            writeln!(out, "INSERT INTO {} ({}) VALUES ({});", table, columns, values.join(", "))?;
+        }
+        Ok(())
+    }
+}
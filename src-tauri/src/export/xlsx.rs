@@ -0,0 +1,324 @@
+use super::zip::ZipWriter;
+use super::{is_temporal_type, prepare_value, ResultExporter};
+use crate::error::{AppError, AppResult};
+use crate::models::{ColumnInfo, ExportOptions, QueryResult};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::fmt::Write as _;
+use std::io::Write;
+
+/// Excel limits per worksheet
+const MAX_ROWS: usize = 1_048_576;
+const MAX_COLUMNS: usize = 16_384;
+const MAX_CELL_CHARS: usize = 32_767;
+
+/// Indexes into `cellXfs` in styles.xml
+const STYLE_DATE: u8 = 1;
+const STYLE_DATETIME: u8 = 2;
+const STYLE_TIME: u8 = 3;
+const STYLE_HEADER: u8 = 4;
+
+const CONTENT_TYPES_HEAD: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts><fills count="2"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="5"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="14" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="22" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="21" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="0" applyFont="1"/></cellXfs><cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles></styleSheet>"#;
+
+/// Office Open XML workbook with typed cells and one sheet per result (set)
+pub struct XlsxExporter {
+    pub options: ExportOptions,
+}
+
+struct Sheet<'a> {
+    name: String,
+    columns: &'a [ColumnInfo],
+    rows: &'a [Vec<serde_json::Value>],
+}
+
+impl XlsxExporter {
+    fn sheets<'a>(&self, result: &'a QueryResult) -> Vec<Sheet<'a>> {
+        let mut sheets = Vec::new();
+
+        if self.options.sheet_per_result_set.unwrap_or(false) && !result.result_sets.is_empty() {
+            for (i, set) in result.result_sets.iter().enumerate() {
+                if set.columns.is_empty() {
+                    continue;
+                }
+                sheets.push(Sheet {
+                    name: format!("Result {}", i + 1),
+                    columns: &set.columns,
+                    rows: &set.rows,
+                });
+            }
+        }
+
+        if sheets.is_empty() {
+            sheets.push(Sheet {
+                name: self.options.sheet_name.clone().unwrap_or_else(|| "Results".to_string()),
+                columns: &result.columns,
+                rows: &result.rows,
+            });
+        }
+
+        let mut used: Vec<String> = Vec::with_capacity(sheets.len());
+        for sheet in &mut sheets {
+            sheet.name = unique_sheet_name(&sheet.name, &used);
+            used.push(sheet.name.to_lowercase());
+        }
+
+        sheets
+    }
+
+    fn sheet_xml(&self, sheet: &Sheet) -> AppResult<String> {
+        let include_headers = self.options.include_headers.unwrap_or(true);
+        let row_count = sheet.rows.len() + usize::from(include_headers);
+
+        if row_count > MAX_ROWS || sheet.columns.len() > MAX_COLUMNS {
+            return Err(AppError::ValidationError(format!(
+                "Sheet '{}' exceeds the XLSX limit of {} rows and {} columns",
+                sheet.name, MAX_ROWS, MAX_COLUMNS
+            )));
+        }
+
+        let column_refs: Vec<String> = (0..sheet.columns.len()).map(column_ref).collect();
+        let mut xml = String::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+        );
+
+        if include_headers {
+            xml.push_str(r#"<sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews>"#);
+        }
+        xml.push_str("<sheetData>");
+
+        let mut row_number = 0;
+
+        if include_headers {
+            row_number += 1;
+            let _ = write!(xml, r#"<row r="{}">"#, row_number);
+            for (column, col_ref) in sheet.columns.iter().zip(&column_refs) {
+                let _ = write!(
+                    xml,
+                    r#"<c r="{}{}" s="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                    col_ref,
+                    row_number,
+                    STYLE_HEADER,
+                    escape_xml(&column.name)
+                );
+            }
+            xml.push_str("</row>");
+        }
+
+        for row in sheet.rows {
+            row_number += 1;
+            let _ = write!(xml, r#"<row r="{}">"#, row_number);
+            for ((value, column), col_ref) in row.iter().zip(sheet.columns).zip(&column_refs) {
+                self.write_cell(&mut xml, &format!("{}{}", col_ref, row_number), value, column);
+            }
+            xml.push_str("</row>");
+        }
+
+        xml.push_str("</sheetData></worksheet>");
+        Ok(xml)
+    }
+
+    fn write_cell(&self, xml: &mut String, cell_ref: &str, value: &serde_json::Value, column: &ColumnInfo) {
+        let text = match value {
+            serde_json::Value::Null => return,
+            serde_json::Value::Bool(b) => {
+                let _ = write!(xml, r#"<c r="{}" t="b"><v>{}</v></c>"#, cell_ref, u8::from(*b));
+                return;
+            }
+            serde_json::Value::Number(n) => {
+                // Excel stores doubles; keep integers it cannot represent exactly as text
+                let exact = n.as_i64().map_or(n.as_u64().is_none(), |i| i.unsigned_abs() <= 1 << 53);
+                if exact {
+                    let _ = write!(xml, r#"<c r="{}"><v>{}</v></c>"#, cell_ref, n);
+                    return;
+                }
+                n.to_string()
+            }
+            serde_json::Value::String(s) => {
+                let data_type = column.data_type.to_lowercase();
+
+                if self.options.date_format.is_none() && is_temporal_type(&data_type) {
+                    if let Some((serial, style)) = excel_serial(s) {
+                        let _ = write!(xml, r#"<c r="{}" s="{}"><v>{}</v></c>"#, cell_ref, style, serial);
+                        return;
+                    }
+                }
+
+                if is_decimal_type(&data_type) {
+                    if let Ok(number) = s.trim().parse::<f64>() {
+                        if number.is_finite() {
+                            let _ = write!(xml, r#"<c r="{}"><v>{}</v></c>"#, cell_ref, number);
+                            return;
+                        }
+                    }
+                }
+
+                match prepare_value(value, column, &self.options) {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                }
+            }
+            other => other.to_string(),
+        };
+
+        let text: String = text.chars().take(MAX_CELL_CHARS).collect();
+        let _ = write!(
+            xml,
+            r#"<c r="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+            cell_ref,
+            escape_xml(&text)
+        );
+    }
+}
+
+impl ResultExporter for XlsxExporter {
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> AppResult<()> {
+        let sheets = self.sheets(result);
+
+        let mut content_types = String::from(CONTENT_TYPES_HEAD);
+        let mut workbook = String::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>"#,
+        );
+        let mut workbook_rels = String::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+        );
+
+        for (i, sheet) in sheets.iter().enumerate() {
+            let n = i + 1;
+            let _ = write!(
+                content_types,
+                r#"<Override PartName="/xl/worksheets/sheet{}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+                n
+            );
+            let _ = write!(
+                workbook,
+                r#"<sheet name="{}" sheetId="{}" r:id="rId{}"/>"#,
+                escape_xml(&sheet.name),
+                n,
+                n
+            );
+            let _ = write!(
+                workbook_rels,
+                r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{}.xml"/>"#,
+                n,
+                n
+            );
+        }
+
+        let _ = write!(
+            workbook_rels,
+            r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>"#,
+            sheets.len() + 1
+        );
+        content_types.push_str("</Types>");
+        workbook.push_str("</sheets></workbook>");
+        workbook_rels.push_str("</Relationships>");
+
+        let mut zip = ZipWriter::new(out);
+        zip.add_file("[Content_Types].xml", content_types.as_bytes())?;
+        zip.add_file("_rels/.rels", ROOT_RELS.as_bytes())?;
+        zip.add_file("xl/workbook.xml", workbook.as_bytes())?;
+        zip.add_file("xl/_rels/workbook.xml.rels", workbook_rels.as_bytes())?;
+        zip.add_file("xl/styles.xml", STYLES.as_bytes())?;
+
+        for (i, sheet) in sheets.iter().enumerate() {
+            let xml = self.sheet_xml(sheet)?;
+            zip.add_file(&format!("xl/worksheets/sheet{}.xml", i + 1), xml.as_bytes())?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+fn is_decimal_type(data_type: &str) -> bool {
+    ["numeric", "decimal"].iter().any(|t| data_type.contains(t))
+}
+
+/// Column letters for a zero-based index: A..Z, AA..
+fn column_ref(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap_or_default()
+}
+
+/// Sheet names are at most 31 characters, cannot contain `[]:*?/\` and must be unique
+fn unique_sheet_name(name: &str, used: &[String]) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '[' | ']' | ':' | '*' | '?' | '/' | '\\'))
+        .collect();
+    let base = match cleaned.trim().trim_matches('\'') {
+        "" => "Sheet".to_string(),
+        trimmed => trimmed.to_string(),
+    };
+
+    let mut candidate: String = base.chars().take(31).collect();
+    let mut suffix = 2;
+    while used.contains(&candidate.to_lowercase()) {
+        let tag = format!(" ({})", suffix);
+        candidate = base.chars().take(31 - tag.len()).collect::<String>() + &tag;
+        suffix += 1;
+    }
+    candidate
+}
+
+/// Convert a date/time string to an Excel serial number and the style that displays it
+fn excel_serial(text: &str) -> Option<(f64, u8)> {
+    let text = text.trim();
+    let epoch = NaiveDate::from_ymd_opt(1899, 12, 30)?;
+
+    let fraction = |time: NaiveTime| {
+        (time.num_seconds_from_midnight() as f64 + time.nanosecond() as f64 / 1e9) / 86_400.0
+    };
+
+    let (serial, style) = if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        let local = dt.naive_local();
+        ((local.date() - epoch).num_days() as f64 + fraction(local.time()), STYLE_DATETIME)
+    } else if let Ok(dt) = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+    {
+        ((dt.date() - epoch).num_days() as f64 + fraction(dt.time()), STYLE_DATETIME)
+    } else if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        ((date - epoch).num_days() as f64, STYLE_DATE)
+    } else if let Ok(time) = NaiveTime::parse_from_str(text, "%H:%M:%S%.f") {
+        return Some((fraction(time), STYLE_TIME));
+    } else {
+        return None;
+    };
+
+    // Excel cannot show dates before 1900
+    (serial >= 1.0).then_some((serial, style))
+}
+
+/// Escape text for XML, dropping control characters XML 1.0 does not allow
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() && (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
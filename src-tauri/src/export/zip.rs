@@ -0,0 +1,115 @@
+//! Minimal ZIP writer: deflated entries, no Zip64, written sequentially.
+
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{self, Write};
+
+/// DOS date for 1980-01-01, the earliest date ZIP can represent
+const DOS_DATE: u16 = 0x21;
+
+struct Entry {
+    name: String,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    offset: u32,
+}
+
+pub struct ZipWriter<'a> {
+    out: &'a mut dyn Write,
+    entries: Vec<Entry>,
+    written: u32,
+}
+
+impl<'a> ZipWriter<'a> {
+    pub fn new(out: &'a mut dyn Write) -> Self {
+        Self { out, entries: Vec::new(), written: 0 }
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.out.write_all(bytes)?;
+        self.written = self.written
+            .checked_add(bytes.len() as u32)
+            .ok_or_else(|| io::Error::other("Archive exceeds 4 GiB"))?;
+        Ok(())
+    }
+
+    /// Compress `data` and add it to the archive as `name`
+    pub fn add_file(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data)?;
+        let compressed = encoder.finish()?;
+
+        let entry = Entry {
+            name: name.to_string(),
+            crc: crc32fast::hash(data),
+            compressed_size: compressed.len() as u32,
+            size: data.len() as u32,
+            offset: self.written,
+        };
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        header.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        header.extend_from_slice(&0u16.to_le_bytes()); // flags
+        header.extend_from_slice(&8u16.to_le_bytes()); // deflate
+        header.extend_from_slice(&0u16.to_le_bytes()); // time
+        header.extend_from_slice(&DOS_DATE.to_le_bytes());
+        header.extend_from_slice(&entry.crc.to_le_bytes());
+        header.extend_from_slice(&entry.compressed_size.to_le_bytes());
+        header.extend_from_slice(&entry.size.to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // extra length
+        header.extend_from_slice(name.as_bytes());
+
+        self.write_bytes(&header)?;
+        self.write_bytes(&compressed)?;
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Write the central directory; the archive is complete afterwards
+    pub fn finish(mut self) -> io::Result<()> {
+        let directory_offset = self.written;
+        let entries = std::mem::take(&mut self.entries);
+
+        for entry in &entries {
+            let mut record = Vec::with_capacity(46 + entry.name.len());
+            record.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+            record.extend_from_slice(&20u16.to_le_bytes()); // version made by
+            record.extend_from_slice(&20u16.to_le_bytes()); // version needed
+            record.extend_from_slice(&0u16.to_le_bytes()); // flags
+            record.extend_from_slice(&8u16.to_le_bytes()); // deflate
+            record.extend_from_slice(&0u16.to_le_bytes()); // time
+            record.extend_from_slice(&DOS_DATE.to_le_bytes());
+            record.extend_from_slice(&entry.crc.to_le_bytes());
+            record.extend_from_slice(&entry.compressed_size.to_le_bytes());
+            record.extend_from_slice(&entry.size.to_le_bytes());
+            record.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            record.extend_from_slice(&0u16.to_le_bytes()); // extra length
+            record.extend_from_slice(&0u16.to_le_bytes()); // comment length
+            record.extend_from_slice(&0u16.to_le_bytes()); // disk number
+            record.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+            record.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+            record.extend_from_slice(&entry.offset.to_le_bytes());
+            record.extend_from_slice(entry.name.as_bytes());
+            self.write_bytes(&record)?;
+        }
+
+        let directory_size = self.written - directory_offset;
+        let count = entries.len() as u16;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        end.extend_from_slice(&0u16.to_le_bytes()); // directory disk
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&count.to_le_bytes());
+        end.extend_from_slice(&directory_size.to_le_bytes());
+        end.extend_from_slice(&directory_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        self.write_bytes(&end)?;
+
+        self.out.flush()
+    }
+}
@@ -0,0 +1,171 @@
+//! Minimal ZIP reader for extension packages: stored and deflated entries, no
+//! Zip64 or encryption. Entries are located through the central directory and
+//! checked against their CRC when read.
+
+use crate::error::{AppError, AppResult};
+use flate2::read::DeflateDecoder;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// End of central directory record, without its comment
+const END_RECORD_SIZE: usize = 22;
+
+/// Unix file type bits in the upper half of the external attributes
+const UNIX_FILE_TYPE: u32 = 0o170000;
+const UNIX_SYMLINK: u32 = 0o120000;
+
+pub struct ZipEntry {
+    pub name: String,
+    pub is_dir: bool,
+    method: u16,
+    crc: u32,
+    compressed_size: u32,
+    pub size: u32,
+    header_offset: u32,
+}
+
+pub struct ZipArchive<'a> {
+    data: &'a [u8],
+    pub entries: Vec<ZipEntry>,
+}
+
+fn invalid(message: &str) -> AppError {
+    AppError::ValidationError(format!("The package is not a valid ZIP archive: {}", message))
+}
+
+fn u16_at(data: &[u8], offset: usize) -> AppResult<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .ok_or_else(|| invalid("unexpected end of file"))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> AppResult<u32> {
+    data.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or_else(|| invalid("unexpected end of file"))
+}
+
+impl<'a> ZipArchive<'a> {
+    /// Read the central directory
+    pub fn parse(data: &'a [u8]) -> AppResult<Self> {
+        if data.len() < END_RECORD_SIZE {
+            return Err(invalid("too short"));
+        }
+        // The end record is followed by a comment of at most 64 KiB
+        let search_from = data.len().saturating_sub(END_RECORD_SIZE + u16::MAX as usize);
+        let end = (search_from..=data.len() - END_RECORD_SIZE)
+            .rev()
+            .find(|&i| data[i..i + 4] == 0x0605_4b50u32.to_le_bytes())
+            .ok_or_else(|| invalid("no end of central directory"))?;
+
+        let count = u16_at(data, end + 10)? as usize;
+        let mut offset = u32_at(data, end + 16)? as usize;
+        if u16_at(data, end + 4)? != 0 || u16_at(data, end + 6)? != 0 {
+            return Err(invalid("multi-disk archives are not supported"));
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            if u32_at(data, offset)? != 0x0201_4b50 {
+                return Err(invalid("damaged central directory"));
+            }
+            let made_by = u16_at(data, offset + 4)?;
+            let flags = u16_at(data, offset + 8)?;
+            let method = u16_at(data, offset + 10)?;
+            let crc = u32_at(data, offset + 16)?;
+            let compressed_size = u32_at(data, offset + 20)?;
+            let size = u32_at(data, offset + 24)?;
+            let name_len = u16_at(data, offset + 28)? as usize;
+            let extra_len = u16_at(data, offset + 30)? as usize;
+            let comment_len = u16_at(data, offset + 32)? as usize;
+            let external = u32_at(data, offset + 38)?;
+            let header_offset = u32_at(data, offset + 42)?;
+            let name_bytes = data
+                .get(offset + 46..offset + 46 + name_len)
+                .ok_or_else(|| invalid("unexpected end of file"))?;
+            let name = String::from_utf8(name_bytes.to_vec()).map_err(|_| invalid("entry name is not UTF-8"))?;
+
+            if flags & 1 != 0 {
+                return Err(invalid(&format!("'{}' is encrypted", name)));
+            }
+            if compressed_size == u32::MAX || size == u32::MAX || header_offset == u32::MAX {
+                return Err(invalid("Zip64 archives are not supported"));
+            }
+            if method != 0 && method != 8 {
+                return Err(invalid(&format!("'{}' uses unsupported compression method {}", name, method)));
+            }
+            if made_by >> 8 == 3 && (external >> 16) & UNIX_FILE_TYPE == UNIX_SYMLINK {
+                return Err(AppError::ValidationError(format!(
+                    "The package contains a symbolic link '{}'",
+                    name
+                )));
+            }
+
+            entries.push(ZipEntry {
+                is_dir: name.ends_with('/'),
+                name,
+                method,
+                crc,
+                compressed_size,
+                size,
+                header_offset,
+            });
+            offset += 46 + name_len + extra_len + comment_len;
+        }
+
+        Ok(Self { data, entries })
+    }
+
+    /// Uncompressed contents of an entry, checked against its size and CRC
+    pub fn read(&self, entry: &ZipEntry) -> AppResult<Vec<u8>> {
+        let header = entry.header_offset as usize;
+        if u32_at(self.data, header)? != 0x0403_4b50 {
+            return Err(invalid(&format!("damaged local header for '{}'", entry.name)));
+        }
+        let start = header + 30 + u16_at(self.data, header + 26)? as usize + u16_at(self.data, header + 28)? as usize;
+        let compressed = self
+            .data
+            .get(start..start + entry.compressed_size as usize)
+            .ok_or_else(|| invalid("unexpected end of file"))?;
+
+        let contents = if entry.method == 0 {
+            compressed.to_vec()
+        } else {
+            // Read one byte past the declared size to catch entries that lie about it
+            let mut contents = Vec::with_capacity(entry.size as usize);
+            DeflateDecoder::new(compressed)
+                .take(entry.size as u64 + 1)
+                .read_to_end(&mut contents)
+                .map_err(|e| invalid(&format!("'{}' could not be decompressed: {}", entry.name, e)))?;
+            contents
+        };
+
+        if contents.len() != entry.size as usize || crc32fast::hash(&contents) != entry.crc {
+            return Err(invalid(&format!("'{}' is corrupt", entry.name)));
+        }
+        Ok(contents)
+    }
+}
+
+/// Relative path for an entry name, rejecting names that could escape the
+/// directory the package is extracted into
+pub fn safe_path(name: &str) -> AppResult<PathBuf> {
+    let unsafe_name = || AppError::ValidationError(format!("The package contains an unsafe path '{}'", name));
+
+    let trimmed = name.trim_end_matches('/');
+    if trimmed.is_empty() || trimmed.contains('\\') || trimmed.contains(':') || trimmed.contains('\0') {
+        return Err(unsafe_name());
+    }
+
+    let mut path = PathBuf::new();
+    for component in Path::new(trimmed).components() {
+        match component {
+            Component::Normal(part) => path.push(part),
+            _ => return Err(unsafe_name()),
+        }
+    }
+    if trimmed.split('/').any(|part| part.is_empty() || part == "." || part == "..") {
+        return Err(unsafe_name());
+    }
+    Ok(path)
+}
@@ -0,0 +1,274 @@
+//! Extensions compiled into the app. They are registered when the app starts
+//! and listed next to the installed ones as official extensions; they can be
+//! turned off but not uninstalled. Turning one on or off runs its lifecycle
+//! hooks, and the ones turned off are remembered in `builtin_extensions.json`.
+//! A hook that fails or panics leaves its extension in the error state until
+//! it is restarted.
+
+use super::logging::ExtensionLogger;
+use super::{health, ExtensionLoader};
+use crate::error::{AppError, AppResult};
+use crate::models::{ExtensionContributions, ExtensionManifest, ExtensionStatus, InstalledExtension};
+use crate::{storage, theme};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::AppHandle;
+
+const STATE_FILE: &str = "builtin_extensions.json";
+
+/// An extension that ships with the app
+pub trait BuiltinExtension: Send + Sync {
+    fn manifest(&self) -> ExtensionManifest;
+
+    /// Called at startup when the extension is on, and when it is turned on
+    fn activate(&self) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// Called when the extension is turned off
+    fn deactivate(&self) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+fn manifest(id: &str, name: &str, description: &str, category: &str) -> ExtensionManifest {
+    ExtensionManifest {
+        id: id.to_string(),
+        name: name.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        description: description.to_string(),
+        author: "dbfordevs".to_string(),
+        category: category.to_string(),
+        main: None,
+        min_app_version: None,
+        permissions: Vec::new(),
+        contributes: ExtensionContributions::default(),
+    }
+}
+
+/// The AI panel and the "with AI" editor actions; they run in the frontend,
+/// which follows the status of this extension
+struct AiAssistant;
+
+impl BuiltinExtension for AiAssistant {
+    fn manifest(&self) -> ExtensionManifest {
+        manifest(
+            "ai-assistant",
+            "AI Query Assistant",
+            "Write, explain and optimize SQL with the AI provider of your choice.",
+            "AI",
+        )
+    }
+}
+
+/// Mock data generation, backed by the data-faker crate
+struct MockDataGenerator;
+
+impl BuiltinExtension for MockDataGenerator {
+    fn manifest(&self) -> ExtensionManifest {
+        manifest(
+            "data-faker",
+            "Mock Data Generator",
+            "Fill tables with realistic fake rows that respect foreign keys.",
+            "Tools",
+        )
+    }
+}
+
+/// The bundled Nordic theme
+struct NordicTheme;
+
+impl BuiltinExtension for NordicTheme {
+    fn manifest(&self) -> ExtensionManifest {
+        manifest(
+            "theme-nordic",
+            "Nordic Theme",
+            "Arctic, north-bluish dark and light themes based on Nord.",
+            theme::THEME_CATEGORY,
+        )
+    }
+
+    /// Go back to the system theme if Nordic was in use
+    fn deactivate(&self) -> AppResult<()> {
+        let loader = ExtensionLoader::new()?;
+        if theme::active(&loader)?.starts_with("nordic-") {
+            theme::set_active(&loader, "system")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SavedState {
+    disabled: Vec<String>,
+}
+
+struct Registered {
+    extension: Box<dyn BuiltinExtension>,
+    status: Mutex<ExtensionStatus>,
+}
+
+static REGISTRY: OnceCell<Vec<Registered>> = OnceCell::new();
+
+fn state_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(STATE_FILE))
+}
+
+fn saved_state() -> AppResult<SavedState> {
+    let path = state_path()?;
+    if !path.exists() {
+        return Ok(SavedState::default());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn save_enabled(id: &str, enabled: bool) -> AppResult<()> {
+    let mut state = saved_state()?;
+    state.disabled.retain(|disabled| disabled != id);
+    if !enabled {
+        state.disabled.push(id.to_string());
+    }
+    fs::write(state_path()?, serde_json::to_string_pretty(&state)?)?;
+    Ok(())
+}
+
+/// Run a hook in isolation, logging how it went
+fn run_hook(id: &str, name: &str, hook: impl FnOnce() -> AppResult<()>) -> AppResult<()> {
+    let logger = ExtensionLogger::new(id);
+    let outcome = health::isolate(hook);
+    match &outcome {
+        Ok(()) => logger.info(format!("{} finished", name)),
+        Err(e) => logger.error(format!("{} failed: {}", name, e)),
+    }
+    outcome
+}
+
+/// The bundled extensions, activating the ones that are on the first time it
+/// is called. One whose activation fails is left in the error state.
+fn registry() -> &'static [Registered] {
+    REGISTRY.get_or_init(|| {
+        let disabled = saved_state().map(|state| state.disabled).unwrap_or_default();
+        let extensions: Vec<Box<dyn BuiltinExtension>> =
+            vec![Box::new(AiAssistant), Box::new(MockDataGenerator), Box::new(NordicTheme)];
+        extensions
+            .into_iter()
+            .map(|extension| {
+                let status = if disabled.contains(&extension.manifest().id) {
+                    ExtensionStatus::Disabled
+                } else {
+                    match run_hook(&extension.manifest().id, "Activation", || extension.activate()) {
+                        Ok(()) => ExtensionStatus::Active,
+                        Err(e) => ExtensionStatus::Error(e.to_string()),
+                    }
+                };
+                Registered {
+                    extension,
+                    status: Mutex::new(status),
+                }
+            })
+            .collect()
+    })
+}
+
+fn find(id: &str) -> Option<&'static Registered> {
+    registry().iter().find(|registered| registered.extension.manifest().id == id)
+}
+
+fn info(registered: &Registered) -> InstalledExtension {
+    InstalledExtension {
+        manifest: registered.extension.manifest(),
+        path: String::new(),
+        sha256: String::new(),
+        installed_at: String::new(),
+        bundled: true,
+        is_official: true,
+        status: registered.status(),
+    }
+}
+
+impl Registered {
+    fn status(&self) -> ExtensionStatus {
+        self.status.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Run a hook, which may look up other extensions, so the status is not
+    /// locked meanwhile. Failing moves to the error state instead of `target`.
+    fn run(
+        &self,
+        app: &AppHandle,
+        name: &str,
+        hook: impl FnOnce() -> AppResult<()>,
+        target: ExtensionStatus,
+    ) -> AppResult<()> {
+        let outcome = run_hook(&self.extension.manifest().id, name, hook);
+        let status = match &outcome {
+            Ok(()) => target,
+            Err(e) => ExtensionStatus::Error(e.to_string()),
+        };
+        *self.status.lock().unwrap_or_else(|e| e.into_inner()) = status.clone();
+        health::emit(app, &self.extension.manifest().id, &status);
+        outcome
+    }
+}
+
+/// Register the bundled extensions and activate the ones that are on
+pub fn register() {
+    registry();
+}
+
+/// Whether the id belongs to a bundled extension
+pub fn is_builtin(id: &str) -> bool {
+    find(id).is_some()
+}
+
+/// The bundled extensions with their status
+pub fn list() -> Vec<InstalledExtension> {
+    registry().iter().map(info).collect()
+}
+
+/// Fail unless the bundled extension is on, for the features it provides
+pub fn ensure_active(id: &str) -> AppResult<()> {
+    let registered = find(id).ok_or_else(|| AppError::Internal(format!("'{}' is not a bundled extension", id)))?;
+    let name = registered.extension.manifest().name;
+    match registered.status() {
+        ExtensionStatus::Active => Ok(()),
+        ExtensionStatus::Error(message) => Err(AppError::ValidationError(format!(
+            "{} failed ({}); restart it in Settings > Extensions",
+            name, message
+        ))),
+        _ => Err(AppError::ValidationError(format!(
+            "{} is turned off; enable it in Settings > Extensions",
+            name
+        ))),
+    }
+}
+
+/// Turn a bundled extension on or off, running its lifecycle hook
+pub fn set_enabled(app: &AppHandle, id: &str, enabled: bool) -> AppResult<InstalledExtension> {
+    let registered = find(id).ok_or_else(|| AppError::ValidationError(format!("'{}' is not a bundled extension", id)))?;
+    let extension = &registered.extension;
+    match (enabled, registered.status()) {
+        (true, ExtensionStatus::Active) | (false, ExtensionStatus::Disabled) => {}
+        (true, _) => registered.run(app, "Activation", || extension.activate(), ExtensionStatus::Active)?,
+        (false, _) => registered.run(app, "Deactivation", || extension.deactivate(), ExtensionStatus::Disabled)?,
+    }
+    save_enabled(id, enabled)?;
+    Ok(info(registered))
+}
+
+/// Run a bundled extension's hooks again to recover from a failure: deactivate
+/// when it was on, then activate. It stays on afterwards.
+pub fn restart(app: &AppHandle, id: &str) -> AppResult<InstalledExtension> {
+    let registered = find(id).ok_or_else(|| AppError::ValidationError(format!("'{}' is not a bundled extension", id)))?;
+    let extension = &registered.extension;
+    if registered.status() == ExtensionStatus::Active {
+        registered.run(app, "Deactivation", || extension.deactivate(), ExtensionStatus::Disabled)?;
+    }
+    registered.run(app, "Activation", || extension.activate(), ExtensionStatus::Active)?;
+    save_enabled(id, true)?;
+    Ok(info(registered))
+}
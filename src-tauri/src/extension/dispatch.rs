@@ -0,0 +1,121 @@
+//! Dispatch of contributed commands. Extensions run in the frontend, so a
+//! command is sent to the runtime as an `extension-command` event carrying a
+//! request id, and the call waits until the runtime answers through
+//! [`complete`] or the time limit passes. A command that is never answered
+//! marks its extension as failed.
+
+use super::logging::ExtensionLogger;
+use super::{health, ExtensionLoader};
+use crate::error::{AppError, AppResult};
+use crate::models::{ExtensionCommandInfo, ExtensionCommandRequest, ExtensionCommandResult};
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::oneshot;
+
+/// Longer than the runtime's own limits for starting the extension and running the command
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(20);
+
+type Reply = Result<Value, String>;
+
+/// Request id -> the call waiting for its result
+static PENDING: OnceCell<Mutex<HashMap<String, oneshot::Sender<Reply>>>> = OnceCell::new();
+
+fn pending() -> &'static Mutex<HashMap<String, oneshot::Sender<Reply>>> {
+    PENDING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Every command contributed by an installed extension
+pub fn commands(loader: &ExtensionLoader) -> AppResult<Vec<ExtensionCommandInfo>> {
+    Ok(loader
+        .list()?
+        .into_iter()
+        .flat_map(|installed| {
+            let manifest = installed.manifest;
+            manifest.contributes.commands.into_iter().map(move |command| ExtensionCommandInfo {
+                extension_id: manifest.id.clone(),
+                extension_name: manifest.name.clone(),
+                command,
+            })
+        })
+        .collect())
+}
+
+/// Run a contributed command in its extension and wait for the result
+pub async fn execute(
+    app: &AppHandle,
+    loader: &ExtensionLoader,
+    command_id: &str,
+    args: Value,
+) -> AppResult<ExtensionCommandResult> {
+    let command = commands(loader)?
+        .into_iter()
+        .find(|info| info.command.id == command_id)
+        .ok_or_else(|| AppError::ValidationError(format!("No installed extension contributes '{}'", command_id)))?;
+
+    let request_id = uuid::Uuid::new_v4().to_string();
+    let (sender, receiver) = oneshot::channel();
+    pending().lock().unwrap_or_else(|e| e.into_inner()).insert(request_id.clone(), sender);
+
+    let logger = ExtensionLogger::new(&command.extension_id);
+    logger.info(format!("Running {}", command_id));
+    let started = Instant::now();
+    let request = ExtensionCommandRequest {
+        request_id: request_id.clone(),
+        extension_id: command.extension_id.clone(),
+        command_id: command_id.to_string(),
+        args,
+    };
+    if let Err(e) = app.emit("extension-command", request) {
+        pending().lock().unwrap_or_else(|e| e.into_inner()).remove(&request_id);
+        return Err(AppError::Internal(format!("Failed to reach the extension runtime: {}", e)));
+    }
+
+    let reply = tokio::time::timeout(COMMAND_TIMEOUT, receiver).await;
+    pending().lock().unwrap_or_else(|e| e.into_inner()).remove(&request_id);
+    let result = match reply {
+        Ok(Ok(Ok(result))) => result,
+        Ok(Ok(Err(message))) => {
+            logger.warn(format!("{} failed: {}", command_id, message));
+            return Err(AppError::GenericError(format!("{} failed: {}", command.command.title, message)));
+        }
+        Ok(Err(_)) => {
+            let message = "The extension runtime dropped the command".to_string();
+            health::fail(app, &command.extension_id, &message);
+            return Err(AppError::Internal(message));
+        }
+        Err(_) => {
+            let message = format!("{} did not finish within {}s", command.command.title, COMMAND_TIMEOUT.as_secs());
+            health::fail(app, &command.extension_id, &message);
+            return Err(AppError::GenericError(message));
+        }
+    };
+
+    let duration_ms = started.elapsed().as_millis() as u64;
+    logger.info(format!("{} finished in {} ms", command_id, duration_ms));
+    Ok(ExtensionCommandResult {
+        extension_id: command.extension_id,
+        command_id: command_id.to_string(),
+        result,
+        duration_ms,
+    })
+}
+
+/// The runtime's answer to a command request: a result, or an error message
+pub fn complete(request_id: &str, result: Option<Value>, error: Option<String>) -> AppResult<()> {
+    let sender = pending()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(request_id)
+        .ok_or_else(|| AppError::ValidationError(format!("No command is waiting for request '{}'", request_id)))?;
+    let reply = match error {
+        Some(error) => Err(error),
+        None => Ok(result.unwrap_or(Value::Null)),
+    };
+    // The caller may have timed out in the meantime
+    let _ = sender.send(reply);
+    Ok(())
+}
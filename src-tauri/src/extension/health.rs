@@ -0,0 +1,80 @@
+//! Crash isolation. Lifecycle hooks run through [`isolate`], so a panicking
+//! hook fails like one returning an error instead of taking the app down.
+//! Bundled extensions keep the failure in their status (see [`super::builtin`]);
+//! for extensions installed from packages, which run in the frontend, the
+//! failures reported by the runtime and the commands it never answered are
+//! kept here until the extension is restarted. Either way an `extension-status`
+//! event tells the frontend.
+
+use super::logging::ExtensionLogger;
+use crate::error::{AppError, AppResult};
+use crate::models::{ExtensionStatus, ExtensionStatusEvent};
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+
+/// Sent with an [`ExtensionStatusEvent`] when an extension fails or recovers
+pub const STATUS_EVENT: &str = "extension-status";
+
+/// Sent with the extension id to have the frontend runtime restart it
+pub const RESTART_EVENT: &str = "extension-restart";
+
+/// Extension id -> why it failed, for extensions installed from packages
+static ERRORS: OnceCell<Mutex<HashMap<String, String>>> = OnceCell::new();
+
+fn errors() -> &'static Mutex<HashMap<String, String>> {
+    ERRORS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Run a hook, turning a panic into an error
+pub fn isolate<T>(hook: impl FnOnce() -> AppResult<T>) -> AppResult<T> {
+    panic::catch_unwind(AssertUnwindSafe(hook)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        Err(AppError::Internal(format!("The extension panicked: {}", message)))
+    })
+}
+
+/// Tell the frontend about a status change. Losing the event is not worth
+/// failing over; the status is also in the extension list.
+pub fn emit(app: &AppHandle, extension_id: &str, status: &ExtensionStatus) {
+    let _ = app.emit(
+        STATUS_EVENT,
+        ExtensionStatusEvent {
+            extension_id: extension_id.to_string(),
+            status: status.clone(),
+        },
+    );
+}
+
+/// The status of an extension installed from a package
+pub fn status(extension_id: &str) -> ExtensionStatus {
+    match errors().lock().unwrap_or_else(|e| e.into_inner()).get(extension_id) {
+        Some(message) => ExtensionStatus::Error(message.clone()),
+        None => ExtensionStatus::Installed,
+    }
+}
+
+/// Mark an extension installed from a package as failed
+pub fn fail(app: &AppHandle, extension_id: &str, message: &str) {
+    ExtensionLogger::new(extension_id).error(message);
+    errors()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(extension_id.to_string(), message.to_string());
+    emit(app, extension_id, &ExtensionStatus::Error(message.to_string()));
+}
+
+/// Forget the failure of an extension installed from a package, telling the
+/// frontend only when there was one
+pub fn clear(app: &AppHandle, extension_id: &str) {
+    let removed = errors().lock().unwrap_or_else(|e| e.into_inner()).remove(extension_id);
+    if removed.is_some() {
+        emit(app, extension_id, &ExtensionStatus::Installed);
+    }
+}
@@ -0,0 +1,176 @@
+//! Per-extension logs, for diagnosing an extension that misbehaves. Each
+//! extension writes through its own [`ExtensionLogger`] to
+//! `logs/extensions/<id>.log` in the app data directory, one JSON entry per
+//! line. A file that grows past [`MAX_LOG_SIZE`] is rotated to `<id>.1.log`,
+//! pushing older ones up to `<id>.3.log`, past which they are dropped.
+//!
+//! The backend logs lifecycle changes, commands and failures; the frontend
+//! runtime adds what the extension itself logs. Entries also go to the
+//! application log (see [`crate::logging`]).
+
+use crate::error::AppResult;
+use crate::models::{ExtensionLogEntry, ExtensionLogLevel};
+use crate::storage;
+use chrono::{SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const LOGS_DIR: &str = "logs/extensions";
+
+/// Size at which the log file is rotated, in bytes
+const MAX_LOG_SIZE: u64 = 256 * 1024;
+
+/// Rotated files kept besides the current one
+const ROTATED_FILES: usize = 3;
+
+/// Longer messages are cut, so one entry cannot fill the log
+const MAX_MESSAGE_LEN: usize = 4000;
+
+/// Entries returned when the caller does not say how many
+pub const DEFAULT_TAIL: usize = 200;
+
+/// Most entries returned at once
+const MAX_TAIL: usize = 5000;
+
+/// Writes are serialized so rotation does not race with another write
+static WRITE_LOCK: OnceCell<Mutex<()>> = OnceCell::new();
+
+fn write_lock() -> &'static Mutex<()> {
+    WRITE_LOCK.get_or_init(|| Mutex::new(()))
+}
+
+fn logs_dir() -> AppResult<PathBuf> {
+    let dir = storage::app_dir()?.join(LOGS_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// The current file for `index` 0, rotated ones above
+fn log_path(extension_id: &str, index: usize) -> AppResult<PathBuf> {
+    let name = match index {
+        0 => format!("{}.log", extension_id),
+        _ => format!("{}.{}.log", extension_id, index),
+    };
+    Ok(logs_dir()?.join(name))
+}
+
+fn is_valid_id(extension_id: &str) -> bool {
+    !extension_id.is_empty()
+        && extension_id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Logger for one extension. Logging never fails the caller; an entry that
+/// cannot be written is lost.
+pub struct ExtensionLogger {
+    extension_id: String,
+}
+
+impl ExtensionLogger {
+    pub fn new(extension_id: &str) -> Self {
+        Self {
+            extension_id: extension_id.to_string(),
+        }
+    }
+
+    pub fn info(&self, message: impl AsRef<str>) {
+        self.log(ExtensionLogLevel::Info, message.as_ref());
+    }
+
+    pub fn warn(&self, message: impl AsRef<str>) {
+        self.log(ExtensionLogLevel::Warn, message.as_ref());
+    }
+
+    pub fn error(&self, message: impl AsRef<str>) {
+        self.log(ExtensionLogLevel::Error, message.as_ref());
+    }
+
+    pub fn log(&self, level: ExtensionLogLevel, message: &str) {
+        let extension_id = self.extension_id.as_str();
+        match level {
+            ExtensionLogLevel::Debug => tracing::debug!(extension_id, "{}", message),
+            ExtensionLogLevel::Info => tracing::info!(extension_id, "{}", message),
+            ExtensionLogLevel::Warn => tracing::warn!(extension_id, "{}", message),
+            ExtensionLogLevel::Error => tracing::error!(extension_id, "{}", message),
+        }
+        if is_valid_id(&self.extension_id) {
+            let _ = self.write(level, message);
+        }
+    }
+
+    fn write(&self, level: ExtensionLogLevel, message: &str) -> AppResult<()> {
+        let message = match message.char_indices().nth(MAX_MESSAGE_LEN) {
+            Some((end, _)) => format!("{}…", &message[..end]),
+            None => message.to_string(),
+        };
+        let entry = ExtensionLogEntry {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level,
+            message,
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+
+        let _guard = write_lock().lock().unwrap_or_else(|e| e.into_inner());
+        let path = log_path(&self.extension_id, 0)?;
+        let size = fs::metadata(&path).map(|meta| meta.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 > MAX_LOG_SIZE {
+            self.rotate()?;
+        }
+        OpenOptions::new().create(true).append(true).open(&path)?.write_all(line.as_bytes())?;
+        Ok(())
+    }
+
+    fn rotate(&self) -> AppResult<()> {
+        let oldest = log_path(&self.extension_id, ROTATED_FILES)?;
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+        for index in (0..ROTATED_FILES).rev() {
+            let from = log_path(&self.extension_id, index)?;
+            if from.exists() {
+                fs::rename(from, log_path(&self.extension_id, index + 1)?)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The last `tail` entries of an extension's log, oldest first. Lines that do
+/// not parse, e.g. one cut short by a crash, are skipped.
+pub fn read(extension_id: &str, tail: usize) -> AppResult<Vec<ExtensionLogEntry>> {
+    if !is_valid_id(extension_id) {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for index in (0..=ROTATED_FILES).rev() {
+        let path = log_path(extension_id, index)?;
+        if !path.exists() {
+            continue;
+        }
+        entries.extend(
+            fs::read_to_string(path)?
+                .lines()
+                .filter_map(|line| serde_json::from_str::<ExtensionLogEntry>(line).ok()),
+        );
+    }
+    let skip = entries.len().saturating_sub(tail.min(MAX_TAIL));
+    Ok(entries.split_off(skip))
+}
+
+/// Delete an extension's log files
+pub fn clear(extension_id: &str) -> AppResult<()> {
+    if !is_valid_id(extension_id) {
+        return Ok(());
+    }
+    let _guard = write_lock().lock().unwrap_or_else(|e| e.into_inner());
+    for index in 0..=ROTATED_FILES {
+        let path = log_path(extension_id, index)?;
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
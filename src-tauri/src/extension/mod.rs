@@ -0,0 +1,196 @@
+//! Installed extensions. Each extension is unpacked from its `.dbx` package
+//! into `extensions/<id>` in the app data directory, together with a small
+//! file recording the package checksum and when it was installed. Settings are
+//! kept apart (see [`settings`]) so they survive an update.
+//!
+//! Extensions run in the frontend, each in its own sandboxed worker; the
+//! backend only hands over the entry point and stores the settings. Official
+//! extensions that ship with the app are registered apart (see [`builtin`]).
+//! Failing extensions are isolated and marked as such (see [`health`]), and
+//! each extension has its own log (see [`logging`]). Extensions with the
+//! `network` permission reach the network through the app (see [`network`]).
+//! Theme extensions need no code at all; see [`crate::theme`].
+
+mod archive;
+pub mod builtin;
+pub mod dispatch;
+pub mod health;
+pub mod logging;
+pub mod network;
+pub mod package;
+mod settings;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ExtensionEntry, ExtensionManifest, ExtensionRuntime, ExtensionSettings, InstalledExtension,
+};
+use crate::storage;
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{SecondsFormat, Utc};
+use logging::ExtensionLogger;
+use package::{INSTALL_FILE, MANIFEST_FILE};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const EXTENSIONS_DIR: &str = "extensions";
+
+/// Contents of the install file
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct InstallRecord {
+    sha256: String,
+    installed_at: String,
+}
+
+/// Installs, lists and removes extensions in a directory
+pub struct ExtensionLoader {
+    root: PathBuf,
+}
+
+impl ExtensionLoader {
+    /// Loader for the app's extension directory
+    pub fn new() -> AppResult<Self> {
+        let root = storage::app_dir()?.join(EXTENSIONS_DIR);
+        fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    fn extension_dir(&self, id: &str) -> AppResult<PathBuf> {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Err(AppError::ValidationError(format!("Invalid extension id '{}'", id)));
+        }
+        Ok(self.root.join(id))
+    }
+
+    fn read(&self, dir: PathBuf) -> AppResult<InstalledExtension> {
+        let manifest: ExtensionManifest = serde_json::from_str(&fs::read_to_string(dir.join(MANIFEST_FILE))?)?;
+        let record: InstallRecord = serde_json::from_str(&fs::read_to_string(dir.join(INSTALL_FILE))?)?;
+        let manifest_id = manifest.id.clone();
+        Ok(InstalledExtension {
+            manifest,
+            path: dir.to_string_lossy().to_string(),
+            sha256: record.sha256,
+            installed_at: record.installed_at,
+            bundled: false,
+            is_official: false,
+            status: health::status(&manifest_id),
+        })
+    }
+
+    /// Every installed extension, by name. Directories that are not complete
+    /// installs, such as an interrupted one, are skipped.
+    pub fn list(&self) -> AppResult<Vec<InstalledExtension>> {
+        let mut extensions = Vec::new();
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with('.') || !entry.file_type()?.is_dir() {
+                continue;
+            }
+            if let Ok(extension) = self.read(entry.path()) {
+                extensions.push(extension);
+            }
+        }
+        extensions.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+        Ok(extensions)
+    }
+
+    /// The installed extension with this id
+    pub fn get(&self, id: &str) -> AppResult<InstalledExtension> {
+        let dir = self.extension_dir(id)?;
+        if !dir.join(INSTALL_FILE).exists() {
+            return Err(AppError::ValidationError(format!("Extension '{}' is not installed", id)));
+        }
+        self.read(dir)
+    }
+
+    /// The extension's manifest and entry point, ready to run
+    pub fn entry(&self, id: &str) -> AppResult<ExtensionEntry> {
+        let installed = self.get(id)?;
+        // Checked again in case the directory was edited after install
+        package::validate_manifest(&installed.manifest)?;
+        let path = PathBuf::from(&installed.path).join(package::entry_path(&installed.manifest)?);
+
+        let (runtime, source) = if installed.manifest.main.as_deref().is_some_and(|main| main.ends_with(".wasm")) {
+            (ExtensionRuntime::Wasm, general_purpose::STANDARD.encode(fs::read(path)?))
+        } else {
+            (ExtensionRuntime::Js, fs::read_to_string(path)?)
+        };
+        Ok(ExtensionEntry {
+            settings: settings::load_with_secrets(&installed.manifest)?,
+            manifest: installed.manifest,
+            runtime,
+            source,
+        })
+    }
+
+    /// The extension's settings for the settings form, without passwords
+    pub fn settings(&self, id: &str) -> AppResult<ExtensionSettings> {
+        settings::load(&self.get(id)?.manifest)
+    }
+
+    /// Change one setting, checked against the type the manifest declares for
+    /// it; null goes back to the default
+    pub fn set_setting(&self, id: &str, key: &str, value: serde_json::Value) -> AppResult<()> {
+        settings::set(&self.get(id)?.manifest, key, value)
+    }
+
+    /// Install a `.dbx` package, replacing an installed version of the same
+    /// extension. With `expected_sha256` the package must match that checksum.
+    pub fn install(&self, package: &[u8], expected_sha256: Option<&str>) -> AppResult<InstalledExtension> {
+        let sha256 = match expected_sha256 {
+            Some(expected) => package::verify_checksum(package, expected)?,
+            None => package::sha256_hex(package),
+        };
+
+        // Unpack next to the final location so the swap is a rename
+        let staging = self.root.join(format!(".staging-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&staging)?;
+        let manifest = match package::extract(package, &staging) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging);
+                return Err(e);
+            }
+        };
+        let record = InstallRecord {
+            sha256,
+            installed_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        };
+        fs::write(staging.join(INSTALL_FILE), serde_json::to_string_pretty(&record)?)?;
+
+        let target = self.extension_dir(&manifest.id)?;
+        let previous = self.root.join(format!(".previous-{}", uuid::Uuid::new_v4()));
+        if target.exists() {
+            fs::rename(&target, &previous)?;
+        }
+        if let Err(e) = fs::rename(&staging, &target) {
+            if previous.exists() {
+                let _ = fs::rename(&previous, &target);
+            }
+            let _ = fs::remove_dir_all(&staging);
+            return Err(e.into());
+        }
+        if previous.exists() {
+            fs::remove_dir_all(&previous)?;
+        }
+
+        ExtensionLogger::new(&manifest.id).info(format!("Installed version {}", manifest.version));
+        self.read(target)
+    }
+
+    /// Remove an installed extension, its files, its settings and its logs
+    pub fn uninstall(&self, id: &str) -> AppResult<()> {
+        let dir = self.extension_dir(id)?;
+        if !dir.exists() {
+            return Err(AppError::ValidationError(format!("Extension '{}' is not installed", id)));
+        }
+        // Passwords are found through the settings the manifest declares
+        let manifest = self.get(id).ok().map(|installed| installed.manifest);
+        fs::remove_dir_all(dir)?;
+        settings::remove(id, manifest.as_ref())?;
+        logging::clear(id)?;
+        tracing::info!(extension_id = id, "Uninstalled");
+        Ok(())
+    }
+}
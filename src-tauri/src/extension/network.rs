@@ -0,0 +1,93 @@
+//! Network access for extensions. The app's content security policy keeps
+//! extension workers from connecting anywhere, so an extension with the
+//! `network` permission sends its `fetch` calls here and the backend makes
+//! the request. Only HTTP and HTTPS URLs are allowed, and responses are
+//! limited to [`MAX_RESPONSE_SIZE`].
+
+use crate::error::{AppError, AppResult};
+use crate::extension::logging::ExtensionLogger;
+use crate::models::{ExtensionFetchRequest, ExtensionFetchResponse, ExtensionManifest};
+use base64::{engine::general_purpose, Engine as _};
+use std::time::Duration;
+
+const NETWORK_PERMISSION: &str = "network";
+
+/// Largest response body handed to an extension, in bytes
+pub const MAX_RESPONSE_SIZE: usize = 20 * 1024 * 1024;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Make an HTTP request for the extension
+pub async fn fetch(manifest: &ExtensionManifest, request: ExtensionFetchRequest) -> AppResult<ExtensionFetchResponse> {
+    if !manifest
+        .permissions
+        .iter()
+        .any(|permission| permission == NETWORK_PERMISSION)
+    {
+        return Err(AppError::ValidationError(format!(
+            "Extension '{}' does not have the network permission",
+            manifest.id
+        )));
+    }
+    let url = reqwest::Url::parse(&request.url)
+        .map_err(|e| AppError::ValidationError(format!("Invalid URL '{}': {}", request.url, e)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(AppError::ValidationError(format!(
+            "Only HTTP and HTTPS URLs can be fetched, not '{}'",
+            url
+        )));
+    }
+    let method = reqwest::Method::from_bytes(request.method.to_uppercase().as_bytes())
+        .map_err(|_| AppError::ValidationError(format!("Invalid HTTP method '{}'", request.method)))?;
+
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| AppError::Internal(format!("Failed to create HTTP client: {}", e)))?;
+    let mut builder = client.request(method.clone(), url.clone());
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = &request.body {
+        let body = general_purpose::STANDARD
+            .decode(body)
+            .map_err(|e| AppError::ValidationError(format!("The request body is not valid base64: {}", e)))?;
+        builder = builder.body(body);
+    }
+
+    let mut response = builder
+        .send()
+        .await
+        .map_err(|e| AppError::GenericError(format!("Request to {} failed: {}", url, e)))?;
+    ExtensionLogger::new(&manifest.id).info(format!("{} {} -> {}", method, url, response.status()));
+
+    let status = response.status();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| value.to_str().ok().map(|value| (name.to_string(), value.to_string())))
+        .collect();
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| AppError::GenericError(format!("Failed to read the response from {}: {}", url, e)))?
+    {
+        if body.len() + chunk.len() > MAX_RESPONSE_SIZE {
+            return Err(AppError::ValidationError(format!(
+                "The response from {} is larger than {} MB",
+                url,
+                MAX_RESPONSE_SIZE / 1024 / 1024
+            )));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(ExtensionFetchResponse {
+        status: status.as_u16(),
+        status_text: status.canonical_reason().unwrap_or_default().to_string(),
+        headers,
+        body: general_purpose::STANDARD.encode(body),
+    })
+}
+
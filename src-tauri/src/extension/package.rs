@@ -0,0 +1,252 @@
+//! The `.dbx` extension package: a ZIP archive laid out as
+//!
+//! ```text
+//! manifest.json    id, name, version, entry point and permissions
+//! main.js          entry point named by the manifest (or a .wasm module)
+//! themes/*.json    definitions of contributed themes, for the Themes category
+//! README.md        optional, shown in the marketplace
+//! assets/...       anything else the extension loads
+//! ```
+//!
+//! A package is checked before anything is written: every entry must have a
+//! safe relative path and a matching CRC, the unpacked size is capped, the
+//! manifest must be valid and its entry point and theme files present, and
+//! when a checksum is known the whole archive must hash to it.
+
+use super::archive::{safe_path, ZipArchive};
+use super::settings;
+use crate::error::{AppError, AppResult};
+use crate::models::{ExtensionManifest, ThemeVariant};
+use crate::{keymap, theme};
+use ring::digest::{digest, SHA256};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Written next to the unpacked files; packages may not contain it
+pub const INSTALL_FILE: &str = ".install.json";
+
+/// Largest package accepted, in bytes
+pub const MAX_PACKAGE_SIZE: usize = 50 * 1024 * 1024;
+
+/// Largest total size of the unpacked files
+const MAX_UNPACKED_SIZE: u64 = 200 * 1024 * 1024;
+
+const MAX_ENTRIES: usize = 5000;
+
+/// Permissions a manifest may ask for
+pub const PERMISSIONS: &[&str] = &["read_schema", "run_queries", "network", "clipboard"];
+
+/// SHA-256 of the data, in lowercase hex
+pub fn sha256_hex(data: &[u8]) -> String {
+    let mut hex = String::with_capacity(64);
+    for byte in digest(&SHA256, data).as_ref() {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+/// Check the package against the expected SHA-256, given in hex
+pub fn verify_checksum(data: &[u8], expected: &str) -> AppResult<String> {
+    let actual = sha256_hex(data);
+    let expected = expected.trim().to_lowercase();
+    if expected.len() != 64 || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(AppError::ValidationError(format!("'{}' is not a SHA-256 checksum", expected)));
+    }
+    if actual != expected {
+        return Err(AppError::ValidationError(format!(
+            "The package checksum does not match: expected {}, got {}",
+            expected, actual
+        )));
+    }
+    Ok(actual)
+}
+
+/// Numeric parts of a `MAJOR.MINOR.PATCH[-prerelease]` version
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version.split_once('-').map_or(version, |(core, _)| core);
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+    let version = (parts.next()??, parts.next()??, parts.next()??);
+    parts.next().is_none().then_some(version)
+}
+
+/// Check the manifest's fields
+pub fn validate_manifest(manifest: &ExtensionManifest) -> AppResult<()> {
+    let id_valid = (3..=64).contains(&manifest.id.len())
+        && manifest.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !manifest.id.starts_with('-')
+        && !manifest.id.ends_with('-');
+    if !id_valid {
+        return Err(AppError::ValidationError(format!(
+            "Invalid extension id '{}': use 3 to 64 lowercase letters, digits and dashes",
+            manifest.id
+        )));
+    }
+    if super::builtin::is_builtin(&manifest.id) {
+        return Err(AppError::ValidationError(format!(
+            "'{}' is bundled with the app and cannot be installed from a package",
+            manifest.id
+        )));
+    }
+    if manifest.name.trim().is_empty() {
+        return Err(AppError::ValidationError("The manifest has no name".to_string()));
+    }
+    if parse_version(&manifest.version).is_none() {
+        return Err(AppError::ValidationError(format!(
+            "Invalid version '{}': expected MAJOR.MINOR.PATCH",
+            manifest.version
+        )));
+    }
+    match &manifest.main {
+        Some(main) if !main.ends_with(".js") && !main.ends_with(".wasm") => {
+            return Err(AppError::ValidationError(format!(
+                "The entry point '{}' must be a .js or .wasm file",
+                main
+            )))
+        }
+        Some(_) => {
+            entry_path(manifest)?;
+        }
+        None if manifest.contributes.themes.is_empty() => {
+            return Err(AppError::ValidationError("The manifest has no entry point".to_string()))
+        }
+        None => {}
+    }
+
+    if let Some(min_version) = &manifest.min_app_version {
+        let required = parse_version(min_version).ok_or_else(|| {
+            AppError::ValidationError(format!("Invalid minAppVersion '{}'", min_version))
+        })?;
+        if parse_version(env!("CARGO_PKG_VERSION")).is_some_and(|current| current < required) {
+            return Err(AppError::ValidationError(format!(
+                "{} needs dbfordevs {} or later",
+                manifest.name, min_version
+            )));
+        }
+    }
+    settings::validate_contributions(&manifest.contributes.settings)?;
+    let commands = &manifest.contributes.commands;
+    for (index, command) in commands.iter().enumerate() {
+        let name = command.id.strip_prefix(&format!("{}.", manifest.id)).unwrap_or_default();
+        if name.is_empty() || command.title.trim().is_empty() {
+            return Err(AppError::ValidationError(format!(
+                "Command '{}' needs a title and an id of the form {}.<name>",
+                command.id, manifest.id
+            )));
+        }
+        if commands[..index].iter().any(|other| other.id == command.id) {
+            return Err(AppError::ValidationError(format!("Command '{}' is declared twice", command.id)));
+        }
+    }
+    keymap::validate_contributions(manifest)?;
+    theme::validate_contributions(manifest)?;
+    for theme in &manifest.contributes.themes {
+        for file in [&theme.dark, &theme.light].into_iter().flatten() {
+            theme_path(file)?;
+        }
+    }
+    if let Some(unknown) = manifest.permissions.iter().find(|p| !PERMISSIONS.contains(&p.as_str())) {
+        return Err(AppError::ValidationError(format!(
+            "Unknown permission '{}'; available: {}",
+            unknown,
+            PERMISSIONS.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Entry point relative to the extension's directory
+pub fn entry_path(manifest: &ExtensionManifest) -> AppResult<PathBuf> {
+    let main = manifest.main.as_deref().ok_or_else(|| {
+        AppError::ValidationError(format!("{} has no code to run; it only contributes themes", manifest.name))
+    })?;
+    safe_path(main)
+}
+
+/// A contributed theme definition relative to the extension's directory
+pub fn theme_path(file: &str) -> AppResult<PathBuf> {
+    if !file.ends_with(".json") {
+        return Err(AppError::ValidationError(format!("The theme file '{}' must be a .json file", file)));
+    }
+    safe_path(file)
+}
+
+/// Validate the package and unpack it into `dir`, which must be empty.
+/// Returns its manifest.
+pub fn extract(data: &[u8], dir: &Path) -> AppResult<ExtensionManifest> {
+    if data.len() > MAX_PACKAGE_SIZE {
+        return Err(AppError::ValidationError(format!(
+            "The package is larger than {} MB",
+            MAX_PACKAGE_SIZE / 1024 / 1024
+        )));
+    }
+    let archive = ZipArchive::parse(data)?;
+    if archive.entries.len() > MAX_ENTRIES {
+        return Err(AppError::ValidationError(format!("The package has more than {} files", MAX_ENTRIES)));
+    }
+
+    // Check every path and the total size before writing anything
+    let mut files = Vec::new();
+    let mut total: u64 = 0;
+    for entry in &archive.entries {
+        let path = safe_path(&entry.name)?;
+        if path == Path::new(INSTALL_FILE) {
+            return Err(AppError::ValidationError(format!("The package may not contain {}", INSTALL_FILE)));
+        }
+        if files.iter().any(|(existing, _)| *existing == path) {
+            return Err(AppError::ValidationError(format!("The package contains '{}' twice", entry.name)));
+        }
+        total += entry.size as u64;
+        if total > MAX_UNPACKED_SIZE {
+            return Err(AppError::ValidationError(format!(
+                "The package unpacks to more than {} MB",
+                MAX_UNPACKED_SIZE / 1024 / 1024
+            )));
+        }
+        files.push((path, entry));
+    }
+
+    let manifest_entry = archive
+        .entries
+        .iter()
+        .find(|entry| entry.name == MANIFEST_FILE)
+        .ok_or_else(|| AppError::ValidationError(format!("The package has no {}", MANIFEST_FILE)))?;
+    let manifest: ExtensionManifest = serde_json::from_slice(&archive.read(manifest_entry)?)
+        .map_err(|e| AppError::ValidationError(format!("Invalid {}: {}", MANIFEST_FILE, e)))?;
+    validate_manifest(&manifest)?;
+    let file_entry = |path: &Path| files.iter().find(|(file, entry)| file == path && !entry.is_dir).map(|(_, entry)| *entry);
+    if let Some(main) = &manifest.main {
+        if file_entry(&entry_path(&manifest)?).is_none() {
+            return Err(AppError::ValidationError(format!("The entry point '{}' is not in the package", main)));
+        }
+    }
+    for theme in &manifest.contributes.themes {
+        let variants = [(&theme.dark, ThemeVariant::Dark), (&theme.light, ThemeVariant::Light)];
+        for (file, variant) in variants.into_iter().filter_map(|(file, variant)| Some((file.as_ref()?, variant))) {
+            let entry = file_entry(&theme_path(file)?).ok_or_else(|| {
+                AppError::ValidationError(format!("The theme file '{}' is not in the package", file))
+            })?;
+            let json = String::from_utf8(archive.read(entry)?)
+                .map_err(|_| AppError::ValidationError(format!("The theme file '{}' is not UTF-8", file)))?;
+            theme::parse_definition(&json, variant).map_err(|e| match e {
+                AppError::ValidationError(message) => AppError::ValidationError(format!("{}: {}", file, message)),
+                e => e,
+            })?;
+        }
+    }
+
+    for (path, entry) in files {
+        let target = dir.join(path);
+        if entry.is_dir {
+            fs::create_dir_all(&target)?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(target, archive.read(entry)?)?;
+    }
+    Ok(manifest)
+}
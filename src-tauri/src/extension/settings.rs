@@ -0,0 +1,230 @@
+//! Extension settings. Each extension's values are a JSON object in
+//! `extension_settings/<id>.json`; settings the manifest contributes are
+//! checked against their declared type, and anything else the extension
+//! stores for itself is kept as given. Password settings go to the system
+//! keychain instead, one entry per extension and key, and are handed to the
+//! extension but never back to the settings form.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{ExtensionManifest, ExtensionSettings, SettingContribution, SettingType};
+use crate::storage;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::PathBuf;
+
+const SETTINGS_DIR: &str = "extension_settings";
+
+/// Keychain service of an extension's passwords; the entry user is the setting key
+const KEYRING_SERVICE: &str = "com.dbfordevs.app.extension";
+
+/// Largest settings file an extension may store, in bytes
+const MAX_SETTINGS_SIZE: usize = 1024 * 1024;
+
+fn paths(id: &str) -> AppResult<(PathBuf, PathBuf)> {
+    let dir = storage::app_dir()?.join(SETTINGS_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok((dir.join(format!("{}.json", id)), dir.join(format!("{}.secrets.json", id))))
+}
+
+fn keyring_entry(id: &str, key: &str) -> AppResult<keyring::Entry> {
+    keyring::Entry::new(&format!("{}.{}", KEYRING_SERVICE, id), key)
+        .map_err(|e| AppError::ConfigError(format!("Cannot open the keychain entry for '{}': {}", key, e)))
+}
+
+fn read_secret(id: &str, key: &str) -> AppResult<Option<String>> {
+    match keyring_entry(id, key)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(AppError::ConfigError(format!("Cannot read '{}' from the keychain: {}", key, e))),
+    }
+}
+
+fn write_secret(id: &str, key: &str, secret: Option<&str>) -> AppResult<()> {
+    let entry = keyring_entry(id, key)?;
+    let result = match secret {
+        Some(secret) => entry.set_password(secret),
+        None => match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            other => other,
+        },
+    };
+    result.map_err(|e| AppError::ConfigError(format!("Cannot store '{}' in the keychain: {}", key, e)))
+}
+
+/// Password settings the manifest declares
+fn password_keys(manifest: &ExtensionManifest) -> impl Iterator<Item = &str> {
+    manifest
+        .contributes
+        .settings
+        .iter()
+        .filter(|setting| setting.setting_type == SettingType::Password)
+        .map(|setting| setting.key.as_str())
+}
+
+/// The extension's stored passwords, moving any left in the older
+/// `<id>.secrets.json` file into the keychain first
+fn read_secrets(manifest: &ExtensionManifest) -> AppResult<Map<String, Value>> {
+    let (_, secrets_path) = paths(&manifest.id)?;
+    if secrets_path.exists() {
+        let stored = read(&secrets_path)?;
+        let moved = stored
+            .iter()
+            .filter_map(|(key, value)| value.as_str().map(|secret| (key, secret)))
+            .try_for_each(|(key, secret)| write_secret(&manifest.id, key, Some(secret)));
+        if let Err(e) = moved {
+            // Keep the file until the keychain can take the passwords
+            tracing::warn!(extension_id = %manifest.id, "{}", e);
+            return Ok(stored);
+        }
+        fs::remove_file(&secrets_path)?;
+    }
+
+    // Without a keychain the passwords read as unset rather than failing the extension
+    let mut secrets = Map::new();
+    for key in password_keys(manifest) {
+        match read_secret(&manifest.id, key) {
+            Ok(Some(secret)) => {
+                secrets.insert(key.to_string(), Value::String(secret));
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(extension_id = %manifest.id, "{}", e),
+        }
+    }
+    Ok(secrets)
+}
+
+fn read(path: &PathBuf) -> AppResult<Map<String, Value>> {
+    if !path.exists() {
+        return Ok(Map::new());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn write(path: &PathBuf, values: &Map<String, Value>) -> AppResult<()> {
+    let content = serde_json::to_string_pretty(values)?;
+    if content.len() > MAX_SETTINGS_SIZE {
+        return Err(AppError::ValidationError(format!(
+            "Extension settings are larger than {} KB",
+            MAX_SETTINGS_SIZE / 1024
+        )));
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Check a value against the setting's declared type
+pub fn validate_value(setting: &SettingContribution, value: &Value) -> AppResult<()> {
+    let valid = match setting.setting_type {
+        SettingType::String | SettingType::Password => value.is_string(),
+        SettingType::Number => value.is_number(),
+        SettingType::Boolean => value.is_boolean(),
+        SettingType::Select => value.as_str().is_some_and(|v| setting.options.iter().any(|option| option == v)),
+    };
+    if valid {
+        return Ok(());
+    }
+    let expected = match setting.setting_type {
+        SettingType::Select => format!("one of {}", setting.options.join(", ")),
+        SettingType::Number => "a number".to_string(),
+        SettingType::Boolean => "true or false".to_string(),
+        SettingType::String | SettingType::Password => "text".to_string(),
+    };
+    Err(AppError::ValidationError(format!("Setting '{}' must be {}", setting.key, expected)))
+}
+
+/// Check the manifest's setting declarations
+pub fn validate_contributions(settings: &[SettingContribution]) -> AppResult<()> {
+    for (index, setting) in settings.iter().enumerate() {
+        if setting.key.trim().is_empty() || setting.title.trim().is_empty() {
+            return Err(AppError::ValidationError("Every contributed setting needs a key and a title".to_string()));
+        }
+        if settings[..index].iter().any(|other| other.key == setting.key) {
+            return Err(AppError::ValidationError(format!("Setting '{}' is declared twice", setting.key)));
+        }
+        if setting.setting_type == SettingType::Select && setting.options.is_empty() {
+            return Err(AppError::ValidationError(format!("Select setting '{}' has no options", setting.key)));
+        }
+        match (&setting.default, setting.setting_type) {
+            (Some(_), SettingType::Password) => {
+                return Err(AppError::ValidationError(format!(
+                    "Password setting '{}' cannot have a default",
+                    setting.key
+                )))
+            }
+            (Some(default), _) => validate_value(setting, default)?,
+            (None, _) => {}
+        }
+    }
+    Ok(())
+}
+
+fn defaults(manifest: &ExtensionManifest) -> Map<String, Value> {
+    manifest
+        .contributes
+        .settings
+        .iter()
+        .filter_map(|setting| setting.default.clone().map(|default| (setting.key.clone(), default)))
+        .collect()
+}
+
+/// Settings for the form: saved values over the defaults, without passwords
+pub fn load(manifest: &ExtensionManifest) -> AppResult<ExtensionSettings> {
+    let (values_path, _) = paths(&manifest.id)?;
+    let mut values = defaults(manifest);
+    values.extend(read(&values_path)?);
+    let secrets = read_secrets(manifest)?.into_iter().map(|(key, _)| key).collect();
+    Ok(ExtensionSettings { values, secrets })
+}
+
+/// Settings for the extension itself, passwords included
+pub fn load_with_secrets(manifest: &ExtensionManifest) -> AppResult<Map<String, Value>> {
+    let (values_path, _) = paths(&manifest.id)?;
+    let mut values = defaults(manifest);
+    values.extend(read(&values_path)?);
+    values.extend(read_secrets(manifest)?);
+    Ok(values)
+}
+
+/// Change one setting; null goes back to the default
+pub fn set(manifest: &ExtensionManifest, key: &str, value: Value) -> AppResult<()> {
+    let setting = manifest.contributes.settings.iter().find(|setting| setting.key == key);
+    if let Some(setting) = setting {
+        if !value.is_null() {
+            validate_value(setting, &value)?;
+        }
+    } else if key.trim().is_empty() {
+        return Err(AppError::ValidationError("The setting key is empty".to_string()));
+    }
+
+    if setting.is_some_and(|setting| setting.setting_type == SettingType::Password) {
+        // Move passwords out of the older file first so it cannot bring back the old value
+        read_secrets(manifest)?;
+        return write_secret(&manifest.id, key, value.as_str());
+    }
+
+    let (values_path, _) = paths(&manifest.id)?;
+    let mut values = read(&values_path)?;
+    if value.is_null() {
+        values.remove(key);
+    } else {
+        values.insert(key.to_string(), value);
+    }
+    write(&values_path, &values)
+}
+
+/// Forget the extension's settings and passwords
+pub fn remove(id: &str, manifest: Option<&ExtensionManifest>) -> AppResult<()> {
+    let (values_path, secrets_path) = paths(id)?;
+    for path in [values_path, secrets_path] {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+    for key in manifest.into_iter().flat_map(password_keys) {
+        if let Err(e) = write_secret(id, key, None) {
+            tracing::warn!(extension_id = id, "{}", e);
+        }
+    }
+    Ok(())
+}
+
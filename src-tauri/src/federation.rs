@@ -0,0 +1,128 @@
+//! Joins across connections, run locally: the rows of each source are copied
+//! into an in-memory SQLite database on this machine, where the query runs.
+//! Nothing is written to any connection, and the database is gone once the
+//! query returns.
+
+use crate::db::{quote_identifier, DatabaseDriver, PoolRef, QueryLimits, SqliteDriver};
+use crate::error::{AppError, AppResult};
+use crate::models::{ColumnInfo, DatabaseType, QueryResult};
+use serde_json::Value;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+/// SQLite accepts at most this many bound values per statement
+const MAX_BOUND_VALUES: usize = 32_000;
+
+/// Rows written per INSERT
+const INSERT_BATCH_ROWS: usize = 500;
+
+fn local_error(e: sqlx::Error) -> AppError {
+    AppError::QueryError(format!("Local database: {}", e))
+}
+
+/// An in-memory SQLite database holding the copied sources
+pub struct LocalDatabase {
+    pool: SqlitePool,
+    aliases: HashSet<String>,
+}
+
+impl LocalDatabase {
+    pub async fn open() -> AppResult<Self> {
+        let options = SqliteConnectOptions::from_str("sqlite::memory:").map_err(local_error)?;
+        // One connection, since every connection to :memory: is its own database
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .map_err(local_error)?;
+        Ok(Self { pool, aliases: HashSet::new() })
+    }
+
+    /// SQLite version and where it runs, to label results with
+    pub async fn engine(&self) -> String {
+        let version: Option<(String,)> = sqlx::query_as("SELECT sqlite_version()").fetch_optional(&self.pool).await.ok().flatten();
+        match version {
+            Some((version,)) => format!("SQLite {} (in memory, local)", version),
+            None => "SQLite (in memory, local)".to_string(),
+        }
+    }
+
+    /// Create a table named `alias` holding `rows`. Columns have no declared
+    /// type, so every value keeps the type it was copied with.
+    pub async fn load(&mut self, alias: &str, columns: &[ColumnInfo], rows: &[Vec<Value>]) -> AppResult<()> {
+        if !is_alias(alias) {
+            return Err(AppError::ValidationError(format!(
+                "'{}' is not a valid alias; use letters, digits and underscores",
+                alias
+            )));
+        }
+        if !self.aliases.insert(alias.to_lowercase()) {
+            return Err(AppError::ValidationError(format!("The alias {} is used twice", alias)));
+        }
+        if columns.is_empty() {
+            return Err(AppError::ValidationError(format!("{} has no columns", alias)));
+        }
+
+        // Results may repeat a column name, as joins often do
+        let mut names: Vec<String> = Vec::with_capacity(columns.len());
+        for column in columns {
+            let mut name = column.name.clone();
+            let mut copy = 2;
+            while names.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+                name = format!("{}_{}", column.name, copy);
+                copy += 1;
+            }
+            names.push(name);
+        }
+        let quote = |name: &str| quote_identifier(&DatabaseType::SQLite, name);
+        let column_list = names.iter().map(|name| quote(name)).collect::<Vec<_>>().join(", ");
+        sqlx::query(&format!("CREATE TABLE {} ({})", quote(alias), column_list))
+            .execute(&self.pool)
+            .await
+            .map_err(local_error)?;
+
+        let row_placeholders = format!("({})", vec!["?"; names.len()].join(", "));
+        let batch_rows = (MAX_BOUND_VALUES / names.len()).clamp(1, INSERT_BATCH_ROWS);
+        let mut tx = self.pool.begin().await.map_err(local_error)?;
+        for batch in rows.chunks(batch_rows) {
+            let sql = format!(
+                "INSERT INTO {} ({}) VALUES {}",
+                quote(alias),
+                column_list,
+                vec![row_placeholders.as_str(); batch.len()].join(", ")
+            );
+            let mut query = sqlx::query(&sql);
+            for row in batch {
+                for index in 0..names.len() {
+                    query = match row.get(index).unwrap_or(&Value::Null) {
+                        Value::Null => query.bind(None::<String>),
+                        Value::Bool(value) => query.bind(i64::from(*value)),
+                        Value::Number(number) => match number.as_i64() {
+                            Some(value) => query.bind(value),
+                            None => query.bind(number.as_f64()),
+                        },
+                        Value::String(text) => query.bind(text.clone()),
+                        other => query.bind(other.to_string()),
+                    };
+                }
+            }
+            query.execute(&mut *tx).await.map_err(local_error)?;
+        }
+        tx.commit().await.map_err(local_error)
+    }
+
+    /// Run SQLite SQL over the loaded sources
+    pub async fn query(&self, sql: &str, limits: &QueryLimits) -> AppResult<QueryResult> {
+        SqliteDriver.execute_query_limited(PoolRef::Sqlite(&self.pool), sql, limits).await
+    }
+
+    pub async fn close(self) {
+        self.pool.close().await;
+    }
+}
+
+fn is_alias(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_') && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
@@ -0,0 +1,77 @@
+/// Delimiters tried when sniffing a CSV file
+const CANDIDATE_DELIMITERS: [char; 4] = [',', ';', '\t', '|'];
+
+/// Records sampled when sniffing the delimiter
+const SNIFF_RECORDS: usize = 20;
+
+/// Split RFC 4180 delimited text into records; quoted fields may contain delimiters and newlines
+pub fn parse_delimited(text: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' && field.is_empty() {
+            in_quotes = true;
+        } else if c == delimiter {
+            record.push(std::mem::take(&mut field));
+        } else if c == '\n' || c == '\r' {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+            record.push(std::mem::take(&mut field));
+            // Skip blank lines
+            if record.len() > 1 || !record[0].is_empty() {
+                records.push(std::mem::take(&mut record));
+            } else {
+                record.clear();
+            }
+        } else {
+            field.push(c);
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+/// Pick the candidate delimiter that splits the first records into the most, equally sized fields
+pub fn sniff_delimiter(text: &str) -> char {
+    // Only look at the start of the file, cut at a line break
+    let sample = match text.char_indices().nth(64 * 1024) {
+        Some((end, _)) => &text[..text[..end].rfind('\n').unwrap_or(end)],
+        None => text,
+    };
+
+    let mut best = (',', 1);
+    for delimiter in CANDIDATE_DELIMITERS {
+        let records = parse_delimited(sample, delimiter);
+        let records = &records[..records.len().min(SNIFF_RECORDS)];
+        let Some(first) = records.first() else {
+            continue;
+        };
+
+        let fields = first.len();
+        if fields > best.1 && records.iter().all(|r| r.len() == fields) {
+            best = (delimiter, fields);
+        }
+    }
+    best.0
+}
@@ -0,0 +1,48 @@
+use crate::error::{AppError, AppResult};
+
+/// Parse a JSON array of objects or NDJSON into column names and rows.
+/// Columns are the union of all object keys; missing keys become null.
+pub fn parse_json(text: &str) -> AppResult<(Vec<String>, Vec<Vec<serde_json::Value>>)> {
+    let items: Vec<serde_json::Value> = if text.trim_start().starts_with('[') {
+        serde_json::from_str(text)?
+    } else {
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut objects = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        match item {
+            serde_json::Value::Object(object) => objects.push(object),
+            _ => {
+                return Err(AppError::ValidationError(format!(
+                    "Row {} is not a JSON object",
+                    index + 1
+                )))
+            }
+        }
+    }
+
+    let mut columns: Vec<String> = Vec::new();
+    for object in &objects {
+        for key in object.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    let rows = objects
+        .into_iter()
+        .map(|mut object| {
+            columns
+                .iter()
+                .map(|column| object.remove(column).unwrap_or(serde_json::Value::Null))
+                .collect()
+        })
+        .collect();
+
+    Ok((columns, rows))
+}
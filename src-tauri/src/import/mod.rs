@@ -0,0 +1,290 @@
+//! Readers that turn CSV, TSV and JSON files into rows for table imports.
+
+mod csv;
+mod json;
+
+use crate::error::{AppError, AppResult};
+use crate::models::{DatabaseType, ImportColumn, ImportColumnType, ImportFileOptions, ImportFormat};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+pub use self::csv::{parse_delimited, sniff_delimiter};
+pub use self::json::parse_json;
+
+/// Contents of an import file
+pub struct ImportData {
+    pub format: ImportFormat,
+    pub delimiter: Option<char>,
+    pub has_header: bool,
+    pub columns: Vec<String>,
+    /// Rows as read; CSV rows may be shorter or longer than `columns`
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+/// Read and parse an import file, detecting whatever `options` leaves unset
+pub fn read_import_file(path: &str, options: &ImportFileOptions) -> AppResult<ImportData> {
+    let text = std::fs::read_to_string(path)?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+
+    let format = options.format.unwrap_or_else(|| detect_format(path));
+
+    if format == ImportFormat::Json {
+        let (columns, rows) = parse_json(text)?;
+        return Ok(ImportData {
+            format,
+            delimiter: None,
+            has_header: false,
+            columns,
+            rows,
+        });
+    }
+
+    let delimiter = match options.delimiter.as_deref() {
+        Some("\\t") => '\t',
+        Some(d) => {
+            let mut chars = d.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => return Err(AppError::ValidationError(format!("Delimiter must be one character, got '{}'", d))),
+            }
+        }
+        None if format == ImportFormat::Tsv => '\t',
+        None => sniff_delimiter(text),
+    };
+    let has_header = options.has_header.unwrap_or(true);
+
+    let mut records = parse_delimited(text, delimiter).into_iter();
+    let width = records.as_slice().iter().map(Vec::len).max().unwrap_or(0);
+
+    let columns = if has_header {
+        column_names(records.next().unwrap_or_default())
+    } else {
+        (1..=width).map(|i| format!("column_{}", i)).collect()
+    };
+
+    let rows = records
+        .map(|record| {
+            record
+                .into_iter()
+                .map(|field| {
+                    if field.is_empty() {
+                        serde_json::Value::Null
+                    } else {
+                        serde_json::Value::String(field)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(ImportData {
+        format,
+        delimiter: Some(delimiter),
+        has_header,
+        columns,
+        rows,
+    })
+}
+
+/// Format implied by the file extension, defaulting to CSV
+pub fn detect_format(path: &str) -> ImportFormat {
+    let extension = std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    match extension.as_str() {
+        "tsv" | "tab" => ImportFormat::Tsv,
+        "json" | "ndjson" | "jsonl" => ImportFormat::Json,
+        _ => ImportFormat::Csv,
+    }
+}
+
+/// Header names with blanks filled in and duplicates suffixed (`id`, `id_2`, ...)
+fn column_names(header: Vec<String>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::with_capacity(header.len());
+    for (i, raw) in header.into_iter().enumerate() {
+        let base = match raw.trim() {
+            "" => format!("column_{}", i + 1),
+            trimmed => trimmed.to_string(),
+        };
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while names.contains(&name) {
+            name = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// Infer each column's type from the given rows
+pub fn infer_columns(names: &[String], rows: &[Vec<serde_json::Value>]) -> Vec<ImportColumn> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let mut column_type: Option<ImportColumnType> = None;
+            let mut nullable = false;
+
+            for value in rows.iter().map(|row| row.get(index).unwrap_or(&serde_json::Value::Null)) {
+                match value_type(value) {
+                    Some(t) => column_type = Some(column_type.map_or(t, |current| widen(current, t))),
+                    None => nullable = true,
+                }
+            }
+
+            ImportColumn {
+                name: name.clone(),
+                column_type: column_type.unwrap_or(ImportColumnType::Text),
+                nullable,
+            }
+        })
+        .collect()
+}
+
+/// Type of a single value; None for null
+fn value_type(value: &serde_json::Value) -> Option<ImportColumnType> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::Bool(_) => Some(ImportColumnType::Boolean),
+        serde_json::Value::Number(n) if n.is_f64() => Some(ImportColumnType::Float),
+        serde_json::Value::Number(_) => Some(ImportColumnType::Integer),
+        serde_json::Value::String(s) => Some(text_type(s)),
+        _ => Some(ImportColumnType::Text),
+    }
+}
+
+fn text_type(text: &str) -> ImportColumnType {
+    let text = text.trim();
+    // Leading zeros (zip codes, account numbers) must survive, so keep them as text
+    let leading_zero = text.len() > 1 && text.starts_with('0') && text.as_bytes()[1].is_ascii_digit();
+
+    if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+        ImportColumnType::Boolean
+    } else if !leading_zero && !text.starts_with('+') && text.parse::<i64>().is_ok() {
+        ImportColumnType::Integer
+    } else if !leading_zero && text.contains(|c: char| c.is_ascii_digit()) && text.parse::<f64>().is_ok_and(f64::is_finite) {
+        ImportColumnType::Float
+    } else if NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok() {
+        ImportColumnType::Date
+    } else if parse_timestamp(text).is_some() {
+        ImportColumnType::Timestamp
+    } else {
+        ImportColumnType::Text
+    }
+}
+
+/// Narrowest type that holds values of both types
+fn widen(a: ImportColumnType, b: ImportColumnType) -> ImportColumnType {
+    use ImportColumnType::*;
+    match (a, b) {
+        _ if a == b => a,
+        (Integer, Float) | (Float, Integer) => Float,
+        (Date, Timestamp) | (Timestamp, Date) => Timestamp,
+        _ => Text,
+    }
+}
+
+fn parse_timestamp(text: &str) -> Option<NaiveDateTime> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Some(dt.naive_utc());
+    }
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+}
+
+/// Convert a file value to the JSON type drivers bind for `column_type`.
+/// Values that do not convert are passed through so the database reports the error.
+pub fn convert_value(value: serde_json::Value, column_type: ImportColumnType) -> serde_json::Value {
+    let text = match &value {
+        serde_json::Value::String(s) => s.trim(),
+        _ => return value,
+    };
+
+    let converted = match column_type {
+        ImportColumnType::Boolean if text.eq_ignore_ascii_case("true") => Some(serde_json::Value::Bool(true)),
+        ImportColumnType::Boolean if text.eq_ignore_ascii_case("false") => Some(serde_json::Value::Bool(false)),
+        ImportColumnType::Integer => text.parse::<i64>().ok().map(serde_json::Value::from),
+        ImportColumnType::Float => text
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number),
+        // Normalise so every database accepts the literal; offsets are converted to UTC
+        ImportColumnType::Timestamp => parse_timestamp(text)
+            .map(|dt| serde_json::Value::String(dt.format("%Y-%m-%d %H:%M:%S%.f").to_string())),
+        _ => None,
+    };
+
+    converted.unwrap_or(value)
+}
+
+/// Column type used when creating a table for `column_type`; None for databases without DDL
+pub fn sql_type(database_type: &DatabaseType, column_type: ImportColumnType) -> Option<&'static str> {
+    use ImportColumnType::*;
+    let sql_type = match database_type {
+        DatabaseType::PostgreSQL => match column_type {
+            Boolean => "BOOLEAN",
+            Integer => "BIGINT",
+            Float => "DOUBLE PRECISION",
+            Date => "DATE",
+            Timestamp => "TIMESTAMP",
+            Text => "TEXT",
+        },
+        DatabaseType::MySQL => match column_type {
+            Boolean => "BOOLEAN",
+            Integer => "BIGINT",
+            Float => "DOUBLE",
+            Date => "DATE",
+            Timestamp => "DATETIME(6)",
+            Text => "TEXT",
+        },
+        DatabaseType::SQLite => match column_type {
+            Boolean => "BOOLEAN",
+            Integer => "INTEGER",
+            Float => "REAL",
+            Date => "DATE",
+            Timestamp => "DATETIME",
+            Text => "TEXT",
+        },
+        DatabaseType::MSSQL => match column_type {
+            Boolean => "BIT",
+            Integer => "BIGINT",
+            Float => "FLOAT",
+            Date => "DATE",
+            Timestamp => "DATETIME2",
+            Text => "NVARCHAR(MAX)",
+        },
+        // Columns reject NULL unless declared Nullable, and empty fields import as NULL
+        DatabaseType::ClickHouse => match column_type {
+            Boolean => "Nullable(Bool)",
+            Integer => "Nullable(Int64)",
+            Float => "Nullable(Float64)",
+            Date => "Nullable(Date32)",
+            Timestamp => "Nullable(DateTime64(6))",
+            Text => "Nullable(String)",
+        },
+        DatabaseType::DuckDB => match column_type {
+            Boolean => "BOOLEAN",
+            Integer => "BIGINT",
+            Float => "DOUBLE",
+            Date => "DATE",
+            Timestamp => "TIMESTAMP",
+            Text => "VARCHAR",
+        },
+        // Booleans are numbers before Oracle 23; 4000 bytes is the longest VARCHAR2 everywhere
+        DatabaseType::Oracle => match column_type {
+            Boolean => "NUMBER(1)",
+            Integer => "NUMBER(19)",
+            Float => "BINARY_DOUBLE",
+            Date => "DATE",
+            Timestamp => "TIMESTAMP(6)",
+            Text => "VARCHAR2(4000)",
+        },
+        DatabaseType::MongoDB | DatabaseType::Redis => return None,
+    };
+    Some(sql_type)
+}
@@ -0,0 +1,334 @@
+//! Keyboard shortcuts. The app's own bindings and the shortcuts extensions
+//! suggest for their commands are resolved into one keymap, with the user's
+//! changes from `keybindings.json` on top. When two commands ask for the same
+//! shortcut, a user's choice wins over a built-in binding, which wins over an
+//! extension; among extensions the first one listed keeps it. The loser is
+//! left unbound and reports who holds the shortcut.
+//!
+//! Shortcuts are written as modifiers and a key joined by `+`, e.g.
+//! `Mod+Shift+K`; `Mod` is Cmd on macOS and Ctrl elsewhere. They are stored
+//! and returned in a canonical form: `Mod`, `Alt`, `Shift`, then the key.
+
+use crate::error::{AppError, AppResult};
+use crate::extension::{dispatch, ExtensionLoader};
+use crate::models::{ExtensionManifest, KeyBinding, KeyBindingSource, ShortcutConflict};
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+const KEYBINDINGS_FILE: &str = "keybindings.json";
+
+struct BuiltinBinding {
+    command_id: &'static str,
+    title: &'static str,
+    shortcut: &'static str,
+    /// Handled by the editor or the dialogs rather than the app's key handler
+    fixed: bool,
+}
+
+const fn binding(command_id: &'static str, title: &'static str, shortcut: &'static str) -> BuiltinBinding {
+    BuiltinBinding {
+        command_id,
+        title,
+        shortcut,
+        fixed: false,
+    }
+}
+
+const fn fixed(command_id: &'static str, title: &'static str, shortcut: &'static str) -> BuiltinBinding {
+    BuiltinBinding {
+        command_id,
+        title,
+        shortcut,
+        fixed: true,
+    }
+}
+
+/// The app's bindings, in the order they are listed
+const BUILTIN_BINDINGS: &[BuiltinBinding] = &[
+    fixed("editor.executeQuery", "Execute query", "Mod+Enter"),
+    binding("app.newConnection", "New connection", "Mod+K"),
+    binding("app.openSettings", "Open settings", "Mod+,"),
+    binding("app.toggleSidebar", "Toggle sidebar", "Mod+B"),
+    binding("ai.togglePanel", "Toggle AI Assistant", "Mod+Shift+A"),
+    binding("ai.togglePanelQuick", "Toggle AI Assistant (quick)", "Mod+P"),
+    binding("ai.togglePanelLegacy", "Toggle AI Assistant (legacy)", "Mod+Alt+B"),
+    fixed("editor.explainWithAi", "Explain with AI", "Mod+Shift+E"),
+    fixed("editor.optimizeWithAi", "Optimize with AI", "Mod+Shift+O"),
+    binding("tabs.newQuery", "New query tab", "Mod+T"),
+    binding("tabs.close", "Close tab", "Mod+W"),
+    binding("changes.showDiff", "View changes diff", "Mod+Shift+D"),
+    binding("changes.undo", "Undo last pending change", "Mod+Z"),
+    binding("editor.find", "Find", "Mod+F"),
+    binding("editor.findReplace", "Find and Replace", "Mod+Alt+F"),
+    binding("results.scrollUp", "Scroll results up", "Alt+ArrowUp"),
+    binding("results.scrollDown", "Scroll results down", "Alt+ArrowDown"),
+    binding("app.help", "Open help", "F1"),
+    binding("app.toggleFullscreen", "Toggle fullscreen", "F11"),
+    fixed("app.closeDialogs", "Close dialogs", "Escape"),
+];
+
+/// Keys that need no modifier, since they do not type anything
+const FUNCTION_KEYS: &[&str] = &[
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "Escape",
+];
+
+const NAMED_KEYS: &[&str] = &[
+    "Enter",
+    "Tab",
+    "Space",
+    "Backspace",
+    "Delete",
+    "Insert",
+    "Home",
+    "End",
+    "PageUp",
+    "PageDown",
+    "ArrowUp",
+    "ArrowDown",
+    "ArrowLeft",
+    "ArrowRight",
+];
+
+const PUNCTUATION: &str = ",./;'[]\\-=`";
+
+/// The user's changes: command id -> shortcut, or None to unbind it
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SavedKeybindings {
+    overrides: BTreeMap<String, Option<String>>,
+}
+
+fn key_name(key: &str) -> Option<String> {
+    let lower = key.to_ascii_lowercase();
+    let alias = match lower.as_str() {
+        "esc" => "Escape",
+        "return" => "Enter",
+        "up" => "ArrowUp",
+        "down" => "ArrowDown",
+        "left" => "ArrowLeft",
+        "right" => "ArrowRight",
+        "del" => "Delete",
+        _ => "",
+    };
+    if !alias.is_empty() {
+        return Some(alias.to_string());
+    }
+    if key.len() == 1 {
+        let c = key.chars().next()?;
+        if c.is_ascii_alphanumeric() {
+            return Some(c.to_ascii_uppercase().to_string());
+        }
+        return PUNCTUATION.contains(c).then(|| key.to_string());
+    }
+    FUNCTION_KEYS
+        .iter()
+        .chain(NAMED_KEYS)
+        .find(|name| name.eq_ignore_ascii_case(key))
+        .map(|name| name.to_string())
+}
+
+/// Parse a shortcut into canonical form. Cmd, Ctrl and Meta all mean `Mod`,
+/// and Option means `Alt`. Keys that type something need `Mod` or `Alt`.
+pub fn normalize(shortcut: &str) -> AppResult<String> {
+    let invalid = |reason: &str| AppError::ValidationError(format!("Invalid shortcut '{}': {}", shortcut, reason));
+    let parts: Vec<&str> = shortcut.split('+').map(str::trim).collect();
+    let (key, modifiers) = parts.split_last().ok_or_else(|| invalid("it is empty"))?;
+    if key.is_empty() || modifiers.iter().any(|m| m.is_empty()) {
+        return Err(invalid("join modifiers and the key with '+'"));
+    }
+
+    let (mut ctrl, mut alt, mut shift) = (false, false, false);
+    for modifier in modifiers {
+        let flag = match modifier.to_ascii_lowercase().as_str() {
+            "mod" | "cmd" | "command" | "ctrl" | "control" | "meta" | "cmdorctrl" => &mut ctrl,
+            "alt" | "option" | "opt" => &mut alt,
+            "shift" => &mut shift,
+            _ => return Err(invalid(&format!("unknown modifier '{}'", modifier))),
+        };
+        if *flag {
+            return Err(invalid(&format!("'{}' is given twice", modifier)));
+        }
+        *flag = true;
+    }
+
+    let key = key_name(key).ok_or_else(|| invalid(&format!("unknown key '{}'", key)))?;
+    if !ctrl && !alt && !FUNCTION_KEYS.contains(&key.as_str()) {
+        return Err(invalid("add Mod or Alt so it does not get in the way of typing"));
+    }
+
+    let mut canonical: Vec<&str> = Vec::new();
+    if ctrl {
+        canonical.push("Mod");
+    }
+    if alt {
+        canonical.push("Alt");
+    }
+    if shift {
+        canonical.push("Shift");
+    }
+    canonical.push(&key);
+    Ok(canonical.join("+"))
+}
+
+/// Check the shortcuts an extension suggests for its commands
+pub fn validate_contributions(manifest: &ExtensionManifest) -> AppResult<()> {
+    let mut seen: HashMap<String, &str> = HashMap::new();
+    for command in &manifest.contributes.commands {
+        let Some(shortcut) = &command.shortcut else {
+            continue;
+        };
+        let shortcut = normalize(shortcut)?;
+        if let Some(other) = seen.insert(shortcut.clone(), &command.id) {
+            return Err(AppError::ValidationError(format!(
+                "Commands '{}' and '{}' both ask for {}",
+                other, command.id, shortcut
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn keybindings_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(KEYBINDINGS_FILE))
+}
+
+fn saved() -> AppResult<SavedKeybindings> {
+    let path = keybindings_path()?;
+    if !path.exists() {
+        return Ok(SavedKeybindings::default());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn save(saved: &SavedKeybindings) -> AppResult<()> {
+    fs::write(keybindings_path()?, serde_json::to_string_pretty(saved)?)?;
+    Ok(())
+}
+
+/// Every command that can have a shortcut, before conflicts are resolved
+fn bindings(loader: &ExtensionLoader) -> AppResult<Vec<KeyBinding>> {
+    let mut bindings: Vec<KeyBinding> = BUILTIN_BINDINGS
+        .iter()
+        .map(|builtin| KeyBinding {
+            command_id: builtin.command_id.to_string(),
+            title: builtin.title.to_string(),
+            source: KeyBindingSource::Builtin,
+            extension_id: None,
+            default_shortcut: Some(builtin.shortcut.to_string()),
+            shortcut: None,
+            overridden: false,
+            fixed: builtin.fixed,
+            conflict: None,
+        })
+        .collect();
+
+    for info in dispatch::commands(loader)? {
+        // Checked on install; skip one that was edited afterwards
+        let default_shortcut = info.command.shortcut.as_deref().and_then(|s| normalize(s).ok());
+        bindings.push(KeyBinding {
+            title: format!("{}: {}", info.extension_name, info.command.title),
+            command_id: info.command.id,
+            source: KeyBindingSource::Extension,
+            extension_id: Some(info.extension_id),
+            default_shortcut,
+            shortcut: None,
+            overridden: false,
+            fixed: false,
+            conflict: None,
+        });
+    }
+    Ok(bindings)
+}
+
+/// The keymap in effect: built-in bindings, then those of the installed
+/// extensions, each with its shortcut or the conflict that left it unbound
+pub fn resolve(loader: &ExtensionLoader) -> AppResult<Vec<KeyBinding>> {
+    let overrides = saved()?.overrides;
+    let mut bindings = bindings(loader)?;
+
+    let mut requested: Vec<Option<String>> = Vec::with_capacity(bindings.len());
+    for binding in &mut bindings {
+        match overrides.get(&binding.command_id) {
+            Some(shortcut) if !binding.fixed => {
+                binding.overridden = true;
+                requested.push(shortcut.clone());
+            }
+            _ => requested.push(binding.default_shortcut.clone()),
+        }
+    }
+
+    // Users' choices first, then built-in bindings, then extensions in order
+    let mut order: Vec<usize> = (0..bindings.len()).collect();
+    order.sort_by_key(|&i| (!bindings[i].overridden, bindings[i].source == KeyBindingSource::Extension));
+
+    let mut holders: HashMap<String, usize> = HashMap::new();
+    for i in order {
+        let Some(shortcut) = requested[i].take() else {
+            continue;
+        };
+        match holders.get(&shortcut) {
+            Some(&holder) => {
+                bindings[i].conflict = Some(ShortcutConflict {
+                    shortcut,
+                    command_id: bindings[holder].command_id.clone(),
+                    title: bindings[holder].title.clone(),
+                });
+            }
+            None => {
+                holders.insert(shortcut.clone(), i);
+                bindings[i].shortcut = Some(shortcut);
+            }
+        }
+    }
+    Ok(bindings)
+}
+
+/// Change a command's shortcut, or unbind it with None. A shortcut already in
+/// use by another command is refused; free it first.
+pub fn set_shortcut(loader: &ExtensionLoader, command_id: &str, shortcut: Option<&str>) -> AppResult<Vec<KeyBinding>> {
+    let keymap = resolve(loader)?;
+    let binding = keymap
+        .iter()
+        .find(|binding| binding.command_id == command_id)
+        .ok_or_else(|| AppError::ValidationError(format!("Unknown command '{}'", command_id)))?;
+    if binding.fixed {
+        return Err(AppError::ValidationError(format!(
+            "The shortcut for {} cannot be changed",
+            binding.title
+        )));
+    }
+
+    let shortcut = shortcut.map(normalize).transpose()?;
+    if let Some(shortcut) = &shortcut {
+        if let Some(holder) = keymap
+            .iter()
+            .find(|other| other.command_id != command_id && other.shortcut.as_ref() == Some(shortcut))
+        {
+            return Err(AppError::ValidationError(format!(
+                "{} is already used by {}",
+                shortcut, holder.title
+            )));
+        }
+    }
+
+    let mut saved = saved()?;
+    if shortcut == binding.default_shortcut {
+        saved.overrides.remove(command_id);
+    } else {
+        saved.overrides.insert(command_id.to_string(), shortcut);
+    }
+    save(&saved)?;
+    resolve(loader)
+}
+
+/// Go back to a command's default shortcut
+pub fn reset_shortcut(loader: &ExtensionLoader, command_id: &str) -> AppResult<Vec<KeyBinding>> {
+    let mut saved = saved()?;
+    if saved.overrides.remove(command_id).is_some() {
+        save(&saved)?;
+    }
+    resolve(loader)
+}
@@ -1,45 +1,232 @@
+mod ai_usage;
+mod audit;
+mod backup;
+mod blob;
+mod change_feed;
+mod chart;
 mod commands;
+mod confirmation;
+mod connection_usage;
 mod db;
+mod env_vars;
 mod error;
+mod export;
+mod extension;
+mod federation;
+mod import;
+mod keymap;
+mod logging;
+mod marketplace;
+mod metrics;
+mod migration;
 mod models;
+mod monitor;
+mod prompt_templates;
+mod read_only;
+mod result_store;
+mod schema_cache;
+mod schema_search;
+mod sql;
 mod storage;
+mod templates;
+mod theme;
+mod transfer;
 
-use commands::{connections, queries, tables, utils};
+use commands::{
+    ai, app_logs, audit_log, backups, browse, charts, connections, databases, diagram, diff, documents, editor, exports,
+    extensions, imports, keybindings, mock_data, monitoring, notifications, pins, queries, redis_keys, saved_queries,
+    search, tables, transfers, utils, workspace,
+};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    logging::init();
+    tracing::info!(version = env!("CARGO_PKG_VERSION"), "Starting dbfordevs");
+
     // Install default drivers for sqlx::any
     sqlx::any::install_default_drivers();
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_http::init())
+        .setup(|_app| {
+            extension::builtin::register();
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             // Connection commands
             connections::test_connection,
             connections::save_connection,
             connections::connect,
+            connections::switch_database,
             connections::disconnect,
             connections::list_connections,
             connections::delete_connection,
             connections::get_connection,
+            connections::get_connection_insights,
+            connections::export_connections,
+            connections::import_connections,
+            connections::list_connection_templates,
+            connections::check_template_host,
+            // Database commands
+            databases::create_database,
+            databases::drop_database,
+            databases::create_schema,
+            databases::drop_schema,
+            databases::get_users_and_roles,
+            // Monitoring commands
+            monitoring::get_server_stats,
+            monitoring::get_active_queries,
+            monitoring::kill_session,
+            monitoring::get_slow_queries,
+            monitoring::start_server_monitor,
+            monitoring::stop_server_monitor,
+            monitoring::get_performance_metrics,
+            monitoring::reset_performance_metrics,
+            // Change notification commands
+            notifications::listen_channel,
+            notifications::unlisten_channel,
+            notifications::list_listen_channels,
+            notifications::watch_table,
+            notifications::unwatch_table,
             // Query commands
             queries::execute_query,
+            queries::get_result_rows,
+            queries::get_cell_blob,
+            queries::save_blob_to_file,
+            queries::release_result,
+            queries::close_session,
+            queries::ask_data_question,
+            queries::run_federated_query,
+            queries::explain_query,
+            queries::vector_search,
+            queries::run_sql_file,
+            backups::get_backup_tools,
+            backups::backup_database,
+            backups::restore_database,
+            transfers::copy_table_between_connections,
+            transfers::cancel_table_copy,
+            charts::prepare_chart_data,
+            browse::browse_table,
+            browse::get_referenced_row,
+            browse::get_referencing_rows,
             queries::get_tables,
+            queries::list_databases,
+            queries::list_schemas,
             queries::get_table_schema,
             queries::get_all_table_schemas,
             queries::insert_row,
+            queries::upsert_rows,
             queries::update_row,
             queries::delete_row,
             queries::drop_table,
             // Table commands
             tables::generate_table_ddl,
+            tables::generate_database_ddl,
             tables::rename_table,
+            tables::truncate_table,
+            tables::duplicate_table,
+            tables::add_column,
+            tables::drop_column,
+            tables::rename_column,
+            tables::alter_column_type,
             tables::get_table_properties,
+            tables::count_exact_rows,
+            tables::preview_schema_comments,
+            tables::apply_schema_comments,
+            tables::get_table_stats,
+            tables::get_table_partitions,
             tables::get_table_relationships,
+            tables::get_table_privileges,
+            tables::generate_migration,
+            diff::diff_table_data,
+            diff::diff_results,
+            diagram::generate_er_model,
+            // Document commands
+            documents::find_documents,
+            // Redis commands
+            redis_keys::redis_scan_keys,
+            redis_keys::redis_get_value,
+            redis_keys::redis_set_value,
+            redis_keys::redis_delete_keys,
+            // Export commands
+            exports::export_query_results,
+            // Import commands
+            imports::preview_import_file,
+            imports::import_file_into_table,
+            mock_data::generate_mock_data,
+            // Editor commands
+            editor::format_sql,
+            editor::lint_sql,
+            editor::get_query_parameters,
+            editor::get_sql_completions,
+            // Saved query commands
+            saved_queries::list_saved_queries,
+            saved_queries::save_saved_query,
+            saved_queries::delete_saved_query,
+            saved_queries::set_saved_query_preset,
+            saved_queries::get_saved_query_params,
+            search::search_database_objects,
+            search::search_table_data,
+            // Favorite commands
+            pins::pin_object,
+            pins::list_pinned,
+            // Audit commands
+            audit_log::get_audit_log,
+            audit_log::export_audit_log,
+            // AI usage commands
+            ai::record_ai_usage,
+            ai::get_ai_usage_stats,
+            // Prompt template commands
+            ai::get_prompt_templates,
+            ai::save_prompt_template,
+            ai::reset_prompt_to_default,
+            // Semantic schema search commands
+            ai::get_schema_documents,
+            ai::save_schema_index,
+            ai::build_local_schema_index,
+            ai::get_schema_index_info,
+            ai::semantic_search_schema,
+            // Marketplace commands
+            extensions::search_marketplace,
+            extensions::get_marketplace_registry_url,
+            extensions::set_marketplace_registry_url,
+            extensions::list_installed_extensions,
+            extensions::set_builtin_extension_enabled,
+            extensions::restart_extension,
+            extensions::report_extension_status,
+            extensions::get_extension_logs,
+            extensions::append_extension_log,
+            extensions::clear_extension_logs,
+            extensions::install_extension,
+            extensions::install_marketplace_extension,
+            extensions::uninstall_extension,
+            extensions::load_extension,
+            extensions::get_extension_settings,
+            extensions::set_extension_setting,
+            extensions::list_extension_commands,
+            extensions::execute_extension_command,
+            extensions::complete_extension_command,
+            extensions::extension_fetch,
+            extensions::list_themes,
+            extensions::get_theme_css,
+            extensions::get_active_theme,
+            extensions::set_active_theme,
+            // Keybinding commands
+            keybindings::get_keymap,
+            keybindings::set_keybinding,
+            keybindings::reset_keybinding,
+            // Application log commands
+            app_logs::get_app_logs,
+            app_logs::get_log_settings,
+            app_logs::set_log_level,
+            // Workspace commands
+            workspace::save_workspace,
+            workspace::restore_workspace,
             // Utility commands
             utils::copy_to_clipboard,
             utils::read_from_clipboard,
+            utils::copy_results_as,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,248 @@
+//! Application logs. The backend logs through `tracing`; events go to a file
+//! in `logs/` in the app data directory that rolls over daily (a week is
+//! kept), to a ring buffer of recent events that the UI can show and copy
+//! into a bug report, and to stderr in debug builds.
+//!
+//! The level is set for the whole app and optionally per module, e.g. `db`
+//! or `extension::dispatch`; the settings are saved in `log_settings.json`
+//! and applied without a restart. Other crates only log warnings and errors.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{AppLogEntry, AppLogs, LogSettings};
+use crate::storage;
+use chrono::{SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt as format, reload, EnvFilter, Layer, Registry};
+
+const LOG_DIR: &str = "logs";
+const LOG_FILE_PREFIX: &str = "dbfordevs";
+const KEPT_LOG_FILES: usize = 7;
+const SETTINGS_FILE: &str = "log_settings.json";
+
+/// Recent events kept in memory
+const BUFFER_SIZE: usize = 2000;
+
+/// Events returned when the caller does not say how many
+pub const DEFAULT_LIMIT: usize = 500;
+
+const LEVELS: &[&str] = &["trace", "debug", "info", "warn", "error", "off"];
+const DEFAULT_LEVEL: &str = "info";
+
+static BUFFER: OnceCell<Mutex<VecDeque<AppLogEntry>>> = OnceCell::new();
+static FILTER: OnceCell<reload::Handle<EnvFilter, Registry>> = OnceCell::new();
+
+fn buffer() -> &'static Mutex<VecDeque<AppLogEntry>> {
+    BUFFER.get_or_init(|| Mutex::new(VecDeque::with_capacity(BUFFER_SIZE)))
+}
+
+fn log_dir() -> AppResult<PathBuf> {
+    let dir = storage::app_dir()?.join(LOG_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn settings_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(SETTINGS_FILE))
+}
+
+fn default_settings() -> LogSettings {
+    LogSettings {
+        level: DEFAULT_LEVEL.to_string(),
+        modules: BTreeMap::new(),
+    }
+}
+
+/// The saved settings, or the defaults when there are none
+pub fn settings() -> AppResult<LogSettings> {
+    let path = settings_path()?;
+    if !path.exists() {
+        return Ok(default_settings());
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn validate_level(level: &str) -> AppResult<()> {
+    if LEVELS.contains(&level) {
+        return Ok(());
+    }
+    Err(AppError::ValidationError(format!(
+        "Unknown log level '{}'; available: {}",
+        level,
+        LEVELS.join(", ")
+    )))
+}
+
+/// A module path within the app, e.g. `db` or `extension::dispatch`
+fn validate_module(module: &str) -> AppResult<()> {
+    let valid = !module.is_empty()
+        && module
+            .split("::")
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'));
+    if valid {
+        return Ok(());
+    }
+    Err(AppError::ValidationError(format!("Invalid module '{}'; use a path such as db::manager", module)))
+}
+
+fn filter(settings: &LogSettings) -> AppResult<EnvFilter> {
+    let krate = env!("CARGO_CRATE_NAME");
+    let mut directives = format!("warn,{}={}", krate, settings.level);
+    for (module, level) in &settings.modules {
+        directives.push_str(&format!(",{}::{}={}", krate, module, level));
+    }
+    EnvFilter::try_new(&directives).map_err(|e| AppError::ValidationError(format!("Invalid log filter: {}", e)))
+}
+
+/// Keeps the most recent events for [`recent`]
+struct RingBuffer;
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for RingBuffer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let entry = AppLogEntry {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level: metadata.level().as_str().to_ascii_lowercase(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        };
+        let mut buffer = buffer().lock().unwrap_or_else(|e| e.into_inner());
+        if buffer.len() == BUFFER_SIZE {
+            buffer.pop_front();
+        }
+        buffer.push_back(entry);
+    }
+}
+
+/// Set up logging with the saved settings. Called once at startup; logging
+/// to the file is skipped when the log directory cannot be created.
+pub fn init() {
+    let settings = settings().unwrap_or_else(|_| default_settings());
+    let filter = filter(&settings).unwrap_or_else(|_| EnvFilter::new(DEFAULT_LEVEL));
+    let (filter, handle) = reload::Layer::new(filter);
+
+    let file = log_dir().ok().and_then(|dir| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .filename_suffix("log")
+            .max_log_files(KEPT_LOG_FILES)
+            .build(dir)
+            .ok()
+    });
+    let file = file.map(|appender| format::layer().with_writer(appender).with_ansi(false));
+    let stderr = cfg!(debug_assertions).then(|| format::layer().with_writer(std::io::stderr));
+
+    if tracing_subscriber::registry()
+        .with(filter)
+        .with(file)
+        .with(stderr)
+        .with(RingBuffer)
+        .try_init()
+        .is_ok()
+    {
+        let _ = FILTER.set(handle);
+    }
+
+    // Log panics too, then let the default hook report them as before
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let location = info.location().map(|l| format!("{}:{}", l.file(), l.line())).unwrap_or_default();
+        tracing::error!(location = %location, "Panic: {}", panic_message(info));
+        default_hook(info);
+    }));
+}
+
+fn panic_message(info: &std::panic::PanicHookInfo<'_>) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_string())
+}
+
+/// Change the level of the whole app, or of one module; None for a module
+/// goes back to the app's level, and None for the app to the default
+pub fn set_level(level: Option<&str>, module: Option<&str>) -> AppResult<LogSettings> {
+    if let Some(level) = level {
+        validate_level(level)?;
+    }
+    let mut settings = settings()?;
+    match module {
+        Some(module) => {
+            validate_module(module)?;
+            match level {
+                Some(level) => settings.modules.insert(module.to_string(), level.to_string()),
+                None => settings.modules.remove(module),
+            };
+        }
+        None => settings.level = level.unwrap_or(DEFAULT_LEVEL).to_string(),
+    }
+
+    let filter = filter(&settings)?;
+    if let Some(handle) = FILTER.get() {
+        handle
+            .reload(filter)
+            .map_err(|e| AppError::Internal(format!("Failed to change the log level: {}", e)))?;
+    }
+    fs::write(settings_path()?, serde_json::to_string_pretty(&settings)?)?;
+    tracing::info!(level = %settings.level, modules = ?settings.modules, "Log levels changed");
+    Ok(settings)
+}
+
+fn severity(level: &str) -> usize {
+    LEVELS.iter().position(|known| *known == level).unwrap_or(0)
+}
+
+/// The most recent events, oldest first, optionally only those at `min_level`
+/// or above
+pub fn recent(limit: usize, min_level: Option<&str>) -> AppResult<AppLogs> {
+    if let Some(level) = min_level {
+        validate_level(level)?;
+    }
+    let min = min_level.map(severity).unwrap_or(0);
+    let buffer = buffer().lock().unwrap_or_else(|e| e.into_inner());
+    let matching: Vec<&AppLogEntry> = buffer.iter().filter(|entry| severity(&entry.level) >= min).collect();
+    let skip = matching.len().saturating_sub(limit);
+    Ok(AppLogs {
+        entries: matching.into_iter().skip(skip).cloned().collect(),
+        directory: log_dir()?.to_string_lossy().to_string(),
+    })
+}
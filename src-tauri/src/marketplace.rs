@@ -0,0 +1,241 @@
+//! Client for the extension marketplace. The registry is a curated JSON index
+//! (`marketplace/index.json` in the repository by default, or any URL the user
+//! configures). The index is cached in memory for a few minutes, and the last
+//! download is kept in the app data directory so the marketplace can still be
+//! browsed offline.
+
+use crate::error::{AppError, AppResult};
+use crate::extension::package::MAX_PACKAGE_SIZE;
+use crate::models::{MarketplaceExtension, MarketplaceIndex, MarketplaceSearchResult};
+use crate::storage;
+use chrono::{SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_REGISTRY_URL: &str = "https://raw.githubusercontent.com/dschwarz/dbfordevs/main/marketplace/index.json";
+
+const SETTINGS_FILE: &str = "marketplace.json";
+const CACHE_FILE: &str = "marketplace_index.json";
+
+/// How long a downloaded index is used before fetching it again
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+const PACKAGE_TIMEOUT: Duration = Duration::from_secs(120);
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarketplaceSettings {
+    registry_url: Option<String>,
+}
+
+/// The saved copy of the last download
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedIndex {
+    registry_url: String,
+    fetched_at: String,
+    extensions: Vec<MarketplaceExtension>,
+}
+
+/// Last index downloaded this session, with when it was downloaded
+static MEMORY_CACHE: OnceCell<Mutex<Option<(Instant, CachedIndex)>>> = OnceCell::new();
+
+fn memory_cache() -> &'static Mutex<Option<(Instant, CachedIndex)>> {
+    MEMORY_CACHE.get_or_init(|| Mutex::new(None))
+}
+
+fn settings_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(SETTINGS_FILE))
+}
+
+fn cache_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(CACHE_FILE))
+}
+
+/// The configured registry, or the default one
+pub fn registry_url() -> AppResult<String> {
+    let path = settings_path()?;
+    if !path.exists() {
+        return Ok(DEFAULT_REGISTRY_URL.to_string());
+    }
+    let settings: MarketplaceSettings = serde_json::from_str(&fs::read_to_string(path)?)?;
+    Ok(settings.registry_url.unwrap_or_else(|| DEFAULT_REGISTRY_URL.to_string()))
+}
+
+/// Use another registry; None goes back to the default
+pub fn set_registry_url(url: Option<&str>) -> AppResult<String> {
+    let url = url.map(str::trim).filter(|url| !url.is_empty());
+    if let Some(url) = url {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return Err(AppError::ValidationError(
+                "The registry URL must start with https:// or http://".to_string(),
+            ));
+        }
+    }
+
+    let settings = MarketplaceSettings {
+        registry_url: url.map(str::to_string),
+    };
+    fs::write(settings_path()?, serde_json::to_string_pretty(&settings)?)?;
+    *memory_cache().lock().unwrap_or_else(|e| e.into_inner()) = None;
+    registry_url()
+}
+
+async fn get(url: &str, timeout: Duration) -> AppResult<reqwest::Response> {
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| AppError::Internal(format!("Failed to create HTTP client: {}", e)))?;
+    client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| AppError::GenericError(format!("Failed to reach the marketplace: {}", e)))
+}
+
+async fn download(url: &str) -> AppResult<Vec<MarketplaceExtension>> {
+    let response = get(url, REQUEST_TIMEOUT).await?;
+    let index: MarketplaceIndex = response
+        .json()
+        .await
+        .map_err(|e| AppError::GenericError(format!("The marketplace index is not valid: {}", e)))?;
+    Ok(index.extensions)
+}
+
+/// The registry's extensions and whether they came from the saved copy
+async fn index(refresh: bool) -> AppResult<(CachedIndex, bool)> {
+    let url = registry_url()?;
+    if !refresh {
+        if let Some((fetched, cached)) = memory_cache().lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            if cached.registry_url == url && fetched.elapsed() < CACHE_TTL {
+                return Ok((cached.clone(), false));
+            }
+        }
+    }
+
+    match download(&url).await {
+        Ok(extensions) => {
+            let cached = CachedIndex {
+                registry_url: url,
+                fetched_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+                extensions,
+            };
+            fs::write(cache_path()?, serde_json::to_string(&cached)?)?;
+            *memory_cache().lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), cached.clone()));
+            Ok((cached, false))
+        }
+        Err(error) => {
+            tracing::warn!(registry = %url, "Could not download the marketplace index: {}", error);
+            // Fall back to the last download from the same registry
+            let path = cache_path()?;
+            if path.exists() {
+                let saved: CachedIndex = serde_json::from_str(&fs::read_to_string(path)?)?;
+                if saved.registry_url == url {
+                    return Ok((saved, true));
+                }
+            }
+            Err(error)
+        }
+    }
+}
+
+/// How well an extension matches the query; None when it does not match
+fn relevance(extension: &MarketplaceExtension, terms: &[String]) -> Option<u32> {
+    let name = extension.name.to_lowercase();
+    let id = extension.id.to_lowercase();
+    let description = extension.description.to_lowercase();
+    let author = extension.author.to_lowercase();
+
+    let mut score = 0;
+    for term in terms {
+        score += if name.contains(term.as_str()) || id.contains(term.as_str()) {
+            3
+        } else if extension.tags.iter().any(|tag| tag.to_lowercase() == *term) {
+            2
+        } else if description.contains(term.as_str()) || author.contains(term.as_str()) {
+            1
+        } else {
+            return None;
+        };
+    }
+    Some(score)
+}
+
+/// Extensions matching the query and category. With a query, the best matches
+/// come first; otherwise featured extensions, then the most downloaded.
+pub async fn search(query: Option<&str>, category: Option<&str>, refresh: bool) -> AppResult<MarketplaceSearchResult> {
+    let (index, offline) = index(refresh).await?;
+
+    let mut categories: Vec<String> = Vec::new();
+    for extension in &index.extensions {
+        if !categories.contains(&extension.category) {
+            categories.push(extension.category.clone());
+        }
+    }
+    categories.sort();
+
+    let terms: Vec<String> = query.unwrap_or_default().split_whitespace().map(str::to_lowercase).collect();
+    let category = category.map(str::trim).filter(|category| !category.is_empty());
+
+    let mut matches: Vec<(u32, MarketplaceExtension)> = index
+        .extensions
+        .into_iter()
+        .filter(|extension| category.is_none_or(|category| extension.category.eq_ignore_ascii_case(category)))
+        .filter_map(|extension| relevance(&extension, &terms).map(|score| (score, extension)))
+        .collect();
+    matches.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then(b.is_featured.cmp(&a.is_featured))
+            .then(b.downloads.cmp(&a.downloads))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    Ok(MarketplaceSearchResult {
+        extensions: matches.into_iter().map(|(_, extension)| extension).collect(),
+        categories,
+        registry_url: index.registry_url,
+        fetched_at: index.fetched_at,
+        offline,
+    })
+}
+
+/// Download an extension's package, with the checksum the registry lists for it
+pub async fn download_package(id: &str) -> AppResult<(Vec<u8>, String)> {
+    let (index, _) = index(false).await?;
+    let extension = index
+        .extensions
+        .into_iter()
+        .find(|extension| extension.id == id)
+        .ok_or_else(|| AppError::ValidationError(format!("The marketplace has no extension '{}'", id)))?;
+    let (Some(url), Some(sha256)) = (extension.download_url, extension.sha256) else {
+        return Err(AppError::ValidationError(format!(
+            "{} has no package with a checksum in the marketplace",
+            extension.name
+        )));
+    };
+
+    let mut response = get(&url, PACKAGE_TIMEOUT).await?;
+    let mut package = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| AppError::GenericError(format!("Failed to download {}: {}", extension.name, e)))?
+    {
+        package.extend_from_slice(&chunk);
+        if package.len() > MAX_PACKAGE_SIZE {
+            return Err(AppError::ValidationError(format!(
+                "The package is larger than {} MB",
+                MAX_PACKAGE_SIZE / 1024 / 1024
+            )));
+        }
+    }
+    Ok((package, sha256))
+}
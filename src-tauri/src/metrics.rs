@@ -0,0 +1,178 @@
+//! Local performance metrics: query latency, connections, schema cache hits
+//! and AI request durations, kept in memory for the performance panel. They
+//! cover the current session only and never leave the machine.
+
+use crate::models::{CacheMetrics, ConnectionMetrics, LatencyBucket, LatencyHistogram, PerformanceMetrics};
+use chrono::{DateTime, SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Upper bounds of the histogram buckets, in milliseconds; one more bucket
+/// takes anything slower
+const BUCKET_BOUNDS_MS: [u64; 12] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10_000, 30_000];
+
+#[derive(Default)]
+struct Histogram {
+    counts: [u64; BUCKET_BOUNDS_MS.len() + 1],
+    total_ms: u64,
+    max_ms: u64,
+}
+
+impl Histogram {
+    fn record(&mut self, ms: u64) {
+        let bucket = BUCKET_BOUNDS_MS.iter().position(|bound| ms <= *bound).unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.counts[bucket] += 1;
+        self.total_ms = self.total_ms.saturating_add(ms);
+        self.max_ms = self.max_ms.max(ms);
+    }
+
+    /// The bound of the bucket holding the `quantile` duration, the slowest
+    /// one seen when that is lower
+    fn percentile(&self, quantile: f64) -> Option<u64> {
+        let count: u64 = self.counts.iter().sum();
+        if count == 0 {
+            return None;
+        }
+        let rank = ((count as f64 * quantile).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, bucket_count) in self.counts.iter().enumerate() {
+            seen += bucket_count;
+            if seen >= rank {
+                let bound = BUCKET_BOUNDS_MS.get(bucket).copied().unwrap_or(self.max_ms);
+                return Some(bound.min(self.max_ms));
+            }
+        }
+        Some(self.max_ms)
+    }
+
+    fn summary(&self) -> LatencyHistogram {
+        let buckets = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| LatencyBucket {
+                le_ms: BUCKET_BOUNDS_MS.get(bucket).copied(),
+                count: *count,
+            })
+            .collect();
+        LatencyHistogram {
+            count: self.counts.iter().sum(),
+            total_ms: self.total_ms,
+            max_ms: self.max_ms,
+            p50_ms: self.percentile(0.5),
+            p95_ms: self.percentile(0.95),
+            p99_ms: self.percentile(0.99),
+            buckets,
+        }
+    }
+}
+
+struct Metrics {
+    since: DateTime<Utc>,
+    queries: Histogram,
+    failed_queries: u64,
+    connections_opened: u64,
+    connections_failed: u64,
+    cache_hits: u64,
+    cache_misses: u64,
+    ai_requests: Histogram,
+    ai_operations: BTreeMap<String, Histogram>,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        Self {
+            since: Utc::now(),
+            queries: Histogram::default(),
+            failed_queries: 0,
+            connections_opened: 0,
+            connections_failed: 0,
+            cache_hits: 0,
+            cache_misses: 0,
+            ai_requests: Histogram::default(),
+            ai_operations: BTreeMap::new(),
+        }
+    }
+}
+
+static METRICS: OnceCell<Mutex<Metrics>> = OnceCell::new();
+
+fn metrics() -> MutexGuard<'static, Metrics> {
+    METRICS
+        .get_or_init(|| Mutex::new(Metrics::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+fn millis(elapsed: Duration) -> u64 {
+    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// A query run from the editor; failed ones count towards the latency too
+pub fn record_query(elapsed: Duration, succeeded: bool) {
+    let mut metrics = metrics();
+    metrics.queries.record(millis(elapsed));
+    if !succeeded {
+        metrics.failed_queries += 1;
+    }
+}
+
+pub fn record_connection(succeeded: bool) {
+    let mut metrics = metrics();
+    if succeeded {
+        metrics.connections_opened += 1;
+    } else {
+        metrics.connections_failed += 1;
+    }
+}
+
+/// A lookup in the schema cache
+pub fn record_cache_lookup(hit: bool) {
+    let mut metrics = metrics();
+    if hit {
+        metrics.cache_hits += 1;
+    } else {
+        metrics.cache_misses += 1;
+    }
+}
+
+/// An AI request, timed by the frontend that made it
+pub fn record_ai_request(operation: &str, duration_ms: u64) {
+    let mut metrics = metrics();
+    metrics.ai_requests.record(duration_ms);
+    metrics.ai_operations.entry(operation.to_string()).or_default().record(duration_ms);
+}
+
+/// The metrics so far, with the number of connections open now
+pub fn snapshot(active_connections: usize) -> PerformanceMetrics {
+    let metrics = metrics();
+    let lookups = metrics.cache_hits + metrics.cache_misses;
+    PerformanceMetrics {
+        since: metrics.since.to_rfc3339_opts(SecondsFormat::Secs, true),
+        queries: metrics.queries.summary(),
+        failed_queries: metrics.failed_queries,
+        connections: ConnectionMetrics {
+            active: active_connections,
+            opened: metrics.connections_opened,
+            failed: metrics.connections_failed,
+        },
+        schema_cache: CacheMetrics {
+            hits: metrics.cache_hits,
+            misses: metrics.cache_misses,
+            hit_rate: (lookups > 0).then(|| metrics.cache_hits as f64 / lookups as f64),
+        },
+        ai_requests: metrics.ai_requests.summary(),
+        ai_operations: metrics
+            .ai_operations
+            .iter()
+            .map(|(operation, histogram)| (operation.clone(), histogram.summary()))
+            .collect(),
+    }
+}
+
+/// Start measuring again
+pub fn reset() {
+    *metrics() = Metrics::new();
+}
@@ -0,0 +1,412 @@
+//! Migration builder: turns the difference between a table's current and
+//! desired schema into dialect-specific ALTER TABLE statements.
+
+mod sqlite;
+
+use crate::db::{quote_identifier, quote_qualified, sql_literal};
+use crate::error::{AppError, AppResult};
+use crate::models::{
+    ColumnInfo, ColumnRename, DatabaseType, ForeignKeyInfo, IndexInfo, MigrationPlan, TableRelationship, TableSchema,
+};
+use std::collections::HashMap;
+
+/// What the database currently has for the table
+pub struct CurrentTable<'a> {
+    pub schema: &'a TableSchema,
+    pub indexes: &'a [IndexInfo],
+    /// Foreign keys in both directions, used for constraint names
+    pub relationships: &'a [TableRelationship],
+}
+
+/// Differences between the current and desired table
+struct SchemaChanges {
+    /// Current name -> desired name, for every column that is kept
+    kept: Vec<(String, String)>,
+    renamed: Vec<(String, String)>,
+    dropped: Vec<String>,
+    added: Vec<ColumnInfo>,
+    /// (current, desired) for columns whose type or nullability changed
+    altered: Vec<(ColumnInfo, ColumnInfo)>,
+    current_primary_key: Vec<String>,
+    desired_primary_key: Vec<String>,
+    dropped_indexes: Vec<IndexInfo>,
+    added_indexes: Vec<IndexInfo>,
+    /// Indexes the table should end up with (used by the SQLite rebuild)
+    final_indexes: Vec<IndexInfo>,
+    dropped_foreign_keys: Vec<(ForeignKeyInfo, Option<String>)>,
+    added_foreign_keys: Vec<ForeignKeyInfo>,
+}
+
+impl SchemaChanges {
+    fn primary_key_changed(&self) -> bool {
+        self.current_primary_key != self.desired_primary_key
+    }
+}
+
+/// Build the statements that migrate `current` to `desired`. Without `indexes` the
+/// existing secondary indexes are kept.
+pub fn build_migration(
+    database_type: &DatabaseType,
+    current: CurrentTable,
+    table_name: &str,
+    desired: &TableSchema,
+    renames: &[ColumnRename],
+    indexes: Option<&[IndexInfo]>,
+) -> AppResult<MigrationPlan> {
+    if matches!(database_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+        return Err(AppError::ValidationError("Migrations can only be generated for SQL databases".to_string()));
+    }
+    if desired.columns.is_empty() {
+        return Err(AppError::ValidationError("A table needs at least one column".to_string()));
+    }
+
+    let changes = diff_schema(&current, desired, renames, indexes)?;
+    let mut warnings = Vec::new();
+
+    for column in &changes.dropped {
+        warnings.push(format!("Dropping column {} deletes its data", column));
+    }
+    for (from, to) in &changes.altered {
+        if !same_type(&from.data_type, &to.data_type) {
+            warnings.push(format!(
+                "Changing {} from {} to {} may fail or lose data",
+                to.name, from.data_type, to.data_type
+            ));
+        }
+    }
+    for column in changes.added.iter().filter(|c| !c.nullable) {
+        warnings.push(format!(
+            "Adding NOT NULL column {} fails on a table with rows unless it has a default",
+            column.name
+        ));
+    }
+
+    if matches!(database_type, DatabaseType::SQLite) {
+        return Ok(sqlite::build(table_name, desired, &changes, warnings));
+    }
+
+    let statements = alter_statements(database_type, table_name, &current, &changes, &mut warnings);
+
+    Ok(MigrationPlan {
+        table_name: table_name.to_string(),
+        statements,
+        warnings,
+        rebuild: false,
+    })
+}
+
+fn diff_schema(
+    current: &CurrentTable,
+    desired: &TableSchema,
+    renames: &[ColumnRename],
+    indexes: Option<&[IndexInfo]>,
+) -> AppResult<SchemaChanges> {
+    let renames: HashMap<&str, &str> = renames.iter().map(|r| (r.from.as_str(), r.to.as_str())).collect();
+
+    for (from, to) in &renames {
+        if !current.schema.columns.iter().any(|c| c.name == *from) {
+            return Err(AppError::ValidationError(format!("Cannot rename missing column '{}'", from)));
+        }
+        if !desired.columns.iter().any(|c| c.name == *to) {
+            return Err(AppError::ValidationError(format!("Renamed column '{}' is not in the new schema", to)));
+        }
+    }
+
+    let new_name = |name: &str| renames.get(name).copied().unwrap_or(name).to_string();
+
+    let mut kept = Vec::new();
+    let mut renamed = Vec::new();
+    let mut dropped = Vec::new();
+    let mut altered = Vec::new();
+
+    for column in &current.schema.columns {
+        let target = new_name(&column.name);
+        match desired.columns.iter().find(|c| c.name == target) {
+            Some(wanted) => {
+                if target != column.name {
+                    renamed.push((column.name.clone(), target.clone()));
+                }
+                if !same_type(&column.data_type, &wanted.data_type) || column.nullable != wanted.nullable {
+                    altered.push((column.clone(), wanted.clone()));
+                }
+                kept.push((column.name.clone(), target));
+            }
+            None => dropped.push(column.name.clone()),
+        }
+    }
+
+    let added: Vec<ColumnInfo> = desired
+        .columns
+        .iter()
+        .filter(|c| !kept.iter().any(|(_, to)| *to == c.name))
+        .cloned()
+        .collect();
+
+    let current_primary_key: Vec<String> = current.schema.primary_keys.iter().map(|k| new_name(k)).collect();
+    let desired_primary_key = if desired.primary_keys.is_empty() {
+        desired.columns.iter().filter(|c| c.is_primary_key).map(|c| c.name.clone()).collect()
+    } else {
+        desired.primary_keys.clone()
+    };
+
+    // Secondary indexes only; the primary key is handled above
+    let existing: Vec<IndexInfo> = current
+        .indexes
+        .iter()
+        .filter(|i| !i.is_primary)
+        .map(|i| IndexInfo {
+            columns: i.columns.iter().map(|c| new_name(c)).collect(),
+            ..i.clone()
+        })
+        .collect();
+    let surviving = |index: &IndexInfo| index.columns.iter().all(|c| desired.columns.iter().any(|d| d.name == *c));
+
+    let (dropped_indexes, added_indexes, final_indexes) = match indexes {
+        Some(wanted) => {
+            let wanted: Vec<IndexInfo> = wanted.iter().filter(|i| !i.is_primary).cloned().collect();
+            let dropped_indexes = existing
+                .iter()
+                .filter(|i| !wanted.iter().any(|w| same_index(i, w)))
+                .cloned()
+                .collect();
+            let added_indexes = wanted
+                .iter()
+                .filter(|w| !existing.iter().any(|i| same_index(i, w)))
+                .cloned()
+                .collect();
+            (dropped_indexes, added_indexes, wanted)
+        }
+        // Keep existing indexes, except those on dropped columns
+        None => {
+            let (kept, dropped): (Vec<IndexInfo>, Vec<IndexInfo>) = existing.into_iter().partition(|i| surviving(i));
+            (dropped, vec![], kept)
+        }
+    };
+
+    let current_foreign_keys: Vec<ForeignKeyInfo> = current
+        .schema
+        .foreign_keys
+        .iter()
+        .map(|fk| ForeignKeyInfo {
+            column: new_name(&fk.column),
+            ..fk.clone()
+        })
+        .collect();
+
+    let dropped_foreign_keys = current
+        .schema
+        .foreign_keys
+        .iter()
+        .zip(&current_foreign_keys)
+        .filter(|(_, renamed_fk)| !desired.foreign_keys.iter().any(|d| same_foreign_key(renamed_fk, d)))
+        .map(|(fk, _)| {
+            let name = current
+                .relationships
+                .iter()
+                .find(|r| {
+                    r.source_column == fk.column
+                        && r.target_table == fk.references_table
+                        && r.target_column == fk.references_column
+                })
+                .and_then(|r| r.constraint_name.clone());
+            (fk.clone(), name)
+        })
+        .collect();
+
+    let added_foreign_keys = desired
+        .foreign_keys
+        .iter()
+        .filter(|d| !current_foreign_keys.iter().any(|fk| same_foreign_key(fk, d)))
+        .cloned()
+        .collect();
+
+    Ok(SchemaChanges {
+        kept,
+        renamed,
+        dropped,
+        added,
+        altered,
+        current_primary_key,
+        desired_primary_key,
+        dropped_indexes,
+        added_indexes,
+        final_indexes,
+        dropped_foreign_keys,
+        added_foreign_keys,
+    })
+}
+
+/// ALTER TABLE statements for PostgreSQL, MySQL and SQL Server
+fn alter_statements(
+    database_type: &DatabaseType,
+    table_name: &str,
+    current: &CurrentTable,
+    changes: &SchemaChanges,
+    warnings: &mut Vec<String>,
+) -> Vec<String> {
+    let table = quote_qualified(database_type, table_name);
+    let q = |name: &str| quote_identifier(database_type, name);
+    let base_name = table_name.rsplit('.').next().unwrap_or(table_name);
+    let schema_prefix = table_name
+        .rsplit_once('.')
+        .map(|(schema, _)| format!("{}.", quote_qualified(database_type, schema)))
+        .unwrap_or_default();
+    let mut statements = Vec::new();
+
+    for (fk, name) in &changes.dropped_foreign_keys {
+        match (name, database_type) {
+            (Some(name), DatabaseType::MySQL) => {
+                statements.push(format!("ALTER TABLE {} DROP FOREIGN KEY {};", table, q(name)))
+            }
+            (Some(name), _) => statements.push(format!("ALTER TABLE {} DROP CONSTRAINT {};", table, q(name))),
+            (None, _) => warnings.push(format!(
+                "The foreign key on {} has no known constraint name; drop it manually",
+                fk.column
+            )),
+        }
+    }
+
+    for index in &changes.dropped_indexes {
+        match database_type {
+            DatabaseType::PostgreSQL | DatabaseType::Oracle => {
+                statements.push(format!("DROP INDEX {}{};", schema_prefix, q(&index.name)))
+            }
+            _ => statements.push(format!("DROP INDEX {} ON {};", q(&index.name), table)),
+        }
+    }
+
+    let primary_key_name = current.indexes.iter().find(|i| i.is_primary).map(|i| i.name.clone());
+    if changes.primary_key_changed() && !changes.current_primary_key.is_empty() {
+        match (database_type, primary_key_name) {
+            (DatabaseType::MySQL, _) => statements.push(format!("ALTER TABLE {} DROP PRIMARY KEY;", table)),
+            (_, Some(name)) => statements.push(format!("ALTER TABLE {} DROP CONSTRAINT {};", table, q(&name))),
+            (DatabaseType::PostgreSQL, None) => statements.push(format!(
+                "ALTER TABLE {} DROP CONSTRAINT {};",
+                table,
+                q(&format!("{}_pkey", base_name))
+            )),
+            (_, None) => warnings.push("The primary key constraint name is unknown; drop it manually".to_string()),
+        }
+    }
+
+    for (from, to) in &changes.renamed {
+        match database_type {
+            DatabaseType::MSSQL => statements.push(format!(
+                "EXEC sp_rename {}, {}, 'COLUMN';",
+                sql_literal(database_type, &serde_json::Value::String(format!("{}.{}", table_name, from))),
+                sql_literal(database_type, &serde_json::Value::String(to.clone()))
+            )),
+            _ => statements.push(format!("ALTER TABLE {} RENAME COLUMN {} TO {};", table, q(from), q(to))),
+        }
+    }
+
+    for column in &changes.dropped {
+        statements.push(format!("ALTER TABLE {} DROP COLUMN {};", table, q(column)));
+    }
+
+    for column in &changes.added {
+        let definition = column_definition(database_type, column);
+        let add = match database_type {
+            DatabaseType::MSSQL => format!("ADD {}", definition),
+            DatabaseType::Oracle => format!("ADD ({})", definition),
+            _ => format!("ADD COLUMN {}", definition),
+        };
+        statements.push(format!("ALTER TABLE {} {};", table, add));
+    }
+
+    for (from, to) in &changes.altered {
+        let column = q(&to.name);
+        let null = if to.nullable { "NULL" } else { "NOT NULL" };
+        match database_type {
+            DatabaseType::PostgreSQL => {
+                if !same_type(&from.data_type, &to.data_type) {
+                    statements.push(format!(
+                        "ALTER TABLE {} ALTER COLUMN {} TYPE {} USING {}::{};",
+                        table, column, to.data_type, column, to.data_type
+                    ));
+                }
+                if from.nullable != to.nullable {
+                    let action = if to.nullable { "DROP NOT NULL" } else { "SET NOT NULL" };
+                    statements.push(format!("ALTER TABLE {} ALTER COLUMN {} {};", table, column, action));
+                }
+            }
+            DatabaseType::MySQL => statements.push(format!(
+                "ALTER TABLE {} MODIFY COLUMN {} {} {};",
+                table, column, to.data_type, null
+            )),
+            // Oracle refuses to set the nullability a column already has
+            DatabaseType::Oracle => statements.push(format!(
+                "ALTER TABLE {} MODIFY ({} {}{});",
+                table,
+                column,
+                to.data_type,
+                if from.nullable == to.nullable { String::new() } else { format!(" {}", null) }
+            )),
+            _ => statements.push(format!(
+                "ALTER TABLE {} ALTER COLUMN {} {} {};",
+                table, column, to.data_type, null
+            )),
+        }
+    }
+
+    if changes.primary_key_changed() && !changes.desired_primary_key.is_empty() {
+        let columns: Vec<String> = changes.desired_primary_key.iter().map(|c| q(c)).collect();
+        statements.push(format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} PRIMARY KEY ({});",
+            table,
+            q(&format!("pk_{}", base_name)),
+            columns.join(", ")
+        ));
+    }
+
+    for index in &changes.added_indexes {
+        statements.push(create_index(database_type, &table, index));
+    }
+
+    for fk in &changes.added_foreign_keys {
+        statements.push(format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({});",
+            table,
+            q(&format!("fk_{}_{}", base_name, fk.column)),
+            q(&fk.column),
+            quote_qualified(database_type, &fk.references_table),
+            q(&fk.references_column)
+        ));
+    }
+
+    statements
+}
+
+fn column_definition(database_type: &DatabaseType, column: &ColumnInfo) -> String {
+    format!(
+        "{} {}{}",
+        quote_identifier(database_type, &column.name),
+        column.data_type,
+        if column.nullable { "" } else { " NOT NULL" }
+    )
+}
+
+fn create_index(database_type: &DatabaseType, table: &str, index: &IndexInfo) -> String {
+    let columns: Vec<String> = index.columns.iter().map(|c| quote_identifier(database_type, c)).collect();
+    format!(
+        "CREATE {}INDEX {} ON {} ({});",
+        if index.is_unique { "UNIQUE " } else { "" },
+        quote_identifier(database_type, &index.name),
+        table,
+        columns.join(", ")
+    )
+}
+
+/// Type names compared case- and whitespace-insensitively
+fn same_type(a: &str, b: &str) -> bool {
+    let normalize = |t: &str| t.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    normalize(a) == normalize(b)
+}
+
+fn same_index(a: &IndexInfo, b: &IndexInfo) -> bool {
+    a.name == b.name && a.columns == b.columns && a.is_unique == b.is_unique
+}
+
+fn same_foreign_key(a: &ForeignKeyInfo, b: &ForeignKeyInfo) -> bool {
+    a.column == b.column && a.references_table == b.references_table && a.references_column == b.references_column
+}
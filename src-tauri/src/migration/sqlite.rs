@@ -0,0 +1,118 @@
+//! SQLite migrations. ALTER TABLE here only renames, adds nullable columns and
+//! drops unconstrained ones; anything else rebuilds the table.
+
+use super::{column_definition, create_index, SchemaChanges};
+use crate::db::quote_identifier;
+use crate::models::{DatabaseType, MigrationPlan, TableSchema};
+
+const SQLITE: DatabaseType = DatabaseType::SQLite;
+
+pub(super) fn build(
+    table_name: &str,
+    desired: &TableSchema,
+    changes: &SchemaChanges,
+    mut warnings: Vec<String>,
+) -> MigrationPlan {
+    let table = quote_identifier(&SQLITE, table_name);
+
+    if !needs_rebuild(changes) {
+        let mut statements = Vec::new();
+        for index in &changes.dropped_indexes {
+            statements.push(format!("DROP INDEX {};", quote_identifier(&SQLITE, &index.name)));
+        }
+        for (from, to) in &changes.renamed {
+            statements.push(format!(
+                "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                table,
+                quote_identifier(&SQLITE, from),
+                quote_identifier(&SQLITE, to)
+            ));
+        }
+        for column in &changes.dropped {
+            statements.push(format!("ALTER TABLE {} DROP COLUMN {};", table, quote_identifier(&SQLITE, column)));
+        }
+        for column in &changes.added {
+            statements.push(format!("ALTER TABLE {} ADD COLUMN {};", table, column_definition(&SQLITE, column)));
+        }
+        for index in &changes.added_indexes {
+            statements.push(create_index(&SQLITE, &table, index));
+        }
+
+        return MigrationPlan {
+            table_name: table_name.to_string(),
+            statements,
+            warnings,
+            rebuild: false,
+        };
+    }
+
+    warnings.push(format!(
+        "{} is rebuilt; column defaults, CHECK constraints and triggers are not carried over",
+        table_name
+    ));
+
+    let new_table = quote_identifier(&SQLITE, &format!("_new_{}", table_name));
+    let mut definitions: Vec<String> = desired.columns.iter().map(|c| column_definition(&SQLITE, c)).collect();
+    if !changes.desired_primary_key.is_empty() {
+        let columns: Vec<String> = changes.desired_primary_key.iter().map(|c| quote_identifier(&SQLITE, c)).collect();
+        definitions.push(format!("PRIMARY KEY ({})", columns.join(", ")));
+    }
+    for fk in &desired.foreign_keys {
+        definitions.push(format!(
+            "FOREIGN KEY ({}) REFERENCES {} ({})",
+            quote_identifier(&SQLITE, &fk.column),
+            quote_identifier(&SQLITE, &fk.references_table),
+            quote_identifier(&SQLITE, &fk.references_column)
+        ));
+    }
+
+    let targets: Vec<String> = changes.kept.iter().map(|(_, to)| quote_identifier(&SQLITE, to)).collect();
+    let sources: Vec<String> = changes.kept.iter().map(|(from, _)| quote_identifier(&SQLITE, from)).collect();
+
+    let mut statements = vec![
+        "PRAGMA foreign_keys = OFF;".to_string(),
+        "BEGIN TRANSACTION;".to_string(),
+        format!("CREATE TABLE {} (\n  {}\n);", new_table, definitions.join(",\n  ")),
+    ];
+    if !targets.is_empty() {
+        statements.push(format!(
+            "INSERT INTO {} ({}) SELECT {} FROM {};",
+            new_table,
+            targets.join(", "),
+            sources.join(", "),
+            table
+        ));
+    }
+    statements.push(format!("DROP TABLE {};", table));
+    statements.push(format!("ALTER TABLE {} RENAME TO {};", new_table, table));
+    // Indexes backing UNIQUE and PRIMARY KEY constraints are created by SQLite itself
+    for index in changes.final_indexes.iter().filter(|i| !i.name.starts_with("sqlite_autoindex")) {
+        statements.push(create_index(&SQLITE, &table, index));
+    }
+    statements.push("PRAGMA foreign_key_check;".to_string());
+    statements.push("COMMIT;".to_string());
+    statements.push("PRAGMA foreign_keys = ON;".to_string());
+
+    MigrationPlan {
+        table_name: table_name.to_string(),
+        statements,
+        warnings,
+        rebuild: true,
+    }
+}
+
+/// Whether the changes go beyond what SQLite's ALTER TABLE supports
+fn needs_rebuild(changes: &SchemaChanges) -> bool {
+    let constrained = |column: &String| {
+        changes.current_primary_key.contains(column)
+            || changes.final_indexes.iter().chain(&changes.dropped_indexes).any(|i| i.columns.contains(column))
+            || changes.dropped_foreign_keys.iter().any(|(fk, _)| fk.column == *column)
+    };
+
+    !changes.altered.is_empty()
+        || changes.primary_key_changed()
+        || !changes.dropped_foreign_keys.is_empty()
+        || !changes.added_foreign_keys.is_empty()
+        || changes.added.iter().any(|c| !c.nullable || c.is_primary_key)
+        || changes.dropped.iter().any(constrained)
+}
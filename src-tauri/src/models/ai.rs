@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Tokens spent on one AI request, as reported by the frontend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageRecord {
+    /// RFC 3339, UTC; set when the record is stored
+    #[serde(default)]
+    pub timestamp: String,
+    pub provider: String,
+    pub model: String,
+    /// What the request was for, e.g. `chat` or `generate_sql`
+    pub operation: String,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    /// US dollars from the frontend's price table; 0 for models without a price
+    pub estimated_cost: f64,
+    /// How long the request took, for the performance metrics
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// Tokens and cost of the AI requests made in one calendar month
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageStats {
+    /// `YYYY-MM`, UTC
+    pub month: String,
+    pub request_count: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    pub estimated_cost: f64,
+    /// Totals per model, most tokens first
+    pub by_model: Vec<AiModelUsage>,
+}
+
+/// One model's share of a month's AI usage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiModelUsage {
+    pub provider: String,
+    pub model: String,
+    pub request_count: u64,
+    pub total_tokens: u64,
+    pub estimated_cost: f64,
+}
+
+/// An AI system prompt the user can replace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptTemplate {
+    /// Identifier, e.g. `sql_generation`
+    pub name: String,
+    pub description: String,
+    /// The user's template; None when the built-in prompt is used
+    pub content: Option<String>,
+}
+
+/// A table or column as indexed for semantic schema search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaDocument {
+    pub table: String,
+    /// None for the table itself
+    pub column: Option<String>,
+    /// What is embedded: names split into words, the type and any comment
+    pub text: String,
+}
+
+/// The embedding model a connection's schema index was built with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaIndexInfo {
+    /// `provider/model`, or `local` for the built-in hashed embeddings
+    pub model: String,
+    pub dimensions: usize,
+    pub document_count: usize,
+    /// RFC 3339, UTC
+    pub created_at: String,
+}
+
+/// A table or column matching a semantic search, best first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaSearchHit {
+    pub table: String,
+    pub column: Option<String>,
+    pub text: String,
+    /// Cosine similarity to the query, from -1 to 1
+    pub score: f32,
+}
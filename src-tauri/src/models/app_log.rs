@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One event from the application log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppLogEntry {
+    /// RFC 3339, UTC
+    pub timestamp: String,
+    /// trace, debug, info, warn or error
+    pub level: String,
+    /// Module that logged it, e.g. `dbfordevs::db::manager`
+    pub target: String,
+    pub message: String,
+    /// Structured fields of the event, e.g. the connection id
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Recent log events, for attaching to a bug report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppLogs {
+    pub entries: Vec<AppLogEntry>,
+    /// Where the full log files are
+    pub directory: String,
+}
+
+/// Which events are logged: a level for the whole app and optional levels
+/// for modules, e.g. `db` or `extension::dispatch`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogSettings {
+    pub level: String,
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+/// One data or schema change made through the app, as kept in the audit log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub id: String,
+    /// RFC 3339, UTC
+    pub timestamp: String,
+    /// Operating system account running the app
+    pub user: String,
+    /// Login the connection uses, if it has one
+    pub database_user: Option<String>,
+    pub connection_id: String,
+    pub connection_name: String,
+    /// Command that made the change, e.g. `update_row`
+    pub operation: String,
+    pub table: Option<String>,
+    /// SQL sent to the database, or a description of the change when the driver builds the SQL
+    pub statement: String,
+    /// Values bound to the statement's placeholders
+    pub params: Vec<serde_json::Value>,
+    pub affected_rows: Option<u64>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Narrows `get_audit_log` and `export_audit_log`; unset fields match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogFilter {
+    pub connection_id: Option<String>,
+    /// Exact table name
+    pub table: Option<String>,
+    pub operation: Option<String>,
+    /// RFC 3339 timestamps; entries at `since` are included, entries at `until` are not
+    pub since: Option<String>,
+    pub until: Option<String>,
+    /// Most recent entries to return (default 500 for `get_audit_log`, no limit for exports)
+    pub limit: Option<usize>,
+}
@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+
+/// What a backup file holds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupFormat {
+    /// The database's own format: a pg_dump custom archive, or a copy of the SQLite
+    /// file. MySQL and the databases without a dump tool always get a SQL script.
+    #[default]
+    Native,
+    /// A plain SQL script of CREATE and INSERT statements
+    Sql,
+}
+
+/// Back up a connected database to a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRequest {
+    pub connection_id: String,
+    pub path: String,
+    #[serde(default)]
+    pub format: BackupFormat,
+    /// Leave out the rows, keeping only the schema
+    #[serde(default)]
+    pub schema_only: bool,
+    /// Write the script without the dump tools even when they are installed
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+/// Restore a backup file into a connected database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreRequest {
+    pub connection_id: String,
+    pub path: String,
+    /// Drop the objects in the backup before recreating them (pg_restore archives only)
+    #[serde(default)]
+    pub clean: bool,
+    pub confirmation_token: Option<String>,
+}
+
+/// A command line tool backups and restores of a connection use
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupTool {
+    /// e.g. `pg_dump`
+    pub name: String,
+    /// What the tool is used for, e.g. `backup` or `restore of archives`
+    pub purpose: String,
+    /// Where it was found; None when it is not installed
+    pub path: Option<String>,
+    /// First line of `--version`
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupOperation {
+    Backup,
+    Restore,
+}
+
+/// Payload of the `backup-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupProgress {
+    pub operation: BackupOperation,
+    pub path: String,
+    /// Bytes written to the backup, or read from it while restoring
+    pub bytes: u64,
+    /// Size of the file being restored
+    pub total_bytes: Option<u64>,
+    /// Latest line of the tool's output, or the table being written
+    pub message: Option<String>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupSummary {
+    pub operation: BackupOperation,
+    pub path: String,
+    /// The tool that did the work, e.g. `pg_dump (PostgreSQL) 16.2`, or `built-in`
+    pub method: String,
+    pub bytes: u64,
+    /// Tables written by the built-in script
+    pub tables: Option<usize>,
+    /// Rows written by the built-in script
+    pub rows: Option<usize>,
+    /// Output of the tool worth showing, such as warnings, capped at the last 50 lines
+    pub messages: Vec<String>,
+    pub execution_time_ms: u64,
+}
@@ -0,0 +1,88 @@
+use super::{QueryResult, TableRelationship};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SortColumn {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FilterOperator {
+    Equals,
+    NotEquals,
+    /// Substring match on the column's text, case-insensitive where the database allows
+    Contains,
+    IsNull,
+    IsNotNull,
+    GreaterThan,
+    GreaterOrEqual,
+    LessThan,
+    LessOrEqual,
+    /// Inclusive range from `value` to `value_to`
+    Between,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnFilter {
+    pub column: String,
+    pub operator: FilterOperator,
+    /// Unused by `isNull` and `isNotNull`
+    pub value: Option<serde_json::Value>,
+    /// Upper bound for `between`
+    pub value_to: Option<serde_json::Value>,
+}
+
+/// A page of table data, sorted and filtered by the database
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowseRequest {
+    pub connection_id: String,
+    pub table_name: String,
+    pub sort: Option<Vec<SortColumn>>,
+    /// Combined with AND
+    pub filters: Option<Vec<ColumnFilter>>,
+    /// Rows per page (default 100)
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Always run COUNT(*) instead of using the catalog estimate for large tables
+    pub exact_count: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowseResult {
+    #[serde(flatten)]
+    pub result: QueryResult,
+    /// Rows matching the filters; None if they could not be counted
+    pub total_rows: Option<u64>,
+    /// True if `total_rows` comes from table statistics rather than COUNT(*)
+    pub total_is_estimate: bool,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+/// A row and the foreign key to follow from it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RowReferenceRequest {
+    pub connection_id: String,
+    pub table_name: String,
+    /// Primary key of the row navigated from
+    pub primary_key: HashMap<String, serde_json::Value>,
+    /// From `get_table_relationships`; other columns of the same constraint are matched too
+    pub relationship: TableRelationship,
+    /// Referencing rows returned (default 100)
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,66 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A cell value with its kind, so NULL, an empty string, text reading "null",
+/// binary data and JSON keep their meaning between an editor and the database.
+/// Sent as `{"type": "int", "value": 42}`; `{"type": "null"}` is SQL NULL, and a
+/// column left out of a row is not written at all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum CellValue {
+    Null,
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    /// Base64 encoded
+    Bytes(String),
+    Json(Value),
+    /// A date, time or timestamp as text, read by the database as the column's type
+    Timestamp(String),
+}
+
+impl CellValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, CellValue::Null)
+    }
+
+    /// The value as plain JSON, the form results and the audit log use
+    pub fn to_json(&self) -> Value {
+        match self {
+            CellValue::Null => Value::Null,
+            CellValue::Text(text) | CellValue::Bytes(text) | CellValue::Timestamp(text) => Value::String(text.clone()),
+            CellValue::Int(value) => Value::from(*value),
+            CellValue::Float(value) => serde_json::Number::from_f64(*value).map(Value::Number).unwrap_or(Value::Null),
+            CellValue::Bool(value) => Value::Bool(*value),
+            CellValue::Json(value) => value.clone(),
+        }
+    }
+
+    /// The decoded bytes of a `Bytes` value; None for other kinds or invalid base64
+    pub fn bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            CellValue::Bytes(encoded) => general_purpose::STANDARD.decode(encoded.trim()).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Values that arrive without a kind, such as filter values, take the kind of
+/// their JSON type
+impl From<Value> for CellValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => CellValue::Null,
+            Value::Bool(value) => CellValue::Bool(value),
+            Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+                (Some(value), _) => CellValue::Int(value),
+                (None, Some(value)) => CellValue::Float(value),
+                (None, None) => CellValue::Text(number.to_string()),
+            },
+            Value::String(text) => CellValue::Text(text),
+            other => CellValue::Json(other),
+        }
+    }
+}
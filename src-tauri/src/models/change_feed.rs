@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// A NOTIFY received on a channel the app listens on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbNotification {
+    pub connection_id: String,
+    pub channel: String,
+    pub payload: String,
+    /// Backend process that sent it
+    pub process_id: u32,
+    /// RFC 3339, UTC
+    pub received_at: String,
+}
+
+/// Rows changed in a watched table since the last check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableChangeEvent {
+    pub connection_id: String,
+    /// As given to `watch_table`, e.g. `public.users`
+    pub table: String,
+    pub inserted: i64,
+    pub updated: i64,
+    pub deleted: i64,
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// How the values falling on the same x (and group) are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartAggregation {
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+/// One plotted value: an aggregation of a column, or the number of rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartMeasure {
+    /// None only with `count`, which then counts rows rather than non-null values
+    pub column: Option<String>,
+    pub aggregation: ChartAggregation,
+}
+
+/// What to plot from a query result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSpec {
+    pub x_column: String,
+    pub measures: Vec<ChartMeasure>,
+    /// Splits each measure into one series per distinct value of this column
+    pub group_by: Option<String>,
+    /// Most distinct x values kept, in order of first appearance; defaults to 1000
+    pub max_points: Option<usize>,
+    /// Most groups kept, in order of first appearance; defaults to 20
+    pub max_groups: Option<usize>,
+}
+
+/// A line or set of bars, with one value per x label
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSeries {
+    pub name: String,
+    /// Index into the spec's measures
+    pub measure: usize,
+    /// The group_by value of this series
+    pub group: Option<serde_json::Value>,
+    /// None where no row had a value to aggregate
+    pub values: Vec<Option<f64>>,
+}
+
+/// Series ready to plot, aggregated from a query result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartData {
+    pub labels: Vec<serde_json::Value>,
+    pub series: Vec<ChartSeries>,
+    /// Rows that went into the series
+    pub rows_used: usize,
+    /// Rows left out because their x value or group was past the limits
+    pub rows_dropped: usize,
+    pub truncated: bool,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Returned instead of running a destructive command; call it again with `token` to go ahead
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmationRequired {
+    pub token: String,
+    /// What the command will do, for the confirmation dialog
+    pub summary: String,
+    /// Table the command changes, when it targets one
+    pub table: Option<String>,
+    /// Rows the command would remove or change, from table statistics
+    pub estimated_rows: Option<u64>,
+    /// Other objects the command drops or changes along with its target
+    pub dependents: Vec<String>,
+    pub expires_in_secs: u64,
+}
+
+/// Outcome of a command guarded by a confirmation token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum Confirmable<T> {
+    ConfirmationRequired(ConfirmationRequired),
+    Executed(T),
+}
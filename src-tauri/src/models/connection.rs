@@ -7,6 +7,11 @@ pub enum DatabaseType {
     MySQL,
     SQLite,
     MSSQL,
+    MongoDB,
+    Redis,
+    ClickHouse,
+    DuckDB,
+    Oracle,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +26,80 @@ pub struct ConnectionConfig {
     pub username: Option<String>,
     pub password: Option<String>,
     pub ssl_mode: Option<String>,
-    /// For SQLite, this is the file path
+    /// Database file for SQLite and DuckDB; an empty DuckDB path opens an in-memory database
     pub file_path: Option<String>,
+    /// Pool tuning; driver defaults apply when unset
+    pub pool: Option<PoolSettings>,
+    /// Certificates for TLS and mutual TLS
+    pub tls: Option<TlsSettings>,
+    /// Folder shown in the sidebar; `/` separates nested folders, e.g. `Clients/Acme`
+    pub group: Option<String>,
+    /// Label color as `#rrggbb`
+    pub color: Option<String>,
+    pub environment: Option<ConnectionEnvironment>,
+    /// Refuse statements and commands that change data or schema. Unset means
+    /// read-only for production connections.
+    pub read_only: Option<bool>,
+    /// Most rows kept from each result set of a query run from the editor
+    pub default_row_limit: Option<u32>,
+    /// `.env` file with variables for `${NAME}` placeholders in the other fields
+    pub env_file: Option<String>,
+}
+
+impl ConnectionConfig {
+    /// Whether changes are refused on this connection
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.unwrap_or(self.environment == Some(ConnectionEnvironment::Prod))
+    }
+}
+
+/// Which environment a connection points at, shown as a tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionEnvironment {
+    Dev,
+    Staging,
+    Prod,
+}
+
+/// Certificate files (PEM) for TLS connections
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsSettings {
+    /// CA used to verify the server certificate
+    pub ca_cert_path: Option<String>,
+    /// Client certificate for mutual TLS
+    pub client_cert_path: Option<String>,
+    /// Private key matching the client certificate
+    pub client_key_path: Option<String>,
+    /// Overrides `ssl_mode` when set
+    pub verify_mode: Option<TlsVerifyMode>,
+}
+
+/// How strictly the server certificate is checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TlsVerifyMode {
+    /// Encrypt without verifying the certificate
+    None,
+    /// Verify the certificate chain against the CA
+    Ca,
+    /// Verify the chain and that the certificate matches the host name
+    Full,
+}
+
+/// Connection pool tuning; unset fields keep the driver defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSettings {
+    pub max_connections: Option<u32>,
+    pub min_idle: Option<u32>,
+    /// Seconds to wait for a free connection before failing
+    pub acquire_timeout_secs: Option<u64>,
+    /// Seconds an unused connection stays open
+    pub idle_timeout_secs: Option<u64>,
+    /// Milliseconds a statement may run before the server cancels it
+    pub statement_timeout_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +111,10 @@ pub struct ConnectionInfo {
     pub host: Option<String>,
     pub database: String,
     pub connected: bool,
+    pub group: Option<String>,
+    pub color: Option<String>,
+    pub environment: Option<ConnectionEnvironment>,
+    pub read_only: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,3 +125,29 @@ pub struct TestConnectionResult {
     pub server_version: Option<String>,
 }
 
+
+/// Portable set of saved connections, optionally encrypted with a password
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionBundle {
+    pub format: String,
+    pub version: u32,
+    /// Plain connections; absent when the bundle is encrypted
+    pub connections: Option<Vec<ConnectionConfig>>,
+    pub encryption: Option<BundleEncryption>,
+    /// Base64 AES-256-GCM ciphertext of the connections JSON
+    pub ciphertext: Option<String>,
+}
+
+/// Key derivation and cipher parameters of an encrypted bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleEncryption {
+    pub algorithm: String,
+    pub kdf: String,
+    pub iterations: u32,
+    /// Base64 PBKDF2 salt
+    pub salt: String,
+    /// Base64 AES-GCM nonce
+    pub nonce: String,
+}
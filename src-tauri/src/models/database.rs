@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Options for `create_database`; unsupported options are rejected rather than ignored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDatabaseOptions {
+    pub name: String,
+    /// Owning role (PostgreSQL) or login (SQL Server)
+    pub owner: Option<String>,
+    /// ENCODING on PostgreSQL, CHARACTER SET on MySQL
+    pub charset: Option<String>,
+    /// LC_COLLATE/LC_CTYPE on PostgreSQL, COLLATE on MySQL and SQL Server
+    pub collation: Option<String>,
+}
+
+/// Options for `create_schema`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSchemaOptions {
+    pub name: String,
+    /// AUTHORIZATION role (PostgreSQL) or user (SQL Server)
+    pub owner: Option<String>,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// Tables and foreign keys of a database, laid out as a graph for the ER diagram
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErModel {
+    pub nodes: Vec<ErNode>,
+    pub edges: Vec<ErEdge>,
+}
+
+/// A table in the diagram
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErNode {
+    /// Table name as returned by `get_all_table_schemas`, e.g. `public.users`
+    pub id: String,
+    pub schema: Option<String>,
+    pub name: String,
+    pub columns: Vec<ErColumn>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    pub is_primary_key: bool,
+    pub is_foreign_key: bool,
+    /// Covered on its own by a unique index or constraint
+    pub is_unique: bool,
+}
+
+/// How many rows of the source table can point at one row of the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Cardinality {
+    /// The foreign key columns are the primary key or a unique key of the source
+    OneToOne,
+    ManyToOne,
+}
+
+/// A foreign key from `source` to `target`; composite keys are a single edge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErEdge {
+    pub source: String,
+    pub source_columns: Vec<String>,
+    pub target: String,
+    pub target_columns: Vec<String>,
+    pub cardinality: Cardinality,
+    /// True when every foreign key column is nullable, so the relationship is optional
+    pub optional: bool,
+}
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+/// A table on a specific connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableRef {
+    pub connection_id: String,
+    pub table_name: String,
+}
+
+/// Compare the rows of two tables matched by key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableDiffRequest {
+    pub source: TableRef,
+    pub target: TableRef,
+    /// Columns that identify a row; defaults to the source table's primary key
+    pub key_columns: Option<Vec<String>>,
+    /// Rows fetched per page from each table (default 1000)
+    pub chunk_size: Option<u32>,
+    /// Row differences returned in detail (default 1000); all are counted
+    pub max_rows: Option<u32>,
+    /// Also return INSERT/UPDATE/DELETE statements that make the target match the source
+    pub generate_sql: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RowDiffKind {
+    /// Only in the source
+    Inserted,
+    /// In both with different values
+    Updated,
+    /// Only in the target
+    Deleted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RowDiff {
+    pub kind: RowDiffKind,
+    pub key: serde_json::Map<String, serde_json::Value>,
+    /// Columns whose values differ (updates only)
+    pub changed_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableDiffResult {
+    pub key_columns: Vec<String>,
+    /// Columns present in both tables
+    pub compared_columns: Vec<String>,
+    pub source_rows: usize,
+    pub target_rows: usize,
+    pub inserted: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub unchanged: usize,
+    pub rows: Vec<RowDiff>,
+    /// True when more differences were found than `rows` holds
+    pub truncated: bool,
+    /// Reconciling DML for the target, when requested
+    pub sql: Option<Vec<String>>,
+    pub execution_time_ms: u64,
+}
+
+/// How a row of the second result differs from the first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultRowChange {
+    /// Only in the second result
+    Added,
+    /// Only in the first result
+    Removed,
+    /// In both with different values
+    Changed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultRowDiff {
+    pub change: ResultRowChange,
+    /// Values of the key columns
+    pub key: serde_json::Map<String, serde_json::Value>,
+    /// The row in the first result, in `columns` order; None when added
+    pub before: Option<Vec<serde_json::Value>>,
+    /// The row in the second result, in `columns` order; None when removed
+    pub after: Option<Vec<serde_json::Value>>,
+    /// Columns whose values differ (changes only)
+    pub changed_columns: Vec<String>,
+}
+
+/// Differences between two kept query results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultDiff {
+    /// Columns rows were matched on; every compared column when none were given
+    pub key_columns: Vec<String>,
+    /// Columns both results have, in the first result's order
+    pub columns: Vec<String>,
+    /// Columns only the first result has
+    pub removed_columns: Vec<String>,
+    /// Columns only the second result has
+    pub added_columns: Vec<String>,
+    pub rows_a: usize,
+    pub rows_b: usize,
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+    pub unchanged: usize,
+    pub rows: Vec<ResultRowDiff>,
+    /// True when more differences were found than `rows` holds
+    pub truncated: bool,
+    pub execution_time_ms: u64,
+}
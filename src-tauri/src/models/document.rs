@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// A find request for the document viewer; filter, projection and sort are extended JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentQuery {
+    pub connection_id: String,
+    pub collection: String,
+    pub filter: Option<serde_json::Value>,
+    pub projection: Option<serde_json::Value>,
+    pub sort: Option<serde_json::Value>,
+    pub limit: Option<u32>,
+    pub skip: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentResult {
+    pub documents: Vec<serde_json::Value>,
+    pub execution_time_ms: u64,
+}
@@ -0,0 +1,98 @@
+use super::ColumnInfo;
+use serde::{Deserialize, Serialize};
+
+/// File formats query results can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    /// A single JSON array of row objects
+    Json,
+    /// One JSON object per line
+    Ndjson,
+    /// Excel workbook with typed cells
+    Xlsx,
+    /// Apache Parquet with Arrow column types, written in batches
+    Parquet,
+}
+
+/// Formatting options shared by the exporters; unset fields use the defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOptions {
+    /// Indent JSON output (default false; NDJSON is never indented)
+    pub pretty: Option<bool>,
+    /// chrono format string applied to date and time columns, e.g. `%d/%m/%Y`
+    pub date_format: Option<String>,
+    /// Write binary columns as base64 (default true) instead of `\x` hex
+    pub binary_as_base64: Option<bool>,
+    /// Write a header row (CSV and XLSX, default true)
+    pub include_headers: Option<bool>,
+    /// Worksheet name for XLSX exports (default `Results`, or the table name)
+    pub sheet_name: Option<String>,
+    /// Write each result set of a multi-statement script to its own XLSX sheet
+    pub sheet_per_result_set: Option<bool>,
+    /// Rows fetched and written per Parquet row group (default 10000)
+    pub batch_size: Option<u32>,
+}
+
+/// Text formats results can be copied to the clipboard as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CopyFormat {
+    Markdown,
+    Html,
+    Tsv,
+    /// An indented JSON array of row objects
+    Json,
+    /// One INSERT statement per row
+    Insert,
+}
+
+/// Rows to copy: those given, or every row of a result kept in the backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyResultsRequest {
+    pub format: CopyFormat,
+    pub columns: Vec<ColumnInfo>,
+    pub rows: Option<Vec<Vec<serde_json::Value>>>,
+    /// Copy every row of this stored result instead of `rows`
+    pub result_id: Option<String>,
+    /// Table named in INSERT statements (default `table_name`)
+    pub table_name: Option<String>,
+    /// Connection whose quoting the INSERT statements use
+    pub connection_id: Option<String>,
+}
+
+/// Run a query and write its results to a file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRequest {
+    pub connection_id: String,
+    #[serde(default)]
+    pub sql: String,
+    /// Export this whole table instead of running `sql`
+    pub table_name: Option<String>,
+    pub format: ExportFormat,
+    /// Destination file path
+    pub path: String,
+    pub options: Option<ExportOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportSummary {
+    pub path: String,
+    pub format: ExportFormat,
+    pub rows: usize,
+    pub bytes: u64,
+    pub execution_time_ms: u64,
+}
+
+/// Payload of the `export-progress` event emitted while a batched export runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportProgress {
+    pub path: String,
+    pub rows_written: usize,
+}
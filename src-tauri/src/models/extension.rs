@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+
+/// `manifest.json` at the root of an extension package
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionManifest {
+    /// Lowercase letters, digits and dashes, e.g. `data-faker`
+    pub id: String,
+    pub name: String,
+    /// `MAJOR.MINOR.PATCH`, optionally with a `-prerelease` suffix
+    pub version: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub author: String,
+    #[serde(default)]
+    pub category: String,
+    /// Entry point inside the package, a `.js` or `.wasm` file; extensions
+    /// that only contribute themes have none
+    #[serde(default)]
+    pub main: Option<String>,
+    /// Oldest app version the extension works with
+    pub min_app_version: Option<String>,
+    /// What the extension is allowed to do, e.g. `read_schema`
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub contributes: ExtensionContributions,
+}
+
+/// What an extension adds to the app, declared in its manifest
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionContributions {
+    #[serde(default)]
+    pub settings: Vec<SettingContribution>,
+    #[serde(default)]
+    pub commands: Vec<CommandContribution>,
+    /// Only for extensions in the `Themes` category
+    #[serde(default)]
+    pub themes: Vec<ThemeContribution>,
+}
+
+/// A color theme: definition files in the package for its variants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeContribution {
+    /// Lowercase letters, digits and dashes, e.g. `solarized`
+    pub id: String,
+    pub name: String,
+    /// Definition of the dark variant, e.g. `themes/dark.json`
+    pub dark: Option<String>,
+    pub light: Option<String>,
+}
+
+/// A command the extension offers; it registers the handler when activated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandContribution {
+    /// `<extension id>.<name>`, e.g. `data-faker.fill-table`
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Suggested shortcut, e.g. `Mod+Shift+F`; left unbound when it is taken
+    #[serde(default)]
+    pub shortcut: Option<String>,
+}
+
+/// A setting the user can change in the extension's settings form
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingContribution {
+    pub key: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(rename = "type")]
+    pub setting_type: SettingType,
+    /// Not allowed for passwords
+    pub default: Option<serde_json::Value>,
+    /// Choices of a `select` setting
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SettingType {
+    String,
+    Number,
+    Boolean,
+    Select,
+    /// Stored apart from the other settings and never sent back to the settings form
+    Password,
+}
+
+/// An extension's settings as the settings form sees them
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionSettings {
+    /// Saved values over the contributed defaults; passwords are left out
+    pub values: serde_json::Map<String, serde_json::Value>,
+    /// Password settings that have a value
+    pub secrets: Vec<String>,
+}
+
+/// An extension unpacked into the app's extension directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledExtension {
+    pub manifest: ExtensionManifest,
+    pub path: String,
+    /// SHA-256 of the package it was installed from, in hex
+    pub sha256: String,
+    /// RFC 3339, UTC
+    pub installed_at: String,
+    /// Compiled into the app rather than installed from a package; path,
+    /// checksum and install time are empty
+    #[serde(default)]
+    pub bundled: bool,
+    #[serde(default)]
+    pub is_official: bool,
+    pub status: ExtensionStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtensionStatus {
+    /// Installed from a package; the frontend runtime starts it when enabled
+    Installed,
+    /// A bundled extension that is on
+    Active,
+    /// A bundled extension that was turned off
+    Disabled,
+    /// A lifecycle hook or runtime call panicked or failed; restart it to recover
+    Error(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtensionLogLevel {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+/// One line of an extension's log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionLogEntry {
+    /// RFC 3339, UTC
+    pub timestamp: String,
+    pub level: ExtensionLogLevel,
+    pub message: String,
+}
+
+/// Payload of the `extension-status` event, sent when an extension fails or recovers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionStatusEvent {
+    pub extension_id: String,
+    pub status: ExtensionStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtensionRuntime {
+    Js,
+    Wasm,
+}
+
+/// What the frontend needs to start an extension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionEntry {
+    pub manifest: ExtensionManifest,
+    pub runtime: ExtensionRuntime,
+    /// The entry script, or the WebAssembly module in base64
+    pub source: String,
+    /// Current settings, passwords included
+    pub settings: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An HTTP request an extension with the `network` permission makes through the app
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionFetchRequest {
+    pub url: String,
+    pub method: String,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// Request body in base64
+    pub body: Option<String>,
+}
+
+/// The response to an [`ExtensionFetchRequest`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionFetchResponse {
+    pub status: u16,
+    pub status_text: String,
+    pub headers: Vec<(String, String)>,
+    /// Response body in base64
+    pub body: String,
+}
+
+/// A contributed command with the extension that provides it, for the palette
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionCommandInfo {
+    pub extension_id: String,
+    pub extension_name: String,
+    #[serde(flatten)]
+    pub command: CommandContribution,
+}
+
+/// Payload of the `extension-command` event: a command for the runtime to run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionCommandRequest {
+    pub request_id: String,
+    pub extension_id: String,
+    pub command_id: String,
+    pub args: serde_json::Value,
+}
+
+/// What a command returned
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtensionCommandResult {
+    pub extension_id: String,
+    pub command_id: String,
+    pub result: serde_json::Value,
+    pub duration_ms: u64,
+}
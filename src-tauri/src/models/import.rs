@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// File formats data can be imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Csv,
+    Tsv,
+    /// A JSON array of objects, or one object per line (NDJSON)
+    Json,
+}
+
+/// Column type inferred from the values in an import file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportColumnType {
+    Boolean,
+    Integer,
+    Float,
+    Date,
+    Timestamp,
+    Text,
+}
+
+/// How to read an import file; unset fields are detected from the file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFileOptions {
+    /// Defaults to the file extension (`.tsv`, `.json`, `.ndjson`, `.jsonl`, otherwise CSV)
+    pub format: Option<ImportFormat>,
+    /// Field delimiter for CSV; sniffed from the file when unset
+    pub delimiter: Option<String>,
+    /// Whether the first CSV/TSV record holds column names (default true)
+    pub has_header: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportColumn {
+    pub name: String,
+    pub column_type: ImportColumnType,
+    /// True if any sampled value was empty
+    pub nullable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPreview {
+    pub format: ImportFormat,
+    /// Delimiter used for CSV/TSV
+    pub delimiter: Option<String>,
+    pub has_header: bool,
+    pub columns: Vec<ImportColumn>,
+    /// First rows of the file, used for type inference
+    pub rows: Vec<Vec<serde_json::Value>>,
+    pub total_rows: usize,
+}
+
+/// Whether the import creates the target table or appends to an existing one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportMode {
+    Create,
+    Append,
+}
+
+/// Maps a file column to a table column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportColumnMapping {
+    pub source: String,
+    pub target: String,
+    /// SQL type used when creating the table; defaults to one matching the inferred type
+    pub data_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRequest {
+    pub connection_id: String,
+    pub path: String,
+    pub table_name: String,
+    pub mode: ImportMode,
+    pub options: Option<ImportFileOptions>,
+    /// Columns to import; all file columns under their own names when unset
+    pub mapping: Option<Vec<ImportColumnMapping>>,
+    /// Rows inserted per transaction (default 500)
+    pub batch_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowError {
+    /// 1-based data row in the file, not counting the header
+    pub row: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    pub table_name: String,
+    pub rows_imported: usize,
+    pub rows_failed: usize,
+    /// Errors for the failed rows, capped at the first 1000
+    pub errors: Vec<ImportRowError>,
+    pub execution_time_ms: u64,
+}
+
+/// Result of `generate_mock_data`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MockDataSummary {
+    pub table_name: String,
+    pub rows_inserted: usize,
+    pub rows_failed: usize,
+    /// Errors for the failed rows, capped at the first 1000
+    pub errors: Vec<ImportRowError>,
+    pub execution_time_ms: u64,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyBindingSource {
+    /// One of the app's own shortcuts
+    Builtin,
+    /// Contributed by an installed extension
+    Extension,
+}
+
+/// The command that holds a shortcut another binding asked for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutConflict {
+    /// The shortcut both asked for
+    pub shortcut: String,
+    pub command_id: String,
+    pub title: String,
+}
+
+/// A command in the resolved keymap. Shortcuts are in canonical form, e.g.
+/// `Mod+Shift+K`, where `Mod` is Cmd on macOS and Ctrl elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyBinding {
+    pub command_id: String,
+    pub title: String,
+    pub source: KeyBindingSource,
+    pub extension_id: Option<String>,
+    /// The shortcut when the user has not changed it
+    pub default_shortcut: Option<String>,
+    /// The shortcut in effect; None when unbound or lost to a conflict
+    pub shortcut: Option<String>,
+    /// Changed by the user
+    pub overridden: bool,
+    /// Handled by the editor itself, so it cannot be changed
+    pub fixed: bool,
+    pub conflict: Option<ShortcutConflict>,
+}
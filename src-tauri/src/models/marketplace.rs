@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// An extension listed in the marketplace registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceExtension {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub version: String,
+    pub author: String,
+    /// e.g. `Validators`, `AI`, `Exporters`, `Themes`, `Tools` or `Connectors`
+    pub category: String,
+    #[serde(default)]
+    pub downloads: u64,
+    /// Average rating out of 5; 0 when unrated
+    #[serde(default)]
+    pub rating: f32,
+    #[serde(default)]
+    pub rating_count: u64,
+    #[serde(default)]
+    pub is_official: bool,
+    #[serde(default)]
+    pub is_featured: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub repository: Option<String>,
+    /// Where the `.dbx` package can be downloaded from
+    pub download_url: Option<String>,
+    /// SHA-256 of the package, in hex; required to install from the registry
+    pub sha256: Option<String>,
+}
+
+/// The registry's index file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceIndex {
+    #[serde(default)]
+    pub extensions: Vec<MarketplaceExtension>,
+}
+
+/// Extensions matching a marketplace search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketplaceSearchResult {
+    pub extensions: Vec<MarketplaceExtension>,
+    /// Every category in the index, for the filter
+    pub categories: Vec<String>,
+    pub registry_url: String,
+    /// RFC 3339 time the index was downloaded
+    pub fetched_at: String,
+    /// True when the registry could not be reached and a saved copy was used
+    pub offline: bool,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Durations that fell at or below `le_ms`, and above the previous bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyBucket {
+    /// None for the last bucket, which takes everything slower
+    pub le_ms: Option<u64>,
+    pub count: u64,
+}
+
+/// How long a kind of operation took. Percentiles are the upper bound of the
+/// bucket they fall in, so they are estimates.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LatencyHistogram {
+    pub count: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+    pub p99_ms: Option<u64>,
+    pub buckets: Vec<LatencyBucket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionMetrics {
+    /// Connections open now
+    pub active: usize,
+    pub opened: u64,
+    pub failed: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    /// Between 0 and 1; None before the cache was used
+    pub hit_rate: Option<f64>,
+}
+
+/// Performance of this session, measured on this machine and never sent anywhere
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceMetrics {
+    /// RFC 3339, UTC; when measuring started or the metrics were last reset
+    pub since: String,
+    pub queries: LatencyHistogram,
+    pub failed_queries: u64,
+    pub connections: ConnectionMetrics,
+    /// Table schemas cached for editor completions
+    pub schema_cache: CacheMetrics,
+    pub ai_requests: LatencyHistogram,
+    /// AI request durations by what they were for, e.g. `chat`
+    pub ai_operations: BTreeMap<String, LatencyHistogram>,
+}
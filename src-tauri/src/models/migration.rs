@@ -0,0 +1,33 @@
+use super::{IndexInfo, TableSchema};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnRename {
+    pub from: String,
+    pub to: String,
+}
+
+/// Desired shape of a table, as edited in the schema editor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationRequest {
+    pub connection_id: String,
+    pub table_name: String,
+    pub desired: TableSchema,
+    /// Columns renamed in the editor; without these a rename looks like drop + add
+    pub renames: Option<Vec<ColumnRename>>,
+    /// Desired secondary indexes; existing indexes are kept when unset
+    pub indexes: Option<Vec<IndexInfo>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationPlan {
+    pub table_name: String,
+    pub statements: Vec<String>,
+    /// Changes that need review, e.g. dropped columns or lossy type changes
+    pub warnings: Vec<String>,
+    /// True if SQLite has to copy the table because ALTER TABLE cannot express the change
+    pub rebuild: bool,
+}
@@ -1,6 +1,70 @@
+mod ai;
+mod app_log;
+mod audit;
+mod backup;
+mod browse;
+mod cell;
+mod chart;
+mod change_feed;
+mod confirmation;
 mod connection;
+mod database;
+mod diagram;
+mod diff;
+mod document;
+mod export;
+mod extension;
+mod import;
+mod keymap;
+mod marketplace;
+mod metrics;
+mod migration;
+mod monitoring;
+mod pin;
+mod privilege;
 mod query;
+mod redis_keys;
+mod saved_query;
+mod search;
+mod sql;
+mod template;
+mod theme;
+mod transfer;
+mod usage;
+mod workspace;
 
+pub use ai::*;
+pub use app_log::*;
+pub use audit::*;
+pub use backup::*;
+pub use browse::*;
+pub use cell::*;
+pub use chart::*;
+pub use change_feed::*;
+pub use confirmation::*;
 pub use connection::*;
+pub use database::*;
+pub use diagram::*;
+pub use diff::*;
+pub use document::*;
+pub use export::*;
+pub use extension::*;
+pub use import::*;
+pub use keymap::*;
+pub use marketplace::*;
+pub use metrics::*;
+pub use migration::*;
+pub use monitoring::*;
+pub use pin::*;
+pub use privilege::*;
 pub use query::*;
+pub use redis_keys::*;
+pub use saved_query::*;
+pub use search::*;
+pub use sql::*;
+pub use template::*;
+pub use theme::*;
+pub use transfer::*;
+pub use usage::*;
+pub use workspace::*;
 
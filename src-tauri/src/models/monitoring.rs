@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+
+/// Server-wide health figures; fields the server does not expose are None
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStats {
+    pub active_connections: Option<u64>,
+    pub max_connections: Option<u64>,
+    pub uptime_seconds: Option<u64>,
+    /// Share of reads served from memory, 0.0 to 1.0
+    pub cache_hit_ratio: Option<f64>,
+    /// Largest first
+    pub database_sizes: Vec<DatabaseSize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseSize {
+    pub name: String,
+    pub size_bytes: u64,
+}
+
+/// A session that is running a statement, from pg_stat_activity, the process list or similar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveQuery {
+    /// Pass to `kill_session`
+    pub session_id: String,
+    pub user: Option<String>,
+    pub database: Option<String>,
+    /// Client address
+    pub client: Option<String>,
+    pub state: Option<String>,
+    pub query: Option<String>,
+    pub duration_ms: Option<u64>,
+}
+
+/// Payload of the `server-monitor` event emitted on every poll
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerMonitorSnapshot {
+    pub connection_id: String,
+    pub stats: Option<ServerStats>,
+    pub active_queries: Vec<ActiveQuery>,
+    /// Set when the poll failed; the monitor keeps polling
+    pub error: Option<String>,
+}
+
+/// Ranking for `get_slow_queries`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SlowQueryOrder {
+    #[default]
+    TotalTime,
+    MeanTime,
+    Calls,
+}
+
+/// Aggregated timings for one normalized statement, from pg_stat_statements or
+/// performance_schema
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowQueryEntry {
+    /// Statement text with literals replaced by placeholders
+    pub query: String,
+    pub calls: u64,
+    pub total_time_ms: f64,
+    pub mean_time_ms: f64,
+    pub max_time_ms: Option<f64>,
+    /// Rows returned or affected across all calls
+    pub rows: Option<u64>,
+    pub database: Option<String>,
+}
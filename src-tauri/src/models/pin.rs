@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of object pinned to a connection's favorites
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PinnedObjectType {
+    Table,
+    View,
+    /// A saved query
+    Query,
+}
+
+/// A table, view or saved query pinned to a connection's favorites
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinnedObject {
+    pub connection_id: String,
+    pub object_type: PinnedObjectType,
+    /// Table or view name as `get_tables` lists it, or the saved query's ID
+    pub name: String,
+    /// RFC 3339, UTC
+    pub pinned_at: String,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// A login or role on the server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseUser {
+    pub name: String,
+    /// Account host on MySQL, e.g. `%` or `localhost`
+    pub host: Option<String>,
+    /// False for PostgreSQL NOLOGIN roles and locked MySQL accounts
+    pub can_login: bool,
+    pub is_superuser: bool,
+    /// Roles granted to this user
+    pub member_of: Vec<String>,
+}
+
+/// Where a privilege that applies to a table was granted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PrivilegeScope {
+    /// Granted on the table itself
+    Table,
+    /// Granted on the whole database (MySQL `db.*`)
+    Database,
+    /// Granted globally (MySQL `*.*`)
+    Server,
+}
+
+/// One privilege a grantee holds on a table; a grants matrix is grantee x privilege_type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePrivilege {
+    /// Role name, `PUBLIC`, or `'user'@'host'` on MySQL
+    pub grantee: String,
+    /// SELECT, INSERT, UPDATE, ...
+    pub privilege_type: String,
+    pub is_grantable: bool,
+    pub grantor: Option<String>,
+    pub scope: PrivilegeScope,
+}
@@ -1,4 +1,6 @@
+use super::CellValue;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -7,6 +9,15 @@ pub struct QueryRequest {
     pub sql: String,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// Token from a previous call, needed to run UPDATE or DELETE without WHERE
+    pub confirmation_token: Option<String>,
+    /// Run on this named session (an editor tab's own connection) rather than the shared pool
+    pub session_id: Option<String>,
+    /// Keep the rows in the backend and return only this many; the rest are
+    /// fetched with `get_result_rows`
+    pub window_size: Option<u32>,
+    /// Values for the `:name` and `${name}` placeholders in `sql`
+    pub params: Option<HashMap<String, CellValue>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +27,134 @@ pub struct QueryResult {
     pub rows: Vec<Vec<serde_json::Value>>,
     pub affected_rows: Option<u64>,
     pub execution_time_ms: u64,
+    /// One entry per statement when a script ran several; empty for a single statement
+    pub result_sets: Vec<ResultSet>,
+}
+
+/// A query result whose rows may be kept in the backend. When they are,
+/// `rows` holds the first window and `result_id` fetches the others, or a
+/// single cell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowedQueryResult {
+    #[serde(flatten)]
+    pub result: QueryResult,
+    pub result_id: Option<String>,
+    /// Rows in the whole result
+    pub total_rows: usize,
+}
+
+/// Rows of a stored result, from `offset` on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultWindow {
+    pub result_id: String,
+    pub offset: usize,
+    pub total_rows: usize,
+    pub rows: Vec<Vec<serde_json::Value>>,
+}
+
+/// A query the AI assistant wrote to answer a question about the data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataQuestionRequest {
+    pub connection_id: String,
+    /// The user's question, kept in the audit log with the query
+    pub question: String,
+    pub sql: String,
+    /// Most rows to return; capped by the connection's row limit
+    pub max_rows: Option<u32>,
+}
+
+/// The query that answered a question, and its rows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataQuestionAnswer {
+    pub question: String,
+    pub sql: String,
+    pub result: QueryResult,
+    /// True when the query returned more rows than were kept
+    pub truncated: bool,
+}
+
+/// Rows copied into the local database of a federated query, under `alias`.
+/// Give a `result_id`, or a `connection_id` with a `table_name` or a read-only `sql`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedSource {
+    /// Name the federated query uses for these rows
+    pub alias: String,
+    /// A result kept by `execute_query`
+    pub result_id: Option<String>,
+    pub connection_id: Option<String>,
+    pub table_name: Option<String>,
+    pub sql: Option<String>,
+}
+
+/// A query joining rows from several connections
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedQueryRequest {
+    pub sources: Vec<FederatedSource>,
+    /// SQLite SQL over the sources' aliases
+    pub sql: String,
+    /// Most rows copied from each source (default 100000)
+    pub max_source_rows: Option<u32>,
+}
+
+/// How many rows a federated query copied from a source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedSourceInfo {
+    pub alias: String,
+    pub rows: usize,
+    /// True when the source had more rows than were copied
+    pub truncated: bool,
+}
+
+/// Rows of a federated query. The join runs in an in-memory SQLite database
+/// on this machine, not on any of the connections.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FederatedQueryResult {
+    pub result: QueryResult,
+    pub sources: Vec<FederatedSourceInfo>,
+    /// What ran the query, e.g. `SQLite 3.46 (in memory, local)`
+    pub engine: String,
+}
+
+/// A query's execution plan, with the statistics and indexes of the tables it
+/// uses, for the index advisor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPlan {
+    pub sql: String,
+    /// The plan as the database prints it
+    pub plan: String,
+    /// True when the query was run, so the plan has actual rows and timings
+    pub analyzed: bool,
+    pub tables: Vec<PlanTable>,
+}
+
+/// A table the planned query reads or writes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanTable {
+    pub name: String,
+    /// None when the database could not report statistics for the table
+    pub stats: Option<TableStats>,
+    pub indexes: Vec<IndexInfo>,
+}
+
+/// Output of one statement in a multi-statement script
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResultSet {
+    pub statement: String,
+    pub columns: Vec<ColumnInfo>,
+    pub rows: Vec<Vec<serde_json::Value>>,
+    pub affected_rows: Option<u64>,
+    pub execution_time_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -25,6 +164,10 @@ pub struct ColumnInfo {
     pub data_type: String,
     pub nullable: bool,
     pub is_primary_key: bool,
+    /// Type name exactly as the driver reports it (e.g. `INT4[]`, `BIGINT UNSIGNED`)
+    pub native_type: Option<String>,
+    /// PostgreSQL type OID
+    pub type_oid: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,8 +175,14 @@ pub struct ColumnInfo {
 pub struct TableInfo {
     pub name: String,
     pub schema: Option<String>,
+    /// `BASE TABLE`, or `PARTITIONED TABLE` and `PARTITION` for partitioned tables and their partitions
     pub table_type: String,
     pub row_count: Option<i64>,
+    /// Table this one is a partition of
+    pub parent_table: Option<String>,
+    /// Pinned to the connection's favorites
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +194,38 @@ pub struct TableSchema {
     pub foreign_keys: Vec<ForeignKeyInfo>,
 }
 
+/// Options for `truncate_table`; only PostgreSQL uses them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TruncateOptions {
+    /// Reset sequences owned by the table's columns
+    pub restart_identity: bool,
+    /// Also truncate tables that reference this one
+    pub cascade: bool,
+}
+
+/// Options for `generate_database_ddl`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DatabaseDdlOptions {
+    /// Drop every object before creating it again
+    pub include_drops: bool,
+    /// Prefix names with their schema, or database for MySQL and ClickHouse.
+    /// SQL Server names always carry their schema; SQLite has none
+    pub qualify_names: bool,
+}
+
+/// Column added with `add_column`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewColumn {
+    pub name: String,
+    pub data_type: String,
+    pub nullable: bool,
+    /// SQL expression used as the column default, e.g. `0` or `'pending'`
+    pub default_value: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ForeignKeyInfo {
@@ -81,6 +262,20 @@ pub struct ExtendedColumnInfo {
     pub is_primary_key: bool,
     pub default_value: Option<String>,
     pub comment: Option<String>,
+    /// Identity or auto-increment column whose value the database assigns
+    pub is_identity: bool,
+    /// Identity that rejects explicit values (`GENERATED ALWAYS`, SQL Server `IDENTITY`)
+    pub identity_always: bool,
+    /// Expression of a generated/computed column
+    pub generation_expression: Option<String>,
+    pub collation: Option<String>,
+}
+
+impl ExtendedColumnInfo {
+    /// Whether an INSERT may name this column
+    pub fn is_insertable(&self) -> bool {
+        self.generation_expression.is_none() && !self.identity_always
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,8 +288,84 @@ pub struct TableProperties {
     pub foreign_keys: Vec<ForeignKeyInfo>,
     pub indexes: Vec<IndexInfo>,
     pub constraints: Vec<ConstraintInfo>,
+    /// Estimated for large tables; see `row_count_exact`
     pub row_count: Option<i64>,
+    /// Whether `row_count` was counted rather than estimated
+    pub row_count_exact: bool,
+    pub table_comment: Option<String>,
+}
+
+/// A comment stored on a table or one of its columns
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectComment {
+    pub table: String,
+    /// None for a comment on the table itself
+    pub column: Option<String>,
+    pub comment: String,
+}
+
+/// Descriptions to store as comments on a table and its columns
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaComments {
+    /// None leaves the table's comment as it is
     pub table_comment: Option<String>,
+    pub columns: Vec<ColumnComment>,
+}
+
+/// A column's description; an empty comment removes the existing one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnComment {
+    pub column: String,
+    pub comment: String,
+}
+
+/// Storage figures for a table; fields the database does not track are None
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableStats {
+    pub table_name: String,
+    /// Data on disk, excluding indexes
+    pub table_size_bytes: Option<u64>,
+    pub index_size_bytes: Option<u64>,
+    pub total_size_bytes: Option<u64>,
+    /// From table statistics, so it can lag behind COUNT(*)
+    pub approximate_rows: Option<u64>,
+    /// Rows deleted or updated but not yet vacuumed (PostgreSQL)
+    pub dead_rows: Option<u64>,
+    /// dead_rows as a share of all row versions, 0.0 to 1.0; high values call for VACUUM
+    pub dead_row_ratio: Option<f64>,
+    /// Allocated but unused space that OPTIMIZE TABLE, compact or a rebuild would reclaim
+    pub free_bytes: Option<u64>,
+    pub last_vacuum: Option<String>,
+    pub last_analyze: Option<String>,
+}
+
+/// How a partitioned table is split
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TablePartitioning {
+    pub table_name: String,
+    /// RANGE, LIST, HASH or KEY
+    pub strategy: String,
+    /// Partition key, e.g. `created_at` or `YEAR(created_at)`
+    pub key: String,
+    pub partitions: Vec<PartitionInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionInfo {
+    pub name: String,
+    /// Bound clause, e.g. `FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')` or `VALUES LESS THAN (2025)`
+    pub bound: String,
+    pub size_bytes: Option<u64>,
+    /// From table statistics, so it can lag behind COUNT(*)
+    pub approximate_rows: Option<u64>,
+    /// Set when the partition is itself partitioned
+    pub sub_partitioning: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,3 +378,112 @@ pub struct TableRelationship {
     pub constraint_name: Option<String>,
 }
 
+/// A view that reads another table or view, or a table with a foreign key to another table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectDependency {
+    /// Named like `TableInfo::name`
+    pub object: String,
+    /// `TABLE`, `VIEW` or `MATERIALIZED VIEW`
+    pub object_type: String,
+    pub depends_on: String,
+    /// The foreign key, when the dependency is one
+    pub constraint_name: Option<String>,
+}
+
+impl ObjectDependency {
+    /// Views are dropped along with what they read; a foreign key only loses its constraint
+    pub fn is_foreign_key(&self) -> bool {
+        self.constraint_name.is_some()
+    }
+}
+
+
+/// Run a .sql file statement by statement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFileRequest {
+    pub connection_id: String,
+    pub path: String,
+    /// Keep going after a failed statement (default false: stop at the first error)
+    pub continue_on_error: Option<bool>,
+    /// Statements executed between progress events (default 100)
+    pub batch_size: Option<u32>,
+}
+
+/// Payload of the `sql-file-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFileProgress {
+    pub path: String,
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    pub statements_executed: usize,
+    pub statements_failed: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFileError {
+    /// 1-based position of the statement in the file
+    pub statement_index: usize,
+    /// Start of the failed statement
+    pub statement: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlFileSummary {
+    pub path: String,
+    pub statements_executed: usize,
+    pub statements_failed: usize,
+    /// Errors for the failed statements, capped at the first 1000
+    pub errors: Vec<SqlFileError>,
+    /// True if execution stopped at an error before the end of the file
+    pub stopped: bool,
+    pub execution_time_ms: u64,
+}
+
+/// A binary cell of a stored result, for previewing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CellBlob {
+    /// Sniffed from the first bytes, e.g. `image/png`
+    pub content_type: String,
+    /// File extension matching the content type, without the dot
+    pub extension: String,
+    pub size: usize,
+    /// The bytes as base64; None when the blob is too large to preview
+    pub data: Option<String>,
+}
+
+/// Distance used to rank nearest neighbors, as the pgvector operators define it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VectorMetric {
+    /// Euclidean distance, `<->`
+    #[default]
+    L2,
+    /// Cosine distance, `<=>`
+    Cosine,
+    /// Negative inner product, `<#>`
+    InnerProduct,
+    /// Taxicab distance, `<+>` (pgvector 0.7 and later)
+    L1,
+}
+
+/// Rows of a table whose vector column is nearest a given vector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VectorSearchRequest {
+    pub connection_id: String,
+    pub table_name: String,
+    /// A `vector` column of the table
+    pub column: String,
+    pub vector: Vec<f32>,
+    #[serde(default)]
+    pub metric: VectorMetric,
+    /// Rows returned, nearest first (default 10)
+    pub limit: Option<u32>,
+}
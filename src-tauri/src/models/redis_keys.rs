@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisKeyInfo {
+    pub key: String,
+    pub key_type: String,
+    /// Seconds until expiry; None when the key does not expire
+    pub ttl: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisScanResult {
+    /// Cursor for the next SCAN call; 0 when the iteration is complete
+    pub cursor: u64,
+    pub keys: Vec<RedisKeyInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedisValue {
+    pub key: String,
+    pub key_type: String,
+    pub ttl: Option<i64>,
+    /// Total number of elements (or bytes for strings), which may exceed what `value` holds
+    pub length: u64,
+    pub value: serde_json::Value,
+}
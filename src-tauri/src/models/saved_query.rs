@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Kind of value a variable takes. Values are entered as text and read as this kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    #[default]
+    Text,
+    Integer,
+    Number,
+    Boolean,
+    /// A date, time or timestamp, read by the database as the column's type
+    Date,
+}
+
+/// A named placeholder of a saved query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryVariable {
+    /// As written in the SQL without the `:` or `${}`
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub variable_type: VariableType,
+    /// Used when neither the run nor the connection's preset gives a value
+    #[serde(default)]
+    pub default_value: Option<String>,
+}
+
+/// A query kept for reuse, with the variables it takes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedQuery {
+    /// Assigned when first saved
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    pub sql: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub variables: Vec<QueryVariable>,
+    /// Variable values per connection ID, so the same query runs against dev,
+    /// staging and production with each one's own IDs
+    #[serde(default)]
+    pub presets: HashMap<String, HashMap<String, String>>,
+    /// RFC 3339, UTC; set when saved
+    #[serde(default)]
+    pub updated_at: String,
+}
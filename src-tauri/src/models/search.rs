@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A table, view or routine whose name, definition or comment matched a search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectMatch {
+    /// Named as `get_tables` lists tables, so a match can be opened
+    pub object: String,
+    pub schema: Option<String>,
+    /// "table", "view", "materialized view", "function", "procedure", "trigger", ...
+    pub object_type: String,
+    /// The matching column, for column matches and column comments
+    pub column: Option<String>,
+    /// The column's type, or the line of the definition or comment that matched
+    pub detail: Option<String>,
+}
+
+/// Matches of `search_database_objects`, grouped by where the pattern was found
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectSearchResult {
+    pub pattern: String,
+    /// Tables and views by name
+    pub tables: Vec<ObjectMatch>,
+    /// Columns by name
+    pub columns: Vec<ObjectMatch>,
+    /// Views and materialized views by definition
+    pub views: Vec<ObjectMatch>,
+    /// Functions, procedures and triggers by name or body
+    pub routines: Vec<ObjectMatch>,
+    /// Tables, columns and routines by comment
+    pub comments: Vec<ObjectMatch>,
+    /// A group reached the match limit, so more matches exist
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSearchOptions {
+    /// Match the term as a POSIX regular expression (PostgreSQL only)
+    pub regex: Option<bool>,
+    /// Matching rows returned per table (default 50, at most 1000)
+    pub max_rows_per_table: Option<u32>,
+    /// Tables searched at the same time (default 4, at most 8)
+    pub concurrency: Option<u32>,
+}
+
+/// A text cell containing the search term
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataMatch {
+    pub column: String,
+    /// The cell's text, shortened around the match
+    pub value: String,
+}
+
+/// A row with at least one matching cell
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataMatchRow {
+    /// Primary key of the row, for opening it; empty when the table has none
+    pub primary_key: HashMap<String, serde_json::Value>,
+    pub matches: Vec<DataMatch>,
+}
+
+/// One table's matches, sent as a `table-data-search-matches` event when the
+/// table has been searched
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableDataMatches {
+    pub search_id: String,
+    pub table: String,
+    /// Text columns that were searched
+    pub columns: Vec<String>,
+    pub rows: Vec<DataMatchRow>,
+    /// More rows matched than the per-table limit
+    pub truncated: bool,
+    /// Why the table could not be searched
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSearchSummary {
+    pub search_id: String,
+    /// Every table searched, in the order they finished
+    pub tables: Vec<TableDataMatches>,
+    pub execution_time_ms: u64,
+}
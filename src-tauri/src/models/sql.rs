@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+    /// Leave keywords as written
+    Preserve,
+}
+
+/// Where list separators go when a list is split over several lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CommaStyle {
+    /// `a,` at the end of each line
+    #[default]
+    Trailing,
+    /// `, b` at the start of each following line
+    Leading,
+}
+
+/// Options for `format_sql`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SqlFormatOptions {
+    pub keyword_case: KeywordCase,
+    /// Spaces per indentation level
+    pub indent_width: usize,
+    pub comma_style: CommaStyle,
+}
+
+impl Default for SqlFormatOptions {
+    fn default() -> Self {
+        Self {
+            keyword_case: KeywordCase::Upper,
+            indent_width: 2,
+            comma_style: CommaStyle::Trailing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    Warning,
+    Info,
+}
+
+/// A problem found by `lint_sql`. Lines and columns are 1-based and the end is
+/// exclusive, matching editor markers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlDiagnostic {
+    /// Rule that fired, e.g. `missing-where`
+    pub code: String,
+    pub message: String,
+    pub severity: DiagnosticSeverity,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CompletionKind {
+    Keyword,
+    Schema,
+    Table,
+    Column,
+    /// Table alias or CTE name from the statement being edited
+    Alias,
+}
+
+/// A candidate returned by `get_sql_completions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SqlCompletion {
+    pub label: String,
+    pub kind: CompletionKind,
+    /// Column type and table, or the schema of a table
+    pub detail: Option<String>,
+    /// Text to insert, quoted where the name needs it
+    pub insert_text: String,
+}
@@ -0,0 +1,35 @@
+use super::DatabaseType;
+use serde::Serialize;
+
+/// Starting values for a connection to a managed database service
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionTemplate {
+    pub id: &'static str,
+    /// Service name shown in the picker, e.g. `Amazon RDS for PostgreSQL`
+    pub name: &'static str,
+    pub provider: CloudProvider,
+    pub database_type: DatabaseType,
+    pub port: u16,
+    /// TLS the service requires, using the connection form's `ssl_mode` values
+    pub ssl_mode: &'static str,
+    pub username: Option<&'static str>,
+    pub database: Option<&'static str>,
+    /// Endings the service's host names have; empty when any host is accepted
+    pub host_suffixes: &'static [&'static str],
+    /// Placeholder for the host field
+    pub host_example: &'static str,
+    /// Setup advice shown under the form, such as where to get the CA bundle
+    pub notes: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CloudProvider {
+    Aws,
+    Azure,
+    Gcp,
+    Supabase,
+    Neon,
+    PlanetScale,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeVariant {
+    Dark,
+    Light,
+}
+
+impl ThemeVariant {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeVariant::Dark => "dark",
+            ThemeVariant::Light => "light",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeSource {
+    /// Shipped with the app
+    Bundled,
+    /// Contributed by an installed extension
+    Extension,
+}
+
+/// A theme the user can pick, in addition to the built-in light and dark
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeInfo {
+    pub id: String,
+    pub name: String,
+    pub source: ThemeSource,
+    pub extension_id: Option<String>,
+    pub variants: Vec<ThemeVariant>,
+}
+
+/// A theme variant's stylesheet, scoped to the class that turns it on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThemeCss {
+    pub theme_id: String,
+    pub variant: ThemeVariant,
+    /// Set on the document root, e.g. `theme-nordic-dark`
+    pub class_name: String,
+    pub css: String,
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// Copy a table's rows from one connection into a table of another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCopyRequest {
+    /// Chosen by the caller, so the copy can be followed and cancelled while it runs
+    pub transfer_id: String,
+    pub source_connection_id: String,
+    pub source_table: String,
+    pub target_connection_id: String,
+    /// Table to fill; the source table's name without its schema when unset.
+    /// It is created from the source's columns when it does not exist.
+    pub target_table: Option<String>,
+    /// Rows read and inserted at a time (default 500, at most 1000)
+    pub batch_size: Option<u32>,
+}
+
+/// Payload of the `table-copy-progress` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCopyProgress {
+    pub transfer_id: String,
+    pub rows_copied: usize,
+    pub rows_failed: usize,
+    /// Rows in the source table, from its statistics where the database keeps them
+    pub total_rows: Option<u64>,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCopyRowError {
+    /// 1-based position of the row in the source table, in primary key order
+    pub row: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableCopySummary {
+    pub transfer_id: String,
+    pub source_table: String,
+    pub target_table: String,
+    /// The CREATE TABLE statement run on the target, when the table was created
+    pub create_statement: Option<String>,
+    pub rows_copied: usize,
+    pub rows_failed: usize,
+    /// Errors for the failed rows, capped at the first 1000
+    pub errors: Vec<TableCopyRowError>,
+    /// Stopped by `cancel_table_copy`; the rows copied until then stay in the target
+    pub cancelled: bool,
+    pub execution_time_ms: u64,
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// How often a table was queried on a connection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableUsage {
+    pub table: String,
+    pub count: u64,
+    /// RFC 3339 time of the latest query
+    pub last_used: String,
+}
+
+/// How often a column was filtered on in a WHERE clause
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnUsage {
+    /// None when the statement did not tell which table the column belongs to
+    pub table: Option<String>,
+    pub column: String,
+    pub count: u64,
+    pub last_used: String,
+}
+
+/// Usage of one connection, kept on this machine only
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionUsage {
+    pub connection_id: String,
+    pub last_connected_at: Option<String>,
+    pub connect_count: u64,
+    pub query_count: u64,
+    pub last_query_at: Option<String>,
+    pub tables: Vec<TableUsage>,
+    pub filter_columns: Vec<ColumnUsage>,
+}
+
+/// A table ranked by how much it is used
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableInsight {
+    pub table: String,
+    pub count: u64,
+    pub last_used: String,
+    /// Query count decayed by age, relative to the top table (1.0)
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionInsights {
+    pub connection_id: String,
+    pub last_connected_at: Option<String>,
+    pub connect_count: u64,
+    pub query_count: u64,
+    pub last_query_at: Option<String>,
+    /// Most recently queried tables first
+    pub recent_tables: Vec<TableUsage>,
+    /// Most used tables first, recent use counting more than old
+    pub top_tables: Vec<TableInsight>,
+    /// Columns filtered on most often first
+    pub filter_columns: Vec<ColumnUsage>,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// How far a view was scrolled, in pixels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollPosition {
+    pub top: f64,
+    pub left: f64,
+}
+
+/// An open tab as the frontend keeps it, with its layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceTab {
+    pub id: String,
+    pub title: String,
+    /// query, table, schema, properties or diagram
+    #[serde(rename = "type")]
+    pub tab_type: String,
+    /// The connection the tab runs against
+    pub connection_id: String,
+    /// SQL text of a query tab
+    #[serde(default)]
+    pub content: Option<String>,
+    #[serde(default)]
+    pub table_name: Option<String>,
+    /// Share of the tab's height given to the results, in percent
+    #[serde(default)]
+    pub result_pane_size: Option<f64>,
+    #[serde(default)]
+    pub editor_scroll: Option<ScrollPosition>,
+}
+
+/// The open tabs and which one is active, restored on the next start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workspace {
+    /// RFC 3339, UTC; set when the workspace is saved
+    #[serde(default)]
+    pub saved_at: String,
+    pub tabs: Vec<WorkspaceTab>,
+    #[serde(default)]
+    pub active_tab_id: Option<String>,
+    #[serde(default)]
+    pub active_connection_id: Option<String>,
+}
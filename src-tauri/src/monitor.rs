@@ -0,0 +1,99 @@
+//! Background polling for the server dashboard. Each monitored connection has
+//! one task that emits a `server-monitor` event per interval until it is
+//! stopped or the connection goes away.
+
+use crate::db::{get_connection_manager, get_driver};
+use crate::error::{AppError, AppResult};
+use crate::models::{ActiveQuery, ServerMonitorSnapshot, ServerStats};
+use crate::storage;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+
+/// Connection id -> polling task
+static MONITORS: OnceCell<Mutex<HashMap<String, JoinHandle<()>>>> = OnceCell::new();
+
+fn monitors() -> &'static Mutex<HashMap<String, JoinHandle<()>>> {
+    MONITORS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Start polling a connection, replacing any monitor already running for it
+pub fn start(app: AppHandle, connection_id: String, interval: Duration) {
+    let id = connection_id.clone();
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            let connected = get_connection_manager().read().await.is_connected(&id);
+            let _ = app.emit("server-monitor", snapshot(&id).await);
+            if !connected {
+                break;
+            }
+        }
+    });
+
+    let previous = monitors()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(connection_id, handle);
+    if let Some(previous) = previous {
+        previous.abort();
+    }
+}
+
+/// Stop polling a connection; returns false if it was not being monitored
+pub fn stop(connection_id: &str) -> bool {
+    let handle = monitors()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(connection_id);
+
+    match handle {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Read stats and active queries once; a failure is reported in the snapshot
+pub async fn snapshot(connection_id: &str) -> ServerMonitorSnapshot {
+    match poll(connection_id).await {
+        Ok((stats, active_queries)) => ServerMonitorSnapshot {
+            connection_id: connection_id.to_string(),
+            stats: Some(stats),
+            active_queries,
+            error: None,
+        },
+        Err(e) => ServerMonitorSnapshot {
+            connection_id: connection_id.to_string(),
+            stats: None,
+            active_queries: vec![],
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+async fn poll(connection_id: &str) -> AppResult<(ServerStats, Vec<ActiveQuery>)> {
+    let manager = get_connection_manager().read().await;
+
+    if !manager.is_connected(connection_id) {
+        return Err(AppError::ConnectionError("Connection not found or not connected".to_string()));
+    }
+
+    let config = storage::get_connection(connection_id)?
+        .ok_or_else(|| AppError::ConfigError("Connection config not found".to_string()))?;
+
+    let driver = get_driver(&config);
+    let pool_ref = manager.get_pool_ref(connection_id)?;
+
+    let stats = driver.get_server_stats(pool_ref).await?;
+    let active_queries = driver.get_active_queries(pool_ref).await?;
+    Ok((stats, active_queries))
+}
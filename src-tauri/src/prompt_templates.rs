@@ -0,0 +1,115 @@
+//! User templates for the AI system prompts. The built-in prompts live in the
+//! frontend with the AI calls; a team can replace any of them with a file
+//! `prompts/<name>.md` in the app data directory. Templates may use the
+//! placeholders `{{schema}}`, `{{dialect}}` and `{{selected_table}}`, which the
+//! frontend fills in from the editor's context.
+
+use crate::error::{AppError, AppResult};
+use crate::models::PromptTemplate;
+use crate::storage;
+use std::fs;
+use std::path::PathBuf;
+
+const PROMPTS_DIR: &str = "prompts";
+
+/// Longest template accepted, in bytes
+const MAX_TEMPLATE_SIZE: usize = 64 * 1024;
+
+pub const PLACEHOLDERS: &[&str] = &["schema", "dialect", "selected_table"];
+
+/// Prompts that can be replaced, with what each is used for
+pub const PROMPTS: &[(&str, &str)] = &[
+    ("sql_generation", "Generating SQL from a description"),
+    ("chat", "The AI chat panel"),
+    ("query_explanation", "Explaining a query"),
+    ("optimization", "Suggesting query optimizations"),
+    ("error_explanation", "Explaining and fixing a failed query"),
+    ("index_advisor", "Suggesting indexes from an execution plan"),
+    ("schema_docs", "Describing a table and its columns"),
+];
+
+fn find(name: &str) -> AppResult<&'static (&'static str, &'static str)> {
+    PROMPTS.iter().find(|(known, _)| *known == name).ok_or_else(|| {
+        let names: Vec<&str> = PROMPTS.iter().map(|(known, _)| *known).collect();
+        AppError::ValidationError(format!("Unknown prompt '{}'; available: {}", name, names.join(", ")))
+    })
+}
+
+fn template_path(name: &str) -> AppResult<PathBuf> {
+    let dir = storage::app_dir()?.join(PROMPTS_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(format!("{}.md", name)))
+}
+
+/// Every prompt, with the user's template where there is one
+pub fn list() -> AppResult<Vec<PromptTemplate>> {
+    PROMPTS
+        .iter()
+        .map(|(name, description)| {
+            let path = template_path(name)?;
+            let content = if path.exists() { Some(fs::read_to_string(path)?) } else { None };
+            Ok(PromptTemplate {
+                name: name.to_string(),
+                description: description.to_string(),
+                content,
+            })
+        })
+        .collect()
+}
+
+/// Check a template: not empty, not too long, and every `{{...}}` a known placeholder
+pub fn validate(content: &str) -> AppResult<()> {
+    if content.trim().is_empty() {
+        return Err(AppError::ValidationError("The template is empty".to_string()));
+    }
+    if content.len() > MAX_TEMPLATE_SIZE {
+        return Err(AppError::ValidationError(format!(
+            "The template is longer than {} KB",
+            MAX_TEMPLATE_SIZE / 1024
+        )));
+    }
+
+    let mut rest = content;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| AppError::ValidationError("Unclosed '{{' in the template".to_string()))?;
+        let placeholder = after[..end].trim();
+        if !PLACEHOLDERS.contains(&placeholder) {
+            return Err(AppError::ValidationError(format!(
+                "Unknown placeholder '{{{{{}}}}}'; available: {}",
+                placeholder,
+                PLACEHOLDERS.iter().map(|p| format!("{{{{{}}}}}", p)).collect::<Vec<_>>().join(", ")
+            )));
+        }
+        rest = &after[end + 2..];
+    }
+    Ok(())
+}
+
+/// Replace a prompt with the user's template
+pub fn save(name: &str, content: &str) -> AppResult<PromptTemplate> {
+    let (name, description) = find(name)?;
+    validate(content)?;
+    fs::write(template_path(name)?, content)?;
+    Ok(PromptTemplate {
+        name: name.to_string(),
+        description: description.to_string(),
+        content: Some(content.to_string()),
+    })
+}
+
+/// Remove the user's template so the built-in prompt is used again
+pub fn reset_to_default(name: &str) -> AppResult<PromptTemplate> {
+    let (name, description) = find(name)?;
+    let path = template_path(name)?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(PromptTemplate {
+        name: name.to_string(),
+        description: description.to_string(),
+        content: None,
+    })
+}
@@ -0,0 +1,80 @@
+//! Enforcement of read-only connections. Commands that change data or schema
+//! check the connection first; ad-hoc queries are checked statement by
+//! statement so reads still work. PostgreSQL, MySQL, SQLite and DuckDB
+//! connections are also opened read-only, so the database refuses writes
+//! that pass these checks, such as those made by functions a SELECT calls.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{ConnectionConfig, DatabaseType};
+use sql_parse::Dialect;
+
+/// MongoDB commands that only read
+const MONGO_READ_COMMANDS: &[&str] = &[
+    "find", "aggregate", "count", "distinct", "listCollections", "listIndexes", "listDatabases", "dbStats",
+    "collStats", "explain", "ping", "buildInfo", "serverStatus", "hello", "isMaster", "connectionStatus",
+];
+
+/// Redis commands that only read
+const REDIS_READ_COMMANDS: &[&str] = &[
+    "GET", "MGET", "STRLEN", "GETRANGE", "EXISTS", "TYPE", "TTL", "PTTL", "KEYS", "SCAN", "DBSIZE", "HGET",
+    "HMGET", "HGETALL", "HKEYS", "HVALS", "HLEN", "HEXISTS", "HSCAN", "LRANGE", "LLEN", "LINDEX", "SMEMBERS",
+    "SCARD", "SISMEMBER", "SSCAN", "ZRANGE", "ZRANGEBYSCORE", "ZREVRANGE", "ZCARD", "ZSCORE", "ZRANK", "ZSCAN",
+    "XRANGE", "XREVRANGE", "XLEN", "XINFO", "INFO", "PING", "TIME", "MEMORY", "OBJECT",
+];
+
+fn refuse(config: &ConnectionConfig) -> AppError {
+    AppError::ValidationError(format!("Connection '{}' is read-only", config.name))
+}
+
+/// Fail if the connection is read-only
+pub fn check_change(config: &ConnectionConfig) -> AppResult<()> {
+    if config.is_read_only() {
+        return Err(refuse(config));
+    }
+    Ok(())
+}
+
+/// Whether `sql` (or the MongoDB or Redis command) cannot change anything
+fn reads(database_type: &DatabaseType, sql: &str) -> bool {
+    match database_type {
+        // MongoDB runs the command named by the first key, so only that key
+        // counts; aggregation stages that write are refused too
+        DatabaseType::MongoDB => serde_json::from_str::<serde_json::Value>(sql.trim()).ok().is_some_and(|command| {
+            let text = command.to_string();
+            command
+                .as_object()
+                .and_then(|c| c.keys().next())
+                .is_some_and(|k| MONGO_READ_COMMANDS.contains(&k.as_str()))
+                && !text.contains("\"$out\"")
+                && !text.contains("\"$merge\"")
+        }),
+        DatabaseType::Redis => sql
+            .split_whitespace()
+            .next()
+            .is_some_and(|name| REDIS_READ_COMMANDS.iter().any(|c| c.eq_ignore_ascii_case(name))),
+        _ => sql_parse::is_read_only(sql, Dialect::from(database_type)),
+    }
+}
+
+/// Whether `sql` is a single statement (or MongoDB or Redis command) that
+/// cannot change anything
+pub fn is_single_read(database_type: &DatabaseType, sql: &str) -> bool {
+    match database_type {
+        DatabaseType::MongoDB | DatabaseType::Redis => reads(database_type, sql),
+        _ => sql_parse::split_statements(sql, Dialect::from(database_type)).len() == 1 && reads(database_type, sql),
+    }
+}
+
+/// Fail if the connection is read-only and `sql` (or the MongoDB or Redis
+/// command) could change anything
+pub fn check_query(config: &ConnectionConfig, sql: &str) -> AppResult<()> {
+    if !config.is_read_only() {
+        return Ok(());
+    }
+
+    if reads(&config.database_type, sql) {
+        Ok(())
+    } else {
+        Err(refuse(config))
+    }
+}
@@ -0,0 +1,327 @@
+//! Rows of large query results, kept in the backend so the result grid can
+//! fetch the window it shows instead of receiving every row at once. Rows are
+//! kept in memory as they arrive until they pass the memory threshold; from
+//! then on they are written to a temporary SQLite file and read back a window
+//! at a time. Only the latest few results are kept; older
+//! ones are dropped with their files.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{ColumnInfo, ResultWindow};
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions, SqliteSynchronous};
+use std::fs;
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// Estimated size above which a result's rows go to a temporary file
+const MEMORY_THRESHOLD_BYTES: usize = 64 * 1024 * 1024;
+
+/// Results kept at once; storing another drops the oldest
+const MAX_RESULTS: usize = 8;
+
+/// Most rows returned by one window
+pub const MAX_WINDOW_ROWS: usize = 10_000;
+
+/// Rows written per INSERT when spilling
+const INSERT_BATCH_ROWS: usize = 500;
+
+enum Rows {
+    Memory(Vec<Vec<Value>>),
+    Spilled { pool: SqlitePool, path: PathBuf },
+}
+
+struct StoredResult {
+    id: String,
+    columns: Vec<ColumnInfo>,
+    rows: Rows,
+    total_rows: usize,
+}
+
+impl Rows {
+    async fn discard(self) {
+        if let Rows::Spilled { pool, path } = self {
+            pool.close().await;
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Stored results, oldest first
+static RESULTS: OnceCell<Mutex<Vec<StoredResult>>> = OnceCell::new();
+
+fn results() -> &'static Mutex<Vec<StoredResult>> {
+    RESULTS.get_or_init(|| {
+        // Files left behind when the app last quit
+        let _ = fs::remove_dir_all(spill_dir());
+        Mutex::new(Vec::new())
+    })
+}
+
+fn spill_dir() -> PathBuf {
+    std::env::temp_dir().join("dbfordevs-results")
+}
+
+/// A rough size of the rows in memory, enough to decide whether to spill
+fn estimated_size(rows: &[Vec<Value>]) -> usize {
+    fn value_size(value: &Value) -> usize {
+        match value {
+            Value::String(text) => 24 + text.len(),
+            Value::Array(items) => 24 + items.iter().map(value_size).sum::<usize>(),
+            Value::Object(map) => 48 + map.iter().map(|(key, value)| key.len() + value_size(value)).sum::<usize>(),
+            _ => 16,
+        }
+    }
+    rows.iter().map(|row| 24 + row.iter().map(value_size).sum::<usize>()).sum()
+}
+
+fn spill_error(e: sqlx::Error) -> AppError {
+    AppError::Internal(format!("Failed to write the result to a temporary file: {}", e))
+}
+
+/// Move rows to a new SQLite file, one JSON array per row in rowid order
+async fn spill(id: &str, rows: Vec<Vec<Value>>) -> AppResult<Rows> {
+    // Clear the files of the last run first, so they are not cleared after this one is written
+    results();
+    fs::create_dir_all(spill_dir())?;
+    let path = spill_dir().join(format!("{}.sqlite", id));
+    let options = SqliteConnectOptions::new()
+        .filename(&path)
+        .create_if_missing(true)
+        // A scratch file: losing it in a crash loses nothing
+        .journal_mode(SqliteJournalMode::Off)
+        .synchronous(SqliteSynchronous::Off);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(spill_error)?;
+
+    let written: AppResult<()> = async {
+        sqlx::query("CREATE TABLE result_rows (data TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .map_err(spill_error)?;
+        append(&pool, &rows).await
+    }
+    .await;
+
+    if let Err(e) = written {
+        pool.close().await;
+        let _ = fs::remove_file(&path);
+        return Err(e);
+    }
+    Ok(Rows::Spilled { pool, path })
+}
+
+/// Add rows to the end of a spilled result
+async fn append(pool: &SqlitePool, rows: &[Vec<Value>]) -> AppResult<()> {
+    let mut tx = pool.begin().await.map_err(spill_error)?;
+    for batch in rows.chunks(INSERT_BATCH_ROWS) {
+        let placeholders = vec!["(?)"; batch.len()].join(", ");
+        let sql = format!("INSERT INTO result_rows (data) VALUES {}", placeholders);
+        let mut query = sqlx::query(&sql);
+        for row in batch {
+            query = query.bind(serde_json::to_string(row)?);
+        }
+        query.execute(&mut *tx).await.map_err(spill_error)?;
+    }
+    tx.commit().await.map_err(spill_error)
+}
+
+/// A result's rows as a query reads them: kept in memory until they pass
+/// the threshold, then written to a temporary file batch by batch
+pub struct ResultWriter {
+    id: String,
+    columns: Vec<ColumnInfo>,
+    rows: Rows,
+    size: usize,
+    total_rows: usize,
+}
+
+impl ResultWriter {
+    pub fn new() -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            columns: Vec::new(),
+            rows: Rows::Memory(Vec::new()),
+            size: 0,
+            total_rows: 0,
+        }
+    }
+
+    pub fn columns(&self) -> &[ColumnInfo] {
+        &self.columns
+    }
+
+    pub fn total_rows(&self) -> usize {
+        self.total_rows
+    }
+
+    /// Add a batch of rows, with the result's columns
+    pub async fn push(&mut self, columns: &[ColumnInfo], batch: Vec<Vec<Value>>) -> AppResult<()> {
+        if self.columns.is_empty() {
+            self.columns = columns.to_vec();
+        }
+        self.total_rows += batch.len();
+        let rows = match &mut self.rows {
+            Rows::Spilled { pool, .. } => return append(pool, &batch).await,
+            Rows::Memory(rows) => rows,
+        };
+        self.size += estimated_size(&batch);
+        rows.extend(batch);
+        if self.size > MEMORY_THRESHOLD_BYTES {
+            tracing::debug!(result_id = %self.id, rows = self.total_rows, "Writing a large result to a temporary file");
+            let rows = std::mem::take(rows);
+            self.rows = spill(&self.id, rows).await?;
+        }
+        Ok(())
+    }
+
+    /// Keep the result and return its id. The oldest result is dropped when
+    /// too many are kept.
+    pub async fn finish(self) -> AppResult<String> {
+        let evicted = {
+            let mut results = results().lock().await;
+            results.push(StoredResult {
+                id: self.id.clone(),
+                columns: self.columns,
+                rows: self.rows,
+                total_rows: self.total_rows,
+            });
+            let excess = results.len().saturating_sub(MAX_RESULTS);
+            results.drain(..excess).collect::<Vec<_>>()
+        };
+        for result in evicted {
+            result.rows.discard().await;
+        }
+        Ok(self.id)
+    }
+
+    /// Drop the rows, e.g. when the query fails partway through
+    pub async fn discard(self) {
+        self.rows.discard().await;
+    }
+}
+
+/// Keep a result's rows and return its id, as `ResultWriter` does for rows
+/// read all at once
+pub async fn store(columns: Vec<ColumnInfo>, rows: Vec<Vec<Value>>) -> AppResult<String> {
+    let mut writer = ResultWriter::new();
+    writer.push(&columns, rows).await?;
+    writer.finish().await
+}
+
+/// Up to `count` rows of a stored result, starting at row `offset`
+pub async fn window(result_id: &str, offset: usize, count: usize) -> AppResult<ResultWindow> {
+    let count = count.min(MAX_WINDOW_ROWS);
+    let (total_rows, pool) = {
+        let results = results().lock().await;
+        let result = results
+            .iter()
+            .find(|result| result.id == result_id)
+            .ok_or_else(|| AppError::ValidationError("The result is no longer available; run the query again".to_string()))?;
+        match &result.rows {
+            Rows::Memory(rows) => {
+                return Ok(ResultWindow {
+                    result_id: result_id.to_string(),
+                    offset,
+                    total_rows: result.total_rows,
+                    rows: rows.iter().skip(offset).take(count).cloned().collect(),
+                });
+            }
+            // Read without the lock, so other results stay available meanwhile
+            Rows::Spilled { pool, .. } => (result.total_rows, pool.clone()),
+        }
+    };
+
+    let data: Vec<(String,)> = sqlx::query_as("SELECT data FROM result_rows WHERE rowid > ? ORDER BY rowid LIMIT ?")
+        .bind(offset as i64)
+        .bind(count as i64)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to read the result: {}", e)))?;
+    let rows = data
+        .into_iter()
+        .map(|(row,)| serde_json::from_str(&row))
+        .collect::<Result<Vec<Vec<Value>>, _>>()?;
+
+    Ok(ResultWindow {
+        result_id: result_id.to_string(),
+        offset,
+        total_rows,
+        rows,
+    })
+}
+
+/// One cell of a stored result, with its column
+pub async fn cell(result_id: &str, row: usize, column: usize) -> AppResult<(ColumnInfo, Value)> {
+    let info = {
+        let results = results().lock().await;
+        let result = results
+            .iter()
+            .find(|result| result.id == result_id)
+            .ok_or_else(|| AppError::ValidationError("The result is no longer available; run the query again".to_string()))?;
+        result.columns.get(column).cloned()
+    };
+    let info = info.ok_or_else(|| AppError::ValidationError(format!("The result has no column {}", column)))?;
+    let value = window(result_id, row, 1)
+        .await?
+        .rows
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::ValidationError(format!("The result has no row {}", row)))?
+        .into_iter()
+        .nth(column)
+        .unwrap_or(Value::Null);
+    Ok((info, value))
+}
+
+/// Columns of a stored result
+pub async fn columns(result_id: &str) -> AppResult<Vec<ColumnInfo>> {
+    let results = results().lock().await;
+    results
+        .iter()
+        .find(|result| result.id == result_id)
+        .map(|result| result.columns.clone())
+        .ok_or_else(|| AppError::ValidationError("The result is no longer available; run the query again".to_string()))
+}
+
+/// Every row of a stored result
+pub async fn all_rows(result_id: &str) -> AppResult<Vec<Vec<Value>>> {
+    let total_rows = {
+        let results = results().lock().await;
+        results
+            .iter()
+            .find(|result| result.id == result_id)
+            .map(|result| result.total_rows)
+            .ok_or_else(|| AppError::ValidationError("The result is no longer available; run the query again".to_string()))?
+    };
+    let mut rows = Vec::with_capacity(total_rows);
+    while rows.len() < total_rows {
+        let window = window(result_id, rows.len(), MAX_WINDOW_ROWS).await?;
+        if window.rows.is_empty() {
+            break;
+        }
+        rows.extend(window.rows);
+    }
+    Ok(rows)
+}
+
+/// Drop a stored result; returns false if it was not kept
+pub async fn release(result_id: &str) -> bool {
+    let removed = {
+        let mut results = results().lock().await;
+        results
+            .iter()
+            .position(|result| result.id == result_id)
+            .map(|index| results.remove(index))
+    };
+    match removed {
+        Some(result) => {
+            result.rows.discard().await;
+            true
+        }
+        None => false,
+    }
+}
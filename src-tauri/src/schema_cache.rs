@@ -0,0 +1,35 @@
+//! Table schemas per connection, kept from the last `get_all_table_schemas`
+//! so editor completions do not query the database on every keystroke.
+
+use crate::metrics;
+use crate::models::TableSchema;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Connection id -> schemas of every table
+static SCHEMAS: OnceCell<Mutex<HashMap<String, Arc<Vec<TableSchema>>>>> = OnceCell::new();
+
+fn schemas() -> &'static Mutex<HashMap<String, Arc<Vec<TableSchema>>>> {
+    SCHEMAS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn get(connection_id: &str) -> Option<Arc<Vec<TableSchema>>> {
+    let schemas = schemas().lock().unwrap_or_else(|e| e.into_inner());
+    let tables = schemas.get(connection_id).cloned();
+    metrics::record_cache_lookup(tables.is_some());
+    tables
+}
+
+/// Replace the cached schemas for a connection
+pub fn store(connection_id: &str, tables: Vec<TableSchema>) -> Arc<Vec<TableSchema>> {
+    let tables = Arc::new(tables);
+    let mut schemas = schemas().lock().unwrap_or_else(|e| e.into_inner());
+    schemas.insert(connection_id.to_string(), tables.clone());
+    tables
+}
+
+pub fn clear(connection_id: &str) {
+    let mut schemas = schemas().lock().unwrap_or_else(|e| e.into_inner());
+    schemas.remove(connection_id);
+}
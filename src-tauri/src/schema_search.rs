@@ -0,0 +1,320 @@
+//! Semantic search over a connection's tables and columns. Each table and
+//! column becomes a short document (its name split into words, its type and
+//! its comment) with an embedding, and a search ranks the documents by cosine
+//! similarity to the query's embedding.
+//!
+//! Embeddings come from the AI provider, computed by the frontend, or from the
+//! built-in `local` model, which hashes words and character trigrams and works
+//! offline. Each connection's index is kept in the app data directory as JSON
+//! metadata and a file of raw little-endian f32 vectors.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{ObjectComment, SchemaDocument, SchemaIndexInfo, SchemaSearchHit, TableSchema};
+use crate::storage;
+use chrono::{SecondsFormat, Utc};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+const INDEX_DIR: &str = "schema_index";
+
+/// Model name of the built-in embeddings
+pub const LOCAL_MODEL: &str = "local";
+
+/// Size of the built-in embeddings
+const LOCAL_DIMENSIONS: usize = 256;
+
+/// Results returned when the caller gives no limit
+const DEFAULT_LIMIT: usize = 20;
+
+/// What the JSON file holds; the vectors are in a separate file
+#[derive(Serialize, Deserialize)]
+struct IndexFile {
+    info: SchemaIndexInfo,
+    documents: Vec<SchemaDocument>,
+}
+
+struct SchemaIndex {
+    info: SchemaIndexInfo,
+    documents: Vec<SchemaDocument>,
+    /// One normalised vector per document, back to back
+    vectors: Vec<f32>,
+}
+
+/// Connection id -> index, loaded from disk on first use
+static INDEXES: OnceCell<Mutex<HashMap<String, Arc<SchemaIndex>>>> = OnceCell::new();
+
+fn indexes() -> &'static Mutex<HashMap<String, Arc<SchemaIndex>>> {
+    INDEXES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn index_paths(connection_id: &str) -> AppResult<(PathBuf, PathBuf)> {
+    if connection_id.is_empty() || !connection_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(AppError::ValidationError(format!("Invalid connection id '{}'", connection_id)));
+    }
+    let dir = storage::app_dir()?.join(INDEX_DIR);
+    fs::create_dir_all(&dir)?;
+    Ok((dir.join(format!("{}.json", connection_id)), dir.join(format!("{}.vec", connection_id))))
+}
+
+/// Lowercase words of an identifier, splitting snake_case and camelCase
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            words.push(std::mem::take(&mut current));
+            previous_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    words.push(current);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Table name without its schema, as words
+fn table_words(table: &str) -> String {
+    split_words(table.rsplit('.').next().unwrap_or(table)).join(" ")
+}
+
+/// One document for each table and each of its columns
+pub fn documents(tables: &[TableSchema], comments: &[ObjectComment]) -> Vec<SchemaDocument> {
+    let comment = |table: &str, column: Option<&str>| {
+        comments
+            .iter()
+            .find(|c| c.table == table && c.column.as_deref() == column)
+            .map(|c| c.comment.trim())
+            .filter(|c| !c.is_empty())
+    };
+
+    let mut documents = Vec::new();
+    for table in tables {
+        let name = table_words(&table.table_name);
+        let columns: Vec<String> = table.columns.iter().map(|c| split_words(&c.name).join(" ")).collect();
+        let mut text = format!("table {}", name);
+        if let Some(comment) = comment(&table.table_name, None) {
+            text.push_str(&format!(": {}", comment));
+        }
+        text.push_str(&format!(". columns: {}", columns.join(", ")));
+        documents.push(SchemaDocument {
+            table: table.table_name.clone(),
+            column: None,
+            text,
+        });
+
+        for (column, words) in table.columns.iter().zip(columns) {
+            let mut text = format!("column {} ({}) of table {}", words, column.data_type, name);
+            if let Some(comment) = comment(&table.table_name, Some(&column.name)) {
+                text.push_str(&format!(": {}", comment));
+            }
+            documents.push(SchemaDocument {
+                table: table.table_name.clone(),
+                column: Some(column.name.clone()),
+                text,
+            });
+        }
+    }
+    documents
+}
+
+/// FNV-1a, which unlike the std hasher is stable across releases, so saved
+/// local embeddings stay comparable
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Built-in embedding: words and their character trigrams hashed into a
+/// fixed-size vector. Catches shared words and spellings ("addr" and
+/// "address") but not synonyms.
+pub fn local_embedding(text: &str) -> Vec<f32> {
+    let mut vector = vec![0.0; LOCAL_DIMENSIONS];
+    let mut add = |feature: &str, weight: f32| {
+        let hash = fnv1a(feature);
+        let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[(hash % LOCAL_DIMENSIONS as u64) as usize] += sign * weight;
+    };
+
+    for word in split_words(text) {
+        // Fold simple plurals so "customers" matches "customer"
+        let word = match word.strip_suffix('s') {
+            Some(stem) if stem.len() > 2 && !stem.ends_with('s') => stem.to_string(),
+            _ => word,
+        };
+        add(&word, 1.0);
+        let padded: Vec<char> = format!(" {} ", word).chars().collect();
+        for trigram in padded.windows(3) {
+            add(&trigram.iter().collect::<String>(), 0.5);
+        }
+    }
+
+    normalize(&mut vector);
+    vector
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+}
+
+/// Store an index built with `model`, one vector per document
+pub fn save(
+    connection_id: &str,
+    model: &str,
+    documents: Vec<SchemaDocument>,
+    vectors: Vec<Vec<f32>>,
+) -> AppResult<SchemaIndexInfo> {
+    if documents.is_empty() {
+        return Err(AppError::ValidationError("The schema has no tables to index".to_string()));
+    }
+    if vectors.len() != documents.len() {
+        return Err(AppError::ValidationError(format!(
+            "Got {} embeddings for {} documents",
+            vectors.len(),
+            documents.len()
+        )));
+    }
+    let dimensions = vectors[0].len();
+    if dimensions == 0 || vectors.iter().any(|v| v.len() != dimensions) {
+        return Err(AppError::ValidationError("Embeddings must all have the same, non-zero size".to_string()));
+    }
+
+    let mut flat = Vec::with_capacity(dimensions * vectors.len());
+    for mut vector in vectors {
+        normalize(&mut vector);
+        flat.extend(vector);
+    }
+
+    let info = SchemaIndexInfo {
+        model: model.to_string(),
+        dimensions,
+        document_count: documents.len(),
+        created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+    let file = IndexFile { info, documents };
+
+    let (json_path, vector_path) = index_paths(connection_id)?;
+    let bytes: Vec<u8> = flat.iter().flat_map(|v| v.to_le_bytes()).collect();
+    fs::write(vector_path, bytes)?;
+    fs::write(json_path, serde_json::to_string(&file)?)?;
+
+    let index = Arc::new(SchemaIndex {
+        info: file.info.clone(),
+        documents: file.documents,
+        vectors: flat,
+    });
+    indexes().lock().unwrap_or_else(|e| e.into_inner()).insert(connection_id.to_string(), index);
+    Ok(file.info)
+}
+
+/// Index the documents with the built-in embeddings
+pub fn build_local(connection_id: &str, documents: Vec<SchemaDocument>) -> AppResult<SchemaIndexInfo> {
+    let vectors = documents.iter().map(|document| local_embedding(&document.text)).collect();
+    save(connection_id, LOCAL_MODEL, documents, vectors)
+}
+
+fn load(connection_id: &str) -> AppResult<Option<Arc<SchemaIndex>>> {
+    if let Some(index) = indexes().lock().unwrap_or_else(|e| e.into_inner()).get(connection_id) {
+        return Ok(Some(index.clone()));
+    }
+
+    let (json_path, vector_path) = index_paths(connection_id)?;
+    if !json_path.exists() || !vector_path.exists() {
+        return Ok(None);
+    }
+    let file: IndexFile = serde_json::from_str(&fs::read_to_string(json_path)?)?;
+    let vectors: Vec<f32> = fs::read(vector_path)?
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect();
+    if vectors.len() != file.info.dimensions * file.documents.len() {
+        return Err(AppError::ConfigError("The schema index is damaged; rebuild it".to_string()));
+    }
+
+    let index = Arc::new(SchemaIndex {
+        info: file.info,
+        documents: file.documents,
+        vectors,
+    });
+    indexes().lock().unwrap_or_else(|e| e.into_inner()).insert(connection_id.to_string(), index.clone());
+    Ok(Some(index))
+}
+
+/// The connection's index, if one has been built
+pub fn info(connection_id: &str) -> AppResult<Option<SchemaIndexInfo>> {
+    Ok(load(connection_id)?.map(|index| index.info.clone()))
+}
+
+/// Tables and columns closest to the query, best first. Indexes built with a
+/// provider's model need `query_vector`, the query embedded with that model;
+/// local indexes embed the query here.
+pub fn search(
+    connection_id: &str,
+    query: &str,
+    model: Option<&str>,
+    query_vector: Option<Vec<f32>>,
+    limit: Option<usize>,
+) -> AppResult<Vec<SchemaSearchHit>> {
+    let index = load(connection_id)?
+        .ok_or_else(|| AppError::ValidationError("Index the schema before searching it".to_string()))?;
+
+    let mut query_vector = if index.info.model == LOCAL_MODEL {
+        local_embedding(query)
+    } else {
+        match (model, query_vector) {
+            (Some(model), Some(vector)) if model == index.info.model => vector,
+            _ => {
+                return Err(AppError::ValidationError(format!(
+                    "The schema index was built with {}; rebuild it to search with the current model",
+                    index.info.model
+                )))
+            }
+        }
+    };
+    if query_vector.len() != index.info.dimensions {
+        return Err(AppError::ValidationError(format!(
+            "The query embedding has {} dimensions but the index has {}",
+            query_vector.len(),
+            index.info.dimensions
+        )));
+    }
+    normalize(&mut query_vector);
+
+    let mut hits: Vec<SchemaSearchHit> = index
+        .documents
+        .iter()
+        .zip(index.vectors.chunks_exact(index.info.dimensions))
+        .map(|(document, vector)| SchemaSearchHit {
+            table: document.table.clone(),
+            column: document.column.clone(),
+            text: document.text.clone(),
+            score: vector.iter().zip(&query_vector).map(|(a, b)| a * b).sum(),
+        })
+        .collect();
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    hits.truncate(limit.unwrap_or(DEFAULT_LIMIT));
+    Ok(hits)
+}
+
+/// Remove the connection's index, for example when its connection is deleted
+pub fn clear(connection_id: &str) -> AppResult<()> {
+    indexes().lock().unwrap_or_else(|e| e.into_inner()).remove(connection_id);
+    let (json_path, vector_path) = index_paths(connection_id)?;
+    for path in [json_path, vector_path] {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,477 @@
+use super::{is_keyword, tokenize, Dialect, Token, TokenKind, KEYWORDS};
+use crate::models::{CompletionKind, SqlCompletion, TableSchema};
+use std::collections::HashSet;
+
+/// Most candidates returned for one request
+const MAX_COMPLETIONS: usize = 100;
+
+/// Keywords that can start a statement
+const STATEMENT_KEYWORDS: &[&str] = &[
+    "SELECT", "INSERT", "UPDATE", "DELETE", "WITH", "CREATE", "ALTER", "DROP", "TRUNCATE", "EXPLAIN", "BEGIN",
+    "COMMIT", "ROLLBACK",
+];
+
+/// Keywords that can follow a table reference
+const AFTER_TABLE_KEYWORDS: &[&str] = &[
+    "AS", "WHERE", "JOIN", "INNER", "LEFT", "RIGHT", "FULL", "CROSS", "ON", "USING", "GROUP", "ORDER", "HAVING",
+    "LIMIT", "SET", "VALUES", "SELECT", "UNION", "RETURNING",
+];
+
+/// Keywords followed by a table name
+const TABLE_KEYWORDS: &[&str] = &["FROM", "JOIN", "INTO", "UPDATE", "TABLE"];
+
+/// Keywords that start a clause, used to tell what a comma separates
+const CLAUSE_KEYWORDS: &[&str] = &[
+    "WITH", "SELECT", "FROM", "JOIN", "ON", "USING", "WHERE", "GROUP", "HAVING", "ORDER", "LIMIT", "SET", "INTO",
+    "UPDATE", "TABLE", "VALUES", "RETURNING",
+];
+
+/// What the text at the cursor completes
+enum Context {
+    /// Start of a statement
+    Statement,
+    /// Table name after FROM, JOIN, INTO, ...
+    Table,
+    /// After a table reference, where an alias or the next clause goes
+    AfterTable,
+    /// A new name, such as an alias after AS; nothing to suggest
+    NewName,
+    /// Member of the schema, table or alias before the dot
+    Qualified(String),
+    /// Select list, condition or other expression
+    Expression,
+}
+
+/// A table, CTE or derived table named in the statement
+struct TableRef<'a> {
+    /// Name as written, unquoted
+    name: String,
+    alias: Option<String>,
+    /// Matching cached table; None for CTEs, subqueries and unknown tables
+    table: Option<&'a TableSchema>,
+}
+
+/// Clause state of one paren level while walking a statement
+#[derive(Default)]
+struct Frame {
+    clause: Option<&'static str>,
+    /// Paren opened where a table goes, so it is a derived table
+    derived: bool,
+}
+
+struct Completions {
+    prefix: String,
+    /// Lower-case keywords when the user types in lower case
+    lower_keywords: bool,
+    dialect: Dialect,
+    items: Vec<(u32, SqlCompletion)>,
+    seen: HashSet<(String, u8)>,
+}
+
+/// Completion candidates for the cursor at 1-based `line` and `column`, best
+/// first. Only the statement around the cursor is considered, and columns come
+/// from the tables it references.
+pub fn complete_sql(
+    sql: &str,
+    line: usize,
+    column: usize,
+    dialect: Dialect,
+    tables: &[TableSchema],
+) -> Vec<SqlCompletion> {
+    let cursor = offset_of(sql, line, column);
+    let tokens = tokenize(sql, dialect);
+
+    if tokens.iter().any(|t| contains_cursor(t, cursor)) {
+        return Vec::new();
+    }
+
+    // Tokens of the statement around the cursor
+    let start = tokens
+        .iter()
+        .rposition(|t| t.kind == TokenKind::Semicolon && t.offset < cursor)
+        .map_or(0, |i| i + 1);
+    let end = tokens[start..]
+        .iter()
+        .position(|t| t.kind == TokenKind::Semicolon)
+        .map_or(tokens.len(), |i| start + i);
+    let statement: Vec<Token> = tokens[start..end].iter().filter(|t| !t.is_comment()).cloned().collect();
+
+    // The word being typed, if the cursor is at the end of (or inside) one
+    let typed = statement
+        .iter()
+        .position(|t| t.kind == TokenKind::Word && t.offset < cursor && cursor <= t.offset + t.text.len());
+    let prefix = typed.map_or(String::new(), |i| sql[statement[i].offset..cursor].to_string());
+    let before_end = typed.unwrap_or_else(|| statement.iter().take_while(|t| t.offset < cursor).count());
+    let before = &statement[..before_end];
+
+    let (refs, ctes) = table_refs(&statement, tables);
+
+    let mut completions = Completions {
+        lower_keywords: !prefix.is_empty() && prefix.chars().all(|c| !c.is_uppercase()),
+        prefix,
+        dialect,
+        items: Vec::new(),
+        seen: HashSet::new(),
+    };
+
+    match context(before) {
+        Context::Statement => completions.keywords(STATEMENT_KEYWORDS, 100),
+        Context::Table => {
+            for cte in &ctes {
+                completions.add(cte, CompletionKind::Alias, Some("CTE".to_string()), completions.identifier(cte), 300);
+            }
+            completions.tables(tables, 200);
+            completions.schemas(tables, 150);
+        }
+        Context::AfterTable => completions.keywords(AFTER_TABLE_KEYWORDS, 100),
+        Context::NewName => {}
+        Context::Qualified(qualifier) => {
+            // A qualifier naming a CTE or subquery has no known columns and
+            // falls through to the schema and table checks
+            if let Some(table) = refs.iter().find(|r| r.is_named(&qualifier)).and_then(|r| r.table) {
+                completions.columns(table, 300);
+            } else if tables.iter().any(|t| in_schema(t, &qualifier)) {
+                for table in tables.iter().filter(|t| in_schema(t, &qualifier)) {
+                    let name = bare_name(&table.table_name);
+                    let insert_text = completions.identifier(name);
+                    completions.add(name, CompletionKind::Table, Some(qualifier.clone()), insert_text, 300);
+                }
+            } else if let Some(table) = find_table(tables, &qualifier) {
+                completions.columns(table, 300);
+            }
+        }
+        Context::Expression => {
+            for table in refs.iter().filter_map(|r| r.table) {
+                completions.columns(table, 300);
+            }
+            for table_ref in &refs {
+                let name = table_ref.alias.as_deref().unwrap_or_else(|| bare_name(&table_ref.name));
+                let detail = table_ref.alias.as_ref().map(|_| table_ref.name.clone());
+                completions.add(name, CompletionKind::Alias, detail, completions.identifier(name), 250);
+            }
+            completions.tables(tables, 150);
+            completions.keywords(KEYWORDS, 100);
+        }
+    }
+
+    completions.finish()
+}
+
+impl TableRef<'_> {
+    fn is_named(&self, name: &str) -> bool {
+        match &self.alias {
+            Some(alias) => alias.eq_ignore_ascii_case(name),
+            None => self.name.eq_ignore_ascii_case(name) || bare_name(&self.name).eq_ignore_ascii_case(name),
+        }
+    }
+}
+
+impl Completions {
+    /// Add a candidate if it matches the prefix. Prefix matches rank above
+    /// matches elsewhere in the label, and an exact match ranks highest.
+    fn add(&mut self, label: &str, kind: CompletionKind, detail: Option<String>, insert_text: String, base: u32) {
+        let label_lower = label.to_lowercase();
+        let prefix = self.prefix.to_lowercase();
+        let bonus = if label_lower == prefix {
+            30
+        } else if label_lower.starts_with(&prefix) {
+            20
+        } else if prefix.len() >= 2 && label_lower.contains(&prefix) {
+            0
+        } else {
+            return;
+        };
+
+        if !self.seen.insert((label_lower, kind as u8)) {
+            return;
+        }
+        self.items.push((
+            base + bonus,
+            SqlCompletion {
+                label: label.to_string(),
+                kind,
+                detail,
+                insert_text,
+            },
+        ));
+    }
+
+    fn keywords(&mut self, keywords: &[&str], base: u32) {
+        for keyword in keywords {
+            let keyword = if self.lower_keywords {
+                keyword.to_lowercase()
+            } else {
+                keyword.to_string()
+            };
+            self.add(&keyword, CompletionKind::Keyword, None, keyword.clone(), base);
+        }
+    }
+
+    fn tables(&mut self, tables: &[TableSchema], base: u32) {
+        for table in tables {
+            let name = bare_name(&table.table_name);
+            // Tables in the default schema go in unqualified unless another schema has the same name
+            let unqualified = schema_of(&table.table_name).is_none_or(|s| ["public", "dbo", "main"].contains(&s))
+                && tables.iter().filter(|t| bare_name(&t.table_name) == name).count() == 1;
+            let insert_text = if unqualified {
+                self.identifier(name)
+            } else {
+                table.table_name.split('.').map(|part| self.identifier(part)).collect::<Vec<_>>().join(".")
+            };
+            let detail = schema_of(&table.table_name).map(str::to_string);
+            self.add(name, CompletionKind::Table, detail, insert_text, base);
+        }
+    }
+
+    fn schemas(&mut self, tables: &[TableSchema], base: u32) {
+        for schema in tables.iter().filter_map(|t| schema_of(&t.table_name)) {
+            self.add(schema, CompletionKind::Schema, None, self.identifier(schema), base);
+        }
+    }
+
+    fn columns(&mut self, table: &TableSchema, base: u32) {
+        for column in &table.columns {
+            let detail = format!("{} · {}", column.data_type, bare_name(&table.table_name));
+            let insert_text = self.identifier(&column.name);
+            self.add(&column.name, CompletionKind::Column, Some(detail), insert_text, base);
+        }
+    }
+
+    /// `name`, quoted if it is not a plain identifier the database would keep as is
+    fn identifier(&self, name: &str) -> String {
+        let plain = name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+        // Postgres folds unquoted names to lower case
+        let folded = self.dialect == Dialect::Postgres && name.chars().any(|c| c.is_uppercase());
+        if plain && !folded && !is_keyword(name) {
+            return name.to_string();
+        }
+        match self.dialect {
+            Dialect::MySql => format!("`{}`", name.replace('`', "``")),
+            Dialect::Mssql => format!("[{}]", name.replace(']', "]]")),
+            _ => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    fn finish(mut self) -> Vec<SqlCompletion> {
+        self.items.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .cmp(a_score)
+                .then(a.label.len().cmp(&b.label.len()))
+                .then_with(|| a.label.cmp(&b.label))
+        });
+        self.items.into_iter().take(MAX_COMPLETIONS).map(|(_, item)| item).collect()
+    }
+}
+
+/// Decide what to complete from the statement tokens before the cursor
+fn context(before: &[Token]) -> Context {
+    let Some(last) = before.last() else {
+        return Context::Statement;
+    };
+    if last.kind == TokenKind::Dot {
+        return match before.len().checked_sub(2).map(|i| &before[i]) {
+            Some(t) if matches!(t.kind, TokenKind::Word | TokenKind::QuotedIdentifier) => {
+                Context::Qualified(unquote(&t.text))
+            }
+            _ => Context::Expression,
+        };
+    }
+
+    let mut frames = vec![Frame::default()];
+    for token in before {
+        walk(&mut frames, token);
+    }
+    let clause = frames.last().and_then(|f| f.clause);
+    let in_table_clause = clause.is_some_and(|c| TABLE_KEYWORDS.contains(&c));
+
+    match last.kind {
+        TokenKind::Word if TABLE_KEYWORDS.iter().any(|k| last.is_word(k)) => Context::Table,
+        TokenKind::Comma if clause == Some("FROM") => Context::Table,
+        TokenKind::Word if in_table_clause && last.is_word("AS") => Context::NewName,
+        TokenKind::Word | TokenKind::Comma if clause == Some("WITH") => Context::NewName,
+        TokenKind::Word | TokenKind::QuotedIdentifier | TokenKind::CloseParen
+            if in_table_clause && !is_keyword(&last.text) =>
+        {
+            Context::AfterTable
+        }
+        _ => Context::Expression,
+    }
+}
+
+/// Track the clause of each paren level
+fn walk(frames: &mut Vec<Frame>, token: &Token) {
+    match token.kind {
+        TokenKind::OpenParen => frames.push(Frame::default()),
+        TokenKind::CloseParen if frames.len() > 1 => {
+            frames.pop();
+        }
+        TokenKind::Word => {
+            if let Some(clause) = CLAUSE_KEYWORDS.iter().find(|k| token.is_word(k)) {
+                if let Some(frame) = frames.last_mut() {
+                    frame.clause = Some(*clause);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Tables referenced anywhere in the statement, and the names of its CTEs
+fn table_refs<'a>(tokens: &[Token], tables: &'a [TableSchema]) -> (Vec<TableRef<'a>>, Vec<String>) {
+    let mut refs = Vec::new();
+    let mut ctes = Vec::new();
+    let mut frames = vec![Frame::default()];
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = &tokens[i];
+        let clause = frames.last().and_then(|f| f.clause);
+        let starts_ref = match token.kind {
+            TokenKind::Word => TABLE_KEYWORDS.iter().any(|k| token.is_word(k)),
+            TokenKind::Comma => clause == Some("FROM"),
+            _ => false,
+        };
+        if clause == Some("WITH") && is_identifier(token) && tokens.get(i + 1).is_some_and(|t| t.is_word("AS")) {
+            ctes.push(unquote(&token.text));
+        }
+
+        // A subquery in FROM is a table named by its alias
+        let closes_derived =
+            token.kind == TokenKind::CloseParen && frames.len() > 1 && frames.last().is_some_and(|f| f.derived);
+        walk(&mut frames, token);
+        i += 1;
+
+        if closes_derived {
+            let (alias, next) = read_alias(tokens, i);
+            if let Some(alias) = alias {
+                refs.push(TableRef {
+                    name: alias,
+                    alias: None,
+                    table: None,
+                });
+            }
+            i = next;
+            continue;
+        }
+        if !starts_ref {
+            continue;
+        }
+        if tokens.get(i).is_some_and(|t| t.kind == TokenKind::OpenParen) {
+            walk(&mut frames, &tokens[i]);
+            if let Some(frame) = frames.last_mut() {
+                frame.derived = true;
+            }
+            i += 1;
+            continue;
+        }
+
+        // schema.table
+        let mut parts = Vec::new();
+        while let Some(part) = tokens.get(i).filter(|t| is_identifier(t)) {
+            parts.push(unquote(&part.text));
+            i += 1;
+            if tokens.get(i).is_some_and(|t| t.kind == TokenKind::Dot) {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        if parts.is_empty() {
+            continue;
+        }
+        let name = parts.join(".");
+        let (alias, next) = read_alias(tokens, i);
+        i = next;
+
+        let table = if ctes.iter().any(|c| c.eq_ignore_ascii_case(&name)) {
+            None
+        } else {
+            find_table(tables, &name)
+        };
+        refs.push(TableRef { name, alias, table });
+    }
+
+    (refs, ctes)
+}
+
+/// `[AS] alias` starting at `i`, and the index after it
+fn read_alias(tokens: &[Token], mut i: usize) -> (Option<String>, usize) {
+    if tokens.get(i).is_some_and(|t| t.is_word("AS")) {
+        i += 1;
+    }
+    match tokens.get(i).filter(|t| is_identifier(t)) {
+        Some(alias) => (Some(unquote(&alias.text)), i + 1),
+        None => (None, i),
+    }
+}
+
+/// A name rather than a keyword
+fn is_identifier(token: &Token) -> bool {
+    token.kind == TokenKind::QuotedIdentifier || (token.kind == TokenKind::Word && !is_keyword(&token.text))
+}
+
+/// Whether the cursor is inside a string, comment or quoted identifier
+fn contains_cursor(token: &Token, cursor: usize) -> bool {
+    let end = token.offset + token.text.len();
+    match token.kind {
+        TokenKind::LineComment => token.offset < cursor && cursor <= end,
+        // An unterminated string runs to the end of the input
+        TokenKind::String if cursor == end => {
+            token.text.starts_with('\'') && (token.text.len() == 1 || !token.text.ends_with('\''))
+        }
+        TokenKind::String | TokenKind::BlockComment | TokenKind::QuotedIdentifier => {
+            token.offset < cursor && cursor < end
+        }
+        _ => false,
+    }
+}
+
+fn unquote(name: &str) -> String {
+    let quoted = [('"', '"'), ('`', '`'), ('[', ']')]
+        .iter()
+        .any(|&(open, close)| name.len() >= 2 && name.starts_with(open) && name.ends_with(close));
+    if quoted {
+        name[1..name.len() - 1].to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Cached table matching a name as written, qualified or not
+fn find_table<'a>(tables: &'a [TableSchema], name: &str) -> Option<&'a TableSchema> {
+    tables
+        .iter()
+        .find(|t| t.table_name.eq_ignore_ascii_case(name))
+        .or_else(|| tables.iter().find(|t| bare_name(&t.table_name).eq_ignore_ascii_case(name)))
+}
+
+fn bare_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn schema_of(name: &str) -> Option<&str> {
+    name.rsplit_once('.').map(|(schema, _)| schema)
+}
+
+fn in_schema(table: &TableSchema, schema: &str) -> bool {
+    schema_of(&table.table_name).is_some_and(|s| s.eq_ignore_ascii_case(schema))
+}
+
+/// Byte offset of a 1-based line and column, clamped to the text
+fn offset_of(sql: &str, line: usize, column: usize) -> usize {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        match sql.match_indices('\n').nth(line - 2) {
+            Some((i, _)) => i + 1,
+            None => return sql.len(),
+        }
+    };
+    let line_text = sql[line_start..].split('\n').next().unwrap_or("");
+    line_start
+        + line_text
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(line_text.len(), |(i, _)| i)
+}
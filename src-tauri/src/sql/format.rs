@@ -0,0 +1,478 @@
+use super::{is_keyword, tokenize, Dialect, Token, TokenKind};
+use crate::models::{CommaStyle, KeywordCase, SqlFormatOptions};
+
+/// Clauses that start a line and put their contents on indented lines below
+const BLOCK_CLAUSES: &[&[&str]] = &[
+    &["WITH"],
+    &["SELECT"],
+    &["FROM"],
+    &["WHERE"],
+    &["GROUP", "BY"],
+    &["HAVING"],
+    &["WINDOW"],
+    &["ORDER", "BY"],
+    &["SET"],
+    &["VALUES"],
+    &["RETURNING"],
+];
+
+/// Clauses that start a line and keep their contents on it
+const LINE_CLAUSES: &[&[&str]] = &[
+    &["INSERT", "INTO"],
+    &["UPDATE"],
+    &["DELETE", "FROM"],
+    &["UNION", "ALL"],
+    &["UNION"],
+    &["EXCEPT"],
+    &["INTERSECT"],
+    &["ON", "CONFLICT"],
+    &["LIMIT"],
+    &["OFFSET"],
+    &["FETCH"],
+];
+
+/// Joins start a new line inside FROM
+const JOINS: &[&[&str]] = &[
+    &["LEFT", "OUTER", "JOIN"],
+    &["RIGHT", "OUTER", "JOIN"],
+    &["FULL", "OUTER", "JOIN"],
+    &["LEFT", "JOIN"],
+    &["RIGHT", "JOIN"],
+    &["FULL", "JOIN"],
+    &["INNER", "JOIN"],
+    &["CROSS", "JOIN"],
+    &["NATURAL", "JOIN"],
+    &["CROSS", "APPLY"],
+    &["OUTER", "APPLY"],
+    &["JOIN"],
+];
+
+/// Words that stay on the clause keyword's line (`SELECT DISTINCT`, `WITH RECURSIVE`)
+const CLAUSE_MODIFIERS: &[&str] = &["DISTINCT", "ALL", "TOP", "RECURSIVE"];
+
+/// Keywords written like functions, with no space before `(`
+const CALL_KEYWORDS: &[&str] = &[
+    "CAST", "CHAR", "VARCHAR", "DECIMAL", "NUMERIC", "TIMESTAMP", "TIME", "DATE", "ANY", "ROW", "LEFT", "RIGHT",
+];
+
+/// Statements whose clauses are laid out; others only get spacing and casing
+const DML_STARTS: &[&str] = &["SELECT", "WITH", "INSERT", "UPDATE", "DELETE", "VALUES", "EXPLAIN"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Clause {
+    /// A block clause, by its first word
+    Block(&'static str),
+    /// A line clause, by its first word
+    Line(&'static str),
+    /// Column list of CREATE TABLE
+    Columns,
+}
+
+struct Paren {
+    /// Laid out over several lines (subquery or column list)
+    block: bool,
+    /// Indent of the line the paren opened on, where the closing paren goes
+    open_indent: usize,
+    base: usize,
+    clause: Option<Clause>,
+}
+
+struct Formatter<'a> {
+    sql: &'a str,
+    options: &'a SqlFormatOptions,
+    out: String,
+    line_indent: usize,
+    at_line_start: bool,
+    /// Indent of clause keywords in the current block
+    base: usize,
+    clause: Option<Clause>,
+    parens: Vec<Paren>,
+    /// A block clause keyword was just written; its contents go on the next line
+    pending_break: bool,
+    statement_start: bool,
+    /// The blank line before the next statement has been written
+    separated: bool,
+    /// First word of the current statement, upper case
+    statement: String,
+    dml: bool,
+    /// CREATE TABLE whose column list has not been opened yet
+    create_table: bool,
+    /// Inside `BETWEEN x AND y`, where AND must not start a line
+    in_between: bool,
+    prev: Option<Token>,
+    prev_prev: Option<Token>,
+    /// Byte offset just past the previous token
+    prev_end: usize,
+}
+
+/// Pretty-print SQL: one clause per line with its contents indented below, and
+/// top-level lists one item per line. Comments are kept and literals untouched.
+pub fn format_sql(sql: &str, dialect: Dialect, options: &SqlFormatOptions) -> String {
+    let tokens = tokenize(sql, dialect);
+    let mut formatter = Formatter {
+        sql,
+        options,
+        out: String::new(),
+        line_indent: 0,
+        at_line_start: true,
+        base: 0,
+        clause: None,
+        parens: Vec::new(),
+        pending_break: false,
+        statement_start: true,
+        separated: true,
+        statement: String::new(),
+        dml: false,
+        create_table: false,
+        in_between: false,
+        prev: None,
+        prev_prev: None,
+        prev_end: 0,
+    };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        i += formatter.token(&tokens, i);
+    }
+
+    let mut out = formatter.out.trim_end().to_string();
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+impl Formatter<'_> {
+    /// Write the token at `i` and return how many tokens were consumed
+    fn token(&mut self, tokens: &[Token], i: usize) -> usize {
+        let token = &tokens[i];
+
+        if token.is_comment() {
+            self.comment(token);
+            return 1;
+        }
+
+        if self.statement_start {
+            self.start_statement(token);
+        }
+        if token.is_word("SELECT") {
+            self.dml = true;
+        }
+        if token.is_word("TABLE") && self.statement == "CREATE" && self.parens.is_empty() {
+            self.create_table = true;
+        }
+
+        let consumed = match self.clause_phrase(tokens, i) {
+            Some(consumed) => consumed,
+            None => {
+                self.plain(tokens, i);
+                1
+            }
+        };
+
+        let last = &tokens[i + consumed - 1];
+        self.prev_end = last.offset + last.text.len();
+        self.prev_prev = self.prev.replace(last.clone());
+        consumed
+    }
+
+    fn start_statement(&mut self, token: &Token) {
+        self.separate_statement();
+        self.statement_start = false;
+        self.statement = token.text.to_ascii_uppercase();
+        self.dml = DML_STARTS.iter().any(|w| token.is_word(w));
+        self.create_table = false;
+    }
+
+    /// Clause keywords, joins and AND/OR that start a new line
+    fn clause_phrase(&mut self, tokens: &[Token], i: usize) -> Option<usize> {
+        if !self.dml || self.inline_depth() > 0 || tokens[i].kind != TokenKind::Word {
+            return None;
+        }
+        let width = self.options.indent_width;
+        let prev_is = |word: &str| self.prev.as_ref().is_some_and(|p| p.is_word(word));
+
+        if let Some(phrase) = match_phrase(tokens, i, BLOCK_CLAUSES) {
+            let excluded = match phrase[0] {
+                // CTEs only; not table hints or WITH TIES
+                "WITH" => !self
+                    .prev
+                    .as_ref()
+                    .is_none_or(|p| matches!(p.kind, TokenKind::Semicolon | TokenKind::OpenParen)),
+                "VALUES" => prev_is("DEFAULT"),
+                _ => false,
+            };
+            if !excluded {
+                self.newline(self.base);
+                self.words(&tokens[i..i + phrase.len()]);
+                self.clause = Some(Clause::Block(phrase[0]));
+                self.pending_break = true;
+                self.in_between = false;
+                return Some(phrase.len());
+            }
+        }
+
+        if let Some(phrase) = match_phrase(tokens, i, LINE_CLAUSES) {
+            // ON CONFLICT DO UPDATE and SELECT ... FOR UPDATE
+            let excluded = phrase[0] == "UPDATE" && (prev_is("DO") || prev_is("FOR"));
+            if !excluded {
+                self.newline(self.base);
+                self.words(&tokens[i..i + phrase.len()]);
+                self.clause = Some(Clause::Line(phrase[0]));
+                self.pending_break = false;
+                return Some(phrase.len());
+            }
+        }
+
+        if self.clause == Some(Clause::Block("FROM")) {
+            if let Some(phrase) = match_phrase(tokens, i, JOINS) {
+                self.newline(self.base + width);
+                self.words(&tokens[i..i + phrase.len()]);
+                return Some(phrase.len());
+            }
+        }
+
+        let token = &tokens[i];
+        if matches!(self.clause, Some(Clause::Block("WHERE" | "HAVING"))) {
+            if token.is_word("AND") && self.in_between {
+                self.in_between = false;
+            } else if token.is_word("AND") || token.is_word("OR") {
+                self.newline(self.base + width);
+                self.words(&tokens[i..=i]);
+                return Some(1);
+            }
+        }
+
+        None
+    }
+
+    /// Any token that is not a clause keyword or comment
+    fn plain(&mut self, tokens: &[Token], i: usize) {
+        let token = &tokens[i];
+        let width = self.options.indent_width;
+
+        if self.pending_break {
+            let modifier = token.kind == TokenKind::Word && CLAUSE_MODIFIERS.iter().any(|w| token.is_word(w));
+            let top_count = self.prev.as_ref().is_some_and(|p| p.is_word("TOP"));
+            if !modifier && !top_count {
+                self.newline(self.base + width);
+                self.pending_break = false;
+            }
+        }
+
+        match token.kind {
+            TokenKind::Semicolon => {
+                self.push(";", false);
+                self.parens.clear();
+                self.base = 0;
+                self.clause = None;
+                self.pending_break = false;
+                self.in_between = false;
+                self.statement_start = true;
+                self.separated = false;
+            }
+            TokenKind::Comma => {
+                let indent = match self.clause {
+                    _ if self.inline_depth() > 0 => None,
+                    Some(Clause::Block(_)) => Some(self.base + width),
+                    Some(Clause::Columns) => Some(self.base),
+                    _ => None,
+                };
+                match (indent, self.options.comma_style) {
+                    (Some(indent), CommaStyle::Trailing) => {
+                        self.push(",", false);
+                        self.newline(indent);
+                    }
+                    (Some(indent), CommaStyle::Leading) => {
+                        self.newline(indent);
+                        self.push(",", false);
+                    }
+                    (None, _) => self.push(",", false),
+                }
+            }
+            TokenKind::OpenParen => {
+                let space = self.space_before(token);
+                self.push("(", space);
+
+                let next = tokens[i + 1..].iter().find(|t| !t.is_comment());
+                let subquery = next.is_some_and(|t| t.is_word("SELECT") || t.is_word("WITH"));
+                let columns = self.create_table && self.parens.is_empty();
+                let block = subquery || columns;
+
+                self.parens.push(Paren {
+                    block,
+                    open_indent: self.line_indent,
+                    base: self.base,
+                    clause: self.clause,
+                });
+                if block {
+                    self.base = self.line_indent + width;
+                    self.clause = if columns { Some(Clause::Columns) } else { None };
+                    self.create_table = false;
+                    self.newline(self.base);
+                }
+            }
+            TokenKind::CloseParen => {
+                if let Some(paren) = self.parens.pop() {
+                    if paren.block {
+                        self.newline(paren.open_indent);
+                        self.base = paren.base;
+                        self.clause = paren.clause;
+                        self.pending_break = false;
+                    }
+                }
+                self.push(")", false);
+            }
+            TokenKind::Word if is_keyword(&token.text) => {
+                if token.is_word("BETWEEN") {
+                    self.in_between = true;
+                }
+                let space = self.space_before(token);
+                let word = self.keyword(&token.text);
+                self.push(&word, space);
+            }
+            _ => {
+                let space = self.space_before(token);
+                self.push(&token.text, space);
+            }
+        }
+    }
+
+    /// Leave a blank line after the previous statement
+    fn separate_statement(&mut self) {
+        if !self.separated && !self.out.is_empty() {
+            self.trim_line();
+            self.out.push_str("\n\n");
+            self.line_indent = 0;
+            self.at_line_start = true;
+        }
+        self.separated = true;
+    }
+
+    fn comment(&mut self, token: &Token) {
+        // Comments on their own line stay there
+        let own_line = self.sql[self.prev_end.min(token.offset)..token.offset].contains('\n') || self.out.is_empty();
+        if own_line && self.statement_start {
+            // Leading comment of the next statement
+            self.separate_statement();
+        }
+        if own_line && !self.at_line_start {
+            self.newline(self.line_indent);
+        }
+        self.push(token.text.trim_end(), !own_line);
+        self.prev_end = token.offset + token.text.len();
+        if token.kind == TokenKind::LineComment {
+            self.newline(self.line_indent);
+        }
+    }
+
+    /// Write the words of a phrase with a single space between them
+    fn words(&mut self, tokens: &[Token]) {
+        for (n, token) in tokens.iter().enumerate() {
+            let word = self.keyword(&token.text);
+            self.push(&word, n > 0);
+        }
+    }
+
+    fn keyword(&self, word: &str) -> String {
+        match self.options.keyword_case {
+            KeywordCase::Upper => word.to_ascii_uppercase(),
+            KeywordCase::Lower => word.to_ascii_lowercase(),
+            KeywordCase::Preserve => word.to_string(),
+        }
+    }
+
+    /// Parens opened since the innermost block paren
+    fn inline_depth(&self) -> usize {
+        self.parens.iter().rev().take_while(|p| !p.block).count()
+    }
+
+    fn space_before(&self, token: &Token) -> bool {
+        let Some(prev) = &self.prev else {
+            return false;
+        };
+        if matches!(
+            token.kind,
+            TokenKind::Comma | TokenKind::CloseParen | TokenKind::CloseBracket | TokenKind::Dot | TokenKind::Semicolon
+        ) || (token.kind == TokenKind::OpenBracket)
+        {
+            return false;
+        }
+        if matches!(prev.kind, TokenKind::OpenParen | TokenKind::OpenBracket | TokenKind::Dot) {
+            return false;
+        }
+        if token.text == "::" || prev.text == "::" {
+            return false;
+        }
+
+        if token.kind == TokenKind::OpenParen {
+            return match prev.kind {
+                TokenKind::Word if is_keyword(&prev.text) => !CALL_KEYWORDS.iter().any(|w| prev.is_word(w)),
+                // Table name before a column list, otherwise a function call
+                TokenKind::Word | TokenKind::QuotedIdentifier => {
+                    self.clause == Some(Clause::Line("INSERT"))
+                        || (!self.dml && self.parens.is_empty() && matches!(self.statement.as_str(), "CREATE" | "ALTER"))
+                }
+                _ => true,
+            };
+        }
+
+        // Unary minus and plus
+        if prev.kind == TokenKind::Operator && (prev.text == "-" || prev.text == "+") {
+            let operand_before = self.prev_prev.as_ref().is_some_and(|t| {
+                matches!(
+                    t.kind,
+                    TokenKind::Number
+                        | TokenKind::String
+                        | TokenKind::Parameter
+                        | TokenKind::QuotedIdentifier
+                        | TokenKind::CloseParen
+                        | TokenKind::CloseBracket
+                ) || (t.kind == TokenKind::Word && (!is_keyword(&t.text) || t.is_word("END")))
+            });
+            return operand_before;
+        }
+
+        true
+    }
+
+    fn push(&mut self, text: &str, space: bool) {
+        if space && !self.at_line_start {
+            self.out.push(' ');
+        }
+        self.out.push_str(text);
+        self.at_line_start = false;
+    }
+
+    /// Start a new line at `indent`, or re-indent the current line if it is empty
+    fn newline(&mut self, indent: usize) {
+        if self.out.is_empty() {
+            self.line_indent = indent;
+            self.out.push_str(&" ".repeat(indent));
+            self.at_line_start = true;
+            return;
+        }
+        if self.at_line_start {
+            let line_start = self.out.rfind('\n').map(|n| n + 1).unwrap_or(0);
+            self.out.truncate(line_start);
+        } else {
+            self.trim_line();
+            self.out.push('\n');
+        }
+        self.out.push_str(&" ".repeat(indent));
+        self.line_indent = indent;
+        self.at_line_start = true;
+    }
+
+    fn trim_line(&mut self) {
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+    }
+}
+
+/// The first phrase whose words start at `tokens[i]`
+fn match_phrase(tokens: &[Token], i: usize, phrases: &[&'static [&'static str]]) -> Option<&'static [&'static str]> {
+    phrases.iter().copied().find(|phrase| {
+        phrase.len() <= tokens.len() - i && phrase.iter().zip(&tokens[i..]).all(|(word, token)| token.is_word(word))
+    })
+}
@@ -0,0 +1,340 @@
+use super::{is_keyword, tokenize, Dialect, Token, TokenKind};
+use crate::models::{DiagnosticSeverity, SqlDiagnostic};
+
+const COMPARISON_OPERATORS: &[&str] = &["=", "<", ">", "<=", ">=", "<>", "!="];
+
+/// State of one query level: the statement, or the inside of a pair of parens
+#[derive(Default)]
+struct Scope {
+    /// Paren opened right after EXISTS, where `SELECT *` and `LIMIT 1` are idiomatic
+    in_exists: bool,
+    /// Current clause keyword, upper case
+    clause: Option<String>,
+    has_where: bool,
+    has_order_by: bool,
+    /// UPDATE or DELETE keyword
+    modification: Option<usize>,
+    /// LIMIT, TOP or FETCH keyword
+    limit: Option<usize>,
+    /// First comma between tables in FROM
+    from_comma: Option<usize>,
+    /// JOIN not yet followed by ON or USING
+    open_join: Option<usize>,
+}
+
+struct Linter<'a> {
+    sql: &'a str,
+    tokens: &'a [Token],
+    /// Index of the matching paren for each paren token
+    matching: Vec<Option<usize>>,
+    scopes: Vec<Scope>,
+    diagnostics: Vec<SqlDiagnostic>,
+}
+
+/// Check SQL for common mistakes without running it: `SELECT *`, UPDATE or
+/// DELETE without WHERE, predicates that cannot use an index, implicit cross
+/// joins and LIMIT without ORDER BY
+pub fn lint_sql(sql: &str, dialect: Dialect) -> Vec<SqlDiagnostic> {
+    let tokens: Vec<Token> = tokenize(sql, dialect).into_iter().filter(|t| !t.is_comment()).collect();
+
+    let mut matching = vec![None; tokens.len()];
+    let mut open = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::OpenParen => open.push(i),
+            TokenKind::CloseParen => {
+                if let Some(start) = open.pop() {
+                    matching[start] = Some(i);
+                    matching[i] = Some(start);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut linter = Linter {
+        sql,
+        tokens: &tokens,
+        matching,
+        scopes: vec![Scope::default()],
+        diagnostics: Vec::new(),
+    };
+    for i in 0..tokens.len() {
+        linter.visit(i);
+    }
+    while let Some(scope) = linter.scopes.pop() {
+        linter.finish(scope);
+    }
+
+    let mut diagnostics = linter.diagnostics;
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+impl Linter<'_> {
+    fn visit(&mut self, i: usize) {
+        let token = &self.tokens[i];
+        match token.kind {
+            TokenKind::OpenParen => {
+                let in_exists = self.word_at(i.wrapping_sub(1)) == Some("EXISTS".to_string());
+                self.scopes.push(Scope {
+                    in_exists,
+                    ..Scope::default()
+                });
+            }
+            // A stray closing paren leaves the statement scope in place
+            TokenKind::CloseParen if self.scopes.len() > 1 => {
+                let scope = self.scopes.pop().unwrap_or_default();
+                self.finish(scope);
+            }
+            TokenKind::Semicolon => {
+                while self.scopes.len() > 1 {
+                    let scope = self.scopes.pop().unwrap_or_default();
+                    self.finish(scope);
+                }
+                let scope = std::mem::take(self.scope());
+                self.finish(scope);
+            }
+            TokenKind::Comma => {
+                let scope = self.scope();
+                if scope.clause.as_deref() == Some("FROM") {
+                    scope.from_comma.get_or_insert(i);
+                }
+            }
+            TokenKind::Operator if token.text == "*" => self.select_star(i),
+            TokenKind::Word => self.word(i),
+            _ => {}
+        }
+    }
+
+    fn word(&mut self, i: usize) {
+        let word = self.tokens[i].text.to_ascii_uppercase();
+        let prev = self.word_at(i.wrapping_sub(1));
+        let next = self.word_at(i + 1);
+        let prev_is = |words: &[&str]| prev.as_deref().is_some_and(|p| words.contains(&p));
+
+        match word.as_str() {
+            "SELECT" | "FROM" | "SET" | "GROUP" | "HAVING" => self.enter_clause(&word),
+            "WHERE" => {
+                self.enter_clause(&word);
+                self.scope().has_where = true;
+            }
+            "ORDER" if next.as_deref() == Some("BY") => {
+                self.enter_clause(&word);
+                self.scope().has_order_by = true;
+            }
+            "LIMIT" => {
+                self.enter_clause(&word);
+                self.scope().limit.get_or_insert(i);
+            }
+            "TOP" if prev_is(&["SELECT", "DISTINCT", "ALL"]) => {
+                self.scope().limit.get_or_insert(i);
+            }
+            "FETCH" if matches!(next.as_deref(), Some("FIRST" | "NEXT")) => {
+                self.scope().limit.get_or_insert(i);
+            }
+            "JOIN" => {
+                self.close_join();
+                let scope = self.scope();
+                scope.clause = Some("FROM".to_string());
+                if !prev_is(&["CROSS", "NATURAL"]) {
+                    scope.open_join = Some(i);
+                }
+            }
+            "ON" | "USING" => {
+                let scope = self.scope();
+                scope.open_join = None;
+                scope.clause = Some("ON".to_string());
+            }
+            // Not ON DELETE CASCADE, ON CONFLICT DO UPDATE or SELECT ... FOR UPDATE
+            "UPDATE" | "DELETE" if !prev_is(&["ON", "DO", "FOR"]) => {
+                self.enter_clause(&word);
+                self.scope().modification.get_or_insert(i);
+            }
+            "LIKE" | "ILIKE" => self.leading_wildcard(i + 1),
+            _ => self.function_predicate(i),
+        }
+    }
+
+    fn enter_clause(&mut self, clause: &str) {
+        if clause != "FROM" {
+            self.close_join();
+        }
+        self.scope().clause = Some(clause.to_string());
+    }
+
+    /// `SELECT *` or `SELECT t.*`; `count(*)` is in its own scope with no clause
+    fn select_star(&mut self, i: usize) {
+        let scope = self.scopes.last().expect("root scope");
+        if scope.in_exists || scope.clause.as_deref() != Some("SELECT") {
+            return;
+        }
+        let starts_item = match self.tokens.get(i.wrapping_sub(1)) {
+            Some(prev) if matches!(prev.kind, TokenKind::Comma | TokenKind::Dot) => true,
+            Some(prev) => ["SELECT", "DISTINCT", "ALL"].iter().any(|w| prev.is_word(w)),
+            None => false,
+        };
+        if starts_item {
+            self.report(
+                i,
+                i,
+                "select-star",
+                DiagnosticSeverity::Info,
+                "SELECT * returns every column; list only the columns you need",
+            );
+        }
+    }
+
+    /// `LIKE '%abc'` cannot use an index
+    fn leading_wildcard(&mut self, i: usize) {
+        let Some(token) = self.tokens.get(i).filter(|t| t.kind == TokenKind::String) else {
+            return;
+        };
+        let pattern = token.text.trim_start_matches(|c: char| c != '\'');
+        if pattern.starts_with("'%") || pattern.starts_with("'_") {
+            self.report(
+                i,
+                i,
+                "leading-wildcard",
+                DiagnosticSeverity::Info,
+                "A LIKE pattern that starts with a wildcard cannot use an index",
+            );
+        }
+    }
+
+    /// `WHERE lower(email) = ...`: a function wrapping a column on the left of a
+    /// comparison stops the database from using an index on that column
+    fn function_predicate(&mut self, i: usize) {
+        let clause = self.scopes.last().and_then(|s| s.clause.as_deref());
+        if !matches!(clause, Some("WHERE" | "ON")) {
+            return;
+        }
+        let token = &self.tokens[i];
+        if is_keyword(&token.text) && !["LEFT", "RIGHT", "DATE", "CAST"].iter().any(|w| token.is_word(w)) {
+            return;
+        }
+        if self.tokens.get(i + 1).map(|t| t.kind) != Some(TokenKind::OpenParen) {
+            return;
+        }
+        let Some(close) = self.matching[i + 1] else {
+            return;
+        };
+
+        let compared = self.tokens.get(close + 1).is_some_and(|t| {
+            (t.kind == TokenKind::Operator && COMPARISON_OPERATORS.contains(&t.text.as_str()))
+                || ["LIKE", "ILIKE", "BETWEEN", "IN"].iter().any(|w| t.is_word(w))
+        });
+        let has_column = self.tokens[i + 2..close].iter().any(|t| {
+            t.kind == TokenKind::QuotedIdentifier || (t.kind == TokenKind::Word && !is_keyword(&t.text))
+        });
+
+        if compared && has_column {
+            self.report(
+                i,
+                close,
+                "non-sargable",
+                DiagnosticSeverity::Info,
+                "A function around a column in a condition prevents using an index on it; compare the column directly",
+            );
+        }
+    }
+
+    /// Report a JOIN that never got ON or USING
+    fn close_join(&mut self) {
+        if let Some(join) = self.scope().open_join.take() {
+            self.report_cross_join(join);
+        }
+    }
+
+    fn report_cross_join(&mut self, join: usize) {
+        self.report(
+            join,
+            join,
+            "implicit-cross-join",
+            DiagnosticSeverity::Warning,
+            "JOIN without ON or USING returns every combination of rows from both tables",
+        );
+    }
+
+    /// Checks that need the whole query level
+    fn finish(&mut self, scope: Scope) {
+        if let Some(join) = scope.open_join {
+            self.report_cross_join(join);
+        }
+
+        if let Some(i) = scope.modification.filter(|_| !scope.has_where) {
+            let message = if self.tokens[i].is_word("UPDATE") {
+                "UPDATE without a WHERE clause changes every row in the table"
+            } else {
+                "DELETE without a WHERE clause removes every row from the table"
+            };
+            self.report(i, i, "missing-where", DiagnosticSeverity::Warning, message);
+        }
+
+        if let Some(i) = scope.from_comma {
+            if scope.has_where {
+                self.report(
+                    i,
+                    i,
+                    "implicit-join",
+                    DiagnosticSeverity::Info,
+                    "Tables listed with commas are joined in WHERE; an explicit JOIN ... ON is clearer",
+                );
+            } else {
+                self.report(
+                    i,
+                    i,
+                    "implicit-cross-join",
+                    DiagnosticSeverity::Warning,
+                    "Tables listed with commas and no WHERE clause return every combination of rows",
+                );
+            }
+        }
+
+        if let Some(i) = scope.limit.filter(|_| !scope.has_order_by && !scope.in_exists) {
+            let message = format!(
+                "{} without ORDER BY returns an arbitrary set of rows",
+                self.tokens[i].text.to_ascii_uppercase()
+            );
+            self.report(i, i, "limit-without-order", DiagnosticSeverity::Warning, &message);
+        }
+    }
+
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().expect("root scope")
+    }
+
+    /// Upper-cased word at `i`, if that token is a word
+    fn word_at(&self, i: usize) -> Option<String> {
+        self.tokens
+            .get(i)
+            .filter(|t| t.kind == TokenKind::Word)
+            .map(|t| t.text.to_ascii_uppercase())
+    }
+
+    /// Add a diagnostic covering tokens `first..=last`
+    fn report(&mut self, first: usize, last: usize, code: &str, severity: DiagnosticSeverity, message: &str) {
+        let start = self.tokens[first].offset;
+        let end = self.tokens[last].offset + self.tokens[last].text.len();
+        let (line, column) = position(self.sql, start);
+        let (end_line, end_column) = position(self.sql, end);
+
+        self.diagnostics.push(SqlDiagnostic {
+            code: code.to_string(),
+            message: message.to_string(),
+            severity,
+            line,
+            column,
+            end_line,
+            end_column,
+        });
+    }
+}
+
+/// 1-based line and column of a byte offset
+fn position(sql: &str, offset: usize) -> (usize, usize) {
+    let before = &sql[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map(|n| n + 1).unwrap_or(0);
+    (line, before[line_start..].chars().count() + 1)
+}
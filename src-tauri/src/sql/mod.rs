@@ -0,0 +1,54 @@
+//! Offline SQL tooling for the editor: a dialect-aware tokenizer and the
+//! formatter, linter, completion engine and named parameters built on it. The tokenizer is
+//! lenient so it copes with half-typed SQL; statements that are meant to run
+//! go through `sql_parse` instead.
+
+mod complete;
+mod format;
+mod lint;
+mod parameters;
+mod tokenizer;
+
+pub use complete::complete_sql;
+pub use format::format_sql;
+pub use lint::lint_sql;
+pub use parameters::{fill_parameters, query_parameters};
+pub use sql_parse::Dialect;
+pub use tokenizer::{tokenize, Token, TokenKind};
+
+use crate::models::DatabaseType;
+
+impl From<&DatabaseType> for Dialect {
+    fn from(database_type: &DatabaseType) -> Self {
+        match database_type {
+            DatabaseType::PostgreSQL => Dialect::Postgres,
+            DatabaseType::MySQL => Dialect::MySql,
+            DatabaseType::SQLite => Dialect::Sqlite,
+            DatabaseType::MSSQL => Dialect::Mssql,
+            DatabaseType::ClickHouse => Dialect::ClickHouse,
+            DatabaseType::DuckDB => Dialect::DuckDb,
+            DatabaseType::Oracle => Dialect::Oracle,
+            DatabaseType::MongoDB | DatabaseType::Redis => Dialect::Generic,
+        }
+    }
+}
+
+/// Reserved words and common type names, upper case
+const KEYWORDS: &[&str] = &[
+    "ADD", "ALL", "ALTER", "AND", "ANY", "APPLY", "AS", "ASC", "BEGIN", "BETWEEN", "BIGINT", "BOOLEAN", "BY",
+    "CASCADE", "CASE", "CAST", "CHAR", "CHECK", "COLUMN", "COMMIT", "CONFLICT", "CONSTRAINT", "CREATE", "CROSS",
+    "CURRENT_DATE", "CURRENT_TIMESTAMP", "DATABASE", "DATE", "DECIMAL", "DEFAULT", "DELETE", "DESC", "DISTINCT",
+    "DO", "DROP", "ELSE", "END", "EXCEPT", "EXISTS", "EXPLAIN", "FALSE", "FETCH", "FIRST", "FOREIGN", "FROM",
+    "FULL", "FUNCTION", "GRANT", "GROUP", "HAVING", "IF", "ILIKE", "IN", "INDEX", "INNER", "INSERT", "INT",
+    "INTEGER", "INTERSECT", "INTERVAL", "INTO", "IS", "JOIN", "KEY", "LATERAL", "LEFT", "LIKE", "LIMIT", "NATURAL",
+    "NEXT", "NOT", "NOTHING", "NULL", "NULLS", "NUMERIC", "OFFSET", "ON", "ONLY", "OR", "ORDER", "OUTER", "OVER",
+    "PARTITION", "PRIMARY", "PROCEDURE", "REAL", "RECURSIVE", "REFERENCES", "RETURNING", "REVOKE", "RIGHT",
+    "ROLLBACK", "ROW", "ROWS", "SCHEMA", "SELECT", "SET", "SMALLINT", "TABLE", "TEXT", "THEN", "TIME", "TIMESTAMP",
+    "TO", "TOP", "TRANSACTION", "TRIGGER", "TRUE", "TRUNCATE", "UNION", "UNIQUE", "UPDATE", "USING", "VALUES",
+    "VARCHAR", "VIEW", "WHEN", "WHERE", "WINDOW", "WITH",
+];
+
+/// Whether `word` is a SQL keyword, in any case
+pub fn is_keyword(word: &str) -> bool {
+    KEYWORDS.iter().any(|k| k.eq_ignore_ascii_case(word))
+}
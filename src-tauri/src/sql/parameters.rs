@@ -0,0 +1,70 @@
+use super::{tokenize, Dialect, Token, TokenKind};
+use crate::db::cell_literal;
+use crate::error::{AppError, AppResult};
+use crate::models::{CellValue, DatabaseType};
+use std::collections::HashMap;
+
+/// Name of a `:name` or `${name}` placeholder. Positional parameters (`$1`, `?`)
+/// and `@name` variables are left to the database.
+fn parameter_name(tokens: &[Token], index: usize, dialect: Dialect) -> Option<&str> {
+    let token = &tokens[index];
+    if token.kind != TokenKind::Parameter {
+        return None;
+    }
+    if let Some(name) = token.text.strip_prefix("${") {
+        return name.strip_suffix('}');
+    }
+    let name = token.text.strip_prefix(':')?;
+    // ClickHouse's own query parameters are written `{name:Type}`
+    let typed_parameter = dialect == Dialect::ClickHouse
+        && index >= 2
+        && tokens[index - 1].kind == TokenKind::Word
+        && tokens[index - 2].kind == TokenKind::Operator
+        && tokens[index - 2].text == "{";
+    (!typed_parameter).then_some(name)
+}
+
+/// Names of the named placeholders in `sql`, each once, in order of first use.
+/// Placeholders inside strings and comments do not count.
+pub fn query_parameters(sql: &str, dialect: Dialect) -> Vec<String> {
+    let tokens = tokenize(sql, dialect);
+    let mut names: Vec<String> = Vec::new();
+    for index in 0..tokens.len() {
+        if let Some(name) = parameter_name(&tokens, index, dialect) {
+            if !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// `sql` with every named placeholder replaced by its value, written as an escaped
+/// literal of the value's kind. Every placeholder needs a value.
+pub fn fill_parameters(sql: &str, database_type: &DatabaseType, values: &HashMap<String, CellValue>) -> AppResult<String> {
+    let dialect = Dialect::from(database_type);
+    let tokens = tokenize(sql, dialect);
+
+    let mut filled = String::with_capacity(sql.len());
+    let mut copied = 0;
+    let mut missing: Vec<&str> = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let Some(name) = parameter_name(&tokens, index, dialect) else {
+            continue;
+        };
+        match values.get(name) {
+            Some(value) => {
+                filled.push_str(&sql[copied..token.offset]);
+                filled.push_str(&cell_literal(database_type, value));
+                copied = token.offset + token.text.len();
+            }
+            None if !missing.contains(&name) => missing.push(name),
+            None => {}
+        }
+    }
+    if !missing.is_empty() {
+        return Err(AppError::ValidationError(format!("No value given for parameter {}", missing.join(", "))));
+    }
+    filled.push_str(&sql[copied..]);
+    Ok(filled)
+}
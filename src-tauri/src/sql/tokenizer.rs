@@ -0,0 +1,285 @@
+use super::Dialect;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Keyword, identifier or function name
+    Word,
+    /// `"name"`, `` `name` `` or `[name]`
+    QuotedIdentifier,
+    /// String literal, including prefixed (`N'..'`, `E'..'`) and dollar-quoted strings
+    String,
+    Number,
+    /// `$1`, `?`, `:name`, `@name` or `${name}`
+    Parameter,
+    LineComment,
+    BlockComment,
+    Operator,
+    OpenParen,
+    CloseParen,
+    OpenBracket,
+    CloseBracket,
+    Comma,
+    Dot,
+    Semicolon,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    /// Byte offset of the token in the source
+    pub offset: usize,
+}
+
+impl Token {
+    pub fn is_word(&self, word: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(word)
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self.kind, TokenKind::LineComment | TokenKind::BlockComment)
+    }
+}
+
+/// Operators made of more than one character, longest first
+const MULTI_CHAR_OPERATORS: &[&str] = &[
+    "->>", "#>>", "<=>", "<>", "<=", ">=", "!=", "::", "||", "->", "#>", "=>", "@>", "<@", "&&", "<<", ">>",
+];
+
+/// Split SQL into tokens, dropping whitespace. Unterminated strings, quoted
+/// identifiers and comments run to the end of the input.
+pub fn tokenize(sql: &str, dialect: Dialect) -> Vec<Token> {
+    let chars: Vec<(usize, char)> = sql.char_indices().collect();
+    let end_offset = sql.len();
+    let offset_at = |i: usize| chars.get(i).map(|&(o, _)| o).unwrap_or(end_offset);
+    let char_at = |i: usize| chars.get(i).map(|&(_, c)| c);
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while let Some(c) = char_at(i) {
+        let start = i;
+        let next = char_at(i + 1);
+
+        let kind = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '-' if next == Some('-') => {
+                i = scan_line_comment(&chars, i);
+                TokenKind::LineComment
+            }
+            '#' if dialect == Dialect::MySql => {
+                i = scan_line_comment(&chars, i);
+                TokenKind::LineComment
+            }
+            '/' if next == Some('*') => {
+                i += 2;
+                while let Some(c) = char_at(i) {
+                    i += 1;
+                    if c == '*' && char_at(i) == Some('/') {
+                        i += 1;
+                        break;
+                    }
+                }
+                TokenKind::BlockComment
+            }
+            '\'' => {
+                i = scan_quoted(&chars, i, '\'', dialect == Dialect::MySql);
+                TokenKind::String
+            }
+            'N' | 'n' | 'E' | 'e' | 'X' | 'x' | 'B' | 'b' if next == Some('\'') => {
+                let backslash_escapes = dialect == Dialect::MySql || matches!(c, 'E' | 'e');
+                i = scan_quoted(&chars, i + 1, '\'', backslash_escapes);
+                TokenKind::String
+            }
+            '"' => {
+                i = scan_quoted(&chars, i, '"', false);
+                TokenKind::QuotedIdentifier
+            }
+            '`' => {
+                i = scan_quoted(&chars, i, '`', false);
+                TokenKind::QuotedIdentifier
+            }
+            '[' if matches!(dialect, Dialect::Mssql | Dialect::Sqlite) => {
+                i = scan_quoted(&chars, i, ']', false);
+                TokenKind::QuotedIdentifier
+            }
+            '$' if next == Some('{') && braced_parameter_end(&chars, i).is_some() => {
+                i = braced_parameter_end(&chars, i).unwrap_or(i + 1);
+                TokenKind::Parameter
+            }
+            '$' if next.is_some_and(|n| n.is_ascii_digit()) => {
+                i += 1;
+                while char_at(i).is_some_and(|c| c.is_ascii_digit()) {
+                    i += 1;
+                }
+                TokenKind::Parameter
+            }
+            '$' if matches!(dialect, Dialect::Postgres | Dialect::DuckDb | Dialect::Generic) => match dollar_tag(&chars, i) {
+                Some(tag_len) => {
+                    i = scan_dollar_quoted(&chars, i, tag_len);
+                    TokenKind::String
+                }
+                None => {
+                    i += 1;
+                    TokenKind::Operator
+                }
+            },
+            '?' if dialect != Dialect::Postgres => {
+                i += 1;
+                TokenKind::Parameter
+            }
+            // Postgres uses `@` as an operator
+            ':' | '@' if next.is_some_and(is_word_start) && !(c == '@' && dialect == Dialect::Postgres) => {
+                i += 1;
+                while char_at(i).is_some_and(is_word_char) {
+                    i += 1;
+                }
+                TokenKind::Parameter
+            }
+            '0'..='9' => {
+                i = scan_number(&chars, i);
+                TokenKind::Number
+            }
+            '.' if next.is_some_and(|n| n.is_ascii_digit()) => {
+                i = scan_number(&chars, i);
+                TokenKind::Number
+            }
+            _ if is_word_start(c) || (c == '#' && dialect == Dialect::Mssql) => {
+                i += 1;
+                while char_at(i).is_some_and(is_word_char) {
+                    i += 1;
+                }
+                TokenKind::Word
+            }
+            '(' => single(&mut i, TokenKind::OpenParen),
+            ')' => single(&mut i, TokenKind::CloseParen),
+            '[' => single(&mut i, TokenKind::OpenBracket),
+            ']' => single(&mut i, TokenKind::CloseBracket),
+            ',' => single(&mut i, TokenKind::Comma),
+            '.' => single(&mut i, TokenKind::Dot),
+            ';' => single(&mut i, TokenKind::Semicolon),
+            _ => {
+                let rest = &sql[offset_at(i)..];
+                let len = MULTI_CHAR_OPERATORS
+                    .iter()
+                    .find(|op| rest.starts_with(*op))
+                    .map(|op| op.chars().count())
+                    .unwrap_or(1);
+                i += len;
+                TokenKind::Operator
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            text: sql[offset_at(start)..offset_at(i)].to_string(),
+            offset: offset_at(start),
+        });
+    }
+
+    tokens
+}
+
+fn single(i: &mut usize, kind: TokenKind) -> TokenKind {
+    *i += 1;
+    kind
+}
+
+fn is_word_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+fn scan_line_comment(chars: &[(usize, char)], mut i: usize) -> usize {
+    while chars.get(i).is_some_and(|&(_, c)| c != '\n') {
+        i += 1;
+    }
+    i
+}
+
+/// Scan from the opening quote at `i` past the closing one. A doubled closing
+/// quote is an escaped quote.
+fn scan_quoted(chars: &[(usize, char)], mut i: usize, close: char, backslash_escapes: bool) -> usize {
+    i += 1;
+    while let Some(&(_, c)) = chars.get(i) {
+        i += 1;
+        if backslash_escapes && c == '\\' {
+            i += 1;
+        } else if c == close {
+            if chars.get(i).map(|&(_, c)| c) == Some(close) {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+    }
+    i.min(chars.len())
+}
+
+/// Index just past the `${name}` starting at `i`, if there is one
+fn braced_parameter_end(chars: &[(usize, char)], i: usize) -> Option<usize> {
+    let mut j = i + 2;
+    if !chars.get(j).is_some_and(|&(_, c)| is_word_start(c)) {
+        return None;
+    }
+    while chars.get(j).is_some_and(|&(_, c)| is_word_char(c) && c != '$') {
+        j += 1;
+    }
+    (chars.get(j).map(|&(_, c)| c) == Some('}')).then_some(j + 1)
+}
+
+/// Length in chars of the `$tag$` starting at `i`, if there is one
+fn dollar_tag(chars: &[(usize, char)], i: usize) -> Option<usize> {
+    let mut j = i + 1;
+    while let Some(&(_, c)) = chars.get(j) {
+        match c {
+            '$' => return Some(j - i + 1),
+            _ if is_word_char(c) && c != '$' && !(j == i + 1 && c.is_ascii_digit()) => j += 1,
+            _ => return None,
+        }
+    }
+    None
+}
+
+fn scan_dollar_quoted(chars: &[(usize, char)], i: usize, tag_len: usize) -> usize {
+    let tag: Vec<char> = chars[i..i + tag_len].iter().map(|&(_, c)| c).collect();
+    let mut j = i + tag_len;
+    while j < chars.len() {
+        if chars[j..].iter().take(tag_len).map(|&(_, c)| c).eq(tag.iter().copied()) {
+            return j + tag_len;
+        }
+        j += 1;
+    }
+    chars.len()
+}
+
+fn scan_number(chars: &[(usize, char)], mut i: usize) -> usize {
+    let at = |i: usize| chars.get(i).map(|&(_, c)| c);
+    // Hex literals (0x1F)
+    if at(i) == Some('0') && matches!(at(i + 1), Some('x' | 'X')) {
+        i += 2;
+        while at(i).is_some_and(|c| c.is_ascii_hexdigit()) {
+            i += 1;
+        }
+        return i;
+    }
+    while at(i).is_some_and(|c| c.is_ascii_digit() || c == '.') {
+        i += 1;
+    }
+    if matches!(at(i), Some('e' | 'E')) {
+        let sign = usize::from(matches!(at(i + 1), Some('+' | '-')));
+        if at(i + 1 + sign).is_some_and(|c| c.is_ascii_digit()) {
+            i += 1 + sign;
+            while at(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+        }
+    }
+    i
+}
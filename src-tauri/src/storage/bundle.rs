@@ -0,0 +1,123 @@
+use crate::error::{AppError, AppResult};
+use crate::models::{BundleEncryption, ConnectionBundle, ConnectionConfig};
+use base64::{engine::general_purpose, Engine as _};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::pbkdf2;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::num::NonZeroU32;
+
+const BUNDLE_FORMAT: &str = "dbfordevs-connections";
+const BUNDLE_VERSION: u32 = 1;
+const ALGORITHM: &str = "AES-256-GCM";
+const KDF: &str = "PBKDF2-HMAC-SHA256";
+const PBKDF2_ITERATIONS: u32 = 600_000;
+const SALT_LEN: usize = 16;
+
+/// Serialize connections to a bundle, encrypting it when a password is given
+pub fn export_bundle(connections: Vec<ConnectionConfig>, password: Option<&str>) -> AppResult<String> {
+    let bundle = match password.filter(|p| !p.is_empty()) {
+        None => ConnectionBundle {
+            format: BUNDLE_FORMAT.to_string(),
+            version: BUNDLE_VERSION,
+            connections: Some(connections),
+            encryption: None,
+            ciphertext: None,
+        },
+        Some(password) => {
+            let rng = SystemRandom::new();
+            let mut salt = [0u8; SALT_LEN];
+            let mut nonce = [0u8; NONCE_LEN];
+            rng.fill(&mut salt)
+                .and_then(|_| rng.fill(&mut nonce))
+                .map_err(|_| AppError::Internal("Failed to generate random bytes".to_string()))?;
+
+            let key = derive_key(password, &salt, PBKDF2_ITERATIONS)?;
+            let mut data = serde_json::to_vec(&connections)?;
+            key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(BUNDLE_FORMAT.as_bytes()), &mut data)
+                .map_err(|_| AppError::Internal("Failed to encrypt connections".to_string()))?;
+
+            ConnectionBundle {
+                format: BUNDLE_FORMAT.to_string(),
+                version: BUNDLE_VERSION,
+                connections: None,
+                encryption: Some(BundleEncryption {
+                    algorithm: ALGORITHM.to_string(),
+                    kdf: KDF.to_string(),
+                    iterations: PBKDF2_ITERATIONS,
+                    salt: general_purpose::STANDARD.encode(salt),
+                    nonce: general_purpose::STANDARD.encode(nonce),
+                }),
+                ciphertext: Some(general_purpose::STANDARD.encode(data)),
+            }
+        }
+    };
+
+    Ok(serde_json::to_string_pretty(&bundle)?)
+}
+
+/// Read connections back out of a bundle produced by `export_bundle`
+pub fn import_bundle(content: &str, password: Option<&str>) -> AppResult<Vec<ConnectionConfig>> {
+    let bundle: ConnectionBundle = serde_json::from_str(content)
+        .map_err(|e| AppError::ConfigError(format!("Not a connection bundle: {}", e)))?;
+
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(AppError::ConfigError(format!("Unknown bundle format '{}'", bundle.format)));
+    }
+    if bundle.version > BUNDLE_VERSION {
+        return Err(AppError::ConfigError(format!(
+            "Bundle version {} is newer than this app supports",
+            bundle.version
+        )));
+    }
+
+    let encryption = match bundle.encryption {
+        None => {
+            return bundle.connections
+                .ok_or_else(|| AppError::ConfigError("Bundle contains no connections".to_string()));
+        }
+        Some(encryption) => encryption,
+    };
+
+    if encryption.algorithm != ALGORITHM || encryption.kdf != KDF {
+        return Err(AppError::ConfigError(format!(
+            "Unsupported bundle encryption {} / {}",
+            encryption.algorithm, encryption.kdf
+        )));
+    }
+
+    let password = password
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| AppError::ValidationError("This bundle is password protected".to_string()))?;
+
+    let decode = |value: &str, what: &str| {
+        general_purpose::STANDARD.decode(value)
+            .map_err(|e| AppError::ConfigError(format!("Invalid bundle {}: {}", what, e)))
+    };
+    let salt = decode(&encryption.salt, "salt")?;
+    let nonce: [u8; NONCE_LEN] = decode(&encryption.nonce, "nonce")?
+        .try_into()
+        .map_err(|_| AppError::ConfigError("Invalid bundle nonce length".to_string()))?;
+    let mut data = decode(bundle.ciphertext.as_deref().unwrap_or_default(), "ciphertext")?;
+
+    // The count comes from the file, so a crafted bundle must not make deriving the key take hours
+    if !(PBKDF2_ITERATIONS..=10 * PBKDF2_ITERATIONS).contains(&encryption.iterations) {
+        return Err(AppError::ConfigError("Invalid key derivation iteration count".to_string()));
+    }
+    let key = derive_key(password, &salt, encryption.iterations)?;
+    let plaintext = key.open_in_place(Nonce::assume_unique_for_key(nonce), Aad::from(BUNDLE_FORMAT.as_bytes()), &mut data)
+        .map_err(|_| AppError::ValidationError("Wrong password or corrupted bundle".to_string()))?;
+
+    Ok(serde_json::from_slice(plaintext)?)
+}
+
+fn derive_key(password: &str, salt: &[u8], iterations: u32) -> AppResult<LessSafeKey> {
+    let iterations = NonZeroU32::new(iterations)
+        .ok_or_else(|| AppError::ConfigError("Invalid key derivation iteration count".to_string()))?;
+
+    let mut key = [0u8; 32];
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, salt, password.as_bytes(), &mut key);
+
+    UnboundKey::new(&AES_256_GCM, &key)
+        .map(LessSafeKey::new)
+        .map_err(|_| AppError::Internal("Failed to build encryption key".to_string()))
+}
@@ -1,3 +1,13 @@
+mod bundle;
+mod pins;
+mod saved_queries;
+mod workspace;
+
+pub use bundle::*;
+pub use pins::*;
+pub use saved_queries::*;
+pub use workspace::*;
+
 use crate::error::{AppError, AppResult};
 use crate::models::ConnectionConfig;
 use dirs::data_dir;
@@ -7,8 +17,8 @@ use std::path::PathBuf;
 
 const CONNECTIONS_FILE: &str = "connections.json";
 
-/// Get the path to the connections storage file
-fn get_connections_path() -> AppResult<PathBuf> {
+/// Get the directory the app keeps its files in, creating it if needed
+pub fn app_dir() -> AppResult<PathBuf> {
     let data_dir = data_dir()
         .ok_or_else(|| AppError::ConfigError("Could not determine data directory".to_string()))?;
     
@@ -18,7 +28,12 @@ fn get_connections_path() -> AppResult<PathBuf> {
     fs::create_dir_all(&app_dir)
         .map_err(|e| AppError::IoError(e))?;
     
-    Ok(app_dir.join(CONNECTIONS_FILE))
+    Ok(app_dir)
+}
+
+/// Get the path to the connections storage file
+fn get_connections_path() -> AppResult<PathBuf> {
+    Ok(app_dir()?.join(CONNECTIONS_FILE))
 }
 
 /// Load all saved connections from storage
@@ -85,3 +100,29 @@ pub fn get_connection(connection_id: &str) -> AppResult<Option<ConnectionConfig>
     Ok(connections.into_iter().find(|c| c.id.as_ref() == Some(&connection_id.to_string())))
 }
 
+
+/// Merge imported connections into storage. Connections whose ID already
+/// exists are replaced, keeping the local password when the import has none.
+pub fn import_connections(imported: Vec<ConnectionConfig>) -> AppResult<Vec<ConnectionConfig>> {
+    let mut connections = load_connections()?;
+    let mut saved = Vec::with_capacity(imported.len());
+
+    for mut config in imported {
+        let id = config.id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        config.id = Some(id.clone());
+
+        match connections.iter_mut().find(|c| c.id.as_ref() == Some(&id)) {
+            Some(existing) => {
+                if config.password.is_none() {
+                    config.password = existing.password.clone();
+                }
+                *existing = config.clone();
+            }
+            None => connections.push(config.clone()),
+        }
+        saved.push(config);
+    }
+
+    save_all_connections(&connections)?;
+    Ok(saved)
+}
@@ -0,0 +1,101 @@
+//! Tables, views and saved queries pinned per connection, listed in the
+//! explorer's Favorites section in the order they were pinned.
+
+use super::{app_dir, get_connection, load_saved_queries};
+use crate::error::{AppError, AppResult};
+use crate::models::{PinnedObject, PinnedObjectType};
+use chrono::{SecondsFormat, Utc};
+use std::fs;
+use std::path::PathBuf;
+
+const PINS_FILE: &str = "pinned_objects.json";
+
+fn pins_path() -> AppResult<PathBuf> {
+    Ok(app_dir()?.join(PINS_FILE))
+}
+
+fn load_pins() -> AppResult<Vec<PinnedObject>> {
+    let path = pins_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Replace the file through a temporary one, so quitting mid-save keeps the old pins
+fn save_pins(pins: &[PinnedObject]) -> AppResult<()> {
+    let path = pins_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(pins)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+/// The connection's pins, oldest first
+pub fn list_pinned(connection_id: &str) -> AppResult<Vec<PinnedObject>> {
+    let mut pins = load_pins()?;
+    pins.retain(|pin| pin.connection_id == connection_id);
+    Ok(pins)
+}
+
+/// Pin or unpin an object on a connection and return the connection's pins.
+/// Pinning an object twice keeps its first pin.
+pub fn set_pinned(
+    connection_id: &str,
+    object_type: PinnedObjectType,
+    name: &str,
+    pinned: bool,
+) -> AppResult<Vec<PinnedObject>> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::ValidationError("Choose an object to pin".to_string()));
+    }
+    let mut pins = load_pins()?;
+    let position = pins
+        .iter()
+        .position(|pin| pin.connection_id == connection_id && pin.object_type == object_type && pin.name == name);
+    match (position, pinned) {
+        (Some(index), false) => {
+            pins.remove(index);
+        }
+        (None, true) => {
+            if get_connection(connection_id)?.is_none() {
+                return Err(AppError::ConfigError("Connection config not found".to_string()));
+            }
+            if object_type == PinnedObjectType::Query && !load_saved_queries()?.iter().any(|query| query.id == name) {
+                return Err(AppError::ValidationError(format!("Unknown saved query '{}'", name)));
+            }
+            pins.push(PinnedObject {
+                connection_id: connection_id.to_string(),
+                object_type,
+                name: name.to_string(),
+                pinned_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            });
+        }
+        _ => return list_pinned(connection_id),
+    }
+    save_pins(&pins)?;
+    list_pinned(connection_id)
+}
+
+/// Drop every pin of a deleted connection
+pub fn forget_pins(connection_id: &str) -> AppResult<()> {
+    let mut pins = load_pins()?;
+    let count = pins.len();
+    pins.retain(|pin| pin.connection_id != connection_id);
+    if pins.len() == count {
+        return Ok(());
+    }
+    save_pins(&pins)
+}
+
+/// Drop the pins of a deleted saved query on every connection
+pub fn unpin_saved_query(query_id: &str) -> AppResult<()> {
+    let mut pins = load_pins()?;
+    let count = pins.len();
+    pins.retain(|pin| !(pin.object_type == PinnedObjectType::Query && pin.name == query_id));
+    if pins.len() == count {
+        return Ok(());
+    }
+    save_pins(&pins)
+}
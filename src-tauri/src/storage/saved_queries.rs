@@ -0,0 +1,196 @@
+//! Saved queries and their variables. A saved query declares the named
+//! placeholders it takes, each with a type and an optional default, and keeps
+//! a preset of values per connection, so one report runs against dev, staging
+//! and production without editing its SQL.
+
+use super::app_dir;
+use crate::error::{AppError, AppResult};
+use crate::models::{CellValue, QueryVariable, SavedQuery, VariableType};
+use crate::sql::{query_parameters, Dialect};
+use chrono::{SecondsFormat, Utc};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+const SAVED_QUERIES_FILE: &str = "saved_queries.json";
+
+fn saved_queries_path() -> AppResult<PathBuf> {
+    Ok(app_dir()?.join(SAVED_QUERIES_FILE))
+}
+
+/// Every saved query, in the order they were first saved
+pub fn load_saved_queries() -> AppResult<Vec<SavedQuery>> {
+    let path = saved_queries_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Replace the file through a temporary one, so quitting mid-save keeps the old list
+fn save_all_saved_queries(queries: &[SavedQuery]) -> AppResult<()> {
+    let path = saved_queries_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(queries)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+fn find_saved_query<'a>(queries: &'a mut [SavedQuery], id: &str) -> AppResult<&'a mut SavedQuery> {
+    queries
+        .iter_mut()
+        .find(|query| query.id == id)
+        .ok_or_else(|| AppError::ValidationError(format!("Unknown saved query '{}'", id)))
+}
+
+fn is_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_') && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Read `text` as a value of the variable's type. Empty text is NULL for every type but text.
+fn parse_variable_value(variable: &QueryVariable, text: &str) -> AppResult<CellValue> {
+    let trimmed = text.trim();
+    if trimmed.is_empty() && variable.variable_type != VariableType::Text {
+        return Ok(CellValue::Null);
+    }
+    let invalid = |expected: &str| {
+        AppError::ValidationError(format!("Variable {} takes {}, not '{}'", variable.name, expected, trimmed))
+    };
+    match variable.variable_type {
+        VariableType::Text => Ok(CellValue::Text(text.to_string())),
+        VariableType::Integer => trimmed.parse().map(CellValue::Int).map_err(|_| invalid("an integer")),
+        VariableType::Number => trimmed
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .map(CellValue::Float)
+            .ok_or_else(|| invalid("a number")),
+        VariableType::Boolean => match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "t" | "yes" | "1" => Ok(CellValue::Bool(true)),
+            "false" | "f" | "no" | "0" => Ok(CellValue::Bool(false)),
+            _ => Err(invalid("true or false")),
+        },
+        VariableType::Date => Ok(CellValue::Timestamp(trimmed.to_string())),
+    }
+}
+
+fn find_variable<'a>(query: &'a SavedQuery, name: &str) -> AppResult<&'a QueryVariable> {
+    query
+        .variables
+        .iter()
+        .find(|variable| variable.name == name)
+        .ok_or_else(|| AppError::ValidationError(format!("{} has no variable {}", query.name, name)))
+}
+
+/// Check the query's values and declare the placeholders its SQL uses but does
+/// not list yet, as text variables. Preset values of removed variables are dropped.
+fn prepare(query: &mut SavedQuery) -> AppResult<()> {
+    query.name = query.name.trim().to_string();
+    if query.name.is_empty() {
+        return Err(AppError::ValidationError("A saved query needs a name".to_string()));
+    }
+    if query.sql.trim().is_empty() {
+        return Err(AppError::ValidationError("A saved query needs SQL".to_string()));
+    }
+
+    let mut declared = HashSet::new();
+    for variable in &query.variables {
+        if !is_variable_name(&variable.name) {
+            return Err(AppError::ValidationError(format!("'{}' is not a valid variable name", variable.name)));
+        }
+        if !declared.insert(variable.name.clone()) {
+            return Err(AppError::ValidationError(format!("Variable {} is declared twice", variable.name)));
+        }
+        if let Some(default) = &variable.default_value {
+            parse_variable_value(variable, default)?;
+        }
+    }
+    for name in query_parameters(&query.sql, Dialect::Generic) {
+        if declared.insert(name.clone()) {
+            query.variables.push(QueryVariable { name, variable_type: VariableType::Text, default_value: None });
+        }
+    }
+
+    let mut presets = std::mem::take(&mut query.presets);
+    for values in presets.values_mut() {
+        values.retain(|name, _| declared.contains(name));
+        for (name, value) in values.iter() {
+            parse_variable_value(find_variable(query, name)?, value)?;
+        }
+    }
+    presets.retain(|_, values| !values.is_empty());
+    query.presets = presets;
+    Ok(())
+}
+
+/// Add a saved query, or replace the one with the same ID
+pub fn save_saved_query(mut query: SavedQuery) -> AppResult<SavedQuery> {
+    prepare(&mut query)?;
+    if query.id.is_empty() {
+        query.id = uuid::Uuid::new_v4().to_string();
+    }
+    query.updated_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let mut queries = load_saved_queries()?;
+    match queries.iter_mut().find(|existing| existing.id == query.id) {
+        Some(existing) => *existing = query.clone(),
+        None => queries.push(query.clone()),
+    }
+    save_all_saved_queries(&queries)?;
+    Ok(query)
+}
+
+pub fn delete_saved_query(id: &str) -> AppResult<()> {
+    let mut queries = load_saved_queries()?;
+    queries.retain(|query| query.id != id);
+    save_all_saved_queries(&queries)
+}
+
+/// Replace the values a connection uses for the query's variables; no values removes the preset
+pub fn set_saved_query_preset(id: &str, connection_id: &str, values: HashMap<String, String>) -> AppResult<SavedQuery> {
+    let mut queries = load_saved_queries()?;
+    let query = find_saved_query(&mut queries, id)?;
+    for (name, value) in &values {
+        parse_variable_value(find_variable(query, name)?, value)?;
+    }
+    if values.is_empty() {
+        query.presets.remove(connection_id);
+    } else {
+        query.presets.insert(connection_id.to_string(), values);
+    }
+    let query = query.clone();
+    save_all_saved_queries(&queries)?;
+    Ok(query)
+}
+
+/// Values for every variable of the query, ready to run it with: the ones
+/// given, else the connection's preset, else each variable's default
+pub fn saved_query_params(
+    id: &str,
+    connection_id: Option<&str>,
+    values: &HashMap<String, String>,
+) -> AppResult<HashMap<String, CellValue>> {
+    let mut queries = load_saved_queries()?;
+    let query = find_saved_query(&mut queries, id)?;
+    let preset = connection_id.and_then(|connection_id| query.presets.get(connection_id));
+
+    let mut params = HashMap::new();
+    let mut missing = Vec::new();
+    for variable in &query.variables {
+        let text = values
+            .get(&variable.name)
+            .or_else(|| preset.and_then(|preset| preset.get(&variable.name)))
+            .or(variable.default_value.as_ref());
+        match text {
+            Some(text) => {
+                params.insert(variable.name.clone(), parse_variable_value(variable, text)?);
+            }
+            None => missing.push(variable.name.as_str()),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(AppError::ValidationError(format!("No value given for variable {}", missing.join(", "))));
+    }
+    Ok(params)
+}
@@ -0,0 +1,73 @@
+//! The open tabs, saved as the user works so the next start restores them.
+//! Tabs whose connection was deleted in the meantime are left out on restore.
+
+use super::{app_dir, load_connections};
+use crate::error::AppResult;
+use crate::models::Workspace;
+use chrono::{SecondsFormat, Utc};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+const WORKSPACE_FILE: &str = "workspace.json";
+
+/// Smallest and largest share of a tab's height the results may take, in percent
+const MIN_RESULT_PANE_SIZE: f64 = 10.0;
+const MAX_RESULT_PANE_SIZE: f64 = 90.0;
+
+fn workspace_path() -> AppResult<PathBuf> {
+    Ok(app_dir()?.join(WORKSPACE_FILE))
+}
+
+/// Keep the active tab pointing at an open tab
+fn fix_active_tab(workspace: &mut Workspace) {
+    let is_open = |id: &String| workspace.tabs.iter().any(|tab| &tab.id == id);
+    if !workspace.active_tab_id.as_ref().is_some_and(is_open) {
+        workspace.active_tab_id = workspace.tabs.first().map(|tab| tab.id.clone());
+    }
+}
+
+/// Save the workspace, replacing the last one. It is written to a temporary
+/// file first, so quitting mid-save cannot leave half a workspace behind.
+pub fn save_workspace(mut workspace: Workspace) -> AppResult<()> {
+    let mut seen = HashSet::new();
+    workspace.tabs.retain(|tab| seen.insert(tab.id.clone()));
+    for tab in &mut workspace.tabs {
+        tab.result_pane_size = tab
+            .result_pane_size
+            .filter(|size| size.is_finite())
+            .map(|size| size.clamp(MIN_RESULT_PANE_SIZE, MAX_RESULT_PANE_SIZE));
+    }
+    fix_active_tab(&mut workspace);
+    workspace.saved_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+
+    let path = workspace_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(&workspace)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+/// The last saved workspace, without the tabs of deleted connections; None
+/// when there is none or it cannot be read
+pub fn load_workspace() -> AppResult<Option<Workspace>> {
+    let path = workspace_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let mut workspace: Workspace = match serde_json::from_str(&fs::read_to_string(path)?) {
+        Ok(workspace) => workspace,
+        Err(e) => {
+            tracing::warn!("Ignoring the saved workspace: {}", e);
+            return Ok(None);
+        }
+    };
+
+    let connections: HashSet<String> = load_connections()?.into_iter().filter_map(|c| c.id).collect();
+    workspace.tabs.retain(|tab| connections.contains(&tab.connection_id));
+    if !workspace.active_connection_id.as_ref().is_some_and(|id| connections.contains(id)) {
+        workspace.active_connection_id = None;
+    }
+    fix_active_tab(&mut workspace);
+    Ok(Some(workspace))
+}
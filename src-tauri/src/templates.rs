@@ -0,0 +1,188 @@
+//! Connection templates for managed database services: the port, TLS mode and
+//! default names each service expects, and the host names it hands out so a
+//! mistyped endpoint is caught before connecting.
+
+use crate::error::{AppError, AppResult};
+use crate::models::{CloudProvider, ConnectionTemplate, DatabaseType};
+
+const RDS_NOTES: &str = "Connections are encrypted but the certificate is not checked. To verify it, download the \
+    RDS CA bundle (global-bundle.pem), set it as the CA certificate and use verify-full.";
+
+const CLOUD_SQL_NOTES: &str = "Use the instance's public IP, or 127.0.0.1 when connecting through the Cloud SQL Auth \
+    Proxy. The proxy encrypts the connection itself, so set SSL to disable with it.";
+
+pub const TEMPLATES: &[ConnectionTemplate] = &[
+    ConnectionTemplate {
+        id: "aws-rds-postgresql",
+        name: "Amazon RDS for PostgreSQL",
+        provider: CloudProvider::Aws,
+        database_type: DatabaseType::PostgreSQL,
+        port: 5432,
+        ssl_mode: "require",
+        username: Some("postgres"),
+        database: Some("postgres"),
+        host_suffixes: &[".rds.amazonaws.com", ".rds.amazonaws.com.cn"],
+        host_example: "mydb.abc123xyz.us-east-1.rds.amazonaws.com",
+        notes: RDS_NOTES,
+    },
+    ConnectionTemplate {
+        id: "aws-rds-mysql",
+        name: "Amazon RDS for MySQL",
+        provider: CloudProvider::Aws,
+        database_type: DatabaseType::MySQL,
+        port: 3306,
+        ssl_mode: "require",
+        username: Some("admin"),
+        database: None,
+        host_suffixes: &[".rds.amazonaws.com", ".rds.amazonaws.com.cn"],
+        host_example: "mydb.abc123xyz.us-east-1.rds.amazonaws.com",
+        notes: RDS_NOTES,
+    },
+    ConnectionTemplate {
+        id: "aws-rds-sqlserver",
+        name: "Amazon RDS for SQL Server",
+        provider: CloudProvider::Aws,
+        database_type: DatabaseType::MSSQL,
+        port: 1433,
+        ssl_mode: "require",
+        username: Some("admin"),
+        database: Some("master"),
+        host_suffixes: &[".rds.amazonaws.com", ".rds.amazonaws.com.cn"],
+        host_example: "mydb.abc123xyz.us-east-1.rds.amazonaws.com",
+        notes: RDS_NOTES,
+    },
+    ConnectionTemplate {
+        id: "azure-postgresql",
+        name: "Azure Database for PostgreSQL",
+        provider: CloudProvider::Azure,
+        database_type: DatabaseType::PostgreSQL,
+        port: 5432,
+        ssl_mode: "verify-full",
+        username: None,
+        database: Some("postgres"),
+        host_suffixes: &[".postgres.database.azure.com"],
+        host_example: "myserver.postgres.database.azure.com",
+        notes: "Flexible servers take the plain admin name. Single servers, which are retired, expect \
+            user@servername.",
+    },
+    ConnectionTemplate {
+        id: "azure-mysql",
+        name: "Azure Database for MySQL",
+        provider: CloudProvider::Azure,
+        database_type: DatabaseType::MySQL,
+        port: 3306,
+        ssl_mode: "verify-full",
+        username: None,
+        database: None,
+        host_suffixes: &[".mysql.database.azure.com"],
+        host_example: "myserver.mysql.database.azure.com",
+        notes: "Add your IP address to the server's firewall rules before connecting.",
+    },
+    ConnectionTemplate {
+        id: "azure-sql",
+        name: "Azure SQL Database",
+        provider: CloudProvider::Azure,
+        database_type: DatabaseType::MSSQL,
+        port: 1433,
+        ssl_mode: "verify-full",
+        username: None,
+        database: None,
+        host_suffixes: &[".database.windows.net"],
+        host_example: "myserver.database.windows.net",
+        notes: "Azure SQL always encrypts connections. Add your IP address to the server's firewall rules first.",
+    },
+    ConnectionTemplate {
+        id: "gcp-cloud-sql-postgresql",
+        name: "Cloud SQL for PostgreSQL",
+        provider: CloudProvider::Gcp,
+        database_type: DatabaseType::PostgreSQL,
+        port: 5432,
+        ssl_mode: "require",
+        username: Some("postgres"),
+        database: Some("postgres"),
+        host_suffixes: &[],
+        host_example: "34.123.45.67",
+        notes: CLOUD_SQL_NOTES,
+    },
+    ConnectionTemplate {
+        id: "gcp-cloud-sql-mysql",
+        name: "Cloud SQL for MySQL",
+        provider: CloudProvider::Gcp,
+        database_type: DatabaseType::MySQL,
+        port: 3306,
+        ssl_mode: "require",
+        username: Some("root"),
+        database: None,
+        host_suffixes: &[],
+        host_example: "34.123.45.67",
+        notes: CLOUD_SQL_NOTES,
+    },
+    ConnectionTemplate {
+        id: "supabase",
+        name: "Supabase",
+        provider: CloudProvider::Supabase,
+        database_type: DatabaseType::PostgreSQL,
+        port: 5432,
+        ssl_mode: "require",
+        username: Some("postgres"),
+        database: Some("postgres"),
+        host_suffixes: &[".supabase.co", ".pooler.supabase.com"],
+        host_example: "db.abcdefghijklmnop.supabase.co",
+        notes: "The direct host needs IPv6. Without it, use the session pooler host \
+            (aws-0-<region>.pooler.supabase.com) with the user postgres.<project-ref>.",
+    },
+    ConnectionTemplate {
+        id: "neon",
+        name: "Neon",
+        provider: CloudProvider::Neon,
+        database_type: DatabaseType::PostgreSQL,
+        port: 5432,
+        ssl_mode: "verify-full",
+        username: None,
+        database: Some("neondb"),
+        host_suffixes: &[".neon.tech"],
+        host_example: "ep-cool-darkness-123456.us-east-2.aws.neon.tech",
+        notes: "Use the endpoint host from the Neon console. Add -pooler after the endpoint ID to go through \
+            the connection pooler.",
+    },
+    ConnectionTemplate {
+        id: "planetscale",
+        name: "PlanetScale",
+        provider: CloudProvider::PlanetScale,
+        database_type: DatabaseType::MySQL,
+        port: 3306,
+        ssl_mode: "verify-full",
+        username: None,
+        database: None,
+        host_suffixes: &[".psdb.cloud"],
+        host_example: "aws.connect.psdb.cloud",
+        notes: "Create a password for the branch in the PlanetScale console. It gives you the username and \
+            password to use here.",
+    },
+];
+
+/// Template with the given ID
+pub fn find(id: &str) -> AppResult<&'static ConnectionTemplate> {
+    TEMPLATES
+        .iter()
+        .find(|template| template.id == id)
+        .ok_or_else(|| AppError::ValidationError(format!("Unknown connection template '{}'", id)))
+}
+
+/// Fail if `host` is not one of the service's host names
+pub fn check_host(template: &ConnectionTemplate, host: &str) -> AppResult<()> {
+    let host = host.trim().trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() {
+        return Err(AppError::ValidationError("Host cannot be empty".to_string()));
+    }
+    if template.host_suffixes.is_empty() || template.host_suffixes.iter().any(|suffix| host.ends_with(suffix)) {
+        return Ok(());
+    }
+
+    Err(AppError::ValidationError(format!(
+        "'{}' is not a {} host; expected a name ending in {}",
+        host,
+        template.name,
+        template.host_suffixes.join(" or ")
+    )))
+}
@@ -0,0 +1,269 @@
+//! Color themes beyond the built-in light and dark. A theme has a dark and/or
+//! a light variant, each a [`ThemeDefinition`] giving a color for every
+//! semantic token; the frontend applies it as CSS variables under a
+//! `theme-<id>-<variant>` class on the document root. Nordic ships with the
+//! app; extensions in the `Themes` category contribute more through
+//! `contributes.themes`, pointing at definition files in their package, which
+//! must set every required token and meet the WCAG AA contrast ratios.
+
+use crate::error::{AppError, AppResult};
+use crate::extension::{builtin, ExtensionLoader};
+use crate::models::{ExtensionManifest, ThemeCss, ThemeInfo, ThemeSource, ThemeVariant};
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use theme_core::{nordic, Appearance, CssBuilder, ThemeDefinition};
+
+/// Extensions must be in this category to contribute themes
+pub const THEME_CATEGORY: &str = "Themes";
+
+/// Themes styled by the app's own stylesheet
+const BUILTIN_THEMES: &[&str] = &["system", "light", "dark"];
+
+/// Largest theme file accepted, in bytes
+const MAX_THEME_SIZE: usize = 64 * 1024;
+
+const ACTIVE_THEME_FILE: &str = "theme.json";
+
+struct BundledTheme {
+    /// The bundled extension that provides it
+    extension_id: &'static str,
+    id: &'static str,
+    name: &'static str,
+    dark: fn() -> ThemeDefinition,
+    light: fn() -> ThemeDefinition,
+}
+
+const BUNDLED_THEMES: &[BundledTheme] = &[BundledTheme {
+    extension_id: "theme-nordic",
+    id: "nordic",
+    name: "Nordic",
+    dark: nordic::dark,
+    light: nordic::light,
+}];
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActiveTheme {
+    theme: String,
+}
+
+/// Check a theme id: lowercase letters, digits and dashes, not taken by a
+/// built-in or bundled theme
+pub fn validate_id(id: &str) -> AppResult<()> {
+    let valid = (2..=40).contains(&id.len())
+        && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !id.starts_with('-')
+        && !id.ends_with('-');
+    if !valid {
+        return Err(AppError::ValidationError(format!(
+            "Invalid theme id '{}': use 2 to 40 lowercase letters, digits and dashes",
+            id
+        )));
+    }
+    if BUILTIN_THEMES.contains(&id) || BUNDLED_THEMES.iter().any(|theme| theme.id == id) {
+        return Err(AppError::ValidationError(format!("The theme id '{}' is reserved", id)));
+    }
+    Ok(())
+}
+
+/// Check the manifest's theme declarations
+pub fn validate_contributions(manifest: &ExtensionManifest) -> AppResult<()> {
+    let themes = &manifest.contributes.themes;
+    if !themes.is_empty() && manifest.category != THEME_CATEGORY {
+        return Err(AppError::ValidationError(format!(
+            "Only extensions in the {} category can contribute themes",
+            THEME_CATEGORY
+        )));
+    }
+    for (index, theme) in themes.iter().enumerate() {
+        validate_id(&theme.id)?;
+        if theme.name.trim().is_empty() {
+            return Err(AppError::ValidationError(format!("Theme '{}' has no name", theme.id)));
+        }
+        if theme.dark.is_none() && theme.light.is_none() {
+            return Err(AppError::ValidationError(format!(
+                "Theme '{}' needs a dark or a light variant",
+                theme.id
+            )));
+        }
+        if themes[..index].iter().any(|other| other.id == theme.id) {
+            return Err(AppError::ValidationError(format!("Theme '{}' is declared twice", theme.id)));
+        }
+    }
+    Ok(())
+}
+
+/// Parse and check a theme definition file for the given variant
+pub fn parse_definition(json: &str, variant: ThemeVariant) -> AppResult<ThemeDefinition> {
+    if json.len() > MAX_THEME_SIZE {
+        return Err(AppError::ValidationError(format!(
+            "The theme file is larger than {} KB",
+            MAX_THEME_SIZE / 1024
+        )));
+    }
+    let definition = ThemeDefinition::from_json(json).map_err(|problems| {
+        let problems: Vec<String> = problems.iter().map(ToString::to_string).collect();
+        AppError::ValidationError(problems.join("; "))
+    })?;
+    let appearance = match variant {
+        ThemeVariant::Dark => Appearance::Dark,
+        ThemeVariant::Light => Appearance::Light,
+    };
+    if definition.appearance != appearance {
+        return Err(AppError::ValidationError(format!(
+            "'{}' is not a {} theme",
+            definition.name,
+            variant.as_str()
+        )));
+    }
+    Ok(definition)
+}
+
+fn variants(dark: bool, light: bool) -> Vec<ThemeVariant> {
+    let mut variants = Vec::new();
+    if dark {
+        variants.push(ThemeVariant::Dark);
+    }
+    if light {
+        variants.push(ThemeVariant::Light);
+    }
+    variants
+}
+
+/// Bundled themes, then those of installed theme extensions by name. A theme
+/// whose id is already taken by an earlier one is left out.
+pub fn list(loader: &ExtensionLoader) -> AppResult<Vec<ThemeInfo>> {
+    let mut themes: Vec<ThemeInfo> = BUNDLED_THEMES
+        .iter()
+        .filter(|theme| builtin::ensure_active(theme.extension_id).is_ok())
+        .map(|theme| ThemeInfo {
+            id: theme.id.to_string(),
+            name: theme.name.to_string(),
+            source: ThemeSource::Bundled,
+            extension_id: Some(theme.extension_id.to_string()),
+            variants: variants(true, true),
+        })
+        .collect();
+
+    for installed in loader.list()? {
+        let manifest = installed.manifest;
+        if manifest.category != THEME_CATEGORY {
+            continue;
+        }
+        for theme in manifest.contributes.themes {
+            if validate_id(&theme.id).is_err() || themes.iter().any(|existing| existing.id == theme.id) {
+                continue;
+            }
+            themes.push(ThemeInfo {
+                variants: variants(theme.dark.is_some(), theme.light.is_some()),
+                id: theme.id,
+                name: theme.name,
+                source: ThemeSource::Extension,
+                extension_id: Some(manifest.id.clone()),
+            });
+        }
+    }
+    Ok(themes)
+}
+
+/// The definition of one theme variant, checked again when read from an
+/// extension in case its directory was edited after install
+fn definition(loader: &ExtensionLoader, theme_id: &str, variant: ThemeVariant) -> AppResult<ThemeDefinition> {
+    let unavailable = || {
+        AppError::ValidationError(format!("Theme '{}' has no {} variant", theme_id, variant.as_str()))
+    };
+
+    if let Some(theme) = BUNDLED_THEMES.iter().find(|theme| theme.id == theme_id) {
+        builtin::ensure_active(theme.extension_id)?;
+        return Ok(match variant {
+            ThemeVariant::Dark => (theme.dark)(),
+            ThemeVariant::Light => (theme.light)(),
+        });
+    }
+
+    let info = list(loader)?
+        .into_iter()
+        .find(|theme| theme.id == theme_id)
+        .ok_or_else(|| AppError::ValidationError(format!("Theme '{}' is not installed", theme_id)))?;
+    let extension_id = info.extension_id.ok_or_else(unavailable)?;
+    let installed = loader.get(&extension_id)?;
+    let theme = installed
+        .manifest
+        .contributes
+        .themes
+        .iter()
+        .find(|theme| theme.id == theme_id)
+        .ok_or_else(unavailable)?;
+    let file = match variant {
+        ThemeVariant::Dark => theme.dark.as_deref(),
+        ThemeVariant::Light => theme.light.as_deref(),
+    }
+    .ok_or_else(unavailable)?;
+
+    let path = PathBuf::from(&installed.path).join(crate::extension::package::theme_path(file)?);
+    parse_definition(&fs::read_to_string(path)?, variant)
+}
+
+/// A theme variant's stylesheet, ready to add to the document
+pub fn css(loader: &ExtensionLoader, theme_id: &str, variant: ThemeVariant) -> AppResult<ThemeCss> {
+    let definition = definition(loader, theme_id, variant)?;
+    let class_name = format!("theme-{}-{}", theme_id, variant.as_str());
+    Ok(ThemeCss {
+        css: CssBuilder::new(&definition).selector(format!(":root.{}", class_name)).build(),
+        theme_id: theme_id.to_string(),
+        variant,
+        class_name,
+    })
+}
+
+/// Split a theme choice such as `nordic-dark` into the theme and its variant
+fn parse_choice(choice: &str) -> Option<(&str, ThemeVariant)> {
+    if let Some(id) = choice.strip_suffix("-dark") {
+        return Some((id, ThemeVariant::Dark));
+    }
+    choice.strip_suffix("-light").map(|id| (id, ThemeVariant::Light))
+}
+
+/// Whether the choice names a built-in theme or an available theme variant
+fn is_available(loader: &ExtensionLoader, choice: &str) -> AppResult<bool> {
+    if BUILTIN_THEMES.contains(&choice) {
+        return Ok(true);
+    }
+    let Some((id, variant)) = parse_choice(choice) else {
+        return Ok(false);
+    };
+    Ok(list(loader)?.iter().any(|theme| theme.id == id && theme.variants.contains(&variant)))
+}
+
+fn active_path() -> AppResult<PathBuf> {
+    Ok(storage::app_dir()?.join(ACTIVE_THEME_FILE))
+}
+
+/// The saved theme choice, e.g. `dark` or `nordic-light`. Falls back to
+/// `system` when the theme has since been uninstalled.
+pub fn active(loader: &ExtensionLoader) -> AppResult<String> {
+    let path = active_path()?;
+    if !path.exists() {
+        return Ok("system".to_string());
+    }
+    let saved: ActiveTheme = serde_json::from_str(&fs::read_to_string(path)?)?;
+    if is_available(loader, &saved.theme)? {
+        Ok(saved.theme)
+    } else {
+        Ok("system".to_string())
+    }
+}
+
+/// Save the theme choice: a built-in theme or `<theme id>-<variant>`
+pub fn set_active(loader: &ExtensionLoader, choice: &str) -> AppResult<String> {
+    if !is_available(loader, choice)? {
+        return Err(AppError::ValidationError(format!("Theme '{}' is not available", choice)));
+    }
+    let saved = ActiveTheme {
+        theme: choice.to_string(),
+    };
+    fs::write(active_path()?, serde_json::to_string_pretty(&saved)?)?;
+    Ok(saved.theme)
+}
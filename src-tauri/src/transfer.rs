@@ -0,0 +1,407 @@
+//! Copying a table between connections, also between different databases.
+//! Missing target tables are created from the source's columns, with each
+//! type mapped to its nearest equivalent in the target's dialect; rows are read
+//! a page at a time and written as multi-row INSERT statements.
+
+use crate::db::{cell_literal, quote_identifier, quote_qualified};
+use crate::error::{AppError, AppResult};
+use crate::models::{CellValue, DatabaseType};
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Transfer ID -> flag set when the copy is asked to stop
+static RUNNING: OnceCell<Mutex<HashMap<String, Arc<AtomicBool>>>> = OnceCell::new();
+
+fn running() -> &'static Mutex<HashMap<String, Arc<AtomicBool>>> {
+    RUNNING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// A running copy, registered under its transfer ID until dropped
+pub struct RunningCopy {
+    id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl RunningCopy {
+    pub fn start(id: &str) -> AppResult<Self> {
+        let mut running = running().lock().unwrap_or_else(|e| e.into_inner());
+        if running.contains_key(id) {
+            return Err(AppError::ValidationError(format!("Transfer {} is already running", id)));
+        }
+        let cancelled = Arc::new(AtomicBool::new(false));
+        running.insert(id.to_string(), cancelled.clone());
+        Ok(Self {
+            id: id.to_string(),
+            cancelled,
+        })
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for RunningCopy {
+    fn drop(&mut self) {
+        running().lock().unwrap_or_else(|e| e.into_inner()).remove(&self.id);
+    }
+}
+
+/// Ask a running copy to stop after its current batch; false if none runs under `id`
+pub fn cancel(id: &str) -> bool {
+    match running().lock().unwrap_or_else(|e| e.into_inner()).get(id) {
+        Some(cancelled) => {
+            cancelled.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
+/// A column type in terms every database has an equivalent for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Boolean,
+    SmallInt,
+    Integer,
+    BigInt,
+    /// Precision and scale, when the source states them
+    Decimal(Option<(u32, u32)>),
+    Float,
+    Double,
+    Date,
+    Time,
+    Timestamp,
+    TimestampTz,
+    Uuid,
+    Json,
+    Binary,
+    /// Maximum length, when the source states one
+    Text(Option<u32>),
+}
+
+impl ColumnKind {
+    fn is_integer(self) -> bool {
+        matches!(self, ColumnKind::SmallInt | ColumnKind::Integer | ColumnKind::BigInt)
+    }
+}
+
+/// Numbers in a type's parentheses, e.g. `[10, 2]` for `DECIMAL(10,2)`; `MAX` counts as none
+fn type_arguments(data_type: &str) -> Vec<u32> {
+    data_type
+        .split_once('(')
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .map(|(arguments, _)| arguments.split(',').filter_map(|a| a.trim().parse().ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Classify a column type as the drivers report it in table schemas
+pub fn column_kind(database_type: &DatabaseType, data_type: &str) -> ColumnKind {
+    let data_type = data_type.trim().to_lowercase();
+    let base = data_type.split('(').next().unwrap_or_default().trim();
+    let arguments = type_arguments(&data_type);
+    let decimal = || ColumnKind::Decimal(arguments.first().map(|&p| (p, arguments.get(1).copied().unwrap_or(0))));
+    let text = || ColumnKind::Text(arguments.first().copied());
+
+    if matches!(database_type, DatabaseType::ClickHouse) {
+        return match base {
+            "bool" => ColumnKind::Boolean,
+            "int8" | "uint8" | "int16" => ColumnKind::SmallInt,
+            "uint16" | "int32" => ColumnKind::Integer,
+            b if b.starts_with("int") || b.starts_with("uint") => ColumnKind::BigInt,
+            "float32" => ColumnKind::Float,
+            "float64" => ColumnKind::Double,
+            b if b.starts_with("decimal") => decimal(),
+            "date" | "date32" => ColumnKind::Date,
+            b if b.starts_with("datetime") => ColumnKind::Timestamp,
+            "uuid" => ColumnKind::Uuid,
+            "json" | "object" => ColumnKind::Json,
+            "fixedstring" => text(),
+            _ => ColumnKind::Text(None),
+        };
+    }
+
+    if matches!(database_type, DatabaseType::Oracle) {
+        return match base {
+            "number" => match arguments[..] {
+                [] => ColumnKind::Decimal(None),
+                [p] | [p, 0] if p <= 4 => ColumnKind::SmallInt,
+                [p] | [p, 0] if p <= 9 => ColumnKind::Integer,
+                [p] | [p, 0] if p <= 18 => ColumnKind::BigInt,
+                _ => decimal(),
+            },
+            // NUMBER(*,0), as the driver reports INTEGER columns
+            "integer" => ColumnKind::Decimal(Some((38, 0))),
+            "binary_float" => ColumnKind::Float,
+            "binary_double" | "float" => ColumnKind::Double,
+            // Oracle dates carry a time of day
+            "date" => ColumnKind::Timestamp,
+            "timestamp" if data_type.ends_with("with time zone") => ColumnKind::TimestampTz,
+            "timestamp" => ColumnKind::Timestamp,
+            "raw" | "long raw" | "blob" => ColumnKind::Binary,
+            "json" => ColumnKind::Json,
+            b if b.contains("char") => text(),
+            _ => ColumnKind::Text(None),
+        };
+    }
+
+    match base {
+        b if b.starts_with("bool") => ColumnKind::Boolean,
+        "bit" if matches!(database_type, DatabaseType::MSSQL) => ColumnKind::Boolean,
+        "tinyint" | "smallint" | "int2" | "smallserial" | "year" => ColumnKind::SmallInt,
+        // Every SQLite integer holds 64 bits
+        "int" | "integer" if matches!(database_type, DatabaseType::SQLite) => ColumnKind::BigInt,
+        "int" | "integer" | "int4" | "mediumint" | "serial" => ColumnKind::Integer,
+        "bigint" | "int8" | "bigserial" => ColumnKind::BigInt,
+        // DuckDB's unsigned and 128-bit integers, in the next wider signed type
+        "utinyint" => ColumnKind::SmallInt,
+        "usmallint" => ColumnKind::Integer,
+        "uinteger" => ColumnKind::BigInt,
+        "ubigint" => ColumnKind::Decimal(Some((20, 0))),
+        "hugeint" | "uhugeint" => ColumnKind::Decimal(Some((38, 0))),
+        "decimal" | "numeric" | "money" | "smallmoney" => decimal(),
+        "float" if matches!(database_type, DatabaseType::MySQL | DatabaseType::DuckDB) => ColumnKind::Float,
+        "real" if matches!(database_type, DatabaseType::PostgreSQL | DatabaseType::MSSQL) => ColumnKind::Float,
+        "float4" => ColumnKind::Float,
+        "float" | "real" | "double" | "double precision" | "float8" => ColumnKind::Double,
+        "date" => ColumnKind::Date,
+        b if b.starts_with("time") && !b.starts_with("timestamp") => ColumnKind::Time,
+        "timestamptz" | "timestamp with time zone" | "datetimeoffset" => ColumnKind::TimestampTz,
+        b if b.starts_with("timestamp") || b.starts_with("datetime") || b == "smalldatetime" => ColumnKind::Timestamp,
+        "uuid" | "uniqueidentifier" => ColumnKind::Uuid,
+        "json" | "jsonb" => ColumnKind::Json,
+        b if b.contains("binary") || b.contains("blob") || b == "bytea" || b == "image" => ColumnKind::Binary,
+        b if b.contains("char") => text(),
+        _ => ColumnKind::Text(None),
+    }
+}
+
+/// The target's type for `kind`. Key columns get bounded types where the
+/// database cannot index unbounded text or binary data.
+pub fn column_type(database_type: &DatabaseType, kind: ColumnKind, key: bool) -> String {
+    let decimal = |max_precision: u32, max_scale: u32, precision: Option<(u32, u32)>| match precision {
+        Some((p, s)) => format!("DECIMAL({}, {})", p.clamp(1, max_precision), s.min(max_scale).min(p)),
+        None => "DECIMAL(38, 10)".to_string(),
+    };
+    let fixed = |name: &str| name.to_string();
+    match database_type {
+        DatabaseType::PostgreSQL => match kind {
+            ColumnKind::Boolean => fixed("BOOLEAN"),
+            ColumnKind::SmallInt => fixed("SMALLINT"),
+            ColumnKind::Integer => fixed("INTEGER"),
+            ColumnKind::BigInt => fixed("BIGINT"),
+            ColumnKind::Decimal(Some((p, s))) => format!("NUMERIC({}, {})", p.clamp(1, 1000), s.min(p)),
+            ColumnKind::Decimal(None) => fixed("NUMERIC"),
+            ColumnKind::Float => fixed("REAL"),
+            ColumnKind::Double => fixed("DOUBLE PRECISION"),
+            ColumnKind::Date => fixed("DATE"),
+            ColumnKind::Time => fixed("TIME"),
+            ColumnKind::Timestamp => fixed("TIMESTAMP"),
+            ColumnKind::TimestampTz => fixed("TIMESTAMPTZ"),
+            ColumnKind::Uuid => fixed("UUID"),
+            ColumnKind::Json => fixed("JSONB"),
+            ColumnKind::Binary => fixed("BYTEA"),
+            ColumnKind::Text(Some(length)) if length > 0 => format!("VARCHAR({})", length),
+            ColumnKind::Text(_) => fixed("TEXT"),
+        },
+        DatabaseType::MySQL => match kind {
+            ColumnKind::Boolean => fixed("BOOLEAN"),
+            ColumnKind::SmallInt => fixed("SMALLINT"),
+            ColumnKind::Integer => fixed("INT"),
+            ColumnKind::BigInt => fixed("BIGINT"),
+            ColumnKind::Decimal(precision) => decimal(65, 30, precision),
+            ColumnKind::Float => fixed("FLOAT"),
+            ColumnKind::Double => fixed("DOUBLE"),
+            ColumnKind::Date => fixed("DATE"),
+            ColumnKind::Time => fixed("TIME(6)"),
+            ColumnKind::Timestamp | ColumnKind::TimestampTz => fixed("DATETIME(6)"),
+            ColumnKind::Uuid => fixed("CHAR(36)"),
+            ColumnKind::Json => fixed("JSON"),
+            ColumnKind::Binary if key => fixed("VARBINARY(255)"),
+            ColumnKind::Binary => fixed("LONGBLOB"),
+            ColumnKind::Text(Some(length)) if length > 0 && length <= 16_383 => format!("VARCHAR({})", length),
+            ColumnKind::Text(_) if key => fixed("VARCHAR(255)"),
+            ColumnKind::Text(_) => fixed("LONGTEXT"),
+        },
+        DatabaseType::MSSQL => match kind {
+            ColumnKind::Boolean => fixed("BIT"),
+            ColumnKind::SmallInt => fixed("SMALLINT"),
+            ColumnKind::Integer => fixed("INT"),
+            ColumnKind::BigInt => fixed("BIGINT"),
+            ColumnKind::Decimal(precision) => decimal(38, 38, precision),
+            ColumnKind::Float => fixed("REAL"),
+            ColumnKind::Double => fixed("FLOAT"),
+            ColumnKind::Date => fixed("DATE"),
+            ColumnKind::Time => fixed("TIME"),
+            ColumnKind::Timestamp => fixed("DATETIME2"),
+            ColumnKind::TimestampTz => fixed("DATETIMEOFFSET"),
+            ColumnKind::Uuid => fixed("UNIQUEIDENTIFIER"),
+            ColumnKind::Binary if key => fixed("VARBINARY(900)"),
+            ColumnKind::Binary => fixed("VARBINARY(MAX)"),
+            ColumnKind::Text(Some(length)) if length > 0 && length <= 4000 => format!("NVARCHAR({})", length),
+            ColumnKind::Json | ColumnKind::Text(_) if key => fixed("NVARCHAR(450)"),
+            ColumnKind::Json | ColumnKind::Text(_) => fixed("NVARCHAR(MAX)"),
+        },
+        DatabaseType::ClickHouse => match kind {
+            ColumnKind::Boolean => fixed("Bool"),
+            ColumnKind::SmallInt => fixed("Int16"),
+            ColumnKind::Integer => fixed("Int32"),
+            ColumnKind::BigInt => fixed("Int64"),
+            ColumnKind::Decimal(precision) => decimal(76, 76, precision).replace("DECIMAL", "Decimal"),
+            ColumnKind::Float => fixed("Float32"),
+            ColumnKind::Double => fixed("Float64"),
+            ColumnKind::Date => fixed("Date32"),
+            ColumnKind::Timestamp | ColumnKind::TimestampTz => fixed("DateTime64(6)"),
+            ColumnKind::Uuid => fixed("UUID"),
+            ColumnKind::Time | ColumnKind::Json | ColumnKind::Binary | ColumnKind::Text(_) => fixed("String"),
+        },
+        DatabaseType::DuckDB => match kind {
+            ColumnKind::Boolean => fixed("BOOLEAN"),
+            ColumnKind::SmallInt => fixed("SMALLINT"),
+            ColumnKind::Integer => fixed("INTEGER"),
+            ColumnKind::BigInt => fixed("BIGINT"),
+            ColumnKind::Decimal(precision) => decimal(38, 38, precision),
+            ColumnKind::Float => fixed("REAL"),
+            ColumnKind::Double => fixed("DOUBLE"),
+            ColumnKind::Date => fixed("DATE"),
+            ColumnKind::Time => fixed("TIME"),
+            ColumnKind::Timestamp => fixed("TIMESTAMP"),
+            ColumnKind::TimestampTz => fixed("TIMESTAMPTZ"),
+            ColumnKind::Uuid => fixed("UUID"),
+            ColumnKind::Binary => fixed("BLOB"),
+            ColumnKind::Json | ColumnKind::Text(_) => fixed("VARCHAR"),
+        },
+        DatabaseType::Oracle => match kind {
+            // Booleans are numbers before Oracle 23
+            ColumnKind::Boolean => fixed("NUMBER(1)"),
+            ColumnKind::SmallInt => fixed("NUMBER(5)"),
+            ColumnKind::Integer => fixed("NUMBER(10)"),
+            ColumnKind::BigInt => fixed("NUMBER(19)"),
+            ColumnKind::Decimal(precision) => decimal(38, 38, precision).replace("DECIMAL", "NUMBER"),
+            ColumnKind::Float => fixed("BINARY_FLOAT"),
+            ColumnKind::Double => fixed("BINARY_DOUBLE"),
+            ColumnKind::Date => fixed("DATE"),
+            // Oracle has no time of day type
+            ColumnKind::Time => fixed("VARCHAR2(15)"),
+            ColumnKind::Timestamp => fixed("TIMESTAMP(6)"),
+            ColumnKind::TimestampTz => fixed("TIMESTAMP(6) WITH TIME ZONE"),
+            ColumnKind::Uuid => fixed("VARCHAR2(36)"),
+            ColumnKind::Binary if key => fixed("RAW(2000)"),
+            ColumnKind::Binary => fixed("BLOB"),
+            ColumnKind::Text(Some(length)) if length > 0 && length <= 4000 => format!("VARCHAR2({} CHAR)", length),
+            ColumnKind::Json | ColumnKind::Text(_) if key => fixed("VARCHAR2(4000)"),
+            ColumnKind::Json | ColumnKind::Text(_) => fixed("CLOB"),
+        },
+        _ => match kind {
+            ColumnKind::Boolean => fixed("BOOLEAN"),
+            ColumnKind::SmallInt | ColumnKind::Integer | ColumnKind::BigInt => fixed("INTEGER"),
+            ColumnKind::Decimal(_) => fixed("NUMERIC"),
+            ColumnKind::Float | ColumnKind::Double => fixed("REAL"),
+            ColumnKind::Date => fixed("DATE"),
+            ColumnKind::Time => fixed("TIME"),
+            ColumnKind::Timestamp | ColumnKind::TimestampTz => fixed("DATETIME"),
+            ColumnKind::Binary => fixed("BLOB"),
+            ColumnKind::Text(Some(length)) if length > 0 => format!("VARCHAR({})", length),
+            ColumnKind::Uuid | ColumnKind::Json | ColumnKind::Text(_) => fixed("TEXT"),
+        },
+    }
+}
+
+/// A column of a table to create on the target
+pub struct NewTableColumn {
+    pub name: String,
+    pub kind: ColumnKind,
+    pub nullable: bool,
+}
+
+/// CREATE TABLE for the target, keeping the source's primary key
+pub fn create_table_sql(
+    database_type: &DatabaseType,
+    table: &str,
+    columns: &[NewTableColumn],
+    primary_keys: &[String],
+) -> String {
+    let clickhouse = matches!(database_type, DatabaseType::ClickHouse);
+    let key_list = primary_keys
+        .iter()
+        .map(|key| quote_identifier(database_type, key))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut definitions: Vec<String> = columns
+        .iter()
+        .map(|column| {
+            let key = primary_keys.contains(&column.name);
+            let data_type = column_type(database_type, column.kind, key);
+            let name = quote_identifier(database_type, &column.name);
+            match (column.nullable && !key, clickhouse) {
+                // ClickHouse columns reject NULL unless the type is wrapped
+                (true, true) => format!("{} Nullable({})", name, data_type),
+                (true, false) | (false, true) => format!("{} {}", name, data_type),
+                (false, false) => format!("{} {} NOT NULL", name, data_type),
+            }
+        })
+        .collect();
+    if !clickhouse && !key_list.is_empty() {
+        definitions.push(format!("PRIMARY KEY ({})", key_list));
+    }
+
+    let mut sql = format!(
+        "CREATE TABLE {} (\n  {}\n)",
+        quote_qualified(database_type, table),
+        definitions.join(",\n  ")
+    );
+    if clickhouse {
+        // ClickHouse tables need an engine; the primary key becomes the sorting key
+        if key_list.is_empty() {
+            sql.push_str(" ENGINE = MergeTree ORDER BY tuple()");
+        } else {
+            sql.push_str(&format!(" ENGINE = MergeTree ORDER BY ({})", key_list));
+        }
+    }
+    sql
+}
+
+/// A source value in the form the target column takes. Binary values arrive
+/// base64 encoded; booleans stored as numbers become booleans and the other
+/// way round.
+pub fn copy_value(kind: ColumnKind, value: Value, binary: bool) -> CellValue {
+    match value {
+        Value::String(text) if binary || text.starts_with("[base64: ") => {
+            let encoded = text.strip_prefix("[base64: ").and_then(|s| s.strip_suffix(']')).unwrap_or(&text);
+            CellValue::Bytes(encoded.to_string())
+        }
+        Value::String(text) => {
+            if kind == ColumnKind::Boolean {
+                match text.trim().to_lowercase().as_str() {
+                    "t" | "true" | "1" | "y" | "yes" | "on" => return CellValue::Bool(true),
+                    "f" | "false" | "0" | "n" | "no" | "off" => return CellValue::Bool(false),
+                    _ => {}
+                }
+            }
+            CellValue::Text(text)
+        }
+        Value::Number(number) if kind == ColumnKind::Boolean => CellValue::Bool(number.as_f64() != Some(0.0)),
+        Value::Bool(value) if kind.is_integer() => CellValue::Int(i64::from(value)),
+        other => CellValue::from(other),
+    }
+}
+
+/// Rows as the VALUES list of a multi-row INSERT. Oracle before 23 has no
+/// multi-row VALUES, so its rows are a UNION ALL query instead.
+pub fn values_list(database_type: &DatabaseType, rows: &[Vec<CellValue>]) -> String {
+    let oracle = matches!(database_type, DatabaseType::Oracle);
+    rows.iter()
+        .map(|row| {
+            let literals: Vec<String> = row.iter().map(|value| cell_literal(database_type, value)).collect();
+            if oracle {
+                format!("SELECT {} FROM dual", literals.join(", "))
+            } else {
+                format!("({})", literals.join(", "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(if oracle { "\n  UNION ALL " } else { ",\n  " })
+}
@@ -7,7 +7,8 @@ import { RenameTableDialog } from "@/components/table";
 import { CreateSchemaDialog } from "@/components/database";
 import { AIPanel } from "@/components/ai";
 import { useUIStore } from "@/stores";
-import { useKeyboardShortcuts } from "@/hooks";
+import { useKeyboardShortcuts, useWorkspacePersistence } from "@/hooks";
+import { syncBuiltinExtensions } from "@/lib/extensions";
 
 function App() {
   const { theme, setTheme, appStyle, setAppStyle } = useUIStore();
@@ -15,10 +16,14 @@ function App() {
   // Initialize keyboard shortcuts
   useKeyboardShortcuts();
 
+  // Reopen the tabs from the last session and keep saving them
+  useWorkspacePersistence();
+
   // Initialize theme and app style on mount
   useEffect(() => {
     setTheme(theme);
     setAppStyle(appStyle);
+    syncBuiltinExtensions().catch((error) => console.error("[Extensions] Failed to load bundled extensions:", error));
   }, []);
 
   return (
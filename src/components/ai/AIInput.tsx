@@ -1,5 +1,17 @@
 import { useState, useRef, useEffect, useCallback, useMemo } from "react";
-import { Send, Sparkles, Search, PlusCircle, RefreshCw, Trash2, GitMerge, Database, Table2, FileCode } from "lucide-react";
+import {
+  Send,
+  Sparkles,
+  Search,
+  PlusCircle,
+  RefreshCw,
+  Trash2,
+  GitMerge,
+  Database,
+  Table2,
+  FileCode,
+  MessageCircleQuestion,
+} from "lucide-react";
 import { Button } from "@/components/ui";
 import { useAIStore } from "@/lib/ai/store";
 import { cn } from "@/lib/utils";
@@ -8,6 +20,8 @@ import { ProviderModelSwitcher } from "./ProviderModelSwitcher";
 
 interface AIInputProps {
   onSend: (message: string) => void;
+  /** Called with the question for `/ask`, which runs a query to answer it */
+  onAsk?: (question: string) => void;
   isLoading: boolean;
 }
 
@@ -27,6 +41,12 @@ interface SlashCommand {
 }
 
 const SLASH_COMMANDS: SlashCommand[] = [
+  {
+    name: "ask",
+    description: "Answer a question by running a read-only query",
+    prompt: "",
+    icon: MessageCircleQuestion,
+  },
   {
     name: "select",
     description: "Generate a SELECT query",
@@ -77,7 +97,7 @@ const SLASH_COMMANDS: SlashCommand[] = [
   },
 ];
 
-export function AIInput({ onSend, isLoading }: AIInputProps) {
+export function AIInput({ onSend, onAsk, isLoading }: AIInputProps) {
   const [value, setValue] = useState("");
   const textareaRef = useRef<HTMLTextAreaElement>(null);
   const highlightRef = useRef<HTMLDivElement>(null);
@@ -360,9 +380,13 @@ export function AIInput({ onSend, isLoading }: AIInputProps) {
 
   const handleSubmit = () => {
     if (!value.trim() || isLoading || !configured) return;
-    // Expand slash command to full prompt before sending
-    const messageToSend = expandSlashCommand(value.trim());
-    onSend(messageToSend);
+    const ask = value.trim().match(/^\/ask\s+([\s\S]+)/i);
+    if (ask && onAsk) {
+      onAsk(ask[1].trim());
+    } else {
+      // Expand slash command to full prompt before sending
+      onSend(expandSlashCommand(value.trim()));
+    }
     setValue("");
     setDropdown((prev) => ({ ...prev, show: false }));
   };
@@ -50,6 +50,7 @@ export function AIPanel() {
     toggleHistoryPanel,
     getActiveSession,
     createNewChatSession,
+    askDataQuestion,
   } = useAIStore();
 
   const activeSession = getActiveSession();
@@ -281,7 +282,7 @@ export function AIPanel() {
         </ScrollArea>
 
         {/* Input area */}
-        <AIInput onSend={sendMessage} isLoading={isLoading} />
+        <AIInput onSend={sendMessage} onAsk={askDataQuestion} isLoading={isLoading} />
       </div>
 
       <AISettingsDialog open={showSettings} onOpenChange={setShowSettings} />
@@ -20,7 +20,21 @@ import {
   SelectValue,
 } from "@/components/ui";
 import { useAIStore } from "@/lib/ai/store";
-import { PROVIDER_INFO, DEFAULT_MODELS, type AIProviderType } from "@/lib/ai/types";
+import {
+  PROVIDER_INFO,
+  DEFAULT_MODELS,
+  type AIProviderType,
+  type GeminiSafetyThreshold,
+} from "@/lib/ai/types";
+import { DEFAULT_CONTEXT_TOKEN_BUDGET } from "@/lib/ai/context";
+import { getAIUsageStats } from "@/lib/ai/usage";
+import {
+  loadPromptTemplates,
+  resetPromptToDefault,
+  savePromptTemplate,
+  type PromptName,
+} from "@/lib/ai/prompts";
+import type { AIUsageStats, PromptTemplate } from "@/types";
 import { open as openUrl } from "@tauri-apps/plugin-shell";
 
 interface AISettingsDialogProps {
@@ -53,8 +67,14 @@ export function AISettingsDialog({
     anthropicModel: settings.aiAnthropicModel || DEFAULT_MODELS.anthropic,
     geminiModel: settings.aiGeminiModel || DEFAULT_MODELS.gemini,
     openaiModel: settings.aiOpenaiModel || DEFAULT_MODELS.openai,
+    openaiOrganization: settings.aiOpenaiOrganization || "",
     temperature: settings.aiTemperature ?? 0.1,
     maxTokens: settings.aiMaxTokens ?? 2048,
+    contextTokenBudget: settings.aiContextTokenBudget ?? DEFAULT_CONTEXT_TOKEN_BUDGET,
+    monthlyTokenBudget: settings.aiMonthlyTokenBudget?.toString() ?? "",
+    monthlyCostBudget: settings.aiMonthlyCostBudget?.toString() ?? "",
+    fallbackProvider: (settings.aiFallbackProvider ?? "none") as string,
+    geminiSafetyThreshold: (settings.aiGeminiSafetyThreshold ?? "default") as string,
   });
 
   const [showKeys, setShowKeys] = useState<Record<AIProviderType, boolean>>({
@@ -64,6 +84,67 @@ export function AISettingsDialog({
   });
 
   const [isSaving, setIsSaving] = useState(false);
+  const [usageStats, setUsageStats] = useState<AIUsageStats | null>(null);
+
+  const [promptTemplates, setPromptTemplates] = useState<PromptTemplate[]>([]);
+  const [selectedPrompt, setSelectedPrompt] = useState<PromptName>("sql_generation");
+  const [promptDraft, setPromptDraft] = useState("");
+  const [promptError, setPromptError] = useState<string | null>(null);
+  const [isSavingPrompt, setIsSavingPrompt] = useState(false);
+
+  const showPromptTemplates = (templates: PromptTemplate[], name: PromptName) => {
+    setPromptTemplates(templates);
+    setPromptDraft(templates.find((t) => t.name === name)?.content ?? "");
+    setPromptError(null);
+  };
+
+  // Load the prompt templates when dialog opens
+  useEffect(() => {
+    if (open) {
+      loadPromptTemplates()
+        .then((templates) => showPromptTemplates(templates, selectedPrompt))
+        .catch((e) => setPromptError(String(e)));
+    }
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [open]);
+
+  const handleSelectPrompt = (name: PromptName) => {
+    setSelectedPrompt(name);
+    showPromptTemplates(promptTemplates, name);
+  };
+
+  const handleSavePrompt = async () => {
+    setIsSavingPrompt(true);
+    try {
+      showPromptTemplates(await savePromptTemplate(selectedPrompt, promptDraft), selectedPrompt);
+    } catch (e) {
+      setPromptError(String(e));
+    } finally {
+      setIsSavingPrompt(false);
+    }
+  };
+
+  const handleResetPrompt = async () => {
+    setIsSavingPrompt(true);
+    try {
+      showPromptTemplates(await resetPromptToDefault(selectedPrompt), selectedPrompt);
+    } catch (e) {
+      setPromptError(String(e));
+    } finally {
+      setIsSavingPrompt(false);
+    }
+  };
+
+  const currentPrompt = promptTemplates.find((t) => t.name === selectedPrompt);
+
+  // Load this month's usage when dialog opens
+  useEffect(() => {
+    if (open) {
+      getAIUsageStats()
+        .then(setUsageStats)
+        .catch(() => setUsageStats(null));
+    }
+  }, [open]);
 
   // Fetch models when dialog opens
   useEffect(() => {
@@ -83,8 +164,14 @@ export function AISettingsDialog({
         anthropicModel: settings.aiAnthropicModel || DEFAULT_MODELS.anthropic,
         geminiModel: settings.aiGeminiModel || DEFAULT_MODELS.gemini,
         openaiModel: settings.aiOpenaiModel || DEFAULT_MODELS.openai,
+        openaiOrganization: settings.aiOpenaiOrganization || "",
         temperature: settings.aiTemperature ?? 0.1,
         maxTokens: settings.aiMaxTokens ?? 2048,
+        contextTokenBudget: settings.aiContextTokenBudget ?? DEFAULT_CONTEXT_TOKEN_BUDGET,
+        monthlyTokenBudget: settings.aiMonthlyTokenBudget?.toString() ?? "",
+        monthlyCostBudget: settings.aiMonthlyCostBudget?.toString() ?? "",
+        fallbackProvider: settings.aiFallbackProvider ?? "none",
+        geminiSafetyThreshold: settings.aiGeminiSafetyThreshold ?? "default",
       });
     }
   }, [open, settings]);
@@ -100,8 +187,18 @@ export function AISettingsDialog({
         aiAnthropicModel: localSettings.anthropicModel,
         aiGeminiModel: localSettings.geminiModel,
         aiOpenaiModel: localSettings.openaiModel,
+        aiOpenaiOrganization: localSettings.openaiOrganization.trim() || undefined,
         aiTemperature: localSettings.temperature,
         aiMaxTokens: localSettings.maxTokens,
+        aiContextTokenBudget: localSettings.contextTokenBudget,
+        aiMonthlyTokenBudget: parseInt(localSettings.monthlyTokenBudget) || undefined,
+        aiMonthlyCostBudget: parseFloat(localSettings.monthlyCostBudget) || undefined,
+        aiGeminiSafetyThreshold:
+          localSettings.geminiSafetyThreshold === "default"
+            ? undefined
+            : (localSettings.geminiSafetyThreshold as GeminiSafetyThreshold),
+        aiFallbackProvider:
+          localSettings.fallbackProvider === "none" ? undefined : (localSettings.fallbackProvider as AIProviderType),
       });
       onOpenChange(false);
     } finally {
@@ -177,10 +274,11 @@ export function AISettingsDialog({
         </DialogHeader>
 
         <Tabs defaultValue="provider" className="w-full">
-          <TabsList className="grid w-full grid-cols-3">
+          <TabsList className="grid w-full grid-cols-4">
             <TabsTrigger value="provider">Provider</TabsTrigger>
             <TabsTrigger value="model">Model</TabsTrigger>
             <TabsTrigger value="parameters">Parameters</TabsTrigger>
+            <TabsTrigger value="prompts">Prompts</TabsTrigger>
           </TabsList>
 
           <TabsContent value="provider" className="space-y-4 py-4">
@@ -245,6 +343,78 @@ export function AISettingsDialog({
               </p>
             </div>
 
+            {currentProvider === "openai" && (
+              <div className="space-y-2">
+                <Label className="text-sm font-medium">Organization ID (optional)</Label>
+                <Input
+                  value={localSettings.openaiOrganization}
+                  onChange={(e) =>
+                    setLocalSettings((prev) => ({ ...prev, openaiOrganization: e.target.value }))
+                  }
+                  placeholder="org-..."
+                  className="font-mono text-sm"
+                />
+                <p className="text-xs text-muted-foreground">
+                  Bill requests to this organization when your key belongs to more than one.
+                </p>
+              </div>
+            )}
+
+            {currentProvider === "gemini" && (
+              <div className="space-y-2">
+                <Label className="text-sm font-medium">Safety Filter</Label>
+                <Select
+                  value={localSettings.geminiSafetyThreshold}
+                  onValueChange={(value) => setLocalSettings((prev) => ({ ...prev, geminiSafetyThreshold: value }))}
+                >
+                  <SelectTrigger>
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="default">Google default</SelectItem>
+                    <SelectItem value="BLOCK_NONE">Block none</SelectItem>
+                    <SelectItem value="BLOCK_ONLY_HIGH">Block only high risk</SelectItem>
+                    <SelectItem value="BLOCK_MEDIUM_AND_ABOVE">Block medium risk and above</SelectItem>
+                    <SelectItem value="BLOCK_LOW_AND_ABOVE">Block low risk and above</SelectItem>
+                  </SelectContent>
+                </Select>
+                <p className="text-xs text-muted-foreground">
+                  How readily Gemini withholds answers it rates as harassment, hate speech, sexual or dangerous
+                  content. Lower it if table or column names trip the filter.
+                </p>
+              </div>
+            )}
+
+            {/* Fallback Provider */}
+            <div className="space-y-2">
+              <Label className="text-sm font-medium">Fallback Provider</Label>
+              <Select
+                value={localSettings.fallbackProvider}
+                onValueChange={(value) => setLocalSettings((prev) => ({ ...prev, fallbackProvider: value }))}
+              >
+                <SelectTrigger>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="none">None</SelectItem>
+                  {(Object.keys(PROVIDER_INFO) as AIProviderType[])
+                    .filter((provider) => provider !== currentProvider)
+                    .map((provider) => (
+                      <SelectItem key={provider} value={provider}>
+                        <div className="flex items-center gap-2">
+                          {PROVIDER_ICONS[provider]}
+                          {PROVIDER_INFO[provider].displayName}
+                        </div>
+                      </SelectItem>
+                    ))}
+                </SelectContent>
+              </Select>
+              <p className="text-xs text-muted-foreground">
+                Used with its saved API key when {PROVIDER_INFO[currentProvider].displayName} keeps failing with
+                rate limits, outages or network errors.
+              </p>
+            </div>
+
             {/* Get API Key link */}
             <div className="rounded-lg border border-border bg-muted/50 p-4">
               <h4 className="text-sm font-medium mb-2">Get an API key</h4>
@@ -372,6 +542,117 @@ export function AISettingsDialog({
                 Maximum number of tokens in the AI response (100-8192).
               </p>
             </div>
+
+            {/* Schema Context Budget */}
+            <div className="space-y-2">
+              <Label className="text-sm font-medium">Schema Context Budget</Label>
+              <Input
+                type="number"
+                value={localSettings.contextTokenBudget}
+                onChange={(e) =>
+                  setLocalSettings((prev) => ({
+                    ...prev,
+                    contextTokenBudget: parseInt(e.target.value) || DEFAULT_CONTEXT_TOKEN_BUDGET,
+                  }))
+                }
+                min={500}
+                max={100000}
+                className="w-full"
+              />
+              <p className="text-xs text-muted-foreground">
+                Tokens spent on table schemas per request. On large databases only the tables most relevant to
+                your prompt are sent with their columns.
+              </p>
+            </div>
+
+            {/* Monthly Budget */}
+            <div className="space-y-2">
+              <Label className="text-sm font-medium">Monthly Budget</Label>
+              <div className="flex gap-2">
+                <Input
+                  type="number"
+                  placeholder="Tokens (no limit)"
+                  value={localSettings.monthlyTokenBudget}
+                  onChange={(e) =>
+                    setLocalSettings((prev) => ({ ...prev, monthlyTokenBudget: e.target.value }))
+                  }
+                  min={0}
+                  className="w-full"
+                />
+                <Input
+                  type="number"
+                  placeholder="USD (no limit)"
+                  value={localSettings.monthlyCostBudget}
+                  onChange={(e) =>
+                    setLocalSettings((prev) => ({ ...prev, monthlyCostBudget: e.target.value }))
+                  }
+                  min={0}
+                  step={0.01}
+                  className="w-full"
+                />
+              </div>
+              <p className="text-xs text-muted-foreground">
+                AI requests are refused once this month's usage reaches either limit. Leave empty for no limit.
+              </p>
+              {usageStats && (
+                <p className="text-xs text-muted-foreground">
+                  This month: {usageStats.totalTokens.toLocaleString()} tokens in {usageStats.requestCount}{" "}
+                  {usageStats.requestCount === 1 ? "request" : "requests"}, about $
+                  {usageStats.estimatedCost.toFixed(2)}
+                </p>
+              )}
+            </div>
+          </TabsContent>
+
+          <TabsContent value="prompts" className="space-y-4 py-4">
+            {/* Prompt Templates */}
+            <div className="space-y-2">
+              <Label className="text-sm font-medium">Prompt</Label>
+              <Select value={selectedPrompt} onValueChange={(value) => handleSelectPrompt(value as PromptName)}>
+                <SelectTrigger>
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {promptTemplates.map((template) => (
+                    <SelectItem key={template.name} value={template.name}>
+                      {template.description}
+                      {template.content ? " (custom)" : ""}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+
+            <div className="space-y-2">
+              <Label className="text-sm font-medium">Template</Label>
+              <textarea
+                value={promptDraft}
+                onChange={(e) => setPromptDraft(e.target.value)}
+                placeholder="Using the built-in prompt. Write a template here to replace it."
+                rows={12}
+                className="w-full rounded-md border bg-background px-3 py-2 font-mono text-xs focus:outline-none focus:ring-1 focus:ring-ring"
+              />
+              <p className="text-xs text-muted-foreground">
+                Use {"{{schema}}"}, {"{{dialect}}"} and {"{{selected_table}}"} to insert the tables and columns,
+                the database type and the selected table. Templates are kept in the prompts folder of the app
+                data directory, so a team can share them.
+              </p>
+              {promptError && <p className="text-xs text-destructive">{promptError}</p>}
+            </div>
+
+            <div className="flex justify-end gap-2">
+              <Button
+                variant="outline"
+                size="sm"
+                onClick={handleResetPrompt}
+                disabled={isSavingPrompt || !currentPrompt?.content}
+              >
+                Reset to Default
+              </Button>
+              <Button size="sm" onClick={handleSavePrompt} disabled={isSavingPrompt || !promptDraft.trim()}>
+                Save Template
+              </Button>
+            </div>
           </TabsContent>
         </Tabs>
 
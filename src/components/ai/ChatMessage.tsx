@@ -8,11 +8,13 @@ import {
   AlertCircle,
   Play,
   Coins,
+  Table2,
+  Shuffle,
 } from "lucide-react";
 import ReactMarkdown from "react-markdown";
 import { Button } from "@/components/ui";
 import { cn } from "@/lib/utils";
-import type { AIChatMessage } from "@/lib/ai";
+import { PROVIDER_INFO, type AIChatMessage } from "@/lib/ai";
 import { useQueryStore } from "@/stores/query";
 import { useConnectionsStore } from "@/stores/connections";
 import { useDatabase } from "@/hooks/useDatabase";
@@ -114,6 +116,7 @@ export function ChatMessage({ message }: ChatMessageProps) {
             sql: message.sql,
             limit: undefined,
             offset: undefined,
+            sessionId: tabId,
           },
           tabId
         );
@@ -371,11 +374,39 @@ export function ChatMessage({ message }: ChatMessageProps) {
           </div>
         )}
 
-        {/* Token usage indicator */}
-        {message.usage && !isUser && (
-          <div className="flex items-center gap-2 text-[10px] text-muted-foreground/60">
-            <Coins className="h-3 w-3" />
-            <span>{formatTokens(message.usage.totalTokens)} tokens</span>
+        {/* Token usage and schema context indicators */}
+        {(message.usage || message.context || message.servedBy?.fallback) && !isUser && (
+          <div className="flex items-center gap-3 text-[10px] text-muted-foreground/60">
+            {message.usage && (
+              <span className="flex items-center gap-2">
+                <Coins className="h-3 w-3" />
+                {formatTokens(message.usage.totalTokens)} tokens
+              </span>
+            )}
+            {message.context && message.context.included.length + message.context.namesOnly.length > 0 && (
+              <span
+                className="flex items-center gap-1.5 cursor-help"
+                title={[
+                  `With columns: ${message.context.included.join(", ") || "none"}`,
+                  `Names only: ${message.context.namesOnly.join(", ") || "none"}`,
+                  `Left out: ${message.context.omitted.join(", ") || "none"}`,
+                  `~${formatTokens(message.context.estimatedTokens)} of ${formatTokens(message.context.tokenBudget)} tokens`,
+                ].join("\n")}
+              >
+                <Table2 className="h-3 w-3" />
+                {message.context.included.length} tables in context
+                {message.context.omitted.length > 0 && ` (${message.context.omitted.length} left out)`}
+              </span>
+            )}
+            {message.servedBy?.fallback && (
+              <span
+                className="flex items-center gap-1.5 cursor-help"
+                title={`The configured provider was unavailable; ${message.servedBy.model} answered instead`}
+              >
+                <Shuffle className="h-3 w-3" />
+                Answered by {PROVIDER_INFO[message.servedBy.provider].displayName}
+              </span>
+            )}
           </div>
         )}
 
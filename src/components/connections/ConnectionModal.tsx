@@ -1,5 +1,16 @@
 import { useState, useEffect, useMemo } from "react";
-import { Loader2, CheckCircle2, XCircle, Database, HelpCircle, Server, Key, FolderOpen } from "lucide-react";
+import {
+  Loader2,
+  CheckCircle2,
+  XCircle,
+  Database,
+  HelpCircle,
+  Server,
+  Key,
+  FolderOpen,
+  Tag,
+  Timer,
+} from "lucide-react";
 import {
   Dialog,
   DialogContent,
@@ -10,6 +21,7 @@ import {
 } from "@/components/ui/dialog";
 import {
   Button,
+  Checkbox,
   Input,
   Label,
   Select,
@@ -26,7 +38,7 @@ import {
 import { Separator } from "@/components/ui/separator";
 import { useUIStore } from "@/stores";
 import { useDatabase } from "@/hooks";
-import type { ConnectionConfig, DatabaseType } from "@/types";
+import type { ConnectionConfig, ConnectionEnvironment, ConnectionTemplate, DatabaseType } from "@/types";
 import { cn } from "@/lib/utils";
 import { DATABASE_DEFAULTS, DATABASE_METADATA } from "@/lib/constants";
 
@@ -96,17 +108,22 @@ function Section({ icon, title, children }: SectionProps) {
 
 export function ConnectionModal() {
   const { showConnectionModal, editingConnectionId, setShowConnectionModal } = useUIStore();
-  const { saveConnection, testConnection, getConnection } = useDatabase();
+  const { saveConnection, testConnection, getConnection, listConnectionTemplates, checkTemplateHost } = useDatabase();
   const [isLoading, setIsLoading] = useState(false);
   const [isTesting, setIsTesting] = useState(false);
   const [isSaving, setIsSaving] = useState(false);
   const [testResult, setTestResult] = useState<{ success: boolean; message: string; serverVersion?: string } | null>(null);
 
   const [formData, setFormData] = useState<ConnectionConfig>(INITIAL_FORM_DATA);
+  const [templates, setTemplates] = useState<ConnectionTemplate[]>([]);
+  const [templateId, setTemplateId] = useState<string | null>(null);
 
   const isEditMode = editingConnectionId !== null;
   const defaults = useMemo(() => DATABASE_DEFAULTS[formData.databaseType], [formData.databaseType]);
   const isSqlite = formData.databaseType === "sqlite";
+  const isDuckDb = formData.databaseType === "duckdb";
+  const isOracle = formData.databaseType === "oracle";
+  const template = templates.find((t) => t.id === templateId);
 
   // Get effective values (use placeholder defaults when field is empty)
   const getEffectiveConfig = (): ConnectionConfig => ({
@@ -137,10 +154,18 @@ export function ConnectionModal() {
     }
   }, [showConnectionModal, editingConnectionId, getConnection]);
 
+  // Load managed service templates for new connections
+  useEffect(() => {
+    if (showConnectionModal && !editingConnectionId && templates.length === 0) {
+      listConnectionTemplates().then(setTemplates);
+    }
+  }, [showConnectionModal, editingConnectionId, templates.length, listConnectionTemplates]);
+
   // Reset form when modal closes
   useEffect(() => {
     if (!showConnectionModal) {
       setFormData(INITIAL_FORM_DATA);
+      setTemplateId(null);
       setTestResult(null);
       setIsSaving(false);
       setIsTesting(false);
@@ -162,6 +187,13 @@ export function ConnectionModal() {
 
     try {
       const effectiveConfig = getEffectiveConfig();
+      if (template && formData.host) {
+        const problem = await checkTemplateHost(template.id, formData.host);
+        if (problem) {
+          setTestResult({ success: false, message: problem });
+          return;
+        }
+      }
       const result = await testConnection(effectiveConfig);
       setTestResult({
         success: result.success,
@@ -193,7 +225,26 @@ export function ConnectionModal() {
     }
   };
 
-  const canTest = formData.name.trim() && (isSqlite ? formData.filePath?.trim() : formData.database.trim());
+  // Fill in the port, TLS mode and default names the service expects
+  const applyTemplate = (id: string) => {
+    const selected = templates.find((t) => t.id === id);
+    setTemplateId(selected?.id ?? null);
+    if (!selected) {
+      return;
+    }
+    setFormData({
+      ...formData,
+      databaseType: selected.databaseType,
+      port: selected.port === DATABASE_DEFAULTS[selected.databaseType].port ? undefined : selected.port,
+      sslMode: selected.sslMode,
+      username: selected.username ?? "",
+      database: selected.database ?? formData.database,
+    });
+  };
+
+  // An empty DuckDB path opens an in-memory database
+  const canTest =
+    formData.name.trim() && (isDuckDb || (isSqlite ? formData.filePath?.trim() : formData.database.trim()));
   const canSave = canTest && testResult?.success;
 
   return (
@@ -247,6 +298,7 @@ export function ConnectionModal() {
                   value={formData.databaseType}
                   onValueChange={(value) => {
                     const dbType = value as DatabaseType;
+                    setTemplateId(null);
                     setFormData({
                       ...formData,
                       databaseType: dbType,
@@ -270,11 +322,144 @@ export function ConnectionModal() {
                   </SelectContent>
                 </Select>
               </FormField>
+
+              {!isEditMode && templates.length > 0 && (
+                <FormField
+                  label="Managed Service"
+                  htmlFor="template"
+                  hint="Fill in the port, SSL mode and default names for a cloud database service"
+                >
+                  <Select value={templateId ?? "none"} onValueChange={applyTemplate}>
+                    <SelectTrigger id="template" className="transition-colors">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value="none">None</SelectItem>
+                      {templates.map((t) => (
+                        <SelectItem key={t.id} value={t.id}>
+                          {t.name}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                  {template && <p className="text-xs text-muted-foreground">{template.notes}</p>}
+                </FormField>
+              )}
             </Section>
 
             <Separator />
 
-            {!isSqlite ? (
+            {/* Organization Section */}
+            <Section icon={<Tag className="h-4 w-4" />} title="Organization">
+              <div className="grid grid-cols-3 gap-4">
+                <div className="col-span-2">
+                  <FormField label="Group" htmlFor="group" hint="Folder in the sidebar. Use / for nested folders.">
+                    <Input
+                      id="group"
+                      placeholder="Clients/Acme"
+                      value={formData.group || ""}
+                      onChange={(e) => setFormData({ ...formData, group: e.target.value || undefined })}
+                      className="transition-colors"
+                    />
+                  </FormField>
+                </div>
+                <FormField label="Color" htmlFor="color" hint="Label color shown next to the connection">
+                  <Input
+                    id="color"
+                    type="color"
+                    value={formData.color || "#64748b"}
+                    onChange={(e) => setFormData({ ...formData, color: e.target.value })}
+                    className="h-9 p-1"
+                  />
+                </FormField>
+              </div>
+              <FormField
+                label="Environment"
+                htmlFor="environment"
+                hint="Production connections are read-only unless you allow changes"
+              >
+                <Select
+                  value={formData.environment || "none"}
+                  onValueChange={(value) =>
+                    setFormData({
+                      ...formData,
+                      environment: value === "none" ? undefined : (value as ConnectionEnvironment),
+                    })
+                  }
+                >
+                  <SelectTrigger id="environment" className="transition-colors">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value="none">None</SelectItem>
+                    <SelectItem value="dev">Development</SelectItem>
+                    <SelectItem value="staging">Staging</SelectItem>
+                    <SelectItem value="prod">Production</SelectItem>
+                  </SelectContent>
+                </Select>
+              </FormField>
+              <div className="flex items-center gap-2">
+                <Checkbox
+                  id="readOnly"
+                  checked={formData.readOnly ?? formData.environment === "prod"}
+                  onCheckedChange={(checked: boolean) => setFormData({ ...formData, readOnly: checked })}
+                />
+                <Label htmlFor="readOnly" className="text-sm">
+                  Read-only: refuse changes to data and schema
+                </Label>
+              </div>
+            </Section>
+
+            <Separator />
+
+            {/* Query Limits Section */}
+            <Section icon={<Timer className="h-4 w-4" />} title="Query Limits">
+              <div className="grid grid-cols-2 gap-4">
+                <FormField
+                  label="Statement Timeout (ms)"
+                  htmlFor="statementTimeoutMs"
+                  hint="Cancel statements that run longer"
+                >
+                  <Input
+                    id="statementTimeoutMs"
+                    type="number"
+                    min={1}
+                    placeholder="No timeout"
+                    value={formData.pool?.statementTimeoutMs || ""}
+                    onChange={(e) =>
+                      setFormData({
+                        ...formData,
+                        pool: {
+                          ...formData.pool,
+                          statementTimeoutMs: e.target.value ? parseInt(e.target.value, 10) : undefined,
+                        },
+                      })
+                    }
+                    className="transition-colors"
+                  />
+                </FormField>
+                <FormField label="Row Limit" htmlFor="defaultRowLimit" hint="Most rows kept from each result">
+                  <Input
+                    id="defaultRowLimit"
+                    type="number"
+                    min={1}
+                    placeholder="No limit"
+                    value={formData.defaultRowLimit || ""}
+                    onChange={(e) =>
+                      setFormData({
+                        ...formData,
+                        defaultRowLimit: e.target.value ? parseInt(e.target.value, 10) : undefined,
+                      })
+                    }
+                    className="transition-colors"
+                  />
+                </FormField>
+              </div>
+            </Section>
+
+            <Separator />
+
+            {!isSqlite && !isDuckDb ? (
               <>
                 {/* Server Section */}
                 <Section icon={<Server className="h-4 w-4" />} title="Server">
@@ -287,7 +472,7 @@ export function ConnectionModal() {
                       >
                         <Input
                           id="host"
-                          placeholder={defaults.host}
+                          placeholder={template?.hostExample ?? defaults.host}
                           value={formData.host || ""}
                           onChange={(e) => setFormData({ ...formData, host: e.target.value })}
                           className="transition-colors"
@@ -316,14 +501,18 @@ export function ConnectionModal() {
                   </div>
 
                   <FormField
-                    label="Database Name"
+                    label={isOracle ? "Service Name" : "Database Name"}
                     htmlFor="database"
-                    hint="The name of the database to connect to"
+                    hint={
+                      isOracle
+                        ? "The service name of the database, e.g. FREEPDB1"
+                        : "The name of the database to connect to"
+                    }
                     required
                   >
                     <Input
                       id="database"
-                      placeholder="mydb"
+                      placeholder={isOracle ? "FREEPDB1" : "mydb"}
                       value={formData.database}
                       onChange={(e) => setFormData({ ...formData, database: e.target.value })}
                       className="transition-colors"
@@ -364,20 +553,38 @@ export function ConnectionModal() {
                       />
                     </FormField>
                   </div>
+
+                  <FormField
+                    label="Environment File"
+                    htmlFor="envFile"
+                    hint="Fields may use ${NAME} placeholders, filled in on connect from this file or the environment"
+                  >
+                    <Input
+                      id="envFile"
+                      placeholder="/path/to/project/.env"
+                      value={formData.envFile || ""}
+                      onChange={(e) => setFormData({ ...formData, envFile: e.target.value || undefined })}
+                      className="transition-colors"
+                    />
+                  </FormField>
                 </Section>
               </>
             ) : (
-              /* SQLite File Section */
+              /* SQLite and DuckDB File Section */
               <Section icon={<FolderOpen className="h-4 w-4" />} title="Database File">
                 <FormField
                   label="File Path"
                   htmlFor="filePath"
-                  hint="Full path to your SQLite database file"
-                  required
+                  hint={
+                    isDuckDb
+                      ? "Full path to your DuckDB database file; leave empty for an in-memory database"
+                      : "Full path to your SQLite database file"
+                  }
+                  required={!isDuckDb}
                 >
                   <Input
                     id="filePath"
-                    placeholder="/path/to/database.db"
+                    placeholder={isDuckDb ? "/path/to/database.duckdb" : "/path/to/database.db"}
                     value={formData.filePath || ""}
                     onChange={(e) =>
                       setFormData({
@@ -391,6 +598,7 @@ export function ConnectionModal() {
                 </FormField>
                 <p className="text-xs text-muted-foreground">
                   If the file doesn't exist, it will be created when you first connect.
+                  {isDuckDb && " Parquet and CSV files can be queried directly, e.g. SELECT * FROM 'data.parquet'."}
                 </p>
               </Section>
             )}
@@ -30,6 +30,8 @@ const DB_CONFIG: Record<DatabaseType, { name: string; brand: string; color: stri
   oracle: { name: "Oracle", brand: "oracle", color: "text-[#F80000]", bgColor: "bg-[#F80000]/10" },
   mongodb: { name: "MongoDB", brand: "mongodb", color: "text-[#47A248]", bgColor: "bg-[#47A248]/10" },
   redis: { name: "Redis", brand: "redis", color: "text-[#FF4438]", bgColor: "bg-[#FF4438]/10" },
+  clickhouse: { name: "ClickHouse", brand: "clickhouse", color: "text-[#FFCC01]", bgColor: "bg-[#FFCC01]/10" },
+  duckdb: { name: "DuckDB", brand: "duckdb", color: "text-[#FFF000]", bgColor: "bg-[#FFF000]/10" },
   cockroachdb: { name: "CockroachDB", brand: "cockroachdb", color: "text-[#6933FF]", bgColor: "bg-[#6933FF]/10" },
   cassandra: { name: "Cassandra", brand: "apachecassandra", color: "text-[#1287B1]", bgColor: "bg-[#1287B1]/10" },
 };
@@ -119,7 +121,7 @@ export function ConnectionPropertiesDialog({
     }
   }, [open, connectionId, getConnection]);
 
-  const isSqlite = config?.databaseType === "sqlite";
+  const isFileDatabase = config?.databaseType === "sqlite" || config?.databaseType === "duckdb";
   const dbConfig = config ? DB_CONFIG[config.databaseType] : null;
 
   return (
@@ -155,7 +157,7 @@ export function ConnectionPropertiesDialog({
 
             {/* Properties */}
             <div className="px-5 py-4 space-y-1">
-              {!isSqlite ? (
+              {!isFileDatabase ? (
                 <>
                   {/* Server Section */}
                   <div className="pb-3">
@@ -203,7 +205,7 @@ export function ConnectionPropertiesDialog({
                   </div>
                 </>
               ) : (
-                /* SQLite Section */
+                /* SQLite and DuckDB Section */
                 <div className="pb-3">
                   <p className="text-xs font-medium text-muted-foreground uppercase tracking-wider mb-2">
                     Database File
@@ -0,0 +1,169 @@
+import { useEffect, useMemo, useState } from "react";
+import { Download, FileQuestion, Loader2 } from "lucide-react";
+import { Button, Dialog, DialogContent, DialogFooter, DialogHeader, DialogTitle } from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-helpers";
+import type { CellBlob } from "@/types";
+
+/** Characters of a text blob shown; the rest is only in the saved file */
+const MAX_TEXT_PREVIEW = 100_000;
+
+/** Bytes shown in the hex dump of a blob with no preview */
+const HEX_DUMP_BYTES = 512;
+
+function formatSize(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+function decodeBase64(data: string): Uint8Array {
+  const binary = atob(data);
+  const bytes = new Uint8Array(binary.length);
+  for (let i = 0; i < binary.length; i++) {
+    bytes[i] = binary.charCodeAt(i);
+  }
+  return bytes;
+}
+
+/** Offset, hex bytes and printable characters, 16 bytes per line */
+function hexDump(bytes: Uint8Array): string {
+  const lines: string[] = [];
+  for (let offset = 0; offset < bytes.length; offset += 16) {
+    const chunk = Array.from(bytes.slice(offset, offset + 16));
+    const hex = chunk.map((byte) => byte.toString(16).padStart(2, "0")).join(" ");
+    const text = chunk.map((byte) => (byte >= 32 && byte < 127 ? String.fromCharCode(byte) : ".")).join("");
+    lines.push(`${offset.toString(16).padStart(8, "0")}  ${hex.padEnd(47)}  ${text}`);
+  }
+  return lines.join("\n");
+}
+
+interface BlobPreviewProps {
+  blob: CellBlob;
+}
+
+function BlobPreview({ blob }: BlobPreviewProps) {
+  const bytes = useMemo(() => (blob.data ? decodeBase64(blob.data) : null), [blob.data]);
+  const [objectUrl, setObjectUrl] = useState<string | null>(null);
+
+  // PDFs are shown from a blob URL; data URLs are too long for large files
+  useEffect(() => {
+    if (!bytes || blob.contentType !== "application/pdf") return;
+    const url = URL.createObjectURL(new Blob([bytes.buffer as ArrayBuffer], { type: blob.contentType }));
+    setObjectUrl(url);
+    return () => URL.revokeObjectURL(url);
+  }, [bytes, blob.contentType]);
+
+  if (!bytes) {
+    return (
+      <div className="flex h-full flex-col items-center justify-center gap-2 text-muted-foreground">
+        <FileQuestion className="h-8 w-8 opacity-40" />
+        <span className="text-sm">Too large to preview; save it to a file instead.</span>
+      </div>
+    );
+  }
+
+  if (blob.contentType.startsWith("image/")) {
+    return (
+      <div className="flex h-full items-center justify-center bg-[repeating-conic-gradient(hsl(var(--muted))_0%_25%,transparent_0%_50%)] bg-[length:16px_16px]">
+        <img
+          src={`data:${blob.contentType};base64,${blob.data}`}
+          alt="Cell contents"
+          className="max-h-full max-w-full object-contain"
+        />
+      </div>
+    );
+  }
+
+  if (blob.contentType === "application/pdf") {
+    return objectUrl ? <iframe src={objectUrl} title="PDF preview" className="h-full w-full rounded border-0" /> : null;
+  }
+
+  if (blob.contentType.startsWith("text/") || blob.contentType === "application/json") {
+    const text = new TextDecoder().decode(bytes);
+    return (
+      <pre className="h-full overflow-auto whitespace-pre-wrap break-all rounded bg-muted/40 p-3 font-mono text-xs">
+        {text.length > MAX_TEXT_PREVIEW ? `${text.slice(0, MAX_TEXT_PREVIEW)}\n…` : text}
+      </pre>
+    );
+  }
+
+  return (
+    <pre className="h-full overflow-auto rounded bg-muted/40 p-3 font-mono text-xs">
+      {hexDump(bytes.slice(0, HEX_DUMP_BYTES))}
+      {bytes.length > HEX_DUMP_BYTES && "\n…"}
+    </pre>
+  );
+}
+
+interface BlobViewerDialogProps {
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  resultId: string;
+  row: number;
+  column: number;
+  columnName: string;
+}
+
+/** Preview of a binary cell as an image, PDF, text or hex dump, and saving it to a file */
+export function BlobViewerDialog({ open, onOpenChange, resultId, row, column, columnName }: BlobViewerDialogProps) {
+  const { getCellBlob, saveBlobToFile } = useDatabase();
+  const [blob, setBlob] = useState<CellBlob | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    if (!open) return;
+    setBlob(null);
+    setError(null);
+    getCellBlob(resultId, row, column)
+      .then(setBlob)
+      .catch((e) => setError(e instanceof Error ? e.message : String(e)));
+  }, [open, resultId, row, column, getCellBlob]);
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    try {
+      const path = await saveBlobToFile(resultId, row, column);
+      showSuccessToast("Saved", path);
+    } catch (e) {
+      showErrorToast("Failed to save the file", e instanceof Error ? e.message : String(e));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="max-w-3xl">
+        <DialogHeader>
+          <DialogTitle className="font-mono text-sm">
+            {columnName} <span className="text-muted-foreground">· row {row + 1}</span>
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="h-[60vh]">
+          {error ? (
+            <div className="flex h-full items-center justify-center text-sm text-destructive">{error}</div>
+          ) : blob ? (
+            <BlobPreview blob={blob} />
+          ) : (
+            <div className="flex h-full items-center justify-center">
+              <Loader2 className="h-5 w-5 animate-spin text-muted-foreground" />
+            </div>
+          )}
+        </div>
+
+        <DialogFooter className="items-center sm:justify-between">
+          <span className="text-xs text-muted-foreground">
+            {blob && `${blob.contentType} · ${formatSize(blob.size)}`}
+          </span>
+          <Button size="sm" onClick={handleSave} disabled={!blob || isSaving}>
+            {isSaving ? <Loader2 className="h-4 w-4 mr-2 animate-spin" /> : <Download className="h-4 w-4 mr-2" />}
+            Save to Downloads
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
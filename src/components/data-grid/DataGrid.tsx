@@ -29,6 +29,7 @@ import {
   Calendar,
   ToggleLeft,
   Database,
+  FileImage,
 } from "lucide-react";
 import { cn, formatTimestamp } from "@/lib/utils";
 import { ExecutionTimeBadge } from "@/components/ui/execution-time-badge";
@@ -37,6 +38,13 @@ import { useCRUDStore } from "@/stores";
 import { EditableCell } from "./EditableCell";
 import { ColumnFilterPopover } from "./ColumnFilterPopover";
 import { ExportMenu } from "./ExportMenu";
+import { BlobViewerDialog } from "./BlobViewerDialog";
+
+/** Binary column types, and values the drivers mark as base64 */
+function isBinaryCell(dataType: string, value: string): boolean {
+  const type = dataType.toLowerCase();
+  return ["bytea", "blob", "binary", "image"].some((t) => type.includes(t)) || value.startsWith("[base64: ");
+}
 
 // Shared utility to generate consistent row IDs
 export function generateRowId(row: Record<string, unknown>, columns: ColumnInfo[]): string {
@@ -140,6 +148,8 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
   } = useCRUDStore();
 
   const [lastSelectedId, setLastSelectedId] = useState<string | null>(null);
+  // Binary cell open in the viewer
+  const [blobCell, setBlobCell] = useState<{ row: number; column: number; columnName: string } | null>(null);
 
   // Helper to create a SelectedRow object with full context
   const createSelectedRow = useCallback((row: Record<string, unknown>) => ({
@@ -210,7 +220,7 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
       enableSorting: false,
     });
 
-    tableColumns.push(...data.columns.map((col) => ({
+    tableColumns.push(...data.columns.map((col, colIndex) => ({
       id: col.name,
       accessorKey: col.name,
       header: ({ column }: { column: any }) => {
@@ -303,6 +313,7 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
                       [colId]: newValue,
                     },
                     primaryKey,
+                    columns: data.columns,
                   });
                 }
                 setEditingCell(null);
@@ -324,7 +335,23 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
         }
 
         let content: React.ReactNode;
-        if (typeof displayValue === "string") {
+        if (typeof displayValue === "string" && data.resultId && !isModified && isBinaryCell(col.dataType, displayValue)) {
+          content = (
+            <button
+              type="button"
+              className="inline-flex items-center gap-1.5 rounded border border-border/50 bg-muted px-1.5 py-0.5 text-[11px] text-muted-foreground hover:text-foreground"
+              onClick={(e) => {
+                e.stopPropagation();
+                setBlobCell({ row: row.index, column: colIndex, columnName: col.name });
+              }}
+              onDoubleClick={(e) => e.stopPropagation()}
+              title="Preview or save"
+            >
+              <FileImage className="h-3 w-3" />
+              Binary
+            </button>
+          );
+        } else if (typeof displayValue === "string") {
           // Try to format as timestamp
           const timestampData = formatTimestamp(displayValue);
           if (timestampData) {
@@ -392,7 +419,7 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
       },
     })));
     return tableColumns;
-  }, [data.columns, editingCell, pendingChanges, tableName, addPendingChange, setEditingCell, lastSelectedId, createSelectedRow, addSelectedRow, toggleRowSelection, columnFilters, setColumnFilter, clearColumnFilter]);
+  }, [data.columns, data.resultId, editingCell, pendingChanges, tableName, addPendingChange, setEditingCell, lastSelectedId, createSelectedRow, addSelectedRow, toggleRowSelection, columnFilters, setColumnFilter, clearColumnFilter]);
 
   const tableData = useMemo(() => {
     return data.rows.map((row) => {
@@ -571,7 +598,7 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
       <div className="flex items-center justify-between border-t border-border bg-muted/40 px-6 py-2 shadow-[0_-1px_3px_rgba(0,0,0,0.02)]">
         <div className="flex items-center gap-6 text-xs text-muted-foreground">
           {/* Export Menu */}
-          <ExportMenu tableName={tableName} />
+          <ExportMenu tableName={tableName} result={data} />
 
           {/* Status Text */}
           <div className="flex items-center gap-1">
@@ -646,6 +673,17 @@ export function DataGrid({ data, onRowClick, tableName }: DataGridProps) {
           </div>
         </div>
       </div>
+
+      {blobCell && data.resultId && (
+        <BlobViewerDialog
+          open
+          onOpenChange={(open) => !open && setBlobCell(null)}
+          resultId={data.resultId}
+          row={blobCell.row}
+          column={blobCell.column}
+          columnName={blobCell.columnName}
+        />
+      )}
     </div>
   );
 }
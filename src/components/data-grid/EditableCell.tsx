@@ -100,7 +100,8 @@ export function EditableCell({
         onChange={(e) => {
           const val = e.target.value;
           if (isNumeric) {
-            setValue(val === "" ? null : Number(val));
+            // Clearing the input is not NULL; the NULL button sets that
+            setValue(val === "" ? "" : Number(val));
           } else {
             setValue(val);
           }
@@ -1,4 +1,4 @@
-import { Download, FileJson, FileText, Database } from "lucide-react";
+import { Download, FileJson, FileText, Database, Code, Table } from "lucide-react";
 import {
   DropdownMenu,
   DropdownMenuContent,
@@ -8,17 +8,23 @@ import {
 } from "@/components/ui/dropdown-menu";
 import { Button } from "@/components/ui/button";
 import { Tooltip, TooltipContent, TooltipTrigger } from "@/components/ui";
-import { useCRUDStore } from "@/stores";
+import { useConnectionsStore, useCRUDStore } from "@/stores";
+import { useDatabase } from "@/hooks";
 import { rowsToInsertSQL, rowsToJSON, rowsToCSV, downloadFile } from "@/lib/export-utils";
 import { copyToClipboard } from "@/lib/utils";
 import { showSuccessToast, showErrorToast } from "@/lib/toast-helpers";
+import type { CopyFormat, QueryResult } from "@/types";
 
 interface ExportMenuProps {
   tableName?: string;
+  /** The whole result, for copying every row rather than the selection */
+  result?: QueryResult;
 }
 
-export function ExportMenu({ tableName }: ExportMenuProps) {
+export function ExportMenu({ tableName, result }: ExportMenuProps) {
   const { selectedRows } = useCRUDStore();
+  const activeConnectionId = useConnectionsStore((state) => state.activeConnectionId);
+  const { copyResultsAs } = useDatabase();
 
   const handleCopyAsInsert = async () => {
     if (selectedRows.length === 0) return;
@@ -81,7 +87,27 @@ export function ExportMenu({ tableName }: ExportMenuProps) {
     showSuccessToast(`Downloaded ${selectedRows.length} row(s) as ${filename}`);
   };
 
+  // Formatted by the backend, which also has the rows of results too large to send here
+  const handleCopyAllAs = async (format: CopyFormat, label: string) => {
+    if (!result) return;
+
+    try {
+      const copied = await copyResultsAs({
+        format,
+        columns: result.columns,
+        rows: result.resultId ? undefined : result.rows,
+        resultId: result.resultId ?? undefined,
+        tableName,
+        connectionId: activeConnectionId ?? undefined,
+      });
+      showSuccessToast(`Copied ${copied.toLocaleString()} row(s) as ${label}`);
+    } catch (error) {
+      showErrorToast("Failed to copy to clipboard", error instanceof Error ? error.message : String(error));
+    }
+  };
+
   const disabled = selectedRows.length === 0;
+  const hasRows = !!result && (result.totalRows ?? result.rows.length) > 0;
 
   return (
     <DropdownMenu>
@@ -91,7 +117,7 @@ export function ExportMenu({ tableName }: ExportMenuProps) {
             <Button
               variant="ghost"
               size="sm"
-              disabled={disabled}
+              disabled={disabled && !hasRows}
               className="gap-2"
             >
               <Download className="h-3.5 w-3.5" />
@@ -100,7 +126,11 @@ export function ExportMenu({ tableName }: ExportMenuProps) {
           </DropdownMenuTrigger>
         </TooltipTrigger>
         <TooltipContent>
-          {disabled ? "Select rows to export" : `Export ${selectedRows.length} selected row(s)`}
+          {disabled
+            ? hasRows
+              ? "Copy all rows, or select rows to export"
+              : "Select rows to export"
+            : `Export ${selectedRows.length} selected row(s)`}
         </TooltipContent>
       </Tooltip>
 
@@ -128,6 +158,33 @@ export function ExportMenu({ tableName }: ExportMenuProps) {
           <FileText className="mr-2 h-4 w-4" />
           Download as CSV
         </DropdownMenuItem>
+
+        {hasRows && (
+          <>
+            <DropdownMenuSeparator />
+
+            <DropdownMenuItem onClick={() => handleCopyAllAs("markdown", "a Markdown table")}>
+              <Table className="mr-2 h-4 w-4" />
+              Copy all rows as Markdown
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => handleCopyAllAs("html", "an HTML table")}>
+              <Code className="mr-2 h-4 w-4" />
+              Copy all rows as HTML
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => handleCopyAllAs("tsv", "TSV")}>
+              <FileText className="mr-2 h-4 w-4" />
+              Copy all rows as TSV
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => handleCopyAllAs("json", "JSON")}>
+              <FileJson className="mr-2 h-4 w-4" />
+              Copy all rows as JSON
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => handleCopyAllAs("insert", "INSERT statements")}>
+              <Database className="mr-2 h-4 w-4" />
+              Copy all rows as INSERT
+            </DropdownMenuItem>
+          </>
+        )}
       </DropdownMenuContent>
     </DropdownMenu>
   );
@@ -0,0 +1,281 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { AlertTriangle, ArchiveRestore, DatabaseBackup, Loader2 } from "lucide-react";
+import {
+  Badge,
+  Button,
+  Checkbox,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showSuccessToast } from "@/lib/toast-helpers";
+import type {
+  BackupFormat,
+  BackupOperation,
+  BackupProgress,
+  BackupSummary,
+  BackupTool,
+  ConfirmationRequired,
+  DatabaseType,
+  SqlFileProgress,
+} from "@/types";
+
+interface BackupDialogProps {
+  mode: BackupOperation;
+  connectionId: string;
+  connectionName: string;
+  databaseType: DatabaseType;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+function formatSize(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+export function BackupDialog({ mode, connectionId, connectionName, databaseType, open, onOpenChange }: BackupDialogProps) {
+  const { getBackupTools, backupDatabase, restoreDatabase } = useDatabase();
+  const [path, setPath] = useState("");
+  const [format, setFormat] = useState<BackupFormat>("native");
+  const [schemaOnly, setSchemaOnly] = useState(false);
+  const [builtin, setBuiltin] = useState(false);
+  const [clean, setClean] = useState(false);
+  const [tools, setTools] = useState<BackupTool[]>([]);
+  const [confirmation, setConfirmation] = useState<ConfirmationRequired | null>(null);
+  const [progress, setProgress] = useState<{ bytes: number; totalBytes: number | null; message: string | null } | null>(
+    null
+  );
+  const [isRunning, setIsRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const isBackup = mode === "backup";
+  const hasNativeFormat = databaseType === "postgresql" || databaseType === "sqlite";
+
+  useEffect(() => {
+    if (!open) return;
+    setConfirmation(null);
+    setProgress(null);
+    setError(null);
+    getBackupTools(connectionId)
+      .then(setTools)
+      .catch(() => setTools([]));
+  }, [open, connectionId, getBackupTools]);
+
+  // Follow the operation's progress; restores without a client tool report as SQL file runs
+  useEffect(() => {
+    if (!isRunning) return;
+    const unlisteners = [
+      listen<BackupProgress>("backup-progress", ({ payload }) => {
+        if (payload.path !== path || payload.done) return;
+        setProgress((prev) => ({
+          bytes: payload.bytes,
+          totalBytes: payload.totalBytes,
+          message: payload.message ?? prev?.message ?? null,
+        }));
+      }),
+      listen<SqlFileProgress>("sql-file-progress", ({ payload }) => {
+        if (payload.path !== path) return;
+        setProgress({
+          bytes: payload.bytesRead,
+          totalBytes: payload.totalBytes,
+          message: `${payload.statementsExecuted.toLocaleString()} statements run`,
+        });
+      }),
+    ];
+    return () => {
+      unlisteners.forEach((unlisten) => unlisten.then((fn) => fn()));
+    };
+  }, [isRunning, path]);
+
+  const finish = (summary: BackupSummary) => {
+    const details = [summary.method, formatSize(summary.bytes)];
+    if (summary.rows !== null) details.push(`${summary.rows.toLocaleString()} rows`);
+    showSuccessToast(isBackup ? "Backup written" : "Backup restored", details.join(", "));
+    onOpenChange(false);
+  };
+
+  const run = async (confirmationToken?: string) => {
+    setIsRunning(true);
+    setError(null);
+    setProgress(null);
+    try {
+      if (isBackup) {
+        finish(await backupDatabase({ connectionId, path: path.trim(), format, schemaOnly, builtin }));
+      } else {
+        const result = await restoreDatabase({ connectionId, path: path.trim(), clean, confirmationToken });
+        if (result.status === "confirmationRequired") {
+          setConfirmation(result);
+        } else {
+          finish(result);
+        }
+      }
+    } catch (error) {
+      setConfirmation(null);
+      setError(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsRunning(false);
+    }
+  };
+
+  const missingTools = tools.filter((tool) => !tool.path);
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !isRunning && onOpenChange(next)}>
+      <DialogContent className="max-w-[560px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            {isBackup ? <DatabaseBackup className="h-5 w-5" /> : <ArchiveRestore className="h-5 w-5" />}
+            {isBackup ? "Back Up" : "Restore Into"} {connectionName}
+          </DialogTitle>
+          <DialogDescription>
+            {isBackup
+              ? "Uses the database's dump tool when it is installed, otherwise writes a SQL script of CREATE and INSERT statements."
+              : "Restores a pg_dump archive, a SQLite database file or a SQL script into this connection."}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="space-y-2">
+            <Label htmlFor="backup-path">{isBackup ? "Save to" : "Backup file"}</Label>
+            <Input
+              id="backup-path"
+              value={path}
+              onChange={(e) => {
+                setPath(e.target.value);
+                setConfirmation(null);
+              }}
+              placeholder={isBackup ? "/path/to/backup.dump" : "/path/to/backup.sql"}
+              className="font-mono"
+              disabled={isRunning}
+              autoFocus
+            />
+          </div>
+
+          {isBackup ? (
+            <>
+              {hasNativeFormat && (
+                <div className="flex items-center gap-3">
+                  <Label className="shrink-0 text-sm">Format</Label>
+                  <Select value={format} onValueChange={(value: BackupFormat) => setFormat(value)}>
+                    <SelectTrigger className="w-[240px]">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value="native">
+                        {databaseType === "postgresql" ? "Custom archive (pg_restore)" : "Database file copy"}
+                      </SelectItem>
+                      <SelectItem value="sql">SQL script</SelectItem>
+                    </SelectContent>
+                  </Select>
+                </div>
+              )}
+              <div className="flex items-center gap-2">
+                <Checkbox
+                  id="backup-schema-only"
+                  checked={schemaOnly}
+                  onCheckedChange={(checked: boolean) => setSchemaOnly(checked)}
+                />
+                <Label htmlFor="backup-schema-only" className="text-sm">
+                  Schema only, without rows
+                </Label>
+              </div>
+              <div className="flex items-center gap-2">
+                <Checkbox id="backup-builtin" checked={builtin} onCheckedChange={(checked: boolean) => setBuiltin(checked)} />
+                <Label htmlFor="backup-builtin" className="text-sm">
+                  Write the built-in SQL script instead of using the dump tool
+                </Label>
+              </div>
+            </>
+          ) : (
+            databaseType === "postgresql" && (
+              <div className="flex items-center gap-2">
+                <Checkbox id="restore-clean" checked={clean} onCheckedChange={(checked: boolean) => setClean(checked)} />
+                <Label htmlFor="restore-clean" className="text-sm">
+                  Drop existing objects before recreating them (pg_dump archives)
+                </Label>
+              </div>
+            )
+          )}
+
+          {tools.length > 0 && (
+            <div className="space-y-1.5 rounded-md border p-3">
+              {tools.map((tool) => (
+                <div key={tool.name} className="flex items-center gap-2 text-xs">
+                  <Badge variant={tool.path ? "secondary" : "outline"} className="font-mono">
+                    {tool.name}
+                  </Badge>
+                  <span className="text-muted-foreground">{tool.purpose}</span>
+                  <span className="ml-auto truncate text-muted-foreground" title={tool.path ?? undefined}>
+                    {tool.version ?? (tool.path ? "found" : "not found")}
+                  </span>
+                </div>
+              ))}
+              {missingTools.length > 0 && (
+                <p className="pt-1 text-xs text-muted-foreground">
+                  Without {missingTools.map((tool) => tool.name).join(" or ")}, backups are written as the built-in SQL
+                  script. Put the tools on PATH or in the tools folder next to the app.
+                </p>
+              )}
+            </div>
+          )}
+
+          {progress && isRunning && (
+            <div className="space-y-1 text-xs text-muted-foreground">
+              <p>
+                {formatSize(progress.bytes)}
+                {progress.totalBytes ? ` of ${formatSize(progress.totalBytes)}` : ""}
+              </p>
+              {progress.message && <p className="truncate font-mono">{progress.message}</p>}
+            </div>
+          )}
+
+          {confirmation && (
+            <div className="flex gap-2 rounded-md border border-destructive/50 bg-destructive/10 p-3 text-sm">
+              <AlertTriangle className="mt-0.5 h-4 w-4 shrink-0 text-destructive" />
+              <p>{confirmation.summary}</p>
+            </div>
+          )}
+
+          {error && <p className="whitespace-pre-wrap text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={() => onOpenChange(false)} disabled={isRunning}>
+            Cancel
+          </Button>
+          <Button
+            variant={confirmation ? "destructive" : "default"}
+            onClick={() => run(confirmation?.token)}
+            disabled={isRunning || !path.trim()}
+          >
+            {isRunning ? (
+              <>
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                {isBackup ? "Backing up..." : "Restoring..."}
+              </>
+            ) : confirmation ? (
+              "Restore"
+            ) : isBackup ? (
+              "Back Up"
+            ) : (
+              "Continue"
+            )}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
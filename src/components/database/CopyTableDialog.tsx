@@ -0,0 +1,197 @@
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { ArrowRightLeft, Loader2 } from "lucide-react";
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { useConnectionsStore } from "@/stores";
+import { showSuccessToast } from "@/lib/toast-helpers";
+import type { TableCopyProgress, TableCopySummary } from "@/types";
+
+interface CopyTableDialogProps {
+  connectionId: string;
+  /** Table to copy, or null while the dialog is closed */
+  tableName: string | null;
+  onOpenChange: (open: boolean) => void;
+}
+
+export function CopyTableDialog({ connectionId, tableName, onOpenChange }: CopyTableDialogProps) {
+  const { copyTableBetweenConnections, cancelTableCopy } = useDatabase();
+  const connections = useConnectionsStore((state) => state.connections).filter(
+    (connection) =>
+      connection.connected &&
+      !connection.readOnly &&
+      connection.databaseType !== "mongodb" &&
+      connection.databaseType !== "redis"
+  );
+  const [targetConnectionId, setTargetConnectionId] = useState("");
+  const [targetTable, setTargetTable] = useState("");
+  const [progress, setProgress] = useState<TableCopyProgress | null>(null);
+  const [summary, setSummary] = useState<TableCopySummary | null>(null);
+  const [isRunning, setIsRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const transferId = useRef<string | null>(null);
+  const open = tableName !== null;
+
+  useEffect(() => {
+    if (!tableName) return;
+    setTargetConnectionId((current) => current || connections.find((c) => c.id !== connectionId)?.id || "");
+    setTargetTable(tableName.split(".").pop() ?? tableName);
+    setProgress(null);
+    setSummary(null);
+    setError(null);
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [tableName]);
+
+  useEffect(() => {
+    if (!isRunning) return;
+    const unlisten = listen<TableCopyProgress>("table-copy-progress", ({ payload }) => {
+      if (payload.transferId === transferId.current) setProgress(payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [isRunning]);
+
+  const handleCopy = async () => {
+    if (!tableName) return;
+    transferId.current = crypto.randomUUID();
+    setIsRunning(true);
+    setError(null);
+    setSummary(null);
+    setProgress(null);
+    try {
+      const result = await copyTableBetweenConnections({
+        transferId: transferId.current,
+        sourceConnectionId: connectionId,
+        sourceTable: tableName,
+        targetConnectionId,
+        targetTable: targetTable.trim() || undefined,
+      });
+      if (result.rowsFailed === 0 && !result.cancelled) {
+        showSuccessToast(
+          "Table copied",
+          `${result.rowsCopied.toLocaleString()} rows into ${result.targetTable}${result.createStatement ? " (created)" : ""}`
+        );
+        onOpenChange(false);
+      } else {
+        setSummary(result);
+      }
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsRunning(false);
+      transferId.current = null;
+    }
+  };
+
+  const handleCancel = () => {
+    if (isRunning && transferId.current) {
+      cancelTableCopy(transferId.current);
+    } else {
+      onOpenChange(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !isRunning && onOpenChange(next)}>
+      <DialogContent className="max-w-[560px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <ArrowRightLeft className="h-5 w-5" />
+            Copy {tableName} to Connection
+          </DialogTitle>
+          <DialogDescription>
+            A missing target table is created with the source's columns, their types mapped to the target database. An
+            existing table receives the columns both tables have.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="flex items-center gap-3">
+            <Label className="w-[110px] shrink-0 text-sm">Target</Label>
+            <Select value={targetConnectionId} onValueChange={setTargetConnectionId} disabled={isRunning}>
+              <SelectTrigger>
+                <SelectValue placeholder="Choose a connection" />
+              </SelectTrigger>
+              <SelectContent>
+                {connections.map((connection) => (
+                  <SelectItem key={connection.id} value={connection.id}>
+                    {connection.name}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+          <div className="flex items-center gap-3">
+            <Label htmlFor="copy-target-table" className="w-[110px] shrink-0 text-sm">
+              Target table
+            </Label>
+            <Input
+              id="copy-target-table"
+              value={targetTable}
+              onChange={(e) => setTargetTable(e.target.value)}
+              className="font-mono"
+              disabled={isRunning}
+            />
+          </div>
+
+          {progress && isRunning && (
+            <p className="text-xs text-muted-foreground">
+              {progress.rowsCopied.toLocaleString()}
+              {progress.totalRows ? ` of about ${progress.totalRows.toLocaleString()}` : ""} rows copied
+              {progress.rowsFailed > 0 && `, ${progress.rowsFailed.toLocaleString()} failed`}
+            </p>
+          )}
+
+          {summary && (
+            <div className="space-y-1 rounded-md border p-3 text-xs">
+              <p>
+                {summary.cancelled ? "Cancelled after" : "Copied"} {summary.rowsCopied.toLocaleString()} rows into{" "}
+                {summary.targetTable}
+                {summary.rowsFailed > 0 && `; ${summary.rowsFailed.toLocaleString()} rows failed`}
+              </p>
+              {summary.errors.slice(0, 5).map((rowError) => (
+                <p key={rowError.row} className="truncate font-mono text-destructive" title={rowError.message}>
+                  Row {rowError.row}: {rowError.message}
+                </p>
+              ))}
+            </div>
+          )}
+
+          {error && <p className="whitespace-pre-wrap text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={handleCancel}>
+            {isRunning ? "Stop" : summary ? "Close" : "Cancel"}
+          </Button>
+          <Button onClick={handleCopy} disabled={isRunning || !targetConnectionId || !targetTable.trim()}>
+            {isRunning ? (
+              <>
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                Copying...
+              </>
+            ) : (
+              "Copy"
+            )}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,140 @@
+import { useState, useEffect } from "react";
+import { Loader2, FileCode, Copy, ExternalLink } from "lucide-react";
+import {
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  DialogFooter,
+} from "@/components/ui/dialog";
+import { Button, Checkbox, Label } from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { useQueryStore } from "@/stores";
+import { copyToClipboard } from "@/lib/utils";
+import { showInfoToast, showErrorToast } from "@/lib/toast-helpers";
+
+interface DatabaseDdlDialogProps {
+  connectionId: string;
+  connectionName: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+export function DatabaseDdlDialog({ connectionId, connectionName, open, onOpenChange }: DatabaseDdlDialogProps) {
+  const { generateDatabaseDdl } = useDatabase();
+  const { addTab, setActiveTab } = useQueryStore();
+
+  const [includeDrops, setIncludeDrops] = useState(false);
+  const [qualifyNames, setQualifyNames] = useState(false);
+  const [script, setScript] = useState<string | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  // Regenerate whenever the dialog opens or an option changes
+  useEffect(() => {
+    if (!open) return;
+    let cancelled = false;
+    setIsLoading(true);
+    setError(null);
+    generateDatabaseDdl(connectionId, { includeDrops, qualifyNames })
+      .then((ddl) => {
+        if (!cancelled) setScript(ddl);
+      })
+      .catch((err) => {
+        if (!cancelled) {
+          setScript(null);
+          setError(err instanceof Error ? err.message : String(err));
+        }
+      })
+      .finally(() => {
+        if (!cancelled) setIsLoading(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [open, connectionId, includeDrops, qualifyNames, generateDatabaseDdl]);
+
+  const handleCopy = async () => {
+    if (!script) return;
+    if (await copyToClipboard(script)) {
+      showInfoToast("DDL Copied", "Database DDL script copied to clipboard.");
+    } else {
+      showErrorToast("Copy Failed", "Could not copy the script to the clipboard.");
+    }
+  };
+
+  const handleOpenInEditor = () => {
+    if (!script) return;
+    const tabId = crypto.randomUUID();
+    addTab({
+      id: tabId,
+      title: `DDL: ${connectionName}`,
+      type: "query",
+      connectionId,
+      content: script,
+    });
+    setActiveTab(tabId);
+    onOpenChange(false);
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="max-w-[800px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <FileCode className="h-5 w-5" />
+            Database DDL
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="flex items-center gap-6">
+            <div className="flex items-center gap-2">
+              <Checkbox
+                id="ddl-include-drops"
+                checked={includeDrops}
+                onCheckedChange={(checked: boolean) => setIncludeDrops(checked)}
+              />
+              <Label htmlFor="ddl-include-drops" className="text-sm">
+                Include DROP statements
+              </Label>
+            </div>
+            <div className="flex items-center gap-2">
+              <Checkbox
+                id="ddl-qualify-names"
+                checked={qualifyNames}
+                onCheckedChange={(checked: boolean) => setQualifyNames(checked)}
+              />
+              <Label htmlFor="ddl-qualify-names" className="text-sm">
+                Qualify names with schema
+              </Label>
+            </div>
+          </div>
+
+          <div className="relative h-[420px] rounded-md border bg-muted/30">
+            {isLoading ? (
+              <div className="flex h-full items-center justify-center">
+                <Loader2 className="h-5 w-5 animate-spin text-muted-foreground" />
+              </div>
+            ) : error ? (
+              <p className="p-4 text-sm text-destructive">{error}</p>
+            ) : (
+              <pre className="h-full overflow-auto p-3 font-mono text-xs whitespace-pre">{script}</pre>
+            )}
+          </div>
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={handleCopy} disabled={isLoading || !script}>
+            <Copy className="mr-2 h-4 w-4" />
+            Copy
+          </Button>
+          <Button onClick={handleOpenInEditor} disabled={isLoading || !script}>
+            <ExternalLink className="mr-2 h-4 w-4" />
+            Open in Editor
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,148 @@
+import { useEffect, useState } from "react";
+import { Loader2, Search } from "lucide-react";
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  ScrollArea,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import type { ObjectMatch, ObjectSearchResult } from "@/types";
+
+interface FindInDatabaseDialogProps {
+  connectionId: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+  /** Open a matched table or view in a tab */
+  onOpenTable: (tableName: string) => void;
+}
+
+const GROUPS: { key: "tables" | "columns" | "views" | "routines" | "comments"; label: string }[] = [
+  { key: "tables", label: "Tables and views" },
+  { key: "columns", label: "Columns" },
+  { key: "views", label: "View definitions" },
+  { key: "routines", label: "Functions, procedures and triggers" },
+  { key: "comments", label: "Comments" },
+];
+
+const OPENABLE_TYPES = new Set(["table", "view", "materialized view", "foreign table"]);
+
+export function FindInDatabaseDialog({ connectionId, open, onOpenChange, onOpenTable }: FindInDatabaseDialogProps) {
+  const { searchDatabaseObjects } = useDatabase();
+  const [pattern, setPattern] = useState("");
+  const [result, setResult] = useState<ObjectSearchResult | null>(null);
+  const [isSearching, setIsSearching] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!open) return;
+    setResult(null);
+    setError(null);
+  }, [open]);
+
+  const handleSearch = async () => {
+    if (!pattern.trim()) return;
+    setIsSearching(true);
+    setError(null);
+    try {
+      setResult(await searchDatabaseObjects(connectionId, pattern));
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+      setResult(null);
+    } finally {
+      setIsSearching(false);
+    }
+  };
+
+  const handleOpen = (match: ObjectMatch) => {
+    if (!OPENABLE_TYPES.has(match.objectType)) return;
+    onOpenTable(match.object);
+    onOpenChange(false);
+  };
+
+  const total = result ? GROUPS.reduce((sum, group) => sum + result[group.key].length, 0) : 0;
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="max-w-[720px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Search className="h-5 w-5" />
+            Find in Database
+          </DialogTitle>
+          <DialogDescription>
+            Search table and column names, view definitions, routine bodies and comments.
+          </DialogDescription>
+        </DialogHeader>
+
+        <form
+          className="flex items-center gap-2"
+          onSubmit={(e) => {
+            e.preventDefault();
+            handleSearch();
+          }}
+        >
+          <Input
+            value={pattern}
+            onChange={(e) => setPattern(e.target.value)}
+            placeholder="e.g. customer_uuid"
+            className="font-mono"
+            autoFocus
+          />
+          <Button type="submit" disabled={isSearching || !pattern.trim()}>
+            {isSearching ? <Loader2 className="h-4 w-4 animate-spin" /> : "Search"}
+          </Button>
+        </form>
+
+        {error && <p className="whitespace-pre-wrap text-sm text-destructive">{error}</p>}
+
+        {result && (
+          <ScrollArea className="max-h-[420px]">
+            {total === 0 ? (
+              <p className="py-4 text-center text-sm text-muted-foreground">Nothing matches "{result.pattern}"</p>
+            ) : (
+              <div className="space-y-4 pr-3">
+                {GROUPS.filter((group) => result[group.key].length > 0).map((group) => (
+                  <div key={group.key}>
+                    <p className="mb-1 text-xs font-medium uppercase text-muted-foreground">
+                      {group.label} ({result[group.key].length})
+                    </p>
+                    {result[group.key].map((match, index) => (
+                      <button
+                        key={`${match.object}-${match.column ?? ""}-${index}`}
+                        type="button"
+                        onClick={() => handleOpen(match)}
+                        disabled={!OPENABLE_TYPES.has(match.objectType)}
+                        className="flex w-full items-baseline gap-2 rounded px-2 py-1 text-left text-sm hover:bg-accent disabled:cursor-default disabled:hover:bg-transparent"
+                      >
+                        <span className="shrink-0 font-mono">
+                          {match.object}
+                          {match.column && `.${match.column}`}
+                        </span>
+                        <span className="shrink-0 text-xs text-muted-foreground">{match.objectType}</span>
+                        {match.detail && (
+                          <span className="truncate font-mono text-xs text-muted-foreground" title={match.detail}>
+                            {match.detail}
+                          </span>
+                        )}
+                      </button>
+                    ))}
+                  </div>
+                ))}
+                {result.truncated && (
+                  <p className="text-xs text-muted-foreground">
+                    Some groups have more matches than shown; narrow the search to see them.
+                  </p>
+                )}
+              </div>
+            )}
+          </ScrollArea>
+        )}
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -1,2 +1,9 @@
 export * from "./CreateSchemaDialog";
 
+export * from "./DatabaseDdlDialog";
+
+export * from "./BackupDialog";
+
+export * from "./CopyTableDialog";
+
+export * from "./FindInDatabaseDialog";
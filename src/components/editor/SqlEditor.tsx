@@ -1,9 +1,13 @@
 import { useEffect, useRef, useMemo } from "react";
-import Editor, { type Monaco, type OnMount } from "@monaco-editor/react";
+import Editor, { loader, type Monaco, type OnMount } from "@monaco-editor/react";
 import type * as MonacoEditor from "monaco-editor";
 import { createSqlCompletionProvider } from "./sql-completion-provider";
 import { registerCustomThemes, getMonacoTheme } from "./monaco-themes";
-import type { TableInfo, TableSchema } from "@/types";
+import type { ScrollPosition, SqlDiagnostic, TableInfo, TableSchema } from "@/types";
+import type { Theme } from "@/stores";
+
+// The content security policy in tauri.conf.json allows scripts from this path only
+loader.config({ paths: { vs: "https://cdn.jsdelivr.net/npm/monaco-editor@0.52.2/min/vs" } });
 
 interface SqlEditorProps {
   value: string;
@@ -11,11 +15,17 @@ interface SqlEditorProps {
   onExecute?: (sql: string) => void;
   onExplainWithAI?: (sql: string) => void;
   onOptimizeWithAI?: (sql: string) => void;
+  onSuggestIndexesWithAI?: (sql: string) => void;
   tables?: TableInfo[];
   schemas?: Record<string, TableSchema>;
-  theme?: "light" | "dark" | "system" | "nordic-dark" | "nordic-light";
+  /** Shown as squiggles in the editor */
+  diagnostics?: SqlDiagnostic[];
+  theme?: Theme;
   readOnly?: boolean;
   height?: string | number;
+  /** Where to scroll to when the editor opens */
+  initialScroll?: ScrollPosition;
+  onScrollChange?: (scroll: ScrollPosition) => void;
 }
 
 export function SqlEditor({
@@ -24,11 +34,15 @@ export function SqlEditor({
   onExecute,
   onExplainWithAI,
   onOptimizeWithAI,
+  onSuggestIndexesWithAI,
   tables = [],
   schemas = {},
+  diagnostics = [],
   theme = "dark",
   readOnly = false,
   height = "100%",
+  initialScroll,
+  onScrollChange,
 }: SqlEditorProps) {
   const editorRef = useRef<MonacoEditor.editor.IStandaloneCodeEditor | null>(null);
   const monacoRef = useRef<Monaco | null>(null);
@@ -39,6 +53,8 @@ export function SqlEditor({
   const onExecuteRef = useRef(onExecute);
   const onExplainWithAIRef = useRef(onExplainWithAI);
   const onOptimizeWithAIRef = useRef(onOptimizeWithAI);
+  const onSuggestIndexesWithAIRef = useRef(onSuggestIndexesWithAI);
+  const onScrollChangeRef = useRef(onScrollChange);
 
   // Keep refs in sync
   useEffect(() => {
@@ -61,6 +77,14 @@ export function SqlEditor({
     onOptimizeWithAIRef.current = onOptimizeWithAI;
   }, [onOptimizeWithAI]);
 
+  useEffect(() => {
+    onSuggestIndexesWithAIRef.current = onSuggestIndexesWithAI;
+  }, [onSuggestIndexesWithAI]);
+
+  useEffect(() => {
+    onScrollChangeRef.current = onScrollChange;
+  }, [onScrollChange]);
+
   // Determine Monaco theme based on app theme
   const monacoTheme = useMemo(() => {
     return getMonacoTheme(theme);
@@ -144,6 +168,39 @@ export function SqlEditor({
     });
     actionDisposablesRef.current.push(optimizeAction);
 
+    // Register "Suggest Indexes with AI" context menu action
+    const suggestIndexesAction = editor.addAction({
+      id: "suggest-indexes-with-ai",
+      label: "Suggest Indexes with AI",
+      contextMenuGroupId: "ai",
+      contextMenuOrder: 3,
+      run: (ed) => {
+        const selection = ed.getSelection();
+        let sql = "";
+        if (selection && !selection.isEmpty()) {
+          sql = ed.getModel()?.getValueInRange(selection) || "";
+        } else {
+          sql = ed.getValue();
+        }
+        if (sql.trim() && onSuggestIndexesWithAIRef.current) {
+          onSuggestIndexesWithAIRef.current(sql);
+        }
+      },
+    });
+    actionDisposablesRef.current.push(suggestIndexesAction);
+
+    // Go back to where the tab was scrolled, then report scrolling
+    if (initialScroll) {
+      editor.setScrollPosition({ scrollTop: initialScroll.top, scrollLeft: initialScroll.left });
+    }
+    actionDisposablesRef.current.push(
+      editor.onDidScrollChange((e) => {
+        if (e.scrollTopChanged || e.scrollLeftChanged) {
+          onScrollChangeRef.current?.({ top: e.scrollTop, left: e.scrollLeft });
+        }
+      })
+    );
+
     // Focus the editor
     editor.focus();
   };
@@ -157,6 +214,27 @@ export function SqlEditor({
     };
   }, []);
 
+  // Show lint diagnostics as markers
+  useEffect(() => {
+    const monaco = monacoRef.current;
+    const model = editorRef.current?.getModel();
+    if (!monaco || !model) return;
+
+    monaco.editor.setModelMarkers(
+      model,
+      "sql-lint",
+      diagnostics.map((d) => ({
+        severity: d.severity === "warning" ? monaco.MarkerSeverity.Warning : monaco.MarkerSeverity.Info,
+        message: d.message,
+        code: d.code,
+        startLineNumber: d.line,
+        startColumn: d.column,
+        endLineNumber: d.endLine,
+        endColumn: d.endColumn,
+      }))
+    );
+  }, [diagnostics]);
+
   // Update theme when app theme changes
   useEffect(() => {
     if (monacoRef.current) {
@@ -1,4 +1,5 @@
 import type * as Monaco from "monaco-editor";
+import type { Theme } from "@/stores";
 
 /**
  * Register custom Monaco themes that match the app's design system
@@ -100,16 +101,14 @@ export function registerCustomThemes(monaco: typeof Monaco) {
 /**
  * Get the Monaco theme name based on the app's current theme setting
  *
- * @param appTheme - The current app theme (light/dark/system/nordic-dark/nordic-light)
+ * @param appTheme - The current app theme (light/dark/system or <theme id>-dark/-light)
  */
-export function getMonacoTheme(
-  appTheme: "light" | "dark" | "system" | "nordic-dark" | "nordic-light"
-): string {
-  // Handle Nordic themes
-  if (appTheme === "nordic-dark") {
+export function getMonacoTheme(appTheme: Theme): string {
+  // Handle bundled and extension themes by their variant
+  if (appTheme.endsWith("-dark")) {
     return "dbfordevs-dark";
   }
-  if (appTheme === "nordic-light") {
+  if (appTheme.endsWith("-light")) {
     return "dbfordevs-light";
   }
 
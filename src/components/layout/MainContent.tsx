@@ -307,7 +307,7 @@ export function MainContent() {
       <div ref={contentRef} className="flex-1 overflow-hidden">
         {activeTab ? (
           activeTab.type === "query" ? (
-            <QueryEditorTab tab={activeTab} />
+            <QueryEditorTab key={activeTab.id} tab={activeTab} />
           ) : activeTab.type === "table" ? (
             <TableViewerTab tab={activeTab} />
           ) : activeTab.type === "properties" ? (
@@ -118,7 +118,8 @@ export function SidePanel() {
     name: col.name,
     type: col.dataType,
     nullable: col.nullable,
-    value: change?.newData?.[col.name] ?? rowData?.[col.name]
+    // A field set to NULL is a change too, so only a missing key falls back to the row
+    value: change?.newData && col.name in change.newData ? change.newData[col.name] : rowData?.[col.name]
   }));
 
   const handleFieldChange = (name: string, newValue: unknown) => {
@@ -151,6 +152,7 @@ export function SidePanel() {
         [name]: newValue,
       },
       primaryKey,
+      columns: rowColumns,
     });
   };
 
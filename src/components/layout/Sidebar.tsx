@@ -17,6 +17,16 @@ import {
   Copy,
   ClipboardPaste,
   Network,
+  Folder,
+  Lock,
+  FileCode,
+  DatabaseBackup,
+  ArchiveRestore,
+  ArrowRightLeft,
+  History,
+  Search,
+  Star,
+  StarOff,
 } from "lucide-react";
 import { cn } from "@/lib/utils";
 import {
@@ -40,17 +50,27 @@ import {
   AlertDialogTitle,
 } from "@/components/ui";
 import { ConnectionPropertiesDialog } from "@/components/connections";
+import { BackupDialog, CopyTableDialog, DatabaseDdlDialog, FindInDatabaseDialog } from "@/components/database";
 import { TableSearch } from "@/components/sidebar/TableSearch";
 import { useConnectionsStore, useUIStore, useQueryStore } from "@/stores";
 import { useDatabase, useToast } from "@/hooks";
-import type { ConnectionInfo, TableInfo } from "@/types";
+import type {
+  BackupOperation,
+  ConfirmationRequired,
+  ConnectionEnvironment,
+  ConnectionInfo,
+  SchemaSearchHit,
+  TableInfo,
+} from "@/types";
+import { useAIStore } from "@/lib/ai/store";
+import { semanticSearchSchema } from "@/lib/ai/embeddings";
 import { BrandIcon } from "@/components/ui";
 import { copyToClipboard, readFromClipboard } from "@/lib/utils";
 import { getDatabaseBrand, getDatabaseColor } from "@/lib/constants";
 import { showSuccessToast, showErrorToast, showInfoToast } from "@/lib/toast-helpers";
 
 interface TreeItemProps {
-  label: string;
+  label: React.ReactNode;
   icon: React.ReactNode;
   children?: React.ReactNode;
   level?: number;
@@ -130,20 +150,102 @@ function TreeItem({
   );
 }
 
+/** Recently queried tables listed above the schemas */
+const RECENT_TABLE_COUNT = 5;
+
+/** Whether a table name recorded from SQL refers to this table */
+function isUsedTable(table: TableInfo, usedName: string): boolean {
+  const name = usedName.toLowerCase();
+  const qualified = table.schema ? `${table.schema}.${table.name}` : table.name;
+  return (
+    table.name.toLowerCase() === name ||
+    qualified.toLowerCase() === name ||
+    (!name.includes(".") && table.name.split(".").pop()?.toLowerCase() === name)
+  );
+}
+
+const ENVIRONMENT_BADGES: Record<ConnectionEnvironment, { label: string; className: string }> = {
+  dev: { label: "dev", className: "bg-emerald-500/15 text-emerald-600 dark:text-emerald-400" },
+  staging: { label: "staging", className: "bg-amber-500/15 text-amber-600 dark:text-amber-400" },
+  prod: { label: "prod", className: "bg-red-500/15 text-red-600 dark:text-red-400" },
+};
+
+/** Connection name with its color label, environment tag and read-only lock */
+function ConnectionLabel({ connection }: { connection: ConnectionInfo }) {
+  const badge = connection.environment ? ENVIRONMENT_BADGES[connection.environment] : null;
+  return (
+    <span className="flex items-center gap-1.5 overflow-hidden">
+      {connection.color && (
+        <span className="w-2 h-2 rounded-sm shrink-0" style={{ backgroundColor: connection.color }} />
+      )}
+      <span className="truncate">{connection.name}</span>
+      {badge && (
+        <span className={cn("rounded px-1 text-[10px] font-medium uppercase shrink-0", badge.className)}>
+          {badge.label}
+        </span>
+      )}
+      {connection.readOnly && <Lock className="h-3 w-3 shrink-0 text-muted-foreground" />}
+    </span>
+  );
+}
+
 function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
   const { activeConnectionId, setActiveConnection } = useConnectionsStore();
   const { openConnectionModal, openRenameTableDialog, openRenameConnectionDialog } = useUIStore();
   const { tablesByConnection, addTab, tabs, setActiveTab, removeTab } = useQueryStore();
-  const { connect, disconnect, getTables, deleteConnection, dropTable, generateTableDdl } = useDatabase();
+  const {
+    connect,
+    disconnect,
+    getTables,
+    pinObject,
+    getConnectionInsights,
+    deleteConnection,
+    dropTable,
+    generateTableDdl,
+  } = useDatabase();
   const { toast } = useToast();
   const [isLoadingTables, setIsLoadingTables] = useState(false);
   const [tablesOpen, setTablesOpen] = useState(false);
   const [showProperties, setShowProperties] = useState(false);
+  const [showDatabaseDdl, setShowDatabaseDdl] = useState(false);
+  const [showFindInDatabase, setShowFindInDatabase] = useState(false);
+  const [backupMode, setBackupMode] = useState<BackupOperation | null>(null);
   const [showDeleteConnectionDialog, setShowDeleteConnectionDialog] = useState(false);
   const [tableToDrop, setTableToDrop] = useState<string | null>(null);
+  const [tableToCopy, setTableToCopy] = useState<string | null>(null);
+  const [dropConfirmation, setDropConfirmation] = useState<ConfirmationRequired | null>(null);
   const [tableSearchQuery, setTableSearchQuery] = useState("");
+  const [semanticSearch, setSemanticSearch] = useState(false);
+  const [semanticHits, setSemanticHits] = useState<SchemaSearchHit[] | null>(null);
+  const [isSearching, setIsSearching] = useState(false);
+  const [recentTableNames, setRecentTableNames] = useState<string[]>([]);
   const isActive = activeConnectionId === connection.id;
 
+  // Rank tables by what their columns and comments mean when semantic search is on
+  useEffect(() => {
+    const query = tableSearchQuery.trim();
+    if (!semanticSearch || !query) {
+      setSemanticHits(null);
+      return;
+    }
+
+    let cancelled = false;
+    setIsSearching(true);
+    semanticSearchSchema(connection.id, query, useAIStore.getState().settings)
+      .then((hits) => {
+        if (!cancelled) setSemanticHits(hits);
+      })
+      .catch((error) => {
+        if (!cancelled) showErrorToast("Semantic Search Failed", error instanceof Error ? error.message : String(error));
+      })
+      .finally(() => {
+        if (!cancelled) setIsSearching(false);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [semanticSearch, tableSearchQuery, connection.id]);
+
   useEffect(() => {
     if (isActive && connection.connected && tablesOpen && !tablesByConnection[connection.id]?.length) {
       loadTables();
@@ -170,6 +272,9 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
     }
 
     setIsLoadingTables(true);
+    getConnectionInsights(connection.id)
+      .then((insights) => setRecentTableNames(insights.recentTables.map((table) => table.table)))
+      .catch((error) => console.error("Failed to load connection insights:", error));
     try {
       await getTables(connection.id);
     } catch (error) {
@@ -210,8 +315,27 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
     }
   };
 
+  const handleTogglePin = async (table: TableInfo) => {
+    try {
+      await pinObject(
+        connection.id,
+        table.tableType.toUpperCase().includes("VIEW") ? "view" : "table",
+        table.name,
+        !table.pinned
+      );
+    } catch (error) {
+      showErrorToast("Failed to update favorites", error instanceof Error ? error.message : String(error));
+    }
+  };
+
   const handleTableDelete = async (tableIdentifier: string) => {
     setTableToDrop(tableIdentifier);
+    setDropConfirmation(null);
+    // Ask for a confirmation token, which also tells us how many rows would be lost
+    const result = await dropTable(connection.id, tableIdentifier);
+    if (result?.status === "confirmationRequired") {
+      setDropConfirmation(result);
+    }
   };
 
   const handleCopyDdl = async (tableIdentifier: string) => {
@@ -324,10 +448,10 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
   };
 
   const confirmTableDelete = async () => {
-    if (!tableToDrop) return;
+    if (!tableToDrop || !dropConfirmation) return;
     try {
-      const result = await dropTable(connection.id, tableToDrop);
-      if (result) {
+      const result = await dropTable(connection.id, tableToDrop, dropConfirmation.token);
+      if (result?.status === "executed") {
         // Remove associated tab if open
         const tabId = `table-${connection.id}-${tableToDrop}`;
         removeTab(tabId);
@@ -348,6 +472,7 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
       });
     } finally {
       setTableToDrop(null);
+      setDropConfirmation(null);
     }
   };
 
@@ -419,7 +544,19 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
   const connectionTables = tablesByConnection[connection.id] || [];
 
   // Filter tables based on search query
-  const filteredTables = tableSearchQuery.trim()
+  // Position of the table's best semantic match; -1 when it has none
+  const hitRank = (table: TableInfo) =>
+    semanticHits?.findIndex((hit) => hit.table === table.name || hit.table === `${table.schema}.${table.name}`) ?? -1;
+  const matchedColumns = (table: TableInfo) =>
+    (semanticHits ?? [])
+      .filter((hit) => hit.column && (hit.table === table.name || hit.table === `${table.schema}.${table.name}`))
+      .slice(0, 2)
+      .map((hit) => hit.column)
+      .join(", ");
+
+  const filteredTables = semanticHits
+    ? connectionTables.filter((table) => hitRank(table) >= 0).sort((a, b) => hitRank(a) - hitRank(b))
+    : tableSearchQuery.trim()
     ? connectionTables.filter((table) =>
         table.name.toLowerCase().includes(tableSearchQuery.toLowerCase())
       )
@@ -435,16 +572,114 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
     return acc;
   }, {});
 
+  // Partitions are listed under their parent rather than beside it
+  const filteredNames = new Set(filteredTables.map((table) => table.name));
+  const partitionsOf = (table: TableInfo) => filteredTables.filter((t) => t.parentTable === table.name);
+  const topLevelTables = (schemaName: string) =>
+    tablesBySchema[schemaName].filter((table) => !table.parentTable || !filteredNames.has(table.parentTable));
+
+  const renderTable = (table: TableInfo, schemaName: string, level: number): React.ReactNode => {
+    // For display, strip the schema prefix if it's there
+    const displayLabel = table.name.startsWith(`${schemaName}.`) 
+      ? table.name.slice(schemaName.length + 1)
+      : table.name;
+    const columns = semanticHits ? matchedColumns(table) : "";
+    const partitions = partitionsOf(table);
+
+    return (
+      <ContextMenu key={table.name}>
+        <ContextMenuTrigger asChild>
+          <div>
+            <TreeItem
+              label={
+                columns ? (
+                  <span>
+                    {displayLabel}
+                    <span className="ml-1 text-muted-foreground">· {columns}</span>
+                  </span>
+                ) : (
+                  displayLabel
+                )
+              }
+              icon={<Table className={cn("h-3.5 w-3.5", table.parentTable ? "text-muted-foreground/50" : "text-muted-foreground")} />}
+              level={level}
+              onClick={() => handleTableClick(table.name, displayLabel)}
+            >
+              {partitions.length > 0 ? partitions.map((partition) => renderTable(partition, schemaName, level + 1)) : undefined}
+            </TreeItem>
+          </div>
+        </ContextMenuTrigger>
+        <ContextMenuContent className="w-56">
+          <ContextMenuItem onSelect={() => handleTableClick(table.name, displayLabel)} className="gap-2">
+            <Table className="h-4 w-4" />
+            View Data
+          </ContextMenuItem>
+          <ContextMenuItem onSelect={() => handleViewProperties(table.name, displayLabel)} className="gap-2">
+            <Info className="h-4 w-4" />
+            View Properties
+          </ContextMenuItem>
+          <ContextMenuItem onSelect={() => handleViewDiagram(table.name, displayLabel)} className="gap-2">
+            <Network className="h-4 w-4" />
+            View Diagram
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+          <ContextMenuItem onSelect={() => handleCopyDdl(table.name)} className="gap-2">
+            <Copy className="h-4 w-4" />
+            Copy
+          </ContextMenuItem>
+          <ContextMenuItem onSelect={() => handlePasteAsNewTable()} className="gap-2">
+            <ClipboardPaste className="h-4 w-4" />
+            Paste
+          </ContextMenuItem>
+          {connection.databaseType !== "mongodb" && connection.databaseType !== "redis" && (
+            <ContextMenuItem onSelect={() => setTableToCopy(table.name)} className="gap-2">
+              <ArrowRightLeft className="h-4 w-4" />
+              Copy to Connection...
+            </ContextMenuItem>
+          )}
+          <ContextMenuItem onSelect={() => handleTogglePin(table)} className="gap-2">
+            {table.pinned ? <StarOff className="h-4 w-4" /> : <Star className="h-4 w-4" />}
+            {table.pinned ? "Remove from Favorites" : "Add to Favorites"}
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+          <ContextMenuItem onSelect={() => handleRenameTable(table.name)} className="gap-2">
+            <Pencil className="h-4 w-4" />
+            Rename Table
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+          <ContextMenuItem
+            onSelect={() => handleTableDelete(table.name)}
+            className="gap-2 text-destructive focus:text-destructive focus:bg-destructive/10"
+          >
+            <Trash2 className="h-4 w-4" />
+            Drop Table
+          </ContextMenuItem>
+        </ContextMenuContent>
+      </ContextMenu>
+    );
+  };
+
   const schemaNames = Object.keys(tablesBySchema).sort();
   const isSingleSchema = schemaNames.length === 1;
 
+  // Pinned tables and views, while not searching
+  const favoriteTables = tableSearchQuery.trim() ? [] : connectionTables.filter((table) => table.pinned);
+
+  // The tables last queried on this connection, while not searching
+  const recentTables = tableSearchQuery.trim()
+    ? []
+    : recentTableNames
+        .map((name) => connectionTables.find((table) => isUsedTable(table, name)))
+        .filter((table, index, all): table is TableInfo => !!table && all.indexOf(table) === index)
+        .slice(0, RECENT_TABLE_COUNT);
+
   return (
     <>
       <ContextMenu>
         <ContextMenuTrigger asChild>
           <div className="rounded-lg overflow-hidden">
             <TreeItem
-              label={connection.name}
+              label={<ConnectionLabel connection={connection} />}
               icon={getIcon()}
               isActive={isActive}
               isConnected={connection.connected}
@@ -467,6 +702,9 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
                               onChange={setTableSearchQuery}
                               onClear={() => setTableSearchQuery("")}
                               matchCount={filteredTables.length}
+                              semantic={semanticSearch}
+                              onToggleSemantic={() => setSemanticSearch((on) => !on)}
+                              searching={isSearching}
                             />
                           </div>
                         )}
@@ -476,84 +714,50 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
                             <span>Loading...</span>
                           </div>
                         ) : schemaNames.length > 0 ? (
-                          schemaNames.map((schemaName) => (
-                            <ContextMenu key={schemaName}>
-                              <ContextMenuTrigger asChild>
-                                <div>
-                                  <TreeItem
-                                    label={schemaName}
-                                    icon={<FolderTree className="h-3.5 w-3.5 text-muted-foreground/50" />}
-                                    level={1}
-                                    defaultOpen={isSingleSchema}
-                                  >
-                                    {tablesBySchema[schemaName].map((table) => {
-                                // For display, strip the schema prefix if it's there
-                                const displayLabel = table.name.startsWith(`${schemaName}.`) 
-                                  ? table.name.slice(schemaName.length + 1)
-                                  : table.name;
-                                
-                                return (
-                                  <ContextMenu key={table.name}>
-                                    <ContextMenuTrigger asChild>
-                                      <div>
-                                        <TreeItem
-                                          label={displayLabel}
-                                          icon={<Table className="h-3.5 w-3.5 text-muted-foreground" />}
-                                          level={2}
-                                          onClick={() => handleTableClick(table.name, displayLabel)}
-                                        />
-                                      </div>
-                                    </ContextMenuTrigger>
-                                    <ContextMenuContent className="w-56">
-                                      <ContextMenuItem onSelect={() => handleTableClick(table.name, displayLabel)} className="gap-2">
-                                        <Table className="h-4 w-4" />
-                                        View Data
-                                      </ContextMenuItem>
-                                      <ContextMenuItem onSelect={() => handleViewProperties(table.name, displayLabel)} className="gap-2">
-                                        <Info className="h-4 w-4" />
-                                        View Properties
-                                      </ContextMenuItem>
-                                      <ContextMenuItem onSelect={() => handleViewDiagram(table.name, displayLabel)} className="gap-2">
-                                        <Network className="h-4 w-4" />
-                                        View Diagram
-                                      </ContextMenuItem>
-                                      <ContextMenuSeparator />
-                                      <ContextMenuItem onSelect={() => handleCopyDdl(table.name)} className="gap-2">
-                                        <Copy className="h-4 w-4" />
-                                        Copy
-                                      </ContextMenuItem>
-                                      <ContextMenuItem onSelect={() => handlePasteAsNewTable()} className="gap-2">
-                                        <ClipboardPaste className="h-4 w-4" />
-                                        Paste
-                                      </ContextMenuItem>
-                                      <ContextMenuSeparator />
-                                      <ContextMenuItem onSelect={() => handleRenameTable(table.name)} className="gap-2">
-                                        <Pencil className="h-4 w-4" />
-                                        Rename Table
-                                      </ContextMenuItem>
-                                      <ContextMenuSeparator />
-                                      <ContextMenuItem
-                                        onSelect={() => handleTableDelete(table.name)}
-                                        className="gap-2 text-destructive focus:text-destructive focus:bg-destructive/10"
-                                      >
-                                        <Trash2 className="h-4 w-4" />
-                                        Drop Table
-                                      </ContextMenuItem>
-                                    </ContextMenuContent>
-                                  </ContextMenu>
-                                );
-                              })}
-                                  </TreeItem>
-                                </div>
-                              </ContextMenuTrigger>
-                              <ContextMenuContent className="w-48">
-                                <ContextMenuItem onSelect={() => handleViewSchemaDiagram(schemaName)} className="gap-2">
-                                  <Network className="h-4 w-4" />
-                                  View Diagram
-                                </ContextMenuItem>
-                              </ContextMenuContent>
-                            </ContextMenu>
-                          ))
+                          <>
+                            {favoriteTables.length > 0 && (
+                              <TreeItem
+                                label="Favorites"
+                                icon={<Star className="h-3.5 w-3.5 text-muted-foreground/50" />}
+                                level={1}
+                                defaultOpen={true}
+                              >
+                                {favoriteTables.map((table) => renderTable(table, table.schema || "default", 2))}
+                              </TreeItem>
+                            )}
+                            {recentTables.length > 0 && (
+                              <TreeItem
+                                label="Recent"
+                                icon={<History className="h-3.5 w-3.5 text-muted-foreground/50" />}
+                                level={1}
+                                defaultOpen={true}
+                              >
+                                {recentTables.map((table) => renderTable(table, table.schema || "default", 2))}
+                              </TreeItem>
+                            )}
+                            {schemaNames.map((schemaName) => (
+                              <ContextMenu key={schemaName}>
+                                <ContextMenuTrigger asChild>
+                                  <div>
+                                    <TreeItem
+                                      label={schemaName}
+                                      icon={<FolderTree className="h-3.5 w-3.5 text-muted-foreground/50" />}
+                                      level={1}
+                                      defaultOpen={isSingleSchema}
+                                    >
+                                      {topLevelTables(schemaName).map((table) => renderTable(table, schemaName, 2))}
+                                    </TreeItem>
+                                  </div>
+                                </ContextMenuTrigger>
+                                <ContextMenuContent className="w-48">
+                                  <ContextMenuItem onSelect={() => handleViewSchemaDiagram(schemaName)} className="gap-2">
+                                    <Network className="h-4 w-4" />
+                                    View Diagram
+                                  </ContextMenuItem>
+                                </ContextMenuContent>
+                              </ContextMenu>
+                            ))}
+                          </>
                         ) : tablesOpen ? (
                           <div className="ml-6 py-2 text-xs text-muted-foreground">No schemas found</div>
                         ) : null}
@@ -582,6 +786,26 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
                 <RefreshCw className={cn("h-4 w-4", isLoadingTables && "animate-spin")} />
                 Refresh
               </ContextMenuItem>
+              {connection.databaseType !== "mongodb" && connection.databaseType !== "redis" && (
+                <>
+                  <ContextMenuItem onSelect={() => setShowFindInDatabase(true)} className="gap-2">
+                    <Search className="h-4 w-4" />
+                    Find in Database...
+                  </ContextMenuItem>
+                  <ContextMenuItem onSelect={() => setShowDatabaseDdl(true)} className="gap-2">
+                    <FileCode className="h-4 w-4" />
+                    Generate Database DDL
+                  </ContextMenuItem>
+                  <ContextMenuItem onSelect={() => setBackupMode("backup")} className="gap-2">
+                    <DatabaseBackup className="h-4 w-4" />
+                    Back Up Database
+                  </ContextMenuItem>
+                  <ContextMenuItem onSelect={() => setBackupMode("restore")} className="gap-2">
+                    <ArchiveRestore className="h-4 w-4" />
+                    Restore Backup
+                  </ContextMenuItem>
+                </>
+              )}
             </>
           ) : (
             <ContextMenuItem onSelect={handleConnect} className="gap-2">
@@ -623,6 +847,35 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
         onOpenChange={setShowProperties}
       />
 
+      <DatabaseDdlDialog
+        connectionId={connection.id}
+        connectionName={connection.name}
+        open={showDatabaseDdl}
+        onOpenChange={setShowDatabaseDdl}
+      />
+
+      <FindInDatabaseDialog
+        connectionId={connection.id}
+        open={showFindInDatabase}
+        onOpenChange={setShowFindInDatabase}
+        onOpenTable={(tableName) => handleTableClick(tableName, tableName.split(".").pop() ?? tableName)}
+      />
+
+      <BackupDialog
+        mode={backupMode ?? "backup"}
+        connectionId={connection.id}
+        connectionName={connection.name}
+        databaseType={connection.databaseType}
+        open={backupMode !== null}
+        onOpenChange={(open) => !open && setBackupMode(null)}
+      />
+
+      <CopyTableDialog
+        connectionId={connection.id}
+        tableName={tableToCopy}
+        onOpenChange={(open) => !open && setTableToCopy(null)}
+      />
+
       <AlertDialog open={showDeleteConnectionDialog} onOpenChange={setShowDeleteConnectionDialog}>
         <AlertDialogContent>
           <AlertDialogHeader>
@@ -649,12 +902,26 @@ function ConnectionItem({ connection }: { connection: ConnectionInfo }) {
             <AlertDialogTitle>Drop Table</AlertDialogTitle>
             <AlertDialogDescription>
               Are you sure you want to drop the table "{tableToDrop}"? All data will be permanently deleted. This action cannot be undone.
+              {dropConfirmation?.estimatedRows != null && (
+                <> About {dropConfirmation.estimatedRows.toLocaleString()} rows will be lost.</>
+              )}
             </AlertDialogDescription>
+            {dropConfirmation && dropConfirmation.dependents.length > 0 && (
+              <div className="text-sm">
+                <p className="text-muted-foreground">These objects depend on it:</p>
+                <ul className="mt-1 max-h-40 list-disc overflow-auto pl-5 font-mono text-xs">
+                  {dropConfirmation.dependents.map((dependent) => (
+                    <li key={dependent}>{dependent}</li>
+                  ))}
+                </ul>
+              </div>
+            )}
           </AlertDialogHeader>
           <AlertDialogFooter>
             <AlertDialogCancel>Cancel</AlertDialogCancel>
             <AlertDialogAction
               onClick={confirmTableDelete}
+              disabled={!dropConfirmation}
               className="bg-destructive text-destructive-foreground hover:bg-destructive/90"
             >
               Drop Table
@@ -676,6 +943,10 @@ export function Sidebar() {
   const { connections } = useConnectionsStore();
   const { loadConnections } = useDatabase();
 
+  // Ungrouped connections first, then one folder per group
+  const ungrouped = connections.filter((conn) => !conn.group);
+  const groups = [...new Set(connections.map((conn) => conn.group).filter((g): g is string => !!g))].sort();
+
   useEffect(() => {
     loadConnections();
   }, [loadConnections]);
@@ -736,9 +1007,25 @@ export function Sidebar() {
               </Button>
             </div>
           ) : (
-            connections.map((conn) => (
-              <ConnectionItem key={conn.id} connection={conn} />
-            ))
+            <>
+              {ungrouped.map((conn) => (
+                <ConnectionItem key={conn.id} connection={conn} />
+              ))}
+              {groups.map((group) => (
+                <TreeItem
+                  key={group}
+                  label={group}
+                  icon={<Folder className="h-4 w-4 text-muted-foreground" />}
+                  defaultOpen={true}
+                >
+                  {connections
+                    .filter((conn) => conn.group === group)
+                    .map((conn) => (
+                      <ConnectionItem key={conn.id} connection={conn} />
+                    ))}
+                </TreeItem>
+              ))}
+            </>
           )}
         </div>
       </ScrollArea>
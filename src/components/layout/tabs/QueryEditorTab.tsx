@@ -1,6 +1,32 @@
 import { useState, useEffect, useCallback, useRef } from "react";
-import { Play, Loader2, Table, Terminal, AlertCircle, RefreshCw } from "lucide-react";
-import { Button, Tooltip, TooltipTrigger, TooltipContent } from "@/components/ui";
+import {
+  Play,
+  Loader2,
+  Table,
+  Terminal,
+  AlertCircle,
+  RefreshCw,
+  AlignLeft,
+  Sparkles,
+  Bookmark,
+  GitCompare,
+  Network,
+  X,
+} from "lucide-react";
+import {
+  Button,
+  Tooltip,
+  TooltipTrigger,
+  TooltipContent,
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from "@/components/ui";
 import { useQueryStore, useConnectionsStore, selectActiveConnection, selectActiveResults, useSchemaStore } from "@/stores";
 import { useUIStore } from "@/stores/ui";
 import { useAIStore } from "@/lib/ai/store";
@@ -10,15 +36,53 @@ import { SqlEditor } from "@/components/editor";
 import { ExecutionTimeBadge } from "@/components/ui/execution-time-badge";
 import { RowCountBadge } from "@/components/ui/row-count-badge";
 import { EmptyQueryState } from "@/components/query-editor/EmptyQueryState";
+import { QueryParametersDialog } from "@/components/query-editor/QueryParametersDialog";
+import { ResultDiffDialog } from "@/components/query-editor/ResultDiffDialog";
+import { FederatedQueryDialog } from "@/components/query-editor/FederatedQueryDialog";
+import { SavedQueriesDropdown } from "@/components/saved-queries/SavedQueriesDropdown";
+import { SaveQueryDialog } from "@/components/saved-queries/SaveQueryDialog";
+import { RunSavedQueryDialog } from "@/components/saved-queries/RunSavedQueryDialog";
 import { QueryHistoryDropdown } from "@/components/query-history/QueryHistoryDropdown";
-import type { Tab, QueryHistoryEntry } from "@/types";
+import { showErrorToast, showInfoToast } from "@/lib/toast-helpers";
+import type {
+  Tab,
+  QueryHistoryEntry,
+  SqlDiagnostic,
+  ConfirmationRequired,
+  ScrollPosition,
+  CellValue,
+  SavedQuery,
+  FederatedQueryResult,
+} from "@/types";
+
+/** Share of the tab's height the results take, in percent */
+const DEFAULT_RESULT_PANE_SIZE = 40;
+const MIN_RESULT_PANE_SIZE = 10;
+const MAX_RESULT_PANE_SIZE = 90;
+
+/** Rows sent with a result, and per "Load more"; the rest stay in the backend */
+const RESULT_WINDOW_ROWS = 1000;
+
+/** Scrolling is kept with the tab once the editor stays still for this long */
+const SCROLL_SAVE_DELAY_MS = 500;
+
+const NO_PARAMETERS: string[] = [];
 
 interface QueryEditorTabProps {
   tab: Tab;
 }
 
 export function QueryEditorTab({ tab }: QueryEditorTabProps) {
-  const { updateTabContent, isExecuting, error, tablesByConnection, addQueryToHistory } = useQueryStore();
+  const {
+    updateTabContent,
+    updateTabLayout,
+    setResults,
+    appendResultRows,
+    isExecuting,
+    error,
+    tablesByConnection,
+    addQueryToHistory,
+  } = useQueryStore();
   const activeConnection = useConnectionsStore(selectActiveConnection);
   const { getSchemas } = useSchemaStore();
   const connectionId = tab.connectionId || activeConnection?.id;
@@ -26,12 +90,77 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
   const schemas = connectionId ? getSchemas(connectionId) : {};
   const results = useQueryStore(selectActiveResults);
   const { theme } = useUIStore();
-  const { setPanelOpen, sendMessage, settings } = useAIStore();
+  const { setPanelOpen, sendMessage, explainQueryError, adviseIndexes, settings } = useAIStore();
   const isAIEnabled = settings.aiEnabled ?? true;
-  const { executeQuery, fetchAllSchemas, refreshSchemas } = useDatabase();
+  const {
+    executeQuery,
+    getResultRows,
+    releaseResult,
+    fetchAllSchemas,
+    refreshSchemas,
+    formatSql,
+    lintSql,
+    getQueryParameters,
+  } = useDatabase();
   const [content, setContent] = useState(tab.content || "");
+  const [isLoadingMore, setIsLoadingMore] = useState(false);
   const [isRefreshing, setIsRefreshing] = useState(false);
   const [activeTooltip, setActiveTooltip] = useState<string | null>(null);
+  const [diagnostics, setDiagnostics] = useState<SqlDiagnostic[]>([]);
+  // UPDATE or DELETE without WHERE waiting for the user to confirm
+  const [pendingConfirmation, setPendingConfirmation] = useState<{
+    sql: string;
+    confirmation: ConfirmationRequired;
+    params?: Record<string, CellValue>;
+  } | null>(null);
+  // Query with named placeholders waiting for their values
+  const [pendingParameters, setPendingParameters] = useState<{ sql: string; names: string[] } | null>(null);
+  const [isSavingQuery, setIsSavingQuery] = useState(false);
+  // Saved query waiting for its variable values
+  const [pendingSavedQuery, setPendingSavedQuery] = useState<SavedQuery | null>(null);
+  // A result kept back from release to compare later runs against
+  const [baselineResultId, setBaselineResultId] = useState<string | null>(null);
+  const [isComparing, setIsComparing] = useState(false);
+  const [isFederating, setIsFederating] = useState(false);
+
+  // Results pane size and editor scrolling are kept with the tab, so they
+  // survive switching tabs and restarting the app
+  const containerRef = useRef<HTMLDivElement>(null);
+  const [resultPaneSize, setResultPaneSize] = useState(tab.resultPaneSize ?? DEFAULT_RESULT_PANE_SIZE);
+  const scrollTimerRef = useRef<ReturnType<typeof setTimeout>>();
+
+  useEffect(() => () => clearTimeout(scrollTimerRef.current), []);
+
+  const handleEditorScroll = useCallback(
+    (editorScroll: ScrollPosition) => {
+      clearTimeout(scrollTimerRef.current);
+      scrollTimerRef.current = setTimeout(() => updateTabLayout(tab.id, { editorScroll }), SCROLL_SAVE_DELAY_MS);
+    },
+    [updateTabLayout, tab.id]
+  );
+
+  const handleResizeStart = (e: React.MouseEvent) => {
+    const container = containerRef.current;
+    if (!container) return;
+    e.preventDefault();
+    let size = resultPaneSize;
+
+    const handleMove = (move: MouseEvent) => {
+      const rect = container.getBoundingClientRect();
+      size = Math.min(
+        MAX_RESULT_PANE_SIZE,
+        Math.max(MIN_RESULT_PANE_SIZE, ((rect.bottom - move.clientY) / rect.height) * 100)
+      );
+      setResultPaneSize(size);
+    };
+    const handleUp = () => {
+      window.removeEventListener("mousemove", handleMove);
+      window.removeEventListener("mouseup", handleUp);
+      updateTabLayout(tab.id, { resultPaneSize: Math.round(size * 10) / 10 });
+    };
+    window.addEventListener("mousemove", handleMove);
+    window.addEventListener("mouseup", handleUp);
+  };
 
   // Fetch all schemas when connection changes
   useEffect(() => {
@@ -51,6 +180,28 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
     }
   }, [connectionId, refreshSchemas]);
 
+  // Lint the query shortly after typing stops
+  const databaseType = activeConnection?.databaseType;
+  useEffect(() => {
+    if (databaseType === "mongodb" || databaseType === "redis") {
+      setDiagnostics([]);
+      return;
+    }
+    const timer = setTimeout(() => {
+      lintSql(content, databaseType).then(setDiagnostics);
+    }, 500);
+    return () => clearTimeout(timer);
+  }, [content, databaseType, lintSql]);
+
+  // Format the editor content in place
+  const handleFormat = useCallback(async () => {
+    const formatted = await formatSql(content, activeConnection?.databaseType);
+    if (formatted !== null) {
+      setContent(formatted);
+      updateTabContent(tab.id, formatted);
+    }
+  }, [content, activeConnection?.databaseType, formatSql, updateTabContent, tab.id]);
+
   // AI context menu handlers
   const handleExplainWithAI = useCallback((sql: string) => {
     if (!isAIEnabled) return;
@@ -66,25 +217,63 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
     sendMessage(`Please optimize this SQL query for better performance:\n\n\`\`\`sql\n${sql}\n\`\`\``);
   }, [isAIEnabled, setPanelOpen, sendMessage]);
 
+  const handleSuggestIndexesWithAI = useCallback((sql: string) => {
+    if (!isAIEnabled) return;
+    setPanelOpen(true);
+    adviseIndexes(sql);
+  }, [isAIEnabled, setPanelOpen, adviseIndexes]);
+
+  // Ask the AI why the last run failed
+  const lastRunSqlRef = useRef("");
+  const handleExplainErrorWithAI = useCallback(() => {
+    if (!isAIEnabled || !error) return;
+    setPanelOpen(true);
+    explainQueryError(lastRunSqlRef.current || content, error);
+  }, [isAIEnabled, error, content, setPanelOpen, explainQueryError]);
+
   useEffect(() => {
     setContent(tab.content || "");
   }, [tab.content]);
 
-  const handleExecute = async (sql?: string) => {
+  const handleExecute = async (sql?: string, confirmationToken?: string, params?: Record<string, CellValue>) => {
     const queryToExecute = sql || content;
     if (!connectionId || !queryToExecute.trim()) return;
 
+    // A confirmed re-run already carries the values given the first time
+    if (!params && !confirmationToken && databaseType !== "mongodb" && databaseType !== "redis") {
+      const names = await getQueryParameters(queryToExecute, databaseType);
+      if (names.length > 0) {
+        setPendingParameters({ sql: queryToExecute, names });
+        return;
+      }
+    }
+
     const startTime = Date.now();
+    lastRunSqlRef.current = queryToExecute;
+    // The rows the previous result kept in the backend are no longer shown
+    const previousResultId = useQueryStore.getState().results[tab.id]?.resultId;
+    if (previousResultId && previousResultId !== baselineResultId) {
+      releaseResult(previousResultId).catch(() => {});
+    }
     const result = await executeQuery(
       {
         connectionId: connectionId,
         sql: queryToExecute,
         limit: undefined,
         offset: undefined,
+        confirmationToken,
+        sessionId: tab.id,
+        params,
+        windowSize: RESULT_WINDOW_ROWS,
       },
       tab.id
     );
 
+    if (result?.status === "confirmationRequired") {
+      setPendingConfirmation({ sql: queryToExecute, confirmation: result, params });
+      return;
+    }
+
     // Save query to history
     const historyEntry: QueryHistoryEntry = {
       id: `${connectionId}-${Date.now()}-${Math.random().toString(36).substring(7)}`,
@@ -92,7 +281,7 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
       sql: queryToExecute,
       executedAt: startTime,
       executionTimeMs: result?.executionTimeMs,
-      rowCount: result?.rows?.length ?? result?.affectedRows,
+      rowCount: result?.totalRows ?? result?.rows?.length ?? result?.affectedRows,
       success: result !== null,
       error: result === null ? useQueryStore.getState().error ?? undefined : undefined,
     };
@@ -100,6 +289,23 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
     addQueryToHistory(historyEntry);
   };
 
+  const handleLoadMore = async () => {
+    if (!results?.resultId) return;
+    setIsLoadingMore(true);
+    try {
+      const page = await getResultRows(results.resultId, results.rows.length, RESULT_WINDOW_ROWS);
+      appendResultRows(tab.id, page.rows);
+    } catch (error) {
+      showErrorToast("Could not load more rows", error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsLoadingMore(false);
+    }
+  };
+
+  const totalRows = results?.totalRows ?? results?.rows.length ?? 0;
+  const remainingRows = totalRows - (results?.rows.length ?? 0);
+  const hasMoreRows = !!results?.resultId && remainingRows > 0;
+
   // Keep handleExecute in a ref for use in event listeners
   const handleExecuteRef = useRef(handleExecute);
   useEffect(() => {
@@ -124,8 +330,36 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
     updateTabContent(tab.id, sql);
   };
 
+  const handleFederatedResult = ({ result, sources, engine }: FederatedQueryResult) => {
+    const previousResultId = useQueryStore.getState().results[tab.id]?.resultId;
+    if (previousResultId && previousResultId !== baselineResultId) {
+      releaseResult(previousResultId).catch(() => {});
+    }
+    setResults(tab.id, result);
+    const copied = sources
+      .map((source) => `${source.alias}: ${source.rows.toLocaleString()} rows${source.truncated ? " (cut short)" : ""}`)
+      .join(", ");
+    showInfoToast(`Ran locally in ${engine}`, copied);
+  };
+
+  const handleClearBaseline = () => {
+    if (baselineResultId && baselineResultId !== results?.resultId) {
+      releaseResult(baselineResultId).catch(() => {});
+    }
+    setBaselineResultId(null);
+  };
+
+  const handleRunSavedQuery = (query: SavedQuery) => {
+    handleSelectExample(query.sql);
+    if (query.variables.length > 0) {
+      setPendingSavedQuery(query);
+    } else {
+      handleExecute(query.sql);
+    }
+  };
+
   return (
-    <div className="flex h-full flex-col">
+    <div ref={containerRef} className="flex h-full flex-col">
       {/* Toolbar */}
       <div className="flex items-center gap-3 border-b border-border bg-muted/30 px-4 py-2">
         <Tooltip open={activeTooltip === "run"}>
@@ -156,11 +390,46 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
 
         {results && (
           <div className="flex items-center gap-2 text-sm">
-            <RowCountBadge rowCount={results.rows.length} affectedRows={results.affectedRows} />
+            <RowCountBadge rowCount={totalRows} affectedRows={results.affectedRows} />
             <ExecutionTimeBadge timeMs={results.executionTimeMs} />
           </div>
         )}
 
+        <Tooltip open={activeTooltip === "format"}>
+          <TooltipTrigger asChild>
+            <Button
+              size="sm"
+              variant="outline"
+              onClick={handleFormat}
+              disabled={!content.trim()}
+              className="gap-2"
+              onMouseEnter={() => setActiveTooltip("format")}
+              onMouseLeave={() => setActiveTooltip(null)}
+            >
+              <AlignLeft className="h-3.5 w-3.5" />
+              Format
+            </Button>
+          </TooltipTrigger>
+          <TooltipContent>Format SQL</TooltipContent>
+        </Tooltip>
+
+        <Tooltip open={activeTooltip === "federate"}>
+          <TooltipTrigger asChild>
+            <Button
+              size="sm"
+              variant="outline"
+              onClick={() => setIsFederating(true)}
+              className="gap-2"
+              onMouseEnter={() => setActiveTooltip("federate")}
+              onMouseLeave={() => setActiveTooltip(null)}
+            >
+              <Network className="h-3.5 w-3.5" />
+              Join
+            </Button>
+          </TooltipTrigger>
+          <TooltipContent>Join rows from several connections, locally</TooltipContent>
+        </Tooltip>
+
         {connectionId && (
           <QueryHistoryDropdown
             connectionId={connectionId}
@@ -170,6 +439,17 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
           />
         )}
 
+        {connectionId && (
+          <SavedQueriesDropdown
+            connectionId={connectionId}
+            canSave={!!content.trim()}
+            onSaveCurrent={() => setIsSavingQuery(true)}
+            onRunQuery={handleRunSavedQuery}
+            activeTooltip={activeTooltip}
+            onSetActiveTooltip={setActiveTooltip}
+          />
+        )}
+
         {connectionId && (
           <Tooltip open={activeTooltip === "refresh"}>
             <TooltipTrigger asChild>
@@ -211,23 +491,74 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
           onExecute={handleExecute}
           onExplainWithAI={isAIEnabled ? handleExplainWithAI : undefined}
           onOptimizeWithAI={isAIEnabled ? handleOptimizeWithAI : undefined}
+          onSuggestIndexesWithAI={isAIEnabled ? handleSuggestIndexesWithAI : undefined}
           tables={tables}
           schemas={schemas}
+          diagnostics={diagnostics}
           theme={theme}
           height="100%"
+          initialScroll={tab.editorScroll}
+          onScrollChange={handleEditorScroll}
         />
       </div>
 
+      {/* Drag to resize the results */}
+      <div
+        className="h-1 shrink-0 cursor-row-resize border-t border-border hover:bg-primary/30"
+        onMouseDown={handleResizeStart}
+      />
+
       {/* Results Area */}
-      <div className="h-2/5 min-h-[200px] border-t border-border flex flex-col">
+      <div className="shrink-0 flex flex-col" style={{ height: `${resultPaneSize}%` }}>
         <div className="flex items-center gap-2 border-b border-border bg-muted/30 px-4 py-2">
           <Table className="h-4 w-4 text-muted-foreground" />
           <span className="text-sm font-medium">Results</span>
           {results && (
             <span className="text-xs text-muted-foreground">
-              ({results.rows.length} rows)
+              {hasMoreRows
+                ? `(${results.rows.length.toLocaleString()} of ${totalRows.toLocaleString()} rows)`
+                : `(${results.rows.length} rows)`}
             </span>
           )}
+          {hasMoreRows && (
+            <Button size="sm" variant="ghost" className="h-6 px-2 text-xs" onClick={handleLoadMore} disabled={isLoadingMore}>
+              {isLoadingMore && <Loader2 className="h-3 w-3 mr-1 animate-spin" />}
+              Load {Math.min(RESULT_WINDOW_ROWS, remainingRows).toLocaleString()} more
+            </Button>
+          )}
+          <div className="ml-auto flex items-center gap-1">
+            {baselineResultId && (
+              <Button size="sm" variant="ghost" className="h-6 px-2 text-xs gap-1" onClick={handleClearBaseline}>
+                <X className="h-3 w-3" />
+                {baselineResultId === results?.resultId ? "Kept for comparison" : "Forget kept result"}
+              </Button>
+            )}
+            {results?.resultId && baselineResultId !== results.resultId && (
+              <Button
+                size="sm"
+                variant="ghost"
+                className="h-6 px-2 text-xs gap-1"
+                onClick={() => {
+                  handleClearBaseline();
+                  setBaselineResultId(results.resultId ?? null);
+                }}
+              >
+                <Bookmark className="h-3 w-3" />
+                Keep for comparison
+              </Button>
+            )}
+            {results?.resultId && baselineResultId && baselineResultId !== results.resultId && (
+              <Button
+                size="sm"
+                variant="outline"
+                className="h-6 px-2 text-xs gap-1"
+                onClick={() => setIsComparing(true)}
+              >
+                <GitCompare className="h-3 w-3" />
+                Compare with kept
+              </Button>
+            )}
+          </div>
         </div>
         <div className="flex-1 overflow-hidden">
           {error ? (
@@ -236,6 +567,12 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
                 <AlertCircle className="h-5 w-5 shrink-0" />
                 <span className="text-sm">{error}</span>
               </div>
+              {isAIEnabled && (
+                <Button size="sm" variant="outline" className="gap-2 shrink-0" onClick={handleExplainErrorWithAI}>
+                  <Sparkles className="h-3.5 w-3.5" />
+                  Explain with AI
+                </Button>
+              )}
             </div>
           ) : results ? (
             <DataGrid data={results} />
@@ -252,6 +589,82 @@ export function QueryEditorTab({ tab }: QueryEditorTabProps) {
           )}
         </div>
       </div>
+
+      <AlertDialog open={!!pendingConfirmation} onOpenChange={(open) => !open && setPendingConfirmation(null)}>
+        <AlertDialogContent>
+          <AlertDialogHeader>
+            <AlertDialogTitle>Run Query?</AlertDialogTitle>
+            <AlertDialogDescription>
+              {pendingConfirmation?.confirmation.summary}.
+              {pendingConfirmation?.confirmation.estimatedRows != null && (
+                <> About {pendingConfirmation.confirmation.estimatedRows.toLocaleString()} rows will be affected.</>
+              )}
+            </AlertDialogDescription>
+          </AlertDialogHeader>
+          <AlertDialogFooter>
+            <AlertDialogCancel>Cancel</AlertDialogCancel>
+            <AlertDialogAction
+              onClick={() => {
+                if (pendingConfirmation) {
+                  handleExecute(
+                    pendingConfirmation.sql,
+                    pendingConfirmation.confirmation.token,
+                    pendingConfirmation.params
+                  );
+                }
+                setPendingConfirmation(null);
+              }}
+              className="bg-destructive text-destructive-foreground hover:bg-destructive/90"
+            >
+              Run
+            </AlertDialogAction>
+          </AlertDialogFooter>
+        </AlertDialogContent>
+      </AlertDialog>
+
+      <QueryParametersDialog
+        names={pendingParameters?.names ?? NO_PARAMETERS}
+        onRun={(params) => {
+          if (pendingParameters) {
+            handleExecute(pendingParameters.sql, undefined, params);
+          }
+          setPendingParameters(null);
+        }}
+        onCancel={() => setPendingParameters(null)}
+      />
+
+      <ResultDiffDialog
+        baselineResultId={isComparing ? baselineResultId : null}
+        currentResultId={isComparing ? results?.resultId ?? null : null}
+        columns={results?.columns.map((column) => column.name) ?? []}
+        onClose={() => setIsComparing(false)}
+      />
+
+      <FederatedQueryDialog
+        open={isFederating}
+        currentResultId={results?.resultId}
+        onOpenChange={setIsFederating}
+        onResult={handleFederatedResult}
+      />
+
+      <SaveQueryDialog
+        open={isSavingQuery}
+        sql={content}
+        databaseType={databaseType}
+        onOpenChange={setIsSavingQuery}
+      />
+
+      {connectionId && (
+        <RunSavedQueryDialog
+          query={pendingSavedQuery}
+          connectionId={connectionId}
+          onRun={(sql, params) => {
+            setPendingSavedQuery(null);
+            handleExecute(sql, undefined, params);
+          }}
+          onCancel={() => setPendingSavedQuery(null)}
+        />
+      )}
     </div>
   );
 }
@@ -1,12 +1,14 @@
-import { useEffect } from "react";
-import { Loader2, RefreshCw, AlertCircle, Save, RotateCcw, PanelRightOpen, PanelRightClose } from "lucide-react";
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { Loader2, RefreshCw, AlertCircle, Save, RotateCcw, PanelRightOpen, PanelRightClose, Radio } from "lucide-react";
 import { Button, Separator, Tooltip, TooltipContent, TooltipTrigger } from "@/components/ui";
-import { useQueryStore, useCRUDStore, useUIStore } from "@/stores";
+import { useQueryStore, useCRUDStore, useUIStore, useConnectionsStore } from "@/stores";
 import { useDatabase, useCRUD } from "@/hooks";
 import { DataGrid } from "@/components/data-grid";
 import { ExecutionTimeBadge } from "@/components/ui/execution-time-badge";
 import { RowCountBadge } from "@/components/ui/row-count-badge";
-import type { Tab } from "@/types";
+import { showErrorToast } from "@/lib/toast-helpers";
+import type { Tab, TableChangeEvent } from "@/types";
 
 interface TableViewerTabProps {
   tab: Tab;
@@ -16,18 +18,22 @@ export function TableViewerTab({ tab }: TableViewerTabProps) {
   const { isExecuting, error, results } = useQueryStore();
   const { pendingChanges, clearPendingChanges } = useCRUDStore();
   const { toggleSidePanel, sidePanelOpen } = useUIStore();
-  const { executeQuery } = useDatabase();
+  const { executeQuery, watchTable, unwatchTable } = useDatabase();
   const { commitChanges } = useCRUD();
   const tabResults = results[tab.id];
   const connectionId = tab.connectionId;
+  const tableIdentifier = tab.tableName ?? tab.title;
+  // Live refresh follows row changes, which only PostgreSQL reports
+  const canWatch = useConnectionsStore(
+    (state) => state.connections.find((c) => c.id === connectionId)?.databaseType === "postgresql"
+  );
+  const [live, setLive] = useState(false);
 
   const pendingCount = Object.keys(pendingChanges).length;
 
   const loadData = async () => {
     if (!connectionId) return;
 
-    const tableIdentifier = tab.tableName ?? tab.title;
-
     await executeQuery(
       {
         connectionId: connectionId,
@@ -43,6 +49,29 @@ export function TableViewerTab({ tab }: TableViewerTabProps) {
     }
   }, [tab.id, connectionId]);
 
+  // Reload when the table changes while live, unless edits are pending
+  const loadDataRef = useRef(loadData);
+  useEffect(() => {
+    loadDataRef.current = loadData;
+  });
+  useEffect(() => {
+    if (!live || !connectionId) return;
+    watchTable(connectionId, tableIdentifier).catch((error) => {
+      setLive(false);
+      showErrorToast("Live refresh unavailable", error instanceof Error ? error.message : String(error));
+    });
+    const unlisten = listen<TableChangeEvent>("table-changed", (event) => {
+      const hasPendingChanges = Object.keys(useCRUDStore.getState().pendingChanges).length > 0;
+      if (event.payload.connectionId === connectionId && event.payload.table === tableIdentifier && !hasPendingChanges) {
+        loadDataRef.current();
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+      unwatchTable(connectionId, tableIdentifier).catch(() => {});
+    };
+  }, [live, connectionId, tableIdentifier, watchTable, unwatchTable]);
+
   // Handle F5 refresh
   useEffect(() => {
     const handleKeyDown = (e: KeyboardEvent) => {
@@ -77,6 +106,26 @@ export function TableViewerTab({ tab }: TableViewerTabProps) {
             Refresh
           </Button>
 
+          {canWatch && (
+            <Tooltip>
+              <TooltipTrigger asChild>
+                <Button
+                  variant={live ? "secondary" : "ghost"}
+                  size="sm"
+                  onClick={() => setLive(!live)}
+                  disabled={!connectionId}
+                  className="gap-2"
+                >
+                  <Radio className={`h-3.5 w-3.5 ${live ? "text-success" : ""}`} />
+                  Live
+                </Button>
+              </TooltipTrigger>
+              <TooltipContent>
+                {live ? "Stop refreshing when rows change" : "Refresh when rows change"}
+              </TooltipContent>
+            </Tooltip>
+          )}
+
                  <Separator orientation="vertical" className="h-4" />
        
                  {pendingCount > 0 && (
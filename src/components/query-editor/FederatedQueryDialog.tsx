@@ -0,0 +1,209 @@
+import { useEffect, useState } from "react";
+import { Loader2, Network, Plus, Trash2 } from "lucide-react";
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { useConnectionsStore } from "@/stores";
+import type { FederatedQueryResult, FederatedSource } from "@/types";
+
+/** Connection select value for the tab's current result */
+const CURRENT_RESULT = "__current_result__";
+
+interface SourceDraft {
+  alias: string;
+  /** A connection ID, or CURRENT_RESULT */
+  origin: string;
+  kind: "table" | "sql";
+  value: string;
+}
+
+interface FederatedQueryDialogProps {
+  open: boolean;
+  /** The tab's kept result, offered as a source */
+  currentResultId?: string | null;
+  onOpenChange: (open: boolean) => void;
+  onResult: (result: FederatedQueryResult) => void;
+}
+
+export function FederatedQueryDialog({ open, currentResultId, onOpenChange, onResult }: FederatedQueryDialogProps) {
+  const { runFederatedQuery } = useDatabase();
+  const connections = useConnectionsStore((state) => state.connections).filter(
+    (connection) => connection.connected && connection.databaseType !== "mongodb" && connection.databaseType !== "redis"
+  );
+  const [sources, setSources] = useState<SourceDraft[]>([]);
+  const [sql, setSql] = useState("");
+  const [isRunning, setIsRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const newSource = (index: number): SourceDraft => ({
+    alias: `t${index + 1}`,
+    origin: connections[0]?.id ?? CURRENT_RESULT,
+    kind: "table",
+    value: "",
+  });
+
+  useEffect(() => {
+    if (open && sources.length === 0) {
+      setSources([newSource(0), newSource(1)]);
+    }
+    setError(null);
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [open]);
+
+  const updateSource = (index: number, change: Partial<SourceDraft>) => {
+    setSources((prev) => prev.map((source, i) => (i === index ? { ...source, ...change } : source)));
+    setError(null);
+  };
+
+  const handleRun = async () => {
+    const request: FederatedSource[] = sources.map((source) =>
+      source.origin === CURRENT_RESULT
+        ? { alias: source.alias.trim(), resultId: currentResultId ?? undefined }
+        : {
+            alias: source.alias.trim(),
+            connectionId: source.origin,
+            tableName: source.kind === "table" ? source.value.trim() : undefined,
+            sql: source.kind === "sql" ? source.value : undefined,
+          }
+    );
+    setIsRunning(true);
+    try {
+      onResult(await runFederatedQuery({ sources: request, sql }));
+      onOpenChange(false);
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsRunning(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !isRunning && onOpenChange(next)}>
+      <DialogContent className="max-w-[720px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Network className="h-5 w-5" />
+            Join Across Connections
+          </DialogTitle>
+          <DialogDescription>
+            Runs locally: each source's rows are copied into an in-memory SQLite database on this machine and the
+            query runs there on that snapshot. Nothing is written to the connections.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="space-y-2">
+            <Label>Sources</Label>
+            {sources.map((source, index) => (
+              <div key={index} className="flex items-center gap-2">
+                <Input
+                  value={source.alias}
+                  onChange={(e) => updateSource(index, { alias: e.target.value })}
+                  placeholder="Alias"
+                  className="w-[100px] shrink-0 font-mono"
+                />
+                <Select value={source.origin} onValueChange={(origin) => updateSource(index, { origin })}>
+                  <SelectTrigger className="w-[180px] shrink-0">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {connections.map((connection) => (
+                      <SelectItem key={connection.id} value={connection.id}>
+                        {connection.name}
+                      </SelectItem>
+                    ))}
+                    {currentResultId && <SelectItem value={CURRENT_RESULT}>This tab's result</SelectItem>}
+                  </SelectContent>
+                </Select>
+                {source.origin !== CURRENT_RESULT && (
+                  <>
+                    <Select
+                      value={source.kind}
+                      onValueChange={(kind: SourceDraft["kind"]) => updateSource(index, { kind })}
+                    >
+                      <SelectTrigger className="w-[90px] shrink-0">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value="table">Table</SelectItem>
+                        <SelectItem value="sql">Query</SelectItem>
+                      </SelectContent>
+                    </Select>
+                    <Input
+                      value={source.value}
+                      onChange={(e) => updateSource(index, { value: e.target.value })}
+                      placeholder={source.kind === "table" ? "schema.table" : "SELECT ..."}
+                      className="font-mono"
+                    />
+                  </>
+                )}
+                <Button
+                  size="icon"
+                  variant="ghost"
+                  className="h-8 w-8 shrink-0"
+                  onClick={() => setSources((prev) => prev.filter((_, i) => i !== index))}
+                  disabled={sources.length <= 1}
+                >
+                  <Trash2 className="h-3.5 w-3.5" />
+                </Button>
+              </div>
+            ))}
+            <Button
+              size="sm"
+              variant="ghost"
+              className="gap-2"
+              onClick={() => setSources((prev) => [...prev, newSource(prev.length)])}
+            >
+              <Plus className="h-3.5 w-3.5" />
+              Add source
+            </Button>
+          </div>
+
+          <div className="space-y-2">
+            <Label htmlFor="federated-sql">Query (SQLite)</Label>
+            <textarea
+              id="federated-sql"
+              value={sql}
+              onChange={(e) => setSql(e.target.value)}
+              placeholder="SELECT * FROM t1 JOIN t2 ON t2.user_id = t1.id"
+              rows={6}
+              className="w-full rounded-md border bg-background px-3 py-2 font-mono text-xs focus:outline-none focus:ring-1 focus:ring-ring"
+            />
+          </div>
+
+          {error && <p className="text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={() => onOpenChange(false)} disabled={isRunning}>
+            Cancel
+          </Button>
+          <Button onClick={handleRun} disabled={isRunning || !sql.trim() || sources.length === 0}>
+            {isRunning ? (
+              <>
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                Running locally...
+              </>
+            ) : (
+              "Run locally"
+            )}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
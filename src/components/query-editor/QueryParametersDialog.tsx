@@ -0,0 +1,142 @@
+import { useEffect, useState } from "react";
+import { Braces } from "lucide-react";
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import type { CellValue } from "@/types";
+
+type ParameterKind = "text" | "number" | "null";
+
+interface ParameterInput {
+  kind: ParameterKind;
+  value: string;
+}
+
+/** Values entered last, per parameter name, so re-running a query asks with them filled in */
+const lastInputs = new Map<string, ParameterInput>();
+
+function toCellValue(input: ParameterInput): CellValue | null {
+  switch (input.kind) {
+    case "null":
+      return { type: "null" };
+    case "text":
+      return { type: "text", value: input.value };
+    case "number": {
+      const trimmed = input.value.trim();
+      const number = Number(trimmed);
+      if (!trimmed || !Number.isFinite(number)) return null;
+      return Number.isInteger(number) && !/[.eE]/.test(trimmed)
+        ? { type: "int", value: number }
+        : { type: "float", value: number };
+    }
+  }
+}
+
+interface QueryParametersDialogProps {
+  /** Parameter names to ask for; the dialog is open while there are any */
+  names: string[];
+  onRun: (params: Record<string, CellValue>) => void;
+  onCancel: () => void;
+}
+
+export function QueryParametersDialog({ names, onRun, onCancel }: QueryParametersDialogProps) {
+  const [inputs, setInputs] = useState<Record<string, ParameterInput>>({});
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    setInputs(
+      Object.fromEntries(names.map((name) => [name, lastInputs.get(name) ?? { kind: "text", value: "" }]))
+    );
+    setError(null);
+  }, [names]);
+
+  const update = (name: string, change: Partial<ParameterInput>) => {
+    setInputs((prev) => ({ ...prev, [name]: { ...prev[name], ...change } }));
+    setError(null);
+  };
+
+  const handleRun = () => {
+    const params: Record<string, CellValue> = {};
+    for (const name of names) {
+      const input = inputs[name] ?? { kind: "text", value: "" };
+      const value = toCellValue(input);
+      if (!value) {
+        setError(`${name} is not a number`);
+        return;
+      }
+      params[name] = value;
+      lastInputs.set(name, input);
+    }
+    onRun(params);
+  };
+
+  return (
+    <Dialog open={names.length > 0} onOpenChange={(open) => !open && onCancel()}>
+      <DialogContent className="max-w-[480px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Braces className="h-5 w-5" />
+            Query Parameters
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-3 py-2">
+          {names.map((name, index) => {
+            const input = inputs[name] ?? { kind: "text", value: "" };
+            return (
+              <div key={name} className="space-y-1.5">
+                <Label htmlFor={`query-param-${name}`} className="font-mono">
+                  {name}
+                </Label>
+                <div className="flex gap-2">
+                  <Input
+                    id={`query-param-${name}`}
+                    value={input.kind === "null" ? "" : input.value}
+                    onChange={(e) => update(name, { value: e.target.value })}
+                    placeholder={input.kind === "null" ? "NULL" : undefined}
+                    disabled={input.kind === "null"}
+                    autoFocus={index === 0}
+                    onKeyDown={(e) => {
+                      if (e.key === "Enter") handleRun();
+                    }}
+                  />
+                  <Select value={input.kind} onValueChange={(kind: ParameterKind) => update(name, { kind })}>
+                    <SelectTrigger className="w-[110px] shrink-0">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      <SelectItem value="text">Text</SelectItem>
+                      <SelectItem value="number">Number</SelectItem>
+                      <SelectItem value="null">NULL</SelectItem>
+                    </SelectContent>
+                  </Select>
+                </div>
+              </div>
+            );
+          })}
+
+          {error && <p className="text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={onCancel}>
+            Cancel
+          </Button>
+          <Button onClick={handleRun}>Run</Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,169 @@
+import { useEffect, useState } from "react";
+import { GitCompare, Loader2 } from "lucide-react";
+import {
+  Badge,
+  Dialog,
+  DialogContent,
+  DialogHeader,
+  DialogTitle,
+  Label,
+  ScrollArea,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { cn } from "@/lib/utils";
+import type { ResultDiff, ResultRowDiff } from "@/types";
+
+/** Select value for matching whole rows instead of a key column */
+const WHOLE_ROWS = "__whole_rows__";
+
+/** Row differences shown; the rest are only counted */
+const MAX_DIFF_ROWS = 500;
+
+interface ResultDiffDialogProps {
+  /** The kept result, compared as "before" */
+  baselineResultId: string | null;
+  /** The latest result, compared as "after" */
+  currentResultId: string | null;
+  /** Columns of the latest result, offered as the key */
+  columns: string[];
+  onClose: () => void;
+}
+
+function cellText(value: unknown): string {
+  if (value === null || value === undefined) return "NULL";
+  return typeof value === "object" ? JSON.stringify(value) : String(value);
+}
+
+function describeRow(row: ResultRowDiff, columns: string[]): string {
+  if (row.change !== "changed") {
+    return Object.entries(row.key)
+      .map(([column, value]) => `${column}=${cellText(value)}`)
+      .join(", ");
+  }
+  return row.changedColumns
+    .map((column) => {
+      const index = columns.indexOf(column);
+      return `${column}: ${cellText(row.before?.[index])} → ${cellText(row.after?.[index])}`;
+    })
+    .join(", ");
+}
+
+export function ResultDiffDialog({ baselineResultId, currentResultId, columns, onClose }: ResultDiffDialogProps) {
+  const { diffResults } = useDatabase();
+  const [keyColumn, setKeyColumn] = useState(WHOLE_ROWS);
+  const [diff, setDiff] = useState<ResultDiff | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const open = !!baselineResultId && !!currentResultId;
+
+  useEffect(() => {
+    if (!baselineResultId || !currentResultId) return;
+    setIsLoading(true);
+    setError(null);
+    const keyColumns = keyColumn === WHOLE_ROWS ? undefined : [keyColumn];
+    diffResults(baselineResultId, currentResultId, keyColumns, MAX_DIFF_ROWS)
+      .then(setDiff)
+      .catch((error) => {
+        setDiff(null);
+        setError(error instanceof Error ? error.message : String(error));
+      })
+      .finally(() => setIsLoading(false));
+  }, [baselineResultId, currentResultId, keyColumn, diffResults]);
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !next && onClose()}>
+      <DialogContent className="max-w-[760px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <GitCompare className="h-5 w-5" />
+            Compare Results
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="flex items-center gap-3">
+            <Label className="shrink-0 text-sm">Match rows on</Label>
+            <Select value={keyColumn} onValueChange={setKeyColumn}>
+              <SelectTrigger className="w-[240px]">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={WHOLE_ROWS}>Whole rows</SelectItem>
+                {columns.map((column) => (
+                  <SelectItem key={column} value={column}>
+                    {column}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+            {isLoading && <Loader2 className="h-4 w-4 animate-spin text-muted-foreground" />}
+          </div>
+
+          {error && <p className="text-sm text-destructive">{error}</p>}
+
+          {diff && (
+            <>
+              <div className="flex flex-wrap items-center gap-2 text-sm">
+                <Badge variant="secondary" className="text-green-600">+{diff.added.toLocaleString()} added</Badge>
+                <Badge variant="secondary" className="text-destructive">
+                  −{diff.removed.toLocaleString()} removed
+                </Badge>
+                <Badge variant="secondary" className="text-amber-600">~{diff.changed.toLocaleString()} changed</Badge>
+                <span className="text-xs text-muted-foreground">
+                  {diff.unchanged.toLocaleString()} unchanged of {diff.rowsA.toLocaleString()} →{" "}
+                  {diff.rowsB.toLocaleString()} rows in {diff.executionTimeMs} ms
+                </span>
+              </div>
+
+              {(diff.addedColumns.length > 0 || diff.removedColumns.length > 0) && (
+                <p className="text-xs text-muted-foreground">
+                  {diff.addedColumns.length > 0 && <>New columns: {diff.addedColumns.join(", ")}. </>}
+                  {diff.removedColumns.length > 0 && <>Dropped columns: {diff.removedColumns.join(", ")}.</>}
+                </p>
+              )}
+
+              <ScrollArea className="h-[360px] rounded-md border">
+                {diff.rows.length === 0 ? (
+                  <p className="p-6 text-center text-sm text-muted-foreground">The results have the same rows</p>
+                ) : (
+                  <div className="divide-y divide-border font-mono text-xs">
+                    {diff.rows.map((row, index) => (
+                      <div key={index} className="flex gap-3 px-3 py-1.5">
+                        <span
+                          className={cn(
+                            "w-4 shrink-0 font-bold",
+                            row.change === "added" && "text-green-600",
+                            row.change === "removed" && "text-destructive",
+                            row.change === "changed" && "text-amber-600"
+                          )}
+                        >
+                          {row.change === "added" ? "+" : row.change === "removed" ? "−" : "~"}
+                        </span>
+                        {row.change === "changed" && (
+                          <span className="shrink-0 text-muted-foreground">
+                            {Object.values(row.key).map(cellText).join(", ")}
+                          </span>
+                        )}
+                        <span className="break-all">{describeRow(row, diff.columns)}</span>
+                      </div>
+                    ))}
+                    {diff.truncated && (
+                      <p className="px-3 py-2 text-muted-foreground">
+                        Showing the first {diff.rows.length.toLocaleString()} differences
+                      </p>
+                    )}
+                  </div>
+                )}
+              </ScrollArea>
+            </>
+          )}
+        </div>
+      </DialogContent>
+    </Dialog>
+  );
+}
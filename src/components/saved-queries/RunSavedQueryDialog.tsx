@@ -0,0 +1,121 @@
+import { useEffect, useState } from "react";
+import { Play, Star } from "lucide-react";
+import {
+  Button,
+  Checkbox,
+  Dialog,
+  DialogContent,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import type { CellValue, SavedQuery } from "@/types";
+import { VARIABLE_TYPES } from "./SaveQueryDialog";
+
+interface RunSavedQueryDialogProps {
+  /** The query to ask variable values for; the dialog is open while set */
+  query: SavedQuery | null;
+  connectionId: string;
+  onRun: (sql: string, params: Record<string, CellValue>) => void;
+  onCancel: () => void;
+}
+
+export function RunSavedQueryDialog({ query, connectionId, onRun, onCancel }: RunSavedQueryDialogProps) {
+  const { setSavedQueryPreset, getSavedQueryParams } = useDatabase();
+  const [values, setValues] = useState<Record<string, string>>({});
+  const [rememberValues, setRememberValues] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  // Start from the connection's preset, then each variable's default
+  useEffect(() => {
+    if (!query) return;
+    const preset = query.presets[connectionId] ?? {};
+    setValues(
+      Object.fromEntries(
+        query.variables.map((variable) => [variable.name, preset[variable.name] ?? variable.defaultValue ?? ""])
+      )
+    );
+    setRememberValues(false);
+    setError(null);
+  }, [query, connectionId]);
+
+  const handleRun = async () => {
+    if (!query) return;
+    try {
+      if (rememberValues) {
+        await setSavedQueryPreset(query.id, connectionId, values);
+      }
+      const params = await getSavedQueryParams(query.id, connectionId, values);
+      onRun(query.sql, params);
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  return (
+    <Dialog open={!!query} onOpenChange={(open) => !open && onCancel()}>
+      <DialogContent className="max-w-[480px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Star className="h-5 w-5" />
+            {query?.name}
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-3 py-2">
+          {query?.description && <p className="text-sm text-muted-foreground">{query.description}</p>}
+
+          {query?.variables.map((variable, index) => (
+            <div key={variable.name} className="space-y-1.5">
+              <Label htmlFor={`saved-query-var-${variable.name}`} className="flex items-center gap-2">
+                <span className="font-mono">{variable.name}</span>
+                <span className="text-xs text-muted-foreground">
+                  {VARIABLE_TYPES.find((type) => type.value === variable.type)?.label}
+                </span>
+              </Label>
+              <Input
+                id={`saved-query-var-${variable.name}`}
+                value={values[variable.name] ?? ""}
+                onChange={(e) => {
+                  setValues((prev) => ({ ...prev, [variable.name]: e.target.value }));
+                  setError(null);
+                }}
+                placeholder={variable.type === "text" ? undefined : "Empty for NULL"}
+                autoFocus={index === 0}
+                onKeyDown={(e) => {
+                  if (e.key === "Enter") handleRun();
+                }}
+              />
+            </div>
+          ))}
+
+          <div className="flex items-center gap-2 pt-1">
+            <Checkbox
+              id="saved-query-remember"
+              checked={rememberValues}
+              onCheckedChange={(checked: boolean) => setRememberValues(checked)}
+            />
+            <Label htmlFor="saved-query-remember" className="text-sm">
+              Remember these values for this connection
+            </Label>
+          </div>
+
+          {error && <p className="text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={onCancel}>
+            Cancel
+          </Button>
+          <Button onClick={handleRun} className="gap-2">
+            <Play className="h-3.5 w-3.5" />
+            Run
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,169 @@
+import { useEffect, useState } from "react";
+import { Loader2, Star } from "lucide-react";
+import {
+  Button,
+  Dialog,
+  DialogContent,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showInfoToast } from "@/lib/toast-helpers";
+import type { DatabaseType, QueryVariable, SavedQuery, VariableType } from "@/types";
+
+export const VARIABLE_TYPES: { value: VariableType; label: string }[] = [
+  { value: "text", label: "Text" },
+  { value: "integer", label: "Integer" },
+  { value: "number", label: "Number" },
+  { value: "boolean", label: "Boolean" },
+  { value: "date", label: "Date" },
+];
+
+interface SaveQueryDialogProps {
+  open: boolean;
+  sql: string;
+  databaseType?: DatabaseType;
+  onOpenChange: (open: boolean) => void;
+}
+
+export function SaveQueryDialog({ open, sql, databaseType, onOpenChange }: SaveQueryDialogProps) {
+  const { getQueryParameters, saveSavedQuery } = useDatabase();
+  const [name, setName] = useState("");
+  const [description, setDescription] = useState("");
+  const [variables, setVariables] = useState<QueryVariable[]>([]);
+  const [isSaving, setIsSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  // Declare a variable for each placeholder in the SQL
+  useEffect(() => {
+    if (!open) return;
+    setName("");
+    setDescription("");
+    setError(null);
+    getQueryParameters(sql, databaseType).then((names) =>
+      setVariables(names.map((name) => ({ name, type: "text", defaultValue: null })))
+    );
+  }, [open, sql, databaseType, getQueryParameters]);
+
+  const updateVariable = (index: number, change: Partial<QueryVariable>) => {
+    setVariables((prev) => prev.map((variable, i) => (i === index ? { ...variable, ...change } : variable)));
+    setError(null);
+  };
+
+  const handleSave = async () => {
+    if (!name.trim()) return;
+    setIsSaving(true);
+    try {
+      const query: SavedQuery = {
+        id: "",
+        name: name.trim(),
+        sql,
+        description: description.trim() || null,
+        variables,
+        presets: {},
+      };
+      const saved = await saveSavedQuery(query);
+      showInfoToast("Query saved", saved.name);
+      onOpenChange(false);
+    } catch (error) {
+      setError(error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Dialog open={open} onOpenChange={(next) => !isSaving && onOpenChange(next)}>
+      <DialogContent className="max-w-[520px]">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <Star className="h-5 w-5" />
+            Save Query
+          </DialogTitle>
+        </DialogHeader>
+
+        <div className="space-y-4 py-2">
+          <div className="space-y-2">
+            <Label htmlFor="saved-query-name">Name</Label>
+            <Input
+              id="saved-query-name"
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder="e.g. Orders for a customer"
+              disabled={isSaving}
+              autoFocus
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="saved-query-description">Description</Label>
+            <Input
+              id="saved-query-description"
+              value={description}
+              onChange={(e) => setDescription(e.target.value)}
+              placeholder="Optional"
+              disabled={isSaving}
+            />
+          </div>
+
+          {variables.length > 0 && (
+            <div className="space-y-2">
+              <Label>Variables</Label>
+              {variables.map((variable, index) => (
+                <div key={variable.name} className="flex items-center gap-2">
+                  <code className="w-[120px] shrink-0 truncate text-sm">{variable.name}</code>
+                  <Select
+                    value={variable.type}
+                    onValueChange={(type: VariableType) => updateVariable(index, { type })}
+                  >
+                    <SelectTrigger className="w-[120px] shrink-0">
+                      <SelectValue />
+                    </SelectTrigger>
+                    <SelectContent>
+                      {VARIABLE_TYPES.map((type) => (
+                        <SelectItem key={type.value} value={type.value}>
+                          {type.label}
+                        </SelectItem>
+                      ))}
+                    </SelectContent>
+                  </Select>
+                  <Input
+                    value={variable.defaultValue ?? ""}
+                    onChange={(e) => updateVariable(index, { defaultValue: e.target.value || null })}
+                    placeholder="Default"
+                    disabled={isSaving}
+                  />
+                </div>
+              ))}
+            </div>
+          )}
+
+          {error && <p className="text-sm text-destructive">{error}</p>}
+        </div>
+
+        <DialogFooter>
+          <Button variant="outline" onClick={() => onOpenChange(false)} disabled={isSaving}>
+            Cancel
+          </Button>
+          <Button onClick={handleSave} disabled={isSaving || !name.trim()}>
+            {isSaving ? (
+              <>
+                <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                Saving...
+              </>
+            ) : (
+              "Save"
+            )}
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -0,0 +1,135 @@
+import { useState, useEffect } from "react";
+import { Star, Plus, Trash2, Braces } from "lucide-react";
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuTrigger,
+  DropdownMenuSeparator,
+  DropdownMenuLabel,
+} from "@/components/ui/dropdown-menu";
+import { Button } from "@/components/ui/button";
+import { Badge } from "@/components/ui/badge";
+import { ScrollArea, Tooltip, TooltipContent, TooltipTrigger } from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showErrorToast } from "@/lib/toast-helpers";
+import type { SavedQuery } from "@/types";
+import { truncateSQL } from "@/components/query-history/query-history-utils";
+
+interface SavedQueriesDropdownProps {
+  connectionId: string;
+  canSave: boolean;
+  onSaveCurrent: () => void;
+  onRunQuery: (query: SavedQuery) => void;
+  activeTooltip: string | null;
+  onSetActiveTooltip: (tooltip: string | null) => void;
+}
+
+export function SavedQueriesDropdown({
+  connectionId,
+  canSave,
+  onSaveCurrent,
+  onRunQuery,
+  activeTooltip,
+  onSetActiveTooltip,
+}: SavedQueriesDropdownProps) {
+  const { listSavedQueries, deleteSavedQuery } = useDatabase();
+  const [queries, setQueries] = useState<SavedQuery[]>([]);
+  const [isDropdownOpen, setIsDropdownOpen] = useState(false);
+
+  useEffect(() => {
+    onSetActiveTooltip(null);
+    if (isDropdownOpen) {
+      listSavedQueries()
+        .then(setQueries)
+        .catch((error) => showErrorToast("Could not load saved queries", String(error)));
+    }
+  }, [isDropdownOpen, onSetActiveTooltip, listSavedQueries]);
+
+  const handleDelete = async (e: React.MouseEvent, query: SavedQuery) => {
+    e.preventDefault();
+    e.stopPropagation();
+    try {
+      await deleteSavedQuery(query.id);
+      setQueries((prev) => prev.filter((q) => q.id !== query.id));
+    } catch (error) {
+      showErrorToast("Could not delete the saved query", String(error));
+    }
+  };
+
+  return (
+    <DropdownMenu open={isDropdownOpen} onOpenChange={setIsDropdownOpen}>
+      <Tooltip open={activeTooltip === "saved" && !isDropdownOpen}>
+        <TooltipTrigger asChild>
+          <DropdownMenuTrigger asChild>
+            <Button
+              variant="ghost"
+              size="sm"
+              className="gap-2"
+              onMouseEnter={() => onSetActiveTooltip("saved")}
+              onMouseLeave={() => onSetActiveTooltip(null)}
+            >
+              <Star className="h-3.5 w-3.5" />
+              Saved
+            </Button>
+          </DropdownMenuTrigger>
+        </TooltipTrigger>
+        <TooltipContent>Saved queries</TooltipContent>
+      </Tooltip>
+
+      <DropdownMenuContent align="end" className="w-[420px]">
+        <DropdownMenuLabel>Saved Queries</DropdownMenuLabel>
+        <DropdownMenuItem disabled={!canSave} onSelect={onSaveCurrent} className="gap-2">
+          <Plus className="h-3.5 w-3.5" />
+          Save current query...
+        </DropdownMenuItem>
+        <DropdownMenuSeparator />
+
+        <ScrollArea className="max-h-[360px]">
+          <div className="p-1">
+            {queries.length === 0 ? (
+              <div className="flex flex-col items-center justify-center py-8 text-center">
+                <Star className="h-8 w-8 text-muted-foreground mb-2" />
+                <p className="text-sm text-muted-foreground">No saved queries yet</p>
+              </div>
+            ) : (
+              queries.map((query) => (
+                <DropdownMenuItem
+                  key={query.id}
+                  className="group flex flex-col items-start gap-1 p-2 cursor-pointer"
+                  onSelect={() => onRunQuery(query)}
+                >
+                  <div className="flex items-center gap-2 w-full">
+                    <span className="text-sm font-medium truncate flex-1 min-w-0">{query.name}</span>
+                    {query.variables.length > 0 && (
+                      <Badge variant="secondary" className="text-xs gap-1">
+                        <Braces className="h-3 w-3" />
+                        {query.variables.length}
+                      </Badge>
+                    )}
+                    {query.presets[connectionId] && (
+                      <Badge variant="outline" className="text-xs">
+                        Preset
+                      </Badge>
+                    )}
+                    <button
+                      type="button"
+                      className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-destructive"
+                      title="Delete saved query"
+                      onClick={(e) => handleDelete(e, query)}
+                    >
+                      <Trash2 className="h-3.5 w-3.5" />
+                    </button>
+                  </div>
+                  <code className="text-xs font-mono text-muted-foreground truncate w-full">
+                    {query.description || truncateSQL(query.sql, 60)}
+                  </code>
+                </DropdownMenuItem>
+              ))
+            )}
+          </div>
+        </ScrollArea>
+      </DropdownMenuContent>
+    </DropdownMenu>
+  );
+}
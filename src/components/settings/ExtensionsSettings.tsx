@@ -0,0 +1,489 @@
+import { useCallback, useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { Loader2, Package, Play, Power, RotateCcw, ScrollText, Settings2, Trash2 } from "lucide-react";
+import {
+  Button,
+  Checkbox,
+  Input,
+  Label,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+  Separator,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { applyBuiltinStatus, setBuiltinExtensionEnabled, useExtensionsStore } from "@/lib/extensions";
+import { useKeymapStore, useUIStore } from "@/stores";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-helpers";
+import type {
+  ExtensionLogEntry,
+  ExtensionLogLevel,
+  ExtensionSettings,
+  ExtensionStatusEvent,
+  InstalledExtension,
+  SettingContribution,
+} from "@/types";
+
+interface SettingsFormProps {
+  extension: InstalledExtension;
+}
+
+/**
+ * Form for the settings an extension contributes. Each change is saved on its
+ * own and checked against the setting's type by the backend; password fields
+ * are write-only.
+ */
+function ExtensionSettingsForm({ extension }: SettingsFormProps) {
+  const { getExtensionSettings, setExtensionSetting } = useDatabase();
+  const { id, contributes } = extension.manifest;
+  const [settings, setSettings] = useState<ExtensionSettings | null>(null);
+  const [passwords, setPasswords] = useState<Record<string, string>>({});
+
+  const refresh = useCallback(async () => {
+    try {
+      setSettings(await getExtensionSettings(id));
+    } catch (error) {
+      showErrorToast("Could not load settings", error instanceof Error ? error.message : String(error));
+    }
+  }, [getExtensionSettings, id]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const save = async (key: string, value: unknown) => {
+    try {
+      await setExtensionSetting(id, key, value);
+      await refresh();
+    } catch (error) {
+      showErrorToast("Invalid setting", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  if (!settings) return null;
+
+  const field = (setting: SettingContribution) => {
+    const value = settings.values[setting.key];
+    switch (setting.type) {
+      case "boolean":
+        return <Checkbox checked={value === true} onCheckedChange={(checked: boolean) => save(setting.key, checked)} />;
+      case "select":
+        return (
+          <Select value={typeof value === "string" ? value : undefined} onValueChange={(v) => save(setting.key, v)}>
+            <SelectTrigger className="w-[180px] h-8">
+              <SelectValue placeholder="Choose..." />
+            </SelectTrigger>
+            <SelectContent>
+              {setting.options.map((option) => (
+                <SelectItem key={option} value={option}>
+                  {option}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        );
+      case "password":
+        return (
+          <div className="flex gap-1">
+            <Input
+              type="password"
+              className="w-[180px] h-8"
+              placeholder={settings.secrets.includes(setting.key) ? "Saved" : "Not set"}
+              value={passwords[setting.key] ?? ""}
+              onChange={(e) => setPasswords({ ...passwords, [setting.key]: e.target.value })}
+              onBlur={() => {
+                const password = passwords[setting.key];
+                if (password) save(setting.key, password).then(() => setPasswords({ ...passwords, [setting.key]: "" }));
+              }}
+            />
+            {settings.secrets.includes(setting.key) && (
+              <Button variant="ghost" size="sm" className="h-8" onClick={() => save(setting.key, null)}>
+                Clear
+              </Button>
+            )}
+          </div>
+        );
+      default:
+        return (
+          <Input
+            key={`${setting.key}-${String(value ?? "")}`}
+            type={setting.type === "number" ? "number" : "text"}
+            className="w-[180px] h-8"
+            defaultValue={value === undefined || value === null ? "" : String(value)}
+            onBlur={(e) => {
+              const text = e.target.value;
+              if (text === String(value ?? "")) return;
+              if (!text) save(setting.key, null);
+              else save(setting.key, setting.type === "number" ? Number(text) : text);
+            }}
+          />
+        );
+    }
+  };
+
+  return (
+    <div className="ml-8 rounded-lg border border-border">
+      {contributes.settings.map((setting, index) => (
+        <div key={setting.key}>
+          {index > 0 && <Separator />}
+          <div className="flex items-center justify-between gap-4 px-3 py-2">
+            <div className="space-y-0.5">
+              <Label className="text-xs font-medium">{setting.title}</Label>
+              {setting.description && <p className="text-[11px] text-muted-foreground">{setting.description}</p>}
+            </div>
+            {field(setting)}
+          </div>
+        </div>
+      ))}
+    </div>
+  );
+}
+
+const LOG_LEVEL_CLASSES: Record<ExtensionLogLevel, string> = {
+  debug: "text-muted-foreground",
+  info: "text-foreground",
+  warn: "text-warning",
+  error: "text-destructive",
+};
+
+interface LogsPanelProps {
+  extensionId: string;
+}
+
+/**
+ * The end of an extension's log, to see what it did before it misbehaved
+ */
+function ExtensionLogsPanel({ extensionId }: LogsPanelProps) {
+  const { getExtensionLogs, clearExtensionLogs } = useDatabase();
+  const [entries, setEntries] = useState<ExtensionLogEntry[] | null>(null);
+
+  const refresh = useCallback(async () => {
+    try {
+      setEntries(await getExtensionLogs(extensionId));
+    } catch (error) {
+      showErrorToast("Could not load the log", error instanceof Error ? error.message : String(error));
+    }
+  }, [getExtensionLogs, extensionId]);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const handleClear = async () => {
+    try {
+      await clearExtensionLogs(extensionId);
+      await refresh();
+    } catch (error) {
+      showErrorToast("Could not clear the log", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  if (!entries) return null;
+
+  return (
+    <div className="ml-8 rounded-lg border border-border">
+      <div className="flex items-center justify-between px-3 py-1.5">
+        <span className="text-xs font-medium">Log</span>
+        <div className="flex gap-1">
+          <Button variant="ghost" size="sm" className="h-7 text-xs" onClick={refresh}>
+            Refresh
+          </Button>
+          <Button variant="ghost" size="sm" className="h-7 text-xs" onClick={handleClear} disabled={entries.length === 0}>
+            Clear
+          </Button>
+        </div>
+      </div>
+      <Separator />
+      <div className="max-h-60 overflow-auto px-3 py-2 font-mono text-[11px]">
+        {entries.length === 0 && <p className="text-muted-foreground">Nothing logged yet.</p>}
+        {entries.map((entry, index) => (
+          <div key={index} className={`whitespace-pre-wrap break-all ${LOG_LEVEL_CLASSES[entry.level]}`}>
+            <span className="text-muted-foreground">{new Date(entry.timestamp).toLocaleTimeString()}</span>{" "}
+            {entry.level.toUpperCase()} {entry.message}
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+}
+
+/**
+ * Installed extensions: install from a .dbx file, start and stop them, run the
+ * commands they register, restart the ones that failed and read their logs
+ */
+export function ExtensionsSettings() {
+  const { listInstalledExtensions, installExtension, uninstallExtension, restartExtension, executeExtensionCommand } =
+    useDatabase();
+  const { running, activate, deactivate } = useExtensionsStore();
+  const [installed, setInstalled] = useState<InstalledExtension[]>([]);
+  const [packagePath, setPackagePath] = useState("");
+  const [isInstalling, setIsInstalling] = useState(false);
+  const [settingsOpen, setSettingsOpen] = useState<string | null>(null);
+  const [logsOpen, setLogsOpen] = useState<string | null>(null);
+
+  /** Whether the active theme is one the extension contributes */
+  const providesActiveTheme = (extension: InstalledExtension) => {
+    const theme = useUIStore.getState().theme;
+    return extension.manifest.contributes.themes.some((t) => theme === `${t.id}-dark` || theme === `${t.id}-light`);
+  };
+
+  const refresh = useCallback(async () => {
+    setInstalled(await listInstalledExtensions());
+  }, [listInstalledExtensions]);
+
+  /** Pick up the shortcuts of added or removed commands */
+  const reloadKeymap = () =>
+    useKeymapStore.getState().loadKeymap().catch((error) => console.error("[Keymap] Failed to reload:", error));
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  // Extensions can fail or recover at any time, e.g. when a command times out
+  useEffect(() => {
+    const unlisten = listen<ExtensionStatusEvent>("extension-status", () => {
+      refresh();
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [refresh]);
+
+  const handleInstall = async () => {
+    if (!packagePath.trim()) return;
+    setIsInstalling(true);
+    try {
+      const extension = await installExtension(packagePath.trim());
+      // Restart a running copy so the new version is used
+      if (running[extension.manifest.id]) {
+        await deactivate(extension.manifest.id);
+        await activate(extension.manifest.id);
+      }
+      // Reload the active theme in case the update changed it
+      if (providesActiveTheme(extension)) {
+        const { theme, setTheme } = useUIStore.getState();
+        setTheme(theme);
+      }
+      setPackagePath("");
+      reloadKeymap();
+      showSuccessToast("Extension installed", `${extension.manifest.name} ${extension.manifest.version}`);
+      await refresh();
+    } catch (error) {
+      showErrorToast("Install failed", error instanceof Error ? error.message : String(error));
+    } finally {
+      setIsInstalling(false);
+    }
+  };
+
+  const handleUninstall = async (extension: InstalledExtension) => {
+    try {
+      await deactivate(extension.manifest.id);
+      await uninstallExtension(extension.manifest.id);
+      if (providesActiveTheme(extension)) {
+        useUIStore.getState().setTheme("system");
+      }
+      reloadKeymap();
+      await refresh();
+    } catch (error) {
+      showErrorToast("Uninstall failed", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  const handleToggleBuiltin = async (extension: InstalledExtension) => {
+    try {
+      await setBuiltinExtensionEnabled(extension.manifest.id, extension.status !== "active");
+      await refresh();
+    } catch (error) {
+      showErrorToast("Could not change the extension", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  const handleRestart = async (extension: InstalledExtension) => {
+    try {
+      const restarted = await restartExtension(extension.manifest.id);
+      if (restarted.bundled) await applyBuiltinStatus(restarted);
+    } catch (error) {
+      showErrorToast("Restart failed", error instanceof Error ? error.message : String(error));
+    } finally {
+      await refresh();
+    }
+  };
+
+  const handleRunCommand = async (title: string, commandId: string) => {
+    try {
+      const { result, durationMs } = await executeExtensionCommand(commandId);
+      const output = result === null || result === undefined ? "" : `: ${JSON.stringify(result).slice(0, 200)}`;
+      showSuccessToast(title, `Finished in ${durationMs} ms${output}`);
+    } catch (error) {
+      showErrorToast("Command failed", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  return (
+    <div className="space-y-6 animate-fade-in">
+      <div>
+        <h2 className="text-xl font-semibold mb-1">Extensions</h2>
+        <p className="text-sm text-muted-foreground">
+          Extensions run sandboxed, with no access to your connections or files.
+        </p>
+      </div>
+
+      <div className="rounded-xl border border-border bg-card p-4 space-y-2">
+        <Label className="text-sm font-medium">Install from file</Label>
+        <div className="flex gap-2">
+          <Input
+            value={packagePath}
+            onChange={(e) => setPackagePath(e.target.value)}
+            placeholder="/path/to/extension.dbx"
+            className="font-mono text-xs"
+          />
+          <Button size="sm" onClick={handleInstall} disabled={isInstalling || !packagePath.trim()}>
+            {isInstalling ? <Loader2 className="h-4 w-4 animate-spin" /> : "Install"}
+          </Button>
+        </div>
+      </div>
+
+      <div className="rounded-xl border border-border bg-card p-1">
+        {installed.length === 0 && (
+          <p className="p-4 text-sm text-muted-foreground">No extensions installed.</p>
+        )}
+        {installed.map((extension, index) => {
+          const { id, name, version, description, permissions } = extension.manifest;
+          const state = running[id];
+          const isRunning = state?.status === "running";
+          const isBusy = state?.status === "starting" || state?.status === "stopping";
+          const error = typeof extension.status === "object" ? extension.status.error : state?.error;
+          const hasFailed = typeof extension.status === "object" || state?.status === "failed";
+
+          return (
+            <div key={id}>
+              {index > 0 && <Separator />}
+              <div className="p-3 space-y-2">
+                <div className="flex items-start justify-between gap-3">
+                  <div className="flex items-start gap-3 min-w-0">
+                    <Package className="h-5 w-5 mt-0.5 text-muted-foreground shrink-0" />
+                    <div className="min-w-0">
+                      <div className="flex items-center gap-2">
+                        <span className="text-sm font-medium">{name}</span>
+                        <span className="text-xs text-muted-foreground">v{version}</span>
+                        {extension.isOfficial && (
+                          <span className="rounded bg-primary/10 px-1.5 py-0.5 text-[10px] font-medium text-primary">
+                            Official
+                          </span>
+                        )}
+                      </div>
+                      {description && <p className="text-xs text-muted-foreground">{description}</p>}
+                      {extension.manifest.contributes.themes.length > 0 && (
+                        <p className="text-[11px] text-muted-foreground">
+                          Themes: {extension.manifest.contributes.themes.map((t) => t.name).join(", ")}
+                        </p>
+                      )}
+                      {permissions.length > 0 && (
+                        <p className="text-[11px] text-muted-foreground">Permissions: {permissions.join(", ")}</p>
+                      )}
+                      {hasFailed && error && <p className="text-xs text-destructive">{error}</p>}
+                    </div>
+                  </div>
+                  <div className="flex items-center gap-1 shrink-0">
+                    <Button
+                      variant="ghost"
+                      size="icon"
+                      className="h-8 w-8"
+                      title="Log"
+                      onClick={() => setLogsOpen(logsOpen === id ? null : id)}
+                    >
+                      <ScrollText className="h-4 w-4" />
+                    </Button>
+                    {extension.manifest.contributes.settings.length > 0 && (
+                      <Button
+                        variant="ghost"
+                        size="icon"
+                        className="h-8 w-8"
+                        title="Settings"
+                        onClick={() => setSettingsOpen(settingsOpen === id ? null : id)}
+                      >
+                        <Settings2 className="h-4 w-4" />
+                      </Button>
+                    )}
+                    {hasFailed && (
+                      <Button
+                        variant="outline"
+                        size="sm"
+                        disabled={isBusy}
+                        onClick={() => handleRestart(extension)}
+                      >
+                        <RotateCcw className="h-4 w-4 mr-1" />
+                        Restart
+                      </Button>
+                    )}
+                    {extension.bundled && (
+                      <Button
+                        variant={extension.status === "active" ? "secondary" : "outline"}
+                        size="sm"
+                        onClick={() => handleToggleBuiltin(extension)}
+                      >
+                        <Power className="h-4 w-4 mr-1" />
+                        {extension.status === "active" ? "Disable" : "Enable"}
+                      </Button>
+                    )}
+                    {/* Theme-only extensions have no code to run; pick their themes under Appearance */}
+                    {extension.manifest.main && (
+                      <Button
+                        variant={isRunning ? "secondary" : "outline"}
+                        size="sm"
+                        disabled={isBusy}
+                        onClick={() => (state ? deactivate(id) : activate(id))}
+                      >
+                        {isBusy ? (
+                          <Loader2 className="h-4 w-4 animate-spin" />
+                        ) : (
+                          <>
+                            <Power className="h-4 w-4 mr-1" />
+                            {state ? "Disable" : "Enable"}
+                          </>
+                        )}
+                      </Button>
+                    )}
+                    {!extension.bundled && (
+                      <Button
+                        variant="ghost"
+                        size="icon"
+                        className="h-8 w-8"
+                        title="Uninstall"
+                        onClick={() => handleUninstall(extension)}
+                      >
+                        <Trash2 className="h-4 w-4" />
+                      </Button>
+                    )}
+                  </div>
+                </div>
+                {settingsOpen === id && <ExtensionSettingsForm extension={extension} />}
+                {logsOpen === id && <ExtensionLogsPanel extensionId={id} />}
+                {extension.manifest.contributes.commands.length > 0 && (
+                  <div className="flex flex-wrap gap-1 pl-8">
+                    {extension.manifest.contributes.commands.map((command) => (
+                      <Button
+                        key={command.id}
+                        variant="outline"
+                        size="sm"
+                        className="h-7 text-xs"
+                        title={command.description || undefined}
+                        disabled={isBusy}
+                        onClick={() => handleRunCommand(command.title, command.id)}
+                      >
+                        <Play className="h-3 w-3 mr-1" />
+                        {command.title}
+                      </Button>
+                    ))}
+                  </div>
+                )}
+              </div>
+            </div>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
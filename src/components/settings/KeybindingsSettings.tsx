@@ -0,0 +1,179 @@
+import { useEffect, useState } from "react";
+import { RotateCcw, X } from "lucide-react";
+import { Button } from "@/components/ui";
+import { useKeymapStore } from "@/stores";
+import { shortcutFromEvent, shortcutKeys } from "@/lib/keymap";
+import { showErrorToast } from "@/lib/toast-helpers";
+import type { KeyBinding } from "@/types";
+
+interface ShortcutKeysProps {
+  keys: string[];
+}
+
+function ShortcutKeys({ keys }: ShortcutKeysProps) {
+  return (
+    <div className="flex items-center gap-1">
+      {keys.map((key, idx) => (
+        <span key={idx}>
+          <kbd className="px-2 py-1 bg-muted rounded-md border border-border text-[11px] font-mono font-medium shadow-sm">
+            {key}
+          </kbd>
+          {idx < keys.length - 1 && <span className="text-muted-foreground mx-0.5">+</span>}
+        </span>
+      ))}
+    </div>
+  );
+}
+
+interface BindingRowProps {
+  binding: KeyBinding;
+  recording: boolean;
+  onRecord: (recording: boolean) => void;
+}
+
+/**
+ * One command and its shortcut. While recording, the next key combination
+ * pressed becomes the shortcut; Escape cancels.
+ */
+function BindingRow({ binding, recording, onRecord }: BindingRowProps) {
+  const { setKeybinding, resetKeybinding } = useKeymapStore();
+
+  const change = async (action: () => Promise<void>) => {
+    try {
+      await action();
+    } catch (error) {
+      showErrorToast("Could not change the shortcut", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent) => {
+    e.preventDefault();
+    // Keep the app's own shortcuts from firing while recording
+    e.nativeEvent.stopPropagation();
+    if (e.key === "Escape" && !e.metaKey && !e.ctrlKey && !e.altKey && !e.shiftKey) {
+      onRecord(false);
+      return;
+    }
+    const shortcut = shortcutFromEvent(e.nativeEvent);
+    if (!shortcut) return;
+    onRecord(false);
+    change(() => setKeybinding(binding.commandId, shortcut));
+  };
+
+  return (
+    <div className="flex items-center justify-between gap-4 py-3">
+      <div className="min-w-0 space-y-0.5">
+        <div className="flex items-center gap-2">
+          <span className="text-sm">{binding.title}</span>
+          {binding.overridden && (
+            <span className="rounded bg-primary/10 px-1.5 py-0.5 text-[10px] font-medium text-primary">Changed</span>
+          )}
+        </div>
+        {binding.conflict && (
+          <p className="text-[11px] text-destructive">
+            {shortcutKeys(binding.conflict.shortcut).join("+")} is used by {binding.conflict.title}
+          </p>
+        )}
+      </div>
+      <div className="flex items-center gap-1 shrink-0">
+        {recording ? (
+          <button
+            autoFocus
+            className="rounded-md border border-primary px-2 py-1 text-[11px] text-muted-foreground"
+            onKeyDown={handleKeyDown}
+            onBlur={() => onRecord(false)}
+          >
+            Press a key combination...
+          </button>
+        ) : binding.shortcut ? (
+          <ShortcutKeys keys={shortcutKeys(binding.shortcut)} />
+        ) : (
+          <span className="text-[11px] text-muted-foreground">Not bound</span>
+        )}
+        {!binding.fixed && !recording && (
+          <>
+            <Button variant="ghost" size="sm" className="h-7 text-xs" onClick={() => onRecord(true)}>
+              Change
+            </Button>
+            {binding.shortcut && (
+              <Button
+                variant="ghost"
+                size="icon"
+                className="h-7 w-7"
+                title="Remove shortcut"
+                onClick={() => change(() => setKeybinding(binding.commandId, null))}
+              >
+                <X className="h-3.5 w-3.5" />
+              </Button>
+            )}
+            {binding.overridden && (
+              <Button
+                variant="ghost"
+                size="icon"
+                className="h-7 w-7"
+                title="Reset to default"
+                onClick={() => change(() => resetKeybinding(binding.commandId))}
+              >
+                <RotateCcw className="h-3.5 w-3.5" />
+              </Button>
+            )}
+          </>
+        )}
+      </div>
+    </div>
+  );
+}
+
+/**
+ * The keymap: the app's shortcuts and those of extension commands, with the
+ * user's changes and the shortcuts lost to conflicts
+ */
+export function KeybindingsSettings() {
+  const { bindings, loadKeymap } = useKeymapStore();
+  const [recording, setRecording] = useState<string | null>(null);
+
+  // Reloaded on open to pick up installed and removed extensions
+  useEffect(() => {
+    loadKeymap().catch((error) =>
+      showErrorToast("Could not load shortcuts", error instanceof Error ? error.message : String(error))
+    );
+  }, [loadKeymap]);
+
+  const sections = [
+    { title: "General", bindings: bindings.filter((b) => b.source === "builtin") },
+    { title: "Extensions", bindings: bindings.filter((b) => b.source === "extension") },
+  ];
+
+  return (
+    <div className="space-y-6 animate-fade-in">
+      <div>
+        <h2 className="text-xl font-semibold mb-1">Keyboard Shortcuts</h2>
+        <p className="text-sm text-muted-foreground">Master dbfordevs with these handy keys.</p>
+      </div>
+
+      {sections
+        .filter((section) => section.bindings.length > 0)
+        .map((section) => (
+          <div key={section.title} className="space-y-2">
+            <h3 className="text-sm font-medium text-muted-foreground">{section.title}</h3>
+            <div className="rounded-xl border border-border bg-card px-4 divide-y divide-border">
+              {section.bindings.map((binding) => (
+                <BindingRow
+                  key={binding.commandId}
+                  binding={binding}
+                  recording={recording === binding.commandId}
+                  onRecord={(on) => setRecording(on ? binding.commandId : null)}
+                />
+              ))}
+              {section.title === "General" && (
+                <div className="flex items-center justify-between py-3">
+                  <span className="text-sm">Search settings</span>
+                  <ShortcutKeys keys={["Cmd", "F"]} />
+                </div>
+              )}
+            </div>
+          </div>
+        ))}
+    </div>
+  );
+}
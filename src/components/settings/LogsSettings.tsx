@@ -0,0 +1,151 @@
+import { useEffect, useState } from "react";
+import { Copy, X } from "lucide-react";
+import {
+  Button,
+  Input,
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+  Separator,
+} from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showErrorToast, showSuccessToast } from "@/lib/toast-helpers";
+import type { AppLogEntry, LogSettings } from "@/types";
+import { SettingRow } from "./SettingRow";
+
+const LEVELS = ["trace", "debug", "info", "warn", "error", "off"];
+
+/** Events copied for a bug report */
+const COPIED_EVENTS = 1000;
+
+function formatEntry(entry: AppLogEntry): string {
+  const fields = Object.entries(entry.fields)
+    .map(([key, value]) => ` ${key}=${value}`)
+    .join("");
+  return `${entry.timestamp} ${entry.level.toUpperCase()} ${entry.target}: ${entry.message}${fields}`;
+}
+
+interface LevelSelectProps {
+  value: string;
+  onChange: (level: string) => void;
+}
+
+function LevelSelect({ value, onChange }: LevelSelectProps) {
+  return (
+    <Select value={value} onValueChange={onChange}>
+      <SelectTrigger className="w-28 h-8">
+        <SelectValue />
+      </SelectTrigger>
+      <SelectContent>
+        {LEVELS.map((level) => (
+          <SelectItem key={level} value={level}>
+            {level}
+          </SelectItem>
+        ))}
+      </SelectContent>
+    </Select>
+  );
+}
+
+/**
+ * The application log: how much is logged, for the app and per module, and
+ * copying the recent events to attach to a bug report
+ */
+export function LogsSettings() {
+  const { getAppLogs, getLogSettings, setLogLevel } = useDatabase();
+  const [settings, setSettings] = useState<LogSettings | null>(null);
+  const [directory, setDirectory] = useState("");
+  const [module, setModule] = useState("");
+
+  useEffect(() => {
+    Promise.all([getLogSettings(), getAppLogs(0)])
+      .then(([settings, logs]) => {
+        setSettings(settings);
+        setDirectory(logs.directory);
+      })
+      .catch((error) =>
+        showErrorToast("Could not load the log settings", error instanceof Error ? error.message : String(error))
+      );
+  }, [getLogSettings, getAppLogs]);
+
+  const changeLevel = async (level: string | null, module?: string) => {
+    try {
+      setSettings(await setLogLevel(level, module));
+    } catch (error) {
+      showErrorToast("Could not change the log level", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  const handleAddModule = async () => {
+    const name = module.trim();
+    if (!name) return;
+    await changeLevel("debug", name);
+    setModule("");
+  };
+
+  const handleCopy = async () => {
+    try {
+      const logs = await getAppLogs(COPIED_EVENTS);
+      await navigator.clipboard.writeText(logs.entries.map(formatEntry).join("\n"));
+      showSuccessToast("Logs copied", `${logs.entries.length} recent events are on the clipboard.`);
+    } catch (error) {
+      showErrorToast("Could not copy the logs", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  if (!settings) return null;
+
+  return (
+    <div className="space-y-2">
+      <h3 className="text-sm font-medium text-muted-foreground">Logs</h3>
+      <div className="rounded-xl border border-border bg-card px-4">
+        <SettingRow label="Log level" description="How much the app writes to its log. Use debug when reporting a bug.">
+          <LevelSelect value={settings.level} onChange={(level) => changeLevel(level)} />
+        </SettingRow>
+        <Separator />
+        <div className="space-y-2 py-4">
+          <p className="text-sm font-medium">Module levels</p>
+          <p className="text-xs text-muted-foreground">Log one part of the app in more or less detail, e.g. db or extension.</p>
+          {Object.entries(settings.modules).map(([name, level]) => (
+            <div key={name} className="flex items-center justify-between gap-2">
+              <span className="font-mono text-xs">{name}</span>
+              <div className="flex items-center gap-1">
+                <LevelSelect value={level} onChange={(level) => changeLevel(level, name)} />
+                <Button
+                  variant="ghost"
+                  size="icon"
+                  className="h-8 w-8"
+                  title="Use the app's level"
+                  onClick={() => changeLevel(null, name)}
+                >
+                  <X className="h-3.5 w-3.5" />
+                </Button>
+              </div>
+            </div>
+          ))}
+          <div className="flex items-center gap-2">
+            <Input
+              value={module}
+              onChange={(e) => setModule(e.target.value)}
+              onKeyDown={(e) => e.key === "Enter" && handleAddModule()}
+              placeholder="db::manager"
+              className="h-8 font-mono text-xs"
+            />
+            <Button variant="outline" size="sm" onClick={handleAddModule} disabled={!module.trim()}>
+              Add
+            </Button>
+          </div>
+        </div>
+        <Separator />
+        <SettingRow label="Recent logs" description={`Saved daily in ${directory}`}>
+          <Button variant="outline" size="sm" onClick={handleCopy}>
+            <Copy className="h-4 w-4 mr-2" />
+            Copy
+          </Button>
+        </SettingRow>
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,173 @@
+import { useCallback, useEffect, useState } from "react";
+import { RotateCcw } from "lucide-react";
+import { Button } from "@/components/ui";
+import { useDatabase } from "@/hooks";
+import { showErrorToast } from "@/lib/toast-helpers";
+import type { LatencyHistogram, PerformanceMetrics } from "@/types";
+
+const REFRESH_INTERVAL_MS = 5000;
+
+function formatMs(ms: number | null): string {
+  if (ms === null) return "-";
+  return ms >= 1000 ? `${(ms / 1000).toFixed(1)} s` : `${ms} ms`;
+}
+
+interface StatProps {
+  label: string;
+  value: string;
+}
+
+function Stat({ label, value }: StatProps) {
+  return (
+    <div className="space-y-0.5">
+      <p className="text-[11px] text-muted-foreground">{label}</p>
+      <p className="text-sm font-medium tabular-nums">{value}</p>
+    </div>
+  );
+}
+
+interface LatencyProps {
+  histogram: LatencyHistogram;
+}
+
+/** Percentiles of a histogram and a bar per bucket */
+function Latency({ histogram }: LatencyProps) {
+  if (histogram.count === 0) {
+    return <p className="text-xs text-muted-foreground">Nothing measured yet.</p>;
+  }
+  const largest = Math.max(...histogram.buckets.map((bucket) => bucket.count));
+  return (
+    <div className="space-y-3">
+      <div className="grid grid-cols-5 gap-2">
+        <Stat label="Average" value={formatMs(Math.round(histogram.totalMs / histogram.count))} />
+        <Stat label="p50" value={formatMs(histogram.p50Ms)} />
+        <Stat label="p95" value={formatMs(histogram.p95Ms)} />
+        <Stat label="p99" value={formatMs(histogram.p99Ms)} />
+        <Stat label="Slowest" value={formatMs(histogram.maxMs)} />
+      </div>
+      <div className="flex h-16 items-end gap-1">
+        {histogram.buckets.map((bucket, index) => (
+          <div
+            key={index}
+            className="flex-1 rounded-sm bg-primary/60"
+            style={{ height: `${Math.max((bucket.count / largest) * 100, bucket.count > 0 ? 4 : 1)}%` }}
+            title={`${bucket.leMs === null ? "slower" : `≤ ${formatMs(bucket.leMs)}`}: ${bucket.count}`}
+          />
+        ))}
+      </div>
+    </div>
+  );
+}
+
+interface SectionProps {
+  title: string;
+  children: React.ReactNode;
+}
+
+function Section({ title, children }: SectionProps) {
+  return (
+    <div className="space-y-2">
+      <h3 className="text-sm font-medium text-muted-foreground">{title}</h3>
+      <div className="rounded-xl border border-border bg-card p-4">{children}</div>
+    </div>
+  );
+}
+
+/**
+ * Query latency, connections, schema cache hits and AI request durations of
+ * this session. Measured on this machine only; nothing is sent anywhere.
+ */
+export function PerformanceSettings() {
+  const { getPerformanceMetrics, resetPerformanceMetrics } = useDatabase();
+  const [metrics, setMetrics] = useState<PerformanceMetrics | null>(null);
+
+  const refresh = useCallback(async () => {
+    try {
+      setMetrics(await getPerformanceMetrics());
+    } catch (error) {
+      showErrorToast("Could not load the metrics", error instanceof Error ? error.message : String(error));
+    }
+  }, [getPerformanceMetrics]);
+
+  useEffect(() => {
+    refresh();
+    const timer = setInterval(refresh, REFRESH_INTERVAL_MS);
+    return () => clearInterval(timer);
+  }, [refresh]);
+
+  const handleReset = async () => {
+    try {
+      await resetPerformanceMetrics();
+      await refresh();
+    } catch (error) {
+      showErrorToast("Could not reset the metrics", error instanceof Error ? error.message : String(error));
+    }
+  };
+
+  return (
+    <div className="space-y-6 animate-fade-in">
+      <div className="flex items-start justify-between gap-4">
+        <div>
+          <h2 className="text-xl font-semibold mb-1">Performance</h2>
+          <p className="text-sm text-muted-foreground">
+            Measured on this machine since {metrics ? new Date(metrics.since).toLocaleTimeString() : "..."}; nothing
+            is sent anywhere.
+          </p>
+        </div>
+        <Button variant="outline" size="sm" onClick={handleReset}>
+          <RotateCcw className="h-4 w-4 mr-2" />
+          Reset
+        </Button>
+      </div>
+
+      {metrics && (
+        <>
+          <Section title="Queries">
+            <div className="space-y-3">
+              <div className="grid grid-cols-5 gap-2">
+                <Stat label="Run" value={metrics.queries.count.toLocaleString()} />
+                <Stat label="Failed" value={metrics.failedQueries.toLocaleString()} />
+              </div>
+              <Latency histogram={metrics.queries} />
+            </div>
+          </Section>
+
+          <div className="grid grid-cols-2 gap-4">
+            <Section title="Connections">
+              <div className="grid grid-cols-3 gap-2">
+                <Stat label="Open" value={metrics.connections.active.toLocaleString()} />
+                <Stat label="Opened" value={metrics.connections.opened.toLocaleString()} />
+                <Stat label="Failed" value={metrics.connections.failed.toLocaleString()} />
+              </div>
+            </Section>
+            <Section title="Schema cache">
+              <div className="grid grid-cols-3 gap-2">
+                <Stat
+                  label="Hit rate"
+                  value={metrics.schemaCache.hitRate === null ? "-" : `${Math.round(metrics.schemaCache.hitRate * 100)}%`}
+                />
+                <Stat label="Hits" value={metrics.schemaCache.hits.toLocaleString()} />
+                <Stat label="Misses" value={metrics.schemaCache.misses.toLocaleString()} />
+              </div>
+            </Section>
+          </div>
+
+          <Section title="AI requests">
+            <div className="space-y-3">
+              <Latency histogram={metrics.aiRequests} />
+              {Object.entries(metrics.aiOperations).map(([operation, histogram]) => (
+                <div key={operation} className="flex items-center justify-between text-xs">
+                  <span className="font-mono">{operation}</span>
+                  <span className="text-muted-foreground tabular-nums">
+                    {histogram.count} × avg {formatMs(Math.round(histogram.totalMs / histogram.count))}, p95{" "}
+                    {formatMs(histogram.p95Ms)}
+                  </span>
+                </div>
+              ))}
+            </div>
+          </Section>
+        </>
+      )}
+    </div>
+  );
+}
@@ -13,8 +13,10 @@ import {
   Input,
   Checkbox,
 } from "@/components/ui";
-import { useUIStore } from "@/stores";
+import { useUIStore, type Theme } from "@/stores";
 import { useToast } from "@/hooks/useToast";
+import { useDatabase } from "@/hooks";
+import type { ThemeInfo, ThemeVariant } from "@/types";
 import { open } from "@tauri-apps/plugin-shell";
 import { getVersion } from "@tauri-apps/api/app";
 import { useEffect, useState, useMemo } from "react";
@@ -36,9 +38,16 @@ import {
   X,
   Sparkles,
   Bot,
+  Package,
+  Gauge,
 } from "lucide-react";
 import { cn } from "@/lib/utils";
 import { useAIStore } from "@/lib/ai/store";
+import { ExtensionsSettings } from "./ExtensionsSettings";
+import { KeybindingsSettings } from "./KeybindingsSettings";
+import { LogsSettings } from "./LogsSettings";
+import { PerformanceSettings } from "./PerformanceSettings";
+import { setBuiltinExtensionEnabled } from "@/lib/extensions";
 
 interface SettingRowProps {
   label: string;
@@ -58,30 +67,16 @@ function SettingRow({ label, description, children }: SettingRowProps) {
   );
 }
 
-interface ShortcutItemProps {
-  label: string;
-  keys: string[];
-}
-
-function ShortcutItem({ label, keys }: ShortcutItemProps) {
-  return (
-    <div className="flex items-center justify-between py-3 group">
-      <span className="text-sm">{label}</span>
-      <div className="flex items-center gap-1">
-        {keys.map((key, idx) => (
-          <span key={idx}>
-            <kbd className="px-2 py-1 bg-muted rounded-md border border-border text-[11px] font-mono font-medium shadow-sm">
-              {key}
-            </kbd>
-            {idx < keys.length - 1 && <span className="text-muted-foreground mx-0.5">+</span>}
-          </span>
-        ))}
-      </div>
-    </div>
-  );
-}
-
-type TabValue = "general" | "ai" | "editor" | "appearance" | "keybindings" | "advanced" | "about";
+type TabValue =
+  | "general"
+  | "ai"
+  | "editor"
+  | "appearance"
+  | "keybindings"
+  | "extensions"
+  | "performance"
+  | "advanced"
+  | "about";
 
 interface TabConfig {
   value: TabValue;
@@ -95,6 +90,8 @@ const TABS: TabConfig[] = [
   { value: "editor", label: "Editor", icon: <Code className="h-4 w-4" /> },
   { value: "appearance", label: "Appearance", icon: <Sun className="h-4 w-4" /> },
   { value: "keybindings", label: "Keybindings", icon: <Keyboard className="h-4 w-4" /> },
+  { value: "extensions", label: "Extensions", icon: <Package className="h-4 w-4" /> },
+  { value: "performance", label: "Performance", icon: <Gauge className="h-4 w-4" /> },
   { value: "advanced", label: "Advanced", icon: <Settings className="h-4 w-4" /> },
   { value: "about", label: "About", icon: <Info className="h-4 w-4" /> },
 ];
@@ -123,13 +120,21 @@ const ALL_SETTINGS: SettingItem[] = [
   { label: "Enable Animations", description: "Enable smooth animations throughout the interface.", keywords: ["animation", "animations", "smooth", "motion", "transition", "effects"], tabValue: "appearance" },
   // Keybindings
   { label: "Keyboard Shortcuts", description: "Master dbfordevs with these handy keys.", keywords: ["keyboard", "shortcut", "key", "binding", "find", "replace", "search", "shortcuts"], tabValue: "keybindings" },
+  { label: "Extensions", description: "Install, enable and run extensions.", keywords: ["extension", "plugin", "install", "dbx", "marketplace"], tabValue: "extensions" },
+  // Performance
+  { label: "Performance", description: "Query latency, connections, cache hits and AI request times, measured locally.", keywords: ["performance", "metrics", "latency", "slow", "cache", "speed"], tabValue: "performance" },
   // Advanced
   { label: "Developer Mode", description: "Enable additional debugging information and console logging.", keywords: ["developer", "debug", "mode", "console"], tabValue: "advanced" },
-  { label: "Debug Logging", description: "Log detailed information to help troubleshoot issues.", keywords: ["debug", "log", "logging", "troubleshoot"], tabValue: "advanced" },
+  { label: "Log level", description: "How much the app writes to its log. Use debug when reporting a bug.", keywords: ["debug", "log", "logging", "troubleshoot", "level", "bug", "report"], tabValue: "advanced" },
   { label: "Reset all settings", description: "Restore all settings to their default values.", keywords: ["reset", "default", "restore"], tabValue: "advanced" },
   { label: "Clear cache", description: "Clear cached data and temporary files.", keywords: ["cache", "clear", "temporary", "files"], tabValue: "advanced" },
 ];
 
+/** e.g. "Nordic Dark" */
+function themeVariantLabel(theme: ThemeInfo, variant: ThemeVariant): string {
+  return `${theme.name} ${variant === "dark" ? "Dark" : "Light"}`;
+}
+
 export function SettingsDialog() {
   const {
     showSettingsDialog,
@@ -145,6 +150,8 @@ export function SettingsDialog() {
     settingsDialogTab,
   } = useUIStore();
   const { toast } = useToast();
+  const { listThemes } = useDatabase();
+  const [themes, setThemes] = useState<ThemeInfo[]>([]);
   const [version, setVersion] = useState<string>("");
   const [activeTab, setActiveTab] = useState<TabValue>(settingsDialogTab);
   const [searchQuery, setSearchQuery] = useState("");
@@ -158,6 +165,11 @@ export function SettingsDialog() {
     setActiveTab(settingsDialogTab);
   }, [settingsDialogTab]);
 
+  // Bundled and extension themes; reloaded on open to pick up new installs
+  useEffect(() => {
+    if (showSettingsDialog) listThemes().then(setThemes);
+  }, [showSettingsDialog, listThemes]);
+
   // Keyboard shortcut for search
   useEffect(() => {
     const handleKeyDown = (e: KeyboardEvent) => {
@@ -177,18 +189,19 @@ export function SettingsDialog() {
   }, [showSettingsDialog, searchOpen]);
 
   const handleThemeChange = (newTheme: string) => {
-    setTheme(newTheme as "light" | "dark" | "system" | "nordic-dark" | "nordic-light");
+    setTheme(newTheme as Theme);
 
-    // Get label from built-in themes
+    // Get label from built-in themes, then from bundled and extension themes
     const builtInLabels: Record<string, string> = {
       system: "System",
       light: "Light",
       dark: "Dark",
-      "nordic-dark": "Nordic Dark",
-      "nordic-light": "Nordic Light",
     };
+    const variant = newTheme.endsWith("-dark") ? "dark" : "light";
+    const match = themes.find((t) => `${t.id}-${variant}` === newTheme && t.variants.includes(variant));
+    const themeLabel = match && themeVariantLabel(match, variant);
 
-    const label = builtInLabels[newTheme] || newTheme;
+    const label = builtInLabels[newTheme] || themeLabel || newTheme;
 
     toast({
       title: "Theme updated",
@@ -375,7 +388,7 @@ export function SettingsDialog() {
 
                   {/* AI Assistant Tab */}
                   {activeTab === "ai" && (() => {
-                    const { settings, updateSettings } = useAIStore.getState();
+                    const { settings } = useAIStore.getState();
 
                     return (
                       <div className="space-y-6 animate-fade-in">
@@ -394,7 +407,10 @@ export function SettingsDialog() {
                             <Checkbox
                               checked={settings.aiEnabled ?? true}
                               onCheckedChange={(checked: boolean) => {
-                                updateSettings({ aiEnabled: checked });
+                                // The assistant is a bundled extension; this also updates the AI settings
+                                setBuiltinExtensionEnabled("ai-assistant", checked).catch((error) =>
+                                  console.error("Failed to change the AI assistant:", error)
+                                );
                                 toast({
                                   title: checked ? "AI Assistant enabled" : "AI Assistant disabled",
                                   description: checked
@@ -648,19 +664,21 @@ export function SettingsDialog() {
                                   <span>Dark</span>
                                 </div>
                               </SelectItem>
-                              {/* Nordic Themes */}
-                              <SelectItem value="nordic-dark">
-                                <div className="flex items-center gap-2">
-                                  <Moon className="h-4 w-4 text-primary" />
-                                  <span>Nordic Dark</span>
-                                </div>
-                              </SelectItem>
-                              <SelectItem value="nordic-light">
-                                <div className="flex items-center gap-2">
-                                  <Sun className="h-4 w-4 text-primary" />
-                                  <span>Nordic Light</span>
-                                </div>
-                              </SelectItem>
+                              {/* Bundled and extension themes */}
+                              {themes.flatMap((t) =>
+                                t.variants.map((variant) => (
+                                  <SelectItem key={`${t.id}-${variant}`} value={`${t.id}-${variant}`}>
+                                    <div className="flex items-center gap-2">
+                                      {variant === "dark" ? (
+                                        <Moon className="h-4 w-4 text-primary" />
+                                      ) : (
+                                        <Sun className="h-4 w-4 text-primary" />
+                                      )}
+                                      <span>{themeVariantLabel(t, variant)}</span>
+                                    </div>
+                                  </SelectItem>
+                                ))
+                              )}
                             </SelectContent>
                           </Select>
                         </SettingRow>
@@ -706,30 +724,12 @@ export function SettingsDialog() {
                   )}
 
                   {/* Keybindings Tab */}
-                  {activeTab === "keybindings" && (
-                    <div className="space-y-6 animate-fade-in">
-                      <div>
-                        <h2 className="text-xl font-semibold mb-1">Keyboard Shortcuts</h2>
-                        <p className="text-sm text-muted-foreground">Master dbfordevs with these handy keys.</p>
-                      </div>
+                  {activeTab === "keybindings" && <KeybindingsSettings />}
 
-                      <div className="rounded-xl border border-border bg-card p-4 divide-y divide-border">
-                        <ShortcutItem label="Execute query" keys={["Cmd", "Enter"]} />
-                        <ShortcutItem label="New connection" keys={["Cmd", "K"]} />
-                        <ShortcutItem label="Open settings" keys={["Cmd", ","]} />
-                        <ShortcutItem label="Toggle sidebar" keys={["Cmd", "B"]} />
-                        <ShortcutItem label="Toggle AI Assistant" keys={["Cmd", "Shift", "A"]} />
-                        <ShortcutItem label="New query tab" keys={["Cmd", "T"]} />
-                        <ShortcutItem label="Close tab" keys={["Cmd", "W"]} />
-                        <ShortcutItem label="View changes diff" keys={["Cmd", "Shift", "D"]} />
-                        <ShortcutItem label="Toggle fullscreen" keys={["F11"]} />
-                        <ShortcutItem label="Find" keys={["Cmd", "F"]} />
-                        <ShortcutItem label="Find and Replace" keys={["Cmd", "Option", "F"]} />
-                        <ShortcutItem label="Search settings" keys={["Cmd", "F"]} />
-                        <ShortcutItem label="Close dialogs" keys={["Esc"]} />
-                      </div>
-                    </div>
-                  )}
+                  {/* Extensions Tab */}
+                  {activeTab === "extensions" && <ExtensionsSettings />}
+
+                  {activeTab === "performance" && <PerformanceSettings />}
 
                   {/* Advanced Tab */}
                   {activeTab === "advanced" && (
@@ -747,13 +747,6 @@ export function SettingsDialog() {
                           <Checkbox defaultChecked={false} />
                         </SettingRow>
                         <Separator />
-                        <SettingRow
-                          label="Debug Logging"
-                          description="Log detailed information to help troubleshoot issues."
-                        >
-                          <Checkbox defaultChecked={false} />
-                        </SettingRow>
-                        <Separator />
                         <SettingRow
                           label="Reset all settings"
                           description="Restore all settings to their default values."
@@ -791,6 +784,8 @@ export function SettingsDialog() {
                           </Button>
                         </SettingRow>
                       </div>
+
+                      <LogsSettings />
                     </div>
                   )}
 
@@ -1,16 +1,29 @@
 import { useState, useEffect } from "react";
-import { Search, X } from "lucide-react";
+import { Loader2, Search, Sparkles, X } from "lucide-react";
 import { Input } from "@/components/ui/input";
 import { Button } from "@/components/ui/button";
+import { cn } from "@/lib/utils";
 
 interface TableSearchProps {
   value: string;
   onChange: (value: string) => void;
   onClear: () => void;
   matchCount?: number;
+  /** Search by meaning, matching column names and comments, instead of by table name */
+  semantic?: boolean;
+  onToggleSemantic?: () => void;
+  searching?: boolean;
 }
 
-export function TableSearch({ value, onChange, onClear, matchCount }: TableSearchProps) {
+export function TableSearch({
+  value,
+  onChange,
+  onClear,
+  matchCount,
+  semantic,
+  onToggleSemantic,
+  searching,
+}: TableSearchProps) {
   const [localValue, setLocalValue] = useState(value);
 
   // Sync local value with prop value
@@ -56,7 +69,7 @@ export function TableSearch({ value, onChange, onClear, matchCount }: TableSearc
         <Search className="absolute left-2 top-1/2 transform -translate-y-1/2 h-3.5 w-3.5 text-muted-foreground" />
         <Input
           id="table-search-input"
-          placeholder="Search tables (press /)"
+          placeholder={semantic ? "Describe the data you need" : "Search tables (press /)"}
           value={localValue}
           onChange={(e) => setLocalValue(e.target.value)}
           onKeyDown={(e) => {
@@ -68,6 +81,7 @@ export function TableSearch({ value, onChange, onClear, matchCount }: TableSearc
           className="pl-8 pr-20 h-8 text-xs"
         />
         <div className="absolute right-1 top-1/2 transform -translate-y-1/2 flex items-center gap-1">
+          {searching && <Loader2 className="h-3 w-3 animate-spin text-muted-foreground" />}
           {matchCount !== undefined && localValue && (
             <span className="text-xs text-muted-foreground px-2">
               {matchCount}
@@ -83,6 +97,17 @@ export function TableSearch({ value, onChange, onClear, matchCount }: TableSearc
               <X className="h-3 w-3" />
             </Button>
           )}
+          {onToggleSemantic && (
+            <Button
+              variant="ghost"
+              size="icon"
+              className={cn("h-6 w-6", semantic && "text-primary")}
+              onClick={onToggleSemantic}
+              title={semantic ? "Search by table name" : "Search by meaning"}
+            >
+              <Sparkles className="h-3 w-3" />
+            </Button>
+          )}
         </div>
       </div>
     </div>
@@ -9,13 +9,21 @@ import {
   ShieldCheck,
   RefreshCw,
   Copy,
+  MessageSquareText,
+  Sparkles,
+  Layers,
+  Hash,
 } from "lucide-react";
 import { Button, Tooltip, TooltipContent, TooltipTrigger } from "@/components/ui";
 import { useDatabase, useToast } from "@/hooks";
-import type { Tab, TableProperties } from "@/types";
+import { useConnectionsStore, useQueryStore } from "@/stores";
+import { useAIStore } from "@/lib/ai/store";
+import { aiGenerateSchemaDocs } from "@/lib/ai/api";
+import type { SchemaDocs } from "@/lib/ai/types";
+import type { Tab, TablePartitioning, TableProperties } from "@/types";
 import { cn, copyToClipboard } from "@/lib/utils";
 
-type CategoryType = "columns" | "primaryKeys" | "foreignKeys" | "indexes" | "constraints";
+type CategoryType = "columns" | "primaryKeys" | "foreignKeys" | "indexes" | "constraints" | "partitions" | "comments";
 
 interface CategoryItemProps {
   icon: React.ReactNode;
@@ -53,12 +61,14 @@ interface TablePropertiesTabProps {
 }
 
 export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
-  const { getTableProperties, generateTableDdl } = useDatabase();
+  const { getTableProperties, getTablePartitions, generateTableDdl, countExactRows } = useDatabase();
   const { toast } = useToast();
   const [isLoading, setIsLoading] = useState(true);
   const [properties, setProperties] = useState<TableProperties | null>(null);
+  const [partitioning, setPartitioning] = useState<TablePartitioning | null>(null);
   const [activeCategory, setActiveCategory] = useState<CategoryType>("columns");
   const [error, setError] = useState<string | null>(null);
+  const [isCounting, setIsCounting] = useState(false);
 
   const loadProperties = async () => {
     if (!tab.tableName || !tab.connectionId) return;
@@ -67,8 +77,13 @@ export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
     setError(null);
 
     try {
-      const data = await getTableProperties(tab.connectionId, tab.tableName);
+      const [data, partitions] = await Promise.all([
+        getTableProperties(tab.connectionId, tab.tableName),
+        // Partitions are extra detail; a failure leaves the category out
+        getTablePartitions(tab.connectionId, tab.tableName).catch(() => null),
+      ]);
       setProperties(data);
+      setPartitioning(partitions);
     } catch (err) {
       setError(err instanceof Error ? err.message : "Failed to load table properties");
     } finally {
@@ -76,6 +91,24 @@ export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
     }
   };
 
+  const handleCountRows = async () => {
+    if (!tab.tableName || !tab.connectionId) return;
+
+    setIsCounting(true);
+    try {
+      const rowCount = await countExactRows(tab.connectionId, tab.tableName);
+      setProperties((current) => (current ? { ...current, rowCount, rowCountExact: true } : current));
+    } catch (error) {
+      toast({
+        title: "Count Failed",
+        description: error instanceof Error ? error.message : String(error),
+        variant: "destructive",
+      });
+    } finally {
+      setIsCounting(false);
+    }
+  };
+
   const handleCopyDdl = async () => {
     if (!tab.tableName || !tab.connectionId) return;
 
@@ -134,6 +167,15 @@ export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
     { id: "foreignKeys" as const, icon: <Link2 className="h-4 w-4" />, label: "Foreign Keys", count: properties.foreignKeys.length },
     { id: "indexes" as const, icon: <ListOrdered className="h-4 w-4" />, label: "Indexes", count: properties.indexes.length },
     { id: "constraints" as const, icon: <ShieldCheck className="h-4 w-4" />, label: "Constraints", count: properties.constraints.length },
+    ...(partitioning
+      ? [{ id: "partitions" as const, icon: <Layers className="h-4 w-4" />, label: "Partitions", count: partitioning.partitions.length }]
+      : []),
+    {
+      id: "comments" as const,
+      icon: <MessageSquareText className="h-4 w-4" />,
+      label: "Comments",
+      count: properties.columns.filter((c) => c.comment).length,
+    },
   ];
 
   return (
@@ -145,9 +187,27 @@ export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
           <span className="font-medium">{displayName}</span>
           {properties.rowCount != null && (
             <span className="text-xs text-muted-foreground">
-              ({properties.rowCount.toLocaleString()} rows)
+              ({properties.rowCountExact ? "" : "~"}
+              {properties.rowCount.toLocaleString()} rows{properties.rowCountExact ? "" : ", estimated"})
             </span>
           )}
+          {!properties.rowCountExact && (
+            <Tooltip>
+              <TooltipTrigger asChild>
+                <Button
+                  variant="ghost"
+                  size="sm"
+                  className="h-6 px-2 text-xs"
+                  onClick={handleCountRows}
+                  disabled={isCounting}
+                >
+                  {isCounting ? <Loader2 className="h-3 w-3 animate-spin" /> : <Hash className="h-3 w-3" />}
+                  <span className="ml-1">Count rows</span>
+                </Button>
+              </TooltipTrigger>
+              <TooltipContent>Count every row exactly; may take a while on large tables</TooltipContent>
+            </Tooltip>
+          )}
         </div>
         <div className="flex items-center gap-1">
           <Tooltip>
@@ -202,6 +262,17 @@ export function TablePropertiesTab({ tab }: TablePropertiesTabProps) {
           {activeCategory === "constraints" && (
             <ConstraintsTable constraints={properties.constraints} />
           )}
+          {activeCategory === "partitions" && partitioning && (
+            <PartitionsTable partitioning={partitioning} />
+          )}
+          {activeCategory === "comments" && tab.connectionId && tab.tableName && (
+            <CommentsView
+              connectionId={tab.connectionId}
+              tableName={tab.tableName}
+              properties={properties}
+              onApplied={loadProperties}
+            />
+          )}
         </div>
       </div>
     </div>
@@ -231,7 +302,10 @@ function ColumnsTable({ columns }: { columns: TableProperties["columns"] }) {
           {columns.map((col, idx) => (
             <tr key={col.name} className={idx % 2 === 0 ? "bg-background" : "bg-muted/20"}>
               <td className="px-4 py-2 font-mono text-xs">{col.name}</td>
-              <td className="px-4 py-2 font-mono text-xs text-muted-foreground">{col.dataType}</td>
+              <td className="px-4 py-2 font-mono text-xs text-muted-foreground">
+                {col.dataType}
+                {col.collation && <span className="opacity-60"> COLLATE {col.collation}</span>}
+              </td>
               <td className="px-4 py-2 text-center">
                 {col.isPrimaryKey && <Key className="h-3.5 w-3.5 mx-auto text-amber-500" />}
               </td>
@@ -241,7 +315,9 @@ function ColumnsTable({ columns }: { columns: TableProperties["columns"] }) {
                 </span>
               </td>
               <td className="px-4 py-2 font-mono text-xs text-muted-foreground">
-                {col.defaultValue || "—"}
+                {col.generationExpression
+                  ? `GENERATED AS (${col.generationExpression})`
+                  : col.defaultValue || (col.isIdentity ? (col.identityAlways ? "IDENTITY (always)" : "IDENTITY") : "—")}
               </td>
             </tr>
           ))}
@@ -382,6 +458,195 @@ function ConstraintsTable({ constraints }: { constraints: TableProperties["const
   );
 }
 
+function formatSize(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  if (bytes < 1024 * 1024 * 1024) return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+  return `${(bytes / (1024 * 1024 * 1024)).toFixed(1)} GB`;
+}
+
+function PartitionsTable({ partitioning }: { partitioning: TablePartitioning }) {
+  return (
+    <div className="space-y-3">
+      <p className="text-sm text-muted-foreground">
+        Partitioned by <span className="font-mono text-foreground">{partitioning.strategy}</span>
+        {partitioning.key && <span className="font-mono text-foreground"> ({partitioning.key})</span>}
+      </p>
+      {partitioning.partitions.length === 0 ? (
+        <EmptyState message="No partitions found" />
+      ) : (
+        <div className="rounded-lg border overflow-hidden">
+          <table className="w-full text-sm">
+            <thead className="bg-muted/50">
+              <tr>
+                <th className="px-4 py-2 text-left font-medium">Name</th>
+                <th className="px-4 py-2 text-left font-medium">Bound</th>
+                <th className="px-4 py-2 text-right font-medium">Rows (approx.)</th>
+                <th className="px-4 py-2 text-right font-medium">Size</th>
+              </tr>
+            </thead>
+            <tbody>
+              {partitioning.partitions.map((partition, idx) => (
+                <tr key={partition.name} className={idx % 2 === 0 ? "bg-background" : "bg-muted/20"}>
+                  <td className="px-4 py-2 font-mono text-xs">
+                    {partition.name}
+                    {partition.subPartitioning && (
+                      <span className="ml-2 px-2 py-0.5 bg-muted rounded text-muted-foreground">
+                        {partition.subPartitioning}
+                      </span>
+                    )}
+                  </td>
+                  <td className="px-4 py-2 font-mono text-xs text-muted-foreground truncate max-w-md" title={partition.bound}>
+                    {partition.bound || "—"}
+                  </td>
+                  <td className="px-4 py-2 text-right text-xs tabular-nums">
+                    {partition.approximateRows != null ? partition.approximateRows.toLocaleString() : "—"}
+                  </td>
+                  <td className="px-4 py-2 text-right text-xs tabular-nums">
+                    {partition.sizeBytes != null ? formatSize(partition.sizeBytes) : "—"}
+                  </td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      )}
+    </div>
+  );
+}
+
+interface CommentsViewProps {
+  connectionId: string;
+  tableName: string;
+  properties: TableProperties;
+  onApplied: () => void;
+}
+
+/** Existing comments, and descriptions the AI proposes as COMMENT statements or a data dictionary */
+function CommentsView({ connectionId, tableName, properties, onApplied }: CommentsViewProps) {
+  const { previewSchemaComments, applySchemaComments } = useDatabase();
+  const { toast } = useToast();
+  const { settings, isConfigured } = useAIStore();
+  const databaseType = useConnectionsStore((s) => s.connections.find((c) => c.id === connectionId)?.databaseType);
+  const [docs, setDocs] = useState<SchemaDocs | null>(null);
+  const [statements, setStatements] = useState<string[]>([]);
+  const [format, setFormat] = useState<"statements" | "markdown">("statements");
+  const [isGenerating, setIsGenerating] = useState(false);
+  const [isApplying, setIsApplying] = useState(false);
+  const canApply = databaseType === "postgresql" || databaseType === "mysql";
+
+  const handleGenerate = async () => {
+    setIsGenerating(true);
+    try {
+      const generated = await aiGenerateSchemaDocs({ table: properties, databaseType }, settings);
+      setDocs(generated);
+      const comments = { tableComment: generated.tableComment || undefined, columns: generated.columns };
+      setStatements(canApply ? (await previewSchemaComments(connectionId, tableName, comments)) ?? [] : []);
+      setFormat(canApply ? "statements" : "markdown");
+    } catch (error) {
+      toast({
+        title: "Documentation Failed",
+        description: error instanceof Error ? error.message : String(error),
+        variant: "destructive",
+      });
+    } finally {
+      setIsGenerating(false);
+    }
+  };
+
+  const handleApply = async () => {
+    if (!docs) return;
+    setIsApplying(true);
+    const comments = { tableComment: docs.tableComment || undefined, columns: docs.columns };
+    const result = await applySchemaComments(connectionId, tableName, comments);
+    setIsApplying(false);
+    if (result) {
+      toast({ title: "Comments Applied", description: `Comments stored on ${docs.columns.length} columns.` });
+      setDocs(null);
+      onApplied();
+    } else {
+      toast({
+        title: "Apply Failed",
+        description: useQueryStore.getState().error ?? "Could not apply the comments",
+        variant: "destructive",
+      });
+    }
+  };
+
+  const output = format === "statements" ? statements.map((s) => `${s};`).join("\n") : docs?.markdown ?? "";
+  const proposed = (column: string) => docs?.columns.find((c) => c.column === column)?.comment;
+
+  return (
+    <div className="space-y-4">
+      <div className="flex items-center justify-between">
+        <p className="text-sm text-muted-foreground">
+          {properties.tableComment || "The table has no comment."}
+        </p>
+        <Button size="sm" onClick={handleGenerate} disabled={isGenerating || !isConfigured()}>
+          {isGenerating ? <Loader2 className="h-4 w-4 animate-spin" /> : <Sparkles className="h-4 w-4" />}
+          Generate with AI
+        </Button>
+      </div>
+
+      <div className="rounded-lg border overflow-hidden">
+        <table className="w-full text-sm">
+          <thead className="bg-muted/50">
+            <tr>
+              <th className="px-4 py-2 text-left font-medium">Column</th>
+              <th className="px-4 py-2 text-left font-medium">Comment</th>
+              {docs && <th className="px-4 py-2 text-left font-medium">Proposed</th>}
+            </tr>
+          </thead>
+          <tbody>
+            {properties.columns.map((col, idx) => (
+              <tr key={col.name} className={idx % 2 === 0 ? "bg-background" : "bg-muted/20"}>
+                <td className="px-4 py-2 font-mono text-xs">{col.name}</td>
+                <td className="px-4 py-2 text-xs text-muted-foreground">{col.comment || "—"}</td>
+                {docs && <td className="px-4 py-2 text-xs">{proposed(col.name) || "—"}</td>}
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+
+      {docs && (
+        <div className="space-y-2">
+          <div className="flex items-center gap-2">
+            {canApply && (
+              <Button
+                variant={format === "statements" ? "secondary" : "ghost"}
+                size="sm"
+                onClick={() => setFormat("statements")}
+              >
+                Statements
+              </Button>
+            )}
+            <Button
+              variant={format === "markdown" ? "secondary" : "ghost"}
+              size="sm"
+              onClick={() => setFormat("markdown")}
+            >
+              Markdown
+            </Button>
+            <div className="flex-1" />
+            <Button variant="outline" size="sm" onClick={() => copyToClipboard(output)}>
+              <Copy className="h-4 w-4" />
+              Copy
+            </Button>
+            {canApply && (
+              <Button size="sm" onClick={handleApply} disabled={isApplying || statements.length === 0}>
+                {isApplying && <Loader2 className="h-4 w-4 animate-spin" />}
+                Apply Comments
+              </Button>
+            )}
+          </div>
+          <pre className="rounded-lg border bg-muted/30 p-3 font-mono text-xs whitespace-pre-wrap">{output}</pre>
+        </div>
+      )}
+    </div>
+  );
+}
+
 function EmptyState({ message }: { message: string }) {
   return (
     <div className="flex items-center justify-center h-32 text-muted-foreground text-sm">
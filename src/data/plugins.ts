@@ -4,7 +4,7 @@ export interface ExtensionData {
   description: string;
   version: string;
   author: string;
-  category: "Validators" | "AI" | "Exporters" | "Themes";
+  category: "Validators" | "AI" | "Exporters" | "Themes" | "Tools" | "Connectors";
   downloads: string;
   rating: number;
   isOfficial: boolean;
@@ -91,6 +91,50 @@ export const EXTENSIONS: ExtensionData[] = [
     rating: 4.5,
     isOfficial: false,
   },
+  {
+    id: "data-faker",
+    name: "Mock Data Generator",
+    description: "Fill tables with realistic fake rows based on column names and types, reusing existing foreign keys.",
+    version: "0.2.1",
+    author: "dbfordevs",
+    category: "Tools",
+    downloads: "0",
+    rating: 0,
+    isOfficial: true,
+  },
+  {
+    id: "connector-clickhouse",
+    name: "ClickHouse Connector",
+    description: "Connect to ClickHouse over HTTP(S): run queries, browse tables from the system tables, and view DDL.",
+    version: "0.2.1",
+    author: "dbfordevs",
+    category: "Connectors",
+    downloads: "0",
+    rating: 0,
+    isOfficial: true,
+  },
+  {
+    id: "connector-duckdb",
+    name: "DuckDB Connector",
+    description: "Open DuckDB files or in-memory databases and query Parquet and CSV files in place.",
+    version: "0.2.1",
+    author: "dbfordevs",
+    category: "Connectors",
+    downloads: "0",
+    rating: 0,
+    isOfficial: true,
+  },
+  {
+    id: "connector-oracle",
+    name: "Oracle Connector",
+    description: "Connect to Oracle Database 12.2 and later through the Oracle Instant Client.",
+    version: "0.2.1",
+    author: "dbfordevs",
+    category: "Connectors",
+    downloads: "0",
+    rating: 0,
+    isOfficial: true,
+  },
 ];
 
 // Backwards compatibility alias
@@ -3,6 +3,7 @@ export { useValidator } from "./useValidator";
 export { useToast, toast } from "./useToast";
 export { useAsyncOperation } from "./useAsyncOperation";
 export { useKeyboardShortcuts } from "./useKeyboardShortcuts";
+export { useWorkspacePersistence } from "./useWorkspace";
 export { useCRUD } from "./useCRUD";
 export type { UseAsyncOperationOptions, UseAsyncOperationResult } from "./useAsyncOperation";
 
@@ -2,6 +2,7 @@ import { useCallback } from "react";
 import { useCRUDStore, useQueryStore, selectActiveTab } from "@/stores";
 import { useDatabase } from "@/hooks";
 import { useToast } from "@/hooks/useToast";
+import { toCellValues } from "@/lib/cell-value";
 
 export function useCRUD() {
   const {
@@ -28,20 +29,20 @@ export function useCRUD() {
           result = await updateRow(
             activeTab.connectionId,
             change.tableName,
-            change.primaryKey,
-            change.newData || {}
+            toCellValues(change.primaryKey, change.columns),
+            toCellValues(change.newData || {}, change.columns)
           );
         } else if (change.type === "delete") {
           result = await deleteRow(
             activeTab.connectionId,
             change.tableName,
-            change.primaryKey
+            toCellValues(change.primaryKey, change.columns)
           );
         } else if (change.type === "insert") {
           result = await insertRow(
             activeTab.connectionId,
             change.tableName,
-            change.newData || {}
+            toCellValues(change.newData || {}, change.columns)
           );
         }
 
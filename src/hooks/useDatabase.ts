@@ -4,13 +4,88 @@ import { useConnectionsStore, useQueryStore, useSchemaStore } from "@/stores";
 import type {
   ConnectionConfig,
   ConnectionInfo,
+  ConnectionInsights,
+  ConnectionTemplate,
   TestConnectionResult,
   QueryRequest,
   QueryResult,
+  ResultWindow,
+  CellBlob,
+  CellValue,
+  VectorSearchRequest,
+  BrowseRequest,
+  BrowseResult,
+  RowReferenceRequest,
+  SqlFileRequest,
+  SqlFileSummary,
+  BackupRequest,
+  BackupSummary,
+  BackupTool,
+  RestoreRequest,
+  TableCopyRequest,
+  TableCopySummary,
+  ObjectSearchResult,
+  DataSearchOptions,
+  DataSearchSummary,
+  PinnedObject,
+  PinnedObjectType,
+  ChartSpec,
+  ChartData,
+  ExportRequest,
+  CopyResultsRequest,
+  ExportSummary,
+  ImportFileOptions,
+  ImportPreview,
+  ImportRequest,
+  ImportSummary,
+  MockDataSummary,
   TableInfo,
   TableSchema,
   TableProperties,
+  TableStats,
+  TablePartitioning,
   TableRelationship,
+  SchemaComments,
+  ErModel,
+  DatabaseUser,
+  TablePrivilege,
+  ServerStats,
+  ActiveQuery,
+  SlowQueryEntry,
+  SlowQueryOrder,
+  NewColumn,
+  TruncateOptions,
+  DatabaseDdlOptions,
+  CreateDatabaseOptions,
+  CreateSchemaOptions,
+  Confirmable,
+  MigrationRequest,
+  MigrationPlan,
+  TableDiffRequest,
+  TableDiffResult,
+  ResultDiff,
+  FederatedQueryRequest,
+  FederatedQueryResult,
+  DatabaseType,
+  SqlFormatOptions,
+  SqlDiagnostic,
+  SqlCompletion,
+  AuditEntry,
+  AuditLogFilter,
+  ExportFormat,
+  ExportOptions,
+  MarketplaceSearchResult,
+  InstalledExtension,
+  ExtensionLogEntry,
+  AppLogs,
+  PerformanceMetrics,
+  Workspace,
+  SavedQuery,
+  LogSettings,
+  ExtensionSettings,
+  ExtensionCommandInfo,
+  ThemeInfo,
+  ExtensionCommandResult,
 } from "@/types";
 
 /**
@@ -114,6 +189,29 @@ export function useDatabase() {
     [setConnecting, setConnectionError, updateConnection, setActiveConnection]
   );
 
+  /**
+   * Reconnect to another database on the same server for this session
+   */
+  const switchDatabase = useCallback(
+    async (connectionId: string, database: string): Promise<boolean> => {
+      setConnecting(true);
+      setConnectionError(null);
+
+      try {
+        await invoke("switch_database", { connectionId, database });
+        updateConnection(connectionId, { connected: true, database });
+        return true;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setConnectionError(message);
+        return false;
+      } finally {
+        setConnecting(false);
+      }
+    },
+    [setConnecting, setConnectionError, updateConnection]
+  );
+
   /**
    * Disconnect from a database
    */
@@ -174,6 +272,14 @@ export function useDatabase() {
     [setConnectionError]
   );
 
+  /**
+   * When a connection was last used, its recent and most queried tables and
+   * its most filtered columns
+   */
+  const getConnectionInsights = useCallback(async (connectionId: string): Promise<ConnectionInsights> => {
+    return await invoke<ConnectionInsights>("get_connection_insights", { connectionId });
+  }, []);
+
   /**
    * Delete a connection
    */
@@ -201,243 +307,327 @@ export function useDatabase() {
   );
 
   /**
-   * Execute a SQL query
+   * Export saved connections as a JSON bundle (encrypted when a password is given)
    */
-  const executeQuery = useCallback(
-    async (request: QueryRequest, tabId: string): Promise<QueryResult | null> => {
-      setExecuting(true);
-      setQueryError(null);
+  const exportConnections = useCallback(
+    async (options: {
+      connectionIds?: string[];
+      includeCredentials: boolean;
+      password?: string;
+    }): Promise<string | null> => {
+      setConnectionError(null);
 
       try {
-        const result = await invoke<QueryResult>("execute_query", { request });
-        setResults(tabId, result);
-        return result;
+        return await invoke<string>("export_connections", {
+          connectionIds: options.connectionIds ?? null,
+          includeCredentials: options.includeCredentials,
+          password: options.password ?? null,
+        });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
-        setQueryError(message);
+        setConnectionError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError, setResults]
+    [setConnectionError]
   );
 
   /**
-   * Get tables for a connection
+   * Import connections from a bundle produced by exportConnections
    */
-  const getTables = useCallback(
-    async (connectionId: string): Promise<TableInfo[]> => {
+  const importConnections = useCallback(
+    async (content: string, password?: string): Promise<ConnectionInfo[] | null> => {
       setLoading(true);
-      setQueryError(null);
+      setConnectionError(null);
 
       try {
-        const tables = await invoke<TableInfo[]>("get_tables", { connectionId });
-        setTablesForConnection(connectionId, tables);
-        return tables;
+        const imported = await invoke<ConnectionInfo[]>("import_connections", {
+          content,
+          password: password ?? null,
+        });
+        const connections = await invoke<ConnectionInfo[]>("list_connections");
+        setConnections(connections);
+        return imported;
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
-        setQueryError(message);
-        return [];
+        setConnectionError(message);
+        return null;
       } finally {
         setLoading(false);
       }
     },
-    [setLoading, setQueryError, setTablesForConnection]
+    [setLoading, setConnectionError, setConnections]
   );
 
   /**
-   * Get schema for a specific table
+   * Templates for managed services such as Amazon RDS, Supabase and Neon
    */
-  const getTableSchema = useCallback(
-    async (connectionId: string, tableName: string): Promise<TableSchema | null> => {
-      setLoading(true);
+  const listConnectionTemplates = useCallback(async (): Promise<ConnectionTemplate[]> => {
+    try {
+      return await invoke<ConnectionTemplate[]>("list_connection_templates");
+    } catch (error) {
+      console.error("Failed to load connection templates:", error);
+      return [];
+    }
+  }, []);
+
+  /**
+   * Check that a host belongs to the template's service; returns the problem, or null when it matches
+   */
+  const checkTemplateHost = useCallback(async (templateId: string, host: string): Promise<string | null> => {
+    try {
+      await invoke("check_template_host", { templateId, host });
+      return null;
+    } catch (error) {
+      return error instanceof Error ? error.message : String(error);
+    }
+  }, []);
+
+  /**
+   * Execute a SQL query; UPDATE or DELETE without WHERE returns a confirmation request unless a token is passed
+   */
+  const executeQuery = useCallback(
+    async (request: QueryRequest, tabId: string): Promise<Confirmable<QueryResult> | null> => {
+      setExecuting(true);
       setQueryError(null);
 
       try {
-        const schema = await invoke<TableSchema>("get_table_schema", {
-          connectionId,
-          tableName,
-        });
-        setTableSchema(schema);
-        return schema;
+        const result = await invoke<Confirmable<QueryResult>>("execute_query", { request });
+        if (result.status === "executed") {
+          setResults(tabId, result);
+        }
+        return result;
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
       } finally {
-        setLoading(false);
+        setExecuting(false);
       }
     },
-    [setLoading, setQueryError, setTableSchema]
+    [setExecuting, setQueryError, setResults]
   );
 
   /**
-   * Fetch all table schemas for a connection and cache them
+   * Fetch rows of a result kept in the backend by a windowed executeQuery
    */
-  const fetchAllSchemas = useCallback(
-    async (connectionId: string): Promise<void> => {
-      setLoading(true);
+  const getResultRows = useCallback(async (resultId: string, offset: number, count: number): Promise<ResultWindow> => {
+    return await invoke<ResultWindow>("get_result_rows", { resultId, offset, count });
+  }, []);
+
+  /**
+   * A binary cell of a result kept in the backend, with its content type
+   */
+  const getCellBlob = useCallback(async (resultId: string, row: number, column: number): Promise<CellBlob> => {
+    return await invoke<CellBlob>("get_cell_blob", { resultId, row, column });
+  }, []);
+
+  /**
+   * Write a binary cell to a file, by default in the downloads folder; returns the path written
+   */
+  const saveBlobToFile = useCallback(
+    async (resultId: string, row: number, column: number, path?: string): Promise<string> => {
+      return await invoke<string>("save_blob_to_file", { resultId, row, column, path });
+    },
+    []
+  );
+
+  /**
+   * Drop a result kept in the backend
+   */
+  const releaseResult = useCallback(async (resultId: string): Promise<boolean> => {
+    return await invoke<boolean>("release_result", { resultId });
+  }, []);
+
+  /**
+   * Rows nearest a vector by a pgvector column, with a distance column, nearest first
+   */
+  const vectorSearch = useCallback(async (request: VectorSearchRequest): Promise<QueryResult> => {
+    return await invoke<QueryResult>("vector_search", { request });
+  }, []);
+
+  /**
+   * Copy result rows to the clipboard, formatted by the backend; returns the rows copied
+   */
+  const copyResultsAs = useCallback(async (request: CopyResultsRequest): Promise<number> => {
+    return await invoke<number>("copy_results_as", { request });
+  }, []);
+
+  /**
+   * Execute a .sql file, e.g. to restore a dump
+   */
+  const runSqlFile = useCallback(
+    async (request: SqlFileRequest): Promise<SqlFileSummary | null> => {
       setQueryError(null);
 
       try {
-        const schemas = await invoke<TableSchema[]>("get_all_table_schemas", {
-          connectionId,
-        });
-
-        // Cache all schemas
-        schemas.forEach((schema) => {
-          setSchemas(connectionId, schema.tableName, schema);
-        });
+        return await invoke<SqlFileSummary>("run_sql_file", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
-      } finally {
-        setLoading(false);
+        return null;
       }
     },
-    [setLoading, setQueryError, setSchemas]
+    [setQueryError]
   );
 
   /**
-   * Refresh all cached schemas for a connection
+   * The dump and restore tools a connection would use, and whether each is installed
    */
-  const refreshSchemas = useCallback(
-    async (connectionId: string): Promise<void> => {
-      clearSchemas(connectionId);
-      await fetchAllSchemas(connectionId);
+  const getBackupTools = useCallback(async (connectionId: string): Promise<BackupTool[]> => {
+    return await invoke<BackupTool[]>("get_backup_tools", { connectionId });
+  }, []);
+
+  /**
+   * Back up a database to a file; progress arrives as "backup-progress" events
+   */
+  const backupDatabase = useCallback(async (request: BackupRequest): Promise<BackupSummary> => {
+    return await invoke<BackupSummary>("backup_database", { request });
+  }, []);
+
+  /**
+   * Restore a backup file; returns a confirmation to show first unless a token is given
+   */
+  const restoreDatabase = useCallback(async (request: RestoreRequest): Promise<Confirmable<BackupSummary>> => {
+    return await invoke<Confirmable<BackupSummary>>("restore_database", { request });
+  }, []);
+
+  /**
+   * Copy a table into another connection, creating the target table when it is missing;
+   * progress arrives as "table-copy-progress" events
+   */
+  const copyTableBetweenConnections = useCallback(async (request: TableCopyRequest): Promise<TableCopySummary> => {
+    return await invoke<TableCopySummary>("copy_table_between_connections", { request });
+  }, []);
+
+  /**
+   * Stop a running table copy after its current batch
+   */
+  const cancelTableCopy = useCallback(async (transferId: string): Promise<boolean> => {
+    return await invoke<boolean>("cancel_table_copy", { transferId });
+  }, []);
+
+  /**
+   * Find table and column names, view definitions, routine bodies and comments containing a pattern
+   */
+  const searchDatabaseObjects = useCallback(
+    async (connectionId: string, pattern: string): Promise<ObjectSearchResult> => {
+      return await invoke<ObjectSearchResult>("search_database_objects", { connectionId, pattern });
     },
-    [clearSchemas, fetchAllSchemas]
+    []
   );
 
   /**
-   * Insert a new row
+   * Search the text columns of the given tables for a term; each table's matches arrive
+   * as a "table-data-search-matches" event as soon as it is searched
    */
-  const insertRow = useCallback(
+  const searchTableData = useCallback(
     async (
+      searchId: string,
       connectionId: string,
-      tableName: string,
-      values: Record<string, unknown>
-    ): Promise<QueryResult | null> => {
-      setExecuting(true);
+      tables: string[],
+      term: string,
+      options?: DataSearchOptions
+    ): Promise<DataSearchSummary> => {
+      return await invoke<DataSearchSummary>("search_table_data", {
+        searchId,
+        connectionId,
+        tables,
+        term,
+        options: options ?? null,
+      });
+    },
+    []
+  );
+
+  /**
+   * Aggregate a query result into chart series
+   */
+  const prepareChartData = useCallback(async (result: QueryResult, spec: ChartSpec): Promise<ChartData> => {
+    return await invoke<ChartData>("prepare_chart_data", { result, spec });
+  }, []);
+
+  /**
+   * Fetch a page of table data, sorted and filtered by the database
+   */
+  const browseTable = useCallback(
+    async (request: BrowseRequest): Promise<BrowseResult | null> => {
       setQueryError(null);
 
       try {
-        const result = await invoke<QueryResult>("insert_row", {
-          connectionId,
-          tableName,
-          values,
-        });
-        return result;
+        return await invoke<BrowseResult>("browse_table", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError]
+    [setQueryError]
   );
 
   /**
-   * Update a row
+   * Fetch the parent row a foreign key of the given row points to
    */
-  const updateRow = useCallback(
-    async (
-      connectionId: string,
-      tableName: string,
-      primaryKey: Record<string, unknown>,
-      values: Record<string, unknown>
-    ): Promise<QueryResult | null> => {
-      setExecuting(true);
+  const getReferencedRow = useCallback(
+    async (request: RowReferenceRequest): Promise<QueryResult | null> => {
       setQueryError(null);
 
       try {
-        const result = await invoke<QueryResult>("update_row", {
-          connectionId,
-          tableName,
-          primaryKey,
-          values,
-        });
-        return result;
+        return await invoke<QueryResult>("get_referenced_row", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError]
+    [setQueryError]
   );
 
   /**
-   * Delete a row
+   * Fetch the child rows whose foreign key points to the given row
    */
-  const deleteRow = useCallback(
-    async (
-      connectionId: string,
-      tableName: string,
-      primaryKey: Record<string, unknown>
-    ): Promise<QueryResult | null> => {
-      setExecuting(true);
+  const getReferencingRows = useCallback(
+    async (request: RowReferenceRequest): Promise<QueryResult | null> => {
       setQueryError(null);
 
       try {
-        const result = await invoke<QueryResult>("delete_row", {
-          connectionId,
-          tableName,
-          primaryKey,
-        });
-        return result;
+        return await invoke<QueryResult>("get_referencing_rows", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError]
+    [setQueryError]
   );
 
   /**
-   * Drop a table
+   * Run a query and write its results to a file
    */
-  const dropTable = useCallback(
-    async (connectionId: string, tableName: string): Promise<QueryResult | null> => {
-      setExecuting(true);
+  const exportQueryResults = useCallback(
+    async (request: ExportRequest): Promise<ExportSummary | null> => {
       setQueryError(null);
 
       try {
-        const result = await invoke<QueryResult>("drop_table", {
-          connectionId,
-          tableName,
-        });
-        return result;
+        return await invoke<ExportSummary>("export_query_results", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError]
+    [setQueryError]
   );
 
   /**
-   * Generate CREATE TABLE DDL for a table
+   * Detect the format and column types of a CSV, TSV or JSON file
    */
-  const generateTableDdl = useCallback(
-    async (connectionId: string, tableName: string): Promise<string | null> => {
+  const previewImportFile = useCallback(
+    async (path: string, options?: ImportFileOptions): Promise<ImportPreview | null> => {
+      setQueryError(null);
+
       try {
-        const ddl = await invoke<string>("generate_table_ddl", {
-          connectionId,
-          tableName,
-        });
-        return ddl;
+        return await invoke<ImportPreview>("preview_import_file", { path, options });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
@@ -448,42 +638,42 @@ export function useDatabase() {
   );
 
   /**
-   * Rename a table
+   * Load a CSV, TSV or JSON file into a table
    */
-  const renameTable = useCallback(
-    async (connectionId: string, oldName: string, newName: string): Promise<QueryResult | null> => {
-      setExecuting(true);
+  const importFileIntoTable = useCallback(
+    async (request: ImportRequest): Promise<ImportSummary | null> => {
       setQueryError(null);
 
       try {
-        const result = await invoke<QueryResult>("rename_table", {
-          connectionId,
-          oldName,
-          newName,
-        });
-        return result;
+        return await invoke<ImportSummary>("import_file_into_table", { request });
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return null;
-      } finally {
-        setExecuting(false);
       }
     },
-    [setExecuting, setQueryError]
+    [setQueryError]
   );
 
   /**
-   * Get full table properties including extended column info, indexes, and constraints
+   * Insert generated rows into a table; the same seed generates the same rows
    */
-  const getTableProperties = useCallback(
-    async (connectionId: string, tableName: string): Promise<TableProperties | null> => {
+  const generateMockData = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      count: number,
+      seed?: number
+    ): Promise<MockDataSummary | null> => {
+      setQueryError(null);
+
       try {
-        const properties = await invoke<TableProperties>("get_table_properties", {
+        return await invoke<MockDataSummary>("generate_mock_data", {
           connectionId,
           tableName,
+          count,
+          seed,
         });
-        return properties;
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
@@ -494,46 +684,1427 @@ export function useDatabase() {
   );
 
   /**
-   * Get table relationships (foreign keys both inbound and outbound)
+   * Get tables for a connection
    */
-  const getTableRelationships = useCallback(
-    async (connectionId: string, tableName: string): Promise<TableRelationship[]> => {
+  const getTables = useCallback(
+    async (connectionId: string): Promise<TableInfo[]> => {
+      setLoading(true);
+      setQueryError(null);
+
       try {
-        const relationships = await invoke<TableRelationship[]>("get_table_relationships", {
-          connectionId,
-          tableName,
-        });
-        return relationships;
+        const tables = await invoke<TableInfo[]>("get_tables", { connectionId });
+        setTablesForConnection(connectionId, tables);
+        return tables;
       } catch (error) {
         const message = error instanceof Error ? error.message : String(error);
         setQueryError(message);
         return [];
+      } finally {
+        setLoading(false);
       }
     },
-    [setQueryError]
+    [setLoading, setQueryError, setTablesForConnection]
+  );
+
+  /**
+   * Pin a table, view or saved query to the connection's favorites, or unpin it with pinned = false;
+   * the connection's loaded tables are marked to match
+   */
+  const pinObject = useCallback(
+    async (
+      connectionId: string,
+      objectType: PinnedObjectType,
+      name: string,
+      pinned = true
+    ): Promise<PinnedObject[]> => {
+      const pins = await invoke<PinnedObject[]>("pin_object", { connectionId, objectType, name, pinned });
+      const tables = useQueryStore.getState().tablesByConnection[connectionId];
+      if (tables && objectType !== "query") {
+        const pinnedNames = new Set(pins.filter((pin) => pin.objectType !== "query").map((pin) => pin.name));
+        setTablesForConnection(
+          connectionId,
+          tables.map((table) => ({ ...table, pinned: pinnedNames.has(table.name) }))
+        );
+      }
+      return pins;
+    },
+    [setTablesForConnection]
+  );
+
+  /**
+   * The connection's pinned tables, views and saved queries, in the order they were pinned
+   */
+  const listPinned = useCallback(async (connectionId: string): Promise<PinnedObject[]> => {
+    return await invoke<PinnedObject[]>("list_pinned", { connectionId });
+  }, []);
+
+  /**
+   * List databases on the connected server
+   */
+  const listDatabases = useCallback(
+    async (connectionId: string): Promise<string[]> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<string[]>("list_databases", { connectionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * List schemas in the connected database
+   */
+  const listSchemas = useCallback(
+    async (connectionId: string): Promise<string[]> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<string[]>("list_schemas", { connectionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Create a database on the connected server
+   */
+  const createDatabase = useCallback(
+    async (connectionId: string, options: CreateDatabaseOptions): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<QueryResult>("create_database", { connectionId, options });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Drop a database; without a token this returns a confirmation request instead
+   */
+  const dropDatabase = useCallback(
+    async (connectionId: string, name: string, confirmationToken?: string): Promise<Confirmable<QueryResult> | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<Confirmable<QueryResult>>("drop_database", { connectionId, name, confirmationToken });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Create a schema in the connected database
+   */
+  const createSchema = useCallback(
+    async (connectionId: string, options: CreateSchemaOptions): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<QueryResult>("create_schema", { connectionId, options });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Drop a schema; without a token this returns a confirmation request instead
+   */
+  const dropSchema = useCallback(
+    async (connectionId: string, name: string, cascade?: boolean, confirmationToken?: string): Promise<Confirmable<QueryResult> | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<Confirmable<QueryResult>>("drop_schema", { connectionId, name, cascade, confirmationToken });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Get schema for a specific table
+   */
+  const getTableSchema = useCallback(
+    async (connectionId: string, tableName: string): Promise<TableSchema | null> => {
+      setLoading(true);
+      setQueryError(null);
+
+      try {
+        const schema = await invoke<TableSchema>("get_table_schema", {
+          connectionId,
+          tableName,
+        });
+        setTableSchema(schema);
+        return schema;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setLoading(false);
+      }
+    },
+    [setLoading, setQueryError, setTableSchema]
+  );
+
+  /**
+   * Fetch all table schemas for a connection and cache them
+   */
+  const fetchAllSchemas = useCallback(
+    async (connectionId: string): Promise<void> => {
+      setLoading(true);
+      setQueryError(null);
+
+      try {
+        const schemas = await invoke<TableSchema[]>("get_all_table_schemas", {
+          connectionId,
+        });
+
+        // Cache all schemas
+        schemas.forEach((schema) => {
+          setSchemas(connectionId, schema.tableName, schema);
+        });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+      } finally {
+        setLoading(false);
+      }
+    },
+    [setLoading, setQueryError, setSchemas]
+  );
+
+  /**
+   * Refresh all cached schemas for a connection
+   */
+  const refreshSchemas = useCallback(
+    async (connectionId: string): Promise<void> => {
+      clearSchemas(connectionId);
+      await fetchAllSchemas(connectionId);
+    },
+    [clearSchemas, fetchAllSchemas]
+  );
+
+  /**
+   * Insert a new row
+   */
+  const insertRow = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      values: Record<string, CellValue>
+    ): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("insert_row", {
+          connectionId,
+          tableName,
+          values,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Insert rows, updating the existing row instead where one has the same primary key or unique index values
+   */
+  const upsertRows = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      rows: Record<string, CellValue>[]
+    ): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<QueryResult>("upsert_rows", {
+          connectionId,
+          tableName,
+          rows,
+        });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Update a row
+   */
+  const updateRow = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      primaryKey: Record<string, CellValue>,
+      values: Record<string, CellValue>
+    ): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("update_row", {
+          connectionId,
+          tableName,
+          primaryKey,
+          values,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Delete a row
+   */
+  const deleteRow = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      primaryKey: Record<string, CellValue>
+    ): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("delete_row", {
+          connectionId,
+          tableName,
+          primaryKey,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Drop a table; without a token this returns a confirmation request instead
+   */
+  const dropTable = useCallback(
+    async (connectionId: string, tableName: string, confirmationToken?: string): Promise<Confirmable<QueryResult> | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<Confirmable<QueryResult>>("drop_table", {
+          connectionId,
+          tableName,
+          confirmationToken,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Generate CREATE TABLE DDL for a table
+   */
+  const generateTableDdl = useCallback(
+    async (connectionId: string, tableName: string): Promise<string | null> => {
+      try {
+        const ddl = await invoke<string>("generate_table_ddl", {
+          connectionId,
+          tableName,
+        });
+        return ddl;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Generate a dependency-ordered DDL script for every object in the database
+   */
+  const generateDatabaseDdl = useCallback(
+    async (connectionId: string, options?: DatabaseDdlOptions): Promise<string> => {
+      return invoke<string>("generate_database_ddl", {
+        connectionId,
+        options,
+      });
+    },
+    []
+  );
+
+  /**
+   * Remove every row from a table; without a token this returns a confirmation request instead
+   */
+  const truncateTable = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      options?: TruncateOptions,
+      confirmationToken?: string
+    ): Promise<Confirmable<QueryResult> | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<Confirmable<QueryResult>>("truncate_table", {
+          connectionId,
+          tableName,
+          options,
+          confirmationToken,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Copy a table's structure, and optionally its rows, to a new table
+   */
+  const duplicateTable = useCallback(
+    async (connectionId: string, tableName: string, newName: string, includeData: boolean): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("duplicate_table", {
+          connectionId,
+          tableName,
+          newName,
+          includeData,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Add a column to a table
+   */
+  const addColumn = useCallback(
+    async (connectionId: string, tableName: string, column: NewColumn): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("add_column", {
+          connectionId,
+          tableName,
+          column,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Drop a column from a table
+   */
+  const dropColumn = useCallback(
+    async (connectionId: string, tableName: string, columnName: string): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("drop_column", {
+          connectionId,
+          tableName,
+          columnName,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Rename a column
+   */
+  const renameColumn = useCallback(
+    async (connectionId: string, tableName: string, oldName: string, newName: string): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("rename_column", {
+          connectionId,
+          tableName,
+          oldName,
+          newName,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Change a column's type and nullability
+   */
+  const alterColumnType = useCallback(
+    async (
+      connectionId: string,
+      tableName: string,
+      columnName: string,
+      dataType: string,
+      nullable: boolean
+    ): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("alter_column_type", {
+          connectionId,
+          tableName,
+          columnName,
+          dataType,
+          nullable,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Rename a table
+   */
+  const renameTable = useCallback(
+    async (connectionId: string, oldName: string, newName: string): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        const result = await invoke<QueryResult>("rename_table", {
+          connectionId,
+          oldName,
+          newName,
+        });
+        return result;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Get full table properties including extended column info, indexes, and constraints
+   */
+  const getTableProperties = useCallback(
+    async (connectionId: string, tableName: string): Promise<TableProperties | null> => {
+      try {
+        const properties = await invoke<TableProperties>("get_table_properties", {
+          connectionId,
+          tableName,
+        });
+        return properties;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Count a table's rows exactly; slow on large tables, whose properties only carry an estimate
+   */
+  const countExactRows = useCallback(
+    async (connectionId: string, tableName: string): Promise<number> => {
+      return invoke<number>("count_exact_rows", {
+        connectionId,
+        tableName,
+      });
+    },
+    []
+  );
+
+  /**
+   * Statements that would store comments on a table and its columns
+   */
+  const previewSchemaComments = useCallback(
+    async (connectionId: string, tableName: string, comments: SchemaComments): Promise<string[] | null> => {
+      try {
+        return await invoke<string[]>("preview_schema_comments", {
+          connectionId,
+          tableName,
+          comments,
+        });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Store comments on a table and its columns (PostgreSQL and MySQL)
+   */
+  const applySchemaComments = useCallback(
+    async (connectionId: string, tableName: string, comments: SchemaComments): Promise<QueryResult | null> => {
+      setExecuting(true);
+      setQueryError(null);
+
+      try {
+        return await invoke<QueryResult>("apply_schema_comments", {
+          connectionId,
+          tableName,
+          comments,
+        });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      } finally {
+        setExecuting(false);
+      }
+    },
+    [setExecuting, setQueryError]
+  );
+
+  /**
+   * Get on-disk size, index size, estimated rows and bloat indicators for a table
+   */
+  const getTableStats = useCallback(
+    async (connectionId: string, tableName: string): Promise<TableStats | null> => {
+      try {
+        const stats = await invoke<TableStats>("get_table_stats", {
+          connectionId,
+          tableName,
+        });
+        return stats;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * List the partitions of a partitioned table; null when it is not partitioned
+   */
+  const getTablePartitions = useCallback(
+    async (connectionId: string, tableName: string): Promise<TablePartitioning | null> => {
+      return invoke<TablePartitioning | null>("get_table_partitions", {
+        connectionId,
+        tableName,
+      });
+    },
+    []
+  );
+
+  /**
+   * Get table relationships (foreign keys both inbound and outbound)
+   */
+  const getTableRelationships = useCallback(
+    async (connectionId: string, tableName: string): Promise<TableRelationship[]> => {
+      try {
+        const relationships = await invoke<TableRelationship[]>("get_table_relationships", {
+          connectionId,
+          tableName,
+        });
+        return relationships;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Build the ER diagram graph, optionally limited to some schemas
+   */
+  const generateErModel = useCallback(
+    async (connectionId: string, schemas?: string[]): Promise<ErModel | null> => {
+      try {
+        const model = await invoke<ErModel>("generate_er_model", {
+          connectionId,
+          schemas,
+        });
+        return model;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Get the privileges each user or role holds on a table
+   */
+  const getTablePrivileges = useCallback(
+    async (connectionId: string, tableName: string): Promise<TablePrivilege[]> => {
+      try {
+        const privileges = await invoke<TablePrivilege[]>("get_table_privileges", {
+          connectionId,
+          tableName,
+        });
+        return privileges;
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * List logins and roles on the connected server
+   */
+  const getUsersAndRoles = useCallback(
+    async (connectionId: string): Promise<DatabaseUser[]> => {
+      try {
+        return await invoke<DatabaseUser[]>("get_users_and_roles", { connectionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Get connection counts, uptime, cache hit ratio and database sizes
+   */
+  const getServerStats = useCallback(
+    async (connectionId: string): Promise<ServerStats | null> => {
+      try {
+        return await invoke<ServerStats>("get_server_stats", { connectionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * List sessions that are running a statement
+   */
+  const getActiveQueries = useCallback(
+    async (connectionId: string): Promise<ActiveQuery[]> => {
+      try {
+        return await invoke<ActiveQuery[]>("get_active_queries", { connectionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
   );
 
+  /**
+   * Terminate a session listed by getActiveQueries
+   */
+  const killSession = useCallback(
+    async (connectionId: string, sessionId: string): Promise<boolean> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<boolean>("kill_session", { connectionId, sessionId });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return false;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Top statements by total time, mean time or calls
+   */
+  const getSlowQueries = useCallback(
+    async (connectionId: string, orderBy?: SlowQueryOrder, limit?: number): Promise<SlowQueryEntry[]> => {
+      try {
+        return await invoke<SlowQueryEntry[]>("get_slow_queries", { connectionId, orderBy, limit });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return [];
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Poll the server and emit "server-monitor" events until stopServerMonitor is called
+   */
+  const startServerMonitor = useCallback(
+    async (connectionId: string, intervalMs?: number): Promise<boolean> => {
+      try {
+        return await invoke<boolean>("start_server_monitor", { connectionId, intervalMs });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return false;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Stop the monitor started by startServerMonitor
+   */
+  const stopServerMonitor = useCallback(async (connectionId: string): Promise<boolean> => {
+    try {
+      return await invoke<boolean>("stop_server_monitor", { connectionId });
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      setQueryError(message);
+      return false;
+    }
+  }, [setQueryError]);
+
+  /**
+   * Generate the ALTER statements for an edited table schema without running them
+   */
+  const generateMigration = useCallback(
+    async (request: MigrationRequest): Promise<MigrationPlan | null> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<MigrationPlan>("generate_migration", { request });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Compare the rows of two tables matched by key
+   */
+  const diffTableData = useCallback(
+    async (request: TableDiffRequest): Promise<TableDiffResult | null> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<TableDiffResult>("diff_table_data", { request });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Join rows from several connections or kept results. The rows are copied
+   * into an in-memory SQLite database on this machine and the query runs there.
+   */
+  const runFederatedQuery = useCallback(async (request: FederatedQueryRequest): Promise<FederatedQueryResult> => {
+    return await invoke<FederatedQueryResult>("run_federated_query", { request });
+  }, []);
+
+  /**
+   * Compare two results kept by executeQuery, matching rows on keyColumns
+   * (or on whole rows when none are given)
+   */
+  const diffResults = useCallback(
+    async (resultA: string, resultB: string, keyColumns?: string[], maxRows?: number): Promise<ResultDiff> => {
+      return await invoke<ResultDiff>("diff_results", { resultA, resultB, keyColumns, maxRows });
+    },
+    []
+  );
+
+  /**
+   * Pretty-print SQL locally, without a connection or the AI provider
+   */
+  const formatSql = useCallback(
+    async (
+      sql: string,
+      databaseType?: DatabaseType,
+      options?: SqlFormatOptions
+    ): Promise<string | null> => {
+      try {
+        return await invoke<string>("format_sql", { sql, databaseType, options });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Check SQL for common problems without running it
+   */
+  const lintSql = useCallback(
+    async (sql: string, databaseType?: DatabaseType): Promise<SqlDiagnostic[]> => {
+      try {
+        return await invoke<SqlDiagnostic[]>("lint_sql", { sql, databaseType });
+      } catch (error) {
+        console.error("Failed to lint SQL:", error);
+        return [];
+      }
+    },
+    []
+  );
+
+  /**
+   * Names of the named placeholders (`:name`, `${name}`) in SQL, in order of first use
+   */
+  const getQueryParameters = useCallback(
+    async (sql: string, databaseType?: DatabaseType): Promise<string[]> => {
+      try {
+        return await invoke<string[]>("get_query_parameters", { sql, databaseType });
+      } catch (error) {
+        console.error("Failed to read query parameters:", error);
+        return [];
+      }
+    },
+    []
+  );
+
+  /**
+   * Get ranked completions for the cursor (1-based line and column) from the
+   * cached schema, without a round trip to the database
+   */
+  const getSqlCompletions = useCallback(
+    async (connectionId: string, sql: string, line: number, column: number): Promise<SqlCompletion[]> => {
+      try {
+        return await invoke<SqlCompletion[]>("get_sql_completions", { connectionId, sql, line, column });
+      } catch (error) {
+        console.error("Failed to get SQL completions:", error);
+        return [];
+      }
+    },
+    []
+  );
+
+  /**
+   * Read recorded data and schema changes, newest first
+   */
+  const getAuditLog = useCallback(
+    async (filter?: AuditLogFilter): Promise<AuditEntry[] | null> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<AuditEntry[]>("get_audit_log", { filter });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Write recorded changes to a file for compliance reviews
+   */
+  const exportAuditLog = useCallback(
+    async (
+      path: string,
+      format: ExportFormat,
+      filter?: AuditLogFilter,
+      options?: ExportOptions
+    ): Promise<ExportSummary | null> => {
+      setQueryError(null);
+
+      try {
+        return await invoke<ExportSummary>("export_audit_log", { path, format, filter, options });
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        setQueryError(message);
+        return null;
+      }
+    },
+    [setQueryError]
+  );
+
+  /**
+   * Search the extension marketplace; pass refresh to download the registry index again
+   */
+  const searchMarketplace = useCallback(
+    async (query?: string, category?: string, refresh = false): Promise<MarketplaceSearchResult | null> => {
+      try {
+        return await invoke<MarketplaceSearchResult>("search_marketplace", { query, category, refresh });
+      } catch (error) {
+        console.error("Failed to search the marketplace:", error);
+        return null;
+      }
+    },
+    []
+  );
+
+  /**
+   * URL of the marketplace registry index in use
+   */
+  const getMarketplaceRegistryUrl = useCallback(async (): Promise<string> => {
+    return await invoke<string>("get_marketplace_registry_url");
+  }, []);
+
+  /**
+   * Use another marketplace registry; null goes back to the default
+   */
+  const setMarketplaceRegistryUrl = useCallback(async (url: string | null): Promise<string> => {
+    return await invoke<string>("set_marketplace_registry_url", { url });
+  }, []);
+
+  /**
+   * Extensions installed in the app
+   */
+  const listInstalledExtensions = useCallback(async (): Promise<InstalledExtension[]> => {
+    try {
+      return await invoke<InstalledExtension[]>("list_installed_extensions");
+    } catch (error) {
+      console.error("Failed to list installed extensions:", error);
+      return [];
+    }
+  }, []);
+
+  /**
+   * Install a `.dbx` package from disk; with sha256 the package must match it
+   */
+  const installExtension = useCallback(
+    async (path: string, sha256?: string): Promise<InstalledExtension> => {
+      return await invoke<InstalledExtension>("install_extension", { path, sha256 });
+    },
+    []
+  );
+
+  /**
+   * Download an extension from the marketplace and install it
+   */
+  const installMarketplaceExtension = useCallback(async (id: string): Promise<InstalledExtension> => {
+    return await invoke<InstalledExtension>("install_marketplace_extension", { id });
+  }, []);
+
+  /**
+   * Remove an installed extension
+   */
+  const uninstallExtension = useCallback(async (id: string): Promise<void> => {
+    await invoke("uninstall_extension", { id });
+  }, []);
+
+  /**
+   * Recover a failed extension: bundled ones run their hooks again, packages
+   * get their worker restarted
+   */
+  const restartExtension = useCallback(async (id: string): Promise<InstalledExtension> => {
+    return await invoke<InstalledExtension>("restart_extension", { id });
+  }, []);
+
+  /**
+   * The last entries of an extension's log, oldest first
+   */
+  const getExtensionLogs = useCallback(async (extensionId: string, tail?: number): Promise<ExtensionLogEntry[]> => {
+    return await invoke<ExtensionLogEntry[]>("get_extension_logs", { extensionId, tail: tail ?? null });
+  }, []);
+
+  /**
+   * Delete an extension's log
+   */
+  const clearExtensionLogs = useCallback(async (extensionId: string): Promise<void> => {
+    await invoke("clear_extension_logs", { extensionId });
+  }, []);
+
+  /**
+   * Recent application log events, optionally only those at a level or above
+   */
+  const getAppLogs = useCallback(async (limit?: number, level?: string): Promise<AppLogs> => {
+    return await invoke<AppLogs>("get_app_logs", { limit: limit ?? null, level: level ?? null });
+  }, []);
+
+  const getLogSettings = useCallback(async (): Promise<LogSettings> => {
+    return await invoke<LogSettings>("get_log_settings");
+  }, []);
+
+  /**
+   * Change the app's log level, or a module's when given; null resets it
+   */
+  const setLogLevel = useCallback(async (level: string | null, module?: string): Promise<LogSettings> => {
+    return await invoke<LogSettings>("set_log_level", { level, module: module ?? null });
+  }, []);
+
+  /**
+   * LISTEN on a PostgreSQL channel; each NOTIFY arrives as a "db-notification"
+   * event. Returns the channels the connection listens on.
+   */
+  const listenChannel = useCallback(async (connectionId: string, channel: string): Promise<string[]> => {
+    return await invoke<string[]>("listen_channel", { connectionId, channel });
+  }, []);
+
+  const unlistenChannel = useCallback(async (connectionId: string, channel: string): Promise<string[]> => {
+    return await invoke<string[]>("unlisten_channel", { connectionId, channel });
+  }, []);
+
+  /**
+   * Emit "table-changed" events when rows of a PostgreSQL table change, until unwatchTable
+   */
+  const watchTable = useCallback(async (connectionId: string, table: string, intervalMs?: number): Promise<void> => {
+    await invoke("watch_table", { connectionId, table, intervalMs: intervalMs ?? null });
+  }, []);
+
+  const unwatchTable = useCallback(async (connectionId: string, table: string): Promise<void> => {
+    await invoke("unwatch_table", { connectionId, table });
+  }, []);
+
+  /**
+   * Query latency, connections, schema cache hits and AI request durations of
+   * this session, measured locally
+   */
+  const getPerformanceMetrics = useCallback(async (): Promise<PerformanceMetrics> => {
+    return await invoke<PerformanceMetrics>("get_performance_metrics");
+  }, []);
+
+  const resetPerformanceMetrics = useCallback(async (): Promise<void> => {
+    await invoke("reset_performance_metrics");
+  }, []);
+
+  /**
+   * Save the open tabs and their layout, to restore on the next start
+   */
+  const saveWorkspace = useCallback(async (workspace: Workspace): Promise<void> => {
+    await invoke("save_workspace", { workspace });
+  }, []);
+
+  /**
+   * The tabs open when the app was last used, or null the first time
+   */
+  const restoreWorkspace = useCallback(async (): Promise<Workspace | null> => {
+    return await invoke<Workspace | null>("restore_workspace");
+  }, []);
+
+  /**
+   * Every saved query with its variables and per-connection presets
+   */
+  const listSavedQueries = useCallback(async (): Promise<SavedQuery[]> => {
+    return await invoke<SavedQuery[]>("list_saved_queries");
+  }, []);
+
+  /**
+   * Add or replace a saved query; placeholders its SQL uses but does not declare become text variables
+   */
+  const saveSavedQuery = useCallback(async (query: SavedQuery): Promise<SavedQuery> => {
+    return await invoke<SavedQuery>("save_saved_query", { query });
+  }, []);
+
+  const deleteSavedQuery = useCallback(async (queryId: string): Promise<void> => {
+    await invoke("delete_saved_query", { queryId });
+  }, []);
+
+  /**
+   * Keep the values a connection runs a saved query with; empty values remove the preset
+   */
+  const setSavedQueryPreset = useCallback(
+    async (queryId: string, connectionId: string, values: Record<string, string>): Promise<SavedQuery> => {
+      return await invoke<SavedQuery>("set_saved_query_preset", { queryId, connectionId, values });
+    },
+    []
+  );
+
+  /**
+   * Typed values for a saved query's variables, to pass as executeQuery's params.
+   * Given values win over the connection's preset, which wins over the defaults.
+   */
+  const getSavedQueryParams = useCallback(
+    async (queryId: string, connectionId?: string, values?: Record<string, string>): Promise<Record<string, CellValue>> => {
+      return await invoke<Record<string, CellValue>>("get_saved_query_params", { queryId, connectionId, values });
+    },
+    []
+  );
+
+  /**
+   * An extension's settings for its settings form; password values are not returned
+   */
+  const getExtensionSettings = useCallback(async (id: string): Promise<ExtensionSettings> => {
+    return await invoke<ExtensionSettings>("get_extension_settings", { id });
+  }, []);
+
+  /**
+   * Change one extension setting; null goes back to the default
+   */
+  const setExtensionSetting = useCallback(async (id: string, key: string, value: unknown): Promise<void> => {
+    await invoke("set_extension_setting", { id, key, value: value ?? null });
+  }, []);
+
+  /**
+   * Commands contributed by the installed extensions
+   */
+  const listExtensionCommands = useCallback(async (): Promise<ExtensionCommandInfo[]> => {
+    try {
+      return await invoke<ExtensionCommandInfo[]>("list_extension_commands");
+    } catch (error) {
+      console.error("Failed to list extension commands:", error);
+      return [];
+    }
+  }, []);
+
+  /**
+   * Run a contributed command; the extension is started if it is not running
+   */
+  const executeExtensionCommand = useCallback(
+    async (commandId: string, args?: unknown): Promise<ExtensionCommandResult> => {
+      return await invoke<ExtensionCommandResult>("execute_extension_command", { commandId, args: args ?? null });
+    },
+    []
+  );
+
+  /**
+   * Bundled themes and those contributed by installed theme extensions
+   */
+  const listThemes = useCallback(async (): Promise<ThemeInfo[]> => {
+    try {
+      return await invoke<ThemeInfo[]>("list_themes");
+    } catch (error) {
+      console.error("Failed to list themes:", error);
+      return [];
+    }
+  }, []);
+
   return {
     testConnection,
     saveConnection,
     connect,
+    switchDatabase,
     disconnect,
     loadConnections,
     getConnection,
+    getConnectionInsights,
     deleteConnection,
+    exportConnections,
+    importConnections,
+    listConnectionTemplates,
+    checkTemplateHost,
     executeQuery,
+    getResultRows,
+    getCellBlob,
+    saveBlobToFile,
+    releaseResult,
+    copyResultsAs,
+    vectorSearch,
+    runSqlFile,
+    getBackupTools,
+    backupDatabase,
+    restoreDatabase,
+    copyTableBetweenConnections,
+    cancelTableCopy,
+    searchDatabaseObjects,
+    searchTableData,
+    prepareChartData,
+    browseTable,
+    getReferencedRow,
+    getReferencingRows,
+    exportQueryResults,
+    previewImportFile,
+    importFileIntoTable,
+    generateMockData,
     getTables,
+    pinObject,
+    listPinned,
+    listDatabases,
+    listSchemas,
+    createDatabase,
+    dropDatabase,
+    createSchema,
+    dropSchema,
     getTableSchema,
     fetchAllSchemas,
     refreshSchemas,
     insertRow,
+    upsertRows,
     updateRow,
     deleteRow,
     dropTable,
     generateTableDdl,
+    generateDatabaseDdl,
     renameTable,
+    truncateTable,
+    duplicateTable,
+    addColumn,
+    dropColumn,
+    renameColumn,
+    alterColumnType,
     getTableProperties,
+    countExactRows,
+    previewSchemaComments,
+    applySchemaComments,
+    getTableStats,
+    getTablePartitions,
     getTableRelationships,
+    generateErModel,
+    getTablePrivileges,
+    getUsersAndRoles,
+    getServerStats,
+    getActiveQueries,
+    killSession,
+    getSlowQueries,
+    startServerMonitor,
+    stopServerMonitor,
+    generateMigration,
+    diffTableData,
+    diffResults,
+    runFederatedQuery,
+    formatSql,
+    lintSql,
+    getQueryParameters,
+    getSqlCompletions,
+    getAuditLog,
+    exportAuditLog,
+    searchMarketplace,
+    getMarketplaceRegistryUrl,
+    setMarketplaceRegistryUrl,
+    listInstalledExtensions,
+    installExtension,
+    installMarketplaceExtension,
+    uninstallExtension,
+    restartExtension,
+    getExtensionLogs,
+    clearExtensionLogs,
+    getAppLogs,
+    getLogSettings,
+    setLogLevel,
+    listenChannel,
+    unlistenChannel,
+    watchTable,
+    unwatchTable,
+    getPerformanceMetrics,
+    resetPerformanceMetrics,
+    saveWorkspace,
+    restoreWorkspace,
+    listSavedQueries,
+    saveSavedQuery,
+    deleteSavedQuery,
+    setSavedQueryPreset,
+    getSavedQueryParams,
+    getExtensionSettings,
+    setExtensionSetting,
+    listExtensionCommands,
+    listThemes,
+    executeExtensionCommand,
   };
 }
 
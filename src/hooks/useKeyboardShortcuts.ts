@@ -1,7 +1,74 @@
 import { useEffect } from "react";
-import { useUIStore, useQueryStore, useConnectionsStore, selectActiveConnection } from "@/stores";
+import { useUIStore, useQueryStore, useConnectionsStore, useKeymapStore, selectActiveConnection } from "@/stores";
 import { useAIStore } from "@/lib/ai/store";
+import { matchesShortcut } from "@/lib/keymap";
+import { showErrorToast } from "@/lib/toast-helpers";
+import { useDatabase } from "./useDatabase";
+
+/**
+ * Open Monaco's find widget in the active query tab, optionally with replace
+ */
+function openEditorFind(e: KeyboardEvent, withReplace: boolean) {
+  const monacoEditor = document.querySelector(".monaco-editor");
+  if (!monacoEditor) return;
+  if (withReplace) e.preventDefault();
+
+  // If the editor doesn't have focus, focus it first
+  if (!monacoEditor.contains(document.activeElement)) {
+    e.preventDefault();
+    const textarea = monacoEditor.querySelector("textarea");
+    if (textarea) {
+      textarea.focus();
+    }
+
+    // Trigger find via a new event
+    const event = new KeyboardEvent("keydown", {
+      key: "f",
+      code: "KeyF",
+      keyCode: 70,
+      metaKey: e.metaKey,
+      ctrlKey: e.ctrlKey,
+      altKey: withReplace,
+      bubbles: true,
+      cancelable: true,
+    });
+    textarea?.dispatchEvent(event);
+  }
+
+  // Robust polling to ensure the find widget opens and its input gets focus
+  let attempts = 0;
+  const focusFindInput = () => {
+    const findWidget = document.querySelector(".monaco-editor .find-widget");
+    if (findWidget) {
+      // Check if replace is expanded, if not, try to click the toggle
+      if (withReplace) {
+        const replaceToggle = findWidget.querySelector(".monaco-button.expand") as HTMLElement;
+        if (replaceToggle && !findWidget.classList.contains("replace-expanded")) {
+          replaceToggle.click();
+        }
+      }
+
+      const findInput = findWidget.querySelector("input, textarea") as HTMLElement;
+      if (findInput) {
+        findInput.focus();
+        if (findInput instanceof HTMLInputElement || findInput instanceof HTMLTextAreaElement) {
+          findInput.select();
+        }
+      }
+    } else if (attempts < 10) {
+      attempts++;
+      setTimeout(focusFindInput, 50);
+    }
+  };
 
+  setTimeout(focusFindInput, 50);
+}
+
+/**
+ * Global keyboard shortcuts, following the keymap resolved by the backend:
+ * the app's own bindings, the user's changes to them, and the shortcuts of
+ * extension commands
+ */
 export function useKeyboardShortcuts() {
   const {
     setShowConnectionModal,
@@ -24,32 +91,48 @@ export function useKeyboardShortcuts() {
 
   const activeConnection = useConnectionsStore(selectActiveConnection);
 
+  const { bindings, loadKeymap } = useKeymapStore();
+  const { executeExtensionCommand } = useDatabase();
+
   const { settings: aiSettings, panelOpen: aiPanelOpen, togglePanel: toggleAIPanel, setPanelOpen: setAIPanelOpen } = useAIStore();
   const isAIEnabled = aiSettings.aiEnabled ?? true;
 
   useEffect(() => {
-    const handleKeyDown = (e: KeyboardEvent) => {
-      const isMod = e.metaKey || e.ctrlKey;
-      const isShift = e.shiftKey;
-      const isAlt = e.altKey;
+    loadKeymap().catch((error) => console.error("[Keymap] Failed to load keyboard shortcuts:", error));
+  }, [loadKeymap]);
 
-      // Close all modals on Escape
-      if (e.key === "Escape") {
+  useEffect(() => {
+    const isQueryTabActive = () => tabs.find((t) => t.id === activeTabId)?.type === "query";
+
+    const toggleAI = (e: KeyboardEvent) => {
+      e.preventDefault();
+      if (isAIEnabled) {
+        toggleAIPanel();
+      }
+    };
+
+    const scrollResults = (e: KeyboardEvent, scrollAmount: number) => {
+      const resultsArea = document.querySelector(".overflow-auto");
+      if (resultsArea) {
+        e.preventDefault();
+        resultsArea.scrollBy({ top: scrollAmount, behavior: "smooth" });
+      }
+    };
+
+    // What each built-in command does; the editor handles the rest itself
+    const actions: Record<string, (e: KeyboardEvent) => void> = {
+      // Close all modals
+      "app.closeDialogs": () => {
         if (showConnectionModal) setShowConnectionModal(false);
         if (showSettingsDialog) setShowSettingsDialog(false);
         if (showDiffModal) setShowDiffModal(false);
         if (aiPanelOpen) setAIPanelOpen(false);
-        return;
-      }
-
-      // Mod + K: New Connection
-      if (isMod && e.key.toLowerCase() === "k") {
+      },
+      "app.newConnection": (e) => {
         e.preventDefault();
         setShowConnectionModal(true);
-      }
-
-      // Mod + T: New Query Tab
-      if (isMod && e.key.toLowerCase() === "t") {
+      },
+      "tabs.newQuery": (e) => {
         e.preventDefault();
         if (activeConnection) {
           addTab({
@@ -60,175 +143,48 @@ export function useKeyboardShortcuts() {
             content: "",
           });
         }
-      }
-
-      // Mod + W: Close Current Tab
-      if (isMod && e.key.toLowerCase() === "w") {
+      },
+      "tabs.close": (e) => {
         if (activeTabId) {
           e.preventDefault();
           removeTab(activeTabId);
         }
-      }
-
-      // Mod + ,: Open Settings
-      if (isMod && e.key === ",") {
+      },
+      "app.openSettings": (e) => {
         e.preventDefault();
         setShowSettingsDialog(true);
-      }
-
-      // Mod + B: Toggle Sidebar
-      if (isMod && e.key.toLowerCase() === "b") {
+      },
+      "app.toggleSidebar": (e) => {
         e.preventDefault();
         toggleSidebar();
-      }
-
-      // Mod + P: Toggle AI Assistant
-      if (isMod && e.key.toLowerCase() === "p") {
-        e.preventDefault();
-        if (isAIEnabled) {
-          toggleAIPanel();
-        }
-      }
-
-      // Mod + Alt + B or Mod + Shift + A: Toggle AI Assistant (legacy)
-      if ((isMod && isAlt && e.key.toLowerCase() === "b") || (isMod && isShift && e.key.toLowerCase() === "a")) {
-        e.preventDefault();
-        if (isAIEnabled) {
-          toggleAIPanel();
-        }
-      }
-
-      // Mod + Shift + D: View Changes Diff
-      if (isMod && isShift && e.key.toLowerCase() === "d") {
+      },
+      "ai.togglePanel": toggleAI,
+      "ai.togglePanelQuick": toggleAI,
+      "ai.togglePanelLegacy": toggleAI,
+      "changes.showDiff": (e) => {
         e.preventDefault();
         setShowDiffModal(true);
-      }
-
-      // Mod + Alt + F: Focus Find and Replace in Editor
-      if (isMod && isAlt && e.key.toLowerCase() === "f") {
-        const activeTab = tabs.find(t => t.id === activeTabId);
-        if (activeTab && activeTab.type === "query") {
-          const monacoEditor = document.querySelector(".monaco-editor");
-          if (monacoEditor) {
-            e.preventDefault();
-            // If the editor doesn't have focus, focus it first
-            if (!monacoEditor.contains(document.activeElement)) {
-              const textarea = monacoEditor.querySelector("textarea");
-              if (textarea) {
-                textarea.focus();
-              }
-              
-              // Trigger find/replace via a new event
-              const event = new KeyboardEvent("keydown", {
-                key: "f",
-                code: "KeyF",
-                keyCode: 70,
-                metaKey: e.metaKey,
-                ctrlKey: e.ctrlKey,
-                altKey: true,
-                bubbles: true,
-                cancelable: true
-              });
-              textarea?.dispatchEvent(event);
-            }
-
-            // Robust polling to ensure the find widget opens with replace
-            let attempts = 0;
-            const focusReplaceInput = () => {
-              const findWidget = document.querySelector(".monaco-editor .find-widget");
-              if (findWidget) {
-                // Check if replace is expanded, if not, try to click the toggle
-                const replaceToggle = findWidget.querySelector(".monaco-button.expand") as HTMLElement;
-                if (replaceToggle && !findWidget.classList.contains("replace-expanded")) {
-                  replaceToggle.click();
-                }
-
-                const inputs = findWidget.querySelectorAll("input, textarea");
-                const findInput = inputs[0] as HTMLElement;
-
-                if (findInput) {
-                  findInput.focus();
-                  if (findInput instanceof HTMLInputElement || findInput instanceof HTMLTextAreaElement) {
-                    findInput.select();
-                  }
-                }
-              } else if (attempts < 10) {
-                attempts++;
-                setTimeout(focusReplaceInput, 50);
-              }
-            };
-            
-            setTimeout(focusReplaceInput, 50);
-          }
-        }
-      }
-
-      // Mod + F: Focus Find in Editor
-      if (isMod && !isShift && !isAlt && e.key.toLowerCase() === "f") {
-        const activeTab = tabs.find(t => t.id === activeTabId);
-        if (activeTab && activeTab.type === "query") {
-          const monacoEditor = document.querySelector(".monaco-editor");
-          if (monacoEditor) {
-            // If the editor doesn't have focus, focus it first
-            if (!monacoEditor.contains(document.activeElement)) {
-              e.preventDefault();
-              const textarea = monacoEditor.querySelector("textarea");
-              if (textarea) {
-                textarea.focus();
-              }
-              
-              // Trigger find via a new event
-              const event = new KeyboardEvent("keydown", {
-                key: "f",
-                code: "KeyF",
-                keyCode: 70,
-                metaKey: e.metaKey,
-                ctrlKey: e.ctrlKey,
-                bubbles: true,
-                cancelable: true
-              });
-              textarea?.dispatchEvent(event);
-            }
-
-            // Robust polling to ensure the find widget input gets focus
-            let attempts = 0;
-            const focusFindInput = () => {
-              const findWidget = document.querySelector(".monaco-editor .find-widget");
-              const findInput = findWidget?.querySelector("input, textarea") as HTMLElement;
-              
-              if (findInput) {
-                findInput.focus();
-                if (findInput instanceof HTMLInputElement || findInput instanceof HTMLTextAreaElement) {
-                  findInput.select();
-                }
-              } else if (attempts < 10) {
-                attempts++;
-                setTimeout(focusFindInput, 50);
-              }
-            };
-            
-            setTimeout(focusFindInput, 50);
-          }
-        }
-      }
-
-      // Mod + Z: Undo Pending Change
-      if (isMod && !isShift && e.key.toLowerCase() === "z") {
+      },
+      "editor.findReplace": (e) => {
+        if (isQueryTabActive()) openEditorFind(e, true);
+      },
+      "editor.find": (e) => {
+        if (isQueryTabActive()) openEditorFind(e, false);
+      },
+      // Undo the last pending change
+      "changes.undo": (e) => {
         if (pendingChanges.length > 0) {
           e.preventDefault();
           const lastChange = pendingChanges[pendingChanges.length - 1];
           removePendingChange(lastChange.id);
         }
-      }
-
-      // F1: Open Help (Settings -> About or just Settings)
-      if (e.key === "F1") {
+      },
+      // Open help (Settings -> About or just Settings)
+      "app.help": (e) => {
         e.preventDefault();
         setShowSettingsDialog(true);
-      }
-
-      // F11: Toggle Fullscreen
-      if (e.key === "F11") {
+      },
+      "app.toggleFullscreen": (e) => {
         e.preventDefault();
         if (!document.fullscreenElement) {
           document.documentElement.requestFullscreen().catch((err) => {
@@ -237,29 +193,37 @@ export function useKeyboardShortcuts() {
         } else {
           document.exitFullscreen();
         }
-      }
+      },
+      "results.scrollUp": (e) => scrollResults(e, -40),
+      "results.scrollDown": (e) => scrollResults(e, 40),
+    };
 
-      // Alt + Up/Down: Navigate Results (Scroll)
-      if (isAlt && (e.key === "ArrowUp" || e.key === "ArrowDown")) {
-        const resultsArea = document.querySelector(".overflow-auto");
-        if (resultsArea) {
-          e.preventDefault();
-          const scrollAmount = e.key === "ArrowUp" ? -40 : 40;
-          resultsArea.scrollBy({ top: scrollAmount, behavior: "smooth" });
-        }
+    const handleKeyDown = (e: KeyboardEvent) => {
+      const binding = bindings.find((b) => b.shortcut && matchesShortcut(e, b.shortcut));
+      if (!binding) return;
+
+      if (binding.source === "extension") {
+        e.preventDefault();
+        executeExtensionCommand(binding.commandId).catch((error) =>
+          showErrorToast(binding.title, error instanceof Error ? error.message : String(error))
+        );
+        return;
       }
+      actions[binding.commandId]?.(e);
     };
 
     window.addEventListener("keydown", handleKeyDown);
     return () => window.removeEventListener("keydown", handleKeyDown);
   }, [
+    bindings,
+    executeExtensionCommand,
     showConnectionModal,
     showSettingsDialog,
     showDiffModal,
     aiPanelOpen,
     isAIEnabled,
     activeConnection,
-    tabs.length,
+    tabs,
     activeTabId,
     pendingChanges,
     setShowConnectionModal,
@@ -273,4 +237,3 @@ export function useKeyboardShortcuts() {
     removePendingChange,
   ]);
 }
-
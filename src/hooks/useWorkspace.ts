@@ -0,0 +1,55 @@
+import { useEffect } from "react";
+import { useConnectionsStore, useQueryStore } from "@/stores";
+import { useDatabase } from "./useDatabase";
+
+/** Changes are saved once tabs stay untouched for this long */
+const SAVE_DELAY_MS = 1000;
+
+/**
+ * Restore the tabs open when the app was last used, then save them as they
+ * change: their SQL, connection, result pane size and scroll position
+ */
+export function useWorkspacePersistence() {
+  const { saveWorkspace, restoreWorkspace } = useDatabase();
+
+  useEffect(() => {
+    let restored = false;
+    let timer: ReturnType<typeof setTimeout> | undefined;
+
+    const save = () => {
+      clearTimeout(timer);
+      const { tabs, activeTabId } = useQueryStore.getState();
+      const { activeConnectionId } = useConnectionsStore.getState();
+      saveWorkspace({ tabs, activeTabId, activeConnectionId }).catch((error) =>
+        console.error("[Workspace] Failed to save the open tabs:", error)
+      );
+    };
+
+    restoreWorkspace()
+      .then((workspace) => {
+        // Tabs opened before the workspace came back win
+        if (!workspace || useQueryStore.getState().tabs.length > 0) return;
+        useQueryStore.getState().restoreTabs(workspace.tabs, workspace.activeTabId);
+        if (workspace.activeConnectionId && !useConnectionsStore.getState().activeConnectionId) {
+          useConnectionsStore.getState().setActiveConnection(workspace.activeConnectionId);
+        }
+      })
+      .catch((error) => console.error("[Workspace] Failed to restore the open tabs:", error))
+      .finally(() => {
+        restored = true;
+      });
+
+    const unsubscribe = useQueryStore.subscribe((state, previous) => {
+      if (!restored || (state.tabs === previous.tabs && state.activeTabId === previous.activeTabId)) return;
+      clearTimeout(timer);
+      timer = setTimeout(save, SAVE_DELAY_MS);
+    });
+    window.addEventListener("beforeunload", save);
+
+    return () => {
+      unsubscribe();
+      window.removeEventListener("beforeunload", save);
+      clearTimeout(timer);
+    };
+  }, [saveWorkspace, restoreWorkspace]);
+}
@@ -4,12 +4,17 @@
  * Direct AI SDK implementation with streaming, token tracking, and error recovery.
  */
 
-import { generateText, streamText } from "ai";
-import { getProviderModel } from "./providers";
+import { APICallError, generateText, jsonSchema, NoObjectGeneratedError, Output, streamText } from "ai";
+import { contentFilteredError, describeProviderError, getProviderModel, hasApiKey } from "./providers";
 import {
   sqlGenerationPrompt,
   queryExplanationPrompt,
   optimizationPrompt,
+  errorExplanationPrompt,
+  indexAdvisorPrompt,
+  indexAdvisorInput,
+  schemaDocsPrompt,
+  schemaDocsInput,
   type QueryContext,
 } from "./prompts";
 import type {
@@ -17,6 +22,13 @@ import type {
   GeneratedSQL,
   ExplainQueryRequest,
   QueryExplanation,
+  ExplainErrorRequest,
+  ErrorExplanation,
+  AdviseIndexesRequest,
+  IndexAdvice,
+  IndexSuggestion,
+  GenerateSchemaDocsRequest,
+  SchemaDocs,
   AIChatRequest,
   AISettings,
   AIModelsConfig,
@@ -25,8 +37,14 @@ import type {
   QueryVariant,
   QueryDiff,
   AIChatMessage,
+  AIProviderType,
+  ServedBy,
 } from "./types";
-import { AVAILABLE_MODELS, MODEL_PRICING } from "./types";
+import { AVAILABLE_MODELS } from "./types";
+import type { ColumnComment, TableProperties } from "@/types";
+import { checkBudget, recordUsage, toTokenUsage } from "./usage";
+
+export { calculateCost } from "./usage";
 
 /** Maximum retry attempts for failed requests */
 const MAX_RETRIES = 3;
@@ -49,6 +67,189 @@ function parseSQLFromResponse(text: string): string {
   return text.trim();
 }
 
+/** Structured answer for SQL generation */
+const generatedSQLSchema = jsonSchema<GeneratedSQL>({
+  type: "object",
+  properties: {
+    sql: { type: "string", description: "The SQL query only, without markdown fences" },
+    explanation: { type: "string", description: "One or two sentences on how the query answers the request" },
+    confidence: {
+      type: "number",
+      minimum: 0,
+      maximum: 1,
+      description: "How sure you are that the query is correct for the request and schema, from 0 to 1",
+    },
+  },
+  required: ["sql", "explanation", "confidence"],
+  additionalProperties: false,
+});
+
+/** Structured answer for query explanations */
+const queryExplanationSchema = jsonSchema<QueryExplanation>({
+  type: "object",
+  properties: {
+    summary: { type: "string", description: "One sentence on what the query does" },
+    steps: { type: "array", items: { type: "string" }, description: "The query's operations, in execution order" },
+    warnings: {
+      type: "array",
+      items: { type: "string" },
+      description: "Performance problems, edge cases and other concerns; empty when there are none",
+    },
+  },
+  required: ["summary", "steps", "warnings"],
+  additionalProperties: false,
+});
+
+/** Structured answer for query optimization */
+const queryOptimizationSchema = jsonSchema<{ optimizedSql: string; changes: string[] }>({
+  type: "object",
+  properties: {
+    optimizedSql: {
+      type: "string",
+      description: "The rewritten query without markdown fences, or the original query if it cannot be improved",
+    },
+    changes: { type: "array", items: { type: "string" }, description: "Each change made, or index to add" },
+  },
+  required: ["optimizedSql", "changes"],
+  additionalProperties: false,
+});
+
+/** Structured answer for failed query explanations */
+const errorExplanationSchema = jsonSchema<ErrorExplanation>({
+  type: "object",
+  properties: {
+    cause: { type: "string", description: "Short name of the problem, such as \"Unknown column\"" },
+    explanation: { type: "string", description: "Why the query failed, in one or two sentences" },
+    correctedSql: {
+      type: "string",
+      description: "The fixed query without markdown fences, or an empty string if it cannot be fixed",
+    },
+  },
+  required: ["cause", "explanation", "correctedSql"],
+  additionalProperties: false,
+});
+
+/** Structured answer for index suggestions */
+const indexAdviceSchema = jsonSchema<{ summary: string; suggestions: IndexSuggestion[] }>({
+  type: "object",
+  properties: {
+    summary: { type: "string", description: "What the plan shows, in a sentence or two" },
+    suggestions: {
+      type: "array",
+      items: {
+        type: "object",
+        properties: {
+          statement: { type: "string", description: "Complete CREATE INDEX statement without markdown fences" },
+          table: { type: "string" },
+          columns: { type: "array", items: { type: "string" }, description: "Indexed columns in order" },
+          impact: { type: "string", enum: ["high", "medium", "low"] },
+          estimatedImprovement: { type: "string", description: "What should change in the plan" },
+          reason: { type: "string", description: "The part of the plan this index addresses" },
+        },
+        required: ["statement", "table", "columns", "impact", "estimatedImprovement", "reason"],
+        additionalProperties: false,
+      },
+    },
+  },
+  required: ["summary", "suggestions"],
+  additionalProperties: false,
+});
+
+const schemaDocsSchema = jsonSchema<{ tableComment: string; columns: ColumnComment[] }>({
+  type: "object",
+  properties: {
+    tableComment: { type: "string", description: "What the table holds, in one sentence" },
+    columns: {
+      type: "array",
+      items: {
+        type: "object",
+        properties: {
+          column: { type: "string", description: "Exact column name" },
+          comment: { type: "string", description: "What the column holds, in one sentence" },
+        },
+        required: ["column", "comment"],
+        additionalProperties: false,
+      },
+    },
+  },
+  required: ["tableComment", "columns"],
+  additionalProperties: false,
+});
+
+/**
+ * Free text from a model that did not return valid structured output, so the
+ * caller can fall back to parsing it
+ */
+function unstructuredText(
+  error: unknown,
+  track: ReturnType<typeof usageTracker>,
+  provider: AIProviderType
+): string {
+  if (NoObjectGeneratedError.isInstance(error) && error.finishReason === "content-filter") {
+    track(error.usage);
+    throw contentFilteredError(provider);
+  }
+  if (NoObjectGeneratedError.isInstance(error) && error.text) {
+    track(error.usage);
+    console.warn("[AI API] Structured output failed, parsing text instead:", error.message);
+    return error.text;
+  }
+  throw error;
+}
+
+/**
+ * Parse an explanation written in the explanation prompt's markdown format
+ */
+function parseExplanationText(text: string): QueryExplanation {
+  const summaryMatch = text.match(/\*\*Summary:\*\*\s*(.+?)(?=\n\*\*|$)/s);
+  const stepsMatch = text.match(
+    /\*\*Step-by-step breakdown:\*\*\s*([\s\S]*?)(?=\n\*\*|$)/
+  );
+  const warningsMatch = text.match(
+    /\*\*Potential issues:\*\*\s*([\s\S]*?)(?=\n\*\*|$)/
+  );
+
+  const steps = stepsMatch
+    ? stepsMatch[1]
+        .split(/\n\d+\.\s+/)
+        .filter((s) => s.trim())
+        .map((s) => s.trim())
+    : [];
+
+  const warnings = warningsMatch
+    ? warningsMatch[1]
+        .split(/\n[-*]\s+/)
+        .filter((s) => s.trim())
+        .map((s) => s.trim())
+    : [];
+
+  return {
+    summary: summaryMatch ? summaryMatch[1].trim() : text.split("\n")[0],
+    steps,
+    warnings,
+  };
+}
+
+/**
+ * Parse an optimization answer with a CHANGES list and a SQL code block
+ */
+function parseOptimizationText(text: string): { optimizedSql: string; changes: string[] } {
+  const changesMatch = text.match(/CHANGES:\s*([\s\S]*?)(?=\n\n|```|$)/i);
+  const changes: string[] = [];
+
+  if (changesMatch) {
+    const changeLines = changesMatch[1].split(/\n[-*]\s*/);
+    changeLines.forEach(line => {
+      const trimmed = line.trim();
+      if (trimmed && !trimmed.startsWith("CHANGES")) {
+        changes.push(trimmed);
+      }
+    });
+  }
+
+  return { optimizedSql: parseSQLFromResponse(text), changes };
+}
+
 /**
  * Build query context from request
  */
@@ -64,15 +265,16 @@ function buildQueryContext(request: GenerateSQLRequest): QueryContext {
 }
 
 /**
- * Calculate cost estimate from token usage
+ * Records a request's token usage against the model that served it, timed
+ * from when the tracker is created
  */
-export function calculateCost(usage: TokenUsage, modelId: string): number {
-  const pricing = MODEL_PRICING[modelId];
-  if (!pricing) return 0;
-
-  const inputCost = (usage.promptTokens / 1_000_000) * pricing.inputPer1M;
-  const outputCost = (usage.completionTokens / 1_000_000) * pricing.outputPer1M;
-  return inputCost + outputCost;
+function usageTracker(settings: AISettings, model: { modelId: string }, operation: string) {
+  const startedAt = performance.now();
+  return (usage: { inputTokens?: number; outputTokens?: number } | undefined) => {
+    if (usage) {
+      void recordUsage(settings, model.modelId, operation, toTokenUsage(usage), performance.now() - startedAt);
+    }
+  };
 }
 
 /**
@@ -86,9 +288,12 @@ function sleep(ms: number): Promise<void> {
  * Check if an error is retryable
  */
 function isRetryableError(error: unknown): boolean {
+  if (APICallError.isInstance(error) && error.statusCode !== undefined) {
+    return error.isRetryable || error.statusCode === 429 || error.statusCode >= 500;
+  }
   if (error instanceof Error) {
     const message = error.message.toLowerCase();
-    // Rate limits, timeouts, and temporary server errors are retryable
+    // Rate limits, timeouts, temporary server errors and dropped connections are retryable
     return (
       message.includes("rate limit") ||
       message.includes("429") ||
@@ -96,7 +301,11 @@ function isRetryableError(error: unknown): boolean {
       message.includes("503") ||
       message.includes("502") ||
       message.includes("500") ||
-      message.includes("overloaded")
+      message.includes("overloaded") ||
+      message.includes("fetch failed") ||
+      message.includes("network") ||
+      message.includes("econnreset") ||
+      message.includes("econnrefused")
     );
   }
   return false;
@@ -130,6 +339,52 @@ async function withRetry<T>(
   throw lastError;
 }
 
+type ProviderModel = ReturnType<typeof getProviderModel>;
+
+/**
+ * Settings for each provider to try in turn: the configured one, then the
+ * fallback provider if one is set and has an API key
+ */
+function providerCandidates(settings: AISettings): AISettings[] {
+  const fallback = settings.aiFallbackProvider;
+  if (!fallback || fallback === settings.aiProvider || !hasApiKey(settings, fallback)) {
+    return [settings];
+  }
+  return [settings, { ...settings, aiProvider: fallback }];
+}
+
+/**
+ * Execute with retry logic on the configured provider, then on the fallback
+ * provider if the first still fails with a transient error. `fn` gets the
+ * model and the settings of the provider being tried.
+ */
+async function withFailover<T>(
+  settings: AISettings,
+  fn: (model: ProviderModel, settings: AISettings) => Promise<T>
+): Promise<{ value: T; servedBy: ServedBy }> {
+  const candidates = providerCandidates(settings);
+
+  for (let index = 0; ; index++) {
+    const candidate = candidates[index];
+    try {
+      const model = getProviderModel(candidate);
+      const value = await withRetry(() => fn(model, candidate));
+      return {
+        value,
+        servedBy: { provider: candidate.aiProvider, model: model.modelId, fallback: index > 0 },
+      };
+    } catch (error) {
+      if (index === candidates.length - 1 || !isRetryableError(error)) {
+        throw describeProviderError(error);
+      }
+      console.warn(
+        `[AI API] ${candidate.aiProvider} failed, falling back to ${candidates[index + 1].aiProvider}:`,
+        error
+      );
+    }
+  }
+}
+
 /**
  * Generate suggested follow-up actions based on context and response
  */
@@ -210,30 +465,46 @@ export async function aiGenerateSQL(
 ): Promise<GeneratedSQL> {
   console.log("[AI API] aiGenerateSQL request:", JSON.stringify(request, null, 2));
 
-  return withRetry(async () => {
-    const model = getProviderModel(settings);
-    const context = buildQueryContext(request);
-    const systemPrompt = sqlGenerationPrompt(context);
+  await checkBudget(settings);
 
-    const { text, usage } = await generateText({
-      model,
-      system: systemPrompt,
-      prompt: request.prompt,
-      temperature: settings.aiTemperature ?? 0.3,
-      maxOutputTokens: settings.aiMaxTokens ?? 2048,
-    });
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "generate_sql");
+    const context = buildQueryContext(request);
+    const systemPrompt = sqlGenerationPrompt(context) + `
+RESPONSE FORMAT:
+Put only the SQL in the sql field. Explain it briefly in the explanation field instead.
+`;
 
-    console.log("[AI API] aiGenerateSQL response:", text);
-    console.log("[AI API] Token usage:", usage);
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: systemPrompt,
+        prompt: request.prompt,
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: generatedSQLSchema }),
+      });
 
-    const sql = parseSQLFromResponse(text);
+      console.log("[AI API] aiGenerateSQL response:", output);
+      console.log("[AI API] Token usage:", usage);
+      track(usage);
 
-    return {
-      sql,
-      explanation: undefined,
-      confidence: 0.9,
-    };
+      return {
+        sql: parseSQLFromResponse(output.sql),
+        explanation: output.explanation?.trim() || undefined,
+        confidence: Math.min(Math.max(output.confidence, 0), 1),
+      };
+    } catch (error) {
+      // Without structured output there is no self-assessed confidence
+      return {
+        sql: parseSQLFromResponse(unstructuredText(error, track, settings.aiProvider)),
+        explanation: undefined,
+        confidence: 0.5,
+      };
+    }
   });
+
+  return { ...value, servedBy };
 }
 
 /**
@@ -245,53 +516,35 @@ export async function aiExplainQuery(
 ): Promise<QueryExplanation> {
   console.log("[AI API] aiExplainQuery request:", JSON.stringify(request, null, 2));
 
-  return withRetry(async () => {
-    const model = getProviderModel(settings);
+  await checkBudget(settings);
+
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "explain_query");
     const context: QueryContext = {
       databaseType: request.databaseType,
       tables: [],
     };
     const systemPrompt = queryExplanationPrompt(context);
 
-    const { text } = await generateText({
-      model,
-      system: systemPrompt,
-      prompt: `Please explain the following SQL query:\n\n${request.sql}`,
-      temperature: settings.aiTemperature ?? 0.3,
-      maxOutputTokens: settings.aiMaxTokens ?? 2048,
-    });
-
-    console.log("[AI API] aiExplainQuery response:", text);
-
-    // Parse the structured response
-    const summaryMatch = text.match(/\*\*Summary:\*\*\s*(.+?)(?=\n\*\*|$)/s);
-    const stepsMatch = text.match(
-      /\*\*Step-by-step breakdown:\*\*\s*([\s\S]*?)(?=\n\*\*|$)/
-    );
-    const warningsMatch = text.match(
-      /\*\*Potential issues:\*\*\s*([\s\S]*?)(?=\n\*\*|$)/
-    );
-
-    const steps = stepsMatch
-      ? stepsMatch[1]
-          .split(/\n\d+\.\s+/)
-          .filter((s) => s.trim())
-          .map((s) => s.trim())
-      : [];
-
-    const warnings = warningsMatch
-      ? warningsMatch[1]
-          .split(/\n[-*]\s+/)
-          .filter((s) => s.trim())
-          .map((s) => s.trim())
-      : [];
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: systemPrompt,
+        prompt: `Please explain the following SQL query:\n\n${request.sql}`,
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: queryExplanationSchema }),
+      });
 
-    return {
-      summary: summaryMatch ? summaryMatch[1].trim() : text.split("\n")[0],
-      steps,
-      warnings,
-    };
+      console.log("[AI API] aiExplainQuery response:", output);
+      track(usage);
+      return output;
+    } catch (error) {
+      return parseExplanationText(unstructuredText(error, track, settings.aiProvider));
+    }
   });
+
+  return { ...value, servedBy };
 }
 
 /**
@@ -313,23 +566,25 @@ export interface StreamingChatResponse {
   suggestedActions?: SuggestedAction[];
   queryVariants?: QueryVariant[];
   queryDiff?: QueryDiff;
+  servedBy?: ServedBy;
 }
 
 /**
  * Chat with AI assistant - streaming version
- * Returns an async generator that yields partial responses
+ * Returns an async generator that yields partial responses. A stream that
+ * fails before any text arrives is retried on the fallback provider.
  */
 export async function* aiChatStream(
   request: AIChatRequest,
   messages: AIChatMessage[],
   settings: AISettings
-): AsyncGenerator<{ text: string; done: boolean; usage?: TokenUsage }> {
+): AsyncGenerator<{ text: string; done: boolean; usage?: TokenUsage; servedBy?: ServedBy }> {
   console.log("[AI API] aiChatStream request:", JSON.stringify(request, null, 2));
   console.log("[AI API] Message history length:", messages.length);
 
   const { chatPrompt } = await import("./prompts");
 
-  const model = getProviderModel(settings);
+  await checkBudget(settings);
   const context: QueryContext = request.context
     ? buildQueryContext(request.context)
     : { tables: [] };
@@ -338,30 +593,49 @@ export async function* aiChatStream(
   // Convert message history to AI SDK format
   const coreMessages = convertToCoreMessages(messages);
 
-  const result = streamText({
-    model,
-    system: systemPrompt,
-    messages: coreMessages,
-    temperature: settings.aiTemperature ?? 0.3,
-    maxOutputTokens: settings.aiMaxTokens ?? 2048,
-  });
+  const candidates = providerCandidates(settings);
+  for (let index = 0; index < candidates.length; index++) {
+    const candidate = candidates[index];
+    let fullText = "";
 
-  let fullText = "";
+    try {
+      const model = getProviderModel(candidate);
+      const track = usageTracker(candidate, model, "chat");
+      const result = streamText({
+        model,
+        system: systemPrompt,
+        messages: coreMessages,
+        temperature: candidate.aiTemperature ?? 0.3,
+        maxOutputTokens: candidate.aiMaxTokens ?? 2048,
+      });
 
-  for await (const chunk of result.textStream) {
-    fullText += chunk;
-    yield { text: fullText, done: false };
-  }
+      for await (const chunk of result.textStream) {
+        fullText += chunk;
+        yield { text: fullText, done: false };
+      }
 
-  // Get final usage after stream completes
-  const usage = await result.usage;
-  const tokenUsage: TokenUsage | undefined = usage ? {
-    promptTokens: usage.inputTokens ?? 0,
-    completionTokens: usage.outputTokens ?? 0,
-    totalTokens: (usage.inputTokens ?? 0) + (usage.outputTokens ?? 0),
-  } : undefined;
+      // Get final usage after stream completes
+      const usage = await result.usage;
+      const tokenUsage: TokenUsage | undefined = usage ? toTokenUsage(usage) : undefined;
+      track(usage);
+      if (!fullText && (await result.finishReason) === "content-filter") {
+        throw contentFilteredError(candidate.aiProvider);
+      }
 
-  yield { text: fullText, done: true, usage: tokenUsage };
+      const servedBy: ServedBy = { provider: candidate.aiProvider, model: model.modelId, fallback: index > 0 };
+      yield { text: fullText, done: true, usage: tokenUsage, servedBy };
+      return;
+    } catch (error) {
+      // Text already shown cannot be taken back, so only a stream that produced nothing fails over
+      if (fullText || index === candidates.length - 1 || !isRetryableError(error)) {
+        throw describeProviderError(error);
+      }
+      console.warn(
+        `[AI API] ${candidate.aiProvider} failed, falling back to ${candidates[index + 1].aiProvider}:`,
+        error
+      );
+    }
+  }
 }
 
 /**
@@ -375,10 +649,12 @@ export async function aiChat(
   console.log("[AI API] aiChat request:", JSON.stringify(request, null, 2));
   console.log("[AI API] Message history length:", messages.length);
 
-  return withRetry(async () => {
+  await checkBudget(settings);
+
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
     const { chatPrompt } = await import("./prompts");
 
-    const model = getProviderModel(settings);
+    const track = usageTracker(settings, model, "chat");
     const context: QueryContext = request.context
       ? buildQueryContext(request.context)
       : { tables: [] };
@@ -387,7 +663,7 @@ export async function aiChat(
     // Convert message history to AI SDK format
     const coreMessages = convertToCoreMessages(messages);
 
-    const { text, usage } = await generateText({
+    const { text, usage, finishReason } = await generateText({
       model,
       system: systemPrompt,
       messages: coreMessages,
@@ -396,6 +672,10 @@ export async function aiChat(
     });
 
     console.log("[AI API] aiChat response:", text);
+    if (finishReason === "content-filter" && !text) {
+      track(usage);
+      throw contentFilteredError(settings.aiProvider);
+    }
 
     // Check if the response looks like SQL
     const sql = parseSQLFromResponse(text);
@@ -404,11 +684,8 @@ export async function aiChat(
         sql
       );
 
-    const tokenUsage: TokenUsage | undefined = usage ? {
-      promptTokens: usage.inputTokens ?? 0,
-      completionTokens: usage.outputTokens ?? 0,
-      totalTokens: (usage.inputTokens ?? 0) + (usage.outputTokens ?? 0),
-    } : undefined;
+    const tokenUsage: TokenUsage | undefined = usage ? toTokenUsage(usage) : undefined;
+    track(usage);
 
     // Generate suggested follow-up actions
     const suggestedActions = generateSuggestedActions(
@@ -437,6 +714,8 @@ export async function aiChat(
       suggestedActions,
     };
   });
+
+  return { ...value, servedBy };
 }
 
 /**
@@ -449,8 +728,10 @@ export async function aiGenerateMultipleQueries(
 ): Promise<QueryVariant[]> {
   console.log("[AI API] aiGenerateMultipleQueries request:", JSON.stringify(request, null, 2));
 
-  return withRetry(async () => {
-    const model = getProviderModel(settings);
+  await checkBudget(settings);
+
+  const { value } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "generate_variants");
     const context = buildQueryContext(request);
     const systemPrompt = sqlGenerationPrompt(context) + `
 
@@ -474,7 +755,7 @@ SQL:
 ...and so on.
 `;
 
-    const { text } = await generateText({
+    const { text, usage } = await generateText({
       model,
       system: systemPrompt,
       prompt: request.prompt,
@@ -483,6 +764,7 @@ SQL:
     });
 
     console.log("[AI API] aiGenerateMultipleQueries response:", text);
+    track(usage);
 
     // Parse variants from response
     const variants: QueryVariant[] = [];
@@ -512,6 +794,8 @@ SQL:
 
     return variants.slice(0, count);
   });
+
+  return value;
 }
 
 /**
@@ -524,8 +808,10 @@ export async function aiOptimizeQuery(
 ): Promise<QueryDiff> {
   console.log("[AI API] aiOptimizeQuery request:", sql);
 
-  return withRetry(async () => {
-    const model = getProviderModel(settings);
+  await checkBudget(settings);
+
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "optimize_query");
     const context: QueryContext = {
       databaseType,
       tables: [],
@@ -543,39 +829,259 @@ CHANGES:
 Then provide the optimized query in a code block.
 `;
 
-    const { text } = await generateText({
-      model,
-      system: systemPrompt,
-      prompt: `Please optimize the following SQL query:\n\n\`\`\`sql\n${sql}\n\`\`\``,
-      temperature: settings.aiTemperature ?? 0.3,
-      maxOutputTokens: settings.aiMaxTokens ?? 2048,
-    });
+    let result: { optimizedSql: string; changes: string[] };
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: systemPrompt,
+        prompt: `Please optimize the following SQL query:\n\n\`\`\`sql\n${sql}\n\`\`\``,
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: queryOptimizationSchema }),
+      });
 
-    console.log("[AI API] aiOptimizeQuery response:", text);
+      console.log("[AI API] aiOptimizeQuery response:", output);
+      track(usage);
+      result = { optimizedSql: parseSQLFromResponse(output.optimizedSql), changes: output.changes };
+    } catch (error) {
+      result = parseOptimizationText(unstructuredText(error, track, settings.aiProvider));
+    }
 
-    // Extract optimized SQL
-    const optimizedSQL = parseSQLFromResponse(text);
+    return {
+      original: sql,
+      optimized: result.optimizedSql,
+      changes: result.changes.length > 0 ? result.changes : ["Query structure optimized"],
+    };
+  });
+
+  return { ...value, servedBy };
+}
+
+/**
+ * Explain why a query failed and suggest a corrected query
+ */
+export async function aiExplainError(
+  request: ExplainErrorRequest,
+  settings: AISettings
+): Promise<ErrorExplanation> {
+  console.log("[AI API] aiExplainError request:", JSON.stringify(request, null, 2));
+
+  await checkBudget(settings);
 
-    // Extract changes list
-    const changesMatch = text.match(/CHANGES:\s*([\s\S]*?)(?=\n\n|```|$)/i);
-    const changes: string[] = [];
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "explain_error");
+    const context: QueryContext = {
+      databaseType: request.databaseType,
+      tables: request.tables ?? [],
+    };
+    const systemPrompt = errorExplanationPrompt(context);
+    const prompt = `This query:\n\n\`\`\`sql\n${request.sql}\n\`\`\`\n\nfailed with:\n\n${request.errorMessage}`;
 
-    if (changesMatch) {
-      const changeLines = changesMatch[1].split(/\n[-*]\s*/);
-      changeLines.forEach(line => {
-        const trimmed = line.trim();
-        if (trimmed && !trimmed.startsWith("CHANGES")) {
-          changes.push(trimmed);
-        }
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: systemPrompt,
+        prompt,
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: errorExplanationSchema }),
       });
+
+      console.log("[AI API] aiExplainError response:", output);
+      track(usage);
+      const correctedSql = output.correctedSql ? parseSQLFromResponse(output.correctedSql) : "";
+      return {
+        cause: output.cause,
+        explanation: output.explanation,
+        correctedSql: correctedSql || undefined,
+      };
+    } catch (error) {
+      const text = unstructuredText(error, track, settings.aiProvider);
+      const codeBlock = text.match(/```(?:sql)?\s*([\s\S]*?)```/i);
+      return {
+        cause: "Query failed",
+        explanation: text.replace(/```(?:sql)?\s*[\s\S]*?```/gi, "").trim(),
+        correctedSql: codeBlock ? codeBlock[1].trim() : undefined,
+      };
     }
+  });
 
-    return {
-      original: sql,
-      optimized: optimizedSQL,
-      changes: changes.length > 0 ? changes : ["Query structure optimized"],
-    };
+  return { ...value, servedBy };
+}
+
+/**
+ * Unquoted, lowercased identifier, for comparing index columns and table names
+ */
+function normalizeIdentifier(name: string): string {
+  return name.replace(/["`[\]]/g, "").trim().toLowerCase();
+}
+
+/**
+ * Name of an existing index whose leading columns are the suggested ones, so
+ * it already serves the same lookups
+ */
+export function findEquivalentIndex(suggestion: IndexSuggestion, request: AdviseIndexesRequest): string | undefined {
+  const tableName = normalizeIdentifier(suggestion.table);
+  const table = request.plan.tables.find((t) => {
+    const name = normalizeIdentifier(t.name);
+    return name === tableName || name.split(".").pop() === tableName.split(".").pop();
+  });
+  const columns = suggestion.columns.map(normalizeIdentifier);
+  if (!table || columns.length === 0) return undefined;
+
+  return table.indexes.find(
+    (index) =>
+      index.columns.length >= columns.length &&
+      columns.every((column, i) => normalizeIdentifier(index.columns[i]) === column)
+  )?.name;
+}
+
+/**
+ * Suggest indexes for a query from its execution plan and table statistics.
+ * Suggestions an existing index already covers are dropped, or with `dryRun`
+ * kept and marked with the index that covers them.
+ */
+export async function aiAdviseIndexes(
+  request: AdviseIndexesRequest,
+  settings: AISettings,
+  options: { dryRun?: boolean } = {}
+): Promise<IndexAdvice> {
+  console.log("[AI API] aiAdviseIndexes request:", request.sql);
+
+  await checkBudget(settings);
+
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "advise_indexes");
+
+    let advice: { summary: string; suggestions: IndexSuggestion[] };
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: indexAdvisorPrompt(request.databaseType),
+        prompt: indexAdvisorInput(request),
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: indexAdviceSchema }),
+      });
+
+      console.log("[AI API] aiAdviseIndexes response:", output);
+      track(usage);
+      advice = output;
+    } catch (error) {
+      const text = unstructuredText(error, track, settings.aiProvider);
+      const statements = [...text.matchAll(/CREATE\s+(?:UNIQUE\s+)?INDEX[^;]*;?/gi)].map((m) => m[0].trim());
+      advice = {
+        summary: text.replace(/```(?:sql)?\s*[\s\S]*?```/gi, "").trim(),
+        suggestions: statements.map((statement): IndexSuggestion => {
+          const target = statement.match(/\bON\s+([\w."`[\]]+)\s*(?:USING\s+\w+\s*)?\(([^)]*)\)/i);
+          return {
+            statement,
+            table: target?.[1] ?? "",
+            columns: target ? target[2].split(",").map((c) => c.trim().split(/\s+/)[0]) : [],
+            impact: "medium",
+            estimatedImprovement: "",
+            reason: "",
+          };
+        }),
+      };
+    }
+
+    const suggestions = advice.suggestions
+      .map((suggestion) => ({
+        ...suggestion,
+        statement: parseSQLFromResponse(suggestion.statement),
+        existingIndex: findEquivalentIndex(suggestion, request),
+      }))
+      .filter((suggestion) => options.dryRun || !suggestion.existingIndex);
+
+    return { summary: advice.summary, suggestions, analyzed: request.plan.analyzed };
+  });
+
+  return { ...value, servedBy };
+}
+
+/**
+ * Markdown data dictionary for a table from its descriptions
+ */
+export function schemaDocsMarkdown(
+  table: TableProperties,
+  docs: { tableComment: string; columns: ColumnComment[] }
+): string {
+  const cell = (text: string) => text.replace(/\|/g, "\\|").replace(/\n/g, " ");
+  const name = table.schema && !table.tableName.includes(".") ? `${table.schema}.${table.tableName}` : table.tableName;
+
+  let markdown = `## ${name}\n\n`;
+  if (docs.tableComment) {
+    markdown += `${docs.tableComment}\n\n`;
+  }
+  markdown += "| Column | Type | Nullable | Description |\n";
+  markdown += "| --- | --- | --- | --- |\n";
+  for (const column of table.columns) {
+    const comment = docs.columns.find((c) => c.column === column.name)?.comment ?? "";
+    const label = `${cell(column.name)}${column.isPrimaryKey ? " (PK)" : ""}`;
+    const nullable = column.nullable ? "Yes" : "No";
+    markdown += `| ${label} | ${cell(column.dataType)} | ${nullable} | ${cell(comment)} |\n`;
+  }
+  return markdown;
+}
+
+/**
+ * Describe a table and its columns, for COMMENT statements or a data dictionary
+ */
+export async function aiGenerateSchemaDocs(
+  request: GenerateSchemaDocsRequest,
+  settings: AISettings
+): Promise<SchemaDocs> {
+  console.log("[AI API] aiGenerateSchemaDocs request:", request.table.tableName);
+
+  await checkBudget(settings);
+
+  const { value, servedBy } = await withFailover(settings, async (model, settings) => {
+    const track = usageTracker(settings, model, "generate_schema_docs");
+
+    let docs: { tableComment: string; columns: ColumnComment[] };
+    try {
+      const { output, usage } = await generateText({
+        model,
+        system: schemaDocsPrompt(request.databaseType),
+        prompt: schemaDocsInput(request),
+        temperature: settings.aiTemperature ?? 0.3,
+        maxOutputTokens: settings.aiMaxTokens ?? 2048,
+        output: Output.object({ schema: schemaDocsSchema }),
+      });
+
+      console.log("[AI API] aiGenerateSchemaDocs response:", output);
+      track(usage);
+      docs = output;
+    } catch (error) {
+      // Free text usually lists the columns as "name: description"
+      const lines = unstructuredText(error, track, settings.aiProvider).split("\n");
+      const described = (name: string) =>
+        lines
+          .map((line) => line.replace(/^[\s*\-|`]+/, ""))
+          .find((line) => line.startsWith(`${name}:`) || line.startsWith(`${name} -`) || line.startsWith(`${name}\``))
+          ?.slice(name.length)
+          .replace(/^[`\s:\-|]+/, "")
+          .trim();
+      docs = {
+        tableComment: "",
+        columns: request.table.columns.flatMap((column) => {
+          const comment = described(column.name);
+          return comment ? [{ column: column.name, comment }] : [];
+        }),
+      };
+    }
+
+    // Only columns that exist, in table order
+    const columns = request.table.columns.flatMap((column) => {
+      const comment = docs.columns.find((c) => c.column === column.name)?.comment.trim();
+      return comment ? [{ column: column.name, comment }] : [];
+    });
+    const tableComment = docs.tableComment.trim();
+    return { tableComment, columns, markdown: schemaDocsMarkdown(request.table, { tableComment, columns }) };
   });
+
+  return { ...value, servedBy };
 }
 
 /**
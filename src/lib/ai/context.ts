@@ -0,0 +1,212 @@
+/**
+ * Schema Context Builder
+ *
+ * Chooses which tables go into the system prompt. Tables are ranked by how
+ * well their names match the prompt, by foreign key distance from the
+ * selected table and by how often the connection's queries use them, then
+ * added with their columns until the token budget is spent. Tables that do not fit are sent by name only, or left out.
+ */
+
+import type { SchemaContextReport, TableInfo } from "./types";
+
+/** Default token budget for the schema section of the prompt */
+export const DEFAULT_CONTEXT_TOKEN_BUDGET = 4000;
+
+export interface SchemaContextOptions {
+  prompt: string;
+  selectedTable?: string;
+  tokenBudget?: number;
+  /** Usage weight per table (0 to 1), from the connection's insights */
+  usageWeights?: Record<string, number>;
+}
+
+/**
+ * Rough token count: about four characters per token for English and SQL
+ */
+function estimateTokens(text: string): number {
+  return Math.ceil(text.length / 4);
+}
+
+/**
+ * Fold simple English plurals so "orders" matches the table "order"
+ */
+function singular(word: string): string {
+  if (word.length > 4 && word.endsWith("ies")) return word.slice(0, -3) + "y";
+  if (word.length > 3 && word.endsWith("s") && !word.endsWith("ss")) return word.slice(0, -1);
+  return word;
+}
+
+/**
+ * Lowercase words of a prompt or identifier, splitting snake_case and camelCase
+ */
+function words(text: string): string[] {
+  return text
+    .replace(/([a-z0-9])([A-Z])/g, "$1 $2")
+    .toLowerCase()
+    .split(/[^a-z0-9]+/)
+    .filter((word) => word.length > 1)
+    .map(singular);
+}
+
+/**
+ * Table name without its schema, lowercased
+ */
+function tableKey(name: string): string {
+  return (name.split(".").pop() ?? name).toLowerCase();
+}
+
+/**
+ * Foreign key hops from the selected table to every table linked to it.
+ * Columns named `<table>_id` count as links for tables whose foreign keys
+ * were not loaded.
+ */
+function foreignKeyDistances(tables: TableInfo[], selectedTable?: string): Map<string, number> {
+  const distances = new Map<string, number>();
+  if (!selectedTable) return distances;
+
+  const keys = new Set(tables.map((table) => tableKey(table.name)));
+  const edges = new Map<string, Set<string>>();
+  const link = (from: string, to: string) => {
+    if (from === to || !keys.has(to)) return;
+    if (!edges.has(from)) edges.set(from, new Set());
+    if (!edges.has(to)) edges.set(to, new Set());
+    edges.get(from)!.add(to);
+    edges.get(to)!.add(from);
+  };
+
+  for (const table of tables) {
+    const key = tableKey(table.name);
+    for (const reference of table.references ?? []) {
+      link(key, tableKey(reference));
+    }
+    for (const column of table.columns ?? []) {
+      const match = column.name.toLowerCase().match(/^(.+)_id$/);
+      if (match) {
+        link(key, match[1]);
+        link(key, `${match[1]}s`);
+      }
+    }
+  }
+
+  const start = tableKey(selectedTable);
+  distances.set(start, 0);
+  const queue = [start];
+  while (queue.length > 0) {
+    const current = queue.shift()!;
+    for (const next of edges.get(current) ?? []) {
+      if (!distances.has(next)) {
+        distances.set(next, distances.get(current)! + 1);
+        queue.push(next);
+      }
+    }
+  }
+  return distances;
+}
+
+/**
+ * Relevance of a table to the prompt; higher is more relevant
+ */
+function relevance(
+  table: TableInfo,
+  prompt: string,
+  promptWords: Set<string>,
+  distance: number | undefined,
+  usageWeight: number | undefined
+): number {
+  const key = tableKey(table.name);
+  let score = 0;
+
+  // Explicit @table references
+  if (new RegExp(`@(?:\\w+\\.)?${key.replace(/[^a-z0-9_]/g, "")}\\b`).test(prompt.toLowerCase())) {
+    score += 10;
+  }
+
+  const nameWords = words(key);
+  if (nameWords.length > 0) {
+    score += (4 * nameWords.filter((word) => promptWords.has(word)).length) / nameWords.length;
+  }
+
+  const matchedColumns = (table.columns ?? []).filter((column) =>
+    words(column.name).some((word) => word !== "id" && promptWords.has(word))
+  ).length;
+  score += Math.min(matchedColumns * 0.5, 2);
+
+  if (distance !== undefined) {
+    score += 6 / (distance + 1);
+  }
+  // Frequently queried tables rank higher, though well below an explicit @table reference
+  score += 3 * Math.min(Math.max(usageWeight ?? 0, 0), 1);
+  return score;
+}
+
+/**
+ * Schema text for a table, as the system prompt lists it
+ */
+function tableText(table: TableInfo): string {
+  let text = `\nTable: ${table.schema ? `${table.schema}.` : ""}${table.name}\nColumns:\n`;
+  for (const column of table.columns ?? []) {
+    text += `  - ${column.name} : ${column.dataType} NOT NULL (PRIMARY KEY)\n`;
+  }
+  return text;
+}
+
+/**
+ * Tables to send with a prompt, most relevant first, and a report of what
+ * was included
+ */
+export function buildSchemaContext(
+  tables: TableInfo[],
+  options: SchemaContextOptions
+): { tables: TableInfo[]; report: SchemaContextReport } {
+  const tokenBudget = options.tokenBudget ?? DEFAULT_CONTEXT_TOKEN_BUDGET;
+  const promptWords = new Set(words(options.prompt));
+  const distances = foreignKeyDistances(tables, options.selectedTable);
+  const usageWeights = new Map<string, number>();
+  for (const [name, weight] of Object.entries(options.usageWeights ?? {})) {
+    const key = tableKey(name);
+    usageWeights.set(key, Math.max(usageWeights.get(key) ?? 0, weight));
+  }
+
+  const ranked = tables
+    .map((table, index) => ({
+      table,
+      index,
+      score: relevance(
+        table,
+        options.prompt,
+        promptWords,
+        distances.get(tableKey(table.name)),
+        usageWeights.get(tableKey(table.name))
+      ),
+    }))
+    .sort((a, b) => b.score - a.score || a.index - b.index);
+
+  const selected: TableInfo[] = [];
+  const report: SchemaContextReport = {
+    included: [],
+    namesOnly: [],
+    omitted: [],
+    estimatedTokens: 0,
+    tokenBudget,
+  };
+
+  for (const { table } of ranked) {
+    const hasColumns = (table.columns?.length ?? 0) > 0;
+    const fullCost = estimateTokens(tableText(table));
+    const nameCost = estimateTokens(`${table.name}, `);
+
+    if (hasColumns && report.estimatedTokens + fullCost <= tokenBudget) {
+      selected.push(table);
+      report.included.push(table.name);
+      report.estimatedTokens += fullCost;
+    } else if (report.estimatedTokens + nameCost <= tokenBudget) {
+      selected.push(hasColumns ? { ...table, columns: undefined } : table);
+      report.namesOnly.push(table.name);
+      report.estimatedTokens += nameCost;
+    } else {
+      report.omitted.push(table.name);
+    }
+  }
+
+  return { tables: selected, report };
+}
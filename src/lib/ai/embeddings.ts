@@ -0,0 +1,107 @@
+/**
+ * Semantic Schema Search
+ *
+ * Every table and column of a connection is embedded once and kept in a
+ * vector index by the backend, so a description such as "customer billing
+ * address" finds the columns that hold it. OpenAI and Gemini embeddings are
+ * computed here; without a key for either, the backend's built-in embeddings
+ * are used, which match shared words but not synonyms.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+import { embed, embedMany } from "ai";
+import type { SchemaDocument, SchemaIndexInfo, SchemaSearchHit } from "@/types";
+import { getEmbeddingModel } from "./providers";
+import type { AISettings } from "./types";
+import { checkBudget, recordUsage } from "./usage";
+
+/** Model name the backend uses for its built-in embeddings */
+export const LOCAL_EMBEDDING_MODEL = "local";
+
+/**
+ * Record the tokens an embedding request spent, against the provider that
+ * served it, and how long it took since `startedAt`
+ */
+function trackEmbedding(
+  settings: AISettings,
+  embedding: NonNullable<ReturnType<typeof getEmbeddingModel>>,
+  tokens: number,
+  startedAt: number
+): void {
+  void recordUsage(
+    { ...settings, aiProvider: embedding.provider },
+    embedding.modelId,
+    "embed_schema",
+    { promptTokens: tokens, completionTokens: 0, totalTokens: tokens },
+    performance.now() - startedAt
+  );
+}
+
+/**
+ * The model and size of a connection's schema index, or null before it is built
+ */
+export async function getSchemaIndexInfo(connectionId: string): Promise<SchemaIndexInfo | null> {
+  return invoke<SchemaIndexInfo | null>("get_schema_index_info", { connectionId });
+}
+
+/**
+ * Embed every table and column of the connection, replacing its index
+ */
+export async function indexSchema(connectionId: string, settings: AISettings): Promise<SchemaIndexInfo> {
+  const embedding = getEmbeddingModel(settings);
+  if (!embedding) {
+    return invoke<SchemaIndexInfo>("build_local_schema_index", { connectionId });
+  }
+
+  await checkBudget(settings);
+  const documents = await invoke<SchemaDocument[]>("get_schema_documents", { connectionId });
+  const startedAt = performance.now();
+  const { embeddings, usage } = await embedMany({
+    model: embedding.model,
+    values: documents.map((document) => document.text),
+  });
+  trackEmbedding(settings, embedding, usage.tokens, startedAt);
+
+  return invoke<SchemaIndexInfo>("save_schema_index", {
+    connectionId,
+    model: embedding.name,
+    documents,
+    vectors: embeddings,
+  });
+}
+
+/**
+ * Tables and columns that best match a description, best first. The schema
+ * is indexed first if it has not been, or if the embedding model changed.
+ */
+export async function semanticSearchSchema(
+  connectionId: string,
+  query: string,
+  settings: AISettings,
+  limit = 20
+): Promise<SchemaSearchHit[]> {
+  const embedding = getEmbeddingModel(settings);
+  const model = embedding?.name ?? LOCAL_EMBEDDING_MODEL;
+
+  const info = await getSchemaIndexInfo(connectionId);
+  if (!info || info.model !== model) {
+    await indexSchema(connectionId, settings);
+  }
+
+  if (!embedding) {
+    return invoke<SchemaSearchHit[]>("semantic_search_schema", { connectionId, query, limit });
+  }
+
+  await checkBudget(settings);
+  const startedAt = performance.now();
+  const { embedding: queryVector, usage } = await embed({ model: embedding.model, value: query });
+  trackEmbedding(settings, embedding, usage.tokens, startedAt);
+
+  return invoke<SchemaSearchHit[]>("semantic_search_schema", {
+    connectionId,
+    query,
+    model,
+    queryVector,
+    limit,
+  });
+}
@@ -10,10 +10,19 @@ export type {
   GeneratedSQL,
   ExplainQueryRequest,
   QueryExplanation,
+  ExplainErrorRequest,
+  ErrorExplanation,
+  AdviseIndexesRequest,
+  IndexSuggestion,
+  IndexAdvice,
+  GenerateSchemaDocsRequest,
+  SchemaDocs,
+  ServedBy,
   AIChatMessage,
   AIChatRequest,
   AIChatResponse,
   AIProviderType,
+  GeminiSafetyThreshold,
   AIModel,
   AIModelsConfig,
   AISettings,
@@ -29,6 +38,7 @@ export type {
   AIChatSession,
   AIChatHistorySettings,
   AIStorageMetadata,
+  SchemaContextReport,
 } from "./types";
 
 // Export constants
@@ -54,9 +64,17 @@ export {
   aiChat,
   aiGenerateMultipleQueries,
   aiOptimizeQuery,
+  aiExplainError,
+  aiAdviseIndexes,
+  findEquivalentIndex,
+  aiGenerateSchemaDocs,
+  schemaDocsMarkdown,
   getAIModels,
 } from "./api";
 
+// Export usage accounting
+export { AIBudgetExceededError, checkBudget, getAIUsageStats, recordUsage } from "./usage";
+
 // Export providers
 export {
   createAnthropicProvider,
@@ -66,6 +84,21 @@ export {
   validateApiKey,
 } from "./providers";
 
+// Export prompt templates
+export {
+  loadPromptTemplates,
+  savePromptTemplate,
+  resetPromptToDefault,
+  renderPromptTemplate,
+  type PromptName,
+} from "./prompts";
+
+// Export semantic schema search
+export { getSchemaIndexInfo, indexSchema, semanticSearchSchema, LOCAL_EMBEDDING_MODEL } from "./embeddings";
+
+// Export schema context builder
+export { buildSchemaContext, DEFAULT_CONTEXT_TOKEN_BUDGET } from "./context";
+
 // Export utilities
 export {
   generateChatTitle,
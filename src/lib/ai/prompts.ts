@@ -3,9 +3,15 @@
  *
  * System prompts for SQL generation, explanation, and optimization.
  * Ported from crates/ai-assistant/src/prompts.rs
+ *
+ * Each prompt can be replaced by a user template saved in the app data
+ * directory (see `get_prompt_templates`). Templates may use the placeholders
+ * {{schema}}, {{dialect}} and {{selected_table}}.
  */
 
-import type { TableInfo } from "./types";
+import { invoke } from "@tauri-apps/api/core";
+import type { PromptTemplate } from "@/types";
+import type { AdviseIndexesRequest, GenerateSchemaDocsRequest, TableInfo } from "./types";
 
 export interface QueryContext {
   databaseType?: string;
@@ -17,10 +23,178 @@ export interface QueryContext {
   currentQuery?: string;
 }
 
+/** Names of the prompts a user template can replace */
+export type PromptName =
+  | "sql_generation"
+  | "chat"
+  | "query_explanation"
+  | "optimization"
+  | "error_explanation"
+  | "index_advisor"
+  | "schema_docs";
+
+/** User templates by prompt name */
+let promptOverrides: Partial<Record<PromptName, string>> = {};
+
+/**
+ * Use the saved user templates in place of the built-in prompts
+ */
+export function setPromptOverrides(templates: PromptTemplate[]): void {
+  promptOverrides = {};
+  for (const template of templates) {
+    if (template.content) {
+      promptOverrides[template.name as PromptName] = template.content;
+    }
+  }
+}
+
+/**
+ * Load the saved user templates and start using them
+ */
+export async function loadPromptTemplates(): Promise<PromptTemplate[]> {
+  const templates = await invoke<PromptTemplate[]>("get_prompt_templates");
+  setPromptOverrides(templates);
+  return templates;
+}
+
+/**
+ * Save a user template for a prompt; the backend rejects unknown placeholders
+ */
+export async function savePromptTemplate(name: PromptName, content: string): Promise<PromptTemplate[]> {
+  await invoke<PromptTemplate>("save_prompt_template", { name, content });
+  return loadPromptTemplates();
+}
+
+/**
+ * Drop the user template for a prompt and go back to the built-in one
+ */
+export async function resetPromptToDefault(name: PromptName): Promise<PromptTemplate[]> {
+  await invoke<PromptTemplate>("reset_prompt_to_default", { name });
+  return loadPromptTemplates();
+}
+
+/**
+ * Fill in a template's placeholders from the query context
+ */
+export function renderPromptTemplate(template: string, context: QueryContext): string {
+  const values: Record<string, string> = {
+    schema: schemaText(context).trim(),
+    dialect: context.databaseType ?? "",
+    selected_table: context.selectedTable ?? "",
+  };
+  return template.replace(/\{\{\s*(\w+)\s*\}\}/g, (match, name: string) => values[name] ?? match);
+}
+
+/**
+ * The user's template for a prompt, rendered, or undefined to use the built-in one
+ */
+function userPrompt(name: PromptName, context: QueryContext): string | undefined {
+  const template = promptOverrides[name];
+  return template === undefined ? undefined : renderPromptTemplate(template, context);
+}
+
+/**
+ * The query open in the editor, which the user may refer to as "this query"
+ */
+function currentQueryText(context: QueryContext): string {
+  if (!context.currentQuery || !context.currentQuery.trim()) {
+    return "";
+  }
+  return `CURRENT QUERY IN EDITOR:
+The user currently has the following SQL query in their editor. They may ask you to modify, explain, or improve it:
+\`\`\`sql
+${context.currentQuery}
+\`\`\`
+
+If the user asks about "this query", "my query", "the current query", or similar, they are referring to the query above.
+You can reference this query when generating new queries or providing explanations.
+
+`;
+}
+
+/**
+ * Tables and columns in the context, as listed in the SQL generation prompt
+ */
+function schemaText(context: QueryContext): string {
+  if (context.tables.length === 0) {
+    return "";
+  }
+
+  let text = "AVAILABLE TABLES AND THEIR COLUMNS:\n";
+  text += "=".repeat(50) + "\n";
+  const tablesWithSchema: TableInfo[] = [];
+  const tablesWithoutSchema: string[] = [];
+
+  for (const table of context.tables) {
+    if (!table.columns || table.columns.length === 0) {
+      tablesWithoutSchema.push(table.name);
+    } else {
+      tablesWithSchema.push(table);
+    }
+  }
+
+  // Tables with full schema
+  for (const table of tablesWithSchema) {
+    // Build display name for the table
+    // Different databases use different naming conventions:
+    // - PostgreSQL: schema.table (e.g., "public.accounts")
+    // - MySQL: just table name (e.g., "users") - database is implicit
+    // - SQLite: just table name
+    const tableNameIncludesSchema = table.name.includes('.');
+
+    let displayTableName: string;
+    const dbType = context.databaseType?.toLowerCase();
+    const isMySQLOrSQLite = dbType === 'mysql' || dbType === 'mariadb' || dbType === 'sqlite';
+
+    if (isMySQLOrSQLite) {
+      // For MySQL/SQLite: show just the table name (no database prefix in queries)
+      displayTableName = tableNameIncludesSchema ? table.name.split('.').pop()! : table.name;
+      // Optionally add database info in comment
+      if (table.schema) {
+        displayTableName = `${displayTableName}  /* database: ${table.schema} */`;
+      }
+    } else {
+      // For PostgreSQL: use schema.table format
+      displayTableName = tableNameIncludesSchema
+        ? table.name
+        : (table.schema ? `${table.schema}.${table.name}` : table.name);
+    }
+
+    text += `\nTable: ${displayTableName}\n`;
+    text += `Columns (USE THESE EXACT NAMES ONLY):\n`;
+    for (const col of table.columns || []) {
+      const pk = col.isPrimaryKey ? " (PRIMARY KEY)" : "";
+      const nullable = col.isNullable ? " NULL" : " NOT NULL";
+      text += `  - ${col.name} : ${col.dataType}${nullable}${pk}\n`;
+    }
+  }
+
+  // Tables without schema (just names)
+  if (tablesWithoutSchema.length > 0) {
+    text += `\nOther available tables (schema not loaded): ${tablesWithoutSchema.join(", ")}\n`;
+    text += "Note: For tables without loaded schemas, you may need to ask the user to specify column names.\n";
+  }
+
+  text += "=".repeat(50) + "\n\n";
+
+  if (tablesWithSchema.length > 0) {
+    text += "⚠️ IMPORTANT: The columns listed above are the ONLY columns that exist in these tables.\n";
+    text += "You MUST use the exact column names as shown. DO NOT assume or invent column names.\n";
+    text += "For example, if you see 'USERNAME' in the schema, use 'USERNAME' not 'name' or 'user_name'.\n\n";
+  }
+
+  return text;
+}
+
 /**
  * Generate the system prompt for SQL generation
  */
 export function sqlGenerationPrompt(context: QueryContext): string {
+  const custom = userPrompt("sql_generation", context);
+  if (custom !== undefined) {
+    return `${custom}\n\n${currentQueryText(context)}`;
+  }
+
   let prompt = `You are an expert SQL developer assistant for dbfordevs, a database management tool.
 Your task is to generate accurate, efficient SQL queries based on natural language descriptions.
 
@@ -63,70 +237,7 @@ IMPORTANT RULES:
   }
 
   // Add schema context
-  if (context.tables.length > 0) {
-    prompt += "AVAILABLE TABLES AND THEIR COLUMNS:\n";
-    prompt += "=".repeat(50) + "\n";
-    const tablesWithSchema: TableInfo[] = [];
-    const tablesWithoutSchema: string[] = [];
-
-    for (const table of context.tables) {
-      if (!table.columns || table.columns.length === 0) {
-        tablesWithoutSchema.push(table.name);
-      } else {
-        tablesWithSchema.push(table);
-      }
-    }
-
-    // Tables with full schema
-    for (const table of tablesWithSchema) {
-      // Build display name for the table
-      // Different databases use different naming conventions:
-      // - PostgreSQL: schema.table (e.g., "public.accounts")
-      // - MySQL: just table name (e.g., "users") - database is implicit
-      // - SQLite: just table name
-      const tableNameIncludesSchema = table.name.includes('.');
-
-      let displayTableName: string;
-      const dbType = context.databaseType?.toLowerCase();
-      const isMySQLOrSQLite = dbType === 'mysql' || dbType === 'mariadb' || dbType === 'sqlite';
-
-      if (isMySQLOrSQLite) {
-        // For MySQL/SQLite: show just the table name (no database prefix in queries)
-        displayTableName = tableNameIncludesSchema ? table.name.split('.').pop()! : table.name;
-        // Optionally add database info in comment
-        if (table.schema) {
-          displayTableName = `${displayTableName}  /* database: ${table.schema} */`;
-        }
-      } else {
-        // For PostgreSQL: use schema.table format
-        displayTableName = tableNameIncludesSchema
-          ? table.name
-          : (table.schema ? `${table.schema}.${table.name}` : table.name);
-      }
-
-      prompt += `\nTable: ${displayTableName}\n`;
-      prompt += `Columns (USE THESE EXACT NAMES ONLY):\n`;
-      for (const col of table.columns || []) {
-        const pk = col.isPrimaryKey ? " (PRIMARY KEY)" : "";
-        const nullable = col.isNullable ? " NULL" : " NOT NULL";
-        prompt += `  - ${col.name} : ${col.dataType}${nullable}${pk}\n`;
-      }
-    }
-
-    // Tables without schema (just names)
-    if (tablesWithoutSchema.length > 0) {
-      prompt += `\nOther available tables (schema not loaded): ${tablesWithoutSchema.join(", ")}\n`;
-      prompt += "Note: For tables without loaded schemas, you may need to ask the user to specify column names.\n";
-    }
-
-    prompt += "=".repeat(50) + "\n\n";
-
-    if (tablesWithSchema.length > 0) {
-      prompt += "⚠️ IMPORTANT: The columns listed above are the ONLY columns that exist in these tables.\n";
-      prompt += "You MUST use the exact column names as shown. DO NOT assume or invent column names.\n";
-      prompt += "For example, if you see 'USERNAME' in the schema, use 'USERNAME' not 'name' or 'user_name'.\n\n";
-    }
-  }
+  prompt += schemaText(context);
 
   // Add selected table context
   if (context.selectedTable) {
@@ -134,18 +245,7 @@ IMPORTANT RULES:
   }
 
   // Add current query context if available
-  if (context.currentQuery && context.currentQuery.trim()) {
-    prompt += `CURRENT QUERY IN EDITOR:
-The user currently has the following SQL query in their editor. They may ask you to modify, explain, or improve it:
-\`\`\`sql
-${context.currentQuery}
-\`\`\`
-
-If the user asks about "this query", "my query", "the current query", or similar, they are referring to the query above.
-You can reference this query when generating new queries or providing explanations.
-
-`;
-  }
+  prompt += currentQueryText(context);
 
   // Debug logging
   console.log("[AI Prompt] Generated system prompt with", context.tables.length, "tables");
@@ -158,6 +258,11 @@ You can reference this query when generating new queries or providing explanatio
  * Generate the system prompt for query explanation
  */
 export function queryExplanationPrompt(context: QueryContext): string {
+  const custom = userPrompt("query_explanation", context);
+  if (custom !== undefined) {
+    return custom;
+  }
+
   let prompt = `You are an expert SQL developer assistant. Your task is to explain SQL queries in clear,
 understandable terms for developers of varying experience levels.
 
@@ -194,6 +299,11 @@ FORMAT YOUR RESPONSE AS:
  * Generate the system prompt for query optimization
  */
 export function optimizationPrompt(context: QueryContext): string {
+  const custom = userPrompt("optimization", context);
+  if (custom !== undefined) {
+    return custom;
+  }
+
   let prompt = `You are an expert database performance engineer. Your task is to analyze SQL queries
 and suggest optimizations to improve their performance.
 
@@ -262,9 +372,199 @@ FORMAT YOUR RESPONSE AS:
   return prompt;
 }
 
+/**
+ * Generate the system prompt for explaining a failed query
+ */
+export function errorExplanationPrompt(context: QueryContext): string {
+  const custom = userPrompt("error_explanation", context);
+  if (custom !== undefined) {
+    return custom;
+  }
+
+  let prompt = `You are an expert SQL developer assistant. A query the user ran failed, and your task is
+to explain why and fix it.
+
+GUIDELINES:
+1. Interpret the database's error message and code (for example PostgreSQL SQLSTATE 42703 is an
+   undefined column, 42P01 an undefined table, 42601 a syntax error; MySQL 1054 is an unknown column)
+2. Point to the exact part of the query that caused the error
+3. Explain the cause in one or two plain sentences
+4. Give a corrected query that keeps the user's intent, using only tables and columns that exist
+5. If the query cannot be fixed without more information (such as a missing table), say what is
+   needed and leave the corrected query empty
+
+`;
+
+  if (context.databaseType) {
+    prompt += `DATABASE TYPE: ${context.databaseType}\n`;
+  }
+
+  const tablesWithColumns = context.tables.filter((t) => t.columns && t.columns.length > 0);
+  if (tablesWithColumns.length > 0) {
+    prompt += "\nKNOWN TABLES AND COLUMNS:\n";
+    for (const table of tablesWithColumns) {
+      const name = table.schema && !table.name.includes(".") ? `${table.schema}.${table.name}` : table.name;
+      prompt += `- ${name}: ${(table.columns || []).map((c) => c.name).join(", ")}\n`;
+    }
+  }
+
+  const otherTables = context.tables.filter((t) => !t.columns || t.columns.length === 0);
+  if (otherTables.length > 0) {
+    prompt += `\nOTHER TABLES: ${otherTables.map((t) => t.name).join(", ")}\n`;
+  }
+
+  return prompt;
+}
+
+/**
+ * Generate system prompt for suggesting indexes from an execution plan
+ */
+export function indexAdvisorPrompt(databaseType?: string): string {
+  const custom = userPrompt("index_advisor", { databaseType, tables: [] });
+  if (custom !== undefined) {
+    return custom;
+  }
+
+  let prompt = `You are an expert database performance engineer. Given a query, its execution plan and
+statistics for the tables it uses, suggest indexes that would make the query faster.
+
+GUIDELINES:
+1. Base every suggestion on the plan: sequential or full table scans of large tables, sorts and
+   hash joins over many rows, and filters that discard most of the rows read
+2. Prefer one composite index over several single-column ones; put equality columns first,
+   then range and ORDER BY columns
+3. Do not suggest an index that an existing one already covers as a leading prefix
+4. Skip small tables, where a scan is as fast as an index lookup
+5. Write complete CREATE INDEX statements in the database's syntax with descriptive names
+6. Rate the impact high, medium or low and say what should change in the plan
+7. If no index would help, return no suggestions and say why in the summary
+
+`;
+
+  if (databaseType) {
+    prompt += `DATABASE TYPE: ${databaseType}\n`;
+  }
+
+  return prompt;
+}
+
+/**
+ * The query, plan and table statistics, as the user prompt for the index advisor
+ */
+export function indexAdvisorInput(request: AdviseIndexesRequest): string {
+  const { plan } = request;
+  let input = `QUERY:\n\`\`\`sql\n${request.sql}\n\`\`\`\n\n`;
+  input += `${plan.analyzed ? "ACTUAL EXECUTION PLAN" : "ESTIMATED EXECUTION PLAN"}:\n`;
+  input += `\`\`\`text\n${plan.plan}\n\`\`\`\n`;
+
+  for (const table of plan.tables) {
+    input += `\nTABLE: ${table.name}\n`;
+    const rows = table.stats?.approximateRows;
+    if (rows !== undefined) {
+      input += `  Approximate rows: ${rows}\n`;
+    }
+    if (table.stats?.totalSizeBytes !== undefined) {
+      input += `  Total size: ${table.stats.totalSizeBytes} bytes\n`;
+    }
+    if (table.indexes.length === 0) {
+      input += "  Existing indexes: none\n";
+    } else {
+      input += "  Existing indexes:\n";
+      for (const index of table.indexes) {
+        const kind = index.isPrimary ? " (primary key)" : index.isUnique ? " (unique)" : "";
+        input += `    - ${index.name}${kind}: ${index.columns.join(", ")}\n`;
+      }
+    }
+  }
+
+  return input;
+}
+
+/**
+ * Generate system prompt for describing a table and its columns
+ */
+export function schemaDocsPrompt(databaseType?: string): string {
+  const custom = userPrompt("schema_docs", { databaseType, tables: [] });
+  if (custom !== undefined) {
+    return custom;
+  }
+
+  let prompt = `You are an expert data modeller writing documentation for a database. Given a table's
+columns, keys, indexes and constraints, write a short description of the table and of each column.
+
+GUIDELINES:
+1. Describe what the data means, not the type: "When the customer confirmed the order" rather
+   than "A timestamp column"
+2. Mention units, allowed values and relationships where the name, type, foreign keys or CHECK
+   constraints make them clear
+3. Keep each description to one sentence of at most 200 characters, and do not start it with
+   "This column"
+4. Keep an existing comment when it is already accurate, improving only its wording
+5. If a column's meaning cannot be inferred, describe it as well as the name allows rather than
+   guessing business rules
+6. Describe every column, using the exact column names given
+
+`;
+
+  if (databaseType) {
+    prompt += `DATABASE TYPE: ${databaseType}\n`;
+  }
+
+  return prompt;
+}
+
+/**
+ * Build the user message describing the table to document
+ */
+export function schemaDocsInput(request: GenerateSchemaDocsRequest): string {
+  const { table } = request;
+  const name = table.schema && !table.tableName.includes(".") ? `${table.schema}.${table.tableName}` : table.tableName;
+  let input = `TABLE: ${name}\n`;
+  if (table.tableComment) {
+    input += `Existing comment: ${table.tableComment}\n`;
+  }
+  if (table.rowCount !== undefined) {
+    input += `Rows: ${table.rowCount}\n`;
+  }
+
+  input += "\nCOLUMNS:\n";
+  for (const column of table.columns) {
+    input += `- ${column.name} : ${column.dataType}${column.nullable ? " NULL" : " NOT NULL"}`;
+    if (column.isPrimaryKey) input += " (PRIMARY KEY)";
+    if (column.defaultValue) input += ` DEFAULT ${column.defaultValue}`;
+    if (column.comment) input += ` -- existing comment: ${column.comment}`;
+    input += "\n";
+  }
+
+  if (table.foreignKeys.length > 0) {
+    input += "\nFOREIGN KEYS:\n";
+    for (const fk of table.foreignKeys) {
+      input += `- ${fk.column} -> ${fk.referencesTable}.${fk.referencesColumn}\n`;
+    }
+  }
+  if (table.constraints.length > 0) {
+    input += "\nCONSTRAINTS:\n";
+    for (const constraint of table.constraints) {
+      input += `- ${constraint.constraintType} ${constraint.name}: ${constraint.definition}\n`;
+    }
+  }
+  if (table.indexes.length > 0) {
+    input += "\nINDEXES:\n";
+    for (const index of table.indexes) {
+      input += `- ${index.name}${index.isUnique ? " (unique)" : ""}: ${index.columns.join(", ")}\n`;
+    }
+  }
+
+  return input;
+}
+
 /**
  * Generate system prompt for general AI chat with SQL context
  */
 export function chatPrompt(context: QueryContext): string {
+  const custom = userPrompt("chat", context);
+  if (custom !== undefined) {
+    return `${custom}\n\n${currentQueryText(context)}`;
+  }
   return sqlGenerationPrompt(context);
 }
@@ -8,6 +8,7 @@
 import { createAnthropic } from "@ai-sdk/anthropic";
 import { createGoogleGenerativeAI } from "@ai-sdk/google";
 import { createOpenAI } from "@ai-sdk/openai";
+import { APICallError, defaultSettingsMiddleware, wrapLanguageModel } from "ai";
 import { tauriFetchWrapper } from "./tauri-fetch";
 import { DEFAULT_MODELS, type AISettings, type AIProviderType } from "./types";
 
@@ -34,12 +35,21 @@ export function createGeminiProvider(apiKey: string) {
   });
 }
 
+/** Harm categories the Gemini safety threshold applies to */
+const GEMINI_HARM_CATEGORIES = [
+  "HARM_CATEGORY_HARASSMENT",
+  "HARM_CATEGORY_HATE_SPEECH",
+  "HARM_CATEGORY_SEXUALLY_EXPLICIT",
+  "HARM_CATEGORY_DANGEROUS_CONTENT",
+] as const;
+
 /**
  * Create an OpenAI provider instance with Tauri fetch
  */
-export function createOpenAIProvider(apiKey: string) {
+export function createOpenAIProvider(apiKey: string, organization?: string) {
   return createOpenAI({
     apiKey,
+    organization: organization?.trim() || undefined,
     fetch: tauriFetchWrapper,
   });
 }
@@ -79,7 +89,21 @@ export function getProviderModel(settings: AISettings) {
       modelId = DEFAULT_MODELS.gemini;
     }
 
-    return gemini(modelId);
+    const threshold = settings.aiGeminiSafetyThreshold;
+    if (!threshold) {
+      return gemini(modelId);
+    }
+    // Safety settings are sent per request, so apply them to every call made with the model
+    return wrapLanguageModel({
+      model: gemini(modelId),
+      middleware: defaultSettingsMiddleware({
+        settings: {
+          providerOptions: {
+            google: { safetySettings: GEMINI_HARM_CATEGORIES.map((category) => ({ category, threshold })) },
+          },
+        },
+      }),
+    });
   }
 
   if (provider === "openai") {
@@ -87,7 +111,7 @@ export function getProviderModel(settings: AISettings) {
     if (!apiKey) {
       throw new Error("OpenAI API key is not configured");
     }
-    const openai = createOpenAIProvider(apiKey);
+    const openai = createOpenAIProvider(apiKey, settings.aiOpenaiOrganization);
     const modelId = settings.aiOpenaiModel || DEFAULT_MODELS.openai;
 
     return openai(modelId);
@@ -96,6 +120,113 @@ export function getProviderModel(settings: AISettings) {
   throw new Error(`Unknown provider: ${provider}`);
 }
 
+/**
+ * Whether an API key is set for a provider
+ */
+export function hasApiKey(settings: AISettings, provider: AIProviderType): boolean {
+  switch (provider) {
+    case "anthropic":
+      return !!(settings.aiAnthropicApiKey || settings.aiApiKey);
+    case "gemini":
+      return !!settings.aiGeminiApiKey;
+    case "openai":
+      return !!settings.aiOpenaiApiKey;
+  }
+}
+
+/** Embedding models for semantic schema search; Anthropic offers none */
+export const EMBEDDING_MODELS: Partial<Record<AIProviderType, string>> = {
+  openai: "text-embedding-3-small",
+  gemini: "gemini-embedding-001",
+};
+
+/**
+ * Embedding model for semantic schema search: the current provider's, or
+ * another provider's that has an API key. Null when neither OpenAI nor Gemini
+ * is configured.
+ */
+export function getEmbeddingModel(settings: AISettings) {
+  const current = settings.aiProvider || "anthropic";
+  const provider = ([current, "openai", "gemini"] as AIProviderType[]).find(
+    (candidate) => EMBEDDING_MODELS[candidate] && hasApiKey(settings, candidate)
+  );
+  if (!provider) {
+    return null;
+  }
+
+  const modelId = EMBEDDING_MODELS[provider]!;
+  const model =
+    provider === "openai"
+      ? createOpenAIProvider(settings.aiOpenaiApiKey!, settings.aiOpenaiOrganization).embeddingModel(modelId)
+      : createGeminiProvider(settings.aiGeminiApiKey!).embeddingModel(modelId);
+  return { provider, modelId, model, name: `${provider}/${modelId}` };
+}
+
+/**
+ * Error fields in a provider's JSON error body. OpenAI and Gemini put them
+ * under `error`; Anthropic uses `error.type` instead of a code, and Gemini
+ * adds a gRPC `status` such as `INVALID_ARGUMENT`.
+ */
+function parseErrorBody(body: string | undefined): { code?: string; status?: string; message?: string } {
+  try {
+    const error = JSON.parse(body ?? "")?.error;
+    return {
+      code: typeof error?.code === "string" ? error.code : error?.type,
+      status: typeof error?.status === "string" ? error.status : undefined,
+      message: typeof error?.message === "string" ? error.message : undefined,
+    };
+  } catch {
+    return {};
+  }
+}
+
+/**
+ * Turn a failed provider API call into a readable error, keeping the
+ * provider's own message as detail
+ */
+export function describeProviderError(error: unknown): Error {
+  if (!APICallError.isInstance(error)) {
+    return error instanceof Error ? error : new Error(String(error));
+  }
+
+  const { code, status, message } = parseErrorBody(error.responseBody);
+  let summary: string;
+  if (status === "INVALID_ARGUMENT" && message && /api key/i.test(message)) {
+    // Gemini rejects bad keys with 400 rather than 401
+    summary = "The API key was rejected. Check it in AI settings";
+  } else if (status === "FAILED_PRECONDITION") {
+    summary = "The Gemini API is not available for this account or region";
+  } else if (code === "insufficient_quota") {
+    summary = "The account has run out of credits. Check billing in the provider console";
+  } else if (code === "model_not_found" || error.statusCode === 404) {
+    summary = "The model is not available to this API key. Choose another in AI settings";
+  } else if (error.statusCode === 401) {
+    summary = "The API key was rejected. Check it in AI settings";
+  } else if (error.statusCode === 403) {
+    summary = "The API key is not allowed to use this model or organization";
+  } else if (error.statusCode === 429) {
+    summary = "Rate limit reached. Wait a moment and try again";
+  } else if (error.statusCode !== undefined && error.statusCode >= 500) {
+    summary = `The provider is unavailable (HTTP ${error.statusCode}). Try again later`;
+  } else {
+    return error;
+  }
+
+  return new Error(message ? `${summary}: ${message}` : summary);
+}
+
+/**
+ * Error for a response the provider's safety filter withheld
+ */
+export function contentFilteredError(provider: AIProviderType): Error {
+  if (provider === "gemini") {
+    return new Error(
+      "Gemini's safety filter blocked the response. Rephrase the request or lower the safety threshold in AI settings"
+    );
+  }
+  return new Error("The provider's content filter blocked the response. Rephrase the request");
+}
+
 /**
  * Validate an API key by attempting a minimal API call
  */
@@ -20,11 +20,33 @@ import type {
   AIStorageMetadata,
   TokenUsage,
   SessionUsageStats,
+  ServedBy,
 } from "./types";
 import { AVAILABLE_MODELS, DEFAULT_MODELS } from "./types";
 import { calculateCost, aiChatStream } from "./api";
 import * as api from "./api";
 import { generateChatTitle, cleanupOldChats, migrateToVersion1 } from "./utils";
+import { loadPromptTemplates } from "./prompts";
+import { buildSchemaContext } from "./context";
+import type { ConnectionInsights, DataQuestionAnswer, DataQuestionRequest, QueryPlan } from "@/types";
+
+/** Rows fetched to answer a data question */
+const DATA_QUESTION_MAX_ROWS = 200;
+
+/** Rows shown in the chat for a data question */
+const DATA_QUESTION_PREVIEW_ROWS = 10;
+
+/** Plain-text table of the first rows of an answer, for a chat code block */
+function formatAnswerPreview(answer: DataQuestionAnswer): string {
+  const { columns, rows } = answer.result;
+  const cell = (value: unknown) =>
+    value === null || value === undefined ? "NULL" : typeof value === "object" ? JSON.stringify(value) : String(value);
+  const lines = [columns.map((c) => c.name), ...rows.slice(0, DATA_QUESTION_PREVIEW_ROWS).map((r) => r.map(cell))];
+  const widths = columns.map((_, i) => Math.min(40, Math.max(...lines.map((line) => line[i]?.length ?? 0))));
+  return lines
+    .map((line) => line.map((value, i) => value.slice(0, widths[i]).padEnd(widths[i])).join(" | "))
+    .join("\n");
+}
 
 /** Calculate aggregated usage stats for a session */
 function calculateSessionUsageStats(messages: AIChatMessage[], modelId: string): SessionUsageStats {
@@ -89,7 +111,7 @@ function tableMatchesReference(table: TableInfo, reference: string): boolean {
 async function fetchTableSchema(
   connectionId: string,
   tableName: string
-): Promise<{ columns: ColumnInfo[] } | null> {
+): Promise<{ columns: ColumnInfo[]; references: string[] } | null> {
   try {
     const schema = await invoke<{
       tableName: string;
@@ -99,6 +121,7 @@ async function fetchTableSchema(
         nullable: boolean;
         isPrimaryKey: boolean;
       }>;
+      foreignKeys: Array<{ referencesTable: string }>;
     }>("get_table_schema", { connectionId, tableName });
 
     return {
@@ -108,6 +131,7 @@ async function fetchTableSchema(
         isNullable: c.nullable,
         isPrimaryKey: c.isPrimaryKey,
       })),
+      references: schema.foreignKeys.map((fk) => fk.referencesTable),
     };
   } catch (error) {
     console.error(`[AI Store] Failed to fetch schema for ${tableName}:`, error);
@@ -115,6 +139,18 @@ async function fetchTableSchema(
   }
 }
 
+/** Weights of the connection's most used tables, for ranking the schema context */
+async function fetchUsageWeights(connectionId?: string): Promise<Record<string, number> | undefined> {
+  if (!connectionId) return undefined;
+  try {
+    const insights = await invoke<ConnectionInsights>("get_connection_insights", { connectionId });
+    return Object.fromEntries(insights.topTables.map((table) => [table.table, table.weight]));
+  } catch (error) {
+    console.error("[AI Store] Failed to fetch connection insights:", error);
+    return undefined;
+  }
+}
+
 
 interface AIState {
   // Settings
@@ -161,6 +197,12 @@ interface AIState {
   setPanelOpen: (open: boolean) => void;
   togglePanel: () => void;
   sendMessage: (message: string, useStreaming?: boolean) => Promise<void>;
+  /** Answer a question by having the AI write a read-only query and running it */
+  askDataQuestion: (question: string) => Promise<void>;
+  /** Ask the AI why a query failed; the answer is added to the active chat */
+  explainQueryError: (sql: string, errorMessage: string) => Promise<void>;
+  /** Ask the AI for indexes that would speed up a query, based on its execution plan */
+  adviseIndexes: (sql: string) => Promise<void>;
   setContext: (context: Partial<AIState["context"]>) => void;
   updateSettings: (settings: Partial<AISettings>) => Promise<void>;
   setApiKey: (key: string, provider?: AIProviderType) => Promise<void>;
@@ -310,7 +352,7 @@ export const useAIStore = create<AIState>()(
                   console.log(`[AI Store] Fetching schema for: ${tableNameForFetch}`);
                   const schema = await fetchTableSchema(context.connectionId!, tableNameForFetch);
                   if (schema) {
-                    return { ...table, columns: schema.columns };
+                    return { ...table, columns: schema.columns, references: schema.references };
                   }
                 }
                 return table;
@@ -323,12 +365,21 @@ export const useAIStore = create<AIState>()(
             ? (context.tables || []).find(t => tableMatchesReference(t, referencedTables[0]))?.name
             : context.selectedTable;
 
+          // Keep the schema within the token budget, most relevant tables first
+          const schemaContext = buildSchemaContext(tablesWithSchema, {
+            prompt: message,
+            selectedTable,
+            tokenBudget: settings.aiContextTokenBudget,
+            usageWeights: await fetchUsageWeights(context.connectionId),
+          });
+          console.log("[AI Store] Schema context:", schemaContext.report);
+
           const requestContext = {
             prompt: message,
             databaseType: context.databaseType,
             databaseName: context.databaseName,
             schemaName: context.schemaName,
-            tables: tablesWithSchema,
+            tables: schemaContext.tables,
             selectedTable,
             currentQuery: context.currentQuery,
           };
@@ -343,6 +394,7 @@ export const useAIStore = create<AIState>()(
               content: "",
               timestamp: new Date(),
               isStreaming: true,
+              context: schemaContext.report,
             };
 
             // Add placeholder message
@@ -361,10 +413,12 @@ export const useAIStore = create<AIState>()(
             );
 
             let finalUsage: TokenUsage | undefined;
+            let servedBy: ServedBy | undefined;
 
             for await (const chunk of stream) {
               if (chunk.done) {
                 finalUsage = chunk.usage;
+                servedBy = chunk.servedBy;
               }
 
               // Update streaming message content
@@ -437,6 +491,7 @@ export const useAIStore = create<AIState>()(
                               content: finalContent,
                               sql: looksLikeSQL ? sql : undefined,
                               usage: finalUsage,
+                              servedBy,
                               isStreaming: false,
                             }
                           : m
@@ -464,6 +519,8 @@ export const useAIStore = create<AIState>()(
               sql: response.sql,
               timestamp: new Date(),
               usage: response.usage,
+              servedBy: response.servedBy,
+              context: schemaContext.report,
             };
 
             set((state) => ({
@@ -532,6 +589,250 @@ export const useAIStore = create<AIState>()(
         }
       },
 
+      askDataQuestion: async (question: string) => {
+        const { context, settings, createNewChatSession } = get();
+        if (!get().activeChatSessionId) {
+          createNewChatSession();
+        }
+        const sessionId = get().activeChatSessionId;
+        const addMessage = (message: AIChatMessage) =>
+          set((state) => ({
+            chatSessions: state.chatSessions.map(s =>
+              s.id === sessionId
+                ? {
+                    ...s,
+                    messages: [...s.messages, message],
+                    title: s.messages.length === 0 ? generateChatTitle(message) : s.title,
+                    updatedAt: new Date(),
+                  }
+                : s
+            ),
+          }));
+
+        addMessage({ id: crypto.randomUUID(), role: "user", content: question, timestamp: new Date() });
+        set({ isLoading: true });
+
+        let sql: string | undefined;
+        try {
+          if (!context.connectionId) {
+            throw new Error("Connect to a database to ask questions about its data");
+          }
+
+          const schemaContext = buildSchemaContext(context.tables || [], {
+            prompt: question,
+            selectedTable: context.selectedTable,
+            tokenBudget: settings.aiContextTokenBudget,
+            usageWeights: await fetchUsageWeights(context.connectionId),
+          });
+          const generated = await api.aiGenerateSQL(
+            {
+              prompt: `Write a single read-only SELECT query that answers this question: ${question}`,
+              databaseType: context.databaseType,
+              databaseName: context.databaseName,
+              schemaName: context.schemaName,
+              tables: schemaContext.tables,
+              selectedTable: context.selectedTable,
+            },
+            settings
+          );
+          sql = generated.sql;
+
+          // The backend refuses anything but one read-only statement and logs the run
+          const request: DataQuestionRequest = {
+            connectionId: context.connectionId,
+            question,
+            sql,
+            maxRows: DATA_QUESTION_MAX_ROWS,
+          };
+          const answer = await invoke<DataQuestionAnswer>("ask_data_question", { request });
+
+          const rowCount = answer.result.rows.length;
+          let content = generated.explanation ? `${generated.explanation}\n\n` : "";
+          content += answer.truncated
+            ? `The query returned more than ${rowCount} rows; only the first ${rowCount} were fetched.`
+            : `The query returned ${rowCount} ${rowCount === 1 ? "row" : "rows"}.`;
+          if (rowCount > 0) {
+            const shown = Math.min(rowCount, DATA_QUESTION_PREVIEW_ROWS);
+            content += rowCount > shown ? ` The first ${shown}:` : "";
+            content += `\n\n\`\`\`text\n${formatAnswerPreview(answer)}\n\`\`\``;
+          }
+
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content,
+            sql: answer.sql,
+            timestamp: new Date(),
+            context: schemaContext.report,
+            servedBy: generated.servedBy,
+          });
+        } catch (error) {
+          const message = error instanceof Error ? error.message : String(error);
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content: `Error: ${message}`,
+            sql,
+            timestamp: new Date(),
+          });
+        } finally {
+          set({ isLoading: false });
+        }
+      },
+
+      explainQueryError: async (sql: string, errorMessage: string) => {
+        const { context, settings, createNewChatSession } = get();
+        if (!get().activeChatSessionId) {
+          createNewChatSession();
+        }
+        const sessionId = get().activeChatSessionId;
+
+        const userMessage: AIChatMessage = {
+          id: crypto.randomUUID(),
+          role: "user",
+          content: `Why did this query fail?\n\n\`\`\`sql\n${sql}\n\`\`\`\n\n${errorMessage}`,
+          timestamp: new Date(),
+        };
+        const addMessage = (message: AIChatMessage) =>
+          set((state) => ({
+            chatSessions: state.chatSessions.map(s =>
+              s.id === sessionId
+                ? {
+                    ...s,
+                    messages: [...s.messages, message],
+                    title: s.messages.length === 0 ? generateChatTitle(message) : s.title,
+                    updatedAt: new Date(),
+                  }
+                : s
+            ),
+          }));
+
+        addMessage(userMessage);
+        set({ isLoading: true });
+
+        try {
+          const schemaContext = buildSchemaContext(context.tables || [], {
+            prompt: `${sql}\n${errorMessage}`,
+            selectedTable: context.selectedTable,
+            tokenBudget: settings.aiContextTokenBudget,
+            usageWeights: await fetchUsageWeights(context.connectionId),
+          });
+          const explanation = await api.aiExplainError(
+            { sql, errorMessage, databaseType: context.databaseType, tables: schemaContext.tables },
+            settings
+          );
+
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content: `**${explanation.cause}**\n\n${explanation.explanation}`,
+            sql: explanation.correctedSql,
+            timestamp: new Date(),
+            context: schemaContext.report,
+            servedBy: explanation.servedBy,
+          });
+        } catch (error) {
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content:
+              error instanceof Error
+                ? `Error: ${error.message}`
+                : "An error occurred while processing your request.",
+            timestamp: new Date(),
+          });
+        } finally {
+          set({ isLoading: false });
+        }
+      },
+
+      adviseIndexes: async (sql: string) => {
+        const { context, settings, createNewChatSession } = get();
+        if (!get().activeChatSessionId) {
+          createNewChatSession();
+        }
+        const sessionId = get().activeChatSessionId;
+
+        const addMessage = (message: AIChatMessage) =>
+          set((state) => ({
+            chatSessions: state.chatSessions.map(s =>
+              s.id === sessionId
+                ? {
+                    ...s,
+                    messages: [...s.messages, message],
+                    title: s.messages.length === 0 ? generateChatTitle(message) : s.title,
+                    updatedAt: new Date(),
+                  }
+                : s
+            ),
+          }));
+
+        addMessage({
+          id: crypto.randomUUID(),
+          role: "user",
+          content: `Suggest indexes for this query:\n\n\`\`\`sql\n${sql}\n\`\`\``,
+          timestamp: new Date(),
+        });
+        set({ isLoading: true });
+
+        try {
+          if (!context.connectionId) {
+            throw new Error("Connect to a database to get index suggestions");
+          }
+
+          // Read-only queries are run for actual row counts; others are only planned
+          const plan = await invoke<QueryPlan>("explain_query", {
+            connectionId: context.connectionId,
+            sql,
+            analyze: true,
+          });
+          const advice = await api.aiAdviseIndexes(
+            { sql, plan, databaseType: context.databaseType },
+            settings,
+            { dryRun: true }
+          );
+
+          const fresh = advice.suggestions.filter((s) => !s.existingIndex);
+          const covered = advice.suggestions.filter((s) => s.existingIndex);
+          let content = advice.summary;
+          if (!plan.analyzed) {
+            content += "\n\n_Based on the estimated plan; the query was not run._";
+          }
+          for (const suggestion of fresh) {
+            content += `\n\n**${suggestion.table}** (${suggestion.columns.join(", ")}), ${suggestion.impact} impact`;
+            content += `\n${[suggestion.reason, suggestion.estimatedImprovement].filter(Boolean).join(" ")}`;
+          }
+          for (const suggestion of covered) {
+            content += `\n\n${suggestion.table} (${suggestion.columns.join(", ")}) is already covered by`;
+            content += ` \`${suggestion.existingIndex}\``;
+          }
+          if (fresh.length === 0 && covered.length === 0) {
+            content += "\n\nNo new indexes are needed.";
+          }
+
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content,
+            sql: fresh.length > 0 ? fresh.map((s) => s.statement.replace(/;?\s*$/, ";")).join("\n") : undefined,
+            timestamp: new Date(),
+            servedBy: advice.servedBy,
+          });
+        } catch (error) {
+          addMessage({
+            id: crypto.randomUUID(),
+            role: "assistant",
+            content:
+              error instanceof Error
+                ? `Error: ${error.message}`
+                : "An error occurred while processing your request.",
+            timestamp: new Date(),
+          });
+        } finally {
+          set({ isLoading: false });
+        }
+      },
+
       setContext: (context) =>
         set((state) => ({
           context: { ...state.context, ...context },
@@ -735,6 +1036,11 @@ export const useAIStore = create<AIState>()(
           );
         }
 
+        // Use the user's prompt templates, if any are saved
+        loadPromptTemplates().catch((error) =>
+          console.error("[AI Store] Failed to load prompt templates:", error)
+        );
+
         console.log(`[AI Store] Rehydration complete. ${state.chatSessions.length} chat sessions loaded.`);
       },
     }
@@ -2,12 +2,16 @@
  * AI Assistant Types
  */
 
+import type { ColumnComment, QueryPlan, TableProperties } from "@/types";
+
 /** Table information for AI context */
 export interface TableInfo {
   name: string;
   schema?: string;
   tableType?: string;
   columns?: ColumnInfo[];
+  /** Tables this one references through foreign keys */
+  references?: string[];
 }
 
 /** Column information for AI context */
@@ -35,6 +39,7 @@ export interface GeneratedSQL {
   sql: string;
   explanation?: string;
   confidence: number;
+  servedBy?: ServedBy;
 }
 
 /** Request for query explanation */
@@ -48,6 +53,71 @@ export interface QueryExplanation {
   summary: string;
   steps: string[];
   warnings: string[];
+  servedBy?: ServedBy;
+}
+
+/** Request for explaining a failed query */
+export interface ExplainErrorRequest {
+  sql: string;
+  errorMessage: string;
+  databaseType?: string;
+  tables?: TableInfo[];
+}
+
+/** Why a query failed and how to fix it */
+export interface ErrorExplanation {
+  /** Short name of the problem, e.g. "Unknown column" */
+  cause: string;
+  explanation: string;
+  /** Fixed query; absent when the query cannot be fixed without more information */
+  correctedSql?: string;
+  servedBy?: ServedBy;
+}
+
+/** Request for index suggestions, with the plan and tables from the backend */
+export interface AdviseIndexesRequest {
+  sql: string;
+  plan: QueryPlan;
+  databaseType?: string;
+}
+
+/** An index the advisor suggests */
+export interface IndexSuggestion {
+  /** Complete CREATE INDEX statement */
+  statement: string;
+  table: string;
+  columns: string[];
+  impact: "high" | "medium" | "low";
+  /** Expected effect, e.g. "Seq Scan on orders (1.2M rows) becomes an Index Scan" */
+  estimatedImprovement: string;
+  reason: string;
+  /** Name of an existing index that already covers these columns */
+  existingIndex?: string;
+}
+
+/** Index suggestions for a query */
+export interface IndexAdvice {
+  suggestions: IndexSuggestion[];
+  /** What the plan shows, in a sentence or two */
+  summary: string;
+  /** True when the advice is based on actual rows and timings rather than estimates */
+  analyzed: boolean;
+  servedBy?: ServedBy;
+}
+
+/** Request to describe a table and its columns */
+export interface GenerateSchemaDocsRequest {
+  table: TableProperties;
+  databaseType?: string;
+}
+
+/** Descriptions the AI proposes for a table and its columns */
+export interface SchemaDocs {
+  tableComment: string;
+  columns: ColumnComment[];
+  /** Data dictionary for the table in markdown */
+  markdown: string;
+  servedBy?: ServedBy;
 }
 
 /** AI chat message */
@@ -67,6 +137,30 @@ export interface AIChatMessage {
   queryDiff?: QueryDiff;
   /** Whether this message is still streaming */
   isStreaming?: boolean;
+  /** Tables sent as schema context for this answer (assistant messages only) */
+  context?: SchemaContextReport;
+  /** Provider and model that answered (assistant messages only) */
+  servedBy?: ServedBy;
+}
+
+/** The provider that answered a request */
+export interface ServedBy {
+  provider: AIProviderType;
+  model: string;
+  /** True when the configured provider failed and the fallback provider answered */
+  fallback: boolean;
+}
+
+/** Tables chosen for a prompt's schema context */
+export interface SchemaContextReport {
+  /** Tables sent with their columns, most relevant first */
+  included: string[];
+  /** Tables sent by name only */
+  namesOnly: string[];
+  /** Tables left out to stay within the budget */
+  omitted: string[];
+  estimatedTokens: number;
+  tokenBudget: number;
 }
 
 /** AI chat request */
@@ -85,6 +179,13 @@ export interface AIChatResponse {
 /** Available AI provider types */
 export type AIProviderType = "anthropic" | "gemini" | "openai";
 
+/** How readily Gemini's safety filter withholds a response, from least to most */
+export type GeminiSafetyThreshold =
+  | "BLOCK_NONE"
+  | "BLOCK_ONLY_HIGH"
+  | "BLOCK_MEDIUM_AND_ABOVE"
+  | "BLOCK_LOW_AND_ABOVE";
+
 /** AI model definition */
 export interface AIModel {
   id: string;
@@ -121,11 +222,29 @@ export interface AISettings {
   aiGeminiModel?: string;
   aiOpenaiModel?: string;
 
+  /** Gemini safety filter level for harassment, hate speech, sexual and dangerous content; unset uses Google's default */
+  aiGeminiSafetyThreshold?: GeminiSafetyThreshold;
+
+  /** OpenAI organization ID, sent as the OpenAI-Organization header */
+  aiOpenaiOrganization?: string;
+
   /** Temperature for AI generation (0.0 - 2.0) */
   aiTemperature?: number;
 
   /** Max tokens for AI generation */
   aiMaxTokens?: number;
+
+  /** Token budget for table schemas in the prompt */
+  aiContextTokenBudget?: number;
+
+  /** Provider to try when the configured one keeps failing with rate limits, outages or network errors */
+  aiFallbackProvider?: AIProviderType;
+
+  /** Tokens all AI requests may use per calendar month; unset for no limit */
+  aiMonthlyTokenBudget?: number;
+
+  /** Estimated US dollars all AI requests may cost per calendar month; unset for no limit */
+  aiMonthlyCostBudget?: number;
 }
 
 /** Token usage tracking */
@@ -169,6 +288,7 @@ export interface QueryDiff {
   original: string;
   optimized: string;
   changes: string[];
+  servedBy?: ServedBy;
 }
 
 /** Table reference for @ mentions */
@@ -288,6 +408,9 @@ export const MODEL_PRICING: Record<string, ModelPricing> = {
   "gpt-5.2-2025-12-11": { inputPer1M: 1.75, outputPer1M: 14 },
   "gpt-5-mini-2025-08-07": { inputPer1M: 0.25, outputPer1M: 2 },
   "gpt-5.2-pro-2025-12-11": { inputPer1M: 21, outputPer1M: 168 },
+  // Embeddings
+  "text-embedding-3-small": { inputPer1M: 0.02, outputPer1M: 0 },
+  "gemini-embedding-001": { inputPer1M: 0.15, outputPer1M: 0 },
 };
 
 
@@ -0,0 +1,100 @@
+/**
+ * AI Usage Accounting
+ *
+ * Each request's token usage is recorded by the backend, which keeps it in
+ * the app data directory. Requests are refused once the month's usage reaches
+ * the token or cost budget in the AI settings.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+import type { AIUsageRecord, AIUsageStats } from "@/types";
+import type { AISettings, TokenUsage } from "./types";
+import { MODEL_PRICING } from "./types";
+
+/** Raised instead of sending a request once a monthly budget is used up */
+export class AIBudgetExceededError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = "AIBudgetExceededError";
+  }
+}
+
+/**
+ * Calculate cost estimate from token usage
+ */
+export function calculateCost(usage: TokenUsage, modelId: string): number {
+  const pricing = MODEL_PRICING[modelId];
+  if (!pricing) return 0;
+
+  const inputCost = (usage.promptTokens / 1_000_000) * pricing.inputPer1M;
+  const outputCost = (usage.completionTokens / 1_000_000) * pricing.outputPer1M;
+  return inputCost + outputCost;
+}
+
+/**
+ * Token counts from an AI SDK usage object
+ */
+export function toTokenUsage(usage: { inputTokens?: number; outputTokens?: number }): TokenUsage {
+  const promptTokens = usage.inputTokens ?? 0;
+  const completionTokens = usage.outputTokens ?? 0;
+  return { promptTokens, completionTokens, totalTokens: promptTokens + completionTokens };
+}
+
+/**
+ * Record the tokens a request spent, and how long it took for the performance
+ * metrics. Failures are only logged: the request has already been answered.
+ */
+export async function recordUsage(
+  settings: AISettings,
+  modelId: string,
+  operation: string,
+  usage: TokenUsage,
+  durationMs?: number
+): Promise<void> {
+  const record: AIUsageRecord = {
+    provider: settings.aiProvider || "anthropic",
+    model: modelId,
+    operation,
+    ...usage,
+    estimatedCost: calculateCost(usage, modelId),
+    durationMs: durationMs === undefined ? undefined : Math.round(durationMs),
+  };
+  try {
+    await invoke("record_ai_usage", { record });
+  } catch (error) {
+    console.warn("[AI Usage] Failed to record usage:", error);
+  }
+}
+
+/**
+ * Tokens and cost of AI requests in a month (`YYYY-MM`), the current one by default
+ */
+export async function getAIUsageStats(month?: string): Promise<AIUsageStats> {
+  return invoke<AIUsageStats>("get_ai_usage_stats", { month });
+}
+
+/**
+ * Throw AIBudgetExceededError when this month's usage has reached a budget
+ * set in the AI settings
+ */
+export async function checkBudget(settings: AISettings): Promise<void> {
+  const tokenBudget = settings.aiMonthlyTokenBudget;
+  const costBudget = settings.aiMonthlyCostBudget;
+  if (!tokenBudget && !costBudget) return;
+
+  const stats = await getAIUsageStats();
+  if (tokenBudget && stats.totalTokens >= tokenBudget) {
+    throw new AIBudgetExceededError(
+      `Monthly AI token budget reached: ${stats.totalTokens.toLocaleString()} of ` +
+        `${tokenBudget.toLocaleString()} tokens used in ${stats.month}. ` +
+        "Raise the budget in AI settings or wait until next month."
+    );
+  }
+  if (costBudget && stats.estimatedCost >= costBudget) {
+    throw new AIBudgetExceededError(
+      `Monthly AI cost budget reached: about $${stats.estimatedCost.toFixed(2)} of ` +
+        `$${costBudget.toFixed(2)} spent in ${stats.month}. ` +
+        "Raise the budget in AI settings or wait until next month."
+    );
+  }
+}
@@ -0,0 +1,89 @@
+import type { CellValue, ColumnInfo } from "@/types";
+
+type CellKind = CellValue["type"] | "decimal" | "any";
+
+/** The kind a column's values are sent as, from its type name */
+function columnKind(dataType: string): CellKind {
+  const type = dataType.toLowerCase();
+  // Arrays go as they are, for the driver to bind element by element
+  if (type.endsWith("[]") || type.startsWith("_")) return "any";
+  if (type.includes("json")) return "json";
+  if (type.includes("bool") || /^bit(\(1\))?$/.test(type)) return "bool";
+  if (/(^|[^a-z])u?(tiny|small|medium|big)?(int(eger|\d+)?|serial\d?)([^a-z]|$)/.test(type)) return "int";
+  if (/float|double|real/.test(type)) return "float";
+  // Exact numbers go as text so no digits are lost
+  if (/numeric|decimal|money|number/.test(type)) return "decimal";
+  if ((type.includes("date") || type.includes("time")) && !type.includes("interval")) return "timestamp";
+  return "any";
+}
+
+/** A value by its JavaScript type, for columns with no kind of their own */
+function fromJson(value: unknown): CellValue {
+  if (value === null || value === undefined) return { type: "null" };
+  if (typeof value === "string") return { type: "text", value };
+  if (typeof value === "boolean") return { type: "bool", value };
+  if (typeof value === "number") {
+    return Number.isInteger(value) ? { type: "int", value } : { type: "float", value };
+  }
+  return { type: "json", value };
+}
+
+/**
+ * A result or edited value as the kind its column takes. Text that does not
+ * fit the column, such as "abc" for an integer, is sent as text and left to
+ * the database to reject.
+ */
+export function toCellValue(value: unknown, column?: Pick<ColumnInfo, "dataType">): CellValue {
+  if (value === null || value === undefined) return { type: "null" };
+
+  // Binary values marked by the driver; unmarked ones in binary columns are
+  // sent as text, which is base64 for PostgreSQL and the bytes themselves for
+  // the others, as they appear in results
+  if (typeof value === "string") {
+    const marked = value.match(/^\[base64: (.*)\]$/s);
+    if (marked) return { type: "bytes", value: marked[1] };
+  }
+
+  const text = typeof value === "string" ? value : typeof value === "object" ? JSON.stringify(value) : String(value);
+  switch (column ? columnKind(column.dataType) : "any") {
+    case "json":
+      if (typeof value !== "string") return { type: "json", value };
+      try {
+        return { type: "json", value: JSON.parse(value) };
+      } catch {
+        // Text that is not JSON is stored as a JSON string
+        return { type: "json", value };
+      }
+    case "bool":
+      if (typeof value === "boolean") return { type: "bool", value };
+      if (/^(true|t|1)$/i.test(text.trim())) return { type: "bool", value: true };
+      if (/^(false|f|0)$/i.test(text.trim())) return { type: "bool", value: false };
+      return { type: "text", value: text };
+    case "int": {
+      const number = typeof value === "number" ? value : /^\s*-?\d+\s*$/.test(text) ? Number(text) : NaN;
+      // Integers past 2^53 go as text so the database reads every digit
+      return Number.isSafeInteger(number) ? { type: "int", value: number } : { type: "text", value: text.trim() };
+    }
+    case "float": {
+      const number = typeof value === "number" ? value : text.trim() === "" ? NaN : Number(text);
+      return Number.isFinite(number) ? { type: "float", value: number } : { type: "text", value: text };
+    }
+    case "decimal":
+      return { type: "text", value: text.trim() };
+    case "timestamp":
+      return { type: "timestamp", value: text };
+    default:
+      return fromJson(value);
+  }
+}
+
+/** The values of a row by column name, each as the kind its column takes */
+export function toCellValues(values: Record<string, unknown>, columns: ColumnInfo[] = []): Record<string, CellValue> {
+  const cells: Record<string, CellValue> = {};
+  for (const [name, value] of Object.entries(values)) {
+    // Undefined means the column was left out, not NULL
+    if (value === undefined) continue;
+    cells[name] = toCellValue(value, columns.find((column) => column.name === name));
+  }
+  return cells;
+}
@@ -27,6 +27,8 @@ export const DATABASE_DEFAULTS: Record<DatabaseType, DatabaseDefaults> = {
   oracle: { port: 1521, username: "system", host: "localhost" },
   mongodb: { port: 27017, username: "", host: "localhost" },
   redis: { port: 6379, username: "", host: "localhost" },
+  clickhouse: { port: 8123, username: "default", host: "localhost" },
+  duckdb: { port: 0, username: "", host: "" },
   cockroachdb: { port: 26257, username: "root", host: "localhost" },
   cassandra: { port: 9042, username: "cassandra", host: "localhost" },
 };
@@ -43,6 +45,8 @@ export const DATABASE_METADATA: Record<DatabaseType, DatabaseMetadata> = {
   oracle: { name: "Oracle", icon: "🔴", brand: "oracle", color: "text-[#F80000]" },
   mongodb: { name: "MongoDB", icon: "🍃", brand: "mongodb", color: "text-[#47A248]" },
   redis: { name: "Redis", icon: "🔴", brand: "redis", color: "text-[#FF4438]" },
+  clickhouse: { name: "ClickHouse", icon: "🟡", brand: "clickhouse", color: "text-[#FFCC01]" },
+  duckdb: { name: "DuckDB", icon: "🦆", brand: "duckdb", color: "text-[#FFF000]" },
   cockroachdb: { name: "CockroachDB", icon: "🪳", brand: "cockroachdb", color: "text-[#6933FF]" },
   cassandra: { name: "Cassandra", icon: "🔵", brand: "apachecassandra", color: "text-[#1287B1]" },
 };
@@ -0,0 +1,53 @@
+/**
+ * Bundled Extensions
+ *
+ * Frontend side of the lifecycle hooks of the extensions that ship with the
+ * app. The backend keeps their status and runs its own hooks; the features
+ * implemented here follow that status.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+import type { InstalledExtension } from "@/types";
+import { useAIStore } from "@/lib/ai/store";
+import { useUIStore } from "@/stores";
+
+type BuiltinHook = (active: boolean) => void | Promise<void>;
+
+const BUILTIN_HOOKS: Record<string, BuiltinHook> = {
+  "ai-assistant": (active) => useAIStore.getState().updateSettings({ aiEnabled: active }),
+  // Reapply the theme; a Nordic variant falls back to the system theme once turned off
+  "theme-nordic": () => {
+    const { theme, setTheme } = useUIStore.getState();
+    setTheme(theme);
+  },
+};
+
+/**
+ * Run the frontend hook of a bundled extension for its current status
+ */
+export async function applyBuiltinStatus(extension: InstalledExtension): Promise<void> {
+  const hook = BUILTIN_HOOKS[extension.manifest.id];
+  if (hook) await hook(extension.status === "active");
+}
+
+/**
+ * Turn a bundled extension on or off, in the backend and then in the frontend
+ */
+export async function setBuiltinExtensionEnabled(id: string, enabled: boolean): Promise<InstalledExtension> {
+  const extension = await invoke<InstalledExtension>("set_builtin_extension_enabled", { id, enabled });
+  await applyBuiltinStatus(extension);
+  return extension;
+}
+
+/**
+ * Bring the frontend in line with the bundled extensions that are turned off
+ * or failed to start
+ */
+export async function syncBuiltinExtensions(): Promise<void> {
+  const extensions = await invoke<InstalledExtension[]>("list_installed_extensions");
+  for (const extension of extensions) {
+    if (extension.bundled && extension.status !== "active") {
+      await applyBuiltinStatus(extension);
+    }
+  }
+}
@@ -0,0 +1,9 @@
+/**
+ * Extensions Library - Main exports
+ */
+
+export type { Extension, ExtensionCommand, NotificationKind } from "./runtime";
+export { ExtensionInstance } from "./runtime";
+export type { ExtensionStatus, RunningExtension } from "./store";
+export { useExtensionsStore } from "./store";
+export { applyBuiltinStatus, setBuiltinExtensionEnabled, syncBuiltinExtensions } from "./builtin";
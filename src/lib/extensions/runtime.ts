@@ -0,0 +1,432 @@
+/**
+ * Extension Runtime
+ *
+ * Runs an installed extension's entry point in its own Web Worker. Workers
+ * have no DOM and no access to the Tauri bridge, and the app's content
+ * security policy keeps them from loading remote scripts or connecting
+ * anywhere. Network APIs are removed too; with the `network` permission the
+ * extension gets a `fetch` that the backend carries out instead. The
+ * extension talks to the app only through the host API:
+ *
+ * - `dbfordevs.commands.register(id, title, handler)` adds a command; the
+ *   handler receives the command's arguments
+ * - `dbfordevs.settings.get()` / `dbfordevs.settings.update(values)`, where
+ *   values of contributed settings must match their declared type
+ * - `dbfordevs.notifications.show(message, kind)`
+ * - `dbfordevs.log.debug/info/warn/error(...values)` writes to the
+ *   extension's log, which can be read in Settings > Extensions; `console`
+ *   is redirected there too
+ *
+ * A JavaScript entry point sets `module.exports` to an {@link Extension}.
+ * A WebAssembly module exports `memory`, `alloc(len)`, optional `activate()`,
+ * `deactivate()` and `run_command(ptr, len)`, and imports `env.notify(ptr,
+ * len, kind)`, `env.register_command(idPtr, idLen, titlePtr, titleLen)`,
+ * `env.settings_read(ptr, len)`, `env.settings_write(ptr, len)` and
+ * `env.log(ptr, len, level)` with levels 0 to 3 for debug to error.
+ *
+ * Lifecycle hooks and commands are given a time limit; an extension that
+ * overruns it, or throws from `activate`, is stopped.
+ */
+
+import { invoke } from "@tauri-apps/api/core";
+import type { ExtensionEntry, ExtensionFetchResponse, ExtensionLogLevel } from "@/types";
+
+/** What a JavaScript extension's entry script exports */
+export interface Extension {
+  activate(host: unknown): void | Promise<void>;
+  deactivate?(): void | Promise<void>;
+}
+
+export type NotificationKind = "info" | "success" | "error";
+
+/** A command an extension registered */
+export interface ExtensionCommand {
+  id: string;
+  title: string;
+}
+
+export interface ExtensionHostCallbacks {
+  onCommandRegistered(command: ExtensionCommand): void;
+  onNotification(message: string, kind: NotificationKind): void;
+  /** The worker crashed or was stopped for overrunning a hook */
+  onFailure(error: string): void;
+}
+
+const ACTIVATE_TIMEOUT_MS = 5000;
+const DEACTIVATE_TIMEOUT_MS = 2000;
+const COMMAND_TIMEOUT_MS = 10000;
+
+/** Log lines kept per second; a chatty extension cannot flood the backend */
+const MAX_LOGS_PER_SECOND = 50;
+
+const LOG_LEVELS: ExtensionLogLevel[] = ["debug", "info", "warn", "error"];
+
+/**
+ * Bootstrap evaluated in the worker before the extension. Kept as a string so
+ * the worker can be created from a Blob without a separate bundle entry.
+ */
+const WORKER_BOOTSTRAP = `
+"use strict";
+const post = self.postMessage.bind(self);
+const NETWORK = ["fetch", "XMLHttpRequest", "WebSocket", "EventSource", "WebTransport"];
+const ALWAYS_BLOCKED = ["indexedDB", "caches", "BroadcastChannel", "Worker", "SharedWorker"];
+
+function block(name, value) {
+  for (let target = self; target; target = Object.getPrototypeOf(target)) {
+    if (Object.prototype.hasOwnProperty.call(target, name)) {
+      try { Object.defineProperty(target, name, { value, configurable: false, writable: false }); } catch (e) {}
+    }
+  }
+}
+
+let settings = {};
+const commands = new Map();
+let extension = null;
+let wasm = null;
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+function notify(message, kind) {
+  post({ type: "notify", message: String(message), kind: kind === "success" || kind === "error" ? kind : "info" });
+}
+
+function register(id, title, handler) {
+  commands.set(String(id), handler);
+  post({ type: "register", id: String(id), title: String(title) });
+}
+
+function describe(value) {
+  if (typeof value === "string") return value;
+  if (value instanceof Error) return value.stack || value.message;
+  try {
+    return JSON.stringify(value);
+  } catch (e) {
+    return String(value);
+  }
+}
+
+function log(level, values) {
+  post({ type: "log", level, message: values.map(describe).join(" ") });
+}
+
+const logger = Object.freeze({
+  debug: (...values) => log("debug", values),
+  info: (...values) => log("info", values),
+  warn: (...values) => log("warn", values),
+  error: (...values) => log("error", values),
+});
+
+function toBase64(bytes) {
+  let binary = "";
+  for (let i = 0; i < bytes.length; i += 0x8000) {
+    binary += String.fromCharCode.apply(null, bytes.subarray(i, i + 0x8000));
+  }
+  return btoa(binary);
+}
+
+const fetches = new Map();
+let nextFetchId = 0;
+
+/** fetch() for extensions with the network permission, carried out by the backend */
+async function relayFetch(input, init) {
+  const request = new Request(input, init);
+  const body = new Uint8Array(await request.arrayBuffer());
+  const response = await new Promise((resolve, reject) => {
+    const fetchId = nextFetchId++;
+    fetches.set(fetchId, { resolve, reject });
+    post({
+      type: "fetch",
+      fetchId,
+      request: {
+        url: request.url,
+        method: request.method,
+        headers: Array.from(request.headers.entries()),
+        body: body.length ? toBase64(body) : null,
+      },
+    });
+  });
+  const bytes = Uint8Array.from(atob(response.body), (c) => c.charCodeAt(0));
+  const empty = [101, 204, 205, 304].includes(response.status);
+  return new Response(empty ? null : bytes, {
+    status: response.status,
+    statusText: response.statusText,
+    headers: response.headers,
+  });
+}
+
+function fetchDone(message) {
+  const pending = fetches.get(message.fetchId);
+  if (!pending) return;
+  fetches.delete(message.fetchId);
+  if (typeof message.error === "string") pending.reject(new TypeError(message.error));
+  else pending.resolve(message.response);
+}
+
+function updateSettings(values) {
+  settings = Object.assign({}, settings, values);
+  post({ type: "settings", values });
+}
+
+const host = Object.freeze({
+  commands: Object.freeze({ register }),
+  settings: Object.freeze({ get: () => JSON.parse(JSON.stringify(settings)), update: updateSettings }),
+  notifications: Object.freeze({ show: notify }),
+  log: logger,
+});
+
+function readString(ptr, len) {
+  return decoder.decode(new Uint8Array(wasm.memory.buffer, ptr, len));
+}
+
+function writeString(text) {
+  const bytes = encoder.encode(text);
+  const ptr = wasm.alloc(bytes.length);
+  new Uint8Array(wasm.memory.buffer, ptr, bytes.length).set(bytes);
+  return [ptr, bytes.length];
+}
+
+async function load(entry) {
+  settings = entry.settings || {};
+  const permissions = entry.manifest.permissions || [];
+  const network = permissions.includes("network");
+  NETWORK.forEach((name) => block(name, network && name === "fetch" ? relayFetch : undefined));
+  ALWAYS_BLOCKED.forEach((name) => block(name));
+  console.log = logger.info;
+  console.info = logger.info;
+  console.debug = logger.debug;
+  console.warn = logger.warn;
+  console.error = logger.error;
+  self.onunhandledrejection = (event) => log("error", ["Unhandled rejection:", event.reason]);
+
+  if (entry.runtime === "wasm") {
+    const bytes = Uint8Array.from(atob(entry.source), (c) => c.charCodeAt(0));
+    const { instance } = await WebAssembly.instantiate(bytes, {
+      env: {
+        notify: (ptr, len, kind) => notify(readString(ptr, len), ["info", "success", "error"][kind] || "info"),
+        register_command: (idPtr, idLen, titlePtr, titleLen) => {
+          const id = readString(idPtr, idLen);
+          register(id, readString(titlePtr, titleLen), () => {
+            const [ptr, len] = writeString(id);
+            wasm.run_command(ptr, len);
+          });
+        },
+        settings_read: (ptr, len) => {
+          const bytes = encoder.encode(JSON.stringify(settings));
+          if (bytes.length <= len) new Uint8Array(wasm.memory.buffer, ptr, bytes.length).set(bytes);
+          return bytes.length;
+        },
+        settings_write: (ptr, len) => updateSettings(JSON.parse(readString(ptr, len))),
+        log: (ptr, len, level) => log(["debug", "info", "warn", "error"][level] || "info", [readString(ptr, len)]),
+      },
+    });
+    wasm = instance.exports;
+    extension = {
+      activate: () => wasm.activate && wasm.activate(),
+      deactivate: () => wasm.deactivate && wasm.deactivate(),
+    };
+  } else {
+    // Loaded as a script rather than evaluated, which the content security policy forbids
+    const wrapped = "self.__extensionMain = function (module, exports, dbfordevs) {\\n" + entry.source + "\\n};";
+    const url = URL.createObjectURL(new Blob([wrapped], { type: "text/javascript" }));
+    try {
+      loadScript(url);
+    } finally {
+      URL.revokeObjectURL(url);
+    }
+    const main = self.__extensionMain;
+    delete self.__extensionMain;
+    const module = { exports: {} };
+    main(module, module.exports, host);
+    extension = module.exports.default || module.exports;
+  }
+  if (!extension || typeof extension.activate !== "function") {
+    throw new Error("The extension does not export an activate function");
+  }
+}
+
+const loadScript = self.importScripts.bind(self);
+block("importScripts");
+
+self.onmessage = async (event) => {
+  const message = event.data;
+  if (message.type === "fetchDone") return fetchDone(message);
+  try {
+    let result;
+    if (message.type === "load") {
+      await load(message.entry);
+    } else if (message.type === "activate") {
+      await extension.activate(host);
+    } else if (message.type === "deactivate") {
+      if (extension.deactivate) await extension.deactivate();
+    } else if (message.type === "command") {
+      const handler = commands.get(message.commandId);
+      if (!handler) throw new Error("Unknown command " + message.commandId);
+      result = await handler(message.args);
+    }
+    post({ type: "done", callId: message.callId, result: result === undefined ? null : JSON.parse(JSON.stringify(result)) });
+  } catch (error) {
+    post({ type: "done", callId: message.callId, error: error && error.message ? error.message : String(error) });
+  }
+};
+`;
+
+interface PendingCall {
+  resolve: (result: unknown) => void;
+  reject: (error: Error) => void;
+  timer: ReturnType<typeof setTimeout>;
+}
+
+/**
+ * One running extension
+ */
+export class ExtensionInstance {
+  readonly id: string;
+  private worker: Worker | null = null;
+  private workerUrl: string | null = null;
+  private nextCallId = 0;
+  private pending = new Map<number, PendingCall>();
+  private logWindowStart = 0;
+  private logCount = 0;
+
+  constructor(
+    id: string,
+    private callbacks: ExtensionHostCallbacks
+  ) {
+    this.id = id;
+  }
+
+  /**
+   * Load the entry point into a new worker and run `activate`
+   */
+  async activate(): Promise<ExtensionEntry> {
+    const entry = await invoke<ExtensionEntry>("load_extension", { id: this.id });
+
+    this.workerUrl = URL.createObjectURL(new Blob([WORKER_BOOTSTRAP], { type: "text/javascript" }));
+    this.worker = new Worker(this.workerUrl);
+    this.worker.onmessage = (event) => this.handleMessage(event.data);
+    this.worker.onerror = (event) => {
+      event.preventDefault();
+      this.fail(event.message || "The extension crashed");
+    };
+
+    try {
+      await this.call({ type: "load", entry }, ACTIVATE_TIMEOUT_MS, "load");
+      await this.call({ type: "activate" }, ACTIVATE_TIMEOUT_MS, "activate");
+    } catch (error) {
+      this.stop();
+      throw error;
+    }
+    return entry;
+  }
+
+  /**
+   * Run `deactivate`, then stop the worker whether or not it finished
+   */
+  async deactivate(): Promise<void> {
+    if (!this.worker) return;
+    try {
+      await this.call({ type: "deactivate" }, DEACTIVATE_TIMEOUT_MS, "deactivate");
+    } finally {
+      this.stop();
+    }
+  }
+
+  /**
+   * Run one of the extension's registered commands
+   */
+  async runCommand(commandId: string, args?: unknown): Promise<unknown> {
+    if (!this.worker) throw new Error(`${this.id} is not running`);
+    return this.call({ type: "command", commandId, args: args ?? null }, COMMAND_TIMEOUT_MS, commandId);
+  }
+
+  private call(message: Record<string, unknown>, timeoutMs: number, label: string): Promise<unknown> {
+    const worker = this.worker;
+    if (!worker) return Promise.reject(new Error(`${this.id} is not running`));
+
+    const callId = this.nextCallId++;
+    return new Promise((resolve, reject) => {
+      const timer = setTimeout(() => {
+        this.pending.delete(callId);
+        const error = `${this.id} did not finish ${label} within ${timeoutMs / 1000}s and was stopped`;
+        reject(new Error(error));
+        this.fail(error);
+      }, timeoutMs);
+      this.pending.set(callId, { resolve, reject, timer });
+      worker.postMessage({ ...message, callId });
+    });
+  }
+
+  private handleMessage(message: Record<string, unknown>) {
+    switch (message.type) {
+      case "done": {
+        const call = this.pending.get(message.callId as number);
+        if (!call) return;
+        clearTimeout(call.timer);
+        this.pending.delete(message.callId as number);
+        if (typeof message.error === "string") call.reject(new Error(message.error));
+        else call.resolve(message.result);
+        break;
+      }
+      case "register":
+        this.callbacks.onCommandRegistered({ id: String(message.id), title: String(message.title) });
+        break;
+      case "notify":
+        this.callbacks.onNotification(String(message.message), message.kind as NotificationKind);
+        break;
+      case "log": {
+        const level = LOG_LEVELS.find((known) => known === message.level) ?? "info";
+        this.log(level, String(message.message));
+        break;
+      }
+      case "fetch":
+        invoke<ExtensionFetchResponse>("extension_fetch", { id: this.id, request: message.request })
+          .then((response) => this.worker?.postMessage({ type: "fetchDone", fetchId: message.fetchId, response }))
+          .catch((error) =>
+            this.worker?.postMessage({ type: "fetchDone", fetchId: message.fetchId, error: String(error) })
+          );
+        break;
+      case "settings":
+        for (const [key, value] of Object.entries(message.values as Record<string, unknown>)) {
+          invoke("set_extension_setting", { id: this.id, key, value: value ?? null }).catch((error) =>
+            this.callbacks.onNotification(`Could not save setting ${key}: ${error}`, "error")
+          );
+        }
+        break;
+    }
+  }
+
+  /**
+   * Write to the extension's log, dropping lines past the rate limit
+   */
+  private log(level: ExtensionLogLevel, message: string) {
+    const now = Date.now();
+    if (now - this.logWindowStart >= 1000) {
+      const dropped = this.logCount - MAX_LOGS_PER_SECOND;
+      this.logWindowStart = now;
+      this.logCount = 0;
+      if (dropped > 0) this.log("warn", `${dropped} log lines were dropped`);
+    }
+    if (++this.logCount > MAX_LOGS_PER_SECOND) return;
+    invoke("append_extension_log", { extensionId: this.id, level, message }).catch((error) =>
+      console.error(`[Extensions] Failed to write the log of ${this.id}:`, error)
+    );
+  }
+
+  private fail(error: string) {
+    if (!this.worker) return;
+    this.stop();
+    this.callbacks.onFailure(error);
+  }
+
+  private stop() {
+    this.worker?.terminate();
+    this.worker = null;
+    if (this.workerUrl) URL.revokeObjectURL(this.workerUrl);
+    this.workerUrl = null;
+    for (const call of this.pending.values()) {
+      clearTimeout(call.timer);
+      call.reject(new Error(`${this.id} was stopped`));
+    }
+    this.pending.clear();
+  }
+}
@@ -0,0 +1,173 @@
+/**
+ * Extensions Store
+ *
+ * Which extensions are enabled, and the state of the running ones. Enabled
+ * extensions are started again when the app loads. Also answers the
+ * backend's "extension-command" requests, starting the extension if needed,
+ * restarts extensions on "extension-restart", and reports failures to the
+ * backend so they show up as the extension's status.
+ */
+
+import { create } from "zustand";
+import { persist } from "zustand/middleware";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import type { ExtensionCommandRequest } from "@/types";
+import { showErrorToast, showInfoToast, showSuccessToast } from "@/lib/toast-helpers";
+import { ExtensionInstance, type ExtensionCommand } from "./runtime";
+
+export type ExtensionStatus = "starting" | "running" | "stopping" | "failed";
+
+export interface RunningExtension {
+  name: string;
+  status: ExtensionStatus;
+  commands: ExtensionCommand[];
+  error?: string;
+}
+
+interface ExtensionsState {
+  /** Ids of the extensions started with the app */
+  enabled: string[];
+  running: Record<string, RunningExtension>;
+  activate: (id: string) => Promise<void>;
+  deactivate: (id: string) => Promise<void>;
+  runCommand: (id: string, commandId: string, args?: unknown) => Promise<unknown>;
+}
+
+/** Worker handles; kept out of the state so it stays serializable */
+const instances = new Map<string, ExtensionInstance>();
+
+/** Tell the backend an extension failed, or started again when there is no error */
+const reportStatus = (id: string, error: string | null) =>
+  invoke("report_extension_status", { id, error }).catch((e) =>
+    console.error(`[Extensions] Failed to report the status of ${id}:`, e)
+  );
+
+export const useExtensionsStore = create<ExtensionsState>()(
+  persist(
+    (set, get) => {
+      const update = (id: string, changes: Partial<RunningExtension>) =>
+        set((state) => {
+          const current = state.running[id];
+          if (!current) return state;
+          return { running: { ...state.running, [id]: { ...current, ...changes } } };
+        });
+      const remove = (id: string) =>
+        set((state) => {
+          const running = { ...state.running };
+          delete running[id];
+          return { running };
+        });
+
+      return {
+        enabled: [],
+        running: {},
+
+        activate: async (id) => {
+          if (instances.has(id)) return;
+
+          const instance = new ExtensionInstance(id, {
+            onCommandRegistered: (command) =>
+              set((state) => {
+                const current = state.running[id];
+                if (!current) return state;
+                const commands = [...current.commands.filter((c) => c.id !== command.id), command];
+                return { running: { ...state.running, [id]: { ...current, commands } } };
+              }),
+            onNotification: (message, kind) => {
+              const title = get().running[id]?.name ?? id;
+              if (kind === "error") showErrorToast(title, message);
+              else if (kind === "success") showSuccessToast(title, message);
+              else showInfoToast(title, message);
+            },
+            onFailure: (error) => {
+              instances.delete(id);
+              update(id, { status: "failed", commands: [], error });
+              reportStatus(id, error);
+            },
+          });
+          instances.set(id, instance);
+          set((state) => ({
+            running: { ...state.running, [id]: { name: id, status: "starting", commands: [] } },
+            enabled: state.enabled.includes(id) ? state.enabled : [...state.enabled, id],
+          }));
+
+          try {
+            const entry = await instance.activate();
+            update(id, { name: entry.manifest.name, status: "running", error: undefined });
+            reportStatus(id, null);
+          } catch (error) {
+            const message = error instanceof Error ? error.message : String(error);
+            instances.delete(id);
+            update(id, { status: "failed", commands: [], error: message });
+            reportStatus(id, message);
+          }
+        },
+
+        deactivate: async (id) => {
+          const instance = instances.get(id);
+          set((state) => ({ enabled: state.enabled.filter((enabledId) => enabledId !== id) }));
+          if (!instance) {
+            remove(id);
+            return;
+          }
+
+          update(id, { status: "stopping" });
+          try {
+            await instance.deactivate();
+          } catch (error) {
+            console.warn(`[Extensions] ${id} did not deactivate cleanly:`, error);
+          } finally {
+            instances.delete(id);
+            remove(id);
+          }
+        },
+
+        runCommand: async (id, commandId, args) => {
+          if (!instances.has(id)) {
+            await get().activate(id);
+          }
+          const state = get().running[id];
+          const instance = instances.get(id);
+          if (!instance || state?.status !== "running") {
+            throw new Error(state?.error ?? `${id} is not running`);
+          }
+          if (!state.commands.some((command) => command.id === commandId)) {
+            throw new Error(`${state.name} did not register ${commandId} when it started`);
+          }
+          return instance.runCommand(commandId, args);
+        },
+      };
+    },
+    {
+      name: "dbfordevs-extensions",
+      partialize: (state) => ({ enabled: state.enabled }),
+      onRehydrateStorage: () => (state) => {
+        if (!state) return;
+        // Start the extensions that were enabled in the last session
+        for (const id of state.enabled) {
+          state.activate(id).catch((error) => console.error(`[Extensions] Failed to start ${id}:`, error));
+        }
+      },
+    }
+  )
+);
+
+// Run the commands the backend dispatches and send back the outcome
+listen<ExtensionCommandRequest>("extension-command", async ({ payload }) => {
+  const { requestId, extensionId, commandId, args } = payload;
+  try {
+    const result = await useExtensionsStore.getState().runCommand(extensionId, commandId, args);
+    await invoke("complete_extension_command", { requestId, result: result ?? null, error: null });
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    await invoke("complete_extension_command", { requestId, result: null, error: message }).catch(console.error);
+  }
+}).catch((error) => console.error("[Extensions] Failed to listen for commands:", error));
+
+// Restart an extension's worker when asked through restart_extension
+listen<string>("extension-restart", async ({ payload: id }) => {
+  const { deactivate, activate } = useExtensionsStore.getState();
+  await deactivate(id);
+  await activate(id);
+}).catch((error) => console.error("[Extensions] Failed to listen for restarts:", error));
@@ -0,0 +1,71 @@
+/**
+ * Keymap helpers
+ *
+ * Shortcuts from the backend's keymap look like "Mod+Alt+Shift+K": the
+ * modifiers in that order, then the key. Mod is Cmd on macOS and Ctrl
+ * elsewhere; letters and digits are matched by their physical key so that
+ * Alt and Shift combinations still match.
+ */
+
+const PUNCTUATION_CODES: Record<string, string> = {
+  Comma: ",",
+  Period: ".",
+  Slash: "/",
+  Semicolon: ";",
+  Quote: "'",
+  BracketLeft: "[",
+  BracketRight: "]",
+  Backslash: "\\",
+  Minus: "-",
+  Equal: "=",
+  Backquote: "`",
+};
+
+const MODIFIER_KEYS = ["Meta", "Control", "Alt", "Shift"];
+
+const KEY_LABELS: Record<string, string> = {
+  Mod: "Cmd",
+  Alt: "Option",
+  Escape: "Esc",
+  ArrowUp: "↑",
+  ArrowDown: "↓",
+  ArrowLeft: "←",
+  ArrowRight: "→",
+};
+
+function eventKey(e: KeyboardEvent): string | null {
+  if (/^Key[A-Z]$/.test(e.code)) return e.code.slice(3);
+  if (/^Digit[0-9]$/.test(e.code)) return e.code.slice(5);
+  if (PUNCTUATION_CODES[e.code]) return PUNCTUATION_CODES[e.code];
+  if (e.key === " ") return "Space";
+  if (!e.key || MODIFIER_KEYS.includes(e.key)) return null;
+  return e.key.length === 1 ? e.key.toUpperCase() : e.key;
+}
+
+/**
+ * The shortcut a key press makes, or null for a lone modifier
+ */
+export function shortcutFromEvent(e: KeyboardEvent): string | null {
+  const key = eventKey(e);
+  if (!key) return null;
+  const parts: string[] = [];
+  if (e.metaKey || e.ctrlKey) parts.push("Mod");
+  if (e.altKey) parts.push("Alt");
+  if (e.shiftKey) parts.push("Shift");
+  parts.push(key);
+  return parts.join("+");
+}
+
+/**
+ * Whether the key press is exactly the shortcut, with no extra modifiers
+ */
+export function matchesShortcut(e: KeyboardEvent, shortcut: string): boolean {
+  return shortcutFromEvent(e) === shortcut;
+}
+
+/**
+ * The keys of a shortcut as shown to the user, e.g. ["Cmd", "Shift", "K"]
+ */
+export function shortcutKeys(shortcut: string): string[] {
+  return shortcut.split("+").map((key) => KEY_LABELS[key] ?? key);
+}
@@ -4,3 +4,4 @@ export * from "./ui";
 export * from "./crud";
 export * from "./schema";
 
+export * from "./keymap";
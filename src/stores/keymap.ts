@@ -0,0 +1,30 @@
+import { create } from "zustand";
+import { invoke } from "@tauri-apps/api/core";
+import type { KeyBinding } from "@/types";
+
+interface KeymapState {
+  /** The resolved keymap; empty until loaded */
+  bindings: KeyBinding[];
+
+  // Actions
+  loadKeymap: () => Promise<void>;
+  /** Change a command's shortcut; null leaves it unbound */
+  setKeybinding: (commandId: string, shortcut: string | null) => Promise<void>;
+  resetKeybinding: (commandId: string) => Promise<void>;
+}
+
+export const useKeymapStore = create<KeymapState>((set) => ({
+  bindings: [],
+
+  loadKeymap: async () => {
+    set({ bindings: await invoke<KeyBinding[]>("get_keymap") });
+  },
+
+  setKeybinding: async (commandId, shortcut) => {
+    set({ bindings: await invoke<KeyBinding[]>("set_keybinding", { commandId, shortcut }) });
+  },
+
+  resetKeybinding: async (commandId) => {
+    set({ bindings: await invoke<KeyBinding[]>("reset_keybinding", { commandId }) });
+  },
+}));
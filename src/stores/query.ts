@@ -1,5 +1,6 @@
 import { create } from "zustand";
 import { persist } from "zustand/middleware";
+import { invoke } from "@tauri-apps/api/core";
 import type { QueryResult, Tab, TableInfo, TableSchema, QueryHistoryEntry } from "@/types";
 
 interface QueryState {
@@ -25,8 +26,11 @@ interface QueryState {
   removeTab: (id: string) => void;
   setActiveTab: (id: string | null) => void;
   updateTabContent: (id: string, content: string) => void;
+  updateTabLayout: (id: string, layout: Pick<Tab, "resultPaneSize" | "editorScroll">) => void;
+  restoreTabs: (tabs: Tab[], activeTabId: string | null) => void;
   setResults: (tabId: string, results: QueryResult) => void;
   clearResults: (tabId: string) => void;
+  appendResultRows: (tabId: string, rows: QueryResult["rows"]) => void;
   setTablesForConnection: (connectionId: string, tables: TableInfo[]) => void;
   clearTablesForConnection: (connectionId: string) => void;
   setTableSchema: (schema: TableSchema | null) => void;
@@ -57,6 +61,12 @@ export const useQueryStore = create<QueryState>()(
 
   removeTab: (id) =>
     set((state) => {
+      const closing = state.tabs.find((t) => t.id === id);
+      if (closing?.type === "query") {
+        // Return the tab's session connection to the pool; it may never have been opened
+        invoke("close_session", { connectionId: closing.connectionId, sessionId: id }).catch(() => {});
+      }
+
       const newTabs = state.tabs.filter((t) => t.id !== id);
       const newResults = { ...state.results };
       delete newResults[id];
@@ -81,6 +91,13 @@ export const useQueryStore = create<QueryState>()(
       tabs: state.tabs.map((t) => (t.id === id ? { ...t, content } : t)),
     })),
 
+  updateTabLayout: (id, layout) =>
+    set((state) => ({
+      tabs: state.tabs.map((t) => (t.id === id ? { ...t, ...layout } : t)),
+    })),
+
+  restoreTabs: (tabs, activeTabId) => set({ tabs, activeTabId }),
+
   setResults: (tabId, results) =>
     set((state) => ({
       results: { ...state.results, [tabId]: results },
@@ -94,6 +111,13 @@ export const useQueryStore = create<QueryState>()(
       return { results: newResults };
     }),
 
+  appendResultRows: (tabId, rows) =>
+    set((state) => {
+      const results = state.results[tabId];
+      if (!results) return state;
+      return { results: { ...state.results, [tabId]: { ...results, rows: [...results.rows, ...rows] } } };
+    }),
+
   setTablesForConnection: (connectionId, tables) =>
     set((state) => ({
       tablesByConnection: { ...state.tablesByConnection, [connectionId]: tables },
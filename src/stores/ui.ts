@@ -1,16 +1,17 @@
 import { create } from "zustand";
 import { persist } from "zustand/middleware";
-import type { PendingChange } from "@/types";
+import { invoke } from "@tauri-apps/api/core";
+import type { PendingChange, ThemeCss } from "@/types";
 
 /**
- * Theme type - all built-in themes:
+ * Theme type:
  * - "light": Default light theme
  * - "dark": Default dark theme
  * - "system": Follows OS preference
- * - "nordic-dark": Arctic, north-bluish dark theme based on Nord
- * - "nordic-light": Arctic, north-bluish light theme based on Nord
+ * - "<theme id>-dark" / "<theme id>-light": a variant of a bundled or
+ *   extension theme, e.g. "nordic-dark"; its CSS comes from the backend
  */
-type Theme = "light" | "dark" | "system" | "nordic-dark" | "nordic-light";
+export type Theme = "light" | "dark" | "system" | `${string}-dark` | `${string}-light`;
 type AppStyle = "developer" | "web";
 
 interface EditorSettings {
@@ -88,9 +89,12 @@ interface UIState {
   setEditMode: (editMode: boolean) => void;
 }
 
+/** Style element holding the CSS of the active non built-in theme */
+const THEME_STYLE_ID = "dbfordevs-theme";
+
 export const useUIStore = create<UIState>()(
   persist(
-    (set) => ({
+    (set, get) => ({
       theme: "system",
       appStyle: "developer",
       sidebarOpen: true,
@@ -131,22 +135,44 @@ export const useUIStore = create<UIState>()(
         const root = document.documentElement;
 
         // Remove all theme classes
-        root.classList.remove("dark", "theme-nordic-dark", "theme-nordic-light");
+        for (const name of Array.from(root.classList)) {
+          if (name === "dark" || name.startsWith("theme-")) root.classList.remove(name);
+        }
 
         // Apply theme-specific class
-        if (theme === "nordic-dark") {
-          root.classList.add("theme-nordic-dark");
-        } else if (theme === "nordic-light") {
-          root.classList.add("theme-nordic-light");
-        } else if (theme === "system") {
+        if (theme === "system") {
           const prefersDark = window.matchMedia("(prefers-color-scheme: dark)").matches;
           root.classList.toggle("dark", prefersDark);
         } else if (theme === "dark") {
           root.classList.add("dark");
+        } else if (theme !== "light") {
+          // Bundled and extension themes: load the variant's CSS and turn on its class
+          const variant = theme.endsWith("-dark") ? "dark" : "light";
+          const themeId = theme.slice(0, -(variant.length + 1));
+          invoke<ThemeCss>("get_theme_css", { themeId, variant })
+            .then(({ className, css }) => {
+              if (get().theme !== theme) return;
+              let style = document.getElementById(THEME_STYLE_ID);
+              if (!style) {
+                style = document.createElement("style");
+                style.id = THEME_STYLE_ID;
+                document.head.appendChild(style);
+              }
+              style.textContent = css;
+              root.classList.add(className);
+            })
+            .catch((error) => {
+              // The theme's extension was probably uninstalled
+              console.error(`[Theme] Failed to load ${theme}:`, error);
+              get().setTheme("system");
+            });
         }
         // "light" theme - no class needed (default)
 
         set({ theme });
+        invoke("set_active_theme", { theme }).catch((error) =>
+          console.error("[Theme] Failed to save the theme choice:", error)
+        );
       },
 
       setAppStyle: (appStyle) => {
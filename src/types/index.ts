@@ -8,6 +8,8 @@ export type DatabaseType =
   | "oracle"
   | "mongodb"
   | "redis"
+  | "clickhouse"
+  | "duckdb"
   | "cockroachdb"
   | "cassandra";
 
@@ -22,6 +24,59 @@ export interface ConnectionConfig {
   password?: string;
   sslMode?: string;
   filePath?: string;
+  pool?: PoolSettings;
+  tls?: TlsSettings;
+  /** Sidebar folder; `/` separates nested folders, e.g. `Clients/Acme` */
+  group?: string;
+  /** Label color as `#rrggbb` */
+  color?: string;
+  environment?: ConnectionEnvironment;
+  /** Refuse changes to data and schema; unset means read-only for production connections */
+  readOnly?: boolean;
+  /** Most rows kept from each result set of a query run from the editor */
+  defaultRowLimit?: number;
+  /** `.env` file with variables for `${NAME}` placeholders in the other fields */
+  envFile?: string;
+}
+
+export type ConnectionEnvironment = "dev" | "staging" | "prod";
+
+export type CloudProvider = "aws" | "azure" | "gcp" | "supabase" | "neon" | "planetscale";
+
+/** Starting values for a connection to a managed database service */
+export interface ConnectionTemplate {
+  id: string;
+  name: string;
+  provider: CloudProvider;
+  databaseType: DatabaseType;
+  port: number;
+  /** TLS mode the service requires */
+  sslMode: string;
+  username?: string;
+  database?: string;
+  /** Endings the service's host names have; empty when any host is accepted */
+  hostSuffixes: string[];
+  hostExample: string;
+  notes: string;
+}
+
+/** Certificate files (PEM) for TLS connections */
+export interface TlsSettings {
+  caCertPath?: string;
+  clientCertPath?: string;
+  clientKeyPath?: string;
+  /** Overrides sslMode when set */
+  verifyMode?: "none" | "ca" | "full";
+}
+
+/** Connection pool tuning; unset fields keep the driver defaults */
+export interface PoolSettings {
+  maxConnections?: number;
+  minIdle?: number;
+  acquireTimeoutSecs?: number;
+  idleTimeoutSecs?: number;
+  /** Milliseconds a statement may run before the server cancels it */
+  statementTimeoutMs?: number;
 }
 
 export interface ConnectionInfo {
@@ -31,6 +86,10 @@ export interface ConnectionInfo {
   host?: string;
   database: string;
   connected: boolean;
+  group?: string;
+  color?: string;
+  environment?: ConnectionEnvironment;
+  readOnly: boolean;
 }
 
 export interface TestConnectionResult {
@@ -39,12 +98,50 @@ export interface TestConnectionResult {
   serverVersion?: string;
 }
 
+export interface TableUsage {
+  table: string;
+  count: number;
+  lastUsed: string;
+}
+
+/** How often a column was filtered on in a WHERE clause */
+export interface ColumnUsage {
+  /** Null when the query did not tell which table the column belongs to */
+  table: string | null;
+  column: string;
+  count: number;
+  lastUsed: string;
+}
+
+/** A connection's usage, recorded on this machine only */
+export interface ConnectionInsights {
+  connectionId: string;
+  lastConnectedAt: string | null;
+  connectCount: number;
+  queryCount: number;
+  lastQueryAt: string | null;
+  /** Most recently queried first */
+  recentTables: TableUsage[];
+  /** Most used first; weight is the count decayed by age, 1 for the top table */
+  topTables: (TableUsage & { weight: number })[];
+  /** Filtered on most often first */
+  filterColumns: ColumnUsage[];
+}
+
 // Query types
 export interface QueryRequest {
   connectionId: string;
   sql: string;
   limit?: number;
   offset?: number;
+  /** Token from a previous call, needed to run UPDATE or DELETE without WHERE */
+  confirmationToken?: string;
+  /** Run on this editor tab's own connection rather than the shared pool */
+  sessionId?: string;
+  /** Keep the rows in the backend and return only this many; fetch the rest with getResultRows */
+  windowSize?: number;
+  /** Values for the query's named placeholders (`:name`, `${name}`), written in as escaped literals */
+  params?: Record<string, CellValue>;
 }
 
 export interface QueryResult {
@@ -52,6 +149,573 @@ export interface QueryResult {
   rows: any[][];
   affectedRows?: number;
   executionTimeMs: number;
+  /** One entry per statement when a script ran several; empty for a single statement */
+  resultSets: ResultSet[];
+  /** Set when the rows are kept in the backend, for fetching more rows or a single cell */
+  resultId?: string | null;
+  /** Rows in the whole result, when it came from executeQuery */
+  totalRows?: number;
+}
+
+/**
+ * A cell value with its kind, as insert and update take them, so NULL, an
+ * empty string and the text "null" stay apart. A column left out of a row is
+ * not written at all.
+ */
+export type CellValue =
+  | { type: "null" }
+  | { type: "text"; value: string }
+  | { type: "int"; value: number }
+  | { type: "float"; value: number }
+  | { type: "bool"; value: boolean }
+  /** Base64 encoded */
+  | { type: "bytes"; value: string }
+  | { type: "json"; value: unknown }
+  /** Date, time or timestamp text, read by the database as the column's type */
+  | { type: "timestamp"; value: string };
+
+/** A binary cell of a result kept in the backend, for previewing */
+export interface CellBlob {
+  /** Sniffed from the first bytes, e.g. "image/png" */
+  contentType: string;
+  /** Without the dot */
+  extension: string;
+  size: number;
+  /** Base64; null when the blob is too large to preview */
+  data: string | null;
+}
+
+/** Rows of a result kept in the backend, from offset on */
+export interface ResultWindow {
+  resultId: string;
+  offset: number;
+  totalRows: number;
+  rows: any[][];
+}
+
+/** A query the AI assistant wrote to answer a question about the data */
+export interface DataQuestionRequest {
+  connectionId: string;
+  question: string;
+  sql: string;
+  /** Most rows to return; capped by the connection's row limit */
+  maxRows?: number;
+}
+
+/** The query that answered a question, and its rows */
+export interface DataQuestionAnswer {
+  question: string;
+  sql: string;
+  result: QueryResult;
+  /** True when the query returned more rows than were kept */
+  truncated: boolean;
+}
+
+/**
+ * Rows copied into the local database of a federated query, under `alias`.
+ * Give a `resultId`, or a `connectionId` with a `tableName` or a read-only `sql`.
+ */
+export interface FederatedSource {
+  alias: string;
+  /** A result kept by executeQuery */
+  resultId?: string;
+  connectionId?: string;
+  tableName?: string;
+  sql?: string;
+}
+
+/** A query joining rows from several connections */
+export interface FederatedQueryRequest {
+  sources: FederatedSource[];
+  /** SQLite SQL over the sources' aliases */
+  sql: string;
+  /** Most rows copied from each source (default 100000) */
+  maxSourceRows?: number;
+}
+
+export interface FederatedSourceInfo {
+  alias: string;
+  rows: number;
+  /** True when the source had more rows than were copied */
+  truncated: boolean;
+}
+
+/** Rows of a federated query, joined in an in-memory SQLite database on this machine */
+export interface FederatedQueryResult {
+  result: QueryResult;
+  sources: FederatedSourceInfo[];
+  /** What ran the query, e.g. "SQLite 3.46 (in memory, local)" */
+  engine: string;
+}
+
+/** pgvector distance: l2 `<->`, cosine `<=>`, innerProduct `<#>` (negated), l1 `<+>` */
+export type VectorMetric = "l2" | "cosine" | "innerProduct" | "l1";
+
+/** Rows of a table whose vector column is nearest a given vector */
+export interface VectorSearchRequest {
+  connectionId: string;
+  tableName: string;
+  column: string;
+  vector: number[];
+  /** Defaults to l2 */
+  metric?: VectorMetric;
+  /** Defaults to 10 */
+  limit?: number;
+}
+
+/** A query's execution plan, with the statistics and indexes of the tables it uses */
+export interface QueryPlan {
+  sql: string;
+  /** The plan as the database prints it */
+  plan: string;
+  /** True when the query was run, so the plan has actual rows and timings */
+  analyzed: boolean;
+  tables: PlanTable[];
+}
+
+/** A table the planned query reads or writes */
+export interface PlanTable {
+  name: string;
+  /** Missing when the database could not report statistics for the table */
+  stats?: TableStats;
+  indexes: IndexInfo[];
+}
+
+export interface ResultSet {
+  statement: string;
+  columns: ColumnInfo[];
+  rows: any[][];
+  affectedRows?: number;
+  executionTimeMs: number;
+}
+
+// Table browsing types
+export type SortDirection = "asc" | "desc";
+
+export interface SortColumn {
+  column: string;
+  direction: SortDirection;
+}
+
+export type FilterOperator =
+  | "equals"
+  | "notEquals"
+  | "contains"
+  | "isNull"
+  | "isNotNull"
+  | "greaterThan"
+  | "greaterOrEqual"
+  | "lessThan"
+  | "lessOrEqual"
+  | "between";
+
+export interface ColumnFilter {
+  column: string;
+  operator: FilterOperator;
+  /** Unused by isNull and isNotNull */
+  value?: unknown;
+  /** Upper bound for between */
+  valueTo?: unknown;
+}
+
+export interface BrowseRequest {
+  connectionId: string;
+  tableName: string;
+  sort?: SortColumn[];
+  /** Combined with AND */
+  filters?: ColumnFilter[];
+  /** Rows per page (default 100) */
+  limit?: number;
+  offset?: number;
+  /** Always run COUNT(*) instead of using the estimate for large tables */
+  exactCount?: boolean;
+}
+
+export interface BrowseResult extends QueryResult {
+  totalRows?: number | null;
+  /** True if totalRows comes from table statistics rather than COUNT(*) */
+  totalIsEstimate: boolean;
+  limit: number;
+  offset: number;
+}
+
+/** A row and the foreign key to follow from it */
+export interface RowReferenceRequest {
+  connectionId: string;
+  tableName: string;
+  /** Primary key of the row navigated from */
+  primaryKey: Record<string, unknown>;
+  /** From getTableRelationships; other columns of the same constraint are matched too */
+  relationship: TableRelationship;
+  /** Referencing rows returned (default 100) */
+  limit?: number;
+}
+
+/** Run a .sql file statement by statement */
+export interface SqlFileRequest {
+  connectionId: string;
+  path: string;
+  /** Keep going after a failed statement (default: stop) */
+  continueOnError?: boolean;
+  /** Statements between progress events (default 100) */
+  batchSize?: number;
+}
+
+/** Payload of the "sql-file-progress" event */
+export interface SqlFileProgress {
+  path: string;
+  bytesRead: number;
+  totalBytes: number;
+  statementsExecuted: number;
+  statementsFailed: number;
+}
+
+export interface SqlFileError {
+  /** 1-based position of the statement in the file */
+  statementIndex: number;
+  statement: string;
+  message: string;
+}
+
+export interface SqlFileSummary {
+  path: string;
+  statementsExecuted: number;
+  statementsFailed: number;
+  /** First 1000 statement errors */
+  errors: SqlFileError[];
+  /** Execution stopped at an error before the end of the file */
+  stopped: boolean;
+  executionTimeMs: number;
+}
+
+/**
+ * What a backup file holds: "native" is a pg_dump custom archive or a copy of the
+ * SQLite file; MySQL and databases without a dump tool always get a SQL script
+ */
+export type BackupFormat = "native" | "sql";
+
+export interface BackupRequest {
+  connectionId: string;
+  path: string;
+  format?: BackupFormat;
+  /** Leave out the rows */
+  schemaOnly?: boolean;
+  /** Write the built-in script even when the dump tools are installed */
+  builtin?: boolean;
+}
+
+export interface RestoreRequest {
+  connectionId: string;
+  path: string;
+  /** Drop the backup's objects before recreating them (pg_dump archives only) */
+  clean?: boolean;
+  confirmationToken?: string;
+}
+
+/** A command line tool backups and restores of a connection use */
+export interface BackupTool {
+  name: string;
+  purpose: string;
+  /** Not set when the tool is not installed */
+  path: string | null;
+  version: string | null;
+}
+
+export type BackupOperation = "backup" | "restore";
+
+/** Payload of the "backup-progress" event */
+export interface BackupProgress {
+  operation: BackupOperation;
+  path: string;
+  /** Bytes written to the backup, or read from it while restoring */
+  bytes: number;
+  totalBytes: number | null;
+  /** Latest line of the tool's output, or the table being written */
+  message: string | null;
+  done: boolean;
+}
+
+export interface BackupSummary {
+  operation: BackupOperation;
+  path: string;
+  /** The tool that did the work, or "built-in ..." */
+  method: string;
+  bytes: number;
+  /** Tables and rows written by the built-in script */
+  tables: number | null;
+  rows: number | null;
+  /** Last lines of the tool's output */
+  messages: string[];
+  executionTimeMs: number;
+}
+
+export interface TableCopyRequest {
+  /** Chosen by the caller, so the copy can be followed and cancelled */
+  transferId: string;
+  sourceConnectionId: string;
+  sourceTable: string;
+  targetConnectionId: string;
+  /** Defaults to the source table's name without its schema; created when missing */
+  targetTable?: string;
+  /** Rows per batch (default 500, at most 1000) */
+  batchSize?: number;
+}
+
+/** Payload of the "table-copy-progress" event */
+export interface TableCopyProgress {
+  transferId: string;
+  rowsCopied: number;
+  rowsFailed: number;
+  /** From table statistics where the database keeps them */
+  totalRows: number | null;
+  done: boolean;
+}
+
+export interface TableCopySummary {
+  transferId: string;
+  sourceTable: string;
+  targetTable: string;
+  /** The CREATE TABLE run on the target, when the table was created */
+  createStatement: string | null;
+  rowsCopied: number;
+  rowsFailed: number;
+  /** Failed rows, by 1-based position in the source; capped at 1000 */
+  errors: { row: number; message: string }[];
+  /** Stopped early; the rows copied until then stay in the target */
+  cancelled: boolean;
+  executionTimeMs: number;
+}
+
+/** A table, view or routine whose name, definition or comment matched a search */
+export interface ObjectMatch {
+  /** Named as the explorer lists tables */
+  object: string;
+  schema: string | null;
+  /** "table", "view", "materialized view", "function", "procedure", "trigger", ... */
+  objectType: string;
+  /** The matching column, for column matches and column comments */
+  column: string | null;
+  /** The column's type, or the line of the definition or comment that matched */
+  detail: string | null;
+}
+
+export interface ObjectSearchResult {
+  pattern: string;
+  tables: ObjectMatch[];
+  columns: ObjectMatch[];
+  views: ObjectMatch[];
+  routines: ObjectMatch[];
+  comments: ObjectMatch[];
+  /** A group reached its limit of 200 matches */
+  truncated: boolean;
+}
+
+export interface DataSearchOptions {
+  /** Match the term as a POSIX regular expression (PostgreSQL only) */
+  regex?: boolean;
+  /** Matching rows returned per table; defaults to 50, at most 1000 */
+  maxRowsPerTable?: number;
+  /** Tables searched at the same time; defaults to 4, at most 8 */
+  concurrency?: number;
+}
+
+/** A text cell containing the search term */
+export interface DataMatch {
+  column: string;
+  /** The cell's text, shortened around the match */
+  value: string;
+}
+
+export interface DataMatchRow {
+  /** Primary key of the row; empty when the table has none */
+  primaryKey: Record<string, unknown>;
+  matches: DataMatch[];
+}
+
+/** One table's matches, also sent as a "table-data-search-matches" event when the table is done */
+export interface TableDataMatches {
+  searchId: string;
+  table: string;
+  /** Text columns that were searched */
+  columns: string[];
+  rows: DataMatchRow[];
+  /** More rows matched than the per-table limit */
+  truncated: boolean;
+  /** Why the table could not be searched */
+  error: string | null;
+}
+
+export interface DataSearchSummary {
+  searchId: string;
+  /** Every table searched, in the order they finished */
+  tables: TableDataMatches[];
+  executionTimeMs: number;
+}
+
+export type ChartAggregation = "count" | "sum" | "avg" | "min" | "max";
+
+export interface ChartMeasure {
+  /** Omitted only with "count", which then counts rows */
+  column?: string;
+  aggregation: ChartAggregation;
+}
+
+/** What to plot from a query result; the backend aggregates the rows */
+export interface ChartSpec {
+  xColumn: string;
+  measures: ChartMeasure[];
+  /** One series per distinct value of this column, per measure */
+  groupBy?: string;
+  /** Defaults to 1000 distinct x values */
+  maxPoints?: number;
+  /** Defaults to 20 groups */
+  maxGroups?: number;
+}
+
+export interface ChartSeries {
+  name: string;
+  /** Index into the spec's measures */
+  measure: number;
+  group: unknown | null;
+  /** null where no row had a value */
+  values: (number | null)[];
+}
+
+export interface ChartData {
+  labels: unknown[];
+  series: ChartSeries[];
+  rowsUsed: number;
+  /** Rows past the x value or group limits */
+  rowsDropped: number;
+  truncated: boolean;
+}
+
+export type ExportFormat = "csv" | "json" | "ndjson" | "xlsx" | "parquet";
+
+export interface ExportOptions {
+  /** Indent JSON output (NDJSON is never indented) */
+  pretty?: boolean;
+  /** chrono format string applied to date and time columns */
+  dateFormat?: string;
+  /** Write binary columns as base64 (default) instead of \x hex */
+  binaryAsBase64?: boolean;
+  /** Write a header row (CSV and XLSX, default true) */
+  includeHeaders?: boolean;
+  /** Worksheet name for XLSX (default "Results", or the table name) */
+  sheetName?: string;
+  /** Write each result set of a script to its own XLSX sheet */
+  sheetPerResultSet?: boolean;
+  /** Rows fetched and written per Parquet row group (default 10000) */
+  batchSize?: number;
+}
+
+export type CopyFormat = "markdown" | "html" | "tsv" | "json" | "insert";
+
+/** Rows to copy: those given, or every row of a result kept in the backend */
+export interface CopyResultsRequest {
+  format: CopyFormat;
+  columns: ColumnInfo[];
+  rows?: unknown[][];
+  /** Copy every row of this stored result instead of rows */
+  resultId?: string;
+  /** Table named in INSERT statements */
+  tableName?: string;
+  /** Connection whose quoting the INSERT statements use */
+  connectionId?: string;
+}
+
+export interface ExportRequest {
+  connectionId: string;
+  sql?: string;
+  /** Export this whole table instead of running sql */
+  tableName?: string;
+  format: ExportFormat;
+  path: string;
+  options?: ExportOptions;
+}
+
+/** Payload of the "export-progress" event */
+export interface ExportProgress {
+  path: string;
+  rowsWritten: number;
+}
+
+export interface ExportSummary {
+  path: string;
+  format: ExportFormat;
+  rows: number;
+  bytes: number;
+  executionTimeMs: number;
+}
+
+export type ImportFormat = "csv" | "tsv" | "json";
+
+export type ImportColumnType = "boolean" | "integer" | "float" | "date" | "timestamp" | "text";
+
+/** How to read an import file; unset fields are detected */
+export interface ImportFileOptions {
+  format?: ImportFormat;
+  delimiter?: string;
+  /** First CSV/TSV record holds column names (default true) */
+  hasHeader?: boolean;
+}
+
+export interface ImportColumn {
+  name: string;
+  columnType: ImportColumnType;
+  nullable: boolean;
+}
+
+export interface ImportPreview {
+  format: ImportFormat;
+  delimiter?: string;
+  hasHeader: boolean;
+  columns: ImportColumn[];
+  rows: any[][];
+  totalRows: number;
+}
+
+export interface ImportColumnMapping {
+  source: string;
+  target: string;
+  /** SQL type used when creating the table */
+  dataType?: string;
+}
+
+export interface ImportRequest {
+  connectionId: string;
+  path: string;
+  tableName: string;
+  mode: "create" | "append";
+  options?: ImportFileOptions;
+  /** All file columns under their own names when omitted */
+  mapping?: ImportColumnMapping[];
+  /** Rows inserted per transaction (default 500) */
+  batchSize?: number;
+}
+
+export interface ImportRowError {
+  /** 1-based data row, not counting the header */
+  row: number;
+  message: string;
+}
+
+export interface ImportSummary {
+  tableName: string;
+  rowsImported: number;
+  rowsFailed: number;
+  /** First 1000 row errors */
+  errors: ImportRowError[];
+  executionTimeMs: number;
+}
+
+export interface MockDataSummary {
+  tableName: string;
+  rowsInserted: number;
+  rowsFailed: number;
+  /** First 1000 row errors */
+  errors: ImportRowError[];
+  executionTimeMs: number;
 }
 
 export interface ColumnInfo {
@@ -59,6 +723,10 @@ export interface ColumnInfo {
   dataType: string;
   nullable: boolean;
   isPrimaryKey: boolean;
+  /** Type name exactly as the driver reports it */
+  nativeType?: string | null;
+  /** PostgreSQL type OID */
+  typeOid?: number | null;
 }
 
 export interface QueryHistoryEntry {
@@ -75,8 +743,24 @@ export interface QueryHistoryEntry {
 export interface TableInfo {
   name: string;
   schema?: string;
+  /** "BASE TABLE", or "PARTITIONED TABLE" and "PARTITION" for partitioned tables and their partitions */
   tableType: string;
   rowCount?: number;
+  /** Table this one is a partition of */
+  parentTable?: string;
+  /** Pinned to the connection's favorites */
+  pinned?: boolean;
+}
+
+export type PinnedObjectType = "table" | "view" | "query";
+
+/** A table, view or saved query pinned to a connection's favorites */
+export interface PinnedObject {
+  connectionId: string;
+  objectType: PinnedObjectType;
+  /** Table or view name as listed by getTables, or the saved query's ID */
+  name: string;
+  pinnedAt: string;
 }
 
 export interface TableSchema {
@@ -86,6 +770,65 @@ export interface TableSchema {
   foreignKeys: ForeignKeyInfo[];
 }
 
+/** Options for generateDatabaseDdl */
+export interface DatabaseDdlOptions {
+  /** Start the script with DROP statements for every object */
+  includeDrops?: boolean;
+  /** Qualify every name with its schema or database */
+  qualifyNames?: boolean;
+}
+
+/** Options for truncateTable; only PostgreSQL uses them */
+export interface TruncateOptions {
+  /** Reset sequences owned by the table's columns */
+  restartIdentity?: boolean;
+  /** Also truncate tables that reference this one */
+  cascade?: boolean;
+}
+
+/** Options for createDatabase; unsupported options are rejected */
+export interface CreateDatabaseOptions {
+  name: string;
+  /** Owning role (PostgreSQL) or login (SQL Server) */
+  owner?: string;
+  /** ENCODING on PostgreSQL, CHARACTER SET on MySQL */
+  charset?: string;
+  /** LC_COLLATE/LC_CTYPE on PostgreSQL, COLLATE on MySQL and SQL Server */
+  collation?: string;
+}
+
+export interface CreateSchemaOptions {
+  name: string;
+  /** AUTHORIZATION role (PostgreSQL) or user (SQL Server) */
+  owner?: string;
+}
+
+/** Returned instead of running a destructive command; call it again with the token to go ahead */
+export interface ConfirmationRequired {
+  status: "confirmationRequired";
+  token: string;
+  summary: string;
+  /** Table the command changes, when it targets one */
+  table?: string;
+  /** Rows the command would remove or change, from table statistics */
+  estimatedRows?: number;
+  /** Other objects the command drops or changes along with its target */
+  dependents: string[];
+  expiresInSecs: number;
+}
+
+/** Outcome of a command guarded by a confirmation token */
+export type Confirmable<T> = ConfirmationRequired | ({ status: "executed" } & T);
+
+/** Column added with addColumn */
+export interface NewColumn {
+  name: string;
+  dataType: string;
+  nullable: boolean;
+  /** SQL expression used as the column default, e.g. `0` or `'pending'` */
+  defaultValue?: string;
+}
+
 export interface ForeignKeyInfo {
   column: string;
   referencesTable: string;
@@ -114,6 +857,13 @@ export interface ExtendedColumnInfo {
   isPrimaryKey: boolean;
   defaultValue?: string;
   comment?: string;
+  /** Identity or auto-increment column whose value the database assigns */
+  isIdentity: boolean;
+  /** Identity that rejects explicit values (GENERATED ALWAYS, SQL Server IDENTITY) */
+  identityAlways: boolean;
+  /** Expression of a generated/computed column */
+  generationExpression?: string;
+  collation?: string;
 }
 
 export interface TableProperties {
@@ -124,10 +874,66 @@ export interface TableProperties {
   foreignKeys: ForeignKeyInfo[];
   indexes: IndexInfo[];
   constraints: ConstraintInfo[];
+  /** Estimated for large tables; see rowCountExact */
   rowCount?: number;
+  /** Whether rowCount was counted rather than estimated */
+  rowCountExact: boolean;
   tableComment?: string;
 }
 
+/** Descriptions to store as comments on a table and its columns */
+export interface SchemaComments {
+  /** Missing leaves the table's comment as it is */
+  tableComment?: string;
+  columns: ColumnComment[];
+}
+
+/** A column's description; an empty comment removes the existing one */
+export interface ColumnComment {
+  column: string;
+  comment: string;
+}
+
+/** Storage figures for a table; fields the database does not track are missing */
+export interface TableStats {
+  tableName: string;
+  /** Data on disk, excluding indexes */
+  tableSizeBytes?: number;
+  indexSizeBytes?: number;
+  totalSizeBytes?: number;
+  /** From table statistics, so it can lag behind COUNT(*) */
+  approximateRows?: number;
+  /** Rows deleted or updated but not yet vacuumed (PostgreSQL) */
+  deadRows?: number;
+  /** deadRows as a share of all row versions, 0 to 1; high values call for VACUUM */
+  deadRowRatio?: number;
+  /** Allocated but unused space that OPTIMIZE TABLE, compact or a rebuild would reclaim */
+  freeBytes?: number;
+  lastVacuum?: string;
+  lastAnalyze?: string;
+}
+
+/** How a partitioned table is split */
+export interface TablePartitioning {
+  tableName: string;
+  /** RANGE, LIST, HASH or KEY */
+  strategy: string;
+  /** Partition key, e.g. created_at or YEAR(created_at) */
+  key: string;
+  partitions: PartitionInfo[];
+}
+
+export interface PartitionInfo {
+  name: string;
+  /** Bound clause, e.g. FOR VALUES FROM ('2024-01-01') TO ('2025-01-01') or VALUES LESS THAN (2025) */
+  bound: string;
+  sizeBytes?: number;
+  /** From table statistics, so it can lag behind COUNT(*) */
+  approximateRows?: number;
+  /** Set when the partition is itself partitioned */
+  subPartitioning?: string;
+}
+
 export interface TableRelationship {
   sourceTable: string;
   sourceColumn: string;
@@ -136,6 +942,381 @@ export interface TableRelationship {
   constraintName?: string;
 }
 
+// ER diagram types
+
+export interface ErColumn {
+  name: string;
+  dataType: string;
+  nullable: boolean;
+  isPrimaryKey: boolean;
+  isForeignKey: boolean;
+  isUnique: boolean;
+}
+
+export interface ErNode {
+  /** Table name as returned by getAllTableSchemas, e.g. `public.users` */
+  id: string;
+  schema?: string;
+  name: string;
+  columns: ErColumn[];
+}
+
+export type Cardinality = "oneToOne" | "manyToOne";
+
+export interface ErEdge {
+  source: string;
+  sourceColumns: string[];
+  target: string;
+  targetColumns: string[];
+  cardinality: Cardinality;
+  /** Every foreign key column is nullable */
+  optional: boolean;
+}
+
+export interface ErModel {
+  nodes: ErNode[];
+  edges: ErEdge[];
+}
+
+// Privilege types
+/** A login or role on the server */
+export interface DatabaseUser {
+  name: string;
+  /** Account host on MySQL, e.g. `%` or `localhost` */
+  host?: string;
+  /** False for PostgreSQL NOLOGIN roles and locked MySQL accounts */
+  canLogin: boolean;
+  isSuperuser: boolean;
+  /** Roles granted to this user */
+  memberOf: string[];
+}
+
+/** Where a privilege that applies to a table was granted */
+export type PrivilegeScope = "table" | "database" | "server";
+
+/** One privilege a grantee holds on a table; a grants matrix is grantee x privilegeType */
+export interface TablePrivilege {
+  /** Role name, `PUBLIC`, or `'user'@'host'` on MySQL */
+  grantee: string;
+  privilegeType: string;
+  isGrantable: boolean;
+  grantor?: string;
+  scope: PrivilegeScope;
+}
+
+// Monitoring types
+/** Server-wide health figures; fields the server does not expose are missing */
+export interface ServerStats {
+  activeConnections?: number;
+  maxConnections?: number;
+  uptimeSeconds?: number;
+  /** Share of reads served from memory, 0 to 1 */
+  cacheHitRatio?: number;
+  /** Largest first */
+  databaseSizes: DatabaseSize[];
+}
+
+export interface DatabaseSize {
+  name: string;
+  sizeBytes: number;
+}
+
+/** A session that is running a statement */
+export interface ActiveQuery {
+  /** Pass to killSession */
+  sessionId: string;
+  user?: string;
+  database?: string;
+  /** Client address */
+  client?: string;
+  state?: string;
+  query?: string;
+  durationMs?: number;
+}
+
+/** Payload of the "server-monitor" event */
+export interface ServerMonitorSnapshot {
+  connectionId: string;
+  stats?: ServerStats;
+  activeQueries: ActiveQuery[];
+  /** Set when the poll failed; the monitor keeps polling */
+  error?: string;
+}
+
+/** Payload of the "db-notification" event: a NOTIFY on a channel listened on */
+export interface DbNotification {
+  connectionId: string;
+  channel: string;
+  payload: string;
+  processId: number;
+  /** RFC 3339 */
+  receivedAt: string;
+}
+
+/** Payload of the "table-changed" event: rows changed in a watched table */
+export interface TableChangeEvent {
+  connectionId: string;
+  table: string;
+  inserted: number;
+  updated: number;
+  deleted: number;
+}
+
+export type SlowQueryOrder = "totalTime" | "meanTime" | "calls";
+
+/** Aggregated timings for one normalized statement */
+export interface SlowQueryEntry {
+  /** Statement text with literals replaced by placeholders */
+  query: string;
+  calls: number;
+  totalTimeMs: number;
+  meanTimeMs: number;
+  maxTimeMs?: number;
+  /** Rows returned or affected across all calls */
+  rows?: number;
+  database?: string;
+}
+
+// Migration types
+export interface ColumnRename {
+  from: string;
+  to: string;
+}
+
+export interface MigrationRequest {
+  connectionId: string;
+  tableName: string;
+  desired: TableSchema;
+  /** Columns renamed in the editor; without these a rename looks like drop + add */
+  renames?: ColumnRename[];
+  /** Desired secondary indexes; existing indexes are kept when unset */
+  indexes?: IndexInfo[];
+}
+
+export interface MigrationPlan {
+  tableName: string;
+  statements: string[];
+  warnings: string[];
+  /** True if SQLite has to copy the table to apply the change */
+  rebuild: boolean;
+}
+
+// Data diff types
+export interface TableRef {
+  connectionId: string;
+  tableName: string;
+}
+
+export interface TableDiffRequest {
+  source: TableRef;
+  target: TableRef;
+  /** Defaults to the source table's primary key */
+  keyColumns?: string[];
+  /** Rows fetched per page (default 1000) */
+  chunkSize?: number;
+  /** Row differences returned in detail (default 1000) */
+  maxRows?: number;
+  /** Return DML that makes the target match the source */
+  generateSql?: boolean;
+}
+
+export interface RowDiff {
+  /** inserted: only in source, deleted: only in target */
+  kind: "inserted" | "updated" | "deleted";
+  key: Record<string, unknown>;
+  changedColumns: string[];
+}
+
+export interface TableDiffResult {
+  keyColumns: string[];
+  comparedColumns: string[];
+  sourceRows: number;
+  targetRows: number;
+  inserted: number;
+  updated: number;
+  deleted: number;
+  unchanged: number;
+  rows: RowDiff[];
+  truncated: boolean;
+  sql?: string[] | null;
+  executionTimeMs: number;
+}
+
+/** How a row of the second result differs from the first */
+export type ResultRowChange = "added" | "removed" | "changed";
+
+export interface ResultRowDiff {
+  change: ResultRowChange;
+  key: Record<string, unknown>;
+  /** The row in the first result, in `columns` order */
+  before?: unknown[] | null;
+  /** The row in the second result, in `columns` order */
+  after?: unknown[] | null;
+  changedColumns: string[];
+}
+
+/** Differences between two kept query results */
+export interface ResultDiff {
+  /** Columns rows were matched on; every compared column when none were given */
+  keyColumns: string[];
+  /** Columns both results have */
+  columns: string[];
+  removedColumns: string[];
+  addedColumns: string[];
+  rowsA: number;
+  rowsB: number;
+  added: number;
+  removed: number;
+  changed: number;
+  unchanged: number;
+  rows: ResultRowDiff[];
+  truncated: boolean;
+  executionTimeMs: number;
+}
+
+// Editor types
+export type KeywordCase = "upper" | "lower" | "preserve";
+
+export type CommaStyle = "trailing" | "leading";
+
+export interface SqlFormatOptions {
+  keywordCase?: KeywordCase;
+  /** Spaces per indentation level, 1-8 (default 2) */
+  indentWidth?: number;
+  commaStyle?: CommaStyle;
+}
+
+export interface SqlDiagnostic {
+  /** Rule that fired, e.g. `missing-where` */
+  code: string;
+  message: string;
+  severity: "warning" | "info";
+  /** 1-based; the end is exclusive */
+  line: number;
+  column: number;
+  endLine: number;
+  endColumn: number;
+}
+
+export type CompletionKind = "keyword" | "schema" | "table" | "column" | "alias";
+
+export interface SqlCompletion {
+  label: string;
+  kind: CompletionKind;
+  /** Column type and table, or the schema of a table */
+  detail?: string;
+  /** Quoted where the name needs it */
+  insertText: string;
+}
+
+/** One data or schema change made through the app, as kept in the audit log */
+export interface AuditEntry {
+  id: string;
+  /** RFC 3339, UTC */
+  timestamp: string;
+  /** Operating system account running the app */
+  user: string;
+  databaseUser?: string;
+  connectionId: string;
+  connectionName: string;
+  /** Command that made the change, e.g. `update_row` */
+  operation: string;
+  table?: string;
+  /** SQL sent to the database, or a description of the change when the driver builds the SQL */
+  statement: string;
+  params: unknown[];
+  affectedRows?: number;
+  success: boolean;
+  error?: string;
+}
+
+/** Narrows getAuditLog and exportAuditLog; unset fields match everything */
+export interface AuditLogFilter {
+  connectionId?: string;
+  table?: string;
+  operation?: string;
+  /** RFC 3339; entries at `since` are included, entries at `until` are not */
+  since?: string;
+  until?: string;
+  /** Most recent entries to return (default 500 for getAuditLog, no limit for exports) */
+  limit?: number;
+}
+
+/** Tokens spent on one AI request, as recorded with recordAiUsage */
+export interface AIUsageRecord {
+  /** RFC 3339, UTC; set by the backend */
+  timestamp?: string;
+  provider: string;
+  model: string;
+  /** What the request was for, e.g. `chat` or `generate_sql` */
+  operation: string;
+  promptTokens: number;
+  completionTokens: number;
+  totalTokens: number;
+  /** US dollars; 0 for models without a price */
+  estimatedCost: number;
+  /** How long the request took, for the performance metrics */
+  durationMs?: number;
+}
+
+/** Tokens and cost of the AI requests made in one calendar month */
+export interface AIUsageStats {
+  /** `YYYY-MM`, UTC */
+  month: string;
+  requestCount: number;
+  promptTokens: number;
+  completionTokens: number;
+  totalTokens: number;
+  estimatedCost: number;
+  /** Totals per model, most tokens first */
+  byModel: AIModelUsage[];
+}
+
+/** One model's share of a month's AI usage */
+export interface AIModelUsage {
+  provider: string;
+  model: string;
+  requestCount: number;
+  totalTokens: number;
+  estimatedCost: number;
+}
+
+/** A table or column as indexed for semantic schema search */
+export interface SchemaDocument {
+  table: string;
+  /** Null for the table itself */
+  column: string | null;
+  /** What is embedded: names split into words, the type and any comment */
+  text: string;
+}
+
+/** The embedding model a connection's schema index was built with */
+export interface SchemaIndexInfo {
+  /** `provider/model`, or `local` for the built-in embeddings */
+  model: string;
+  dimensions: number;
+  documentCount: number;
+  createdAt: string;
+}
+
+/** A table or column matching a semantic search */
+export interface SchemaSearchHit {
+  table: string;
+  column: string | null;
+  text: string;
+  /** Cosine similarity to the query, from -1 to 1 */
+  score: number;
+}
+
+/** An AI system prompt the user can replace with their own template */
+export interface PromptTemplate {
+  /** Identifier, e.g. `sql_generation` */
+  name: string;
+  description: string;
+  /** The user's template; null when the built-in prompt is used */
+  content: string | null;
+}
+
 // Validator types
 export interface ValidatorInfo {
   id: string;
@@ -169,6 +1350,282 @@ export interface ValidationResult {
   warnings: ValidationMessage[];
 }
 
+// Marketplace types
+/** An extension listed in the marketplace registry */
+export interface MarketplaceExtension {
+  id: string;
+  name: string;
+  description: string;
+  version: string;
+  author: string;
+  category: string;
+  downloads: number;
+  /** Average rating out of 5; 0 when unrated */
+  rating: number;
+  ratingCount: number;
+  isOfficial: boolean;
+  isFeatured: boolean;
+  tags: string[];
+  repository?: string;
+  /** Where the `.dbx` package can be downloaded from */
+  downloadUrl?: string;
+  /** SHA-256 of the package, in hex; required to install from the registry */
+  sha256?: string;
+}
+
+/** `manifest.json` at the root of a `.dbx` extension package */
+export interface ExtensionManifest {
+  id: string;
+  name: string;
+  version: string;
+  description: string;
+  author: string;
+  category: string;
+  /** Entry point inside the package, a `.js` or `.wasm` file; theme-only extensions have none */
+  main?: string | null;
+  minAppVersion?: string;
+  permissions: string[];
+  contributes: ExtensionContributions;
+}
+
+/** What an extension adds to the app, declared in its manifest */
+export interface ExtensionContributions {
+  settings: SettingContribution[];
+  commands: CommandContribution[];
+  /** Only for extensions in the "Themes" category */
+  themes: ThemeContribution[];
+}
+
+/** A color theme: files in the package that declare its CSS variables */
+export interface ThemeContribution {
+  id: string;
+  name: string;
+  dark?: string | null;
+  light?: string | null;
+}
+
+/** A command the extension offers; it registers the handler when activated */
+export interface CommandContribution {
+  /** `<extension id>.<name>` */
+  id: string;
+  title: string;
+  description: string;
+  /** Suggested shortcut, e.g. "Mod+Shift+F"; left unbound when taken */
+  shortcut?: string | null;
+}
+
+/** A contributed command with the extension that provides it */
+export interface ExtensionCommandInfo extends CommandContribution {
+  extensionId: string;
+  extensionName: string;
+}
+
+/** Payload of the "extension-command" event */
+export interface ExtensionCommandRequest {
+  requestId: string;
+  extensionId: string;
+  commandId: string;
+  args: unknown;
+}
+
+/** What a contributed command returned */
+export interface ExtensionCommandResult {
+  extensionId: string;
+  commandId: string;
+  result: unknown;
+  durationMs: number;
+}
+
+export type SettingType = "string" | "number" | "boolean" | "select" | "password";
+
+/** A setting the user can change in the extension's settings form */
+export interface SettingContribution {
+  key: string;
+  title: string;
+  description: string;
+  type: SettingType;
+  /** Not allowed for passwords */
+  default?: unknown;
+  /** Choices of a `select` setting */
+  options: string[];
+}
+
+/** An extension's settings as the settings form sees them */
+export interface ExtensionSettings {
+  /** Saved values over the contributed defaults; passwords are left out */
+  values: Record<string, unknown>;
+  /** Password settings that have a value */
+  secrets: string[];
+}
+
+export type ExtensionRuntime = "js" | "wasm";
+
+/** What the runtime needs to start an extension */
+export interface ExtensionEntry {
+  manifest: ExtensionManifest;
+  runtime: ExtensionRuntime;
+  /** The entry script, or the WebAssembly module in base64 */
+  source: string;
+  /** Current settings, passwords included */
+  settings: Record<string, unknown>;
+}
+
+/** Response to a `fetch` an extension with the `network` permission made through the backend */
+export interface ExtensionFetchResponse {
+  status: number;
+  statusText: string;
+  headers: [string, string][];
+  /** Response body in base64 */
+  body: string;
+}
+
+/** An extension unpacked into the app's extension directory */
+export interface InstalledExtension {
+  manifest: ExtensionManifest;
+  path: string;
+  sha256: string;
+  installedAt: string;
+  /** Compiled into the app; path, checksum and install time are empty */
+  bundled: boolean;
+  isOfficial: boolean;
+  status: InstalledExtensionStatus;
+}
+
+/**
+ * "installed" for packages, whose runtime state is in the extensions store;
+ * `{ error }` when a lifecycle hook or runtime call failed, until restarted
+ */
+export type InstalledExtensionStatus = "installed" | "active" | "disabled" | { error: string };
+
+/** The command holding a shortcut another binding asked for */
+export interface ShortcutConflict {
+  shortcut: string;
+  commandId: string;
+  title: string;
+}
+
+/**
+ * A command in the resolved keymap. Shortcuts look like "Mod+Shift+K", where
+ * Mod is Cmd on macOS and Ctrl elsewhere.
+ */
+export interface KeyBinding {
+  commandId: string;
+  title: string;
+  source: "builtin" | "extension";
+  extensionId: string | null;
+  defaultShortcut: string | null;
+  /** The shortcut in effect; null when unbound or lost to a conflict */
+  shortcut: string | null;
+  /** Changed by the user */
+  overridden: boolean;
+  /** Handled by the editor itself, so it cannot be changed */
+  fixed: boolean;
+  conflict: ShortcutConflict | null;
+}
+
+export type ExtensionLogLevel = "debug" | "info" | "warn" | "error";
+
+/** One line of an extension's log */
+export interface ExtensionLogEntry {
+  timestamp: string;
+  level: ExtensionLogLevel;
+  message: string;
+}
+
+/** Payload of the "extension-status" event */
+export interface ExtensionStatusEvent {
+  extensionId: string;
+  status: InstalledExtensionStatus;
+}
+
+export interface MarketplaceSearchResult {
+  extensions: MarketplaceExtension[];
+  /** Every category in the index, for the filter */
+  categories: string[];
+  registryUrl: string;
+  fetchedAt: string;
+  /** True when the registry could not be reached and a saved copy was used */
+  offline: boolean;
+}
+
+export type ThemeVariant = "dark" | "light";
+
+/** A theme beyond the built-in light and dark, bundled or from an extension */
+export interface ThemeInfo {
+  id: string;
+  name: string;
+  source: "bundled" | "extension";
+  extensionId?: string | null;
+  variants: ThemeVariant[];
+}
+
+/** A theme variant's stylesheet, scoped to the class that turns it on */
+export interface ThemeCss {
+  themeId: string;
+  variant: ThemeVariant;
+  /** Set on the document root, e.g. `theme-nordic-dark` */
+  className: string;
+  css: string;
+}
+
+// Application log types
+export type AppLogLevel = "trace" | "debug" | "info" | "warn" | "error";
+
+/** An event from the application log */
+export interface AppLogEntry {
+  timestamp: string;
+  level: AppLogLevel;
+  /** The module that logged it, e.g. "dbfordevs::db::manager" */
+  target: string;
+  message: string;
+  fields: Record<string, string>;
+}
+
+export interface AppLogs {
+  /** Oldest first */
+  entries: AppLogEntry[];
+  /** Where the log files are kept */
+  directory: string;
+}
+
+/** Log levels for the app and for single modules, e.g. "db" */
+export interface LogSettings {
+  level: AppLogLevel | "off";
+  modules: Record<string, AppLogLevel | "off">;
+}
+
+// Performance metric types
+export interface LatencyBucket {
+  /** Upper bound; null for the last bucket, which takes everything slower */
+  leMs: number | null;
+  count: number;
+}
+
+/** Durations of a kind of operation; percentiles are bucket bounds, so estimates */
+export interface LatencyHistogram {
+  count: number;
+  totalMs: number;
+  maxMs: number;
+  p50Ms: number | null;
+  p95Ms: number | null;
+  p99Ms: number | null;
+  buckets: LatencyBucket[];
+}
+
+/** Performance of this session, measured locally and never sent anywhere */
+export interface PerformanceMetrics {
+  /** RFC 3339; when measuring started or was last reset */
+  since: string;
+  queries: LatencyHistogram;
+  failedQueries: number;
+  connections: { active: number; opened: number; failed: number };
+  /** Table schemas cached for editor completions */
+  schemaCache: { hits: number; misses: number; hitRate: number | null };
+  aiRequests: LatencyHistogram;
+  /** By what the request was for, e.g. "chat" */
+  aiOperations: Record<string, LatencyHistogram>;
+}
+
 // UI types
 export interface Tab {
   id: string;
@@ -177,6 +1634,50 @@ export interface Tab {
   connectionId: string;
   content?: string;
   tableName?: string; // Full table identifier for "table", "properties", and "diagram" types
+  /** Share of a query tab's height given to the results, in percent */
+  resultPaneSize?: number;
+  editorScroll?: ScrollPosition;
+}
+
+/** How far a view was scrolled, in pixels */
+export interface ScrollPosition {
+  top: number;
+  left: number;
+}
+
+/** The open tabs, saved as the user works and restored on the next start */
+export interface Workspace {
+  /** RFC 3339; set by the backend */
+  savedAt?: string;
+  tabs: Tab[];
+  activeTabId: string | null;
+  activeConnectionId: string | null;
+}
+
+/** Kind of value a saved query's variable takes; values are entered as text */
+export type VariableType = "text" | "integer" | "number" | "boolean" | "date";
+
+/** A named placeholder of a saved query */
+export interface QueryVariable {
+  /** As written in the SQL without the `:` or `${}` */
+  name: string;
+  type: VariableType;
+  /** Used when neither the run nor the connection's preset gives a value */
+  defaultValue?: string | null;
+}
+
+/** A query kept for reuse, with the variables it takes */
+export interface SavedQuery {
+  /** Empty until first saved */
+  id: string;
+  name: string;
+  sql: string;
+  description?: string | null;
+  variables: QueryVariable[];
+  /** Variable values per connection ID, so one query runs against each environment */
+  presets: Record<string, Record<string, string>>;
+  /** RFC 3339; set by the backend */
+  updatedAt?: string;
 }
 
 export interface PendingChange {
@@ -186,5 +1687,7 @@ export interface PendingChange {
   originalData?: Record<string, unknown>;
   newData?: Record<string, unknown>;
   primaryKey: Record<string, unknown>;
+  /** Columns of the row, giving the kind each value is sent as */
+  columns?: ColumnInfo[];
 }
 